@@ -1,4 +1,4 @@
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use orbit_essentials::types::WasmModuleExtraChunks;
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -9,3 +9,19 @@ pub struct UploadCanisterModulesInput {
     pub station_wasm_module: Option<Vec<u8>>,
     pub station_wasm_module_extra_chunks: Option<Option<WasmModuleExtraChunks>>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SetStationSubnetsInput {
+    pub subnets: Vec<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SetTombstoneGracePeriodInput {
+    pub grace_period_secs: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ListStationSubnetsResponse {
+    pub subnets: Vec<Principal>,
+    pub subnet_types: Vec<String>,
+}
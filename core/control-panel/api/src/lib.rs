@@ -28,3 +28,7 @@ pub use canister::*;
 /// Registry DTOs.
 mod registry;
 pub use registry::*;
+
+/// Notification DTOs.
+mod notification;
+pub use notification::*;
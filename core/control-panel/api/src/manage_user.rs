@@ -6,6 +6,11 @@ pub struct DeleteUserResponse {
     pub user: UserDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RestoreUserResponse {
+    pub user: UserDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct RegisterUserInput {
     pub station: Option<UserStationDTO>,
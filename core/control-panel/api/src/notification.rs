@@ -0,0 +1,57 @@
+use crate::TimestampRfc3339;
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum NotificationChannelDTO {
+    Email(String),
+    Webhook(NotificationWebhookDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NotificationWebhookDTO {
+    pub canister_id: Principal,
+    pub method_name: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SetNotificationChannelsInput {
+    pub channels: Vec<NotificationChannelDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NotifyApproversInput {
+    pub request_id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub approvers: Vec<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NotifyApproversResponse {
+    pub notified_users: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalReminderDTO {
+    pub station_id: Principal,
+    pub request_id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalReminderEmailDTO {
+    pub email: String,
+    pub reminder: ApprovalReminderDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TakeApprovalReminderEmailsInput {
+    pub limit: Option<u16>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TakeApprovalReminderEmailsResponse {
+    pub emails: Vec<ApprovalReminderEmailDTO>,
+}
@@ -144,9 +144,28 @@ pub enum RegistryEntrySortBy {
 pub struct NextWasmModuleVersionInput {
     pub name: String,
     pub current_version: String,
+    pub max_version: Option<String>,
 }
 
 #[derive(CandidType, Deserialize, serde::Serialize, Clone, Debug, Eq, PartialEq)]
 pub struct NextWasmModuleVersionResponse {
     pub entry: Option<RegistryEntryDTO>,
 }
+
+#[derive(CandidType, Deserialize, serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct GetCompatibilityMatrixInput {
+    pub name: String,
+}
+
+#[derive(CandidType, Deserialize, serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct WasmModuleCompatibilityDTO {
+    pub version: String,
+    pub dependencies: Vec<WasmModuleRegistryEntryDependencyDTO>,
+    pub api_version: Option<String>,
+    pub min_upgrade_from: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, serde::Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct GetCompatibilityMatrixResponse {
+    pub entries: Vec<WasmModuleCompatibilityDTO>,
+}
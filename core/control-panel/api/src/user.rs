@@ -1,4 +1,4 @@
-use crate::TimestampRfc3339;
+use crate::{NotificationChannelDTO, TimestampRfc3339};
 use candid::{CandidType, Deserialize, Principal};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -6,6 +6,8 @@ pub struct UserDTO {
     pub identity: Principal,
     pub subscription_status: UserSubscriptionStatusDTO,
     pub last_active: TimestampRfc3339,
+    pub notification_channels: Vec<NotificationChannelDTO>,
+    pub deleted_at: Option<TimestampRfc3339>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -1,4 +1,4 @@
-use crate::UserSubscriptionStatusDTO;
+use crate::{TimestampRfc3339, UserSubscriptionStatusDTO};
 use candid::{CandidType, Deserialize, Principal};
 use orbit_essentials::cmc::SubnetSelection;
 
@@ -39,6 +39,7 @@ pub enum ManageUserStationsInput {
     Add(Vec<UserStationDTO>),
     Remove(Vec<Principal>),
     Update(Vec<UpdateUserStationInput>),
+    Restore(Vec<Principal>),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -49,6 +50,14 @@ pub struct ListUserStationsInput {
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct ListUserStationsResponse {
     pub stations: Vec<UserStationDTO>,
+    pub removed_stations: Vec<RemovedUserStationDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RemovedUserStationDTO {
+    pub station: UserStationDTO,
+    pub removed_at: TimestampRfc3339,
+    pub restorable_until: TimestampRfc3339,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -0,0 +1,83 @@
+//! Approval reminder services.
+use crate::core::middlewares::{call_context, logger, use_canister_call_metric};
+use crate::core::CallContext;
+use crate::services::{ApprovalReminderService, APPROVAL_REMINDER_SERVICE};
+use control_panel_api::{
+    NotifyApproversInput, NotifyApproversResponse, TakeApprovalReminderEmailsInput,
+    TakeApprovalReminderEmailsResponse,
+};
+use ic_cdk_macros::update;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[update(name = "notify_approvers")]
+async fn notify_approvers(input: NotifyApproversInput) -> ApiResult<NotifyApproversResponse> {
+    CONTROLLER.notify_approvers(input).await
+}
+
+#[update(name = "take_approval_reminder_emails")]
+async fn take_approval_reminder_emails(
+    input: TakeApprovalReminderEmailsInput,
+) -> ApiResult<TakeApprovalReminderEmailsResponse> {
+    CONTROLLER.take_approval_reminder_emails(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: ApprovalReminderController =
+        ApprovalReminderController::new(Arc::clone(&APPROVAL_REMINDER_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct ApprovalReminderController {
+    approval_reminder_service: Arc<ApprovalReminderService>,
+}
+
+impl ApprovalReminderController {
+    fn new(approval_reminder_service: Arc<ApprovalReminderService>) -> Self {
+        Self {
+            approval_reminder_service,
+        }
+    }
+
+    /// Forwards a request of the calling station to the notification channels of its approvers.
+    #[with_middleware(
+        guard = logger::<()>(__target_fn, context, None),
+        tail = logger(__target_fn, context, Some(&result)),
+        context = &call_context()
+    )]
+    #[with_middleware(tail = use_canister_call_metric("notify_approvers", &result))]
+    async fn notify_approvers(
+        &self,
+        input: NotifyApproversInput,
+    ) -> ApiResult<NotifyApproversResponse> {
+        let ctx = CallContext::get();
+        let notified_users = self
+            .approval_reminder_service
+            .notify_approvers(input, &ctx)
+            .await?;
+
+        Ok(NotifyApproversResponse {
+            notified_users: notified_users as u64,
+        })
+    }
+
+    /// Takes the oldest e-mails of the outbox, only callable by the controllers.
+    #[with_middleware(tail = use_canister_call_metric("take_approval_reminder_emails", &result))]
+    async fn take_approval_reminder_emails(
+        &self,
+        input: TakeApprovalReminderEmailsInput,
+    ) -> ApiResult<TakeApprovalReminderEmailsResponse> {
+        let ctx = CallContext::get();
+        let emails = self
+            .approval_reminder_service
+            .take_emails(input.limit, &ctx)?;
+
+        Ok(TakeApprovalReminderEmailsResponse {
+            emails: emails.into_iter().map(Into::into).collect(),
+        })
+    }
+}
@@ -2,9 +2,12 @@
 use super::AVAILABLE_TOKENS_USER_REGISTRATION;
 use crate::core::ic_cdk::{api::set_certified_data, spawn};
 use crate::core::metrics::recompute_all_metrics;
-use crate::services::CANISTER_SERVICE;
-use control_panel_api::UploadCanisterModulesInput;
-use ic_cdk_macros::{init, post_upgrade};
+use crate::services::{CANISTER_SERVICE, USER_SERVICE};
+use control_panel_api::{
+    ListStationSubnetsResponse, SetStationSubnetsInput, SetTombstoneGracePeriodInput,
+    UploadCanisterModulesInput,
+};
+use ic_cdk_macros::{init, post_upgrade, query};
 use ic_cdk_timers::{set_timer, set_timer_interval};
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::cdk::update;
@@ -23,6 +26,21 @@ async fn upload_canister_modules(input: UploadCanisterModulesInput) -> ApiResult
     CANISTER_SERVICE.upload_canister_modules(input).await
 }
 
+#[update]
+async fn set_station_subnets(input: SetStationSubnetsInput) -> ApiResult<()> {
+    CANISTER_SERVICE.set_station_subnets(input)
+}
+
+#[update]
+async fn set_tombstone_grace_period(input: SetTombstoneGracePeriodInput) -> ApiResult<()> {
+    CANISTER_SERVICE.set_tombstone_grace_period(input)
+}
+
+#[query]
+async fn list_station_subnets() -> ApiResult<ListStationSubnetsResponse> {
+    Ok(CANISTER_SERVICE.list_station_subnets())
+}
+
 fn set_certified_data_for_skip_certification() {
     set_certified_data(&certified_data_for_skip_certification());
 }
@@ -54,6 +72,10 @@ fn init_timers_fn() {
             });
         },
     );
+
+    set_timer_interval(Duration::from_secs(HOUR), || {
+        USER_SERVICE.purge_expired_tombstones()
+    });
 }
 
 #[init]
@@ -20,6 +20,10 @@ pub use station::*;
 mod registry;
 pub use registry::*;
 
+/// Approval reminder entrypoints.
+mod approval_reminder;
+pub use approval_reminder::*;
+
 /// HTTP entrypoints.
 mod http;
 pub use http::*;
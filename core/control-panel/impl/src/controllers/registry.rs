@@ -6,8 +6,9 @@ use crate::{
 use control_panel_api::{
     AddRegistryEntryInput, AddRegistryEntryResponse, DeleteRegistryEntryInput,
     DeleteRegistryEntryResponse, EditRegistryEntryInput, EditRegistryEntryResponse,
-    GetRegistryEntryInput, GetRegistryEntryResponse, NextWasmModuleVersionInput,
-    NextWasmModuleVersionResponse, SearchRegistryInput, SearchRegistryResponse,
+    GetCompatibilityMatrixInput, GetCompatibilityMatrixResponse, GetRegistryEntryInput,
+    GetRegistryEntryResponse, NextWasmModuleVersionInput, NextWasmModuleVersionResponse,
+    SearchRegistryInput, SearchRegistryResponse,
 };
 use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
@@ -32,6 +33,13 @@ async fn next_wasm_module_version(
     CONTROLLER.next_wasm_module_version(input).await
 }
 
+#[query(name = "get_compatibility_matrix")]
+async fn get_compatibility_matrix(
+    input: GetCompatibilityMatrixInput,
+) -> ApiResult<GetCompatibilityMatrixResponse> {
+    CONTROLLER.get_compatibility_matrix(input).await
+}
+
 #[update(name = "add_registry_entry")]
 async fn add_registry_entry(input: AddRegistryEntryInput) -> ApiResult<AddRegistryEntryResponse> {
     CONTROLLER.add_registry_entry(input).await
@@ -88,15 +96,34 @@ impl RegistryController {
         &self,
         input: NextWasmModuleVersionInput,
     ) -> ApiResult<NextWasmModuleVersionResponse> {
-        let entry = self
-            .registry_service
-            .find_next_wasm_module_version(&input.name, &input.current_version)?;
+        let entry = self.registry_service.find_next_wasm_module_version(
+            &input.name,
+            &input.current_version,
+            input.max_version.as_deref(),
+        )?;
 
         Ok(NextWasmModuleVersionResponse {
             entry: entry.map(|e| e.into()),
         })
     }
 
+    /// Returns the compatibility matrix of all the versions of the given wasm module.
+    pub async fn get_compatibility_matrix(
+        &self,
+        input: GetCompatibilityMatrixInput,
+    ) -> ApiResult<GetCompatibilityMatrixResponse> {
+        let entries = self
+            .registry_service
+            .find_wasm_module_entries(&input.name)?;
+
+        Ok(GetCompatibilityMatrixResponse {
+            entries: entries
+                .into_iter()
+                .map(|entry| entry.to_compatibility_dto())
+                .collect(),
+        })
+    }
+
     /// Searches the registry for entries.
     pub async fn search_registry(
         &self,
@@ -86,9 +86,17 @@ impl StationController {
             &input.filter_by_labels.unwrap_or_default(),
             &ctx,
         )?;
+        let removed_stations = self
+            .user_station_service
+            .list_removed_stations(&user.id, &ctx)?;
+        let grace_period_ns = self.user_station_service.tombstone_grace_period_ns();
 
         Ok(ListUserStationsResponse {
             stations: stations.into_iter().map(UserStationDTO::from).collect(),
+            removed_stations: removed_stations
+                .into_iter()
+                .map(|removed| removed.to_dto(grace_period_ns))
+                .collect(),
         })
     }
 
@@ -120,6 +128,10 @@ impl StationController {
                     &ctx,
                 )?;
             }
+            ManageUserStationsInput::Restore(canister_ids) => {
+                self.user_station_service
+                    .restore_stations(&user.id, canister_ids, &ctx)?;
+            }
         }
 
         Ok(())
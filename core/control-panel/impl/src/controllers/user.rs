@@ -5,7 +5,8 @@ use crate::services::USER_SERVICE;
 use crate::{core::CallContext, services::UserService};
 use control_panel_api::{
     DeleteUserResponse, GetUserResponse, GetWaitingListResponse, RegisterUserInput,
-    RegisterUserResponse, UpdateWaitingListInput, UserDTO,
+    RegisterUserResponse, RestoreUserResponse, SetNotificationChannelsInput,
+    UpdateWaitingListInput, UserDTO,
 };
 use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
@@ -52,6 +53,11 @@ async fn subscribe_to_waiting_list(email: String) -> ApiResult<()> {
     CONTROLLER.subscribe_to_waiting_list(email).await
 }
 
+#[update(name = "set_notification_channels")]
+async fn set_notification_channels(input: SetNotificationChannelsInput) -> ApiResult<()> {
+    CONTROLLER.set_notification_channels(input).await
+}
+
 #[update(name = "get_waiting_list")]
 async fn get_waiting_list() -> ApiResult<GetWaitingListResponse> {
     CONTROLLER.get_waiting_list().await
@@ -67,6 +73,11 @@ async fn delete_user() -> ApiResult<DeleteUserResponse> {
     CONTROLLER.delete_user().await
 }
 
+#[update(name = "restore_user")]
+async fn restore_user() -> ApiResult<RestoreUserResponse> {
+    CONTROLLER.restore_user().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: UserController = UserController::new(Arc::clone(&USER_SERVICE));
@@ -123,6 +134,25 @@ impl UserController {
         Ok(())
     }
 
+    #[with_middleware(
+        guard = logger::<()>(__target_fn, context, None),
+        tail = logger(__target_fn, context, Some(&result)),
+        context = &call_context()
+    )]
+    #[with_middleware(tail = use_canister_call_metric("set_notification_channels", &result))]
+    async fn set_notification_channels(
+        &self,
+        input: SetNotificationChannelsInput,
+    ) -> ApiResult<()> {
+        let ctx: CallContext = CallContext::get();
+        self.user_service.set_notification_channels(
+            input.channels.into_iter().map(Into::into).collect(),
+            &ctx,
+        )?;
+
+        Ok(())
+    }
+
     #[with_middleware(tail = use_canister_call_metric("get_waiting_list", &result))]
     async fn get_waiting_list(&self) -> ApiResult<GetWaitingListResponse> {
         let ctx: CallContext = CallContext::get();
@@ -165,6 +195,21 @@ impl UserController {
         })
     }
 
+    #[with_middleware(
+        guard = logger::<()>(__target_fn, context, None),
+        tail = logger(__target_fn, context, Some(&result)),
+        context = &call_context()
+    )]
+    #[with_middleware(tail = use_canister_call_metric("restore_user", &result))]
+    async fn restore_user(&self) -> ApiResult<RestoreUserResponse> {
+        let ctx: CallContext = CallContext::get();
+        let restored_user = self.user_service.restore_user(&ctx.caller(), &ctx).await?;
+
+        Ok(RestoreUserResponse {
+            user: UserDTO::from(restored_user),
+        })
+    }
+
     #[with_middleware(
         guard = logger::<()>(__target_fn, context, None),
         tail = logger(__target_fn, context, Some(&result)),
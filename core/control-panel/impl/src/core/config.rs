@@ -1,6 +1,7 @@
-use super::CANISTER_CONFIG_STATE_SIZE;
+use super::{CANISTER_CONFIG_STATE_SIZE, DEFAULT_TOMBSTONE_GRACE_PERIOD_NS};
 use crate::core::ic_cdk::api::time;
 use crate::SYSTEM_VERSION;
+use candid::Principal;
 use ic_stable_structures::{storable::Bound, Storable};
 use orbit_essentials::storable;
 use orbit_essentials::types::{Timestamp, WasmModuleExtraChunks};
@@ -23,6 +24,16 @@ pub struct CanisterConfig {
 
     /// The version of the canister.
     pub version: Option<String>,
+
+    /// The subnets that users can choose to deploy their stations to.
+    #[serde(default)]
+    pub station_subnets: Vec<Principal>,
+
+    /// How long removed stations and deleted users can still be restored, in seconds.
+    ///
+    /// If not set, the default grace period is used.
+    #[serde(default)]
+    pub tombstone_grace_period_secs: Option<u64>,
 }
 
 impl Default for CanisterConfig {
@@ -33,6 +44,8 @@ impl Default for CanisterConfig {
             station_wasm_module_extra_chunks: None,
             last_upgrade_timestamp: time(),
             version: None,
+            station_subnets: vec![],
+            tombstone_grace_period_secs: None,
         }
     }
 }
@@ -49,8 +62,17 @@ impl CanisterConfig {
             station_wasm_module_extra_chunks,
             last_upgrade_timestamp: time(),
             version: Some(SYSTEM_VERSION.to_string()),
+            station_subnets: vec![],
+            tombstone_grace_period_secs: None,
         }
     }
+
+    /// Returns the grace period during which tombstones can be restored, in nanoseconds.
+    pub fn tombstone_grace_period_ns(&self) -> u64 {
+        self.tombstone_grace_period_secs
+            .map(|secs| secs.saturating_mul(1_000_000_000))
+            .unwrap_or(DEFAULT_TOMBSTONE_GRACE_PERIOD_NS)
+    }
 }
 
 /// Configuration state of the canister.
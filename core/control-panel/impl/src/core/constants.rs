@@ -24,6 +24,9 @@ pub const CANISTER_CONFIG_STATE_SIZE: u32 = WASM_PAGE_SIZE * CANISTER_CONFIG_TOT
 /// The maximum number of Wasm pages that we allow to use for the stable storage.
 pub const MAX_WASM_PAGES: u64 = MAX_STABLE_MEMORY_SIZE / WASM_PAGE_SIZE as u64;
 
+/// The subnet types that users can request for their stations, in addition to the allowed subnets.
+pub const STATION_SUBNET_TYPES: [&str; 1] = ["fiduciary"];
+
 /// The initial cycle balance to set for new station canisters.
 pub const INITIAL_STATION_CYCLES: u128 = 2_500_000_000_000;
 
@@ -39,6 +42,9 @@ pub const ONE_WEEK_NS: u64 = 7 * ONE_DAY_NS;
 /// The nanoseconds equivalent of 30 days.
 pub const ONE_MONTH_NS: u64 = 30 * ONE_DAY_NS;
 
+/// The default grace period during which removed stations and deleted users can be restored.
+pub const DEFAULT_TOMBSTONE_GRACE_PERIOD_NS: u64 = ONE_WEEK_NS;
+
 /// The NNS Root canister id added to station and upgrader canisters as a recovery method.
 pub const NNS_ROOT_CANISTER_ID: Principal = Principal::from_slice(&[0, 0, 0, 0, 0, 0, 0, 3, 1, 1]);
 
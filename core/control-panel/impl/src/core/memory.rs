@@ -17,6 +17,7 @@ pub const ARTIFACT_INDEX_MEMORY_ID: MemoryId = MemoryId::new(5);
 pub const REGISTRY_MEMORY_ID: MemoryId = MemoryId::new(6);
 pub const REGISTRY_INDEX_MEMORY_ID: MemoryId = MemoryId::new(7);
 pub const REGISTRY_SORT_INDEX_MEMORY_ID: MemoryId = MemoryId::new(8);
+pub const APPROVAL_REMINDER_EMAIL_MEMORY_ID: MemoryId = MemoryId::new(9);
 
 thread_local! {
   /// Static configuration of the canister.
@@ -39,6 +39,24 @@ pub fn recompute_all_metrics() {
     });
 }
 
+/// The deployed stations of the user that are counted, none while the user is deleted.
+fn counted_deployed_stations(user: &User) -> f64 {
+    if user.is_deleted() {
+        return 0.0;
+    }
+
+    user.deployed_stations.len() as f64
+}
+
+/// The stations associated with the user that are counted, none while the user is deleted.
+fn counted_user_stations(user: &User) -> f64 {
+    if user.is_deleted() {
+        return 0.0;
+    }
+
+    user.stations.len() as f64
+}
+
 /// Metric for the number of users that have been registered, labeled by subscription status.
 pub struct MetricRegisteredUsers;
 
@@ -58,7 +76,7 @@ impl ApplicationMetric<User> for MetricRegisteredUsers {
     fn recalculate(&mut self, models: &[User]) {
         let mut labeled_totals = BTreeMap::new();
 
-        for user in models {
+        for user in models.iter().filter(|user| !user.is_deleted()) {
             let label = user.subscription_status.to_string();
             let current_total = labeled_totals.get(&label).unwrap_or(&0.0);
 
@@ -71,11 +89,13 @@ impl ApplicationMetric<User> for MetricRegisteredUsers {
     }
 
     fn sum(&mut self, current: &User, previous: Option<&User>) {
-        let previous_label = previous.map(|user| user.subscription_status.to_string());
-        let label = current.subscription_status.to_string();
+        let previous_label = previous
+            .filter(|user| !user.is_deleted())
+            .map(|user| user.subscription_status.to_string());
+        let label = (!current.is_deleted()).then(|| current.subscription_status.to_string());
 
         match (label, previous_label) {
-            (label, Some(previous_label)) => {
+            (Some(label), Some(previous_label)) => {
                 // Only update the metric if the label has changed.
                 if label != previous_label {
                     self.dec(
@@ -85,13 +105,24 @@ impl ApplicationMetric<User> for MetricRegisteredUsers {
                     self.inc(SERVICE_NAME, &labels! { "status" => label.as_str() });
                 }
             }
-            (label, None) => {
+            (Some(label), None) => {
                 self.inc(SERVICE_NAME, &labels! { "status" => label.as_str() });
             }
+            (None, Some(previous_label)) => {
+                self.dec(
+                    SERVICE_NAME,
+                    &labels! { "status" => previous_label.as_str() },
+                );
+            }
+            (None, None) => {}
         }
     }
 
     fn sub(&mut self, model: &User) {
+        if model.is_deleted() {
+            return;
+        }
+
         let label_value = model.subscription_status.to_string();
 
         self.dec(SERVICE_NAME, &labels! { "status" => label_value.as_str() });
@@ -115,15 +146,15 @@ impl ApplicationMetric<User> for MetricDeployedStations {
     fn recalculate(&mut self, models: &[User]) {
         let mut deployed_stations = 0.0;
         for user in models {
-            deployed_stations += user.deployed_stations.len() as f64;
+            deployed_stations += counted_deployed_stations(user);
         }
 
         self.set(SERVICE_NAME, deployed_stations);
     }
 
     fn sum(&mut self, current: &User, previous: Option<&User>) {
-        let diff_deployed_stations = current.deployed_stations.len() as f64
-            - previous.map_or(0.0, |user| user.deployed_stations.len() as f64);
+        let diff_deployed_stations =
+            counted_deployed_stations(current) - previous.map_or(0.0, counted_deployed_stations);
 
         let current_total = self.get(SERVICE_NAME);
 
@@ -135,7 +166,7 @@ impl ApplicationMetric<User> for MetricDeployedStations {
 
         self.set(
             SERVICE_NAME,
-            current_total.sub(model.deployed_stations.len() as f64),
+            current_total.sub(counted_deployed_stations(model)),
         );
     }
 }
@@ -157,7 +188,7 @@ impl ApplicationMetric<User> for MetricUserStations {
     fn recalculate(&mut self, models: &[User]) {
         let mut user_stations = 0.0;
         for user in models {
-            user_stations += user.stations.len() as f64;
+            user_stations += counted_user_stations(user);
         }
 
         self.set(SERVICE_NAME, user_stations);
@@ -165,7 +196,7 @@ impl ApplicationMetric<User> for MetricUserStations {
 
     fn sum(&mut self, current: &User, previous: Option<&User>) {
         let diff_user_stations =
-            current.stations.len() as f64 - previous.map_or(0.0, |user| user.stations.len() as f64);
+            counted_user_stations(current) - previous.map_or(0.0, counted_user_stations);
 
         let current_total = self.get(SERVICE_NAME);
 
@@ -175,7 +206,10 @@ impl ApplicationMetric<User> for MetricUserStations {
     fn sub(&mut self, model: &User) {
         let current_total = self.get(SERVICE_NAME);
 
-        self.set(SERVICE_NAME, current_total.sub(model.stations.len() as f64));
+        self.set(
+            SERVICE_NAME,
+            current_total.sub(counted_user_stations(model)),
+        );
     }
 }
 
@@ -410,6 +444,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deleted_user_is_excluded_from_metrics() {
+        let mut user = mock_user();
+        user.stations = vec![UserStation {
+            canister_id: Principal::from_slice(&[1; 29]),
+            name: "Main Station".to_string(),
+            labels: Vec::new(),
+        }];
+        user.deployed_stations = vec![Principal::from_slice(&[1; 29])];
+        user.subscription_status = UserSubscriptionStatus::Approved;
+        let status = user.subscription_status.to_string();
+
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        user.deleted_at = Some(1);
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        assert_eq!(MetricUserStations.get(SERVICE_NAME), 0.0);
+        assert_eq!(MetricDeployedStations.get(SERVICE_NAME), 0.0);
+        assert_eq!(
+            MetricRegisteredUsers.get(SERVICE_NAME, &labels! { "status" => status.as_str() }),
+            0.0
+        );
+
+        // purging the deleted user leaves the metrics untouched
+        USER_REPOSITORY.remove(&user.to_key());
+
+        assert_eq!(MetricUserStations.get(SERVICE_NAME), 0.0);
+        assert_eq!(MetricDeployedStations.get(SERVICE_NAME), 0.0);
+
+        // restoring the user counts it again
+        user.deleted_at = None;
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        assert_eq!(MetricUserStations.get(SERVICE_NAME), 1.0);
+        assert_eq!(MetricDeployedStations.get(SERVICE_NAME), 1.0);
+        assert_eq!(
+            MetricRegisteredUsers.get(SERVICE_NAME, &labels! { "status" => status.as_str() }),
+            1.0
+        );
+    }
+
     #[test]
     fn test_active_users_metric_starts_with_none() {
         let hourly = labels! { "time" => "hourly" };
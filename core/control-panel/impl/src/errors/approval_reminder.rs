@@ -0,0 +1,30 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for approval reminder errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum ApprovalReminderError {
+    /// The reminder is forwarded to too many approvers.
+    #[error(r#"The reminder can be forwarded to at most {max_approvers} approvers."#)]
+    TooManyApprovers {
+        /// The maximum number of approvers of a reminder.
+        max_approvers: usize,
+    },
+    /// Only the controllers can take the e-mails of the outbox.
+    #[error(r#"Only the controllers can take the approval reminder e-mails."#)]
+    Forbidden,
+}
+
+impl DetailableError for ApprovalReminderError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            ApprovalReminderError::TooManyApprovers { max_approvers } => {
+                details.insert("max_approvers".to_string(), max_approvers.to_string());
+                Some(details)
+            }
+            ApprovalReminderError::Forbidden => None,
+        }
+    }
+}
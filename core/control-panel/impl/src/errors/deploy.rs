@@ -8,13 +8,28 @@ pub enum DeployError {
     /// The deployment of the station canister failed.
     #[error(r#"The deployment of the station canister failed due to `{reason}`"#)]
     Failed { reason: String },
+    /// The subnet is not one of the subnets that stations can be deployed to.
+    #[error(r#"Stations can not be deployed to the subnet `{subnet}`"#)]
+    SubnetNotAllowed { subnet: String },
+    /// The subnet type is not one of the types that stations can be deployed to.
+    #[error(r#"Stations can not be deployed to subnets of type `{subnet_type}`"#)]
+    SubnetTypeNotAllowed { subnet_type: String },
 }
 
 impl DetailableError for DeployError {
     fn details(&self) -> Option<HashMap<String, String>> {
         let mut details = HashMap::new();
-        let DeployError::Failed { reason } = self;
-        details.insert("reason".to_string(), reason.to_string());
+        match self {
+            DeployError::Failed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+            }
+            DeployError::SubnetNotAllowed { subnet } => {
+                details.insert("subnet".to_string(), subnet.to_string());
+            }
+            DeployError::SubnetTypeNotAllowed { subnet_type } => {
+                details.insert("subnet_type".to_string(), subnet_type.to_string());
+            }
+        }
         Some(details)
     }
 }
@@ -0,0 +1,24 @@
+use crate::models::{ApprovalReminder, ApprovalReminderEmail};
+use control_panel_api::{ApprovalReminderDTO, ApprovalReminderEmailDTO};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+
+impl From<ApprovalReminder> for ApprovalReminderDTO {
+    fn from(reminder: ApprovalReminder) -> Self {
+        ApprovalReminderDTO {
+            station_id: reminder.station_id,
+            request_id: reminder.request_id,
+            title: reminder.title,
+            summary: reminder.summary,
+            created_at: timestamp_to_rfc3339(&reminder.created_at),
+        }
+    }
+}
+
+impl From<ApprovalReminderEmail> for ApprovalReminderEmailDTO {
+    fn from(email: ApprovalReminderEmail) -> Self {
+        ApprovalReminderEmailDTO {
+            email: email.email,
+            reminder: email.reminder.into(),
+        }
+    }
+}
@@ -12,3 +12,5 @@ pub use helper::*;
 
 mod registry;
 pub use registry::*;
+
+mod approval_reminder;
@@ -167,6 +167,29 @@ impl From<WasmModuleRegistryEntryDependency>
     }
 }
 
+impl RegistryEntry {
+    /// Returns the compatibility information of the wasm module entry.
+    pub fn to_compatibility_dto(self) -> control_panel_api::WasmModuleCompatibilityDTO {
+        let api_version = self.api_version().map(str::to_string);
+        let min_upgrade_from = self.min_upgrade_from().map(str::to_string);
+
+        match self.value {
+            RegistryValue::WasmModule(wasm_module) => {
+                control_panel_api::WasmModuleCompatibilityDTO {
+                    version: wasm_module.version,
+                    dependencies: wasm_module
+                        .dependencies
+                        .into_iter()
+                        .map(Into::into)
+                        .collect(),
+                    api_version,
+                    min_upgrade_from,
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
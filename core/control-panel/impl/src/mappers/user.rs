@@ -1,12 +1,12 @@
 use crate::{
     core::ic_cdk::next_time,
     errors::UserError,
-    models::{CanDeployStation, User, UserSubscriptionStatus},
+    models::{CanDeployStation, NotificationChannel, User, UserSubscriptionStatus},
 };
 use candid::Principal;
 use control_panel_api::{
-    CanDeployStationResponse, RegisterUserInput, SubscribedUserDTO, UserDTO,
-    UserSubscriptionStatusDTO,
+    CanDeployStationResponse, NotificationChannelDTO, NotificationWebhookDTO, RegisterUserInput,
+    SubscribedUserDTO, UserDTO, UserSubscriptionStatusDTO,
 };
 use orbit_essentials::api::ApiError;
 use orbit_essentials::types::UUID;
@@ -36,6 +36,10 @@ impl UserMapper {
             subscription_status: UserSubscriptionStatus::Unsubscribed,
             stations: stations.into_iter().map(|station| station.into()).collect(),
             deployed_stations: vec![],
+            deployed_station_subnets: vec![],
+            notification_channels: vec![],
+            removed_stations: vec![],
+            deleted_at: None,
             last_active: registration_time,
             last_update_timestamp: registration_time,
         }
@@ -48,6 +52,39 @@ impl From<User> for UserDTO {
             identity: user.identity,
             subscription_status: user.subscription_status.into(),
             last_active: timestamp_to_rfc3339(&user.last_active),
+            notification_channels: user
+                .notification_channels
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            deleted_at: user.deleted_at.as_ref().map(timestamp_to_rfc3339),
+        }
+    }
+}
+
+impl From<NotificationChannel> for NotificationChannelDTO {
+    fn from(channel: NotificationChannel) -> Self {
+        match channel {
+            NotificationChannel::Email(email) => NotificationChannelDTO::Email(email),
+            NotificationChannel::Webhook {
+                canister_id,
+                method_name,
+            } => NotificationChannelDTO::Webhook(NotificationWebhookDTO {
+                canister_id,
+                method_name,
+            }),
+        }
+    }
+}
+
+impl From<NotificationChannelDTO> for NotificationChannel {
+    fn from(channel: NotificationChannelDTO) -> Self {
+        match channel {
+            NotificationChannelDTO::Email(email) => NotificationChannel::Email(email),
+            NotificationChannelDTO::Webhook(webhook) => NotificationChannel::Webhook {
+                canister_id: webhook.canister_id,
+                method_name: webhook.method_name,
+            },
         }
     }
 }
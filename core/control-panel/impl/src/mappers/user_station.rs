@@ -1,4 +1,5 @@
-use crate::models::UserStation;
+use crate::models::{RemovedUserStation, UserStation};
+use orbit_essentials::utils::timestamp_to_rfc3339;
 
 impl From<UserStation> for control_panel_api::UserStationDTO {
     fn from(user_station: UserStation) -> Self {
@@ -20,6 +21,16 @@ impl From<control_panel_api::UserStationDTO> for UserStation {
     }
 }
 
+impl RemovedUserStation {
+    pub fn to_dto(self, grace_period_ns: u64) -> control_panel_api::RemovedUserStationDTO {
+        control_panel_api::RemovedUserStationDTO {
+            restorable_until: timestamp_to_rfc3339(&self.restorable_until(grace_period_ns)),
+            removed_at: timestamp_to_rfc3339(&self.removed_at),
+            station: self.station.into(),
+        }
+    }
+}
+
 pub trait UpdateUserStationInputInto {
     fn into_user_station(self) -> (Option<u64>, UserStation);
 }
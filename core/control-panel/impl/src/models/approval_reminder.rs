@@ -0,0 +1,38 @@
+use candid::Principal;
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+
+/// A request of a station that awaits the approval of the users it is forwarded to.
+#[storable]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ApprovalReminder {
+    /// The station the request belongs to.
+    pub station_id: Principal,
+    /// The id of the request in the station.
+    pub request_id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    /// The time at which the station forwarded the reminder.
+    pub created_at: Timestamp,
+}
+
+/// A reminder waiting in the outbox for the mailer to send it to the e-mail address of a user.
+#[storable]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ApprovalReminderEmail {
+    pub email: String,
+    pub reminder: ApprovalReminder,
+}
+
+impl ApprovalReminder {
+    pub const MAX_TITLE_LEN: usize = 255;
+    pub const MAX_SUMMARY_LEN: usize = 1_000;
+    /// The maximum number of approvers a single reminder can be forwarded to.
+    pub const MAX_APPROVERS: usize = 500;
+}
+
+impl ApprovalReminderEmail {
+    /// The maximum number of e-mails kept in the outbox, the reminders beyond it are dropped
+    /// until the mailer drains it.
+    pub const MAX_OUTBOX_LEN: usize = 10_000;
+}
@@ -12,4 +12,7 @@ pub use registry_entry::*;
 mod artifact;
 pub use artifact::*;
 
+mod approval_reminder;
+pub use approval_reminder::*;
+
 pub mod indexes;
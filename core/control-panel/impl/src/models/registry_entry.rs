@@ -175,6 +175,13 @@ impl RegistryEntry {
     pub const MIN_TAG_LENGTH: usize = 2;
     pub const MAX_TAG_LENGTH: usize = 32;
 
+    /// The metadata key with the version of the public API exposed by the wasm module (e.g. `1.0.0`).
+    pub const API_VERSION_METADATA_KEY: &'static str = "api_version";
+    /// The metadata key with the minimum version that can be upgraded directly to the wasm module.
+    ///
+    /// Used to avoid recommending upgrades that skip required intermediate versions.
+    pub const MIN_UPGRADE_FROM_METADATA_KEY: &'static str = "min_upgrade_from";
+
     /// Creates a new registry entry with a random id and default values.
     ///
     /// The value of the entry is a wasm module with a random id and default values.
@@ -203,6 +210,20 @@ impl RegistryEntry {
         format!("{}{}/{}", Self::NAMESPACE_PREFIX, self.namespace, self.name)
     }
 
+    /// Returns the public API version declared in the metadata of the entry, if any.
+    pub fn api_version(&self) -> Option<&str> {
+        self.metadata
+            .get(Self::API_VERSION_METADATA_KEY)
+            .map(String::as_str)
+    }
+
+    /// Returns the minimum version that can be upgraded directly to this entry, if any.
+    pub fn min_upgrade_from(&self) -> Option<&str> {
+        self.metadata
+            .get(Self::MIN_UPGRADE_FROM_METADATA_KEY)
+            .map(String::as_str)
+    }
+
     pub fn to_kind(&self) -> RegistryValueKind {
         match &self.value {
             RegistryValue::WasmModule(_) => RegistryValueKind::WasmModule,
@@ -534,6 +555,24 @@ fn validate_tags(tags: &[String]) -> ModelValidatorResult<RegistryError> {
     Ok(())
 }
 
+fn validate_compatibility_metadata(entry: &RegistryEntry) -> ModelValidatorResult<RegistryError> {
+    for (key, value) in [
+        (RegistryEntry::API_VERSION_METADATA_KEY, entry.api_version()),
+        (
+            RegistryEntry::MIN_UPGRADE_FROM_METADATA_KEY,
+            entry.min_upgrade_from(),
+        ),
+    ] {
+        if let Some(Err(e)) = value.map(semver::Version::parse) {
+            return Err(RegistryError::ValidationError {
+                info: format!("Invalid semver in metadata `{}`: {}", key, e),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_metadata(metadata: &BTreeMap<String, String>) -> ModelValidatorResult<RegistryError> {
     if metadata.len() > RegistryEntry::MAX_METADATA_ENTRIES {
         return Err(RegistryError::ValidationError {
@@ -595,6 +634,7 @@ impl ModelValidator<RegistryError> for RegistryEntry {
         validate_categories(&self.categories)?;
         validate_tags(&self.tags)?;
         validate_metadata(&self.metadata)?;
+        validate_compatibility_metadata(self)?;
         validate_timestamps(self.created_at, self.updated_at)?;
 
         match &self.value {
@@ -1,7 +1,8 @@
-use super::UserStation;
+use super::{RemovedUserStation, UserStation};
 use crate::errors::UserError;
 use candid::Principal;
 use email_address::EmailAddress;
+use orbit_essentials::cmc::SubnetSelection;
 use orbit_essentials::model::ModelKey;
 use orbit_essentials::storable;
 use orbit_essentials::{
@@ -41,6 +42,20 @@ impl std::fmt::Display for UserSubscriptionStatus {
     }
 }
 
+/// Where the control panel forwards the events of the stations of a user, e.g. the requests
+/// awaiting their approval.
+#[storable]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum NotificationChannel {
+    /// The address the e-mails are sent to by the mailer that drains the e-mail outbox.
+    Email(String),
+    /// The canister endpoint that is called with the event.
+    Webhook {
+        canister_id: Principal,
+        method_name: String,
+    },
+}
+
 /// The identity of an user.
 #[storable]
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -58,12 +73,33 @@ pub struct User {
     /// The stations that have ever been deployed for the user by the control panel.
     /// Used to bound the total number of stations a user could deploy via the control panel.
     pub deployed_stations: Vec<Principal>,
+    /// The subnets that were chosen for the deployed stations, the stations deployed to the
+    /// default subnet are not recorded.
+    #[serde(default)]
+    pub deployed_station_subnets: Vec<DeployedStationSubnet>,
+    /// The channels the events of the stations of the user are forwarded to.
+    #[serde(default)]
+    pub notification_channels: Vec<NotificationChannel>,
+    /// The stations the user removed that can still be restored, oldest first.
+    #[serde(default)]
+    pub removed_stations: Vec<RemovedUserStation>,
+    /// When the user deleted their profile, the user is purged once the grace period elapses.
+    #[serde(default)]
+    pub deleted_at: Option<Timestamp>,
     /// The timestamp of last time the user was active.
     pub last_active: Timestamp,
     /// Last time the identity was updated.
     pub last_update_timestamp: Timestamp,
 }
 
+/// The subnet selection a station was deployed with.
+#[storable]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DeployedStationSubnet {
+    pub canister_id: Principal,
+    pub subnet_selection: SubnetSelection,
+}
+
 #[storable]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 pub struct UserKey(pub UUID);
@@ -79,11 +115,59 @@ impl User {
     pub const EMAIL_LEN_RANGE: (u8, u8) = (1, 100);
     pub const MAX_STATIONS: u8 = 15;
     pub const MAX_DEPLOYED_STATIONS: u8 = 3;
+    pub const MAX_NOTIFICATION_CHANNELS: u8 = 5;
+    pub const METHOD_NAME_LEN_RANGE: (u8, u8) = (1, 100);
+    pub const MAX_REMOVED_STATIONS: u8 = 15;
 
     pub fn to_key(&self) -> UserKey {
         UserKey(self.id)
     }
 
+    /// Whether the user deleted their profile and is awaiting to be purged.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Whether the user deleted their profile and it can no longer be restored.
+    pub fn is_deletion_expired(&self, now: Timestamp, grace_period_ns: u64) -> bool {
+        self.deleted_at
+            .is_some_and(|deleted_at| now >= deleted_at.saturating_add(grace_period_ns))
+    }
+
+    /// Moves the given stations to the removed stations, dropping the oldest removed stations
+    /// above the limit.
+    pub fn remove_stations(&mut self, station_ids: &[Principal], now: Timestamp) {
+        let (removed, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.stations)
+            .into_iter()
+            .partition(|station| station_ids.contains(&station.canister_id));
+
+        self.stations = kept;
+        for station in removed {
+            self.removed_stations
+                .retain(|removed| removed.station.canister_id != station.canister_id);
+            self.removed_stations.push(RemovedUserStation {
+                station,
+                removed_at: now,
+            });
+        }
+
+        let max_removed_stations = Self::MAX_REMOVED_STATIONS as usize;
+        if self.removed_stations.len() > max_removed_stations {
+            let overflow = self.removed_stations.len() - max_removed_stations;
+            self.removed_stations.drain(..overflow);
+        }
+    }
+
+    /// Drops the removed stations that can no longer be restored, returns true if any was dropped.
+    pub fn purge_expired_removed_stations(&mut self, now: Timestamp, grace_period_ns: u64) -> bool {
+        let total_removed_stations = self.removed_stations.len();
+
+        self.removed_stations
+            .retain(|removed| !removed.is_expired(now, grace_period_ns));
+
+        self.removed_stations.len() != total_removed_stations
+    }
+
     pub fn can_deploy_station(&self) -> CanDeployStation {
         match self.subscription_status {
             UserSubscriptionStatus::Approved => (),
@@ -144,12 +228,48 @@ fn validate_stations(stations: &[UserStation]) -> ModelValidatorResult<UserError
     Ok(())
 }
 
+fn validate_notification_channels(
+    channels: &[NotificationChannel],
+) -> ModelValidatorResult<UserError> {
+    if channels.len() > User::MAX_NOTIFICATION_CHANNELS as usize {
+        return Err(UserError::ValidationError {
+            info: format!(
+                "Too many notification channels, expected at most {} but got {}",
+                User::MAX_NOTIFICATION_CHANNELS,
+                channels.len()
+            ),
+        });
+    }
+
+    for channel in channels.iter() {
+        match channel {
+            NotificationChannel::Email(email) => validate_email(email)?,
+            NotificationChannel::Webhook { method_name, .. } => {
+                if (method_name.len() < User::METHOD_NAME_LEN_RANGE.0 as usize)
+                    || (method_name.len() > User::METHOD_NAME_LEN_RANGE.1 as usize)
+                {
+                    return Err(UserError::ValidationError {
+                        info: format!(
+                            "Webhook method name length must be between {} and {}",
+                            User::METHOD_NAME_LEN_RANGE.0,
+                            User::METHOD_NAME_LEN_RANGE.1,
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 impl ModelValidator<UserError> for User {
     fn validate(&self) -> ModelValidatorResult<UserError> {
         if let UserSubscriptionStatus::Pending(email) = &self.subscription_status {
             validate_email(email)?;
         }
         validate_stations(&self.stations)?;
+        validate_notification_channels(&self.notification_channels)?;
 
         Ok(())
     }
@@ -158,6 +278,7 @@ impl ModelValidator<UserError> for User {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::user_station_model_utils::mock_user_station;
     use ic_stable_structures::Storable;
     use rstest::rstest;
     use tests::user_model_utils::mock_user;
@@ -214,6 +335,74 @@ mod tests {
         assert!(validate_stations(&user_with_too_many_stations.stations).is_err());
     }
 
+    #[test]
+    fn check_notification_channels_validation() {
+        let webhook = NotificationChannel::Webhook {
+            canister_id: Principal::anonymous(),
+            method_name: "on_approval_reminder".to_string(),
+        };
+
+        assert!(validate_notification_channels(&[
+            NotificationChannel::Email("john@example.com".to_string()),
+            webhook.clone(),
+        ])
+        .is_ok());
+        assert!(
+            validate_notification_channels(&[NotificationChannel::Email("john".to_string())])
+                .is_err()
+        );
+        assert!(
+            validate_notification_channels(&[NotificationChannel::Webhook {
+                canister_id: Principal::anonymous(),
+                method_name: String::new(),
+            }])
+            .is_err()
+        );
+        assert!(validate_notification_channels(&vec![
+            webhook;
+            User::MAX_NOTIFICATION_CHANNELS as usize + 1
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn removed_stations_are_kept_until_the_grace_period_elapses() {
+        let mut user = mock_user();
+        user.stations = vec![mock_user_station(), mock_user_station()];
+        let removed_station = user.stations[0].clone();
+
+        user.remove_stations(&[removed_station.canister_id], 10);
+
+        assert_eq!(user.stations.len(), 1);
+        assert_eq!(user.removed_stations.len(), 1);
+        assert_eq!(user.removed_stations[0].station, removed_station);
+        assert_eq!(user.removed_stations[0].restorable_until(5), 15);
+
+        assert!(!user.purge_expired_removed_stations(14, 5));
+        assert_eq!(user.removed_stations.len(), 1);
+
+        assert!(user.purge_expired_removed_stations(15, 5));
+        assert!(user.removed_stations.is_empty());
+    }
+
+    #[test]
+    fn removed_stations_are_capped() {
+        let mut user = mock_user();
+
+        for now in 0..=User::MAX_REMOVED_STATIONS as u64 {
+            user.stations = vec![mock_user_station()];
+            let station_id = user.stations[0].canister_id;
+
+            user.remove_stations(&[station_id], now);
+        }
+
+        assert_eq!(
+            user.removed_stations.len(),
+            User::MAX_REMOVED_STATIONS as usize
+        );
+        assert_eq!(user.removed_stations[0].removed_at, 1);
+    }
+
     #[rstest]
     #[case::empty_name(&"")]
     #[case::invalid_email(&"john")]
@@ -236,6 +425,10 @@ pub mod user_model_utils {
             subscription_status: UserSubscriptionStatus::Unsubscribed,
             stations: vec![],
             deployed_stations: vec![],
+            deployed_station_subnets: vec![],
+            notification_channels: vec![],
+            removed_stations: vec![],
+            deleted_at: None,
             last_active: 0,
             last_update_timestamp: 0,
         }
@@ -2,6 +2,7 @@ use crate::errors::UserError;
 use candid::Principal;
 use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
 use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
 
 pub const NAME_LEN_RANGE: (u8, u8) = (1, 48);
 pub const MAX_LABELS: usize = 25;
@@ -19,6 +20,27 @@ pub struct UserStation {
     pub labels: Vec<String>,
 }
 
+/// A station the user removed, kept until the grace period elapses so that the removal can be undone.
+#[storable]
+#[derive(Clone, Debug, Ord, Eq, PartialEq, PartialOrd)]
+pub struct RemovedUserStation {
+    // The station that was removed.
+    pub station: UserStation,
+    // The time the station was removed.
+    pub removed_at: Timestamp,
+}
+
+impl RemovedUserStation {
+    /// Returns the time after which the station can no longer be restored.
+    pub fn restorable_until(&self, grace_period_ns: u64) -> Timestamp {
+        self.removed_at.saturating_add(grace_period_ns)
+    }
+
+    pub fn is_expired(&self, now: Timestamp, grace_period_ns: u64) -> bool {
+        now >= self.restorable_until(grace_period_ns)
+    }
+}
+
 impl PartialEq for UserStation {
     fn eq(&self, other: &Self) -> bool {
         self.canister_id == other.canister_id
@@ -0,0 +1,101 @@
+use crate::{
+    core::{with_memory_manager, Memory, APPROVAL_REMINDER_EMAIL_MEMORY_ID},
+    models::ApprovalReminderEmail,
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use orbit_essentials::types::Timestamp;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<Timestamp, ApprovalReminderEmail, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(APPROVAL_REMINDER_EMAIL_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref APPROVAL_REMINDER_EMAIL_REPOSITORY: Arc<ApprovalReminderEmailRepository> =
+        Arc::new(ApprovalReminderEmailRepository::default());
+}
+
+/// The outbox of the approval reminder e-mails, keyed by the time they were queued at so that the
+/// mailer takes them in order.
+#[derive(Default, Debug)]
+pub struct ApprovalReminderEmailRepository {}
+
+impl StableDb<Timestamp, ApprovalReminderEmail, VirtualMemory<Memory>>
+    for ApprovalReminderEmailRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<Timestamp, ApprovalReminderEmail, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<Timestamp, ApprovalReminderEmail, VirtualMemory<Memory>>
+    for ApprovalReminderEmailRepository
+{
+}
+
+impl ApprovalReminderEmailRepository {
+    /// Removes and returns the oldest e-mails of the outbox.
+    pub fn take_oldest(&self, limit: usize) -> Vec<ApprovalReminderEmail> {
+        DB.with(|m| {
+            let mut db = m.borrow_mut();
+            let keys = db
+                .iter()
+                .take(limit)
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+
+            keys.into_iter().filter_map(|key| db.remove(&key)).collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApprovalReminder;
+    use candid::Principal;
+
+    fn email(request_id: &str) -> ApprovalReminderEmail {
+        ApprovalReminderEmail {
+            email: "john@example.com".to_string(),
+            reminder: ApprovalReminder {
+                station_id: Principal::anonymous(),
+                request_id: request_id.to_string(),
+                title: "Transfer".to_string(),
+                summary: None,
+                created_at: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn takes_the_oldest_emails() {
+        let repository = ApprovalReminderEmailRepository::default();
+
+        repository.insert(2, email("second"));
+        repository.insert(1, email("first"));
+        repository.insert(3, email("third"));
+
+        let taken = repository.take_oldest(2);
+
+        assert_eq!(
+            taken
+                .iter()
+                .map(|email| email.reminder.request_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+        assert_eq!(repository.len(), 1);
+    }
+}
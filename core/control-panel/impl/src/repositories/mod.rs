@@ -9,4 +9,7 @@ pub use user::*;
 mod registry;
 pub use registry::*;
 
+mod approval_reminder_email;
+pub use approval_reminder_email::*;
+
 pub mod indexes;
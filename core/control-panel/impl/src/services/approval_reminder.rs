@@ -0,0 +1,270 @@
+use crate::{
+    core::{
+        ic_cdk::{api::print, next_time},
+        CallContext,
+    },
+    errors::ApprovalReminderError,
+    models::{ApprovalReminder, ApprovalReminderEmail, NotificationChannel},
+    repositories::{
+        ApprovalReminderEmailRepository, UserRepository, APPROVAL_REMINDER_EMAIL_REPOSITORY,
+        USER_REPOSITORY,
+    },
+};
+use candid::Principal;
+use control_panel_api::{ApprovalReminderDTO, NotifyApproversInput};
+use ic_cdk::api::call::call_raw;
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository};
+use std::{collections::BTreeSet, sync::Arc};
+
+lazy_static! {
+    pub static ref APPROVAL_REMINDER_SERVICE: Arc<ApprovalReminderService> =
+        Arc::new(ApprovalReminderService::new(
+            Arc::clone(&USER_REPOSITORY),
+            Arc::clone(&APPROVAL_REMINDER_EMAIL_REPOSITORY),
+        ));
+}
+
+/// The webhooks to call with a reminder once its e-mails are queued.
+#[derive(Debug, Default)]
+pub struct ApprovalReminderDispatch {
+    pub notified_users: usize,
+    pub webhooks: Vec<(Principal, String)>,
+    pub reminder: Option<ApprovalReminder>,
+}
+
+/// Forwards the requests awaiting the approval of users to their notification channels, since the
+/// stations only know the identities of their users and not how to reach them.
+#[derive(Default, Debug)]
+pub struct ApprovalReminderService {
+    user_repository: Arc<UserRepository>,
+    email_repository: Arc<ApprovalReminderEmailRepository>,
+}
+
+impl ApprovalReminderService {
+    pub const DEFAULT_TAKE_LIMIT: usize = 100;
+
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        email_repository: Arc<ApprovalReminderEmailRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            email_repository,
+        }
+    }
+
+    /// Queues the e-mails of the reminder and returns the webhooks to call with it.
+    ///
+    /// The calling canister is the station of the request, only the approvers that added it to their
+    /// stations are reminded so that other canisters cannot reach the channels of the users.
+    pub fn dispatch(
+        &self,
+        input: NotifyApproversInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<ApprovalReminderDispatch> {
+        if input.approvers.len() > ApprovalReminder::MAX_APPROVERS {
+            Err(ApprovalReminderError::TooManyApprovers {
+                max_approvers: ApprovalReminder::MAX_APPROVERS,
+            })?
+        }
+
+        let station_id = ctx.caller();
+        let mut reminded_users = BTreeSet::new();
+        let users = input
+            .approvers
+            .iter()
+            .filter_map(|identity| self.user_repository.find_by_identity(identity))
+            .filter(|user| {
+                user.stations
+                    .iter()
+                    .any(|station| station.canister_id == station_id)
+            })
+            .filter(|user| reminded_users.insert(user.id))
+            .collect::<Vec<_>>();
+
+        if users.is_empty() {
+            return Ok(ApprovalReminderDispatch::default());
+        }
+
+        let reminder = ApprovalReminder {
+            station_id,
+            request_id: input.request_id,
+            title: input
+                .title
+                .chars()
+                .take(ApprovalReminder::MAX_TITLE_LEN)
+                .collect(),
+            summary: input.summary.map(|summary| {
+                summary
+                    .chars()
+                    .take(ApprovalReminder::MAX_SUMMARY_LEN)
+                    .collect()
+            }),
+            created_at: next_time(),
+        };
+
+        let mut webhooks = Vec::new();
+        for channel in users.iter().flat_map(|user| &user.notification_channels) {
+            match channel {
+                NotificationChannel::Email(email) => {
+                    if self.email_repository.len() >= ApprovalReminderEmail::MAX_OUTBOX_LEN {
+                        print(format!(
+                            "The approval reminder outbox is full, dropping the e-mail of request {}",
+                            reminder.request_id
+                        ));
+                        continue;
+                    }
+
+                    self.email_repository.insert(
+                        next_time(),
+                        ApprovalReminderEmail {
+                            email: email.to_string(),
+                            reminder: reminder.clone(),
+                        },
+                    );
+                }
+                NotificationChannel::Webhook {
+                    canister_id,
+                    method_name,
+                } => webhooks.push((*canister_id, method_name.to_string())),
+            }
+        }
+
+        Ok(ApprovalReminderDispatch {
+            notified_users: users.len(),
+            webhooks,
+            reminder: Some(reminder),
+        })
+    }
+
+    /// Forwards the reminder to the channels of the approvers and returns the number of users reminded,
+    /// the failed webhook calls are logged and not retried.
+    pub async fn notify_approvers(
+        &self,
+        input: NotifyApproversInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<usize> {
+        let dispatch = self.dispatch(input, ctx)?;
+
+        if let Some(reminder) = dispatch.reminder {
+            let arg = candid::encode_one(ApprovalReminderDTO::from(reminder))
+                .expect("Failed to encode the approval reminder");
+
+            for (canister_id, method_name) in dispatch.webhooks {
+                if let Err((_, error)) = call_raw(canister_id, &method_name, arg.clone(), 0).await {
+                    print(format!(
+                        "Failed to call the approval reminder webhook {}.{}: {}",
+                        canister_id, method_name, error
+                    ));
+                }
+            }
+        }
+
+        Ok(dispatch.notified_users)
+    }
+
+    /// Removes and returns the oldest e-mails of the outbox for the mailer to send them.
+    pub fn take_emails(
+        &self,
+        limit: Option<u16>,
+        ctx: &CallContext,
+    ) -> ServiceResult<Vec<ApprovalReminderEmail>> {
+        if !ctx.is_controller() {
+            Err(ApprovalReminderError::Forbidden)?
+        }
+
+        Ok(self
+            .email_repository
+            .take_oldest(limit.map(usize::from).unwrap_or(Self::DEFAULT_TAKE_LIMIT)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{user_model_utils::mock_user, UserStation};
+    use orbit_essentials::cdk::mocks::TEST_CONTROLLER_ID;
+
+    fn input(approvers: Vec<Principal>) -> NotifyApproversInput {
+        NotifyApproversInput {
+            request_id: "request-1".to_string(),
+            title: "Transfer 10 ICP".to_string(),
+            summary: None,
+            approvers,
+        }
+    }
+
+    #[test]
+    fn reminds_the_approvers_of_the_calling_station() {
+        let station_id = Principal::from_slice(&[7; 29]);
+        let service = ApprovalReminderService::default();
+
+        let mut member = mock_user();
+        member.stations = vec![UserStation {
+            canister_id: station_id,
+            name: "Treasury".to_string(),
+            labels: Vec::new(),
+        }];
+        member.notification_channels = vec![
+            NotificationChannel::Email("john@example.com".to_string()),
+            NotificationChannel::Webhook {
+                canister_id: Principal::from_slice(&[8; 29]),
+                method_name: "on_approval_reminder".to_string(),
+            },
+        ];
+        let mut stranger = mock_user();
+        stranger.notification_channels =
+            vec![NotificationChannel::Email("jane@example.com".to_string())];
+
+        USER_REPOSITORY.insert(member.to_key(), member.clone());
+        USER_REPOSITORY.insert(stranger.to_key(), stranger.clone());
+
+        let dispatch = service
+            .dispatch(
+                input(vec![member.identity, member.identity, stranger.identity]),
+                &CallContext::new(station_id),
+            )
+            .unwrap();
+
+        assert_eq!(dispatch.notified_users, 1);
+        assert_eq!(
+            dispatch.webhooks,
+            vec![(
+                Principal::from_slice(&[8; 29]),
+                "on_approval_reminder".to_string()
+            )]
+        );
+
+        assert!(service
+            .take_emails(None, &CallContext::new(station_id))
+            .is_err());
+
+        let emails = service
+            .take_emails(None, &CallContext::new(TEST_CONTROLLER_ID))
+            .unwrap();
+
+        assert_eq!(emails.len(), 1);
+        assert_eq!(emails[0].email, "john@example.com");
+        assert_eq!(emails[0].reminder.station_id, station_id);
+        assert!(service
+            .take_emails(None, &CallContext::new(TEST_CONTROLLER_ID))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn rejects_too_many_approvers() {
+        let service = ApprovalReminderService::default();
+
+        assert!(service
+            .dispatch(
+                input(vec![
+                    Principal::anonymous();
+                    ApprovalReminder::MAX_APPROVERS + 1
+                ]),
+                &CallContext::new(Principal::from_slice(&[7; 29])),
+            )
+            .is_err());
+    }
+}
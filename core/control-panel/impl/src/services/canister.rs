@@ -1,5 +1,5 @@
 use crate::core::ic_cdk::api::{print, time};
-use crate::core::{canister_config, write_canister_config, CallContext};
+use crate::core::{canister_config, write_canister_config, CallContext, STATION_SUBNET_TYPES};
 use crate::errors::CanisterError;
 use crate::repositories::{UserRepository, USER_REPOSITORY};
 use crate::SYSTEM_VERSION;
@@ -7,7 +7,10 @@ use canfund::manager::options::{EstimatedRuntime, FundManagerOptions, FundStrate
 use canfund::manager::RegisterOpts;
 use canfund::operations::fetch::{FetchCyclesBalance, FetchCyclesBalanceFromPrometheusMetrics};
 use canfund::FundManager;
-use control_panel_api::UploadCanisterModulesInput;
+use control_panel_api::{
+    ListStationSubnetsResponse, SetStationSubnetsInput, SetTombstoneGracePeriodInput,
+    UploadCanisterModulesInput,
+};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ServiceResult;
 use orbit_essentials::repository::Repository;
@@ -65,6 +68,49 @@ impl CanisterService {
         Ok(())
     }
 
+    /// Replaces the subnets that users can choose to deploy their stations to.
+    pub fn set_station_subnets(&self, input: SetStationSubnetsInput) -> ServiceResult<()> {
+        self.assert_controller(&CallContext::get(), "set_station_subnets".to_string())?;
+
+        let mut config = canister_config().unwrap_or_default();
+        config.station_subnets = input.subnets;
+        config.station_subnets.sort();
+        config.station_subnets.dedup();
+        write_canister_config(config);
+
+        Ok(())
+    }
+
+    /// Sets the grace period during which removed stations and deleted users can be restored.
+    pub fn set_tombstone_grace_period(
+        &self,
+        input: SetTombstoneGracePeriodInput,
+    ) -> ServiceResult<()> {
+        self.assert_controller(
+            &CallContext::get(),
+            "set_tombstone_grace_period".to_string(),
+        )?;
+
+        let mut config = canister_config().unwrap_or_default();
+        config.tombstone_grace_period_secs = Some(input.grace_period_secs);
+        write_canister_config(config);
+
+        Ok(())
+    }
+
+    /// Returns the subnets and subnet types that users can choose to deploy their stations to.
+    pub fn list_station_subnets(&self) -> ListStationSubnetsResponse {
+        ListStationSubnetsResponse {
+            subnets: canister_config()
+                .map(|config| config.station_subnets)
+                .unwrap_or_default(),
+            subnet_types: STATION_SUBNET_TYPES
+                .iter()
+                .map(|subnet_type| subnet_type.to_string())
+                .collect(),
+        }
+    }
+
     pub async fn init_canister(&self) -> ServiceResult<()> {
         self.start_canister_cycles_monitoring();
 
@@ -1,6 +1,9 @@
 use super::{UserService, UserStationService};
 use crate::{
-    core::{canister_config, CallContext, INITIAL_STATION_CYCLES, NNS_ROOT_CANISTER_ID},
+    core::{
+        canister_config, CallContext, INITIAL_STATION_CYCLES, NNS_ROOT_CANISTER_ID,
+        STATION_SUBNET_TYPES,
+    },
     errors::{DeployError, UserError},
     models::{CanDeployStation, UserStation},
     services::{USER_SERVICE, USER_STATION_SERVICE},
@@ -11,7 +14,7 @@ use ic_cdk::api::id as self_canister_id;
 use ic_cdk::api::management_canister::main::{self as mgmt};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ServiceResult;
-use orbit_essentials::cmc::create_canister;
+use orbit_essentials::cmc::{create_canister, SubnetFilter, SubnetSelection};
 use orbit_essentials::install_chunked_code::install_chunked_code;
 use std::sync::Arc;
 
@@ -66,10 +69,15 @@ impl DeployService {
             }
         }
 
-        // Creates the station canister with some initial cycles
-        let station_canister = create_canister(input.subnet_selection, INITIAL_STATION_CYCLES)
-            .await
-            .map_err(|err| DeployError::Failed { reason: err })?;
+        if let Some(subnet_selection) = &input.subnet_selection {
+            validate_subnet_selection(subnet_selection, &config.station_subnets)?;
+        }
+
+        // Creates the station canister with some initial cycles, on the chosen subnet if any
+        let station_canister =
+            create_canister(input.subnet_selection.clone(), INITIAL_STATION_CYCLES)
+                .await
+                .map_err(|err| DeployError::Failed { reason: err })?;
 
         // Adds the station canister as a controller of itself so that it can change its own settings
         mgmt::update_settings(mgmt::UpdateSettingsArgument {
@@ -118,7 +126,7 @@ impl DeployService {
         .map_err(|err| DeployError::Failed { reason: err })?;
 
         self.user_service
-            .add_deployed_station(&user.id, station_canister, ctx)
+            .add_deployed_station(&user.id, station_canister, input.subnet_selection, ctx)
             .await?;
 
         // Adds the deployed station to the user
@@ -137,3 +145,77 @@ impl DeployService {
         Ok(station_canister)
     }
 }
+
+/// Checks that the subnet is in the allow-list, or that the subnet type can be requested.
+fn validate_subnet_selection(
+    subnet_selection: &SubnetSelection,
+    allowed_subnets: &[Principal],
+) -> Result<(), DeployError> {
+    match subnet_selection {
+        SubnetSelection::Subnet { subnet } => {
+            if !allowed_subnets.contains(subnet) {
+                return Err(DeployError::SubnetNotAllowed {
+                    subnet: subnet.to_text(),
+                });
+            }
+        }
+        SubnetSelection::Filter(SubnetFilter { subnet_type }) => {
+            if let Some(subnet_type) = subnet_type {
+                if !STATION_SUBNET_TYPES.contains(&subnet_type.as_str()) {
+                    return Err(DeployError::SubnetTypeNotAllowed {
+                        subnet_type: subnet_type.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_allowed_subnets_and_subnet_types_can_be_chosen() {
+        let allowed_subnet = Principal::from_slice(&[1; 29]);
+
+        assert!(validate_subnet_selection(
+            &SubnetSelection::Subnet {
+                subnet: allowed_subnet
+            },
+            &[allowed_subnet]
+        )
+        .is_ok());
+        assert_eq!(
+            validate_subnet_selection(
+                &SubnetSelection::Subnet {
+                    subnet: Principal::from_slice(&[2; 29])
+                },
+                &[allowed_subnet]
+            ),
+            Err(DeployError::SubnetNotAllowed {
+                subnet: Principal::from_slice(&[2; 29]).to_text()
+            })
+        );
+        assert!(validate_subnet_selection(
+            &SubnetSelection::Filter(SubnetFilter {
+                subnet_type: Some("fiduciary".to_string())
+            }),
+            &[]
+        )
+        .is_ok());
+        assert_eq!(
+            validate_subnet_selection(
+                &SubnetSelection::Filter(SubnetFilter {
+                    subnet_type: Some("verified_application".to_string())
+                }),
+                &[]
+            ),
+            Err(DeployError::SubnetTypeNotAllowed {
+                subnet_type: "verified_application".to_string()
+            })
+        );
+    }
+}
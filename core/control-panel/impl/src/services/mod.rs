@@ -17,3 +17,6 @@ pub use deploy::*;
 
 mod registry;
 pub use registry::*;
+
+mod approval_reminder;
+pub use approval_reminder::*;
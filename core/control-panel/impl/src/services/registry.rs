@@ -257,14 +257,10 @@ impl RegistryService {
         Ok(registry)
     }
 
-    /// Finds the next version of the registry entry by name and the current version.
+    /// Finds all the wasm module entries by name, sorted by version in ascending order.
     ///
-    /// If there is no next version, `None` is returned.
-    pub fn find_next_wasm_module_version(
-        &self,
-        name: &str,
-        current_version: &str,
-    ) -> ServiceResult<Option<RegistryEntry>> {
+    /// Fails if the wasm module has no entries in the registry.
+    pub fn find_wasm_module_entries(&self, name: &str) -> ServiceResult<Vec<RegistryEntry>> {
         let fullname = match name.starts_with(RegistryEntry::NAMESPACE_PREFIX) {
             true => name.to_string(),
             false => format!(
@@ -282,7 +278,7 @@ impl RegistryService {
             Some(RegistryEntrySortBy::Version(SortDirection::Asc)),
         );
 
-        let mut entries = results
+        let entries = results
             .iter()
             .filter_map(|id| self.get(id).ok())
             .collect::<Vec<RegistryEntry>>();
@@ -293,19 +289,54 @@ impl RegistryService {
             })?;
         }
 
+        Ok(entries)
+    }
+
+    /// Finds the next version of the registry entry by name and the current version.
+    ///
+    /// Versions above `max_version` are never suggested, and neither are the versions that can't be
+    /// upgraded to directly from the current version (see `min_upgrade_from`), in which case the
+    /// required intermediate version is suggested instead.
+    ///
+    /// If there is no next version, `None` is returned.
+    pub fn find_next_wasm_module_version(
+        &self,
+        name: &str,
+        current_version: &str,
+        max_version: Option<&str>,
+    ) -> ServiceResult<Option<RegistryEntry>> {
+        let mut entries = self.find_wasm_module_entries(name)?;
+
         entries.retain(|entry| match &entry.value {
             RegistryValue::WasmModule(wasm_module) => wasm_module.version != current_version,
         });
 
         let current_version = HelperMapper::to_semver(current_version);
+        let max_version = max_version.map(HelperMapper::to_semver);
 
         for entry in entries {
             match &entry.value {
                 RegistryValue::WasmModule(wasm_module) => {
                     let new_version = HelperMapper::to_semver(&wasm_module.version);
-                    if new_version > current_version {
-                        return Ok(Some(entry));
+                    if new_version <= current_version {
+                        continue;
+                    }
+
+                    if max_version
+                        .as_ref()
+                        .is_some_and(|max_version| new_version > *max_version)
+                    {
+                        break;
+                    }
+
+                    if entry
+                        .min_upgrade_from()
+                        .is_some_and(|min| HelperMapper::to_semver(min) > current_version)
+                    {
+                        continue;
                     }
+
+                    return Ok(Some(entry));
                 }
             }
         }
@@ -595,7 +626,7 @@ mod tests {
         }
 
         let next = REGISTRY_SERVICE
-            .find_next_wasm_module_version("module", "1.0.3")
+            .find_next_wasm_module_version("module", "1.0.3", None)
             .unwrap();
 
         assert!(next.is_some());
@@ -623,15 +654,90 @@ mod tests {
         }
 
         let next = REGISTRY_SERVICE
-            .find_next_wasm_module_version("module", "1.0.9")
+            .find_next_wasm_module_version("module", "1.0.9", None)
+            .unwrap();
+
+        assert!(next.is_none());
+    }
+
+    #[test]
+    fn should_not_suggest_versions_above_max_version() {
+        for i in 0..10 {
+            let mut entry = create_registry_entry();
+            entry.name = "module".to_string();
+            entry.value = RegistryValue::WasmModule(WasmModuleRegistryValue {
+                wasm_artifact_id: *Uuid::new_v4().as_bytes(),
+                version: format!("1.0.{}", i),
+                dependencies: Vec::new(),
+                module_extra_chunks: None,
+            });
+
+            REGISTRY_REPOSITORY.insert(entry.id, entry.clone());
+        }
+
+        let next = REGISTRY_SERVICE
+            .find_next_wasm_module_version("module", "1.0.3", Some("1.0.3"))
             .unwrap();
 
         assert!(next.is_none());
+
+        let next = REGISTRY_SERVICE
+            .find_next_wasm_module_version("module", "1.0.3", Some("1.0.5"))
+            .unwrap();
+
+        match next.unwrap().value {
+            RegistryValue::WasmModule(wasm_module) => {
+                assert_eq!(wasm_module.version, "1.0.4");
+            }
+        }
+    }
+
+    #[test]
+    fn should_skip_versions_not_upgradable_from_current_version() {
+        for i in 0..10 {
+            let mut entry = create_registry_entry();
+            entry.name = "module".to_string();
+            entry.value = RegistryValue::WasmModule(WasmModuleRegistryValue {
+                wasm_artifact_id: *Uuid::new_v4().as_bytes(),
+                version: format!("1.0.{}", i),
+                dependencies: Vec::new(),
+                module_extra_chunks: None,
+            });
+
+            if i == 4 {
+                entry.metadata.insert(
+                    RegistryEntry::MIN_UPGRADE_FROM_METADATA_KEY.to_string(),
+                    "1.0.2".to_string(),
+                );
+            }
+
+            REGISTRY_REPOSITORY.insert(entry.id, entry.clone());
+        }
+
+        let next = REGISTRY_SERVICE
+            .find_next_wasm_module_version("module", "1.0.1", None)
+            .unwrap();
+
+        match next.unwrap().value {
+            RegistryValue::WasmModule(wasm_module) => {
+                assert_eq!(wasm_module.version, "1.0.2");
+            }
+        }
+
+        let next = REGISTRY_SERVICE
+            .find_next_wasm_module_version("module", "1.0.3", None)
+            .unwrap();
+
+        match next.unwrap().value {
+            RegistryValue::WasmModule(wasm_module) => {
+                assert_eq!(wasm_module.version, "1.0.4");
+            }
+        }
     }
 
     #[test]
     fn should_fail_if_wasm_module_not_found() {
-        let result = REGISTRY_SERVICE.find_next_wasm_module_version("module", "1.0.0");
+        let result = REGISTRY_SERVICE.find_next_wasm_module_version("module", "1.0.0", None);
 
         assert!(result.is_err());
     }
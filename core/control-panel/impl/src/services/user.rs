@@ -1,8 +1,11 @@
 use crate::{
-    core::{generate_uuid_v4, ic_cdk::next_time, CallContext},
+    core::{canister_config, generate_uuid_v4, ic_cdk::next_time, CallContext},
     errors::UserError,
     mappers::{SubscribedUser, UserMapper},
-    models::{CanDeployStation, User, UserId, UserKey, UserSubscriptionStatus},
+    models::{
+        CanDeployStation, DeployedStationSubnet, NotificationChannel, User, UserId, UserKey,
+        UserSubscriptionStatus,
+    },
     repositories::{UserRepository, USER_REPOSITORY},
     services::canister::FUND_MANAGER,
 };
@@ -10,6 +13,7 @@ use candid::Principal;
 use canfund::manager::RegisterOpts;
 use control_panel_api::{RegisterUserInput, UpdateWaitingListInput};
 use lazy_static::lazy_static;
+use orbit_essentials::cmc::SubnetSelection;
 use orbit_essentials::repository::Repository;
 use orbit_essentials::{
     api::{ApiError, ServiceResult},
@@ -88,6 +92,7 @@ impl UserService {
         input: RegisterUserInput,
         ctx: &CallContext,
     ) -> ServiceResult<User, ApiError> {
+        self.purge_expired_deleted_user(&ctx.caller());
         self.assert_identity_is_unregistered(&ctx.caller())?;
 
         if ctx.caller() == Principal::anonymous() {
@@ -107,20 +112,82 @@ impl UserService {
         Ok(user)
     }
 
+    /// Deletes the user, which can be restored until the grace period elapses.
     pub async fn remove_user(
         &self,
         user_identity: &Principal,
         ctx: &CallContext,
     ) -> ServiceResult<User> {
-        let user = self.get_user_by_identity(user_identity, ctx)?;
+        let mut user = self.get_user_by_identity(user_identity, ctx)?;
 
         self.assert_user_access(&user, ctx)?;
 
-        self.user_repository.remove(&user.to_key());
+        if !user.is_deleted() {
+            let now = next_time();
+            user.deleted_at = Some(now);
+            user.last_update_timestamp = now;
+
+            self.user_repository.insert(user.to_key(), user.clone());
+        }
 
         Ok(user)
     }
 
+    /// Restores the deleted user if the grace period did not elapse yet.
+    pub async fn restore_user(
+        &self,
+        user_identity: &Principal,
+        ctx: &CallContext,
+    ) -> ServiceResult<User> {
+        let mut user = self.get_user_by_identity(user_identity, ctx)?;
+
+        if user.is_deletion_expired(next_time(), self.tombstone_grace_period_ns()) {
+            Err(UserError::NotFound {
+                user: user.identity.to_text(),
+            })?
+        }
+
+        if user.is_deleted() {
+            user.deleted_at = None;
+            user.last_update_timestamp = next_time();
+
+            self.user_repository.insert(user.to_key(), user.clone());
+        }
+
+        Ok(user)
+    }
+
+    /// Purges the deleted users and the removed stations whose grace period elapsed.
+    pub fn purge_expired_tombstones(&self) {
+        let now = next_time();
+        let grace_period_ns = self.tombstone_grace_period_ns();
+
+        for mut user in self.user_repository.list() {
+            if user.is_deletion_expired(now, grace_period_ns) {
+                self.user_repository.remove(&user.to_key());
+            } else if user.purge_expired_removed_stations(now, grace_period_ns) {
+                self.user_repository.insert(user.to_key(), user);
+            }
+        }
+    }
+
+    /// Purges the user of the identity if it was deleted and its grace period elapsed, so that
+    /// the identity can register again.
+    fn purge_expired_deleted_user(&self, identity: &Principal) {
+        if let Some(user) = self.user_repository.find_by_identity(identity) {
+            if user.is_deletion_expired(next_time(), self.tombstone_grace_period_ns()) {
+                self.user_repository.remove(&user.to_key());
+            }
+        }
+    }
+
+    /// Returns the grace period during which deleted users can be restored.
+    fn tombstone_grace_period_ns(&self) -> u64 {
+        canister_config()
+            .unwrap_or_default()
+            .tombstone_grace_period_ns()
+    }
+
     pub async fn subscribe_to_waiting_list(
         &self,
         email: String,
@@ -149,6 +216,24 @@ impl UserService {
         Ok(user)
     }
 
+    /// Replaces the channels the events of the stations of the caller are forwarded to.
+    pub fn set_notification_channels(
+        &self,
+        channels: Vec<NotificationChannel>,
+        ctx: &CallContext,
+    ) -> ServiceResult<User> {
+        let mut user = self.get_user_by_identity(&ctx.caller(), ctx)?;
+
+        user.notification_channels = channels;
+        user.last_update_timestamp = next_time();
+
+        user.validate()?;
+
+        self.user_repository.insert(user.to_key(), user.clone());
+
+        Ok(user)
+    }
+
     pub fn get_waiting_list(&self, ctx: &CallContext) -> ServiceResult<Vec<SubscribedUser>> {
         self.assert_controller(ctx)?;
 
@@ -176,12 +261,26 @@ impl UserService {
     }
 
     /// Returns all deployed stations in the system.
+    ///
+    /// The stations of deleted users and the removed stations are excluded while they can still
+    /// be restored.
     pub fn get_all_deployed_stations(&self) -> BTreeSet<Principal> {
         let users = self.user_repository.list();
 
         users
             .into_iter()
-            .flat_map(|user| user.deployed_stations)
+            .filter(|user| !user.is_deleted())
+            .flat_map(|user| {
+                let removed_stations = user
+                    .removed_stations
+                    .iter()
+                    .map(|removed| removed.station.canister_id)
+                    .collect::<BTreeSet<_>>();
+
+                user.deployed_stations
+                    .into_iter()
+                    .filter(move |canister_id| !removed_stations.contains(canister_id))
+            })
             .collect()
     }
 
@@ -189,11 +288,18 @@ impl UserService {
         &self,
         user_id: &UserId,
         station_canister_id: Principal,
+        subnet_selection: Option<SubnetSelection>,
         ctx: &CallContext,
     ) -> ServiceResult<User> {
         let mut user = self.get_user(user_id, ctx)?;
 
         user.deployed_stations.push(station_canister_id);
+        if let Some(subnet_selection) = subnet_selection {
+            user.deployed_station_subnets.push(DeployedStationSubnet {
+                canister_id: station_canister_id,
+                subnet_selection,
+            });
+        }
 
         user.validate()?;
 
@@ -261,9 +367,11 @@ impl UserService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::ic_cdk::api::set_mock_ic_time;
     use crate::models::{user_model_utils::mock_user, UserSubscriptionStatus};
     use control_panel_api::{UserStationDTO, UserSubscriptionStatusDTO};
     use orbit_essentials::cdk::mocks::TEST_CONTROLLER_ID;
+    use std::time::SystemTime;
 
     #[test]
     fn get_user_returns_not_found_err() {
@@ -429,6 +537,52 @@ mod tests {
         let result = service.remove_user(&user.identity, &ctx).await;
 
         assert!(result.is_ok());
+
+        let removed_user = service.user_repository.get(&user.to_key()).unwrap();
+        assert!(removed_user.is_deleted());
+    }
+
+    #[tokio::test]
+    async fn can_restore_removed_user() {
+        crate::core::test_utils::init_canister_config();
+
+        let mut user: User = mock_user();
+        user.deployed_stations = vec![Principal::from_slice(&[3; 29])];
+        let ctx = CallContext::new(user.identity);
+        let service = UserService::default();
+
+        service.user_repository.insert(user.to_key(), user.clone());
+
+        service.remove_user(&user.identity, &ctx).await.unwrap();
+
+        assert!(!service
+            .get_all_deployed_stations()
+            .contains(&user.deployed_stations[0]));
+
+        let restored_user = service.restore_user(&user.identity, &ctx).await.unwrap();
+
+        assert!(!restored_user.is_deleted());
+        assert!(service
+            .get_all_deployed_stations()
+            .contains(&user.deployed_stations[0]));
+    }
+
+    #[tokio::test]
+    async fn expired_removed_user_is_purged() {
+        crate::core::test_utils::init_canister_config();
+        set_mock_ic_time(SystemTime::now());
+
+        let mut user: User = mock_user();
+        user.deleted_at = Some(0);
+        let ctx = CallContext::new(user.identity);
+        let service = UserService::default();
+
+        service.user_repository.insert(user.to_key(), user.clone());
+
+        assert!(service.restore_user(&user.identity, &ctx).await.is_err());
+
+        service.purge_expired_tombstones();
+
         assert!(service.user_repository.get(&user.to_key()).is_none());
     }
 }
@@ -1,6 +1,10 @@
 use crate::{
-    core::{ic_cdk::api::print, CallContext},
-    models::{UserId, UserStation},
+    core::{
+        canister_config,
+        ic_cdk::{api::print, next_time},
+        CallContext,
+    },
+    models::{RemovedUserStation, UserId, UserStation},
     repositories::{UserRepository, USER_REPOSITORY},
     services::{UserService, USER_SERVICE},
 };
@@ -65,6 +69,30 @@ impl UserStationService {
         Ok(stations)
     }
 
+    /// Finds the removed stations of the user that can still be restored.
+    pub fn list_removed_stations(
+        &self,
+        user_id: &UserId,
+        ctx: &CallContext,
+    ) -> ServiceResult<Vec<RemovedUserStation>> {
+        let user = self.user_service.get_user(user_id, ctx)?;
+        let now = next_time();
+        let grace_period_ns = self.tombstone_grace_period_ns();
+
+        Ok(user
+            .removed_stations
+            .into_iter()
+            .filter(|removed| !removed.is_expired(now, grace_period_ns))
+            .collect())
+    }
+
+    /// Returns the grace period during which removed stations can be restored.
+    pub fn tombstone_grace_period_ns(&self) -> u64 {
+        canister_config()
+            .unwrap_or_default()
+            .tombstone_grace_period_ns()
+    }
+
     /// Adds the provided stations to the user.
     ///
     /// If a station with the same ID already exists, it is updated.
@@ -77,6 +105,10 @@ impl UserStationService {
         let mut user = self.user_service.get_user(user_id, ctx)?;
 
         for new_station in stations {
+            // Adding back a removed station supersedes its removal.
+            user.removed_stations
+                .retain(|removed| removed.station.canister_id != new_station.canister_id);
+
             let station_index = user
                 .stations
                 .iter()
@@ -100,6 +132,8 @@ impl UserStationService {
     }
 
     /// Removes stations with the given IDs from the user.
+    ///
+    /// The removed stations can be restored until the grace period elapses.
     pub fn remove_stations(
         &self,
         user_id: &UserId,
@@ -107,9 +141,41 @@ impl UserStationService {
         ctx: &CallContext,
     ) -> ServiceResult<()> {
         let mut user = self.user_service.get_user(user_id, ctx)?;
+        let now = next_time();
+
+        user.purge_expired_removed_stations(now, self.tombstone_grace_period_ns());
+        user.remove_stations(&station_ids, now);
+
+        user.validate()?;
+
+        self.user_repository.insert(user.to_key(), user.clone());
+
+        Ok(())
+    }
+
+    /// Restores the removed stations with the given IDs to the user.
+    ///
+    /// Stations that were not removed or whose grace period elapsed are ignored.
+    pub fn restore_stations(
+        &self,
+        user_id: &UserId,
+        station_ids: Vec<Principal>,
+        ctx: &CallContext,
+    ) -> ServiceResult<()> {
+        let mut user = self.user_service.get_user(user_id, ctx)?;
 
-        user.stations
-            .retain(|station| !station_ids.contains(&station.canister_id));
+        user.purge_expired_removed_stations(next_time(), self.tombstone_grace_period_ns());
+
+        let (restored, removed): (Vec<_>, Vec<_>) = std::mem::take(&mut user.removed_stations)
+            .into_iter()
+            .partition(|removed| station_ids.contains(&removed.station.canister_id));
+
+        user.removed_stations = removed;
+        for restored_station in restored {
+            if !user.stations.contains(&restored_station.station) {
+                user.stations.push(restored_station.station);
+            }
+        }
 
         user.validate()?;
 
@@ -160,7 +226,9 @@ impl UserStationService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::ic_cdk::api::set_mock_ic_time;
     use crate::models::{user_model_utils::mock_user, user_station_model_utils::mock_user_station};
+    use std::time::SystemTime;
 
     #[test]
     fn test_add_stations() {
@@ -290,6 +358,71 @@ mod tests {
         let updated_user = USER_REPOSITORY.get(&user.to_key()).unwrap();
 
         assert_eq!(updated_user.stations.len(), 0);
+        assert_eq!(updated_user.removed_stations.len(), 1);
+    }
+
+    #[test]
+    fn test_restore_removed_stations() {
+        let removed_station = mock_user_station();
+
+        let mut user = mock_user();
+        user.stations = vec![removed_station.clone(), mock_user_station()];
+
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let ctx = CallContext::new(user.identity);
+
+        USER_STATION_SERVICE
+            .remove_stations(&user.id, vec![removed_station.canister_id], &ctx)
+            .unwrap();
+
+        let removed_stations = USER_STATION_SERVICE
+            .list_removed_stations(&user.id, &ctx)
+            .unwrap();
+
+        assert_eq!(removed_stations.len(), 1);
+        assert_eq!(removed_stations[0].station, removed_station);
+
+        USER_STATION_SERVICE
+            .restore_stations(&user.id, vec![removed_station.canister_id], &ctx)
+            .unwrap();
+
+        let updated_user = USER_REPOSITORY.get(&user.to_key()).unwrap();
+
+        assert_eq!(updated_user.stations.len(), 2);
+        assert!(updated_user.stations.contains(&removed_station));
+        assert!(updated_user.removed_stations.is_empty());
+    }
+
+    #[test]
+    fn test_expired_removed_stations_are_not_restored() {
+        set_mock_ic_time(SystemTime::now());
+
+        let removed_station = mock_user_station();
+
+        let mut user = mock_user();
+        user.removed_stations = vec![RemovedUserStation {
+            station: removed_station.clone(),
+            removed_at: 0,
+        }];
+
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let ctx = CallContext::new(user.identity);
+
+        assert!(USER_STATION_SERVICE
+            .list_removed_stations(&user.id, &ctx)
+            .unwrap()
+            .is_empty());
+
+        USER_STATION_SERVICE
+            .restore_stations(&user.id, vec![removed_station.canister_id], &ctx)
+            .unwrap();
+
+        let updated_user = USER_REPOSITORY.get(&user.to_key()).unwrap();
+
+        assert!(updated_user.stations.is_empty());
+        assert!(updated_user.removed_stations.is_empty());
     }
 
     #[test]
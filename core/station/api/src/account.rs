@@ -1,7 +1,15 @@
 use crate::{
-    AllowDTO, MetadataDTO, PaginationInput, RequestPolicyRuleDTO, RequestPolicyRuleInput, UuidDTO,
+    AllowDTO, MetadataDTO, PaginationInput, RequestPolicyRuleDTO, RequestPolicyRuleInput,
+    TimestampRfc3339, UuidDTO,
 };
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkProfileDTO {
+    Mainnet,
+    Testnet,
+    Local,
+}
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct AccountCallerPrivilegesDTO {
@@ -17,15 +25,58 @@ pub struct AccountDTO {
     pub address: String,
     pub blockchain: String,
     pub standard: String,
+    pub network: NetworkProfileDTO,
     pub symbol: String,
     pub decimals: u32,
     pub balance: Option<AccountBalanceInfoDTO>,
     pub metadata: Vec<MetadataDTO>,
     pub transfer_request_policy: Option<RequestPolicyRuleDTO>,
     pub configs_request_policy: Option<RequestPolicyRuleDTO>,
+    pub trusted_destinations: Vec<TrustedDestinationDTO>,
+    pub subaccounts: Vec<AccountSubaccountDTO>,
+    pub webhooks: Vec<AccountWebhookDTO>,
+    pub assets: Vec<AccountAssetDTO>,
     pub last_modification_timestamp: String,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountAssetDTO {
+    pub asset_id: UuidDTO,
+    pub symbol: String,
+    pub decimals: u32,
+    pub balance: Option<AccountBalanceInfoDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountWebhookDTO {
+    pub canister_id: Principal,
+    pub method_name: String,
+    /// The secret the callbacks are signed with, it is never returned by the station.
+    pub secret: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountSubaccountDTO {
+    pub index: u32,
+    pub name: String,
+    pub address: String,
+    pub balance: Option<AccountBalanceInfoDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum TrustedDestinationPeriodDTO {
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TrustedDestinationDTO {
+    pub address: String,
+    pub max_amount: candid::Nat,
+    pub period: TrustedDestinationPeriodDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditAccountOperationInput {
     pub account_id: UuidDTO,
@@ -35,11 +86,41 @@ pub struct EditAccountOperationInput {
     pub transfer_permission: Option<AllowDTO>,
     pub configs_request_policy: Option<RequestPolicyRuleInput>,
     pub transfer_request_policy: Option<RequestPolicyRuleInput>,
+    /// The new list of webhooks called when a transfer of the account completes or fails.
+    pub webhooks: Option<Vec<AccountWebhookDTO>>,
+    /// The new list of registered assets held by the account in addition to its own asset.
+    pub assets: Option<Vec<UuidDTO>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditAccountOperationDTO {
     pub input: EditAccountOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditAccountOperationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetAutoApprovalForTrustedDestinationsOperationInput {
+    pub account_id: UuidDTO,
+    /// The new list of trusted destinations, which replaces the current one.
+    pub trusted_destinations: Vec<TrustedDestinationDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetAutoApprovalForTrustedDestinationsOperationDTO {
+    pub input: SetAutoApprovalForTrustedDestinationsOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DeriveSubaccountOperationInput {
+    pub account_id: UuidDTO,
+    pub name: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DeriveSubaccountOperationDTO {
+    pub subaccount_index: Option<u32>,
+    pub input: DeriveSubaccountOperationInput,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -47,6 +128,7 @@ pub struct AddAccountOperationInput {
     pub name: String,
     pub blockchain: String,
     pub standard: String,
+    pub network: Option<NetworkProfileDTO>,
     pub metadata: Vec<MetadataDTO>,
     pub read_permission: AllowDTO,
     pub configs_permission: AllowDTO,
@@ -83,6 +165,25 @@ pub struct AccountBalanceDTO {
     pub balance: candid::Nat,
     pub decimals: u32,
     pub last_update_timestamp: String,
+    pub pending_deposits: Vec<PendingDepositDTO>,
+    pub fiat_values: Vec<FiatValueDTO>,
+    pub assets: Vec<AccountAssetDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FiatValueDTO {
+    pub currency: String,
+    pub value: candid::Nat,
+    pub decimals: u32,
+    pub rate_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PendingDepositDTO {
+    pub id: String,
+    pub amount: candid::Nat,
+    pub confirmations: u32,
+    pub required_confirmations: Option<u32>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -97,6 +198,78 @@ pub struct FetchAccountBalancesResponse {
     pub balances: Vec<AccountBalanceDTO>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ReserveAccountDTO {
+    pub account_id: UuidDTO,
+    pub name: String,
+    pub blockchain: String,
+    pub standard: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub addresses: Vec<String>,
+    pub balance: Option<candid::Nat>,
+    pub last_update_timestamp: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ReservesSnapshotDTO {
+    pub taken_at: TimestampRfc3339,
+    pub accounts: Vec<ReserveAccountDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetProofOfReservesResponse {
+    /// The candid encoding of the `ReservesSnapshotDTO`, whose sha256 hash is certified.
+    #[serde(with = "serde_bytes")]
+    pub snapshot: Vec<u8>,
+    #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
+    pub certificate: Option<Vec<u8>>,
+    #[serde(with = "serde_bytes")]
+    pub witness: Vec<u8>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListNftsInput {
+    pub account_id: UuidDTO,
+    /// The token id after which to start listing, the listing starts from the first token when not set.
+    pub prev: Option<candid::Nat>,
+    pub take: Option<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListNftsResponse {
+    /// The ids of the tokens held by the NFT account, in the order of the collection.
+    pub token_ids: Vec<candid::Nat>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DiscoverAccountsInput {
+    pub ledger_canister_ids: Vec<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DiscoveredAccountDTO {
+    pub ledger_canister_id: Principal,
+    pub address: String,
+    pub account_id: Option<UuidDTO>,
+    pub symbol: String,
+    pub decimals: u32,
+    pub balance: candid::Nat,
+    pub proposed_operation: Option<AddAccountOperationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FailedLedgerDiscoveryDTO {
+    pub ledger_canister_id: Principal,
+    pub reason: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DiscoverAccountsResponse {
+    pub accounts: Vec<DiscoveredAccountDTO>,
+    pub failed_ledgers: Vec<FailedLedgerDiscoveryDTO>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ListAccountsInput {
     pub search_term: Option<String>,
@@ -110,3 +283,168 @@ pub struct ListAccountsResponse {
     pub total: u64,
     pub privileges: Vec<AccountCallerPrivilegesDTO>,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum FundingRequestStatusDTO {
+    Pending,
+    CollectRequested { request_id: UuidDTO },
+    Expired,
+}
+
+/// The ICRC-2 approval the payer must submit to the ledger to fund the account.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FundingApprovalDTO {
+    pub ledger_canister_id: Principal,
+    /// The ICRC-1 textual account of the spender, which is the ledger account of the station account.
+    pub spender: String,
+    /// The amount to approve, which covers the ledger fee of the collection.
+    pub amount: candid::Nat,
+    pub expires_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FundingRequestDTO {
+    pub id: UuidDTO,
+    pub account_id: UuidDTO,
+    pub payer: String,
+    pub amount: candid::Nat,
+    pub reference: Option<String>,
+    pub approval: FundingApprovalDTO,
+    pub status: FundingRequestStatusDTO,
+    pub requested_by: UuidDTO,
+    pub expires_at: TimestampRfc3339,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateFundingRequestInput {
+    pub account_id: UuidDTO,
+    /// The ICRC-1 textual account of the payer.
+    pub payer: String,
+    pub amount: candid::Nat,
+    pub reference: Option<String>,
+    pub expires_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateFundingRequestResponse {
+    pub funding_request: FundingRequestDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListFundingRequestsInput {
+    pub account_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListFundingRequestsResponse {
+    pub funding_requests: Vec<FundingRequestDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum AccountTransactionKindDTO {
+    Transfer,
+    Mint,
+    Burn,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum AccountTransactionDirectionDTO {
+    Inbound,
+    Outbound,
+}
+
+/// A transaction of the account as recorded by the ledger, including the deposits.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccountTransactionDTO {
+    pub account_id: UuidDTO,
+    pub block_index: u64,
+    pub kind: AccountTransactionKindDTO,
+    pub direction: AccountTransactionDirectionDTO,
+    /// The other account of the transfer, mints and burns have none.
+    pub counterparty: Option<String>,
+    pub amount: candid::Nat,
+    pub fee: Option<candid::Nat>,
+    pub memo: Option<Vec<u8>>,
+    pub timestamp: Option<TimestampRfc3339>,
+    /// The transfer of the station that submitted the transaction, if any.
+    pub transfer_id: Option<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ImportAccountTransactionsInput {
+    pub account_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ImportAccountTransactionsResponse {
+    /// The number of transactions imported by the call, more may be left to import.
+    pub imported: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountTransactionsInput {
+    pub account_id: UuidDTO,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAccountTransactionsResponse {
+    pub transactions: Vec<AccountTransactionDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapabilityScopeDTO {
+    Transfers,
+    Balances,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CapabilityGrantDTO {
+    pub id: UuidDTO,
+    pub account_id: UuidDTO,
+    pub grantee: Principal,
+    pub scopes: Vec<CapabilityScopeDTO>,
+    pub expires_at: TimestampRfc3339,
+    pub created_by: UuidDTO,
+    pub created_at: TimestampRfc3339,
+    pub revoked_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateCapabilityGrantOperationInput {
+    pub account_id: UuidDTO,
+    /// The principal of the third-party canister or service.
+    pub grantee: Principal,
+    pub scopes: Vec<CapabilityScopeDTO>,
+    /// At most one year after the creation of the grant.
+    pub expires_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateCapabilityGrantOperationDTO {
+    pub grant: Option<CapabilityGrantDTO>,
+    pub input: CreateCapabilityGrantOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListCapabilityGrantsInput {
+    pub account_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListCapabilityGrantsResponse {
+    pub grants: Vec<CapabilityGrantDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RevokeCapabilityGrantOperationInput {
+    pub grant_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RevokeCapabilityGrantOperationDTO {
+    pub input: RevokeCapabilityGrantOperationInput,
+}
@@ -1,4 +1,4 @@
-use crate::{ChangeMetadataDTO, MetadataDTO, PaginationInput, UuidDTO};
+use crate::{ChangeMetadataDTO, MetadataDTO, PaginationInput, TimestampRfc3339, UuidDTO};
 use candid::{CandidType, Deserialize};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -9,6 +9,7 @@ pub struct AddressBookEntryDTO {
     pub blockchain: String,
     pub labels: Vec<String>,
     pub metadata: Vec<MetadataDTO>,
+    pub requires_memo: bool,
     pub last_modification_timestamp: String,
 }
 
@@ -32,11 +33,14 @@ pub struct AddAddressBookEntryOperationInput {
     pub blockchain: String,
     pub metadata: Vec<MetadataDTO>,
     pub labels: Vec<String>,
+    pub requires_memo: Option<bool>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditAddressBookEntryOperationDTO {
     pub input: EditAddressBookEntryOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditAddressBookEntryOperationInput>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -45,6 +49,7 @@ pub struct EditAddressBookEntryOperationInput {
     pub address_owner: Option<String>,
     pub labels: Option<Vec<String>>,
     pub change_metadata: Option<ChangeMetadataDTO>,
+    pub requires_memo: Option<bool>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -57,6 +62,37 @@ pub struct RemoveAddressBookEntryOperationInput {
     pub address_book_entry_id: UuidDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddressBookLabelDTO {
+    pub name: String,
+    pub description: Option<String>,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddressBookLabelInput {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ManageAddressBookLabelsOperationDTO {
+    pub input: ManageAddressBookLabelsOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ManageAddressBookLabelsOperationInput {
+    /// The labels to define, the description of the labels that are already defined is replaced.
+    pub set_labels: Vec<AddressBookLabelInput>,
+    /// The labels to remove, which must no longer be used by any entry.
+    pub remove_labels: Vec<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListAddressBookLabelsResponse {
+    pub labels: Vec<AddressBookLabelDTO>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct GetAddressBookEntryInputDTO {
     pub address_book_entry_id: UuidDTO,
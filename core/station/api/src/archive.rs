@@ -0,0 +1,49 @@
+use super::TimestampRfc3339;
+use crate::{RequestDTO, Sha256HashDTO, TransferDTO};
+use candid::{CandidType, Deserialize};
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum ArchivedRecordDTO {
+    Request(RequestDTO),
+    Transfer(TransferDTO),
+}
+
+/// A batch of settled records exported to the archive canister.
+///
+/// The `hash` is the sha256 of the candid encoding of `(sequence, previous_hash, records)`, so
+/// each chunk commits to all the chunks before it.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ArchiveChunkDTO {
+    pub sequence: u64,
+    pub previous_hash: Option<Sha256HashDTO>,
+    pub records: Vec<ArchivedRecordDTO>,
+    pub hash: Sha256HashDTO,
+    pub archived_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct QueryArchiveInput {
+    pub from_sequence: Option<u64>,
+    pub limit: Option<u16>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct QueryArchiveResponse {
+    pub chunks: Vec<ArchiveChunkDTO>,
+    pub next_sequence: Option<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DownloadArchiveChunkInput {
+    pub sequence: Option<u64>,
+}
+
+/// A chunk kept in the station, as the candid encoding of its `ArchiveChunkDTO`.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct DownloadArchiveChunkResponse {
+    pub sequence: u64,
+    pub hash: Sha256HashDTO,
+    #[serde(with = "serde_bytes")]
+    pub chunk: Vec<u8>,
+    pub next_sequence: Option<u64>,
+}
@@ -0,0 +1,89 @@
+use crate::{ChangeMetadataDTO, MetadataDTO, PaginationInput, UuidDTO};
+use candid::{CandidType, Deserialize, Principal};
+
+/// An ICRC-1 token registered by the station without a canister upgrade.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredAssetDTO {
+    pub id: UuidDTO,
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u32,
+    pub metadata: Vec<MetadataDTO>,
+    pub last_modification_timestamp: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RegisteredAssetCallerPrivilegesDTO {
+    pub id: UuidDTO,
+    pub can_edit: bool,
+    pub can_delete: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddAssetOperationDTO {
+    pub asset: Option<RegisteredAssetDTO>,
+    pub input: AddAssetOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddAssetOperationInput {
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u32,
+    pub metadata: Vec<MetadataDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditAssetOperationDTO {
+    pub input: EditAssetOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditAssetOperationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditAssetOperationInput {
+    pub asset_id: UuidDTO,
+    pub index_canister_id: Option<Principal>,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+    pub decimals: Option<u32>,
+    pub change_metadata: Option<ChangeMetadataDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveAssetOperationDTO {
+    pub input: RemoveAssetOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveAssetOperationInput {
+    pub asset_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRegisteredAssetInput {
+    pub asset_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetRegisteredAssetResponse {
+    pub asset: RegisteredAssetDTO,
+    pub privileges: RegisteredAssetCallerPrivilegesDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRegisteredAssetsInput {
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRegisteredAssetsResponse {
+    pub assets: Vec<RegisteredAssetDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+    pub privileges: Vec<RegisteredAssetCallerPrivilegesDTO>,
+}
@@ -1,6 +1,10 @@
 use crate::MetadataDTO;
 use candid::{CandidType, Deserialize};
 
+/// The version of the station API, reported by the station in its capabilities so that the clients
+/// can tell whether they were built against a compatible interface.
+pub const STATION_API_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct AssetDTO {
     /// The blockchain identifier (e.g., `ethereum`, `bitcoin`, `icp`, etc.)
@@ -25,6 +29,8 @@ pub struct CapabilitiesDTO {
     pub name: String,
     /// The current version of the canister.
     pub version: String,
+    /// The version of the station API that the canister implements.
+    pub api_version: Option<String>,
     /// The list of assets that are supported by the canister (e.g. `ICP`, `BTC`, `ETH`, etc.)
     pub supported_assets: Vec<AssetDTO>,
 }
@@ -33,3 +39,9 @@ pub struct CapabilitiesDTO {
 pub struct CapabilitiesResponse {
     pub capabilities: CapabilitiesDTO,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct ListSupportedAssetsResponse {
+    /// The assets that accounts can be added for, including the discovered SNS tokens.
+    pub assets: Vec<AssetDTO>,
+}
@@ -0,0 +1,446 @@
+//! Typed async client of the station canister, built on top of `ic-agent`.
+//!
+//! Enabled with the `client` feature, it lets Rust services integrate with a station without
+//! encoding and decoding the candid calls by hand:
+//!
+//! ```ignore
+//! let client = StationClient::new(agent, station_id);
+//! let mut pages = client.list_accounts_pages(ListAccountsInput { search_term: None, paginate: None });
+//! while let Some(page) = pages.next_page().await {
+//!     for account in page?.accounts { /* ... */ }
+//! }
+//! ```
+use crate::*;
+use candid::{CandidType, Principal};
+use ic_agent::{Agent, AgentError};
+use serde::de::DeserializeOwned;
+use std::{marker::PhantomData, time::Duration};
+use thiserror::Error;
+
+pub type StationClientResult<T> = Result<T, StationClientError>;
+
+#[derive(Error, Debug)]
+pub enum StationClientError {
+    #[error("The station API returned an error: {}", .0.code)]
+    Api(ApiErrorDTO),
+    #[error("Failed to encode or decode candid: {0}")]
+    Candid(#[from] candid::Error),
+    #[error("Error in the IC agent: {0}")]
+    Agent(#[from] AgentError),
+}
+
+impl From<ApiErrorDTO> for StationClientError {
+    fn from(value: ApiErrorDTO) -> Self {
+        Self::Api(value)
+    }
+}
+
+/// Defines how the calls that failed because of a transient error of the network are retried.
+///
+/// The errors returned by the station itself are never retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts of a call, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry, which doubles with every retry.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Whether update calls are retried as well.
+    ///
+    /// Disabled by default since an update that timed out may still have been executed, retrying
+    /// it could for example create the same request twice.
+    pub retry_updates: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(5),
+            retry_updates: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries the calls.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+
+    fn is_transient(error: &AgentError) -> bool {
+        match error {
+            AgentError::TransportError(..) | AgentError::TimeoutWaitingForResponse(..) => true,
+            AgentError::HttpError(payload) => payload.status == 429 || payload.status >= 500,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CallKind {
+    Query,
+    Update,
+}
+
+/// A typed client of a station canister.
+#[derive(Clone)]
+pub struct StationClient {
+    agent: Agent,
+    station_id: Principal,
+    retry_policy: RetryPolicy,
+}
+
+macro_rules! station_methods {
+    ($($kind:ident $method:ident($($input:ty)?) -> $output:ty;)*) => {
+        $(station_methods!(@method $kind $method($($input)?) -> $output);)*
+    };
+    (@method $kind:ident $method:ident() -> $output:ty) => {
+        #[doc = concat!("Calls the `", stringify!($method), "` method of the station.")]
+        pub async fn $method(&self) -> StationClientResult<$output> {
+            self.call(station_methods!(@kind $kind), stringify!($method), ()).await
+        }
+    };
+    (@method $kind:ident $method:ident($input:ty) -> $output:ty) => {
+        #[doc = concat!("Calls the `", stringify!($method), "` method of the station.")]
+        pub async fn $method(&self, input: $input) -> StationClientResult<$output> {
+            self.call(station_methods!(@kind $kind), stringify!($method), input).await
+        }
+    };
+    (@kind query) => { CallKind::Query };
+    (@kind update) => { CallKind::Update };
+}
+
+macro_rules! station_paginated_methods {
+    ($($method:ident, $pages:ident($input:ty) -> $output:ty;)*) => {
+        $(
+            #[doc = concat!("Returns the pages of the `", stringify!($method), "` method of the station, starting at the offset of the input.")]
+            pub fn $pages(&self, input: $input) -> Pages<'_, $input, $output> {
+                Pages::new(self, stringify!($method), input)
+            }
+        )*
+    };
+}
+
+impl StationClient {
+    pub fn new(agent: Agent, station_id: Principal) -> Self {
+        Self {
+            agent,
+            station_id,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn station_id(&self) -> Principal {
+        self.station_id
+    }
+
+    pub fn agent(&self) -> &Agent {
+        &self.agent
+    }
+
+    /// Calls the method and decodes its `Result` response.
+    async fn call<Req, Res>(
+        &self,
+        kind: CallKind,
+        method: &str,
+        input: Req,
+    ) -> StationClientResult<Res>
+    where
+        Req: CandidType,
+        Res: CandidType + DeserializeOwned,
+    {
+        let response: Result<Res, ApiErrorDTO> = self.call_raw(kind, method, input).await?;
+
+        Ok(response?)
+    }
+
+    /// Calls the method with the retry policy of the client and decodes its response as is.
+    async fn call_raw<Req, Res>(
+        &self,
+        kind: CallKind,
+        method: &str,
+        input: Req,
+    ) -> StationClientResult<Res>
+    where
+        Req: CandidType,
+        Res: CandidType + DeserializeOwned,
+    {
+        let arg = candid::encode_one(input)?;
+        let retries = kind == CallKind::Query || self.retry_policy.retry_updates;
+
+        let mut attempt = 0;
+        let response = loop {
+            attempt += 1;
+
+            let result = match kind {
+                CallKind::Query => {
+                    self.agent
+                        .query(&self.station_id, method)
+                        .with_arg(arg.clone())
+                        .call()
+                        .await
+                }
+                CallKind::Update => {
+                    self.agent
+                        .update(&self.station_id, method)
+                        .with_arg(arg.clone())
+                        .call_and_wait()
+                        .await
+                }
+            };
+
+            match result {
+                Err(error)
+                    if retries
+                        && attempt < self.retry_policy.max_attempts
+                        && RetryPolicy::is_transient(&error) =>
+                {
+                    tokio::time::sleep(self.retry_policy.backoff(attempt - 1)).await;
+                }
+                result => break result?,
+            }
+        };
+
+        Ok(candid::decode_one(&response)?)
+    }
+
+    /// Calls the `health_status` method of the station, which does not fail.
+    pub async fn health_status(&self) -> StationClientResult<HealthStatus> {
+        self.call_raw(CallKind::Query, "health_status", ()).await
+    }
+
+    station_methods! {
+        query system_info() -> SystemInfoResponse;
+        query capabilities() -> CapabilitiesResponse;
+        query get_station_attestation() -> GetStationAttestationResponse;
+        update list_supported_assets() -> ListSupportedAssetsResponse;
+        query get_registered_asset(GetRegisteredAssetInput) -> GetRegisteredAssetResponse;
+        query list_registered_assets(ListRegisteredAssetsInput) -> ListRegisteredAssetsResponse;
+        query me() -> MeResponse;
+        update view_as(ViewAsInput) -> ViewAsResponse;
+        query list_support_access_log(ListSupportAccessLogInput) -> ListSupportAccessLogResponse;
+        update report_onboarding_step(ReportOnboardingStepInput) -> ReportOnboardingStepResponse;
+        update create_calendar_feed() -> CreateCalendarFeedResponse;
+        update revoke_calendar_feed() -> ();
+        update set_locale(SetLocaleInput) -> ();
+        update reauthenticate() -> ReauthenticateResponse;
+        update set_notification_preferences(SetNotificationPreferencesInput) -> ();
+        query list_notifications(ListNotificationsInput) -> ListNotificationsResponse;
+        update mark_notifications_read(MarkNotificationsReadInput) -> ();
+        query get_external_canister(GetExternalCanisterInput) -> GetExternalCanisterResponse;
+        query list_external_canisters(ListExternalCanistersInput) -> ListExternalCanistersResponse;
+        query get_external_canister_filters(GetExternalCanisterFiltersInput) -> GetExternalCanisterFiltersResponse;
+        query get_account(GetAccountInput) -> GetAccountResponse;
+        update fetch_account_balances(FetchAccountBalancesInput) -> FetchAccountBalancesResponse;
+        update list_nfts(ListNftsInput) -> ListNftsResponse;
+        query get_proof_of_reserves() -> GetProofOfReservesResponse;
+        update create_funding_request(CreateFundingRequestInput) -> CreateFundingRequestResponse;
+        query list_funding_requests(ListFundingRequestsInput) -> ListFundingRequestsResponse;
+        update import_account_transactions(ImportAccountTransactionsInput) -> ImportAccountTransactionsResponse;
+        query list_account_transactions(ListAccountTransactionsInput) -> ListAccountTransactionsResponse;
+        query list_capability_grants(ListCapabilityGrantsInput) -> ListCapabilityGrantsResponse;
+        update discover_accounts(DiscoverAccountsInput) -> DiscoverAccountsResponse;
+        query list_accounts(ListAccountsInput) -> ListAccountsResponse;
+        query list_account_transfers(ListAccountTransfersInput) -> ListAccountTransfersResponse;
+        query get_transfers(GetTransfersInput) -> GetTransfersResponse;
+        query get_transfer(GetTransferInput) -> GetTransferResponse;
+        query get_transfer_receipt(GetTransferReceiptInput) -> GetTransferReceiptResponse;
+        update audit_pending_outflows(AuditPendingOutflowsInput) -> AuditPendingOutflowsResponse;
+        query get_spending_summary(GetSpendingSummaryInput) -> GetSpendingSummaryResponse;
+        update get_transfer_fees(GetTransferFeesInput) -> GetTransferFeesResponse;
+        query list_scheduled_transfers(ListScheduledTransfersInput) -> ListScheduledTransfersResponse;
+        update cancel_scheduled_transfer(CancelScheduledTransferInput) -> CancelScheduledTransferResponse;
+        query list_transfer_categories() -> ListTransferCategoriesResponse;
+        update set_transfer_category(SetTransferCategoryInput) -> SetTransferCategoryResponse;
+        update remove_transfer_category(RemoveTransferCategoryInput) -> RemoveTransferCategoryResponse;
+        update create_payout_run(CreatePayoutRunInput) -> CreatePayoutRunResponse;
+        query get_payout_run(GetPayoutRunInput) -> GetPayoutRunResponse;
+        query get_address_book_entry(GetAddressBookEntryInputDTO) -> GetAddressBookEntryResponseDTO;
+        query list_address_book_entries(ListAddressBookEntriesInputDTO) -> ListAddressBookEntriesResponseDTO;
+        update create_request(CreateRequestInput) -> CreateRequestResponse;
+        update request_user_group_membership(RequestUserGroupMembershipInput) -> CreateRequestResponse;
+        query list_requests(ListRequestsInput) -> ListRequestsResponse;
+        update create_request_view(CreateRequestViewInput) -> RequestViewResponse;
+        update edit_request_view(EditRequestViewInput) -> RequestViewResponse;
+        update remove_request_view(RemoveRequestViewInput) -> ();
+        query list_request_views() -> ListRequestViewsResponse;
+        query list_requests_by_view(ListRequestsByViewInput) -> ListRequestsResponse;
+        query search_requests(SearchRequestsInput) -> ListRequestsResponse;
+        query get_request(GetRequestInput) -> GetRequestResponse;
+        query get_next_approvable_request(GetNextApprovableRequestInput) -> GetNextApprovableRequestResponse;
+        update submit_request_approval(SubmitRequestApprovalInput) -> SubmitRequestApprovalResponse;
+        update cancel_request(CancelRequestInput) -> CancelRequestResponse;
+        update amend_request(AmendRequestInput) -> AmendRequestResponse;
+        query get_user(GetUserInput) -> GetUserResponse;
+        query list_users(ListUsersInput) -> ListUsersResponse;
+        query list_permissions(ListPermissionsInput) -> ListPermissionsResponse;
+        query get_permission(GetPermissionInput) -> GetPermissionResponse;
+        query suggest_policies() -> SuggestPoliciesResponse;
+        query export_access_policies() -> ExportAccessPoliciesResponse;
+        query preview_access_policies_import(PreviewAccessPoliciesImportInput) -> PreviewAccessPoliciesImportResponse;
+        query list_request_policies(ListRequestPoliciesInput) -> ListRequestPoliciesResponse;
+        query get_request_policy(GetRequestPolicyInput) -> GetRequestPolicyResponse;
+        query get_user_group(GetUserGroupInput) -> GetUserGroupResponse;
+        query list_user_groups(ListUserGroupsInput) -> ListUserGroupsResponse;
+        update notify_failed_station_upgrade(NotifyFailedStationUpgradeInput) -> ();
+        update resume_blockchain(ResumeBlockchainInput) -> ();
+        update query_archive(QueryArchiveInput) -> QueryArchiveResponse;
+        query download_archive_chunk(DownloadArchiveChunkInput) -> DownloadArchiveChunkResponse;
+        update upload_locale_catalog(UploadLocaleCatalogInput) -> UploadLocaleCatalogResponse;
+        query list_notification_templates() -> ListNotificationTemplatesResponse;
+        query list_pending_executions() -> ListPendingExecutionsResponse;
+    }
+
+    station_paginated_methods! {
+        list_support_access_log, list_support_access_log_pages(ListSupportAccessLogInput) -> ListSupportAccessLogResponse;
+        list_external_canisters, list_external_canisters_pages(ListExternalCanistersInput) -> ListExternalCanistersResponse;
+        list_account_transactions, list_account_transactions_pages(ListAccountTransactionsInput) -> ListAccountTransactionsResponse;
+        list_accounts, list_accounts_pages(ListAccountsInput) -> ListAccountsResponse;
+        list_registered_assets, list_registered_assets_pages(ListRegisteredAssetsInput) -> ListRegisteredAssetsResponse;
+        list_address_book_entries, list_address_book_entries_pages(ListAddressBookEntriesInputDTO) -> ListAddressBookEntriesResponseDTO;
+        list_requests, list_requests_pages(ListRequestsInput) -> ListRequestsResponse;
+        list_users, list_users_pages(ListUsersInput) -> ListUsersResponse;
+        list_permissions, list_permissions_pages(ListPermissionsInput) -> ListPermissionsResponse;
+        list_request_policies, list_request_policies_pages(ListRequestPoliciesInput) -> ListRequestPoliciesResponse;
+        list_user_groups, list_user_groups_pages(ListUserGroupsInput) -> ListUserGroupsResponse;
+    }
+}
+
+/// The input of a paginated method, whose offset is moved forward to fetch the next pages.
+pub trait PaginatedInput: CandidType + Clone {
+    fn set_offset(&mut self, offset: u64);
+}
+
+/// The response of a paginated method.
+pub trait PaginatedResponse: CandidType + DeserializeOwned {
+    type Item;
+
+    fn next_offset(&self) -> Option<u64>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Iterates over the pages of a paginated method, fetching them one at a time.
+pub struct Pages<'c, I, R> {
+    client: &'c StationClient,
+    method: &'static str,
+    input: Option<I>,
+    _response: PhantomData<R>,
+}
+
+impl<'c, I: PaginatedInput, R: PaginatedResponse> Pages<'c, I, R> {
+    fn new(client: &'c StationClient, method: &'static str, input: I) -> Self {
+        Self {
+            client,
+            method,
+            input: Some(input),
+            _response: PhantomData,
+        }
+    }
+
+    /// Fetches the next page, returns `None` once all the pages were fetched.
+    ///
+    /// The iteration stops after an error, the pages are not fetched again.
+    pub async fn next_page(&mut self) -> Option<StationClientResult<R>> {
+        let mut input = self.input.take()?;
+
+        let result: StationClientResult<R> = self
+            .client
+            .call(CallKind::Query, self.method, input.clone())
+            .await;
+
+        if let Ok(page) = &result {
+            if let Some(next_offset) = page.next_offset() {
+                input.set_offset(next_offset);
+                self.input = Some(input);
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Fetches all the remaining pages and returns their items.
+    pub async fn collect_items(mut self) -> StationClientResult<Vec<R::Item>> {
+        let mut items = Vec::new();
+        while let Some(page) = self.next_page().await {
+            items.extend(page?.into_items());
+        }
+
+        Ok(items)
+    }
+}
+
+macro_rules! paginated {
+    ($($input:ty => $response:ty, $items:ident: $item:ty;)*) => {
+        $(
+            impl PaginatedInput for $input {
+                fn set_offset(&mut self, offset: u64) {
+                    let paginate = self.paginate.get_or_insert(PaginationInput {
+                        offset: None,
+                        limit: None,
+                    });
+                    paginate.offset = Some(offset);
+                }
+            }
+
+            impl PaginatedResponse for $response {
+                type Item = $item;
+
+                fn next_offset(&self) -> Option<u64> {
+                    self.next_offset
+                }
+
+                fn into_items(self) -> Vec<Self::Item> {
+                    self.$items
+                }
+            }
+        )*
+    };
+}
+
+paginated! {
+    ListSupportAccessLogInput => ListSupportAccessLogResponse, entries: SupportAccessLogEntryDTO;
+    ListExternalCanistersInput => ListExternalCanistersResponse, canisters: ExternalCanisterDTO;
+    ListAccountTransactionsInput => ListAccountTransactionsResponse, transactions: AccountTransactionDTO;
+    ListAccountsInput => ListAccountsResponse, accounts: AccountDTO;
+    ListRegisteredAssetsInput => ListRegisteredAssetsResponse, assets: RegisteredAssetDTO;
+    ListAddressBookEntriesInputDTO => ListAddressBookEntriesResponseDTO, address_book_entries: AddressBookEntryDTO;
+    ListRequestsInput => ListRequestsResponse, requests: RequestDTO;
+    ListUsersInput => ListUsersResponse, users: UserDTO;
+    ListPermissionsInput => ListPermissionsResponse, permissions: PermissionDTO;
+    ListUserGroupsInput => ListUserGroupsResponse, user_groups: UserGroupDTO;
+}
+
+impl PaginatedInput for PaginationInput {
+    fn set_offset(&mut self, offset: u64) {
+        self.offset = Some(offset);
+    }
+}
+
+impl PaginatedResponse for ListRequestPoliciesResponse {
+    type Item = RequestPolicyDTO;
+
+    fn next_offset(&self) -> Option<u64> {
+        self.next_offset
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.policies
+    }
+}
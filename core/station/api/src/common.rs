@@ -16,6 +16,18 @@ pub struct ApiErrorDTO {
     pub details: Option<HashMap<String, String>>,
 }
 
+/// A record whose sha256 hash is certified by the station, to verify the responses of query calls.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CertifiedRecordDTO {
+    /// The candid encoding of the record, whose sha256 hash is certified.
+    #[serde(with = "serde_bytes")]
+    pub record: Vec<u8>,
+    #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
+    pub certificate: Option<Vec<u8>>,
+    #[serde(with = "serde_bytes")]
+    pub witness: Vec<u8>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct PaginationInput {
     pub offset: Option<u64>,
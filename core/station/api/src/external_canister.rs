@@ -117,6 +117,8 @@ pub struct ChangeExternalCanisterOperationInput {
     pub module_extra_chunks: Option<WasmModuleExtraChunks>,
     #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
     pub arg: Option<Vec<u8>>,
+    /// The pending request for the same canister that is cancelled once this one is approved.
+    pub replaces: Option<UuidDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -125,6 +127,7 @@ pub struct ChangeExternalCanisterOperationDTO {
     pub mode: CanisterInstallMode,
     pub module_checksum: Sha256HashDTO,
     pub arg_checksum: Option<Sha256HashDTO>,
+    pub replaces: Option<UuidDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -143,6 +146,28 @@ pub struct ConfigureExternalCanisterSettingsInput {
     pub state: Option<ExternalCanisterStateDTO>,
     pub permissions: Option<ExternalCanisterPermissionsUpdateInput>,
     pub request_policies: Option<ExternalCanisterRequestPoliciesUpdateInput>,
+    pub monitoring: Option<ExternalCanisterMonitoringInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExternalCanisterMonitoringRulesDTO {
+    pub cycles_threshold: Option<u64>,
+    pub notify_on_stopped: bool,
+    pub notify_on_module_hash_change: bool,
+    pub auto_fund_cycles: Option<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum ExternalCanisterMonitoringInput {
+    Enable(ExternalCanisterMonitoringRulesDTO),
+    Disable,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExternalCanisterMonitoringDTO {
+    pub rules: ExternalCanisterMonitoringRulesDTO,
+    pub configured_by: UuidDTO,
+    pub last_checked_at: Option<TimestampRfc3339>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -281,6 +306,8 @@ pub struct ExternalCanisterDTO {
     pub request_policies: ExternalCanisterRequestPoliciesDTO,
     pub created_at: TimestampRfc3339,
     pub modified_at: Option<TimestampRfc3339>,
+    pub monitoring: Option<ExternalCanisterMonitoringDTO>,
+    pub module_hash: Option<Sha256HashDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -363,6 +390,7 @@ pub struct GetExternalCanisterFiltersResponse {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct FundExternalCanisterSendCyclesInput {
     pub cycles: u64,
+    pub from_account_id: Option<UuidDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
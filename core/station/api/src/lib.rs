@@ -4,6 +4,9 @@
 mod capabilities;
 pub use capabilities::*;
 
+mod asset;
+pub use asset::*;
+
 mod address_book;
 pub use address_book::*;
 
@@ -13,6 +16,9 @@ pub use common::*;
 mod system;
 pub use system::*;
 
+mod archive;
+pub use archive::*;
+
 mod metadata;
 pub use metadata::*;
 
@@ -48,3 +54,8 @@ pub use resource::*;
 
 mod disaster_recovery;
 pub use disaster_recovery::*;
+
+#[cfg(feature = "client")]
+mod client;
+#[cfg(feature = "client")]
+pub use client::*;
@@ -1,12 +1,16 @@
 use super::TimestampRfc3339;
 use crate::{EvaluationSummaryReasonDTO, RequestOperationTypeDTO, UuidDTO};
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use std::fmt::{Display, Formatter};
 
 pub const SYSTEM_MESSAGE_NOTIFICATION_TYPE: &str = "system-message";
 pub const REQUEST_CREATED_NOTIFICATION_TYPE: &str = "request-created";
 pub const REQUEST_FAILED_NOTIFICATION_TYPE: &str = "request-failed";
 pub const REQUEST_REJECTED_NOTIFICATION_TYPE: &str = "request-rejected";
+pub const REQUEST_CANCELLED_NOTIFICATION_TYPE: &str = "request-cancelled";
+pub const EXTERNAL_CANISTER_MONITORING_NOTIFICATION_TYPE: &str = "external-canister-monitoring";
+pub const REQUEST_COMMENTED_NOTIFICATION_TYPE: &str = "request-commented";
+pub const PENDING_VOTE_REMINDER_NOTIFICATION_TYPE: &str = "pending-vote-reminder";
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum NotificationStatusDTO {
@@ -20,6 +24,10 @@ pub enum NotificationTypeDTO {
     RequestCreated(RequestCreatedNotificationDTO),
     RequestFailed(RequestFailedNotificationDTO),
     RequestRejected(RequestRejectedNotificationDTO),
+    RequestCancelled(RequestCancelledNotificationDTO),
+    ExternalCanisterMonitoring(ExternalCanisterMonitoringNotificationDTO),
+    RequestCommented(RequestCommentedNotificationDTO),
+    PendingVoteReminder(PendingVoteReminderNotificationDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -44,10 +52,56 @@ pub struct RequestRejectedNotificationDTO {
     pub reasons: Option<Vec<EvaluationSummaryReasonDTO>>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCancelledNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCommentedNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub comment_id: UuidDTO,
+    pub author_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PendingVoteReminderNotificationDTO {
+    pub request_id: UuidDTO,
+    pub operation_type: RequestOperationTypeDTO,
+    pub expiration_dt: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExternalCanisterMonitoringNotificationDTO {
+    pub external_canister_id: UuidDTO,
+    pub canister_id: Principal,
+    pub event: ExternalCanisterMonitoringEventDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum ExternalCanisterMonitoringEventDTO {
+    LowCycles {
+        cycles: u64,
+        threshold: u64,
+        fund_request_id: Option<UuidDTO>,
+    },
+    Stopped,
+    ModuleHashChanged {
+        previous: Option<String>,
+        current: Option<String>,
+    },
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum NotificationTypeInput {
     SystemMessage,
     RequestCreated,
+    ExternalCanisterMonitoring,
+    RequestCommented,
+    PendingVoteReminder,
 }
 
 impl Display for NotificationTypeInput {
@@ -59,6 +113,15 @@ impl Display for NotificationTypeInput {
             NotificationTypeInput::RequestCreated => {
                 write!(f, "{}", REQUEST_CREATED_NOTIFICATION_TYPE)
             }
+            NotificationTypeInput::ExternalCanisterMonitoring => {
+                write!(f, "{}", EXTERNAL_CANISTER_MONITORING_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::RequestCommented => {
+                write!(f, "{}", REQUEST_COMMENTED_NOTIFICATION_TYPE)
+            }
+            NotificationTypeInput::PendingVoteReminder => {
+                write!(f, "{}", PENDING_VOTE_REMINDER_NOTIFICATION_TYPE)
+            }
         }
     }
 }
@@ -92,3 +155,57 @@ pub struct MarkNotificationsReadInput {
     pub notification_ids: Vec<UuidDTO>,
     pub read: bool,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UploadLocaleCatalogInput {
+    pub locale: String,
+    /// The message templates by message key.
+    pub messages: Vec<(String, String)>,
+    pub set_as_default: Option<bool>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UploadLocaleCatalogResponse {
+    pub missing_keys: Vec<String>,
+    pub unused_keys: Vec<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum NotificationTemplateEventDTO {
+    RequestCreated,
+    RequestRejected,
+    RequestFailed,
+    RequestCancelled,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct NotificationTemplateKeyDTO {
+    pub event: NotificationTemplateEventDTO,
+    /// The operation of the requests the template applies to, any operation if unset.
+    pub operation_type: Option<RequestOperationTypeDTO>,
+    /// The locale of the users the template applies to, any locale if unset.
+    pub locale: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct NotificationTemplateDTO {
+    pub key: NotificationTemplateKeyDTO,
+    pub title: String,
+    pub message: Option<String>,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum NotificationTemplateChangeDTO {
+    Set {
+        key: NotificationTemplateKeyDTO,
+        title: String,
+        message: Option<String>,
+    },
+    Remove(NotificationTemplateKeyDTO),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListNotificationTemplatesResponse {
+    pub templates: Vec<NotificationTemplateDTO>,
+}
@@ -1,5 +1,8 @@
-use crate::{BasicUserDTO, PaginationInput, ResourceDTO, UserGroupDTO, UuidDTO};
-use candid::{CandidType, Deserialize};
+use crate::{
+    BasicUserDTO, PaginationInput, RequestOperationInput, ResourceDTO, TimestampRfc3339,
+    UserGroupDTO, UuidDTO,
+};
+use candid::{CandidType, Deserialize, Principal};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct PermissionCallerPrivilegesDTO {
@@ -57,6 +60,8 @@ pub struct GetPermissionResponse {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditPermissionOperationDTO {
     pub input: EditPermissionOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditPermissionOperationInput>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -65,4 +70,87 @@ pub struct EditPermissionOperationInput {
     pub auth_scope: Option<AuthScopeDTO>,
     pub users: Option<Vec<UuidDTO>>,
     pub user_groups: Option<Vec<UuidDTO>>,
+    /// The time the changes take effect, the permission is updated right away when not set.
+    pub effective_from: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccessPolicyDocumentDTO {
+    pub user_groups: Vec<String>,
+    pub users: Vec<AccessPolicyDocumentUserDTO>,
+    pub permissions: Vec<AccessPolicyDocumentPermissionDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccessPolicyDocumentUserDTO {
+    pub name: String,
+    pub identities: Vec<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccessPolicyDocumentPermissionDTO {
+    pub resource: ResourceDTO,
+    pub auth_scope: AuthScopeDTO,
+    pub user_groups: Vec<String>,
+    pub users: Vec<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExportAccessPoliciesResponse {
+    pub document: AccessPolicyDocumentDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum AccessPolicyImportConflictDTO {
+    ExistingUserGroup {
+        name: String,
+    },
+    MissingUser {
+        name: String,
+    },
+    InvalidResource {
+        resource: ResourceDTO,
+        reason: String,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AccessPolicyImportReportDTO {
+    pub created_user_groups: Vec<String>,
+    pub imported_permissions: u64,
+    pub conflicts: Vec<AccessPolicyImportConflictDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ImportAccessPoliciesOperationInput {
+    pub document: AccessPolicyDocumentDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ImportAccessPoliciesOperationDTO {
+    pub input: ImportAccessPoliciesOperationInput,
+    pub report: Option<AccessPolicyImportReportDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PreviewAccessPoliciesImportInput {
+    pub document: AccessPolicyDocumentDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PreviewAccessPoliciesImportResponse {
+    pub report: AccessPolicyImportReportDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PolicySuggestionDTO {
+    pub reason: String,
+    pub operation: RequestOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SuggestPoliciesResponse {
+    pub analyzed_requests: u64,
+    pub analyzed_from: TimestampRfc3339,
+    pub suggestions: Vec<PolicySuggestionDTO>,
 }
@@ -1,22 +1,36 @@
 use super::{
-    EditAccountOperationInput, TimestampRfc3339, TransferOperationDTO, TransferOperationInput,
+    AddScheduledTransferOperationDTO, AddScheduledTransferOperationInput, ApproveOperationDTO,
+    ApproveOperationInput, EditAccountOperationInput, SplitTransferOperationDTO,
+    SplitTransferOperationInput, SwapTokensOperationDTO, SwapTokensOperationInput,
+    SweepAccountOperationDTO, SweepAccountOperationInput, TimestampRfc3339,
+    TransferNftOperationDTO, TransferNftOperationInput, TransferOperationDTO,
+    TransferOperationInput,
 };
 use crate::{
     AddAccountOperationDTO, AddAccountOperationInput, AddAddressBookEntryOperationDTO,
-    AddAddressBookEntryOperationInput, AddUserGroupOperationDTO, AddUserGroupOperationInput,
-    AddUserOperationDTO, AddUserOperationInput, CallExternalCanisterOperationDTO,
-    CallExternalCanisterOperationInput, ChangeExternalCanisterOperationDTO,
-    ChangeExternalCanisterOperationInput, ConfigureExternalCanisterOperationDTO,
-    ConfigureExternalCanisterOperationInput, CreateExternalCanisterOperationDTO,
-    CreateExternalCanisterOperationInput, DisplayUserDTO, EditAccountOperationDTO,
-    EditAddressBookEntryOperationDTO, EditAddressBookEntryOperationInput,
-    EditPermissionOperationDTO, EditPermissionOperationInput, EditUserGroupOperationDTO,
-    EditUserGroupOperationInput, EditUserOperationDTO, EditUserOperationInput,
-    FundExternalCanisterOperationDTO, FundExternalCanisterOperationInput,
-    ManageSystemInfoOperationDTO, ManageSystemInfoOperationInput, PaginationInput,
-    RemoveAddressBookEntryOperationDTO, RemoveAddressBookEntryOperationInput,
+    AddAddressBookEntryOperationInput, AddAssetOperationDTO, AddAssetOperationInput,
+    AddTeamOperationDTO, AddTeamOperationInput, AddUserGroupOperationDTO,
+    AddUserGroupOperationInput, AddUserOperationDTO, AddUserOperationInput,
+    CallExternalCanisterOperationDTO, CallExternalCanisterOperationInput, CertifiedRecordDTO,
+    ChangeExternalCanisterOperationDTO, ChangeExternalCanisterOperationInput,
+    ConfigureExternalCanisterOperationDTO, ConfigureExternalCanisterOperationInput,
+    CreateCapabilityGrantOperationDTO, CreateCapabilityGrantOperationInput,
+    CreateExternalCanisterOperationDTO, CreateExternalCanisterOperationInput,
+    DeriveSubaccountOperationDTO, DeriveSubaccountOperationInput, DisplayUserDTO,
+    EditAccountOperationDTO, EditAddressBookEntryOperationDTO, EditAddressBookEntryOperationInput,
+    EditAssetOperationDTO, EditAssetOperationInput, EditPermissionOperationDTO,
+    EditPermissionOperationInput, EditUserGroupOperationDTO, EditUserGroupOperationInput,
+    EditUserOperationDTO, EditUserOperationInput, FundExternalCanisterOperationDTO,
+    FundExternalCanisterOperationInput, ImportAccessPoliciesOperationDTO,
+    ImportAccessPoliciesOperationInput, ManageAddressBookLabelsOperationDTO,
+    ManageAddressBookLabelsOperationInput, ManageSystemInfoOperationDTO,
+    ManageSystemInfoOperationInput, PaginationInput, RemoveAddressBookEntryOperationDTO,
+    RemoveAddressBookEntryOperationInput, RemoveAssetOperationDTO, RemoveAssetOperationInput,
     RemoveUserGroupOperationDTO, RemoveUserGroupOperationInput, RequestEvaluationResultDTO,
-    RequestPolicyRuleDTO, RequestSpecifierDTO, SetDisasterRecoveryOperationDTO,
+    RequestPolicyExpirationInput, RequestPolicyRuleDTO, RequestSpecifierDTO,
+    RevokeCapabilityGrantOperationDTO, RevokeCapabilityGrantOperationInput,
+    SetAutoApprovalForTrustedDestinationsOperationDTO,
+    SetAutoApprovalForTrustedDestinationsOperationInput, SetDisasterRecoveryOperationDTO,
     SetDisasterRecoveryOperationInput, SortDirection, SystemUpgradeOperationDTO,
     SystemUpgradeOperationInput, UuidDTO,
 };
@@ -32,6 +46,7 @@ pub enum RequestStatusDTO {
     Processing { started_at: TimestampRfc3339 },
     Completed { completed_at: TimestampRfc3339 },
     Failed { reason: Option<String> },
+    Validating,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -44,6 +59,7 @@ pub enum RequestStatusCodeDTO {
     Processing = 5,
     Completed = 6,
     Failed = 7,
+    Validating = 8,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -83,6 +99,22 @@ pub enum RequestOperationDTO {
     EditRequestPolicy(Box<EditRequestPolicyOperationDTO>),
     RemoveRequestPolicy(Box<RemoveRequestPolicyOperationDTO>),
     ManageSystemInfo(Box<ManageSystemInfoOperationDTO>),
+    Approve(Box<ApproveOperationDTO>),
+    SetAutoApprovalForTrustedDestinations(Box<SetAutoApprovalForTrustedDestinationsOperationDTO>),
+    TransferNft(Box<TransferNftOperationDTO>),
+    DeriveSubaccount(Box<DeriveSubaccountOperationDTO>),
+    ImportAccessPolicies(Box<ImportAccessPoliciesOperationDTO>),
+    AddAsset(Box<AddAssetOperationDTO>),
+    EditAsset(Box<EditAssetOperationDTO>),
+    RemoveAsset(Box<RemoveAssetOperationDTO>),
+    SwapTokens(Box<SwapTokensOperationDTO>),
+    AddScheduledTransfer(Box<AddScheduledTransferOperationDTO>),
+    AddTeam(Box<AddTeamOperationDTO>),
+    SweepAccount(Box<SweepAccountOperationDTO>),
+    SplitTransfer(Box<SplitTransferOperationDTO>),
+    ManageAddressBookLabels(Box<ManageAddressBookLabelsOperationDTO>),
+    CreateCapabilityGrant(Box<CreateCapabilityGrantOperationDTO>),
+    RevokeCapabilityGrant(Box<RevokeCapabilityGrantOperationDTO>),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -110,6 +142,22 @@ pub enum RequestOperationInput {
     EditRequestPolicy(EditRequestPolicyOperationInput),
     RemoveRequestPolicy(RemoveRequestPolicyOperationInput),
     ManageSystemInfo(ManageSystemInfoOperationInput),
+    Approve(ApproveOperationInput),
+    SetAutoApprovalForTrustedDestinations(SetAutoApprovalForTrustedDestinationsOperationInput),
+    TransferNft(TransferNftOperationInput),
+    DeriveSubaccount(DeriveSubaccountOperationInput),
+    ImportAccessPolicies(ImportAccessPoliciesOperationInput),
+    AddAsset(AddAssetOperationInput),
+    EditAsset(EditAssetOperationInput),
+    RemoveAsset(RemoveAssetOperationInput),
+    SwapTokens(SwapTokensOperationInput),
+    AddScheduledTransfer(AddScheduledTransferOperationInput),
+    AddTeam(AddTeamOperationInput),
+    SweepAccount(SweepAccountOperationInput),
+    SplitTransfer(SplitTransferOperationInput),
+    ManageAddressBookLabels(ManageAddressBookLabelsOperationInput),
+    CreateCapabilityGrant(CreateCapabilityGrantOperationInput),
+    RevokeCapabilityGrant(RevokeCapabilityGrantOperationInput),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -137,6 +185,22 @@ pub enum RequestOperationTypeDTO {
     RemoveRequestPolicy,
     ManageSystemInfo,
     ConfigureExternalCanister,
+    Approve,
+    SetAutoApprovalForTrustedDestinations,
+    TransferNft,
+    DeriveSubaccount,
+    ImportAccessPolicies,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    SwapTokens,
+    AddScheduledTransfer,
+    AddTeam,
+    SweepAccount,
+    SplitTransfer,
+    ManageAddressBookLabels,
+    CreateCapabilityGrant,
+    RevokeCapabilityGrant,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -164,6 +228,22 @@ pub enum ListRequestsOperationTypeDTO {
     ManageSystemInfo,
     SetDisasterRecovery,
     ConfigureExternalCanister(Option<Principal>),
+    Approve(Option<UuidDTO>),
+    SetAutoApprovalForTrustedDestinations(Option<UuidDTO>),
+    TransferNft(Option<UuidDTO>),
+    DeriveSubaccount(Option<UuidDTO>),
+    ImportAccessPolicies,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    SwapTokens(Option<UuidDTO>),
+    AddScheduledTransfer(Option<UuidDTO>),
+    AddTeam,
+    SweepAccount(Option<UuidDTO>),
+    SplitTransfer(Option<UuidDTO>),
+    ManageAddressBookLabels,
+    CreateCapabilityGrant(Option<UuidDTO>),
+    RevokeCapabilityGrant,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -186,6 +266,17 @@ pub struct RequestDTO {
     pub status: RequestStatusDTO,
     pub expiration_dt: TimestampRfc3339,
     pub execution_plan: RequestExecutionScheduleDTO,
+    pub confidential: bool,
+    pub tags: Vec<String>,
+    pub depends_on: Option<UuidDTO>,
+    pub amendments: Vec<RequestAmendmentDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestAmendmentDTO {
+    pub previous_operation: RequestOperationDTO,
+    pub reason: Option<String>,
+    pub amended_at: TimestampRfc3339,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -200,6 +291,13 @@ pub struct RequestAdditionalInfoDTO {
     pub requester_name: String,
     pub approvers: Vec<DisplayUserDTO>,
     pub evaluation_result: Option<RequestEvaluationResultDTO>,
+    pub destination_warning: Option<DestinationWarningDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DestinationWarningDTO {
+    NeverUsed,
+    RecentlyUsed { first_used_at: TimestampRfc3339 },
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -208,6 +306,12 @@ pub struct CreateRequestInput {
     pub title: Option<String>,
     pub summary: Option<String>,
     pub execution_plan: Option<RequestExecutionScheduleDTO>,
+    /// Hides the request from the users that are not involved in it, defaults to `false`.
+    pub confidential: Option<bool>,
+    /// Free-form tags to organize the request, they are matched case-insensitively.
+    pub tags: Option<Vec<String>>,
+    /// The request that must complete before this request is executed.
+    pub depends_on: Option<UuidDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -224,6 +328,46 @@ pub struct SubmitRequestApprovalResponse {
     pub additional_info: RequestAdditionalInfoDTO,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EvaluateRequestPoliciesInput {
+    pub operation: RequestOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EvaluateRequestPoliciesResponse {
+    /// The evaluation of the policies as if the request was created by the caller.
+    pub evaluation_result: RequestEvaluationResultDTO,
+    /// The users that could approve the request.
+    pub possible_approvers: Vec<DisplayUserDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelRequestInput {
+    pub request_id: UuidDTO,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelRequestResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AmendRequestInput {
+    pub request_id: UuidDTO,
+    pub operation: RequestOperationInput,
+    pub reason: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AmendRequestResponse {
+    pub request: RequestDTO,
+    pub privileges: RequestCallerPrivilegesDTO,
+    pub additional_info: RequestAdditionalInfoDTO,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct GetRequestInput {
     pub request_id: UuidDTO,
@@ -235,6 +379,8 @@ pub struct GetRequestResponse {
     pub request: RequestDTO,
     pub privileges: RequestCallerPrivilegesDTO,
     pub additional_info: RequestAdditionalInfoDTO,
+    /// The certified `RequestDTO` of the request, as returned without full info.
+    pub certification: Option<CertifiedRecordDTO>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -258,6 +404,8 @@ pub struct ListRequestsInput {
     pub sort_by: Option<ListRequestsSortBy>,
     pub only_approvable: bool,
     pub with_evaluation_results: bool,
+    /// Only lists the requests that have any of the tags.
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -269,6 +417,106 @@ pub struct ListRequestsResponse {
     pub additional_info: Vec<RequestAdditionalInfoDTO>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestViewQueryDTO {
+    pub statuses: Option<Vec<RequestStatusCodeDTO>>,
+    pub operation_types: Option<Vec<ListRequestsOperationTypeDTO>>,
+    pub requester_ids: Option<Vec<UuidDTO>>,
+    pub approver_ids: Option<Vec<UuidDTO>>,
+    pub min_age_secs: Option<u64>,
+    pub max_age_secs: Option<u64>,
+    pub only_approvable: bool,
+    pub sort_by: Option<ListRequestsSortBy>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestViewDTO {
+    pub id: UuidDTO,
+    pub name: String,
+    pub owner_id: UuidDTO,
+    pub shared_with_group_id: Option<UuidDTO>,
+    pub query: RequestViewQueryDTO,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateRequestViewInput {
+    pub name: String,
+    pub shared_with_group_id: Option<UuidDTO>,
+    pub query: RequestViewQueryDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct EditRequestViewInput {
+    pub view_id: UuidDTO,
+    pub name: String,
+    pub shared_with_group_id: Option<UuidDTO>,
+    pub query: RequestViewQueryDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestViewResponse {
+    pub view: RequestViewDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveRequestViewInput {
+    pub view_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestViewsResponse {
+    pub views: Vec<RequestViewDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCommentDTO {
+    pub id: UuidDTO,
+    pub request_id: UuidDTO,
+    pub author: DisplayUserDTO,
+    pub body: String,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddRequestCommentInput {
+    pub request_id: UuidDTO,
+    pub body: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddRequestCommentResponse {
+    pub comment: RequestCommentDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestCommentsInput {
+    pub request_id: UuidDTO,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestCommentsResponse {
+    /// The comments of the request, the oldest first.
+    pub comments: Vec<RequestCommentDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListRequestsByViewInput {
+    pub view_id: UuidDTO,
+    pub paginate: Option<PaginationInput>,
+    pub with_evaluation_results: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SearchRequestsInput {
+    pub query: String,
+    pub paginate: Option<PaginationInput>,
+    pub with_evaluation_results: bool,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct GetNextApprovableRequestInput {
     pub excluded_request_ids: Vec<UuidDTO>,
@@ -288,6 +536,11 @@ pub struct CreateRequestResponse {
 pub struct AddRequestPolicyOperationInput {
     pub specifier: RequestSpecifierDTO,
     pub rule: RequestPolicyRuleDTO,
+    /// The time the requests matching the policy stay open for approvals, in seconds, the
+    /// default expiration period of the requests applies when not set.
+    pub expiration_period_secs: Option<u64>,
+    /// The time the policy takes effect, the policy is added right away when not set.
+    pub effective_from: Option<TimestampRfc3339>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -301,11 +554,16 @@ pub struct EditRequestPolicyOperationInput {
     pub policy_id: UuidDTO,
     pub specifier: Option<RequestSpecifierDTO>,
     pub rule: Option<RequestPolicyRuleDTO>,
+    pub expiration_period_secs: Option<RequestPolicyExpirationInput>,
+    /// The time the changes take effect, the policy is updated right away when not set.
+    pub effective_from: Option<TimestampRfc3339>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditRequestPolicyOperationDTO {
     pub input: EditRequestPolicyOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditRequestPolicyOperationInput>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -1,6 +1,6 @@
 use crate::{
     resource::ResourceDTO, CallExternalCanisterResourceTargetDTO, ExternalCanisterIdDTO,
-    MetadataDTO, PaginationInput, ResourceIdsDTO, UuidDTO,
+    MetadataDTO, PaginationInput, ResourceIdsDTO, TimestampRfc3339, UuidDTO,
 };
 use candid::{CandidType, Deserialize};
 
@@ -28,6 +28,9 @@ pub enum RequestSpecifierDTO {
     EditUserGroup(ResourceIdsDTO),
     RemoveUserGroup(ResourceIdsDTO),
     ManageSystemInfo,
+    AddAsset,
+    EditAsset(ResourceIdsDTO),
+    RemoveAsset(ResourceIdsDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -55,12 +58,32 @@ pub struct QuorumDTO {
     pub min_approved: u16,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct VelocityLimitDTO {
+    pub max_amount: candid::Nat,
+    pub window_secs: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AmountRangeDTO {
+    pub min_amount: Option<candid::Nat>,
+    pub max_amount: Option<candid::Nat>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum RequestPolicyRuleInput {
     Remove,
     Set(RequestPolicyRuleDTO),
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RequestPolicyExpirationInput {
+    /// The requests matching the policy expire after the default expiration period.
+    Remove,
+    /// The expiration period of the requests matching the policy, in seconds.
+    Set(u64),
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum EvaluationStatusDTO {
     Approved,
@@ -75,6 +98,13 @@ pub enum RequestPolicyRuleDTO {
     Quorum(QuorumDTO),
     AllowListedByMetadata(MetadataDTO),
     AllowListed,
+    AllowListedByLabel(String),
+    TrustedDestination,
+    RecentAuthentication(u32),
+    VelocityLimit(VelocityLimitDTO),
+    AmountRange(AmountRangeDTO),
+    DestinationAge(u32),
+    Veto(UserSpecifierDTO),
     AnyOf(Vec<RequestPolicyRuleDTO>),
     AllOf(Vec<RequestPolicyRuleDTO>),
     Not(Box<RequestPolicyRuleDTO>),
@@ -97,6 +127,35 @@ pub enum EvaluatedRequestPolicyRuleDTO {
         metadata: MetadataDTO,
     },
     AllowListed,
+    AllowListedByLabel {
+        label: String,
+        address_book_entry_id: Option<UuidDTO>,
+    },
+    TrustedDestination {
+        max_amount: Option<candid::Nat>,
+        period_usage: candid::Nat,
+    },
+    RecentAuthentication {
+        max_reauthentication_age_mins: u32,
+        stale_approvers: Vec<UuidDTO>,
+    },
+    VelocityLimit {
+        max_amount: candid::Nat,
+        window_secs: u64,
+        spent: candid::Nat,
+    },
+    AmountRange {
+        min_amount: Option<candid::Nat>,
+        max_amount: Option<candid::Nat>,
+        amount: Option<candid::Nat>,
+    },
+    DestinationAge {
+        min_age_days: u32,
+        first_used_at: Option<TimestampRfc3339>,
+    },
+    Veto {
+        vetoed_by: Vec<UuidDTO>,
+    },
     AnyOf(Vec<RequestPolicyRuleResultDTO>),
     AllOf(Vec<RequestPolicyRuleResultDTO>),
     Not(Box<RequestPolicyRuleResultDTO>),
@@ -114,6 +173,12 @@ pub enum EvaluationSummaryReasonDTO {
     AllowList,
     AllowListMetadata,
     AutoApproved,
+    TrustedDestination,
+    RecentAuthentication,
+    VelocityLimit,
+    AmountRange,
+    DestinationAge,
+    Veto,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -136,6 +201,8 @@ pub struct RequestPolicyDTO {
     pub id: UuidDTO,
     pub specifier: RequestSpecifierDTO,
     pub rule: RequestPolicyRuleDTO,
+    /// The time the requests matching the policy stay open for approvals, in seconds.
+    pub expiration_period_secs: Option<u64>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
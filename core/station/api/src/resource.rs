@@ -13,6 +13,7 @@ pub enum ResourceDTO {
     System(SystemResourceActionDTO),
     User(UserResourceActionDTO),
     UserGroup(ResourceActionDTO),
+    Asset(ResourceActionDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -48,6 +49,7 @@ pub enum UserResourceActionDTO {
     Create,
     Read(ResourceIdDTO),
     Update(ResourceIdDTO),
+    ViewAs(ResourceIdDTO),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -65,6 +67,7 @@ pub enum SystemResourceActionDTO {
     Capabilities,
     ManageSystemInfo,
     Upgrade,
+    AuditOutflows,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
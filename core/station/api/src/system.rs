@@ -1,5 +1,8 @@
 use super::TimestampRfc3339;
-use crate::{DisasterRecoveryCommitteeDTO, MetadataDTO, Sha256HashDTO, UuidDTO};
+use crate::{
+    DisasterRecoveryCommitteeDTO, MetadataDTO, NetworkProfileDTO, NotificationTemplateChangeDTO,
+    RequestOperationTypeDTO, RequestPolicyDTO, Sha256HashDTO, UuidDTO,
+};
 use candid::{CandidType, Deserialize, Principal};
 use orbit_essentials::types::WasmModuleExtraChunks;
 
@@ -13,6 +16,44 @@ pub struct SystemInfoDTO {
     pub raw_rand_successful: bool,
     pub disaster_recovery: Option<DisasterRecoveryDTO>,
     pub cycle_obtain_strategy: CycleObtainStrategyDTO,
+    pub request_operation_limits: RequestOperationLimitsDTO,
+    pub rpc_providers: Vec<RpcProvidersConfigDTO>,
+    pub finality_thresholds: Vec<FinalityThresholdDTO>,
+    pub transfer_retry_policy: TransferRetryPolicyDTO,
+    pub max_suggested_version: Option<String>,
+    pub paused_blockchains: Vec<PausedBlockchainDTO>,
+    pub archive_sink: Option<ArchiveSinkDTO>,
+    pub archive_head: Option<ArchiveHeadDTO>,
+    pub last_self_check: Option<SelfCheckReportDTO>,
+    pub default_locale: Option<String>,
+    pub stable_memory_guardrail: StableMemoryGuardrailDTO,
+    pub stable_memory_usage: StableMemoryUsageDTO,
+    pub approval_reminders: ApprovalRemindersDTO,
+    pub request_creation_rate_limits: RequestCreationRateLimitsDTO,
+    pub default_request_expiration_secs: u64,
+    pub cycle_thresholds: CycleThresholdsDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SelfCheckResultDTO {
+    pub name: String,
+    pub passed: bool,
+    pub details: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SelfCheckReportDTO {
+    pub version: String,
+    pub checked_at: TimestampRfc3339,
+    pub passed: bool,
+    pub checks: Vec<SelfCheckResultDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PausedBlockchainDTO {
+    pub blockchain: String,
+    pub paused_at: TimestampRfc3339,
+    pub reason: String,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -41,10 +82,128 @@ pub enum CycleObtainStrategyInput {
     MintFromNativeToken { account_id: UuidDTO },
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestOperationLimitsDTO {
+    pub max_wasm_module_size: u64,
+    pub max_arg_size: u64,
+    pub max_batch_length: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RpcProviderDTO {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RpcProvidersConfigDTO {
+    pub blockchain: String,
+    pub providers: Vec<RpcProviderDTO>,
+    pub read_quorum: u8,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ManageSystemInfoOperationInput {
     pub name: Option<String>,
     pub cycle_obtain_strategy: Option<CycleObtainStrategyInput>,
+    pub request_operation_limits: Option<RequestOperationLimitsDTO>,
+    pub rpc_providers: Option<Vec<RpcProvidersConfigDTO>>,
+    pub finality_thresholds: Option<Vec<FinalityThresholdDTO>>,
+    pub transfer_retry_policy: Option<TransferRetryPolicyDTO>,
+    pub max_suggested_version: Option<VersionPinInput>,
+    pub archive_sink: Option<ArchiveSinkInput>,
+    pub stable_memory_guardrail: Option<StableMemoryGuardrailDTO>,
+    pub approval_reminders: Option<ApprovalRemindersDTO>,
+    pub notification_templates: Option<Vec<NotificationTemplateChangeDTO>>,
+    pub request_creation_rate_limits: Option<RequestCreationRateLimitsDTO>,
+    pub default_request_expiration_secs: Option<u64>,
+    pub cycle_thresholds: Option<CycleThresholdsDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CycleThresholdsDTO {
+    pub min_runtime_secs: u64,
+    pub fund_runtime_secs: u64,
+    pub max_runtime_cycles_fund: u64,
+    pub fallback_min_cycles: u64,
+    pub fallback_fund_cycles: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ArchiveSinkDTO {
+    pub canister_id: Option<Principal>,
+    pub retention_days: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum ArchiveSinkInput {
+    Set(ArchiveSinkDTO),
+    Remove,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ArchiveHeadDTO {
+    pub sequence: u64,
+    pub hash: Sha256HashDTO,
+    pub archived_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct FinalityThresholdDTO {
+    pub blockchain: String,
+    pub required_confirmations: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferRetryPolicyDTO {
+    pub max_attempts: u32,
+    pub initial_backoff_secs: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StableMemoryGuardrailDTO {
+    pub high_water_mark_percent: u8,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApprovalRemindersDTO {
+    pub control_panel_id: Option<Principal>,
+    pub pending_vote_lead_time_secs: Option<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum RateLimitPeriodDTO {
+    Hour,
+    Day,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCreationRateLimitDTO {
+    /// The operation of the counted requests, all the requests are counted if unset.
+    pub operation_type: Option<RequestOperationTypeDTO>,
+    pub period: RateLimitPeriodDTO,
+    pub max_requests: u32,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RequestCreationRateLimitsDTO {
+    pub limits: Vec<RequestCreationRateLimitDTO>,
+    /// The members of this group are not rate limited.
+    pub override_group_id: Option<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StableMemoryUsageDTO {
+    pub used_bytes: u64,
+    pub capacity_bytes: u64,
+    pub high_water_mark_bytes: u64,
+    pub new_requests_refused: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum VersionPinInput {
+    Pin(String),
+    Unpin,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
@@ -70,6 +229,7 @@ pub struct InitAccountInput {
     pub name: String,
     pub blockchain: String,
     pub standard: String,
+    pub network: Option<NetworkProfileDTO>,
     pub metadata: Vec<MetadataDTO>,
 }
 
@@ -133,3 +293,68 @@ pub struct SystemUpgradeOperationDTO {
 pub struct NotifyFailedStationUpgradeInput {
     pub reason: String,
 }
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ResumeBlockchainInput {
+    pub blockchain: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PendingRequestExecutionDTO {
+    pub request_id: UuidDTO,
+    pub title: String,
+    pub operation: String,
+    pub scheduled_at: TimestampRfc3339,
+    pub queue_position: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PendingTransferExecutionDTO {
+    pub transfer_id: UuidDTO,
+    pub request_id: UuidDTO,
+    pub from_account_id: UuidDTO,
+    pub to: String,
+    pub amount: candid::Nat,
+    pub scheduled_at: TimestampRfc3339,
+    pub paused: bool,
+    pub queue_position: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListPendingExecutionsResponse {
+    pub requests: Vec<PendingRequestExecutionDTO>,
+    pub transfers: Vec<PendingTransferExecutionDTO>,
+    pub next_request_execution_at: Option<TimestampRfc3339>,
+    pub next_transfer_execution_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AttestedAdminDTO {
+    pub id: UuidDTO,
+    pub name: String,
+    pub identities: Vec<Principal>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct StationAttestationDTO {
+    pub station_id: Principal,
+    /// The hash of the wasm module installed on the station, if the management canister reported it.
+    pub wasm_module_hash: Option<Sha256HashDTO>,
+    pub version: String,
+    pub upgrader_id: Principal,
+    pub admins: Vec<AttestedAdminDTO>,
+    pub disaster_recovery_committee: Option<DisasterRecoveryCommitteeDTO>,
+    pub request_policies: Vec<RequestPolicyDTO>,
+    pub attested_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetStationAttestationResponse {
+    /// The candid encoding of the `StationAttestationDTO`, whose sha256 hash is certified.
+    #[serde(with = "serde_bytes")]
+    pub attestation: Vec<u8>,
+    #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
+    pub certificate: Option<Vec<u8>>,
+    #[serde(with = "serde_bytes")]
+    pub witness: Vec<u8>,
+}
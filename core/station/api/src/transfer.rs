@@ -1,6 +1,8 @@
-use super::{AccountDTO, TimestampRfc3339};
-use crate::{MetadataDTO, UuidDTO};
-use candid::{CandidType, Deserialize};
+use super::{AccountDTO, FiatValueDTO, TimestampRfc3339};
+use crate::{
+    CertifiedRecordDTO, MetadataDTO, PaginationInput, RequestExecutionScheduleDTO, UuidDTO,
+};
+use candid::{CandidType, Deserialize, Principal};
 
 pub type NetworkIdDTO = String;
 
@@ -18,6 +20,21 @@ pub struct TransferOperationInput {
     pub fee: Option<candid::Nat>,
     pub metadata: Vec<MetadataDTO>,
     pub network: Option<NetworkDTO>,
+    /// The ICRC-1 textual account to pull the funds from with `icrc2_transfer_from`, using
+    /// the allowance it approved for the station account.
+    pub spend_from: Option<String>,
+    /// The memo recorded on the ledger, numeric memos are the only ones supported by the ICP ledger.
+    pub memo: Option<TransferMemoDTO>,
+    /// The registered transfer category the transfer is booked under (e.g. `payroll`).
+    pub category: Option<String>,
+    /// The registered asset held by the account that is sent, the own asset of the account if not set.
+    pub asset_id: Option<UuidDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum TransferMemoDTO {
+    Number(u64),
+    Blob(#[serde(with = "serde_bytes")] Vec<u8>),
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -29,12 +46,145 @@ pub struct TransferOperationDTO {
     pub fee: Option<candid::Nat>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApproveOperationInput {
+    pub from_account_id: UuidDTO,
+    /// The ICRC-1 textual account of the spender.
+    pub spender: String,
+    pub amount: candid::Nat,
+    pub expected_allowance: Option<candid::Nat>,
+    pub expires_at: Option<TimestampRfc3339>,
+    pub fee: Option<candid::Nat>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ApproveOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    pub input: ApproveOperationInput,
+    pub block_index: Option<u64>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DexDTO {
+    ICPSwap,
+    Sonic,
+    KongSwap,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SwapTokensOperationInput {
+    /// The account that sells its asset.
+    pub from_account_id: UuidDTO,
+    /// The account that receives the bought asset.
+    pub to_account_id: UuidDTO,
+    pub dex: DexDTO,
+    /// The pool canister of the pair, required by the DEXes that have a canister per pool (e.g. ICPSwap).
+    pub pool_canister_id: Option<Principal>,
+    pub amount_in: candid::Nat,
+    /// The amount of the bought asset that the approvers agreed to, usually the quote of the DEX when
+    /// the request was created.
+    pub expected_amount_out: candid::Nat,
+    /// The maximum slippage from the expected amount, in basis points.
+    pub max_slippage_bps: u16,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SwapTokensResultDTO {
+    pub amount_in: candid::Nat,
+    pub amount_out: candid::Nat,
+    pub executed_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SwapTokensOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    pub to_account: Option<AccountDTO>,
+    pub input: SwapTokensOperationInput,
+    /// The minimum amount of the bought asset, below which the swap is not executed.
+    pub min_amount_out: candid::Nat,
+    /// The trade of the swap, only available after the operation is executed.
+    pub result: Option<SwapTokensResultDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SweepAccountOperationInput {
+    pub from_account_id: UuidDTO,
+    pub to: String,
+    pub network: Option<NetworkDTO>,
+    pub metadata: Vec<MetadataDTO>,
+    pub memo: Option<TransferMemoDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SweepAccountOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    pub network: NetworkDTO,
+    pub input: SweepAccountOperationInput,
+    pub transfer_id: Option<UuidDTO>,
+    /// The balance minus the fee at the time the request was executed.
+    pub amount: Option<candid::Nat>,
+    pub fee: Option<candid::Nat>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum SplitTransferShareDTO {
+    BasisPoints(u16),
+    Amount(candid::Nat),
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SplitTransferDestinationDTO {
+    pub to: String,
+    pub share: SplitTransferShareDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SplitTransferOperationInput {
+    pub from_account_id: UuidDTO,
+    pub total_amount: candid::Nat,
+    pub destinations: Vec<SplitTransferDestinationDTO>,
+    pub network: Option<NetworkDTO>,
+    pub metadata: Vec<MetadataDTO>,
+    pub memo: Option<TransferMemoDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SplitTransferOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    pub network: NetworkDTO,
+    pub input: SplitTransferOperationInput,
+    /// The transfers of the destinations in their order.
+    pub transfer_ids: Vec<UuidDTO>,
+    pub fee: Option<candid::Nat>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferNftOperationInput {
+    pub from_account_id: UuidDTO,
+    pub token_id: candid::Nat,
+    /// The ICRC-1 textual account of the new owner of the token.
+    pub to: String,
+    pub memo: Option<Vec<u8>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferNftOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    pub input: TransferNftOperationInput,
+    pub block_index: Option<u64>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub enum TransferStatusDTO {
     Created,
     Processing {
         started_at: TimestampRfc3339,
     },
+    Confirming {
+        submitted_at: TimestampRfc3339,
+        confirmations: u32,
+        required_confirmations: u32,
+    },
     Completed {
         signature: Option<String>,
         hash: Option<String>,
@@ -49,6 +199,7 @@ pub enum TransferStatusDTO {
 pub enum TransferStatusTypeDTO {
     Created,
     Processing,
+    Confirming,
     Completed,
     Failed,
 }
@@ -64,6 +215,18 @@ pub struct TransferDTO {
     pub status: TransferStatusDTO,
     pub network: NetworkDTO,
     pub metadata: Vec<MetadataDTO>,
+    pub memo: Option<TransferMemoDTO>,
+    pub category: Option<String>,
+    pub asset_id: Option<UuidDTO>,
+    pub fiat_values: Vec<FiatValueDTO>,
+    pub failed_attempts: Vec<TransferAttemptDTO>,
+    pub next_attempt_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferAttemptDTO {
+    pub attempted_at: TimestampRfc3339,
+    pub error: String,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -81,12 +244,94 @@ pub struct GetTransfersResponse {
     pub transfers: Vec<TransferDTO>,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferInput {
+    pub transfer_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferResponse {
+    pub transfer: TransferDTO,
+    /// The certified `TransferDTO` of the transfer, without its fiat values.
+    pub certification: Option<CertifiedRecordDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferReceiptApprovalDTO {
+    pub approver_id: UuidDTO,
+    pub approved_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferReceiptDTO {
+    pub transfer_id: UuidDTO,
+    pub request_id: UuidDTO,
+    pub from_account_id: UuidDTO,
+    pub blockchain: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub to_address: String,
+    pub amount: candid::Nat,
+    pub fee: candid::Nat,
+    pub block_index: Option<u64>,
+    pub transaction_hash: Option<String>,
+    pub approvals: Vec<TransferReceiptApprovalDTO>,
+    pub completed_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferReceiptInput {
+    pub transfer_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferReceiptResponse {
+    /// The candid encoding of the `TransferReceiptDTO`, whose sha256 hash is certified.
+    #[serde(with = "serde_bytes")]
+    pub receipt: Vec<u8>,
+    #[serde(deserialize_with = "orbit_essentials::deserialize::deserialize_option_blob")]
+    pub certificate: Option<Vec<u8>>,
+    #[serde(with = "serde_bytes")]
+    pub witness: Vec<u8>,
+}
+
+/// The event sent to the webhooks of an account when one of its transfers completes or fails.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferWebhookEventDTO {
+    pub station_id: Principal,
+    pub transfer: TransferDTO,
+}
+
+/// The argument of the calls made to the webhooks of an account.
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferWebhookCallbackInput {
+    /// The candid encoding of the `TransferWebhookEventDTO`.
+    #[serde(with = "serde_bytes")]
+    pub event: Vec<u8>,
+    /// The hex encoded HMAC-SHA256 of the event, keyed by the secret of the webhook if it has one.
+    pub signature: Option<String>,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ListAccountTransfersInput {
     pub status: Option<TransferStatusTypeDTO>,
     pub to_dt: Option<TimestampRfc3339>,
     pub from_dt: Option<TimestampRfc3339>,
     pub account_id: UuidDTO,
+    /// Only the transfers with this value of a well-known metadata key (e.g. `invoice_id`).
+    pub metadata: Option<MetadataDTO>,
+    /// Only the transfers booked under this transfer category.
+    pub category: Option<String>,
+    /// Only the transfers sent to this destination address.
+    pub to_address: Option<String>,
+    /// Only the transfers of at least this amount.
+    pub min_amount: Option<candid::Nat>,
+    /// Only the transfers of at most this amount.
+    pub max_amount: Option<candid::Nat>,
+    /// The `next_cursor` of the previous page, the transfers are listed from the newest.
+    pub cursor: Option<String>,
+    /// The maximum number of transfers in the page.
+    pub limit: Option<u16>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -102,4 +347,282 @@ pub struct TransferListItemDTO {
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct ListAccountTransfersResponse {
     pub transfers: Vec<TransferListItemDTO>,
+    /// The cursor of the next page, if there are more transfers.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferExportFormatDTO {
+    /// Comma-separated values, the first chunk starts with the header line.
+    Csv,
+    /// One JSON object per line.
+    Json,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExportAccountTransfersInput {
+    pub account_id: UuidDTO,
+    pub from_dt: Option<TimestampRfc3339>,
+    pub to_dt: Option<TimestampRfc3339>,
+    pub format: TransferExportFormatDTO,
+    /// The `next_cursor` of the previous chunk, the transfers are exported from the newest.
+    pub cursor: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ExportAccountTransfersResponse {
+    /// The exported lines of the chunk, to be appended to the previous chunks.
+    pub content: String,
+    /// The cursor of the next chunk, if there are more transfers.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum PendingOutflowOutcomeDTO {
+    OnChain { details: Vec<MetadataDTO> },
+    NotOnChain,
+    Unknown { reason: String },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PendingOutflowAuditDTO {
+    pub transfer: TransferDTO,
+    pub outcome: PendingOutflowOutcomeDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AuditPendingOutflowsInput {
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AuditPendingOutflowsResponse {
+    pub outflows: Vec<PendingOutflowAuditDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferFeesInput {
+    pub account_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum TransferFeeTierDTO {
+    Slow,
+    Standard,
+    Fast,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferFeeDTO {
+    pub tier: TransferFeeTierDTO,
+    /// The fee to set on the transfer, in the smallest unit of the asset.
+    pub fee: candid::Nat,
+    pub metadata: Vec<MetadataDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetTransferFeesResponse {
+    pub fees: Vec<TransferFeeDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum SpendingPeriodDTO {
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum SpendingGroupByDTO {
+    Account,
+    Destination,
+    Tag,
+    Asset,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetSpendingSummaryInput {
+    pub period: SpendingPeriodDTO,
+    pub group_by: SpendingGroupByDTO,
+    pub from_dt: Option<TimestampRfc3339>,
+    pub to_dt: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SpendingSummaryEntryDTO {
+    pub period_start: TimestampRfc3339,
+    /// The group of the transfers (e.g. the account id, the destination address or the tag).
+    pub group: String,
+    /// The symbol of the asset the total is denominated in.
+    pub symbol: String,
+    pub total: candid::Nat,
+    pub count: u64,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetSpendingSummaryResponse {
+    pub entries: Vec<SpendingSummaryEntryDTO>,
+    /// The time until which the completed transfers are included in the summary.
+    pub aggregated_until: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddScheduledTransferOperationInput {
+    pub transfer: TransferOperationInput,
+    /// The time between two executions, at least one hour.
+    pub interval_secs: u64,
+    /// The time of the first execution, immediately after the approval if not set.
+    pub start_at: Option<TimestampRfc3339>,
+    /// No execution happens after this time, the standing order runs until cancelled if not set.
+    pub end_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct AddScheduledTransferOperationDTO {
+    pub from_account: Option<AccountDTO>,
+    /// The standing order, only available after the operation is executed.
+    pub scheduled_transfer_id: Option<UuidDTO>,
+    pub input: AddScheduledTransferOperationInput,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub enum ScheduledTransferStatusDTO {
+    Active,
+    Completed {
+        completed_at: TimestampRfc3339,
+    },
+    Cancelled {
+        cancelled_at: TimestampRfc3339,
+        cancelled_by: UuidDTO,
+    },
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ScheduledTransferDTO {
+    pub id: UuidDTO,
+    pub request_id: UuidDTO,
+    pub transfer: TransferOperationInput,
+    pub interval_secs: u64,
+    pub next_execution_at: TimestampRfc3339,
+    pub end_at: Option<TimestampRfc3339>,
+    pub executions: u64,
+    pub last_transfer_id: Option<UuidDTO>,
+    /// The reason the last execution could not create its transfer.
+    pub last_error: Option<String>,
+    pub status: ScheduledTransferStatusDTO,
+    pub created_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListScheduledTransfersInput {
+    pub account_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListScheduledTransfersResponse {
+    pub scheduled_transfers: Vec<ScheduledTransferDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelScheduledTransferInput {
+    pub scheduled_transfer_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CancelScheduledTransferResponse {
+    pub scheduled_transfer: ScheduledTransferDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct TransferCategoryDTO {
+    pub name: String,
+    pub description: Option<String>,
+    pub last_modification_timestamp: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListTransferCategoriesResponse {
+    pub categories: Vec<TransferCategoryDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetTransferCategoryInput {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetTransferCategoryResponse {
+    pub category: TransferCategoryDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveTransferCategoryInput {
+    pub name: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct RemoveTransferCategoryResponse {
+    pub category: TransferCategoryDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreatePayoutRunInput {
+    /// The name of the run (e.g. "Payroll 2024-03"), the transfer requests are titled after it.
+    pub name: String,
+    pub summary: Option<String>,
+    pub transfers: Vec<TransferOperationInput>,
+    pub execution_plan: Option<RequestExecutionScheduleDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreatePayoutRunResponse {
+    pub payout_run: PayoutRunDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetPayoutRunInput {
+    pub payout_run_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct GetPayoutRunResponse {
+    pub payout_run: PayoutRunDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum PayoutRunStatusDTO {
+    Pending,
+    Completed,
+    PartiallyCompleted,
+    Failed,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PayoutRunAssetTotalDTO {
+    pub blockchain: String,
+    pub symbol: String,
+    pub decimals: u32,
+    /// The amount of all the transfers of the run.
+    pub requested: candid::Nat,
+    /// The amount of the completed transfers.
+    pub completed: candid::Nat,
+    /// The amount of the transfers that can still complete.
+    pub pending: candid::Nat,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct PayoutRunDTO {
+    pub id: UuidDTO,
+    pub name: String,
+    pub created_by: UuidDTO,
+    pub created_at: TimestampRfc3339,
+    pub request_ids: Vec<UuidDTO>,
+    pub status: PayoutRunStatusDTO,
+    pub pending_count: u64,
+    pub completed_count: u64,
+    pub failed_count: u64,
+    pub totals: Vec<PayoutRunAssetTotalDTO>,
 }
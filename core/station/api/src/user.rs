@@ -1,5 +1,8 @@
 use super::TimestampRfc3339;
-use crate::{PaginationInput, UserGroupDTO, UuidDTO};
+use crate::{
+    AccountCallerPrivilegesDTO, AccountDTO, NotificationTypeInput, PaginationInput, UserGroupDTO,
+    UuidDTO,
+};
 use candid::{CandidType, Deserialize, Principal};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -22,6 +25,9 @@ pub struct UserDTO {
     pub status: UserStatusDTO,
     pub name: String,
     pub last_modification_timestamp: TimestampRfc3339,
+    pub locale: Option<String>,
+    pub approve_only_identities: Vec<Principal>,
+    pub muted_notification_types: Vec<NotificationTypeInput>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -54,6 +60,7 @@ pub struct AddUserOperationInput {
     pub identities: Vec<Principal>,
     pub groups: Vec<String>,
     pub status: UserStatusDTO,
+    pub approve_only_identities: Option<Vec<Principal>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -70,11 +77,14 @@ pub struct EditUserOperationInput {
     pub groups: Option<Vec<String>>,
     pub status: Option<UserStatusDTO>,
     pub cancel_pending_requests: Option<bool>,
+    pub approve_only_identities: Option<Vec<Principal>>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
 pub struct EditUserOperationDTO {
     pub input: EditUserOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditUserOperationInput>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -120,4 +130,90 @@ pub enum UserPrivilege {
 pub struct MeResponse {
     pub me: UserDTO,
     pub privileges: Vec<UserPrivilege>,
+    pub onboarding: UserOnboardingDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum OnboardingStepDTO {
+    IdentityConfirmed,
+    JoinedGroups,
+    FirstVoteCast,
+    NotificationPreferencesSet,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct OnboardingStepStatusDTO {
+    pub step: OnboardingStepDTO,
+    pub completed_at: Option<TimestampRfc3339>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct UserOnboardingDTO {
+    pub steps: Vec<OnboardingStepStatusDTO>,
+    pub next_step: Option<OnboardingStepDTO>,
+    pub is_completed: bool,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ReportOnboardingStepInput {
+    pub step: OnboardingStepDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ReportOnboardingStepResponse {
+    pub onboarding: UserOnboardingDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetLocaleInput {
+    pub locale: Option<String>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ReauthenticateResponse {
+    pub authenticated_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SetNotificationPreferencesInput {
+    pub muted_notification_types: Vec<NotificationTypeInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct CreateCalendarFeedResponse {
+    pub path: String,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ViewAsInput {
+    pub user_id: UuidDTO,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ViewAsResponse {
+    pub user: UserDTO,
+    pub privileges: Vec<UserPrivilege>,
+    pub accounts: Vec<AccountDTO>,
+    pub account_privileges: Vec<AccountCallerPrivilegesDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct SupportAccessLogEntryDTO {
+    pub id: UuidDTO,
+    pub viewer_id: UuidDTO,
+    pub viewed_user_id: UuidDTO,
+    pub viewed_at: TimestampRfc3339,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListSupportAccessLogInput {
+    pub viewed_user_id: Option<UuidDTO>,
+    pub paginate: Option<PaginationInput>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
+pub struct ListSupportAccessLogResponse {
+    pub entries: Vec<SupportAccessLogEntryDTO>,
+    pub next_offset: Option<u64>,
+    pub total: u64,
 }
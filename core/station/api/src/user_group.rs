@@ -1,4 +1,4 @@
-use crate::{PaginationInput, UuidDTO};
+use crate::{PaginationInput, ResourceDTO, UuidDTO};
 use candid::{CandidType, Deserialize};
 
 #[derive(CandidType, serde::Serialize, Deserialize, Debug, Clone)]
@@ -25,15 +25,51 @@ pub struct AddUserGroupOperationDTO {
     pub input: AddUserGroupOperationInput,
 }
 
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct AddTeamOperationInput {
+    /// The name of the user group of the team.
+    pub name: String,
+    /// The users added to the group.
+    pub member_ids: Vec<UuidDTO>,
+    /// The resources the group is allowed to access.
+    pub resources: Vec<ResourceDTO>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct AddTeamOperationDTO {
+    pub user_group: Option<UserGroupDTO>,
+    pub input: AddTeamOperationInput,
+}
+
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct EditUserGroupOperationInput {
     pub user_group_id: UuidDTO,
     pub name: String,
+    /// The users added to the group.
+    pub add_members: Option<Vec<UuidDTO>>,
+    /// The users removed from the group.
+    pub remove_members: Option<Vec<UuidDTO>>,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub enum UserGroupMembershipActionDTO {
+    Join,
+    Leave,
+}
+
+#[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
+pub struct RequestUserGroupMembershipInput {
+    pub user_group_id: UuidDTO,
+    pub action: UserGroupMembershipActionDTO,
+    /// Why the caller wants to join or leave the group, shown to the approvers.
+    pub summary: Option<String>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
 pub struct EditUserGroupOperationDTO {
     pub input: EditUserGroupOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    pub previous: Option<EditUserGroupOperationInput>,
 }
 
 #[derive(CandidType, serde::Serialize, Deserialize, Clone, Debug)]
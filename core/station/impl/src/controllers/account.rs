@@ -1,19 +1,30 @@
 use crate::core::middlewares::use_canister_call_metric;
 use crate::mappers::authorization::FetchAccountBalancesInputRef;
 use crate::mappers::HelperMapper;
-use crate::models::resource::{AccountResourceAction, Resource};
+use crate::models::resource::{AccountResourceAction, Resource, ResourceId};
+use crate::models::CapabilityScope;
 use crate::{
-    core::middlewares::{authorize, call_context},
-    services::AccountService,
+    core::middlewares::{authorize, authorize_with_capability, call_context},
+    services::{
+        AccountService, CapabilityGrantService, FundingRequestService, ProofOfReservesService,
+        TransactionHistoryService, CAPABILITY_GRANT_SERVICE, FUNDING_REQUEST_SERVICE,
+        PROOF_OF_RESERVES_SERVICE, TRANSACTION_HISTORY_SERVICE,
+    },
 };
 use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    AccountCallerPrivilegesDTO, FetchAccountBalancesInput, FetchAccountBalancesResponse,
-    GetAccountInput, GetAccountResponse, ListAccountsInput, ListAccountsResponse,
+    AccountCallerPrivilegesDTO, CreateFundingRequestInput, CreateFundingRequestResponse,
+    DiscoverAccountsInput, DiscoverAccountsResponse, FetchAccountBalancesInput,
+    FetchAccountBalancesResponse, GetAccountInput, GetAccountResponse, GetProofOfReservesResponse,
+    ImportAccountTransactionsInput, ImportAccountTransactionsResponse,
+    ListAccountTransactionsInput, ListAccountTransactionsResponse, ListAccountsInput,
+    ListAccountsResponse, ListCapabilityGrantsInput, ListCapabilityGrantsResponse,
+    ListFundingRequestsInput, ListFundingRequestsResponse, ListNftsInput, ListNftsResponse,
 };
+use std::sync::Arc;
 
 // Canister entrypoints for the controller.
 #[query(name = "get_account")]
@@ -33,19 +44,91 @@ async fn fetch_account_balances(
     CONTROLLER.fetch_account_balances(input).await
 }
 
+#[update(name = "list_nfts")]
+async fn list_nfts(input: ListNftsInput) -> ApiResult<ListNftsResponse> {
+    CONTROLLER.list_nfts(input).await
+}
+
+#[query(name = "get_proof_of_reserves")]
+async fn get_proof_of_reserves() -> ApiResult<GetProofOfReservesResponse> {
+    CONTROLLER.get_proof_of_reserves().await
+}
+
+#[update(name = "discover_accounts")]
+async fn discover_accounts(input: DiscoverAccountsInput) -> ApiResult<DiscoverAccountsResponse> {
+    CONTROLLER.discover_accounts(input).await
+}
+
+#[update(name = "create_funding_request")]
+async fn create_funding_request(
+    input: CreateFundingRequestInput,
+) -> ApiResult<CreateFundingRequestResponse> {
+    CONTROLLER.create_funding_request(input).await
+}
+
+#[query(name = "list_funding_requests")]
+async fn list_funding_requests(
+    input: ListFundingRequestsInput,
+) -> ApiResult<ListFundingRequestsResponse> {
+    CONTROLLER.list_funding_requests(input).await
+}
+
+#[update(name = "import_account_transactions")]
+async fn import_account_transactions(
+    input: ImportAccountTransactionsInput,
+) -> ApiResult<ImportAccountTransactionsResponse> {
+    CONTROLLER.import_account_transactions(input).await
+}
+
+#[query(name = "list_account_transactions")]
+async fn list_account_transactions(
+    input: ListAccountTransactionsInput,
+) -> ApiResult<ListAccountTransactionsResponse> {
+    CONTROLLER.list_account_transactions(input).await
+}
+
+#[query(name = "list_capability_grants")]
+async fn list_capability_grants(
+    input: ListCapabilityGrantsInput,
+) -> ApiResult<ListCapabilityGrantsResponse> {
+    CONTROLLER.list_capability_grants(input).await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: AccountController = AccountController::new(AccountService::default());
+    static ref CONTROLLER: AccountController = AccountController::new(
+        AccountService::default(),
+        Arc::clone(&FUNDING_REQUEST_SERVICE),
+        Arc::clone(&TRANSACTION_HISTORY_SERVICE),
+        Arc::clone(&PROOF_OF_RESERVES_SERVICE),
+        Arc::clone(&CAPABILITY_GRANT_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct AccountController {
     account_service: AccountService,
+    funding_request_service: Arc<FundingRequestService>,
+    transaction_history_service: Arc<TransactionHistoryService>,
+    proof_of_reserves_service: Arc<ProofOfReservesService>,
+    capability_grant_service: Arc<CapabilityGrantService>,
 }
 
 impl AccountController {
-    pub fn new(account_service: AccountService) -> Self {
-        Self { account_service }
+    pub fn new(
+        account_service: AccountService,
+        funding_request_service: Arc<FundingRequestService>,
+        transaction_history_service: Arc<TransactionHistoryService>,
+        proof_of_reserves_service: Arc<ProofOfReservesService>,
+        capability_grant_service: Arc<CapabilityGrantService>,
+    ) -> Self {
+        Self {
+            account_service,
+            funding_request_service,
+            transaction_history_service,
+            proof_of_reserves_service,
+            capability_grant_service,
+        }
     }
 
     #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
@@ -93,7 +176,7 @@ impl AccountController {
         })
     }
 
-    #[with_middleware(guard = authorize(&call_context(), &FetchAccountBalancesInputRef(&input).to_resources()))]
+    #[with_middleware(guard = authorize_with_capability(&call_context(), CapabilityScope::Balances, &FetchAccountBalancesInputRef(&input).to_resources()))]
     #[with_middleware(tail = use_canister_call_metric("fetch_account_balances", &result))]
     async fn fetch_account_balances(
         &self,
@@ -103,4 +186,107 @@ impl AccountController {
 
         Ok(FetchAccountBalancesResponse { balances })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("list_nfts", &result))]
+    async fn list_nfts(&self, input: ListNftsInput) -> ApiResult<ListNftsResponse> {
+        let token_ids = self.account_service.list_nfts(input).await?;
+
+        Ok(ListNftsResponse { token_ids })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::Read(ResourceId::Any))]))]
+    async fn get_proof_of_reserves(&self) -> ApiResult<GetProofOfReservesResponse> {
+        self.proof_of_reserves_service.get_proof_of_reserves()
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::Create)]))]
+    #[with_middleware(tail = use_canister_call_metric("discover_accounts", &result))]
+    async fn discover_accounts(
+        &self,
+        input: DiscoverAccountsInput,
+    ) -> ApiResult<DiscoverAccountsResponse> {
+        let ctx = call_context();
+        let discovery = self.account_service.discover_accounts(input, &ctx).await?;
+
+        Ok(discovery.into())
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("create_funding_request", &result))]
+    async fn create_funding_request(
+        &self,
+        input: CreateFundingRequestInput,
+    ) -> ApiResult<CreateFundingRequestResponse> {
+        let ctx = call_context();
+        let funding_request = self
+            .funding_request_service
+            .create_funding_request(input, &ctx)
+            .await?;
+
+        Ok(CreateFundingRequestResponse {
+            funding_request: funding_request.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_funding_requests(
+        &self,
+        input: ListFundingRequestsInput,
+    ) -> ApiResult<ListFundingRequestsResponse> {
+        let funding_requests = self
+            .funding_request_service
+            .list_funding_requests(HelperMapper::to_uuid(input.account_id)?.as_bytes())?;
+
+        Ok(ListFundingRequestsResponse {
+            funding_requests: funding_requests.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("import_account_transactions", &result))]
+    async fn import_account_transactions(
+        &self,
+        input: ImportAccountTransactionsInput,
+    ) -> ApiResult<ImportAccountTransactionsResponse> {
+        let imported = self
+            .transaction_history_service
+            .import_account_transactions(HelperMapper::to_uuid(input.account_id)?.as_bytes())
+            .await?;
+
+        Ok(ImportAccountTransactionsResponse {
+            imported: imported as u64,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_account_transactions(
+        &self,
+        input: ListAccountTransactionsInput,
+    ) -> ApiResult<ListAccountTransactionsResponse> {
+        let result = self.transaction_history_service.list_account_transactions(
+            HelperMapper::to_uuid(input.account_id)?.as_bytes(),
+            input.paginate,
+        )?;
+
+        Ok(ListAccountTransactionsResponse {
+            transactions: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_capability_grants(
+        &self,
+        input: ListCapabilityGrantsInput,
+    ) -> ApiResult<ListCapabilityGrantsResponse> {
+        let grants = self
+            .capability_grant_service
+            .list_capability_grants(HelperMapper::to_uuid(input.account_id)?.as_bytes());
+
+        Ok(ListCapabilityGrantsResponse {
+            grants: grants.into_iter().map(Into::into).collect(),
+        })
+    }
 }
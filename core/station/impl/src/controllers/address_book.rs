@@ -12,7 +12,7 @@ use orbit_essentials::with_middleware;
 use station_api::{
     AddressBookEntryCallerPrivilegesDTO, GetAddressBookEntryInputDTO,
     GetAddressBookEntryResponseDTO, ListAddressBookEntriesInputDTO,
-    ListAddressBookEntriesResponseDTO,
+    ListAddressBookEntriesResponseDTO, ListAddressBookLabelsResponse,
 };
 
 // Canister entrypoints for the controller.
@@ -30,6 +30,11 @@ async fn list_address_book_entries(
     CONTROLLER.list_address_book_entries(input).await
 }
 
+#[query(name = "list_address_book_labels")]
+async fn list_address_book_labels() -> ApiResult<ListAddressBookLabelsResponse> {
+    CONTROLLER.list_address_book_labels().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: AddressBookController =
@@ -102,4 +107,16 @@ impl AddressBookController {
             privileges,
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::AddressBook(ResourceAction::List)]))]
+    async fn list_address_book_labels(&self) -> ApiResult<ListAddressBookLabelsResponse> {
+        Ok(ListAddressBookLabelsResponse {
+            labels: self
+                .address_book_service
+                .list_labels()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
 }
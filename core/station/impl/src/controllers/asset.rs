@@ -0,0 +1,95 @@
+use crate::{
+    core::middlewares::{authorize, call_context},
+    mappers::HelperMapper,
+    models::resource::{Resource, ResourceAction},
+    services::{AssetService, ASSET_SERVICE},
+};
+use ic_cdk_macros::query;
+use lazy_static::lazy_static;
+use orbit_essentials::api::ApiResult;
+use orbit_essentials::with_middleware;
+use station_api::{
+    GetRegisteredAssetInput, GetRegisteredAssetResponse, ListRegisteredAssetsInput,
+    ListRegisteredAssetsResponse,
+};
+use std::sync::Arc;
+
+// Canister entrypoints for the controller.
+#[query(name = "get_registered_asset")]
+async fn get_registered_asset(
+    input: GetRegisteredAssetInput,
+) -> ApiResult<GetRegisteredAssetResponse> {
+    CONTROLLER.get_registered_asset(input).await
+}
+
+#[query(name = "list_registered_assets")]
+async fn list_registered_assets(
+    input: ListRegisteredAssetsInput,
+) -> ApiResult<ListRegisteredAssetsResponse> {
+    CONTROLLER.list_registered_assets(input).await
+}
+
+// Controller initialization and implementation.
+lazy_static! {
+    static ref CONTROLLER: AssetController = AssetController::new(Arc::clone(&ASSET_SERVICE));
+}
+
+#[derive(Debug)]
+pub struct AssetController {
+    asset_service: Arc<AssetService>,
+}
+
+impl AssetController {
+    pub fn new(asset_service: Arc<AssetService>) -> Self {
+        Self { asset_service }
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn get_registered_asset(
+        &self,
+        input: GetRegisteredAssetInput,
+    ) -> ApiResult<GetRegisteredAssetResponse> {
+        let ctx = call_context();
+        let asset_id = HelperMapper::to_uuid(input.asset_id)?;
+
+        let asset = self
+            .asset_service
+            .get_registered_asset(asset_id.as_bytes())?;
+        let privileges = self
+            .asset_service
+            .get_caller_privileges_for_asset(asset_id.as_bytes(), &ctx);
+
+        Ok(GetRegisteredAssetResponse {
+            asset: asset.into(),
+            privileges: privileges.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Asset(ResourceAction::List)]))]
+    async fn list_registered_assets(
+        &self,
+        input: ListRegisteredAssetsInput,
+    ) -> ApiResult<ListRegisteredAssetsResponse> {
+        let ctx = call_context();
+        let result = self
+            .asset_service
+            .list_registered_assets(input.paginate, &ctx)?;
+
+        let privileges = result
+            .items
+            .iter()
+            .map(|asset| {
+                self.asset_service
+                    .get_caller_privileges_for_asset(&asset.id, &ctx)
+                    .into()
+            })
+            .collect();
+
+        Ok(ListRegisteredAssetsResponse {
+            assets: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+            privileges,
+        })
+    }
+}
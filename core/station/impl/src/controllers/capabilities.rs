@@ -4,30 +4,42 @@ use crate::{
         read_system_info, ASSETS,
     },
     models::resource::{Resource, SystemResourceAction},
+    services::{AssetService, ASSET_SERVICE},
     SYSTEM_VERSION,
 };
-use ic_cdk_macros::query;
+use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
-use station_api::{CapabilitiesDTO, CapabilitiesResponse};
+use station_api::{
+    CapabilitiesDTO, CapabilitiesResponse, ListSupportedAssetsResponse, STATION_API_VERSION,
+};
+use std::sync::Arc;
 
 #[query(name = "capabilities")]
 async fn capabilities() -> ApiResult<CapabilitiesResponse> {
     CONTROLLER.capabilities().await
 }
 
+#[update(name = "list_supported_assets")]
+async fn list_supported_assets() -> ApiResult<ListSupportedAssetsResponse> {
+    CONTROLLER.list_supported_assets().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: CapabilitiesController = CapabilitiesController::new();
+    static ref CONTROLLER: CapabilitiesController =
+        CapabilitiesController::new(Arc::clone(&ASSET_SERVICE));
 }
 
 #[derive(Debug)]
-pub struct CapabilitiesController {}
+pub struct CapabilitiesController {
+    asset_service: Arc<AssetService>,
+}
 
 impl CapabilitiesController {
-    fn new() -> Self {
-        Self {}
+    fn new(asset_service: Arc<AssetService>) -> Self {
+        Self { asset_service }
     }
 
     #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::Capabilities)]))]
@@ -39,8 +51,18 @@ impl CapabilitiesController {
             capabilities: CapabilitiesDTO {
                 name: system.get_name().to_string(),
                 version: SYSTEM_VERSION.to_string(),
+                api_version: Some(STATION_API_VERSION.to_string()),
                 supported_assets: assets.into_iter().map(|asset| asset.into()).collect(),
             },
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::Capabilities)]))]
+    async fn list_supported_assets(&self) -> ApiResult<ListSupportedAssetsResponse> {
+        let assets = self.asset_service.list_supported_assets().await;
+
+        Ok(ListSupportedAssetsResponse {
+            assets: assets.into_iter().map(|asset| asset.into()).collect(),
+        })
+    }
 }
@@ -1,18 +1,30 @@
-use crate::{core::ic_cdk::api::canister_balance, SERVICE_NAME};
+use crate::{
+    core::{certification::http_witness, ic_cdk::api::canister_balance},
+    factories::blockchains::HttpOutcallTransport,
+    services::{CalendarFeedService, CALENDAR_FEED_SERVICE},
+    SERVICE_NAME,
+};
+use ic_cdk::api::management_canister::http_request::{self as outcall, TransformArgs};
 use ic_cdk_macros::query;
 use lazy_static::lazy_static;
 use orbit_essentials::api::{HeaderField, HttpRequest, HttpResponse};
-use orbit_essentials::http::{add_skip_certification_headers, not_found, parse_path};
+use orbit_essentials::http::{add_skip_certification_headers_with_witness, not_found, parse_path};
 use orbit_essentials::metrics::with_metrics_registry;
 
 // Canister entrypoints for the controller.
 #[query(name = "http_request", decoding_quota = 10000)]
 async fn http_request(request: HttpRequest) -> HttpResponse {
     let mut resp = CONTROLLER.router(request).await;
-    add_skip_certification_headers(&mut resp);
+    add_skip_certification_headers_with_witness(&mut resp, &http_witness());
     resp
 }
 
+/// Strips the non deterministic parts of the JSON-RPC responses received through HTTPS outcalls.
+#[query(name = "transform_rpc_response", hidden = true)]
+fn transform_rpc_response(args: TransformArgs) -> outcall::HttpResponse {
+    HttpOutcallTransport::transform(args)
+}
+
 // Controller initialization and implementation.
 lazy_static! {
     static ref CONTROLLER: HttpController = HttpController::new();
@@ -30,6 +42,9 @@ impl HttpController {
         match parse_path(&request.url) {
             Some(path) => match path.trim_end_matches('/') {
                 "/metrics" => self.metrics(request).await,
+                path if path.starts_with(CalendarFeedService::PATH_PREFIX) => {
+                    self.calendar_feed(path, request)
+                }
                 _ => not_found(),
             },
             None => not_found(),
@@ -38,11 +53,7 @@ impl HttpController {
 
     async fn metrics(&self, request: HttpRequest) -> HttpResponse {
         if request.method.to_lowercase() != "get" {
-            return HttpResponse {
-                status_code: 405,
-                headers: vec![HeaderField("Allow".into(), "GET".into())],
-                body: "405 Method Not Allowed".as_bytes().to_owned(),
-            };
+            return method_not_allowed();
         }
 
         // Add dynamic metrics, dropped after the request since query calls don't save state changes.
@@ -59,4 +70,31 @@ impl HttpController {
             registry.export_metrics_as_http_response()
         })
     }
+
+    /// Serves the ICS calendar feed of the user that owns the capability url.
+    fn calendar_feed(&self, path: &str, request: HttpRequest) -> HttpResponse {
+        if request.method.to_lowercase() != "get" {
+            return method_not_allowed();
+        }
+
+        match CALENDAR_FEED_SERVICE.render_feed(path) {
+            Some(feed) => HttpResponse {
+                status_code: 200,
+                headers: vec![
+                    HeaderField("Content-Type".into(), "text/calendar; charset=utf-8".into()),
+                    HeaderField("Cache-Control".into(), "no-store".into()),
+                ],
+                body: feed.into_bytes(),
+            },
+            None => not_found(),
+        }
+    }
+}
+
+fn method_not_allowed() -> HttpResponse {
+    HttpResponse {
+        status_code: 405,
+        headers: vec![HeaderField("Allow".into(), "GET".into())],
+        body: "405 Method Not Allowed".as_bytes().to_owned(),
+    }
 }
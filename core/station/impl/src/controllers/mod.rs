@@ -9,6 +9,9 @@ pub use system::*;
 mod capabilities;
 pub use capabilities::*;
 
+mod asset;
+pub use asset::*;
+
 mod external_canister;
 pub use external_canister::*;
 
@@ -3,16 +3,20 @@ use crate::{
         authorization::Authorization,
         middlewares::{authorize, call_context},
     },
-    models::resource::{PermissionResourceAction, Resource},
-    services::permission::{PermissionService, PERMISSION_SERVICE},
+    models::resource::{PermissionResourceAction, Resource, ResourceAction, ResourceId},
+    services::{
+        permission::{PermissionService, PERMISSION_SERVICE},
+        PolicySuggestionService, POLICY_SUGGESTION_SERVICE,
+    },
 };
 use ic_cdk_macros::query;
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetPermissionInput, GetPermissionResponse, ListPermissionsInput, ListPermissionsResponse,
-    PermissionCallerPrivilegesDTO,
+    ExportAccessPoliciesResponse, GetPermissionInput, GetPermissionResponse, ListPermissionsInput,
+    ListPermissionsResponse, PermissionCallerPrivilegesDTO, PreviewAccessPoliciesImportInput,
+    PreviewAccessPoliciesImportResponse, SuggestPoliciesResponse,
 };
 use std::sync::Arc;
 
@@ -27,20 +31,46 @@ async fn list_permissions(input: ListPermissionsInput) -> ApiResult<ListPermissi
     CONTROLLER.list_permissions(input).await
 }
 
+#[query(name = "export_access_policies")]
+async fn export_access_policies() -> ApiResult<ExportAccessPoliciesResponse> {
+    CONTROLLER.export_access_policies().await
+}
+
+#[query(name = "preview_access_policies_import")]
+async fn preview_access_policies_import(
+    input: PreviewAccessPoliciesImportInput,
+) -> ApiResult<PreviewAccessPoliciesImportResponse> {
+    CONTROLLER.preview_access_policies_import(input).await
+}
+
+#[query(name = "suggest_policies")]
+async fn suggest_policies() -> ApiResult<SuggestPoliciesResponse> {
+    CONTROLLER.suggest_policies().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: PermissionController =
-        PermissionController::new(Arc::clone(&PERMISSION_SERVICE));
+    static ref CONTROLLER: PermissionController = PermissionController::new(
+        Arc::clone(&PERMISSION_SERVICE),
+        Arc::clone(&POLICY_SUGGESTION_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct PermissionController {
     permission_service: Arc<PermissionService>,
+    policy_suggestion_service: Arc<PolicySuggestionService>,
 }
 
 impl PermissionController {
-    fn new(permission_service: Arc<PermissionService>) -> Self {
-        Self { permission_service }
+    fn new(
+        permission_service: Arc<PermissionService>,
+        policy_suggestion_service: Arc<PolicySuggestionService>,
+    ) -> Self {
+        Self {
+            permission_service,
+            policy_suggestion_service,
+        }
     }
 
     #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Read)]))]
@@ -94,4 +124,38 @@ impl PermissionController {
             privileges,
         })
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Read)]))]
+    async fn export_access_policies(&self) -> ApiResult<ExportAccessPoliciesResponse> {
+        let document = self.permission_service.export_access_policies();
+
+        Ok(ExportAccessPoliciesResponse {
+            document: document.into(),
+        })
+    }
+
+    /// Reports what importing the document would change, to review it before creating the import request.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Read)]))]
+    async fn preview_access_policies_import(
+        &self,
+        input: PreviewAccessPoliciesImportInput,
+    ) -> ApiResult<PreviewAccessPoliciesImportResponse> {
+        let report = self
+            .permission_service
+            .preview_access_policies_import(&input.document.into())
+            .await?;
+
+        Ok(PreviewAccessPoliciesImportResponse {
+            report: report.into(),
+        })
+    }
+
+    /// Only the admins that can edit both the permissions and the request policies can see the
+    /// suggestions, since they expose who creates and approves which requests.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Permission(PermissionResourceAction::Update), Resource::RequestPolicy(ResourceAction::Update(ResourceId::Any))]))]
+    async fn suggest_policies(&self) -> ApiResult<SuggestPoliciesResponse> {
+        let suggestions = self.policy_suggestion_service.suggest_policies()?;
+
+        Ok(suggestions.into())
+    }
 }
@@ -3,12 +3,17 @@ use crate::{
     core::ic_cdk::api::{time, trap},
     core::limiter::Limiter,
     core::middlewares::{authorize, call_context, use_canister_call_metric},
-    core::CallContext,
+    core::{utils::PaginatedData, CallContext},
     errors::{RequestError, RequestExecuteError},
     mappers::HelperMapper,
     models::rate_limiter::RequestRateLimiterKey,
     models::resource::{RequestResourceAction, Resource},
-    services::{RequestService, REQUEST_SERVICE},
+    models::Request,
+    services::{
+        RecordCertificationService, RequestCommentService, RequestService, RequestViewService,
+        RECORD_CERTIFICATION_SERVICE, REQUEST_COMMENT_SERVICE, REQUEST_SERVICE,
+        REQUEST_VIEW_SERVICE,
+    },
 };
 use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
@@ -16,10 +21,16 @@ use orbit_essentials::api::ApiResult;
 use orbit_essentials::types::UUID;
 use orbit_essentials::with_middleware;
 use station_api::{
-    CreateRequestInput, CreateRequestResponse, GetNextApprovableRequestInput,
-    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse, ListRequestsInput,
-    ListRequestsResponse, RequestAdditionalInfoDTO, RequestCallerPrivilegesDTO,
-    SubmitRequestApprovalInput, SubmitRequestApprovalResponse,
+    AddRequestCommentInput, AddRequestCommentResponse, AmendRequestInput, AmendRequestResponse,
+    CancelRequestInput, CancelRequestResponse, CreateRequestInput, CreateRequestResponse,
+    CreateRequestViewInput, EditRequestViewInput, EvaluateRequestPoliciesInput,
+    EvaluateRequestPoliciesResponse, GetNextApprovableRequestInput,
+    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse,
+    ListRequestCommentsInput, ListRequestCommentsResponse, ListRequestViewsResponse,
+    ListRequestsByViewInput, ListRequestsInput, ListRequestsResponse, RemoveRequestViewInput,
+    RequestAdditionalInfoDTO, RequestCallerPrivilegesDTO, RequestUserGroupMembershipInput,
+    RequestViewResponse, SearchRequestsInput, SubmitRequestApprovalInput,
+    SubmitRequestApprovalResponse,
 };
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -44,6 +55,13 @@ async fn get_next_approvable_request(
     CONTROLLER.get_next_approvable_request(input).await
 }
 
+#[query(name = "evaluate_request_policies")]
+async fn evaluate_request_policies(
+    input: EvaluateRequestPoliciesInput,
+) -> ApiResult<EvaluateRequestPoliciesResponse> {
+    CONTROLLER.evaluate_request_policies(input).await
+}
+
 #[update(name = "submit_request_approval")]
 async fn submit_request_approval(
     input: SubmitRequestApprovalInput,
@@ -51,11 +69,74 @@ async fn submit_request_approval(
     CONTROLLER.submit_request_approval(input).await
 }
 
+#[update(name = "cancel_request")]
+async fn cancel_request(input: CancelRequestInput) -> ApiResult<CancelRequestResponse> {
+    CONTROLLER.cancel_request(input).await
+}
+
+#[update(name = "amend_request")]
+async fn amend_request(input: AmendRequestInput) -> ApiResult<AmendRequestResponse> {
+    CONTROLLER.amend_request(input).await
+}
+
 #[update(name = "create_request")]
 async fn create_request(input: CreateRequestInput) -> ApiResult<CreateRequestResponse> {
     CONTROLLER.create_request(input, arg_data_raw_size()).await
 }
 
+#[update(name = "request_user_group_membership")]
+async fn request_user_group_membership(
+    input: RequestUserGroupMembershipInput,
+) -> ApiResult<CreateRequestResponse> {
+    CONTROLLER
+        .request_user_group_membership(input, arg_data_raw_size())
+        .await
+}
+
+#[query(name = "list_request_views")]
+async fn list_request_views() -> ApiResult<ListRequestViewsResponse> {
+    CONTROLLER.list_request_views().await
+}
+
+#[query(name = "list_requests_by_view")]
+async fn list_requests_by_view(input: ListRequestsByViewInput) -> ApiResult<ListRequestsResponse> {
+    CONTROLLER.list_requests_by_view(input).await
+}
+
+#[query(name = "search_requests")]
+async fn search_requests(input: SearchRequestsInput) -> ApiResult<ListRequestsResponse> {
+    CONTROLLER.search_requests(input).await
+}
+
+#[update(name = "create_request_view")]
+async fn create_request_view(input: CreateRequestViewInput) -> ApiResult<RequestViewResponse> {
+    CONTROLLER.create_request_view(input).await
+}
+
+#[update(name = "edit_request_view")]
+async fn edit_request_view(input: EditRequestViewInput) -> ApiResult<RequestViewResponse> {
+    CONTROLLER.edit_request_view(input).await
+}
+
+#[update(name = "remove_request_view")]
+async fn remove_request_view(input: RemoveRequestViewInput) -> ApiResult<()> {
+    CONTROLLER.remove_request_view(input).await
+}
+
+#[update(name = "add_request_comment")]
+async fn add_request_comment(
+    input: AddRequestCommentInput,
+) -> ApiResult<AddRequestCommentResponse> {
+    CONTROLLER.add_request_comment(input).await
+}
+
+#[query(name = "list_request_comments")]
+async fn list_request_comments(
+    input: ListRequestCommentsInput,
+) -> ApiResult<ListRequestCommentsResponse> {
+    CONTROLLER.list_request_comments(input).await
+}
+
 #[update(name = "try_execute_request", hidden = true)]
 async fn try_execute_request(id: UUID) -> Result<(), RequestExecuteError> {
     CONTROLLER.try_execute_request(id).await
@@ -122,17 +203,35 @@ async fn rate_limit_create_request(ctx: &CallContext, msg_arg_data_size: usize)
 
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: RequestController = RequestController::new(Arc::clone(&REQUEST_SERVICE));
+    static ref CONTROLLER: RequestController = RequestController::new(
+        Arc::clone(&REQUEST_SERVICE),
+        Arc::clone(&REQUEST_VIEW_SERVICE),
+        Arc::clone(&REQUEST_COMMENT_SERVICE),
+        Arc::clone(&RECORD_CERTIFICATION_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct RequestController {
     request_service: Arc<RequestService>,
+    request_view_service: Arc<RequestViewService>,
+    request_comment_service: Arc<RequestCommentService>,
+    record_certification_service: Arc<RecordCertificationService>,
 }
 
 impl RequestController {
-    fn new(request_service: Arc<RequestService>) -> Self {
-        Self { request_service }
+    fn new(
+        request_service: Arc<RequestService>,
+        request_view_service: Arc<RequestViewService>,
+        request_comment_service: Arc<RequestCommentService>,
+        record_certification_service: Arc<RecordCertificationService>,
+    ) -> Self {
+        Self {
+            request_service,
+            request_view_service,
+            request_comment_service,
+            record_certification_service,
+        }
     }
 
     #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
@@ -154,7 +253,7 @@ impl RequestController {
             .await?;
         let additional_info = self
             .request_service
-            .get_request_additional_info(&request, true)?;
+            .get_request_additional_info(&request, true, ctx)?;
 
         Ok(CreateRequestResponse {
             request: request.to_dto(),
@@ -163,6 +262,55 @@ impl RequestController {
         })
     }
 
+    /// Any caller that can read the group can ask to join or leave it, the members only change once
+    /// the request is approved.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("request_user_group_membership", &result))]
+    async fn request_user_group_membership(
+        &self,
+        input: RequestUserGroupMembershipInput,
+        msg_arg_data_size: usize,
+    ) -> ApiResult<CreateRequestResponse> {
+        let ctx = &call_context();
+
+        rate_limit_create_request(ctx, msg_arg_data_size).await?;
+
+        let request = self
+            .request_service
+            .request_user_group_membership(input, ctx)
+            .await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true, ctx)?;
+
+        Ok(CreateRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn evaluate_request_policies(
+        &self,
+        input: EvaluateRequestPoliciesInput,
+    ) -> ApiResult<EvaluateRequestPoliciesResponse> {
+        let ctx = &call_context();
+        let (evaluation_result, possible_approvers) = self
+            .request_service
+            .evaluate_request_policies(input.operation, ctx)
+            .await?;
+
+        Ok(EvaluateRequestPoliciesResponse {
+            evaluation_result: evaluation_result.into(),
+            possible_approvers: possible_approvers.into_iter().map(Into::into).collect(),
+        })
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
     async fn get_request(&self, input: GetRequestInput) -> ApiResult<GetRequestResponse> {
         let ctx = &call_context();
@@ -175,7 +323,10 @@ impl RequestController {
             .await?;
         let additional_info = self
             .request_service
-            .get_request_additional_info(&request, true)?;
+            .get_request_additional_info(&request, true, ctx)?;
+        let certification = self
+            .record_certification_service
+            .request_certification(&request);
 
         Ok(GetRequestResponse {
             request: match input.with_full_info {
@@ -184,6 +335,7 @@ impl RequestController {
             },
             privileges: privileges.into(),
             additional_info: additional_info.into(),
+            certification,
         })
     }
 
@@ -193,18 +345,41 @@ impl RequestController {
         let with_evaluation_results = input.with_evaluation_results;
         let result = self.request_service.list_requests(input, &ctx).await?;
 
+        self.to_list_requests_response(result, with_evaluation_results, &ctx)
+            .await
+    }
+
+    /// Searches the requests by keywords, with the same permissions as `list_requests`.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Request(RequestResourceAction::List)]))]
+    async fn search_requests(&self, input: SearchRequestsInput) -> ApiResult<ListRequestsResponse> {
+        let ctx = call_context();
+        let with_evaluation_results = input.with_evaluation_results;
+        let result = self.request_service.search_requests(input, &ctx)?;
+
+        self.to_list_requests_response(result, with_evaluation_results, &ctx)
+            .await
+    }
+
+    async fn to_list_requests_response(
+        &self,
+        result: PaginatedData<Request>,
+        with_evaluation_results: bool,
+        ctx: &CallContext,
+    ) -> ApiResult<ListRequestsResponse> {
         let mut privileges = Vec::new();
         let mut additionals = Vec::new();
 
         for request in &result.items {
             let privilege = self
                 .request_service
-                .get_caller_privileges_for_request(&request.id, &ctx)
+                .get_caller_privileges_for_request(&request.id, ctx)
                 .await?;
 
-            let additional_info = self
-                .request_service
-                .get_request_additional_info(request, with_evaluation_results)?;
+            let additional_info = self.request_service.get_request_additional_info(
+                request,
+                with_evaluation_results,
+                ctx,
+            )?;
 
             privileges.push(RequestCallerPrivilegesDTO::from(privilege));
             additionals.push(RequestAdditionalInfoDTO::from(additional_info));
@@ -219,6 +394,99 @@ impl RequestController {
         })
     }
 
+    /// Lists the requests matching a saved view of the caller, with the same permissions as `list_requests`.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Request(RequestResourceAction::List)]))]
+    async fn list_requests_by_view(
+        &self,
+        input: ListRequestsByViewInput,
+    ) -> ApiResult<ListRequestsResponse> {
+        let ctx = call_context();
+        let input = self.request_view_service.to_list_requests_input(
+            input.view_id,
+            input.paginate,
+            input.with_evaluation_results,
+            &ctx,
+        )?;
+
+        self.list_requests(input).await
+    }
+
+    /// Returns the views owned by the caller or shared with one of its groups.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    async fn list_request_views(&self) -> ApiResult<ListRequestViewsResponse> {
+        let ctx = call_context();
+        let views = self.request_view_service.list_views(&ctx)?;
+
+        Ok(ListRequestViewsResponse {
+            views: views.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("create_request_view", &result))]
+    async fn create_request_view(
+        &self,
+        input: CreateRequestViewInput,
+    ) -> ApiResult<RequestViewResponse> {
+        let ctx = call_context();
+        let view = self.request_view_service.create_view(input, &ctx).await?;
+
+        Ok(RequestViewResponse { view: view.into() })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("edit_request_view", &result))]
+    async fn edit_request_view(
+        &self,
+        input: EditRequestViewInput,
+    ) -> ApiResult<RequestViewResponse> {
+        let ctx = call_context();
+        let view = self.request_view_service.edit_view(input, &ctx)?;
+
+        Ok(RequestViewResponse { view: view.into() })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("remove_request_view", &result))]
+    async fn remove_request_view(&self, input: RemoveRequestViewInput) -> ApiResult<()> {
+        let ctx = call_context();
+        self.request_view_service.remove_view(input.view_id, &ctx)?;
+
+        Ok(())
+    }
+
+    /// Comments can be added by the users that can read the request.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("add_request_comment", &result))]
+    async fn add_request_comment(
+        &self,
+        input: AddRequestCommentInput,
+    ) -> ApiResult<AddRequestCommentResponse> {
+        let ctx = call_context();
+        let comment = self
+            .request_comment_service
+            .add_comment(input, &ctx)
+            .await?;
+
+        Ok(AddRequestCommentResponse {
+            comment: comment.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_request_comments(
+        &self,
+        input: ListRequestCommentsInput,
+    ) -> ApiResult<ListRequestCommentsResponse> {
+        let result = self.request_comment_service.list_comments(input)?;
+
+        Ok(ListRequestCommentsResponse {
+            comments: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
     #[with_middleware(guard = authorize(&call_context(), &[Resource::Request(RequestResourceAction::List)]))]
     async fn get_next_approvable_request(
         &self,
@@ -238,12 +506,16 @@ impl RequestController {
 
             let additional_info = self
                 .request_service
-                .get_request_additional_info(&request, true)?;
+                .get_request_additional_info(&request, true, &ctx)?;
+            let certification = self
+                .record_certification_service
+                .request_certification(&request);
 
             Ok(Some(GetRequestResponse {
                 request: request.to_dto(),
                 privileges: privileges.into(),
                 additional_info: additional_info.into(),
+                certification,
             }))
         } else {
             Ok(None)
@@ -267,7 +539,7 @@ impl RequestController {
             .await?;
         let additional_info = self
             .request_service
-            .get_request_additional_info(&request, true)?;
+            .get_request_additional_info(&request, true, ctx)?;
 
         Ok(SubmitRequestApprovalResponse {
             request: request.to_dto(),
@@ -276,6 +548,47 @@ impl RequestController {
         })
     }
 
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("cancel_request", &result))]
+    async fn cancel_request(&self, input: CancelRequestInput) -> ApiResult<CancelRequestResponse> {
+        let ctx = &call_context();
+        let request = self.request_service.cancel_request(input, ctx).await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true, ctx)?;
+
+        Ok(CancelRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
+    /// The caller needs the permission to create the amended operation, as for a new request.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("amend_request", &result))]
+    async fn amend_request(&self, input: AmendRequestInput) -> ApiResult<AmendRequestResponse> {
+        let ctx = &call_context();
+        let request = self.request_service.amend_request(input, ctx).await?;
+        let privileges = self
+            .request_service
+            .get_caller_privileges_for_request(&request.id, ctx)
+            .await?;
+        let additional_info = self
+            .request_service
+            .get_request_additional_info(&request, true, ctx)?;
+
+        Ok(AmendRequestResponse {
+            request: request.to_dto(),
+            privileges: privileges.into(),
+            additional_info: additional_info.into(),
+        })
+    }
+
     // No authorization middleware as the caller is checked to be the station canister.
     async fn try_execute_request(&self, id: UUID) -> Result<(), RequestExecuteError> {
         let ctx = call_context();
@@ -1,33 +1,41 @@
 use crate::{
     core::{
-        ic_cdk::api::{canister_balance, set_certified_data, trap},
+        certification::update_certified_data,
+        ic_cdk::api::{canister_balance, trap},
         middlewares::{authorize, call_context},
     },
     errors::AuthorizationError,
+    jobs,
+    mappers::blockchain::BlockchainMapper,
     migration,
     models::resource::{Resource, SystemResourceAction},
-    services::{SystemService, SYSTEM_SERVICE},
+    services::{
+        ArchiveService, AttestationService, CircuitBreakerService, LocaleService,
+        NotificationTemplateService, PendingExecutionService, SystemService, ARCHIVE_SERVICE,
+        ATTESTATION_SERVICE, CIRCUIT_BREAKER_SERVICE, LOCALE_SERVICE,
+        NOTIFICATION_TEMPLATE_SERVICE, PENDING_EXECUTION_SERVICE, RECORD_CERTIFICATION_SERVICE,
+        SYSTEM_SERVICE, TRANSFER_RECEIPT_SERVICE,
+    },
     SYSTEM_VERSION,
 };
 use ic_cdk_macros::{post_upgrade, query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
-use orbit_essentials::http::certified_data_for_skip_certification;
 use orbit_essentials::with_middleware;
 use station_api::{
-    HealthStatus, NotifyFailedStationUpgradeInput, SystemInfoResponse, SystemInstall, SystemUpgrade,
+    DownloadArchiveChunkInput, DownloadArchiveChunkResponse, GetStationAttestationResponse,
+    HealthStatus, ListNotificationTemplatesResponse, ListPendingExecutionsResponse,
+    NotifyFailedStationUpgradeInput, QueryArchiveInput, QueryArchiveResponse,
+    ResumeBlockchainInput, SystemInfoResponse, SystemInstall, SystemUpgrade,
+    UploadLocaleCatalogInput, UploadLocaleCatalogResponse,
 };
 use std::sync::Arc;
 
-fn set_certified_data_for_skip_certification() {
-    set_certified_data(&certified_data_for_skip_certification());
-}
-
 // Canister entrypoints for the controller.
 #[cfg(any(not(feature = "canbench"), test))]
 #[ic_cdk_macros::init]
 async fn initialize(input: Option<SystemInstall>) {
-    set_certified_data_for_skip_certification();
+    update_certified_data();
     match input {
         Some(SystemInstall::Init(input)) => CONTROLLER.initialize(input).await,
         Some(SystemInstall::Upgrade(_)) | None => trap("Invalid args to initialize canister"),
@@ -64,7 +72,10 @@ async fn post_upgrade(input: Option<SystemInstall>) {
     // datatype from the one that was initially stored.
     migration::MigrationHandler::run();
 
-    set_certified_data_for_skip_certification();
+    TRANSFER_RECEIPT_SERVICE.restore_certified_receipts();
+    RECORD_CERTIFICATION_SERVICE.start_restoring_certified_records();
+    jobs::schedule_certified_records_restore();
+    update_certified_data();
     match input {
         None => CONTROLLER.post_upgrade(None).await,
         Some(SystemInstall::Upgrade(input)) => CONTROLLER.post_upgrade(Some(input)).await,
@@ -87,19 +98,88 @@ async fn notify_failed_station_upgrade(input: NotifyFailedStationUpgradeInput) -
     CONTROLLER.notify_failed_station_upgrade(input).await
 }
 
+#[update(name = "resume_blockchain")]
+async fn resume_blockchain(input: ResumeBlockchainInput) -> ApiResult<()> {
+    CONTROLLER.resume_blockchain(input).await
+}
+
+#[update(name = "query_archive")]
+async fn query_archive(input: QueryArchiveInput) -> ApiResult<QueryArchiveResponse> {
+    CONTROLLER.query_archive(input).await
+}
+
+#[query(name = "download_archive_chunk")]
+async fn download_archive_chunk(
+    input: DownloadArchiveChunkInput,
+) -> ApiResult<DownloadArchiveChunkResponse> {
+    CONTROLLER.download_archive_chunk(input).await
+}
+
+#[update(name = "upload_locale_catalog")]
+async fn upload_locale_catalog(
+    input: UploadLocaleCatalogInput,
+) -> ApiResult<UploadLocaleCatalogResponse> {
+    CONTROLLER.upload_locale_catalog(input).await
+}
+
+#[query(name = "list_notification_templates")]
+async fn list_notification_templates() -> ApiResult<ListNotificationTemplatesResponse> {
+    CONTROLLER.list_notification_templates().await
+}
+
+#[query(name = "list_pending_executions")]
+async fn list_pending_executions() -> ApiResult<ListPendingExecutionsResponse> {
+    CONTROLLER.list_pending_executions().await
+}
+
+#[query(name = "get_station_attestation")]
+async fn get_station_attestation() -> ApiResult<GetStationAttestationResponse> {
+    CONTROLLER.get_station_attestation().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: SystemController = SystemController::new(Arc::clone(&SYSTEM_SERVICE));
+    static ref CONTROLLER: SystemController = SystemController::new(
+        Arc::clone(&SYSTEM_SERVICE),
+        Arc::clone(&CIRCUIT_BREAKER_SERVICE),
+        Arc::clone(&ARCHIVE_SERVICE),
+        Arc::clone(&LOCALE_SERVICE),
+        Arc::clone(&PENDING_EXECUTION_SERVICE),
+        Arc::clone(&NOTIFICATION_TEMPLATE_SERVICE),
+        Arc::clone(&ATTESTATION_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct SystemController {
     system_service: Arc<SystemService>,
+    circuit_breaker_service: Arc<CircuitBreakerService>,
+    archive_service: Arc<ArchiveService>,
+    locale_service: Arc<LocaleService>,
+    pending_execution_service: Arc<PendingExecutionService>,
+    notification_template_service: Arc<NotificationTemplateService>,
+    attestation_service: Arc<AttestationService>,
 }
 
 impl SystemController {
-    fn new(system_service: Arc<SystemService>) -> Self {
-        Self { system_service }
+    fn new(
+        system_service: Arc<SystemService>,
+        circuit_breaker_service: Arc<CircuitBreakerService>,
+        archive_service: Arc<ArchiveService>,
+        locale_service: Arc<LocaleService>,
+        pending_execution_service: Arc<PendingExecutionService>,
+        notification_template_service: Arc<NotificationTemplateService>,
+        attestation_service: Arc<AttestationService>,
+    ) -> Self {
+        Self {
+            system_service,
+            circuit_breaker_service,
+            archive_service,
+            locale_service,
+            pending_execution_service,
+            notification_template_service,
+            attestation_service,
+        }
     }
 
     #[cfg(any(not(feature = "canbench"), test))]
@@ -152,6 +232,77 @@ impl SystemController {
             .notify_failed_station_upgrade(input.reason)
             .await
     }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn resume_blockchain(&self, input: ResumeBlockchainInput) -> ApiResult<()> {
+        let blockchain = BlockchainMapper::to_blockchain(input.blockchain)?;
+
+        self.circuit_breaker_service.resume_blockchain(&blockchain)
+    }
+
+    // The archive holds the confidential requests as well, hence it is restricted like the
+    // management of the system.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn query_archive(&self, input: QueryArchiveInput) -> ApiResult<QueryArchiveResponse> {
+        self.archive_service.query_archive(input).await
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn download_archive_chunk(
+        &self,
+        input: DownloadArchiveChunkInput,
+    ) -> ApiResult<DownloadArchiveChunkResponse> {
+        self.archive_service.download_archive_chunk(input)
+    }
+
+    /// Stores the translations of the notifications in a locale and reports the keys the catalog
+    /// misses or that the station does not emit.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn upload_locale_catalog(
+        &self,
+        input: UploadLocaleCatalogInput,
+    ) -> ApiResult<UploadLocaleCatalogResponse> {
+        let report = self.locale_service.upload_catalog(
+            &input.locale,
+            input.messages.into_iter().collect(),
+            input.set_as_default.unwrap_or(false),
+        )?;
+
+        Ok(UploadLocaleCatalogResponse {
+            missing_keys: report.missing_keys,
+            unused_keys: report.unused_keys,
+        })
+    }
+
+    /// Lists the templates that word the notifications about requests.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    async fn list_notification_templates(&self) -> ApiResult<ListNotificationTemplatesResponse> {
+        Ok(ListNotificationTemplatesResponse {
+            templates: self
+                .notification_template_service
+                .list_templates()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+
+    /// Shows the approved requests and the created transfers waiting for the execution jobs.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::SystemInfo)]))]
+    async fn list_pending_executions(&self) -> ApiResult<ListPendingExecutionsResponse> {
+        let ctx = call_context();
+
+        Ok(self
+            .pending_execution_service
+            .list_pending_executions(&ctx)
+            .into())
+    }
+
+    // No authorization middleware as the attestation is meant for the counterparties of the station,
+    // which are usually not its users.
+    async fn get_station_attestation(&self) -> ApiResult<GetStationAttestationResponse> {
+        self.attestation_service.get_station_attestation()
+    }
 }
 
 #[cfg(test)]
@@ -1,17 +1,44 @@
 use crate::{
-    core::middlewares::{authorize, call_context},
-    mappers::{authorization::GetTransfersInputRef, HelperMapper},
-    models::resource::Resource,
-    services::TransferService,
+    core::middlewares::{
+        authorize, authorize_with_capability, call_context, use_canister_call_metric,
+    },
+    mappers::{
+        authorization::{
+            CreatePayoutRunInputRef, GetTransferInputRef, GetTransferReceiptInputRef,
+            GetTransfersInputRef,
+        },
+        HelperMapper,
+    },
+    models::{
+        resource::{AccountResourceAction, RequestResourceAction, Resource, SystemResourceAction},
+        CapabilityScope,
+    },
+    services::{
+        PayoutRunService, RecordCertificationService, ScheduledTransferService,
+        SpendingSummaryService, TransferCategoryService, TransferExportService,
+        TransferReceiptService, TransferService, PAYOUT_RUN_SERVICE, RECORD_CERTIFICATION_SERVICE,
+        SCHEDULED_TRANSFER_SERVICE, SPENDING_SUMMARY_SERVICE, TRANSFER_CATEGORY_SERVICE,
+        TRANSFER_EXPORT_SERVICE, TRANSFER_RECEIPT_SERVICE,
+    },
 };
-use ic_cdk_macros::query;
+use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::{ApiError, ApiResult};
+use orbit_essentials::utils::rfc3339_to_timestamp;
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetTransfersInput, GetTransfersResponse, ListAccountTransfersInput,
-    ListAccountTransfersResponse,
+    AuditPendingOutflowsInput, AuditPendingOutflowsResponse, CancelScheduledTransferInput,
+    CancelScheduledTransferResponse, CreatePayoutRunInput, CreatePayoutRunResponse,
+    ExportAccountTransfersInput, ExportAccountTransfersResponse, GetPayoutRunInput,
+    GetPayoutRunResponse, GetSpendingSummaryInput, GetSpendingSummaryResponse,
+    GetTransferFeesInput, GetTransferFeesResponse, GetTransferInput, GetTransferReceiptInput,
+    GetTransferReceiptResponse, GetTransferResponse, GetTransfersInput, GetTransfersResponse,
+    ListAccountTransfersInput, ListAccountTransfersResponse, ListScheduledTransfersInput,
+    ListScheduledTransfersResponse, ListTransferCategoriesResponse, RemoveTransferCategoryInput,
+    RemoveTransferCategoryResponse, SetTransferCategoryInput, SetTransferCategoryResponse,
 };
+use std::sync::Arc;
+use uuid::Uuid;
 
 // Canister entrypoints for the controller.
 #[query(name = "get_transfers")]
@@ -19,6 +46,18 @@ async fn get_transfers(input: GetTransfersInput) -> ApiResult<GetTransfersRespon
     CONTROLLER.get_transfers(input).await
 }
 
+#[query(name = "get_transfer")]
+async fn get_transfer(input: GetTransferInput) -> ApiResult<GetTransferResponse> {
+    CONTROLLER.get_transfer(input).await
+}
+
+#[query(name = "get_transfer_receipt")]
+async fn get_transfer_receipt(
+    input: GetTransferReceiptInput,
+) -> ApiResult<GetTransferReceiptResponse> {
+    CONTROLLER.get_transfer_receipt(input).await
+}
+
 #[query(name = "list_account_transfers")]
 async fn list_account_transfers(
     input: ListAccountTransfersInput,
@@ -26,19 +65,122 @@ async fn list_account_transfers(
     CONTROLLER.list_account_transfers(input).await
 }
 
+#[query(name = "export_account_transfers")]
+async fn export_account_transfers(
+    input: ExportAccountTransfersInput,
+) -> ApiResult<ExportAccountTransfersResponse> {
+    CONTROLLER.export_account_transfers(input).await
+}
+
+#[update(name = "get_transfer_fees")]
+async fn get_transfer_fees(input: GetTransferFeesInput) -> ApiResult<GetTransferFeesResponse> {
+    CONTROLLER.get_transfer_fees(input).await
+}
+
+#[update(name = "audit_pending_outflows")]
+async fn audit_pending_outflows(
+    input: AuditPendingOutflowsInput,
+) -> ApiResult<AuditPendingOutflowsResponse> {
+    CONTROLLER.audit_pending_outflows(input).await
+}
+
+#[query(name = "get_spending_summary")]
+async fn get_spending_summary(
+    input: GetSpendingSummaryInput,
+) -> ApiResult<GetSpendingSummaryResponse> {
+    CONTROLLER.get_spending_summary(input).await
+}
+
+#[query(name = "list_scheduled_transfers")]
+async fn list_scheduled_transfers(
+    input: ListScheduledTransfersInput,
+) -> ApiResult<ListScheduledTransfersResponse> {
+    CONTROLLER.list_scheduled_transfers(input).await
+}
+
+#[update(name = "cancel_scheduled_transfer")]
+async fn cancel_scheduled_transfer(
+    input: CancelScheduledTransferInput,
+) -> ApiResult<CancelScheduledTransferResponse> {
+    CONTROLLER.cancel_scheduled_transfer(input).await
+}
+
+#[query(name = "list_transfer_categories")]
+async fn list_transfer_categories() -> ApiResult<ListTransferCategoriesResponse> {
+    CONTROLLER.list_transfer_categories().await
+}
+
+#[update(name = "set_transfer_category")]
+async fn set_transfer_category(
+    input: SetTransferCategoryInput,
+) -> ApiResult<SetTransferCategoryResponse> {
+    CONTROLLER.set_transfer_category(input).await
+}
+
+#[update(name = "remove_transfer_category")]
+async fn remove_transfer_category(
+    input: RemoveTransferCategoryInput,
+) -> ApiResult<RemoveTransferCategoryResponse> {
+    CONTROLLER.remove_transfer_category(input).await
+}
+
+#[update(name = "create_payout_run")]
+async fn create_payout_run(input: CreatePayoutRunInput) -> ApiResult<CreatePayoutRunResponse> {
+    CONTROLLER.create_payout_run(input).await
+}
+
+#[query(name = "get_payout_run")]
+async fn get_payout_run(input: GetPayoutRunInput) -> ApiResult<GetPayoutRunResponse> {
+    CONTROLLER.get_payout_run(input).await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: TransferController = TransferController::new(TransferService::default());
+    static ref CONTROLLER: TransferController = TransferController::new(
+        TransferService::default(),
+        Arc::clone(&SPENDING_SUMMARY_SERVICE),
+        Arc::clone(&SCHEDULED_TRANSFER_SERVICE),
+        Arc::clone(&TRANSFER_RECEIPT_SERVICE),
+        Arc::clone(&TRANSFER_CATEGORY_SERVICE),
+        Arc::clone(&TRANSFER_EXPORT_SERVICE),
+        Arc::clone(&PAYOUT_RUN_SERVICE),
+        Arc::clone(&RECORD_CERTIFICATION_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct TransferController {
     transfer_service: TransferService,
+    spending_summary_service: Arc<SpendingSummaryService>,
+    scheduled_transfer_service: Arc<ScheduledTransferService>,
+    transfer_receipt_service: Arc<TransferReceiptService>,
+    transfer_category_service: Arc<TransferCategoryService>,
+    transfer_export_service: Arc<TransferExportService>,
+    payout_run_service: Arc<PayoutRunService>,
+    record_certification_service: Arc<RecordCertificationService>,
 }
 
 impl TransferController {
-    fn new(transfer_service: TransferService) -> Self {
-        Self { transfer_service }
+    fn new(
+        transfer_service: TransferService,
+        spending_summary_service: Arc<SpendingSummaryService>,
+        scheduled_transfer_service: Arc<ScheduledTransferService>,
+        transfer_receipt_service: Arc<TransferReceiptService>,
+        transfer_category_service: Arc<TransferCategoryService>,
+        transfer_export_service: Arc<TransferExportService>,
+        payout_run_service: Arc<PayoutRunService>,
+        record_certification_service: Arc<RecordCertificationService>,
+    ) -> Self {
+        Self {
+            transfer_service,
+            spending_summary_service,
+            scheduled_transfer_service,
+            transfer_receipt_service,
+            transfer_category_service,
+            transfer_export_service,
+            payout_run_service,
+            record_certification_service,
+        }
     }
 
     #[with_middleware(
@@ -61,18 +203,217 @@ impl TransferController {
         })
     }
 
-    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(
+        guard = authorize(&call_context(), &GetTransferInputRef(&input).to_resources())
+    )]
+    async fn get_transfer(&self, input: GetTransferInput) -> ApiResult<GetTransferResponse> {
+        let transfer_id = *HelperMapper::to_uuid(input.transfer_id)?.as_bytes();
+        let transfer = self
+            .transfer_service
+            .get_transfer(&transfer_id, &call_context())?;
+        let certification = self
+            .record_certification_service
+            .transfer_certification(&transfer);
+
+        Ok(GetTransferResponse {
+            transfer: transfer.to_dto(),
+            certification,
+        })
+    }
+
+    #[with_middleware(
+        guard = authorize(&call_context(), &GetTransferReceiptInputRef(&input).to_resources())
+    )]
+    async fn get_transfer_receipt(
+        &self,
+        input: GetTransferReceiptInput,
+    ) -> ApiResult<GetTransferReceiptResponse> {
+        let transfer_id = *HelperMapper::to_uuid(input.transfer_id)?.as_bytes();
+
+        self.transfer_receipt_service
+            .get_transfer_receipt(&transfer_id, &call_context())
+    }
+
+    #[with_middleware(guard = authorize_with_capability(&call_context(), CapabilityScope::Transfers, &[Resource::from(&input)]))]
     async fn list_account_transfers(
         &self,
         input: ListAccountTransfersInput,
     ) -> ApiResult<ListAccountTransfersResponse> {
-        let transfers = self.transfer_service.list_account_transfers(input)?;
+        let (transfers, next_transfer_id) = self
+            .transfer_service
+            .list_account_transfers(input, &call_context())?;
 
         Ok(ListAccountTransfersResponse {
             transfers: transfers
                 .into_iter()
                 .map(|t| t.to_list_item_dto())
                 .collect(),
+            next_cursor: next_transfer_id
+                .map(|transfer_id| Uuid::from_bytes(transfer_id).hyphenated().to_string()),
+        })
+    }
+
+    #[with_middleware(guard = authorize_with_capability(&call_context(), CapabilityScope::Transfers, &[Resource::from(&input)]))]
+    async fn export_account_transfers(
+        &self,
+        input: ExportAccountTransfersInput,
+    ) -> ApiResult<ExportAccountTransfersResponse> {
+        let (content, next_transfer_id) = self
+            .transfer_export_service
+            .export_account_transfers(input, &call_context())?;
+
+        Ok(ExportAccountTransfersResponse {
+            content,
+            next_cursor: next_transfer_id
+                .map(|transfer_id| Uuid::from_bytes(transfer_id).hyphenated().to_string()),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("get_transfer_fees", &result))]
+    async fn get_transfer_fees(
+        &self,
+        input: GetTransferFeesInput,
+    ) -> ApiResult<GetTransferFeesResponse> {
+        let fees = self.transfer_service.get_transfer_fees(input).await?;
+
+        Ok(GetTransferFeesResponse {
+            fees: fees.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::AuditOutflows)]))]
+    #[with_middleware(tail = use_canister_call_metric("audit_pending_outflows", &result))]
+    async fn audit_pending_outflows(
+        &self,
+        input: AuditPendingOutflowsInput,
+    ) -> ApiResult<AuditPendingOutflowsResponse> {
+        let result = self
+            .transfer_service
+            .audit_pending_outflows(input.paginate)
+            .await?;
+
+        Ok(AuditPendingOutflowsResponse {
+            outflows: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::List)]))]
+    async fn get_spending_summary(
+        &self,
+        input: GetSpendingSummaryInput,
+    ) -> ApiResult<GetSpendingSummaryResponse> {
+        let summary = self.spending_summary_service.get_spending_summary(
+            input.period.into(),
+            input.group_by.into(),
+            input.from_dt.map(|from_dt| rfc3339_to_timestamp(&from_dt)),
+            input.to_dt.map(|to_dt| rfc3339_to_timestamp(&to_dt)),
+            &call_context(),
+        )?;
+
+        Ok(summary.into())
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    async fn list_scheduled_transfers(
+        &self,
+        input: ListScheduledTransfersInput,
+    ) -> ApiResult<ListScheduledTransfersResponse> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let scheduled_transfers = self
+            .scheduled_transfer_service
+            .list_scheduled_transfers(account_id.as_bytes());
+
+        Ok(ListScheduledTransfersResponse {
+            scheduled_transfers: scheduled_transfers.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("cancel_scheduled_transfer", &result))]
+    async fn cancel_scheduled_transfer(
+        &self,
+        input: CancelScheduledTransferInput,
+    ) -> ApiResult<CancelScheduledTransferResponse> {
+        let scheduled_transfer_id = HelperMapper::to_uuid(input.scheduled_transfer_id)?;
+        let scheduled_transfer = self
+            .scheduled_transfer_service
+            .cancel_scheduled_transfer(scheduled_transfer_id.as_bytes(), &call_context())?;
+
+        Ok(CancelScheduledTransferResponse {
+            scheduled_transfer: scheduled_transfer.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Account(AccountResourceAction::List)]))]
+    async fn list_transfer_categories(&self) -> ApiResult<ListTransferCategoriesResponse> {
+        let categories = self.transfer_category_service.list_categories();
+
+        Ok(ListTransferCategoriesResponse {
+            categories: categories.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn set_transfer_category(
+        &self,
+        input: SetTransferCategoryInput,
+    ) -> ApiResult<SetTransferCategoryResponse> {
+        let category = self
+            .transfer_category_service
+            .set_category(input.name, input.description)?;
+
+        Ok(SetTransferCategoryResponse {
+            category: category.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::System(SystemResourceAction::ManageSystemInfo)]))]
+    async fn remove_transfer_category(
+        &self,
+        input: RemoveTransferCategoryInput,
+    ) -> ApiResult<RemoveTransferCategoryResponse> {
+        let category = self
+            .transfer_category_service
+            .remove_category(&input.name)?;
+
+        Ok(RemoveTransferCategoryResponse {
+            category: category.into(),
+        })
+    }
+
+    #[with_middleware(
+        guard = authorize(&call_context(), &CreatePayoutRunInputRef(&input).to_resources())
+    )]
+    #[with_middleware(tail = use_canister_call_metric("create_payout_run", &result))]
+    async fn create_payout_run(
+        &self,
+        input: CreatePayoutRunInput,
+    ) -> ApiResult<CreatePayoutRunResponse> {
+        let payout_run = self
+            .payout_run_service
+            .create_payout_run(input, &call_context())
+            .await?;
+        let summary = self
+            .payout_run_service
+            .get_payout_run_summary(&payout_run.id)?;
+
+        Ok(CreatePayoutRunResponse {
+            payout_run: summary.into(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::Request(RequestResourceAction::List)]))]
+    async fn get_payout_run(&self, input: GetPayoutRunInput) -> ApiResult<GetPayoutRunResponse> {
+        let payout_run_id = HelperMapper::to_uuid(input.payout_run_id)?;
+        let summary = self
+            .payout_run_service
+            .get_payout_run_summary(payout_run_id.as_bytes())?;
+
+        Ok(GetPayoutRunResponse {
+            payout_run: summary.into(),
         })
     }
 }
@@ -1,17 +1,25 @@
 use crate::{
-    core::middlewares::{authorize, call_context},
+    core::middlewares::{authorize, call_context, use_canister_call_metric},
     mappers::HelperMapper,
-    models::resource::{Resource, UserResourceAction},
-    services::UserService,
+    models::resource::{Resource, ResourceId, UserResourceAction},
+    services::{
+        CalendarFeedService, SupportAccessService, UserService, CALENDAR_FEED_SERVICE,
+        SUPPORT_ACCESS_SERVICE,
+    },
 };
-use ic_cdk_macros::query;
+use ic_cdk_macros::{query, update};
 use lazy_static::lazy_static;
 use orbit_essentials::api::ApiResult;
+use orbit_essentials::utils::timestamp_to_rfc3339;
 use orbit_essentials::with_middleware;
 use station_api::{
-    GetUserInput, GetUserResponse, ListUsersInput, ListUsersResponse, MeResponse,
-    UserCallerPrivilegesDTO,
+    AccountCallerPrivilegesDTO, CreateCalendarFeedResponse, GetUserInput, GetUserResponse,
+    ListSupportAccessLogInput, ListSupportAccessLogResponse, ListUsersInput, ListUsersResponse,
+    MeResponse, ReauthenticateResponse, ReportOnboardingStepInput, ReportOnboardingStepResponse,
+    SetLocaleInput, SetNotificationPreferencesInput, UserCallerPrivilegesDTO, ViewAsInput,
+    ViewAsResponse,
 };
+use std::sync::Arc;
 
 // Canister entrypoints for the controller.
 #[query(name = "get_user")]
@@ -29,19 +37,77 @@ async fn me() -> ApiResult<MeResponse> {
     CONTROLLER.me().await
 }
 
+#[update(name = "view_as")]
+async fn view_as(input: ViewAsInput) -> ApiResult<ViewAsResponse> {
+    CONTROLLER.view_as(input).await
+}
+
+#[query(name = "list_support_access_log")]
+async fn list_support_access_log(
+    input: ListSupportAccessLogInput,
+) -> ApiResult<ListSupportAccessLogResponse> {
+    CONTROLLER.list_support_access_log(input).await
+}
+
+#[update(name = "report_onboarding_step")]
+async fn report_onboarding_step(
+    input: ReportOnboardingStepInput,
+) -> ApiResult<ReportOnboardingStepResponse> {
+    CONTROLLER.report_onboarding_step(input).await
+}
+
+#[update(name = "set_locale")]
+async fn set_locale(input: SetLocaleInput) -> ApiResult<()> {
+    CONTROLLER.set_locale(input).await
+}
+
+#[update(name = "reauthenticate")]
+async fn reauthenticate() -> ApiResult<ReauthenticateResponse> {
+    CONTROLLER.reauthenticate().await
+}
+
+#[update(name = "set_notification_preferences")]
+async fn set_notification_preferences(input: SetNotificationPreferencesInput) -> ApiResult<()> {
+    CONTROLLER.set_notification_preferences(input).await
+}
+
+#[update(name = "create_calendar_feed")]
+async fn create_calendar_feed() -> ApiResult<CreateCalendarFeedResponse> {
+    CONTROLLER.create_calendar_feed().await
+}
+
+#[update(name = "revoke_calendar_feed")]
+async fn revoke_calendar_feed() -> ApiResult<()> {
+    CONTROLLER.revoke_calendar_feed().await
+}
+
 // Controller initialization and implementation.
 lazy_static! {
-    static ref CONTROLLER: UserController = UserController::new(UserService::default());
+    static ref CONTROLLER: UserController = UserController::new(
+        UserService::default(),
+        Arc::clone(&CALENDAR_FEED_SERVICE),
+        Arc::clone(&SUPPORT_ACCESS_SERVICE)
+    );
 }
 
 #[derive(Debug)]
 pub struct UserController {
     user_service: UserService,
+    calendar_feed_service: Arc<CalendarFeedService>,
+    support_access_service: Arc<SupportAccessService>,
 }
 
 impl UserController {
-    fn new(user_service: UserService) -> Self {
-        Self { user_service }
+    fn new(
+        user_service: UserService,
+        calendar_feed_service: Arc<CalendarFeedService>,
+        support_access_service: Arc<SupportAccessService>,
+    ) -> Self {
+        Self {
+            user_service,
+            calendar_feed_service,
+            support_access_service,
+        }
     }
 
     #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
@@ -93,16 +159,144 @@ impl UserController {
         let privileges = self.user_service.get_caller_privileges(&ctx).await?;
 
         Ok(MeResponse {
+            onboarding: user.onboarding.clone().into(),
             me: user.into(),
             privileges,
         })
     }
+
+    /// Returns what is visible to the user without acting on its behalf, the view is recorded in the
+    /// support access log.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&input)]))]
+    #[with_middleware(tail = use_canister_call_metric("view_as", &result))]
+    async fn view_as(&self, input: ViewAsInput) -> ApiResult<ViewAsResponse> {
+        let ctx = call_context();
+        let view = self
+            .support_access_service
+            .view_as(HelperMapper::to_uuid(input.user_id)?.as_bytes(), &ctx)
+            .await?;
+
+        Ok(ViewAsResponse {
+            user: view.user.into(),
+            privileges: view.privileges,
+            accounts: view
+                .accounts
+                .into_iter()
+                .map(|account| account.to_dto())
+                .collect(),
+            account_privileges: view
+                .account_privileges
+                .into_iter()
+                .map(AccountCallerPrivilegesDTO::from)
+                .collect(),
+        })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::User(UserResourceAction::ViewAs(ResourceId::Any))]))]
+    async fn list_support_access_log(
+        &self,
+        input: ListSupportAccessLogInput,
+    ) -> ApiResult<ListSupportAccessLogResponse> {
+        let viewed_user_id = match input.viewed_user_id {
+            Some(user_id) => Some(*HelperMapper::to_uuid(user_id)?.as_bytes()),
+            None => None,
+        };
+        let result = self
+            .support_access_service
+            .list_support_access_log(viewed_user_id.as_ref(), input.paginate)?;
+
+        Ok(ListSupportAccessLogResponse {
+            entries: result.items.into_iter().map(Into::into).collect(),
+            next_offset: result.next_offset,
+            total: result.total,
+        })
+    }
+
+    /// Completes a step of the onboarding checklist of the caller that is reported by the user.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("report_onboarding_step", &result))]
+    async fn report_onboarding_step(
+        &self,
+        input: ReportOnboardingStepInput,
+    ) -> ApiResult<ReportOnboardingStepResponse> {
+        let ctx = call_context();
+        let user = self
+            .user_service
+            .report_onboarding_step(input.step.into(), &ctx)?;
+
+        Ok(ReportOnboardingStepResponse {
+            onboarding: user.onboarding.into(),
+        })
+    }
+
+    /// Sets the locale the notifications of the caller are rendered in.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("set_locale", &result))]
+    async fn set_locale(&self, input: SetLocaleInput) -> ApiResult<()> {
+        let ctx = call_context();
+        self.user_service.set_locale(input.locale, &ctx)?;
+
+        Ok(())
+    }
+
+    /// Records that the caller re-authenticated, for the `RecentAuthentication` rules to check when
+    /// the user approves requests from its other identities.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("reauthenticate", &result))]
+    async fn reauthenticate(&self) -> ApiResult<ReauthenticateResponse> {
+        let ctx = call_context();
+        let reauthentication = self.user_service.reauthenticate(&ctx)?;
+
+        Ok(ReauthenticateResponse {
+            authenticated_at: timestamp_to_rfc3339(&reauthentication.at),
+        })
+    }
+
+    /// Sets the types of the notifications the caller does not receive.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("set_notification_preferences", &result))]
+    async fn set_notification_preferences(
+        &self,
+        input: SetNotificationPreferencesInput,
+    ) -> ApiResult<()> {
+        let ctx = call_context();
+        self.user_service.set_muted_notification_types(
+            input
+                .muted_notification_types
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            &ctx,
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates the capability url of the caller's calendar feed, revoking the previous one.
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("create_calendar_feed", &result))]
+    async fn create_calendar_feed(&self) -> ApiResult<CreateCalendarFeedResponse> {
+        let ctx = call_context();
+        let path = self.calendar_feed_service.create_feed_path(&ctx).await?;
+
+        Ok(CreateCalendarFeedResponse { path })
+    }
+
+    #[with_middleware(guard = authorize(&call_context(), &[Resource::from(&call_context())]))]
+    #[with_middleware(tail = use_canister_call_metric("revoke_calendar_feed", &result))]
+    async fn revoke_calendar_feed(&self) -> ApiResult<()> {
+        let ctx = call_context();
+        self.calendar_feed_service.revoke_feed(&ctx)?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use candid::Principal;
+    use station_api::OnboardingStepDTO;
 
     use crate::{
         core::{set_mock_caller, test_utils, validation::disable_mock_resource_validation},
@@ -134,6 +328,7 @@ mod tests {
                 identities: vec![identity],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                approve_only_identities: vec![],
             })
             .expect("Failed to add user");
 
@@ -155,6 +350,7 @@ mod tests {
                 identities: vec![identity],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                approve_only_identities: vec![],
             })
             .expect("Failed to add user");
 
@@ -169,4 +365,55 @@ mod tests {
             "Non existent group should be ignored"
         );
     }
+
+    #[tokio::test]
+    async fn report_onboarding_step_completes_the_checklist() {
+        let ctx = setup();
+        disable_mock_resource_validation();
+
+        let identity = Principal::from_slice(&[1; 29]);
+        ctx.user_service
+            .add_user(AddUserOperationInput {
+                groups: vec![],
+                identities: vec![identity],
+                name: "user-1".to_string(),
+                status: UserStatus::Active,
+                approve_only_identities: vec![],
+            })
+            .expect("Failed to add user");
+
+        set_mock_caller(identity);
+        let response = CONTROLLER.me().await.unwrap();
+        assert_eq!(
+            response.onboarding.next_step,
+            Some(OnboardingStepDTO::IdentityConfirmed)
+        );
+
+        let response = CONTROLLER
+            .report_onboarding_step(ReportOnboardingStepInput {
+                step: OnboardingStepDTO::NotificationPreferencesSet,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.onboarding.next_step,
+            Some(OnboardingStepDTO::JoinedGroups)
+        );
+        assert!(response.onboarding.steps.iter().all(|status| {
+            status.completed_at.is_some()
+                == matches!(
+                    status.step,
+                    OnboardingStepDTO::IdentityConfirmed
+                        | OnboardingStepDTO::NotificationPreferencesSet
+                )
+        }));
+
+        CONTROLLER
+            .report_onboarding_step(ReportOnboardingStepInput {
+                step: OnboardingStepDTO::FirstVoteCast,
+            })
+            .await
+            .expect_err("Steps tracked by the station can't be reported");
+    }
 }
@@ -12,5 +12,19 @@ thread_local! {
           name: "Internet Computer".to_string(),
           metadata: Metadata::default(),
         },
+        Asset {
+          blockchain: Blockchain::Bitcoin,
+          standard: BlockchainStandard::Native,
+          symbol: "BTC".to_string(),
+          name: "Bitcoin".to_string(),
+          metadata: Metadata::default(),
+        },
+        Asset {
+          blockchain: Blockchain::Ethereum,
+          standard: BlockchainStandard::Native,
+          symbol: "ETH".to_string(),
+          name: "Ethereum".to_string(),
+          metadata: Metadata::default(),
+        },
       ].into_iter().collect());
 }
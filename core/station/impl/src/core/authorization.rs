@@ -10,7 +10,7 @@ use crate::{
             NotificationResourceAction, RequestResourceAction, Resource, ResourceId,
             UserResourceAction,
         },
-        NotificationKey, User,
+        NotificationKey, RequestId, User, ADMIN_GROUP_ID,
     },
     repositories::{NOTIFICATION_REPOSITORY, REQUEST_REPOSITORY},
     services::permission::PERMISSION_SERVICE,
@@ -26,6 +26,16 @@ impl Authorization {
             return true;
         }
 
+        // Approve-only identities are restricted to reading and voting, whatever their permissions.
+        if ctx.caller_is_approve_only() && resource.changes_configuration() {
+            return false;
+        }
+
+        // The permissions to read requests don't apply to the confidential ones.
+        if let Some(is_visible) = is_confidential_request_visible(ctx, resource) {
+            return is_visible;
+        }
+
         // Gets the expanded list of resources.
         // e.g. if the resource is for account(1), then the list will expand to [account(1), account(any)]
         let resources = resource.to_expanded_list();
@@ -62,6 +72,38 @@ impl Authorization {
             false
         })
     }
+
+    /// Returns true if the request is confidential and the caller can't read it, the records derived
+    /// from the request (e.g. its transfers) are then hidden from the caller as well, whatever the
+    /// permissions of the caller on them.
+    pub fn is_confidential_request_hidden(ctx: &CallContext, request_id: &RequestId) -> bool {
+        !ctx.caller_is_controller_or_self()
+            && is_confidential_request_visible(
+                ctx,
+                &Resource::Request(RequestResourceAction::Read(ResourceId::Id(*request_id))),
+            ) == Some(false)
+    }
+}
+
+/// Returns whether the caller can read the request if the resource is a confidential request, only
+/// active admins and the users with default access to the request (e.g. the requester) can read it.
+fn is_confidential_request_visible(ctx: &CallContext, resource: &Resource) -> Option<bool> {
+    let Resource::Request(RequestResourceAction::Read(ResourceId::Id(request_id))) = resource
+    else {
+        return None;
+    };
+
+    if !REQUEST_REPOSITORY
+        .find_indexed_fields_by_request_id(request_id)?
+        .confidential
+    {
+        return None;
+    }
+
+    Some(ctx.user().is_some_and(|user| {
+        user.is_active()
+            && (user.groups.contains(ADMIN_GROUP_ID) || has_default_resource_access(user, resource))
+    }))
 }
 
 /// Checks if the user had access to the resource based on default rules (non-permission based).
@@ -141,6 +183,7 @@ mod tests {
         models::{
             account_test_utils,
             permission::{Allow, Permission},
+            request_test_utils::mock_request,
             resource::{AccountResourceAction, ResourceAction},
             user_group_test_utils,
             user_test_utils::{self, mock_user},
@@ -219,6 +262,83 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn approve_only_identity_cannot_change_the_configuration() {
+        let mut test_context = setup();
+        for resource in [
+            Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+            Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        ] {
+            let permission =
+                Permission::new(Allow::users(vec![test_context.finance_user.id]), resource);
+            PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+        }
+
+        let hot_identity = Principal::from_slice(&[4; 29]);
+        test_context.finance_user.identities.push(hot_identity);
+        test_context.finance_user.approve_only_identities = vec![hot_identity];
+        USER_REPOSITORY.insert(
+            test_context.finance_user.to_key(),
+            test_context.finance_user.clone(),
+        );
+
+        let cold_ctx = CallContext::new(test_context.finance_user.identities[0]);
+        let hot_ctx = CallContext::new(hot_identity);
+
+        let transfer = Resource::Account(AccountResourceAction::Transfer(ResourceId::Any));
+        assert!(Authorization::is_allowed(&cold_ctx, &transfer));
+        assert!(!Authorization::is_allowed(&hot_ctx, &transfer));
+
+        for resource in [
+            Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        ] {
+            assert!(Authorization::is_allowed(&cold_ctx, &resource));
+            assert!(Authorization::is_allowed(&hot_ctx, &resource));
+        }
+    }
+
+    #[tokio::test]
+    async fn confidential_request_is_hidden_from_readers() {
+        let test_context = setup();
+        let permission = Permission::new(
+            Allow::user_groups(vec![test_context.finance_user_group.id]),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission.to_owned());
+
+        let mut request = mock_request();
+        request.requested_by = [3; 16];
+        request.approvals = vec![];
+        request.confidential = true;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.to_owned());
+
+        let resource = Resource::Request(RequestResourceAction::Read(ResourceId::Id(request.id)));
+
+        assert!(!Authorization::is_allowed(
+            &CallContext::new(test_context.finance_user.identities[0]),
+            &resource
+        ));
+        // the admin and the requester
+        assert!(Authorization::is_allowed(
+            &CallContext::new(Principal::from_slice(&[1; 29])),
+            &resource
+        ));
+        assert!(Authorization::is_allowed(
+            &CallContext::new(Principal::from_slice(&[3; 29])),
+            &resource
+        ));
+
+        request.confidential = false;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.to_owned());
+
+        assert!(Authorization::is_allowed(
+            &CallContext::new(test_context.finance_user.identities[0]),
+            &resource
+        ));
+    }
+
     #[tokio::test]
     async fn fail_user_has_access_to_admin_resource() {
         let admin_access = Permission::new(
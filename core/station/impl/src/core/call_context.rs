@@ -2,6 +2,7 @@ use crate::core::ic_cdk::api::{id as self_canister_id, is_controller};
 use crate::models::User;
 use crate::repositories::USER_REPOSITORY;
 use candid::Principal;
+use orbit_essentials::types::Timestamp;
 
 #[cfg(not(test))]
 use ic_cdk::api::caller;
@@ -61,6 +62,29 @@ impl CallContext {
         }
     }
 
+    /// Creates the context of the given user, which is used to evaluate what is visible to the user
+    /// without a call from one of its identities.
+    pub fn of_user(user: User) -> Self {
+        Self {
+            caller: user
+                .identities
+                .first()
+                .copied()
+                .unwrap_or(Principal::anonymous()),
+            user: Some(user),
+        }
+    }
+
+    /// Returns the last time the user of the caller re-authenticated from another of its identities.
+    ///
+    /// The delegation chain of a call is not exposed to canisters, so a re-authentication made by
+    /// the caller itself proves nothing about its session and is not taken into account.
+    pub fn reauthenticated_at(&self) -> Option<Timestamp> {
+        self.user
+            .as_ref()
+            .and_then(|user| user.reauthenticated_at(&self.caller))
+    }
+
     pub fn caller(&self) -> Principal {
         self.caller
     }
@@ -81,12 +105,20 @@ impl CallContext {
     pub fn caller_is_controller(&self) -> bool {
         is_controller(&self.caller)
     }
+
+    /// Returns true if the caller is an approve-only identity of its user, which can vote on
+    /// requests but can't create them or change the configuration of the station.
+    pub fn caller_is_approve_only(&self) -> bool {
+        self.user
+            .as_ref()
+            .is_some_and(|user| user.is_approve_only(&self.caller))
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::models::{user_test_utils::mock_user, ADMIN_GROUP_ID};
+    use crate::models::{user_test_utils::mock_user, UserReauthentication, ADMIN_GROUP_ID};
     use orbit_essentials::cdk::mocks::TEST_CANISTER_ID;
     use orbit_essentials::repository::Repository;
 
@@ -141,4 +173,40 @@ pub mod tests {
         let call_context = CallContext::new(caller);
         assert!(call_context.user().is_some());
     }
+
+    #[test]
+    fn check_caller_is_approve_only() {
+        let cold_identity = Principal::from_slice(&[1; 29]);
+        let hot_identity = Principal::from_slice(&[2; 29]);
+        let mut user = mock_user();
+        user.identities = vec![cold_identity, hot_identity];
+        user.approve_only_identities = vec![hot_identity];
+
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        assert!(!CallContext::new(cold_identity).caller_is_approve_only());
+        assert!(CallContext::new(hot_identity).caller_is_approve_only());
+        assert!(!CallContext::new(Principal::anonymous()).caller_is_approve_only());
+    }
+
+    #[test]
+    fn reauthentications_only_count_for_the_other_identities() {
+        let identity = Principal::from_slice(&[3; 29]);
+        let other_identity = Principal::from_slice(&[4; 29]);
+        let mut user = mock_user();
+        user.identities = vec![identity, other_identity];
+        user.reauthentication = Some(UserReauthentication { identity, at: 100 });
+
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        assert_eq!(CallContext::new(identity).reauthenticated_at(), None);
+        assert_eq!(
+            CallContext::new(other_identity).reauthenticated_at(),
+            Some(100)
+        );
+        assert_eq!(
+            CallContext::new(Principal::anonymous()).reauthenticated_at(),
+            None
+        );
+    }
 }
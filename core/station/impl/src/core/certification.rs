@@ -0,0 +1,308 @@
+//! The data certified by the station, which is served with a witness so that the callers of its query
+//! calls and HTTP requests can verify the responses without trusting the replica that answered them.
+//!
+//! The certified tree has the following branches:
+//!
+//! - `attestation`: the sha256 hash of the latest attestation of the identity of the station.
+//! - `http_expr`: the skip certification expression of the HTTP responses.
+//! - `proof_of_reserves`: the sha256 hash of the latest snapshot of the account balances.
+//! - `requests`: the sha256 hashes of the requests, labeled by their request id.
+//! - `transfer_receipts`: the sha256 hashes of the transfer receipts, labeled by their transfer id.
+//! - `transfers`: the sha256 hashes of the transfers, labeled by their transfer id.
+
+use crate::core::ic_cdk::api::set_certified_data;
+use crate::models::{RequestId, TransferId};
+use ic_certification::{fork, labeled, leaf, pruned, AsHashTree, HashTree, RbTree};
+use orbit_essentials::http::skip_certification_asset_tree;
+use std::{cell::RefCell, thread::LocalKey};
+
+pub const ATTESTATION_LABEL: &str = "attestation";
+pub const PROOF_OF_RESERVES_LABEL: &str = "proof_of_reserves";
+pub const REQUESTS_LABEL: &str = "requests";
+pub const TRANSFER_RECEIPTS_LABEL: &str = "transfer_receipts";
+pub const TRANSFERS_LABEL: &str = "transfers";
+
+type RecordHashes = RbTree<Vec<u8>, Vec<u8>>;
+
+thread_local! {
+    /// The hash of the latest attestation, reset on upgrades until the attestation is certified again.
+    static ATTESTATION_HASH: RefCell<Option<[u8; 32]>> = const { RefCell::new(None) };
+
+    /// The hash of the latest reserves snapshot, reset on upgrades until the snapshot is taken again.
+    static RESERVES_HASH: RefCell<Option<[u8; 32]>> = const { RefCell::new(None) };
+
+    /// The hashes of the transfer receipts, which are restored from stable memory on upgrades.
+    static RECEIPT_HASHES: RefCell<RecordHashes> = RefCell::new(RbTree::new());
+
+    /// The hashes of the requests, which are restored from stable memory on upgrades.
+    static REQUEST_HASHES: RefCell<RecordHashes> = RefCell::new(RbTree::new());
+
+    /// The hashes of the transfers, which are restored from stable memory on upgrades.
+    static TRANSFER_HASHES: RefCell<RecordHashes> = RefCell::new(RbTree::new());
+}
+
+fn attestation_tree() -> HashTree {
+    let attestation_hash = ATTESTATION_HASH.with(|hash| *hash.borrow());
+
+    labeled(
+        ATTESTATION_LABEL,
+        leaf(
+            attestation_hash
+                .map(|hash| hash.to_vec())
+                .unwrap_or_default(),
+        ),
+    )
+}
+
+fn reserves_tree() -> HashTree {
+    let reserves_hash = RESERVES_HASH.with(|hash| *hash.borrow());
+
+    labeled(
+        PROOF_OF_RESERVES_LABEL,
+        leaf(reserves_hash.map(|hash| hash.to_vec()).unwrap_or_default()),
+    )
+}
+
+fn records_tree(label: &str, hashes: &'static LocalKey<RefCell<RecordHashes>>) -> HashTree {
+    hashes.with(|hashes| labeled(label, hashes.borrow().as_hash_tree()))
+}
+
+/// The branch of the records with its content pruned, which is cheaper than building its tree.
+fn pruned_records_tree(label: &str, hashes: &'static LocalKey<RefCell<RecordHashes>>) -> HashTree {
+    hashes.with(|hashes| labeled(label, pruned(hashes.borrow().root_hash())))
+}
+
+/// The branch of the records revealing the hash of the record with the given id only.
+fn records_witness(
+    label: &str,
+    hashes: &'static LocalKey<RefCell<RecordHashes>>,
+    id: &[u8],
+) -> HashTree {
+    hashes.with(|hashes| labeled(label, hashes.borrow().witness(id)))
+}
+
+/// Assembles the branches of the certified tree, which are kept sorted by their labels.
+fn compose(
+    attestation: HashTree,
+    http: HashTree,
+    reserves: HashTree,
+    requests: HashTree,
+    receipts: HashTree,
+    transfers: HashTree,
+) -> HashTree {
+    fork(
+        fork(attestation, http),
+        fork(fork(reserves, requests), fork(receipts, transfers)),
+    )
+}
+
+#[cfg(test)]
+fn certified_tree() -> HashTree {
+    compose(
+        attestation_tree(),
+        skip_certification_asset_tree(),
+        reserves_tree(),
+        records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+}
+
+/// The root hash of the certified tree, computed from the root hashes of the records.
+fn certified_root_hash() -> [u8; 32] {
+    compose(
+        attestation_tree(),
+        skip_certification_asset_tree(),
+        reserves_tree(),
+        pruned_records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        pruned_records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        pruned_records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+    .digest()
+}
+
+/// Sets the root hash of the certified tree as the certified data of the canister.
+pub fn update_certified_data() {
+    set_certified_data(&certified_root_hash());
+}
+
+/// Certifies the hash of a new attestation of the station.
+pub fn certify_attestation_hash(hash: [u8; 32]) {
+    ATTESTATION_HASH.with(|attestation_hash| *attestation_hash.borrow_mut() = Some(hash));
+
+    update_certified_data();
+}
+
+/// Certifies the hash of a new reserves snapshot.
+pub fn certify_reserves_hash(hash: [u8; 32]) {
+    RESERVES_HASH.with(|reserves_hash| *reserves_hash.borrow_mut() = Some(hash));
+
+    update_certified_data();
+}
+
+/// Adds the hash of the transfer receipt to the certified tree, without updating the certified data.
+///
+/// This is used to restore the receipts after an upgrade, with a single update of the certified data.
+pub fn insert_transfer_receipt_hash(transfer_id: TransferId, hash: [u8; 32]) {
+    RECEIPT_HASHES.with(|hashes| {
+        hashes
+            .borrow_mut()
+            .insert(transfer_id.to_vec(), hash.to_vec())
+    });
+}
+
+/// Certifies the hash of a new transfer receipt.
+pub fn certify_transfer_receipt_hash(transfer_id: TransferId, hash: [u8; 32]) {
+    insert_transfer_receipt_hash(transfer_id, hash);
+
+    update_certified_data();
+}
+
+/// Adds the hash of the request to the certified tree, without updating the certified data.
+///
+/// This is used to restore the requests after an upgrade, with a single update of the certified data.
+pub fn insert_request_hash(request_id: RequestId, hash: [u8; 32]) {
+    REQUEST_HASHES.with(|hashes| {
+        hashes
+            .borrow_mut()
+            .insert(request_id.to_vec(), hash.to_vec())
+    });
+}
+
+/// Certifies the hash of the latest version of the request.
+pub fn certify_request_hash(request_id: RequestId, hash: [u8; 32]) {
+    insert_request_hash(request_id, hash);
+
+    update_certified_data();
+}
+
+/// Removes the hash of the request from the certified tree, e.g. once it is archived.
+pub fn remove_request_hash(request_id: &RequestId) {
+    REQUEST_HASHES.with(|hashes| hashes.borrow_mut().delete(request_id.as_slice()));
+
+    update_certified_data();
+}
+
+/// Adds the hash of the transfer to the certified tree, without updating the certified data.
+///
+/// This is used to restore the transfers after an upgrade, with a single update of the certified data.
+pub fn insert_transfer_hash(transfer_id: TransferId, hash: [u8; 32]) {
+    TRANSFER_HASHES.with(|hashes| {
+        hashes
+            .borrow_mut()
+            .insert(transfer_id.to_vec(), hash.to_vec())
+    });
+}
+
+/// Certifies the hash of the latest version of the transfer.
+pub fn certify_transfer_hash(transfer_id: TransferId, hash: [u8; 32]) {
+    insert_transfer_hash(transfer_id, hash);
+
+    update_certified_data();
+}
+
+/// Removes the hash of the transfer from the certified tree, e.g. once it is archived.
+pub fn remove_transfer_hash(transfer_id: &TransferId) {
+    TRANSFER_HASHES.with(|hashes| hashes.borrow_mut().delete(transfer_id.as_slice()));
+
+    update_certified_data();
+}
+
+/// The witness of the HTTP responses, which reveals the `http_expr` branch only.
+pub fn http_witness() -> HashTree {
+    compose(
+        pruned(attestation_tree().digest()),
+        skip_certification_asset_tree(),
+        pruned(reserves_tree().digest()),
+        pruned_records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        pruned_records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        pruned_records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+}
+
+/// The witness of the attestation, which reveals the `attestation` branch only.
+pub fn attestation_witness() -> HashTree {
+    compose(
+        attestation_tree(),
+        pruned(skip_certification_asset_tree().digest()),
+        pruned(reserves_tree().digest()),
+        pruned_records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        pruned_records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        pruned_records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+}
+
+/// The witness of the proof of reserves, which reveals the `proof_of_reserves` branch only.
+pub fn reserves_witness() -> HashTree {
+    compose(
+        pruned(attestation_tree().digest()),
+        pruned(skip_certification_asset_tree().digest()),
+        reserves_tree(),
+        pruned_records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        pruned_records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        pruned_records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+}
+
+/// The witness of a transfer receipt, which reveals the hash of the receipt of the transfer only.
+pub fn transfer_receipt_witness(transfer_id: &TransferId) -> HashTree {
+    compose(
+        pruned(attestation_tree().digest()),
+        pruned(skip_certification_asset_tree().digest()),
+        pruned(reserves_tree().digest()),
+        pruned_records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        records_witness(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES, transfer_id),
+        pruned_records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+}
+
+/// The witness of a request, which reveals the hash of the request only.
+pub fn request_witness(request_id: &RequestId) -> HashTree {
+    compose(
+        pruned(attestation_tree().digest()),
+        pruned(skip_certification_asset_tree().digest()),
+        pruned(reserves_tree().digest()),
+        records_witness(REQUESTS_LABEL, &REQUEST_HASHES, request_id),
+        pruned_records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        pruned_records_tree(TRANSFERS_LABEL, &TRANSFER_HASHES),
+    )
+}
+
+/// The witness of a transfer, which reveals the hash of the transfer only.
+pub fn transfer_witness(transfer_id: &TransferId) -> HashTree {
+    compose(
+        pruned(attestation_tree().digest()),
+        pruned(skip_certification_asset_tree().digest()),
+        pruned(reserves_tree().digest()),
+        pruned_records_tree(REQUESTS_LABEL, &REQUEST_HASHES),
+        pruned_records_tree(TRANSFER_RECEIPTS_LABEL, &RECEIPT_HASHES),
+        records_witness(TRANSFERS_LABEL, &TRANSFER_HASHES, transfer_id),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn witnesses_have_the_certified_root_hash() {
+        certify_attestation_hash([6; 32]);
+        certify_reserves_hash([7; 32]);
+        certify_transfer_receipt_hash([1; 16], [8; 32]);
+        certify_transfer_receipt_hash([2; 16], [9; 32]);
+        certify_request_hash([3; 16], [10; 32]);
+        certify_transfer_hash([4; 16], [11; 32]);
+        certify_transfer_hash([5; 16], [12; 32]);
+        remove_transfer_hash(&[5; 16]);
+
+        let root_hash = certified_tree().digest();
+
+        assert_eq!(certified_root_hash(), root_hash);
+        assert_eq!(http_witness().digest(), root_hash);
+        assert_eq!(attestation_witness().digest(), root_hash);
+        assert_eq!(reserves_witness().digest(), root_hash);
+        assert_eq!(transfer_receipt_witness(&[1; 16]).digest(), root_hash);
+        assert_eq!(request_witness(&[3; 16]).digest(), root_hash);
+        assert_eq!(transfer_witness(&[4; 16]).digest(), root_hash);
+    }
+}
@@ -34,6 +34,10 @@ pub const SYSTEM_RESERVED_MEMORY_BYTES: u32 = SYSTEM_RESERVED_MEMORY_PAGES * WAS
 /// updated to support storing more buckets in a backwards compatible way.
 pub const STABLE_MEMORY_BUCKET_SIZE: u16 = (MIB / WASM_PAGE_SIZE) as u16;
 
+/// The stable memory the managed memories can grow to, bounded by the 32768 buckets of the memory manager.
+pub const STABLE_MEMORY_CAPACITY: u64 =
+    32_768 * STABLE_MEMORY_BUCKET_SIZE as u64 * WASM_PAGE_SIZE as u64;
+
 /// The maximum number of Wasm pages that we allow to use for the stable storage.
 pub const MAX_WASM_PAGES: u64 = MAX_STABLE_MEMORY_SIZE / WASM_PAGE_SIZE as u64;
 
@@ -33,6 +33,11 @@ lazy_static! {
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
             Resource::System(SystemResourceAction::Upgrade),
         ),
+        // Admins can audit the pending transfers against the blockchain history
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::System(SystemResourceAction::AuditOutflows),
+        ),
         // users
         (
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
@@ -50,6 +55,12 @@ lazy_static! {
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
             Resource::User(UserResourceAction::Update(ResourceId::Any)),
         ),
+        // Admins can view what other users can see to debug their access, the permission can be
+        // granted to a support group instead
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::User(UserResourceAction::ViewAs(ResourceId::Any)),
+        ),
         // user groups
         (
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
@@ -131,6 +142,27 @@ lazy_static! {
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
             Resource::AddressBook(ResourceAction::Delete(ResourceId::Any)),
         ),
+        // assets
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Create),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::List),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+        ),
+        (
+            Allow::user_groups(vec![*ADMIN_GROUP_ID]),
+            Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
+        ),
         // accounts
         (
             Allow::user_groups(vec![*ADMIN_GROUP_ID]),
@@ -208,6 +240,19 @@ pub fn default_policies(admin_quorum: u16) -> Vec<(RequestSpecifier, RequestPoli
             RequestSpecifier::RemoveAddressBookEntry(ResourceIds::Any),
             RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), admin_quorum),
         ),
+        // assets
+        (
+            RequestSpecifier::AddAsset,
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), admin_quorum),
+        ),
+        (
+            RequestSpecifier::EditAsset(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), admin_quorum),
+        ),
+        (
+            RequestSpecifier::RemoveAsset(ResourceIds::Any),
+            RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), admin_quorum),
+        ),
         // permissions
         (
             RequestSpecifier::EditPermission(ResourceSpecifier::Any),
@@ -1,8 +1,10 @@
-use super::{MAX_WASM_PAGES, STABLE_MEMORY_BUCKET_SIZE, SYSTEM_RESERVED_MEMORY_PAGES};
+use super::{
+    MAX_WASM_PAGES, STABLE_MEMORY_BUCKET_SIZE, SYSTEM_RESERVED_MEMORY_PAGES, WASM_PAGE_SIZE,
+};
 use crate::models::system::{SystemInfo, SystemState};
 use ic_stable_structures::{
     memory_manager::{MemoryId, MemoryManager},
-    Cell, DefaultMemoryImpl, RestrictedMemory,
+    Cell, DefaultMemoryImpl, Memory as _, RestrictedMemory,
 };
 use std::cell::RefCell;
 
@@ -29,6 +31,30 @@ pub const REQUEST_RESOURCE_INDEX_MEMORY_ID: MemoryId = MemoryId::new(30);
 pub const POLICY_RESOURCE_INDEX_MEMORY_ID: MemoryId = MemoryId::new(31);
 pub const REQUEST_EVALUATION_RESULT_MEMORY_ID: MemoryId = MemoryId::new(32);
 pub const EXTERNAL_CANISTER_MEMORY_ID: MemoryId = MemoryId::new(33);
+pub const FUNDING_REQUEST_MEMORY_ID: MemoryId = MemoryId::new(34);
+pub const SUPPORT_ACCESS_LOG_MEMORY_ID: MemoryId = MemoryId::new(35);
+pub const ACCOUNT_TRANSACTION_MEMORY_ID: MemoryId = MemoryId::new(36);
+pub const SPENDING_AGGREGATE_MEMORY_ID: MemoryId = MemoryId::new(37);
+pub const REGISTERED_ASSET_MEMORY_ID: MemoryId = MemoryId::new(38);
+pub const SCHEDULED_POLICY_CHANGE_MEMORY_ID: MemoryId = MemoryId::new(39);
+pub const REQUEST_VIEW_MEMORY_ID: MemoryId = MemoryId::new(40);
+pub const SCHEDULED_TRANSFER_MEMORY_ID: MemoryId = MemoryId::new(41);
+pub const CAPABILITY_GRANT_MEMORY_ID: MemoryId = MemoryId::new(42);
+pub const ACCOUNT_SPEND_MEMORY_ID: MemoryId = MemoryId::new(43);
+pub const TRANSFER_METADATA_INDEX_MEMORY_ID: MemoryId = MemoryId::new(44);
+pub const TRANSFER_ACCOUNT_STATUS_INDEX_MEMORY_ID: MemoryId = MemoryId::new(45);
+pub const TRANSFER_DESTINATION_INDEX_MEMORY_ID: MemoryId = MemoryId::new(46);
+pub const TRANSFER_RECEIPT_MEMORY_ID: MemoryId = MemoryId::new(47);
+pub const LOCALE_CATALOG_MEMORY_ID: MemoryId = MemoryId::new(48);
+pub const TRANSFER_CATEGORY_MEMORY_ID: MemoryId = MemoryId::new(49);
+pub const TRANSFER_CATEGORY_INDEX_MEMORY_ID: MemoryId = MemoryId::new(50);
+pub const ADDRESS_BOOK_LABEL_MEMORY_ID: MemoryId = MemoryId::new(51);
+pub const REQUEST_COMMENT_MEMORY_ID: MemoryId = MemoryId::new(52);
+pub const PAYOUT_RUN_MEMORY_ID: MemoryId = MemoryId::new(53);
+pub const DESTINATION_FIRST_USE_MEMORY_ID: MemoryId = MemoryId::new(54);
+pub const NOTIFICATION_TEMPLATE_MEMORY_ID: MemoryId = MemoryId::new(55);
+pub const REQUEST_TERM_INDEX_MEMORY_ID: MemoryId = MemoryId::new(56);
+pub const ARCHIVED_CHUNK_MEMORY_ID: MemoryId = MemoryId::new(57);
 
 thread_local! {
   /// Static configuration of the canister.
@@ -46,6 +72,11 @@ pub fn with_memory_manager<R>(f: impl FnOnce(&MemoryManager<Memory>) -> R) -> R
     MEMORY_MANAGER.with(|cell| f(&cell.borrow()))
 }
 
+/// The number of bytes of stable memory currently allocated to the canister.
+pub fn stable_memory_used_bytes() -> u64 {
+    DefaultMemoryImpl::default().size() * WASM_PAGE_SIZE as u64
+}
+
 /// Reserve the first stable memory page for the configuration stable cell.
 pub fn system_state_memory() -> Memory {
     RestrictedMemory::new(
@@ -6,8 +6,12 @@ use crate::{
     },
     SERVICE_NAME,
 };
+use ic_stable_structures::Storable;
 use orbit_essentials::{
-    metrics::{labels, ApplicationGaugeMetric, ApplicationGaugeVecMetric, ApplicationMetric},
+    metrics::{
+        labels, with_metrics_registry, ApplicationGaugeMetric, ApplicationGaugeVecMetric,
+        ApplicationMetric,
+    },
     utils::amount_to_f64,
 };
 use orbit_essentials::{
@@ -144,6 +148,82 @@ pub fn metrics_observe_remove_transfer(observer: &mut Observer<Transfer>) {
     }));
 }
 
+/// Records a value written to the repository, tracking the write rate and the size of the stored values
+/// to forecast the growth of the stable memory.
+///
+/// The value is encoded once more to measure it, so this should only be used for the repositories that
+/// are worth watching.
+pub fn observe_repository_write<Value: Storable>(repository: &str, value: &Value) {
+    let value_size = value.to_bytes().len() as f64;
+    let repository_labels = labels! { "repository" => repository };
+
+    with_metrics_registry(SERVICE_NAME, |registry| {
+        let writes = registry.counter_vec_mut(
+            "repository_writes",
+            &["repository"],
+            "The number of values written to the repository.",
+        );
+        writes.with(&repository_labels).inc();
+        let total_writes = writes.with(&repository_labels).get();
+
+        let written_bytes = registry.counter_vec_mut(
+            "repository_written_bytes",
+            &["repository"],
+            "The total size in bytes of the values written to the repository.",
+        );
+        written_bytes.with(&repository_labels).inc_by(value_size);
+        let total_written_bytes = written_bytes.with(&repository_labels).get();
+
+        registry
+            .gauge_vec_mut(
+                "repository_average_value_size_bytes",
+                "The average size in bytes of the values written to the repository.",
+                &["repository"],
+            )
+            .with(&repository_labels)
+            .set(total_written_bytes / total_writes);
+    });
+}
+
+/// Records a range scan of the repository with the number of entries it went through, making the
+/// scans that grow with the stored data visible before they run out of instructions.
+pub fn observe_repository_scan(repository: &str, scanned_entries: usize) {
+    let scanned_entries = scanned_entries as f64;
+    let repository_labels = labels! { "repository" => repository };
+
+    with_metrics_registry(SERVICE_NAME, |registry| {
+        registry
+            .counter_vec_mut(
+                "repository_scans",
+                &["repository"],
+                "The number of range scans of the repository.",
+            )
+            .with(&repository_labels)
+            .inc();
+
+        registry
+            .counter_vec_mut(
+                "repository_scanned_entries",
+                &["repository"],
+                "The total number of entries that range scans of the repository went through.",
+            )
+            .with(&repository_labels)
+            .inc_by(scanned_entries);
+
+        let max_scan_length = registry
+            .gauge_vec_mut(
+                "repository_max_scan_length",
+                "The largest number of entries a single range scan of the repository went through.",
+                &["repository"],
+            )
+            .with(&repository_labels);
+
+        if scanned_entries > max_scan_length.get() {
+            max_scan_length.set(scanned_entries);
+        }
+    });
+}
+
 /// Metric for the number of users that have been registered, labeled by their status.
 pub struct MetricTotalUsers;
 
@@ -673,4 +753,53 @@ mod tests {
 
         assert_eq!(MetricTotalPolicies.get(SERVICE_NAME), 2.0);
     }
+
+    #[test]
+    fn test_repository_write_and_scan_metrics() {
+        let mut transfer = mock_transfer();
+        let value_size = transfer.to_bytes().len() as f64;
+        let repository_labels = labels! { "repository" => "transfers" };
+
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+        transfer.status = TransferStatus::Processing { started_at: 1 };
+        let updated_value_size = transfer.to_bytes().len() as f64;
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+
+        with_metrics_registry(SERVICE_NAME, |registry| {
+            assert_eq!(
+                registry
+                    .counter_vec_mut("repository_writes", &["repository"], "")
+                    .with(&repository_labels)
+                    .get(),
+                2.0
+            );
+            assert_eq!(
+                registry
+                    .gauge_vec_mut("repository_average_value_size_bytes", "", &["repository"])
+                    .with(&repository_labels)
+                    .get(),
+                (value_size + updated_value_size) / 2.0
+            );
+        });
+
+        observe_repository_scan("transfers", 3);
+        observe_repository_scan("transfers", 1);
+
+        with_metrics_registry(SERVICE_NAME, |registry| {
+            assert_eq!(
+                registry
+                    .counter_vec_mut("repository_scanned_entries", &["repository"], "")
+                    .with(&repository_labels)
+                    .get(),
+                4.0
+            );
+            assert_eq!(
+                registry
+                    .gauge_vec_mut("repository_max_scan_length", "", &["repository"])
+                    .with(&repository_labels)
+                    .get(),
+                3.0
+            );
+        });
+    }
 }
@@ -1,8 +1,8 @@
 use super::authorization::Authorization;
 use super::CallContext;
 use crate::core::ic_cdk::api::trap;
-use crate::models::resource::Resource;
-use crate::services::SYSTEM_SERVICE;
+use crate::models::{resource::Resource, CapabilityScope};
+use crate::services::{CAPABILITY_GRANT_SERVICE, SYSTEM_SERVICE};
 use crate::SERVICE_NAME;
 use orbit_essentials::api::ApiResult;
 use orbit_essentials::metrics::{labels, with_metrics_registry};
@@ -46,6 +46,28 @@ pub fn authorize(ctx: &CallContext, resources: &[Resource]) {
     }
 }
 
+/// Middleware to authorize a read query that can also be called with a capability grant
+///
+/// The holder of an active grant of the scope for the account of every requested resource is let
+/// through, the other callers are authorized with their permissions.
+pub fn authorize_with_capability(
+    ctx: &CallContext,
+    scope: CapabilityScope,
+    resources: &[Resource],
+) {
+    let granted = !resources.is_empty()
+        && resources
+            .iter()
+            .all(|resource| CAPABILITY_GRANT_SERVICE.is_granted(&ctx.caller(), &scope, resource));
+
+    if granted {
+        SYSTEM_SERVICE.assert_system_readiness();
+        return;
+    }
+
+    authorize(ctx, resources);
+}
+
 pub fn use_canister_call_metric<T>(called_method: &str, result: &ApiResult<T>)
 where
     T: std::fmt::Debug,
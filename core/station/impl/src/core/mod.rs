@@ -8,6 +8,8 @@ pub use constants::*;
 
 pub mod cache;
 
+pub mod certification;
+
 pub mod limiter;
 
 mod memory;
@@ -46,20 +48,4 @@ pub mod request;
 pub mod utils;
 
 #[cfg(test)]
-pub mod test_utils {
-    use crate::core::write_system_info;
-    use crate::models::system::SystemInfo;
-    use candid::Principal;
-
-    pub const UPGRADER_CANISTER_ID: [u8; 29] = [25; 29];
-
-    pub fn init_canister_system() -> SystemInfo {
-        let mut system: SystemInfo = SystemInfo::default();
-        system
-            .set_upgrader_canister_id(Principal::from_slice(self::UPGRADER_CANISTER_ID.as_slice()));
-
-        write_system_info(system.clone());
-
-        system
-    }
-}
+pub mod test_utils;
@@ -1,14 +1,14 @@
-use super::evaluation::Evaluate;
+use super::{evaluation::Evaluate, ic_cdk::api::time};
 use crate::{
     errors::EvaluateError,
     models::{
         indexes::request_index::RequestIndexFields,
         request_policy_rule::{
-            EvaluateRequestPolicyRule, RequestEvaluationResult, RequestPolicyRule,
-            RequestPolicyRuleResult,
+            EvaluateRequestPolicyRule, EvaluatedRequestPolicyRule, EvaluationCacheKey,
+            RequestEvaluationResult, RequestPolicyRule, RequestPolicyRuleResult,
         },
         request_specifier::{Match, UserInvolvedInPolicyRuleForRequestResource, UserSpecifier},
-        EvaluationStatus, Request, RequestId, User, UserId, UserStatus,
+        EvaluationStatus, Request, RequestId, RequestPolicy, User, UserId, UserStatus,
     },
     repositories::{
         request_policy::REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY, USER_REPOSITORY,
@@ -16,7 +16,79 @@ use crate::{
 };
 use anyhow::Context;
 use orbit_essentials::{repository::Repository, types::UUID};
-use std::{collections::HashSet, sync::Arc};
+use std::{cell::Cell, collections::HashSet, sync::Arc};
+
+thread_local! {
+    /// Incremented by the changes that can alter the evaluation of the votes already cast, the
+    /// cached evaluations of the requests are only reused within the same generation.
+    static EVALUATION_GENERATION: Cell<u64> = const { Cell::new(0) };
+    /// The time this canister instance started evaluating requests, the generation starts over
+    /// after an upgrade so the evaluations cached before it are told apart by the epoch.
+    static EVALUATION_EPOCH: u64 = time();
+}
+
+/// Invalidates the cached evaluations of all the requests, their next vote evaluates their
+/// policies in full.
+pub fn invalidate_cached_evaluations() {
+    EVALUATION_GENERATION.with(|generation| generation.set(generation.get() + 1));
+}
+
+fn evaluation_cache_key(request: &Request) -> EvaluationCacheKey {
+    EvaluationCacheKey {
+        epoch: EVALUATION_EPOCH.with(|epoch| *epoch),
+        generation: EVALUATION_GENERATION.with(Cell::get),
+        approvals_count: request.approvals.len(),
+    }
+}
+
+fn find_matching_policies(request: &Request) -> Vec<RequestPolicy> {
+    request
+        .operation
+        .to_resources()
+        .iter()
+        .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
+        .collect()
+}
+
+fn aggregate_status(evaluation_statuses: &[RequestPolicyRuleResult]) -> EvaluationStatus {
+    if evaluation_statuses
+        .iter()
+        .any(RequestPolicyRuleResult::is_vetoed)
+    {
+        // A veto takes precedence over all the policies, whatever their progress.
+        return EvaluationStatus::Rejected;
+    }
+
+    // The policies that are only a veto never approve the request on their own.
+    let evaluation_statuses = evaluation_statuses
+        .iter()
+        .filter(|result| {
+            !matches!(
+                result.evaluated_rule,
+                EvaluatedRequestPolicyRule::Veto { .. }
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if evaluation_statuses
+        .iter()
+        .any(|result| result.status == EvaluationStatus::Approved)
+    {
+        // If any policy of the request is approved, then the request is approved.
+        EvaluationStatus::Approved
+    } else if evaluation_statuses
+        .iter()
+        .all(|result| result.status == EvaluationStatus::Rejected)
+    {
+        // Only if all policies are rejected then the request is rejected,
+        // this applies an implicit `OR` between policies.
+        EvaluationStatus::Rejected
+    } else {
+        // Since there are matching policies, but none of them approved or rejected the request, we keep it in the
+        // pending status until one of the policies evaluates it as approved or rejected.
+        EvaluationStatus::Pending
+    }
+}
 
 pub struct RequestEvaluator {
     pub policy_rule_evaluator: Arc<dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>>,
@@ -37,13 +109,7 @@ impl RequestEvaluator {
 
 impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
     fn evaluate(&self) -> Result<RequestEvaluationResult, EvaluateError> {
-        let matching_policies = self
-            .request
-            .operation
-            .to_resources()
-            .iter()
-            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource.to_owned()))
-            .collect::<Vec<_>>();
+        let matching_policies = find_matching_policies(&self.request);
 
         if matching_policies.is_empty() {
             // Since requests handle security critical operations, we want to reject them by default if
@@ -52,6 +118,7 @@ impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
                 request_id: self.request.id,
                 status: EvaluationStatus::Rejected,
                 policy_results: vec![],
+                cache_key: Some(evaluation_cache_key(&self.request)),
             });
         }
 
@@ -71,27 +138,107 @@ impl Evaluate<RequestEvaluationResult> for RequestEvaluator {
 
         Ok(RequestEvaluationResult {
             request_id: self.request.id,
-            status: {
-                if evaluation_statuses
-                    .iter()
-                    .any(|result| result.status == EvaluationStatus::Approved)
-                {
-                    // If any policy of the request is approved, then the request is approved.
-                    EvaluationStatus::Approved
-                } else if evaluation_statuses
-                    .iter()
-                    .all(|result| result.status == EvaluationStatus::Rejected)
-                {
-                    // Only if all policies are rejected then the request is rejected,
-                    // this applies an implicit `OR` between policies.
-                    EvaluationStatus::Rejected
-                } else {
-                    // Since there are matching policies, but none of them approved or rejected the request, we keep it in the
-                    // pending status until one of the policies evaluates it as approved or rejected.
-                    EvaluationStatus::Pending
-                }
-            },
+            status: aggregate_status(&evaluation_statuses),
             policy_results: evaluation_statuses,
+            cache_key: Some(evaluation_cache_key(&self.request)),
+        })
+    }
+}
+
+/// Evaluates the request after a vote was added to it by applying the vote to its previous
+/// evaluation, so that only the rules the vote can change are evaluated again.
+///
+/// Falls back to a full evaluation when the previous one is missing or no longer current, e.g.
+/// because the policies or the users changed since.
+pub struct RequestVoteEvaluator {
+    pub policy_rule_evaluator: Arc<dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>>,
+    pub request: Request,
+    pub previous_evaluation: Option<RequestEvaluationResult>,
+    pub voter_id: UserId,
+}
+
+impl RequestVoteEvaluator {
+    pub fn new(
+        policy_rule_evaluator: Arc<dyn EvaluateRequestPolicyRule<RequestPolicyRuleResult>>,
+        request: Request,
+        previous_evaluation: Option<RequestEvaluationResult>,
+        voter_id: UserId,
+    ) -> Self {
+        Self {
+            policy_rule_evaluator,
+            request,
+            previous_evaluation,
+            voter_id,
+        }
+    }
+
+    /// The previous evaluation can be reused if it was computed in the current generation and the
+    /// vote of the voter is the only one added to the request since.
+    fn is_reusable(&self, previous_evaluation: &RequestEvaluationResult) -> bool {
+        let Some(approvals_count) = self.request.approvals.len().checked_sub(1) else {
+            return false;
+        };
+
+        previous_evaluation.request_id == self.request.id
+            && previous_evaluation.cache_key
+                == Some(EvaluationCacheKey {
+                    approvals_count,
+                    ..evaluation_cache_key(&self.request)
+                })
+            && self
+                .request
+                .approvals
+                .last()
+                .is_some_and(|approval| approval.approver_id == self.voter_id)
+    }
+}
+
+impl Evaluate<RequestEvaluationResult> for RequestVoteEvaluator {
+    fn evaluate(&self) -> Result<RequestEvaluationResult, EvaluateError> {
+        let full_evaluation = || {
+            RequestEvaluator::new(self.policy_rule_evaluator.clone(), self.request.to_owned())
+                .evaluate()
+        };
+
+        let Some(previous_evaluation) = self
+            .previous_evaluation
+            .as_ref()
+            .filter(|previous_evaluation| self.is_reusable(previous_evaluation))
+        else {
+            return full_evaluation();
+        };
+
+        let matching_policies = find_matching_policies(&self.request);
+        if matching_policies.is_empty()
+            || matching_policies.len() != previous_evaluation.policy_results.len()
+        {
+            return full_evaluation();
+        }
+
+        let request = Arc::new(self.request.to_owned());
+        let mut evaluation_statuses = Vec::new();
+
+        for (policy, previous) in matching_policies
+            .into_iter()
+            .zip(&previous_evaluation.policy_results)
+        {
+            let evaluation_status = self
+                .policy_rule_evaluator
+                .apply_vote(
+                    (request.to_owned(), Arc::new(policy.rule)),
+                    previous,
+                    &self.voter_id,
+                )
+                .context("failed to apply the vote to the policy rule")?;
+
+            evaluation_statuses.push(evaluation_status);
+        }
+
+        Ok(RequestEvaluationResult {
+            request_id: self.request.id,
+            status: aggregate_status(&evaluation_statuses),
+            policy_results: evaluation_statuses,
+            cache_key: Some(evaluation_cache_key(&self.request)),
         })
     }
 }
@@ -211,8 +358,10 @@ impl
     ) -> Result<PossibleApprovers, EvaluateError> {
         let mut possible_approvers = PossibleApprovers::default();
         match criteria.as_ref() {
+            // the users of a veto can vote to reject the request
             RequestPolicyRule::QuorumPercentage(approver_specifier, _)
-            | RequestPolicyRule::Quorum(approver_specifier, _) => match approver_specifier {
+            | RequestPolicyRule::Quorum(approver_specifier, _)
+            | RequestPolicyRule::Veto(approver_specifier) => match approver_specifier {
                 UserSpecifier::Any => {
                     possible_approvers.match_all = true;
 
@@ -229,9 +378,14 @@ impl
                     Ok(possible_approvers)
                 }
             },
-            RequestPolicyRule::AllowListed | RequestPolicyRule::AllowListedByMetadata(_) => {
-                Ok(possible_approvers)
-            }
+            RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListedByLabel(_)
+            | RequestPolicyRule::TrustedDestination
+            | RequestPolicyRule::RecentAuthentication(_)
+            | RequestPolicyRule::VelocityLimit(_)
+            | RequestPolicyRule::AmountRange(_)
+            | RequestPolicyRule::DestinationAge(_) => Ok(possible_approvers),
             RequestPolicyRule::And(criterias) | RequestPolicyRule::Or(criterias) => {
                 for criteria in criterias.iter() {
                     let result = self.evaluate((request.clone(), Arc::new(criteria.clone())));
@@ -342,7 +496,8 @@ impl
     ) -> Result<bool, EvaluateError> {
         match criteria.as_ref() {
             RequestPolicyRule::QuorumPercentage(approver_specifier, _)
-            | RequestPolicyRule::Quorum(approver_specifier, _) => {
+            | RequestPolicyRule::Quorum(approver_specifier, _)
+            | RequestPolicyRule::Veto(approver_specifier) => {
                 let can_approve = self
                     .approver_matcher
                     .is_match(UserInvolvedInPolicyRuleForRequestResource {
@@ -355,9 +510,14 @@ impl
 
                 Ok(can_approve)
             }
-            RequestPolicyRule::AllowListed | RequestPolicyRule::AllowListedByMetadata(_) => {
-                Ok(false)
-            }
+            RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListedByLabel(_)
+            | RequestPolicyRule::TrustedDestination
+            | RequestPolicyRule::RecentAuthentication(_)
+            | RequestPolicyRule::VelocityLimit(_)
+            | RequestPolicyRule::AmountRange(_)
+            | RequestPolicyRule::DestinationAge(_) => Ok(false),
             RequestPolicyRule::And(criterias) | RequestPolicyRule::Or(criterias) => {
                 let request = &request_id;
                 let approver_id = &approver_id;
@@ -405,8 +565,8 @@ mod tests {
             resource::ResourceIds,
             user_test_utils::{self, mock_user},
             Account, AccountKey, AddUserGroupOperation, AddUserGroupOperationInput, Blockchain,
-            BlockchainStandard, EvaluatedRequestPolicyRule, Metadata, MetadataItem, Percentage,
-            RequestOperation, RequestPolicy, RequestStatus, ADMIN_GROUP_ID,
+            BlockchainStandard, EvaluatedRequestPolicyRule, Metadata, MetadataItem, NetworkProfile,
+            Percentage, RequestOperation, RequestPolicy, RequestStatus, ADMIN_GROUP_ID,
         },
         repositories::{
             request_policy::REQUEST_POLICY_REPOSITORY, ACCOUNT_REPOSITORY,
@@ -536,6 +696,55 @@ mod tests {
         assert_eq!(result.status, EvaluationStatus::Rejected);
     }
 
+    #[tokio::test]
+    async fn veto_rejects_regardless_of_the_other_policies() {
+        let mut request = mock_request();
+        let mut quorum_policy = mock_request_policy();
+        let mut veto_policy = mock_request_policy();
+        let user = user_test_utils::add_user(&[1; 16]);
+        let officer = user_test_utils::add_user(&[2; 16]);
+
+        request.operation = RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "test".to_string(),
+            },
+        });
+        request.requested_by = user.id;
+        request.approvals = vec![];
+
+        quorum_policy.specifier = RequestSpecifier::AddUserGroup;
+        quorum_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![user.id]), 1);
+        veto_policy.specifier = RequestSpecifier::AddUserGroup;
+        veto_policy.rule = RequestPolicyRule::Veto(UserSpecifier::Id(vec![officer.id]));
+
+        REQUEST_POLICY_REPOSITORY.insert(quorum_policy.id, quorum_policy.clone());
+        REQUEST_POLICY_REPOSITORY.insert(veto_policy.id, veto_policy.clone());
+
+        let evaluate = |request: &Request| {
+            RequestEvaluator {
+                request: request.to_owned(),
+                policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            }
+            .evaluate()
+            .unwrap()
+        };
+
+        // the veto alone does not approve the request
+        assert_eq!(evaluate(&request).status, EvaluationStatus::Pending);
+
+        request.approvals = vec![mock_approved_with_user(user.id)];
+        assert_eq!(evaluate(&request).status, EvaluationStatus::Approved);
+
+        request.approvals.push(mock_rejected_with_user(officer.id));
+        let result = evaluate(&request);
+
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert!(result
+            .get_status_reason()
+            .contains(&station_api::EvaluationSummaryReasonDTO::Veto));
+    }
+
     #[tokio::test]
     async fn is_approved_disregarding_inactive_users() {
         let mut request = mock_request();
@@ -630,6 +839,7 @@ mod tests {
                 blockchain: Blockchain::InternetComputer,
                 address: "a".to_owned(),
                 standard: BlockchainStandard::Native,
+                network: NetworkProfile::Mainnet,
                 symbol: "S".to_owned(),
                 decimals: 1,
                 name: "test".to_owned(),
@@ -637,6 +847,10 @@ mod tests {
                 metadata: Metadata::default(),
                 transfer_request_policy_id: None,
                 configs_request_policy_id: None,
+                trusted_destinations: Vec::new(),
+                subaccounts: Vec::new(),
+                webhooks: Vec::new(),
+                assets: Vec::new(),
                 last_modification_timestamp: 0,
             },
         );
@@ -667,6 +881,7 @@ mod tests {
                     }),
                 ]),
                 specifier: RequestSpecifier::Transfer(ResourceIds::Any),
+                expiration_period_secs: None,
             },
         );
 
@@ -756,4 +971,76 @@ mod tests {
             }
         );
     }
+
+    #[tokio::test]
+    async fn applying_votes_matches_the_full_evaluation() {
+        let users = (1..=4)
+            .map(|i| user_test_utils::add_user(&[i; 16]))
+            .collect::<Vec<_>>();
+        let mut request = mock_request();
+        let mut policy = mock_request_policy();
+
+        request.operation = RequestOperation::AddUserGroup(AddUserGroupOperation {
+            user_group_id: None,
+            input: AddUserGroupOperationInput {
+                name: "test".to_string(),
+            },
+        });
+        request.approvals = vec![];
+
+        policy.specifier = RequestSpecifier::AddUserGroup;
+        policy.rule = RequestPolicyRule::And(vec![
+            RequestPolicyRule::Or(vec![
+                RequestPolicyRule::Quorum(UserSpecifier::Id(vec![users[0].id, users[1].id]), 2),
+                RequestPolicyRule::QuorumPercentage(UserSpecifier::Any, Percentage(75)),
+            ]),
+            RequestPolicyRule::Not(Box::new(RequestPolicyRule::Quorum(
+                UserSpecifier::Id(vec![users[3].id]),
+                1,
+            ))),
+        ]);
+
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+
+        let full_evaluation = |request: &Request| {
+            RequestEvaluator::new(REQUEST_POLICY_RULE_EVALUATOR.to_owned(), request.to_owned())
+                .evaluate()
+                .unwrap()
+        };
+        let mut evaluation = full_evaluation(&request);
+
+        let votes = [
+            mock_approved_with_user(users[0].id),
+            mock_approved_with_user(users[2].id),
+            mock_rejected_with_user(users[1].id),
+        ];
+
+        for (i, vote) in votes.into_iter().enumerate() {
+            let voter_id = vote.approver_id;
+            request.approvals.push(vote);
+
+            if i == 2 {
+                // a new approver invalidates the cached evaluation, which would count one less
+                user_test_utils::add_user(&[5; 16]);
+            }
+
+            let vote_evaluator = RequestVoteEvaluator::new(
+                REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+                request.to_owned(),
+                Some(evaluation.to_owned()),
+                voter_id,
+            );
+
+            assert_eq!(vote_evaluator.is_reusable(&evaluation), i != 2);
+
+            evaluation = vote_evaluator.evaluate().unwrap();
+            let expected = full_evaluation(&request);
+
+            assert_eq!(evaluation.status, expected.status);
+            assert_eq!(evaluation.policy_results, expected.policy_results);
+            assert_eq!(evaluation.cache_key, expected.cache_key);
+        }
+
+        assert_eq!(evaluation.status, EvaluationStatus::Pending);
+    }
 }
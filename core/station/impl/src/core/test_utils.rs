@@ -0,0 +1,328 @@
+//! Test utilities to initialize and populate the canister state.
+
+use crate::core::write_system_info;
+use crate::models::{
+    account_test_utils::mock_account,
+    request_policy_rule::RequestPolicyRule,
+    request_specifier::{RequestSpecifier, UserSpecifier},
+    request_test_utils::mock_request,
+    resource::ResourceIds,
+    system::SystemInfo,
+    Account, Metadata, Request, RequestApproval, RequestApprovalStatus, RequestOperation,
+    RequestPolicy, RequestStatus, TransferOperation, TransferOperationInput, User, UserGroup,
+    UserStatus,
+};
+use crate::repositories::{
+    ACCOUNT_REPOSITORY, REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY, USER_GROUP_REPOSITORY,
+    USER_REPOSITORY,
+};
+use candid::Principal;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use uuid::Uuid;
+
+pub const UPGRADER_CANISTER_ID: [u8; 29] = [25; 29];
+
+pub fn init_canister_system() -> SystemInfo {
+    let mut system: SystemInfo = SystemInfo::default();
+    system.set_upgrader_canister_id(Principal::from_slice(self::UPGRADER_CANISTER_ID.as_slice()));
+
+    write_system_info(system.clone());
+
+    system
+}
+
+/// The station state created by the [`ScenarioBuilder`], with all the records already stored.
+#[derive(Clone, Debug, Default)]
+pub struct Scenario {
+    pub groups: Vec<UserGroup>,
+    pub users: Vec<User>,
+    pub accounts: Vec<Account>,
+    pub policies: Vec<RequestPolicy>,
+    pub requests: Vec<Request>,
+}
+
+impl Scenario {
+    /// Returns the group with the given name.
+    pub fn group(&self, name: &str) -> &UserGroup {
+        self.groups
+            .iter()
+            .find(|group| group.name == name)
+            .unwrap_or_else(|| panic!("group `{}` is not part of the scenario", name))
+    }
+
+    /// Returns the account with the given name.
+    pub fn account(&self, name: &str) -> &Account {
+        self.accounts
+            .iter()
+            .find(|account| account.name == name)
+            .unwrap_or_else(|| panic!("account `{}` is not part of the scenario", name))
+    }
+
+    /// Returns the users that are members of the group with the given name.
+    pub fn group_users(&self, name: &str) -> Vec<&User> {
+        let group_id = self.group(name).id;
+
+        self.users
+            .iter()
+            .filter(|user| user.groups.contains(&group_id))
+            .collect()
+    }
+}
+
+struct ScenarioAccount {
+    name: String,
+    approvers_group: String,
+    quorum: u16,
+}
+
+struct ScenarioRequest {
+    account: String,
+    status: RequestStatus,
+    approvals: usize,
+}
+
+/// Builds a fully wired and deterministic station state for tests.
+///
+/// Records get sequential ids and timestamps, so the same builder always produces the same state:
+///
+/// ```ignore
+/// let scenario = ScenarioBuilder::new()
+///     .with_group("finance", 3)
+///     .with_account("treasury", "finance", 2)
+///     .with_transfer_request("treasury", RequestStatus::Created, 1)
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct ScenarioBuilder {
+    groups: Vec<(String, usize)>,
+    accounts: Vec<ScenarioAccount>,
+    requests: Vec<ScenarioRequest>,
+    sequence: u32,
+}
+
+impl ScenarioBuilder {
+    /// The prefix of all the ids generated by the builder, to avoid clashes with other mocked records.
+    const ID_PREFIX: u128 = 0x5ce0_0000_0000_0000_0000_0000_0000_0000;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a user group with the given number of active members.
+    pub fn with_group(mut self, name: &str, members: usize) -> Self {
+        self.groups.push((name.to_string(), members));
+        self
+    }
+
+    /// Adds an account whose transfers require `quorum` approvals from the members of the given group.
+    pub fn with_account(mut self, name: &str, approvers_group: &str, quorum: u16) -> Self {
+        self.accounts.push(ScenarioAccount {
+            name: name.to_string(),
+            approvers_group: approvers_group.to_string(),
+            quorum,
+        });
+        self
+    }
+
+    /// Adds a transfer request from the given account, approved by the first `approvals` approvers.
+    pub fn with_transfer_request(
+        mut self,
+        account: &str,
+        status: RequestStatus,
+        approvals: usize,
+    ) -> Self {
+        self.requests.push(ScenarioRequest {
+            account: account.to_string(),
+            status,
+            approvals,
+        });
+        self
+    }
+
+    /// Initializes the canister system and stores all the records of the scenario.
+    pub fn build(mut self) -> Scenario {
+        init_canister_system();
+
+        let mut scenario = Scenario::default();
+
+        for (name, members) in std::mem::take(&mut self.groups) {
+            let group = UserGroup {
+                id: self.next_id(),
+                name: name.clone(),
+                last_modification_timestamp: 0,
+            };
+
+            USER_GROUP_REPOSITORY.insert(group.id, group.clone());
+
+            for position in 0..members {
+                let id = self.next_id();
+                let user = User {
+                    id,
+                    name: format!("{}_{}", name, position),
+                    status: UserStatus::Active,
+                    identities: vec![Principal::from_slice(&id)],
+                    groups: vec![group.id],
+                    last_modification_timestamp: 0,
+                };
+
+                USER_REPOSITORY.insert(user.to_key(), user.clone());
+                scenario.users.push(user);
+            }
+
+            scenario.groups.push(group);
+        }
+
+        for scenario_account in std::mem::take(&mut self.accounts) {
+            let mut account = mock_account();
+            account.id = self.next_id();
+            account.name = scenario_account.name;
+
+            let policy = RequestPolicy {
+                id: self.next_id(),
+                specifier: RequestSpecifier::Transfer(ResourceIds::Ids(vec![account.id])),
+                rule: RequestPolicyRule::Quorum(
+                    UserSpecifier::Group(vec![
+                        scenario.group(&scenario_account.approvers_group).id,
+                    ]),
+                    scenario_account.quorum,
+                ),
+                expiration_period_secs: None,
+            };
+
+            account.transfer_request_policy_id = Some(policy.id);
+
+            REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+            ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+            scenario.policies.push(policy);
+            scenario.accounts.push(account);
+        }
+
+        for scenario_request in std::mem::take(&mut self.requests) {
+            let account = scenario.account(&scenario_request.account).clone();
+            let approvers: Vec<UUID> = match account
+                .transfer_request_policy_id
+                .and_then(|id| scenario.policies.iter().find(|policy| policy.id == id))
+                .map(|policy| &policy.rule)
+            {
+                Some(RequestPolicyRule::Quorum(UserSpecifier::Group(groups), _)) => scenario
+                    .users
+                    .iter()
+                    .filter(|user| user.groups.iter().any(|group| groups.contains(group)))
+                    .map(|user| user.id)
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            let timestamp = self.next_timestamp();
+            let mut request = mock_request();
+            request.id = self.next_id();
+            request.requested_by = approvers
+                .first()
+                .copied()
+                .or_else(|| scenario.users.first().map(|user| user.id))
+                .unwrap_or(request.requested_by);
+            request.status = scenario_request.status;
+            request.operation = RequestOperation::Transfer(TransferOperation {
+                transfer_id: None,
+                fee: None,
+                input: TransferOperationInput {
+                    from_account_id: account.id,
+                    to: "0x1234".to_string(),
+                    amount: candid::Nat::from(100_u64),
+                    fee: None,
+                    metadata: Metadata::default(),
+                    network: "mainnet".to_string(),
+                    spend_from: None,
+                    memo: None,
+                    category: None,
+                    asset_id: None,
+                },
+            });
+            request.approvals = approvers
+                .iter()
+                .take(scenario_request.approvals)
+                .map(|approver_id| RequestApproval {
+                    approver_id: *approver_id,
+                    status: RequestApprovalStatus::Approved,
+                    status_reason: None,
+                    decided_dt: timestamp,
+                    last_modification_timestamp: timestamp,
+                    reauthenticated_at: None,
+                })
+                .collect();
+            request.created_timestamp = timestamp;
+            request.last_modification_timestamp = timestamp;
+            request.expiration_dt = u64::MAX;
+
+            REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+            scenario.requests.push(request);
+        }
+
+        scenario
+    }
+
+    fn next_id(&mut self) -> UUID {
+        self.sequence += 1;
+
+        *Uuid::from_u128(Self::ID_PREFIX | self.sequence as u128).as_bytes()
+    }
+
+    fn next_timestamp(&mut self) -> u64 {
+        self.sequence += 1;
+
+        self.sequence as u64 * 1_000_000_000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scenario_builder_wires_the_station_state() {
+        let scenario = ScenarioBuilder::new()
+            .with_group("finance", 3)
+            .with_group("operations", 2)
+            .with_account("treasury", "finance", 2)
+            .with_transfer_request("treasury", RequestStatus::Created, 1)
+            .with_transfer_request("treasury", RequestStatus::Approved, 2)
+            .build();
+
+        assert_eq!(scenario.users.len(), 5);
+        assert_eq!(scenario.group_users("finance").len(), 3);
+        assert_eq!(
+            scenario.account("treasury").transfer_request_policy_id,
+            Some(scenario.policies[0].id)
+        );
+        assert_eq!(scenario.requests[0].approvals.len(), 1);
+        assert_eq!(scenario.requests[1].approvals.len(), 2);
+
+        for request in scenario.requests.iter() {
+            assert_eq!(
+                REQUEST_REPOSITORY.get(&request.to_key()).as_ref(),
+                Some(request)
+            );
+        }
+    }
+
+    #[test]
+    fn scenario_builder_is_deterministic() {
+        let build = || {
+            ScenarioBuilder::new()
+                .with_group("finance", 2)
+                .with_account("treasury", "finance", 1)
+                .with_transfer_request("treasury", RequestStatus::Created, 0)
+                .build()
+        };
+
+        let first = build();
+        let second = build();
+
+        assert_eq!(first.users, second.users);
+        assert_eq!(first.accounts, second.accounts);
+        assert_eq!(first.requests, second.requests);
+    }
+}
@@ -1,5 +1,6 @@
 use candid::Principal;
 use orbit_essentials::types::UUID;
+use sha2::{Digest, Sha256};
 
 use super::authorization::Authorization;
 use super::CallContext;
@@ -85,6 +86,30 @@ pub fn calculate_minimum_threshold(percentage: &Percentage, total_value: &usize)
     }
 }
 
+/// Computes the HMAC-SHA256 of the message keyed by the given key (RFC 2104).
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut key_block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        key_block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        key_block[..key.len()].copy_from_slice(key);
+    }
+
+    let padded_key = |pad: u8| key_block.map(|byte| byte ^ pad);
+    let inner_hash = Sha256::new()
+        .chain_update(padded_key(0x36))
+        .chain_update(message)
+        .finalize();
+
+    Sha256::new()
+        .chain_update(padded_key(0x5c))
+        .chain_update(inner_hash)
+        .finalize()
+        .into()
+}
+
 /// Retains items based on the result of a authorization check evaluation.
 ///
 /// This function will evaluate the access for each item in the list and retain only the
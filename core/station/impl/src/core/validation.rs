@@ -8,12 +8,13 @@ use crate::{
     factories::blockchains::InternetComputer,
     models::{
         resource::{Resource, ResourceId, ResourceIds},
-        AccountKey, AddressBookEntryKey, NotificationKey, RequestKey, UserKey,
+        AccountKey, AddressBookEntryKey, AddressBookLabel, NotificationKey, RequestKey, UserKey,
     },
     repositories::{
         permission::PERMISSION_REPOSITORY, request_policy::REQUEST_POLICY_REPOSITORY,
-        ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY, NOTIFICATION_REPOSITORY, REQUEST_REPOSITORY,
-        USER_GROUP_REPOSITORY, USER_REPOSITORY,
+        ACCOUNT_REPOSITORY, ADDRESS_BOOK_LABEL_REPOSITORY, ADDRESS_BOOK_REPOSITORY,
+        CAPABILITY_GRANT_REPOSITORY, NOTIFICATION_REPOSITORY, REGISTERED_ASSET_REPOSITORY,
+        REQUEST_REPOSITORY, USER_GROUP_REPOSITORY, USER_REPOSITORY,
     },
     services::SYSTEM_SERVICE,
 };
@@ -117,6 +118,21 @@ impl EnsureIdExists<UUID> for EnsureUserGroup {
 
 impl EnsureResourceIdExists for EnsureUserGroup {}
 
+pub struct EnsureRegisteredAsset {}
+
+impl EnsureIdExists<UUID> for EnsureRegisteredAsset {
+    fn id_exists(id: &UUID) -> Result<(), RecordValidationError> {
+        ensure_entry_exists(REGISTERED_ASSET_REPOSITORY.to_owned(), *id).ok_or(
+            RecordValidationError::NotFound {
+                model_name: "RegisteredAsset".to_string(),
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            },
+        )
+    }
+}
+
+impl EnsureResourceIdExists for EnsureRegisteredAsset {}
+
 pub struct EnsureAccount {}
 
 impl EnsureIdExists<UUID> for EnsureAccount {
@@ -132,6 +148,19 @@ impl EnsureIdExists<UUID> for EnsureAccount {
 
 impl EnsureResourceIdExists for EnsureAccount {}
 
+pub struct EnsureCapabilityGrant {}
+
+impl EnsureIdExists<UUID> for EnsureCapabilityGrant {
+    fn id_exists(id: &UUID) -> Result<(), RecordValidationError> {
+        ensure_entry_exists(CAPABILITY_GRANT_REPOSITORY.to_owned(), *id).ok_or(
+            RecordValidationError::NotFound {
+                model_name: "CapabilityGrant".to_string(),
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            },
+        )
+    }
+}
+
 pub struct EnsureAddressBookEntry {}
 
 impl EnsureIdExists<UUID> for EnsureAddressBookEntry {
@@ -149,6 +178,26 @@ impl EnsureIdExists<UUID> for EnsureAddressBookEntry {
 
 impl EnsureResourceIdExists for EnsureAddressBookEntry {}
 
+pub struct EnsureAddressBookLabel {}
+
+impl EnsureIdExists<String> for EnsureAddressBookLabel {
+    /// The labels are free-form until the first label of the taxonomy is defined.
+    fn id_exists(label: &String) -> Result<(), RecordValidationError> {
+        if ADDRESS_BOOK_LABEL_REPOSITORY.is_empty() {
+            return Ok(());
+        }
+
+        ensure_entry_exists(
+            ADDRESS_BOOK_LABEL_REPOSITORY.to_owned(),
+            AddressBookLabel::key(label),
+        )
+        .ok_or(RecordValidationError::NotFound {
+            model_name: "AddressBookLabel".to_string(),
+            id: label.to_owned(),
+        })
+    }
+}
+
 pub struct EnsureRequest {}
 
 impl EnsureIdExists<UUID> for EnsureRequest {
@@ -33,12 +33,21 @@ pub enum AccountError {
         r#"Fetching account balances can only be done for a maximum of {max} accounts at a time."#
     )]
     AccountBalancesBatchRange { min: u8, max: u8 },
+    /// Discovering accounts can only be done for a maximum of 10 ledgers at a time.
+    #[error(r#"Discovering accounts can only be done for a maximum of {max} ledgers at a time."#)]
+    DiscoveryLedgersBatchRange { min: u8, max: u8 },
     /// The account has failed validation.
     #[error(r#"The account has failed validation."#)]
     ValidationError { info: String },
     /// An account with the given name already exists.
     #[error(r#"An account with the given name already exists."#)]
     AccountNameAlreadyExists,
+    /// The account does not hold the requested asset.
+    #[error(r#"The account does not hold the asset {asset_id}."#)]
+    AssetNotHeld { asset_id: String },
+    /// The reserves were not certified yet, e.g. right after an upgrade.
+    #[error(r#"The reserves are not certified yet, please retry later."#)]
+    ReservesNotCertified,
 }
 
 impl DetailableError for AccountError {
@@ -91,6 +100,15 @@ impl DetailableError for AccountError {
                 details.insert("max".to_string(), max.to_string());
                 Some(details)
             }
+            AccountError::DiscoveryLedgersBatchRange { min, max } => {
+                details.insert("min".to_string(), min.to_string());
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
+            AccountError::AssetNotHeld { asset_id } => {
+                details.insert("asset_id".to_string(), asset_id.to_string());
+                Some(details)
+            }
             _ => None,
         }
     }
@@ -33,6 +33,12 @@ pub enum AddressBookError {
     /// The account has failed validation.
     #[error(r#"The account has failed validation."#)]
     ValidationError { info: String },
+    /// The label is not defined by the address book label taxonomy.
+    #[error(r#"The label `{label}` is not defined by the address book labels."#)]
+    UndefinedLabel { label: String },
+    /// The label is still used by address book entries.
+    #[error(r#"The label `{label}` is still used by {entries} address book entries."#)]
+    LabelInUse { label: String, entries: usize },
 }
 
 impl DetailableError for AddressBookError {
@@ -84,6 +90,15 @@ impl DetailableError for AddressBookError {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
             }
+            AddressBookError::UndefinedLabel { label } => {
+                details.insert("label".to_string(), label.to_string());
+                Some(details)
+            }
+            AddressBookError::LabelInUse { label, entries } => {
+                details.insert("label".to_string(), label.to_string());
+                details.insert("entries".to_string(), entries.to_string());
+                Some(details)
+            }
         }
     }
 }
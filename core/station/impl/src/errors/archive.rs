@@ -0,0 +1,34 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the archive sink.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum ArchiveError {
+    /// The station does not export its history to an archive canister.
+    #[error(r#"No archive canister is configured."#)]
+    NotConfigured,
+    /// The call to the archive canister failed.
+    #[error(r#"The call to the archive canister failed: {reason}"#)]
+    CallFailed { reason: String },
+    /// The chunk is not kept in the station.
+    #[error(r#"The archive chunk {sequence} is not kept in the station."#)]
+    ChunkNotFound { sequence: u64 },
+}
+
+impl DetailableError for ArchiveError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            ArchiveError::CallFailed { reason } => {
+                details.insert("reason".to_string(), reason.to_string());
+                Some(details)
+            }
+            ArchiveError::ChunkNotFound { sequence } => {
+                details.insert("sequence".to_string(), sequence.to_string());
+                Some(details)
+            }
+            ArchiveError::NotConfigured => None,
+        }
+    }
+}
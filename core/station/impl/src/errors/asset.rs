@@ -0,0 +1,76 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the registered assets.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum AssetError {
+    /// The registered asset was not found.
+    #[error(r#"The asset with id {id} was not found."#)]
+    NotFound { id: String },
+    /// The asset symbol length is out of range.
+    #[error(r#"The asset symbol length is out of range, it must be between {min_length} and {max_length}."#)]
+    InvalidSymbolLength { min_length: u8, max_length: u8 },
+    /// The asset name length is out of range.
+    #[error(r#"The asset name length is out of range, it must be between {min_length} and {max_length}."#)]
+    InvalidNameLength { min_length: u8, max_length: u8 },
+    /// The asset decimals are above the maximum.
+    #[error(r#"The asset decimals cannot be more than {max_decimals}."#)]
+    InvalidDecimals { max_decimals: u32 },
+    /// The ledger is already supported by the station without being registered.
+    #[error(r#"The ledger '{ledger_canister_id}' is already supported by the station."#)]
+    BuiltInLedger { ledger_canister_id: String },
+    /// The ledger is already registered as another asset.
+    #[error(r#"The ledger '{ledger_canister_id}' is already registered."#)]
+    DuplicateLedger { ledger_canister_id: String },
+    /// The asset is still held by accounts of the station.
+    #[error(r#"The asset cannot be removed while {accounts} account(s) hold it."#)]
+    InUse { accounts: usize },
+    /// The asset has failed validation.
+    #[error(r#"The asset has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for AssetError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            AssetError::NotFound { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            AssetError::InvalidSymbolLength {
+                min_length,
+                max_length,
+            }
+            | AssetError::InvalidNameLength {
+                min_length,
+                max_length,
+            } => {
+                details.insert("min_length".to_string(), min_length.to_string());
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            AssetError::InvalidDecimals { max_decimals } => {
+                details.insert("max_decimals".to_string(), max_decimals.to_string());
+                Some(details)
+            }
+            AssetError::BuiltInLedger { ledger_canister_id }
+            | AssetError::DuplicateLedger { ledger_canister_id } => {
+                details.insert(
+                    "ledger_canister_id".to_string(),
+                    ledger_canister_id.to_string(),
+                );
+                Some(details)
+            }
+            AssetError::InUse { accounts } => {
+                details.insert("accounts".to_string(), accounts.to_string());
+                Some(details)
+            }
+            AssetError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use orbit_essentials::api::DetailableError;
+use orbit_essentials::api::{ApiError, DetailableError};
 use std::collections::HashMap;
 use thiserror::Error;
 
+/// The submission error info of ledgers that reported being temporarily unavailable.
+pub const LEDGER_TEMPORARILY_UNAVAILABLE_INFO: &str = "Ledger temporarily unavailable";
+
 /// Container for blockchain api errors.
 #[derive(Error, Debug, Eq, PartialEq, Clone)]
 pub enum BlockchainApiError {
@@ -17,6 +20,43 @@ pub enum BlockchainApiError {
     /// The to address is invalid.
     #[error("The to address '{address}' is invalid: {error}")]
     InvalidToAddress { address: String, error: String },
+    /// The outcome of a previously submitted transaction could not be determined.
+    #[error(r#"The outcome of the transaction could not be determined: {info}"#)]
+    TransactionLookupFailed { info: String },
+    /// The ledger of the account is not supported.
+    #[error(r#"The ledger '{ledger_canister_id}' of the account is not supported."#)]
+    UnsupportedLedger { ledger_canister_id: String },
+    /// The account does not support subaccounts.
+    #[error(r#"The {blockchain} {standard} accounts do not support subaccounts."#)]
+    SubaccountsNotSupported {
+        blockchain: String,
+        standard: String,
+    },
+    /// The account can not pull funds from allowances granted by other ledger accounts.
+    #[error(r#"The {blockchain} {standard} accounts do not support allowances."#)]
+    AllowancesNotSupported {
+        blockchain: String,
+        standard: String,
+    },
+}
+
+impl BlockchainApiError {
+    /// Whether the submission error means that the transaction was certainly not executed and can
+    /// be submitted again, e.g. because the ledger was busy or the call never reached the ledger.
+    pub fn is_transient(error: &ApiError) -> bool {
+        let info = error
+            .details
+            .as_ref()
+            .and_then(|details| details.get("info"))
+            .map(String::as_str)
+            .unwrap_or_default();
+
+        match error.code.as_str() {
+            "TRANSACTION_SUBMIT_FAILED" => info == LEDGER_TEMPORARILY_UNAVAILABLE_INFO,
+            "BLOCKCHAIN_NETWORK_ERROR" => info.starts_with("rejection_code: SysTransient"),
+            _ => false,
+        }
+    }
 }
 
 impl DetailableError for BlockchainApiError {
@@ -40,6 +80,29 @@ impl DetailableError for BlockchainApiError {
                 details.insert("error".to_string(), error.to_string());
                 Some(details)
             }
+            BlockchainApiError::TransactionLookupFailed { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            BlockchainApiError::UnsupportedLedger { ledger_canister_id } => {
+                details.insert(
+                    "ledger_canister_id".to_string(),
+                    ledger_canister_id.to_string(),
+                );
+                Some(details)
+            }
+            BlockchainApiError::SubaccountsNotSupported {
+                blockchain,
+                standard,
+            }
+            | BlockchainApiError::AllowancesNotSupported {
+                blockchain,
+                standard,
+            } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("standard".to_string(), standard.to_string());
+                Some(details)
+            }
         }
     }
 }
@@ -0,0 +1,45 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the capability grants.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum CapabilityGrantError {
+    /// The capability grant was not found.
+    #[error(r#"The capability grant with id {id} was not found."#)]
+    NotFound { id: String },
+    /// The expiration is in the past or too far in the future.
+    #[error(
+        r#"The capability grant must expire in the future and within {max_duration_days} days."#
+    )]
+    InvalidExpiration { max_duration_days: u64 },
+    /// The capability grant was already revoked.
+    #[error(r#"The capability grant with id {id} is already revoked."#)]
+    AlreadyRevoked { id: String },
+    /// The capability grant has failed validation.
+    #[error(r#"The capability grant has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for CapabilityGrantError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            CapabilityGrantError::NotFound { id } | CapabilityGrantError::AlreadyRevoked { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            CapabilityGrantError::InvalidExpiration { max_duration_days } => {
+                details.insert(
+                    "max_duration_days".to_string(),
+                    max_duration_days.to_string(),
+                );
+                Some(details)
+            }
+            CapabilityGrantError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
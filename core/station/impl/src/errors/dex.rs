@@ -0,0 +1,51 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the DEX swaps.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum DexError {
+    /// The DEX requires the pool canister of the pair.
+    #[error(r#"The DEX {dex} requires the pool canister of the pair."#)]
+    PoolRequired { dex: String },
+    /// The DEX does not list a pool for the pair.
+    #[error(r#"The DEX {dex} has no pool for the pair."#)]
+    PoolNotFound { dex: String },
+    /// The DEX failed to quote the swap.
+    #[error(r#"The DEX {dex} failed to quote the swap: {info}"#)]
+    QuoteFailed { dex: String, info: String },
+    /// The quote of the DEX is below the minimum amount that the approvers agreed to.
+    #[error(r#"The quote {quote} is below the minimum amount out {min_amount_out}."#)]
+    SlippageExceeded {
+        min_amount_out: String,
+        quote: String,
+    },
+    /// The DEX failed to execute the swap.
+    #[error(r#"The DEX {dex} failed to execute the swap: {info}"#)]
+    SwapFailed { dex: String, info: String },
+}
+
+impl DetailableError for DexError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            DexError::PoolRequired { dex } | DexError::PoolNotFound { dex } => {
+                details.insert("dex".to_string(), dex.to_string());
+                Some(details)
+            }
+            DexError::QuoteFailed { dex, info } | DexError::SwapFailed { dex, info } => {
+                details.insert("dex".to_string(), dex.to_string());
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            DexError::SlippageExceeded {
+                min_amount_out,
+                quote,
+            } => {
+                details.insert("min_amount_out".to_string(), min_amount_out.to_string());
+                details.insert("quote".to_string(), quote.to_string());
+                Some(details)
+            }
+        }
+    }
+}
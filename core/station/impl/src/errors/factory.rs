@@ -11,17 +11,36 @@ pub enum FactoryError {
         blockchain: String,
         standard: String,
     },
+    /// The selected network is not supported for the accounts of the blockchain and standard.
+    #[error(r#"The network {network} is not supported for {blockchain} {standard} accounts."#)]
+    UnsupportedNetwork {
+        blockchain: String,
+        standard: String,
+        network: String,
+    },
 }
 
 impl DetailableError for FactoryError {
     fn details(&self) -> Option<HashMap<String, String>> {
         let mut details = HashMap::new();
-        let FactoryError::UnsupportedBlockchainAccount {
-            blockchain,
-            standard,
-        } = self;
-        details.insert("blockchain".to_string(), blockchain.to_string());
-        details.insert("standard".to_string(), standard.to_string());
+        match self {
+            FactoryError::UnsupportedBlockchainAccount {
+                blockchain,
+                standard,
+            } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("standard".to_string(), standard.to_string());
+            }
+            FactoryError::UnsupportedNetwork {
+                blockchain,
+                standard,
+                network,
+            } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                details.insert("standard".to_string(), standard.to_string());
+                details.insert("network".to_string(), network.to_string());
+            }
+        }
 
         Some(details)
     }
@@ -0,0 +1,42 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for funding request errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum FundingRequestError {
+    /// The requested funding request was not found.
+    #[error(r#"The requested funding request was not found."#)]
+    NotFound { id: String },
+    /// The payer is not a valid ICRC-1 account.
+    #[error(r#"The payer `{payer}` is not a valid ICRC-1 account: {error}"#)]
+    InvalidPayer { payer: String, error: String },
+    /// The funding request must expire in the future.
+    #[error(r#"The funding request must expire in the future."#)]
+    InvalidExpiration,
+    /// The funding request has failed validation.
+    #[error(r#"The funding request has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for FundingRequestError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            FundingRequestError::NotFound { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            FundingRequestError::InvalidPayer { payer, error } => {
+                details.insert("payer".to_string(), payer.to_string());
+                details.insert("error".to_string(), error.to_string());
+                Some(details)
+            }
+            FundingRequestError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            _ => None,
+        }
+    }
+}
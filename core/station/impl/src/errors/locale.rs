@@ -0,0 +1,31 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the localization of the messages.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum LocaleError {
+    /// The locale is not a valid locale tag.
+    #[error(r#"The locale `{locale}` is not a valid locale tag."#)]
+    InvalidLocale { locale: String },
+    /// A message of the catalog is too long.
+    #[error(r#"The message `{key}` exceeds the maximum length of {max_length}."#)]
+    MessageTooLong { key: String, max_length: usize },
+}
+
+impl DetailableError for LocaleError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            LocaleError::InvalidLocale { locale } => {
+                details.insert("locale".to_string(), locale.to_string());
+                Some(details)
+            }
+            LocaleError::MessageTooLong { key, max_length } => {
+                details.insert("key".to_string(), key.to_string());
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+        }
+    }
+}
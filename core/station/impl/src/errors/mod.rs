@@ -8,6 +8,24 @@ pub use account::*;
 mod address_book;
 pub use address_book::*;
 
+mod archive;
+pub use archive::*;
+
+mod locale;
+pub use locale::*;
+
+mod notification_template;
+pub use notification_template::*;
+
+mod transfer_category;
+pub use transfer_category::*;
+
+mod asset;
+pub use asset::*;
+
+mod dex;
+pub use dex::*;
+
 mod metadata;
 pub use metadata::*;
 
@@ -41,6 +59,18 @@ pub use request::*;
 mod request_execute;
 pub use request_execute::*;
 
+mod request_view;
+pub use request_view::*;
+
+mod request_comment;
+pub use request_comment::*;
+
+mod scheduled_transfer;
+pub use scheduled_transfer::*;
+
+mod capability_grant;
+pub use capability_grant::*;
+
 mod evaluate;
 pub use evaluate::*;
 
@@ -62,6 +92,12 @@ pub use change_canister::*;
 mod external_canister;
 pub use external_canister::*;
 
+mod funding_request;
+pub use funding_request::*;
+
+mod payout_run;
+pub use payout_run::*;
+
 mod request_policy;
 pub use request_policy::*;
 
@@ -70,3 +106,6 @@ pub use validation::*;
 
 mod disaster_recovery;
 pub use disaster_recovery::*;
+
+mod rpc;
+pub use rpc::*;
@@ -0,0 +1,41 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the templates of the notifications.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum NotificationTemplateError {
+    /// The locale of the template is not a valid locale tag.
+    #[error(r#"The locale `{locale}` is not a valid locale tag."#)]
+    InvalidLocale { locale: String },
+    /// The title of the template is empty or too long.
+    #[error(r#"The title must be between 1 and {max_length} characters."#)]
+    InvalidTitleLength { max_length: usize },
+    /// The message of the template is too long.
+    #[error(r#"The message exceeds the maximum length of {max_length}."#)]
+    MessageTooLong { max_length: usize },
+    /// The template references a placeholder that the station does not fill.
+    #[error(r#"The placeholder `{{{placeholder}}}` is not supported."#)]
+    UnknownPlaceholder { placeholder: String },
+}
+
+impl DetailableError for NotificationTemplateError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            NotificationTemplateError::InvalidLocale { locale } => {
+                details.insert("locale".to_string(), locale.to_string());
+                Some(details)
+            }
+            NotificationTemplateError::InvalidTitleLength { max_length }
+            | NotificationTemplateError::MessageTooLong { max_length } => {
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            NotificationTemplateError::UnknownPlaceholder { placeholder } => {
+                details.insert("placeholder".to_string(), placeholder.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for payout run errors.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum PayoutRunError {
+    /// The requested payout run was not found.
+    #[error(r#"The requested payout run was not found."#)]
+    NotFound { id: String },
+    /// One of the transfer requests of the payout run could not be created.
+    #[error(r#"The transfer {index} of the payout run could not be requested: {error}"#)]
+    TransferRequestFailed { index: usize, error: String },
+    /// The payout run has failed validation.
+    #[error(r#"The payout run has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for PayoutRunError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            PayoutRunError::NotFound { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            PayoutRunError::TransferRequestFailed { index, error } => {
+                details.insert("index".to_string(), index.to_string());
+                details.insert("error".to_string(), error.to_string());
+                Some(details)
+            }
+            PayoutRunError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
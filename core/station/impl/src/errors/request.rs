@@ -27,11 +27,40 @@ pub enum RequestError {
     /// You can't add your approval decision to the request.
     #[error(r#"You can't add your approval decision to the request."#)]
     ApprovalNotAllowed,
+    /// The approval requires the approver to have re-authenticated more recently.
+    #[error(r#"Approving this request requires to have re-authenticated from another identity within the last {max_reauthentication_age_mins} minutes."#)]
+    RecentAuthenticationRequired { max_reauthentication_age_mins: u32 },
+    /// Only the requester can cancel the request.
+    #[error(r#"Only the requester can cancel the request."#)]
+    CancellationNotAllowed,
+    /// Only the requester can amend the request, before other users voted on it.
+    #[error(r#"Only the requester can amend the request, as long as no other user voted on it."#)]
+    AmendmentNotAllowed,
     /// Request execution failed due to {reason}.
     #[error(r#"Request execution failed due to `{reason}`."#)]
     ExecutionError { reason: String },
     #[error(r#"You don't have permission to create the requested request."#)]
     Unauthorized,
+    /// The request operation exceeds one of the configured limits.
+    #[error(r#"The request operation exceeds the `{limit}` limit of {max}, got {actual}."#)]
+    OperationLimitExceeded {
+        limit: String,
+        max: u64,
+        actual: u64,
+    },
+    /// The stable memory usage crossed the high-water mark, new requests are refused.
+    #[error(r#"The station uses {used_bytes} bytes of stable memory, above its high-water mark of {high_water_mark_bytes} bytes, only upgrade and system requests can be created."#)]
+    StorageCapacityReached {
+        used_bytes: u64,
+        high_water_mark_bytes: u64,
+    },
+    /// The requester reached one of the configured request creation rate limits.
+    #[error(r#"You reached the limit of {max_requests} requests per {period}, try again later."#)]
+    CreationRateLimitExceeded {
+        max_requests: u32,
+        period: String,
+        operation_type: Option<String>,
+    },
     /// Request policy not found for id `{id}`.
     #[error(r#"Request policy not found for id `{id}`"#)]
     PolicyNotFound { id: String },
@@ -65,10 +94,48 @@ impl DetailableError for RequestError {
                 details.insert("reason".to_string(), reason.to_string());
                 Some(details)
             }
+            RequestError::RecentAuthenticationRequired {
+                max_reauthentication_age_mins,
+            } => {
+                details.insert(
+                    "max_reauthentication_age_mins".to_string(),
+                    max_reauthentication_age_mins.to_string(),
+                );
+                Some(details)
+            }
             RequestError::PolicyNotFound { id } => {
                 details.insert("id".to_string(), id.to_string());
                 Some(details)
             }
+            RequestError::OperationLimitExceeded { limit, max, actual } => {
+                details.insert("limit".to_string(), limit.to_string());
+                details.insert("max".to_string(), max.to_string());
+                details.insert("actual".to_string(), actual.to_string());
+                Some(details)
+            }
+            RequestError::StorageCapacityReached {
+                used_bytes,
+                high_water_mark_bytes,
+            } => {
+                details.insert("used_bytes".to_string(), used_bytes.to_string());
+                details.insert(
+                    "high_water_mark_bytes".to_string(),
+                    high_water_mark_bytes.to_string(),
+                );
+                Some(details)
+            }
+            RequestError::CreationRateLimitExceeded {
+                max_requests,
+                period,
+                operation_type,
+            } => {
+                details.insert("max_requests".to_string(), max_requests.to_string());
+                details.insert("period".to_string(), period.to_string());
+                if let Some(operation_type) = operation_type {
+                    details.insert("operation_type".to_string(), operation_type.to_string());
+                }
+                Some(details)
+            }
             _ => None,
         }
     }
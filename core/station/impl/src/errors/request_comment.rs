@@ -0,0 +1,30 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the request comments.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum RequestCommentError {
+    /// The comment is empty or too long.
+    #[error(r#"The comment cannot be empty or longer than {max_length} characters."#)]
+    InvalidBodyLength { max_length: usize },
+    /// The thread of the request is full.
+    #[error(r#"A request cannot have more than {max} comments."#)]
+    TooManyComments { max: usize },
+}
+
+impl DetailableError for RequestCommentError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            RequestCommentError::InvalidBodyLength { max_length } => {
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            RequestCommentError::TooManyComments { max } => {
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the saved request views.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum RequestViewError {
+    /// The request view was not found.
+    #[error(r#"The request view with id {id} was not found."#)]
+    NotFound { id: String },
+    /// Only the owner of the view can change it.
+    #[error(r#"Only the owner of the request view can change it."#)]
+    Forbidden,
+    /// The view name length is out of range.
+    #[error(r#"The request view name length is out of range, it must be between {min_length} and {max_length}."#)]
+    InvalidNameLength { min_length: u8, max_length: u8 },
+    /// The view filters by too many entries.
+    #[error(r#"The request view cannot filter by more than {max} users or operation types."#)]
+    TooManyFilterEntries { max: usize },
+    /// The user owns too many views.
+    #[error(r#"A user cannot own more than {max} request views."#)]
+    TooManyViews { max: usize },
+    /// The view can only be shared with a group of its owner.
+    #[error(r#"The request view can only be shared with a group you are a member of."#)]
+    NotGroupMember { group_id: String },
+    /// The request view has failed validation.
+    #[error(r#"The request view has failed validation."#)]
+    ValidationError { info: String },
+}
+
+impl DetailableError for RequestViewError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            RequestViewError::NotFound { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            RequestViewError::InvalidNameLength {
+                min_length,
+                max_length,
+            } => {
+                details.insert("min_length".to_string(), min_length.to_string());
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            RequestViewError::TooManyFilterEntries { max }
+            | RequestViewError::TooManyViews { max } => {
+                details.insert("max".to_string(), max.to_string());
+                Some(details)
+            }
+            RequestViewError::NotGroupMember { group_id } => {
+                details.insert("group_id".to_string(), group_id.to_string());
+                Some(details)
+            }
+            RequestViewError::ValidationError { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            RequestViewError::Forbidden => None,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for errors of the JSON-RPC providers used by outcall based blockchain adapters.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum RpcError {
+    /// No RPC providers are configured for the blockchain.
+    #[error(r#"No RPC providers are configured for the blockchain `{blockchain}`."#)]
+    NotConfigured { blockchain: String },
+    /// The RPC providers configuration is invalid.
+    #[error(r#"The RPC providers configuration is invalid: {info}"#)]
+    InvalidConfig { info: String },
+    /// None of the RPC providers could be reached.
+    #[error(r#"None of the RPC providers could be reached: {info}"#)]
+    AllProvidersFailed { info: String },
+    /// The RPC providers did not reach the required quorum on the response.
+    #[error(r#"The RPC providers returned inconsistent responses, {quorum} matching responses are required."#)]
+    InconsistentResponses { quorum: u8 },
+    /// The RPC provider returned an error.
+    #[error(r#"The RPC provider `{provider}` returned an error: {info}"#)]
+    ProviderError { provider: String, info: String },
+}
+
+impl DetailableError for RpcError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            RpcError::NotConfigured { blockchain } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+                Some(details)
+            }
+            RpcError::InvalidConfig { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            RpcError::AllProvidersFailed { info } => {
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+            RpcError::InconsistentResponses { quorum } => {
+                details.insert("quorum".to_string(), quorum.to_string());
+                Some(details)
+            }
+            RpcError::ProviderError { provider, info } => {
+                details.insert("provider".to_string(), provider.to_string());
+                details.insert("info".to_string(), info.to_string());
+                Some(details)
+            }
+        }
+    }
+}
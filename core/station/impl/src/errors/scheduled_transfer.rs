@@ -0,0 +1,42 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the scheduled transfers.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum ScheduledTransferError {
+    /// The scheduled transfer was not found.
+    #[error(r#"The scheduled transfer with id {id} was not found."#)]
+    NotFound { id: String },
+    /// The interval between two executions is too short.
+    #[error(
+        r#"The interval between two executions must be at least {min_interval_secs} seconds."#
+    )]
+    InvalidInterval { min_interval_secs: u64 },
+    /// The end date is before the first execution.
+    #[error(r#"The end date of the scheduled transfer must be after its first execution."#)]
+    InvalidEndDate,
+    /// The scheduled transfer already ended or was cancelled.
+    #[error(r#"The scheduled transfer with id {id} is not active."#)]
+    NotActive { id: String },
+}
+
+impl DetailableError for ScheduledTransferError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            ScheduledTransferError::NotFound { id } | ScheduledTransferError::NotActive { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
+            ScheduledTransferError::InvalidInterval { min_interval_secs } => {
+                details.insert(
+                    "min_interval_secs".to_string(),
+                    min_interval_secs.to_string(),
+                );
+                Some(details)
+            }
+            ScheduledTransferError::InvalidEndDate => None,
+        }
+    }
+}
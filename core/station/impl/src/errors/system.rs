@@ -16,6 +16,11 @@ pub enum SystemError {
     UpgradeFailed { reason: String },
     #[error(r#"No station upgrade request is processing."#)]
     NoStationUpgradeProcessing,
+    #[error(r#"The transfer executions of the blockchain {blockchain} are not paused."#)]
+    BlockchainNotPaused { blockchain: String },
+    /// The attestation of the station was not certified since the last upgrade yet.
+    #[error(r#"The attestation of the station is not certified yet."#)]
+    AttestationNotCertified,
 }
 
 impl DetailableError for SystemError {
@@ -37,6 +42,11 @@ impl DetailableError for SystemError {
 
                 Some(details)
             }
+            SystemError::BlockchainNotPaused { blockchain } => {
+                details.insert("blockchain".to_string(), blockchain.to_string());
+
+                Some(details)
+            }
             _ => Some(details),
         }
     }
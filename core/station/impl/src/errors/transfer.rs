@@ -16,13 +16,20 @@ pub enum TransferError {
     /// Transfer execution failed due to {reason}.
     #[error(r#"Transfer execution failed due to `{reason}`."#)]
     ExecutionError { reason: String },
+    /// Transfer execution failed with an error that may not happen again, e.g. the ledger was busy.
+    #[error(r#"Transfer execution failed temporarily due to `{reason}`."#)]
+    TransientExecutionError { reason: String },
+    /// The receipt is only issued once the transfer is completed.
+    #[error(r#"The receipt of the transfer was not found, it is issued once the transfer is completed."#)]
+    ReceiptNotFound { transfer_id: String },
 }
 
 impl DetailableError for TransferError {
     fn details(&self) -> Option<HashMap<String, String>> {
         let mut details = HashMap::new();
         match self {
-            TransferError::TransferNotFound { transfer_id } => {
+            TransferError::TransferNotFound { transfer_id }
+            | TransferError::ReceiptNotFound { transfer_id } => {
                 details.insert("transfer_id".to_string(), transfer_id.to_string());
                 Some(details)
             }
@@ -34,7 +41,8 @@ impl DetailableError for TransferError {
                 details.insert("info".to_string(), info.to_string());
                 Some(details)
             }
-            TransferError::ExecutionError { reason } => {
+            TransferError::ExecutionError { reason }
+            | TransferError::TransientExecutionError { reason } => {
                 details.insert("reason".to_string(), reason.to_string());
                 Some(details)
             }
@@ -0,0 +1,38 @@
+use orbit_essentials::api::DetailableError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Container for the errors of the registry of transfer categories.
+#[derive(Error, Debug, Eq, PartialEq, Clone)]
+pub enum TransferCategoryError {
+    /// The name is not a valid category name.
+    #[error(r#"The category name `{name}` must be a lowercase slug of at most {max_length} characters."#)]
+    InvalidName { name: String, max_length: usize },
+    /// The description of the category is too long.
+    #[error(r#"The category description exceeds the maximum length of {max_length}."#)]
+    DescriptionTooLong { max_length: usize },
+    /// The category is not registered.
+    #[error(r#"The transfer category `{name}` is not registered."#)]
+    NotFound { name: String },
+}
+
+impl DetailableError for TransferCategoryError {
+    fn details(&self) -> Option<HashMap<String, String>> {
+        let mut details = HashMap::new();
+        match self {
+            TransferCategoryError::InvalidName { name, max_length } => {
+                details.insert("name".to_string(), name.to_string());
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            TransferCategoryError::DescriptionTooLong { max_length } => {
+                details.insert("max_length".to_string(), max_length.to_string());
+                Some(details)
+            }
+            TransferCategoryError::NotFound { name } => {
+                details.insert("name".to_string(), name.to_string());
+                Some(details)
+            }
+        }
+    }
+}
@@ -16,6 +16,9 @@ pub enum UserError {
     /// Identity not allowed to be added to the user.
     #[error(r#"Identity not allowed to be added to the user."#)]
     IdentityNotAllowed { identity: String },
+    /// The approve-only identity is not one of the identities of the user.
+    #[error(r#"The approve-only identity {identity} is not associated with the user."#)]
+    ApproveOnlyIdentityNotAssociated { identity: String },
     /// The user has too many unconfirmed identities.
     #[error(r#"The user has too many unconfirmed identities, it cannot have more than {max_identities}."#)]
     TooManyUnconfirmedIdentities {
@@ -79,6 +82,9 @@ pub enum UserError {
     // error for when non existent user group is getting added
     #[error(r#"The user group {group_id} does not exist."#)]
     UserGroupDoesNotExist { group_id: String },
+    /// The onboarding step is tracked by the station and can't be reported by the user.
+    #[error(r#"The onboarding step {step} is tracked by the station and can't be reported."#)]
+    OnboardingStepNotSelfReported { step: String },
 }
 
 impl DetailableError for UserError {
@@ -125,10 +131,18 @@ impl DetailableError for UserError {
                 details.insert("identity".to_string(), identity.to_string());
                 Some(details)
             }
+            UserError::ApproveOnlyIdentityNotAssociated { identity } => {
+                details.insert("identity".to_string(), identity.to_string());
+                Some(details)
+            }
             UserError::NameAlreadyHasUser { user } => {
                 details.insert("user".to_string(), user.to_string());
                 Some(details)
             }
+            UserError::OnboardingStepNotSelfReported { step } => {
+                details.insert("step".to_string(), step.to_string());
+                Some(details)
+            }
             _ => None,
         }
     }
@@ -28,6 +28,18 @@ pub enum UserGroupError {
         /// The user group id.
         id: String,
     },
+    /// The caller asked to join a user group they are already a member of.
+    #[error("The user is already a member of the user group with id {id}.")]
+    AlreadyMember {
+        /// The user group id.
+        id: String,
+    },
+    /// The caller asked to leave a user group they are not a member of.
+    #[error("The user is not a member of the user group with id {id}.")]
+    NotMember {
+        /// The user group id.
+        id: String,
+    },
     #[error("Cannot delete the user group marked as the disaster recovery committee.")]
     CannotDeleteDisasterRecoveryCommittee {
         /// The user group id.
@@ -55,6 +67,10 @@ impl DetailableError for UserGroupError {
                 details.insert("id".to_string(), id.to_string());
                 Some(details)
             }
+            UserGroupError::AlreadyMember { id } | UserGroupError::NotMember { id } => {
+                details.insert("id".to_string(), id.to_string());
+                Some(details)
+            }
             UserGroupError::CannotDeleteDisasterRecoveryCommittee { id } => {
                 details.insert("id".to_string(), id.to_string());
                 Some(details)
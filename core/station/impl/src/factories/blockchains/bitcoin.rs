@@ -0,0 +1,963 @@
+use super::{
+    BlockchainApi, BlockchainApiResult, BlockchainTransactionConfirmation,
+    BlockchainTransactionFee, BlockchainTransactionFeeOption, BlockchainTransactionFeeTier,
+    BlockchainTransactionLookup, BlockchainTransactionSubmitted,
+    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
+};
+use crate::{
+    errors::BlockchainApiError,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, ApproveOperationInput, Blockchain, BlockchainStandard, Metadata,
+        NetworkProfile, Transfer,
+    },
+    repositories::TRANSFER_REPOSITORY,
+};
+use async_trait::async_trait;
+use bech32::{hrp, segwit, Fe32, Hrp};
+use ic_cdk::api::management_canister::{
+    bitcoin::{
+        bitcoin_get_balance, bitcoin_get_current_fee_percentiles, bitcoin_get_utxos,
+        bitcoin_send_transaction, BitcoinNetwork, GetBalanceRequest,
+        GetCurrentFeePercentilesRequest, GetUtxosRequest, GetUtxosResponse, Outpoint,
+        SendTransactionRequest, Utxo, UtxoFilter,
+    },
+    ecdsa::{
+        ecdsa_public_key, sign_with_ecdsa, EcdsaCurve, EcdsaKeyId, EcdsaPublicKeyArgument,
+        SignWithEcdsaArgument,
+    },
+};
+use num_bigint::BigUint;
+use orbit_essentials::api::ApiError;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use station_api::TransferStatusTypeDTO;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+};
+
+/// The submitted transaction details key with the outpoints spent by the transaction, comma separated.
+pub const TRANSACTION_SUBMITTED_DETAILS_SPENT_OUTPOINTS_KEY: &str = "spent_outpoints";
+/// The submitted transaction details key with the output that is watched to count the confirmations.
+pub const TRANSACTION_SUBMITTED_DETAILS_TRACKED_OUTPOINT_KEY: &str = "tracked_outpoint";
+/// The submitted transaction details key with the address that holds the tracked output.
+pub const TRANSACTION_SUBMITTED_DETAILS_TRACKED_ADDRESS_KEY: &str = "tracked_address";
+/// The fee metadata key with the fee rate used to estimate the fee, in millisatoshi per vbyte.
+pub const TRANSACTION_FEE_METADATA_FEE_RATE_KEY: &str = "fee_rate";
+
+thread_local! {
+    /// The outpoints spent by the transactions sent for each account, kept until they leave the UTXO set.
+    static RESERVED_OUTPOINTS: RefCell<HashMap<AccountId, BTreeSet<String>>> = RefCell::new(HashMap::new());
+}
+
+/// The adapter of native Bitcoin accounts, backed by the Bitcoin API of the Internet Computer.
+///
+/// Each account holds the funds of a P2WPKH address derived from the threshold ECDSA key of the
+/// station, with the account id as the derivation path. Transactions are built and signed by the
+/// station and transfers are only completed once the transaction has `REQUIRED_CONFIRMATIONS`, unless
+/// a different finality threshold is configured for Bitcoin.
+///
+/// The testnet profile uses the Bitcoin testnet and the local profile the regtest network of dfx.
+#[derive(Debug)]
+pub struct Bitcoin {
+    profile: NetworkProfile,
+    network: BitcoinNetwork,
+}
+
+impl Bitcoin {
+    pub const BLOCKCHAIN: Blockchain = Blockchain::Bitcoin;
+    pub const STANDARD: BlockchainStandard = BlockchainStandard::Native;
+    pub const SYMBOL: &'static str = "BTC";
+    pub const DECIMALS: u32 = 8;
+    /// The default number of blocks on top of the transaction before the transfer is completed.
+    pub const REQUIRED_CONFIRMATIONS: u32 = 6;
+    /// Outputs below this amount are not relayed by the network, smaller change is left as fee.
+    pub const DUST_THRESHOLD: u64 = 546;
+    /// The virtual size of a transaction with one P2WPKH input and two P2WPKH outputs.
+    pub const TYPICAL_TRANSACTION_VSIZE: u64 = 141;
+    /// The fee rate used when the network has no fee percentiles (e.g. regtest), in millisatoshi per vbyte.
+    pub const FALLBACK_FEE_RATE: u64 = 2_000;
+    /// The maximum number of inputs of a transaction.
+    pub const MAX_INPUTS: usize = 100;
+    /// The maximum number of UTXO pages fetched for an address.
+    pub const MAX_UTXO_PAGES: usize = 10;
+
+    /// The sequence of the inputs, which signals that the transaction can be replaced by fee (BIP-125).
+    const INPUT_SEQUENCE: u32 = 0xffff_fffd;
+    const SIGHASH_ALL: u32 = 1;
+
+    pub fn create(profile: NetworkProfile) -> Self {
+        Self {
+            profile,
+            network: match profile {
+                NetworkProfile::Mainnet => BitcoinNetwork::Mainnet,
+                NetworkProfile::Testnet => BitcoinNetwork::Testnet,
+                NetworkProfile::Local => BitcoinNetwork::Regtest,
+            },
+        }
+    }
+
+    fn key_id(&self) -> EcdsaKeyId {
+        EcdsaKeyId {
+            curve: EcdsaCurve::Secp256k1,
+            name: match self.network {
+                BitcoinNetwork::Mainnet => "key_1",
+                BitcoinNetwork::Testnet => "test_key_1",
+                BitcoinNetwork::Regtest => "dfx_test_key",
+            }
+            .to_string(),
+        }
+    }
+
+    fn hrp(&self) -> Hrp {
+        match self.network {
+            BitcoinNetwork::Mainnet => hrp::BC,
+            BitcoinNetwork::Testnet => hrp::TB,
+            BitcoinNetwork::Regtest => hrp::BCRT,
+        }
+    }
+
+    fn derivation_path(station_account_id: &AccountId) -> Vec<Vec<u8>> {
+        vec![station_account_id.to_vec()]
+    }
+
+    fn network_error(err: (ic_cdk::api::call::RejectionCode, String)) -> BlockchainApiError {
+        BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        }
+    }
+
+    /// Returns the current fee rates of the network in millisatoshi per vbyte, by increasing percentile.
+    async fn fee_percentiles(&self) -> Result<Vec<u64>, BlockchainApiError> {
+        let (percentiles,) = bitcoin_get_current_fee_percentiles(GetCurrentFeePercentilesRequest {
+            network: self.network,
+        })
+        .await
+        .map_err(Self::network_error)?;
+
+        Ok(percentiles)
+    }
+
+    /// Returns the fee rate of the tier, which is the 25th, 50th or 75th percentile of the current fee rates.
+    fn tier_fee_rate(percentiles: &[u64], tier: BlockchainTransactionFeeTier) -> u64 {
+        let position = match tier {
+            BlockchainTransactionFeeTier::Slow => percentiles.len() / 4,
+            BlockchainTransactionFeeTier::Standard => percentiles.len() / 2,
+            BlockchainTransactionFeeTier::Fast => percentiles.len() * 3 / 4,
+        };
+
+        percentiles
+            .get(position)
+            .copied()
+            .unwrap_or(Self::FALLBACK_FEE_RATE)
+    }
+
+    /// The absolute fee of a typical transaction paying the given fee rate.
+    fn fee_for_rate(fee_rate: u64) -> BlockchainTransactionFee {
+        let fee = (fee_rate * Self::TYPICAL_TRANSACTION_VSIZE).div_ceil(1_000);
+
+        BlockchainTransactionFee {
+            fee: BigUint::from(fee),
+            metadata: Metadata::new(
+                [(
+                    TRANSACTION_FEE_METADATA_FEE_RATE_KEY.to_string(),
+                    fee_rate.to_string(),
+                )]
+                .into(),
+            ),
+        }
+    }
+
+    /// Returns the compressed public key derived for the account.
+    async fn public_key(&self, station_account: &Account) -> Result<Vec<u8>, BlockchainApiError> {
+        let (response,) = ecdsa_public_key(EcdsaPublicKeyArgument {
+            canister_id: None,
+            derivation_path: Self::derivation_path(&station_account.id),
+            key_id: self.key_id(),
+        })
+        .await
+        .map_err(Self::network_error)?;
+
+        Ok(response.public_key)
+    }
+
+    /// Returns the P2WPKH address of the given compressed public key.
+    pub fn p2wpkh_address(&self, public_key: &[u8]) -> Result<String, BlockchainApiError> {
+        segwit::encode(self.hrp(), segwit::VERSION_0, &hash160(public_key)).map_err(|err| {
+            BlockchainApiError::BlockchainNetworkError {
+                info: format!("Failed to encode the address: {}", err),
+            }
+        })
+    }
+
+    /// Returns the locking script of a native segwit address of the network (e.g. `bc1...`).
+    pub fn script_pubkey(&self, address: &str) -> Result<Vec<u8>, BlockchainApiError> {
+        let invalid_address = |error: String| BlockchainApiError::InvalidToAddress {
+            address: address.to_string(),
+            error,
+        };
+        let (address_hrp, version, program) =
+            segwit::decode(address).map_err(|err| invalid_address(err.to_string()))?;
+
+        if address_hrp != self.hrp() {
+            return Err(invalid_address(format!(
+                "the address is not a {:?} address",
+                self.network
+            )));
+        }
+
+        Ok(witness_script_pubkey(version, &program))
+    }
+
+    fn ensure_network(&self, blockchain_network: &str) -> Result<(), BlockchainApiError> {
+        let network = NetworkProfile::from_str(blockchain_network).map_err(|_| {
+            BlockchainApiError::TransactionSubmitFailed {
+                info: format!("Unsupported Bitcoin network `{}`", blockchain_network),
+            }
+        })?;
+
+        if network != self.profile {
+            return Err(BlockchainApiError::TransactionSubmitFailed {
+                info: format!(
+                    "The transfer network `{}` does not match the account network",
+                    blockchain_network
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns all the UTXOs of the address, fetching up to `MAX_UTXO_PAGES` pages.
+    async fn utxos(&self, address: &str) -> Result<GetUtxosResponse, BlockchainApiError> {
+        let (mut response,) = bitcoin_get_utxos(GetUtxosRequest {
+            address: address.to_string(),
+            network: self.network,
+            filter: None,
+        })
+        .await
+        .map_err(Self::network_error)?;
+
+        for _ in 1..Self::MAX_UTXO_PAGES {
+            let Some(page) = response.next_page.take() else {
+                break;
+            };
+
+            let (next,) = bitcoin_get_utxos(GetUtxosRequest {
+                address: address.to_string(),
+                network: self.network,
+                filter: Some(UtxoFilter::Page(page)),
+            })
+            .await
+            .map_err(Self::network_error)?;
+
+            response.utxos.extend(next.utxos);
+            response.next_page = next.next_page;
+        }
+
+        Ok(response)
+    }
+
+    /// Returns the outpoints that must not be spent, either because they are spent by a transaction
+    /// that is not yet confirmed or because they are watched to count its confirmations.
+    fn unspendable_outpoints(&self, station_account: &Account) -> BTreeSet<String> {
+        let mut outpoints = RESERVED_OUTPOINTS.with(|reserved| {
+            reserved
+                .borrow()
+                .get(&station_account.id)
+                .cloned()
+                .unwrap_or_default()
+        });
+
+        let submitted_transfers = [
+            TransferStatusTypeDTO::Processing,
+            TransferStatusTypeDTO::Confirming,
+        ]
+        .into_iter()
+        .flat_map(|status| {
+            TRANSFER_REPOSITORY.find_by_account(station_account.id, None, None, Some(status))
+        });
+
+        for transfer in submitted_transfers {
+            for (key, value) in transfer.submitted_details.unwrap_or_default() {
+                if key == TRANSACTION_SUBMITTED_DETAILS_SPENT_OUTPOINTS_KEY
+                    || key == TRANSACTION_SUBMITTED_DETAILS_TRACKED_OUTPOINT_KEY
+                {
+                    outpoints.extend(value.split(',').map(str::to_string));
+                }
+            }
+        }
+
+        outpoints
+    }
+
+    /// Reserves the outpoints spent by a sent transaction, and releases the previously reserved
+    /// outpoints that are no longer part of the UTXO set of the account.
+    fn reserve_outpoints(
+        station_account_id: &AccountId,
+        utxo_set: &BTreeSet<String>,
+        spent: &[String],
+    ) {
+        RESERVED_OUTPOINTS.with(|reserved| {
+            let mut reserved = reserved.borrow_mut();
+            let outpoints = reserved.entry(*station_account_id).or_default();
+
+            outpoints.retain(|outpoint| utxo_set.contains(outpoint));
+            outpoints.extend(spent.iter().cloned());
+        });
+    }
+
+    async fn sign(
+        &self,
+        station_account: &Account,
+        message_hash: [u8; 32],
+    ) -> Result<Vec<u8>, BlockchainApiError> {
+        let (response,) = sign_with_ecdsa(SignWithEcdsaArgument {
+            message_hash: message_hash.to_vec(),
+            derivation_path: Self::derivation_path(&station_account.id),
+            key_id: self.key_id(),
+        })
+        .await
+        .map_err(Self::network_error)?;
+
+        Ok(response.signature)
+    }
+}
+
+/// An unsigned transaction that spends P2WPKH outputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct UnsignedTransaction {
+    inputs: Vec<Utxo>,
+    outputs: Vec<(u64, Vec<u8>)>,
+}
+
+impl UnsignedTransaction {
+    const VERSION: u32 = 2;
+    const LOCK_TIME: u32 = 0;
+
+    /// The transaction id, which is displayed in reverse byte order.
+    fn txid(&self) -> String {
+        let mut txid = double_sha256(&self.serialize(None));
+        txid.reverse();
+
+        hex::encode(txid)
+    }
+
+    /// Serializes the transaction, with the segwit marker and witnesses if they are provided.
+    fn serialize(&self, witnesses: Option<&[Vec<Vec<u8>>]>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend(Self::VERSION.to_le_bytes());
+
+        if witnesses.is_some() {
+            buffer.extend([0x00, 0x01]);
+        }
+
+        write_compact_size(&mut buffer, self.inputs.len() as u64);
+        for input in &self.inputs {
+            buffer.extend(serialize_outpoint(&input.outpoint));
+            write_compact_size(&mut buffer, 0);
+            buffer.extend(Bitcoin::INPUT_SEQUENCE.to_le_bytes());
+        }
+
+        buffer.extend(self.serialize_outputs());
+
+        if let Some(witnesses) = witnesses {
+            for witness in witnesses {
+                write_compact_size(&mut buffer, witness.len() as u64);
+                for item in witness {
+                    write_compact_size(&mut buffer, item.len() as u64);
+                    buffer.extend(item);
+                }
+            }
+        }
+
+        buffer.extend(Self::LOCK_TIME.to_le_bytes());
+
+        buffer
+    }
+
+    fn serialize_outputs(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_compact_size(&mut buffer, self.outputs.len() as u64);
+        for (value, script_pubkey) in &self.outputs {
+            buffer.extend(value.to_le_bytes());
+            write_compact_size(&mut buffer, script_pubkey.len() as u64);
+            buffer.extend(script_pubkey);
+        }
+
+        buffer
+    }
+
+    /// The BIP-143 signature hash of a P2WPKH input with `SIGHASH_ALL`.
+    fn p2wpkh_sighash(&self, input_index: usize, public_key_hash: &[u8]) -> [u8; 32] {
+        let prevouts = self
+            .inputs
+            .iter()
+            .flat_map(|input| serialize_outpoint(&input.outpoint))
+            .collect::<Vec<u8>>();
+        let sequences = self
+            .inputs
+            .iter()
+            .flat_map(|_| Bitcoin::INPUT_SEQUENCE.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let outputs = self.serialize_outputs();
+        // the outputs are hashed without their count
+        let outputs = &outputs[compact_size_len(self.outputs.len() as u64)..];
+        let input = &self.inputs[input_index];
+
+        let mut preimage = Vec::new();
+        preimage.extend(Self::VERSION.to_le_bytes());
+        preimage.extend(double_sha256(&prevouts));
+        preimage.extend(double_sha256(&sequences));
+        preimage.extend(serialize_outpoint(&input.outpoint));
+        preimage.extend([0x19, 0x76, 0xa9, 0x14]);
+        preimage.extend(public_key_hash);
+        preimage.extend([0x88, 0xac]);
+        preimage.extend(input.value.to_le_bytes());
+        preimage.extend(Bitcoin::INPUT_SEQUENCE.to_le_bytes());
+        preimage.extend(double_sha256(outputs));
+        preimage.extend(Self::LOCK_TIME.to_le_bytes());
+        preimage.extend(Bitcoin::SIGHASH_ALL.to_le_bytes());
+
+        double_sha256(&preimage)
+    }
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    sha256(&sha256(data))
+}
+
+fn hash160(data: &[u8]) -> Vec<u8> {
+    Ripemd160::digest(sha256(data)).to_vec()
+}
+
+fn compact_size_len(value: u64) -> usize {
+    match value {
+        0..=0xfc => 1,
+        0xfd..=0xffff => 3,
+        0x1_0000..=0xffff_ffff => 5,
+        _ => 9,
+    }
+}
+
+fn write_compact_size(buffer: &mut Vec<u8>, value: u64) {
+    match value {
+        0..=0xfc => buffer.push(value as u8),
+        0xfd..=0xffff => {
+            buffer.push(0xfd);
+            buffer.extend((value as u16).to_le_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            buffer.push(0xfe);
+            buffer.extend((value as u32).to_le_bytes());
+        }
+        _ => {
+            buffer.push(0xff);
+            buffer.extend(value.to_le_bytes());
+        }
+    }
+}
+
+fn serialize_outpoint(outpoint: &Outpoint) -> Vec<u8> {
+    let mut buffer = outpoint.txid.clone();
+    buffer.extend(outpoint.vout.to_le_bytes());
+
+    buffer
+}
+
+/// The outpoint as `<txid>:<vout>`, with the transaction id in display order.
+fn outpoint_id(outpoint: &Outpoint) -> String {
+    let mut txid = outpoint.txid.clone();
+    txid.reverse();
+
+    format!("{}:{}", hex::encode(txid), outpoint.vout)
+}
+
+/// The locking script of a segwit output, `OP_n <program>`.
+fn witness_script_pubkey(version: Fe32, program: &[u8]) -> Vec<u8> {
+    let version = version.to_u8();
+    let mut script = vec![match version {
+        0 => 0x00,
+        version => 0x50 + version,
+    }];
+    script.push(program.len() as u8);
+    script.extend(program);
+
+    script
+}
+
+/// Encodes a raw `r || s` signature in DER, with `s` normalized to the lower half of the curve order
+/// as required by the relay policy of the network.
+fn to_der_signature(signature: &[u8]) -> Vec<u8> {
+    let order = BigUint::parse_bytes(
+        b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        16,
+    )
+    .expect("invalid curve order");
+    let r = BigUint::from_bytes_be(&signature[..32]);
+    let mut s = BigUint::from_bytes_be(&signature[32..]);
+    if s > &order >> 1 {
+        s = &order - s;
+    }
+
+    let encode_integer = |value: &BigUint| {
+        let mut bytes = value.to_bytes_be();
+        if bytes[0] & 0x80 != 0 {
+            bytes.insert(0, 0x00);
+        }
+
+        let mut integer = vec![0x02, bytes.len() as u8];
+        integer.extend(bytes);
+        integer
+    };
+
+    let mut body = encode_integer(&r);
+    body.extend(encode_integer(&s));
+
+    let mut der = vec![0x30, body.len() as u8];
+    der.extend(body);
+
+    der
+}
+
+/// Selects the largest UTXOs until they cover the target amount.
+fn select_utxos(mut utxos: Vec<Utxo>, target: u64) -> Result<Vec<Utxo>, BlockchainApiError> {
+    utxos.sort_by(|a, b| b.value.cmp(&a.value));
+
+    let mut selected = Vec::new();
+    let mut total = 0u64;
+    for utxo in utxos {
+        if total >= target {
+            break;
+        }
+
+        total = total.saturating_add(utxo.value);
+        selected.push(utxo);
+    }
+
+    if total < target {
+        return Err(BlockchainApiError::TransactionSubmitFailed {
+            info: format!(
+                "Insufficient confirmed funds, {} satoshis available but {} required",
+                total, target
+            ),
+        });
+    }
+
+    if selected.len() > Bitcoin::MAX_INPUTS {
+        return Err(BlockchainApiError::TransactionSubmitFailed {
+            info: format!(
+                "The transfer requires more than {} inputs",
+                Bitcoin::MAX_INPUTS
+            ),
+        });
+    }
+
+    Ok(selected)
+}
+
+fn details_value<'a>(details: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    details
+        .iter()
+        .find(|(detail_key, _)| detail_key == key)
+        .map(|(_, value)| value.as_str())
+}
+
+#[async_trait]
+impl BlockchainApi for Bitcoin {
+    async fn generate_address(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        let public_key = self.public_key(station_account).await?;
+
+        Ok(self.p2wpkh_address(&public_key)?)
+    }
+
+    async fn balance(&self, station_account: &Account) -> BlockchainApiResult<BigUint> {
+        let (balance,) = bitcoin_get_balance(GetBalanceRequest {
+            address: station_account.address.clone(),
+            network: self.network,
+            min_confirmations: None,
+        })
+        .await
+        .map_err(|_| BlockchainApiError::FetchBalanceFailed {
+            account_id: uuid::Uuid::from_bytes(station_account.id)
+                .hyphenated()
+                .to_string(),
+        })?;
+
+        Ok(BigUint::from(balance))
+    }
+
+    async fn decimals(&self, _station_account: &Account) -> BlockchainApiResult<u32> {
+        Ok(Self::DECIMALS)
+    }
+
+    /// The fee of a typical transaction at the median fee rate of the recent transactions.
+    async fn transaction_fee(
+        &self,
+        _station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainTransactionFee> {
+        let percentiles = self.fee_percentiles().await?;
+
+        Ok(Self::fee_for_rate(Self::tier_fee_rate(
+            &percentiles,
+            BlockchainTransactionFeeTier::Standard,
+        )))
+    }
+
+    async fn transaction_fee_options(
+        &self,
+        _station_account: &Account,
+    ) -> BlockchainApiResult<Vec<BlockchainTransactionFeeOption>> {
+        let percentiles = self.fee_percentiles().await?;
+
+        Ok(BlockchainTransactionFeeTier::ALL
+            .into_iter()
+            .map(|tier| BlockchainTransactionFeeOption {
+                tier,
+                fee: Self::fee_for_rate(Self::tier_fee_rate(&percentiles, tier)),
+            })
+            .collect())
+    }
+
+    fn default_network(&self) -> String {
+        self.profile.to_string()
+    }
+
+    /// Sends a transaction that pays the amount to the destination and the change back to the account,
+    /// the fee of the transfer is the absolute fee of the transaction.
+    async fn submit_transaction(
+        &self,
+        station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        self.ensure_network(&transfer.blockchain_network)?;
+
+        let to_script_pubkey = self.script_pubkey(&transfer.to_address)?;
+        let amount = HelperMapper::nat_to_u64(transfer.amount.clone())?;
+        let fee = HelperMapper::nat_to_u64(transfer.fee.clone())?;
+        if amount < Self::DUST_THRESHOLD {
+            Err(BlockchainApiError::TransactionSubmitFailed {
+                info: format!(
+                    "The amount must be at least {} satoshis",
+                    Self::DUST_THRESHOLD
+                ),
+            })?
+        }
+
+        let public_key = self.public_key(station_account).await?;
+        let public_key_hash = hash160(&public_key);
+        let change_script_pubkey = witness_script_pubkey(segwit::VERSION_0, &public_key_hash);
+
+        let utxo_set = self.utxos(&station_account.address).await?;
+        let unspendable = self.unspendable_outpoints(station_account);
+        let utxo_ids = utxo_set
+            .utxos
+            .iter()
+            .map(|utxo| outpoint_id(&utxo.outpoint))
+            .collect::<BTreeSet<String>>();
+        let spendable = utxo_set
+            .utxos
+            .into_iter()
+            .filter(|utxo| !unspendable.contains(&outpoint_id(&utxo.outpoint)))
+            .collect::<Vec<Utxo>>();
+
+        let inputs = select_utxos(spendable, amount.saturating_add(fee))?;
+        let total = inputs.iter().map(|utxo| utxo.value).sum::<u64>();
+        let change = total - amount - fee;
+
+        let mut outputs = vec![(amount, to_script_pubkey)];
+        if change >= Self::DUST_THRESHOLD {
+            outputs.push((change, change_script_pubkey));
+        }
+        let transaction = UnsignedTransaction { inputs, outputs };
+
+        let mut witnesses = Vec::new();
+        for input_index in 0..transaction.inputs.len() {
+            let sighash = transaction.p2wpkh_sighash(input_index, &public_key_hash);
+            let mut signature = to_der_signature(&self.sign(station_account, sighash).await?);
+            signature.push(Self::SIGHASH_ALL as u8);
+
+            witnesses.push(vec![signature, public_key.clone()]);
+        }
+
+        let spent_outpoints = transaction
+            .inputs
+            .iter()
+            .map(|utxo| outpoint_id(&utxo.outpoint))
+            .collect::<Vec<String>>();
+        // reserved before sending, since other transfers of the account can be submitted concurrently
+        Self::reserve_outpoints(&station_account.id, &utxo_ids, &spent_outpoints);
+
+        let txid = transaction.txid();
+        if let Err(err) = bitcoin_send_transaction(SendTransactionRequest {
+            transaction: transaction.serialize(Some(&witnesses)),
+            network: self.network,
+        })
+        .await
+        {
+            RESERVED_OUTPOINTS.with(|reserved| {
+                if let Some(outpoints) = reserved.borrow_mut().get_mut(&station_account.id) {
+                    outpoints.retain(|outpoint| !spent_outpoints.contains(outpoint));
+                }
+            });
+
+            Err(BlockchainApiError::TransactionSubmitFailed {
+                info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+            })?
+        }
+
+        // the change output is watched when there is one, since it can't be spent by the recipient
+        let (tracked_vout, tracked_address) = match transaction.outputs.len() {
+            2 => (1, station_account.address.clone()),
+            _ => (0, transfer.to_address.clone()),
+        };
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY.to_string(),
+                    txid.clone(),
+                ),
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_SPENT_OUTPOINTS_KEY.to_string(),
+                    spent_outpoints.join(","),
+                ),
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_TRACKED_OUTPOINT_KEY.to_string(),
+                    format!("{}:{}", txid, tracked_vout),
+                ),
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_TRACKED_ADDRESS_KEY.to_string(),
+                    tracked_address,
+                ),
+            ],
+        })
+    }
+
+    async fn find_transaction(
+        &self,
+        _station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionLookup> {
+        match &transfer.submitted_details {
+            Some(details) => Ok(BlockchainTransactionLookup::Found(
+                BlockchainTransactionSubmitted {
+                    details: details.clone(),
+                },
+            )),
+            None => Err(BlockchainApiError::TransactionLookupFailed {
+                info: "Bitcoin transactions can only be looked up once submitted".to_string(),
+            }
+            .into()),
+        }
+    }
+
+    async fn approve(
+        &self,
+        _station_account: &Account,
+        _approval: &ApproveOperationInput,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        Err(BlockchainApiError::TransactionSubmitFailed {
+            info: "Bitcoin does not support allowances".to_string(),
+        }
+        .into())
+    }
+
+    fn required_confirmations(&self) -> u32 {
+        Self::REQUIRED_CONFIRMATIONS
+    }
+
+    /// Counts the confirmations from the height of the block that included the tracked output, which
+    /// is recorded in the details the first time the output is seen in the UTXO set.
+    ///
+    /// The transaction is always reported as pending, the transfer is completed once the confirmations
+    /// reach the finality threshold of Bitcoin.
+    async fn transaction_confirmation(
+        &self,
+        _station_account: &Account,
+        submitted: &BlockchainTransactionSubmitted,
+    ) -> Result<BlockchainTransactionConfirmation, ApiError> {
+        let (Some(tracked_outpoint), Some(tracked_address)) = (
+            details_value(
+                &submitted.details,
+                TRANSACTION_SUBMITTED_DETAILS_TRACKED_OUTPOINT_KEY,
+            ),
+            details_value(
+                &submitted.details,
+                TRANSACTION_SUBMITTED_DETAILS_TRACKED_ADDRESS_KEY,
+            ),
+        ) else {
+            return Err(BlockchainApiError::TransactionLookupFailed {
+                info: "The submitted transaction has no tracked output".to_string(),
+            }
+            .into());
+        };
+
+        let utxo_set = self.utxos(tracked_address).await?;
+        let block_height = match details_value(
+            &submitted.details,
+            TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+        ) {
+            Some(block_height) => Some(HelperMapper::to_u64(block_height)? as u32),
+            None => utxo_set
+                .utxos
+                .iter()
+                .find(|utxo| outpoint_id(&utxo.outpoint) == tracked_outpoint)
+                .map(|utxo| utxo.height),
+        };
+
+        let confirmations = block_height
+            .map(|height| utxo_set.tip_height.saturating_sub(height) + 1)
+            .unwrap_or_default();
+
+        let mut details = submitted.details.clone();
+        if let Some(height) = block_height {
+            details.retain(|(key, _)| key != TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY);
+            details.push((
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                height.to_string(),
+            ));
+        }
+
+        Ok(BlockchainTransactionConfirmation::Pending {
+            confirmations,
+            details: BlockchainTransactionSubmitted { details },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_utxo(txid: u8, value: u64) -> Utxo {
+        Utxo {
+            outpoint: Outpoint {
+                txid: vec![txid; 32],
+                vout: 0,
+            },
+            value,
+            height: 100,
+        }
+    }
+
+    #[test]
+    fn fee_tiers_follow_the_fee_percentiles() {
+        let percentiles = (1..=100).map(|rate| rate * 1_000).collect::<Vec<_>>();
+
+        assert_eq!(
+            Bitcoin::tier_fee_rate(&percentiles, BlockchainTransactionFeeTier::Slow),
+            26_000
+        );
+        assert_eq!(
+            Bitcoin::tier_fee_rate(&percentiles, BlockchainTransactionFeeTier::Standard),
+            51_000
+        );
+        assert_eq!(
+            Bitcoin::tier_fee_rate(&percentiles, BlockchainTransactionFeeTier::Fast),
+            76_000
+        );
+        assert_eq!(
+            Bitcoin::tier_fee_rate(&[], BlockchainTransactionFeeTier::Fast),
+            Bitcoin::FALLBACK_FEE_RATE
+        );
+        assert_eq!(
+            Bitcoin::fee_for_rate(51_000).fee,
+            BigUint::from(51 * Bitcoin::TYPICAL_TRANSACTION_VSIZE)
+        );
+    }
+
+    #[test]
+    fn derives_p2wpkh_address() {
+        let public_key =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+
+        assert_eq!(
+            Bitcoin::create(NetworkProfile::Mainnet)
+                .p2wpkh_address(&public_key)
+                .unwrap(),
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        );
+    }
+
+    #[test]
+    fn only_native_segwit_addresses_of_the_network_are_supported() {
+        let bitcoin = Bitcoin::create(NetworkProfile::Mainnet);
+
+        assert_eq!(
+            hex::encode(
+                bitcoin
+                    .script_pubkey("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4")
+                    .unwrap()
+            ),
+            "0014751e76e8199196d454941c45d1b3a323f1433bd6"
+        );
+        assert!(bitcoin
+            .script_pubkey("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx")
+            .is_err());
+        assert!(bitcoin
+            .script_pubkey("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2")
+            .is_err());
+    }
+
+    #[test]
+    fn der_signature_uses_low_s() {
+        let mut signature = vec![0x80; 32];
+        signature.extend(vec![0xee; 32]);
+
+        let der = to_der_signature(&signature);
+
+        assert_eq!(der[0], 0x30);
+        assert_eq!(der[1] as usize, der.len() - 2);
+        // r has the high bit set and is padded
+        assert_eq!(&der[2..5], &[0x02, 33, 0x00]);
+        // s is above the half order, so it is replaced by `n - s`
+        assert_eq!(der[37], 0x02);
+        let s_len = der[38] as usize;
+        assert_eq!(der[39], 0x11);
+        assert_eq!(der.len(), 39 + s_len);
+    }
+
+    #[test]
+    fn selects_largest_utxos_first() {
+        let selected = select_utxos(
+            vec![
+                mock_utxo(1, 1_000),
+                mock_utxo(2, 50_000),
+                mock_utxo(3, 20_000),
+            ],
+            60_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected.iter().map(|utxo| utxo.value).collect::<Vec<_>>(),
+            vec![50_000, 20_000]
+        );
+        assert!(select_utxos(vec![mock_utxo(1, 1_000)], 60_000).is_err());
+    }
+
+    #[test]
+    fn segwit_serialization_keeps_txid_stable() {
+        let transaction = UnsignedTransaction {
+            inputs: vec![mock_utxo(1, 50_000)],
+            outputs: vec![(40_000, vec![0x00, 0x14, 0x01])],
+        };
+        let witnesses = vec![vec![vec![0x01, 0x02], vec![0x03]]];
+
+        let signed = transaction.serialize(Some(&witnesses));
+        let unsigned = transaction.serialize(None);
+
+        assert_eq!(&signed[4..6], &[0x00, 0x01]);
+        assert_eq!(signed.len(), unsigned.len() + 2 + 1 + 3 + 2);
+        assert_eq!(transaction.txid().len(), 64);
+    }
+}
@@ -0,0 +1,775 @@
+use super::{
+    cycles_ledger_withdraw, icrc1_balance_of, icrc1_decimals, icrc1_fee, icrc1_transfer,
+    icrc2_allowance, icrc2_approve, icrc2_transfer_from, AllowanceArgs, ApproveArgs,
+    BlockchainAllowance, BlockchainAllowanceSpender, BlockchainApi, BlockchainApiResult,
+    BlockchainPendingDeposit, BlockchainTransactionFee, BlockchainTransactionLookup,
+    BlockchainTransactionSubmitted, CyclesLedger, CyclesLedgerWithdrawArgs, Icrc1TransferArgs,
+    InternetComputer, TransferFromArgs, TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+};
+use crate::{
+    core::ic_cdk::{api::id as station_canister_self_id, next_time},
+    errors::BlockchainApiError,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, ApproveOperationInput, Blockchain, BlockchainStandard, IcrcAccount,
+        Metadata, NetworkProfile, Transfer, ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+    },
+    services::ASSET_SERVICE,
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Principal};
+use num_bigint::BigUint;
+use orbit_essentials::types::Timestamp;
+use std::{cell::RefCell, collections::HashMap, str::FromStr};
+
+/// The account metadata key of the Bitcoin address used to deposit BTC into a ckBTC account.
+pub const ACCOUNT_METADATA_BTC_DEPOSIT_ADDRESS_KEY: &str = "btc_deposit_address";
+
+thread_local! {
+    /// The latest `update_balance` call made to the minter for each account, reset on upgrades.
+    static UPDATE_BALANCE_CALLS: RefCell<HashMap<AccountId, UpdateBalanceCall>> = RefCell::new(HashMap::new());
+}
+
+/// The outcome of the latest `update_balance` call made to the minter for an account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateBalanceCall {
+    pub called_at: Timestamp,
+    pub pending_deposits: Vec<BlockchainPendingDeposit>,
+}
+
+/// Returns the latest `update_balance` call made to the minter for the given account.
+pub fn last_update_balance_call(account_id: &AccountId) -> Option<UpdateBalanceCall> {
+    UPDATE_BALANCE_CALLS.with(|calls| calls.borrow().get(account_id).cloned())
+}
+
+fn record_update_balance_call(account_id: AccountId, call: UpdateBalanceCall) {
+    UPDATE_BALANCE_CALLS.with(|calls| {
+        calls.borrow_mut().insert(account_id, call);
+    });
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct MinterAccountArgs {
+    owner: Option<Principal>,
+    subaccount: Option<Vec<u8>>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct Outpoint {
+    txid: Vec<u8>,
+    vout: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct Utxo {
+    outpoint: Outpoint,
+    value: u64,
+    height: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct PendingUtxo {
+    outpoint: Outpoint,
+    value: u64,
+    confirmations: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum UtxoStatus {
+    ValueTooSmall(Utxo),
+    Tainted(Utxo),
+    Checked(Utxo),
+    Minted {
+        block_index: u64,
+        minted_amount: u64,
+        utxo: Utxo,
+    },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum UpdateBalanceError {
+    GenericError {
+        error_code: u64,
+        error_message: String,
+    },
+    TemporarilyUnavailable(String),
+    AlreadyProcessing,
+    NoNewUtxos {
+        current_confirmations: Option<u32>,
+        required_confirmations: u32,
+        pending_utxos: Option<Vec<PendingUtxo>>,
+    },
+}
+
+impl Outpoint {
+    /// Bitcoin transaction ids are displayed in reverse byte order.
+    fn to_deposit_id(&self) -> String {
+        let mut txid = self.txid.clone();
+        txid.reverse();
+
+        format!("{}:{}", hex::encode(txid), self.vout)
+    }
+}
+
+/// The adapter of ckBTC accounts, the ICRC-1 token on the Internet Computer that is backed by BTC.
+///
+/// BTC is deposited to the Bitcoin address derived by the ckBTC minter for the account, and minted as
+/// ckBTC once the minter is notified with `update_balance` and the deposit has enough confirmations.
+///
+/// The adapter also serves the accounts of the SNS tokens discovered by the asset service and of the
+/// cycles ledger, which are plain ICRC-1 tokens without deposit addresses.
+///
+/// The testnet accounts hold ckTESTBTC, which is backed by testnet BTC, local replicas have no ckBTC.
+#[derive(Debug)]
+pub struct CkBtc {
+    station_canister_id: Principal,
+    network: NetworkProfile,
+}
+
+impl CkBtc {
+    pub const BLOCKCHAIN: Blockchain = Blockchain::InternetComputer;
+    pub const STANDARD: BlockchainStandard = BlockchainStandard::ICRC1;
+    pub const SYMBOL: &'static str = "ckBTC";
+    pub const DECIMALS: u32 = 8;
+    pub const LEDGER_CANISTER_ID: &'static str = "mxzaz-hqaaa-aaaar-qaada-cai";
+    pub const MINTER_CANISTER_ID: &'static str = "mqygn-cqaaa-aaaar-qaadq-cai";
+    pub const TESTNET_LEDGER_CANISTER_ID: &'static str = "mc6ru-gyaaa-aaaar-qaaaq-cai";
+    pub const TESTNET_MINTER_CANISTER_ID: &'static str = "ml52i-qqaaa-aaaar-qaaba-cai";
+    /// The minimum time between two `update_balance` calls for the same account, the minter rate limits them.
+    pub const UPDATE_BALANCE_INTERVAL_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    pub fn create(network: NetworkProfile) -> Self {
+        Self {
+            station_canister_id: station_canister_self_id(),
+            network,
+        }
+    }
+
+    /// The mainnet ckBTC ledger.
+    pub fn ledger_canister_id() -> Principal {
+        Principal::from_text(Self::LEDGER_CANISTER_ID).unwrap()
+    }
+
+    /// Returns the ckBTC ledger and minter of the network, if any.
+    pub fn ckbtc_canisters(network: &NetworkProfile) -> Option<(Principal, Principal)> {
+        let (ledger, minter) = match network {
+            NetworkProfile::Mainnet => (Self::LEDGER_CANISTER_ID, Self::MINTER_CANISTER_ID),
+            NetworkProfile::Testnet => (
+                Self::TESTNET_LEDGER_CANISTER_ID,
+                Self::TESTNET_MINTER_CANISTER_ID,
+            ),
+            NetworkProfile::Local => return None,
+        };
+
+        Some((
+            Principal::from_text(ledger).unwrap(),
+            Principal::from_text(minter).unwrap(),
+        ))
+    }
+
+    fn minter_canister_id(&self) -> Result<Principal, BlockchainApiError> {
+        Self::ckbtc_canisters(&self.network)
+            .map(|(_, minter)| minter)
+            .ok_or(BlockchainApiError::BlockchainNetworkError {
+                info: format!("There is no ckBTC minter on the {} network", self.network),
+            })
+    }
+
+    /// Ensures the account holds the ckBTC of its network, which is the only ICRC-1 ledger with BTC deposits.
+    fn ensure_ckbtc_account(&self, station_account: &Account) -> Result<(), BlockchainApiError> {
+        let ledger_canister_id = station_account
+            .metadata
+            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+            .unwrap_or_default();
+
+        match Principal::from_text(&ledger_canister_id) {
+            Ok(ledger) if self.is_ckbtc_ledger(&ledger) => Ok(()),
+            _ => Err(BlockchainApiError::UnsupportedLedger { ledger_canister_id }),
+        }
+    }
+
+    /// Returns the ledger of the account, which is either the ckBTC ledger, the cycles ledger, a
+    /// discovered SNS ledger or a registered ledger.
+    fn account_ledger(&self, station_account: &Account) -> Result<Principal, BlockchainApiError> {
+        let ledger_canister_id = station_account
+            .metadata
+            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+            .unwrap_or_default();
+
+        match Principal::from_text(&ledger_canister_id) {
+            Ok(ledger)
+                if self.is_ckbtc_ledger(&ledger)
+                    || CyclesLedger::is_cycles_ledger(&ledger)
+                    || ASSET_SERVICE.is_supported_sns_ledger(&ledger)
+                    || ASSET_SERVICE.is_registered_ledger(&ledger) =>
+            {
+                Ok(ledger)
+            }
+            _ => Err(BlockchainApiError::UnsupportedLedger { ledger_canister_id }),
+        }
+    }
+
+    fn is_ckbtc_ledger(&self, ledger: &Principal) -> bool {
+        Self::ckbtc_canisters(&self.network)
+            .is_some_and(|(ckbtc_ledger, _)| ckbtc_ledger == *ledger)
+    }
+
+    /// The ICRC-1 account of the station account, which uses the same subaccount as the ICP accounts.
+    pub fn station_account_to_icrc_account(&self, station_account_id: &AccountId) -> IcrcAccount {
+        IcrcAccount::new(
+            self.station_canister_id,
+            Some(InternetComputer::subaccount_from_station_account_id(
+                station_account_id,
+            )),
+        )
+    }
+
+    /// The ICRC-1 account of the subaccount of the station account with the given index.
+    pub fn station_subaccount_to_icrc_account(
+        &self,
+        station_account_id: &AccountId,
+        index: u32,
+    ) -> IcrcAccount {
+        IcrcAccount::new(
+            self.station_canister_id,
+            Some(
+                InternetComputer::subaccount_from_station_account_id_and_index(
+                    station_account_id,
+                    index,
+                ),
+            ),
+        )
+    }
+
+    /// Withdraws cycles of the cycles ledger account to the canister and returns the withdrawal block index.
+    pub async fn withdraw_cycles(
+        &self,
+        station_account: &Account,
+        canister_id: Principal,
+        cycles: u128,
+    ) -> BlockchainApiResult<u64> {
+        let ledger = self.account_ledger(station_account)?;
+        if !CyclesLedger::is_cycles_ledger(&ledger) {
+            Err(BlockchainApiError::UnsupportedLedger {
+                ledger_canister_id: ledger.to_text(),
+            })?;
+        }
+
+        let block_index = cycles_ledger_withdraw(CyclesLedgerWithdrawArgs {
+            from_subaccount: Some(
+                InternetComputer::subaccount_from_station_account_id(&station_account.id).to_vec(),
+            ),
+            to: canister_id,
+            amount: cycles.into(),
+            created_at_time: Some(next_time()),
+        })
+        .await?;
+
+        Ok(HelperMapper::nat_to_u64(block_index)?)
+    }
+
+    fn minter_account_args(&self, station_account: &Account) -> MinterAccountArgs {
+        MinterAccountArgs {
+            owner: Some(self.station_canister_id),
+            subaccount: Some(
+                InternetComputer::subaccount_from_station_account_id(&station_account.id).to_vec(),
+            ),
+        }
+    }
+
+    /// Returns the Bitcoin address derived by the minter to deposit BTC into the account.
+    pub async fn btc_deposit_address(
+        &self,
+        station_account: &Account,
+    ) -> Result<String, BlockchainApiError> {
+        self.ensure_ckbtc_account(station_account)?;
+
+        let (address,): (String,) = ic_cdk::call(
+            self.minter_canister_id()?,
+            "get_btc_address",
+            (self.minter_account_args(station_account),),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        Ok(address)
+    }
+
+    /// Notifies the minter of new deposits with `update_balance` and returns the deposits that are
+    /// still waiting for confirmations.
+    ///
+    /// The minter is called at most once every `UPDATE_BALANCE_INTERVAL_NS` per account, otherwise the
+    /// outcome of the latest call is returned.
+    pub async fn update_balance(
+        &self,
+        station_account: &Account,
+    ) -> Result<Vec<BlockchainPendingDeposit>, BlockchainApiError> {
+        self.ensure_ckbtc_account(station_account)?;
+
+        let now = next_time();
+        let previous_call = last_update_balance_call(&station_account.id);
+
+        if let Some(call) = &previous_call {
+            if now.saturating_sub(call.called_at) < Self::UPDATE_BALANCE_INTERVAL_NS {
+                return Ok(call.pending_deposits.to_owned());
+            }
+        }
+
+        let (result,): (Result<Vec<UtxoStatus>, UpdateBalanceError>,) = ic_cdk::call(
+            self.minter_canister_id()?,
+            "update_balance",
+            (self.minter_account_args(station_account),),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let pending_deposits = match result {
+            // new deposits were processed, the minted ones are now part of the balance
+            Ok(_) => Vec::new(),
+            Err(UpdateBalanceError::NoNewUtxos {
+                required_confirmations,
+                pending_utxos,
+                ..
+            }) => pending_utxos
+                .unwrap_or_default()
+                .into_iter()
+                .map(|utxo| BlockchainPendingDeposit {
+                    id: utxo.outpoint.to_deposit_id(),
+                    amount: BigUint::from(utxo.value),
+                    confirmations: utxo.confirmations,
+                    required_confirmations: Some(required_confirmations),
+                })
+                .collect(),
+            Err(UpdateBalanceError::AlreadyProcessing)
+            | Err(UpdateBalanceError::TemporarilyUnavailable(_)) => previous_call
+                .map(|call| call.pending_deposits)
+                .unwrap_or_default(),
+            Err(UpdateBalanceError::GenericError {
+                error_code,
+                error_message,
+            }) => Err(BlockchainApiError::BlockchainNetworkError {
+                info: format!("Error code {}: {}", error_code, error_message),
+            })?,
+        };
+
+        record_update_balance_call(
+            station_account.id,
+            UpdateBalanceCall {
+                called_at: now,
+                pending_deposits: pending_deposits.to_owned(),
+            },
+        );
+
+        Ok(pending_deposits)
+    }
+}
+
+#[async_trait]
+impl BlockchainApi for CkBtc {
+    async fn generate_address(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        self.account_ledger(station_account)?;
+
+        Ok(self
+            .station_account_to_icrc_account(&station_account.id)
+            .to_string())
+    }
+
+    async fn balance(&self, station_account: &Account) -> BlockchainApiResult<BigUint> {
+        let ledger = self.account_ledger(station_account)?;
+
+        let balance = icrc1_balance_of(
+            ledger,
+            &self.station_account_to_icrc_account(&station_account.id),
+        )
+        .await
+        .map_err(|_| BlockchainApiError::FetchBalanceFailed {
+            account_id: uuid::Uuid::from_bytes(station_account.id)
+                .hyphenated()
+                .to_string(),
+        })?;
+
+        Ok(balance.0)
+    }
+
+    async fn generate_subaccount_address(
+        &self,
+        station_account: &Account,
+        index: u32,
+    ) -> BlockchainApiResult<String> {
+        self.account_ledger(station_account)?;
+
+        Ok(self
+            .station_subaccount_to_icrc_account(&station_account.id, index)
+            .to_string())
+    }
+
+    async fn subaccount_balance(
+        &self,
+        station_account: &Account,
+        index: u32,
+    ) -> BlockchainApiResult<BigUint> {
+        let ledger = self.account_ledger(station_account)?;
+
+        let balance = icrc1_balance_of(
+            ledger,
+            &self.station_subaccount_to_icrc_account(&station_account.id, index),
+        )
+        .await
+        .map_err(|_| BlockchainApiError::FetchBalanceFailed {
+            account_id: uuid::Uuid::from_bytes(station_account.id)
+                .hyphenated()
+                .to_string(),
+        })?;
+
+        Ok(balance.0)
+    }
+
+    async fn decimals(&self, station_account: &Account) -> BlockchainApiResult<u32> {
+        let ledger = self.account_ledger(station_account)?;
+        if self.is_ckbtc_ledger(&ledger) {
+            return Ok(Self::DECIMALS);
+        }
+
+        if CyclesLedger::is_cycles_ledger(&ledger) {
+            return Ok(CyclesLedger::DECIMALS);
+        }
+
+        if let Some(asset) = ASSET_SERVICE.find_registered_asset_by_ledger(&ledger) {
+            return Ok(asset.decimals);
+        }
+
+        match ASSET_SERVICE.find_sns_token_by_ledger(&ledger) {
+            Some(token) => Ok(token.decimals),
+            None => Ok(icrc1_decimals(ledger).await? as u32),
+        }
+    }
+
+    async fn transaction_fee(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainTransactionFee> {
+        let fee = icrc1_fee(self.account_ledger(station_account)?).await?;
+
+        Ok(BlockchainTransactionFee {
+            fee: fee.0,
+            metadata: Metadata::default(),
+        })
+    }
+
+    fn default_network(&self) -> String {
+        self.network.to_string()
+    }
+
+    async fn submit_transaction(
+        &self,
+        station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        let ledger = self.account_ledger(station_account)?;
+
+        let memo = InternetComputer::icrc1_transfer_memo(transfer)?;
+        let created_at_time = InternetComputer::transfer_created_at_time(transfer);
+        let to = IcrcAccount::from_str(&transfer.to_address).map_err(|error| {
+            BlockchainApiError::InvalidToAddress {
+                address: transfer.to_address.clone(),
+                error,
+            }
+        })?;
+        let station_subaccount =
+            InternetComputer::subaccount_from_station_account_id(&station_account.id).to_vec();
+
+        let block_index = match &transfer.spend_from {
+            Some(from) => {
+                icrc2_transfer_from(
+                    ledger,
+                    TransferFromArgs {
+                        spender_subaccount: Some(station_subaccount),
+                        from: from.into(),
+                        to: (&to).into(),
+                        amount: transfer.amount.clone(),
+                        fee: Some(transfer.fee.clone()),
+                        memo: Some(memo),
+                        created_at_time: Some(created_at_time),
+                    },
+                )
+                .await?
+            }
+            None => {
+                icrc1_transfer(
+                    ledger,
+                    Icrc1TransferArgs {
+                        from_subaccount: Some(station_subaccount),
+                        to: (&to).into(),
+                        amount: transfer.amount.clone(),
+                        fee: Some(transfer.fee.clone()),
+                        memo: Some(memo),
+                        created_at_time: Some(created_at_time),
+                    },
+                )
+                .await?
+            }
+        };
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![(
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                HelperMapper::nat_to_u64(block_index)?.to_string(),
+            )],
+        })
+    }
+
+    async fn find_transaction(
+        &self,
+        _station_account: &Account,
+        _transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionLookup> {
+        Err(BlockchainApiError::TransactionLookupFailed {
+            info: "ckBTC transfers can not be looked up".to_string(),
+        }
+        .into())
+    }
+
+    async fn approve(
+        &self,
+        station_account: &Account,
+        approval: &ApproveOperationInput,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        let ledger = self.account_ledger(station_account)?;
+
+        let block_index = icrc2_approve(
+            ledger,
+            ApproveArgs {
+                from_subaccount: Some(
+                    InternetComputer::subaccount_from_station_account_id(&station_account.id)
+                        .to_vec(),
+                ),
+                spender: (&approval.spender).into(),
+                amount: approval.amount.clone(),
+                expected_allowance: approval.expected_allowance.clone(),
+                expires_at: approval.expires_at,
+                fee: approval.fee.clone(),
+                memo: None,
+                created_at_time: Some(next_time()),
+            },
+        )
+        .await?;
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![(
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                HelperMapper::nat_to_u64(block_index)?.to_string(),
+            )],
+        })
+    }
+
+    fn allowance_spender(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainAllowanceSpender> {
+        Ok(BlockchainAllowanceSpender {
+            ledger_canister_id: self.account_ledger(station_account)?,
+            spender: self.station_account_to_icrc_account(&station_account.id),
+        })
+    }
+
+    async fn allowance(
+        &self,
+        station_account: &Account,
+        owner: &IcrcAccount,
+    ) -> BlockchainApiResult<BlockchainAllowance> {
+        let spender = self.allowance_spender(station_account)?;
+        let allowance = icrc2_allowance(
+            spender.ledger_canister_id,
+            AllowanceArgs {
+                account: owner.into(),
+                spender: (&spender.spender).into(),
+            },
+        )
+        .await?;
+
+        Ok(BlockchainAllowance {
+            amount: allowance.allowance.0,
+            expires_at: allowance.expires_at,
+        })
+    }
+
+    async fn deposit_addresses(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<Vec<(String, String)>> {
+        if !self.is_ckbtc_ledger(&self.account_ledger(station_account)?) {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![(
+            ACCOUNT_METADATA_BTC_DEPOSIT_ADDRESS_KEY.to_string(),
+            self.btc_deposit_address(station_account).await?,
+        )])
+    }
+
+    async fn pending_deposits(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<Vec<BlockchainPendingDeposit>> {
+        if !self.is_ckbtc_ledger(&self.account_ledger(station_account)?) {
+            return Ok(Vec::new());
+        }
+
+        Ok(self.update_balance(station_account).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{read_system_info, write_system_info},
+        models::{account_test_utils::mock_account, SnsToken},
+    };
+    use std::collections::BTreeMap;
+
+    fn mock_ckbtc_account(ledger_canister_id: &str) -> Account {
+        let mut account = mock_account();
+        account.standard = BlockchainStandard::ICRC1;
+        account.metadata = Metadata::new(BTreeMap::from([(
+            ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+            ledger_canister_id.to_string(),
+        )]));
+
+        account
+    }
+
+    #[test]
+    fn only_ckbtc_ledger_is_supported() {
+        let ckbtc = CkBtc::create(NetworkProfile::Mainnet);
+
+        assert!(ckbtc
+            .ensure_ckbtc_account(&mock_ckbtc_account(CkBtc::LEDGER_CANISTER_ID))
+            .is_ok());
+        assert_eq!(
+            ckbtc.ensure_ckbtc_account(&mock_ckbtc_account("ryjl3-tyaaa-aaaaa-aaaba-cai")),
+            Err(BlockchainApiError::UnsupportedLedger {
+                ledger_canister_id: "ryjl3-tyaaa-aaaaa-aaaba-cai".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn ckbtc_ledger_depends_on_network() {
+        let testnet_ckbtc = CkBtc::create(NetworkProfile::Testnet);
+
+        assert!(testnet_ckbtc
+            .ensure_ckbtc_account(&mock_ckbtc_account(CkBtc::TESTNET_LEDGER_CANISTER_ID))
+            .is_ok());
+        assert!(testnet_ckbtc
+            .ensure_ckbtc_account(&mock_ckbtc_account(CkBtc::LEDGER_CANISTER_ID))
+            .is_err());
+        assert!(CkBtc::create(NetworkProfile::Local)
+            .ensure_ckbtc_account(&mock_ckbtc_account(CkBtc::LEDGER_CANISTER_ID))
+            .is_err());
+        assert!(CkBtc::ckbtc_canisters(&NetworkProfile::Local).is_none());
+    }
+
+    #[test]
+    fn discovered_sns_ledger_is_supported() {
+        crate::core::test_utils::init_canister_system();
+
+        let ckbtc = CkBtc::create(NetworkProfile::Mainnet);
+        let sns_ledger = "2ouva-viaaa-aaaaq-aaamq-cai";
+        assert!(ckbtc
+            .account_ledger(&mock_ckbtc_account(sns_ledger))
+            .is_err());
+
+        let mut system_info = read_system_info();
+        system_info.set_sns_tokens(
+            vec![SnsToken {
+                root_canister_id: Principal::from_text("3e3x2-xyaaa-aaaaq-aaalq-cai").unwrap(),
+                ledger_canister_id: Principal::from_text(sns_ledger).unwrap(),
+                index_canister_id: None,
+                symbol: "CHAT".to_string(),
+                name: "OpenChat".to_string(),
+                decimals: 8,
+            }],
+            next_time(),
+        );
+        write_system_info(system_info);
+
+        assert_eq!(
+            ckbtc.account_ledger(&mock_ckbtc_account(sns_ledger)),
+            Ok(Principal::from_text(sns_ledger).unwrap())
+        );
+        assert!(ckbtc
+            .account_ledger(&mock_ckbtc_account(CkBtc::LEDGER_CANISTER_ID))
+            .is_ok());
+    }
+
+    #[test]
+    fn cycles_ledger_is_supported() {
+        crate::core::test_utils::init_canister_system();
+
+        let ckbtc = CkBtc::create(NetworkProfile::Mainnet);
+
+        assert_eq!(
+            ckbtc.account_ledger(&mock_ckbtc_account(CyclesLedger::LEDGER_CANISTER_ID)),
+            Ok(CyclesLedger::ledger_canister_id())
+        );
+        assert!(ckbtc
+            .ensure_ckbtc_account(&mock_ckbtc_account(CyclesLedger::LEDGER_CANISTER_ID))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn recent_update_balance_call_is_reused() {
+        let account = mock_ckbtc_account(CkBtc::LEDGER_CANISTER_ID);
+        let pending_deposit = BlockchainPendingDeposit {
+            id: "00ff:0".to_string(),
+            amount: BigUint::from(10_000_u64),
+            confirmations: 2,
+            required_confirmations: Some(6),
+        };
+
+        record_update_balance_call(
+            account.id,
+            UpdateBalanceCall {
+                called_at: next_time(),
+                pending_deposits: vec![pending_deposit.clone()],
+            },
+        );
+
+        let pending_deposits = CkBtc::create(NetworkProfile::Mainnet)
+            .update_balance(&account)
+            .await
+            .unwrap();
+
+        assert_eq!(pending_deposits, vec![pending_deposit]);
+    }
+
+    #[test]
+    fn deposit_id_uses_reversed_txid() {
+        let outpoint = Outpoint {
+            txid: vec![0x01, 0x02, 0xff],
+            vout: 3,
+        };
+
+        assert_eq!(outpoint.to_deposit_id(), "ff0201:3");
+    }
+
+    #[test]
+    fn subaccount_with_index_zero_is_the_account_itself() {
+        let ckbtc = CkBtc::create(NetworkProfile::Mainnet);
+        let account = mock_ckbtc_account(CkBtc::LEDGER_CANISTER_ID);
+
+        assert_eq!(
+            ckbtc.station_subaccount_to_icrc_account(&account.id, 0),
+            ckbtc.station_account_to_icrc_account(&account.id)
+        );
+        assert_ne!(
+            ckbtc.station_subaccount_to_icrc_account(&account.id, 1),
+            ckbtc.station_account_to_icrc_account(&account.id)
+        );
+    }
+}
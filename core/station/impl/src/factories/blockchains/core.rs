@@ -1,9 +1,13 @@
-use super::InternetComputer;
+use super::{Bitcoin, CkBtc, Ethereum, EthereumNetwork, Icrc7, InternetComputer};
 use crate::{
-    errors::FactoryError,
-    models::{Account, Blockchain, BlockchainStandard, Metadata, Transfer},
+    errors::{BlockchainApiError, FactoryError},
+    models::{
+        Account, ApproveOperationInput, Blockchain, BlockchainStandard, IcrcAccount, Metadata,
+        NetworkProfile, Transfer,
+    },
 };
 use async_trait::async_trait;
+use candid::Principal;
 use num_bigint::BigUint;
 use orbit_essentials::api::ApiError;
 use std::collections::HashMap;
@@ -29,6 +33,28 @@ impl BlockchainTransactionFee {
     }
 }
 
+/// The speed tier of a transaction fee, where faster tiers pay more to be included sooner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlockchainTransactionFeeTier {
+    Slow,
+    Standard,
+    Fast,
+}
+
+impl BlockchainTransactionFeeTier {
+    pub const ALL: [BlockchainTransactionFeeTier; 3] = [
+        BlockchainTransactionFeeTier::Slow,
+        BlockchainTransactionFeeTier::Standard,
+        BlockchainTransactionFeeTier::Fast,
+    ];
+}
+
+#[derive(Clone, Debug, Hash)]
+pub struct BlockchainTransactionFeeOption {
+    pub tier: BlockchainTransactionFeeTier,
+    pub fee: BlockchainTransactionFee,
+}
+
 #[derive(Clone, Debug, Hash)]
 pub struct BlockchainTransactionSubmitted {
     /// Depending on the blockchain, it returns details of the submitted transaction (e.g. block_height).
@@ -44,6 +70,58 @@ impl BlockchainTransactionSubmitted {
     }
 }
 
+/// The result of looking up a previously submitted transaction on the blockchain.
+#[derive(Clone, Debug, Hash)]
+pub enum BlockchainTransactionLookup {
+    /// The transaction was found on chain with the given details (e.g. block_height).
+    Found(BlockchainTransactionSubmitted),
+    /// The transaction was not found within the inspected blockchain history.
+    NotFound,
+}
+
+/// A deposit to the account that was received by the blockchain but is not yet part of its balance.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockchainPendingDeposit {
+    /// The identifier of the deposit on the source blockchain (e.g. `<txid>:<vout>` for Bitcoin).
+    pub id: String,
+    /// The deposited amount, in the smallest unit of the asset.
+    pub amount: BigUint,
+    /// The number of confirmations the deposit has received so far.
+    pub confirmations: u32,
+    /// The number of confirmations required before the deposit is credited, if known.
+    pub required_confirmations: Option<u32>,
+}
+
+/// The ledger and the spender account that other ledger accounts approve to fund an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockchainAllowanceSpender {
+    pub ledger_canister_id: Principal,
+    pub spender: IcrcAccount,
+}
+
+/// An allowance granted to an account by another ledger account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockchainAllowance {
+    /// The remaining amount that can be pulled, in the smallest unit of the asset.
+    pub amount: BigUint,
+    /// The expiration of the allowance in nanoseconds since the epoch, if any.
+    pub expires_at: Option<u64>,
+}
+
+/// The confirmation status of a submitted transaction.
+#[derive(Clone, Debug, Hash)]
+pub enum BlockchainTransactionConfirmation {
+    /// The transaction has enough confirmations to be considered final.
+    Final,
+    /// The transaction still awaits confirmations, the details are updated with the observed progress.
+    Pending {
+        confirmations: u32,
+        details: BlockchainTransactionSubmitted,
+    },
+    /// The transaction failed on chain (e.g. it was reverted or replaced).
+    Failed { reason: String },
+}
+
 #[async_trait]
 pub trait BlockchainApi: Send + Sync {
     /// Generates a new address for the given account.
@@ -54,6 +132,31 @@ pub trait BlockchainApi: Send + Sync {
     /// Returns the latest balance of the given account.
     async fn balance(&self, account: &Account) -> Result<BigUint, ApiError>;
 
+    /// Generates the address of the subaccount of the given account with the given index, the index
+    /// `0` is the main address of the account.
+    async fn generate_subaccount_address(
+        &self,
+        account: &Account,
+        _index: u32,
+    ) -> Result<String, ApiError> {
+        Err(BlockchainApiError::SubaccountsNotSupported {
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+        })?
+    }
+
+    /// Returns the latest balance of the subaccount of the given account with the given index.
+    async fn subaccount_balance(
+        &self,
+        account: &Account,
+        _index: u32,
+    ) -> Result<BigUint, ApiError> {
+        Err(BlockchainApiError::SubaccountsNotSupported {
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+        })?
+    }
+
     /// Returns the decimals of the given account.
     async fn decimals(&self, account: &Account) -> Result<u32, ApiError>;
 
@@ -63,6 +166,24 @@ pub trait BlockchainApi: Send + Sync {
         account: &Account,
     ) -> Result<BlockchainTransactionFee, ApiError>;
 
+    /// Returns the fee of each speed tier, which can be used as the fee of a transfer.
+    ///
+    /// Blockchains with a fixed fee (e.g. the Internet Computer ledgers) return the same fee for every tier.
+    async fn transaction_fee_options(
+        &self,
+        account: &Account,
+    ) -> Result<Vec<BlockchainTransactionFeeOption>, ApiError> {
+        let fee = self.transaction_fee(account).await?;
+
+        Ok(BlockchainTransactionFeeTier::ALL
+            .into_iter()
+            .map(|tier| BlockchainTransactionFeeOption {
+                tier,
+                fee: fee.clone(),
+            })
+            .collect())
+    }
+
     /// Returns the default network.
     fn default_network(&self) -> String;
 
@@ -72,19 +193,114 @@ pub trait BlockchainApi: Send + Sync {
         account: &Account,
         transfer: &Transfer,
     ) -> Result<BlockchainTransactionSubmitted, ApiError>;
+
+    /// Looks up the transaction that was submitted for the given transfer by its deduplication key.
+    ///
+    /// Returns an error if the outcome can not be determined (e.g. the history is no longer available).
+    async fn find_transaction(
+        &self,
+        account: &Account,
+        transfer: &Transfer,
+    ) -> Result<BlockchainTransactionLookup, ApiError>;
+
+    /// Grants the spender an allowance over the funds of the given account (e.g. ICRC-2 `icrc2_approve`).
+    async fn approve(
+        &self,
+        account: &Account,
+        approval: &ApproveOperationInput,
+    ) -> Result<BlockchainTransactionSubmitted, ApiError>;
+
+    /// Returns the ledger and the spender account that other ledger accounts approve (e.g. with ICRC-2
+    /// `icrc2_approve`) to let the given account pull funds from them.
+    fn allowance_spender(&self, account: &Account) -> Result<BlockchainAllowanceSpender, ApiError> {
+        Err(BlockchainApiError::AllowancesNotSupported {
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+        })?
+    }
+
+    /// Returns the allowance the owner granted to the given account (e.g. ICRC-2 `icrc2_allowance`).
+    async fn allowance(
+        &self,
+        account: &Account,
+        _owner: &IcrcAccount,
+    ) -> Result<BlockchainAllowance, ApiError> {
+        Err(BlockchainApiError::AllowancesNotSupported {
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+        })?
+    }
+
+    /// Returns the additional addresses that can be used to deposit funds into the account, keyed by
+    /// their account metadata key (e.g. the Bitcoin deposit address of a ckBTC account).
+    async fn deposit_addresses(
+        &self,
+        _account: &Account,
+    ) -> Result<Vec<(String, String)>, ApiError> {
+        Ok(Vec::new())
+    }
+
+    /// The number of confirmations a submitted transaction needs before its transfer is completed.
+    ///
+    /// Submitted transactions are final by default (e.g. on the Internet Computer ledgers).
+    fn required_confirmations(&self) -> u32 {
+        0
+    }
+
+    /// Returns the confirmation status of a transaction submitted with the given details.
+    async fn transaction_confirmation(
+        &self,
+        _account: &Account,
+        _submitted: &BlockchainTransactionSubmitted,
+    ) -> Result<BlockchainTransactionConfirmation, ApiError> {
+        Ok(BlockchainTransactionConfirmation::Final)
+    }
+
+    /// Returns the deposits that were received but are not yet part of the balance of the account.
+    async fn pending_deposits(
+        &self,
+        _account: &Account,
+    ) -> Result<Vec<BlockchainPendingDeposit>, ApiError> {
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Debug)]
 pub struct BlockchainApiFactory {}
 
 impl BlockchainApiFactory {
+    /// Builds the adapter of the accounts of the blockchain and standard on the given network.
     pub fn build(
         blockchain: &Blockchain,
         standard: &BlockchainStandard,
+        network: &NetworkProfile,
     ) -> Result<Box<dyn BlockchainApi>, FactoryError> {
+        let unsupported_network = || FactoryError::UnsupportedNetwork {
+            blockchain: blockchain.to_string(),
+            standard: standard.to_string(),
+            network: network.to_string(),
+        };
+
         match (blockchain, standard) {
-            (Blockchain::InternetComputer, BlockchainStandard::Native) => {
-                Ok(Box::new(InternetComputer::create()))
+            (Blockchain::InternetComputer, BlockchainStandard::Native) => match network {
+                // there is no test ICP ledger, the ICP of local replicas is served by the mainnet ledger id
+                NetworkProfile::Testnet => Err(unsupported_network()),
+                _ => Ok(Box::new(InternetComputer::create(*network))),
+            },
+            (Blockchain::InternetComputer, BlockchainStandard::ICRC1) => {
+                Ok(Box::new(CkBtc::create(*network)))
+            }
+            (Blockchain::InternetComputer, BlockchainStandard::ICRC7) => {
+                Ok(Box::new(Icrc7::create(*network)))
+            }
+            (Blockchain::Bitcoin, BlockchainStandard::Native) => {
+                Ok(Box::new(Bitcoin::create(*network)))
+            }
+            (Blockchain::Ethereum, BlockchainStandard::Native) => {
+                let network =
+                    EthereumNetwork::from_profile(network).ok_or_else(unsupported_network)?;
+
+                Ok(Box::new(Ethereum::create(network)))
             }
             (blockchain, standard) => Err(FactoryError::UnsupportedBlockchainAccount {
                 blockchain: blockchain.to_string(),
@@ -0,0 +1,122 @@
+//! Candid types and calls of the cycles ledger, the ICRC-1 ledger of the cycles held outside of canisters.
+
+use crate::errors::{BlockchainApiError, LEDGER_TEMPORARILY_UNAVAILABLE_INFO};
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+/// The cycles ledger holds cycles as an ICRC-1 token, its accounts are served by the ICRC-1 adapter.
+///
+/// Besides the ICRC-1 transfers, the cycles of an account can be withdrawn to any canister, which is
+/// how the cycles held by the station accounts fund the canisters.
+pub struct CyclesLedger;
+
+impl CyclesLedger {
+    pub const SYMBOL: &'static str = "TCYCLES";
+    pub const DECIMALS: u32 = 12;
+    pub const LEDGER_CANISTER_ID: &'static str = "um5iw-rqaaa-aaaaq-qaaba-cai";
+
+    pub fn ledger_canister_id() -> Principal {
+        Principal::from_text(Self::LEDGER_CANISTER_ID).unwrap()
+    }
+
+    pub fn is_cycles_ledger(ledger: &Principal) -> bool {
+        *ledger == Self::ledger_canister_id()
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct CyclesLedgerWithdrawArgs {
+    pub from_subaccount: Option<Vec<u8>>,
+    pub to: Principal,
+    pub amount: Nat,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CyclesLedgerRejectionCode {
+    NoError,
+    CanisterError,
+    SysTransient,
+    DestinationInvalid,
+    Unknown,
+    SysFatal,
+    CanisterReject,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum CyclesLedgerWithdrawError {
+    GenericError {
+        message: String,
+        error_code: Nat,
+    },
+    TemporarilyUnavailable,
+    FailedToWithdraw {
+        fee_block: Option<Nat>,
+        rejection_code: CyclesLedgerRejectionCode,
+        rejection_reason: String,
+    },
+    Duplicate {
+        duplicate_of: Nat,
+    },
+    BadFee {
+        expected_fee: Nat,
+    },
+    InvalidReceiver {
+        receiver: Principal,
+    },
+    CreatedInFuture {
+        ledger_time: u64,
+    },
+    TooOld,
+    InsufficientFunds {
+        balance: Nat,
+    },
+}
+
+/// Calls `withdraw` on the cycles ledger to send cycles of the account to the canister and returns
+/// the index of the withdrawal block.
+pub async fn cycles_ledger_withdraw(
+    args: CyclesLedgerWithdrawArgs,
+) -> Result<Nat, BlockchainApiError> {
+    let (result,): (Result<Nat, CyclesLedgerWithdrawError>,) =
+        ic_cdk::call(CyclesLedger::ledger_canister_id(), "withdraw", (args,))
+            .await
+            .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+                info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+            })?;
+
+    result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+        info: match err {
+            CyclesLedgerWithdrawError::GenericError {
+                message,
+                error_code,
+            } => format!("Error code {}: {}", error_code, message),
+            CyclesLedgerWithdrawError::TemporarilyUnavailable => {
+                LEDGER_TEMPORARILY_UNAVAILABLE_INFO.to_string()
+            }
+            CyclesLedgerWithdrawError::FailedToWithdraw {
+                rejection_code,
+                rejection_reason,
+                ..
+            } => format!(
+                "The canister rejected the cycles, rejection_code: {:?}, reason: {}",
+                rejection_code, rejection_reason
+            ),
+            CyclesLedgerWithdrawError::Duplicate { duplicate_of } => {
+                format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+            }
+            CyclesLedgerWithdrawError::BadFee { expected_fee } => {
+                format!("Bad fee, expected: {}", expected_fee)
+            }
+            CyclesLedgerWithdrawError::InvalidReceiver { receiver } => {
+                format!("Invalid receiver: {}", receiver)
+            }
+            CyclesLedgerWithdrawError::CreatedInFuture { ledger_time } => {
+                format!("Tx created in future, ledger_time: {}", ledger_time)
+            }
+            CyclesLedgerWithdrawError::TooOld => "Tx too old".to_string(),
+            CyclesLedgerWithdrawError::InsufficientFunds { balance } => {
+                format!("Insufficient balance, balance: {}", balance)
+            }
+        },
+    })
+}
@@ -0,0 +1,804 @@
+use super::{
+    BlockchainApi, BlockchainApiResult, BlockchainTransactionConfirmation,
+    BlockchainTransactionFee, BlockchainTransactionFeeOption, BlockchainTransactionFeeTier,
+    BlockchainTransactionLookup, BlockchainTransactionSubmitted, RpcClient,
+    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
+};
+use crate::{
+    errors::BlockchainApiError,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, ApproveOperationInput, Blockchain, BlockchainStandard, Metadata,
+        NetworkProfile, Transfer,
+    },
+};
+use async_trait::async_trait;
+use ic_cdk::api::management_canister::ecdsa::{
+    ecdsa_public_key, sign_with_ecdsa, EcdsaCurve, EcdsaKeyId, EcdsaPublicKeyArgument,
+    SignWithEcdsaArgument,
+};
+use k256::{
+    ecdsa::{RecoveryId, Signature, VerifyingKey},
+    elliptic_curve::sec1::ToEncodedPoint,
+    PublicKey,
+};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use orbit_essentials::api::ApiError;
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+use std::{cell::RefCell, collections::HashMap, str::FromStr};
+
+/// The submitted transaction details key with the nonce used by the transaction.
+pub const TRANSACTION_SUBMITTED_DETAILS_NONCE_KEY: &str = "nonce";
+/// The submitted transaction details key with the address that sent the transaction.
+pub const TRANSACTION_SUBMITTED_DETAILS_FROM_ADDRESS_KEY: &str = "from_address";
+/// The fee metadata key with the maximum fee per gas, in wei.
+pub const TRANSACTION_FEE_METADATA_MAX_FEE_PER_GAS_KEY: &str = "max_fee_per_gas";
+/// The fee metadata key with the maximum priority fee per gas, in wei.
+pub const TRANSACTION_FEE_METADATA_MAX_PRIORITY_FEE_PER_GAS_KEY: &str = "max_priority_fee_per_gas";
+/// The fee metadata key with the gas limit of the transaction.
+pub const TRANSACTION_FEE_METADATA_GAS_LIMIT_KEY: &str = "gas_limit";
+
+thread_local! {
+    /// The next nonce to use for each account, so concurrent transfers don't reuse a nonce.
+    static NEXT_NONCES: RefCell<HashMap<AccountId, u64>> = RefCell::new(HashMap::new());
+}
+
+/// The Ethereum networks supported by the station.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EthereumNetwork {
+    Mainnet,
+    Sepolia,
+}
+
+impl EthereumNetwork {
+    /// The network of the profile, the testnet profile uses Sepolia and local networks are not supported.
+    pub fn from_profile(profile: &NetworkProfile) -> Option<Self> {
+        match profile {
+            NetworkProfile::Mainnet => Some(EthereumNetwork::Mainnet),
+            NetworkProfile::Testnet => Some(EthereumNetwork::Sepolia),
+            NetworkProfile::Local => None,
+        }
+    }
+
+    pub fn profile(&self) -> NetworkProfile {
+        match self {
+            EthereumNetwork::Mainnet => NetworkProfile::Mainnet,
+            EthereumNetwork::Sepolia => NetworkProfile::Testnet,
+        }
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            EthereumNetwork::Mainnet => 1,
+            EthereumNetwork::Sepolia => 11_155_111,
+        }
+    }
+}
+
+/// The adapter of native Ethereum accounts, which reaches the network through the EVM RPC canister.
+///
+/// Each account holds the funds of the address derived from the threshold ECDSA key of the station,
+/// with the account id as the derivation path. Transactions are EIP-1559 transactions signed by the
+/// station, and transfers are only completed once the block that includes them is finalized.
+#[derive(Debug)]
+pub struct Ethereum {
+    network: EthereumNetwork,
+}
+
+impl Ethereum {
+    pub const BLOCKCHAIN: Blockchain = Blockchain::Ethereum;
+    pub const STANDARD: BlockchainStandard = BlockchainStandard::Native;
+    pub const SYMBOL: &'static str = "ETH";
+    pub const DECIMALS: u32 = 18;
+    /// The gas used by a transfer of ether to an externally owned account.
+    pub const GAS_LIMIT: u64 = 21_000;
+    /// The number of blocks that are usually needed before a block is finalized (two epochs).
+    pub const REQUIRED_CONFIRMATIONS: u32 = 64;
+
+    pub fn create(network: EthereumNetwork) -> Self {
+        Self { network }
+    }
+
+    fn key_id(&self) -> EcdsaKeyId {
+        EcdsaKeyId {
+            curve: EcdsaCurve::Secp256k1,
+            name: match self.network {
+                EthereumNetwork::Mainnet => "key_1",
+                EthereumNetwork::Sepolia => "test_key_1",
+            }
+            .to_string(),
+        }
+    }
+
+    fn derivation_path(station_account_id: &AccountId) -> Vec<Vec<u8>> {
+        vec![station_account_id.to_vec()]
+    }
+
+    fn network_error(err: (ic_cdk::api::call::RejectionCode, String)) -> BlockchainApiError {
+        BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        }
+    }
+
+    fn rpc_client() -> BlockchainApiResult<RpcClient> {
+        Ok(RpcClient::via_evm_rpc_canister(&Self::BLOCKCHAIN)?)
+    }
+
+    /// Returns the base fee per gas of the latest block and the suggested priority fee per gas.
+    async fn fee_market(&self) -> BlockchainApiResult<(BigUint, BigUint)> {
+        let client = Self::rpc_client()?;
+        let block = client
+            .call("eth_getBlockByNumber", json!(["latest", false]))
+            .await?;
+        let base_fee_per_gas = parse_quantity(&block["baseFeePerGas"])?;
+        let max_priority_fee_per_gas =
+            parse_quantity(&client.call("eth_maxPriorityFeePerGas", json!([])).await?)?;
+
+        Ok((base_fee_per_gas, max_priority_fee_per_gas))
+    }
+
+    /// The fee of a transfer for the tier, which pays the priority fee on top of once, twice or three times
+    /// the base fee, the headroom decides how long the transaction stays includable when the base fee rises.
+    fn tier_fee(
+        base_fee_per_gas: &BigUint,
+        max_priority_fee_per_gas: &BigUint,
+        tier: BlockchainTransactionFeeTier,
+    ) -> BlockchainTransactionFee {
+        let base_fee_multiplier = match tier {
+            BlockchainTransactionFeeTier::Slow => 1u32,
+            BlockchainTransactionFeeTier::Standard => 2u32,
+            BlockchainTransactionFeeTier::Fast => 3u32,
+        };
+        let max_fee_per_gas = base_fee_per_gas * base_fee_multiplier + max_priority_fee_per_gas;
+        let fee = &max_fee_per_gas * Self::GAS_LIMIT;
+
+        BlockchainTransactionFee {
+            fee,
+            metadata: Metadata::new(
+                [
+                    (
+                        TRANSACTION_FEE_METADATA_MAX_FEE_PER_GAS_KEY.to_string(),
+                        max_fee_per_gas.to_string(),
+                    ),
+                    (
+                        TRANSACTION_FEE_METADATA_MAX_PRIORITY_FEE_PER_GAS_KEY.to_string(),
+                        max_priority_fee_per_gas.to_string(),
+                    ),
+                    (
+                        TRANSACTION_FEE_METADATA_GAS_LIMIT_KEY.to_string(),
+                        Self::GAS_LIMIT.to_string(),
+                    ),
+                ]
+                .into(),
+            ),
+        }
+    }
+
+    /// Returns the compressed public key derived for the account.
+    async fn public_key(&self, station_account: &Account) -> Result<Vec<u8>, BlockchainApiError> {
+        let (response,) = ecdsa_public_key(EcdsaPublicKeyArgument {
+            canister_id: None,
+            derivation_path: Self::derivation_path(&station_account.id),
+            key_id: self.key_id(),
+        })
+        .await
+        .map_err(Self::network_error)?;
+
+        Ok(response.public_key)
+    }
+
+    fn ensure_network(&self, blockchain_network: &str) -> Result<(), BlockchainApiError> {
+        let network = NetworkProfile::from_str(blockchain_network)
+            .ok()
+            .and_then(|profile| EthereumNetwork::from_profile(&profile))
+            .ok_or_else(|| BlockchainApiError::TransactionSubmitFailed {
+                info: format!("Unsupported Ethereum network `{}`", blockchain_network),
+            })?;
+
+        if network != self.network {
+            return Err(BlockchainApiError::TransactionSubmitFailed {
+                info: format!(
+                    "The transfer network `{}` does not match the account network",
+                    blockchain_network
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reserves the next nonce of the account, which is the highest of the pending transaction count
+    /// reported by the network and the nonce following the last transaction sent by the station.
+    fn reserve_nonce(station_account_id: &AccountId, pending_transaction_count: u64) -> u64 {
+        NEXT_NONCES.with(|nonces| {
+            let mut nonces = nonces.borrow_mut();
+            let next_nonce = nonces.entry(*station_account_id).or_default();
+            let nonce = (*next_nonce).max(pending_transaction_count);
+            *next_nonce = nonce + 1;
+
+            nonce
+        })
+    }
+
+    /// Forgets the local nonce of the account, so the next transfer uses the count of the network.
+    fn release_nonce(station_account_id: &AccountId) {
+        NEXT_NONCES.with(|nonces| {
+            nonces.borrow_mut().remove(station_account_id);
+        });
+    }
+
+    /// Signs the transaction and returns its raw encoding.
+    async fn sign_transaction(
+        &self,
+        station_account: &Account,
+        public_key: &[u8],
+        transaction: &Eip1559Transaction,
+    ) -> Result<Vec<u8>, BlockchainApiError> {
+        let signing_failed = |info: String| BlockchainApiError::TransactionSubmitFailed { info };
+        let message_hash = transaction.signing_hash();
+
+        let (response,) = sign_with_ecdsa(SignWithEcdsaArgument {
+            message_hash: message_hash.to_vec(),
+            derivation_path: Self::derivation_path(&station_account.id),
+            key_id: self.key_id(),
+        })
+        .await
+        .map_err(Self::network_error)?;
+
+        let signature = Signature::from_slice(&response.signature)
+            .map_err(|err| signing_failed(format!("Invalid signature: {}", err)))?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+        let verifying_key = VerifyingKey::from_sec1_bytes(public_key)
+            .map_err(|err| signing_failed(format!("Invalid public key: {}", err)))?;
+
+        // the y parity is not returned by the signing api, so it is found by recovering the key
+        let y_parity = (0..=1)
+            .find(|y_parity| {
+                RecoveryId::from_byte(*y_parity)
+                    .and_then(|recovery_id| {
+                        VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id)
+                            .ok()
+                    })
+                    .is_some_and(|recovered| recovered == verifying_key)
+            })
+            .ok_or_else(|| signing_failed("Failed to recover the signature parity".to_string()))?;
+
+        let signature = signature.to_bytes();
+
+        Ok(transaction.encode_signed(y_parity, &signature[..32], &signature[32..]))
+    }
+}
+
+/// An EIP-1559 transaction that transfers ether, without data nor access list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Eip1559Transaction {
+    chain_id: u64,
+    nonce: u64,
+    max_priority_fee_per_gas: BigUint,
+    max_fee_per_gas: BigUint,
+    gas_limit: u64,
+    to: Vec<u8>,
+    value: BigUint,
+}
+
+impl Eip1559Transaction {
+    const TRANSACTION_TYPE: u8 = 0x02;
+
+    fn fields(&self) -> Vec<Vec<u8>> {
+        vec![
+            rlp_uint(&BigUint::from(self.chain_id)),
+            rlp_uint(&BigUint::from(self.nonce)),
+            rlp_uint(&self.max_priority_fee_per_gas),
+            rlp_uint(&self.max_fee_per_gas),
+            rlp_uint(&BigUint::from(self.gas_limit)),
+            rlp_bytes(&self.to),
+            rlp_uint(&self.value),
+            // data
+            rlp_bytes(&[]),
+            // access list
+            rlp_list(&[]),
+        ]
+    }
+
+    /// The hash signed by the sender, `keccak256(0x02 || rlp([chain_id, ..., access_list]))`.
+    fn signing_hash(&self) -> [u8; 32] {
+        let mut payload = vec![Self::TRANSACTION_TYPE];
+        payload.extend(rlp_list(&self.fields()));
+
+        keccak256(&payload)
+    }
+
+    /// The raw transaction, `0x02 || rlp([chain_id, ..., access_list, y_parity, r, s])`.
+    fn encode_signed(&self, y_parity: u8, r: &[u8], s: &[u8]) -> Vec<u8> {
+        let mut fields = self.fields();
+        fields.push(rlp_uint(&BigUint::from(y_parity)));
+        fields.push(rlp_uint(&BigUint::from_bytes_be(r)));
+        fields.push(rlp_uint(&BigUint::from_bytes_be(s)));
+
+        let mut raw = vec![Self::TRANSACTION_TYPE];
+        raw.extend(rlp_list(&fields));
+
+        raw
+    }
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn rlp_length_prefix(offset: u8, length: usize) -> Vec<u8> {
+    if length < 56 {
+        return vec![offset + length as u8];
+    }
+
+    let length_bytes = length
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|byte| *byte == 0)
+        .collect::<Vec<u8>>();
+
+    let mut prefix = vec![offset + 55 + length_bytes.len() as u8];
+    prefix.extend(length_bytes);
+
+    prefix
+}
+
+/// The RLP encoding of a byte string.
+fn rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+
+    let mut encoded = rlp_length_prefix(0x80, bytes.len());
+    encoded.extend(bytes);
+
+    encoded
+}
+
+/// The RLP encoding of an unsigned integer, as a big endian byte string without leading zeros.
+fn rlp_uint(value: &BigUint) -> Vec<u8> {
+    match value.is_zero() {
+        true => rlp_bytes(&[]),
+        false => rlp_bytes(&value.to_bytes_be()),
+    }
+}
+
+/// The RLP encoding of a list of already encoded items.
+fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut encoded = rlp_length_prefix(0xc0, payload.len());
+    encoded.extend(payload);
+
+    encoded
+}
+
+/// Returns the address of the given public key, with the EIP-55 mixed case checksum.
+fn public_key_to_address(public_key: &[u8]) -> Result<String, BlockchainApiError> {
+    let public_key = PublicKey::from_sec1_bytes(public_key).map_err(|err| {
+        BlockchainApiError::BlockchainNetworkError {
+            info: format!("Invalid public key: {}", err),
+        }
+    })?;
+    let point = public_key.to_encoded_point(false);
+    // the uncompressed point is prefixed with `0x04`
+    let hash = keccak256(&point.as_bytes()[1..]);
+
+    Ok(to_checksum_address(&hash[12..]))
+}
+
+/// Encodes the address with the EIP-55 checksum, which uppercases the letters whose nibble in the
+/// hash of the lowercase address is 8 or more.
+fn to_checksum_address(address: &[u8]) -> String {
+    let address = hex::encode(address);
+    let hash = keccak256(address.as_bytes());
+
+    let checksummed = address
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            match nibble >= 8 {
+                true => c.to_ascii_uppercase(),
+                false => c,
+            }
+        })
+        .collect::<String>();
+
+    format!("0x{}", checksummed)
+}
+
+/// Parses a `0x` prefixed address of 20 bytes.
+fn parse_address(address: &str) -> Result<Vec<u8>, BlockchainApiError> {
+    let invalid_address = |error: &str| BlockchainApiError::InvalidToAddress {
+        address: address.to_string(),
+        error: error.to_string(),
+    };
+
+    let hex_address = address
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid_address("the address must start with 0x"))?;
+    if hex_address.len() != 40 {
+        return Err(invalid_address("the address must have 40 hex characters"));
+    }
+
+    hex::decode(hex_address).map_err(|_| invalid_address("the address is not hex encoded"))
+}
+
+/// Parses a `0x` prefixed hex quantity of a JSON-RPC response.
+fn parse_quantity(value: &Value) -> Result<BigUint, BlockchainApiError> {
+    let invalid_quantity = || BlockchainApiError::BlockchainNetworkError {
+        info: format!("Invalid quantity in the RPC response: {}", value),
+    };
+
+    let digits = value
+        .as_str()
+        .and_then(|quantity| quantity.strip_prefix("0x"))
+        .ok_or_else(invalid_quantity)?;
+    if digits.is_empty() {
+        return Ok(BigUint::zero());
+    }
+
+    BigUint::parse_bytes(digits.as_bytes(), 16).ok_or_else(invalid_quantity)
+}
+
+fn parse_quantity_u64(value: &Value) -> Result<u64, BlockchainApiError> {
+    u64::try_from(parse_quantity(value)?).map_err(|_| BlockchainApiError::BlockchainNetworkError {
+        info: format!("Quantity out of range in the RPC response: {}", value),
+    })
+}
+
+fn to_quantity(value: &BigUint) -> String {
+    format!("0x{:x}", value)
+}
+
+#[async_trait]
+impl BlockchainApi for Ethereum {
+    async fn generate_address(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        let public_key = self.public_key(station_account).await?;
+
+        Ok(public_key_to_address(&public_key)?)
+    }
+
+    async fn balance(&self, station_account: &Account) -> BlockchainApiResult<BigUint> {
+        let balance = Self::rpc_client()?
+            .read("eth_getBalance", json!([station_account.address, "latest"]))
+            .await?;
+
+        Ok(parse_quantity(&balance)?)
+    }
+
+    async fn decimals(&self, _station_account: &Account) -> BlockchainApiResult<u32> {
+        Ok(Self::DECIMALS)
+    }
+
+    /// The maximum fee of a transfer, which allows the base fee to double before the transaction
+    /// is included. Only the base fee of the block and the priority fee are actually charged.
+    async fn transaction_fee(
+        &self,
+        _station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainTransactionFee> {
+        let (base_fee_per_gas, max_priority_fee_per_gas) = self.fee_market().await?;
+
+        Ok(Self::tier_fee(
+            &base_fee_per_gas,
+            &max_priority_fee_per_gas,
+            BlockchainTransactionFeeTier::Standard,
+        ))
+    }
+
+    async fn transaction_fee_options(
+        &self,
+        _station_account: &Account,
+    ) -> BlockchainApiResult<Vec<BlockchainTransactionFeeOption>> {
+        let (base_fee_per_gas, max_priority_fee_per_gas) = self.fee_market().await?;
+
+        Ok(BlockchainTransactionFeeTier::ALL
+            .into_iter()
+            .map(|tier| BlockchainTransactionFeeOption {
+                tier,
+                fee: Self::tier_fee(&base_fee_per_gas, &max_priority_fee_per_gas, tier),
+            })
+            .collect())
+    }
+
+    fn default_network(&self) -> String {
+        self.network.profile().to_string()
+    }
+
+    /// Sends a transaction whose maximum fee per gas is the fee of the transfer divided by the gas limit.
+    async fn submit_transaction(
+        &self,
+        station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        self.ensure_network(&transfer.blockchain_network)?;
+
+        let to = parse_address(&transfer.to_address)?;
+        let max_fee_per_gas = &transfer.fee.0 / Self::GAS_LIMIT;
+        if max_fee_per_gas.is_zero() {
+            Err(BlockchainApiError::TransactionSubmitFailed {
+                info: format!("The fee must cover at least {} gas", Self::GAS_LIMIT),
+            })?
+        }
+
+        let public_key = self.public_key(station_account).await?;
+        let from_address = public_key_to_address(&public_key)?;
+
+        let client = Self::rpc_client()?;
+        let pending_transaction_count = parse_quantity_u64(
+            &client
+                .call("eth_getTransactionCount", json!([from_address, "pending"]))
+                .await?,
+        )?;
+        let max_priority_fee_per_gas =
+            parse_quantity(&client.call("eth_maxPriorityFeePerGas", json!([])).await?)?
+                .min(max_fee_per_gas.clone());
+
+        // reserved before signing, since other transfers of the account can be submitted concurrently
+        let nonce = Self::reserve_nonce(&station_account.id, pending_transaction_count);
+        let transaction = Eip1559Transaction {
+            chain_id: self.network.chain_id(),
+            nonce,
+            max_priority_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit: Self::GAS_LIMIT,
+            to,
+            value: transfer.amount.0.clone(),
+        };
+
+        let raw_transaction = match self
+            .sign_transaction(station_account, &public_key, &transaction)
+            .await
+        {
+            Ok(raw_transaction) => raw_transaction,
+            Err(err) => {
+                Self::release_nonce(&station_account.id);
+
+                Err(err)?
+            }
+        };
+        let transaction_hash = format!("0x{}", hex::encode(keccak256(&raw_transaction)));
+
+        if let Err(err) = client
+            .call(
+                "eth_sendRawTransaction",
+                json!([format!("0x{}", hex::encode(&raw_transaction))]),
+            )
+            .await
+        {
+            Self::release_nonce(&station_account.id);
+
+            Err(BlockchainApiError::TransactionSubmitFailed {
+                info: err.to_string(),
+            })?
+        }
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY.to_string(),
+                    transaction_hash,
+                ),
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_NONCE_KEY.to_string(),
+                    nonce.to_string(),
+                ),
+                (
+                    TRANSACTION_SUBMITTED_DETAILS_FROM_ADDRESS_KEY.to_string(),
+                    from_address,
+                ),
+            ],
+        })
+    }
+
+    async fn find_transaction(
+        &self,
+        _station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionLookup> {
+        match &transfer.submitted_details {
+            Some(details) => Ok(BlockchainTransactionLookup::Found(
+                BlockchainTransactionSubmitted {
+                    details: details.clone(),
+                },
+            )),
+            None => Err(BlockchainApiError::TransactionLookupFailed {
+                info: "Ethereum transactions can only be looked up once submitted".to_string(),
+            }
+            .into()),
+        }
+    }
+
+    async fn approve(
+        &self,
+        _station_account: &Account,
+        _approval: &ApproveOperationInput,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        Err(BlockchainApiError::TransactionSubmitFailed {
+            info: "Ether does not support allowances".to_string(),
+        }
+        .into())
+    }
+
+    fn required_confirmations(&self) -> u32 {
+        Self::REQUIRED_CONFIRMATIONS
+    }
+
+    /// Polls the receipt of the transaction, which is final once its block is finalized.
+    ///
+    /// A transaction without receipt whose nonce was used by another transaction was replaced.
+    async fn transaction_confirmation(
+        &self,
+        _station_account: &Account,
+        submitted: &BlockchainTransactionSubmitted,
+    ) -> Result<BlockchainTransactionConfirmation, ApiError> {
+        let details = submitted.metadata_map();
+        let (Some(transaction_hash), Some(nonce), Some(from_address)) = (
+            details.get(TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY),
+            details.get(TRANSACTION_SUBMITTED_DETAILS_NONCE_KEY),
+            details.get(TRANSACTION_SUBMITTED_DETAILS_FROM_ADDRESS_KEY),
+        ) else {
+            return Err(BlockchainApiError::TransactionLookupFailed {
+                info: "The submitted transaction has no hash, nonce or sender".to_string(),
+            }
+            .into());
+        };
+
+        let client = Self::rpc_client()?;
+        let receipt = client
+            .read("eth_getTransactionReceipt", json!([transaction_hash]))
+            .await?;
+
+        if receipt.is_null() {
+            let transaction_count = parse_quantity_u64(
+                &client
+                    .call("eth_getTransactionCount", json!([from_address, "latest"]))
+                    .await?,
+            )?;
+
+            if transaction_count > HelperMapper::to_u64(nonce)? {
+                return Ok(BlockchainTransactionConfirmation::Failed {
+                    reason:
+                        "The transaction was replaced by another transaction with the same nonce"
+                            .to_string(),
+                });
+            }
+
+            return Ok(BlockchainTransactionConfirmation::Pending {
+                confirmations: 0,
+                details: submitted.clone(),
+            });
+        }
+
+        if receipt["status"].as_str() == Some("0x0") {
+            return Ok(BlockchainTransactionConfirmation::Failed {
+                reason: "The transaction was reverted".to_string(),
+            });
+        }
+
+        let block_number = parse_quantity_u64(&receipt["blockNumber"])?;
+        let finalized_block = client
+            .call("eth_getBlockByNumber", json!(["finalized", false]))
+            .await?;
+        if block_number <= parse_quantity_u64(&finalized_block["number"])? {
+            return Ok(BlockchainTransactionConfirmation::Final);
+        }
+
+        let latest_block_number =
+            parse_quantity_u64(&client.call("eth_blockNumber", json!([])).await?)?;
+        let confirmations = latest_block_number.saturating_sub(block_number) + 1;
+
+        let mut details = submitted.details.clone();
+        details.retain(|(key, _)| key != TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY);
+        details.push((
+            TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+            block_number.to_string(),
+        ));
+
+        Ok(BlockchainTransactionConfirmation::Pending {
+            confirmations: u32::try_from(confirmations).unwrap_or(u32::MAX),
+            details: BlockchainTransactionSubmitted { details },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_tiers_add_headroom_over_the_base_fee() {
+        let fees = BlockchainTransactionFeeTier::ALL
+            .map(|tier| Ethereum::tier_fee(&BigUint::from(10u32), &BigUint::from(2u32), tier).fee);
+
+        assert_eq!(
+            fees,
+            [12u32, 22, 32]
+                .map(|max_fee_per_gas| BigUint::from(max_fee_per_gas) * Ethereum::GAS_LIMIT)
+        );
+    }
+
+    #[test]
+    fn rlp_encodes_strings_integers_and_lists() {
+        assert_eq!(rlp_bytes(b"dog"), hex::decode("83646f67").unwrap());
+        assert_eq!(rlp_bytes(&[]), vec![0x80]);
+        assert_eq!(rlp_bytes(&[0x0f]), vec![0x0f]);
+        assert_eq!(rlp_uint(&BigUint::zero()), vec![0x80]);
+        assert_eq!(rlp_uint(&BigUint::from(1024u32)), vec![0x82, 0x04, 0x00]);
+        assert_eq!(
+            rlp_list(&[rlp_bytes(b"cat"), rlp_bytes(b"dog")]),
+            hex::decode("c88363617483646f67").unwrap()
+        );
+        assert_eq!(rlp_list(&[]), vec![0xc0]);
+
+        let long_string = b"Lorem ipsum dolor sit amet, consectetur adipisicing elit";
+        let encoded = rlp_bytes(long_string);
+        assert_eq!(&encoded[..2], &[0xb8, 0x38]);
+        assert_eq!(&encoded[2..], long_string);
+    }
+
+    #[test]
+    fn derives_checksummed_address() {
+        // the public key of the private key `1`
+        let public_key =
+            hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+                .unwrap();
+
+        assert_eq!(
+            public_key_to_address(&public_key).unwrap(),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+        );
+        assert_eq!(
+            to_checksum_address(&hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap()),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+    }
+
+    #[test]
+    fn only_hex_addresses_of_20_bytes_are_valid() {
+        assert_eq!(
+            parse_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+                .unwrap()
+                .len(),
+            20
+        );
+        assert!(parse_address("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+        assert!(parse_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA").is_err());
+        assert!(parse_address("0xzzAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    }
+
+    #[test]
+    fn nonces_are_not_reused() {
+        let account_id = [7; 16];
+
+        assert_eq!(Ethereum::reserve_nonce(&account_id, 3), 3);
+        assert_eq!(Ethereum::reserve_nonce(&account_id, 3), 4);
+        assert_eq!(Ethereum::reserve_nonce(&account_id, 10), 10);
+
+        Ethereum::release_nonce(&account_id);
+        assert_eq!(Ethereum::reserve_nonce(&account_id, 5), 5);
+    }
+
+    #[test]
+    fn signed_transaction_appends_the_signature() {
+        let transaction = Eip1559Transaction {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: BigUint::from(1u32),
+            max_fee_per_gas: BigUint::from(2u32),
+            gas_limit: Ethereum::GAS_LIMIT,
+            to: vec![0x11; 20],
+            value: BigUint::from(1u32),
+        };
+
+        let signed = transaction.encode_signed(1, &[0x22; 32], &[0x33; 32]);
+
+        assert_eq!(signed[0], 0x02);
+        assert!(signed
+            .ends_with(&[[0x01, 0xa0].as_slice(), &[0x22; 32], &[0xa0], &[0x33; 32]].concat()));
+        assert_ne!(transaction.signing_hash(), keccak256(&signed));
+    }
+}
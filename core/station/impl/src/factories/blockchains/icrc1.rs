@@ -0,0 +1,140 @@
+//! Candid types and calls of the ICRC-1 fungible token standard.
+
+use crate::{
+    errors::{BlockchainApiError, LEDGER_TEMPORARILY_UNAVAILABLE_INFO},
+    models::IcrcAccount,
+};
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Icrc1Account {
+    pub owner: Principal,
+    pub subaccount: Option<Vec<u8>>,
+}
+
+impl From<&IcrcAccount> for Icrc1Account {
+    fn from(account: &IcrcAccount) -> Self {
+        Self {
+            owner: account.owner,
+            subaccount: account
+                .effective_subaccount()
+                .map(|subaccount| subaccount.to_vec()),
+        }
+    }
+}
+
+fn network_error(err: (ic_cdk::api::call::RejectionCode, String)) -> BlockchainApiError {
+    BlockchainApiError::BlockchainNetworkError {
+        info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+    }
+}
+
+/// Calls `icrc1_balance_of` on the ledger and returns the balance of the account.
+pub async fn icrc1_balance_of(
+    ledger: Principal,
+    account: &IcrcAccount,
+) -> Result<Nat, BlockchainApiError> {
+    let (balance,): (Nat,) =
+        ic_cdk::call(ledger, "icrc1_balance_of", (Icrc1Account::from(account),))
+            .await
+            .map_err(network_error)?;
+
+    Ok(balance)
+}
+
+/// Calls `icrc1_symbol` on the ledger and returns the symbol of the token.
+pub async fn icrc1_symbol(ledger: Principal) -> Result<String, BlockchainApiError> {
+    let (symbol,): (String,) = ic_cdk::call(ledger, "icrc1_symbol", ())
+        .await
+        .map_err(network_error)?;
+
+    Ok(symbol)
+}
+
+/// Calls `icrc1_name` on the ledger and returns the name of the token.
+pub async fn icrc1_name(ledger: Principal) -> Result<String, BlockchainApiError> {
+    let (name,): (String,) = ic_cdk::call(ledger, "icrc1_name", ())
+        .await
+        .map_err(network_error)?;
+
+    Ok(name)
+}
+
+/// Calls `icrc1_decimals` on the ledger and returns the decimals of the token.
+pub async fn icrc1_decimals(ledger: Principal) -> Result<u8, BlockchainApiError> {
+    let (decimals,): (u8,) = ic_cdk::call(ledger, "icrc1_decimals", ())
+        .await
+        .map_err(network_error)?;
+
+    Ok(decimals)
+}
+
+/// Calls `icrc1_fee` on the ledger and returns the transfer fee of the token.
+pub async fn icrc1_fee(ledger: Principal) -> Result<Nat, BlockchainApiError> {
+    let (fee,): (Nat,) = ic_cdk::call(ledger, "icrc1_fee", ())
+        .await
+        .map_err(network_error)?;
+
+    Ok(fee)
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Icrc1TransferArgs {
+    pub from_subaccount: Option<Vec<u8>>,
+    pub to: Icrc1Account,
+    pub amount: Nat,
+    pub fee: Option<Nat>,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum Icrc1TransferError {
+    BadFee { expected_fee: Nat },
+    BadBurn { min_burn_amount: Nat },
+    InsufficientFunds { balance: Nat },
+    TooOld,
+    CreatedInFuture { ledger_time: u64 },
+    Duplicate { duplicate_of: Nat },
+    TemporarilyUnavailable,
+    GenericError { error_code: Nat, message: String },
+}
+
+/// Calls `icrc1_transfer` on the ledger and returns the index of the transfer block.
+pub async fn icrc1_transfer(
+    ledger: Principal,
+    args: Icrc1TransferArgs,
+) -> Result<Nat, BlockchainApiError> {
+    let (result,): (Result<Nat, Icrc1TransferError>,) =
+        ic_cdk::call(ledger, "icrc1_transfer", (args,))
+            .await
+            .map_err(network_error)?;
+
+    result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+        info: match err {
+            Icrc1TransferError::BadFee { expected_fee } => {
+                format!("Bad fee, expected: {}", expected_fee)
+            }
+            Icrc1TransferError::BadBurn { min_burn_amount } => {
+                format!("Bad burn, min_burn_amount: {}", min_burn_amount)
+            }
+            Icrc1TransferError::InsufficientFunds { balance } => {
+                format!("Insufficient balance, balance: {}", balance)
+            }
+            Icrc1TransferError::TooOld => "Tx too old".to_string(),
+            Icrc1TransferError::CreatedInFuture { ledger_time } => {
+                format!("Tx created in future, ledger_time: {}", ledger_time)
+            }
+            Icrc1TransferError::Duplicate { duplicate_of } => {
+                format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+            }
+            Icrc1TransferError::TemporarilyUnavailable => {
+                LEDGER_TEMPORARILY_UNAVAILABLE_INFO.to_string()
+            }
+            Icrc1TransferError::GenericError {
+                error_code,
+                message,
+            } => format!("Error code {}: {}", error_code, message),
+        },
+    })
+}
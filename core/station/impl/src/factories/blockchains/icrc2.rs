@@ -0,0 +1,168 @@
+//! Candid types and calls of the ICRC-2 approve and transfer from standard.
+
+use super::Icrc1Account;
+use crate::errors::{BlockchainApiError, LEDGER_TEMPORARILY_UNAVAILABLE_INFO};
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct ApproveArgs {
+    pub from_subaccount: Option<Vec<u8>>,
+    pub spender: Icrc1Account,
+    pub amount: Nat,
+    pub expected_allowance: Option<Nat>,
+    pub expires_at: Option<u64>,
+    pub fee: Option<Nat>,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum ApproveError {
+    BadFee { expected_fee: Nat },
+    InsufficientFunds { balance: Nat },
+    AllowanceChanged { current_allowance: Nat },
+    Expired { ledger_time: u64 },
+    TooOld,
+    CreatedInFuture { ledger_time: u64 },
+    Duplicate { duplicate_of: Nat },
+    TemporarilyUnavailable,
+    GenericError { error_code: Nat, message: String },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct AllowanceArgs {
+    pub account: Icrc1Account,
+    pub spender: Icrc1Account,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Allowance {
+    pub allowance: Nat,
+    pub expires_at: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct TransferFromArgs {
+    pub spender_subaccount: Option<Vec<u8>>,
+    pub from: Icrc1Account,
+    pub to: Icrc1Account,
+    pub amount: Nat,
+    pub fee: Option<Nat>,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TransferFromError {
+    BadFee { expected_fee: Nat },
+    BadBurn { min_burn_amount: Nat },
+    InsufficientFunds { balance: Nat },
+    InsufficientAllowance { allowance: Nat },
+    TooOld,
+    CreatedInFuture { ledger_time: u64 },
+    Duplicate { duplicate_of: Nat },
+    TemporarilyUnavailable,
+    GenericError { error_code: Nat, message: String },
+}
+
+/// Calls `icrc2_approve` on the ledger and returns the index of the approval block.
+pub async fn icrc2_approve(
+    ledger: Principal,
+    args: ApproveArgs,
+) -> Result<Nat, BlockchainApiError> {
+    let (result,): (Result<Nat, ApproveError>,) = ic_cdk::call(ledger, "icrc2_approve", (args,))
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+    result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+        info: match err {
+            ApproveError::BadFee { expected_fee } => {
+                format!("Bad fee, expected: {}", expected_fee)
+            }
+            ApproveError::InsufficientFunds { balance } => {
+                format!("Insufficient balance to pay the fee, balance: {}", balance)
+            }
+            ApproveError::AllowanceChanged { current_allowance } => {
+                format!(
+                    "Allowance changed, current_allowance: {}",
+                    current_allowance
+                )
+            }
+            ApproveError::Expired { ledger_time } => {
+                format!("Approval expired, ledger_time: {}", ledger_time)
+            }
+            ApproveError::TooOld => "Tx too old".to_string(),
+            ApproveError::CreatedInFuture { ledger_time } => {
+                format!("Tx created in future, ledger_time: {}", ledger_time)
+            }
+            ApproveError::Duplicate { duplicate_of } => {
+                format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+            }
+            ApproveError::TemporarilyUnavailable => LEDGER_TEMPORARILY_UNAVAILABLE_INFO.to_string(),
+            ApproveError::GenericError {
+                error_code,
+                message,
+            } => format!("Error code {}: {}", error_code, message),
+        },
+    })
+}
+
+/// Calls `icrc2_allowance` on the ledger and returns the allowance the account granted to the spender.
+pub async fn icrc2_allowance(
+    ledger: Principal,
+    args: AllowanceArgs,
+) -> Result<Allowance, BlockchainApiError> {
+    let (allowance,): (Allowance,) = ic_cdk::call(ledger, "icrc2_allowance", (args,))
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+    Ok(allowance)
+}
+
+/// Calls `icrc2_transfer_from` on the ledger and returns the index of the transfer block.
+pub async fn icrc2_transfer_from(
+    ledger: Principal,
+    args: TransferFromArgs,
+) -> Result<Nat, BlockchainApiError> {
+    let (result,): (Result<Nat, TransferFromError>,) =
+        ic_cdk::call(ledger, "icrc2_transfer_from", (args,))
+            .await
+            .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+                info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+            })?;
+
+    result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+        info: match err {
+            TransferFromError::BadFee { expected_fee } => {
+                format!("Bad fee, expected: {}", expected_fee)
+            }
+            TransferFromError::BadBurn { min_burn_amount } => {
+                format!("Bad burn, min_burn_amount: {}", min_burn_amount)
+            }
+            TransferFromError::InsufficientFunds { balance } => {
+                format!("Insufficient balance, balance: {}", balance)
+            }
+            TransferFromError::InsufficientAllowance { allowance } => {
+                format!("Insufficient allowance, allowance: {}", allowance)
+            }
+            TransferFromError::TooOld => "Tx too old".to_string(),
+            TransferFromError::CreatedInFuture { ledger_time } => {
+                format!("Tx created in future, ledger_time: {}", ledger_time)
+            }
+            TransferFromError::Duplicate { duplicate_of } => {
+                format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+            }
+            TransferFromError::TemporarilyUnavailable => {
+                LEDGER_TEMPORARILY_UNAVAILABLE_INFO.to_string()
+            }
+            TransferFromError::GenericError {
+                error_code,
+                message,
+            } => format!("Error code {}: {}", error_code, message),
+        },
+    })
+}
@@ -0,0 +1,319 @@
+//! Candid types and calls of the ICRC-7 NFT standard, and the adapter of the NFT accounts.
+
+use super::{
+    BlockchainApi, BlockchainApiResult, BlockchainTransactionFee, BlockchainTransactionLookup,
+    BlockchainTransactionSubmitted, Icrc1Account, InternetComputer,
+    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+};
+use crate::{
+    core::ic_cdk::{api::id as station_canister_self_id, next_time},
+    errors::BlockchainApiError,
+    mappers::HelperMapper,
+    models::{
+        Account, Blockchain, BlockchainStandard, IcrcAccount, Metadata, NetworkProfile, Transfer,
+        TransferNftOperationInput, ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+    },
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Nat, Principal};
+use num_bigint::BigUint;
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct Icrc7TransferArg {
+    pub from_subaccount: Option<Vec<u8>>,
+    pub to: Icrc1Account,
+    pub token_id: Nat,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum Icrc7TransferError {
+    NonExistingTokenId,
+    InvalidRecipient,
+    Unauthorized,
+    TooOld,
+    CreatedInFuture { ledger_time: u64 },
+    Duplicate { duplicate_of: Nat },
+    GenericError { error_code: Nat, message: String },
+    GenericBatchError { error_code: Nat, message: String },
+}
+
+fn network_error(err: (ic_cdk::api::call::RejectionCode, String)) -> BlockchainApiError {
+    BlockchainApiError::BlockchainNetworkError {
+        info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+    }
+}
+
+/// Calls `icrc7_balance_of` on the collection and returns the number of tokens held by the account.
+pub async fn icrc7_balance_of(
+    collection: Principal,
+    account: &IcrcAccount,
+) -> Result<Nat, BlockchainApiError> {
+    let (balances,): (Vec<Nat>,) = ic_cdk::call(
+        collection,
+        "icrc7_balance_of",
+        (vec![Icrc1Account::from(account)],),
+    )
+    .await
+    .map_err(network_error)?;
+
+    balances
+        .into_iter()
+        .next()
+        .ok_or(BlockchainApiError::BlockchainNetworkError {
+            info: "The collection returned no balance for the account".to_string(),
+        })
+}
+
+/// Calls `icrc7_tokens_of` on the collection and returns the ids of the tokens held by the account,
+/// starting after the `prev` token id.
+pub async fn icrc7_tokens_of(
+    collection: Principal,
+    account: &IcrcAccount,
+    prev: Option<Nat>,
+    take: Option<Nat>,
+) -> Result<Vec<Nat>, BlockchainApiError> {
+    let (token_ids,): (Vec<Nat>,) = ic_cdk::call(
+        collection,
+        "icrc7_tokens_of",
+        (Icrc1Account::from(account), prev, take),
+    )
+    .await
+    .map_err(network_error)?;
+
+    Ok(token_ids)
+}
+
+/// Calls `icrc7_transfer` on the collection with a single transfer and returns its transaction index.
+pub async fn icrc7_transfer(
+    collection: Principal,
+    arg: Icrc7TransferArg,
+) -> Result<Nat, BlockchainApiError> {
+    let (results,): (Vec<Option<Result<Nat, Icrc7TransferError>>>,) =
+        ic_cdk::call(collection, "icrc7_transfer", (vec![arg],))
+            .await
+            .map_err(network_error)?;
+
+    let result = results.into_iter().next().flatten().ok_or(
+        BlockchainApiError::TransactionSubmitFailed {
+            info: "The collection did not process the transfer".to_string(),
+        },
+    )?;
+
+    result.map_err(|err| BlockchainApiError::TransactionSubmitFailed {
+        info: match err {
+            Icrc7TransferError::NonExistingTokenId => "Non existing token id".to_string(),
+            Icrc7TransferError::InvalidRecipient => "Invalid recipient".to_string(),
+            Icrc7TransferError::Unauthorized => "Unauthorized".to_string(),
+            Icrc7TransferError::TooOld => "Tx too old".to_string(),
+            Icrc7TransferError::CreatedInFuture { ledger_time } => {
+                format!("Tx created in future, ledger_time: {}", ledger_time)
+            }
+            Icrc7TransferError::Duplicate { duplicate_of } => {
+                format!("Tx duplicate, duplicate_of: {}", duplicate_of)
+            }
+            Icrc7TransferError::GenericError {
+                error_code,
+                message,
+            }
+            | Icrc7TransferError::GenericBatchError {
+                error_code,
+                message,
+            } => {
+                format!("error_code: {}, message: {}", error_code, message)
+            }
+        },
+    })
+}
+
+/// The adapter of NFT accounts, which hold the tokens of an ICRC-7 collection on the Internet Computer.
+///
+/// The balance of an NFT account is the number of tokens it holds, the tokens themselves are listed
+/// with `list_nfts` and moved with the `TransferNft` operation instead of regular transfers.
+#[derive(Debug)]
+pub struct Icrc7 {
+    station_canister_id: Principal,
+    network: NetworkProfile,
+}
+
+impl Icrc7 {
+    pub const BLOCKCHAIN: Blockchain = Blockchain::InternetComputer;
+    pub const STANDARD: BlockchainStandard = BlockchainStandard::ICRC7;
+    /// The maximum number of token ids that are listed at once.
+    pub const MAX_LIST_NFTS: u64 = 100;
+
+    pub fn create(network: NetworkProfile) -> Self {
+        Self {
+            station_canister_id: station_canister_self_id(),
+            network,
+        }
+    }
+
+    /// Returns the collection canister of the account, stored as its ledger canister id.
+    pub fn collection_canister_id(
+        station_account: &Account,
+    ) -> Result<Principal, BlockchainApiError> {
+        let ledger_canister_id = station_account
+            .metadata
+            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+            .unwrap_or_default();
+
+        Principal::from_text(&ledger_canister_id)
+            .map_err(|_| BlockchainApiError::UnsupportedLedger { ledger_canister_id })
+    }
+
+    /// The ICRC-1 account that holds the tokens, which uses the same subaccount as the ICP accounts.
+    fn station_account_to_icrc_account(&self, station_account: &Account) -> IcrcAccount {
+        IcrcAccount::new(
+            self.station_canister_id,
+            Some(InternetComputer::subaccount_from_station_account_id(
+                &station_account.id,
+            )),
+        )
+    }
+
+    /// Returns the ids of the tokens held by the account, starting after the `prev` token id.
+    pub async fn list_nfts(
+        &self,
+        station_account: &Account,
+        prev: Option<Nat>,
+        take: Option<u64>,
+    ) -> Result<Vec<Nat>, BlockchainApiError> {
+        icrc7_tokens_of(
+            Self::collection_canister_id(station_account)?,
+            &self.station_account_to_icrc_account(station_account),
+            prev,
+            Some(Nat::from(
+                take.unwrap_or(Self::MAX_LIST_NFTS).min(Self::MAX_LIST_NFTS),
+            )),
+        )
+        .await
+    }
+
+    /// Transfers the token to the destination account and returns the transaction index.
+    pub async fn transfer_nft(
+        &self,
+        station_account: &Account,
+        input: &TransferNftOperationInput,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        let transaction_index = icrc7_transfer(
+            Self::collection_canister_id(station_account)?,
+            Icrc7TransferArg {
+                from_subaccount: Some(
+                    InternetComputer::subaccount_from_station_account_id(&station_account.id)
+                        .to_vec(),
+                ),
+                to: (&input.to).into(),
+                token_id: input.token_id.clone(),
+                memo: input.memo.clone(),
+                created_at_time: Some(next_time()),
+            },
+        )
+        .await?;
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![(
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                HelperMapper::nat_to_u64(transaction_index)?.to_string(),
+            )],
+        })
+    }
+}
+
+#[async_trait]
+impl BlockchainApi for Icrc7 {
+    async fn generate_address(&self, station_account: &Account) -> BlockchainApiResult<String> {
+        Self::collection_canister_id(station_account)?;
+
+        Ok(self
+            .station_account_to_icrc_account(station_account)
+            .to_string())
+    }
+
+    async fn balance(&self, station_account: &Account) -> BlockchainApiResult<BigUint> {
+        let balance = icrc7_balance_of(
+            Self::collection_canister_id(station_account)?,
+            &self.station_account_to_icrc_account(station_account),
+        )
+        .await
+        .map_err(|_| BlockchainApiError::FetchBalanceFailed {
+            account_id: uuid::Uuid::from_bytes(station_account.id)
+                .hyphenated()
+                .to_string(),
+        })?;
+
+        Ok(balance.0)
+    }
+
+    async fn decimals(&self, _station_account: &Account) -> BlockchainApiResult<u32> {
+        Ok(0)
+    }
+
+    async fn transaction_fee(
+        &self,
+        _station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainTransactionFee> {
+        Ok(BlockchainTransactionFee {
+            fee: BigUint::from(0u64),
+            metadata: Metadata::default(),
+        })
+    }
+
+    fn default_network(&self) -> String {
+        self.network.to_string()
+    }
+
+    async fn submit_transaction(
+        &self,
+        _station_account: &Account,
+        _transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        Err(BlockchainApiError::TransactionSubmitFailed {
+            info: "NFTs can only be moved with the TransferNft operation".to_string(),
+        }
+        .into())
+    }
+
+    async fn find_transaction(
+        &self,
+        _station_account: &Account,
+        _transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionLookup> {
+        Err(BlockchainApiError::TransactionLookupFailed {
+            info: "NFT accounts do not have transfers".to_string(),
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_test_utils::mock_account;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn collection_is_read_from_the_account_metadata() {
+        let mut account = mock_account();
+        account.standard = BlockchainStandard::ICRC7;
+        account.metadata = Metadata::new(BTreeMap::from([(
+            ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+            "ryjl3-tyaaa-aaaaa-aaaba-cai".to_string(),
+        )]));
+
+        assert_eq!(
+            Icrc7::collection_canister_id(&account),
+            Ok(Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap())
+        );
+
+        account.metadata = Metadata::default();
+
+        assert_eq!(
+            Icrc7::collection_canister_id(&account),
+            Err(BlockchainApiError::UnsupportedLedger {
+                ledger_canister_id: String::new()
+            })
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use super::{
-    BlockchainApi, BlockchainApiResult, BlockchainTransactionFee, BlockchainTransactionSubmitted,
-    TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    icrc2_allowance, icrc2_approve, icrc2_transfer_from, AllowanceArgs, ApproveArgs,
+    BlockchainAllowance, BlockchainAllowanceSpender, BlockchainApi, BlockchainApiResult,
+    BlockchainTransactionFee, BlockchainTransactionLookup, BlockchainTransactionSubmitted,
+    TransferFromArgs, TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
     TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
 };
 use crate::{
@@ -8,7 +10,8 @@ use crate::{
     errors::BlockchainApiError,
     mappers::HelperMapper,
     models::{
-        Account, AccountId, Blockchain, BlockchainStandard, Metadata, Transfer, METADATA_MEMO_KEY,
+        Account, AccountId, ApproveOperationInput, Blockchain, BlockchainStandard, IcrcAccount,
+        Metadata, NetworkProfile, Transfer, TransferMemo, TransferStatus, METADATA_MEMO_KEY,
     },
 };
 use async_trait::async_trait;
@@ -16,7 +19,7 @@ use byteorder::{BigEndian, ByteOrder};
 use candid::Principal;
 use ic_ledger_types::{
     account_balance, query_blocks, transfer, AccountBalanceArgs, AccountIdentifier, GetBlocksArgs,
-    Memo, QueryBlocksResponse, Subaccount, Timestamp, Tokens, Transaction, TransferArgs,
+    Memo, Operation, QueryBlocksResponse, Subaccount, Timestamp, Tokens, Transaction, TransferArgs,
     TransferError as LedgerTransferError, DEFAULT_FEE,
 };
 use num_bigint::BigUint;
@@ -25,39 +28,15 @@ use orbit_essentials::{
     cdk::{self},
 };
 use sha2::{Digest, Sha256};
-use std::{
-    fmt::{Display, Formatter},
-    str::FromStr,
-};
+use std::str::FromStr;
 use uuid::Uuid;
 
+/// The adapter of ICP accounts, the ledger of local replicas is deployed with the mainnet canister id.
 #[derive(Debug)]
 pub struct InternetComputer {
     /// This canister id is used to derive all the different subaccount ids for the station accounts.
     station_canister_id: Principal,
-}
-
-pub enum InternetComputerNetwork {
-    Mainnet,
-}
-
-impl FromStr for InternetComputerNetwork {
-    type Err = ();
-
-    fn from_str(variant: &str) -> Result<InternetComputerNetwork, Self::Err> {
-        match variant {
-            "mainnet" => Ok(InternetComputerNetwork::Mainnet),
-            _ => Err(()),
-        }
-    }
-}
-
-impl Display for InternetComputerNetwork {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InternetComputerNetwork::Mainnet => write!(f, "mainnet"),
-        }
-    }
+    network: NetworkProfile,
 }
 
 pub struct SubmitTransferResponse {
@@ -70,11 +49,13 @@ impl InternetComputer {
     pub const STANDARD: BlockchainStandard = BlockchainStandard::Native;
     pub const ICP_LEDGER_CANISTER_ID: &'static str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
     pub const DECIMALS: u32 = 8;
-    pub const MAIN_NETWORK: InternetComputerNetwork = InternetComputerNetwork::Mainnet;
+    /// The maximum number of recent ledger blocks inspected when looking up a submitted transaction.
+    pub const MAX_LOOKUP_BLOCKS: u64 = 2_000;
 
-    pub fn create() -> Self {
+    pub fn create(network: NetworkProfile) -> Self {
         Self {
             station_canister_id: station_canister_self_id(),
+            network,
         }
     }
 
@@ -89,6 +70,19 @@ impl InternetComputer {
         subaccount_id
     }
 
+    /// Generates the subaccount id of the subaccount of the given station_account with the given index.
+    ///
+    /// The index is stored in the last 4 bytes, so the index `0` is the subaccount of the station_account itself.
+    pub fn subaccount_from_station_account_id_and_index(
+        station_account_id: &AccountId,
+        index: u32,
+    ) -> [u8; 32] {
+        let mut subaccount_id = Self::subaccount_from_station_account_id(station_account_id);
+        subaccount_id[28..32].copy_from_slice(&index.to_be_bytes());
+
+        subaccount_id
+    }
+
     pub fn ledger_canister_id() -> Principal {
         Principal::from_text(Self::ICP_LEDGER_CANISTER_ID).unwrap()
     }
@@ -121,12 +115,35 @@ impl InternetComputer {
         account.to_hex()
     }
 
+    /// Creates the ledger account id of the subaccount of the given station_account with the given index.
+    pub fn station_subaccount_to_ledger_account(
+        &self,
+        station_account_id: &AccountId,
+        index: u32,
+    ) -> AccountIdentifier {
+        let subaccount = InternetComputer::subaccount_from_station_account_id_and_index(
+            station_account_id,
+            index,
+        );
+
+        AccountIdentifier::new(&self.station_canister_id, &Subaccount(subaccount))
+    }
+
     /// Returns the latest balance of the given station_account.
     pub async fn balance(&self, station_account: &Account) -> BlockchainApiResult<u64> {
+        self.subaccount_balance(station_account, 0).await
+    }
+
+    /// Returns the latest balance of the subaccount of the given station_account with the given index.
+    pub async fn subaccount_balance(
+        &self,
+        station_account: &Account,
+        index: u32,
+    ) -> BlockchainApiResult<u64> {
         let balance = account_balance(
             Self::ledger_canister_id(),
             AccountBalanceArgs {
-                account: self.station_account_to_ledger_account(&station_account.id),
+                account: self.station_subaccount_to_ledger_account(&station_account.id, index),
             },
         )
         .await
@@ -147,18 +164,55 @@ impl InternetComputer {
         Self::DECIMALS
     }
 
+    /// Returns the ledger memo of the given transfer, which defaults to the `memo` metadata and then to
+    /// the first bytes of the transfer id.
+    pub fn transfer_memo(station_transfer: &Transfer) -> Result<u64, ApiError> {
+        match &station_transfer.memo {
+            Some(TransferMemo::Number(memo)) => return Ok(*memo),
+            Some(TransferMemo::Blob(_)) => Err(BlockchainApiError::TransactionSubmitFailed {
+                info: "The ICP ledger only supports numeric transfer memos".to_string(),
+            })?,
+            None => {}
+        }
+
+        Ok(
+            match station_transfer.metadata_map().get(METADATA_MEMO_KEY) {
+                Some(memo) => HelperMapper::to_u64(memo)?,
+                None => BigEndian::read_u64(&station_transfer.id[0..8]),
+            },
+        )
+    }
+
+    /// Returns the ICRC-1 memo of the given transfer, numeric memos are encoded as big endian bytes.
+    pub fn icrc1_transfer_memo(station_transfer: &Transfer) -> Result<Vec<u8>, ApiError> {
+        match &station_transfer.memo {
+            Some(memo) => Ok(memo.to_icrc1_bytes()),
+            None => Ok(Self::transfer_memo(station_transfer)?
+                .to_be_bytes()
+                .to_vec()),
+        }
+    }
+
+    /// Returns the `created_at_time` used for the ledger deduplication of the given transfer.
+    ///
+    /// Transfers that are being processed use their processing start time, which makes the
+    /// deduplication key reproducible when the outcome of the submission needs to be verified.
+    pub fn transfer_created_at_time(station_transfer: &Transfer) -> u64 {
+        match station_transfer.status {
+            TransferStatus::Processing { started_at } => started_at,
+            _ => cdk::next_time(),
+        }
+    }
+
     pub async fn submit_transfer(
         &self,
         station_account: Account,
         station_transfer: Transfer,
     ) -> Result<SubmitTransferResponse, ApiError> {
-        let current_time = cdk::next_time();
+        let current_time = Self::transfer_created_at_time(&station_transfer);
         let amount: u64 = HelperMapper::nat_to_u64(station_transfer.amount.clone())?;
         let transaction_fee: u64 = HelperMapper::nat_to_u64(station_transfer.fee.clone())?;
-        let memo = match station_transfer.metadata_map().get(METADATA_MEMO_KEY) {
-            Some(memo) => HelperMapper::to_u64(memo)?,
-            None => BigEndian::read_u64(&station_transfer.id[0..8]),
-        };
+        let memo = Self::transfer_memo(&station_transfer)?;
         let to_address =
             AccountIdentifier::from_hex(&station_transfer.to_address).map_err(|error| {
                 BlockchainApiError::InvalidToAddress {
@@ -246,6 +300,173 @@ impl InternetComputer {
             transaction_hash,
         })
     }
+
+    /// Pulls the funds of the transfer from the `from` account with `icrc2_transfer_from`, where the
+    /// station account is the spender of the allowance approved by the `from` account.
+    ///
+    /// The destination of such transfers is an ICRC-1 textual account instead of an account identifier.
+    pub async fn submit_transfer_from(
+        &self,
+        station_account: &Account,
+        station_transfer: &Transfer,
+        from: &IcrcAccount,
+    ) -> Result<SubmitTransferResponse, ApiError> {
+        let memo = Self::icrc1_transfer_memo(station_transfer)?;
+        let to = IcrcAccount::from_str(&station_transfer.to_address).map_err(|error| {
+            BlockchainApiError::InvalidToAddress {
+                address: station_transfer.to_address.clone(),
+                error,
+            }
+        })?;
+
+        let block_index = icrc2_transfer_from(
+            Self::ledger_canister_id(),
+            TransferFromArgs {
+                spender_subaccount: Some(
+                    InternetComputer::subaccount_from_station_account_id(&station_account.id)
+                        .to_vec(),
+                ),
+                from: from.into(),
+                to: (&to).into(),
+                amount: station_transfer.amount.clone(),
+                fee: Some(station_transfer.fee.clone()),
+                memo: Some(memo),
+                created_at_time: Some(Self::transfer_created_at_time(station_transfer)),
+            },
+        )
+        .await?;
+
+        Ok(SubmitTransferResponse {
+            block_height: HelperMapper::nat_to_u64(block_index)?,
+            transaction_hash: None,
+        })
+    }
+
+    /// Grants the spender an allowance over the funds of the station account with `icrc2_approve`.
+    pub async fn approve_spender(
+        &self,
+        station_account: &Account,
+        approval: &ApproveOperationInput,
+    ) -> Result<u64, ApiError> {
+        let block_index = icrc2_approve(
+            Self::ledger_canister_id(),
+            ApproveArgs {
+                from_subaccount: Some(
+                    InternetComputer::subaccount_from_station_account_id(&station_account.id)
+                        .to_vec(),
+                ),
+                spender: (&approval.spender).into(),
+                amount: approval.amount.clone(),
+                expected_allowance: approval.expected_allowance.clone(),
+                expires_at: approval.expires_at,
+                fee: approval.fee.clone(),
+                memo: None,
+                created_at_time: Some(cdk::next_time()),
+            },
+        )
+        .await?;
+
+        Ok(HelperMapper::nat_to_u64(block_index)?)
+    }
+
+    /// Searches the recent ledger blocks for the transaction matching the deduplication key of the transfer.
+    ///
+    /// The key is made of the source and destination accounts, the amount, the memo and, for transfers that
+    /// are being processed, the `created_at_time` used when submitting the transaction.
+    pub async fn find_transfer_block(
+        &self,
+        station_account: &Account,
+        station_transfer: &Transfer,
+    ) -> Result<Option<SubmitTransferResponse>, ApiError> {
+        let amount: u64 = HelperMapper::nat_to_u64(station_transfer.amount.clone())?;
+        let memo = Self::transfer_memo(station_transfer)?;
+        let from_address = self.station_account_to_ledger_account(&station_account.id);
+        let to_address =
+            AccountIdentifier::from_hex(&station_transfer.to_address).map_err(|error| {
+                BlockchainApiError::InvalidToAddress {
+                    address: station_transfer.to_address.clone(),
+                    error,
+                }
+            })?;
+        let (expected_created_at_time, lookup_from_time) = match station_transfer.status {
+            TransferStatus::Processing { started_at } => (Some(started_at), started_at),
+            _ => (None, station_transfer.created_timestamp),
+        };
+
+        let tip = query_blocks(
+            Self::ledger_canister_id(),
+            GetBlocksArgs {
+                start: 0,
+                length: 0,
+            },
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let start = tip
+            .first_block_index
+            .max(tip.chain_length.saturating_sub(Self::MAX_LOOKUP_BLOCKS));
+
+        let response = query_blocks(
+            Self::ledger_canister_id(),
+            GetBlocksArgs {
+                start,
+                length: tip.chain_length.saturating_sub(start),
+            },
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        for (offset, block) in response.blocks.iter().enumerate() {
+            let transaction = &block.transaction;
+            let is_matching_time = match expected_created_at_time {
+                Some(created_at_time) => {
+                    transaction.created_at_time.timestamp_nanos == created_at_time
+                }
+                None => transaction.created_at_time.timestamp_nanos >= lookup_from_time,
+            };
+
+            if !is_matching_time || transaction.memo != Memo(memo) {
+                continue;
+            }
+
+            if let Some(Operation::Transfer {
+                from,
+                to,
+                amount: transferred,
+                ..
+            }) = &transaction.operation
+            {
+                if *from == from_address && *to == to_address && transferred.e8s() == amount {
+                    return Ok(Some(SubmitTransferResponse {
+                        block_height: response.first_block_index + offset as u64,
+                        transaction_hash: Self::hash_transaction(transaction).ok(),
+                    }));
+                }
+            }
+        }
+
+        // the transaction could have been submitted before the oldest inspected block
+        let is_range_covered = match response.blocks.first() {
+            Some(block) => block.timestamp.timestamp_nanos <= lookup_from_time,
+            None => response.first_block_index == 0,
+        };
+
+        if !is_range_covered {
+            Err(BlockchainApiError::TransactionLookupFailed {
+                info: format!(
+                    "the transfer predates the {} most recent ledger blocks",
+                    Self::MAX_LOOKUP_BLOCKS
+                ),
+            })?
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -260,6 +481,26 @@ impl BlockchainApi for InternetComputer {
         Ok(BigUint::from(balance))
     }
 
+    async fn generate_subaccount_address(
+        &self,
+        station_account: &Account,
+        index: u32,
+    ) -> BlockchainApiResult<String> {
+        Ok(self
+            .station_subaccount_to_ledger_account(&station_account.id, index)
+            .to_hex())
+    }
+
+    async fn subaccount_balance(
+        &self,
+        station_account: &Account,
+        index: u32,
+    ) -> BlockchainApiResult<BigUint> {
+        let balance = self.subaccount_balance(station_account, index).await?;
+
+        Ok(BigUint::from(balance))
+    }
+
     async fn decimals(&self, _station_account: &Account) -> BlockchainApiResult<u32> {
         Ok(self.decimals())
     }
@@ -275,7 +516,7 @@ impl BlockchainApi for InternetComputer {
     }
 
     fn default_network(&self) -> String {
-        Self::MAIN_NETWORK.to_string()
+        self.network.to_string()
     }
 
     async fn submit_transaction(
@@ -283,9 +524,16 @@ impl BlockchainApi for InternetComputer {
         station_account: &Account,
         transfer: &Transfer,
     ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
-        let transfer_response = self
-            .submit_transfer(station_account.clone(), transfer.clone())
-            .await?;
+        let transfer_response = match &transfer.spend_from {
+            Some(from) => {
+                self.submit_transfer_from(station_account, transfer, from)
+                    .await?
+            }
+            None => {
+                self.submit_transfer(station_account.clone(), transfer.clone())
+                    .await?
+            }
+        };
 
         Ok(BlockchainTransactionSubmitted {
             details: vec![
@@ -300,4 +548,87 @@ impl BlockchainApi for InternetComputer {
             ],
         })
     }
+
+    async fn find_transaction(
+        &self,
+        station_account: &Account,
+        transfer: &Transfer,
+    ) -> BlockchainApiResult<BlockchainTransactionLookup> {
+        if transfer.spend_from.is_some() {
+            Err(BlockchainApiError::TransactionLookupFailed {
+                info: "transfers pulled with icrc2_transfer_from can not be looked up".to_string(),
+            })?
+        }
+
+        let lookup = self.find_transfer_block(station_account, transfer).await?;
+
+        Ok(match lookup {
+            Some(transfer_response) => {
+                BlockchainTransactionLookup::Found(BlockchainTransactionSubmitted {
+                    details: vec![
+                        (
+                            TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                            transfer_response.block_height.to_string(),
+                        ),
+                        (
+                            TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY.to_string(),
+                            transfer_response.transaction_hash.unwrap_or("".to_string()),
+                        ),
+                    ],
+                })
+            }
+            None => BlockchainTransactionLookup::NotFound,
+        })
+    }
+
+    async fn approve(
+        &self,
+        station_account: &Account,
+        approval: &ApproveOperationInput,
+    ) -> BlockchainApiResult<BlockchainTransactionSubmitted> {
+        let block_height = self.approve_spender(station_account, approval).await?;
+
+        Ok(BlockchainTransactionSubmitted {
+            details: vec![(
+                TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+                block_height.to_string(),
+            )],
+        })
+    }
+
+    fn allowance_spender(
+        &self,
+        station_account: &Account,
+    ) -> BlockchainApiResult<BlockchainAllowanceSpender> {
+        Ok(BlockchainAllowanceSpender {
+            ledger_canister_id: Self::ledger_canister_id(),
+            spender: IcrcAccount::new(
+                self.station_canister_id,
+                Some(InternetComputer::subaccount_from_station_account_id(
+                    &station_account.id,
+                )),
+            ),
+        })
+    }
+
+    async fn allowance(
+        &self,
+        station_account: &Account,
+        owner: &IcrcAccount,
+    ) -> BlockchainApiResult<BlockchainAllowance> {
+        let spender = self.allowance_spender(station_account)?;
+        let allowance = icrc2_allowance(
+            spender.ledger_canister_id,
+            AllowanceArgs {
+                account: owner.into(),
+                spender: (&spender.spender).into(),
+            },
+        )
+        .await?;
+
+        Ok(BlockchainAllowance {
+            amount: allowance.allowance.0,
+            expires_at: allowance.expires_at,
+        })
+    }
 }
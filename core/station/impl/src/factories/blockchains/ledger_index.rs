@@ -0,0 +1,574 @@
+//! Candid types and calls of the ICP and ICRC index canisters, which index the transactions of the
+//! ledger accounts and are used to import the transaction history of the station accounts.
+
+use super::{CkBtc, Icrc1Account, InternetComputer};
+use crate::{
+    errors::BlockchainApiError,
+    mappers::HelperMapper,
+    models::{
+        Account, AccountTransaction, AccountTransactionDirection, AccountTransactionKind,
+        Blockchain, BlockchainStandard, IcrcAccount, NetworkProfile,
+        ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY, ASSET_METADATA_INDEX_CANISTER_ID_KEY,
+    },
+    services::ASSET_SERVICE,
+};
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcpIndexTokens {
+    pub e8s: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcpIndexTimeStamp {
+    pub timestamp_nanos: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum IcpIndexOperation {
+    Approve {
+        fee: IcpIndexTokens,
+        from: String,
+        allowance: IcpIndexTokens,
+        expires_at: Option<IcpIndexTimeStamp>,
+        spender: String,
+    },
+    Burn {
+        from: String,
+        amount: IcpIndexTokens,
+        spender: Option<String>,
+    },
+    Mint {
+        to: String,
+        amount: IcpIndexTokens,
+    },
+    Transfer {
+        to: String,
+        fee: IcpIndexTokens,
+        from: String,
+        amount: IcpIndexTokens,
+        spender: Option<String>,
+    },
+    TransferFrom {
+        to: String,
+        fee: IcpIndexTokens,
+        from: String,
+        amount: IcpIndexTokens,
+        spender: String,
+    },
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcpIndexTransaction {
+    pub memo: u64,
+    pub icrc1_memo: Option<Vec<u8>>,
+    pub operation: IcpIndexOperation,
+    pub created_at_time: Option<IcpIndexTimeStamp>,
+    pub timestamp: Option<IcpIndexTimeStamp>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcpIndexTransactionWithId {
+    pub id: u64,
+    pub transaction: IcpIndexTransaction,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountIdentifierTransactionsArgs {
+    pub max_results: u64,
+    pub start: Option<u64>,
+    pub account_identifier: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountIdentifierTransactionsResponse {
+    pub balance: u64,
+    pub transactions: Vec<IcpIndexTransactionWithId>,
+    pub oldest_tx_id: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountIdentifierTransactionsError {
+    pub message: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexTransfer {
+    pub to: Icrc1Account,
+    pub fee: Option<Nat>,
+    pub from: Icrc1Account,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+    pub amount: Nat,
+    pub spender: Option<Icrc1Account>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexMint {
+    pub to: Icrc1Account,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+    pub amount: Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexBurn {
+    pub from: Icrc1Account,
+    pub memo: Option<Vec<u8>>,
+    pub created_at_time: Option<u64>,
+    pub amount: Nat,
+    pub spender: Option<Icrc1Account>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexTransaction {
+    pub kind: String,
+    pub mint: Option<IcrcIndexMint>,
+    pub burn: Option<IcrcIndexBurn>,
+    pub transfer: Option<IcrcIndexTransfer>,
+    pub timestamp: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexTransactionWithId {
+    pub id: Nat,
+    pub transaction: IcrcIndexTransaction,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct GetAccountTransactionsArgs {
+    pub account: Icrc1Account,
+    pub start: Option<Nat>,
+    pub max_results: Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexTransactions {
+    pub balance: Nat,
+    pub transactions: Vec<IcrcIndexTransactionWithId>,
+    pub oldest_tx_id: Option<Nat>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub struct IcrcIndexTransactionsError {
+    pub message: String,
+}
+
+fn network_error(err: (ic_cdk::api::call::RejectionCode, String)) -> BlockchainApiError {
+    BlockchainApiError::BlockchainNetworkError {
+        info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+    }
+}
+
+fn index_error(message: String) -> BlockchainApiError {
+    BlockchainApiError::BlockchainNetworkError {
+        info: format!("The index canister returned an error: {}", message),
+    }
+}
+
+/// Calls `get_account_identifier_transactions` on the ICP index and returns the transactions of the
+/// account identifier, the most recent first and starting at the `start` transaction id.
+pub async fn icp_index_account_transactions(
+    index: Principal,
+    args: GetAccountIdentifierTransactionsArgs,
+) -> Result<GetAccountIdentifierTransactionsResponse, BlockchainApiError> {
+    let (result,): (
+        Result<GetAccountIdentifierTransactionsResponse, GetAccountIdentifierTransactionsError>,
+    ) = ic_cdk::call(index, "get_account_identifier_transactions", (args,))
+        .await
+        .map_err(network_error)?;
+
+    result.map_err(|err| index_error(err.message))
+}
+
+/// Calls `get_account_transactions` on the ICRC index and returns the transactions of the account,
+/// the most recent first and starting at the `start` transaction id.
+pub async fn icrc_index_account_transactions(
+    index: Principal,
+    args: GetAccountTransactionsArgs,
+) -> Result<IcrcIndexTransactions, BlockchainApiError> {
+    let (result,): (Result<IcrcIndexTransactions, IcrcIndexTransactionsError>,) =
+        ic_cdk::call(index, "get_account_transactions", (args,))
+            .await
+            .map_err(network_error)?;
+
+    result.map_err(|err| index_error(err.message))
+}
+
+/// A page of the transactions of a station account, as reported by the index canister of its ledger.
+#[derive(Clone, Debug)]
+pub struct LedgerIndexPage {
+    /// The transactions of the page, the most recent first.
+    pub transactions: Vec<AccountTransaction>,
+    /// The id of the oldest transaction of the account known to the index canister.
+    pub oldest_tx_id: Option<u64>,
+}
+
+/// The account of a station account on its ledger, as expected by the index canister.
+#[derive(Clone, Debug)]
+enum LedgerIndexAccount {
+    AccountIdentifier(String),
+    Icrc(IcrcAccount),
+}
+
+/// The index canister of the ledger of a station account.
+///
+/// The ICP accounts use the ICP index unless another index canister is set in the account metadata,
+/// the ICRC-1 accounts use the index canister of the ckBTC ledger, of the discovered SNS ledger or the one
+/// set in the account metadata.
+#[derive(Clone, Debug)]
+pub struct LedgerIndex {
+    index_canister_id: Principal,
+    account: LedgerIndexAccount,
+}
+
+impl LedgerIndex {
+    pub const ICP_INDEX_CANISTER_ID: &'static str = "qhbym-qaaaa-aaaaa-aaafq-cai";
+    pub const CKBTC_INDEX_CANISTER_ID: &'static str = "n5wcd-faaaa-aaaar-qaaea-cai";
+    pub const CKTESTBTC_INDEX_CANISTER_ID: &'static str = "mm444-5iaaa-aaaar-qaabq-cai";
+
+    /// Returns the index canister of the ckBTC ledger of the network, if the ledger is the ckBTC ledger.
+    fn ckbtc_index_canister_id(ledger: &Principal, network: &NetworkProfile) -> Option<Principal> {
+        let (ckbtc_ledger, _) = CkBtc::ckbtc_canisters(network)?;
+        if ckbtc_ledger != *ledger {
+            return None;
+        }
+
+        match network {
+            NetworkProfile::Testnet => Principal::from_text(Self::CKTESTBTC_INDEX_CANISTER_ID).ok(),
+            _ => Principal::from_text(Self::CKBTC_INDEX_CANISTER_ID).ok(),
+        }
+    }
+
+    /// Returns the index canister of the station account, failing if its ledger has no known index canister.
+    pub fn of_account(station_account: &Account) -> Result<Self, BlockchainApiError> {
+        let configured_index = station_account
+            .metadata
+            .get(ASSET_METADATA_INDEX_CANISTER_ID_KEY)
+            .and_then(|index| Principal::from_text(index).ok());
+
+        match (&station_account.blockchain, &station_account.standard) {
+            (Blockchain::InternetComputer, BlockchainStandard::Native) => Ok(Self {
+                index_canister_id: configured_index
+                    .unwrap_or_else(|| Principal::from_text(Self::ICP_INDEX_CANISTER_ID).unwrap()),
+                account: LedgerIndexAccount::AccountIdentifier(
+                    InternetComputer::create(station_account.network)
+                        .station_account_address(&station_account.id),
+                ),
+            }),
+            (Blockchain::InternetComputer, BlockchainStandard::ICRC1) => {
+                let ledger_canister_id = station_account
+                    .metadata
+                    .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+                    .unwrap_or_default();
+                let ledger = Principal::from_text(&ledger_canister_id).map_err(|_| {
+                    BlockchainApiError::UnsupportedLedger {
+                        ledger_canister_id: ledger_canister_id.clone(),
+                    }
+                })?;
+
+                let index_canister_id = configured_index
+                    .or_else(|| Self::ckbtc_index_canister_id(&ledger, &station_account.network))
+                    .or_else(|| {
+                        ASSET_SERVICE
+                            .find_registered_asset_by_ledger(&ledger)
+                            .and_then(|asset| asset.index_canister_id)
+                    })
+                    .or_else(|| {
+                        ASSET_SERVICE
+                            .find_sns_token_by_ledger(&ledger)
+                            .and_then(|sns| sns.index_canister_id)
+                    })
+                    .ok_or(BlockchainApiError::UnsupportedLedger { ledger_canister_id })?;
+
+                Ok(Self {
+                    index_canister_id,
+                    account: LedgerIndexAccount::Icrc(
+                        CkBtc::create(station_account.network)
+                            .station_account_to_icrc_account(&station_account.id),
+                    ),
+                })
+            }
+            _ => Err(BlockchainApiError::TransactionLookupFailed {
+                info: format!(
+                    "The transaction history of {} {} accounts can not be imported",
+                    station_account.blockchain, station_account.standard
+                ),
+            }),
+        }
+    }
+
+    /// Returns the transactions of the station account, the most recent first and starting at the
+    /// `start` transaction id.
+    pub async fn account_transactions(
+        &self,
+        station_account: &Account,
+        start: Option<u64>,
+        max_results: u64,
+    ) -> Result<LedgerIndexPage, BlockchainApiError> {
+        match &self.account {
+            LedgerIndexAccount::AccountIdentifier(account_identifier) => {
+                let response = icp_index_account_transactions(
+                    self.index_canister_id,
+                    GetAccountIdentifierTransactionsArgs {
+                        max_results,
+                        start,
+                        account_identifier: account_identifier.clone(),
+                    },
+                )
+                .await?;
+
+                Ok(LedgerIndexPage {
+                    transactions: response
+                        .transactions
+                        .into_iter()
+                        .filter_map(|transaction| {
+                            icp_account_transaction(
+                                station_account,
+                                account_identifier,
+                                transaction,
+                            )
+                        })
+                        .collect(),
+                    oldest_tx_id: response.oldest_tx_id,
+                })
+            }
+            LedgerIndexAccount::Icrc(account) => {
+                let response = icrc_index_account_transactions(
+                    self.index_canister_id,
+                    GetAccountTransactionsArgs {
+                        account: account.into(),
+                        start: start.map(Nat::from),
+                        max_results: Nat::from(max_results),
+                    },
+                )
+                .await?;
+
+                let mut transactions = Vec::with_capacity(response.transactions.len());
+                for transaction in response.transactions {
+                    if let Some(transaction) =
+                        icrc_account_transaction(station_account, account, transaction)?
+                    {
+                        transactions.push(transaction);
+                    }
+                }
+
+                Ok(LedgerIndexPage {
+                    transactions,
+                    oldest_tx_id: response
+                        .oldest_tx_id
+                        .map(HelperMapper::nat_to_u64)
+                        .transpose()?,
+                })
+            }
+        }
+    }
+}
+
+/// The direction of a transfer relative to the station account, transfers to itself are outbound.
+fn transfer_direction(from_station_account: bool) -> AccountTransactionDirection {
+    if from_station_account {
+        AccountTransactionDirection::Outbound
+    } else {
+        AccountTransactionDirection::Inbound
+    }
+}
+
+/// Maps the transaction of the ICP index, approvals are skipped since they do not move funds.
+fn icp_account_transaction(
+    station_account: &Account,
+    account_identifier: &str,
+    transaction: IcpIndexTransactionWithId,
+) -> Option<AccountTransaction> {
+    let IcpIndexTransactionWithId { id, transaction } = transaction;
+
+    let (kind, direction, counterparty, amount, fee) = match transaction.operation {
+        IcpIndexOperation::Approve { .. } => return None,
+        IcpIndexOperation::Mint { amount, .. } => (
+            AccountTransactionKind::Mint,
+            AccountTransactionDirection::Inbound,
+            None,
+            amount,
+            None,
+        ),
+        IcpIndexOperation::Burn { amount, .. } => (
+            AccountTransactionKind::Burn,
+            AccountTransactionDirection::Outbound,
+            None,
+            amount,
+            None,
+        ),
+        IcpIndexOperation::Transfer {
+            to,
+            fee,
+            from,
+            amount,
+            ..
+        }
+        | IcpIndexOperation::TransferFrom {
+            to,
+            fee,
+            from,
+            amount,
+            ..
+        } => {
+            let outbound = from == account_identifier;
+
+            (
+                AccountTransactionKind::Transfer,
+                transfer_direction(outbound),
+                Some(if outbound { to } else { from }),
+                amount,
+                Some(fee),
+            )
+        }
+    };
+
+    let memo = match transaction.icrc1_memo {
+        Some(memo) => Some(memo),
+        None if transaction.memo != 0 => Some(transaction.memo.to_be_bytes().to_vec()),
+        None => None,
+    };
+
+    Some(AccountTransaction {
+        account_id: station_account.id,
+        block_index: id,
+        kind,
+        direction,
+        counterparty,
+        amount: Nat::from(amount.e8s),
+        fee: fee.map(|fee| Nat::from(fee.e8s)),
+        memo,
+        timestamp: transaction
+            .timestamp
+            .or(transaction.created_at_time)
+            .map(|timestamp| timestamp.timestamp_nanos),
+        transfer_id: None,
+    })
+}
+
+/// Maps the transaction of the ICRC index, approvals are skipped since they do not move funds.
+fn icrc_account_transaction(
+    station_account: &Account,
+    account: &IcrcAccount,
+    transaction: IcrcIndexTransactionWithId,
+) -> Result<Option<AccountTransaction>, BlockchainApiError> {
+    let IcrcIndexTransactionWithId { id, transaction } = transaction;
+
+    let (kind, direction, counterparty, amount, fee, memo) =
+        match (transaction.transfer, transaction.mint, transaction.burn) {
+            (Some(transfer), _, _) => {
+                let outbound = icrc_account_eq(&transfer.from, account);
+
+                (
+                    AccountTransactionKind::Transfer,
+                    transfer_direction(outbound),
+                    Some(icrc_account_to_text(if outbound {
+                        &transfer.to
+                    } else {
+                        &transfer.from
+                    })),
+                    transfer.amount,
+                    transfer.fee,
+                    transfer.memo,
+                )
+            }
+            (None, Some(mint), _) => (
+                AccountTransactionKind::Mint,
+                AccountTransactionDirection::Inbound,
+                None,
+                mint.amount,
+                None,
+                mint.memo,
+            ),
+            (None, None, Some(burn)) => (
+                AccountTransactionKind::Burn,
+                AccountTransactionDirection::Outbound,
+                None,
+                burn.amount,
+                None,
+                burn.memo,
+            ),
+            (None, None, None) => return Ok(None),
+        };
+
+    Ok(Some(AccountTransaction {
+        account_id: station_account.id,
+        block_index: HelperMapper::nat_to_u64(id)?,
+        kind,
+        direction,
+        counterparty,
+        amount,
+        fee,
+        memo,
+        timestamp: Some(transaction.timestamp),
+        transfer_id: None,
+    }))
+}
+
+fn icrc_account_eq(ledger_account: &Icrc1Account, account: &IcrcAccount) -> bool {
+    let subaccount = ledger_account
+        .subaccount
+        .as_deref()
+        .filter(|subaccount| *subaccount != IcrcAccount::DEFAULT_SUBACCOUNT);
+
+    ledger_account.owner == account.owner
+        && subaccount
+            == account
+                .effective_subaccount()
+                .as_ref()
+                .map(|s| s.as_slice())
+}
+
+fn icrc_account_to_text(ledger_account: &Icrc1Account) -> String {
+    match &ledger_account.subaccount {
+        Some(subaccount) => match <[u8; 32]>::try_from(subaccount.as_slice()) {
+            Ok(subaccount) => IcrcAccount::new(ledger_account.owner, Some(subaccount)).to_string(),
+            Err(_) => format!("{}.{}", ledger_account.owner, hex::encode(subaccount)),
+        },
+        None => IcrcAccount::new(ledger_account.owner, None).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_test_utils::mock_account;
+
+    #[test]
+    fn icp_transfers_are_mapped_relative_to_the_account() {
+        let account = mock_account();
+        let transfer = |from: &str, to: &str| IcpIndexTransactionWithId {
+            id: 42,
+            transaction: IcpIndexTransaction {
+                memo: 7,
+                icrc1_memo: None,
+                operation: IcpIndexOperation::Transfer {
+                    to: to.to_string(),
+                    fee: IcpIndexTokens { e8s: 10_000 },
+                    from: from.to_string(),
+                    amount: IcpIndexTokens { e8s: 100 },
+                    spender: None,
+                },
+                created_at_time: None,
+                timestamp: Some(IcpIndexTimeStamp { timestamp_nanos: 1 }),
+            },
+        };
+
+        let deposit =
+            icp_account_transaction(&account, "station", transfer("payer", "station")).unwrap();
+
+        assert_eq!(deposit.block_index, 42);
+        assert_eq!(deposit.direction, AccountTransactionDirection::Inbound);
+        assert_eq!(deposit.counterparty, Some("payer".to_string()));
+        assert_eq!(deposit.memo, Some(7u64.to_be_bytes().to_vec()));
+        assert_eq!(deposit.timestamp, Some(1));
+
+        let withdrawal =
+            icp_account_transaction(&account, "station", transfer("station", "payee")).unwrap();
+
+        assert_eq!(withdrawal.direction, AccountTransactionDirection::Outbound);
+        assert_eq!(withdrawal.counterparty, Some("payee".to_string()));
+        assert_eq!(withdrawal.fee, Some(Nat::from(10_000u64)));
+    }
+}
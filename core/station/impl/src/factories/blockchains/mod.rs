@@ -1,5 +1,32 @@
+mod bitcoin;
+pub use bitcoin::*;
+
+mod ckbtc;
+pub use ckbtc::*;
+
 mod core;
 pub use core::*;
 
+mod cycles_ledger;
+pub use cycles_ledger::*;
+
+mod ethereum;
+pub use ethereum::*;
+
+mod icrc1;
+pub use icrc1::*;
+
+mod icrc2;
+pub use icrc2::*;
+
+mod icrc7;
+pub use icrc7::*;
+
 mod internet_computer;
 pub use internet_computer::*;
+
+mod ledger_index;
+pub use ledger_index::*;
+
+mod rpc;
+pub use rpc::*;
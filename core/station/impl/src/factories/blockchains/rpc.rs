@@ -0,0 +1,512 @@
+use crate::{
+    core::read_system_info,
+    errors::RpcError,
+    models::{Blockchain, RpcProvider, RpcProvidersConfig},
+};
+use async_trait::async_trait;
+use candid::{types::value::IDLValue, CandidType, Deserialize, Principal};
+use futures::future;
+use ic_cdk::api::management_canister::http_request::{
+    http_request, CanisterHttpRequestArgument, HttpHeader, HttpMethod, HttpResponse, TransformArgs,
+    TransformContext,
+};
+use serde_json::{json, Value};
+use std::{cell::RefCell, collections::HashMap};
+
+thread_local! {
+    /// The health of the RPC providers, tracked by url and reset on upgrades.
+    static RPC_PROVIDERS_HEALTH: RefCell<HashMap<String, RpcProviderHealth>> = RefCell::new(HashMap::new());
+}
+
+/// The health of a RPC provider, used to rank the providers when failing over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RpcProviderHealth {
+    /// The health score, from `0` (always failing) to `MAX_SCORE` (healthy).
+    pub score: u8,
+    /// The number of consecutive failed calls.
+    pub consecutive_failures: u32,
+}
+
+impl Default for RpcProviderHealth {
+    fn default() -> Self {
+        Self {
+            score: Self::MAX_SCORE,
+            consecutive_failures: 0,
+        }
+    }
+}
+
+impl RpcProviderHealth {
+    pub const MAX_SCORE: u8 = 100;
+    const SUCCESS_RECOVERY: u8 = 10;
+
+    fn record_success(&mut self) {
+        self.score = self
+            .score
+            .saturating_add(Self::SUCCESS_RECOVERY)
+            .min(Self::MAX_SCORE);
+        self.consecutive_failures = 0;
+    }
+
+    fn record_failure(&mut self) {
+        self.score /= 2;
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+    }
+}
+
+/// Returns the current health of the provider with the given url.
+pub fn rpc_provider_health(url: &str) -> RpcProviderHealth {
+    RPC_PROVIDERS_HEALTH.with(|health| health.borrow().get(url).cloned().unwrap_or_default())
+}
+
+fn record_rpc_provider_outcome(url: &str, is_success: bool) {
+    RPC_PROVIDERS_HEALTH.with(|health| {
+        let mut health = health.borrow_mut();
+        let provider_health = health.entry(url.to_string()).or_default();
+
+        match is_success {
+            true => provider_health.record_success(),
+            false => provider_health.record_failure(),
+        }
+    });
+}
+
+/// The JSON-RPC method used to check the health of the providers of the blockchain.
+fn health_check_method(blockchain: &Blockchain) -> Option<&'static str> {
+    match blockchain {
+        Blockchain::Ethereum => Some("eth_blockNumber"),
+        Blockchain::Bitcoin => Some("getblockcount"),
+        Blockchain::InternetComputer => None,
+    }
+}
+
+/// The transport used to send the JSON-RPC requests to the providers.
+#[async_trait]
+pub trait RpcTransport: Send + Sync {
+    /// Posts the JSON body to the given url and returns the response body.
+    async fn post(&self, url: &str, body: Vec<u8>) -> Result<Vec<u8>, String>;
+}
+
+/// Sends the JSON-RPC requests through HTTPS outcalls.
+#[derive(Debug, Default)]
+pub struct HttpOutcallTransport {}
+
+impl HttpOutcallTransport {
+    /// The name of the query method that strips the non deterministic parts of the responses.
+    pub const TRANSFORM_METHOD_NAME: &'static str = "transform_rpc_response";
+    pub const MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+    pub const CYCLES_PER_OUTCALL: u128 = 2_000_000_000;
+
+    /// Keeps only the status and body of the response, so all the replicas reach consensus.
+    pub fn transform(args: TransformArgs) -> HttpResponse {
+        HttpResponse {
+            status: args.response.status,
+            headers: Vec::new(),
+            body: args.response.body,
+        }
+    }
+}
+
+#[async_trait]
+impl RpcTransport for HttpOutcallTransport {
+    async fn post(&self, url: &str, body: Vec<u8>) -> Result<Vec<u8>, String> {
+        let request = CanisterHttpRequestArgument {
+            url: url.to_string(),
+            method: HttpMethod::POST,
+            body: Some(body),
+            max_response_bytes: Some(Self::MAX_RESPONSE_BYTES),
+            transform: Some(TransformContext::from_name(
+                Self::TRANSFORM_METHOD_NAME.to_string(),
+                Vec::new(),
+            )),
+            headers: vec![HttpHeader {
+                name: "Content-Type".to_string(),
+                value: "application/json".to_string(),
+            }],
+        };
+
+        let (response,) = http_request(request, Self::CYCLES_PER_OUTCALL)
+            .await
+            .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))?;
+
+        if response.status < 200_u16 || response.status >= 300_u16 {
+            return Err(format!("unexpected http status {}", response.status));
+        }
+
+        Ok(response.body)
+    }
+}
+
+/// Sends the JSON-RPC requests through the EVM RPC canister, which performs the HTTPS outcalls.
+#[derive(Debug, Default)]
+pub struct EvmRpcCanisterTransport {}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct EvmRpcHttpHeader {
+    name: String,
+    value: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct EvmRpcApi {
+    url: String,
+    headers: Option<Vec<EvmRpcHttpHeader>>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum EvmRpcService {
+    Custom(EvmRpcApi),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum EvmRpcRequestResult {
+    Ok(String),
+    Err(IDLValue),
+}
+
+impl EvmRpcCanisterTransport {
+    pub const EVM_RPC_CANISTER_ID: &'static str = "7hfb6-caaaa-aaaar-qadga-cai";
+    pub const MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+    /// The cycles attached to each request, the unused cycles are refunded by the canister.
+    pub const CYCLES_PER_REQUEST: u128 = 10_000_000_000;
+
+    pub fn evm_rpc_canister_id() -> Principal {
+        Principal::from_text(Self::EVM_RPC_CANISTER_ID).unwrap()
+    }
+}
+
+#[async_trait]
+impl RpcTransport for EvmRpcCanisterTransport {
+    async fn post(&self, url: &str, body: Vec<u8>) -> Result<Vec<u8>, String> {
+        let body = String::from_utf8(body).map_err(|err| err.to_string())?;
+        let service = EvmRpcService::Custom(EvmRpcApi {
+            url: url.to_string(),
+            headers: None,
+        });
+
+        let (result,): (EvmRpcRequestResult,) = ic_cdk::api::call::call_with_payment128(
+            Self::evm_rpc_canister_id(),
+            "request",
+            (service, body, Self::MAX_RESPONSE_BYTES),
+            Self::CYCLES_PER_REQUEST,
+        )
+        .await
+        .map_err(|(code, message)| format!("rejection_code: {:?}, err: {}", code, message))?;
+
+        match result {
+            EvmRpcRequestResult::Ok(response) => Ok(response.into_bytes()),
+            EvmRpcRequestResult::Err(error) => Err(format!("evm rpc error: {}", error)),
+        }
+    }
+}
+
+/// A JSON-RPC client that fails over between the configured providers based on their health.
+///
+/// Reads are only accepted when `read_quorum` providers return the same result.
+pub struct RpcClient {
+    config: RpcProvidersConfig,
+    transport: Box<dyn RpcTransport>,
+}
+
+impl RpcClient {
+    pub fn new(config: RpcProvidersConfig, transport: Box<dyn RpcTransport>) -> Self {
+        Self { config, transport }
+    }
+
+    fn configured_providers(blockchain: &Blockchain) -> Result<RpcProvidersConfig, RpcError> {
+        read_system_info()
+            .get_rpc_providers(blockchain)
+            .cloned()
+            .ok_or(RpcError::NotConfigured {
+                blockchain: blockchain.to_string(),
+            })
+    }
+
+    /// Creates the client for the providers configured for the blockchain through `ManageSystemInfo`.
+    pub fn for_blockchain(blockchain: &Blockchain) -> Result<Self, RpcError> {
+        Ok(Self::new(
+            Self::configured_providers(blockchain)?,
+            Box::new(HttpOutcallTransport::default()),
+        ))
+    }
+
+    /// Creates the client for the configured providers of the blockchain, reached through the EVM RPC canister.
+    pub fn via_evm_rpc_canister(blockchain: &Blockchain) -> Result<Self, RpcError> {
+        Ok(Self::new(
+            Self::configured_providers(blockchain)?,
+            Box::new(EvmRpcCanisterTransport::default()),
+        ))
+    }
+
+    /// Returns the providers sorted by health, keeping the configured order for equal scores.
+    pub fn ranked_providers(&self) -> Vec<RpcProvider> {
+        let mut providers = self.config.providers.clone();
+        providers
+            .sort_by_key(|provider| std::cmp::Reverse(rpc_provider_health(&provider.url).score));
+
+        providers
+    }
+
+    /// Sends a cheap request to every provider to refresh their health, so that the providers skipped
+    /// while failing over can recover their score before the executions need them.
+    pub async fn check_health(&self) {
+        let Some(method) = health_check_method(&self.config.blockchain) else {
+            return;
+        };
+
+        future::join_all(
+            self.config
+                .providers
+                .iter()
+                .map(|provider| self.send(provider, method, &json!([]))),
+        )
+        .await;
+    }
+
+    /// Sends the request to the healthiest provider, failing over to the next one when unreachable.
+    ///
+    /// Used for writes (e.g. submitting a signed transaction) that must not wait for a quorum.
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let mut failures = Vec::new();
+
+        for provider in self.ranked_providers() {
+            match self.send(&provider, method, &params).await {
+                Ok(result) => return result,
+                Err(reason) => failures.push(format!("{}: {}", provider.name, reason)),
+            }
+        }
+
+        Err(RpcError::AllProvidersFailed {
+            info: failures.join("; "),
+        })
+    }
+
+    /// Queries the providers by health until `read_quorum` of them return the same result.
+    pub async fn read(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let quorum = self.config.read_quorum.max(1) as usize;
+        let mut providers = self.ranked_providers().into_iter();
+        let mut results: Vec<(Value, usize)> = Vec::new();
+        let mut failures = Vec::new();
+
+        loop {
+            let best_agreement = results.iter().map(|(_, count)| *count).max().unwrap_or(0);
+            if best_agreement >= quorum {
+                break;
+            }
+
+            let batch: Vec<RpcProvider> =
+                providers.by_ref().take(quorum - best_agreement).collect();
+            if batch.is_empty() {
+                break;
+            }
+
+            let responses = future::join_all(
+                batch
+                    .iter()
+                    .map(|provider| self.send(provider, method, &params)),
+            )
+            .await;
+
+            for (provider, response) in batch.iter().zip(responses) {
+                match response {
+                    Ok(Ok(result)) => {
+                        match results.iter_mut().find(|(value, _)| *value == result) {
+                            Some((_, count)) => *count += 1,
+                            None => results.push((result, 1)),
+                        }
+                    }
+                    Ok(Err(error)) => failures.push(error.to_string()),
+                    Err(reason) => failures.push(format!("{}: {}", provider.name, reason)),
+                }
+            }
+        }
+
+        match results.into_iter().max_by_key(|(_, count)| *count) {
+            Some((result, count)) if count >= quorum => Ok(result),
+            Some(_) => Err(RpcError::InconsistentResponses {
+                quorum: self.config.read_quorum,
+            }),
+            None => Err(RpcError::AllProvidersFailed {
+                info: failures.join("; "),
+            }),
+        }
+    }
+
+    /// Sends the request to the provider and records its health.
+    ///
+    /// The outer error means the provider could not be reached or replied with a malformed response,
+    /// while the inner result contains the JSON-RPC result or error returned by the provider.
+    async fn send(
+        &self,
+        provider: &RpcProvider,
+        method: &str,
+        params: &Value,
+    ) -> Result<Result<Value, RpcError>, String> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response = self
+            .transport
+            .post(&provider.url, body.to_string().into_bytes())
+            .await
+            .and_then(|bytes| {
+                serde_json::from_slice::<Value>(&bytes)
+                    .map_err(|err| format!("malformed response: {}", err))
+            });
+
+        let response = match response {
+            Ok(response) => response,
+            Err(reason) => {
+                record_rpc_provider_outcome(&provider.url, false);
+
+                return Err(reason);
+            }
+        };
+
+        record_rpc_provider_outcome(&provider.url, true);
+
+        if let Some(error) = response.get("error") {
+            return Ok(Err(RpcError::ProviderError {
+                provider: provider.name.clone(),
+                info: error.to_string(),
+            }));
+        }
+
+        Ok(Ok(response.get("result").cloned().unwrap_or(Value::Null)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::rpc_provider_test_utils::mock_rpc_providers_config;
+
+    /// Replies with the configured body for each url, or fails when the url is not configured.
+    struct MockTransport {
+        responses: HashMap<String, String>,
+    }
+
+    #[async_trait]
+    impl RpcTransport for MockTransport {
+        async fn post(&self, url: &str, _body: Vec<u8>) -> Result<Vec<u8>, String> {
+            self.responses
+                .get(url)
+                .map(|body| body.as_bytes().to_vec())
+                .ok_or_else(|| "connection refused".to_string())
+        }
+    }
+
+    fn client(responses: Vec<(&str, &str)>) -> RpcClient {
+        RpcClient::new(
+            mock_rpc_providers_config(),
+            Box::new(MockTransport {
+                responses: responses
+                    .into_iter()
+                    .map(|(url, body)| (url.to_string(), body.to_string()))
+                    .collect(),
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn call_fails_over_to_the_next_provider() {
+        let client = client(vec![(
+            "https://secondary.example.com",
+            r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+        )]);
+
+        let result = client.call("eth_blockNumber", json!([])).await.unwrap();
+
+        assert_eq!(result, json!("0x1"));
+        assert_eq!(
+            rpc_provider_health("https://primary.example.com").consecutive_failures,
+            1
+        );
+        assert_eq!(
+            client.ranked_providers()[0].url,
+            "https://secondary.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn read_requires_quorum_of_matching_results() {
+        let client = client(vec![
+            (
+                "https://primary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+            ),
+            (
+                "https://secondary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x2"}"#,
+            ),
+            (
+                "https://tertiary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+            ),
+        ]);
+
+        let result = client.read("eth_getBalance", json!([])).await.unwrap();
+
+        assert_eq!(result, json!("0x1"));
+    }
+
+    #[tokio::test]
+    async fn read_fails_with_inconsistent_results() {
+        let client = client(vec![
+            (
+                "https://primary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+            ),
+            (
+                "https://secondary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x2"}"#,
+            ),
+        ]);
+
+        let result = client.read("eth_getBalance", json!([])).await;
+
+        assert_eq!(result, Err(RpcError::InconsistentResponses { quorum: 2 }));
+    }
+
+    #[tokio::test]
+    async fn health_check_scores_every_provider() {
+        let client = client(vec![
+            (
+                "https://primary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+            ),
+            (
+                "https://tertiary.example.com",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#,
+            ),
+        ]);
+
+        client.check_health().await;
+
+        assert_eq!(
+            rpc_provider_health("https://primary.example.com"),
+            RpcProviderHealth::default()
+        );
+        assert_eq!(
+            rpc_provider_health("https://secondary.example.com").consecutive_failures,
+            1
+        );
+        assert_eq!(
+            client.ranked_providers().last().unwrap().url,
+            "https://secondary.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn call_fails_when_all_providers_are_unreachable() {
+        let client = client(vec![]);
+
+        let result = client.call("eth_blockNumber", json!([])).await;
+
+        assert!(matches!(result, Err(RpcError::AllProvidersFailed { .. })));
+    }
+}
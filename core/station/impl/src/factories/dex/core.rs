@@ -0,0 +1,105 @@
+use super::{ICPSwap, KongSwap, Sonic};
+use crate::{
+    core::ic_cdk::next_time,
+    errors::DexError,
+    factories::blockchains::{icrc1_transfer, Icrc1Account, Icrc1TransferArgs},
+    models::{Dex, IcrcAccount},
+};
+use async_trait::async_trait;
+use candid::{Nat, Principal};
+
+/// One side of a swap, resolved from a station account.
+#[derive(Clone, Debug)]
+pub struct DexSwapAsset {
+    pub ledger_canister_id: Principal,
+    /// The ledger account of the station account.
+    pub account: IcrcAccount,
+    /// The fee of a transfer on the ledger.
+    pub fee: Nat,
+}
+
+#[derive(Clone, Debug)]
+pub struct DexSwapArgs {
+    /// The asset that is sold.
+    pub from: DexSwapAsset,
+    /// The asset that is bought.
+    pub to: DexSwapAsset,
+    pub amount_in: Nat,
+    pub expected_amount_out: Nat,
+    pub max_slippage_bps: u16,
+    /// The minimum amount of the bought asset, below which the DEX should reject the swap.
+    pub min_amount_out: Nat,
+}
+
+/// The trade of an executed swap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DexSwapReceipt {
+    pub amount_in: Nat,
+    /// The amount of the bought asset received by the station account.
+    pub amount_out: Nat,
+}
+
+#[async_trait]
+pub trait DexAdapter: Send + Sync {
+    /// Returns the amount of the bought asset the DEX would currently give for the swap.
+    async fn quote(&self, args: &DexSwapArgs) -> Result<Nat, DexError>;
+
+    /// Swaps the sold asset of the `from` account and sends the bought asset to the `to` account.
+    async fn swap(&self, args: &DexSwapArgs) -> Result<DexSwapReceipt, DexError>;
+}
+
+pub struct DexAdapterFactory {}
+
+impl DexAdapterFactory {
+    pub fn build(
+        dex: &Dex,
+        pool_canister_id: Option<Principal>,
+    ) -> Result<Box<dyn DexAdapter>, DexError> {
+        match dex {
+            Dex::ICPSwap => {
+                let pool_canister_id = pool_canister_id.ok_or(DexError::PoolRequired {
+                    dex: dex.to_string(),
+                })?;
+
+                Ok(Box::new(ICPSwap::new(pool_canister_id)))
+            }
+            Dex::Sonic => Ok(Box::new(Sonic::new())),
+            Dex::KongSwap => Ok(Box::new(KongSwap::new())),
+        }
+    }
+}
+
+/// Transfers the sold asset from the station account to an account of the DEX.
+pub(super) async fn transfer_to_dex(
+    dex: &Dex,
+    from: &DexSwapAsset,
+    to: Icrc1Account,
+    amount: Nat,
+) -> Result<Nat, DexError> {
+    icrc1_transfer(
+        from.ledger_canister_id,
+        Icrc1TransferArgs {
+            from_subaccount: from
+                .account
+                .effective_subaccount()
+                .map(|subaccount| subaccount.to_vec()),
+            to,
+            amount,
+            fee: Some(from.fee.clone()),
+            memo: None,
+            created_at_time: Some(next_time()),
+        },
+    )
+    .await
+    .map_err(|e| DexError::SwapFailed {
+        dex: dex.to_string(),
+        info: format!("Failed to transfer the tokens to the DEX: {}", e),
+    })
+}
+
+pub(super) fn call_error(dex: &Dex, err: (ic_cdk::api::call::RejectionCode, String)) -> String {
+    format!(
+        "{} rejected the call, rejection_code: {:?}, err: {}",
+        dex, err.0, err.1
+    )
+}
@@ -0,0 +1,224 @@
+//! Candid types and calls of ICPSwap, where each pair is served by its own pool canister.
+
+use super::{call_error, transfer_to_dex, DexAdapter, DexSwapArgs, DexSwapReceipt};
+use crate::{
+    core::ic_cdk::api::id as station_canister_self_id, errors::DexError,
+    factories::blockchains::Icrc1Account, models::Dex,
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+/// The sold tokens are transferred to the deposit subaccount of the station in the pool, deposited,
+/// swapped and the bought tokens are withdrawn to the subaccount of the receiving account.
+pub struct ICPSwap {
+    pool_canister_id: Principal,
+}
+
+impl ICPSwap {
+    pub fn new(pool_canister_id: Principal) -> Self {
+        Self { pool_canister_id }
+    }
+
+    /// The subaccount of the pool where the caller deposits its tokens, derived from its principal.
+    fn deposit_subaccount(principal: &Principal) -> Vec<u8> {
+        let bytes = principal.as_slice();
+        let mut subaccount = vec![0; 32];
+        subaccount[0] = bytes.len() as u8;
+        subaccount[1..1 + bytes.len()].copy_from_slice(bytes);
+
+        subaccount
+    }
+
+    fn pool_error(err: ICPSwapError) -> String {
+        match err {
+            ICPSwapError::CommonError => "Common error".to_string(),
+            ICPSwapError::InternalError(info) => format!("Internal error: {}", info),
+            ICPSwapError::UnsupportedToken(token) => format!("Unsupported token: {}", token),
+            ICPSwapError::InsufficientFunds => "Insufficient funds".to_string(),
+        }
+    }
+
+    async fn call<A, T>(&self, method: &str, args: A) -> Result<Result<T, String>, String>
+    where
+        A: candid::utils::ArgumentEncoder + Send,
+        T: CandidType + for<'de> Deserialize<'de> + Send,
+    {
+        let (result,): (ICPSwapResult<T>,) = ic_cdk::call(self.pool_canister_id, method, args)
+            .await
+            .map_err(|err| call_error(&Dex::ICPSwap, err))?;
+
+        Ok(match result {
+            ICPSwapResult::ok(value) => Ok(value),
+            ICPSwapResult::err(err) => Err(format!("{}: {}", method, Self::pool_error(err))),
+        })
+    }
+
+    /// Returns whether the sold token is the `token0` of the pool.
+    async fn zero_for_one(&self, args: &DexSwapArgs) -> Result<bool, String> {
+        let metadata = self
+            .call::<_, ICPSwapPoolMetadata>("metadata", ())
+            .await??;
+        let from = args.from.ledger_canister_id.to_text();
+        let to = args.to.ledger_canister_id.to_text();
+
+        if metadata.token0.address == from && metadata.token1.address == to {
+            Ok(true)
+        } else if metadata.token1.address == from && metadata.token0.address == to {
+            Ok(false)
+        } else {
+            Err("The pool does not serve the pair of the accounts".to_string())
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum ICPSwapResult<T> {
+    ok(T),
+    err(ICPSwapError),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum ICPSwapError {
+    CommonError,
+    InternalError(String),
+    UnsupportedToken(String),
+    InsufficientFunds,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct ICPSwapToken {
+    address: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct ICPSwapPoolMetadata {
+    token0: ICPSwapToken,
+    token1: ICPSwapToken,
+}
+
+#[allow(non_snake_case)]
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct ICPSwapSwapArgs {
+    amountIn: String,
+    zeroForOne: bool,
+    amountOutMinimum: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct ICPSwapDepositArgs {
+    token: String,
+    amount: Nat,
+    fee: Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct ICPSwapWithdrawToSubaccountArgs {
+    token: String,
+    amount: Nat,
+    fee: Nat,
+    subaccount: Vec<u8>,
+}
+
+#[async_trait]
+impl DexAdapter for ICPSwap {
+    async fn quote(&self, args: &DexSwapArgs) -> Result<Nat, DexError> {
+        let quote_failed = |info| DexError::QuoteFailed {
+            dex: Dex::ICPSwap.to_string(),
+            info,
+        };
+        let zero_for_one = self.zero_for_one(args).await.map_err(quote_failed)?;
+
+        // the deposit and the withdrawal are each charged the fee of their ledger
+        let amount_in = args.amount_in.clone() - args.from.fee.clone();
+        let amount_out: Nat = self
+            .call(
+                "quote",
+                (ICPSwapSwapArgs {
+                    amountIn: amount_in.0.to_string(),
+                    zeroForOne: zero_for_one,
+                    amountOutMinimum: "0".to_string(),
+                },),
+            )
+            .await
+            .and_then(|result| result)
+            .map_err(quote_failed)?;
+
+        if amount_out <= args.to.fee {
+            return Ok(Nat::from(0u64));
+        }
+
+        Ok(amount_out - args.to.fee.clone())
+    }
+
+    async fn swap(&self, args: &DexSwapArgs) -> Result<DexSwapReceipt, DexError> {
+        let swap_failed = |info| DexError::SwapFailed {
+            dex: Dex::ICPSwap.to_string(),
+            info,
+        };
+        let zero_for_one = self.zero_for_one(args).await.map_err(swap_failed)?;
+
+        transfer_to_dex(
+            &Dex::ICPSwap,
+            &args.from,
+            Icrc1Account {
+                owner: self.pool_canister_id,
+                subaccount: Some(Self::deposit_subaccount(&station_canister_self_id())),
+            },
+            args.amount_in.clone(),
+        )
+        .await?;
+
+        let deposited: Nat = self
+            .call(
+                "deposit",
+                (ICPSwapDepositArgs {
+                    token: args.from.ledger_canister_id.to_text(),
+                    amount: args.amount_in.clone(),
+                    fee: args.from.fee.clone(),
+                },),
+            )
+            .await
+            .and_then(|result| result)
+            .map_err(swap_failed)?;
+
+        let min_amount_out = args.min_amount_out.clone() + args.to.fee.clone();
+        let amount_out: Nat = self
+            .call(
+                "swap",
+                (ICPSwapSwapArgs {
+                    amountIn: deposited.0.to_string(),
+                    zeroForOne: zero_for_one,
+                    amountOutMinimum: min_amount_out.0.to_string(),
+                },),
+            )
+            .await
+            .and_then(|result| result)
+            .map_err(swap_failed)?;
+
+        let subaccount = args
+            .to
+            .account
+            .subaccount
+            .map(|subaccount| subaccount.to_vec())
+            .unwrap_or_else(|| vec![0; 32]);
+
+        self.call::<_, Nat>(
+            "withdrawToSubaccount",
+            (ICPSwapWithdrawToSubaccountArgs {
+                token: args.to.ledger_canister_id.to_text(),
+                amount: amount_out.clone(),
+                fee: args.to.fee.clone(),
+                subaccount,
+            },),
+        )
+        .await
+        .and_then(|result| result)
+        .map_err(swap_failed)?;
+
+        Ok(DexSwapReceipt {
+            amount_in: args.amount_in.clone(),
+            amount_out: amount_out - args.to.fee.clone(),
+        })
+    }
+}
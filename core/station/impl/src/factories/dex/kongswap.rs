@@ -0,0 +1,141 @@
+//! Candid types and calls of KongSwap, whose backend canister holds the pools of every pair.
+
+use super::{call_error, transfer_to_dex, DexAdapter, DexSwapArgs, DexSwapReceipt};
+use crate::{errors::DexError, factories::blockchains::Icrc1Account, models::Dex};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Nat, Principal};
+
+/// Swaps are paid with a transfer to the backend canister, which then sends the bought tokens to
+/// the receive address.
+pub struct KongSwap {
+    backend_canister_id: Principal,
+}
+
+impl KongSwap {
+    pub const BACKEND_CANISTER_ID: &'static str = "2ipq2-uqaaa-aaaar-qailq-cai";
+
+    pub fn new() -> Self {
+        Self {
+            backend_canister_id: Principal::from_text(Self::BACKEND_CANISTER_ID).unwrap(),
+        }
+    }
+
+    /// KongSwap identifies the tokens by their chain and ledger (e.g. `IC.ryjl3-tyaaa-aaaaa-aaaba-cai`).
+    fn token(ledger_canister_id: &Principal) -> String {
+        format!("IC.{}", ledger_canister_id)
+    }
+}
+
+impl Default for KongSwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct KongSwapAmountsReply {
+    receive_amount: Nat,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum KongSwapTxId {
+    BlockIndex(Nat),
+    TransactionHash(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct KongSwapArgs {
+    pay_token: String,
+    pay_amount: Nat,
+    pay_tx_id: Option<KongSwapTxId>,
+    receive_token: String,
+    receive_amount: Option<Nat>,
+    receive_address: Option<String>,
+    /// The maximum slippage, in percent.
+    max_slippage: Option<f64>,
+    referred_by: Option<String>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct KongSwapReply {
+    pay_amount: Nat,
+    receive_amount: Nat,
+    status: String,
+}
+
+#[async_trait]
+impl DexAdapter for KongSwap {
+    async fn quote(&self, args: &DexSwapArgs) -> Result<Nat, DexError> {
+        let (result,): (Result<KongSwapAmountsReply, String>,) = ic_cdk::call(
+            self.backend_canister_id,
+            "swap_amounts",
+            (
+                Self::token(&args.from.ledger_canister_id),
+                args.amount_in.clone(),
+                Self::token(&args.to.ledger_canister_id),
+            ),
+        )
+        .await
+        .map_err(|err| DexError::QuoteFailed {
+            dex: Dex::KongSwap.to_string(),
+            info: call_error(&Dex::KongSwap, err),
+        })?;
+
+        result
+            .map(|reply| reply.receive_amount)
+            .map_err(|info| DexError::QuoteFailed {
+                dex: Dex::KongSwap.to_string(),
+                info,
+            })
+    }
+
+    async fn swap(&self, args: &DexSwapArgs) -> Result<DexSwapReceipt, DexError> {
+        let block_index = transfer_to_dex(
+            &Dex::KongSwap,
+            &args.from,
+            Icrc1Account {
+                owner: self.backend_canister_id,
+                subaccount: None,
+            },
+            args.amount_in.clone(),
+        )
+        .await?;
+
+        let (result,): (Result<KongSwapReply, String>,) = ic_cdk::call(
+            self.backend_canister_id,
+            "swap",
+            (KongSwapArgs {
+                pay_token: Self::token(&args.from.ledger_canister_id),
+                pay_amount: args.amount_in.clone(),
+                pay_tx_id: Some(KongSwapTxId::BlockIndex(block_index)),
+                receive_token: Self::token(&args.to.ledger_canister_id),
+                receive_amount: Some(args.expected_amount_out.clone()),
+                receive_address: Some(args.to.account.to_string()),
+                max_slippage: Some(args.max_slippage_bps as f64 / 100.0),
+                referred_by: None,
+            },),
+        )
+        .await
+        .map_err(|err| DexError::SwapFailed {
+            dex: Dex::KongSwap.to_string(),
+            info: call_error(&Dex::KongSwap, err),
+        })?;
+
+        let reply = result.map_err(|info| DexError::SwapFailed {
+            dex: Dex::KongSwap.to_string(),
+            info,
+        })?;
+
+        if reply.status != "Success" {
+            return Err(DexError::SwapFailed {
+                dex: Dex::KongSwap.to_string(),
+                info: format!("The swap ended with status {}", reply.status),
+            });
+        }
+
+        Ok(DexSwapReceipt {
+            amount_in: reply.pay_amount,
+            amount_out: reply.receive_amount,
+        })
+    }
+}
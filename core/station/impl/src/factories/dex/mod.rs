@@ -0,0 +1,11 @@
+mod core;
+pub use core::*;
+
+mod icpswap;
+pub use icpswap::*;
+
+mod kongswap;
+pub use kongswap::*;
+
+mod sonic;
+pub use sonic::*;
@@ -0,0 +1,205 @@
+//! Candid types and calls of Sonic, whose swap canister holds the pools of every pair.
+
+use super::{call_error, transfer_to_dex, DexAdapter, DexSwapArgs, DexSwapReceipt};
+use crate::{
+    core::ic_cdk::{api::id as station_canister_self_id, next_time},
+    errors::DexError,
+    factories::blockchains::{icrc1_transfer, Icrc1Account, Icrc1TransferArgs},
+    models::Dex,
+};
+use async_trait::async_trait;
+use candid::{CandidType, Deserialize, Int, Nat, Principal};
+
+/// The sold tokens are deposited to the balance of the station in the swap canister and the bought
+/// tokens are credited to that balance as well.
+///
+/// Sonic can only withdraw to the default account of the caller, so the bought tokens transit through
+/// the default account of the station before they are transferred to the receiving account, which is
+/// charged the ledger fee twice.
+pub struct Sonic {
+    swap_canister_id: Principal,
+}
+
+impl Sonic {
+    pub const SWAP_CANISTER_ID: &'static str = "3xwpq-ziaaa-aaaah-qcn4a-cai";
+    /// The fee of the pools, in thousandths of the amount in.
+    pub const POOL_FEE_PER_MILLE: u64 = 3;
+    /// The time the swap canister has to execute the swap, in nanoseconds.
+    pub const SWAP_DEADLINE_NS: u64 = 5 * 60 * 1_000_000_000;
+
+    pub fn new() -> Self {
+        Self {
+            swap_canister_id: Principal::from_text(Self::SWAP_CANISTER_ID).unwrap(),
+        }
+    }
+
+    fn receipt(method: &str, receipt: SonicTxReceipt) -> Result<Nat, String> {
+        match receipt {
+            SonicTxReceipt::ok(value) => Ok(value),
+            SonicTxReceipt::err(err) => Err(format!("{}: {}", method, err)),
+        }
+    }
+}
+
+impl Default for Sonic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum SonicTxReceipt {
+    ok(Nat),
+    err(String),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct SonicPairInfo {
+    token0: String,
+    reserve0: Nat,
+    reserve1: Nat,
+}
+
+#[async_trait]
+impl DexAdapter for Sonic {
+    async fn quote(&self, args: &DexSwapArgs) -> Result<Nat, DexError> {
+        let (pair,): (Option<SonicPairInfo>,) = ic_cdk::call(
+            self.swap_canister_id,
+            "getPair",
+            (args.from.ledger_canister_id, args.to.ledger_canister_id),
+        )
+        .await
+        .map_err(|err| DexError::QuoteFailed {
+            dex: Dex::Sonic.to_string(),
+            info: call_error(&Dex::Sonic, err),
+        })?;
+
+        let pair = pair.ok_or(DexError::PoolNotFound {
+            dex: Dex::Sonic.to_string(),
+        })?;
+
+        let (reserve_in, reserve_out) = match pair.token0 == args.from.ledger_canister_id.to_text()
+        {
+            true => (pair.reserve0, pair.reserve1),
+            false => (pair.reserve1, pair.reserve0),
+        };
+
+        // the constant product formula, where the pool keeps its fee out of the amount in
+        let amount_in = (args.amount_in.clone() - args.from.fee.clone())
+            * Nat::from(1_000 - Self::POOL_FEE_PER_MILLE);
+        let amount_out =
+            amount_in.clone() * reserve_out / (reserve_in * Nat::from(1_000u64) + amount_in);
+
+        // the withdrawal and the transit through the default account are each charged the ledger fee
+        let fees = args.to.fee.clone() * Nat::from(2u64);
+        if amount_out <= fees {
+            return Ok(Nat::from(0u64));
+        }
+
+        Ok(amount_out - fees)
+    }
+
+    async fn swap(&self, args: &DexSwapArgs) -> Result<DexSwapReceipt, DexError> {
+        let swap_failed = |info| DexError::SwapFailed {
+            dex: Dex::Sonic.to_string(),
+            info,
+        };
+
+        let (deposit_subaccount,): (Vec<u8>,) =
+            ic_cdk::call(self.swap_canister_id, "initiateICRC1Transfer", ())
+                .await
+                .map_err(|err| swap_failed(call_error(&Dex::Sonic, err)))?;
+
+        transfer_to_dex(
+            &Dex::Sonic,
+            &args.from,
+            Icrc1Account {
+                owner: self.swap_canister_id,
+                subaccount: Some(deposit_subaccount),
+            },
+            args.amount_in.clone(),
+        )
+        .await?;
+
+        let deposited = args.amount_in.clone() - args.from.fee.clone();
+        let (receipt,): (SonicTxReceipt,) = ic_cdk::call(
+            self.swap_canister_id,
+            "deposit",
+            (args.from.ledger_canister_id, deposited.clone()),
+        )
+        .await
+        .map_err(|err| swap_failed(call_error(&Dex::Sonic, err)))?;
+        Self::receipt("deposit", receipt).map_err(swap_failed)?;
+
+        let station_id = station_canister_self_id();
+        let fees = args.to.fee.clone() * Nat::from(2u64);
+        let (receipt,): (SonicTxReceipt,) = ic_cdk::call(
+            self.swap_canister_id,
+            "swapExactTokensForTokens",
+            (
+                deposited,
+                args.min_amount_out.clone() + fees.clone(),
+                vec![
+                    args.from.ledger_canister_id.to_text(),
+                    args.to.ledger_canister_id.to_text(),
+                ],
+                station_id,
+                Int::from(next_time() + Self::SWAP_DEADLINE_NS),
+            ),
+        )
+        .await
+        .map_err(|err| swap_failed(call_error(&Dex::Sonic, err)))?;
+        Self::receipt("swapExactTokensForTokens", receipt).map_err(swap_failed)?;
+
+        // the swap receipt only holds the transaction index, the bought amount is the balance of the station
+        let (balance,): (Nat,) = ic_cdk::call(
+            self.swap_canister_id,
+            "balanceOf",
+            (args.to.ledger_canister_id.to_text(), station_id),
+        )
+        .await
+        .map_err(|err| swap_failed(call_error(&Dex::Sonic, err)))?;
+
+        if balance <= fees {
+            return Err(swap_failed(format!(
+                "The swapped balance {} does not cover the ledger fees",
+                balance
+            )));
+        }
+
+        let (receipt,): (SonicTxReceipt,) = ic_cdk::call(
+            self.swap_canister_id,
+            "withdraw",
+            (args.to.ledger_canister_id, balance.clone()),
+        )
+        .await
+        .map_err(|err| swap_failed(call_error(&Dex::Sonic, err)))?;
+        Self::receipt("withdraw", receipt).map_err(swap_failed)?;
+
+        let amount_out = balance - fees;
+        icrc1_transfer(
+            args.to.ledger_canister_id,
+            Icrc1TransferArgs {
+                from_subaccount: None,
+                to: Icrc1Account::from(&args.to.account),
+                amount: amount_out.clone(),
+                fee: Some(args.to.fee.clone()),
+                memo: None,
+                created_at_time: Some(next_time()),
+            },
+        )
+        .await
+        .map_err(|e| {
+            swap_failed(format!(
+                "Failed to transfer the tokens from the default account: {}",
+                e
+            ))
+        })?;
+
+        Ok(DexSwapReceipt {
+            amount_in: args.amount_in.clone(),
+            amount_out,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 //! This module contains the factories that facilitates the creation of a common interface to handle related components.
 //!
-//! Examples of these are handling the interaction with different blockchains.
+//! Examples of these are handling the interaction with different blockchains and decentralized exchanges.
 
 pub mod blockchains;
+pub mod dex;
 pub mod requests;
@@ -1,11 +1,16 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{AddAccountOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::AccountService,
+    models::{
+        AddAccountOperation, AddAccountOperationInput, Blockchain, BlockchainStandard,
+        ChangeMetadata, Request, RequestExecutionPlan, RequestOperation,
+        ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY, ACCOUNT_METADATA_SYMBOL_KEY,
+    },
+    services::{AccountService, ASSET_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
+use std::collections::BTreeMap;
 
 pub struct AddAccountRequestCreate {}
 
@@ -18,13 +23,16 @@ impl Create<station_api::AddAccountOperationInput> for AddAccountRequestCreate {
         input: station_api::CreateRequestInput,
         operation_input: station_api::AddAccountOperationInput,
     ) -> Result<Request, RequestError> {
+        let mut operation_input: AddAccountOperationInput = operation_input.into();
+        fill_sns_ledger_canister_id(&mut operation_input);
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::AddAccount(AddAccountOperation {
                 account_id: None,
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
@@ -40,6 +48,45 @@ impl Create<station_api::AddAccountOperationInput> for AddAccountRequestCreate {
     }
 }
 
+/// Fills the ledger canister id of ICRC-1 accounts that only specify the symbol of a registered asset
+/// or of a discovered SNS token.
+fn fill_sns_ledger_canister_id(input: &mut AddAccountOperationInput) {
+    if input.blockchain != Blockchain::InternetComputer
+        || input.standard != BlockchainStandard::ICRC1
+        || input
+            .metadata
+            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+            .is_some()
+    {
+        return;
+    }
+
+    let Some(ledger_canister_id) =
+        input
+            .metadata
+            .get(ACCOUNT_METADATA_SYMBOL_KEY)
+            .and_then(|symbol| {
+                ASSET_SERVICE
+                    .find_registered_asset_by_symbol(&symbol)
+                    .map(|asset| asset.ledger_canister_id)
+                    .or_else(|| {
+                        ASSET_SERVICE
+                            .find_sns_token_by_symbol(&symbol)
+                            .map(|token| token.ledger_canister_id)
+                    })
+            })
+    else {
+        return;
+    };
+
+    input
+        .metadata
+        .change(ChangeMetadata::OverrideSpecifiedBy(BTreeMap::from([(
+            ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+            ledger_canister_id.to_text(),
+        )])));
+}
+
 pub struct AddAccountRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o AddAccountOperation,
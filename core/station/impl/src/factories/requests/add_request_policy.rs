@@ -3,11 +3,13 @@ use std::sync::Arc;
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{AddRequestPolicyOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::RequestPolicyService,
+    models::{
+        AddRequestPolicyOperation, PolicyChange, Request, RequestExecutionPlan, RequestOperation,
+    },
+    services::{RequestPolicyService, ScheduledPolicyChangeService},
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use orbit_essentials::{types::UUID, utils::rfc3339_to_timestamp};
 
 pub struct AddRequestPolicyRequestCreate {}
 
@@ -26,6 +28,10 @@ impl Create<station_api::AddRequestPolicyOperationInput> for AddRequestPolicyReq
             Request::default_expiration_dt_ns(),
             RequestOperation::AddRequestPolicy(AddRequestPolicyOperation {
                 policy_id: None,
+                effective_from: operation_input
+                    .effective_from
+                    .as_ref()
+                    .map(|effective_from| rfc3339_to_timestamp(effective_from.as_str())),
                 input: operation_input.into(),
             }),
             input
@@ -46,6 +52,7 @@ pub struct AddRequestPolicyRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o AddRequestPolicyOperation,
     policy_service: Arc<RequestPolicyService>,
+    scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
 }
 
 impl<'p, 'o> AddRequestPolicyRequestExecute<'p, 'o> {
@@ -53,11 +60,13 @@ impl<'p, 'o> AddRequestPolicyRequestExecute<'p, 'o> {
         request: &'p Request,
         operation: &'o AddRequestPolicyOperation,
         policy_service: Arc<RequestPolicyService>,
+        scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
     ) -> Self {
         Self {
             request,
             operation,
             policy_service,
+            scheduled_policy_change_service,
         }
     }
 }
@@ -65,6 +74,21 @@ impl<'p, 'o> AddRequestPolicyRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for AddRequestPolicyRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        if let Some(effective_from) =
+            ScheduledPolicyChangeService::deferred_until(self.operation.effective_from)
+        {
+            // the policy is added by the activation job, so the request has no policy id yet
+            self.scheduled_policy_change_service.schedule_policy_change(
+                self.request.id,
+                PolicyChange::AddRequestPolicy(self.operation.input.to_owned()),
+                effective_from,
+            );
+
+            return Ok(RequestExecuteStage::Completed(
+                self.request.operation.to_owned(),
+            ));
+        }
+
         let policy = self
             .policy_service
             .add_request_policy(self.operation.input.to_owned())
@@ -85,8 +109,12 @@ impl Execute for AddRequestPolicyRequestExecute<'_, '_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{repositories::REQUEST_REPOSITORY, services::REQUEST_POLICY_SERVICE};
-    use orbit_essentials::repository::Repository;
+    use crate::{
+        core::ic_cdk::api::time,
+        repositories::{REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY},
+        services::{REQUEST_POLICY_SERVICE, SCHEDULED_POLICY_CHANGE_SERVICE},
+    };
+    use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
 
     #[tokio::test]
     async fn test_create_request() {
@@ -140,6 +168,7 @@ mod tests {
                 &request,
                 operation,
                 Arc::clone(&REQUEST_POLICY_SERVICE),
+                Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
             )
             .execute()
             .await
@@ -156,6 +185,61 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_execute_request_with_future_effective_time_schedules_the_policy() {
+        let request_id = [0u8; 16];
+        let requested_by_user = [1u8; 16];
+        let effective_from = time() + 24 * 60 * 60 * 1_000_000_000;
+        let mut operation_input =
+            add_request_policy_test_utils::mock_add_request_policy_api_input();
+        operation_input.effective_from = Some(timestamp_to_rfc3339(&effective_from));
+        let mut request_input = add_request_policy_test_utils::mock_request_api_input();
+        request_input.operation =
+            station_api::RequestOperationInput::AddRequestPolicy(operation_input.clone());
+
+        let request = AddRequestPolicyRequestCreate {}
+            .create(
+                request_id,
+                requested_by_user,
+                request_input,
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        let RequestOperation::AddRequestPolicy(operation) = &request.operation else {
+            panic!(
+                "Expected AddRequestPolicy operation, got {:?}",
+                request.operation
+            );
+        };
+        assert_eq!(operation.effective_from, Some(effective_from));
+
+        let stage = AddRequestPolicyRequestExecute::new(
+            &request,
+            operation,
+            Arc::clone(&REQUEST_POLICY_SERVICE),
+            Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
+        )
+        .execute()
+        .await
+        .unwrap();
+
+        match stage {
+            RequestExecuteStage::Completed(RequestOperation::AddRequestPolicy(operation)) => {
+                assert_eq!(operation.policy_id, None)
+            }
+            _ => panic!("Expected RequestExecuteStage::Completed, got {:?}", stage),
+        }
+
+        assert!(REQUEST_POLICY_REPOSITORY.list().is_empty());
+
+        let pending = SCHEDULED_POLICY_CHANGE_SERVICE.list_pending_policy_changes();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].request_id, request_id);
+        assert_eq!(pending[0].effective_from, effective_from);
+    }
 }
 
 #[cfg(test)]
@@ -164,6 +248,8 @@ pub mod add_request_policy_test_utils {
         station_api::AddRequestPolicyOperationInput {
             rule: station_api::RequestPolicyRuleDTO::AutoApproved,
             specifier: station_api::RequestSpecifierDTO::AddRequestPolicy,
+            effective_from: None,
+            expiration_period_secs: None,
         }
     }
 
@@ -175,6 +261,9 @@ pub mod add_request_policy_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
         }
     }
 }
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use super::{transfer::to_transfer_operation_input, Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        AddScheduledTransferOperation, AddScheduledTransferOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::ScheduledTransferService,
+};
+use async_trait::async_trait;
+use orbit_essentials::{types::UUID, utils::rfc3339_to_timestamp};
+
+pub struct AddScheduledTransferRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::AddScheduledTransferOperationInput> for AddScheduledTransferRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::AddScheduledTransferOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::AddScheduledTransfer(AddScheduledTransferOperation {
+                scheduled_transfer_id: None,
+                input: AddScheduledTransferOperationInput {
+                    transfer: to_transfer_operation_input(operation_input.transfer)?,
+                    interval_ns: operation_input.interval_secs.saturating_mul(1_000_000_000),
+                    start_at: operation_input
+                        .start_at
+                        .as_ref()
+                        .map(|start_at| rfc3339_to_timestamp(start_at.as_str())),
+                    end_at: operation_input
+                        .end_at
+                        .as_ref()
+                        .map(|end_at| rfc3339_to_timestamp(end_at.as_str())),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Scheduled transfer".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct AddScheduledTransferRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o AddScheduledTransferOperation,
+    scheduled_transfer_service: Arc<ScheduledTransferService>,
+}
+
+impl<'p, 'o> AddScheduledTransferRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o AddScheduledTransferOperation,
+        scheduled_transfer_service: Arc<ScheduledTransferService>,
+    ) -> Self {
+        Self {
+            request,
+            operation,
+            scheduled_transfer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for AddScheduledTransferRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        // the transfers are created by the standing order, the request itself is done once it exists
+        let scheduled_transfer = self
+            .scheduled_transfer_service
+            .add_scheduled_transfer(self.request, self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to create the scheduled transfer: {}", e),
+            })?;
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::AddScheduledTransfer(ref mut operation) = operation {
+            operation.scheduled_transfer_id = Some(scheduled_transfer.id);
+        }
+
+        Ok(RequestExecuteStage::Completed(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::account_test_utils::mock_account,
+        repositories::{ACCOUNT_REPOSITORY, SCHEDULED_TRANSFER_REPOSITORY},
+        services::SCHEDULED_TRANSFER_SERVICE,
+    };
+    use orbit_essentials::repository::Repository;
+    use uuid::Uuid;
+
+    fn mock_operation_input(
+        from_account_id: UUID,
+    ) -> station_api::AddScheduledTransferOperationInput {
+        station_api::AddScheduledTransferOperationInput {
+            transfer: station_api::TransferOperationInput {
+                from_account_id: Uuid::from_bytes(from_account_id).hyphenated().to_string(),
+                to: "destination".to_string(),
+                amount: candid::Nat::from(100u64),
+                fee: None,
+                metadata: vec![],
+                network: None,
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
+            },
+            interval_secs: 30 * 24 * 60 * 60,
+            start_at: None,
+            end_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_execute_request() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = mock_operation_input(account.id);
+        let request_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::AddScheduledTransfer(
+                operation_input.clone(),
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let request = AddScheduledTransferRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .unwrap();
+
+        assert_eq!(request.title, "Scheduled transfer".to_string());
+
+        let RequestOperation::AddScheduledTransfer(operation) = &request.operation else {
+            panic!("Unexpected operation");
+        };
+        assert_eq!(
+            operation.input.interval_ns,
+            30 * 24 * 60 * 60 * 1_000_000_000
+        );
+
+        let stage = AddScheduledTransferRequestExecute::new(
+            &request,
+            operation,
+            Arc::clone(&SCHEDULED_TRANSFER_SERVICE),
+        )
+        .execute()
+        .await
+        .unwrap();
+
+        let RequestExecuteStage::Completed(RequestOperation::AddScheduledTransfer(operation)) =
+            stage
+        else {
+            panic!("Unexpected stage");
+        };
+
+        let scheduled_transfer = SCHEDULED_TRANSFER_REPOSITORY
+            .get(&operation.scheduled_transfer_id.unwrap())
+            .unwrap();
+        assert_eq!(scheduled_transfer.request_id, request.id);
+        assert_eq!(scheduled_transfer.transfer.from_account_id, account.id);
+    }
+}
@@ -0,0 +1,175 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        AddTeamOperation, AddTeamOperationInput, Request, RequestExecutionPlan, RequestOperation,
+    },
+    services::permission::PermissionService,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+use std::sync::Arc;
+
+pub struct AddTeamRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::AddTeamOperationInput> for AddTeamRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::AddTeamOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::AddTeam(AddTeamOperation {
+                user_group_id: None,
+                input: AddTeamOperationInput::from(operation_input),
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Team setup".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct AddTeamRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o AddTeamOperation,
+    permission_service: Arc<PermissionService>,
+}
+
+impl<'p, 'o> AddTeamRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o AddTeamOperation,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            request,
+            operation,
+            permission_service,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for AddTeamRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let user_group = self
+            .permission_service
+            .add_team(self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to set up the team: {}", e),
+            })?;
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::AddTeam(ref mut operation) = operation {
+            operation.user_group_id = Some(user_group.id);
+        }
+
+        Ok(RequestExecuteStage::Completed(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{
+            resource::{Resource, ResourceAction},
+            user_test_utils::add_user,
+        },
+        repositories::{permission::PERMISSION_REPOSITORY, USER_REPOSITORY},
+        services::permission::PERMISSION_SERVICE,
+    };
+    use orbit_essentials::repository::Repository;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_create_and_execute_request() {
+        let member = add_user(&[1; 16]);
+        let operation_input = station_api::AddTeamOperationInput {
+            name: "Treasury".to_string(),
+            member_ids: vec![Uuid::from_bytes(member.id).hyphenated().to_string()],
+            resources: vec![station_api::ResourceDTO::AddressBook(
+                station_api::ResourceActionDTO::List,
+            )],
+        };
+        let request_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::AddTeam(operation_input.clone()),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let request = AddTeamRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .unwrap();
+
+        assert_eq!(request.title, "Team setup".to_string());
+
+        let RequestOperation::AddTeam(operation) = &request.operation else {
+            panic!("Unexpected operation");
+        };
+
+        let stage =
+            AddTeamRequestExecute::new(&request, operation, Arc::clone(&PERMISSION_SERVICE))
+                .execute()
+                .await
+                .unwrap();
+
+        let RequestExecuteStage::Completed(RequestOperation::AddTeam(operation)) = stage else {
+            panic!("Unexpected stage");
+        };
+
+        let user_group_id = operation.user_group_id.unwrap();
+        let member = USER_REPOSITORY.get(&member.to_key()).unwrap();
+        assert_eq!(member.groups, vec![user_group_id]);
+
+        let permission = PERMISSION_REPOSITORY
+            .get(&Resource::AddressBook(ResourceAction::List))
+            .unwrap();
+        assert!(permission.allow.user_groups.contains(&user_group_id));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_fails_with_unknown_member() {
+        add_user(&[1; 16]);
+        let operation_input = station_api::AddTeamOperationInput {
+            name: "Treasury".to_string(),
+            member_ids: vec![Uuid::from_bytes([2; 16]).hyphenated().to_string()],
+            resources: vec![],
+        };
+        let request_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::AddTeam(operation_input.clone()),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        assert!(AddTeamRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    factories::blockchains::{
+        BlockchainApiFactory, TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    },
+    mappers::HelperMapper,
+    models::{
+        Account, ApproveOperation, ApproveOperationInput, IcrcAccount, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+};
+use async_trait::async_trait;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use orbit_essentials::utils::rfc3339_to_timestamp;
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub struct ApproveRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::ApproveOperationInput> for ApproveRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::ApproveOperationInput,
+    ) -> Result<Request, RequestError> {
+        let from_account_id =
+            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid from_account_id: {}", e),
+                }
+            })?;
+        let spender = IcrcAccount::from_str(&operation_input.spender).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid spender: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::Approve(ApproveOperation {
+                block_index: None,
+                input: ApproveOperationInput {
+                    from_account_id: *from_account_id.as_bytes(),
+                    spender,
+                    amount: operation_input.amount,
+                    expected_allowance: operation_input.expected_allowance,
+                    expires_at: operation_input
+                        .expires_at
+                        .map(|expires_at| rfc3339_to_timestamp(expires_at.as_str())),
+                    fee: operation_input.fee,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Approve spender".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct ApproveRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o ApproveOperation,
+}
+
+impl<'p, 'o> ApproveRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o ApproveOperation) -> Self {
+        Self {
+            _request: request,
+            operation,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for ApproveRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let account = ACCOUNT_REPOSITORY
+            .get(&Account::key(self.operation.input.from_account_id))
+            .ok_or(RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not exist.",
+                    Uuid::from_bytes(self.operation.input.from_account_id).hyphenated()
+                ),
+            })?;
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to build blockchain api: {}", e),
+            })?;
+
+        let approval = blockchain_api
+            .approve(&account, &self.operation.input)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to approve spender: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.block_index = approval
+            .metadata_map()
+            .get(TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY)
+            .and_then(|block_height| block_height.parse().ok());
+
+        Ok(RequestExecuteStage::Completed(RequestOperation::Approve(
+            operation,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user},
+        repositories::USER_REPOSITORY,
+    };
+
+    #[tokio::test]
+    async fn test_create_approve_request() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let request = ApproveRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::Approve(mock_approve_api_input(
+                        &account,
+                    )),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                mock_approve_api_input(&account),
+            )
+            .await
+            .unwrap();
+
+        match request.operation {
+            RequestOperation::Approve(operation) => {
+                assert_eq!(operation.block_index, None);
+                assert_eq!(operation.input.from_account_id, account.id);
+                assert_eq!(operation.input.amount, candid::Nat::from(1_000_u64));
+                assert_eq!(operation.input.spender.subaccount, None);
+            }
+            _ => panic!("Expected an approve operation"),
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_create_approve_request_with_invalid_spender() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut operation_input = mock_approve_api_input(&account);
+        operation_input.spender = "not-a-principal".to_string();
+
+        let result = ApproveRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::Approve(operation_input.clone()),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    fn mock_approve_api_input(account: &Account) -> station_api::ApproveOperationInput {
+        station_api::ApproveOperationInput {
+            from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            spender: "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae".to_string(),
+            amount: candid::Nat::from(1_000_u64),
+            expected_allowance: None,
+            expires_at: None,
+            fee: None,
+        }
+    }
+}
@@ -34,39 +34,8 @@ impl Create<CallExternalCanisterOperationInput> for CallExternalCanisterRequestC
             });
         }
 
-        let arg_rendering = match operation_input.validation_method {
-            Some(ref validation_method) => {
-                let rendering_bytes = self
-                    .external_canister_service
-                    .call_external_canister(
-                        validation_method.canister_id,
-                        validation_method.method_name.clone(),
-                        operation_input.arg.clone(),
-                        None,
-                    )
-                    .await
-                    .map_err(|err| RequestError::ValidationError {
-                        info: format!(
-                            "failed to call validation canister {}: {}",
-                            validation_method.canister_id, err
-                        ),
-                    })?;
-                let rendering =
-                    Decode!(&rendering_bytes, Result<String, String>).map_err(|err| {
-                        RequestError::ValidationError {
-                            info: format!(
-                                "failed to decode validation canister {} reply: {}",
-                                validation_method.canister_id, err
-                            ),
-                        }
-                    })?;
-                Some(rendering.map_err(|err| RequestError::ValidationError {
-                    info: format!("failed to validate call external canister request: {}", err),
-                })?)
-            }
-            None => None,
-        };
-
+        // The validation method is called once the request is created, the argument rendering is
+        // only available after that.
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -77,7 +46,7 @@ impl Create<CallExternalCanisterOperationInput> for CallExternalCanisterRequestC
                     hasher.update(arg);
                     hasher.finalize().to_vec()
                 }),
-                arg_rendering,
+                arg_rendering: None,
                 execution_method_reply: None,
                 input: operation_input.into(),
             }),
@@ -95,6 +64,49 @@ impl Create<CallExternalCanisterOperationInput> for CallExternalCanisterRequestC
     }
 }
 
+impl CallExternalCanisterRequestCreate {
+    /// Calls the validation method of the operation, if any, and sets the argument rendering it replied.
+    pub async fn validate(
+        &self,
+        operation: &CallExternalCanisterOperation,
+    ) -> Result<CallExternalCanisterOperation, RequestError> {
+        let Some(ref validation_method) = operation.input.validation_method else {
+            return Ok(operation.clone());
+        };
+
+        let rendering_bytes = self
+            .external_canister_service
+            .call_external_canister(
+                validation_method.canister_id,
+                validation_method.method_name.clone(),
+                operation.input.arg.clone(),
+                None,
+            )
+            .await
+            .map_err(|err| RequestError::ValidationError {
+                info: format!(
+                    "failed to call validation canister {}: {}",
+                    validation_method.canister_id, err
+                ),
+            })?;
+        let rendering = Decode!(&rendering_bytes, Result<String, String>).map_err(|err| {
+            RequestError::ValidationError {
+                info: format!(
+                    "failed to decode validation canister {} reply: {}",
+                    validation_method.canister_id, err
+                ),
+            }
+        })?;
+
+        let mut operation = operation.clone();
+        operation.arg_rendering = Some(rendering.map_err(|err| RequestError::ValidationError {
+            info: format!("failed to validate call external canister request: {}", err),
+        })?);
+
+        Ok(operation)
+    }
+}
+
 pub struct CallExternalCanisterRequestExecute<'p, 'o> {
     _request: &'p Request,
     operation: &'o CallExternalCanisterOperation,
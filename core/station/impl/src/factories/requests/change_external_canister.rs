@@ -1,14 +1,68 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{ChangeExternalCanisterOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::ChangeCanisterService,
+    mappers::HelperMapper,
+    models::{
+        ChangeExternalCanisterOperation, Request, RequestExecutionPlan, RequestOperation,
+        RequestStatus,
+    },
+    repositories::REQUEST_REPOSITORY,
+    services::{ChangeCanisterService, ExternalCanisterService},
 };
 use async_trait::async_trait;
+use candid::Principal;
+use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
 use sha2::{Digest, Sha256};
 use station_api::{ChangeExternalCanisterOperationInput, CreateRequestInput};
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// Checks that the replaced request still awaits approval and changes the same canister, so that
+/// approving its correction only cancels what it was meant to correct.
+fn validate_replaced_request(
+    replaced_id: &str,
+    canister_id: &Principal,
+) -> Result<(), RequestError> {
+    let replaced_id = HelperMapper::to_uuid(replaced_id.to_string()).map_err(|e| {
+        RequestError::ValidationError {
+            info: format!("Invalid replaced request id: {}", e),
+        }
+    })?;
+    let replaced = REQUEST_REPOSITORY
+        .get(&Request::key(*replaced_id.as_bytes()))
+        .ok_or(RequestError::ValidationError {
+            info: format!(
+                "The replaced request {} does not exist",
+                replaced_id.hyphenated()
+            ),
+        })?;
+
+    match &replaced.operation {
+        RequestOperation::ChangeExternalCanister(operation)
+            if operation.input.canister_id == *canister_id => {}
+        _ => {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The replaced request {} does not change the canister {}",
+                    Uuid::from_bytes(replaced.id).hyphenated(),
+                    canister_id
+                ),
+            })
+        }
+    }
+
+    if replaced.status != RequestStatus::Created {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The replaced request {} is no longer pending",
+                Uuid::from_bytes(replaced.id).hyphenated()
+            ),
+        });
+    }
+
+    Ok(())
+}
 
 pub struct ChangeExternalCanisterRequestCreate;
 
@@ -21,6 +75,10 @@ impl Create<ChangeExternalCanisterOperationInput> for ChangeExternalCanisterRequ
         input: CreateRequestInput,
         operation_input: ChangeExternalCanisterOperationInput,
     ) -> Result<Request, RequestError> {
+        if let Some(replaced_id) = &operation_input.replaces {
+            validate_replaced_request(replaced_id, &operation_input.canister_id)?;
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -60,6 +118,7 @@ pub struct ChangeExternalCanisterRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o ChangeExternalCanisterOperation,
     change_canister_service: Arc<ChangeCanisterService>,
+    external_canister_service: Arc<ExternalCanisterService>,
 }
 
 impl<'p, 'o> ChangeExternalCanisterRequestExecute<'p, 'o> {
@@ -67,11 +126,13 @@ impl<'p, 'o> ChangeExternalCanisterRequestExecute<'p, 'o> {
         request: &'p Request,
         operation: &'o ChangeExternalCanisterOperation,
         change_canister_service: Arc<ChangeCanisterService>,
+        external_canister_service: Arc<ExternalCanisterService>,
     ) -> Self {
         Self {
             request,
             operation,
             change_canister_service,
+            external_canister_service,
         }
     }
 }
@@ -95,6 +156,11 @@ impl Execute for ChangeExternalCanisterRequestExecute<'_, '_> {
                 ),
             })?;
 
+        self.external_canister_service.record_installed_module(
+            &self.operation.input.canister_id,
+            self.operation.module_checksum.clone(),
+        );
+
         Ok(RequestExecuteStage::Completed(
             self.request.operation.clone(),
         ))
@@ -105,6 +105,18 @@ impl Execute for ConfigureExternalCanisterRequestExecute<'_, '_> {
                     .map_err(|e| RequestExecuteError::Failed {
                         reason: format!("Failed to configure settings: {}", e),
                     })?;
+
+                if let Some(monitoring) = &settings.monitoring {
+                    self.external_canister_service
+                        .configure_external_canister_monitoring(
+                            &external_canister.id,
+                            monitoring.clone(),
+                            self.request.requested_by,
+                        )
+                        .map_err(|e| RequestExecuteError::Failed {
+                            reason: format!("Failed to configure monitoring: {}", e),
+                        })?;
+                }
             }
             // these operations do not require an external canister entry
             ConfigureExternalCanisterOperationKind::NativeSettings(settings) => {
@@ -0,0 +1,217 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    core::ic_cdk::next_time,
+    errors::{CapabilityGrantError, RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        CapabilityGrant, CreateCapabilityGrantOperation, CreateCapabilityGrantOperationInput,
+        Request, RequestExecutionPlan, RequestOperation,
+    },
+    services::CAPABILITY_GRANT_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::{model::ModelValidator, types::UUID, utils::try_rfc3339_to_timestamp};
+
+pub struct CreateCapabilityGrantRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::CreateCapabilityGrantOperationInput>
+    for CreateCapabilityGrantRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::CreateCapabilityGrantOperationInput,
+    ) -> Result<Request, RequestError> {
+        let account_id = HelperMapper::to_uuid(operation_input.account_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid account_id: {}", e),
+            }
+        })?;
+        let expires_at = try_rfc3339_to_timestamp(&operation_input.expires_at).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid expires_at: {}", e),
+            }
+        })?;
+        let operation_input = CreateCapabilityGrantOperationInput {
+            account_id: *account_id.as_bytes(),
+            grantee: operation_input.grantee,
+            scopes: operation_input.scopes.into_iter().map(Into::into).collect(),
+            expires_at,
+        };
+
+        // the grant is validated again when the request is executed, since it can expire in between
+        CapabilityGrant::new(
+            request_id,
+            operation_input.clone(),
+            requested_by_user,
+            next_time(),
+        )
+        .validate()
+        .map_err(|e| RequestError::ValidationError {
+            info: match e {
+                CapabilityGrantError::ValidationError { info } => info,
+                e => e.to_string(),
+            },
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::CreateCapabilityGrant(CreateCapabilityGrantOperation {
+                grant_id: None,
+                input: operation_input,
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Capability grant creation".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct CreateCapabilityGrantRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o CreateCapabilityGrantOperation,
+}
+
+impl<'p, 'o> CreateCapabilityGrantRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o CreateCapabilityGrantOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for CreateCapabilityGrantRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let grant = CAPABILITY_GRANT_SERVICE
+            .create_capability_grant(self.operation.input.to_owned(), self.request.requested_by)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to create the capability grant: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.grant_id = Some(grant.id);
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::CreateCapabilityGrant(operation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user, CapabilityScope},
+        repositories::{ACCOUNT_REPOSITORY, CAPABILITY_GRANT_REPOSITORY, USER_REPOSITORY},
+    };
+    use candid::Principal;
+    use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
+    use station_api::CapabilityScopeDTO;
+    use uuid::Uuid;
+
+    fn create_request_input(
+        operation_input: station_api::CreateCapabilityGrantOperationInput,
+    ) -> station_api::CreateRequestInput {
+        station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::CreateCapabilityGrant(operation_input),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        }
+    }
+
+    fn mock_api_input(account_id: UUID) -> station_api::CreateCapabilityGrantOperationInput {
+        station_api::CreateCapabilityGrantOperationInput {
+            account_id: Uuid::from_bytes(account_id).hyphenated().to_string(),
+            grantee: Principal::from_slice(&[5; 29]),
+            scopes: vec![CapabilityScopeDTO::Balances],
+            expires_at: timestamp_to_rfc3339(&(next_time() + 24 * 60 * 60 * 1_000_000_000)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_execute_capability_grant_request() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = mock_api_input(account.id);
+        let request = CreateCapabilityGrantRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                create_request_input(operation_input.clone()),
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        let operation = match &request.operation {
+            RequestOperation::CreateCapabilityGrant(operation) => operation,
+            _ => panic!("Expected a create capability grant operation"),
+        };
+
+        let stage = CreateCapabilityGrantRequestExecute::new(&request, operation)
+            .execute()
+            .await
+            .unwrap();
+
+        let grant_id = match stage {
+            RequestExecuteStage::Completed(RequestOperation::CreateCapabilityGrant(operation)) => {
+                operation.grant_id.unwrap()
+            }
+            _ => panic!("Expected the create capability grant operation to be completed"),
+        };
+
+        let grant = CAPABILITY_GRANT_REPOSITORY.get(&grant_id).unwrap();
+
+        assert_eq!(grant.account_id, account.id);
+        assert_eq!(grant.scopes, vec![CapabilityScope::Balances]);
+        assert_eq!(grant.created_by, user.id);
+    }
+
+    #[tokio::test]
+    async fn fail_create_with_invalid_expiration() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        for expires_at in ["next week".to_string(), timestamp_to_rfc3339(&0)] {
+            let mut operation_input = mock_api_input(account.id);
+            operation_input.expires_at = expires_at;
+
+            let result = CreateCapabilityGrantRequestCreate {}
+                .create(
+                    [1; 16],
+                    mock_user().id,
+                    create_request_input(operation_input.clone()),
+                    operation_input,
+                )
+                .await;
+
+            assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        Account, DeriveSubaccountOperation, DeriveSubaccountOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::ACCOUNT_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct DeriveSubaccountRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::DeriveSubaccountOperationInput> for DeriveSubaccountRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::DeriveSubaccountOperationInput,
+    ) -> Result<Request, RequestError> {
+        let account_id = HelperMapper::to_uuid(operation_input.account_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid account_id: {}", e),
+            }
+        })?;
+        let name = operation_input.name.trim().to_string();
+
+        if name.is_empty() || name.len() > Account::SUBACCOUNT_NAME_RANGE.1 as usize {
+            Err(RequestError::ValidationError {
+                info: format!(
+                    "Subaccount name length must be between {} and {}",
+                    Account::SUBACCOUNT_NAME_RANGE.0,
+                    Account::SUBACCOUNT_NAME_RANGE.1
+                ),
+            })?
+        }
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::DeriveSubaccount(DeriveSubaccountOperation {
+                subaccount_index: None,
+                input: DeriveSubaccountOperationInput {
+                    account_id: *account_id.as_bytes(),
+                    name,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Derive subaccount".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct DeriveSubaccountRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o DeriveSubaccountOperation,
+}
+
+impl<'p, 'o> DeriveSubaccountRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o DeriveSubaccountOperation) -> Self {
+        Self {
+            _request: request,
+            operation,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for DeriveSubaccountRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let subaccount = ACCOUNT_SERVICE
+            .derive_subaccount(
+                &self.operation.input.account_id,
+                self.operation.input.name.clone(),
+            )
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to derive the subaccount: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.subaccount_index = Some(subaccount.index);
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::DeriveSubaccount(operation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user},
+        repositories::{ACCOUNT_REPOSITORY, USER_REPOSITORY},
+    };
+    use orbit_essentials::repository::Repository;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_create_and_execute_derive_subaccount_request() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = station_api::DeriveSubaccountOperationInput {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            name: " Marketing deposits ".to_string(),
+        };
+        let request = DeriveSubaccountRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::DeriveSubaccount(
+                        operation_input.clone(),
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        let operation = match &request.operation {
+            RequestOperation::DeriveSubaccount(operation) => operation,
+            _ => panic!("Expected a derive subaccount operation"),
+        };
+
+        assert_eq!(operation.input.name, "Marketing deposits");
+
+        let stage = DeriveSubaccountRequestExecute::new(&request, operation)
+            .execute()
+            .await
+            .unwrap();
+
+        match stage {
+            RequestExecuteStage::Completed(RequestOperation::DeriveSubaccount(operation)) => {
+                assert_eq!(operation.subaccount_index, Some(1));
+            }
+            _ => panic!("Expected the derive subaccount operation to be completed"),
+        }
+
+        let account = ACCOUNT_REPOSITORY.get(&Account::key(account.id)).unwrap();
+
+        assert_eq!(account.subaccounts.len(), 1);
+        assert_eq!(account.subaccounts[0].name, "Marketing deposits");
+    }
+}
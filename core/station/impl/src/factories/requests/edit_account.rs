@@ -1,12 +1,63 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditAccountOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::ACCOUNT_SERVICE,
+    models::{
+        request_policy_rule::RequestPolicyRuleInput,
+        resource::{AccountResourceAction, Resource, ResourceId},
+        EditAccountOperation, EditAccountOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::{permission::PERMISSION_SERVICE, ACCOUNT_SERVICE, REQUEST_POLICY_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
 
+/// Snapshots the current values of the account fields that are changed by the input.
+fn snapshot_previous(input: &EditAccountOperationInput) -> Option<EditAccountOperationInput> {
+    let account = ACCOUNT_SERVICE.get_account(&input.account_id).ok()?;
+    let permission = |action: fn(ResourceId) -> AccountResourceAction| {
+        PERMISSION_SERVICE
+            .get_permission(&Resource::Account(action(ResourceId::Id(account.id))))
+            .allow
+    };
+    let request_policy = |policy_id: Option<UUID>| match policy_id
+        .and_then(|id| REQUEST_POLICY_SERVICE.get_request_policy(&id).ok())
+    {
+        Some(policy) => RequestPolicyRuleInput::Set(policy.rule),
+        None => RequestPolicyRuleInput::Remove,
+    };
+
+    Some(EditAccountOperationInput {
+        account_id: account.id,
+        name: input.name.as_ref().map(|_| account.name.clone()),
+        read_permission: input
+            .read_permission
+            .as_ref()
+            .map(|_| permission(AccountResourceAction::Read)),
+        configs_permission: input
+            .configs_permission
+            .as_ref()
+            .map(|_| permission(AccountResourceAction::Update)),
+        transfer_permission: input
+            .transfer_permission
+            .as_ref()
+            .map(|_| permission(AccountResourceAction::Transfer)),
+        configs_request_policy: input
+            .configs_request_policy
+            .as_ref()
+            .map(|_| request_policy(account.configs_request_policy_id)),
+        transfer_request_policy: input
+            .transfer_request_policy
+            .as_ref()
+            .map(|_| request_policy(account.transfer_request_policy_id)),
+        webhooks: input.webhooks.as_ref().map(|_| account.webhooks.clone()),
+        assets: input
+            .assets
+            .as_ref()
+            .map(|_| account.assets.iter().map(|held| held.asset_id).collect()),
+    })
+}
+
 pub struct EditAccountRequestCreate {}
 
 #[async_trait]
@@ -18,12 +69,14 @@ impl Create<station_api::EditAccountOperationInput> for EditAccountRequestCreate
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditAccountOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = EditAccountOperationInput::from(operation_input);
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditAccount(EditAccountOperation {
-                input: operation_input.into(),
+                previous: snapshot_previous(&operation_input),
+                input: operation_input,
             }),
             input
                 .execution_plan
@@ -3,7 +3,7 @@ use crate::{
     errors::{RequestError, RequestExecuteError},
     mappers::HelperMapper,
     models::{
-        EditAddressBookEntryOperation, EditAddressBookEntryOperationInput, Request,
+        ChangeMetadata, EditAddressBookEntryOperation, EditAddressBookEntryOperationInput, Request,
         RequestExecutionPlan, RequestOperation,
     },
     services::ADDRESS_BOOK_SERVICE,
@@ -11,6 +11,30 @@ use crate::{
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
 
+/// Snapshots the current values of the address book entry fields that are changed by the input,
+/// where the previous metadata is the whole metadata of the entry.
+fn snapshot_previous(
+    input: &EditAddressBookEntryOperationInput,
+) -> Option<EditAddressBookEntryOperationInput> {
+    let entry = ADDRESS_BOOK_SERVICE
+        .get_entry_by_id(&input.address_book_entry_id)
+        .ok()?;
+
+    Some(EditAddressBookEntryOperationInput {
+        address_book_entry_id: entry.id,
+        address_owner: input
+            .address_owner
+            .as_ref()
+            .map(|_| entry.address_owner.clone()),
+        change_metadata: input
+            .change_metadata
+            .as_ref()
+            .map(|_| ChangeMetadata::ReplaceAllBy(entry.metadata.as_btreemap().clone())),
+        labels: input.labels.as_ref().map(|_| entry.labels.clone()),
+        requires_memo: input.requires_memo.map(|_| entry.requires_memo),
+    })
+}
+
 pub struct EditAddressBookEntryRequestCreate {}
 
 #[async_trait]
@@ -27,17 +51,20 @@ impl Create<station_api::EditAddressBookEntryOperationInput> for EditAddressBook
                 info: format!("Invalid address book entry id: {}", e),
             })?;
 
+        let operation_input = EditAddressBookEntryOperationInput {
+            address_book_entry_id: *address_book_entry_id.as_bytes(),
+            address_owner: operation_input.address_owner,
+            change_metadata: operation_input.change_metadata.map(|m| m.into()),
+            labels: operation_input.labels,
+            requires_memo: operation_input.requires_memo,
+        };
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditAddressBookEntry(EditAddressBookEntryOperation {
-                input: EditAddressBookEntryOperationInput {
-                    address_book_entry_id: *address_book_entry_id.as_bytes(),
-                    address_owner: operation_input.address_owner,
-                    change_metadata: operation_input.change_metadata.map(|m| m.into()),
-                    labels: operation_input.labels,
-                },
+                previous: snapshot_previous(&operation_input),
+                input: operation_input,
             }),
             input
                 .execution_plan
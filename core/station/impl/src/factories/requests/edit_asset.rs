@@ -0,0 +1,102 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        ChangeMetadata, EditAssetOperation, EditAssetOperationInput, Request, RequestExecutionPlan,
+        RequestOperation,
+    },
+    services::ASSET_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+/// Snapshots the current values of the asset fields that are changed by the input, where the
+/// previous metadata is the whole metadata of the asset.
+fn snapshot_previous(input: &EditAssetOperationInput) -> Option<EditAssetOperationInput> {
+    let asset = ASSET_SERVICE.get_registered_asset(&input.asset_id).ok()?;
+
+    Some(EditAssetOperationInput {
+        asset_id: asset.id,
+        index_canister_id: input.index_canister_id.and(asset.index_canister_id),
+        symbol: input.symbol.as_ref().map(|_| asset.symbol.clone()),
+        name: input.name.as_ref().map(|_| asset.name.clone()),
+        decimals: input.decimals.map(|_| asset.decimals),
+        change_metadata: input
+            .change_metadata
+            .as_ref()
+            .map(|_| ChangeMetadata::ReplaceAllBy(asset.metadata.as_btreemap().clone())),
+    })
+}
+
+pub struct EditAssetRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::EditAssetOperationInput> for EditAssetRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::EditAssetOperationInput,
+    ) -> Result<Request, RequestError> {
+        let asset_id = HelperMapper::to_uuid(operation_input.asset_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid asset id: {}", e),
+            }
+        })?;
+
+        let operation_input = EditAssetOperationInput {
+            asset_id: *asset_id.as_bytes(),
+            index_canister_id: operation_input.index_canister_id,
+            symbol: operation_input.symbol,
+            name: operation_input.name,
+            decimals: operation_input.decimals,
+            change_metadata: operation_input.change_metadata.map(|m| m.into()),
+        };
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::EditAsset(EditAssetOperation {
+                previous: snapshot_previous(&operation_input),
+                input: operation_input,
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Asset update".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct EditAssetRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o EditAssetOperation,
+}
+
+impl<'p, 'o> EditAssetRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o EditAssetOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for EditAssetRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ASSET_SERVICE
+            .edit_asset(self.operation.input.to_owned())
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to update asset: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
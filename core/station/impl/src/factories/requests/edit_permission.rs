@@ -1,13 +1,34 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditPermissionOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::permission::PermissionService,
+    models::{
+        EditPermissionOperation, EditPermissionOperationInput, PolicyChange, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::{
+        permission::{PermissionService, PERMISSION_SERVICE},
+        ScheduledPolicyChangeService,
+    },
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use orbit_essentials::{types::UUID, utils::rfc3339_to_timestamp};
 use std::sync::Arc;
 
+/// Snapshots the current values of the permission fields that are changed by the input.
+fn snapshot_previous(input: &EditPermissionOperationInput) -> EditPermissionOperationInput {
+    let allow = PERMISSION_SERVICE.get_permission(&input.resource).allow;
+
+    EditPermissionOperationInput {
+        resource: input.resource.clone(),
+        auth_scope: input.auth_scope.as_ref().map(|_| allow.auth_scope.clone()),
+        users: input.users.as_ref().map(|_| allow.users.clone()),
+        user_groups: input
+            .user_groups
+            .as_ref()
+            .map(|_| allow.user_groups.clone()),
+    }
+}
+
 pub struct EditPermissionRequestCreate {}
 
 #[async_trait]
@@ -19,12 +40,19 @@ impl Create<station_api::EditPermissionOperationInput> for EditPermissionRequest
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditPermissionOperationInput,
     ) -> Result<Request, RequestError> {
+        let effective_from = operation_input
+            .effective_from
+            .as_ref()
+            .map(|effective_from| rfc3339_to_timestamp(effective_from.as_str()));
+        let operation_input = EditPermissionOperationInput::from(operation_input);
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditPermission(EditPermissionOperation {
-                input: operation_input.into(),
+                previous: Some(snapshot_previous(&operation_input)),
+                input: operation_input,
+                effective_from,
             }),
             input
                 .execution_plan
@@ -44,6 +72,7 @@ pub struct EditPermissionRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o EditPermissionOperation,
     policy_service: Arc<PermissionService>,
+    scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
 }
 
 impl<'p, 'o> EditPermissionRequestExecute<'p, 'o> {
@@ -51,11 +80,13 @@ impl<'p, 'o> EditPermissionRequestExecute<'p, 'o> {
         request: &'p Request,
         operation: &'o EditPermissionOperation,
         policy_service: Arc<PermissionService>,
+        scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
     ) -> Self {
         Self {
             request,
             operation,
             policy_service,
+            scheduled_policy_change_service,
         }
     }
 }
@@ -63,6 +94,20 @@ impl<'p, 'o> EditPermissionRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for EditPermissionRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        if let Some(effective_from) =
+            ScheduledPolicyChangeService::deferred_until(self.operation.effective_from)
+        {
+            self.scheduled_policy_change_service.schedule_policy_change(
+                self.request.id,
+                PolicyChange::EditPermission(self.operation.input.to_owned()),
+                effective_from,
+            );
+
+            return Ok(RequestExecuteStage::Completed(
+                self.request.operation.to_owned(),
+            ));
+        }
+
         self.policy_service
             .edit_permission(self.operation.input.to_owned())
             .map_err(|e| RequestExecuteError::Failed {
@@ -81,7 +126,7 @@ mod tests {
     use crate::{
         models::permission::permission_test_utils::mock_permission,
         repositories::{permission::PERMISSION_REPOSITORY, REQUEST_REPOSITORY},
-        services::permission::PERMISSION_SERVICE,
+        services::{permission::PERMISSION_SERVICE, SCHEDULED_POLICY_CHANGE_SERVICE},
     };
     use orbit_essentials::{model::ModelKey, repository::Repository};
 
@@ -140,6 +185,7 @@ mod tests {
                 &request,
                 operation,
                 Arc::clone(&PERMISSION_SERVICE),
+                Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
             )
             .execute()
             .await
@@ -170,6 +216,7 @@ pub mod edit_permission_test_utils {
             auth_scope: None,
             user_groups: None,
             users: Some(vec![Uuid::from_bytes([1u8; 16]).hyphenated().to_string()]),
+            effective_from: None,
         }
     }
 
@@ -181,6 +228,9 @@ pub mod edit_permission_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
         }
     }
 }
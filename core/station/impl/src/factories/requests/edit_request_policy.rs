@@ -2,13 +2,13 @@ use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
     models::{
-        EditRequestPolicyOperation, EditRequestPolicyOperationInput, Request, RequestExecutionPlan,
-        RequestOperation,
+        EditRequestPolicyOperation, EditRequestPolicyOperationInput, PolicyChange, Request,
+        RequestExecutionPlan, RequestOperation, RequestPolicyExpirationInput,
     },
-    services::{RequestPolicyService, REQUEST_POLICY_SERVICE},
+    services::{RequestPolicyService, ScheduledPolicyChangeService, REQUEST_POLICY_SERVICE},
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use orbit_essentials::{types::UUID, utils::rfc3339_to_timestamp};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -23,8 +23,12 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditRequestPolicyOperationInput,
     ) -> Result<Request, RequestError> {
+        let effective_from = operation_input
+            .effective_from
+            .as_ref()
+            .map(|effective_from| rfc3339_to_timestamp(effective_from.as_str()));
         let operation_input = EditRequestPolicyOperationInput::from(operation_input);
-        REQUEST_POLICY_SERVICE
+        let policy = REQUEST_POLICY_SERVICE
             .get_request_policy(&operation_input.policy_id)
             .map_err(|_| RequestError::ValidationError {
                 info: format!(
@@ -38,7 +42,19 @@ impl Create<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyR
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditRequestPolicy(EditRequestPolicyOperation {
+                previous: Some(EditRequestPolicyOperationInput {
+                    policy_id: policy.id,
+                    specifier: operation_input.specifier.as_ref().map(|_| policy.specifier),
+                    rule: operation_input.rule.as_ref().map(|_| policy.rule),
+                    expiration_period_secs: operation_input.expiration_period_secs.as_ref().map(
+                        |_| match policy.expiration_period_secs {
+                            Some(secs) => RequestPolicyExpirationInput::Set(secs),
+                            None => RequestPolicyExpirationInput::Remove,
+                        },
+                    ),
+                }),
                 input: operation_input,
+                effective_from,
             }),
             input
                 .execution_plan
@@ -58,6 +74,7 @@ pub struct EditRequestPolicyRequestExecute<'p, 'o> {
     request: &'p Request,
     operation: &'o EditRequestPolicyOperation,
     policy_service: Arc<RequestPolicyService>,
+    scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
 }
 
 impl<'p, 'o> EditRequestPolicyRequestExecute<'p, 'o> {
@@ -65,11 +82,13 @@ impl<'p, 'o> EditRequestPolicyRequestExecute<'p, 'o> {
         request: &'p Request,
         operation: &'o EditRequestPolicyOperation,
         policy_service: Arc<RequestPolicyService>,
+        scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
     ) -> Self {
         Self {
             request,
             operation,
             policy_service,
+            scheduled_policy_change_service,
         }
     }
 }
@@ -77,6 +96,20 @@ impl<'p, 'o> EditRequestPolicyRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for EditRequestPolicyRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        if let Some(effective_from) =
+            ScheduledPolicyChangeService::deferred_until(self.operation.effective_from)
+        {
+            self.scheduled_policy_change_service.schedule_policy_change(
+                self.request.id,
+                PolicyChange::EditRequestPolicy(self.operation.input.to_owned()),
+                effective_from,
+            );
+
+            return Ok(RequestExecuteStage::Completed(
+                self.request.operation.to_owned(),
+            ));
+        }
+
         self.policy_service
             .edit_request_policy(self.operation.input.to_owned())
             .map_err(|e| RequestExecuteError::Failed {
@@ -95,6 +128,7 @@ mod tests {
     use crate::{
         models::request_policy_test_utils::mock_request_policy,
         repositories::{request_policy::REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY},
+        services::SCHEDULED_POLICY_CHANGE_SERVICE,
     };
     use orbit_essentials::repository::Repository;
     use std::str::FromStr;
@@ -128,6 +162,19 @@ mod tests {
         assert_eq!(request.id, request_id);
         assert_eq!(request.requested_by, requested_by_user);
         assert_eq!(request.title, "Request policy update".to_string());
+
+        match request.operation {
+            RequestOperation::EditRequestPolicy(operation) => assert_eq!(
+                operation.previous,
+                Some(EditRequestPolicyOperationInput {
+                    policy_id: policy.id,
+                    specifier: Some(policy.specifier),
+                    rule: Some(policy.rule),
+                    expiration_period_secs: None,
+                })
+            ),
+            _ => panic!("Expected EditRequestPolicy operation"),
+        }
     }
 
     #[tokio::test]
@@ -167,6 +214,7 @@ mod tests {
                 &request,
                 operation,
                 Arc::clone(&REQUEST_POLICY_SERVICE),
+                Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
             )
             .execute()
             .await
@@ -219,6 +267,7 @@ mod tests {
                 &request,
                 operation,
                 Arc::clone(&REQUEST_POLICY_SERVICE),
+                Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
             )
             .execute()
             .await;
@@ -244,6 +293,8 @@ pub mod edit_request_policy_test_utils {
             specifier: Some(station_api::RequestSpecifierDTO::EditRequestPolicy(
                 station_api::ResourceIdsDTO::Any,
             )),
+            effective_from: None,
+            expiration_period_secs: None,
         }
     }
 
@@ -255,6 +306,9 @@ pub mod edit_request_policy_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
         }
     }
 }
@@ -1,12 +1,32 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditUserOperation, Request, RequestExecutionPlan, RequestOperation},
+    models::{
+        EditUserOperation, EditUserOperationInput, Request, RequestExecutionPlan, RequestOperation,
+    },
     services::USER_SERVICE,
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
 
+/// Snapshots the current values of the user fields that are changed by the input.
+fn snapshot_previous(input: &EditUserOperationInput) -> Option<EditUserOperationInput> {
+    let user = USER_SERVICE.get_user(&input.user_id).ok()?;
+
+    Some(EditUserOperationInput {
+        user_id: user.id,
+        name: input.name.as_ref().map(|_| user.name.clone()),
+        identities: input.identities.as_ref().map(|_| user.identities.clone()),
+        groups: input.groups.as_ref().map(|_| user.groups.clone()),
+        status: input.status.as_ref().map(|_| user.status.clone()),
+        cancel_pending_requests: None,
+        approve_only_identities: input
+            .approve_only_identities
+            .as_ref()
+            .map(|_| user.approve_only_identities.clone()),
+    })
+}
+
 pub struct EditUserRequestCreate {}
 
 #[async_trait]
@@ -18,12 +38,14 @@ impl Create<station_api::EditUserOperationInput> for EditUserRequestCreate {
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditUserOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = EditUserOperationInput::from(operation_input);
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::EditUser(EditUserOperation {
-                input: operation_input.into(),
+                previous: snapshot_previous(&operation_input),
+                input: operation_input,
             }),
             input
                 .execution_plan
@@ -1,12 +1,27 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
-    models::{EditUserGroupOperation, Request, RequestExecutionPlan, RequestOperation},
-    services::USER_GROUP_SERVICE,
+    models::{
+        EditUserGroupOperation, EditUserGroupOperationInput, NotificationType, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::{NOTIFICATION_SERVICE, USER_GROUP_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::types::UUID;
 
+/// Snapshots the current name of the user group that is changed by the input.
+fn snapshot_previous(input: &EditUserGroupOperationInput) -> Option<EditUserGroupOperationInput> {
+    let user_group = USER_GROUP_SERVICE.get(&input.user_group_id).ok()?;
+
+    Some(EditUserGroupOperationInput {
+        user_group_id: user_group.id,
+        name: user_group.name,
+        add_members: vec![],
+        remove_members: vec![],
+    })
+}
+
 pub struct EditUserGroupRequestCreate {}
 
 #[async_trait]
@@ -18,11 +33,15 @@ impl Create<station_api::EditUserGroupOperationInput> for EditUserGroupRequestCr
         input: station_api::CreateRequestInput,
         operation_input: station_api::EditUserGroupOperationInput,
     ) -> Result<Request, RequestError> {
+        let operation_input = EditUserGroupOperationInput::from(operation_input);
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
-            RequestOperation::EditUserGroup(operation_input.into()),
+            RequestOperation::EditUserGroup(EditUserGroupOperation {
+                previous: snapshot_previous(&operation_input),
+                input: operation_input,
+            }),
             input
                 .execution_plan
                 .map(Into::into)
@@ -49,13 +68,36 @@ impl<'p, 'o> EditUserGroupRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for EditUserGroupRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
-        USER_GROUP_SERVICE
+        let user_group = USER_GROUP_SERVICE
             .edit(self.operation.input.clone())
             .await
             .map_err(|e| RequestExecuteError::Failed {
                 reason: format!("Failed to edit user group: {}", e),
             })?;
 
+        // the requests to join or leave a group are mostly made by the members themselves
+        for (user_ids, title) in [
+            (
+                &self.operation.input.add_members,
+                format!("You were added to the user group {}", user_group.name),
+            ),
+            (
+                &self.operation.input.remove_members,
+                format!("You were removed from the user group {}", user_group.name),
+            ),
+        ] {
+            for user_id in user_ids {
+                NOTIFICATION_SERVICE
+                    .send_notification(
+                        *user_id,
+                        NotificationType::SystemMessage,
+                        title.to_owned(),
+                        self.request.summary.to_owned(),
+                    )
+                    .await;
+            }
+        }
+
         Ok(RequestExecuteStage::Completed(
             self.request.operation.clone(),
         ))
@@ -1,15 +1,18 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     errors::{RequestError, RequestExecuteError},
+    factories::blockchains::CkBtc,
     models::{
-        FundExternalCanisterOperation, FundExternalCanisterOperationKind, Request,
+        Account, FundExternalCanisterOperation, FundExternalCanisterOperationKind, Request,
         RequestExecutionPlan, RequestOperation,
     },
+    repositories::ACCOUNT_REPOSITORY,
     services::ExternalCanisterService,
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use orbit_essentials::{repository::Repository, types::UUID};
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct FundExternalCanisterRequestCreate;
 
@@ -63,14 +66,33 @@ impl<'p, 'o> FundExternalCanisterRequestExecute<'p, 'o> {
 impl Execute for FundExternalCanisterRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
         match &self.operation.kind {
-            FundExternalCanisterOperationKind::Send(input) => {
-                self.external_canister_service
-                    .top_up_canister(self.operation.canister_id, input.cycles as u128)
-                    .await
-                    .map_err(|e| RequestExecuteError::Failed {
-                        reason: format!("Failed to fund canister: {}", e),
-                    })?;
-            }
+            FundExternalCanisterOperationKind::Send(input) => match input.from_account_id {
+                Some(from_account_id) => {
+                    let account = ACCOUNT_REPOSITORY
+                        .get(&Account::key(from_account_id))
+                        .ok_or(RequestExecuteError::Failed {
+                            reason: format!(
+                                "Account {} does not exist.",
+                                Uuid::from_bytes(from_account_id).hyphenated()
+                            ),
+                        })?;
+
+                    CkBtc::create(account.network)
+                        .withdraw_cycles(&account, self.operation.canister_id, input.cycles as u128)
+                        .await
+                        .map_err(|e| RequestExecuteError::Failed {
+                            reason: format!("Failed to fund canister: {}", e),
+                        })?;
+                }
+                None => {
+                    self.external_canister_service
+                        .top_up_canister(self.operation.canister_id, input.cycles as u128)
+                        .await
+                        .map_err(|e| RequestExecuteError::Failed {
+                            reason: format!("Failed to fund canister: {}", e),
+                        })?;
+                }
+            },
         }
 
         Ok(RequestExecuteStage::Completed(
@@ -0,0 +1,85 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        ImportAccessPoliciesOperation, ImportAccessPoliciesOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::permission::PermissionService,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+use std::sync::Arc;
+
+pub struct ImportAccessPoliciesRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::ImportAccessPoliciesOperationInput> for ImportAccessPoliciesRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::ImportAccessPoliciesOperationInput,
+    ) -> Result<Request, RequestError> {
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::ImportAccessPolicies(ImportAccessPoliciesOperation {
+                report: None,
+                input: ImportAccessPoliciesOperationInput::from(operation_input),
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Access policies import".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct ImportAccessPoliciesRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o ImportAccessPoliciesOperation,
+    permission_service: Arc<PermissionService>,
+}
+
+impl<'p, 'o> ImportAccessPoliciesRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o ImportAccessPoliciesOperation,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            _request: request,
+            operation,
+            permission_service,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for ImportAccessPoliciesRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let report = self
+            .permission_service
+            .import_access_policies(&self.operation.input.document)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to import the access policies: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.report = Some(report);
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::ImportAccessPolicies(operation),
+        ))
+    }
+}
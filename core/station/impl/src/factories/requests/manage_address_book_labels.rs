@@ -0,0 +1,79 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    models::{
+        ManageAddressBookLabelsOperation, ManageAddressBookLabelsOperationInput, Request,
+        RequestExecutionPlan, RequestOperation,
+    },
+    services::ADDRESS_BOOK_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct ManageAddressBookLabelsRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::ManageAddressBookLabelsOperationInput>
+    for ManageAddressBookLabelsRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::ManageAddressBookLabelsOperationInput,
+    ) -> Result<Request, RequestError> {
+        let operation_input = ManageAddressBookLabelsOperationInput::from(operation_input);
+
+        if operation_input.set_labels.is_empty() && operation_input.remove_labels.is_empty() {
+            return Err(RequestError::ValidationError {
+                info: "At least one label must be set or removed".to_string(),
+            });
+        }
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::ManageAddressBookLabels(ManageAddressBookLabelsOperation {
+                input: operation_input,
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Address book labels update".to_string()),
+            input.summary,
+        );
+
+        Ok(request)
+    }
+}
+
+pub struct ManageAddressBookLabelsRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o ManageAddressBookLabelsOperation,
+}
+
+impl<'p, 'o> ManageAddressBookLabelsRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o ManageAddressBookLabelsOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for ManageAddressBookLabelsRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ADDRESS_BOOK_SERVICE
+            .manage_labels(self.operation.input.to_owned())
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to manage address book labels: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -1,11 +1,23 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
+    core::{
+        ic_cdk::api::id as self_canister_id,
+        validation::{EnsureIdExists, EnsureUserGroup},
+    },
     errors::{RequestError, RequestExecuteError},
-    models::{ManageSystemInfoOperation, Request, RequestExecutionPlan, RequestOperation},
+    mappers::{blockchain::BlockchainMapper, HelperMapper},
+    models::{
+        ApprovalReminders, ArchiveSink, ArchiveSinkChange, FinalityThreshold,
+        ManageSystemInfoOperation, ManageSystemInfoOperationInput, NotificationTemplate,
+        NotificationTemplateChange, Request, RequestCreationRateLimits, RequestExecutionPlan,
+        RequestOperation, RequestPolicy, StableMemoryGuardrail, TransferRetryPolicy, VersionPin,
+    },
     services::SYSTEM_SERVICE,
 };
 use async_trait::async_trait;
-use orbit_essentials::types::UUID;
+use candid::Principal;
+use orbit_essentials::{model::ModelValidator, types::UUID};
+use std::collections::BTreeSet;
 
 pub struct ManageSystemInfoRequestCreate {}
 
@@ -18,12 +30,258 @@ impl Create<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoReq
         input: station_api::CreateRequestInput,
         operation_input: station_api::ManageSystemInfoOperationInput,
     ) -> Result<Request, RequestError> {
+        if let Some(rpc_providers) = &operation_input.rpc_providers {
+            for config in rpc_providers {
+                BlockchainMapper::to_blockchain(config.blockchain.clone()).map_err(|err| {
+                    RequestError::ValidationError {
+                        info: err.to_string(),
+                    }
+                })?;
+            }
+        }
+
+        if let Some(finality_thresholds) = &operation_input.finality_thresholds {
+            for threshold in finality_thresholds {
+                BlockchainMapper::to_blockchain(threshold.blockchain.clone()).map_err(|err| {
+                    RequestError::ValidationError {
+                        info: err.to_string(),
+                    }
+                })?;
+            }
+        }
+
+        if let Some(override_group_id) = operation_input
+            .request_creation_rate_limits
+            .as_ref()
+            .and_then(|rate_limits| rate_limits.override_group_id.clone())
+        {
+            HelperMapper::to_uuid(override_group_id).map_err(|err| {
+                RequestError::ValidationError {
+                    info: err.to_string(),
+                }
+            })?;
+        }
+
+        let operation_input: ManageSystemInfoOperationInput = operation_input.into();
+
+        if let Some(finality_thresholds) = &operation_input.finality_thresholds {
+            let mut blockchains = BTreeSet::new();
+            for threshold in finality_thresholds {
+                if !blockchains.insert(threshold.blockchain.clone()) {
+                    Err(RequestError::ValidationError {
+                        info: format!(
+                            "The finality threshold of {} is set more than once",
+                            threshold.blockchain
+                        ),
+                    })?
+                }
+
+                if threshold.required_confirmations > FinalityThreshold::MAX_REQUIRED_CONFIRMATIONS
+                {
+                    Err(RequestError::ValidationError {
+                        info: format!(
+                            "The finality threshold of {} can not exceed {} confirmations",
+                            threshold.blockchain,
+                            FinalityThreshold::MAX_REQUIRED_CONFIRMATIONS
+                        ),
+                    })?
+                }
+            }
+        }
+
+        if let Some(retry_policy) = &operation_input.transfer_retry_policy {
+            if !(1..=TransferRetryPolicy::MAX_ATTEMPTS).contains(&retry_policy.max_attempts) {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The transfer retry attempts must be between 1 and {}",
+                        TransferRetryPolicy::MAX_ATTEMPTS
+                    ),
+                })?
+            }
+
+            if !(1..=TransferRetryPolicy::MAX_INITIAL_BACKOFF_SECS)
+                .contains(&retry_policy.initial_backoff_secs)
+            {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The transfer retry backoff must be between 1 and {} seconds",
+                        TransferRetryPolicy::MAX_INITIAL_BACKOFF_SECS
+                    ),
+                })?
+            }
+        }
+
+        if let Some(guardrail) = &operation_input.stable_memory_guardrail {
+            if !(StableMemoryGuardrail::MIN_HIGH_WATER_MARK_PERCENT
+                ..=StableMemoryGuardrail::MAX_HIGH_WATER_MARK_PERCENT)
+                .contains(&guardrail.high_water_mark_percent)
+            {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The stable memory high-water mark must be between {}% and {}%",
+                        StableMemoryGuardrail::MIN_HIGH_WATER_MARK_PERCENT,
+                        StableMemoryGuardrail::MAX_HIGH_WATER_MARK_PERCENT
+                    ),
+                })?
+            }
+        }
+
+        if let Some(control_panel_id) = operation_input
+            .approval_reminders
+            .as_ref()
+            .and_then(|approval_reminders| approval_reminders.control_panel_id)
+        {
+            if control_panel_id == Principal::anonymous() || control_panel_id == self_canister_id()
+            {
+                Err(RequestError::ValidationError {
+                    info: "The approval reminders must be forwarded to a control panel canister"
+                        .to_string(),
+                })?
+            }
+        }
+
+        if let Some(lead_time_secs) = operation_input
+            .approval_reminders
+            .as_ref()
+            .and_then(|approval_reminders| approval_reminders.pending_vote_lead_time_secs)
+        {
+            if !(ApprovalReminders::MIN_PENDING_VOTE_LEAD_TIME_SECS
+                ..=ApprovalReminders::MAX_PENDING_VOTE_LEAD_TIME_SECS)
+                .contains(&lead_time_secs)
+            {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The pending vote reminder lead time must be between {} and {} seconds",
+                        ApprovalReminders::MIN_PENDING_VOTE_LEAD_TIME_SECS,
+                        ApprovalReminders::MAX_PENDING_VOTE_LEAD_TIME_SECS
+                    ),
+                })?
+            }
+        }
+
+        if let Some(expiration_secs) = operation_input.default_request_expiration_secs {
+            if !(RequestPolicy::MIN_EXPIRATION_PERIOD_SECS
+                ..=RequestPolicy::MAX_EXPIRATION_PERIOD_SECS)
+                .contains(&expiration_secs)
+            {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The default request expiration must be between {} and {} seconds",
+                        RequestPolicy::MIN_EXPIRATION_PERIOD_SECS,
+                        RequestPolicy::MAX_EXPIRATION_PERIOD_SECS
+                    ),
+                })?
+            }
+        }
+
+        if let Some(thresholds) = &operation_input.cycle_thresholds {
+            if thresholds.min_runtime_secs == 0
+                || thresholds.fund_runtime_secs == 0
+                || thresholds.max_runtime_cycles_fund == 0
+                || thresholds.fallback_fund_cycles == 0
+            {
+                Err(RequestError::ValidationError {
+                    info: "The cycle thresholds must top up the upgrader with some cycles"
+                        .to_string(),
+                })?
+            }
+        }
+
+        if let Some(rpc_providers) = &operation_input.rpc_providers {
+            for config in rpc_providers {
+                config
+                    .validate()
+                    .map_err(|err| RequestError::ValidationError {
+                        info: err.to_string(),
+                    })?;
+            }
+        }
+
+        if let Some(changes) = &operation_input.notification_templates {
+            for change in changes {
+                if let NotificationTemplateChange::Set {
+                    key,
+                    title,
+                    message,
+                } = change
+                {
+                    NotificationTemplate {
+                        key: key.clone(),
+                        title: title.clone(),
+                        message: message.clone(),
+                        last_modification_timestamp: 0,
+                    }
+                    .validate()
+                    .map_err(|err| RequestError::ValidationError {
+                        info: err.to_string(),
+                    })?;
+                }
+            }
+        }
+
+        if let Some(rate_limits) = &operation_input.request_creation_rate_limits {
+            if rate_limits.limits.len() > RequestCreationRateLimits::MAX_LIMITS {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "At most {} request creation rate limits can be configured",
+                        RequestCreationRateLimits::MAX_LIMITS
+                    ),
+                })?
+            }
+
+            if rate_limits
+                .limits
+                .iter()
+                .any(|limit| limit.max_requests == 0)
+            {
+                Err(RequestError::ValidationError {
+                    info: "The request creation rate limits must allow at least one request"
+                        .to_string(),
+                })?
+            }
+
+            if let Some(override_group_id) = &rate_limits.override_group_id {
+                EnsureUserGroup::id_exists(override_group_id)?;
+            }
+        }
+
+        if let Some(VersionPin::Pin(version)) = &operation_input.max_suggested_version {
+            semver::Version::parse(version).map_err(|err| RequestError::ValidationError {
+                info: format!("Invalid max suggested version `{}`: {}", version, err),
+            })?;
+        }
+
+        if let Some(ArchiveSinkChange::Set(sink)) = &operation_input.archive_sink {
+            if let Some(canister_id) = sink.canister_id {
+                if canister_id == Principal::anonymous()
+                    || canister_id == Principal::management_canister()
+                    || canister_id == self_canister_id()
+                {
+                    Err(RequestError::ValidationError {
+                        info: format!("The archive canister {} is not valid", canister_id),
+                    })?
+                }
+            }
+
+            if !(ArchiveSink::MIN_RETENTION_DAYS..=ArchiveSink::MAX_RETENTION_DAYS)
+                .contains(&sink.retention_days)
+            {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The archive retention must be between {} and {} days",
+                        ArchiveSink::MIN_RETENTION_DAYS,
+                        ArchiveSink::MAX_RETENTION_DAYS
+                    ),
+                })?
+            }
+        }
+
         let request = Request::new(
             request_id,
             requested_by_user,
             Request::default_expiration_dt_ns(),
             RequestOperation::ManageSystemInfo(ManageSystemInfoOperation {
-                input: operation_input.into(),
+                input: operation_input,
             }),
             input
                 .execution_plan
@@ -64,10 +322,7 @@ impl Execute for ManageSystemInfoRequestExecute<'_, '_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{
-        core::{read_system_info, test_utils},
-        models::ManageSystemInfoOperationInput,
-    };
+    use crate::core::{read_system_info, test_utils};
     use tests::mnanage_system_info_test_utils::{
         mock_manage_system_info_api_input, mock_request_api_operation,
     };
@@ -96,6 +351,18 @@ mod tests {
                 input: ManageSystemInfoOperationInput {
                     name: Some("name".to_string()),
                     cycle_obtain_strategy: None,
+                    request_operation_limits: None,
+                    rpc_providers: None,
+                    finality_thresholds: None,
+                    transfer_retry_policy: None,
+                    max_suggested_version: None,
+                    archive_sink: None,
+                    stable_memory_guardrail: None,
+                    approval_reminders: None,
+                    notification_templates: None,
+                    request_creation_rate_limits: None,
+                    default_request_expiration_secs: None,
+                    cycle_thresholds: None,
                 },
             })
         );
@@ -104,6 +371,75 @@ mod tests {
         assert_eq!(request.summary, Some("summary".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_create_request_fails_with_invalid_max_suggested_version() {
+        let request_id = *Uuid::new_v4().as_bytes();
+        let requested_by_user = *Uuid::new_v4().as_bytes();
+        let create_request = mock_request_api_operation();
+        let mut input = mock_manage_system_info_api_input();
+        input.max_suggested_version = Some(station_api::VersionPinInput::Pin("1.0".to_string()));
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(request_id, requested_by_user, create_request, input)
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_request_fails_with_invalid_default_request_expiration() {
+        let request_id = *Uuid::new_v4().as_bytes();
+        let requested_by_user = *Uuid::new_v4().as_bytes();
+        let create_request = mock_request_api_operation();
+        let mut input = mock_manage_system_info_api_input();
+        input.default_request_expiration_secs = Some(60);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let result = creator
+            .create(request_id, requested_by_user, create_request, input)
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_execution_sets_default_request_expiration() {
+        test_utils::init_canister_system();
+
+        let request_id = *Uuid::new_v4().as_bytes();
+        let requested_by_user = *Uuid::new_v4().as_bytes();
+        let create_request = mock_request_api_operation();
+        let mut input = mock_manage_system_info_api_input();
+        input.default_request_expiration_secs = Some(24 * 60 * 60);
+
+        let creator = Box::new(ManageSystemInfoRequestCreate {});
+        let request = creator
+            .create(request_id, requested_by_user, create_request, input)
+            .await
+            .unwrap();
+
+        let operation = match &request.operation {
+            RequestOperation::ManageSystemInfo(operation) => operation,
+            _ => panic!("Invalid operation"),
+        };
+
+        ManageSystemInfoRequestExecute::new(&request, operation)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            read_system_info().get_default_request_expiration_secs(),
+            24 * 60 * 60
+        );
+
+        let day_ns = 24 * 60 * 60 * 1_000_000_000;
+        let expiration_dt = Request::default_expiration_dt_ns();
+        assert!(expiration_dt > request.created_timestamp + day_ns);
+        assert!(expiration_dt < request.created_timestamp + 2 * day_ns);
+    }
+
     #[tokio::test]
     async fn test_execution_completed() {
         test_utils::init_canister_system();
@@ -147,6 +483,18 @@ mod mnanage_system_info_test_utils {
         station_api::ManageSystemInfoOperationInput {
             name: Some("name".to_string()),
             cycle_obtain_strategy: None,
+            request_operation_limits: None,
+            rpc_providers: None,
+            finality_thresholds: None,
+            transfer_retry_policy: None,
+            max_suggested_version: None,
+            archive_sink: None,
+            stable_memory_guardrail: None,
+            approval_reminders: None,
+            notification_templates: None,
+            request_creation_rate_limits: None,
+            default_request_expiration_secs: None,
+            cycle_thresholds: None,
         }
     }
 
@@ -158,6 +506,9 @@ mod mnanage_system_info_test_utils {
             operation: station_api::RequestOperationInput::ManageSystemInfo(
                 mock_manage_system_info_api_input(),
             ),
+            confidential: None,
+            tags: None,
+            depends_on: None,
         }
     }
 }
@@ -4,7 +4,8 @@ use crate::{
     models::{Request, RequestOperation},
     services::{
         permission::PERMISSION_SERVICE, CHANGE_CANISTER_SERVICE, DISASTER_RECOVERY_SERVICE,
-        EXTERNAL_CANISTER_SERVICE, REQUEST_POLICY_SERVICE, SYSTEM_SERVICE,
+        EXTERNAL_CANISTER_SERVICE, REQUEST_POLICY_SERVICE, SCHEDULED_POLICY_CHANGE_SERVICE,
+        SCHEDULED_TRANSFER_SERVICE, SYSTEM_SERVICE,
     },
 };
 use async_trait::async_trait;
@@ -18,34 +19,56 @@ use std::sync::Arc;
 
 mod add_account;
 mod add_address_book_entry;
+mod add_asset;
 mod add_request_policy;
+mod add_scheduled_transfer;
+mod add_team;
 mod add_user;
 mod add_user_group;
+mod approve;
 mod call_canister;
 mod change_external_canister;
 mod configure_external_canister;
 mod create_canister;
+mod create_capability_grant;
+mod derive_subaccount;
 mod edit_account;
 mod edit_address_book_entry;
+mod edit_asset;
 mod edit_permission;
 mod edit_request_policy;
 mod edit_user;
 mod edit_user_group;
 mod fund_external_canister;
+mod import_access_policies;
+mod manage_address_book_labels;
 mod manage_system_info;
 mod remove_address_book_entry;
+mod remove_asset;
 mod remove_request_policy;
 mod remove_user_group;
+mod revoke_capability_grant;
+mod set_auto_approval_for_trusted_destinations;
 mod set_disaster_recovery;
+mod split_transfer;
+mod swap_tokens;
+mod sweep_account;
 mod system_upgrade;
 mod transfer;
+mod transfer_nft;
 
 use self::{
     add_account::{AddAccountRequestCreate, AddAccountRequestExecute},
     add_address_book_entry::{AddAddressBookEntryRequestCreate, AddAddressBookEntryRequestExecute},
+    add_asset::{AddAssetRequestCreate, AddAssetRequestExecute},
     add_request_policy::{AddRequestPolicyRequestCreate, AddRequestPolicyRequestExecute},
+    add_scheduled_transfer::{
+        AddScheduledTransferRequestCreate, AddScheduledTransferRequestExecute,
+    },
+    add_team::{AddTeamRequestCreate, AddTeamRequestExecute},
     add_user::{AddUserRequestCreate, AddUserRequestExecute},
     add_user_group::{AddUserGroupRequestCreate, AddUserGroupRequestExecute},
+    approve::{ApproveRequestCreate, ApproveRequestExecute},
     call_canister::{CallExternalCanisterRequestCreate, CallExternalCanisterRequestExecute},
     change_external_canister::{
         ChangeExternalCanisterRequestCreate, ChangeExternalCanisterRequestExecute,
@@ -54,21 +77,44 @@ use self::{
         ConfigureExternalCanisterRequestCreate, ConfigureExternalCanisterRequestExecute,
     },
     create_canister::{CreateExternalCanisterRequestCreate, CreateExternalCanisterRequestExecute},
+    create_capability_grant::{
+        CreateCapabilityGrantRequestCreate, CreateCapabilityGrantRequestExecute,
+    },
+    derive_subaccount::{DeriveSubaccountRequestCreate, DeriveSubaccountRequestExecute},
     edit_account::{EditAccountRequestCreate, EditAccountRequestExecute},
     edit_address_book_entry::{
         EditAddressBookEntryRequestCreate, EditAddressBookEntryRequestExecute,
     },
+    edit_asset::{EditAssetRequestCreate, EditAssetRequestExecute},
     edit_permission::{EditPermissionRequestCreate, EditPermissionRequestExecute},
     edit_request_policy::{EditRequestPolicyRequestCreate, EditRequestPolicyRequestExecute},
     edit_user::{EditUserRequestCreate, EditUserRequestExecute},
     edit_user_group::{EditUserGroupRequestCreate, EditUserGroupRequestExecute},
+    import_access_policies::{
+        ImportAccessPoliciesRequestCreate, ImportAccessPoliciesRequestExecute,
+    },
+    manage_address_book_labels::{
+        ManageAddressBookLabelsRequestCreate, ManageAddressBookLabelsRequestExecute,
+    },
     remove_address_book_entry::{
         RemoveAddressBookEntryRequestCreate, RemoveAddressBookEntryRequestExecute,
     },
+    remove_asset::{RemoveAssetRequestCreate, RemoveAssetRequestExecute},
     remove_request_policy::{RemoveRequestPolicyRequestCreate, RemoveRequestPolicyRequestExecute},
     remove_user_group::{RemoveUserGroupRequestCreate, RemoveUserGroupRequestExecute},
+    revoke_capability_grant::{
+        RevokeCapabilityGrantRequestCreate, RevokeCapabilityGrantRequestExecute,
+    },
+    set_auto_approval_for_trusted_destinations::{
+        SetAutoApprovalForTrustedDestinationsRequestCreate,
+        SetAutoApprovalForTrustedDestinationsRequestExecute,
+    },
+    split_transfer::{SplitTransferRequestCreate, SplitTransferRequestExecute},
+    swap_tokens::{SwapTokensRequestCreate, SwapTokensRequestExecute},
+    sweep_account::{SweepAccountRequestCreate, SweepAccountRequestExecute},
     system_upgrade::{SystemUpgradeRequestCreate, SystemUpgradeRequestExecute},
     transfer::{TransferRequestCreate, TransferRequestExecute},
+    transfer_nft::{TransferNftRequestCreate, TransferNftRequestExecute},
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -106,7 +152,7 @@ impl RequestFactory {
         input: CreateRequestInput,
     ) -> Result<Request, RequestError> {
         let id = *generate_uuid_v4().await.as_bytes();
-        match &input.operation {
+        let mut request = match &input.operation {
             RequestOperationInput::Transfer(operation) => {
                 let creator = Box::new(TransferRequestCreate {});
                 creator
@@ -143,6 +189,24 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::ManageAddressBookLabels(operation) => {
+                let creator = Box::new(ManageAddressBookLabelsRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::CreateCapabilityGrant(operation) => {
+                let creator = Box::new(CreateCapabilityGrantRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::RevokeCapabilityGrant(operation) => {
+                let creator = Box::new(RevokeCapabilityGrantRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddUserGroup(operation) => {
                 let creator = Box::new(AddUserGroupRequestCreate {});
                 creator
@@ -223,6 +287,30 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::ImportAccessPolicies(operation) => {
+                let creator = Box::new(ImportAccessPoliciesRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::AddAsset(operation) => {
+                let creator = Box::new(AddAssetRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::EditAsset(operation) => {
+                let creator = Box::new(EditAssetRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::RemoveAsset(operation) => {
+                let creator = Box::new(RemoveAssetRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
             RequestOperationInput::AddRequestPolicy(operation) => {
                 let creator = Box::new(AddRequestPolicyRequestCreate {});
                 creator
@@ -247,6 +335,84 @@ impl RequestFactory {
                     .create(id, requested_by_user, input.clone(), operation.clone())
                     .await
             }
+            RequestOperationInput::Approve(operation) => {
+                let creator = Box::new(ApproveRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::SetAutoApprovalForTrustedDestinations(operation) => {
+                let creator = Box::new(SetAutoApprovalForTrustedDestinationsRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::TransferNft(operation) => {
+                let creator = Box::new(TransferNftRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::DeriveSubaccount(operation) => {
+                let creator = Box::new(DeriveSubaccountRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::SwapTokens(operation) => {
+                let creator = Box::new(SwapTokensRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::AddScheduledTransfer(operation) => {
+                let creator = Box::new(AddScheduledTransferRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::AddTeam(operation) => {
+                let creator = Box::new(AddTeamRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::SweepAccount(operation) => {
+                let creator = Box::new(SweepAccountRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+            RequestOperationInput::SplitTransfer(operation) => {
+                let creator = Box::new(SplitTransferRequestCreate {});
+                creator
+                    .create(id, requested_by_user, input.clone(), operation.clone())
+                    .await
+            }
+        }?;
+
+        // The policies matching the operation can shorten or extend the default expiration period.
+        if let Some(expiration_dt) = request.policy_expiration_dt() {
+            request.expiration_dt = expiration_dt;
+        }
+
+        Ok(request)
+    }
+
+    /// Runs the validations of the request that require inter-canister calls, returns the operation
+    /// updated with their results.
+    pub async fn validate_request(request: &Request) -> Result<RequestOperation, RequestError> {
+        match &request.operation {
+            RequestOperation::CallExternalCanister(operation) => {
+                let creator = CallExternalCanisterRequestCreate {
+                    external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+                };
+                creator
+                    .validate(operation)
+                    .await
+                    .map(RequestOperation::CallExternalCanister)
+            }
+            operation => Ok(operation.clone()),
         }
     }
 
@@ -255,12 +421,40 @@ impl RequestFactory {
             RequestOperation::Transfer(operation) => {
                 Box::new(TransferRequestExecute::new(request, operation))
             }
+            RequestOperation::Approve(operation) => {
+                Box::new(ApproveRequestExecute::new(request, operation))
+            }
+            RequestOperation::SwapTokens(operation) => {
+                Box::new(SwapTokensRequestExecute::new(request, operation))
+            }
+            RequestOperation::SweepAccount(operation) => {
+                Box::new(SweepAccountRequestExecute::new(request, operation))
+            }
+            RequestOperation::SplitTransfer(operation) => {
+                Box::new(SplitTransferRequestExecute::new(request, operation))
+            }
+            RequestOperation::AddScheduledTransfer(operation) => {
+                Box::new(AddScheduledTransferRequestExecute::new(
+                    request,
+                    operation,
+                    Arc::clone(&SCHEDULED_TRANSFER_SERVICE),
+                ))
+            }
+            RequestOperation::TransferNft(operation) => {
+                Box::new(TransferNftRequestExecute::new(request, operation))
+            }
+            RequestOperation::DeriveSubaccount(operation) => {
+                Box::new(DeriveSubaccountRequestExecute::new(request, operation))
+            }
             RequestOperation::AddAccount(operation) => {
                 Box::new(AddAccountRequestExecute::new(request, operation))
             }
             RequestOperation::EditAccount(operation) => {
                 Box::new(EditAccountRequestExecute::new(request, operation))
             }
+            RequestOperation::SetAutoApprovalForTrustedDestinations(operation) => Box::new(
+                SetAutoApprovalForTrustedDestinationsRequestExecute::new(request, operation),
+            ),
             RequestOperation::AddAddressBookEntry(operation) => {
                 Box::new(AddAddressBookEntryRequestExecute::new(request, operation))
             }
@@ -270,6 +464,15 @@ impl RequestFactory {
             RequestOperation::RemoveAddressBookEntry(operation) => Box::new(
                 RemoveAddressBookEntryRequestExecute::new(request, operation),
             ),
+            RequestOperation::ManageAddressBookLabels(operation) => Box::new(
+                ManageAddressBookLabelsRequestExecute::new(request, operation),
+            ),
+            RequestOperation::CreateCapabilityGrant(operation) => {
+                Box::new(CreateCapabilityGrantRequestExecute::new(request, operation))
+            }
+            RequestOperation::RevokeCapabilityGrant(operation) => {
+                Box::new(RevokeCapabilityGrantRequestExecute::new(request, operation))
+            }
             RequestOperation::AddUserGroup(operation) => {
                 Box::new(AddUserGroupRequestExecute::new(request, operation))
             }
@@ -301,6 +504,7 @@ impl RequestFactory {
                     request,
                     operation,
                     Arc::clone(&CHANGE_CANISTER_SERVICE),
+                    Arc::clone(&EXTERNAL_CANISTER_SERVICE),
                 ))
             }
             RequestOperation::CreateExternalCanister(operation) => {
@@ -336,13 +540,36 @@ impl RequestFactory {
                     request,
                     operation,
                     Arc::clone(&PERMISSION_SERVICE),
+                    Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
                 ))
             }
+            RequestOperation::ImportAccessPolicies(operation) => {
+                Box::new(ImportAccessPoliciesRequestExecute::new(
+                    request,
+                    operation,
+                    Arc::clone(&PERMISSION_SERVICE),
+                ))
+            }
+            RequestOperation::AddTeam(operation) => Box::new(AddTeamRequestExecute::new(
+                request,
+                operation,
+                Arc::clone(&PERMISSION_SERVICE),
+            )),
+            RequestOperation::AddAsset(operation) => {
+                Box::new(AddAssetRequestExecute::new(request, operation))
+            }
+            RequestOperation::EditAsset(operation) => {
+                Box::new(EditAssetRequestExecute::new(request, operation))
+            }
+            RequestOperation::RemoveAsset(operation) => {
+                Box::new(RemoveAssetRequestExecute::new(request, operation))
+            }
             RequestOperation::AddRequestPolicy(operation) => {
                 Box::new(AddRequestPolicyRequestExecute::new(
                     request,
                     operation,
                     Arc::clone(&REQUEST_POLICY_SERVICE),
+                    Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
                 ))
             }
             RequestOperation::EditRequestPolicy(operation) => {
@@ -350,6 +577,7 @@ impl RequestFactory {
                     request,
                     operation,
                     Arc::clone(&REQUEST_POLICY_SERVICE),
+                    Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
                 ))
             }
             RequestOperation::RemoveRequestPolicy(operation) => {
@@ -376,6 +604,9 @@ pub mod requests_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
         }
     }
 }
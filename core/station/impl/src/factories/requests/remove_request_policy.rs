@@ -255,6 +255,9 @@ pub mod remove_request_policy_test_utils {
             title: None,
             summary: None,
             execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
         }
     }
 }
@@ -0,0 +1,82 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        Request, RequestExecutionPlan, RequestOperation, RevokeCapabilityGrantOperation,
+        RevokeCapabilityGrantOperationInput,
+    },
+    services::CAPABILITY_GRANT_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct RevokeCapabilityGrantRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::RevokeCapabilityGrantOperationInput>
+    for RevokeCapabilityGrantRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::RevokeCapabilityGrantOperationInput,
+    ) -> Result<Request, RequestError> {
+        let grant_id = HelperMapper::to_uuid(operation_input.grant_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid grant_id: {}", e),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::RevokeCapabilityGrant(RevokeCapabilityGrantOperation {
+                input: RevokeCapabilityGrantOperationInput {
+                    grant_id: *grant_id.as_bytes(),
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Capability grant revocation".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct RevokeCapabilityGrantRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o RevokeCapabilityGrantOperation,
+}
+
+impl<'p, 'o> RevokeCapabilityGrantRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o RevokeCapabilityGrantOperation) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for RevokeCapabilityGrantRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        CAPABILITY_GRANT_SERVICE
+            .revoke_capability_grant(&self.operation.input.grant_id)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to revoke the capability grant: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    mappers::HelperMapper,
+    models::{
+        validate_trusted_destinations, Request, RequestExecutionPlan, RequestOperation,
+        SetAutoApprovalForTrustedDestinationsOperation,
+        SetAutoApprovalForTrustedDestinationsOperationInput, TrustedDestination,
+    },
+    services::ACCOUNT_SERVICE,
+};
+use async_trait::async_trait;
+use orbit_essentials::types::UUID;
+
+pub struct SetAutoApprovalForTrustedDestinationsRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::SetAutoApprovalForTrustedDestinationsOperationInput>
+    for SetAutoApprovalForTrustedDestinationsRequestCreate
+{
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::SetAutoApprovalForTrustedDestinationsOperationInput,
+    ) -> Result<Request, RequestError> {
+        let account_id = HelperMapper::to_uuid(operation_input.account_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid account_id: {}", e),
+            }
+        })?;
+        let trusted_destinations: Vec<TrustedDestination> = operation_input
+            .trusted_destinations
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        validate_trusted_destinations(&trusted_destinations).map_err(|e| {
+            RequestError::ValidationError {
+                info: e.to_string(),
+            }
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::SetAutoApprovalForTrustedDestinations(
+                SetAutoApprovalForTrustedDestinationsOperation {
+                    input: SetAutoApprovalForTrustedDestinationsOperationInput {
+                        account_id: *account_id.as_bytes(),
+                        trusted_destinations,
+                    },
+                },
+            ),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input
+                .title
+                .unwrap_or_else(|| "Set trusted destinations".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct SetAutoApprovalForTrustedDestinationsRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o SetAutoApprovalForTrustedDestinationsOperation,
+}
+
+impl<'p, 'o> SetAutoApprovalForTrustedDestinationsRequestExecute<'p, 'o> {
+    pub fn new(
+        request: &'p Request,
+        operation: &'o SetAutoApprovalForTrustedDestinationsOperation,
+    ) -> Self {
+        Self { request, operation }
+    }
+}
+
+#[async_trait]
+impl Execute for SetAutoApprovalForTrustedDestinationsRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        ACCOUNT_SERVICE
+            .set_trusted_destinations(
+                &self.operation.input.account_id,
+                self.operation.input.trusted_destinations.clone(),
+            )
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to set the trusted destinations: {}", e),
+            })?;
+
+        Ok(RequestExecuteStage::Completed(
+            self.request.operation.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user, Account},
+        repositories::{ACCOUNT_REPOSITORY, USER_REPOSITORY},
+    };
+    use orbit_essentials::repository::Repository;
+    use station_api::{TrustedDestinationDTO, TrustedDestinationPeriodDTO};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_create_and_execute_set_trusted_destinations_request() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = mock_api_input(&account, vec![("vendor-a", 100), ("vendor-b", 50)]);
+        let request = SetAutoApprovalForTrustedDestinationsRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                station_api::CreateRequestInput {
+                    operation:
+                        station_api::RequestOperationInput::SetAutoApprovalForTrustedDestinations(
+                            operation_input.clone(),
+                        ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        let operation = match &request.operation {
+            RequestOperation::SetAutoApprovalForTrustedDestinations(operation) => operation,
+            _ => panic!("Expected a set auto approval for trusted destinations operation"),
+        };
+
+        assert_eq!(operation.input.account_id, account.id);
+        assert_eq!(operation.input.trusted_destinations.len(), 2);
+
+        let stage = SetAutoApprovalForTrustedDestinationsRequestExecute::new(&request, operation)
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(matches!(stage, RequestExecuteStage::Completed(_)));
+
+        let account = ACCOUNT_REPOSITORY.get(&Account::key(account.id)).unwrap();
+
+        assert_eq!(
+            account.trusted_destinations,
+            operation.input.trusted_destinations
+        );
+    }
+
+    #[tokio::test]
+    async fn fail_create_with_duplicated_destinations() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = mock_api_input(&account, vec![("vendor-a", 100), ("vendor-a", 50)]);
+        let result = SetAutoApprovalForTrustedDestinationsRequestCreate {}
+            .create(
+                [1; 16],
+                mock_user().id,
+                station_api::CreateRequestInput {
+                    operation:
+                        station_api::RequestOperationInput::SetAutoApprovalForTrustedDestinations(
+                            operation_input.clone(),
+                        ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    fn mock_api_input(
+        account: &Account,
+        destinations: Vec<(&str, u64)>,
+    ) -> station_api::SetAutoApprovalForTrustedDestinationsOperationInput {
+        station_api::SetAutoApprovalForTrustedDestinationsOperationInput {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            trusted_destinations: destinations
+                .into_iter()
+                .map(|(address, max_amount)| TrustedDestinationDTO {
+                    address: address.to_string(),
+                    max_amount: candid::Nat::from(max_amount),
+                    period: TrustedDestinationPeriodDTO::Week,
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use super::{transfer::to_transfer_operation_input, Create, Execute, RequestExecuteStage};
+use crate::{
+    core::generate_uuid_v4,
+    errors::{RequestError, RequestExecuteError},
+    factories::blockchains::BlockchainApiFactory,
+    models::{
+        Account, Request, RequestExecutionPlan, RequestOperation, SplitTransferDestination,
+        SplitTransferOperation, SplitTransferOperationInput, SplitTransferShare, Transfer,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+    services::TransferService,
+};
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use orbit_essentials::model::ModelValidator;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use uuid::Uuid;
+
+/// The basis points that the percentages of a split transfer must add up to.
+const TOTAL_BASIS_POINTS: u32 = 10_000;
+
+/// Returns the amount sent to each destination of the split, in their order.
+///
+/// The fixed amounts are paid first and the percentages split what is left of the total, the
+/// rounding remainder goes to the last destination with a percentage so that the whole total is sent.
+fn split_amounts(total: &BigUint, shares: &[SplitTransferShare]) -> Result<Vec<BigUint>, String> {
+    let fixed_total: BigUint = shares
+        .iter()
+        .filter_map(|share| match share {
+            SplitTransferShare::Amount(amount) => Some(amount.0.clone()),
+            SplitTransferShare::BasisPoints(_) => None,
+        })
+        .sum();
+    let basis_points_total: u32 = shares
+        .iter()
+        .filter_map(|share| match share {
+            SplitTransferShare::BasisPoints(basis_points) => Some(u32::from(*basis_points)),
+            SplitTransferShare::Amount(_) => None,
+        })
+        .sum();
+    let last_percentage = shares
+        .iter()
+        .rposition(|share| matches!(share, SplitTransferShare::BasisPoints(_)));
+
+    if &fixed_total > total {
+        return Err(format!(
+            "The fixed amounts add up to {} which exceeds the total amount {}",
+            fixed_total, total
+        ));
+    }
+
+    let remainder = total - &fixed_total;
+
+    match last_percentage {
+        None if remainder != BigUint::from(0u32) => {
+            return Err(format!(
+                "The fixed amounts add up to {} instead of the total amount {}",
+                fixed_total, total
+            ));
+        }
+        Some(_) if basis_points_total != TOTAL_BASIS_POINTS => {
+            return Err(format!(
+                "The percentages add up to {} basis points instead of {}",
+                basis_points_total, TOTAL_BASIS_POINTS
+            ));
+        }
+        _ => {}
+    }
+
+    let mut distributed = BigUint::from(0u32);
+    let amounts = shares
+        .iter()
+        .enumerate()
+        .map(|(position, share)| match share {
+            SplitTransferShare::Amount(amount) => amount.0.clone(),
+            SplitTransferShare::BasisPoints(_) if Some(position) == last_percentage => {
+                &remainder - &distributed
+            }
+            SplitTransferShare::BasisPoints(basis_points) => {
+                let amount = &remainder * BigUint::from(*basis_points) / TOTAL_BASIS_POINTS;
+                distributed += &amount;
+                amount
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(position) = amounts
+        .iter()
+        .position(|amount| *amount == BigUint::from(0u32))
+    {
+        return Err(format!(
+            "The destination at position {} would receive nothing",
+            position
+        ));
+    }
+
+    Ok(amounts)
+}
+
+fn shares_of(destinations: &[SplitTransferDestination]) -> Vec<SplitTransferShare> {
+    destinations
+        .iter()
+        .map(|destination| destination.share.clone())
+        .collect()
+}
+
+pub struct SplitTransferRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::SplitTransferOperationInput> for SplitTransferRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::SplitTransferOperationInput,
+    ) -> Result<Request, RequestError> {
+        if operation_input.destinations.is_empty()
+            || operation_input.destinations.len() > SplitTransferOperationInput::MAX_DESTINATIONS
+        {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "A split transfer must have between 1 and {} destinations",
+                    SplitTransferOperationInput::MAX_DESTINATIONS
+                ),
+            });
+        }
+
+        // each destination is validated as a transfer of its own, the network and memo are
+        // shared by all of them
+        let mut transfer_inputs = operation_input
+            .destinations
+            .iter()
+            .map(|destination| {
+                to_transfer_operation_input(station_api::TransferOperationInput {
+                    from_account_id: operation_input.from_account_id.clone(),
+                    to: destination.to.clone(),
+                    amount: candid::Nat::from(0u64),
+                    fee: None,
+                    metadata: operation_input.metadata.clone(),
+                    network: operation_input.network.clone(),
+                    spend_from: None,
+                    memo: operation_input.memo.clone(),
+                    category: None,
+                    asset_id: None,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let transfer_input = transfer_inputs.remove(0);
+
+        let destinations: Vec<SplitTransferDestination> = operation_input
+            .destinations
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        split_amounts(&operation_input.total_amount.0, &shares_of(&destinations))
+            .map_err(|info| RequestError::ValidationError { info })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::SplitTransfer(SplitTransferOperation {
+                transfer_ids: Vec::new(),
+                fee: None,
+                input: SplitTransferOperationInput {
+                    from_account_id: transfer_input.from_account_id,
+                    total_amount: operation_input.total_amount,
+                    destinations,
+                    metadata: transfer_input.metadata,
+                    network: transfer_input.network,
+                    memo: transfer_input.memo,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Split transfer".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct SplitTransferRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o SplitTransferOperation,
+    transfer_service: TransferService,
+}
+
+impl<'p, 'o> SplitTransferRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o SplitTransferOperation) -> Self {
+        Self {
+            request,
+            operation,
+            transfer_service: TransferService::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for SplitTransferRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let account = ACCOUNT_REPOSITORY
+            .get(&Account::key(self.operation.input.from_account_id))
+            .ok_or(RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not exist.",
+                    Uuid::from_bytes(self.operation.input.from_account_id).hyphenated()
+                ),
+            })?;
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to build blockchain api: {}", e),
+            })?;
+
+        let transaction_fee = blockchain_api
+            .transaction_fee(&account)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to fetch transaction fee: {}", e),
+            })?;
+
+        let amounts = split_amounts(
+            &self.operation.input.total_amount.0,
+            &shares_of(&self.operation.input.destinations),
+        )
+        .map_err(|reason| RequestExecuteError::Failed { reason })?;
+
+        let mut transfers = Vec::with_capacity(amounts.len());
+        for (destination, amount) in self.operation.input.destinations.iter().zip(amounts) {
+            let mut transfer = Transfer::new(
+                self.request.id,
+                *generate_uuid_v4().await.as_bytes(),
+                self.request.requested_by,
+                self.operation.input.from_account_id,
+                destination.to.clone(),
+                self.operation.input.metadata.clone(),
+                candid::Nat(amount),
+                candid::Nat(transaction_fee.fee.clone()),
+                self.operation.input.network.clone(),
+            );
+            transfer.memo = self.operation.input.memo.clone();
+
+            transfer
+                .validate()
+                .map_err(|e| RequestExecuteError::Failed {
+                    reason: format!("Failed to validate transfer: {}", e),
+                })?;
+
+            transfers.push(transfer);
+        }
+
+        // the transfers are only queued once all of them are valid, they are then submitted one
+        // after the other as they share the account they are sent from
+        let mut transfer_ids = Vec::with_capacity(transfers.len());
+        for transfer in transfers {
+            let transfer = self.transfer_service.add_transfer(transfer).map_err(|e| {
+                RequestExecuteError::Failed {
+                    reason: format!("Failed to add transfer: {}", e),
+                }
+            })?;
+
+            transfer_ids.push(transfer.id);
+        }
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::SplitTransfer(ref mut operation) = operation {
+            operation.transfer_ids = transfer_ids;
+            operation.fee = Some(candid::Nat(transaction_fee.fee));
+        }
+
+        Ok(RequestExecuteStage::Processing(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_test_utils::mock_account;
+
+    fn split_input(account_id: &UUID) -> station_api::SplitTransferOperationInput {
+        station_api::SplitTransferOperationInput {
+            from_account_id: Uuid::from_bytes(*account_id).hyphenated().to_string(),
+            total_amount: candid::Nat::from(1_000u64),
+            destinations: vec![
+                station_api::SplitTransferDestinationDTO {
+                    to: "alice".to_string(),
+                    share: station_api::SplitTransferShareDTO::Amount(candid::Nat::from(100u64)),
+                },
+                station_api::SplitTransferDestinationDTO {
+                    to: "bob".to_string(),
+                    share: station_api::SplitTransferShareDTO::BasisPoints(7_000),
+                },
+                station_api::SplitTransferDestinationDTO {
+                    to: "carol".to_string(),
+                    share: station_api::SplitTransferShareDTO::BasisPoints(3_000),
+                },
+            ],
+            network: None,
+            metadata: vec![],
+            memo: None,
+        }
+    }
+
+    fn create_input(
+        operation_input: &station_api::SplitTransferOperationInput,
+    ) -> station_api::CreateRequestInput {
+        station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::SplitTransfer(operation_input.clone()),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_request() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = split_input(&account.id);
+
+        let request = SplitTransferRequestCreate {}
+            .create(
+                [0; 16],
+                [1; 16],
+                create_input(&operation_input),
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.title, "Split transfer".to_string());
+
+        let RequestOperation::SplitTransfer(operation) = &request.operation else {
+            panic!("Unexpected operation");
+        };
+        assert_eq!(operation.input.from_account_id, account.id);
+        assert_eq!(operation.input.destinations.len(), 3);
+        assert!(operation.transfer_ids.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_request_fails_when_the_percentages_do_not_add_up() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut operation_input = split_input(&account.id);
+        operation_input.destinations[2].share =
+            station_api::SplitTransferShareDTO::BasisPoints(2_000);
+
+        let result = SplitTransferRequestCreate {}
+            .create(
+                [0; 16],
+                [1; 16],
+                create_input(&operation_input),
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    #[test]
+    fn splits_the_remainder_of_the_fixed_amounts_by_percentage() {
+        let amounts = split_amounts(
+            &BigUint::from(1_001u64),
+            &[
+                SplitTransferShare::Amount(candid::Nat::from(100u64)),
+                SplitTransferShare::BasisPoints(3_333),
+                SplitTransferShare::BasisPoints(6_667),
+            ],
+        )
+        .unwrap();
+
+        // the rounding remainder goes to the last percentage
+        assert_eq!(
+            amounts,
+            vec![
+                BigUint::from(100u64),
+                BigUint::from(300u64),
+                BigUint::from(601u64)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_splits_that_do_not_add_up_to_the_total() {
+        let total = BigUint::from(1_000u64);
+
+        assert!(split_amounts(
+            &total,
+            &[
+                SplitTransferShare::BasisPoints(5_000),
+                SplitTransferShare::BasisPoints(4_000)
+            ]
+        )
+        .is_err());
+        assert!(split_amounts(
+            &total,
+            &[
+                SplitTransferShare::Amount(candid::Nat::from(600u64)),
+                SplitTransferShare::Amount(candid::Nat::from(300u64))
+            ]
+        )
+        .is_err());
+        assert!(split_amounts(
+            &total,
+            &[
+                SplitTransferShare::Amount(candid::Nat::from(1_100u64)),
+                SplitTransferShare::BasisPoints(10_000)
+            ]
+        )
+        .is_err());
+        // the fixed amounts leave nothing for the percentage
+        assert!(split_amounts(
+            &total,
+            &[
+                SplitTransferShare::Amount(candid::Nat::from(1_000u64)),
+                SplitTransferShare::BasisPoints(10_000)
+            ]
+        )
+        .is_err());
+        assert!(split_amounts(
+            &total,
+            &[
+                SplitTransferShare::Amount(candid::Nat::from(400u64)),
+                SplitTransferShare::Amount(candid::Nat::from(600u64))
+            ]
+        )
+        .is_ok());
+    }
+}
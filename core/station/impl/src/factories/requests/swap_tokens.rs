@@ -0,0 +1,359 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    core::ic_cdk::next_time,
+    errors::{DexError, RequestError, RequestExecuteError},
+    factories::{
+        blockchains::BlockchainApiFactory,
+        dex::{DexAdapterFactory, DexSwapArgs, DexSwapAsset},
+    },
+    mappers::HelperMapper,
+    models::{
+        Account, AccountId, Dex, Request, RequestExecutionPlan, RequestOperation,
+        SwapTokensOperation, SwapTokensOperationInput, SwapTokensResult,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+};
+use async_trait::async_trait;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use uuid::Uuid;
+
+pub struct SwapTokensRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::SwapTokensOperationInput> for SwapTokensRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::SwapTokensOperationInput,
+    ) -> Result<Request, RequestError> {
+        let from_account_id =
+            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid from_account_id: {}", e),
+                }
+            })?;
+        let to_account_id = HelperMapper::to_uuid(operation_input.to_account_id).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid to_account_id: {}", e),
+            }
+        })?;
+
+        let operation_input = SwapTokensOperationInput {
+            from_account_id: *from_account_id.as_bytes(),
+            to_account_id: *to_account_id.as_bytes(),
+            dex: operation_input.dex.into(),
+            pool_canister_id: operation_input.pool_canister_id,
+            amount_in: operation_input.amount_in,
+            expected_amount_out: operation_input.expected_amount_out,
+            max_slippage_bps: operation_input.max_slippage_bps,
+        };
+
+        validate_swap(&operation_input)?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::SwapTokens(SwapTokensOperation {
+                result: None,
+                input: operation_input,
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Swap tokens".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+fn validate_swap(input: &SwapTokensOperationInput) -> Result<(), RequestError> {
+    if input.from_account_id == input.to_account_id {
+        return Err(RequestError::ValidationError {
+            info: "The swap must be between two different accounts".to_string(),
+        });
+    }
+
+    if input.amount_in == candid::Nat::from(0_u64) {
+        return Err(RequestError::ValidationError {
+            info: "The amount to swap must be greater than zero".to_string(),
+        });
+    }
+
+    if input.max_slippage_bps > SwapTokensOperationInput::MAX_SLIPPAGE_BPS {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "The maximum slippage cannot be more than {} basis points",
+                SwapTokensOperationInput::MAX_SLIPPAGE_BPS
+            ),
+        });
+    }
+
+    if input.dex == Dex::ICPSwap && input.pool_canister_id.is_none() {
+        return Err(RequestError::ValidationError {
+            info: DexError::PoolRequired {
+                dex: input.dex.to_string(),
+            }
+            .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+pub struct SwapTokensRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o SwapTokensOperation,
+}
+
+impl<'p, 'o> SwapTokensRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o SwapTokensOperation) -> Self {
+        Self {
+            _request: request,
+            operation,
+        }
+    }
+
+    async fn swap_asset(account_id: &AccountId) -> Result<DexSwapAsset, RequestExecuteError> {
+        let account = ACCOUNT_REPOSITORY.get(&Account::key(*account_id)).ok_or(
+            RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not exist.",
+                    Uuid::from_bytes(*account_id).hyphenated()
+                ),
+            },
+        )?;
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to build blockchain api: {}", e),
+            })?;
+
+        // only the ledger accounts of the station can be swapped on the DEXes
+        let ledger_account = blockchain_api.allowance_spender(&account).map_err(|e| {
+            RequestExecuteError::Failed {
+                reason: format!("The account {} cannot be swapped: {}", account.name, e),
+            }
+        })?;
+        let fee = blockchain_api
+            .transaction_fee(&account)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to fetch the transaction fee: {}", e),
+            })?;
+
+        Ok(DexSwapAsset {
+            ledger_canister_id: ledger_account.ledger_canister_id,
+            account: ledger_account.spender,
+            fee: candid::Nat(fee.fee),
+        })
+    }
+}
+
+#[async_trait]
+impl Execute for SwapTokensRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let input = &self.operation.input;
+        let args = DexSwapArgs {
+            from: Self::swap_asset(&input.from_account_id).await?,
+            to: Self::swap_asset(&input.to_account_id).await?,
+            amount_in: input.amount_in.clone(),
+            expected_amount_out: input.expected_amount_out.clone(),
+            max_slippage_bps: input.max_slippage_bps,
+            min_amount_out: input.min_amount_out(),
+        };
+
+        let dex = DexAdapterFactory::build(&input.dex, input.pool_canister_id).map_err(|e| {
+            RequestExecuteError::Failed {
+                reason: e.to_string(),
+            }
+        })?;
+
+        // the price may have moved since the request was approved, the swap is only executed if the
+        // current quote is still within the slippage that the approvers agreed to
+        let quote = dex
+            .quote(&args)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: e.to_string(),
+            })?;
+
+        if quote < args.min_amount_out {
+            return Err(RequestExecuteError::Failed {
+                reason: DexError::SlippageExceeded {
+                    min_amount_out: args.min_amount_out.to_string(),
+                    quote: quote.to_string(),
+                }
+                .to_string(),
+            });
+        }
+
+        let receipt = dex
+            .swap(&args)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: e.to_string(),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.result = Some(SwapTokensResult {
+            amount_in: receipt.amount_in,
+            amount_out: receipt.amount_out,
+            executed_at: next_time(),
+        });
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::SwapTokens(operation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user},
+        repositories::USER_REPOSITORY,
+    };
+    use candid::Principal;
+
+    #[tokio::test]
+    async fn test_create_swap_tokens_request() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let (from_account, to_account) = mock_accounts();
+
+        let operation_input = mock_swap_api_input(&from_account, &to_account);
+        let request = SwapTokensRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                mock_create_request_input(operation_input.clone()),
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        match request.operation {
+            RequestOperation::SwapTokens(operation) => {
+                assert_eq!(operation.result, None);
+                assert_eq!(operation.input.from_account_id, from_account.id);
+                assert_eq!(operation.input.to_account_id, to_account.id);
+                assert_eq!(operation.input.dex, Dex::KongSwap);
+                assert_eq!(operation.input.min_amount_out(), candid::Nat::from(990_u64));
+            }
+            _ => panic!("Expected a swap tokens operation"),
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_create_swap_tokens_request_with_invalid_input() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let (from_account, to_account) = mock_accounts();
+
+        let mut same_account = mock_swap_api_input(&from_account, &to_account);
+        same_account.to_account_id = same_account.from_account_id.clone();
+
+        let mut too_much_slippage = mock_swap_api_input(&from_account, &to_account);
+        too_much_slippage.max_slippage_bps = SwapTokensOperationInput::MAX_SLIPPAGE_BPS + 1;
+
+        let mut missing_pool = mock_swap_api_input(&from_account, &to_account);
+        missing_pool.dex = station_api::DexDTO::ICPSwap;
+
+        let mut nothing_to_swap = mock_swap_api_input(&from_account, &to_account);
+        nothing_to_swap.amount_in = candid::Nat::from(0_u64);
+
+        for operation_input in [
+            same_account,
+            too_much_slippage,
+            missing_pool,
+            nothing_to_swap,
+        ] {
+            let result = SwapTokensRequestCreate {}
+                .create(
+                    [1; 16],
+                    user.id,
+                    mock_create_request_input(operation_input.clone()),
+                    operation_input,
+                )
+                .await;
+
+            assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+        }
+    }
+
+    #[test]
+    fn min_amount_out_applies_the_max_slippage() {
+        let (from_account, to_account) = mock_accounts();
+        let mut input = SwapTokensOperationInput {
+            from_account_id: from_account.id,
+            to_account_id: to_account.id,
+            dex: Dex::ICPSwap,
+            pool_canister_id: Some(Principal::from_slice(&[3; 10])),
+            amount_in: candid::Nat::from(1_000_u64),
+            expected_amount_out: candid::Nat::from(2_000_u64),
+            max_slippage_bps: 0,
+        };
+
+        assert_eq!(input.min_amount_out(), candid::Nat::from(2_000_u64));
+
+        input.max_slippage_bps = 250;
+        assert_eq!(input.min_amount_out(), candid::Nat::from(1_950_u64));
+    }
+
+    fn mock_accounts() -> (Account, Account) {
+        let from_account = mock_account();
+        let mut to_account = mock_account();
+        to_account.id = [2; 16];
+        to_account.name = "Swap destination".to_string();
+
+        ACCOUNT_REPOSITORY.insert(from_account.to_key(), from_account.clone());
+        ACCOUNT_REPOSITORY.insert(to_account.to_key(), to_account.clone());
+
+        (from_account, to_account)
+    }
+
+    fn mock_create_request_input(
+        operation_input: station_api::SwapTokensOperationInput,
+    ) -> station_api::CreateRequestInput {
+        station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::SwapTokens(operation_input),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        }
+    }
+
+    fn mock_swap_api_input(
+        from_account: &Account,
+        to_account: &Account,
+    ) -> station_api::SwapTokensOperationInput {
+        station_api::SwapTokensOperationInput {
+            from_account_id: Uuid::from_bytes(from_account.id).hyphenated().to_string(),
+            to_account_id: Uuid::from_bytes(to_account.id).hyphenated().to_string(),
+            dex: station_api::DexDTO::KongSwap,
+            pool_canister_id: None,
+            amount_in: candid::Nat::from(1_000_u64),
+            expected_amount_out: candid::Nat::from(1_000_u64),
+            max_slippage_bps: 100,
+        }
+    }
+}
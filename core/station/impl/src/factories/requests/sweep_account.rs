@@ -0,0 +1,231 @@
+use super::{transfer::to_transfer_operation_input, Create, Execute, RequestExecuteStage};
+use crate::{
+    core::generate_uuid_v4,
+    errors::{RequestError, RequestExecuteError},
+    factories::blockchains::BlockchainApiFactory,
+    models::{
+        Account, Request, RequestExecutionPlan, RequestOperation, SweepAccountOperation,
+        SweepAccountOperationInput, Transfer,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+    services::TransferService,
+};
+use async_trait::async_trait;
+use num_bigint::BigUint;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use uuid::Uuid;
+
+/// Returns the amount left to sweep once the fee is paid, if any.
+fn sweep_amount(balance: &BigUint, fee: &BigUint) -> Option<BigUint> {
+    if balance > fee {
+        Some(balance - fee)
+    } else {
+        None
+    }
+}
+
+pub struct SweepAccountRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::SweepAccountOperationInput> for SweepAccountRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::SweepAccountOperationInput,
+    ) -> Result<Request, RequestError> {
+        // the destination, network and memo are validated as for a transfer, the amount is only
+        // known when the request is executed
+        let transfer_input = to_transfer_operation_input(station_api::TransferOperationInput {
+            from_account_id: operation_input.from_account_id,
+            to: operation_input.to,
+            amount: candid::Nat::from(0u64),
+            fee: None,
+            metadata: operation_input.metadata,
+            network: operation_input.network,
+            spend_from: None,
+            memo: operation_input.memo,
+            category: None,
+            asset_id: None,
+        })?;
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::SweepAccount(SweepAccountOperation {
+                transfer_id: None,
+                amount: None,
+                fee: None,
+                input: SweepAccountOperationInput {
+                    from_account_id: transfer_input.from_account_id,
+                    to: transfer_input.to,
+                    metadata: transfer_input.metadata,
+                    network: transfer_input.network,
+                    memo: transfer_input.memo,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Sweep account".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct SweepAccountRequestExecute<'p, 'o> {
+    request: &'p Request,
+    operation: &'o SweepAccountOperation,
+    transfer_service: TransferService,
+}
+
+impl<'p, 'o> SweepAccountRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o SweepAccountOperation) -> Self {
+        Self {
+            request,
+            operation,
+            transfer_service: TransferService::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for SweepAccountRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let account = ACCOUNT_REPOSITORY
+            .get(&Account::key(self.operation.input.from_account_id))
+            .ok_or(RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not exist.",
+                    Uuid::from_bytes(self.operation.input.from_account_id).hyphenated()
+                ),
+            })?;
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to build blockchain api: {}", e),
+            })?;
+
+        // both are fetched now rather than when the request was created, so that the balance
+        // received or spent in between is swept as well
+        let balance =
+            blockchain_api
+                .balance(&account)
+                .await
+                .map_err(|e| RequestExecuteError::Failed {
+                    reason: format!("Failed to fetch account balance: {}", e),
+                })?;
+        let transaction_fee = blockchain_api
+            .transaction_fee(&account)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to fetch transaction fee: {}", e),
+            })?;
+
+        let amount =
+            sweep_amount(&balance, &transaction_fee.fee).ok_or(RequestExecuteError::Failed {
+                reason: format!(
+                    "The balance {} does not cover the transaction fee {}",
+                    balance, transaction_fee.fee
+                ),
+            })?;
+
+        let mut transfer = Transfer::new(
+            self.request.id,
+            *generate_uuid_v4().await.as_bytes(),
+            self.request.requested_by,
+            self.operation.input.from_account_id,
+            self.operation.input.to.clone(),
+            self.operation.input.metadata.clone(),
+            candid::Nat(amount.clone()),
+            candid::Nat(transaction_fee.fee.clone()),
+            self.operation.input.network.clone(),
+        );
+        transfer.memo = self.operation.input.memo.clone();
+        let transfer_id = transfer.id;
+
+        self.transfer_service
+            .add_transfer(transfer)
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to validate transfer: {}", e),
+            })?;
+
+        let mut operation = self.request.operation.clone();
+
+        if let RequestOperation::SweepAccount(ref mut operation) = operation {
+            operation.transfer_id = Some(transfer_id);
+            operation.amount = Some(candid::Nat(amount));
+            operation.fee = Some(candid::Nat(transaction_fee.fee));
+        }
+
+        Ok(RequestExecuteStage::Processing(operation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_test_utils::mock_account;
+
+    #[tokio::test]
+    async fn test_create_request() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = station_api::SweepAccountOperationInput {
+            from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            to: "destination".to_string(),
+            network: None,
+            metadata: vec![],
+            memo: None,
+        };
+        let request_input = station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::SweepAccount(operation_input.clone()),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let request = SweepAccountRequestCreate {}
+            .create([0; 16], [1; 16], request_input, operation_input)
+            .await
+            .unwrap();
+
+        assert_eq!(request.title, "Sweep account".to_string());
+
+        let RequestOperation::SweepAccount(operation) = &request.operation else {
+            panic!("Unexpected operation");
+        };
+        assert_eq!(operation.input.from_account_id, account.id);
+        assert_eq!(operation.input.network, account.network.to_string());
+        assert!(operation.amount.is_none());
+    }
+
+    #[test]
+    fn the_fee_is_deducted_from_the_swept_balance() {
+        assert_eq!(
+            sweep_amount(&BigUint::from(1_000u64), &BigUint::from(10u64)),
+            Some(BigUint::from(990u64))
+        );
+        assert_eq!(
+            sweep_amount(&BigUint::from(10u64), &BigUint::from(10u64)),
+            None
+        );
+        assert_eq!(
+            sweep_amount(&BigUint::from(0u64), &BigUint::from(10u64)),
+            None
+        );
+    }
+}
@@ -1,26 +1,146 @@
 use super::{Create, Execute, RequestExecuteStage};
 use crate::{
     core::generate_uuid_v4,
-    errors::{RequestError, RequestExecuteError},
+    errors::{RequestError, RequestExecuteError, TransferError},
     factories::blockchains::BlockchainApiFactory,
     mappers::HelperMapper,
     models::{
-        Account, Metadata, Request, RequestExecutionPlan, RequestOperation, Transfer,
-        TransferOperation, TransferOperationInput,
+        Account, IcrcAccount, Metadata, NetworkProfile, Request, RequestExecutionPlan,
+        RequestOperation, Transfer, TransferMemo, TransferOperation, TransferOperationInput,
     },
-    repositories::ACCOUNT_REPOSITORY,
-    services::TransferService,
+    repositories::{ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY},
+    services::{TransferService, TRANSFER_CATEGORY_SERVICE},
 };
 use async_trait::async_trait;
 use orbit_essentials::model::ModelValidator;
 use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
+use std::str::FromStr;
 use uuid::Uuid;
 
 fn get_account(from_account_id: &UUID) -> Option<Account> {
     ACCOUNT_REPOSITORY.get(&Account::key(*from_account_id))
 }
 
+/// Maps the transfer of a request to the model, validating it against the account it is sent from.
+pub(super) fn to_transfer_operation_input(
+    operation_input: station_api::TransferOperationInput,
+) -> Result<TransferOperationInput, RequestError> {
+    let from_account_id = HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
+        RequestError::ValidationError {
+            info: format!("Invalid from_account_id: {}", e),
+        }
+    })?;
+    let spend_from = operation_input
+        .spend_from
+        .map(|account| {
+            IcrcAccount::from_str(&account).map_err(|e| RequestError::ValidationError {
+                info: format!("Invalid spend_from account: {}", e),
+            })
+        })
+        .transpose()?;
+    let memo: Option<TransferMemo> = operation_input.memo.map(Into::into);
+    let metadata = Metadata::from(operation_input.metadata);
+    Transfer::validate_metadata(&metadata).map_err(|e| RequestError::ValidationError {
+        info: match e {
+            TransferError::ValidationError { info } => info,
+            e => e.to_string(),
+        },
+    })?;
+    let asset_id = operation_input
+        .asset_id
+        .map(|asset_id| {
+            HelperMapper::to_uuid(asset_id)
+                .map(|asset_id| *asset_id.as_bytes())
+                .map_err(|e| RequestError::ValidationError {
+                    info: format!("Invalid asset_id: {}", e),
+                })
+        })
+        .transpose()?;
+    // the transfers of a held asset are validated against the account as the holder of the asset
+    let account = get_account(from_account_id.as_bytes())
+        .map(|account| account.for_asset(asset_id.as_ref()))
+        .transpose()
+        .map_err(|e| RequestError::ValidationError {
+            info: e.to_string(),
+        })?;
+
+    if let (Some(memo), Some(account)) = (&memo, &account) {
+        memo.validate_for(&account.blockchain, &account.standard)
+            .map_err(|e| RequestError::ValidationError {
+                info: match e {
+                    TransferError::ValidationError { info } => info,
+                    e => e.to_string(),
+                },
+            })?;
+    }
+
+    // exchange deposit addresses credit the funds to the account identified by the memo
+    if let (None, Some(account)) = (&memo, &account) {
+        let requires_memo = ADDRESS_BOOK_REPOSITORY
+            .find_by_address(account.blockchain.clone(), operation_input.to.clone())
+            .is_some_and(|entry| entry.requires_memo);
+
+        if requires_memo {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The destination `{}` requires a memo, as set in its address book entry",
+                    operation_input.to
+                ),
+            });
+        }
+    }
+
+    // only the registered categories can be booked, so that the spending is exported consistently
+    if let Some(category) = &operation_input.category {
+        TRANSFER_CATEGORY_SERVICE
+            .ensure_registered(category)
+            .map_err(|e| RequestError::ValidationError {
+                info: e.to_string(),
+            })?;
+    }
+
+    // the transfer is sent on the network of the account unless a matching one is given
+    let account_network = account
+        .as_ref()
+        .map(|account| account.network)
+        .unwrap_or_default();
+    let network = match operation_input.network {
+        Some(network) => {
+            let profile = NetworkProfile::from_str(&network.id).map_err(|_| {
+                RequestError::ValidationError {
+                    info: format!("Unknown network `{}`", network.id),
+                }
+            })?;
+
+            if profile != account_network {
+                return Err(RequestError::ValidationError {
+                    info: format!(
+                        "The network `{}` does not match the account network `{}`",
+                        profile, account_network
+                    ),
+                });
+            }
+
+            profile
+        }
+        None => account_network,
+    };
+
+    Ok(TransferOperationInput {
+        from_account_id: *from_account_id.as_bytes(),
+        to: operation_input.to,
+        amount: operation_input.amount,
+        fee: operation_input.fee,
+        metadata,
+        network: network.to_string(),
+        spend_from,
+        memo,
+        category: operation_input.category,
+        asset_id,
+    })
+}
+
 pub struct TransferRequestCreate {}
 
 #[async_trait]
@@ -32,12 +152,6 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
         input: station_api::CreateRequestInput,
         operation_input: station_api::TransferOperationInput,
     ) -> Result<Request, RequestError> {
-        let from_account_id =
-            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
-                RequestError::ValidationError {
-                    info: format!("Invalid from_account_id: {}", e),
-                }
-            })?;
         let request = Request::new(
             request_id,
             requested_by_user,
@@ -45,19 +159,7 @@ impl Create<station_api::TransferOperationInput> for TransferRequestCreate {
             RequestOperation::Transfer(TransferOperation {
                 transfer_id: None,
                 fee: None,
-                input: TransferOperationInput {
-                    from_account_id: *from_account_id.as_bytes(),
-                    to: operation_input.to,
-                    amount: operation_input.amount,
-                    fee: operation_input.fee,
-                    // todo: add metadata mapping
-                    metadata: Metadata::default(),
-                    // todo: add network mapping
-                    network: match operation_input.network {
-                        Some(network) => network.id,
-                        None => "mainnet".to_string(),
-                    },
-                },
+                input: to_transfer_operation_input(operation_input)?,
             }),
             input
                 .execution_plan
@@ -92,17 +194,21 @@ impl<'p, 'o> TransferRequestExecute<'p, 'o> {
 #[async_trait]
 impl Execute for TransferRequestExecute<'_, '_> {
     async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
-        let account = get_account(&self.operation.input.from_account_id).ok_or(
-            RequestExecuteError::Failed {
+        let account = get_account(&self.operation.input.from_account_id)
+            .ok_or(RequestExecuteError::Failed {
                 reason: format!(
                     "Account {} does not exist.",
                     Uuid::from_bytes(self.operation.input.from_account_id).hyphenated()
                 ),
-            },
-        )?;
-
-        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)
+            })?
+            .for_asset(self.operation.input.asset_id.as_ref())
             .map_err(|e| RequestExecuteError::Failed {
+                reason: e.to_string(),
+            })?;
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| RequestExecuteError::Failed {
                 reason: format!("Failed to build blockchain api: {}", e),
             })?;
         let fee = match &self.operation.input.fee {
@@ -120,18 +226,24 @@ impl Execute for TransferRequestExecute<'_, '_> {
             }
         };
 
+        let mut transfer = Transfer::new(
+            self.request.id,
+            *generate_uuid_v4().await.as_bytes(),
+            self.request.requested_by,
+            self.operation.input.from_account_id,
+            self.operation.input.to.clone(),
+            self.operation.input.metadata.clone(),
+            self.operation.input.amount.clone(),
+            fee,
+            self.operation.input.network.clone(),
+        );
+        transfer.spend_from = self.operation.input.spend_from.clone();
+        transfer.memo = self.operation.input.memo.clone();
+        transfer.category = self.operation.input.category.clone();
+        transfer.asset_id = self.operation.input.asset_id;
+
         self.transfer_service
-            .add_transfer(Transfer::new(
-                self.request.id,
-                *generate_uuid_v4().await.as_bytes(),
-                self.request.requested_by,
-                self.operation.input.from_account_id,
-                self.operation.input.to.clone(),
-                self.operation.input.metadata.clone(),
-                self.operation.input.amount.clone(),
-                fee,
-                self.operation.input.network.clone(),
-            ))
+            .add_transfer(transfer)
             .map_err(|e| RequestExecuteError::Failed {
                 reason: format!("Failed to validate transfer: {}", e),
             })?;
@@ -141,3 +253,79 @@ impl Execute for TransferRequestExecute<'_, '_> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        account_test_utils::mock_account, address_book_entry_test_utils::mock_address_book_entry,
+    };
+
+    #[test]
+    fn transfers_to_destinations_requiring_a_memo_must_have_one() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut entry = mock_address_book_entry();
+        entry.address = "exchange-deposit-address".to_string();
+        entry.blockchain = account.blockchain.clone();
+        entry.requires_memo = true;
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let input = station_api::TransferOperationInput {
+            from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            to: entry.address.clone(),
+            amount: candid::Nat::from(100u64),
+            fee: None,
+            metadata: vec![],
+            network: None,
+            spend_from: None,
+            memo: None,
+            category: None,
+            asset_id: None,
+        };
+
+        assert!(matches!(
+            to_transfer_operation_input(input.clone()),
+            Err(RequestError::ValidationError { .. })
+        ));
+
+        let transfer = to_transfer_operation_input(station_api::TransferOperationInput {
+            memo: Some(station_api::TransferMemoDTO::Number(42)),
+            ..input
+        })
+        .unwrap();
+        assert_eq!(transfer.memo, Some(TransferMemo::Number(42)));
+    }
+
+    #[test]
+    fn transfers_can_only_be_booked_under_registered_categories() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let input = station_api::TransferOperationInput {
+            from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            to: "destination-address".to_string(),
+            amount: candid::Nat::from(100u64),
+            fee: None,
+            metadata: vec![],
+            network: None,
+            spend_from: None,
+            memo: None,
+            category: Some("payroll".to_string()),
+            asset_id: None,
+        };
+
+        assert!(matches!(
+            to_transfer_operation_input(input.clone()),
+            Err(RequestError::ValidationError { .. })
+        ));
+
+        TRANSFER_CATEGORY_SERVICE
+            .set_category("payroll".to_string(), None)
+            .unwrap();
+
+        let transfer = to_transfer_operation_input(input).unwrap();
+        assert_eq!(transfer.category, Some("payroll".to_string()));
+    }
+}
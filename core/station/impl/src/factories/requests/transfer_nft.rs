@@ -0,0 +1,224 @@
+use super::{Create, Execute, RequestExecuteStage};
+use crate::{
+    errors::{RequestError, RequestExecuteError},
+    factories::blockchains::{Icrc7, TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY},
+    mappers::HelperMapper,
+    models::{
+        Account, BlockchainStandard, IcrcAccount, Request, RequestExecutionPlan, RequestOperation,
+        TransferNftOperation, TransferNftOperationInput,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+};
+use async_trait::async_trait;
+use orbit_essentials::repository::Repository;
+use orbit_essentials::types::UUID;
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub struct TransferNftRequestCreate {}
+
+#[async_trait]
+impl Create<station_api::TransferNftOperationInput> for TransferNftRequestCreate {
+    async fn create(
+        &self,
+        request_id: UUID,
+        requested_by_user: UUID,
+        input: station_api::CreateRequestInput,
+        operation_input: station_api::TransferNftOperationInput,
+    ) -> Result<Request, RequestError> {
+        let from_account_id =
+            HelperMapper::to_uuid(operation_input.from_account_id).map_err(|e| {
+                RequestError::ValidationError {
+                    info: format!("Invalid from_account_id: {}", e),
+                }
+            })?;
+        let to = IcrcAccount::from_str(&operation_input.to).map_err(|e| {
+            RequestError::ValidationError {
+                info: format!("Invalid to: {}", e),
+            }
+        })?;
+
+        if let Some(account) = ACCOUNT_REPOSITORY.get(&Account::key(*from_account_id.as_bytes())) {
+            if account.standard != BlockchainStandard::ICRC7 {
+                Err(RequestError::ValidationError {
+                    info: format!(
+                        "The account {} does not hold NFTs, its standard is {}",
+                        from_account_id.hyphenated(),
+                        account.standard
+                    ),
+                })?
+            }
+        }
+
+        let request = Request::new(
+            request_id,
+            requested_by_user,
+            Request::default_expiration_dt_ns(),
+            RequestOperation::TransferNft(TransferNftOperation {
+                block_index: None,
+                input: TransferNftOperationInput {
+                    from_account_id: *from_account_id.as_bytes(),
+                    token_id: operation_input.token_id,
+                    to,
+                    memo: operation_input.memo,
+                },
+            }),
+            input
+                .execution_plan
+                .map(Into::into)
+                .unwrap_or(RequestExecutionPlan::Immediate),
+            input.title.unwrap_or_else(|| "Transfer NFT".to_string()),
+            input.summary,
+        );
+
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+pub struct TransferNftRequestExecute<'p, 'o> {
+    _request: &'p Request,
+    operation: &'o TransferNftOperation,
+}
+
+impl<'p, 'o> TransferNftRequestExecute<'p, 'o> {
+    pub fn new(request: &'p Request, operation: &'o TransferNftOperation) -> Self {
+        Self {
+            _request: request,
+            operation,
+        }
+    }
+}
+
+#[async_trait]
+impl Execute for TransferNftRequestExecute<'_, '_> {
+    async fn execute(&self) -> Result<RequestExecuteStage, RequestExecuteError> {
+        let account = ACCOUNT_REPOSITORY
+            .get(&Account::key(self.operation.input.from_account_id))
+            .ok_or(RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not exist.",
+                    Uuid::from_bytes(self.operation.input.from_account_id).hyphenated()
+                ),
+            })?;
+
+        if account.standard != BlockchainStandard::ICRC7 {
+            Err(RequestExecuteError::Failed {
+                reason: format!(
+                    "Account {} does not hold NFTs.",
+                    Uuid::from_bytes(account.id).hyphenated()
+                ),
+            })?
+        }
+
+        let submitted = Icrc7::create(account.network)
+            .transfer_nft(&account, &self.operation.input)
+            .await
+            .map_err(|e| RequestExecuteError::Failed {
+                reason: format!("Failed to transfer the NFT: {}", e),
+            })?;
+
+        let mut operation = self.operation.clone();
+        operation.block_index = submitted
+            .metadata_map()
+            .get(TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY)
+            .and_then(|block_height| block_height.parse().ok());
+
+        Ok(RequestExecuteStage::Completed(
+            RequestOperation::TransferNft(operation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user},
+        repositories::USER_REPOSITORY,
+    };
+
+    #[tokio::test]
+    async fn test_create_transfer_nft_request() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let mut account = mock_account();
+        account.standard = BlockchainStandard::ICRC7;
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = mock_transfer_nft_api_input(&account);
+        let request = TransferNftRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::TransferNft(
+                        operation_input.clone(),
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                operation_input,
+            )
+            .await
+            .unwrap();
+
+        match request.operation {
+            RequestOperation::TransferNft(operation) => {
+                assert_eq!(operation.block_index, None);
+                assert_eq!(operation.input.from_account_id, account.id);
+                assert_eq!(operation.input.token_id, candid::Nat::from(7_u64));
+            }
+            _ => panic!("Expected a transfer nft operation"),
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_create_transfer_nft_request_from_fungible_account() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let operation_input = mock_transfer_nft_api_input(&account);
+        let result = TransferNftRequestCreate {}
+            .create(
+                [1; 16],
+                user.id,
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::TransferNft(
+                        operation_input.clone(),
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                operation_input,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+    }
+
+    fn mock_transfer_nft_api_input(account: &Account) -> station_api::TransferNftOperationInput {
+        station_api::TransferNftOperationInput {
+            from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            token_id: candid::Nat::from(7_u64),
+            to: "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae".to_string(),
+            memo: None,
+        }
+    }
+}
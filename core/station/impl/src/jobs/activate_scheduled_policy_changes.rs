@@ -0,0 +1,41 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::services::{ScheduledPolicyChangeService, SCHEDULED_POLICY_CHANGE_SERVICE};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    scheduled_policy_change_service: Arc<ScheduledPolicyChangeService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            scheduled_policy_change_service: Arc::clone(&SCHEDULED_POLICY_CHANGE_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ActivateScheduledPolicyChanges;
+
+    async fn run() -> bool {
+        Self::default().activate_scheduled_policy_changes()
+    }
+}
+
+/// This job is responsible for applying the scheduled request policy and permission changes once
+/// their effective time is reached.
+impl Job {
+    fn activate_scheduled_policy_changes(&self) -> bool {
+        self.scheduled_policy_change_service
+            .activate_due_policy_changes();
+
+        true
+    }
+}
+
+pub fn schedule_activation(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
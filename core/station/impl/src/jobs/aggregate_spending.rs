@@ -0,0 +1,49 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    services::{SpendingSummaryService, SPENDING_SUMMARY_SERVICE},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    spending_summary_service: Arc<SpendingSummaryService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            spending_summary_service: Arc::clone(&SPENDING_SUMMARY_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::AggregateSpending;
+
+    async fn run() -> bool {
+        Self::default().aggregate_spending()
+    }
+}
+
+/// This job is responsible for adding the completed transfers to the spending aggregates.
+impl Job {
+    /// The interval between two aggregations, which is how stale the spending summaries can be.
+    pub const AGGREGATION_INTERVAL_NS: u64 = 15 * 60 * 1_000_000_000;
+
+    /// Aggregates the transfers completed since the last run and schedules the next run.
+    fn aggregate_spending(&self) -> bool {
+        self.spending_summary_service
+            .aggregate_completed_transfers();
+
+        schedule_aggregation(next_time().saturating_add(Self::AGGREGATION_INTERVAL_NS));
+
+        true
+    }
+}
+
+pub fn schedule_aggregation(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
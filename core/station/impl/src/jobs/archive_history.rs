@@ -0,0 +1,61 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::{
+        ic_cdk::{api::print, next_time},
+        read_system_info,
+    },
+    services::{ArchiveService, ARCHIVE_SERVICE},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    archive_service: Arc<ArchiveService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            archive_service: Arc::clone(&ARCHIVE_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ArchiveHistory;
+
+    async fn run() -> bool {
+        Self::default().archive_history().await
+    }
+}
+
+/// This job is responsible for exporting the settled history to the archive sink before pruning it.
+impl Job {
+    /// The interval between two exports once the settled history is caught up, also used to retry
+    /// after the archive canister failed.
+    pub const ARCHIVE_INTERVAL_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    /// Exports the next chunk, the job runs again right away while more chunks are left and stops
+    /// once the archive sink is removed.
+    async fn archive_history(&self) -> bool {
+        if read_system_info().get_archive_sink().is_none() {
+            return true;
+        }
+
+        match self.archive_service.archive_history().await {
+            Ok(true) => return false,
+            Ok(false) => {}
+            Err(error) => print(format!("Failed to archive the settled history: {}", error)),
+        }
+
+        schedule_archiving(next_time().saturating_add(Self::ARCHIVE_INTERVAL_NS));
+
+        true
+    }
+}
+
+pub fn schedule_archiving(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
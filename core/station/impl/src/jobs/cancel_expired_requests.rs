@@ -1,12 +1,28 @@
 use crate::jobs::JobType;
-use crate::{core::ic_cdk::next_time, models::RequestStatusCode, repositories::RequestRepository};
+use crate::services::{RequestService, REQUEST_SERVICE};
+use crate::{
+    core::ic_cdk::next_time,
+    models::RequestStatusCode,
+    repositories::{RequestRepository, REQUEST_REPOSITORY},
+};
 use async_trait::async_trait;
+use std::sync::Arc;
 
 use super::{scheduler::Scheduler, ScheduledJob};
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Job {
-    request_repository: RequestRepository,
+    request_repository: Arc<RequestRepository>,
+    request_service: Arc<RequestService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            request_repository: Arc::clone(&REQUEST_REPOSITORY),
+            request_service: Arc::clone(&REQUEST_SERVICE),
+        }
+    }
 }
 
 #[async_trait]
@@ -19,7 +35,7 @@ impl ScheduledJob for Job {
 
 /// This job is responsible for canceling the requests that have expired while not approved/rejected.
 impl Job {
-    /// Cancel the requests that have expired while still pending.
+    /// Cancel the requests that have expired while still pending, their voters are notified.
     async fn cancel_requests(&self) -> bool {
         let current_time = next_time();
         let requests = self.request_repository.find_by_status_and_expiration_dt(
@@ -29,11 +45,7 @@ impl Job {
         );
 
         for request in requests.into_iter() {
-            self.request_repository.cancel_request(
-                request,
-                "The request has expired".to_string(),
-                next_time(),
-            );
+            self.request_service.expire_request(request).await;
         }
 
         true
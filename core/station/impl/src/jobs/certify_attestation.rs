@@ -0,0 +1,49 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    services::{AttestationService, ATTESTATION_SERVICE},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    attestation_service: Arc<AttestationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            attestation_service: Arc::clone(&ATTESTATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::CertifyAttestation;
+
+    async fn run() -> bool {
+        Self::default().certify_attestation().await
+    }
+}
+
+/// This job is responsible for certifying the latest attestation of the identity of the station.
+impl Job {
+    /// The interval between two attestations, which is how stale the certified admins and policies
+    /// can be.
+    pub const CERTIFICATION_INTERVAL_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    /// Certifies a new attestation and schedules the next run.
+    async fn certify_attestation(&self) -> bool {
+        self.attestation_service.refresh_attestation().await;
+
+        schedule_certification(next_time().saturating_add(Self::CERTIFICATION_INTERVAL_NS));
+
+        true
+    }
+}
+
+pub fn schedule_certification(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
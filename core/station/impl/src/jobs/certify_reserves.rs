@@ -0,0 +1,48 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    services::{ProofOfReservesService, PROOF_OF_RESERVES_SERVICE},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    proof_of_reserves_service: Arc<ProofOfReservesService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            proof_of_reserves_service: Arc::clone(&PROOF_OF_RESERVES_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::CertifyReserves;
+
+    async fn run() -> bool {
+        Self::default().certify_reserves()
+    }
+}
+
+/// This job is responsible for certifying the latest snapshot of the reserves of the station.
+impl Job {
+    /// The interval between two snapshots, which is how stale the certified reserves can be.
+    pub const CERTIFICATION_INTERVAL_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    /// Certifies a new snapshot of the reserves and schedules the next run.
+    fn certify_reserves(&self) -> bool {
+        self.proof_of_reserves_service.certify_reserves();
+
+        schedule_certification(next_time().saturating_add(Self::CERTIFICATION_INTERVAL_NS));
+
+        true
+    }
+}
+
+pub fn schedule_certification(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -0,0 +1,50 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::{ic_cdk::next_time, read_system_info},
+    factories::blockchains::{HttpOutcallTransport, RpcClient},
+};
+use async_trait::async_trait;
+use futures::future;
+
+#[derive(Debug, Default)]
+pub struct Job {}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::CheckRpcProvidersHealth;
+
+    async fn run() -> bool {
+        Self::default().check_rpc_providers_health().await
+    }
+}
+
+/// This job is responsible for checking the health of the configured RPC providers, so that the
+/// failover ranks them by their recent availability rather than by the last execution that used them.
+impl Job {
+    /// The interval between two health checks of the providers.
+    pub const HEALTH_CHECK_INTERVAL_NS: u64 = 5 * 60 * 1_000_000_000;
+
+    /// Checks the providers of all the blockchains and schedules the next check while any are configured.
+    async fn check_rpc_providers_health(&self) -> bool {
+        let configs = read_system_info().get_all_rpc_providers().to_vec();
+
+        if configs.is_empty() {
+            return true;
+        }
+
+        let clients = configs
+            .into_iter()
+            .map(|config| RpcClient::new(config, Box::new(HttpOutcallTransport::default())))
+            .collect::<Vec<_>>();
+
+        future::join_all(clients.iter().map(|client| client.check_health())).await;
+
+        schedule_health_check(next_time().saturating_add(Self::HEALTH_CHECK_INTERVAL_NS));
+
+        true
+    }
+}
+
+pub fn schedule_health_check(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
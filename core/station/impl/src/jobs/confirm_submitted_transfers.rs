@@ -0,0 +1,245 @@
+use super::{
+    execute_created_transfers::{complete_transfer, settle_split_transfer},
+    scheduler::Scheduler,
+    JobType, ScheduledJob,
+};
+use crate::{
+    core::{
+        ic_cdk::{api::print, next_time},
+        read_system_info,
+    },
+    factories::blockchains::{
+        BlockchainApi, BlockchainApiFactory, BlockchainTransactionConfirmation,
+        BlockchainTransactionSubmitted,
+    },
+    models::{Account, Blockchain, Request, RequestOperation, Transfer, TransferStatus},
+    repositories::{AccountRepository, RequestRepository, TransferRepository},
+    services::RequestService,
+};
+use async_trait::async_trait;
+use futures::future;
+use orbit_essentials::repository::Repository;
+use uuid::Uuid;
+
+#[derive(Debug, Default)]
+pub struct Job {
+    transfer_repository: TransferRepository,
+    account_repository: AccountRepository,
+    request_repository: RequestRepository,
+    request_service: RequestService,
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ConfirmSubmittedTransfers;
+
+    async fn run() -> bool {
+        Self::default().confirm_submitted_transfers().await
+    }
+}
+
+/// This job is responsible for completing the transfers whose transactions were submitted to a
+/// blockchain that requires confirmations (e.g. Bitcoin), once they reach the finality threshold.
+impl Job {
+    /// The interval between two checks of the submitted transactions.
+    pub const CONFIRMATION_INTERVAL_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    /// Checks the confirmations of all the submitted transfers and schedules the next check while
+    /// there are transfers awaiting confirmations.
+    async fn confirm_submitted_transfers(&self) -> bool {
+        let transfers = find_awaiting_confirmations(&self.transfer_repository);
+
+        if transfers.is_empty() {
+            return true;
+        }
+
+        let results = future::join_all(
+            transfers
+                .iter()
+                .map(|(transfer, submitted)| self.check_confirmation(transfer, submitted)),
+        )
+        .await;
+
+        let mut awaiting_confirmations = false;
+        for ((mut transfer, submitted), result) in transfers.into_iter().zip(results) {
+            match result {
+                Ok((BlockchainTransactionConfirmation::Final, _)) => {
+                    let request_id = transfer.request_id;
+                    complete_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        transfer,
+                        &submitted,
+                    );
+                    settle_split_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        &self.request_service,
+                        &request_id,
+                    )
+                    .await;
+                }
+                Ok((
+                    BlockchainTransactionConfirmation::Pending {
+                        confirmations,
+                        details,
+                    },
+                    required_confirmations,
+                )) if confirmations >= required_confirmations => {
+                    let request_id = transfer.request_id;
+                    complete_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        transfer,
+                        &details,
+                    );
+                    settle_split_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        &self.request_service,
+                        &request_id,
+                    )
+                    .await;
+                }
+                Ok((
+                    BlockchainTransactionConfirmation::Pending {
+                        confirmations,
+                        details,
+                    },
+                    required_confirmations,
+                )) => {
+                    awaiting_confirmations = true;
+
+                    let status = TransferStatus::Confirming {
+                        submitted_at: match transfer.status {
+                            TransferStatus::Confirming { submitted_at, .. } => submitted_at,
+                            _ => transfer.last_modification_timestamp,
+                        },
+                        confirmations,
+                        required_confirmations,
+                    };
+
+                    if details.details != submitted.details || status != transfer.status {
+                        transfer.status = status;
+                        transfer.submitted_details = Some(details.details);
+                        transfer.last_modification_timestamp = next_time();
+                        self.transfer_repository
+                            .insert(transfer.to_key(), transfer.to_owned());
+                    }
+                }
+                Ok((BlockchainTransactionConfirmation::Failed { reason }, _)) => {
+                    let failed_at = next_time();
+                    transfer.status = TransferStatus::Failed {
+                        reason: reason.clone(),
+                    };
+                    transfer.last_modification_timestamp = failed_at;
+                    self.transfer_repository
+                        .insert(transfer.to_key(), transfer.to_owned());
+
+                    if let Some(request) = self
+                        .request_repository
+                        .get(&Request::key(transfer.request_id))
+                        .filter(|request| {
+                            matches!(
+                                request.operation,
+                                RequestOperation::Transfer(_) | RequestOperation::SweepAccount(_)
+                            )
+                        })
+                    {
+                        self.request_service
+                            .fail_request(request, reason, failed_at)
+                            .await;
+                    }
+
+                    settle_split_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        &self.request_service,
+                        &transfer.request_id,
+                    )
+                    .await;
+                }
+                Err(error) => {
+                    awaiting_confirmations = true;
+
+                    print(format!(
+                        "Failed to check the confirmations of transfer {}: {}",
+                        Uuid::from_bytes(transfer.id).hyphenated(),
+                        error
+                    ));
+                }
+            }
+        }
+
+        if awaiting_confirmations {
+            schedule_confirmations(next_time().saturating_add(Self::CONFIRMATION_INTERVAL_NS));
+        }
+
+        true
+    }
+
+    /// Returns the confirmation status of the submitted transaction with the number of confirmations
+    /// required before the transfer is completed.
+    async fn check_confirmation(
+        &self,
+        transfer: &Transfer,
+        submitted: &BlockchainTransactionSubmitted,
+    ) -> Result<(BlockchainTransactionConfirmation, u32), String> {
+        let account = self
+            .account_repository
+            .get(&Account::key(transfer.from_account))
+            .ok_or("Transfer account not found".to_string())?
+            .for_asset(transfer.asset_id.as_ref())
+            .map_err(|e| e.to_string())?;
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| format!("Failed to build blockchain api: {}", e))?;
+
+        let confirmation = blockchain_api
+            .transaction_confirmation(&account, submitted)
+            .await
+            .map_err(|e| e.to_json_string())?;
+
+        Ok((
+            confirmation,
+            required_confirmations(&account.blockchain, blockchain_api.as_ref()),
+        ))
+    }
+}
+
+/// Returns the number of confirmations after which the transfers of the blockchain are final, which
+/// is the configured finality threshold or the default of the blockchain adapter.
+pub fn required_confirmations(blockchain: &Blockchain, blockchain_api: &dyn BlockchainApi) -> u32 {
+    read_system_info()
+        .get_finality_threshold(blockchain)
+        .unwrap_or_else(|| blockchain_api.required_confirmations())
+}
+
+/// Returns the transfers whose transaction was submitted and awaits confirmations.
+///
+/// Transfers submitted before the `Confirming` status was introduced are still `Processing`.
+pub fn find_awaiting_confirmations(
+    transfer_repository: &TransferRepository,
+) -> Vec<(Transfer, BlockchainTransactionSubmitted)> {
+    [
+        TransferStatus::Confirming {
+            submitted_at: 0,
+            confirmations: 0,
+            required_confirmations: 0,
+        },
+        TransferStatus::Processing { started_at: 0 },
+    ]
+    .into_iter()
+    .flat_map(|status| transfer_repository.find_by_status(status.to_string(), None, None))
+    .filter_map(|transfer| {
+        let details = transfer.submitted_details.clone()?;
+
+        Some((transfer, BlockchainTransactionSubmitted { details }))
+    })
+    .collect()
+}
+
+pub fn schedule_confirmations(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
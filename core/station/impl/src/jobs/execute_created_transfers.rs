@@ -1,21 +1,31 @@
-use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use super::{
+    confirm_submitted_transfers,
+    partition::{run_partitioned, PartitionKey},
+    scheduler::Scheduler,
+    JobType, ScheduledJob,
+};
 use crate::{
-    core::ic_cdk::{api::print, next_time},
-    errors::TransferError,
+    core::{
+        ic_cdk::{api::print, next_time, spawn},
+        read_system_info,
+    },
+    errors::{BlockchainApiError, TransferError},
     factories::blockchains::{
         BlockchainApiFactory, BlockchainTransactionSubmitted,
         TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY,
     },
     models::{
-        Account, Request, RequestOperation, RequestStatus, Transfer, TransferId, TransferStatus,
+        Account, Request, RequestOperation, RequestStatus, Transfer, TransferAttempt, TransferId,
+        TransferStatus,
     },
-    repositories::{AccountRepository, RequestRepository, TransferRepository},
-    services::RequestService,
+    repositories::{
+        AccountRepository, RequestRepository, TransferRepository, DESTINATION_FIRST_USE_REPOSITORY,
+    },
+    services::{CircuitBreakerService, RequestService, ACCOUNT_SERVICE, TRANSFER_RECEIPT_SERVICE},
 };
 use async_trait::async_trait;
-use futures::future;
 
-use orbit_essentials::repository::Repository;
+use orbit_essentials::{repository::Repository, types::UUID};
 use std::collections::HashMap;
 
 use uuid::Uuid;
@@ -26,6 +36,7 @@ pub struct Job {
     account_repository: AccountRepository,
     request_repository: RequestRepository,
     request_service: RequestService,
+    circuit_breaker_service: CircuitBreakerService,
 }
 
 #[async_trait]
@@ -43,14 +54,24 @@ impl Job {
 
     /// Executes all the transfers that have been created but are not yet submitted to the blockchain.
     ///
-    /// This function will process a maximum of `MAX_BATCH_SIZE` transfers at once.
+    /// This function will process a maximum of `MAX_BATCH_SIZE` transfers at once, the transfers of
+    /// different accounts are submitted concurrently while the ones of the same account are submitted
+    /// one after the other.
     async fn execute_created_transfers(&self) -> bool {
         let current_time = next_time();
-        let mut transfers = self.transfer_repository.find_by_status(
-            TransferStatus::Created.to_string(),
-            None,
-            Some(current_time),
-        );
+        let mut transfers: Vec<Transfer> = self
+            .transfer_repository
+            .find_by_status(
+                TransferStatus::Created.to_string(),
+                None,
+                Some(current_time),
+            )
+            .into_iter()
+            // the transfers of a paused blockchain are kept as created until an admin resumes it
+            .filter(|transfer| {
+                !transfer.is_awaiting_retry(current_time) && !self.is_blockchain_paused(transfer)
+            })
+            .collect();
 
         let processing_all_transfers = transfers.len() <= Self::MAX_BATCH_SIZE;
 
@@ -63,6 +84,7 @@ impl Job {
             transfer.status = TransferStatus::Processing {
                 started_at: transfer_processing_time,
             };
+            transfer.next_attempt_at = None;
             transfer.last_modification_timestamp = transfer_processing_time;
             self.transfer_repository
                 .insert(transfer.to_key(), transfer.to_owned());
@@ -96,75 +118,121 @@ impl Job {
             }
         }
 
-        // batch the transfers to be executed
-        let calls = transfers
-            .clone()
+        // batch the transfers to be executed, partitioned by their account
+        let transfers: Vec<Transfer> = transfers
             .into_iter()
             .filter(|transfer| requests.contains_key(&transfer.id))
-            .map(|transfer| self.execute_transfer(transfer));
+            .collect();
 
         // wait for all the transfers to be executed
-        let results = future::join_all(calls).await;
-        let transfers = transfers.clone();
+        let results = run_partitioned(
+            transfers.clone(),
+            |transfer| PartitionKey::Account(transfer.from_account),
+            |transfer| self.execute_transfer(transfer),
+        )
+        .await;
 
         for (pos, result) in results.iter().enumerate() {
             match result {
-                Ok((transfer, details)) => {
+                Ok((transfer, details, 0)) => {
+                    complete_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        transfer.clone(),
+                        details,
+                    );
+                    settle_split_transfer(
+                        &self.transfer_repository,
+                        &self.request_repository,
+                        &self.request_service,
+                        &transfer.request_id,
+                    )
+                    .await;
+                }
+                Ok((transfer, details, required_confirmations)) => {
+                    let submitted_at = next_time();
                     let mut transfer = transfer.clone();
-                    let transfer_completed_time = next_time();
-                    let maybe_transaction_hash = details
-                        .details
-                        .iter()
-                        .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY)
-                        .map(|(_, value)| value.to_owned());
-
-                    transfer.status = TransferStatus::Completed {
-                        completed_at: transfer_completed_time,
-                        hash: maybe_transaction_hash,
-                        signature: None,
+                    transfer.status = TransferStatus::Confirming {
+                        submitted_at,
+                        confirmations: 0,
+                        required_confirmations: *required_confirmations,
                     };
-                    transfer.last_modification_timestamp = transfer_completed_time;
+                    transfer.submitted_details = Some(details.details.clone());
+                    transfer.last_modification_timestamp = submitted_at;
                     self.transfer_repository
                         .insert(transfer.to_key(), transfer.to_owned());
 
                     if let Some(request) = requests.get(&transfer.id) {
                         let mut request = request.clone();
 
-                        if let RequestOperation::Transfer(transfer_operation) =
-                            &mut request.operation
-                        {
-                            transfer_operation.transfer_id = Some(transfer.id);
-                            transfer_operation.fee = Some(transfer.fee);
+                        match &mut request.operation {
+                            RequestOperation::Transfer(transfer_operation) => {
+                                transfer_operation.transfer_id = Some(transfer.id);
+                                transfer_operation.fee = Some(transfer.fee);
+                            }
+                            RequestOperation::SweepAccount(sweep_operation) => {
+                                sweep_operation.transfer_id = Some(transfer.id);
+                                sweep_operation.fee = Some(transfer.fee);
+                            }
+                            _ => {}
                         }
 
-                        request.status = RequestStatus::Completed {
-                            completed_at: transfer_completed_time,
-                        };
-                        request.last_modification_timestamp = transfer_completed_time;
+                        request.last_modification_timestamp = transfer.last_modification_timestamp;
                         self.request_repository
                             .insert(request.to_key(), request.to_owned());
-                    } else {
-                        print(format!(
-                            "Error: request not found for transfer {}",
-                            Uuid::from_bytes(transfer.id).hyphenated()
-                        ));
                     }
+
+                    confirm_submitted_transfers::schedule_confirmations(
+                        next_time().saturating_add(
+                            confirm_submitted_transfers::Job::CONFIRMATION_INTERVAL_NS,
+                        ),
+                    );
                 }
                 Err(e) => {
                     let mut transfer = transfers[pos].clone();
+                    let transfer_failed_time = next_time();
+                    transfer.failed_attempts.push(TransferAttempt {
+                        attempted_at: transfer_failed_time,
+                        error: e.to_string(),
+                    });
+                    transfer.last_modification_timestamp = transfer_failed_time;
+
+                    if let Some(next_attempt_at) = retry_at(&transfer, e, transfer_failed_time) {
+                        transfer.status = TransferStatus::Created;
+                        transfer.next_attempt_at = Some(next_attempt_at);
+                        self.transfer_repository
+                            .insert(transfer.to_key(), transfer.to_owned());
+
+                        schedule_process_transfers(next_attempt_at);
+
+                        continue;
+                    }
+
                     transfer.status = TransferStatus::Failed {
                         reason: e.to_string(),
                     };
-                    let transfer_failed_time = next_time();
-                    transfer.last_modification_timestamp = transfer_failed_time;
                     self.transfer_repository
                         .insert(transfer.to_key(), transfer.to_owned());
 
                     if let Some(request) = requests.get(&transfer.id) {
-                        let request = request.clone();
-                        self.request_service
-                            .fail_request(request, e.to_string(), transfer_failed_time)
-                            .await;
+                        // the request of a standing order stays completed when one of its transfers fails
+                        if matches!(
+                            request.operation,
+                            RequestOperation::Transfer(_) | RequestOperation::SweepAccount(_)
+                        ) {
+                            let request = request.clone();
+                            self.request_service
+                                .fail_request(request, e.to_string(), transfer_failed_time)
+                                .await;
+                        }
+
+                        settle_split_transfer(
+                            &self.transfer_repository,
+                            &self.request_repository,
+                            &self.request_service,
+                            &transfer.request_id,
+                        )
+                        .await;
                     } else {
                         print(format!(
                             "Error: request not found for transfer {}",
@@ -178,13 +246,22 @@ impl Job {
         processing_all_transfers
     }
 
+    fn is_blockchain_paused(&self, transfer: &Transfer) -> bool {
+        self.account_repository
+            .get(&Account::key(transfer.from_account))
+            .is_some_and(|account| self.circuit_breaker_service.is_paused(&account.blockchain))
+    }
+
     /// Executes a single transfer.
     ///
     /// This function will handle the submission of the transfer to the blockchain.
+    ///
+    /// Returns the details of the submitted transaction and the number of confirmations it needs
+    /// before the transfer is completed.
     async fn execute_transfer(
         &self,
         transfer: Transfer,
-    ) -> Result<(Transfer, BlockchainTransactionSubmitted), TransferError> {
+    ) -> Result<(Transfer, BlockchainTransactionSubmitted, u32), TransferError> {
         let account = self
             .account_repository
             .get(&Account::key(transfer.from_account))
@@ -193,23 +270,367 @@ impl Job {
                     "Transfer account not found for id {}",
                     Uuid::from_bytes(transfer.from_account).hyphenated()
                 ),
+            })?
+            .for_asset(transfer.asset_id.as_ref())
+            .map_err(|e| TransferError::ValidationError {
+                info: e.to_string(),
             })?;
 
-        let blockchain_api = BlockchainApiFactory::build(&account.blockchain, &account.standard)
-            .map_err(|e| TransferError::ExecutionError {
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)
+                .map_err(|e| TransferError::ExecutionError {
                 reason: format!("Failed to build blockchain api: {}", e),
             })?;
 
         match blockchain_api.submit_transaction(&account, &transfer).await {
-            Ok(details) => Ok((transfer, details)),
+            Ok(details) => {
+                self.circuit_breaker_service
+                    .record_success(&account.blockchain);
+
+                let required_confirmations = confirm_submitted_transfers::required_confirmations(
+                    &account.blockchain,
+                    blockchain_api.as_ref(),
+                );
+
+                Ok((transfer, details, required_confirmations))
+            }
+
+            Err(error) => {
+                self.circuit_breaker_service
+                    .record_failure(&account.blockchain, &error.to_string())
+                    .await;
+
+                let reason = error.to_json_string();
+                if BlockchainApiError::is_transient(&error) {
+                    Err(TransferError::TransientExecutionError { reason })
+                } else {
+                    Err(TransferError::ExecutionError { reason })
+                }
+            }
+        }
+    }
+}
+
+/// Returns when the failed transfer should be submitted again, if the error is transient and the
+/// retry policy allows another attempt.
+fn retry_at(transfer: &Transfer, error: &TransferError, failed_at: u64) -> Option<u64> {
+    if !matches!(error, TransferError::TransientExecutionError { .. }) {
+        return None;
+    }
+
+    let failed_attempts = transfer.failed_attempts.len() as u32;
+    let retry_policy = read_system_info().get_transfer_retry_policy().clone();
+
+    retry_policy
+        .can_retry(failed_attempts)
+        .then(|| failed_at.saturating_add(retry_policy.backoff_ns(failed_attempts)))
+}
+
+/// Marks the transfer and its request as completed with the details of the submitted transaction,
+/// issues the receipt of the transfer, records the first use of its destination and refreshes the
+/// cached balance of its account.
+pub(super) fn complete_transfer(
+    transfer_repository: &TransferRepository,
+    request_repository: &RequestRepository,
+    mut transfer: Transfer,
+    details: &BlockchainTransactionSubmitted,
+) {
+    let transfer_completed_time = next_time();
+    let maybe_transaction_hash = details
+        .details
+        .iter()
+        .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_TRANSACTION_HASH_KEY)
+        .map(|(_, value)| value.to_owned());
+
+    transfer.status = TransferStatus::Completed {
+        completed_at: transfer_completed_time,
+        hash: maybe_transaction_hash,
+        signature: None,
+    };
+    transfer.submitted_details = Some(details.details.clone());
+    transfer.last_modification_timestamp = transfer_completed_time;
+    transfer_repository.insert(transfer.to_key(), transfer.to_owned());
+
+    match request_repository.get(&Request::key(transfer.request_id)) {
+        Some(mut request) => {
+            // the transfers of a standing order share its request, which is already completed
+            let completes_request = match &mut request.operation {
+                RequestOperation::Transfer(transfer_operation) => {
+                    transfer_operation.transfer_id = Some(transfer.id);
+                    transfer_operation.fee = Some(transfer.fee);
+                    true
+                }
+                RequestOperation::SweepAccount(sweep_operation) => {
+                    sweep_operation.transfer_id = Some(transfer.id);
+                    sweep_operation.fee = Some(transfer.fee);
+                    true
+                }
+                _ => false,
+            };
+
+            if completes_request {
+                request.status = RequestStatus::Completed {
+                    completed_at: transfer_completed_time,
+                };
+                request.last_modification_timestamp = transfer_completed_time;
+                request_repository.insert(request.to_key(), request.to_owned());
+            }
+        }
+        None => print(format!(
+            "Error: request not found for transfer {}",
+            Uuid::from_bytes(transfer.id).hyphenated()
+        )),
+    }
+
+    if let Err(error) = TRANSFER_RECEIPT_SERVICE.issue_receipt(&transfer) {
+        print(format!(
+            "Error: failed to issue the receipt of transfer {}: {}",
+            Uuid::from_bytes(transfer.id).hyphenated(),
+            error
+        ));
+    }
+
+    DESTINATION_FIRST_USE_REPOSITORY.record_use(
+        &transfer.to_address,
+        transfer.id,
+        transfer_completed_time,
+    );
 
-            Err(error) => Err(TransferError::ExecutionError {
-                reason: error.to_json_string(),
-            })?,
+    // the outgoing amount is reflected right away instead of on the next balance fetch
+    let account_id = transfer.from_account;
+    spawn(async move {
+        if let Err(error) = ACCOUNT_SERVICE.refresh_account_balance(&account_id).await {
+            print(format!(
+                "Error: failed to refresh the balance of account {}: {}",
+                Uuid::from_bytes(account_id).hyphenated(),
+                error
+            ));
         }
+    });
+}
+
+/// Settles the request of a split transfer once all of its transfers are completed or failed, the
+/// request fails with the failed transfers as reason when any of them failed.
+pub(super) async fn settle_split_transfer(
+    transfer_repository: &TransferRepository,
+    request_repository: &RequestRepository,
+    request_service: &RequestService,
+    request_id: &UUID,
+) {
+    let Some(mut request) = request_repository.get(&Request::key(*request_id)) else {
+        return;
+    };
+    let RequestOperation::SplitTransfer(operation) = &request.operation else {
+        return;
+    };
+
+    if !matches!(request.status, RequestStatus::Processing { .. }) {
+        return;
+    }
+
+    let mut failures = Vec::new();
+    for transfer_id in operation.transfer_ids.iter() {
+        let Some(transfer) = transfer_repository.get(&Transfer::key(*transfer_id)) else {
+            failures.push(format!(
+                "{}: transfer not found",
+                Uuid::from_bytes(*transfer_id).hyphenated()
+            ));
+            continue;
+        };
+
+        match transfer.status {
+            TransferStatus::Completed { .. } => {}
+            TransferStatus::Failed { reason } => {
+                failures.push(format!("{}: {}", transfer.to_address, reason));
+            }
+            // the other transfers are still on their way
+            _ => return,
+        }
+    }
+
+    let settled_at = next_time();
+
+    if failures.is_empty() {
+        request.status = RequestStatus::Completed {
+            completed_at: settled_at,
+        };
+        request.last_modification_timestamp = settled_at;
+        request_repository.insert(request.to_key(), request.to_owned());
+
+        return;
     }
+
+    let reason = format!(
+        "{} of {} transfers failed, {}",
+        failures.len(),
+        operation.transfer_ids.len(),
+        failures.join("; ")
+    );
+
+    request_service
+        .fail_request(request, reason, settled_at)
+        .await;
 }
 
 pub fn schedule_process_transfers(at_ns: u64) {
     Scheduler::schedule::<Job>(at_ns);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        errors::LEDGER_TEMPORARILY_UNAVAILABLE_INFO,
+        models::{
+            request_test_utils::mock_request, transfer_test_utils::mock_transfer, Metadata,
+            SplitTransferDestination, SplitTransferOperation, SplitTransferOperationInput,
+            SplitTransferShare,
+        },
+    };
+    use orbit_essentials::api::ApiError;
+
+    #[test]
+    fn only_ledger_unavailability_and_undelivered_calls_are_transient() {
+        let unavailable: ApiError = BlockchainApiError::TransactionSubmitFailed {
+            info: LEDGER_TEMPORARILY_UNAVAILABLE_INFO.to_string(),
+        }
+        .into();
+        let undelivered: ApiError = BlockchainApiError::BlockchainNetworkError {
+            info: "rejection_code: SysTransient, err: queue full".to_string(),
+        }
+        .into();
+        let insufficient_funds: ApiError = BlockchainApiError::TransactionSubmitFailed {
+            info: "Insufficient balance, balance: 0".to_string(),
+        }
+        .into();
+        let unknown_outcome: ApiError = BlockchainApiError::BlockchainNetworkError {
+            info: "rejection_code: CanisterError, err: trapped".to_string(),
+        }
+        .into();
+
+        assert!(BlockchainApiError::is_transient(&unavailable));
+        assert!(BlockchainApiError::is_transient(&undelivered));
+        assert!(!BlockchainApiError::is_transient(&insufficient_funds));
+        assert!(!BlockchainApiError::is_transient(&unknown_outcome));
+    }
+
+    #[test]
+    fn transient_failures_are_retried_until_the_attempts_are_exhausted() {
+        test_utils::init_canister_system();
+
+        let transient = TransferError::TransientExecutionError {
+            reason: LEDGER_TEMPORARILY_UNAVAILABLE_INFO.to_string(),
+        };
+        let mut transfer = mock_transfer();
+        transfer.failed_attempts.push(TransferAttempt {
+            attempted_at: 0,
+            error: transient.to_string(),
+        });
+
+        let policy = read_system_info().get_transfer_retry_policy().clone();
+        assert_eq!(
+            retry_at(&transfer, &transient, 0),
+            Some(policy.backoff_ns(1))
+        );
+        assert_eq!(
+            retry_at(
+                &transfer,
+                &TransferError::ExecutionError {
+                    reason: "Insufficient balance".to_string(),
+                },
+                0
+            ),
+            None
+        );
+
+        while transfer.failed_attempts.len() < policy.max_attempts as usize {
+            transfer
+                .failed_attempts
+                .push(transfer.failed_attempts[0].clone());
+        }
+
+        assert_eq!(retry_at(&transfer, &transient, 0), None);
+    }
+
+    #[tokio::test]
+    async fn split_transfer_settles_once_all_its_transfers_are_settled() {
+        test_utils::init_canister_system();
+
+        let transfer_repository = TransferRepository::default();
+        let request_repository = RequestRepository::default();
+        let request_service = RequestService::default();
+
+        let mut request = mock_request();
+        let mut transfers = vec![mock_transfer(), mock_transfer()];
+        for transfer in transfers.iter_mut() {
+            transfer.request_id = request.id;
+            transfer.status = TransferStatus::Completed {
+                signature: None,
+                hash: None,
+                completed_at: 0,
+            };
+        }
+        transfers[1].status = TransferStatus::Processing { started_at: 0 };
+
+        request.status = RequestStatus::Processing { started_at: 0 };
+        request.operation = RequestOperation::SplitTransfer(SplitTransferOperation {
+            transfer_ids: transfers.iter().map(|transfer| transfer.id).collect(),
+            fee: None,
+            input: SplitTransferOperationInput {
+                from_account_id: transfers[0].from_account,
+                total_amount: candid::Nat::from(200u64),
+                destinations: transfers
+                    .iter()
+                    .map(|transfer| SplitTransferDestination {
+                        to: transfer.to_address.clone(),
+                        share: SplitTransferShare::BasisPoints(5_000),
+                    })
+                    .collect(),
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                memo: None,
+            },
+        });
+        request_repository.insert(request.to_key(), request.clone());
+        for transfer in transfers.iter() {
+            transfer_repository.insert(transfer.to_key(), transfer.clone());
+        }
+
+        // one of the transfers is still processing
+        settle_split_transfer(
+            &transfer_repository,
+            &request_repository,
+            &request_service,
+            &request.id,
+        )
+        .await;
+
+        assert!(matches!(
+            request_repository.get(&request.to_key()).unwrap().status,
+            RequestStatus::Processing { .. }
+        ));
+
+        transfers[1].status = TransferStatus::Failed {
+            reason: "Insufficient balance".to_string(),
+        };
+        transfer_repository.insert(transfers[1].to_key(), transfers[1].clone());
+
+        settle_split_transfer(
+            &transfer_repository,
+            &request_repository,
+            &request_service,
+            &request.id,
+        )
+        .await;
+
+        let RequestStatus::Failed {
+            reason: Some(reason),
+        } = request_repository.get(&request.to_key()).unwrap().status
+        else {
+            panic!("The request is expected to fail");
+        };
+        assert!(reason.starts_with("1 of 2 transfers failed"));
+        assert!(reason.contains("Insufficient balance"));
+    }
+}
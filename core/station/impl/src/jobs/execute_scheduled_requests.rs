@@ -1,13 +1,16 @@
-use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use super::{
+    partition::{run_partitioned, PartitionKey},
+    scheduler::Scheduler,
+    JobType, ScheduledJob,
+};
 use crate::{
     core::ic_cdk::next_time,
     errors::RequestExecuteError,
-    models::{Request, RequestStatus},
+    models::{Request, RequestDependencyState, RequestStatus},
     repositories::RequestRepository,
     services::RequestService,
 };
 use async_trait::async_trait;
-use futures::future;
 #[cfg(test)]
 use orbit_essentials::cdk::api::call::RejectionCode;
 #[cfg(not(test))]
@@ -39,15 +42,37 @@ impl Job {
 
     /// Processes all the requests that have been approved but are not yet executed.
     ///
-    /// This function will process a maximum of `MAX_BATCH_SIZE` requests at once.
+    /// This function will process a maximum of `MAX_BATCH_SIZE` requests at once, the requests that
+    /// touch different resources are executed concurrently while the ones that touch the same account or
+    /// external canister are executed one after the other.
     ///
     /// At any point in time, at most `MAX_PROCESSING_REQUESTS` requests can be processing at the same time.
     async fn execute_scheduled_requests(&self) -> bool {
         let current_time = next_time();
-        let mut requests = self
+        let scheduled_requests = self
             .request_repository
             .find_scheduled(None, Some(current_time));
 
+        // the requests wait for their dependency to complete and fail once it can no longer complete
+        let mut requests = Vec::with_capacity(scheduled_requests.len());
+        for mut request in scheduled_requests {
+            match request.dependency_state() {
+                RequestDependencyState::Satisfied => requests.push(request),
+                RequestDependencyState::Pending => {
+                    // scheduled again once the dependency is settled
+                    request.status = RequestStatus::Approved;
+                    request.last_modification_timestamp = next_time();
+                    self.request_repository
+                        .insert(request.to_key(), request.to_owned());
+                }
+                RequestDependencyState::Failed { reason } => {
+                    self.request_service
+                        .fail_request(request, reason, next_time())
+                        .await;
+                }
+            }
+        }
+
         let num_processing_requests = self.request_repository.get_num_processing();
         let batch_size = std::cmp::min(
             Self::MAX_PROCESSING_REQUESTS.saturating_sub(num_processing_requests),
@@ -70,15 +95,11 @@ impl Job {
                 .insert(request.to_key(), request.to_owned());
         }
 
-        // batch the requests to be executed
-        let calls = requests
-            .clone()
-            .into_iter()
-            .map(|request| self.execute_request(request));
-
-        // wait for all the requests to be executed
-        let results = future::join_all(calls).await;
-        let requests = requests.clone();
+        // wait for all the requests to be executed, partitioned by the resource they touch
+        let results = run_partitioned(requests.clone(), PartitionKey::of_request, |request| {
+            self.execute_request(request)
+        })
+        .await;
 
         // update the status of the requests
         for (pos, result) in results.iter().enumerate() {
@@ -0,0 +1,42 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::services::{ScheduledTransferService, SCHEDULED_TRANSFER_SERVICE};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    scheduled_transfer_service: Arc<ScheduledTransferService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            scheduled_transfer_service: Arc::clone(&SCHEDULED_TRANSFER_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ExecuteScheduledTransfers;
+
+    async fn run() -> bool {
+        Self::default().execute_scheduled_transfers().await
+    }
+}
+
+/// This job is responsible for creating the transfers of the standing orders that are due, each
+/// execution schedules the next one of its standing order.
+impl Job {
+    async fn execute_scheduled_transfers(&self) -> bool {
+        self.scheduled_transfer_service
+            .execute_due_scheduled_transfers()
+            .await;
+
+        true
+    }
+}
+
+pub fn schedule_execution(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
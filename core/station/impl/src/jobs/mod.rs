@@ -4,10 +4,17 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 
-use crate::core::ic_cdk::next_time;
 use crate::core::ic_timers::TimerId;
-use crate::models::{RequestExecutionPlan, RequestStatusCode};
-use crate::repositories::TRANSFER_REPOSITORY;
+use crate::core::{
+    ic_cdk::{next_time, spawn},
+    read_system_state,
+};
+use crate::models::{RequestDependencyState, RequestExecutionPlan, RequestStatusCode, SystemState};
+use crate::repositories::{
+    EXTERNAL_CANISTER_REPOSITORY, FUNDING_REQUEST_REPOSITORY, SCHEDULED_POLICY_CHANGE_REPOSITORY,
+    SCHEDULED_TRANSFER_REPOSITORY, TRANSFER_REPOSITORY,
+};
+use crate::services::REQUEST_SERVICE;
 use crate::{
     core::observer::Observer,
     models::{Request, RequestStatus, Transfer, TransferStatus},
@@ -16,16 +23,47 @@ use crate::{
 use async_trait::async_trait;
 use orbit_essentials::repository::Repository;
 
+mod activate_scheduled_policy_changes;
+mod aggregate_spending;
+mod archive_history;
 mod cancel_expired_requests;
+mod certify_attestation;
+mod certify_reserves;
+mod check_rpc_providers_health;
+mod confirm_submitted_transfers;
 mod execute_created_transfers;
 mod execute_scheduled_requests;
+mod execute_scheduled_transfers;
+mod monitor_external_canisters;
+mod partition;
+mod refresh_exchange_rates;
+mod remind_pending_voters;
+mod restore_certified_records;
+mod run_self_check;
 mod scheduler;
+mod validate_requests;
+mod watch_funding_requests;
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum JobType {
     CancelExpiredRequests,
     ExecuteScheduledRequests,
     ExecuteCreatedTransfers,
+    CheckRpcProvidersHealth,
+    CertifyAttestation,
+    ConfirmSubmittedTransfers,
+    MonitorExternalCanisters,
+    WatchFundingRequests,
+    AggregateSpending,
+    ActivateScheduledPolicyChanges,
+    RefreshExchangeRates,
+    CertifyReserves,
+    ExecuteScheduledTransfers,
+    ArchiveHistory,
+    ValidateRequests,
+    RunSelfCheck,
+    RemindPendingVoters,
+    RestoreCertifiedRecords,
 }
 
 #[async_trait]
@@ -137,6 +175,26 @@ impl JobStateDatabase {
         })
     }
 
+    /// Checks if the job type has any scheduled task.
+    fn has_scheduled_tasks(job_type: JobType) -> bool {
+        TIME_JOB_MAPS.with(|time_job_maps| {
+            time_job_maps
+                .borrow()
+                .get(&job_type)
+                .is_some_and(|job_map| !job_map.is_empty())
+        })
+    }
+
+    /// Returns the time of the earliest task scheduled for the job type, if any.
+    fn next_scheduled_task(job_type: JobType) -> Option<u64> {
+        TIME_JOB_MAPS.with(|time_job_maps| {
+            time_job_maps
+                .borrow()
+                .get(&job_type)
+                .and_then(|job_map| job_map.keys().min().copied())
+        })
+    }
+
     /// Returns a copy of the current state of the time job maps for testing purposes.
     #[cfg(test)]
     fn get_time_job_maps() -> HashMap<JobType, TimeJobMap> {
@@ -192,12 +250,30 @@ fn schedule_request_for_execution(request: &Request) -> u64 {
 pub fn jobs_observe_insert_request(observer: &mut Observer<(Request, Option<Request>)>) {
     observer.add_listener(Box::new(|(request, prev)| match &request.status {
         RequestStatus::Created => {
-            if prev.is_some() {
+            // requests that passed their validation are only now open for approvals
+            if !matches!(
+                prev,
+                None | Some(Request {
+                    status: RequestStatus::Validating,
+                    ..
+                })
+            ) {
                 return;
             }
 
             cancel_expired_requests::schedule_expiration(request.expiration_dt);
         }
+        RequestStatus::Validating => {
+            if let Some(Request {
+                status: RequestStatus::Created,
+                ..
+            }) = prev
+            {
+                cancel_expired_requests::cancel_scheduled_expiration(request.expiration_dt);
+            }
+
+            validate_requests::schedule_validation(next_time());
+        }
         RequestStatus::Approved => {
             if let Some(Request {
                 status: RequestStatus::Created,
@@ -211,7 +287,9 @@ pub fn jobs_observe_insert_request(observer: &mut Observer<(Request, Option<Requ
                 execute_scheduled_requests::schedule_request_execution(scheduled_at);
             }
         }
-        RequestStatus::Rejected | RequestStatus::Cancelled { .. } => {
+        RequestStatus::Rejected
+        | RequestStatus::Cancelled { .. }
+        | RequestStatus::Failed { .. } => {
             if let Some(Request {
                 status: RequestStatus::Created,
                 ..
@@ -219,18 +297,58 @@ pub fn jobs_observe_insert_request(observer: &mut Observer<(Request, Option<Requ
             {
                 cancel_expired_requests::cancel_scheduled_expiration(request.expiration_dt);
             }
+
+            settle_request_dependents(request, prev);
+        }
+        RequestStatus::Completed { .. } => {
+            settle_request_dependents(request, prev);
         }
         RequestStatus::Scheduled { .. } => {
             // do nothing, these will exectuted by the timers already set when the request was approved
         }
-        RequestStatus::Processing { .. }
-        | RequestStatus::Completed { .. }
-        | RequestStatus::Failed { .. } => {
+        RequestStatus::Processing { .. } => {
             // do nothing
         }
     }));
 }
 
+/// Schedules the approved requests that wait for the settled request, or fails the requests that
+/// depend on it once it can no longer complete.
+fn settle_request_dependents(request: &Request, prev: &Option<Request>) {
+    let status_changed = prev.as_ref().map_or(true, |prev| {
+        RequestStatusCode::from(prev.status.clone())
+            != RequestStatusCode::from(request.status.clone())
+    });
+    if !status_changed {
+        return;
+    }
+
+    for mut dependent in REQUEST_REPOSITORY.find_dependents(&request.id) {
+        match (&dependent.status, dependent.dependency_state()) {
+            (RequestStatus::Approved, RequestDependencyState::Satisfied) => {
+                let scheduled_at = schedule_request_for_execution(&dependent);
+
+                execute_scheduled_requests::schedule_request_execution(scheduled_at);
+            }
+            (
+                RequestStatus::Created | RequestStatus::Approved,
+                RequestDependencyState::Failed { reason },
+            ) => {
+                dependent.status = RequestStatus::Failed {
+                    reason: Some(reason),
+                };
+                dependent.last_modification_timestamp = next_time();
+                REQUEST_REPOSITORY.insert(dependent.to_key(), dependent.to_owned());
+
+                spawn(async move {
+                    REQUEST_SERVICE.failed_request_hook(&dependent).await;
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
 pub fn jobs_observe_remove_request(observer: &mut Observer<Request>) {
     observer.add_listener(Box::new(|prev| {
         if let Request {
@@ -259,15 +377,125 @@ pub fn jobs_observe_insert_transfer(observer: &mut Observer<(Transfer, Option<Tr
     }));
 }
 
+/// Starts the periodic health check of the RPC providers, unless it is already scheduled.
+pub fn schedule_rpc_providers_health_check() {
+    if !JobStateDatabase::has_scheduled_tasks(check_rpc_providers_health::Job::JOB_TYPE) {
+        check_rpc_providers_health::schedule_health_check(next_time());
+    }
+}
+
+/// Starts the periodic certification of the attestation of the station, unless it is already scheduled.
+pub fn schedule_attestation_certification() {
+    if !JobStateDatabase::has_scheduled_tasks(certify_attestation::Job::JOB_TYPE) {
+        certify_attestation::schedule_certification(next_time());
+    }
+}
+
+/// Starts the periodic monitoring of the external canisters, unless it is already scheduled.
+pub fn schedule_external_canister_monitoring() {
+    if !JobStateDatabase::has_scheduled_tasks(monitor_external_canisters::Job::JOB_TYPE) {
+        monitor_external_canisters::schedule_monitoring(next_time());
+    }
+}
+
+/// Starts watching the allowances of the pending funding requests, unless it is already scheduled.
+pub fn schedule_funding_request_watch() {
+    if !JobStateDatabase::has_scheduled_tasks(watch_funding_requests::Job::JOB_TYPE) {
+        watch_funding_requests::schedule_watch(next_time());
+    }
+}
+
+/// Starts the periodic aggregation of the completed transfers, unless it is already scheduled.
+pub fn schedule_spending_aggregation() {
+    if !JobStateDatabase::has_scheduled_tasks(aggregate_spending::Job::JOB_TYPE) {
+        aggregate_spending::schedule_aggregation(next_time());
+    }
+}
+
+/// Starts the periodic refresh of the exchange rates, unless it is already scheduled.
+pub fn schedule_exchange_rate_refresh() {
+    if !JobStateDatabase::has_scheduled_tasks(refresh_exchange_rates::Job::JOB_TYPE) {
+        refresh_exchange_rates::schedule_refresh(next_time());
+    }
+}
+
+/// Starts the periodic certification of the reserves, unless it is already scheduled.
+pub fn schedule_reserves_certification() {
+    if !JobStateDatabase::has_scheduled_tasks(certify_reserves::Job::JOB_TYPE) {
+        certify_reserves::schedule_certification(next_time());
+    }
+}
+
+/// Starts the periodic export of the settled history to the archive sink, unless it is already scheduled.
+pub fn schedule_history_archiving() {
+    if !JobStateDatabase::has_scheduled_tasks(archive_history::Job::JOB_TYPE) {
+        archive_history::schedule_archiving(next_time());
+    }
+}
+
+/// Starts the periodic reminders of the voters of the requests about to expire, unless it is already scheduled.
+pub fn schedule_pending_vote_reminders() {
+    if !JobStateDatabase::has_scheduled_tasks(remind_pending_voters::Job::JOB_TYPE) {
+        remind_pending_voters::schedule_reminders(next_time());
+    }
+}
+
+/// Starts adding the requests and transfers back to the certified tree, which is done once after each
+/// upgrade of the station.
+pub fn schedule_certified_records_restore() {
+    restore_certified_records::schedule_restore(next_time());
+}
+
+/// Runs the self-check suite, which is done once after each upgrade of the station.
+pub fn schedule_self_check() {
+    run_self_check::schedule_self_check(next_time());
+}
+
+/// Schedules the execution of the created transfers, e.g. once the transfers of a paused blockchain are resumed.
+pub fn schedule_created_transfers_execution() {
+    execute_created_transfers::schedule_process_transfers(next_time());
+}
+
+/// Returns the time of the next run of the job executing the scheduled requests, if it is scheduled.
+pub fn next_request_execution_at() -> Option<u64> {
+    JobStateDatabase::next_scheduled_task(execute_scheduled_requests::Job::JOB_TYPE)
+}
+
+/// Returns the time of the next run of the job executing the created transfers, if it is scheduled.
+pub fn next_transfer_execution_at() -> Option<u64> {
+    JobStateDatabase::next_scheduled_task(execute_created_transfers::Job::JOB_TYPE)
+}
+
+/// Schedules the activation of the policy changes that take effect at the given time.
+pub fn schedule_policy_change_activation(at_ns: u64) {
+    activate_scheduled_policy_changes::schedule_activation(at_ns);
+}
+
+/// Schedules the execution of the standing orders that are due at the given time.
+pub fn schedule_scheduled_transfer_execution(at_ns: u64) {
+    execute_scheduled_transfers::schedule_execution(at_ns);
+}
+
 pub fn initialize_job_timers() {
     // start the expiration timer for each request that is in Created state
     for request in REQUEST_REPOSITORY.find_by_status(RequestStatusCode::Created, None, None) {
         cancel_expired_requests::schedule_expiration(request.expiration_dt);
     }
 
-    // schedule requests that are already approved, but not yet scheduled
+    // schedule requests that are already approved, but not yet scheduled, unless they wait for
+    // their dependency
     for request in REQUEST_REPOSITORY.find_by_status(RequestStatusCode::Approved, None, None) {
-        schedule_request_for_execution(&request);
+        if request.dependency_state() != RequestDependencyState::Pending {
+            schedule_request_for_execution(&request);
+        }
+    }
+
+    if !REQUEST_REPOSITORY
+        .find_by_status(RequestStatusCode::Validating, None, None)
+        .is_empty()
+    {
+        // resume the validation of the requests, once is enough
+        validate_requests::schedule_validation(next_time());
     }
 
     // start the execution timer for each request that is in Scheduled state
@@ -284,6 +512,61 @@ pub fn initialize_job_timers() {
         // kick off execution timer for Transfers, once is enough
         execute_created_transfers::schedule_process_transfers(next_time());
     }
+
+    if !confirm_submitted_transfers::find_awaiting_confirmations(&TRANSFER_REPOSITORY).is_empty() {
+        // resume checking the confirmations of the submitted transfers
+        confirm_submitted_transfers::schedule_confirmations(next_time());
+    }
+
+    if EXTERNAL_CANISTER_REPOSITORY
+        .list()
+        .iter()
+        .any(|external_canister| external_canister.monitoring.is_some())
+    {
+        schedule_external_canister_monitoring();
+    }
+
+    schedule_attestation_certification();
+
+    if let SystemState::Initialized(system_info) = read_system_state() {
+        if !system_info.get_all_rpc_providers().is_empty() {
+            schedule_rpc_providers_health_check();
+        }
+    }
+
+    if !FUNDING_REQUEST_REPOSITORY.find_pending().is_empty() {
+        schedule_funding_request_watch();
+    }
+
+    // resume the activation of the policy changes that are not yet in effect
+    for scheduled_change in SCHEDULED_POLICY_CHANGE_REPOSITORY.find_pending() {
+        schedule_policy_change_activation(scheduled_change.effective_from);
+    }
+
+    // resume the standing orders, the ones that became due during the upgrade are executed right away
+    for scheduled_transfer in SCHEDULED_TRANSFER_REPOSITORY.find_active() {
+        schedule_scheduled_transfer_execution(
+            scheduled_transfer.next_execution_at.max(next_time()),
+        );
+    }
+
+    schedule_spending_aggregation();
+    schedule_exchange_rate_refresh();
+    schedule_reserves_certification();
+
+    if let SystemState::Initialized(system_info) = read_system_state() {
+        if system_info.get_archive_sink().is_some() {
+            schedule_history_archiving();
+        }
+
+        if system_info
+            .get_approval_reminders()
+            .pending_vote_lead_time_secs
+            .is_some()
+        {
+            schedule_pending_vote_reminders();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -300,7 +583,10 @@ mod test {
         RequestRepository, TransferRepository, ACCOUNT_REPOSITORY, TRANSFER_REPOSITORY,
     };
     use crate::{
-        jobs::{cancel_expired_requests, to_coarse_time, JobStateDatabase, ScheduledJob},
+        jobs::{
+            cancel_expired_requests, next_transfer_execution_at, to_coarse_time, JobStateDatabase,
+            ScheduledJob,
+        },
         models::{request_test_utils::mock_request, Request},
         repositories::REQUEST_REPOSITORY,
     };
@@ -407,6 +693,66 @@ mod test {
             .is_none());
     }
 
+    #[tokio::test]
+    async fn test_request_dependents_follow_their_dependency() {
+        let mut dependency = Request {
+            status: RequestStatus::Processing { started_at: 0 },
+            ..mock_request()
+        };
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        // the approved dependent waits for the dependency to complete
+        let waiting = Request {
+            status: RequestStatus::Approved,
+            depends_on: Some(dependency.id),
+            ..mock_request()
+        };
+        let not_approved = Request {
+            status: RequestStatus::Created,
+            depends_on: Some(dependency.id),
+            ..mock_request()
+        };
+        REQUEST_REPOSITORY.insert(waiting.to_key(), waiting.clone());
+        REQUEST_REPOSITORY.insert(not_approved.to_key(), not_approved.clone());
+
+        dependency.status = RequestStatus::Completed { completed_at: 0 };
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        assert!(matches!(
+            REQUEST_REPOSITORY.get(&waiting.to_key()).unwrap().status,
+            RequestStatus::Scheduled { .. }
+        ));
+        assert_eq!(
+            REQUEST_REPOSITORY
+                .get(&not_approved.to_key())
+                .unwrap()
+                .status,
+            RequestStatus::Created
+        );
+
+        // the dependents of a rejected dependency fail, even before they are approved
+        let mut rejected_dependency = Request {
+            status: RequestStatus::Created,
+            ..mock_request()
+        };
+        REQUEST_REPOSITORY.insert(rejected_dependency.to_key(), rejected_dependency.clone());
+
+        let dependent = Request {
+            status: RequestStatus::Created,
+            depends_on: Some(rejected_dependency.id),
+            ..mock_request()
+        };
+        REQUEST_REPOSITORY.insert(dependent.to_key(), dependent.clone());
+
+        rejected_dependency.status = RequestStatus::Rejected;
+        REQUEST_REPOSITORY.insert(rejected_dependency.to_key(), rejected_dependency.clone());
+
+        assert!(matches!(
+            REQUEST_REPOSITORY.get(&dependent.to_key()).unwrap().status,
+            RequestStatus::Failed { .. }
+        ));
+    }
+
     #[tokio::test]
     async fn test_request_removal() {
         assert!(JobStateDatabase::get_time_job_maps()
@@ -463,12 +809,14 @@ mod test {
                 .1,
             1
         );
+        assert_eq!(next_transfer_execution_at(), Some(coarse_time));
 
         Scheduler::run_scheduled::<execute_created_transfers::Job>(coarse_time).await;
 
         assert!(JobStateDatabase::get_time_job_maps()
             .get(&execute_created_transfers::Job::JOB_TYPE)
             .is_none());
+        assert_eq!(next_transfer_execution_at(), None);
     }
 
     #[tokio::test]
@@ -528,8 +876,9 @@ mod test {
         // initialize the job timers
         crate::jobs::initialize_job_timers();
 
-        // all 3 job types should have timers set
-        assert_eq!(JobStateDatabase::get_time_job_maps().len(), 3);
+        // all 3 job types and the periodic attestation, spending, exchange rate and reserves jobs should
+        // have timers set
+        assert_eq!(JobStateDatabase::get_time_job_maps().len(), 7);
 
         // 2 requests are scheduled for expiration
         assert_eq!(
@@ -0,0 +1,333 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::{
+        authorization::Authorization,
+        ic_cdk::{api::print, next_time},
+        CallContext,
+    },
+    mappers::HelperMapper,
+    models::{
+        resource::{ExternalCanisterId, ExternalCanisterResourceAction, Resource},
+        ExternalCanister, ExternalCanisterEntryId, ExternalCanisterKey,
+        ExternalCanisterMonitoringEvent, ExternalCanisterMonitoringNotification, NotificationType,
+        User, UserId,
+    },
+    repositories::{
+        ExternalCanisterRepository, UserRepository, EXTERNAL_CANISTER_REPOSITORY, USER_REPOSITORY,
+    },
+    services::{
+        ExternalCanisterService, NotificationService, RequestService, EXTERNAL_CANISTER_SERVICE,
+        NOTIFICATION_SERVICE, REQUEST_SERVICE,
+    },
+};
+use async_trait::async_trait;
+use futures::future;
+use ic_cdk::api::management_canister::main::{
+    CanisterIdRecord, CanisterStatusResponse, CanisterStatusType,
+};
+use orbit_essentials::{model::ModelKey, repository::Repository, types::UUID};
+use station_api::{
+    CreateRequestInput, FundExternalCanisterOperationInput, FundExternalCanisterOperationKindDTO,
+    FundExternalCanisterSendCyclesInput, RequestOperationInput,
+};
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    external_canister_repository: Arc<ExternalCanisterRepository>,
+    user_repository: Arc<UserRepository>,
+    external_canister_service: Arc<ExternalCanisterService>,
+    notification_service: Arc<NotificationService>,
+    request_service: Arc<RequestService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            external_canister_repository: Arc::clone(&EXTERNAL_CANISTER_REPOSITORY),
+            user_repository: Arc::clone(&USER_REPOSITORY),
+            external_canister_service: Arc::clone(&EXTERNAL_CANISTER_SERVICE),
+            notification_service: Arc::clone(&NOTIFICATION_SERVICE),
+            request_service: Arc::clone(&REQUEST_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::MonitorExternalCanisters;
+
+    async fn run() -> bool {
+        Self::default().monitor_external_canisters().await
+    }
+}
+
+/// This job is responsible for checking the status of the monitored external canisters against their
+/// monitoring rules, notifying the users that can change the canisters about the detected events.
+impl Job {
+    /// The interval between two checks of the monitored external canisters.
+    pub const MONITORING_INTERVAL_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    /// Checks all the monitored external canisters and schedules the next check while there are any.
+    async fn monitor_external_canisters(&self) -> bool {
+        let external_canisters = self
+            .external_canister_repository
+            .list()
+            .into_iter()
+            .filter(|external_canister| {
+                external_canister.monitoring.is_some() && !external_canister.is_archived()
+            })
+            .collect::<Vec<_>>();
+
+        if external_canisters.is_empty() {
+            return true;
+        }
+
+        let statuses = future::join_all(external_canisters.iter().map(|external_canister| {
+            self.external_canister_service
+                .canister_status(CanisterIdRecord {
+                    canister_id: external_canister.canister_id,
+                })
+        }))
+        .await;
+
+        for (external_canister, status) in external_canisters.into_iter().zip(statuses) {
+            match status {
+                Ok(status) => self.observe_status(&external_canister.id, status).await,
+                Err(error) => print(format!(
+                    "Failed to fetch the status of the external canister {}: {}",
+                    external_canister.canister_id, error
+                )),
+            }
+        }
+
+        schedule_monitoring(next_time().saturating_add(Self::MONITORING_INTERVAL_NS));
+
+        true
+    }
+
+    async fn observe_status(&self, id: &ExternalCanisterEntryId, status: CanisterStatusResponse) {
+        // the entry is read again since it could have changed while the status was being fetched
+        let Some(mut external_canister) = self
+            .external_canister_repository
+            .get(&ExternalCanisterKey { id: *id })
+        else {
+            return;
+        };
+        let Some(monitoring) = external_canister.monitoring.as_mut() else {
+            return;
+        };
+
+        let events = monitoring.observe(
+            HelperMapper::nat_to_u64(status.cycles).unwrap_or(u64::MAX),
+            status.status != CanisterStatusType::Running,
+            status.module_hash,
+            next_time(),
+        );
+        let configured_by = monitoring.configured_by;
+        let auto_fund_cycles = monitoring.rules.auto_fund_cycles;
+
+        self.external_canister_repository
+            .insert(external_canister.key(), external_canister.clone());
+
+        for mut event in events {
+            if let (
+                ExternalCanisterMonitoringEvent::LowCycles {
+                    fund_request_id, ..
+                },
+                Some(cycles),
+            ) = (&mut event, auto_fund_cycles)
+            {
+                *fund_request_id = self
+                    .request_funding(&external_canister, &configured_by, cycles)
+                    .await;
+            }
+
+            self.notify(&external_canister, event).await;
+        }
+    }
+
+    /// Creates a `FundExternalCanister` request on behalf of the user that configured the monitoring.
+    async fn request_funding(
+        &self,
+        external_canister: &ExternalCanister,
+        requested_by: &UserId,
+        cycles: u64,
+    ) -> Option<UUID> {
+        let requester = self
+            .user_repository
+            .get(&User::key(*requested_by))
+            .filter(|user| user.is_active())?;
+        let ctx = CallContext::new(*requester.identities.first()?);
+
+        let result = self
+            .request_service
+            .create_request(
+                CreateRequestInput {
+                    operation: RequestOperationInput::FundExternalCanister(
+                        FundExternalCanisterOperationInput {
+                            canister_id: external_canister.canister_id,
+                            kind: FundExternalCanisterOperationKindDTO::Send(
+                                FundExternalCanisterSendCyclesInput {
+                                    cycles,
+                                    from_account_id: None,
+                                },
+                            ),
+                        },
+                    ),
+                    title: Some(format!("Fund {}", external_canister.name)),
+                    summary: Some(
+                        "Automatically requested by the monitoring of the canister after its cycles fell below the threshold."
+                            .to_string(),
+                    ),
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                &ctx,
+            )
+            .await;
+
+        match result {
+            Ok(request) => Some(request.id),
+            Err(error) => {
+                print(format!(
+                    "Failed to request the funding of the external canister {}: {}",
+                    external_canister.canister_id, error
+                ));
+
+                None
+            }
+        }
+    }
+
+    /// Notifies the event to the active users that can change the external canister.
+    async fn notify(
+        &self,
+        external_canister: &ExternalCanister,
+        event: ExternalCanisterMonitoringEvent,
+    ) {
+        let (title, message) = match &event {
+            ExternalCanisterMonitoringEvent::LowCycles {
+                cycles, threshold, ..
+            } => (
+                format!("Low cycles on {}", external_canister.name),
+                format!(
+                    "The canister {} has {} cycles, which is below the threshold of {} cycles.",
+                    external_canister.canister_id, cycles, threshold
+                ),
+            ),
+            ExternalCanisterMonitoringEvent::Stopped => (
+                format!("{} is stopped", external_canister.name),
+                format!(
+                    "The canister {} is stopping or stopped.",
+                    external_canister.canister_id
+                ),
+            ),
+            ExternalCanisterMonitoringEvent::ModuleHashChanged { .. } => (
+                format!("{} was upgraded", external_canister.name),
+                format!(
+                    "The module hash of the canister {} has changed.",
+                    external_canister.canister_id
+                ),
+            ),
+        };
+
+        let resource = Resource::ExternalCanister(ExternalCanisterResourceAction::Change(
+            ExternalCanisterId::Canister(external_canister.canister_id),
+        ));
+
+        for user in self.user_repository.list() {
+            let can_change = user.is_active()
+                && user.identities.first().is_some_and(|identity| {
+                    Authorization::is_allowed(&CallContext::new(*identity), &resource)
+                });
+
+            if !can_change {
+                continue;
+            }
+
+            self.notification_service
+                .send_notification(
+                    user.id,
+                    NotificationType::ExternalCanisterMonitoring(
+                        ExternalCanisterMonitoringNotification {
+                            external_canister_id: external_canister.id,
+                            canister_id: external_canister.canister_id,
+                            event: event.clone(),
+                        },
+                    ),
+                    title.to_owned(),
+                    Some(message.to_owned()),
+                )
+                .await;
+        }
+    }
+}
+
+pub fn schedule_monitoring(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            external_canister_test_utils::mock_external_canister,
+            permission::{Allow, Permission},
+            user_test_utils::mock_user,
+            UserStatus,
+        },
+        repositories::{permission::PERMISSION_REPOSITORY, NOTIFICATION_REPOSITORY},
+    };
+
+    #[tokio::test]
+    async fn notifies_users_that_can_change_the_canister() {
+        test_utils::init_canister_system();
+
+        let operator = mock_user();
+        let inactive_operator = User {
+            status: UserStatus::Inactive,
+            ..mock_user()
+        };
+        let other_user = mock_user();
+        for user in [&operator, &inactive_operator, &other_user] {
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+        }
+
+        let permission = Permission::new(
+            Allow::users(vec![operator.id, inactive_operator.id]),
+            Resource::ExternalCanister(ExternalCanisterResourceAction::Change(
+                ExternalCanisterId::Any,
+            )),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let external_canister = mock_external_canister();
+
+        Job::default()
+            .notify(&external_canister, ExternalCanisterMonitoringEvent::Stopped)
+            .await;
+
+        let notifications = NOTIFICATION_REPOSITORY.find_by_user_id(operator.id);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0].notification_type,
+            NotificationType::ExternalCanisterMonitoring(ExternalCanisterMonitoringNotification {
+                external_canister_id: external_canister.id,
+                canister_id: external_canister.canister_id,
+                event: ExternalCanisterMonitoringEvent::Stopped,
+            })
+        );
+
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(inactive_operator.id)
+            .is_empty());
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(other_user.id)
+            .is_empty());
+    }
+}
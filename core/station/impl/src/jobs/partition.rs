@@ -0,0 +1,150 @@
+//! Partitions the work of the jobs by the resource it touches.
+//!
+//! Items of different partitions are executed concurrently while the items of the same partition are
+//! executed one after the other, which avoids conflicting operations on the same resource (e.g. two
+//! transfers spending the same outputs of a Bitcoin account) without serializing the whole batch.
+use crate::models::{AccountId, Request, RequestOperation};
+use candid::Principal;
+use futures::future;
+use orbit_essentials::types::UUID;
+use std::{collections::BTreeMap, future::Future};
+
+/// The resource touched by an item of a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PartitionKey {
+    Account(AccountId),
+    ExternalCanister(Principal),
+    /// The item does not touch a shared resource and has a partition of its own.
+    Unique(UUID),
+}
+
+impl PartitionKey {
+    /// Returns the partition of the request, which is the account or external canister its operation touches.
+    pub fn of_request(request: &Request) -> Self {
+        match &request.operation {
+            RequestOperation::Transfer(operation) => {
+                PartitionKey::Account(operation.input.from_account_id)
+            }
+            RequestOperation::SweepAccount(operation) => {
+                PartitionKey::Account(operation.input.from_account_id)
+            }
+            RequestOperation::SplitTransfer(operation) => {
+                PartitionKey::Account(operation.input.from_account_id)
+            }
+            RequestOperation::Approve(operation) => {
+                PartitionKey::Account(operation.input.from_account_id)
+            }
+            RequestOperation::SwapTokens(operation) => {
+                PartitionKey::Account(operation.input.from_account_id)
+            }
+            RequestOperation::AddScheduledTransfer(operation) => {
+                PartitionKey::Account(operation.input.transfer.from_account_id)
+            }
+            RequestOperation::TransferNft(operation) => {
+                PartitionKey::Account(operation.input.from_account_id)
+            }
+            RequestOperation::EditAccount(operation) => {
+                PartitionKey::Account(operation.input.account_id)
+            }
+            RequestOperation::DeriveSubaccount(operation) => {
+                PartitionKey::Account(operation.input.account_id)
+            }
+            RequestOperation::CreateCapabilityGrant(operation) => {
+                PartitionKey::Account(operation.input.account_id)
+            }
+            RequestOperation::ChangeExternalCanister(operation) => {
+                PartitionKey::ExternalCanister(operation.input.canister_id)
+            }
+            RequestOperation::ConfigureExternalCanister(operation) => {
+                PartitionKey::ExternalCanister(operation.canister_id)
+            }
+            RequestOperation::FundExternalCanister(operation) => {
+                PartitionKey::ExternalCanister(operation.canister_id)
+            }
+            RequestOperation::CallExternalCanister(operation) => {
+                PartitionKey::ExternalCanister(operation.input.execution_method.canister_id)
+            }
+            _ => PartitionKey::Unique(request.id),
+        }
+    }
+}
+
+/// Runs the items partitioned by the given key and returns the results in the order of the items.
+pub async fn run_partitioned<T, R, Fut>(
+    items: Vec<T>,
+    key: impl Fn(&T) -> PartitionKey,
+    run: impl Fn(T) -> Fut,
+) -> Vec<R>
+where
+    Fut: Future<Output = R>,
+{
+    let mut partitions: BTreeMap<PartitionKey, Vec<(usize, T)>> = BTreeMap::new();
+    for (pos, item) in items.into_iter().enumerate() {
+        partitions.entry(key(&item)).or_default().push((pos, item));
+    }
+
+    let run = &run;
+    let results = future::join_all(partitions.into_values().map(|partition| async move {
+        let mut results = Vec::with_capacity(partition.len());
+        for (pos, item) in partition {
+            results.push((pos, run(item).await));
+        }
+
+        results
+    }))
+    .await;
+
+    let mut results: Vec<(usize, R)> = results.into_iter().flatten().collect();
+    results.sort_by_key(|(pos, _)| *pos);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[tokio::test]
+    async fn items_of_the_same_partition_run_one_after_the_other() {
+        let running: Rc<RefCell<Vec<u8>>> = Rc::default();
+        let max_running_of_account: Rc<RefCell<usize>> = Rc::default();
+
+        let items = vec![([1; 16], 1), ([2; 16], 2), ([1; 16], 3), ([1; 16], 4)];
+        let results = run_partitioned(
+            items,
+            |(account_id, _)| PartitionKey::Account(*account_id),
+            |(account_id, value)| {
+                let running = running.clone();
+                let max_running_of_account = max_running_of_account.clone();
+
+                async move {
+                    running.borrow_mut().push(account_id[0]);
+                    let of_account = running
+                        .borrow()
+                        .iter()
+                        .filter(|id| **id == account_id[0])
+                        .count();
+                    let mut max_running = max_running_of_account.borrow_mut();
+                    *max_running = (*max_running).max(of_account);
+                    drop(max_running);
+
+                    tokio::task::yield_now().await;
+
+                    let pos = running
+                        .borrow()
+                        .iter()
+                        .position(|id| *id == account_id[0])
+                        .unwrap();
+                    running.borrow_mut().remove(pos);
+
+                    value * 10
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(results, vec![10, 20, 30, 40]);
+        assert_eq!(*max_running_of_account.borrow(), 1);
+    }
+}
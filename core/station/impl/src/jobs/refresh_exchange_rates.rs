@@ -0,0 +1,48 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    services::{ExchangeRateService, EXCHANGE_RATE_SERVICE},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    exchange_rate_service: Arc<ExchangeRateService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            exchange_rate_service: Arc::clone(&EXCHANGE_RATE_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RefreshExchangeRates;
+
+    async fn run() -> bool {
+        Self::default().refresh_exchange_rates().await
+    }
+}
+
+/// This job is responsible for refreshing the cached exchange rates of the assets held by the accounts.
+impl Job {
+    /// The interval between two refreshes, well below the age after which a cached rate is no longer used.
+    pub const REFRESH_INTERVAL_NS: u64 = 10 * 60 * 1_000_000_000;
+
+    /// Refreshes the exchange rates and schedules the next run.
+    async fn refresh_exchange_rates(&self) -> bool {
+        self.exchange_rate_service.refresh_rates().await;
+
+        schedule_refresh(next_time().saturating_add(Self::REFRESH_INTERVAL_NS));
+
+        true
+    }
+}
+
+pub fn schedule_refresh(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -0,0 +1,197 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::{
+        ic_cdk::{api::print, next_time},
+        read_system_info,
+    },
+    models::{
+        NotificationType, PendingVoteReminderNotification, Request, RequestStatus,
+        RequestStatusCode, User, UserId,
+    },
+    repositories::{RequestRepository, UserRepository, REQUEST_REPOSITORY, USER_REPOSITORY},
+    services::{LocaleService, NotificationService, LOCALE_SERVICE, NOTIFICATION_SERVICE},
+};
+use async_trait::async_trait;
+use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
+use std::{collections::HashSet, sync::Arc};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct Job {
+    request_repository: Arc<RequestRepository>,
+    user_repository: Arc<UserRepository>,
+    locale_service: Arc<LocaleService>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            request_repository: Arc::clone(&REQUEST_REPOSITORY),
+            user_repository: Arc::clone(&USER_REPOSITORY),
+            locale_service: Arc::clone(&LOCALE_SERVICE),
+            notification_service: Arc::clone(&NOTIFICATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RemindPendingVoters;
+
+    async fn run() -> bool {
+        Self::default().remind_pending_voters().await
+    }
+}
+
+/// This job is responsible for reminding the possible approvers that did not vote yet on the
+/// requests that expire within the lead time configured in the approval reminders.
+impl Job {
+    /// The interval between two runs, which is how late a reminder can be sent.
+    pub const REMINDER_INTERVAL_NS: u64 = 5 * 60 * 1_000_000_000;
+
+    /// Reminds the voters of the requests entering the lead time and schedules the next run, the job
+    /// stops once the reminders are disabled.
+    async fn remind_pending_voters(&self) -> bool {
+        let Some(lead_time_secs) = read_system_info()
+            .get_approval_reminders()
+            .pending_vote_lead_time_secs
+        else {
+            return true;
+        };
+
+        let now = next_time();
+        let requests = self.request_repository.find_by_status_and_expiration_dt(
+            RequestStatusCode::Created,
+            Some(now),
+            Some(now.saturating_add(lead_time_secs.saturating_mul(1_000_000_000))),
+        );
+
+        for request in requests
+            .into_iter()
+            .filter(|request| !request.vote_reminder_sent)
+        {
+            match request.find_all_possible_approvers().await {
+                Ok(possible_approvers) => self.remind(&request, possible_approvers).await,
+                Err(error) => print(format!(
+                    "Failed to find the possible approvers of request {}: {}",
+                    Uuid::from_bytes(request.id).hyphenated(),
+                    error
+                )),
+            }
+        }
+
+        schedule_reminders(now.saturating_add(Self::REMINDER_INTERVAL_NS));
+
+        true
+    }
+
+    /// Notifies the active possible approvers that did not vote yet, the request is only reminded once.
+    async fn remind(&self, request: &Request, possible_approvers: HashSet<UserId>) {
+        // the request is read again since it could have been decided while looking up its approvers
+        let Some(mut request) = self.request_repository.get(&request.to_key()) else {
+            return;
+        };
+
+        if request.status != RequestStatus::Created || request.vote_reminder_sent {
+            return;
+        }
+
+        request.vote_reminder_sent = true;
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
+        let expiration_dt = timestamp_to_rfc3339(&request.expiration_dt);
+        let args = [
+            ("request_title", request.title.as_str()),
+            ("expiration_dt", expiration_dt.as_str()),
+        ];
+
+        let voters = possible_approvers
+            .into_iter()
+            .filter(|user_id| {
+                !request
+                    .approvals
+                    .iter()
+                    .any(|approval| approval.approver_id == *user_id)
+            })
+            .filter_map(|user_id| self.user_repository.get(&User::key(user_id)))
+            .filter(|user| user.is_active());
+
+        for user in voters {
+            let title = self
+                .locale_service
+                .render(&user, "pending_vote_reminder.title", &args);
+            let message = self
+                .locale_service
+                .render(&user, "pending_vote_reminder.message", &args);
+
+            self.notification_service
+                .send_notification(
+                    user.id,
+                    NotificationType::PendingVoteReminder(PendingVoteReminderNotification {
+                        request_id: request.id,
+                    }),
+                    title,
+                    Some(message),
+                )
+                .await;
+        }
+    }
+}
+
+pub fn schedule_reminders(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{request_test_utils::mock_request, user_test_utils::mock_user},
+        repositories::NOTIFICATION_REPOSITORY,
+    };
+
+    #[tokio::test]
+    async fn reminds_the_approvers_that_did_not_vote_once() {
+        test_utils::init_canister_system();
+
+        let voted = mock_user();
+        let pending = mock_user();
+        let mut muted = mock_user();
+        muted.muted_notification_types = vec!["pending-vote-reminder".to_string()];
+        for user in [&voted, &pending, &muted] {
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+        }
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.approvals[0].approver_id = voted.id;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let possible_approvers = HashSet::from([voted.id, pending.id, muted.id]);
+        let job = Job::default();
+        job.remind(&request, possible_approvers.clone()).await;
+        job.remind(&request, possible_approvers).await;
+
+        let notifications = NOTIFICATION_REPOSITORY.find_by_user_id(pending.id);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0].notification_type,
+            NotificationType::PendingVoteReminder(PendingVoteReminderNotification {
+                request_id: request.id,
+            })
+        );
+        assert!(notifications[0].title.contains(&request.title));
+
+        assert!(NOTIFICATION_REPOSITORY.find_by_user_id(voted.id).is_empty());
+        assert!(NOTIFICATION_REPOSITORY.find_by_user_id(muted.id).is_empty());
+        assert!(
+            REQUEST_REPOSITORY
+                .get(&request.to_key())
+                .unwrap()
+                .vote_reminder_sent
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::services::{RecordCertificationService, RECORD_CERTIFICATION_SERVICE};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    record_certification_service: Arc<RecordCertificationService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            record_certification_service: Arc::clone(&RECORD_CERTIFICATION_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RestoreCertifiedRecords;
+
+    async fn run() -> bool {
+        Self::default().restore_certified_records().await
+    }
+}
+
+/// This job is responsible for adding the requests and transfers back to the certified tree after an
+/// upgrade, the records are not certified until it completes.
+impl Job {
+    pub const MAX_BATCH_SIZE: usize = 500;
+
+    /// Restores the next batch of records, the job is rescheduled until all of them are restored.
+    ///
+    /// This function will process a maximum of `MAX_BATCH_SIZE` records at once.
+    async fn restore_certified_records(&self) -> bool {
+        self.record_certification_service
+            .restore_certified_records(Self::MAX_BATCH_SIZE)
+    }
+}
+
+pub fn schedule_restore(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
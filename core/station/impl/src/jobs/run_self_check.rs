@@ -0,0 +1,39 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::services::{SelfCheckService, SELF_CHECK_SERVICE};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    self_check_service: Arc<SelfCheckService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            self_check_service: Arc::clone(&SELF_CHECK_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::RunSelfCheck;
+
+    async fn run() -> bool {
+        Self::default().run_self_check().await
+    }
+}
+
+/// This job is responsible for running the self-check suite once after the station was upgraded.
+impl Job {
+    async fn run_self_check(&self) -> bool {
+        self.self_check_service.run_self_check().await;
+
+        true
+    }
+}
+
+pub fn schedule_self_check(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -0,0 +1,87 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::api::print,
+    models::RequestStatusCode,
+    repositories::RequestRepository,
+    services::{RequestService, REQUEST_SERVICE},
+};
+use async_trait::async_trait;
+use futures::future;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct Job {
+    request_repository: RequestRepository,
+    request_service: Arc<RequestService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            request_repository: RequestRepository::default(),
+            request_service: Arc::clone(&REQUEST_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::ValidateRequests;
+
+    async fn run() -> bool {
+        Self::default().validate_requests().await
+    }
+}
+
+/// This job is responsible for running the validations of the requests that require inter-canister
+/// calls, so that creating these requests does not have to wait for the calls.
+impl Job {
+    pub const MAX_BATCH_SIZE: usize = 20;
+
+    /// Validates the requests awaiting validation, the requests that passed are opened for approvals
+    /// and the other ones are failed with the reason.
+    ///
+    /// This function will process a maximum of `MAX_BATCH_SIZE` requests at once.
+    async fn validate_requests(&self) -> bool {
+        let requests = self
+            .request_repository
+            .find_by_status(RequestStatusCode::Validating, None, None)
+            .into_iter()
+            .take(Self::MAX_BATCH_SIZE + 1)
+            .collect::<Vec<_>>();
+
+        let has_more = requests.len() > Self::MAX_BATCH_SIZE;
+
+        let validations =
+            requests
+                .into_iter()
+                .take(Self::MAX_BATCH_SIZE)
+                .map(|request| async move {
+                    let request_id = request.id;
+
+                    (
+                        request_id,
+                        self.request_service
+                            .complete_request_validation(request)
+                            .await,
+                    )
+                });
+
+        for (request_id, result) in future::join_all(validations).await {
+            if let Err(error) = result {
+                print(format!(
+                    "Failed to complete the validation of request {}: {}",
+                    Uuid::from_bytes(request_id).hyphenated(),
+                    error
+                ));
+            }
+        }
+
+        !has_more
+    }
+}
+
+pub fn schedule_validation(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
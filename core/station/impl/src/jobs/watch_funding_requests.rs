@@ -0,0 +1,53 @@
+use super::{scheduler::Scheduler, JobType, ScheduledJob};
+use crate::{
+    core::ic_cdk::next_time,
+    services::{FundingRequestService, FUNDING_REQUEST_SERVICE},
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct Job {
+    funding_request_service: Arc<FundingRequestService>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            funding_request_service: Arc::clone(&FUNDING_REQUEST_SERVICE),
+        }
+    }
+}
+
+#[async_trait]
+impl ScheduledJob for Job {
+    const JOB_TYPE: JobType = JobType::WatchFundingRequests;
+
+    async fn run() -> bool {
+        Self::default().watch_funding_requests().await
+    }
+}
+
+/// This job is responsible for watching the allowances approved by the payers of the pending funding
+/// requests, requesting the collection of the funds once they are approved.
+impl Job {
+    /// The interval between two checks of the allowances.
+    pub const WATCH_INTERVAL_NS: u64 = 5 * 60 * 1_000_000_000;
+
+    /// Checks the pending funding requests and schedules the next check while there are any left.
+    async fn watch_funding_requests(&self) -> bool {
+        if self
+            .funding_request_service
+            .watch_pending_funding_requests()
+            .await
+        {
+            schedule_watch(next_time().saturating_add(Self::WATCH_INTERVAL_NS));
+        }
+
+        true
+    }
+}
+
+pub fn schedule_watch(at_ns: u64) {
+    Scheduler::schedule::<Job>(at_ns);
+}
@@ -1,15 +1,21 @@
 use crate::{
     core::ic_cdk::next_time,
     errors::MapperError,
+    factories::blockchains::BlockchainPendingDeposit,
     models::{
-        Account, AccountBalance, AccountCallerPrivileges, AccountId, AddAccountOperationInput,
-        BlockchainStandard, ACCOUNT_METADATA_SYMBOL_KEY,
+        Account, AccountBalance, AccountCallerPrivileges, AccountDiscovery, AccountId,
+        AddAccountOperationInput, BlockchainStandard, DiscoveredAccount, FiatValue,
+        ACCOUNT_METADATA_SYMBOL_KEY,
     },
-    repositories::request_policy::REQUEST_POLICY_REPOSITORY,
+    repositories::{request_policy::REQUEST_POLICY_REPOSITORY, REGISTERED_ASSET_REPOSITORY},
 };
 use ic_cdk::print;
 use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
-use station_api::{AccountBalanceDTO, AccountBalanceInfoDTO, AccountDTO};
+use station_api::{
+    AccountAssetDTO, AccountBalanceDTO, AccountBalanceInfoDTO, AccountDTO, AccountSubaccountDTO,
+    DiscoverAccountsResponse, DiscoveredAccountDTO, FailedLedgerDiscoveryDTO, PendingDepositDTO,
+    ReserveAccountDTO,
+};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
@@ -17,6 +23,8 @@ pub struct AccountMapper {}
 
 impl AccountMapper {
     pub fn to_dto(account: Account) -> AccountDTO {
+        let assets = Self::to_asset_dtos(&account);
+
         AccountDTO {
             id: Uuid::from_bytes(account.id).hyphenated().to_string(),
             name: account.name,
@@ -31,10 +39,27 @@ impl AccountMapper {
                 }),
                 None => None,
             },
+            subaccounts: account
+                .subaccounts
+                .into_iter()
+                .map(|subaccount| AccountSubaccountDTO {
+                    index: subaccount.index,
+                    name: subaccount.name,
+                    address: subaccount.address,
+                    balance: subaccount.balance.map(|balance| AccountBalanceInfoDTO {
+                        balance: balance.balance,
+                        decimals: account.decimals,
+                        last_update_timestamp: timestamp_to_rfc3339(
+                            &balance.last_modification_timestamp,
+                        ),
+                    }),
+                })
+                .collect(),
             symbol: account.symbol,
             address: account.address,
             standard: account.standard.to_string(),
             blockchain: account.blockchain.to_string(),
+            network: account.network.into(),
             metadata: account.metadata.into_vec_dto(),
             transfer_request_policy: account.transfer_request_policy_id.and_then(|policy_id| {
                 REQUEST_POLICY_REPOSITORY
@@ -60,6 +85,13 @@ impl AccountMapper {
                         None
                     })
             }),
+            trusted_destinations: account
+                .trusted_destinations
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            assets,
+            webhooks: account.webhooks.into_iter().map(Into::into).collect(),
             last_modification_timestamp: timestamp_to_rfc3339(&account.last_modification_timestamp),
         }
     }
@@ -103,6 +135,7 @@ impl AccountMapper {
             id: account_id,
             blockchain: input.blockchain,
             standard: input.standard,
+            network: input.network,
             name: input.name,
             address: address.unwrap_or("".to_string()),
             decimals: 0,
@@ -111,22 +144,91 @@ impl AccountMapper {
             configs_request_policy_id: None,
             balance: None,
             metadata: input.metadata,
+            trusted_destinations: Vec::new(),
+            subaccounts: Vec::new(),
+            webhooks: Vec::new(),
+            assets: Vec::new(),
             last_modification_timestamp: next_time(),
         };
 
         Ok(new_account)
     }
 
+    pub fn to_reserve_dto(account: Account) -> ReserveAccountDTO {
+        ReserveAccountDTO {
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+            name: account.name,
+            blockchain: account.blockchain.to_string(),
+            standard: account.standard.to_string(),
+            symbol: account.symbol,
+            decimals: account.decimals,
+            addresses: std::iter::once(account.address)
+                .chain(
+                    account
+                        .subaccounts
+                        .into_iter()
+                        .map(|subaccount| subaccount.address),
+                )
+                .collect(),
+            balance: account
+                .balance
+                .as_ref()
+                .map(|balance| balance.balance.clone()),
+            last_update_timestamp: account
+                .balance
+                .map(|balance| timestamp_to_rfc3339(&balance.last_modification_timestamp)),
+        }
+    }
+
     pub fn to_balance_dto(
         balance: AccountBalance,
-        decimals: u32,
-        account_id: AccountId,
+        account: &Account,
+        pending_deposits: Vec<BlockchainPendingDeposit>,
+        fiat_values: Vec<FiatValue>,
     ) -> AccountBalanceDTO {
         AccountBalanceDTO {
-            account_id: Uuid::from_bytes(account_id).hyphenated().to_string(),
+            account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
             balance: balance.balance,
-            decimals,
+            decimals: account.decimals,
             last_update_timestamp: timestamp_to_rfc3339(&balance.last_modification_timestamp),
+            pending_deposits: pending_deposits.into_iter().map(Into::into).collect(),
+            fiat_values: fiat_values.into_iter().map(Into::into).collect(),
+            assets: Self::to_asset_dtos(account),
+        }
+    }
+
+    /// Maps the assets held by the account, the assets that are no longer registered are skipped.
+    pub fn to_asset_dtos(account: &Account) -> Vec<AccountAssetDTO> {
+        account
+            .assets
+            .iter()
+            .filter_map(|held| {
+                let asset = REGISTERED_ASSET_REPOSITORY.get(&held.asset_id)?;
+
+                Some(AccountAssetDTO {
+                    asset_id: Uuid::from_bytes(held.asset_id).hyphenated().to_string(),
+                    symbol: asset.symbol,
+                    decimals: asset.decimals,
+                    balance: held.balance.as_ref().map(|balance| AccountBalanceInfoDTO {
+                        balance: balance.balance.clone(),
+                        decimals: asset.decimals,
+                        last_update_timestamp: timestamp_to_rfc3339(
+                            &balance.last_modification_timestamp,
+                        ),
+                    }),
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<BlockchainPendingDeposit> for PendingDepositDTO {
+    fn from(deposit: BlockchainPendingDeposit) -> Self {
+        PendingDepositDTO {
+            id: deposit.id,
+            amount: candid::Nat(deposit.amount),
+            confirmations: deposit.confirmations,
+            required_confirmations: deposit.required_confirmations,
         }
     }
 }
@@ -146,3 +248,35 @@ impl From<AccountCallerPrivileges> for station_api::AccountCallerPrivilegesDTO {
         }
     }
 }
+
+impl From<DiscoveredAccount> for DiscoveredAccountDTO {
+    fn from(account: DiscoveredAccount) -> Self {
+        DiscoveredAccountDTO {
+            ledger_canister_id: account.ledger_canister_id,
+            address: account.address.to_string(),
+            account_id: account
+                .account_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            symbol: account.symbol,
+            decimals: account.decimals,
+            balance: account.balance,
+            proposed_operation: account.proposed_operation.map(Into::into),
+        }
+    }
+}
+
+impl From<AccountDiscovery> for DiscoverAccountsResponse {
+    fn from(discovery: AccountDiscovery) -> Self {
+        DiscoverAccountsResponse {
+            accounts: discovery.accounts.into_iter().map(Into::into).collect(),
+            failed_ledgers: discovery
+                .failed_ledgers
+                .into_iter()
+                .map(|(ledger_canister_id, reason)| FailedLedgerDiscoveryDTO {
+                    ledger_canister_id,
+                    reason,
+                })
+                .collect(),
+        }
+    }
+}
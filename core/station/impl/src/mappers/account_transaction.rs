@@ -0,0 +1,48 @@
+use crate::models::{AccountTransaction, AccountTransactionDirection, AccountTransactionKind};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{
+    AccountTransactionDTO, AccountTransactionDirectionDTO, AccountTransactionKindDTO,
+};
+use uuid::Uuid;
+
+impl From<AccountTransactionKind> for AccountTransactionKindDTO {
+    fn from(kind: AccountTransactionKind) -> Self {
+        match kind {
+            AccountTransactionKind::Transfer => AccountTransactionKindDTO::Transfer,
+            AccountTransactionKind::Mint => AccountTransactionKindDTO::Mint,
+            AccountTransactionKind::Burn => AccountTransactionKindDTO::Burn,
+        }
+    }
+}
+
+impl From<AccountTransactionDirection> for AccountTransactionDirectionDTO {
+    fn from(direction: AccountTransactionDirection) -> Self {
+        match direction {
+            AccountTransactionDirection::Inbound => AccountTransactionDirectionDTO::Inbound,
+            AccountTransactionDirection::Outbound => AccountTransactionDirectionDTO::Outbound,
+        }
+    }
+}
+
+impl From<AccountTransaction> for AccountTransactionDTO {
+    fn from(transaction: AccountTransaction) -> Self {
+        AccountTransactionDTO {
+            account_id: Uuid::from_bytes(transaction.account_id)
+                .hyphenated()
+                .to_string(),
+            block_index: transaction.block_index,
+            kind: transaction.kind.into(),
+            direction: transaction.direction.into(),
+            counterparty: transaction.counterparty,
+            amount: transaction.amount,
+            fee: transaction.fee,
+            memo: transaction.memo,
+            timestamp: transaction
+                .timestamp
+                .map(|timestamp| timestamp_to_rfc3339(&timestamp)),
+            transfer_id: transaction
+                .transfer_id
+                .map(|transfer_id| Uuid::from_bytes(transfer_id).hyphenated().to_string()),
+        }
+    }
+}
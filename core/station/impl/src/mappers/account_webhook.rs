@@ -0,0 +1,23 @@
+use crate::models::AccountWebhook;
+use station_api::AccountWebhookDTO;
+
+impl From<AccountWebhook> for AccountWebhookDTO {
+    fn from(webhook: AccountWebhook) -> Self {
+        AccountWebhookDTO {
+            canister_id: webhook.canister_id,
+            method_name: webhook.method_name,
+            // the secret is only shared with the receiver of the callbacks
+            secret: None,
+        }
+    }
+}
+
+impl From<AccountWebhookDTO> for AccountWebhook {
+    fn from(webhook: AccountWebhookDTO) -> Self {
+        AccountWebhook {
+            canister_id: webhook.canister_id,
+            method_name: webhook.method_name,
+            secret: webhook.secret,
+        }
+    }
+}
@@ -4,12 +4,13 @@ use crate::errors::MapperError;
 use crate::mappers::blockchain::BlockchainMapper;
 use crate::models::{
     AddAddressBookEntryOperationInput, AddressBookEntry, AddressBookEntryCallerPrivileges,
-    ListAddressBookEntriesInput,
+    AddressBookLabel, ListAddressBookEntriesInput,
 };
 use orbit_essentials::types::UUID;
 use orbit_essentials::utils::timestamp_to_rfc3339;
 use station_api::{
-    AddressBookEntryCallerPrivilegesDTO, AddressBookEntryDTO, ListAddressBookEntriesInputDTO,
+    AddressBookEntryCallerPrivilegesDTO, AddressBookEntryDTO, AddressBookLabelDTO,
+    ListAddressBookEntriesInputDTO,
 };
 use uuid::Uuid;
 
@@ -27,6 +28,7 @@ impl AddressBookMapper {
             blockchain: address_book_entry.blockchain.to_string(),
             metadata: address_book_entry.metadata.into_vec_dto(),
             labels: address_book_entry.labels,
+            requires_memo: address_book_entry.requires_memo,
             last_modification_timestamp: timestamp_to_rfc3339(
                 &address_book_entry.last_modification_timestamp,
             ),
@@ -44,6 +46,7 @@ impl AddressBookMapper {
             blockchain: input.blockchain,
             labels: input.labels,
             metadata: input.metadata.into(),
+            requires_memo: input.requires_memo,
             last_modification_timestamp: next_time(),
         };
 
@@ -87,3 +90,13 @@ impl From<AddressBookEntryCallerPrivileges> for AddressBookEntryCallerPrivileges
         }
     }
 }
+
+impl From<AddressBookLabel> for AddressBookLabelDTO {
+    fn from(label: AddressBookLabel) -> Self {
+        AddressBookLabelDTO {
+            name: label.name,
+            description: label.description,
+            last_modification_timestamp: timestamp_to_rfc3339(&label.last_modification_timestamp),
+        }
+    }
+}
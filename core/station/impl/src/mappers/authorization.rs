@@ -11,7 +11,10 @@ use crate::{
         },
         CanisterMethod, Transfer,
     },
-    repositories::TRANSFER_REPOSITORY,
+    repositories::{
+        CAPABILITY_GRANT_REPOSITORY, SCHEDULED_TRANSFER_REPOSITORY, TRANSFER_RECEIPT_REPOSITORY,
+        TRANSFER_REPOSITORY,
+    },
 };
 use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
@@ -94,6 +97,66 @@ impl From<&station_api::GetAccountInput> for Resource {
     }
 }
 
+impl From<&station_api::ListNftsInput> for Resource {
+    fn from(input: &station_api::ListNftsInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::CreateFundingRequestInput> for Resource {
+    fn from(input: &station_api::CreateFundingRequestInput) -> Self {
+        Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ListFundingRequestsInput> for Resource {
+    fn from(input: &station_api::ListFundingRequestsInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ImportAccountTransactionsInput> for Resource {
+    fn from(input: &station_api::ImportAccountTransactionsInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ListAccountTransactionsInput> for Resource {
+    fn from(input: &station_api::ListAccountTransactionsInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::GetTransferFeesInput> for Resource {
+    fn from(input: &station_api::GetTransferFeesInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::ListAccountTransfersInput> for Resource {
     fn from(input: &station_api::ListAccountTransfersInput) -> Self {
         Resource::Account(AccountResourceAction::Read(ResourceId::Id(
@@ -104,6 +167,79 @@ impl From<&station_api::ListAccountTransfersInput> for Resource {
     }
 }
 
+impl From<&station_api::ExportAccountTransfersInput> for Resource {
+    fn from(input: &station_api::ExportAccountTransfersInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::CreateCapabilityGrantOperationInput> for Resource {
+    fn from(input: &station_api::CreateCapabilityGrantOperationInput) -> Self {
+        Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+// the grants reveal which principals can read the account, so they are only listed to its editors
+impl From<&station_api::ListCapabilityGrantsInput> for Resource {
+    fn from(input: &station_api::ListCapabilityGrantsInput) -> Self {
+        Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::RevokeCapabilityGrantOperationInput> for Resource {
+    fn from(input: &station_api::RevokeCapabilityGrantOperationInput) -> Self {
+        let grant_id = *HelperMapper::to_uuid(input.grant_id.to_owned())
+            .expect("Invalid grant id")
+            .as_bytes();
+
+        match CAPABILITY_GRANT_REPOSITORY.get(&grant_id) {
+            Some(grant) => Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                grant.account_id,
+            ))),
+            None => Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+        }
+    }
+}
+
+impl From<&station_api::ListScheduledTransfersInput> for Resource {
+    fn from(input: &station_api::ListScheduledTransfersInput) -> Self {
+        Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.account_id.to_owned())
+                .expect("Invalid account id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::CancelScheduledTransferInput> for Resource {
+    fn from(input: &station_api::CancelScheduledTransferInput) -> Self {
+        let scheduled_transfer_id = *HelperMapper::to_uuid(input.scheduled_transfer_id.to_owned())
+            .expect("Invalid scheduled transfer id")
+            .as_bytes();
+
+        // stopping a standing order requires the same permission as requesting it, an unknown
+        // standing order is only reported to the users that can transfer from any account
+        match SCHEDULED_TRANSFER_REPOSITORY.get(&scheduled_transfer_id) {
+            Some(scheduled_transfer) => Resource::Account(AccountResourceAction::Transfer(
+                ResourceId::Id(scheduled_transfer.transfer.from_account_id),
+            )),
+            None => Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+        }
+    }
+}
+
 impl From<&station_api::GetUserInput> for Resource {
     fn from(input: &station_api::GetUserInput) -> Self {
         Resource::User(UserResourceAction::Read(ResourceId::Id(
@@ -114,6 +250,16 @@ impl From<&station_api::GetUserInput> for Resource {
     }
 }
 
+impl From<&station_api::ViewAsInput> for Resource {
+    fn from(input: &station_api::ViewAsInput) -> Self {
+        Resource::User(UserResourceAction::ViewAs(ResourceId::Id(
+            *HelperMapper::to_uuid(input.user_id.to_owned())
+                .expect("Invalid user id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetRequestInput> for Resource {
     fn from(input: &station_api::GetRequestInput) -> Self {
         Resource::Request(RequestResourceAction::Read(ResourceId::Id(
@@ -124,6 +270,26 @@ impl From<&station_api::GetRequestInput> for Resource {
     }
 }
 
+impl From<&station_api::AddRequestCommentInput> for Resource {
+    fn from(input: &station_api::AddRequestCommentInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
+impl From<&station_api::ListRequestCommentsInput> for Resource {
+    fn from(input: &station_api::ListRequestCommentsInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetRequestPolicyInput> for Resource {
     fn from(input: &station_api::GetRequestPolicyInput) -> Self {
         Resource::RequestPolicy(ResourceAction::Read(ResourceId::Id(
@@ -144,6 +310,16 @@ impl From<&station_api::GetUserGroupInput> for Resource {
     }
 }
 
+impl From<&station_api::RequestUserGroupMembershipInput> for Resource {
+    fn from(input: &station_api::RequestUserGroupMembershipInput) -> Self {
+        Resource::UserGroup(ResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.user_group_id.to_owned())
+                .expect("Invalid user group id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::SubmitRequestApprovalInput> for Resource {
     fn from(input: &station_api::SubmitRequestApprovalInput) -> Self {
         Resource::Request(RequestResourceAction::Read(ResourceId::Id(
@@ -154,6 +330,16 @@ impl From<&station_api::SubmitRequestApprovalInput> for Resource {
     }
 }
 
+impl From<&station_api::CancelRequestInput> for Resource {
+    fn from(input: &station_api::CancelRequestInput) -> Self {
+        Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.request_id.to_owned())
+                .expect("Invalid request id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::GetAddressBookEntryInputDTO> for Resource {
     fn from(input: &station_api::GetAddressBookEntryInputDTO) -> Self {
         Resource::AddressBook(ResourceAction::Read(ResourceId::Id(
@@ -164,6 +350,16 @@ impl From<&station_api::GetAddressBookEntryInputDTO> for Resource {
     }
 }
 
+impl From<&station_api::GetRegisteredAssetInput> for Resource {
+    fn from(input: &station_api::GetRegisteredAssetInput) -> Self {
+        Resource::Asset(ResourceAction::Read(ResourceId::Id(
+            *HelperMapper::to_uuid(input.asset_id.to_owned())
+                .expect("Invalid asset id")
+                .as_bytes(),
+        )))
+    }
+}
+
 impl From<&station_api::ListNotificationsInput> for Resource {
     fn from(_input: &station_api::ListNotificationsInput) -> Self {
         Resource::Notification(NotificationResourceAction::List)
@@ -172,7 +368,25 @@ impl From<&station_api::ListNotificationsInput> for Resource {
 
 impl From<&station_api::CreateRequestInput> for Resource {
     fn from(input: &station_api::CreateRequestInput) -> Self {
-        match &input.operation {
+        Resource::from(&input.operation)
+    }
+}
+
+impl From<&station_api::AmendRequestInput> for Resource {
+    fn from(input: &station_api::AmendRequestInput) -> Self {
+        Resource::from(&input.operation)
+    }
+}
+
+impl From<&station_api::EvaluateRequestPoliciesInput> for Resource {
+    fn from(input: &station_api::EvaluateRequestPoliciesInput) -> Self {
+        Resource::from(&input.operation)
+    }
+}
+
+impl From<&RequestOperationInput> for Resource {
+    fn from(operation: &RequestOperationInput) -> Self {
+        match operation {
             RequestOperationInput::AddAccount(_) => {
                 Resource::Account(AccountResourceAction::Create)
             }
@@ -183,6 +397,20 @@ impl From<&station_api::CreateRequestInput> for Resource {
                         .as_bytes(),
                 )))
             }
+            RequestOperationInput::SetAutoApprovalForTrustedDestinations(input) => {
+                Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::DeriveSubaccount(input) => {
+                Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
             RequestOperationInput::AddAddressBookEntry(_) => {
                 Resource::AddressBook(ResourceAction::Create)
             }
@@ -200,6 +428,11 @@ impl From<&station_api::CreateRequestInput> for Resource {
                         .as_bytes(),
                 )))
             }
+            RequestOperationInput::ManageAddressBookLabels(_) => {
+                Resource::AddressBook(ResourceAction::Update(ResourceId::Any))
+            }
+            RequestOperationInput::CreateCapabilityGrant(input) => Resource::from(input),
+            RequestOperationInput::RevokeCapabilityGrant(input) => Resource::from(input),
             RequestOperationInput::Transfer(input) => {
                 Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
                     *HelperMapper::to_uuid(input.from_account_id.to_owned())
@@ -207,6 +440,48 @@ impl From<&station_api::CreateRequestInput> for Resource {
                         .as_bytes(),
                 )))
             }
+            RequestOperationInput::TransferNft(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::Approve(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::SwapTokens(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::SweepAccount(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::SplitTransfer(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::AddScheduledTransfer(input) => {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.transfer.from_account_id.to_owned())
+                        .expect("Invalid account id")
+                        .as_bytes(),
+                )))
+            }
             RequestOperationInput::AddUser(_) => Resource::User(UserResourceAction::Create),
             RequestOperationInput::EditUser(input) => {
                 Resource::User(UserResourceAction::Update(ResourceId::Id(
@@ -264,7 +539,9 @@ impl From<&station_api::CreateRequestInput> for Resource {
                     },
                 ))
             }
-            RequestOperationInput::EditPermission(_) => {
+            RequestOperationInput::EditPermission(_)
+            | RequestOperationInput::ImportAccessPolicies(_)
+            | RequestOperationInput::AddTeam(_) => {
                 Resource::Permission(PermissionResourceAction::Update)
             }
             RequestOperationInput::AddRequestPolicy(_) => {
@@ -287,6 +564,21 @@ impl From<&station_api::CreateRequestInput> for Resource {
             RequestOperationInput::ManageSystemInfo(_) => {
                 Resource::System(SystemResourceAction::ManageSystemInfo)
             }
+            RequestOperationInput::AddAsset(_) => Resource::Asset(ResourceAction::Create),
+            RequestOperationInput::EditAsset(input) => {
+                Resource::Asset(ResourceAction::Update(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.asset_id.to_owned())
+                        .expect("Invalid asset id")
+                        .as_bytes(),
+                )))
+            }
+            RequestOperationInput::RemoveAsset(input) => {
+                Resource::Asset(ResourceAction::Delete(ResourceId::Id(
+                    *HelperMapper::to_uuid(input.asset_id.to_owned())
+                        .expect("Invalid asset id")
+                        .as_bytes(),
+                )))
+            }
         }
     }
 }
@@ -357,6 +649,77 @@ impl GetTransfersInputRef<'_> {
     }
 }
 
+pub(crate) struct GetTransferInputRef<'a>(pub &'a station_api::GetTransferInput);
+
+impl GetTransferInputRef<'_> {
+    pub fn to_resources(&self) -> Vec<Resource> {
+        let transfer_id = *HelperMapper::to_uuid(self.0.transfer_id.to_owned())
+            .expect("Invalid transfer id")
+            .as_bytes();
+
+        let transfer = TRANSFER_REPOSITORY
+            .get(&Transfer::key(transfer_id))
+            .unwrap_or_else(|| trap("Failed to unwrap transfer input"));
+
+        vec![Resource::Account(AccountResourceAction::Read(
+            ResourceId::Id(transfer.from_account),
+        ))]
+    }
+}
+
+pub(crate) struct GetTransferReceiptInputRef<'a>(pub &'a station_api::GetTransferReceiptInput);
+
+impl GetTransferReceiptInputRef<'_> {
+    pub fn to_resources(&self) -> Vec<Resource> {
+        let transfer_id = *HelperMapper::to_uuid(self.0.transfer_id.to_owned())
+            .expect("Invalid transfer id")
+            .as_bytes();
+
+        // the receipts are kept after their transfers are archived
+        let receipt = TRANSFER_RECEIPT_REPOSITORY
+            .get(&transfer_id)
+            .unwrap_or_else(|| trap("Failed to unwrap transfer receipt input"));
+
+        vec![Resource::Account(AccountResourceAction::Read(
+            ResourceId::Id(receipt.from_account),
+        ))]
+    }
+}
+
+pub(crate) struct CreatePayoutRunInputRef<'a>(pub &'a station_api::CreatePayoutRunInput);
+
+impl CreatePayoutRunInputRef<'_> {
+    pub fn to_resources(&self) -> Vec<Resource> {
+        let mut account_ids = self
+            .0
+            .transfers
+            .iter()
+            .map(|transfer| {
+                *HelperMapper::to_uuid(transfer.from_account_id.to_owned())
+                    .expect("Invalid account id")
+                    .as_bytes()
+            })
+            .collect::<Vec<UUID>>();
+
+        account_ids.sort();
+        account_ids.dedup();
+
+        // an empty run is rejected by the service, it still requires being able to create requests
+        if account_ids.is_empty() {
+            return vec![Resource::Account(AccountResourceAction::Transfer(
+                ResourceId::Any,
+            ))];
+        }
+
+        account_ids
+            .into_iter()
+            .map(|account_id| {
+                Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(account_id)))
+            })
+            .collect()
+    }
+}
+
 pub(crate) struct MarkNotificationsReadInputRef<'a>(
     pub &'a station_api::MarkNotificationsReadInput,
 );
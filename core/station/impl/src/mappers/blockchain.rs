@@ -1,7 +1,8 @@
 use crate::{
     errors::MapperError,
-    models::{Blockchain, BlockchainStandard},
+    models::{Blockchain, BlockchainStandard, NetworkProfile},
 };
+use station_api::NetworkProfileDTO;
 use std::str::FromStr;
 
 #[derive(Default, Clone, Debug)]
@@ -25,3 +26,23 @@ impl BlockchainMapper {
         Ok(standard)
     }
 }
+
+impl From<NetworkProfile> for NetworkProfileDTO {
+    fn from(network: NetworkProfile) -> Self {
+        match network {
+            NetworkProfile::Mainnet => NetworkProfileDTO::Mainnet,
+            NetworkProfile::Testnet => NetworkProfileDTO::Testnet,
+            NetworkProfile::Local => NetworkProfileDTO::Local,
+        }
+    }
+}
+
+impl From<NetworkProfileDTO> for NetworkProfile {
+    fn from(network: NetworkProfileDTO) -> Self {
+        match network {
+            NetworkProfileDTO::Mainnet => NetworkProfile::Mainnet,
+            NetworkProfileDTO::Testnet => NetworkProfile::Testnet,
+            NetworkProfileDTO::Local => NetworkProfile::Local,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::models::{CapabilityGrant, CapabilityScope};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{CapabilityGrantDTO, CapabilityScopeDTO};
+use uuid::Uuid;
+
+impl From<CapabilityScopeDTO> for CapabilityScope {
+    fn from(scope: CapabilityScopeDTO) -> Self {
+        match scope {
+            CapabilityScopeDTO::Transfers => CapabilityScope::Transfers,
+            CapabilityScopeDTO::Balances => CapabilityScope::Balances,
+        }
+    }
+}
+
+impl From<CapabilityScope> for CapabilityScopeDTO {
+    fn from(scope: CapabilityScope) -> Self {
+        match scope {
+            CapabilityScope::Transfers => CapabilityScopeDTO::Transfers,
+            CapabilityScope::Balances => CapabilityScopeDTO::Balances,
+        }
+    }
+}
+
+impl From<CapabilityGrant> for CapabilityGrantDTO {
+    fn from(grant: CapabilityGrant) -> Self {
+        CapabilityGrantDTO {
+            id: Uuid::from_bytes(grant.id).hyphenated().to_string(),
+            account_id: Uuid::from_bytes(grant.account_id).hyphenated().to_string(),
+            grantee: grant.grantee,
+            scopes: grant.scopes.into_iter().map(Into::into).collect(),
+            expires_at: timestamp_to_rfc3339(&grant.expires_at),
+            created_by: Uuid::from_bytes(grant.created_by).hyphenated().to_string(),
+            created_at: timestamp_to_rfc3339(&grant.created_timestamp),
+            revoked_at: grant.revoked_at.as_ref().map(timestamp_to_rfc3339),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::models::FiatValue;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::FiatValueDTO;
+
+impl From<FiatValue> for FiatValueDTO {
+    fn from(value: FiatValue) -> Self {
+        FiatValueDTO {
+            currency: value.currency.to_string(),
+            value: value.value,
+            decimals: value.decimals,
+            rate_timestamp: timestamp_to_rfc3339(&value.rate_timestamp),
+        }
+    }
+}
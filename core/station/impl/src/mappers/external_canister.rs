@@ -1,3 +1,4 @@
+use super::HelperMapper;
 use crate::{
     core::ic_cdk::next_time,
     models::{
@@ -5,10 +6,11 @@ use crate::{
         ConfigureExternalCanisterSettingsInput, CreateExternalCanisterOperationInput,
         DefiniteCanisterSettingsInput, ExternalCanister, ExternalCanisterCallRequestPolicyRule,
         ExternalCanisterCallerMethodsPrivileges, ExternalCanisterCallerPrivileges,
-        ExternalCanisterChangeRequestPolicyRule, ExternalCanisterPermissions,
-        ExternalCanisterRequestPolicies, ExternalCanisterState, FundExternalCanisterOperation,
-        FundExternalCanisterOperationInput, FundExternalCanisterOperationKind,
-        FundExternalCanisterSendCyclesInput, LogVisibility,
+        ExternalCanisterChangeRequestPolicyRule, ExternalCanisterMonitoring,
+        ExternalCanisterMonitoringInput, ExternalCanisterMonitoringRules,
+        ExternalCanisterPermissions, ExternalCanisterRequestPolicies, ExternalCanisterState,
+        FundExternalCanisterOperation, FundExternalCanisterOperationInput,
+        FundExternalCanisterOperationKind, FundExternalCanisterSendCyclesInput, LogVisibility,
     },
     repositories::ExternalCanisterWhereClauseSort,
 };
@@ -35,6 +37,8 @@ impl ExternalCanisterMapper {
             state: ExternalCanisterState::Active,
             created_at: next_time(),
             modified_at: None,
+            monitoring: None,
+            module_hash: None,
         }
     }
 }
@@ -56,6 +60,70 @@ impl ExternalCanister {
             request_policies: policies.into(),
             created_at: timestamp_to_rfc3339(&self.created_at),
             modified_at: self.modified_at.map(|ts| timestamp_to_rfc3339(&ts)),
+            monitoring: self.monitoring.map(Into::into),
+            module_hash: self.module_hash.map(hex::encode),
+        }
+    }
+}
+
+impl From<ExternalCanisterMonitoring> for station_api::ExternalCanisterMonitoringDTO {
+    fn from(monitoring: ExternalCanisterMonitoring) -> Self {
+        station_api::ExternalCanisterMonitoringDTO {
+            rules: monitoring.rules.into(),
+            configured_by: Uuid::from_bytes(monitoring.configured_by)
+                .hyphenated()
+                .to_string(),
+            last_checked_at: monitoring
+                .last_checked_at
+                .map(|ts| timestamp_to_rfc3339(&ts)),
+        }
+    }
+}
+
+impl From<ExternalCanisterMonitoringRules> for station_api::ExternalCanisterMonitoringRulesDTO {
+    fn from(rules: ExternalCanisterMonitoringRules) -> Self {
+        station_api::ExternalCanisterMonitoringRulesDTO {
+            cycles_threshold: rules.cycles_threshold,
+            notify_on_stopped: rules.notify_on_stopped,
+            notify_on_module_hash_change: rules.notify_on_module_hash_change,
+            auto_fund_cycles: rules.auto_fund_cycles,
+        }
+    }
+}
+
+impl From<station_api::ExternalCanisterMonitoringRulesDTO> for ExternalCanisterMonitoringRules {
+    fn from(rules: station_api::ExternalCanisterMonitoringRulesDTO) -> Self {
+        ExternalCanisterMonitoringRules {
+            cycles_threshold: rules.cycles_threshold,
+            notify_on_stopped: rules.notify_on_stopped,
+            notify_on_module_hash_change: rules.notify_on_module_hash_change,
+            auto_fund_cycles: rules.auto_fund_cycles,
+        }
+    }
+}
+
+impl From<ExternalCanisterMonitoringInput> for station_api::ExternalCanisterMonitoringInput {
+    fn from(input: ExternalCanisterMonitoringInput) -> Self {
+        match input {
+            ExternalCanisterMonitoringInput::Enable(rules) => {
+                station_api::ExternalCanisterMonitoringInput::Enable(rules.into())
+            }
+            ExternalCanisterMonitoringInput::Disable => {
+                station_api::ExternalCanisterMonitoringInput::Disable
+            }
+        }
+    }
+}
+
+impl From<station_api::ExternalCanisterMonitoringInput> for ExternalCanisterMonitoringInput {
+    fn from(input: station_api::ExternalCanisterMonitoringInput) -> Self {
+        match input {
+            station_api::ExternalCanisterMonitoringInput::Enable(rules) => {
+                ExternalCanisterMonitoringInput::Enable(rules.into())
+            }
+            station_api::ExternalCanisterMonitoringInput::Disable => {
+                ExternalCanisterMonitoringInput::Disable
+            }
         }
     }
 }
@@ -234,6 +302,7 @@ impl From<station_api::ConfigureExternalCanisterSettingsInput>
             state: input.state.map(Into::into),
             permissions: input.permissions.map(Into::into),
             request_policies: input.request_policies.map(Into::into),
+            monitoring: input.monitoring.map(Into::into),
         }
     }
 }
@@ -300,6 +369,9 @@ impl From<FundExternalCanisterSendCyclesInput>
     fn from(input: FundExternalCanisterSendCyclesInput) -> Self {
         station_api::FundExternalCanisterSendCyclesInput {
             cycles: input.cycles,
+            from_account_id: input
+                .from_account_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
         }
     }
 }
@@ -310,6 +382,11 @@ impl From<station_api::FundExternalCanisterSendCyclesInput>
     fn from(input: station_api::FundExternalCanisterSendCyclesInput) -> Self {
         FundExternalCanisterSendCyclesInput {
             cycles: input.cycles,
+            from_account_id: input.from_account_id.map(|id| {
+                *HelperMapper::to_uuid(id)
+                    .expect("Invalid account id")
+                    .as_bytes()
+            }),
         }
     }
 }
@@ -0,0 +1,46 @@
+use crate::models::{FundingRequest, FundingRequestStatus};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{FundingApprovalDTO, FundingRequestDTO, FundingRequestStatusDTO};
+use uuid::Uuid;
+
+impl From<FundingRequestStatus> for FundingRequestStatusDTO {
+    fn from(status: FundingRequestStatus) -> Self {
+        match status {
+            FundingRequestStatus::Pending => FundingRequestStatusDTO::Pending,
+            FundingRequestStatus::CollectRequested { request_id } => {
+                FundingRequestStatusDTO::CollectRequested {
+                    request_id: Uuid::from_bytes(request_id).hyphenated().to_string(),
+                }
+            }
+            FundingRequestStatus::Expired => FundingRequestStatusDTO::Expired,
+        }
+    }
+}
+
+impl From<FundingRequest> for FundingRequestDTO {
+    fn from(funding_request: FundingRequest) -> Self {
+        FundingRequestDTO {
+            id: Uuid::from_bytes(funding_request.id)
+                .hyphenated()
+                .to_string(),
+            account_id: Uuid::from_bytes(funding_request.account_id)
+                .hyphenated()
+                .to_string(),
+            payer: funding_request.payer.to_string(),
+            amount: funding_request.amount.clone(),
+            reference: funding_request.reference.clone(),
+            approval: FundingApprovalDTO {
+                ledger_canister_id: funding_request.ledger_canister_id,
+                spender: funding_request.spender.to_string(),
+                amount: funding_request.approve_amount(),
+                expires_at: timestamp_to_rfc3339(&funding_request.expires_at),
+            },
+            status: funding_request.status.into(),
+            requested_by: Uuid::from_bytes(funding_request.requested_by)
+                .hyphenated()
+                .to_string(),
+            expires_at: timestamp_to_rfc3339(&funding_request.expires_at),
+            created_at: timestamp_to_rfc3339(&funding_request.created_timestamp),
+        }
+    }
+}
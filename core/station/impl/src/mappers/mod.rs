@@ -6,6 +6,13 @@ pub mod account;
 
 pub mod asset;
 
+mod registered_asset;
+
+mod request_view;
+pub use request_view::*;
+
+mod request_comment;
+
 pub mod address_book;
 
 pub mod blockchain;
@@ -20,6 +27,8 @@ pub mod notification_status;
 
 pub mod notification_type;
 
+mod notification_template;
+
 pub mod request_operation_type;
 
 pub mod request_operation;
@@ -44,12 +53,39 @@ pub use external_canister::*;
 
 mod user_group;
 
+mod user_onboarding;
+
+mod trusted_destination;
+
+mod account_webhook;
+
+mod funding_request;
+
+mod payout_run;
+
+mod account_transaction;
+
+mod exchange_rate;
+
+mod spending_summary;
+
+mod scheduled_transfer;
+
+mod capability_grant;
+
+mod support_access_log;
+
 mod user_status;
 
 mod transfer;
 pub use transfer::*;
 
 mod transfer_status;
+pub use transfer_status::*;
+
+mod transfer_receipt;
+
+mod pending_execution;
 
 pub mod permission;
 
@@ -0,0 +1,114 @@
+use crate::models::{
+    normalize_locale, NotificationTemplate, NotificationTemplateChange, NotificationTemplateEvent,
+    NotificationTemplateKey,
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{
+    NotificationTemplateChangeDTO, NotificationTemplateDTO, NotificationTemplateEventDTO,
+    NotificationTemplateKeyDTO,
+};
+
+impl From<NotificationTemplateEventDTO> for NotificationTemplateEvent {
+    fn from(event: NotificationTemplateEventDTO) -> Self {
+        match event {
+            NotificationTemplateEventDTO::RequestCreated => {
+                NotificationTemplateEvent::RequestCreated
+            }
+            NotificationTemplateEventDTO::RequestRejected => {
+                NotificationTemplateEvent::RequestRejected
+            }
+            NotificationTemplateEventDTO::RequestFailed => NotificationTemplateEvent::RequestFailed,
+            NotificationTemplateEventDTO::RequestCancelled => {
+                NotificationTemplateEvent::RequestCancelled
+            }
+        }
+    }
+}
+
+impl From<NotificationTemplateEvent> for NotificationTemplateEventDTO {
+    fn from(event: NotificationTemplateEvent) -> Self {
+        match event {
+            NotificationTemplateEvent::RequestCreated => {
+                NotificationTemplateEventDTO::RequestCreated
+            }
+            NotificationTemplateEvent::RequestRejected => {
+                NotificationTemplateEventDTO::RequestRejected
+            }
+            NotificationTemplateEvent::RequestFailed => NotificationTemplateEventDTO::RequestFailed,
+            NotificationTemplateEvent::RequestCancelled => {
+                NotificationTemplateEventDTO::RequestCancelled
+            }
+        }
+    }
+}
+
+impl From<NotificationTemplateKeyDTO> for NotificationTemplateKey {
+    fn from(key: NotificationTemplateKeyDTO) -> Self {
+        NotificationTemplateKey {
+            event: key.event.into(),
+            operation_type: key.operation_type.map(Into::into),
+            locale: key.locale.as_deref().map(normalize_locale),
+        }
+    }
+}
+
+impl From<NotificationTemplateKey> for NotificationTemplateKeyDTO {
+    fn from(key: NotificationTemplateKey) -> Self {
+        NotificationTemplateKeyDTO {
+            event: key.event.into(),
+            operation_type: key.operation_type.map(Into::into),
+            locale: key.locale,
+        }
+    }
+}
+
+impl From<NotificationTemplateChangeDTO> for NotificationTemplateChange {
+    fn from(change: NotificationTemplateChangeDTO) -> Self {
+        match change {
+            NotificationTemplateChangeDTO::Set {
+                key,
+                title,
+                message,
+            } => NotificationTemplateChange::Set {
+                key: key.into(),
+                title,
+                message,
+            },
+            NotificationTemplateChangeDTO::Remove(key) => {
+                NotificationTemplateChange::Remove(key.into())
+            }
+        }
+    }
+}
+
+impl From<NotificationTemplateChange> for NotificationTemplateChangeDTO {
+    fn from(change: NotificationTemplateChange) -> Self {
+        match change {
+            NotificationTemplateChange::Set {
+                key,
+                title,
+                message,
+            } => NotificationTemplateChangeDTO::Set {
+                key: key.into(),
+                title,
+                message,
+            },
+            NotificationTemplateChange::Remove(key) => {
+                NotificationTemplateChangeDTO::Remove(key.into())
+            }
+        }
+    }
+}
+
+impl From<NotificationTemplate> for NotificationTemplateDTO {
+    fn from(template: NotificationTemplate) -> Self {
+        NotificationTemplateDTO {
+            key: template.key.into(),
+            title: template.title,
+            message: template.message,
+            last_modification_timestamp: timestamp_to_rfc3339(
+                &template.last_modification_timestamp,
+            ),
+        }
+    }
+}
@@ -1,12 +1,18 @@
-use crate::models::{RequestOperation, RequestOperationType, RequestStatus, RequestStatusCode};
+use crate::models::{
+    ExternalCanisterMonitoringEvent, RequestOperation, RequestOperationType, RequestStatus,
+    RequestStatusCode,
+};
 use crate::repositories::REQUEST_EVALUATION_RESULT_REPOSITORY;
 use crate::{
     models::{NotificationType, Request},
     repositories::REQUEST_REPOSITORY,
 };
 use orbit_essentials::repository::Repository;
+use orbit_essentials::utils::timestamp_to_rfc3339;
 use station_api::{
-    NotificationTypeDTO, RequestCreatedNotificationDTO, RequestFailedNotificationDTO,
+    ExternalCanisterMonitoringEventDTO, ExternalCanisterMonitoringNotificationDTO,
+    NotificationTypeDTO, PendingVoteReminderNotificationDTO, RequestCancelledNotificationDTO,
+    RequestCommentedNotificationDTO, RequestCreatedNotificationDTO, RequestFailedNotificationDTO,
     RequestRejectedNotificationDTO,
 };
 use uuid::Uuid;
@@ -63,6 +69,27 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     })?,
                 }
             }
+            NotificationType::RequestCancelled(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                match request.status {
+                    RequestStatus::Cancelled { reason } => {
+                        NotificationTypeDTO::RequestCancelled(RequestCancelledNotificationDTO {
+                            request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                            operation_type: RequestOperationType::from(request.operation).into(),
+                            reason,
+                        })
+                    }
+                    status => Err(NotificationMapperError::InvalidRequestStatus {
+                        expected: RequestStatusCode::Cancelled,
+                        found: status.to_type(),
+                    })?,
+                }
+            }
             NotificationType::RequestCreated(ctx) => {
                 let request = REQUEST_REPOSITORY
                     .get(&Request::key(ctx.request_id))
@@ -72,7 +99,32 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
 
                 let account_id = match &request.operation {
                     RequestOperation::Transfer(operation) => Some(operation.input.from_account_id),
+                    RequestOperation::Approve(operation) => Some(operation.input.from_account_id),
+                    RequestOperation::SwapTokens(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
+                    RequestOperation::SweepAccount(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
+                    RequestOperation::SplitTransfer(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
+                    RequestOperation::AddScheduledTransfer(operation) => {
+                        Some(operation.input.transfer.from_account_id)
+                    }
+                    RequestOperation::TransferNft(operation) => {
+                        Some(operation.input.from_account_id)
+                    }
                     RequestOperation::EditAccount(operation) => Some(operation.input.account_id),
+                    RequestOperation::SetAutoApprovalForTrustedDestinations(operation) => {
+                        Some(operation.input.account_id)
+                    }
+                    RequestOperation::DeriveSubaccount(operation) => {
+                        Some(operation.input.account_id)
+                    }
+                    RequestOperation::CreateCapabilityGrant(operation) => {
+                        Some(operation.input.account_id)
+                    }
                     RequestOperation::AddAccount(_)
                     | RequestOperation::AddAddressBookEntry(_)
                     | RequestOperation::EditAddressBookEntry(_)
@@ -82,6 +134,10 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::AddUser(_)
                     | RequestOperation::AddUserGroup(_)
                     | RequestOperation::EditPermission(_)
+                    | RequestOperation::ImportAccessPolicies(_)
+                    | RequestOperation::AddTeam(_)
+                    | RequestOperation::ManageAddressBookLabels(_)
+                    | RequestOperation::RevokeCapabilityGrant(_)
                     | RequestOperation::EditRequestPolicy(_)
                     | RequestOperation::EditUserGroup(_)
                     | RequestOperation::RemoveRequestPolicy(_)
@@ -93,7 +149,10 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::ConfigureExternalCanister(_)
                     | RequestOperation::CreateExternalCanister(_)
                     | RequestOperation::FundExternalCanister(_)
-                    | RequestOperation::CallExternalCanister(_) => None,
+                    | RequestOperation::CallExternalCanister(_)
+                    | RequestOperation::AddAsset(_)
+                    | RequestOperation::EditAsset(_)
+                    | RequestOperation::RemoveAsset(_) => None,
                 };
 
                 let user_id: Option<[u8; 16]> = match &request.operation {
@@ -104,6 +163,11 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::AddUser(_)
                     | RequestOperation::AddUserGroup(_)
                     | RequestOperation::EditPermission(_)
+                    | RequestOperation::ImportAccessPolicies(_)
+                    | RequestOperation::AddTeam(_)
+                    | RequestOperation::ManageAddressBookLabels(_)
+                    | RequestOperation::CreateCapabilityGrant(_)
+                    | RequestOperation::RevokeCapabilityGrant(_)
                     | RequestOperation::EditAccount(_)
                     | RequestOperation::EditAddressBookEntry(_)
                     | RequestOperation::RemoveAddressBookEntry(_)
@@ -112,6 +176,14 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::RemoveRequestPolicy(_)
                     | RequestOperation::RemoveUserGroup(_)
                     | RequestOperation::Transfer(_)
+                    | RequestOperation::Approve(_)
+                    | RequestOperation::SwapTokens(_)
+                    | RequestOperation::SweepAccount(_)
+                    | RequestOperation::SplitTransfer(_)
+                    | RequestOperation::AddScheduledTransfer(_)
+                    | RequestOperation::TransferNft(_)
+                    | RequestOperation::DeriveSubaccount(_)
+                    | RequestOperation::SetAutoApprovalForTrustedDestinations(_)
                     | RequestOperation::ManageSystemInfo(_)
                     | RequestOperation::SetDisasterRecovery(_)
                     | RequestOperation::SystemUpgrade(_)
@@ -119,7 +191,10 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     | RequestOperation::ConfigureExternalCanister(_)
                     | RequestOperation::CreateExternalCanister(_)
                     | RequestOperation::FundExternalCanister(_)
-                    | RequestOperation::CallExternalCanister(_) => None,
+                    | RequestOperation::CallExternalCanister(_)
+                    | RequestOperation::AddAsset(_)
+                    | RequestOperation::EditAsset(_)
+                    | RequestOperation::RemoveAsset(_) => None,
                 };
 
                 NotificationTypeDTO::RequestCreated(RequestCreatedNotificationDTO {
@@ -129,6 +204,66 @@ impl TryFrom<NotificationType> for NotificationTypeDTO {
                     user_id: user_id.map(|id| Uuid::from_bytes(id).to_string()),
                 })
             }
+            NotificationType::ExternalCanisterMonitoring(ctx) => {
+                NotificationTypeDTO::ExternalCanisterMonitoring(
+                    ExternalCanisterMonitoringNotificationDTO {
+                        external_canister_id: Uuid::from_bytes(ctx.external_canister_id)
+                            .to_string(),
+                        canister_id: ctx.canister_id,
+                        event: ctx.event.into(),
+                    },
+                )
+            }
+            NotificationType::RequestCommented(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                NotificationTypeDTO::RequestCommented(RequestCommentedNotificationDTO {
+                    request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                    operation_type: RequestOperationType::from(request.operation).into(),
+                    comment_id: Uuid::from_bytes(ctx.comment_id).to_string(),
+                    author_id: Uuid::from_bytes(ctx.author_id).to_string(),
+                })
+            }
+            NotificationType::PendingVoteReminder(ctx) => {
+                let request = REQUEST_REPOSITORY
+                    .get(&Request::key(ctx.request_id))
+                    .ok_or(NotificationMapperError::RequestNotFound {
+                        request_id: ctx.request_id,
+                    })?;
+
+                NotificationTypeDTO::PendingVoteReminder(PendingVoteReminderNotificationDTO {
+                    request_id: Uuid::from_bytes(ctx.request_id).to_string(),
+                    operation_type: RequestOperationType::from(request.operation).into(),
+                    expiration_dt: timestamp_to_rfc3339(&request.expiration_dt),
+                })
+            }
         })
     }
 }
+
+impl From<ExternalCanisterMonitoringEvent> for ExternalCanisterMonitoringEventDTO {
+    fn from(event: ExternalCanisterMonitoringEvent) -> Self {
+        match event {
+            ExternalCanisterMonitoringEvent::LowCycles {
+                cycles,
+                threshold,
+                fund_request_id,
+            } => ExternalCanisterMonitoringEventDTO::LowCycles {
+                cycles,
+                threshold,
+                fund_request_id: fund_request_id.map(|id| Uuid::from_bytes(id).to_string()),
+            },
+            ExternalCanisterMonitoringEvent::Stopped => ExternalCanisterMonitoringEventDTO::Stopped,
+            ExternalCanisterMonitoringEvent::ModuleHashChanged { previous, current } => {
+                ExternalCanisterMonitoringEventDTO::ModuleHashChanged {
+                    previous: previous.map(hex::encode),
+                    current: current.map(hex::encode),
+                }
+            }
+        }
+    }
+}
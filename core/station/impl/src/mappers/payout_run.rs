@@ -0,0 +1,54 @@
+use crate::models::{PayoutRunAssetTotal, PayoutRunStatus, PayoutRunSummary};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{PayoutRunAssetTotalDTO, PayoutRunDTO, PayoutRunStatusDTO};
+use uuid::Uuid;
+
+impl From<PayoutRunStatus> for PayoutRunStatusDTO {
+    fn from(status: PayoutRunStatus) -> Self {
+        match status {
+            PayoutRunStatus::Pending => PayoutRunStatusDTO::Pending,
+            PayoutRunStatus::Completed => PayoutRunStatusDTO::Completed,
+            PayoutRunStatus::PartiallyCompleted => PayoutRunStatusDTO::PartiallyCompleted,
+            PayoutRunStatus::Failed => PayoutRunStatusDTO::Failed,
+        }
+    }
+}
+
+impl From<PayoutRunAssetTotal> for PayoutRunAssetTotalDTO {
+    fn from(total: PayoutRunAssetTotal) -> Self {
+        PayoutRunAssetTotalDTO {
+            blockchain: total.blockchain,
+            symbol: total.symbol,
+            decimals: total.decimals,
+            requested: total.requested,
+            completed: total.completed,
+            pending: total.pending,
+        }
+    }
+}
+
+impl From<PayoutRunSummary> for PayoutRunDTO {
+    fn from(summary: PayoutRunSummary) -> Self {
+        PayoutRunDTO {
+            id: Uuid::from_bytes(summary.payout_run.id)
+                .hyphenated()
+                .to_string(),
+            name: summary.payout_run.name,
+            created_by: Uuid::from_bytes(summary.payout_run.created_by)
+                .hyphenated()
+                .to_string(),
+            created_at: timestamp_to_rfc3339(&summary.payout_run.created_timestamp),
+            request_ids: summary
+                .payout_run
+                .request_ids
+                .into_iter()
+                .map(|request_id| Uuid::from_bytes(request_id).hyphenated().to_string())
+                .collect(),
+            status: summary.status.into(),
+            pending_count: summary.pending_count,
+            completed_count: summary.completed_count,
+            failed_count: summary.failed_count,
+            totals: summary.totals.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::models::{PendingExecutions, PendingRequestExecution, PendingTransferExecution};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{
+    ListPendingExecutionsResponse, PendingRequestExecutionDTO, PendingTransferExecutionDTO,
+};
+use uuid::Uuid;
+
+impl From<PendingRequestExecution> for PendingRequestExecutionDTO {
+    fn from(pending: PendingRequestExecution) -> Self {
+        PendingRequestExecutionDTO {
+            request_id: Uuid::from_bytes(pending.request_id)
+                .hyphenated()
+                .to_string(),
+            title: pending.title,
+            operation: pending.operation,
+            scheduled_at: timestamp_to_rfc3339(&pending.scheduled_at),
+            queue_position: pending.queue_position as u64,
+        }
+    }
+}
+
+impl From<PendingTransferExecution> for PendingTransferExecutionDTO {
+    fn from(pending: PendingTransferExecution) -> Self {
+        PendingTransferExecutionDTO {
+            transfer_id: Uuid::from_bytes(pending.transfer_id)
+                .hyphenated()
+                .to_string(),
+            request_id: Uuid::from_bytes(pending.request_id)
+                .hyphenated()
+                .to_string(),
+            from_account_id: Uuid::from_bytes(pending.from_account_id)
+                .hyphenated()
+                .to_string(),
+            to: pending.to_address,
+            amount: pending.amount,
+            scheduled_at: timestamp_to_rfc3339(&pending.scheduled_at),
+            paused: pending.paused,
+            queue_position: pending.queue_position as u64,
+        }
+    }
+}
+
+impl From<PendingExecutions> for ListPendingExecutionsResponse {
+    fn from(pending: PendingExecutions) -> Self {
+        ListPendingExecutionsResponse {
+            requests: pending.requests.into_iter().map(Into::into).collect(),
+            transfers: pending.transfers.into_iter().map(Into::into).collect(),
+            next_request_execution_at: pending
+                .next_request_execution_at
+                .as_ref()
+                .map(timestamp_to_rfc3339),
+            next_transfer_execution_at: pending
+                .next_transfer_execution_at
+                .as_ref()
+                .map(timestamp_to_rfc3339),
+        }
+    }
+}
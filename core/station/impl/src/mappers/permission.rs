@@ -1,9 +1,16 @@
 use super::HelperMapper;
-use crate::models::{
-    permission::{Allow, AuthScope, Permission},
-    resource::ResourceIds,
+use crate::{
+    models::{
+        permission::{
+            AccessPolicyDocument, AccessPolicyDocumentPermission, AccessPolicyDocumentUser,
+            AccessPolicyImportConflict, AccessPolicyImportReport, Allow, AuthScope, Permission,
+        },
+        resource::ResourceIds,
+        ImportAccessPoliciesOperation, ImportAccessPoliciesOperationInput,
+    },
+    services::{PolicySuggestion, PolicySuggestionOperation, PolicySuggestions},
 };
-use orbit_essentials::types::UUID;
+use orbit_essentials::{types::UUID, utils::timestamp_to_rfc3339};
 use uuid::Uuid;
 
 impl From<station_api::AuthScopeDTO> for AuthScope {
@@ -108,3 +115,135 @@ impl From<Permission> for station_api::PermissionDTO {
         }
     }
 }
+
+impl From<station_api::AccessPolicyDocumentDTO> for AccessPolicyDocument {
+    fn from(dto: station_api::AccessPolicyDocumentDTO) -> Self {
+        AccessPolicyDocument {
+            user_groups: dto.user_groups,
+            users: dto
+                .users
+                .into_iter()
+                .map(|user| AccessPolicyDocumentUser {
+                    name: user.name,
+                    identities: user.identities,
+                })
+                .collect(),
+            permissions: dto
+                .permissions
+                .into_iter()
+                .map(|permission| AccessPolicyDocumentPermission {
+                    resource: permission.resource.into(),
+                    auth_scope: permission.auth_scope.into(),
+                    user_groups: permission.user_groups,
+                    users: permission.users,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<AccessPolicyDocument> for station_api::AccessPolicyDocumentDTO {
+    fn from(document: AccessPolicyDocument) -> Self {
+        station_api::AccessPolicyDocumentDTO {
+            user_groups: document.user_groups,
+            users: document
+                .users
+                .into_iter()
+                .map(|user| station_api::AccessPolicyDocumentUserDTO {
+                    name: user.name,
+                    identities: user.identities,
+                })
+                .collect(),
+            permissions: document
+                .permissions
+                .into_iter()
+                .map(
+                    |permission| station_api::AccessPolicyDocumentPermissionDTO {
+                        resource: permission.resource.into(),
+                        auth_scope: permission.auth_scope.into(),
+                        user_groups: permission.user_groups,
+                        users: permission.users,
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+impl From<AccessPolicyImportConflict> for station_api::AccessPolicyImportConflictDTO {
+    fn from(conflict: AccessPolicyImportConflict) -> Self {
+        match conflict {
+            AccessPolicyImportConflict::ExistingUserGroup { name } => {
+                station_api::AccessPolicyImportConflictDTO::ExistingUserGroup { name }
+            }
+            AccessPolicyImportConflict::MissingUser { name } => {
+                station_api::AccessPolicyImportConflictDTO::MissingUser { name }
+            }
+            AccessPolicyImportConflict::InvalidResource { resource, reason } => {
+                station_api::AccessPolicyImportConflictDTO::InvalidResource {
+                    resource: resource.into(),
+                    reason,
+                }
+            }
+        }
+    }
+}
+
+impl From<AccessPolicyImportReport> for station_api::AccessPolicyImportReportDTO {
+    fn from(report: AccessPolicyImportReport) -> Self {
+        station_api::AccessPolicyImportReportDTO {
+            created_user_groups: report.created_user_groups,
+            imported_permissions: report.imported_permissions,
+            conflicts: report.conflicts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<station_api::ImportAccessPoliciesOperationInput> for ImportAccessPoliciesOperationInput {
+    fn from(input: station_api::ImportAccessPoliciesOperationInput) -> Self {
+        ImportAccessPoliciesOperationInput {
+            document: input.document.into(),
+        }
+    }
+}
+
+impl From<ImportAccessPoliciesOperation> for station_api::ImportAccessPoliciesOperationDTO {
+    fn from(operation: ImportAccessPoliciesOperation) -> Self {
+        station_api::ImportAccessPoliciesOperationDTO {
+            input: station_api::ImportAccessPoliciesOperationInput {
+                document: operation.input.document.into(),
+            },
+            report: operation.report.map(Into::into),
+        }
+    }
+}
+
+impl From<PolicySuggestion> for station_api::PolicySuggestionDTO {
+    fn from(suggestion: PolicySuggestion) -> Self {
+        station_api::PolicySuggestionDTO {
+            reason: suggestion.reason,
+            operation: match suggestion.operation {
+                PolicySuggestionOperation::EditPermission(input) => {
+                    station_api::RequestOperationInput::EditPermission(input.into())
+                }
+                PolicySuggestionOperation::EditRequestPolicy(input) => {
+                    station_api::RequestOperationInput::EditRequestPolicy(input.into())
+                }
+            },
+        }
+    }
+}
+
+impl From<PolicySuggestions> for station_api::SuggestPoliciesResponse {
+    fn from(suggestions: PolicySuggestions) -> Self {
+        station_api::SuggestPoliciesResponse {
+            analyzed_requests: suggestions.analyzed_requests,
+            analyzed_from: timestamp_to_rfc3339(&suggestions.analyzed_from),
+            suggestions: suggestions
+                .suggestions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
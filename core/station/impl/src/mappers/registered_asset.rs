@@ -0,0 +1,95 @@
+use crate::models::{
+    AddAssetOperation, AddAssetOperationInput, EditAssetOperation, EditAssetOperationInput,
+    RegisteredAsset, RegisteredAssetCallerPrivileges, RemoveAssetOperation,
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{RegisteredAssetCallerPrivilegesDTO, RegisteredAssetDTO};
+use uuid::Uuid;
+
+impl From<RegisteredAsset> for RegisteredAssetDTO {
+    fn from(asset: RegisteredAsset) -> Self {
+        RegisteredAssetDTO {
+            id: Uuid::from_bytes(asset.id).hyphenated().to_string(),
+            ledger_canister_id: asset.ledger_canister_id,
+            index_canister_id: asset.index_canister_id,
+            symbol: asset.symbol,
+            name: asset.name,
+            decimals: asset.decimals,
+            metadata: asset.metadata.into_vec_dto(),
+            last_modification_timestamp: timestamp_to_rfc3339(&asset.last_modification_timestamp),
+        }
+    }
+}
+
+impl From<RegisteredAssetCallerPrivileges> for RegisteredAssetCallerPrivilegesDTO {
+    fn from(input: RegisteredAssetCallerPrivileges) -> Self {
+        RegisteredAssetCallerPrivilegesDTO {
+            id: Uuid::from_bytes(input.id).hyphenated().to_string(),
+            can_edit: input.can_edit,
+            can_delete: input.can_delete,
+        }
+    }
+}
+
+impl AddAssetOperation {
+    pub fn to_dto(self, asset: Option<RegisteredAsset>) -> station_api::AddAssetOperationDTO {
+        station_api::AddAssetOperationDTO {
+            asset: asset.map(Into::into),
+            input: station_api::AddAssetOperationInput {
+                ledger_canister_id: self.input.ledger_canister_id,
+                index_canister_id: self.input.index_canister_id,
+                symbol: self.input.symbol,
+                name: self.input.name,
+                decimals: self.input.decimals,
+                metadata: self.input.metadata.into_vec_dto(),
+            },
+        }
+    }
+}
+
+impl From<station_api::AddAssetOperationInput> for AddAssetOperationInput {
+    fn from(input: station_api::AddAssetOperationInput) -> Self {
+        AddAssetOperationInput {
+            ledger_canister_id: input.ledger_canister_id,
+            index_canister_id: input.index_canister_id,
+            symbol: input.symbol,
+            name: input.name,
+            decimals: input.decimals,
+            metadata: input.metadata.into(),
+        }
+    }
+}
+
+impl From<EditAssetOperationInput> for station_api::EditAssetOperationInput {
+    fn from(input: EditAssetOperationInput) -> Self {
+        station_api::EditAssetOperationInput {
+            asset_id: Uuid::from_bytes(input.asset_id).hyphenated().to_string(),
+            index_canister_id: input.index_canister_id,
+            symbol: input.symbol,
+            name: input.name,
+            decimals: input.decimals,
+            change_metadata: input.change_metadata.map(Into::into),
+        }
+    }
+}
+
+impl From<EditAssetOperation> for station_api::EditAssetOperationDTO {
+    fn from(operation: EditAssetOperation) -> Self {
+        station_api::EditAssetOperationDTO {
+            input: operation.input.into(),
+            previous: operation.previous.map(Into::into),
+        }
+    }
+}
+
+impl From<RemoveAssetOperation> for station_api::RemoveAssetOperationDTO {
+    fn from(operation: RemoveAssetOperation) -> Self {
+        station_api::RemoveAssetOperationDTO {
+            input: station_api::RemoveAssetOperationInput {
+                asset_id: Uuid::from_bytes(operation.input.asset_id)
+                    .hyphenated()
+                    .to_string(),
+            },
+        }
+    }
+}
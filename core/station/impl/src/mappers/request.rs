@@ -1,8 +1,8 @@
 use crate::{
     core::ic_cdk::next_time,
     models::{
-        Request, RequestAdditionalInfo, RequestCallerPrivileges, RequestExecutionPlan,
-        RequestOperation, RequestStatus, UserId,
+        DestinationWarning, Request, RequestAdditionalInfo, RequestCallerPrivileges,
+        RequestExecutionPlan, RequestOperation, RequestStatus, UserId,
     },
 };
 use orbit_essentials::{
@@ -10,7 +10,8 @@ use orbit_essentials::{
     utils::{rfc3339_to_timestamp, timestamp_to_rfc3339},
 };
 use station_api::{
-    CallExternalCanisterOperationDTO, RequestDTO, RequestExecutionScheduleDTO, RequestOperationDTO,
+    CallExternalCanisterOperationDTO, DestinationWarningDTO, RequestAmendmentDTO, RequestDTO,
+    RequestExecutionScheduleDTO, RequestOperationDTO,
 };
 use uuid::Uuid;
 
@@ -36,6 +37,11 @@ impl Request {
             expiration_dt,
             execution_plan,
             approvals: vec![],
+            confidential: false,
+            tags: vec![],
+            depends_on: None,
+            amendments: vec![],
+            vote_reminder_sent: false,
             created_timestamp: now,
             last_modification_timestamp: now,
         }
@@ -71,6 +77,20 @@ impl Request {
                 .iter()
                 .map(|approval| approval.to_owned().into())
                 .collect(),
+            confidential: self.confidential,
+            tags: self.tags,
+            depends_on: self
+                .depends_on
+                .map(|dependency_id| Uuid::from_bytes(dependency_id).hyphenated().to_string()),
+            amendments: self
+                .amendments
+                .into_iter()
+                .map(|amendment| RequestAmendmentDTO {
+                    previous_operation: amendment.previous_operation.into(),
+                    reason: amendment.reason,
+                    amended_at: timestamp_to_rfc3339(&amendment.amended_at),
+                })
+                .collect(),
         }
     }
 
@@ -125,6 +145,20 @@ impl From<RequestAdditionalInfo> for station_api::RequestAdditionalInfoDTO {
                 .map(|approver| approver.into())
                 .collect(),
             evaluation_result: info.evaluation_result.map(|result| result.into()),
+            destination_warning: info.destination_warning.map(Into::into),
+        }
+    }
+}
+
+impl From<DestinationWarning> for DestinationWarningDTO {
+    fn from(warning: DestinationWarning) -> Self {
+        match warning {
+            DestinationWarning::NeverUsed => DestinationWarningDTO::NeverUsed,
+            DestinationWarning::RecentlyUsed { first_used_at } => {
+                DestinationWarningDTO::RecentlyUsed {
+                    first_used_at: timestamp_to_rfc3339(&first_used_at),
+                }
+            }
         }
     }
 }
@@ -0,0 +1,31 @@
+use crate::{
+    models::{RequestComment, User},
+    repositories::USER_REPOSITORY,
+};
+use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
+use station_api::{DisplayUserDTO, RequestCommentDTO};
+use uuid::Uuid;
+
+impl From<RequestComment> for RequestCommentDTO {
+    fn from(comment: RequestComment) -> Self {
+        let author_id = Uuid::from_bytes(comment.author_id).hyphenated().to_string();
+        // the comments of the users that were removed keep showing their id
+        let author_name = USER_REPOSITORY
+            .get(&User::key(comment.author_id))
+            .map(|user| user.name)
+            .unwrap_or_else(|| author_id.clone());
+
+        RequestCommentDTO {
+            id: Uuid::from_bytes(comment.id).hyphenated().to_string(),
+            request_id: Uuid::from_bytes(comment.request_id)
+                .hyphenated()
+                .to_string(),
+            author: DisplayUserDTO {
+                id: author_id,
+                name: author_name,
+            },
+            body: comment.body,
+            created_at: timestamp_to_rfc3339(&comment.created_timestamp),
+        }
+    }
+}
@@ -9,18 +9,21 @@ use crate::{
         },
         Account, AccountKey, AddAccountOperation, AddAccountOperationInput,
         AddAddressBookEntryOperation, AddAddressBookEntryOperationInput, AddRequestPolicyOperation,
-        AddRequestPolicyOperationInput, AddUserOperation, AddUserOperationInput, AddressBookEntry,
-        CallExternalCanisterOperation, CallExternalCanisterOperationInput,
-        CanisterExecutionAndValidationMethodPairInput, CanisterInstallMode,
-        CanisterInstallModeArgs, CanisterMethod, CanisterReinstallModeArgs,
-        CanisterUpgradeModeArgs, ChangeExternalCanisterOperation,
+        AddRequestPolicyOperationInput, AddScheduledTransferOperation, AddUserOperation,
+        AddUserOperationInput, AddressBookEntry, AddressBookLabelInput, ApprovalReminders,
+        ApproveOperation, ArchiveSink, ArchiveSinkChange, CallExternalCanisterOperation,
+        CallExternalCanisterOperationInput, CanisterExecutionAndValidationMethodPairInput,
+        CanisterInstallMode, CanisterInstallModeArgs, CanisterMethod, CanisterReinstallModeArgs,
+        CanisterUpgradeModeArgs, CapabilityGrant, ChangeExternalCanisterOperation,
         ChangeExternalCanisterOperationInput, ConfigureExternalCanisterOperation,
         ConfigureExternalCanisterOperationKind, ConfigureExternalCanisterSettingsInput,
+        CreateCapabilityGrantOperation, CreateCapabilityGrantOperationInput,
         CreateExternalCanisterOperation, CreateExternalCanisterOperationInput,
         CreateExternalCanisterOperationKind, CreateExternalCanisterOperationKindAddExisting,
-        CreateExternalCanisterOperationKindCreateNew, CycleObtainStrategy,
-        DefiniteCanisterSettingsInput, DisasterRecoveryCommittee, EditAccountOperation,
-        EditAccountOperationInput, EditAddressBookEntryOperation, EditPermissionOperation,
+        CreateExternalCanisterOperationKindCreateNew, CycleObtainStrategy, CycleThresholds,
+        DefiniteCanisterSettingsInput, DeriveSubaccountOperation, Dex, DisasterRecoveryCommittee,
+        EditAccountOperation, EditAccountOperationInput, EditAddressBookEntryOperation,
+        EditAddressBookEntryOperationInput, EditAssetOperation, EditPermissionOperation,
         EditPermissionOperationInput, EditRequestPolicyOperation, EditRequestPolicyOperationInput,
         EditUserGroupOperation, EditUserOperation, EditUserOperationInput,
         ExternalCanisterCallPermission, ExternalCanisterCallPermissionExecMethodEntryInput,
@@ -33,25 +36,35 @@ use crate::{
         ExternalCanisterChangeCallPermissionsInput, ExternalCanisterChangeCallRequestPoliciesInput,
         ExternalCanisterChangeRequestPolicyRuleInput, ExternalCanisterPermissionsCreateInput,
         ExternalCanisterPermissionsUpdateInput, ExternalCanisterRequestPoliciesCreateInput,
-        ExternalCanisterRequestPoliciesUpdateInput, FundExternalCanisterOperation, LogVisibility,
-        ManageSystemInfoOperation, ManageSystemInfoOperationInput, RemoveAddressBookEntryOperation,
-        RemoveRequestPolicyOperation, RemoveRequestPolicyOperationInput, RemoveUserGroupOperation,
-        RequestOperation, SetDisasterRecoveryOperation, SetDisasterRecoveryOperationInput,
+        ExternalCanisterRequestPoliciesUpdateInput, FinalityThreshold,
+        FundExternalCanisterOperation, FundExternalCanisterOperationKind, LogVisibility,
+        ManageAddressBookLabelsOperation, ManageAddressBookLabelsOperationInput,
+        ManageSystemInfoOperation, ManageSystemInfoOperationInput, RateLimitPeriod,
+        RemoveAddressBookEntryOperation, RemoveAssetOperation, RemoveRequestPolicyOperation,
+        RemoveRequestPolicyOperationInput, RemoveUserGroupOperation, RequestCreationRateLimit,
+        RequestCreationRateLimits, RequestOperation, RequestOperationLimits,
+        RevokeCapabilityGrantOperation, RpcProvider, RpcProvidersConfig,
+        SetAutoApprovalForTrustedDestinationsOperation, SetDisasterRecoveryOperation,
+        SetDisasterRecoveryOperationInput, SplitTransferDestination, SplitTransferOperation,
+        SplitTransferOperationInput, SplitTransferShare, StableMemoryGuardrail,
+        SwapTokensOperation, SweepAccountOperation, SweepAccountOperationInput,
         SystemUpgradeOperation, SystemUpgradeOperationInput, SystemUpgradeTarget,
-        TransferOperation, User, WasmModuleExtraChunks,
+        TransferNftOperation, TransferOperation, TransferOperationInput, TransferRetryPolicy, User,
+        VersionPin, WasmModuleExtraChunks,
     },
     repositories::{
         AccountRepository, AddressBookRepository, UserRepository, ACCOUNT_REPOSITORY,
-        USER_GROUP_REPOSITORY,
+        CAPABILITY_GRANT_REPOSITORY, REGISTERED_ASSET_REPOSITORY, USER_GROUP_REPOSITORY,
     },
 };
 use orbit_essentials::repository::Repository;
+use orbit_essentials::utils::timestamp_to_rfc3339;
 use station_api::{
     AddAccountOperationDTO, AddAddressBookEntryOperationDTO, AddUserOperationDTO,
     CallExternalCanisterOperationDTO, CanisterMethodDTO, ChangeExternalCanisterOperationDTO,
     CreateExternalCanisterOperationDTO, EditAccountOperationDTO, EditAddressBookEntryOperationDTO,
-    EditUserOperationDTO, NetworkDTO, RemoveAddressBookEntryOperationDTO, RequestOperationDTO,
-    TransferOperationDTO,
+    EditUserOperationDTO, ManageAddressBookLabelsOperationDTO, NetworkDTO,
+    RemoveAddressBookEntryOperationDTO, RequestOperationDTO, TransferOperationDTO,
 };
 use uuid::Uuid;
 
@@ -63,23 +76,259 @@ impl TransferOperation {
                 id: self.input.network.clone(),
                 name: self.input.network.clone(),
             },
-            input: station_api::TransferOperationInput {
+            input: self.input.into(),
+            transfer_id: self
+                .transfer_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            fee: self.fee,
+        }
+    }
+}
+
+impl From<TransferOperationInput> for station_api::TransferOperationInput {
+    fn from(input: TransferOperationInput) -> station_api::TransferOperationInput {
+        station_api::TransferOperationInput {
+            from_account_id: Uuid::from_bytes(input.from_account_id)
+                .hyphenated()
+                .to_string(),
+            amount: input.amount,
+            to: input.to,
+            fee: input.fee,
+            metadata: input.metadata.into_vec_dto(),
+            network: Some(NetworkDTO {
+                id: input.network.clone(),
+                name: input.network,
+            }),
+            spend_from: input.spend_from.map(|account| account.to_string()),
+            memo: input.memo.map(Into::into),
+            category: input.category,
+            asset_id: input
+                .asset_id
+                .map(|asset_id| Uuid::from_bytes(asset_id).hyphenated().to_string()),
+        }
+    }
+}
+
+impl SweepAccountOperation {
+    pub fn to_dto(self, account: Option<Account>) -> station_api::SweepAccountOperationDTO {
+        station_api::SweepAccountOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            network: NetworkDTO {
+                id: self.input.network.clone(),
+                name: self.input.network.clone(),
+            },
+            input: self.input.into(),
+            transfer_id: self
+                .transfer_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            amount: self.amount,
+            fee: self.fee,
+        }
+    }
+}
+
+impl From<SweepAccountOperationInput> for station_api::SweepAccountOperationInput {
+    fn from(input: SweepAccountOperationInput) -> station_api::SweepAccountOperationInput {
+        station_api::SweepAccountOperationInput {
+            from_account_id: Uuid::from_bytes(input.from_account_id)
+                .hyphenated()
+                .to_string(),
+            to: input.to,
+            metadata: input.metadata.into_vec_dto(),
+            network: Some(NetworkDTO {
+                id: input.network.clone(),
+                name: input.network,
+            }),
+            memo: input.memo.map(Into::into),
+        }
+    }
+}
+
+impl SplitTransferOperation {
+    pub fn to_dto(self, account: Option<Account>) -> station_api::SplitTransferOperationDTO {
+        station_api::SplitTransferOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            network: NetworkDTO {
+                id: self.input.network.clone(),
+                name: self.input.network.clone(),
+            },
+            input: self.input.into(),
+            transfer_ids: self
+                .transfer_ids
+                .into_iter()
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                .collect(),
+            fee: self.fee,
+        }
+    }
+}
+
+impl From<SplitTransferShare> for station_api::SplitTransferShareDTO {
+    fn from(share: SplitTransferShare) -> Self {
+        match share {
+            SplitTransferShare::BasisPoints(basis_points) => {
+                station_api::SplitTransferShareDTO::BasisPoints(basis_points)
+            }
+            SplitTransferShare::Amount(amount) => {
+                station_api::SplitTransferShareDTO::Amount(amount)
+            }
+        }
+    }
+}
+
+impl From<station_api::SplitTransferShareDTO> for SplitTransferShare {
+    fn from(share: station_api::SplitTransferShareDTO) -> Self {
+        match share {
+            station_api::SplitTransferShareDTO::BasisPoints(basis_points) => {
+                SplitTransferShare::BasisPoints(basis_points)
+            }
+            station_api::SplitTransferShareDTO::Amount(amount) => {
+                SplitTransferShare::Amount(amount)
+            }
+        }
+    }
+}
+
+impl From<SplitTransferDestination> for station_api::SplitTransferDestinationDTO {
+    fn from(destination: SplitTransferDestination) -> Self {
+        station_api::SplitTransferDestinationDTO {
+            to: destination.to,
+            share: destination.share.into(),
+        }
+    }
+}
+
+impl From<station_api::SplitTransferDestinationDTO> for SplitTransferDestination {
+    fn from(destination: station_api::SplitTransferDestinationDTO) -> Self {
+        SplitTransferDestination {
+            to: destination.to,
+            share: destination.share.into(),
+        }
+    }
+}
+
+impl From<SplitTransferOperationInput> for station_api::SplitTransferOperationInput {
+    fn from(input: SplitTransferOperationInput) -> station_api::SplitTransferOperationInput {
+        station_api::SplitTransferOperationInput {
+            from_account_id: Uuid::from_bytes(input.from_account_id)
+                .hyphenated()
+                .to_string(),
+            total_amount: input.total_amount,
+            destinations: input.destinations.into_iter().map(Into::into).collect(),
+            metadata: input.metadata.into_vec_dto(),
+            network: Some(NetworkDTO {
+                id: input.network.clone(),
+                name: input.network,
+            }),
+            memo: input.memo.map(Into::into),
+        }
+    }
+}
+
+impl AddScheduledTransferOperation {
+    pub fn to_dto(self, account: Option<Account>) -> station_api::AddScheduledTransferOperationDTO {
+        station_api::AddScheduledTransferOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            scheduled_transfer_id: self
+                .scheduled_transfer_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            input: station_api::AddScheduledTransferOperationInput {
+                transfer: self.input.transfer.into(),
+                interval_secs: self.input.interval_ns / 1_000_000_000,
+                start_at: self.input.start_at.as_ref().map(timestamp_to_rfc3339),
+                end_at: self.input.end_at.as_ref().map(timestamp_to_rfc3339),
+            },
+        }
+    }
+}
+
+impl TransferNftOperation {
+    pub fn to_dto(self, account: Option<Account>) -> station_api::TransferNftOperationDTO {
+        station_api::TransferNftOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            input: station_api::TransferNftOperationInput {
                 from_account_id: Uuid::from_bytes(self.input.from_account_id)
                     .hyphenated()
                     .to_string(),
+                token_id: self.input.token_id,
+                to: self.input.to.to_string(),
+                memo: self.input.memo,
+            },
+            block_index: self.block_index,
+        }
+    }
+}
+
+impl ApproveOperation {
+    pub fn to_dto(self, account: Option<Account>) -> station_api::ApproveOperationDTO {
+        station_api::ApproveOperationDTO {
+            from_account: account.map(|account| account.to_dto()),
+            input: station_api::ApproveOperationInput {
+                from_account_id: Uuid::from_bytes(self.input.from_account_id)
+                    .hyphenated()
+                    .to_string(),
+                spender: self.input.spender.to_string(),
                 amount: self.input.amount,
-                to: self.input.to,
+                expected_allowance: self.input.expected_allowance,
+                expires_at: self
+                    .input
+                    .expires_at
+                    .map(|expires_at| timestamp_to_rfc3339(&expires_at)),
                 fee: self.input.fee,
-                metadata: self.input.metadata.into_vec_dto(),
-                network: Some(NetworkDTO {
-                    id: self.input.network.clone(),
-                    name: self.input.network.clone(),
-                }),
             },
-            transfer_id: self
-                .transfer_id
-                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
-            fee: self.fee,
+            block_index: self.block_index,
+        }
+    }
+}
+
+impl From<Dex> for station_api::DexDTO {
+    fn from(dex: Dex) -> Self {
+        match dex {
+            Dex::ICPSwap => station_api::DexDTO::ICPSwap,
+            Dex::Sonic => station_api::DexDTO::Sonic,
+            Dex::KongSwap => station_api::DexDTO::KongSwap,
+        }
+    }
+}
+
+impl From<station_api::DexDTO> for Dex {
+    fn from(dex: station_api::DexDTO) -> Self {
+        match dex {
+            station_api::DexDTO::ICPSwap => Dex::ICPSwap,
+            station_api::DexDTO::Sonic => Dex::Sonic,
+            station_api::DexDTO::KongSwap => Dex::KongSwap,
+        }
+    }
+}
+
+impl SwapTokensOperation {
+    pub fn to_dto(
+        self,
+        from_account: Option<Account>,
+        to_account: Option<Account>,
+    ) -> station_api::SwapTokensOperationDTO {
+        station_api::SwapTokensOperationDTO {
+            from_account: from_account.map(|account| account.to_dto()),
+            to_account: to_account.map(|account| account.to_dto()),
+            min_amount_out: self.input.min_amount_out(),
+            input: station_api::SwapTokensOperationInput {
+                from_account_id: Uuid::from_bytes(self.input.from_account_id)
+                    .hyphenated()
+                    .to_string(),
+                to_account_id: Uuid::from_bytes(self.input.to_account_id)
+                    .hyphenated()
+                    .to_string(),
+                dex: self.input.dex.into(),
+                pool_canister_id: self.input.pool_canister_id,
+                amount_in: self.input.amount_in,
+                expected_amount_out: self.input.expected_amount_out,
+                max_slippage_bps: self.input.max_slippage_bps,
+            },
+            result: self.result.map(|result| station_api::SwapTokensResultDTO {
+                amount_in: result.amount_in,
+                amount_out: result.amount_out,
+                executed_at: timestamp_to_rfc3339(&result.executed_at),
+            }),
         }
     }
 }
@@ -88,17 +337,24 @@ impl AddAccountOperation {
     pub fn to_dto(self, account: Option<Account>) -> AddAccountOperationDTO {
         AddAccountOperationDTO {
             account: account.map(|account: Account| account.to_dto()),
-            input: station_api::AddAccountOperationInput {
-                name: self.input.name,
-                blockchain: self.input.blockchain.to_string(),
-                standard: self.input.standard.to_string(),
-                metadata: self.input.metadata.into_vec_dto(),
-                read_permission: self.input.read_permission.into(),
-                transfer_permission: self.input.transfer_permission.into(),
-                configs_permission: self.input.configs_permission.into(),
-                transfer_request_policy: self.input.transfer_request_policy.map(Into::into),
-                configs_request_policy: self.input.configs_request_policy.map(Into::into),
-            },
+            input: self.input.into(),
+        }
+    }
+}
+
+impl From<AddAccountOperationInput> for station_api::AddAccountOperationInput {
+    fn from(input: AddAccountOperationInput) -> station_api::AddAccountOperationInput {
+        station_api::AddAccountOperationInput {
+            name: input.name,
+            blockchain: input.blockchain.to_string(),
+            standard: input.standard.to_string(),
+            network: Some(input.network.into()),
+            metadata: input.metadata.into_vec_dto(),
+            read_permission: input.read_permission.into(),
+            transfer_permission: input.transfer_permission.into(),
+            configs_permission: input.configs_permission.into(),
+            transfer_request_policy: input.transfer_request_policy.map(Into::into),
+            configs_request_policy: input.configs_request_policy.map(Into::into),
         }
     }
 }
@@ -124,6 +380,7 @@ impl From<station_api::AddAccountOperationInput> for AddAccountOperationInput {
                 .expect("Invalid blockchain"),
             standard: BlockchainMapper::to_blockchain_standard(input.standard)
                 .expect("Invalid blockchain standard"),
+            network: input.network.map(Into::into).unwrap_or_default(),
             metadata: input.metadata.into(),
             read_permission: input.read_permission.into(),
             configs_permission: input.configs_permission.into(),
@@ -134,31 +391,69 @@ impl From<station_api::AddAccountOperationInput> for AddAccountOperationInput {
     }
 }
 
+impl From<EditAccountOperationInput> for station_api::EditAccountOperationInput {
+    fn from(input: EditAccountOperationInput) -> station_api::EditAccountOperationInput {
+        station_api::EditAccountOperationInput {
+            account_id: Uuid::from_bytes(input.account_id).hyphenated().to_string(),
+            name: input.name,
+            read_permission: input.read_permission.map(|policy| policy.into()),
+            transfer_permission: input.transfer_permission.map(|policy| policy.into()),
+            configs_permission: input.configs_permission.map(|policy| policy.into()),
+            transfer_request_policy: input.transfer_request_policy.map(|policy| policy.into()),
+            configs_request_policy: input.configs_request_policy.map(|policy| policy.into()),
+            webhooks: input
+                .webhooks
+                .map(|webhooks| webhooks.into_iter().map(Into::into).collect()),
+            assets: input.assets.map(|assets| {
+                assets
+                    .into_iter()
+                    .map(|asset_id| Uuid::from_bytes(asset_id).hyphenated().to_string())
+                    .collect()
+            }),
+        }
+    }
+}
+
 impl From<EditAccountOperation> for EditAccountOperationDTO {
     fn from(operation: EditAccountOperation) -> EditAccountOperationDTO {
         EditAccountOperationDTO {
-            input: station_api::EditAccountOperationInput {
+            input: operation.input.into(),
+            previous: operation.previous.map(Into::into),
+        }
+    }
+}
+
+impl From<SetAutoApprovalForTrustedDestinationsOperation>
+    for station_api::SetAutoApprovalForTrustedDestinationsOperationDTO
+{
+    fn from(
+        operation: SetAutoApprovalForTrustedDestinationsOperation,
+    ) -> station_api::SetAutoApprovalForTrustedDestinationsOperationDTO {
+        station_api::SetAutoApprovalForTrustedDestinationsOperationDTO {
+            input: station_api::SetAutoApprovalForTrustedDestinationsOperationInput {
                 account_id: Uuid::from_bytes(operation.input.account_id)
                     .hyphenated()
                     .to_string(),
-                name: operation.input.name,
-                read_permission: operation.input.read_permission.map(|policy| policy.into()),
-                transfer_permission: operation
-                    .input
-                    .transfer_permission
-                    .map(|policy| policy.into()),
-                configs_permission: operation
-                    .input
-                    .configs_permission
-                    .map(|policy| policy.into()),
-                transfer_request_policy: operation
+                trusted_destinations: operation
                     .input
-                    .transfer_request_policy
-                    .map(|policy| policy.into()),
-                configs_request_policy: operation
-                    .input
-                    .configs_request_policy
-                    .map(|policy| policy.into()),
+                    .trusted_destinations
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<DeriveSubaccountOperation> for station_api::DeriveSubaccountOperationDTO {
+    fn from(operation: DeriveSubaccountOperation) -> station_api::DeriveSubaccountOperationDTO {
+        station_api::DeriveSubaccountOperationDTO {
+            subaccount_index: operation.subaccount_index,
+            input: station_api::DeriveSubaccountOperationInput {
+                account_id: Uuid::from_bytes(operation.input.account_id)
+                    .hyphenated()
+                    .to_string(),
+                name: operation.input.name,
             },
         }
     }
@@ -176,6 +471,19 @@ impl From<station_api::EditAccountOperationInput> for EditAccountOperationInput
             configs_permission: input.configs_permission.map(|policy| policy.into()),
             transfer_request_policy: input.transfer_request_policy.map(|policy| policy.into()),
             configs_request_policy: input.configs_request_policy.map(|policy| policy.into()),
+            webhooks: input
+                .webhooks
+                .map(|webhooks| webhooks.into_iter().map(Into::into).collect()),
+            assets: input.assets.map(|assets| {
+                assets
+                    .into_iter()
+                    .map(|asset_id| {
+                        *HelperMapper::to_uuid(asset_id)
+                            .expect("Invalid asset id")
+                            .as_bytes()
+                    })
+                    .collect()
+            }),
         }
     }
 }
@@ -194,6 +502,7 @@ impl AddAddressBookEntryOperation {
                 blockchain: self.input.blockchain.to_string(),
                 metadata: self.input.metadata.into_iter().map(Into::into).collect(),
                 labels: self.input.labels,
+                requires_memo: Some(self.input.requires_memo),
             },
         }
     }
@@ -210,6 +519,25 @@ impl From<station_api::AddAddressBookEntryOperationInput> for AddAddressBookEntr
                 .expect("Invalid blockchain"),
             metadata: input.metadata.into_iter().map(Into::into).collect(),
             labels: input.labels,
+            requires_memo: input.requires_memo.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<EditAddressBookEntryOperationInput> for station_api::EditAddressBookEntryOperationInput {
+    fn from(
+        input: EditAddressBookEntryOperationInput,
+    ) -> station_api::EditAddressBookEntryOperationInput {
+        station_api::EditAddressBookEntryOperationInput {
+            address_book_entry_id: Uuid::from_bytes(input.address_book_entry_id)
+                .hyphenated()
+                .to_string(),
+            address_owner: input.address_owner,
+            change_metadata: input
+                .change_metadata
+                .map(|change_metadata| change_metadata.into()),
+            labels: input.labels,
+            requires_memo: input.requires_memo,
         }
     }
 }
@@ -217,17 +545,8 @@ impl From<station_api::AddAddressBookEntryOperationInput> for AddAddressBookEntr
 impl From<EditAddressBookEntryOperation> for EditAddressBookEntryOperationDTO {
     fn from(operation: EditAddressBookEntryOperation) -> EditAddressBookEntryOperationDTO {
         EditAddressBookEntryOperationDTO {
-            input: station_api::EditAddressBookEntryOperationInput {
-                address_book_entry_id: Uuid::from_bytes(operation.input.address_book_entry_id)
-                    .hyphenated()
-                    .to_string(),
-                address_owner: operation.input.address_owner,
-                change_metadata: operation
-                    .input
-                    .change_metadata
-                    .map(|change_metadata| change_metadata.into()),
-                labels: operation.input.labels,
-            },
+            input: operation.input.into(),
+            previous: operation.previous.map(Into::into),
         }
     }
 }
@@ -244,6 +563,94 @@ impl From<RemoveAddressBookEntryOperation> for RemoveAddressBookEntryOperationDT
     }
 }
 
+impl From<station_api::AddressBookLabelInput> for AddressBookLabelInput {
+    fn from(input: station_api::AddressBookLabelInput) -> AddressBookLabelInput {
+        AddressBookLabelInput {
+            name: input.name,
+            description: input.description,
+        }
+    }
+}
+
+impl From<AddressBookLabelInput> for station_api::AddressBookLabelInput {
+    fn from(input: AddressBookLabelInput) -> station_api::AddressBookLabelInput {
+        station_api::AddressBookLabelInput {
+            name: input.name,
+            description: input.description,
+        }
+    }
+}
+
+impl From<station_api::ManageAddressBookLabelsOperationInput>
+    for ManageAddressBookLabelsOperationInput
+{
+    fn from(
+        input: station_api::ManageAddressBookLabelsOperationInput,
+    ) -> ManageAddressBookLabelsOperationInput {
+        ManageAddressBookLabelsOperationInput {
+            set_labels: input.set_labels.into_iter().map(Into::into).collect(),
+            remove_labels: input.remove_labels,
+        }
+    }
+}
+
+impl From<ManageAddressBookLabelsOperation> for ManageAddressBookLabelsOperationDTO {
+    fn from(operation: ManageAddressBookLabelsOperation) -> ManageAddressBookLabelsOperationDTO {
+        ManageAddressBookLabelsOperationDTO {
+            input: station_api::ManageAddressBookLabelsOperationInput {
+                set_labels: operation
+                    .input
+                    .set_labels
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                remove_labels: operation.input.remove_labels,
+            },
+        }
+    }
+}
+
+impl From<CreateCapabilityGrantOperationInput>
+    for station_api::CreateCapabilityGrantOperationInput
+{
+    fn from(
+        input: CreateCapabilityGrantOperationInput,
+    ) -> station_api::CreateCapabilityGrantOperationInput {
+        station_api::CreateCapabilityGrantOperationInput {
+            account_id: Uuid::from_bytes(input.account_id).hyphenated().to_string(),
+            grantee: input.grantee,
+            scopes: input.scopes.into_iter().map(Into::into).collect(),
+            expires_at: timestamp_to_rfc3339(&input.expires_at),
+        }
+    }
+}
+
+impl CreateCapabilityGrantOperation {
+    pub fn to_dto(
+        self,
+        grant: Option<CapabilityGrant>,
+    ) -> station_api::CreateCapabilityGrantOperationDTO {
+        station_api::CreateCapabilityGrantOperationDTO {
+            grant: grant.map(Into::into),
+            input: self.input.into(),
+        }
+    }
+}
+
+impl From<RevokeCapabilityGrantOperation> for station_api::RevokeCapabilityGrantOperationDTO {
+    fn from(
+        operation: RevokeCapabilityGrantOperation,
+    ) -> station_api::RevokeCapabilityGrantOperationDTO {
+        station_api::RevokeCapabilityGrantOperationDTO {
+            input: station_api::RevokeCapabilityGrantOperationInput {
+                grant_id: Uuid::from_bytes(operation.input.grant_id)
+                    .hyphenated()
+                    .to_string(),
+            },
+        }
+    }
+}
+
 impl AddUserOperation {
     pub fn to_dto(self, user: Option<User>) -> AddUserOperationDTO {
         AddUserOperationDTO {
@@ -258,29 +665,36 @@ impl AddUserOperation {
                     .map(|group| Uuid::from_bytes(*group).hyphenated().to_string())
                     .collect(),
                 status: self.input.status.into(),
+                approve_only_identities: Some(self.input.approve_only_identities),
             },
         }
     }
 }
 
+impl From<EditUserOperationInput> for station_api::EditUserOperationInput {
+    fn from(input: EditUserOperationInput) -> station_api::EditUserOperationInput {
+        station_api::EditUserOperationInput {
+            id: Uuid::from_bytes(input.user_id).hyphenated().to_string(),
+            name: input.name,
+            identities: input.identities,
+            groups: input.groups.map(|groups| {
+                groups
+                    .iter()
+                    .map(|group| Uuid::from_bytes(*group).hyphenated().to_string())
+                    .collect()
+            }),
+            status: input.status.map(|status| status.into()),
+            cancel_pending_requests: input.cancel_pending_requests,
+            approve_only_identities: input.approve_only_identities,
+        }
+    }
+}
+
 impl From<EditUserOperation> for EditUserOperationDTO {
     fn from(operation: EditUserOperation) -> EditUserOperationDTO {
         EditUserOperationDTO {
-            input: station_api::EditUserOperationInput {
-                id: Uuid::from_bytes(operation.input.user_id)
-                    .hyphenated()
-                    .to_string(),
-                name: operation.input.name,
-                identities: operation.input.identities,
-                groups: operation.input.groups.map(|groups| {
-                    groups
-                        .iter()
-                        .map(|group| Uuid::from_bytes(*group).hyphenated().to_string())
-                        .collect()
-                }),
-                status: operation.input.status.map(|status| status.into()),
-                cancel_pending_requests: operation.input.cancel_pending_requests,
-            },
+            input: operation.input.into(),
+            previous: operation.previous.map(Into::into),
         }
     }
 }
@@ -300,6 +714,7 @@ impl From<station_api::AddUserOperationInput> for AddUserOperationInput {
                 })
                 .collect(),
             status: input.status.into(),
+            approve_only_identities: input.approve_only_identities.unwrap_or_default(),
         }
     }
 }
@@ -324,6 +739,7 @@ impl From<station_api::EditUserOperationInput> for EditUserOperationInput {
             }),
             status: input.status.map(|status| status.into()),
             cancel_pending_requests: input.cancel_pending_requests,
+            approve_only_identities: input.approve_only_identities,
         }
     }
 }
@@ -493,6 +909,9 @@ impl From<ChangeExternalCanisterOperationInput>
             module: input.module,
             module_extra_chunks: input.module_extra_chunks.map(|c| c.into()),
             arg: input.arg,
+            replaces: input
+                .replaces
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
         }
     }
 }
@@ -509,6 +928,11 @@ impl From<station_api::ChangeExternalCanisterOperationInput>
             module: input.module,
             module_extra_chunks: input.module_extra_chunks.map(|c| c.into()),
             arg: input.arg,
+            replaces: input.replaces.map(|id| {
+                *HelperMapper::to_uuid(id)
+                    .expect("Invalid request id")
+                    .as_bytes()
+            }),
         }
     }
 }
@@ -520,6 +944,10 @@ impl From<ChangeExternalCanisterOperation> for ChangeExternalCanisterOperationDT
             mode: operation.input.mode.into(),
             module_checksum: hex::encode(operation.module_checksum),
             arg_checksum: operation.arg_checksum.map(hex::encode),
+            replaces: operation
+                .input
+                .replaces
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
         }
     }
 }
@@ -573,6 +1001,7 @@ impl From<ConfigureExternalCanisterSettingsInput>
             state: input.state.map(Into::into),
             permissions: input.permissions.map(Into::into),
             request_policies: input.request_policies.map(Into::into),
+            monitoring: input.monitoring.map(Into::into),
         }
     }
 }
@@ -1295,6 +1724,7 @@ impl From<EditPermissionOperationInput> for station_api::EditPermissionOperation
                     .collect()
             }),
             resource: input.resource.into(),
+            effective_from: None,
         }
     }
 }
@@ -1329,7 +1759,13 @@ impl From<station_api::EditPermissionOperationInput> for EditPermissionOperation
 impl From<EditPermissionOperation> for station_api::EditPermissionOperationDTO {
     fn from(operation: EditPermissionOperation) -> station_api::EditPermissionOperationDTO {
         station_api::EditPermissionOperationDTO {
-            input: operation.input.into(),
+            input: station_api::EditPermissionOperationInput {
+                effective_from: operation
+                    .effective_from
+                    .map(|effective_from| timestamp_to_rfc3339(&effective_from)),
+                ..operation.input.into()
+            },
+            previous: operation.previous.map(Into::into),
         }
     }
 }
@@ -1339,6 +1775,8 @@ impl From<AddRequestPolicyOperationInput> for station_api::AddRequestPolicyOpera
         station_api::AddRequestPolicyOperationInput {
             specifier: input.specifier.into(),
             rule: input.rule.into(),
+            expiration_period_secs: input.expiration_period_secs,
+            effective_from: None,
         }
     }
 }
@@ -1348,6 +1786,7 @@ impl From<station_api::AddRequestPolicyOperationInput> for AddRequestPolicyOpera
         AddRequestPolicyOperationInput {
             specifier: input.specifier.into(),
             rule: input.rule.into(),
+            expiration_period_secs: input.expiration_period_secs,
         }
     }
 }
@@ -1358,7 +1797,12 @@ impl From<AddRequestPolicyOperation> for station_api::AddRequestPolicyOperationD
             policy_id: operation
                 .policy_id
                 .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
-            input: operation.input.into(),
+            input: station_api::AddRequestPolicyOperationInput {
+                effective_from: operation
+                    .effective_from
+                    .map(|effective_from| timestamp_to_rfc3339(&effective_from)),
+                ..operation.input.into()
+            },
         }
     }
 }
@@ -1371,6 +1815,8 @@ impl From<EditRequestPolicyOperationInput> for station_api::EditRequestPolicyOpe
             policy_id: Uuid::from_bytes(input.policy_id).hyphenated().to_string(),
             specifier: input.specifier.map(|specifier| specifier.into()),
             rule: input.rule.map(|rule| rule.into()),
+            expiration_period_secs: input.expiration_period_secs.map(Into::into),
+            effective_from: None,
         }
     }
 }
@@ -1385,6 +1831,7 @@ impl From<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyOpe
                 .as_bytes(),
             specifier: input.specifier.map(|specifier| specifier.into()),
             rule: input.rule.map(|rule| rule.into()),
+            expiration_period_secs: input.expiration_period_secs.map(Into::into),
         }
     }
 }
@@ -1392,7 +1839,13 @@ impl From<station_api::EditRequestPolicyOperationInput> for EditRequestPolicyOpe
 impl From<EditRequestPolicyOperation> for station_api::EditRequestPolicyOperationDTO {
     fn from(operation: EditRequestPolicyOperation) -> station_api::EditRequestPolicyOperationDTO {
         station_api::EditRequestPolicyOperationDTO {
-            input: operation.input.into(),
+            input: station_api::EditRequestPolicyOperationInput {
+                effective_from: operation
+                    .effective_from
+                    .map(|effective_from| timestamp_to_rfc3339(&effective_from)),
+                ..operation.input.into()
+            },
+            previous: operation.previous.map(Into::into),
         }
     }
 }
@@ -1488,11 +1941,179 @@ impl From<CycleObtainStrategy> for station_api::CycleObtainStrategyInput {
     }
 }
 
+impl From<RequestOperationLimits> for station_api::RequestOperationLimitsDTO {
+    fn from(limits: RequestOperationLimits) -> Self {
+        station_api::RequestOperationLimitsDTO {
+            max_wasm_module_size: limits.max_wasm_module_size,
+            max_arg_size: limits.max_arg_size,
+            max_batch_length: limits.max_batch_length,
+        }
+    }
+}
+
+impl From<station_api::RequestOperationLimitsDTO> for RequestOperationLimits {
+    fn from(limits: station_api::RequestOperationLimitsDTO) -> Self {
+        RequestOperationLimits {
+            max_wasm_module_size: limits.max_wasm_module_size,
+            max_arg_size: limits.max_arg_size,
+            max_batch_length: limits.max_batch_length,
+        }
+    }
+}
+
+impl From<RateLimitPeriod> for station_api::RateLimitPeriodDTO {
+    fn from(period: RateLimitPeriod) -> Self {
+        match period {
+            RateLimitPeriod::Hour => station_api::RateLimitPeriodDTO::Hour,
+            RateLimitPeriod::Day => station_api::RateLimitPeriodDTO::Day,
+        }
+    }
+}
+
+impl From<station_api::RateLimitPeriodDTO> for RateLimitPeriod {
+    fn from(period: station_api::RateLimitPeriodDTO) -> Self {
+        match period {
+            station_api::RateLimitPeriodDTO::Hour => RateLimitPeriod::Hour,
+            station_api::RateLimitPeriodDTO::Day => RateLimitPeriod::Day,
+        }
+    }
+}
+
+impl From<RequestCreationRateLimits> for station_api::RequestCreationRateLimitsDTO {
+    fn from(rate_limits: RequestCreationRateLimits) -> Self {
+        station_api::RequestCreationRateLimitsDTO {
+            limits: rate_limits
+                .limits
+                .into_iter()
+                .map(|limit| station_api::RequestCreationRateLimitDTO {
+                    operation_type: limit.operation_type.map(Into::into),
+                    period: limit.period.into(),
+                    max_requests: limit.max_requests,
+                })
+                .collect(),
+            override_group_id: rate_limits
+                .override_group_id
+                .map(|group_id| Uuid::from_bytes(group_id).hyphenated().to_string()),
+        }
+    }
+}
+
+impl From<station_api::RequestCreationRateLimitsDTO> for RequestCreationRateLimits {
+    fn from(rate_limits: station_api::RequestCreationRateLimitsDTO) -> Self {
+        RequestCreationRateLimits {
+            limits: rate_limits
+                .limits
+                .into_iter()
+                .map(|limit| RequestCreationRateLimit {
+                    operation_type: limit.operation_type.map(Into::into),
+                    period: limit.period.into(),
+                    max_requests: limit.max_requests,
+                })
+                .collect(),
+            override_group_id: rate_limits.override_group_id.map(|group_id| {
+                *HelperMapper::to_uuid(group_id)
+                    .expect("Invalid user group id")
+                    .as_bytes()
+            }),
+        }
+    }
+}
+
+impl From<RpcProvidersConfig> for station_api::RpcProvidersConfigDTO {
+    fn from(config: RpcProvidersConfig) -> Self {
+        station_api::RpcProvidersConfigDTO {
+            blockchain: config.blockchain.to_string(),
+            providers: config
+                .providers
+                .into_iter()
+                .map(|provider| station_api::RpcProviderDTO {
+                    name: provider.name,
+                    url: provider.url,
+                })
+                .collect(),
+            read_quorum: config.read_quorum,
+        }
+    }
+}
+
+impl From<station_api::RpcProvidersConfigDTO> for RpcProvidersConfig {
+    fn from(config: station_api::RpcProvidersConfigDTO) -> Self {
+        RpcProvidersConfig {
+            blockchain: BlockchainMapper::to_blockchain(config.blockchain)
+                .expect("Invalid blockchain"),
+            providers: config
+                .providers
+                .into_iter()
+                .map(|provider| RpcProvider {
+                    name: provider.name,
+                    url: provider.url,
+                })
+                .collect(),
+            read_quorum: config.read_quorum,
+        }
+    }
+}
+
+impl From<FinalityThreshold> for station_api::FinalityThresholdDTO {
+    fn from(threshold: FinalityThreshold) -> Self {
+        station_api::FinalityThresholdDTO {
+            blockchain: threshold.blockchain.to_string(),
+            required_confirmations: threshold.required_confirmations,
+        }
+    }
+}
+
+impl From<station_api::FinalityThresholdDTO> for FinalityThreshold {
+    fn from(threshold: station_api::FinalityThresholdDTO) -> Self {
+        FinalityThreshold {
+            blockchain: BlockchainMapper::to_blockchain(threshold.blockchain)
+                .expect("Invalid blockchain"),
+            required_confirmations: threshold.required_confirmations,
+        }
+    }
+}
+
+impl From<TransferRetryPolicy> for station_api::TransferRetryPolicyDTO {
+    fn from(policy: TransferRetryPolicy) -> Self {
+        station_api::TransferRetryPolicyDTO {
+            max_attempts: policy.max_attempts,
+            initial_backoff_secs: policy.initial_backoff_secs,
+        }
+    }
+}
+
+impl From<station_api::TransferRetryPolicyDTO> for TransferRetryPolicy {
+    fn from(policy: station_api::TransferRetryPolicyDTO) -> Self {
+        TransferRetryPolicy {
+            max_attempts: policy.max_attempts,
+            initial_backoff_secs: policy.initial_backoff_secs,
+        }
+    }
+}
+
 impl From<ManageSystemInfoOperationInput> for station_api::ManageSystemInfoOperationInput {
     fn from(input: ManageSystemInfoOperationInput) -> station_api::ManageSystemInfoOperationInput {
         station_api::ManageSystemInfoOperationInput {
             name: input.name,
             cycle_obtain_strategy: input.cycle_obtain_strategy.map(|strategy| strategy.into()),
+            request_operation_limits: input.request_operation_limits.map(|limits| limits.into()),
+            rpc_providers: input
+                .rpc_providers
+                .map(|configs| configs.into_iter().map(Into::into).collect()),
+            finality_thresholds: input
+                .finality_thresholds
+                .map(|thresholds| thresholds.into_iter().map(Into::into).collect()),
+            transfer_retry_policy: input.transfer_retry_policy.map(Into::into),
+            max_suggested_version: input.max_suggested_version.map(Into::into),
+            archive_sink: input.archive_sink.map(Into::into),
+            stable_memory_guardrail: input.stable_memory_guardrail.map(Into::into),
+            approval_reminders: input.approval_reminders.map(Into::into),
+            notification_templates: input
+                .notification_templates
+                .map(|changes| changes.into_iter().map(Into::into).collect()),
+            request_creation_rate_limits: input.request_creation_rate_limits.map(Into::into),
+            default_request_expiration_secs: input.default_request_expiration_secs,
+            cycle_thresholds: input.cycle_thresholds.map(Into::into),
         }
     }
 }
@@ -1502,6 +2123,136 @@ impl From<station_api::ManageSystemInfoOperationInput> for ManageSystemInfoOpera
         ManageSystemInfoOperationInput {
             name: input.name,
             cycle_obtain_strategy: input.cycle_obtain_strategy.map(|strategy| strategy.into()),
+            request_operation_limits: input.request_operation_limits.map(|limits| limits.into()),
+            rpc_providers: input
+                .rpc_providers
+                .map(|configs| configs.into_iter().map(Into::into).collect()),
+            finality_thresholds: input
+                .finality_thresholds
+                .map(|thresholds| thresholds.into_iter().map(Into::into).collect()),
+            transfer_retry_policy: input.transfer_retry_policy.map(Into::into),
+            max_suggested_version: input.max_suggested_version.map(Into::into),
+            archive_sink: input.archive_sink.map(Into::into),
+            stable_memory_guardrail: input.stable_memory_guardrail.map(Into::into),
+            approval_reminders: input.approval_reminders.map(Into::into),
+            notification_templates: input
+                .notification_templates
+                .map(|changes| changes.into_iter().map(Into::into).collect()),
+            request_creation_rate_limits: input.request_creation_rate_limits.map(Into::into),
+            default_request_expiration_secs: input.default_request_expiration_secs,
+            cycle_thresholds: input.cycle_thresholds.map(Into::into),
+        }
+    }
+}
+
+impl From<CycleThresholds> for station_api::CycleThresholdsDTO {
+    fn from(thresholds: CycleThresholds) -> Self {
+        station_api::CycleThresholdsDTO {
+            min_runtime_secs: thresholds.min_runtime_secs,
+            fund_runtime_secs: thresholds.fund_runtime_secs,
+            max_runtime_cycles_fund: thresholds.max_runtime_cycles_fund,
+            fallback_min_cycles: thresholds.fallback_min_cycles,
+            fallback_fund_cycles: thresholds.fallback_fund_cycles,
+        }
+    }
+}
+
+impl From<station_api::CycleThresholdsDTO> for CycleThresholds {
+    fn from(thresholds: station_api::CycleThresholdsDTO) -> Self {
+        CycleThresholds {
+            min_runtime_secs: thresholds.min_runtime_secs,
+            fund_runtime_secs: thresholds.fund_runtime_secs,
+            max_runtime_cycles_fund: thresholds.max_runtime_cycles_fund,
+            fallback_min_cycles: thresholds.fallback_min_cycles,
+            fallback_fund_cycles: thresholds.fallback_fund_cycles,
+        }
+    }
+}
+
+impl From<ApprovalReminders> for station_api::ApprovalRemindersDTO {
+    fn from(approval_reminders: ApprovalReminders) -> Self {
+        station_api::ApprovalRemindersDTO {
+            control_panel_id: approval_reminders.control_panel_id,
+            pending_vote_lead_time_secs: approval_reminders.pending_vote_lead_time_secs,
+        }
+    }
+}
+
+impl From<station_api::ApprovalRemindersDTO> for ApprovalReminders {
+    fn from(approval_reminders: station_api::ApprovalRemindersDTO) -> Self {
+        ApprovalReminders {
+            control_panel_id: approval_reminders.control_panel_id,
+            pending_vote_lead_time_secs: approval_reminders.pending_vote_lead_time_secs,
+        }
+    }
+}
+
+impl From<StableMemoryGuardrail> for station_api::StableMemoryGuardrailDTO {
+    fn from(guardrail: StableMemoryGuardrail) -> Self {
+        station_api::StableMemoryGuardrailDTO {
+            high_water_mark_percent: guardrail.high_water_mark_percent,
+        }
+    }
+}
+
+impl From<station_api::StableMemoryGuardrailDTO> for StableMemoryGuardrail {
+    fn from(guardrail: station_api::StableMemoryGuardrailDTO) -> Self {
+        StableMemoryGuardrail {
+            high_water_mark_percent: guardrail.high_water_mark_percent,
+        }
+    }
+}
+
+impl From<VersionPin> for station_api::VersionPinInput {
+    fn from(pin: VersionPin) -> station_api::VersionPinInput {
+        match pin {
+            VersionPin::Pin(version) => station_api::VersionPinInput::Pin(version),
+            VersionPin::Unpin => station_api::VersionPinInput::Unpin,
+        }
+    }
+}
+
+impl From<station_api::VersionPinInput> for VersionPin {
+    fn from(pin: station_api::VersionPinInput) -> VersionPin {
+        match pin {
+            station_api::VersionPinInput::Pin(version) => VersionPin::Pin(version),
+            station_api::VersionPinInput::Unpin => VersionPin::Unpin,
+        }
+    }
+}
+
+impl From<ArchiveSink> for station_api::ArchiveSinkDTO {
+    fn from(sink: ArchiveSink) -> station_api::ArchiveSinkDTO {
+        station_api::ArchiveSinkDTO {
+            canister_id: sink.canister_id,
+            retention_days: sink.retention_days,
+        }
+    }
+}
+
+impl From<station_api::ArchiveSinkDTO> for ArchiveSink {
+    fn from(sink: station_api::ArchiveSinkDTO) -> ArchiveSink {
+        ArchiveSink {
+            canister_id: sink.canister_id,
+            retention_days: sink.retention_days,
+        }
+    }
+}
+
+impl From<ArchiveSinkChange> for station_api::ArchiveSinkInput {
+    fn from(change: ArchiveSinkChange) -> station_api::ArchiveSinkInput {
+        match change {
+            ArchiveSinkChange::Set(sink) => station_api::ArchiveSinkInput::Set(sink.into()),
+            ArchiveSinkChange::Remove => station_api::ArchiveSinkInput::Remove,
+        }
+    }
+}
+
+impl From<station_api::ArchiveSinkInput> for ArchiveSinkChange {
+    fn from(change: station_api::ArchiveSinkInput) -> ArchiveSinkChange {
+        match change {
+            station_api::ArchiveSinkInput::Set(sink) => ArchiveSinkChange::Set(sink.into()),
+            station_api::ArchiveSinkInput::Remove => ArchiveSinkChange::Remove,
         }
     }
 }
@@ -1531,6 +2282,49 @@ impl From<RequestOperation> for RequestOperationDTO {
 
                 RequestOperationDTO::Transfer(Box::new(operation.to_dto(account)))
             }
+            RequestOperation::Approve(operation) => {
+                let account = AccountRepository::default()
+                    .get(&Account::key(operation.input.from_account_id));
+
+                RequestOperationDTO::Approve(Box::new(operation.to_dto(account)))
+            }
+            RequestOperation::SwapTokens(operation) => {
+                let from_account = AccountRepository::default()
+                    .get(&Account::key(operation.input.from_account_id));
+                let to_account =
+                    AccountRepository::default().get(&Account::key(operation.input.to_account_id));
+
+                RequestOperationDTO::SwapTokens(Box::new(
+                    operation.to_dto(from_account, to_account),
+                ))
+            }
+            RequestOperation::AddScheduledTransfer(operation) => {
+                let account = AccountRepository::default()
+                    .get(&Account::key(operation.input.transfer.from_account_id));
+
+                RequestOperationDTO::AddScheduledTransfer(Box::new(operation.to_dto(account)))
+            }
+            RequestOperation::SweepAccount(operation) => {
+                let account = AccountRepository::default()
+                    .get(&Account::key(operation.input.from_account_id));
+
+                RequestOperationDTO::SweepAccount(Box::new(operation.to_dto(account)))
+            }
+            RequestOperation::SplitTransfer(operation) => {
+                let account = AccountRepository::default()
+                    .get(&Account::key(operation.input.from_account_id));
+
+                RequestOperationDTO::SplitTransfer(Box::new(operation.to_dto(account)))
+            }
+            RequestOperation::TransferNft(operation) => {
+                let account = AccountRepository::default()
+                    .get(&Account::key(operation.input.from_account_id));
+
+                RequestOperationDTO::TransferNft(Box::new(operation.to_dto(account)))
+            }
+            RequestOperation::DeriveSubaccount(operation) => {
+                RequestOperationDTO::DeriveSubaccount(Box::new(operation.into()))
+            }
             RequestOperation::AddAccount(operation) => {
                 let account = operation
                     .account_id
@@ -1541,6 +2335,11 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::EditAccount(operation) => {
                 RequestOperationDTO::EditAccount(Box::new(operation.into()))
             }
+            RequestOperation::SetAutoApprovalForTrustedDestinations(operation) => {
+                RequestOperationDTO::SetAutoApprovalForTrustedDestinations(Box::new(
+                    operation.into(),
+                ))
+            }
             RequestOperation::AddAddressBookEntry(operation) => {
                 let address_book_entry = operation.address_book_entry_id.and_then(|id| {
                     AddressBookRepository::default().get(&AddressBookEntry::key(id))
@@ -1556,6 +2355,19 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::RemoveAddressBookEntry(operation) => {
                 RequestOperationDTO::RemoveAddressBookEntry(Box::new(operation.into()))
             }
+            RequestOperation::ManageAddressBookLabels(operation) => {
+                RequestOperationDTO::ManageAddressBookLabels(Box::new(operation.into()))
+            }
+            RequestOperation::CreateCapabilityGrant(operation) => {
+                let grant = operation
+                    .grant_id
+                    .and_then(|id| CAPABILITY_GRANT_REPOSITORY.get(&id));
+
+                RequestOperationDTO::CreateCapabilityGrant(Box::new(operation.to_dto(grant)))
+            }
+            RequestOperation::RevokeCapabilityGrant(operation) => {
+                RequestOperationDTO::RevokeCapabilityGrant(Box::new(operation.into()))
+            }
             RequestOperation::AddUser(operation) => {
                 let user = operation
                     .user_id
@@ -1573,6 +2385,13 @@ impl From<RequestOperation> for RequestOperationDTO {
 
                 RequestOperationDTO::AddUserGroup(Box::new(operation.to_dto(user_group)))
             }
+            RequestOperation::AddTeam(operation) => {
+                let user_group = operation
+                    .user_group_id
+                    .and_then(|id| USER_GROUP_REPOSITORY.get(&id));
+
+                RequestOperationDTO::AddTeam(Box::new(operation.to_dto(user_group)))
+            }
             RequestOperation::EditUserGroup(operation) => {
                 RequestOperationDTO::EditUserGroup(Box::new(operation.into()))
             }
@@ -1603,6 +2422,22 @@ impl From<RequestOperation> for RequestOperationDTO {
             RequestOperation::EditPermission(operation) => {
                 RequestOperationDTO::EditPermission(Box::new(operation.into()))
             }
+            RequestOperation::ImportAccessPolicies(operation) => {
+                RequestOperationDTO::ImportAccessPolicies(Box::new(operation.into()))
+            }
+            RequestOperation::AddAsset(operation) => {
+                let asset = operation
+                    .asset_id
+                    .and_then(|id| REGISTERED_ASSET_REPOSITORY.get(&id));
+
+                RequestOperationDTO::AddAsset(Box::new(operation.to_dto(asset)))
+            }
+            RequestOperation::EditAsset(operation) => {
+                RequestOperationDTO::EditAsset(Box::new(operation.into()))
+            }
+            RequestOperation::RemoveAsset(operation) => {
+                RequestOperationDTO::RemoveAsset(Box::new(operation.into()))
+            }
             RequestOperation::AddRequestPolicy(operation) => {
                 RequestOperationDTO::AddRequestPolicy(Box::new(operation.into()))
             }
@@ -1637,6 +2472,16 @@ impl RequestOperation {
             RequestOperation::EditPermission(_) => {
                 vec![Resource::Permission(PermissionResourceAction::Update)]
             }
+            // the groups created by the import only exist to be referenced by the imported permissions,
+            // so the whole import is governed as a permission edit
+            RequestOperation::ImportAccessPolicies(_) => {
+                vec![Resource::Permission(PermissionResourceAction::Update)]
+            }
+            // a team is only given access through the permissions it is granted, so setting one up is
+            // governed as a permission edit as well
+            RequestOperation::AddTeam(_) => {
+                vec![Resource::Permission(PermissionResourceAction::Update)]
+            }
 
             RequestOperation::Transfer(transfer) => {
                 vec![
@@ -1646,8 +2491,81 @@ impl RequestOperation {
                     Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
                 ]
             }
+            // sweeping moves the whole balance of the account, so it is governed as a transfer
+            RequestOperation::SweepAccount(sweep) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        sweep.input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::SplitTransfer(split) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        split.input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            // the tokens of an NFT account are its funds, so moving one is governed as a transfer
+            RequestOperation::TransferNft(transfer_nft) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        transfer_nft.input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            // an allowance lets the spender move the funds of the account, so it is governed as a transfer
+            RequestOperation::Approve(approve) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        approve.input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            // a swap sells the asset of the account, so it is governed as a transfer
+            RequestOperation::SwapTokens(swap) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        swap.input.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
+            // every execution of the standing order is a transfer from the account
+            RequestOperation::AddScheduledTransfer(scheduled_transfer) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                        scheduled_transfer.input.transfer.from_account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                ]
+            }
 
-            RequestOperation::EditAccount(EditAccountOperation { input }) => {
+            RequestOperation::EditAccount(EditAccountOperation { input, .. }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                        input.account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                ]
+            }
+            // the subaccounts are part of the account configuration, so they are governed as an edit
+            RequestOperation::DeriveSubaccount(DeriveSubaccountOperation { input, .. }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                        input.account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                ]
+            }
+            // the trusted destinations are part of the account configuration, so they are governed as an edit
+            RequestOperation::SetAutoApprovalForTrustedDestinations(
+                SetAutoApprovalForTrustedDestinationsOperation { input },
+            ) => {
                 vec![
                     Resource::Account(AccountResourceAction::Update(ResourceId::Id(
                         input.account_id,
@@ -1673,13 +2591,44 @@ impl RequestOperation {
                     Resource::AddressBook(ResourceAction::Delete(ResourceId::Any)),
                 ]
             }
-            RequestOperation::EditUser(EditUserOperation { input }) => {
+            // the labels apply to all the entries, so changing them is governed as an edit of any entry
+            RequestOperation::ManageAddressBookLabels(_) => {
+                vec![Resource::AddressBook(ResourceAction::Update(
+                    ResourceId::Any,
+                ))]
+            }
+            // who can read the account is part of its configuration, so the grants are governed as an edit
+            RequestOperation::CreateCapabilityGrant(CreateCapabilityGrantOperation {
+                input,
+                ..
+            }) => {
+                vec![
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                        input.account_id,
+                    ))),
+                    Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::RevokeCapabilityGrant(RevokeCapabilityGrantOperation { input }) => {
+                match CAPABILITY_GRANT_REPOSITORY.get(&input.grant_id) {
+                    Some(grant) => vec![
+                        Resource::Account(AccountResourceAction::Update(ResourceId::Id(
+                            grant.account_id,
+                        ))),
+                        Resource::Account(AccountResourceAction::Update(ResourceId::Any)),
+                    ],
+                    None => vec![Resource::Account(AccountResourceAction::Update(
+                        ResourceId::Any,
+                    ))],
+                }
+            }
+            RequestOperation::EditUser(EditUserOperation { input, .. }) => {
                 vec![
                     Resource::User(UserResourceAction::Update(ResourceId::Id(input.user_id))),
                     Resource::User(UserResourceAction::Update(ResourceId::Any)),
                 ]
             }
-            RequestOperation::EditUserGroup(EditUserGroupOperation { input }) => {
+            RequestOperation::EditUserGroup(EditUserGroupOperation { input, .. }) => {
                 vec![
                     Resource::UserGroup(ResourceAction::Update(ResourceId::Id(
                         input.user_group_id,
@@ -1726,16 +2675,29 @@ impl RequestOperation {
             }
             RequestOperation::FundExternalCanister(FundExternalCanisterOperation {
                 canister_id,
-                ..
+                kind,
             }) => {
-                vec![
+                let mut resources = vec![
                     Resource::ExternalCanister(ExternalCanisterResourceAction::Fund(
                         ExternalCanisterId::Any,
                     )),
                     Resource::ExternalCanister(ExternalCanisterResourceAction::Fund(
                         ExternalCanisterId::Canister(*canister_id),
                     )),
-                ]
+                ];
+
+                // the cycles drawn from an account are governed like its transfers
+                let FundExternalCanisterOperationKind::Send(input) = kind;
+                if let Some(from_account_id) = input.from_account_id {
+                    resources.extend([
+                        Resource::Account(AccountResourceAction::Transfer(ResourceId::Id(
+                            from_account_id,
+                        ))),
+                        Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+                    ]);
+                }
+
+                resources
             }
             RequestOperation::CreateExternalCanister(CreateExternalCanisterOperation {
                 ..
@@ -1776,7 +2738,7 @@ impl RequestOperation {
                     )),
                 ]
             }
-            RequestOperation::EditRequestPolicy(EditRequestPolicyOperation { input }) => {
+            RequestOperation::EditRequestPolicy(EditRequestPolicyOperation { input, .. }) => {
                 vec![
                     Resource::RequestPolicy(ResourceAction::Update(ResourceId::Id(
                         input.policy_id,
@@ -1795,6 +2757,21 @@ impl RequestOperation {
             RequestOperation::ManageSystemInfo(_) => {
                 vec![Resource::System(SystemResourceAction::ManageSystemInfo)]
             }
+            RequestOperation::AddAsset(_) => {
+                vec![Resource::Asset(ResourceAction::Create)]
+            }
+            RequestOperation::EditAsset(EditAssetOperation { input, .. }) => {
+                vec![
+                    Resource::Asset(ResourceAction::Update(ResourceId::Id(input.asset_id))),
+                    Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+                ]
+            }
+            RequestOperation::RemoveAsset(RemoveAssetOperation { input }) => {
+                vec![
+                    Resource::Asset(ResourceAction::Delete(ResourceId::Id(input.asset_id))),
+                    Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
+                ]
+            }
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::mappers::HelperMapper;
 use crate::models::{ListRequestsOperationType, RequestOperation, RequestOperationType};
 use station_api::{ListRequestsOperationTypeDTO, RequestOperationTypeDTO};
+use uuid::Uuid;
 
 impl From<station_api::ListRequestsOperationTypeDTO> for ListRequestsOperationType {
     fn from(value: station_api::ListRequestsOperationTypeDTO) -> Self {
@@ -78,6 +79,214 @@ impl From<station_api::ListRequestsOperationTypeDTO> for ListRequestsOperationTy
             station_api::ListRequestsOperationTypeDTO::SetDisasterRecovery => {
                 ListRequestsOperationType::SetDisasterRecovery
             }
+            station_api::ListRequestsOperationTypeDTO::Approve(from_account_id) => {
+                ListRequestsOperationType::Approve(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::SetAutoApprovalForTrustedDestinations(
+                account_id,
+            ) => ListRequestsOperationType::SetAutoApprovalForTrustedDestinations(account_id.map(
+                |id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                },
+            )),
+            station_api::ListRequestsOperationTypeDTO::TransferNft(from_account_id) => {
+                ListRequestsOperationType::TransferNft(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::DeriveSubaccount(account_id) => {
+                ListRequestsOperationType::DeriveSubaccount(account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::ImportAccessPolicies => {
+                ListRequestsOperationType::ImportAccessPolicies
+            }
+            station_api::ListRequestsOperationTypeDTO::AddTeam => {
+                ListRequestsOperationType::AddTeam
+            }
+            station_api::ListRequestsOperationTypeDTO::SweepAccount(from_account_id) => {
+                ListRequestsOperationType::SweepAccount(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::SplitTransfer(from_account_id) => {
+                ListRequestsOperationType::SplitTransfer(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::ManageAddressBookLabels => {
+                ListRequestsOperationType::ManageAddressBookLabels
+            }
+            station_api::ListRequestsOperationTypeDTO::CreateCapabilityGrant(account_id) => {
+                ListRequestsOperationType::CreateCapabilityGrant(account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::RevokeCapabilityGrant => {
+                ListRequestsOperationType::RevokeCapabilityGrant
+            }
+            station_api::ListRequestsOperationTypeDTO::AddAsset => {
+                ListRequestsOperationType::AddAsset
+            }
+            station_api::ListRequestsOperationTypeDTO::EditAsset => {
+                ListRequestsOperationType::EditAsset
+            }
+            station_api::ListRequestsOperationTypeDTO::RemoveAsset => {
+                ListRequestsOperationType::RemoveAsset
+            }
+            station_api::ListRequestsOperationTypeDTO::SwapTokens(from_account_id) => {
+                ListRequestsOperationType::SwapTokens(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+            station_api::ListRequestsOperationTypeDTO::AddScheduledTransfer(from_account_id) => {
+                ListRequestsOperationType::AddScheduledTransfer(from_account_id.map(|id| {
+                    *HelperMapper::to_uuid(id)
+                        .expect("Invalid account id")
+                        .as_bytes()
+                }))
+            }
+        }
+    }
+}
+
+impl From<ListRequestsOperationType> for ListRequestsOperationTypeDTO {
+    fn from(value: ListRequestsOperationType) -> Self {
+        match value {
+            ListRequestsOperationType::Transfer(account_id) => {
+                ListRequestsOperationTypeDTO::Transfer(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::AddAccount => ListRequestsOperationTypeDTO::AddAccount,
+            ListRequestsOperationType::EditAccount => ListRequestsOperationTypeDTO::EditAccount,
+            ListRequestsOperationType::AddAddressBookEntry => {
+                ListRequestsOperationTypeDTO::AddAddressBookEntry
+            }
+            ListRequestsOperationType::EditAddressBookEntry => {
+                ListRequestsOperationTypeDTO::EditAddressBookEntry
+            }
+            ListRequestsOperationType::RemoveAddressBookEntry => {
+                ListRequestsOperationTypeDTO::RemoveAddressBookEntry
+            }
+            ListRequestsOperationType::AddUser => ListRequestsOperationTypeDTO::AddUser,
+            ListRequestsOperationType::EditUser => ListRequestsOperationTypeDTO::EditUser,
+            ListRequestsOperationType::AddUserGroup => ListRequestsOperationTypeDTO::AddUserGroup,
+            ListRequestsOperationType::EditUserGroup => ListRequestsOperationTypeDTO::EditUserGroup,
+            ListRequestsOperationType::RemoveUserGroup => {
+                ListRequestsOperationTypeDTO::RemoveUserGroup
+            }
+            ListRequestsOperationType::SystemUpgrade => ListRequestsOperationTypeDTO::SystemUpgrade,
+            ListRequestsOperationType::CreateExternalCanister => {
+                ListRequestsOperationTypeDTO::CreateExternalCanister
+            }
+            ListRequestsOperationType::ChangeExternalCanister(canister_id) => {
+                ListRequestsOperationTypeDTO::ChangeExternalCanister(canister_id)
+            }
+            ListRequestsOperationType::CallExternalCanister(canister_id) => {
+                ListRequestsOperationTypeDTO::CallExternalCanister(canister_id)
+            }
+            ListRequestsOperationType::ConfigureExternalCanister(canister_id) => {
+                ListRequestsOperationTypeDTO::ConfigureExternalCanister(canister_id)
+            }
+            ListRequestsOperationType::FundExternalCanister(canister_id) => {
+                ListRequestsOperationTypeDTO::FundExternalCanister(canister_id)
+            }
+            ListRequestsOperationType::EditPermission => {
+                ListRequestsOperationTypeDTO::EditPermission
+            }
+            ListRequestsOperationType::AddRequestPolicy => {
+                ListRequestsOperationTypeDTO::AddRequestPolicy
+            }
+            ListRequestsOperationType::EditRequestPolicy => {
+                ListRequestsOperationTypeDTO::EditRequestPolicy
+            }
+            ListRequestsOperationType::RemoveRequestPolicy => {
+                ListRequestsOperationTypeDTO::RemoveRequestPolicy
+            }
+            ListRequestsOperationType::ManageSystemInfo => {
+                ListRequestsOperationTypeDTO::ManageSystemInfo
+            }
+            ListRequestsOperationType::SetDisasterRecovery => {
+                ListRequestsOperationTypeDTO::SetDisasterRecovery
+            }
+            ListRequestsOperationType::Approve(account_id) => {
+                ListRequestsOperationTypeDTO::Approve(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::SetAutoApprovalForTrustedDestinations(account_id) => {
+                ListRequestsOperationTypeDTO::SetAutoApprovalForTrustedDestinations(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::TransferNft(account_id) => {
+                ListRequestsOperationTypeDTO::TransferNft(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::DeriveSubaccount(account_id) => {
+                ListRequestsOperationTypeDTO::DeriveSubaccount(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::ImportAccessPolicies => {
+                ListRequestsOperationTypeDTO::ImportAccessPolicies
+            }
+            ListRequestsOperationType::AddTeam => ListRequestsOperationTypeDTO::AddTeam,
+            ListRequestsOperationType::SweepAccount(account_id) => {
+                ListRequestsOperationTypeDTO::SweepAccount(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::SplitTransfer(account_id) => {
+                ListRequestsOperationTypeDTO::SplitTransfer(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::ManageAddressBookLabels => {
+                ListRequestsOperationTypeDTO::ManageAddressBookLabels
+            }
+            ListRequestsOperationType::CreateCapabilityGrant(account_id) => {
+                ListRequestsOperationTypeDTO::CreateCapabilityGrant(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::RevokeCapabilityGrant => {
+                ListRequestsOperationTypeDTO::RevokeCapabilityGrant
+            }
+            ListRequestsOperationType::AddAsset => ListRequestsOperationTypeDTO::AddAsset,
+            ListRequestsOperationType::EditAsset => ListRequestsOperationTypeDTO::EditAsset,
+            ListRequestsOperationType::RemoveAsset => ListRequestsOperationTypeDTO::RemoveAsset,
+            ListRequestsOperationType::SwapTokens(account_id) => {
+                ListRequestsOperationTypeDTO::SwapTokens(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
+            ListRequestsOperationType::AddScheduledTransfer(account_id) => {
+                ListRequestsOperationTypeDTO::AddScheduledTransfer(
+                    account_id.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                )
+            }
         }
     }
 }
@@ -128,6 +337,34 @@ impl From<RequestOperationTypeDTO> for RequestOperationType {
             RequestOperationTypeDTO::ConfigureExternalCanister => {
                 RequestOperationType::ConfigureExternalCanister
             }
+            RequestOperationTypeDTO::Approve => RequestOperationType::Approve,
+            RequestOperationTypeDTO::SetAutoApprovalForTrustedDestinations => {
+                RequestOperationType::SetAutoApprovalForTrustedDestinations
+            }
+            RequestOperationTypeDTO::TransferNft => RequestOperationType::TransferNft,
+            RequestOperationTypeDTO::DeriveSubaccount => RequestOperationType::DeriveSubaccount,
+            RequestOperationTypeDTO::ImportAccessPolicies => {
+                RequestOperationType::ImportAccessPolicies
+            }
+            RequestOperationTypeDTO::AddAsset => RequestOperationType::AddAsset,
+            RequestOperationTypeDTO::EditAsset => RequestOperationType::EditAsset,
+            RequestOperationTypeDTO::RemoveAsset => RequestOperationType::RemoveAsset,
+            RequestOperationTypeDTO::SwapTokens => RequestOperationType::SwapTokens,
+            RequestOperationTypeDTO::AddScheduledTransfer => {
+                RequestOperationType::AddScheduledTransfer
+            }
+            RequestOperationTypeDTO::AddTeam => RequestOperationType::AddTeam,
+            RequestOperationTypeDTO::SweepAccount => RequestOperationType::SweepAccount,
+            RequestOperationTypeDTO::SplitTransfer => RequestOperationType::SplitTransfer,
+            RequestOperationTypeDTO::ManageAddressBookLabels => {
+                RequestOperationType::ManageAddressBookLabels
+            }
+            RequestOperationTypeDTO::CreateCapabilityGrant => {
+                RequestOperationType::CreateCapabilityGrant
+            }
+            RequestOperationTypeDTO::RevokeCapabilityGrant => {
+                RequestOperationType::RevokeCapabilityGrant
+            }
         }
     }
 }
@@ -178,6 +415,34 @@ impl From<RequestOperationType> for RequestOperationTypeDTO {
             RequestOperationType::ConfigureExternalCanister => {
                 RequestOperationTypeDTO::ConfigureExternalCanister
             }
+            RequestOperationType::Approve => RequestOperationTypeDTO::Approve,
+            RequestOperationType::SetAutoApprovalForTrustedDestinations => {
+                RequestOperationTypeDTO::SetAutoApprovalForTrustedDestinations
+            }
+            RequestOperationType::TransferNft => RequestOperationTypeDTO::TransferNft,
+            RequestOperationType::DeriveSubaccount => RequestOperationTypeDTO::DeriveSubaccount,
+            RequestOperationType::ImportAccessPolicies => {
+                RequestOperationTypeDTO::ImportAccessPolicies
+            }
+            RequestOperationType::AddAsset => RequestOperationTypeDTO::AddAsset,
+            RequestOperationType::EditAsset => RequestOperationTypeDTO::EditAsset,
+            RequestOperationType::RemoveAsset => RequestOperationTypeDTO::RemoveAsset,
+            RequestOperationType::SwapTokens => RequestOperationTypeDTO::SwapTokens,
+            RequestOperationType::AddScheduledTransfer => {
+                RequestOperationTypeDTO::AddScheduledTransfer
+            }
+            RequestOperationType::AddTeam => RequestOperationTypeDTO::AddTeam,
+            RequestOperationType::SweepAccount => RequestOperationTypeDTO::SweepAccount,
+            RequestOperationType::SplitTransfer => RequestOperationTypeDTO::SplitTransfer,
+            RequestOperationType::ManageAddressBookLabels => {
+                RequestOperationTypeDTO::ManageAddressBookLabels
+            }
+            RequestOperationType::CreateCapabilityGrant => {
+                RequestOperationTypeDTO::CreateCapabilityGrant
+            }
+            RequestOperationType::RevokeCapabilityGrant => {
+                RequestOperationTypeDTO::RevokeCapabilityGrant
+            }
         }
     }
 }
@@ -216,6 +481,30 @@ impl From<RequestOperation> for RequestOperationType {
             RequestOperation::RemoveRequestPolicy(_) => RequestOperationType::RemoveRequestPolicy,
             RequestOperation::ManageSystemInfo(_) => RequestOperationType::ManageSystemInfo,
             RequestOperation::SetDisasterRecovery(_) => RequestOperationType::SetDisasterRecovery,
+            RequestOperation::Approve(_) => RequestOperationType::Approve,
+            RequestOperation::SetAutoApprovalForTrustedDestinations(_) => {
+                RequestOperationType::SetAutoApprovalForTrustedDestinations
+            }
+            RequestOperation::TransferNft(_) => RequestOperationType::TransferNft,
+            RequestOperation::DeriveSubaccount(_) => RequestOperationType::DeriveSubaccount,
+            RequestOperation::ImportAccessPolicies(_) => RequestOperationType::ImportAccessPolicies,
+            RequestOperation::AddAsset(_) => RequestOperationType::AddAsset,
+            RequestOperation::EditAsset(_) => RequestOperationType::EditAsset,
+            RequestOperation::RemoveAsset(_) => RequestOperationType::RemoveAsset,
+            RequestOperation::SwapTokens(_) => RequestOperationType::SwapTokens,
+            RequestOperation::AddScheduledTransfer(_) => RequestOperationType::AddScheduledTransfer,
+            RequestOperation::AddTeam(_) => RequestOperationType::AddTeam,
+            RequestOperation::SweepAccount(_) => RequestOperationType::SweepAccount,
+            RequestOperation::SplitTransfer(_) => RequestOperationType::SplitTransfer,
+            RequestOperation::ManageAddressBookLabels(_) => {
+                RequestOperationType::ManageAddressBookLabels
+            }
+            RequestOperation::CreateCapabilityGrant(_) => {
+                RequestOperationType::CreateCapabilityGrant
+            }
+            RequestOperation::RevokeCapabilityGrant(_) => {
+                RequestOperationType::RevokeCapabilityGrant
+            }
         }
     }
 }
@@ -304,6 +593,121 @@ impl RequestOperation {
                 RequestOperation::ManageSystemInfo(_),
                 ListRequestsOperationTypeDTO::ManageSystemInfo,
             ) => true,
+            (
+                RequestOperation::Approve(approve_operation),
+                ListRequestsOperationTypeDTO::Approve(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(approve_operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::SetAutoApprovalForTrustedDestinations(operation),
+                ListRequestsOperationTypeDTO::SetAutoApprovalForTrustedDestinations(account_id),
+            ) => {
+                if let Some(account_id) = account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::TransferNft(operation),
+                ListRequestsOperationTypeDTO::TransferNft(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::DeriveSubaccount(operation),
+                ListRequestsOperationTypeDTO::DeriveSubaccount(account_id),
+            ) => {
+                if let Some(account_id) = account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::ImportAccessPolicies(_),
+                ListRequestsOperationTypeDTO::ImportAccessPolicies,
+            ) => true,
+            (RequestOperation::AddTeam(_), ListRequestsOperationTypeDTO::AddTeam) => true,
+            (RequestOperation::AddAsset(_), ListRequestsOperationTypeDTO::AddAsset) => true,
+            (RequestOperation::EditAsset(_), ListRequestsOperationTypeDTO::EditAsset) => true,
+            (RequestOperation::RemoveAsset(_), ListRequestsOperationTypeDTO::RemoveAsset) => true,
+            (
+                RequestOperation::SwapTokens(operation),
+                ListRequestsOperationTypeDTO::SwapTokens(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::AddScheduledTransfer(operation),
+                ListRequestsOperationTypeDTO::AddScheduledTransfer(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.transfer.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::SweepAccount(operation),
+                ListRequestsOperationTypeDTO::SweepAccount(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::SplitTransfer(operation),
+                ListRequestsOperationTypeDTO::SplitTransfer(from_account_id),
+            ) => {
+                if let Some(account_id) = from_account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.from_account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::ManageAddressBookLabels(_),
+                ListRequestsOperationTypeDTO::ManageAddressBookLabels,
+            ) => true,
+            (
+                RequestOperation::CreateCapabilityGrant(operation),
+                ListRequestsOperationTypeDTO::CreateCapabilityGrant(account_id),
+            ) => {
+                if let Some(account_id) = account_id {
+                    HelperMapper::to_uuid(account_id.clone()).map(|uuid| *uuid.as_bytes())
+                        == Ok(operation.input.account_id)
+                } else {
+                    true
+                }
+            }
+            (
+                RequestOperation::RevokeCapabilityGrant(_),
+                ListRequestsOperationTypeDTO::RevokeCapabilityGrant,
+            ) => true,
             _ => false,
         }
     }
@@ -1,17 +1,20 @@
 use super::HelperMapper;
 use crate::models::{
-    request_policy_rule::RequestPolicyRule,
+    request_policy_rule::{AmountRange, RequestPolicyRule, VelocityLimit},
     request_specifier::{RequestSpecifier, ResourceSpecifier, UserSpecifier},
     resource::{
         AccountResourceAction, ExternalCanisterResourceAction, PermissionResourceAction, Resource,
         ResourceAction, ResourceId, ResourceIds, SystemResourceAction, UserResourceAction,
     },
     EvaluatedRequestPolicyRule, EvaluationStatus, Percentage, RequestEvaluationResult,
-    RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyRuleResult,
+    RequestPolicy, RequestPolicyCallerPrivileges, RequestPolicyExpirationInput,
+    RequestPolicyRuleResult,
 };
+use orbit_essentials::utils::timestamp_to_rfc3339;
 use station_api::{
-    EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO, QuorumDTO, QuorumPercentageDTO,
-    RequestEvaluationResultDTO, RequestPolicyRuleDTO, RequestPolicyRuleResultDTO, UserSpecifierDTO,
+    AmountRangeDTO, EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO, QuorumDTO,
+    QuorumPercentageDTO, RequestEvaluationResultDTO, RequestPolicyRuleDTO,
+    RequestPolicyRuleResultDTO, UserSpecifierDTO, VelocityLimitDTO,
 };
 use uuid::Uuid;
 
@@ -35,6 +38,29 @@ impl From<RequestPolicyRule> for RequestPolicyRuleDTO {
                 RequestPolicyRuleDTO::AllowListedByMetadata(metadata.into())
             }
             RequestPolicyRule::AllowListed => RequestPolicyRuleDTO::AllowListed,
+            RequestPolicyRule::AllowListedByLabel(label) => {
+                RequestPolicyRuleDTO::AllowListedByLabel(label)
+            }
+            RequestPolicyRule::TrustedDestination => RequestPolicyRuleDTO::TrustedDestination,
+            RequestPolicyRule::RecentAuthentication(max_reauthentication_age_mins) => {
+                RequestPolicyRuleDTO::RecentAuthentication(max_reauthentication_age_mins)
+            }
+            RequestPolicyRule::VelocityLimit(limit) => {
+                RequestPolicyRuleDTO::VelocityLimit(VelocityLimitDTO {
+                    max_amount: limit.max_amount,
+                    window_secs: limit.window_secs,
+                })
+            }
+            RequestPolicyRule::AmountRange(range) => {
+                RequestPolicyRuleDTO::AmountRange(AmountRangeDTO {
+                    min_amount: range.min_amount,
+                    max_amount: range.max_amount,
+                })
+            }
+            RequestPolicyRule::DestinationAge(min_age_days) => {
+                RequestPolicyRuleDTO::DestinationAge(min_age_days)
+            }
+            RequestPolicyRule::Veto(specifier) => RequestPolicyRuleDTO::Veto(specifier.into()),
             RequestPolicyRule::Or(policy_rules) => {
                 RequestPolicyRuleDTO::AnyOf(policy_rules.into_iter().map(Into::into).collect())
             }
@@ -63,6 +89,29 @@ impl From<RequestPolicyRuleDTO> for RequestPolicyRule {
                 RequestPolicyRule::AllowListedByMetadata(metadata.into())
             }
             RequestPolicyRuleDTO::AllowListed => RequestPolicyRule::AllowListed,
+            RequestPolicyRuleDTO::AllowListedByLabel(label) => {
+                RequestPolicyRule::AllowListedByLabel(label)
+            }
+            RequestPolicyRuleDTO::TrustedDestination => RequestPolicyRule::TrustedDestination,
+            RequestPolicyRuleDTO::RecentAuthentication(max_reauthentication_age_mins) => {
+                RequestPolicyRule::RecentAuthentication(max_reauthentication_age_mins)
+            }
+            RequestPolicyRuleDTO::VelocityLimit(limit) => {
+                RequestPolicyRule::VelocityLimit(VelocityLimit {
+                    max_amount: limit.max_amount,
+                    window_secs: limit.window_secs,
+                })
+            }
+            RequestPolicyRuleDTO::AmountRange(range) => {
+                RequestPolicyRule::AmountRange(AmountRange {
+                    min_amount: range.min_amount,
+                    max_amount: range.max_amount,
+                })
+            }
+            RequestPolicyRuleDTO::DestinationAge(min_age_days) => {
+                RequestPolicyRule::DestinationAge(min_age_days)
+            }
+            RequestPolicyRuleDTO::Veto(specifier) => RequestPolicyRule::Veto(specifier.into()),
             RequestPolicyRuleDTO::AnyOf(policy_rules) => {
                 RequestPolicyRule::Or(policy_rules.into_iter().map(Into::into).collect())
             }
@@ -140,6 +189,61 @@ impl From<EvaluatedRequestPolicyRule> for EvaluatedRequestPolicyRuleDTO {
                 }
             }
             EvaluatedRequestPolicyRule::AllowListed => EvaluatedRequestPolicyRuleDTO::AllowListed,
+            EvaluatedRequestPolicyRule::AllowListedByLabel {
+                label,
+                address_book_entry_id,
+            } => EvaluatedRequestPolicyRuleDTO::AllowListedByLabel {
+                label,
+                address_book_entry_id: address_book_entry_id
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            },
+            EvaluatedRequestPolicyRule::TrustedDestination {
+                max_amount,
+                period_usage,
+            } => EvaluatedRequestPolicyRuleDTO::TrustedDestination {
+                max_amount,
+                period_usage,
+            },
+            EvaluatedRequestPolicyRule::RecentAuthentication {
+                max_reauthentication_age_mins,
+                stale_approvers,
+            } => EvaluatedRequestPolicyRuleDTO::RecentAuthentication {
+                max_reauthentication_age_mins,
+                stale_approvers: stale_approvers
+                    .into_iter()
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                    .collect(),
+            },
+            EvaluatedRequestPolicyRule::VelocityLimit {
+                max_amount,
+                window_secs,
+                spent,
+            } => EvaluatedRequestPolicyRuleDTO::VelocityLimit {
+                max_amount,
+                window_secs,
+                spent,
+            },
+            EvaluatedRequestPolicyRule::AmountRange { range, amount } => {
+                EvaluatedRequestPolicyRuleDTO::AmountRange {
+                    min_amount: range.min_amount,
+                    max_amount: range.max_amount,
+                    amount,
+                }
+            }
+            EvaluatedRequestPolicyRule::DestinationAge {
+                min_age_days,
+                first_used_at,
+            } => EvaluatedRequestPolicyRuleDTO::DestinationAge {
+                min_age_days,
+                first_used_at: first_used_at
+                    .map(|first_used_at| timestamp_to_rfc3339(&first_used_at)),
+            },
+            EvaluatedRequestPolicyRule::Veto { vetoed_by } => EvaluatedRequestPolicyRuleDTO::Veto {
+                vetoed_by: vetoed_by
+                    .into_iter()
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                    .collect(),
+            },
             EvaluatedRequestPolicyRule::Or(policy_rules) => EvaluatedRequestPolicyRuleDTO::AnyOf(
                 policy_rules.into_iter().map(Into::into).collect(),
             ),
@@ -217,6 +321,33 @@ impl RequestPolicy {
             id: Uuid::from_bytes(self.id).hyphenated().to_string(),
             specifier: self.specifier.into(),
             rule: self.rule.into(),
+            expiration_period_secs: self.expiration_period_secs,
+        }
+    }
+}
+
+impl From<station_api::RequestPolicyExpirationInput> for RequestPolicyExpirationInput {
+    fn from(input: station_api::RequestPolicyExpirationInput) -> Self {
+        match input {
+            station_api::RequestPolicyExpirationInput::Remove => {
+                RequestPolicyExpirationInput::Remove
+            }
+            station_api::RequestPolicyExpirationInput::Set(secs) => {
+                RequestPolicyExpirationInput::Set(secs)
+            }
+        }
+    }
+}
+
+impl From<RequestPolicyExpirationInput> for station_api::RequestPolicyExpirationInput {
+    fn from(input: RequestPolicyExpirationInput) -> Self {
+        match input {
+            RequestPolicyExpirationInput::Remove => {
+                station_api::RequestPolicyExpirationInput::Remove
+            }
+            RequestPolicyExpirationInput::Set(secs) => {
+                station_api::RequestPolicyExpirationInput::Set(secs)
+            }
         }
     }
 }
@@ -282,6 +413,13 @@ impl From<RequestSpecifier> for station_api::RequestSpecifierDTO {
             RequestSpecifier::ManageSystemInfo => {
                 station_api::RequestSpecifierDTO::ManageSystemInfo
             }
+            RequestSpecifier::AddAsset => station_api::RequestSpecifierDTO::AddAsset,
+            RequestSpecifier::EditAsset(asset) => {
+                station_api::RequestSpecifierDTO::EditAsset(asset.into())
+            }
+            RequestSpecifier::RemoveAsset(asset) => {
+                station_api::RequestSpecifierDTO::RemoveAsset(asset.into())
+            }
         }
     }
 }
@@ -347,6 +485,13 @@ impl From<station_api::RequestSpecifierDTO> for RequestSpecifier {
             station_api::RequestSpecifierDTO::ManageSystemInfo => {
                 RequestSpecifier::ManageSystemInfo
             }
+            station_api::RequestSpecifierDTO::AddAsset => RequestSpecifier::AddAsset,
+            station_api::RequestSpecifierDTO::EditAsset(asset) => {
+                RequestSpecifier::EditAsset(asset.into())
+            }
+            station_api::RequestSpecifierDTO::RemoveAsset(asset) => {
+                RequestSpecifier::RemoveAsset(asset.into())
+            }
         }
     }
 }
@@ -494,6 +639,25 @@ impl RequestSpecifier {
                     .map(|id| Resource::UserGroup(ResourceAction::Delete(ResourceId::Id(*id))))
                     .collect::<_>(),
             },
+            RequestSpecifier::AddAsset => vec![Resource::Asset(ResourceAction::Create)],
+            RequestSpecifier::EditAsset(resources) => match resources {
+                ResourceIds::Any => {
+                    vec![Resource::Asset(ResourceAction::Update(ResourceId::Any))]
+                }
+                ResourceIds::Ids(ids) => ids
+                    .iter()
+                    .map(|id| Resource::Asset(ResourceAction::Update(ResourceId::Id(*id))))
+                    .collect::<_>(),
+            },
+            RequestSpecifier::RemoveAsset(resources) => match resources {
+                ResourceIds::Any => {
+                    vec![Resource::Asset(ResourceAction::Delete(ResourceId::Any))]
+                }
+                ResourceIds::Ids(ids) => ids
+                    .iter()
+                    .map(|id| Resource::Asset(ResourceAction::Delete(ResourceId::Id(*id))))
+                    .collect::<_>(),
+            },
         }
     }
 }
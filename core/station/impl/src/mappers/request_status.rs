@@ -19,6 +19,7 @@ impl From<RequestStatus> for RequestStatusDTO {
                 scheduled_at: timestamp_to_rfc3339(&scheduled_at),
             },
             RequestStatus::Cancelled { reason } => RequestStatusDTO::Cancelled { reason },
+            RequestStatus::Validating => RequestStatusDTO::Validating,
         }
     }
 }
@@ -34,6 +35,7 @@ impl From<RequestStatus> for RequestStatusCodeDTO {
             RequestStatus::Processing { .. } => RequestStatusCodeDTO::Processing,
             RequestStatus::Scheduled { .. } => RequestStatusCodeDTO::Scheduled,
             RequestStatus::Cancelled { .. } => RequestStatusCodeDTO::Cancelled,
+            RequestStatus::Validating => RequestStatusCodeDTO::Validating,
         }
     }
 }
@@ -55,6 +57,7 @@ impl From<RequestStatusDTO> for RequestStatus {
                 scheduled_at: rfc3339_to_timestamp(&scheduled_at),
             },
             RequestStatusDTO::Cancelled { reason } => RequestStatus::Cancelled { reason },
+            RequestStatusDTO::Validating => RequestStatus::Validating,
         }
     }
 }
@@ -70,6 +73,23 @@ impl From<RequestStatusCodeDTO> for RequestStatusCode {
             RequestStatusCodeDTO::Processing => RequestStatusCode::Processing,
             RequestStatusCodeDTO::Scheduled => RequestStatusCode::Scheduled,
             RequestStatusCodeDTO::Cancelled => RequestStatusCode::Cancelled,
+            RequestStatusCodeDTO::Validating => RequestStatusCode::Validating,
+        }
+    }
+}
+
+impl From<RequestStatusCode> for RequestStatusCodeDTO {
+    fn from(status: RequestStatusCode) -> Self {
+        match status {
+            RequestStatusCode::Created => RequestStatusCodeDTO::Created,
+            RequestStatusCode::Approved => RequestStatusCodeDTO::Approved,
+            RequestStatusCode::Rejected => RequestStatusCodeDTO::Rejected,
+            RequestStatusCode::Completed => RequestStatusCodeDTO::Completed,
+            RequestStatusCode::Failed => RequestStatusCodeDTO::Failed,
+            RequestStatusCode::Processing => RequestStatusCodeDTO::Processing,
+            RequestStatusCode::Scheduled => RequestStatusCodeDTO::Scheduled,
+            RequestStatusCode::Cancelled => RequestStatusCodeDTO::Cancelled,
+            RequestStatusCode::Validating => RequestStatusCodeDTO::Validating,
         }
     }
 }
@@ -88,6 +108,7 @@ impl RequestStatusMapper {
             RequestStatusCodeDTO::Processing => "processing",
             RequestStatusCodeDTO::Scheduled => "scheduled",
             RequestStatusCodeDTO::Cancelled => "cancelled",
+            RequestStatusCodeDTO::Validating => "validating",
         }
     }
 }
@@ -0,0 +1,143 @@
+use super::HelperMapper;
+use crate::{
+    errors::MapperError,
+    models::{RequestView, RequestViewQuery, RequestViewSortBy, RequestViewSortDirection},
+};
+use orbit_essentials::{types::UUID, utils::timestamp_to_rfc3339};
+use station_api::{ListRequestsSortBy, RequestViewDTO, RequestViewQueryDTO, SortDirection};
+use uuid::Uuid;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+#[derive(Debug)]
+pub struct RequestViewMapper;
+
+impl RequestViewMapper {
+    pub fn to_query(input: RequestViewQueryDTO) -> Result<RequestViewQuery, MapperError> {
+        Ok(RequestViewQuery {
+            statuses: input
+                .statuses
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            operation_types: input
+                .operation_types
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            requester_ids: Self::to_ids(input.requester_ids)?,
+            approver_ids: Self::to_ids(input.approver_ids)?,
+            min_age_ns: input
+                .min_age_secs
+                .map(|secs| secs.saturating_mul(NANOS_PER_SEC)),
+            max_age_ns: input
+                .max_age_secs
+                .map(|secs| secs.saturating_mul(NANOS_PER_SEC)),
+            only_approvable: input.only_approvable,
+            sort_by: input.sort_by.map(Into::into),
+        })
+    }
+
+    fn to_ids(ids: Option<Vec<String>>) -> Result<Vec<UUID>, MapperError> {
+        ids.unwrap_or_default()
+            .into_iter()
+            .map(|id| HelperMapper::to_uuid(id).map(|uuid| *uuid.as_bytes()))
+            .collect()
+    }
+}
+
+fn to_optional_ids(ids: Vec<UUID>) -> Option<Vec<String>> {
+    if ids.is_empty() {
+        return None;
+    }
+
+    Some(
+        ids.into_iter()
+            .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+            .collect(),
+    )
+}
+
+impl From<RequestViewQuery> for RequestViewQueryDTO {
+    fn from(query: RequestViewQuery) -> Self {
+        RequestViewQueryDTO {
+            statuses: (!query.statuses.is_empty())
+                .then(|| query.statuses.into_iter().map(Into::into).collect()),
+            operation_types: (!query.operation_types.is_empty())
+                .then(|| query.operation_types.into_iter().map(Into::into).collect()),
+            requester_ids: to_optional_ids(query.requester_ids),
+            approver_ids: to_optional_ids(query.approver_ids),
+            min_age_secs: query.min_age_ns.map(|ns| ns / NANOS_PER_SEC),
+            max_age_secs: query.max_age_ns.map(|ns| ns / NANOS_PER_SEC),
+            only_approvable: query.only_approvable,
+            sort_by: query.sort_by.map(Into::into),
+        }
+    }
+}
+
+impl From<RequestView> for RequestViewDTO {
+    fn from(view: RequestView) -> Self {
+        RequestViewDTO {
+            id: Uuid::from_bytes(view.id).hyphenated().to_string(),
+            name: view.name,
+            owner_id: Uuid::from_bytes(view.owner_id).hyphenated().to_string(),
+            shared_with_group_id: view
+                .shared_with_group_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            query: view.query.into(),
+            last_modification_timestamp: timestamp_to_rfc3339(&view.last_modification_timestamp),
+        }
+    }
+}
+
+impl From<SortDirection> for RequestViewSortDirection {
+    fn from(direction: SortDirection) -> Self {
+        match direction {
+            SortDirection::Asc => RequestViewSortDirection::Asc,
+            SortDirection::Desc => RequestViewSortDirection::Desc,
+        }
+    }
+}
+
+impl From<RequestViewSortDirection> for SortDirection {
+    fn from(direction: RequestViewSortDirection) -> Self {
+        match direction {
+            RequestViewSortDirection::Asc => SortDirection::Asc,
+            RequestViewSortDirection::Desc => SortDirection::Desc,
+        }
+    }
+}
+
+impl From<ListRequestsSortBy> for RequestViewSortBy {
+    fn from(sort_by: ListRequestsSortBy) -> Self {
+        match sort_by {
+            ListRequestsSortBy::CreatedAt(direction) => {
+                RequestViewSortBy::CreatedAt(direction.into())
+            }
+            ListRequestsSortBy::ExpirationDt(direction) => {
+                RequestViewSortBy::ExpirationDt(direction.into())
+            }
+            ListRequestsSortBy::LastModificationDt(direction) => {
+                RequestViewSortBy::LastModificationDt(direction.into())
+            }
+        }
+    }
+}
+
+impl From<RequestViewSortBy> for ListRequestsSortBy {
+    fn from(sort_by: RequestViewSortBy) -> Self {
+        match sort_by {
+            RequestViewSortBy::CreatedAt(direction) => {
+                ListRequestsSortBy::CreatedAt(direction.into())
+            }
+            RequestViewSortBy::ExpirationDt(direction) => {
+                ListRequestsSortBy::ExpirationDt(direction.into())
+            }
+            RequestViewSortBy::LastModificationDt(direction) => {
+                ListRequestsSortBy::LastModificationDt(direction.into())
+            }
+        }
+    }
+}
@@ -28,6 +28,7 @@ impl From<station_api::ResourceDTO> for Resource {
             station_api::ResourceDTO::Notification(action) => Resource::Notification(action.into()),
             station_api::ResourceDTO::Request(action) => Resource::Request(action.into()),
             station_api::ResourceDTO::System(action) => Resource::System(action.into()),
+            station_api::ResourceDTO::Asset(action) => Resource::Asset(action.into()),
         }
     }
 }
@@ -49,6 +50,7 @@ impl From<Resource> for station_api::ResourceDTO {
             Resource::Notification(action) => station_api::ResourceDTO::Notification(action.into()),
             Resource::Request(action) => station_api::ResourceDTO::Request(action.into()),
             Resource::System(action) => station_api::ResourceDTO::System(action.into()),
+            Resource::Asset(action) => station_api::ResourceDTO::Asset(action.into()),
         }
     }
 }
@@ -126,6 +128,7 @@ impl From<station_api::UserResourceActionDTO> for UserResourceAction {
             station_api::UserResourceActionDTO::Create => UserResourceAction::Create,
             station_api::UserResourceActionDTO::Read(id) => UserResourceAction::Read(id.into()),
             station_api::UserResourceActionDTO::Update(id) => UserResourceAction::Update(id.into()),
+            station_api::UserResourceActionDTO::ViewAs(id) => UserResourceAction::ViewAs(id.into()),
         }
     }
 }
@@ -137,6 +140,7 @@ impl From<UserResourceAction> for station_api::UserResourceActionDTO {
             UserResourceAction::Create => station_api::UserResourceActionDTO::Create,
             UserResourceAction::Read(id) => station_api::UserResourceActionDTO::Read(id.into()),
             UserResourceAction::Update(id) => station_api::UserResourceActionDTO::Update(id.into()),
+            UserResourceAction::ViewAs(id) => station_api::UserResourceActionDTO::ViewAs(id.into()),
         }
     }
 }
@@ -188,6 +192,9 @@ impl From<station_api::SystemResourceActionDTO> for SystemResourceAction {
                 SystemResourceAction::ManageSystemInfo
             }
             station_api::SystemResourceActionDTO::Upgrade => SystemResourceAction::Upgrade,
+            station_api::SystemResourceActionDTO::AuditOutflows => {
+                SystemResourceAction::AuditOutflows
+            }
         }
     }
 }
@@ -203,6 +210,9 @@ impl From<SystemResourceAction> for station_api::SystemResourceActionDTO {
                 station_api::SystemResourceActionDTO::ManageSystemInfo
             }
             SystemResourceAction::Upgrade => station_api::SystemResourceActionDTO::Upgrade,
+            SystemResourceAction::AuditOutflows => {
+                station_api::SystemResourceActionDTO::AuditOutflows
+            }
         }
     }
 }
@@ -0,0 +1,48 @@
+use crate::models::{ScheduledTransfer, ScheduledTransferStatus};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{ScheduledTransferDTO, ScheduledTransferStatusDTO};
+use uuid::Uuid;
+
+impl From<ScheduledTransferStatus> for ScheduledTransferStatusDTO {
+    fn from(status: ScheduledTransferStatus) -> Self {
+        match status {
+            ScheduledTransferStatus::Active => ScheduledTransferStatusDTO::Active,
+            ScheduledTransferStatus::Completed { completed_at } => {
+                ScheduledTransferStatusDTO::Completed {
+                    completed_at: timestamp_to_rfc3339(&completed_at),
+                }
+            }
+            ScheduledTransferStatus::Cancelled {
+                cancelled_at,
+                cancelled_by,
+            } => ScheduledTransferStatusDTO::Cancelled {
+                cancelled_at: timestamp_to_rfc3339(&cancelled_at),
+                cancelled_by: Uuid::from_bytes(cancelled_by).hyphenated().to_string(),
+            },
+        }
+    }
+}
+
+impl From<ScheduledTransfer> for ScheduledTransferDTO {
+    fn from(scheduled_transfer: ScheduledTransfer) -> Self {
+        ScheduledTransferDTO {
+            id: Uuid::from_bytes(scheduled_transfer.id)
+                .hyphenated()
+                .to_string(),
+            request_id: Uuid::from_bytes(scheduled_transfer.request_id)
+                .hyphenated()
+                .to_string(),
+            transfer: scheduled_transfer.transfer.into(),
+            interval_secs: scheduled_transfer.interval_ns / 1_000_000_000,
+            next_execution_at: timestamp_to_rfc3339(&scheduled_transfer.next_execution_at),
+            end_at: scheduled_transfer.end_at.as_ref().map(timestamp_to_rfc3339),
+            executions: scheduled_transfer.executions,
+            last_transfer_id: scheduled_transfer
+                .last_transfer_id
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+            last_error: scheduled_transfer.last_error,
+            status: scheduled_transfer.status.into(),
+            created_at: timestamp_to_rfc3339(&scheduled_transfer.created_timestamp),
+        }
+    }
+}
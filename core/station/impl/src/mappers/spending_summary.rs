@@ -0,0 +1,52 @@
+use crate::{
+    models::{SpendingGroupBy, SpendingPeriod},
+    services::{SpendingSummary, SpendingSummaryEntry},
+};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{
+    GetSpendingSummaryResponse, SpendingGroupByDTO, SpendingPeriodDTO, SpendingSummaryEntryDTO,
+};
+
+impl From<SpendingPeriodDTO> for SpendingPeriod {
+    fn from(period: SpendingPeriodDTO) -> Self {
+        match period {
+            SpendingPeriodDTO::Day => SpendingPeriod::Day,
+            SpendingPeriodDTO::Week => SpendingPeriod::Week,
+            SpendingPeriodDTO::Month => SpendingPeriod::Month,
+        }
+    }
+}
+
+impl From<SpendingGroupByDTO> for SpendingGroupBy {
+    fn from(group_by: SpendingGroupByDTO) -> Self {
+        match group_by {
+            SpendingGroupByDTO::Account => SpendingGroupBy::Account,
+            SpendingGroupByDTO::Destination => SpendingGroupBy::Destination,
+            SpendingGroupByDTO::Tag => SpendingGroupBy::Tag,
+            SpendingGroupByDTO::Asset => SpendingGroupBy::Asset,
+        }
+    }
+}
+
+impl From<SpendingSummaryEntry> for SpendingSummaryEntryDTO {
+    fn from(entry: SpendingSummaryEntry) -> Self {
+        SpendingSummaryEntryDTO {
+            period_start: timestamp_to_rfc3339(&entry.period_start),
+            group: entry.group,
+            symbol: entry.symbol,
+            total: entry.total,
+            count: entry.count,
+        }
+    }
+}
+
+impl From<SpendingSummary> for GetSpendingSummaryResponse {
+    fn from(summary: SpendingSummary) -> Self {
+        GetSpendingSummaryResponse {
+            entries: summary.entries.into_iter().map(Into::into).collect(),
+            aggregated_until: summary
+                .aggregated_until
+                .map(|aggregated_until| timestamp_to_rfc3339(&aggregated_until)),
+        }
+    }
+}
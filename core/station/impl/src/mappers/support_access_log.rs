@@ -0,0 +1,17 @@
+use crate::models::SupportAccessLogEntry;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::SupportAccessLogEntryDTO;
+use uuid::Uuid;
+
+impl From<SupportAccessLogEntry> for SupportAccessLogEntryDTO {
+    fn from(entry: SupportAccessLogEntry) -> Self {
+        SupportAccessLogEntryDTO {
+            id: Uuid::from_bytes(entry.id).hyphenated().to_string(),
+            viewer_id: Uuid::from_bytes(entry.viewer_id).hyphenated().to_string(),
+            viewed_user_id: Uuid::from_bytes(entry.viewed_user_id)
+                .hyphenated()
+                .to_string(),
+            viewed_at: timestamp_to_rfc3339(&entry.viewed_at),
+        }
+    }
+}
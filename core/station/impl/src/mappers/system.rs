@@ -1,4 +1,8 @@
-use crate::{models::system::SystemInfo, repositories::USER_GROUP_REPOSITORY};
+use crate::{
+    core::{stable_memory_used_bytes, STABLE_MEMORY_CAPACITY},
+    models::system::SystemInfo,
+    repositories::USER_GROUP_REPOSITORY,
+};
 use orbit_essentials::{
     repository::Repository,
     utils::{raw_rand_successful, timestamp_to_rfc3339},
@@ -23,6 +27,71 @@ impl SystemInfo {
                 }
             }),
             cycle_obtain_strategy: (*self.get_cycle_obtain_strategy()).into(),
+            request_operation_limits: self.get_request_operation_limits().clone().into(),
+            rpc_providers: self
+                .get_all_rpc_providers()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            finality_thresholds: self
+                .get_finality_thresholds()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            transfer_retry_policy: self.get_transfer_retry_policy().clone().into(),
+            max_suggested_version: self.get_max_suggested_version().map(str::to_string),
+            paused_blockchains: self
+                .get_paused_blockchains()
+                .iter()
+                .map(|paused| station_api::PausedBlockchainDTO {
+                    blockchain: paused.blockchain.to_string(),
+                    paused_at: timestamp_to_rfc3339(&paused.paused_at),
+                    reason: paused.reason.to_owned(),
+                })
+                .collect(),
+            archive_sink: self.get_archive_sink().cloned().map(Into::into),
+            archive_head: self
+                .get_archive_head()
+                .map(|head| station_api::ArchiveHeadDTO {
+                    sequence: head.sequence,
+                    hash: hex::encode(&head.hash),
+                    archived_at: timestamp_to_rfc3339(&head.archived_at),
+                }),
+            last_self_check: self.get_last_self_check().map(|report| {
+                station_api::SelfCheckReportDTO {
+                    version: report.version.to_owned(),
+                    checked_at: timestamp_to_rfc3339(&report.checked_at),
+                    passed: report.passed(),
+                    checks: report
+                        .checks
+                        .iter()
+                        .map(|check| station_api::SelfCheckResultDTO {
+                            name: check.name.to_owned(),
+                            passed: check.passed,
+                            details: check.details.to_owned(),
+                        })
+                        .collect(),
+                }
+            }),
+            default_locale: self.get_default_locale().map(str::to_string),
+            stable_memory_guardrail: self.get_stable_memory_guardrail().clone().into(),
+            stable_memory_usage: {
+                let guardrail = self.get_stable_memory_guardrail();
+                let used_bytes = stable_memory_used_bytes();
+
+                station_api::StableMemoryUsageDTO {
+                    used_bytes,
+                    capacity_bytes: STABLE_MEMORY_CAPACITY,
+                    high_water_mark_bytes: guardrail.high_water_mark_bytes(),
+                    new_requests_refused: guardrail.is_reached(used_bytes),
+                }
+            },
+            approval_reminders: self.get_approval_reminders().clone().into(),
+            request_creation_rate_limits: self.get_request_creation_rate_limits().clone().into(),
+            default_request_expiration_secs: self.get_default_request_expiration_secs(),
+            cycle_thresholds: self.get_cycle_thresholds().clone().into(),
         }
     }
 }
@@ -1,6 +1,18 @@
-use crate::models::Transfer;
-use orbit_essentials::utils::timestamp_to_rfc3339;
-use station_api::{NetworkDTO, TransferDTO, TransferListItemDTO};
+use crate::{
+    factories::blockchains::{BlockchainTransactionFeeOption, BlockchainTransactionFeeTier},
+    models::{
+        Account, PendingOutflowAudit, PendingOutflowOutcome, Transfer, TransferCategory,
+        TransferMemo,
+    },
+    repositories::ACCOUNT_REPOSITORY,
+    services::EXCHANGE_RATE_SERVICE,
+};
+use orbit_essentials::{repository::Repository, utils::timestamp_to_rfc3339};
+use station_api::{
+    MetadataDTO, NetworkDTO, PendingOutflowAuditDTO, PendingOutflowOutcomeDTO, TransferAttemptDTO,
+    TransferCategoryDTO, TransferDTO, TransferFeeDTO, TransferFeeTierDTO, TransferListItemDTO,
+    TransferMemoDTO,
+};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
@@ -8,6 +20,19 @@ pub struct TransferMapper {}
 
 impl TransferMapper {
     pub fn to_dto(transfer: Transfer) -> TransferDTO {
+        // the amount is valued at the latest rates, not at the rates of the time of the transfer
+        let fiat_values = ACCOUNT_REPOSITORY
+            .get(&Account::key(transfer.from_account))
+            .and_then(|account| account.for_asset(transfer.asset_id.as_ref()).ok())
+            .map(|account| {
+                EXCHANGE_RATE_SERVICE.fiat_values(
+                    &account.symbol,
+                    &transfer.amount,
+                    account.decimals,
+                )
+            })
+            .unwrap_or_default();
+
         TransferDTO {
             id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
             request_id: Uuid::from_bytes(transfer.request_id)
@@ -25,6 +50,21 @@ impl TransferMapper {
                 .to_string(),
             to: transfer.to_address,
             status: transfer.status.into(),
+            memo: transfer.memo.map(Into::into),
+            category: transfer.category,
+            asset_id: transfer
+                .asset_id
+                .map(|asset_id| Uuid::from_bytes(asset_id).hyphenated().to_string()),
+            fiat_values: fiat_values.into_iter().map(Into::into).collect(),
+            failed_attempts: transfer
+                .failed_attempts
+                .into_iter()
+                .map(|attempt| TransferAttemptDTO {
+                    attempted_at: timestamp_to_rfc3339(&attempt.attempted_at),
+                    error: attempt.error,
+                })
+                .collect(),
+            next_attempt_at: transfer.next_attempt_at.as_ref().map(timestamp_to_rfc3339),
         }
     }
 
@@ -51,3 +91,79 @@ impl Transfer {
         TransferMapper::to_list_item_dto(self.clone())
     }
 }
+
+impl From<TransferMemo> for TransferMemoDTO {
+    fn from(memo: TransferMemo) -> Self {
+        match memo {
+            TransferMemo::Number(number) => TransferMemoDTO::Number(number),
+            TransferMemo::Blob(blob) => TransferMemoDTO::Blob(blob),
+        }
+    }
+}
+
+impl From<TransferMemoDTO> for TransferMemo {
+    fn from(memo: TransferMemoDTO) -> Self {
+        match memo {
+            TransferMemoDTO::Number(number) => TransferMemo::Number(number),
+            TransferMemoDTO::Blob(blob) => TransferMemo::Blob(blob),
+        }
+    }
+}
+
+impl From<TransferCategory> for TransferCategoryDTO {
+    fn from(category: TransferCategory) -> Self {
+        TransferCategoryDTO {
+            name: category.name,
+            description: category.description,
+            last_modification_timestamp: timestamp_to_rfc3339(
+                &category.last_modification_timestamp,
+            ),
+        }
+    }
+}
+
+impl From<PendingOutflowOutcome> for PendingOutflowOutcomeDTO {
+    fn from(outcome: PendingOutflowOutcome) -> Self {
+        match outcome {
+            PendingOutflowOutcome::OnChain { details } => PendingOutflowOutcomeDTO::OnChain {
+                details: details
+                    .into_iter()
+                    .map(|(key, value)| MetadataDTO { key, value })
+                    .collect(),
+            },
+            PendingOutflowOutcome::NotOnChain => PendingOutflowOutcomeDTO::NotOnChain,
+            PendingOutflowOutcome::Unknown { reason } => {
+                PendingOutflowOutcomeDTO::Unknown { reason }
+            }
+        }
+    }
+}
+
+impl From<PendingOutflowAudit> for PendingOutflowAuditDTO {
+    fn from(audit: PendingOutflowAudit) -> Self {
+        PendingOutflowAuditDTO {
+            transfer: audit.transfer.to_dto(),
+            outcome: audit.outcome.into(),
+        }
+    }
+}
+
+impl From<BlockchainTransactionFeeTier> for TransferFeeTierDTO {
+    fn from(tier: BlockchainTransactionFeeTier) -> Self {
+        match tier {
+            BlockchainTransactionFeeTier::Slow => TransferFeeTierDTO::Slow,
+            BlockchainTransactionFeeTier::Standard => TransferFeeTierDTO::Standard,
+            BlockchainTransactionFeeTier::Fast => TransferFeeTierDTO::Fast,
+        }
+    }
+}
+
+impl From<BlockchainTransactionFeeOption> for TransferFeeDTO {
+    fn from(option: BlockchainTransactionFeeOption) -> Self {
+        TransferFeeDTO {
+            tier: option.tier.into(),
+            fee: candid::Nat(option.fee.fee),
+            metadata: option.fee.metadata.into_vec_dto(),
+        }
+    }
+}
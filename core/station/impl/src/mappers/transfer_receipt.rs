@@ -0,0 +1,41 @@
+use crate::models::{TransferReceipt, TransferReceiptApproval};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{TransferReceiptApprovalDTO, TransferReceiptDTO};
+use uuid::Uuid;
+
+impl From<TransferReceiptApproval> for TransferReceiptApprovalDTO {
+    fn from(approval: TransferReceiptApproval) -> Self {
+        TransferReceiptApprovalDTO {
+            approver_id: Uuid::from_bytes(approval.approver_id)
+                .hyphenated()
+                .to_string(),
+            approved_at: timestamp_to_rfc3339(&approval.approved_at),
+        }
+    }
+}
+
+impl From<TransferReceipt> for TransferReceiptDTO {
+    fn from(receipt: TransferReceipt) -> Self {
+        TransferReceiptDTO {
+            transfer_id: Uuid::from_bytes(receipt.transfer_id)
+                .hyphenated()
+                .to_string(),
+            request_id: Uuid::from_bytes(receipt.request_id)
+                .hyphenated()
+                .to_string(),
+            from_account_id: Uuid::from_bytes(receipt.from_account)
+                .hyphenated()
+                .to_string(),
+            blockchain: receipt.blockchain,
+            symbol: receipt.symbol,
+            decimals: receipt.decimals,
+            to_address: receipt.to_address,
+            amount: receipt.amount,
+            fee: receipt.fee,
+            block_index: receipt.block_index,
+            transaction_hash: receipt.transaction_hash,
+            approvals: receipt.approvals.into_iter().map(Into::into).collect(),
+            completed_at: timestamp_to_rfc3339(&receipt.completed_at),
+        }
+    }
+}
@@ -9,6 +9,15 @@ impl From<TransferStatus> for TransferStatusDTO {
                 started_at: timestamp_to_rfc3339(&started_at),
             },
             TransferStatus::Created => TransferStatusDTO::Created,
+            TransferStatus::Confirming {
+                submitted_at,
+                confirmations,
+                required_confirmations,
+            } => TransferStatusDTO::Confirming {
+                submitted_at: timestamp_to_rfc3339(&submitted_at),
+                confirmations,
+                required_confirmations,
+            },
             TransferStatus::Completed {
                 signature,
                 hash,
@@ -30,8 +39,25 @@ impl From<TransferStatus> for TransferStatusTypeDTO {
         match status {
             TransferStatus::Processing { .. } => TransferStatusTypeDTO::Processing,
             TransferStatus::Created => TransferStatusTypeDTO::Created,
+            TransferStatus::Confirming { .. } => TransferStatusTypeDTO::Confirming,
             TransferStatus::Completed { .. } => TransferStatusTypeDTO::Completed,
             TransferStatus::Failed { .. } => TransferStatusTypeDTO::Failed,
         }
     }
 }
+
+#[derive(Debug)]
+pub struct TransferStatusMapper;
+
+impl TransferStatusMapper {
+    /// The name of the status type, as stored in the transfer status indexes.
+    pub fn from_status_type_dto(status: &TransferStatusTypeDTO) -> &str {
+        match status {
+            TransferStatusTypeDTO::Created => "created",
+            TransferStatusTypeDTO::Processing => "processing",
+            TransferStatusTypeDTO::Confirming => "confirming",
+            TransferStatusTypeDTO::Completed => "completed",
+            TransferStatusTypeDTO::Failed => "failed",
+        }
+    }
+}
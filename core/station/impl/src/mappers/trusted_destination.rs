@@ -0,0 +1,42 @@
+use crate::models::{TrustedDestination, TrustedDestinationPeriod};
+use station_api::{TrustedDestinationDTO, TrustedDestinationPeriodDTO};
+
+impl From<TrustedDestinationPeriod> for TrustedDestinationPeriodDTO {
+    fn from(period: TrustedDestinationPeriod) -> Self {
+        match period {
+            TrustedDestinationPeriod::Day => TrustedDestinationPeriodDTO::Day,
+            TrustedDestinationPeriod::Week => TrustedDestinationPeriodDTO::Week,
+            TrustedDestinationPeriod::Month => TrustedDestinationPeriodDTO::Month,
+        }
+    }
+}
+
+impl From<TrustedDestinationPeriodDTO> for TrustedDestinationPeriod {
+    fn from(period: TrustedDestinationPeriodDTO) -> Self {
+        match period {
+            TrustedDestinationPeriodDTO::Day => TrustedDestinationPeriod::Day,
+            TrustedDestinationPeriodDTO::Week => TrustedDestinationPeriod::Week,
+            TrustedDestinationPeriodDTO::Month => TrustedDestinationPeriod::Month,
+        }
+    }
+}
+
+impl From<TrustedDestination> for TrustedDestinationDTO {
+    fn from(destination: TrustedDestination) -> Self {
+        TrustedDestinationDTO {
+            address: destination.address,
+            max_amount: destination.max_amount,
+            period: destination.period.into(),
+        }
+    }
+}
+
+impl From<TrustedDestinationDTO> for TrustedDestination {
+    fn from(destination: TrustedDestinationDTO) -> Self {
+        TrustedDestination {
+            address: destination.address,
+            max_amount: destination.max_amount,
+            period: destination.period.into(),
+        }
+    }
+}
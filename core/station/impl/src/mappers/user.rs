@@ -4,6 +4,7 @@ use crate::{
     errors::UserError,
     models::{
         AddUserOperationInput, DisplayUser, EditUserOperationInput, User, UserCallerPrivileges,
+        UserOnboarding,
     },
     repositories::USER_GROUP_REPOSITORY,
 };
@@ -12,7 +13,7 @@ use orbit_essentials::{
     types::UUID,
     utils::{rfc3339_to_timestamp, timestamp_to_rfc3339},
 };
-use station_api::{BasicUserDTO, DisplayUserDTO, UserDTO};
+use station_api::{BasicUserDTO, DisplayUserDTO, NotificationTypeInput, UserDTO};
 use uuid::Uuid;
 
 #[derive(Default, Clone, Debug)]
@@ -27,8 +28,27 @@ impl UserMapper {
             name: input.name,
             status: input.status,
             last_modification_timestamp: next_time(),
+            calendar_feed_token_hash: None,
+            onboarding: UserOnboarding::default(),
+            locale: None,
+            approve_only_identities: input.approve_only_identities,
+            muted_notification_types: vec![],
+            reauthentication: None,
         }
     }
+
+    /// Maps the string representation of a notification type muted by a user back to its input.
+    pub fn to_notification_type_input(notification_type: &str) -> Option<NotificationTypeInput> {
+        [
+            NotificationTypeInput::SystemMessage,
+            NotificationTypeInput::RequestCreated,
+            NotificationTypeInput::ExternalCanisterMonitoring,
+            NotificationTypeInput::RequestCommented,
+            NotificationTypeInput::PendingVoteReminder,
+        ]
+        .into_iter()
+        .find(|input| input.to_string() == notification_type)
+    }
 }
 
 impl From<User> for UserDTO {
@@ -45,6 +65,15 @@ impl From<User> for UserDTO {
                 .map(Into::into)
                 .collect(),
             last_modification_timestamp: timestamp_to_rfc3339(&user.last_modification_timestamp),
+            locale: user.locale,
+            approve_only_identities: user.approve_only_identities,
+            muted_notification_types: user
+                .muted_notification_types
+                .iter()
+                .filter_map(|notification_type| {
+                    UserMapper::to_notification_type_input(notification_type)
+                })
+                .collect(),
         }
     }
 }
@@ -87,6 +116,16 @@ impl From<UserDTO> for User {
             last_modification_timestamp: rfc3339_to_timestamp(
                 user.last_modification_timestamp.as_str(),
             ),
+            calendar_feed_token_hash: None,
+            onboarding: UserOnboarding::default(),
+            locale: user.locale,
+            approve_only_identities: user.approve_only_identities,
+            muted_notification_types: user
+                .muted_notification_types
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            reauthentication: None,
         }
     }
 }
@@ -95,6 +134,13 @@ impl User {
     pub fn update_with(&mut self, input: EditUserOperationInput) -> Result<(), UserError> {
         if let Some(new_identities) = &input.identities {
             self.identities = new_identities.to_owned();
+            // the identities that are removed from the user are no longer approve-only
+            self.approve_only_identities
+                .retain(|identity| new_identities.contains(identity));
+        }
+
+        if let Some(approve_only_identities) = input.approve_only_identities {
+            self.approve_only_identities = approve_only_identities;
         }
 
         if let Some(new_groups) = input.groups {
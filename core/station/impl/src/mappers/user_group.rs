@@ -1,8 +1,8 @@
 use super::HelperMapper;
 use crate::models::{
-    AddUserGroupOperation, AddUserGroupOperationInput, EditUserGroupOperation,
-    EditUserGroupOperationInput, RemoveUserGroupOperation, RemoveUserGroupOperationInput,
-    UserGroup, UserGroupCallerPrivileges,
+    AddTeamOperation, AddTeamOperationInput, AddUserGroupOperation, AddUserGroupOperationInput,
+    EditUserGroupOperation, EditUserGroupOperationInput, RemoveUserGroupOperation,
+    RemoveUserGroupOperationInput, UserGroup, UserGroupCallerPrivileges,
 };
 use uuid::Uuid;
 
@@ -18,6 +18,34 @@ impl From<AddUserGroupOperationInput> for station_api::AddUserGroupOperationInpu
     }
 }
 
+impl From<station_api::AddTeamOperationInput> for AddTeamOperationInput {
+    fn from(input: station_api::AddTeamOperationInput) -> Self {
+        Self {
+            name: input.name,
+            member_ids: input
+                .member_ids
+                .into_iter()
+                .map(|id| *HelperMapper::to_uuid(id).expect("Invalid UUID").as_bytes())
+                .collect(),
+            resources: input.resources.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<AddTeamOperationInput> for station_api::AddTeamOperationInput {
+    fn from(input: AddTeamOperationInput) -> Self {
+        Self {
+            name: input.name,
+            member_ids: input
+                .member_ids
+                .into_iter()
+                .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                .collect(),
+            resources: input.resources.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 impl From<station_api::EditUserGroupOperationInput> for EditUserGroupOperationInput {
     fn from(input: station_api::EditUserGroupOperationInput) -> Self {
         Self {
@@ -25,6 +53,18 @@ impl From<station_api::EditUserGroupOperationInput> for EditUserGroupOperationIn
                 .expect("Invalid UUID")
                 .as_bytes(),
             name: input.name,
+            add_members: input
+                .add_members
+                .unwrap_or_default()
+                .into_iter()
+                .map(|id| *HelperMapper::to_uuid(id).expect("Invalid UUID").as_bytes())
+                .collect(),
+            remove_members: input
+                .remove_members
+                .unwrap_or_default()
+                .into_iter()
+                .map(|id| *HelperMapper::to_uuid(id).expect("Invalid UUID").as_bytes())
+                .collect(),
         }
     }
 }
@@ -36,6 +76,20 @@ impl From<EditUserGroupOperationInput> for station_api::EditUserGroupOperationIn
                 .hyphenated()
                 .to_string(),
             name: input.name,
+            add_members: Some(
+                input
+                    .add_members
+                    .into_iter()
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                    .collect(),
+            ),
+            remove_members: Some(
+                input
+                    .remove_members
+                    .into_iter()
+                    .map(|id| Uuid::from_bytes(id).hyphenated().to_string())
+                    .collect(),
+            ),
         }
     }
 }
@@ -78,10 +132,20 @@ impl AddUserGroupOperation {
     }
 }
 
+impl AddTeamOperation {
+    pub fn to_dto(self, user_group: Option<UserGroup>) -> station_api::AddTeamOperationDTO {
+        station_api::AddTeamOperationDTO {
+            user_group: user_group.map(|user_group| user_group.into()),
+            input: self.input.into(),
+        }
+    }
+}
+
 impl From<EditUserGroupOperation> for station_api::EditUserGroupOperationDTO {
     fn from(operation: EditUserGroupOperation) -> Self {
         Self {
             input: operation.input.into(),
+            previous: operation.previous.map(Into::into),
         }
     }
 }
@@ -103,14 +167,6 @@ impl From<station_api::AddUserGroupOperationInput> for AddUserGroupOperation {
     }
 }
 
-impl From<station_api::EditUserGroupOperationInput> for EditUserGroupOperation {
-    fn from(input: station_api::EditUserGroupOperationInput) -> Self {
-        Self {
-            input: input.into(),
-        }
-    }
-}
-
 impl From<station_api::RemoveUserGroupOperationInput> for RemoveUserGroupOperation {
     fn from(input: station_api::RemoveUserGroupOperationInput) -> Self {
         Self {
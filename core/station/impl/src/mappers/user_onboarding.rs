@@ -0,0 +1,47 @@
+use crate::models::{OnboardingStep, UserOnboarding};
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use station_api::{OnboardingStepDTO, OnboardingStepStatusDTO, UserOnboardingDTO};
+
+impl From<OnboardingStep> for OnboardingStepDTO {
+    fn from(step: OnboardingStep) -> Self {
+        match step {
+            OnboardingStep::IdentityConfirmed => OnboardingStepDTO::IdentityConfirmed,
+            OnboardingStep::JoinedGroups => OnboardingStepDTO::JoinedGroups,
+            OnboardingStep::FirstVoteCast => OnboardingStepDTO::FirstVoteCast,
+            OnboardingStep::NotificationPreferencesSet => {
+                OnboardingStepDTO::NotificationPreferencesSet
+            }
+        }
+    }
+}
+
+impl From<OnboardingStepDTO> for OnboardingStep {
+    fn from(step: OnboardingStepDTO) -> Self {
+        match step {
+            OnboardingStepDTO::IdentityConfirmed => OnboardingStep::IdentityConfirmed,
+            OnboardingStepDTO::JoinedGroups => OnboardingStep::JoinedGroups,
+            OnboardingStepDTO::FirstVoteCast => OnboardingStep::FirstVoteCast,
+            OnboardingStepDTO::NotificationPreferencesSet => {
+                OnboardingStep::NotificationPreferencesSet
+            }
+        }
+    }
+}
+
+impl From<UserOnboarding> for UserOnboardingDTO {
+    fn from(onboarding: UserOnboarding) -> Self {
+        UserOnboardingDTO {
+            steps: OnboardingStep::ALL
+                .into_iter()
+                .map(|step| OnboardingStepStatusDTO {
+                    step: step.into(),
+                    completed_at: onboarding
+                        .completed_at(&step)
+                        .map(|completed_at| timestamp_to_rfc3339(&completed_at)),
+                })
+                .collect(),
+            next_step: onboarding.next_step().map(Into::into),
+            is_completed: onboarding.is_completed(),
+        }
+    }
+}
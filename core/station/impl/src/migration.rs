@@ -6,14 +6,14 @@ use crate::models::resource::{ExternalCanisterResourceAction, Resource, SystemRe
 use crate::models::{
     Account, AccountKey, AddressBookEntry, AddressBookEntryKey, ExternalCanister,
     ExternalCanisterKey, ListRequestsOperationType, Request, RequestKey, RequestOperation,
-    RequestPolicy, User, UserGroup, UserKey,
+    RequestPolicy, Transfer, TransferKey, User, UserGroup, UserKey,
 };
 use crate::repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY};
 use crate::repositories::{
     AccountRepository, AddressBookRepository, ExternalCanisterRepository, RequestPolicyRepository,
-    RequestRepository, RequestWhereClause, UserGroupRepository, UserRepository, ACCOUNT_REPOSITORY,
-    ADDRESS_BOOK_REPOSITORY, EXTERNAL_CANISTER_REPOSITORY, REQUEST_POLICY_REPOSITORY,
-    USER_GROUP_REPOSITORY, USER_REPOSITORY,
+    RequestRepository, RequestWhereClause, TransferRepository, UserGroupRepository, UserRepository,
+    ACCOUNT_REPOSITORY, ADDRESS_BOOK_REPOSITORY, EXTERNAL_CANISTER_REPOSITORY,
+    REQUEST_POLICY_REPOSITORY, TRANSFER_REPOSITORY, USER_GROUP_REPOSITORY, USER_REPOSITORY,
 };
 use crate::{concat_str_arrays, STABLE_MEMORY_VERSION};
 use crate::{core::with_memory_manager, repositories::REQUEST_REPOSITORY};
@@ -148,6 +148,8 @@ fn apply_migration() {
     PERMISSION_REPOSITORY.rebuild();
     REQUEST_POLICY_REPOSITORY.rebuild();
     REQUEST_REPOSITORY.rebuild();
+    // indexes the well-known metadata, status and destination of the existing transfers
+    TRANSFER_REPOSITORY.rebuild();
 }
 
 impl<'de> Deserialize<'de> for Resource {
@@ -161,8 +163,8 @@ impl<'de> Deserialize<'de> for Resource {
         const REMOVED_VARIANTS: [&str; 1] = ["ChangeCanister"];
 
         // IMPORTANT: The size of the array must be hardcoded, to make sure it can be checked at compile-time.
-        static EXPECTED_VARIANTS: [&str; 11] = {
-            let variants: [&str; CURRENT_VARIANTS.len() + REMOVED_VARIANTS.len()] = [""; 11];
+        static EXPECTED_VARIANTS: [&str; 12] = {
+            let variants: [&str; CURRENT_VARIANTS.len() + REMOVED_VARIANTS.len()] = [""; 12];
             concat_str_arrays!(CURRENT_VARIANTS, REMOVED_VARIANTS);
 
             variants
@@ -280,6 +282,10 @@ impl<'de> Deserialize<'de> for Resource {
                         let value = variant_access.newtype_variant()?;
                         Ok(Resource::UserGroup(value))
                     }
+                    "Asset" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(Resource::Asset(value))
+                    }
                     _ => Err(de::Error::unknown_variant(&variant, &EXPECTED_VARIANTS)),
                 }
             }
@@ -300,7 +306,7 @@ impl<'de> Deserialize<'de> for RequestSpecifier {
         const REMOVED_VARIANTS: [&str; 1] = ["ChangeCanister"];
 
         // IMPORTANT: The size of the array must be hardcoded, to make sure it can be checked at compile-time.
-        static EXPECTED_VARIANTS: [&str; 23] = {
+        static EXPECTED_VARIANTS: [&str; 26] = {
             let variants: [&str; CURRENT_VARIANTS.len() + REMOVED_VARIANTS.len()] =
                 concat_str_arrays!(CURRENT_VARIANTS, REMOVED_VARIANTS);
 
@@ -403,6 +409,15 @@ impl<'de> Deserialize<'de> for RequestSpecifier {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestSpecifier::FundExternalCanister(value))
                     }
+                    "AddAsset" => Ok(RequestSpecifier::AddAsset),
+                    "EditAsset" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestSpecifier::EditAsset(value))
+                    }
+                    "RemoveAsset" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestSpecifier::RemoveAsset(value))
+                    }
                     _ => Err(de::Error::unknown_variant(&variant, &EXPECTED_VARIANTS)),
                 }
             }
@@ -423,7 +438,7 @@ impl<'de> Deserialize<'de> for RequestOperation {
         const REMOVED_VARIANTS: [&str; 1] = ["ChangeCanister"];
 
         // IMPORTANT: The size of the array must be hardcoded, to make sure it can be checked at compile-time.
-        static EXPECTED_VARIANTS: [&str; 24] = {
+        static EXPECTED_VARIANTS: [&str; 40] = {
             let variants: [&str; CURRENT_VARIANTS.len() + REMOVED_VARIANTS.len()] =
                 concat_str_arrays!(CURRENT_VARIANTS, REMOVED_VARIANTS);
 
@@ -547,6 +562,72 @@ impl<'de> Deserialize<'de> for RequestOperation {
                         let value = variant_access.newtype_variant()?;
                         Ok(RequestOperation::SetDisasterRecovery(value))
                     }
+                    "Approve" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::Approve(value))
+                    }
+                    "SetAutoApprovalForTrustedDestinations" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::SetAutoApprovalForTrustedDestinations(
+                            value,
+                        ))
+                    }
+                    "TransferNft" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::TransferNft(value))
+                    }
+                    "DeriveSubaccount" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::DeriveSubaccount(value))
+                    }
+                    "ImportAccessPolicies" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::ImportAccessPolicies(value))
+                    }
+                    "AddAsset" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::AddAsset(value))
+                    }
+                    "EditAsset" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::EditAsset(value))
+                    }
+                    "RemoveAsset" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::RemoveAsset(value))
+                    }
+                    "SwapTokens" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::SwapTokens(value))
+                    }
+                    "AddScheduledTransfer" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::AddScheduledTransfer(value))
+                    }
+                    "AddTeam" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::AddTeam(value))
+                    }
+                    "SweepAccount" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::SweepAccount(value))
+                    }
+                    "SplitTransfer" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::SplitTransfer(value))
+                    }
+                    "ManageAddressBookLabels" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::ManageAddressBookLabels(value))
+                    }
+                    "CreateCapabilityGrant" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::CreateCapabilityGrant(value))
+                    }
+                    "RevokeCapabilityGrant" => {
+                        let value = variant_access.newtype_variant()?;
+                        Ok(RequestOperation::RevokeCapabilityGrant(value))
+                    }
                     _ => Err(de::Error::unknown_variant(&variant, &EXPECTED_VARIANTS)),
                 }
             }
@@ -600,3 +681,4 @@ impl RebuildRepository<ExternalCanisterKey, ExternalCanister, VirtualMemory<Memo
 impl RebuildRepository<UUID, UserGroup, VirtualMemory<Memory>> for UserGroupRepository {}
 impl RebuildRepository<UserKey, User, VirtualMemory<Memory>> for UserRepository {}
 impl RebuildRepository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRepository {}
+impl RebuildRepository<TransferKey, Transfer, VirtualMemory<Memory>> for TransferRepository {}
@@ -1,8 +1,13 @@
-use super::{AccountBalance, Blockchain, BlockchainStandard};
+use super::{
+    validate_trusted_destinations, validate_webhooks, AccountBalance, AccountWebhook,
+    AddAccountOperationInput, Blockchain, BlockchainStandard, IcrcAccount, NetworkProfile,
+    RegisteredAssetId, TrustedDestination,
+};
 use crate::errors::AccountError;
 use crate::models::Metadata;
 use crate::repositories::request_policy::REQUEST_POLICY_REPOSITORY;
-use candid::{CandidType, Deserialize};
+use crate::repositories::REGISTERED_ASSET_REPOSITORY;
+use candid::{CandidType, Deserialize, Nat, Principal};
 use orbit_essentials::model::ModelKey;
 use orbit_essentials::repository::Repository;
 use orbit_essentials::storable;
@@ -11,17 +16,22 @@ use orbit_essentials::{
     types::{Timestamp, UUID},
 };
 use std::{collections::HashMap, hash::Hash};
+use uuid::Uuid;
 
 /// The account metadata key for the asset symbol;
 pub const ACCOUNT_METADATA_SYMBOL_KEY: &str = "symbol";
 
+/// The account metadata key for the ledger canister id of non-native ICRC-1 assets.
+pub const ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY: &str = "ledger_canister_id";
+
 /// The account id, which is a UUID.
 pub type AccountId = UUID;
 
 /// Represents a account in the system.
 ///
-/// A account can be associated with one or more users and can only hold one type of asset,
-/// which is defined by the blockchain, standard and symbol.
+/// A account can be associated with one or more users and holds the asset defined by the blockchain,
+/// standard and symbol, accounts of the Internet Computer can also hold registered ICRC-1 assets at
+/// the same address, under the same owners and policies.
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Account {
@@ -33,6 +43,9 @@ pub struct Account {
     pub address: String,
     /// The blockchain standard (e.g. `native`, `icrc1`, `erc20`, etc.)
     pub standard: BlockchainStandard,
+    /// The network of the account, which is selected at creation and can not be changed afterwards.
+    #[serde(default)]
+    pub network: NetworkProfile,
     /// The asset symbol (e.g. `ICP`, `ETH`, `BTC`, etc.)
     pub symbol: String,
     /// The asset decimals (e.g. `8` for `BTC`, `18` for `ETH`, etc.)
@@ -55,10 +68,47 @@ pub struct Account {
     /// This policy is non exaustive, this means that the account can have other policies that are enforced
     /// by the system that are globally defined.
     pub configs_request_policy_id: Option<UUID>,
+    /// The destinations whose transfers can be auto approved by the `TrustedDestination` policy rule.
+    #[serde(default)]
+    pub trusted_destinations: Vec<TrustedDestination>,
+    /// The ledger subaccounts owned by the account in addition to its main address.
+    #[serde(default)]
+    pub subaccounts: Vec<AccountSubaccount>,
+    /// The canister endpoints called when a transfer of the account completes or fails.
+    #[serde(default)]
+    pub webhooks: Vec<AccountWebhook>,
+    /// The registered assets held by the account in addition to its own asset.
+    #[serde(default)]
+    pub assets: Vec<AccountAsset>,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
 }
 
+/// A registered ICRC-1 asset held by an account in addition to its own asset, the funds are held at
+/// the ledger account of the station account and are sent with the same owners and policies.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountAsset {
+    pub asset_id: RegisteredAssetId,
+    /// The last fetched balance of the asset.
+    pub balance: Option<AccountBalance>,
+}
+
+/// A ledger subaccount owned by an account in addition to its main address (e.g. a per-department
+/// deposit address), the balance of the account is the sum of its main address and subaccounts.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountSubaccount {
+    /// The index the subaccount is derived with, the index `0` is the main address of the account.
+    pub index: u32,
+    /// The subaccount name (e.g. `Marketing deposits`)
+    pub name: String,
+    /// The subaccount address, which can be used to deposit funds into the account.
+    pub address: String,
+    /// The last fetched balance of the subaccount.
+    pub balance: Option<AccountBalance>,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AccountKey {
@@ -79,6 +129,30 @@ pub struct AccountCallerPrivileges {
     pub can_transfer: bool,
 }
 
+/// A non-empty balance found on a ledger at an address that the station can derive.
+#[derive(Debug, Clone)]
+pub struct DiscoveredAccount {
+    /// The ledger that holds the balance.
+    pub ledger_canister_id: Principal,
+    /// The station owned ledger account that holds the balance.
+    pub address: IcrcAccount,
+    /// The existing station account whose subaccount holds the balance, if any.
+    pub account_id: Option<AccountId>,
+    pub symbol: String,
+    pub decimals: u32,
+    pub balance: Nat,
+    /// The operation to add an account for the asset, only set if the asset is supported by the station.
+    pub proposed_operation: Option<AddAccountOperationInput>,
+}
+
+/// The result of discovering the station balances on a set of ledgers.
+#[derive(Debug, Clone, Default)]
+pub struct AccountDiscovery {
+    pub accounts: Vec<DiscoveredAccount>,
+    /// The ledgers that could not be queried, with the failure reason.
+    pub failed_ledgers: Vec<(Principal, String)>,
+}
+
 fn validate_symbol(symbol: &str) -> ModelValidatorResult<AccountError> {
     if (symbol.len() < Account::SYMBOL_RANGE.0 as usize)
         || (symbol.len() > Account::SYMBOL_RANGE.1 as usize)
@@ -108,6 +182,91 @@ fn validate_address(address: &str) -> ModelValidatorResult<AccountError> {
     Ok(())
 }
 
+fn validate_subaccounts(subaccounts: &[AccountSubaccount]) -> ModelValidatorResult<AccountError> {
+    if subaccounts.len() > Account::MAX_SUBACCOUNTS as usize {
+        return Err(AccountError::ValidationError {
+            info: format!(
+                "An account can have at most {} subaccounts",
+                Account::MAX_SUBACCOUNTS
+            ),
+        });
+    }
+
+    for (position, subaccount) in subaccounts.iter().enumerate() {
+        if (subaccount.name.trim().len() < Account::SUBACCOUNT_NAME_RANGE.0 as usize)
+            || (subaccount.name.len() > Account::SUBACCOUNT_NAME_RANGE.1 as usize)
+        {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "Subaccount name length must be between {} and {}",
+                    Account::SUBACCOUNT_NAME_RANGE.0,
+                    Account::SUBACCOUNT_NAME_RANGE.1
+                ),
+            });
+        }
+
+        if subaccount.index == 0
+            || subaccounts[..position]
+                .iter()
+                .any(|other| other.index == subaccount.index || other.name == subaccount.name)
+        {
+            return Err(AccountError::ValidationError {
+                info: format!("The subaccount {} is duplicated", subaccount.name),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_assets(account: &Account) -> ModelValidatorResult<AccountError> {
+    if account.assets.is_empty() {
+        return Ok(());
+    }
+
+    if account.blockchain != Blockchain::InternetComputer {
+        return Err(AccountError::ValidationError {
+            info: format!(
+                "Only the accounts of the Internet Computer can hold other assets, not {}",
+                account.blockchain
+            ),
+        });
+    }
+
+    if account.assets.len() > Account::MAX_ASSETS as usize {
+        return Err(AccountError::ValidationError {
+            info: format!(
+                "An account can hold at most {} other assets",
+                Account::MAX_ASSETS
+            ),
+        });
+    }
+
+    let own_ledger = account
+        .metadata
+        .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY);
+    for (position, held) in account.assets.iter().enumerate() {
+        let asset_id = Uuid::from_bytes(held.asset_id).hyphenated().to_string();
+        let asset = REGISTERED_ASSET_REPOSITORY.get(&held.asset_id).ok_or(
+            AccountError::ValidationError {
+                info: format!("The asset {} is not registered", asset_id),
+            },
+        )?;
+
+        if own_ledger.as_deref() == Some(asset.ledger_canister_id.to_text().as_str())
+            || account.assets[..position]
+                .iter()
+                .any(|other| other.asset_id == held.asset_id)
+        {
+            return Err(AccountError::ValidationError {
+                info: format!("The asset {} is already held by the account", asset_id),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_policy_id(policy_id: &UUID, field_name: &str) -> ModelValidatorResult<AccountError> {
     REQUEST_POLICY_REPOSITORY
         .get(policy_id)
@@ -122,6 +281,10 @@ impl ModelValidator<AccountError> for Account {
         self.metadata.validate()?;
         validate_symbol(&self.symbol)?;
         validate_address(&self.address)?;
+        validate_trusted_destinations(&self.trusted_destinations)?;
+        validate_subaccounts(&self.subaccounts)?;
+        validate_webhooks(&self.webhooks)?;
+        validate_assets(self)?;
 
         if let Some(transfer_request_policy_id) = &self.transfer_request_policy_id {
             validate_policy_id(transfer_request_policy_id, "transfer_request_policy_id")?;
@@ -139,6 +302,9 @@ impl Account {
     pub const ADDRESS_RANGE: (u8, u8) = (1, 255);
     pub const SYMBOL_RANGE: (u8, u8) = (1, 8);
     pub const MAX_POLICIES: u8 = 10;
+    pub const MAX_SUBACCOUNTS: u8 = 50;
+    pub const SUBACCOUNT_NAME_RANGE: (u8, u8) = (1, 64);
+    pub const MAX_ASSETS: u8 = 20;
 
     /// Creates a new account key from the given key components.
     pub fn key(id: AccountId) -> AccountKey {
@@ -152,12 +318,62 @@ impl Account {
     pub fn metadata_map(&self) -> HashMap<String, String> {
         self.metadata.map()
     }
+
+    /// The index of the next derived subaccount, indexes are never reused.
+    pub fn next_subaccount_index(&self) -> u32 {
+        self.subaccounts
+            .iter()
+            .map(|subaccount| subaccount.index)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    /// Returns the account as the holder of the given asset, which the blockchain adapters serve like
+    /// an account of the asset, `None` selects the own asset of the account.
+    pub fn for_asset(&self, asset_id: Option<&RegisteredAssetId>) -> Result<Account, AccountError> {
+        let Some(asset_id) = asset_id else {
+            return Ok(self.clone());
+        };
+        let not_held = || AccountError::AssetNotHeld {
+            asset_id: Uuid::from_bytes(*asset_id).hyphenated().to_string(),
+        };
+        let held = self
+            .assets
+            .iter()
+            .find(|held| held.asset_id == *asset_id)
+            .ok_or_else(not_held)?;
+        let registered_asset = REGISTERED_ASSET_REPOSITORY
+            .get(asset_id)
+            .ok_or_else(not_held)?;
+        let asset = registered_asset.to_asset();
+
+        Ok(Account {
+            standard: asset.standard,
+            symbol: asset.symbol,
+            decimals: registered_asset.decimals,
+            balance: held.balance.clone(),
+            metadata: asset.metadata,
+            // the balances of the subaccounts are the ones of the own asset of the account
+            subaccounts: self
+                .subaccounts
+                .iter()
+                .map(|subaccount| AccountSubaccount {
+                    balance: None,
+                    ..subaccount.clone()
+                })
+                .collect(),
+            assets: Vec::new(),
+            ..self.clone()
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::account_test_utils::mock_account;
     use super::*;
+    use crate::models::registered_asset_test_utils::mock_registered_asset;
 
     #[test]
     fn fail_symbol_validation_too_short() {
@@ -273,6 +489,78 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn fail_duplicated_subaccount_name() {
+        let mut account = mock_account();
+        account.subaccounts = vec![
+            AccountSubaccount {
+                index: 1,
+                name: "Marketing".to_string(),
+                address: "0x1".to_string(),
+                balance: None,
+            },
+            AccountSubaccount {
+                index: 2,
+                name: "Marketing".to_string(),
+                address: "0x2".to_string(),
+                balance: None,
+            },
+        ];
+
+        let result = validate_subaccounts(&account.subaccounts);
+
+        assert_eq!(
+            result.unwrap_err(),
+            AccountError::ValidationError {
+                info: "The subaccount Marketing is duplicated".to_string()
+            }
+        );
+
+        account.subaccounts[1].name = "Payroll".to_string();
+
+        assert!(validate_subaccounts(&account.subaccounts).is_ok());
+        assert_eq!(account.next_subaccount_index(), 3);
+    }
+
+    #[test]
+    fn serves_the_held_assets_as_accounts_of_the_asset() {
+        let asset = mock_registered_asset();
+        REGISTERED_ASSET_REPOSITORY.insert(asset.id, asset.clone());
+
+        let mut account = mock_account();
+        account.assets = vec![AccountAsset {
+            asset_id: asset.id,
+            balance: None,
+        }];
+
+        assert!(validate_assets(&account).is_ok());
+        assert_eq!(account.for_asset(None).unwrap(), account);
+
+        let holder = account.for_asset(Some(&asset.id)).unwrap();
+
+        assert_eq!(holder.id, account.id);
+        assert_eq!(holder.standard, BlockchainStandard::ICRC1);
+        assert_eq!(holder.symbol, asset.symbol);
+        assert_eq!(holder.decimals, asset.decimals);
+        assert_eq!(
+            holder.metadata.get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY),
+            Some(asset.ledger_canister_id.to_text())
+        );
+        assert_eq!(
+            account.for_asset(Some(&[9; 16])),
+            Err(AccountError::AssetNotHeld {
+                asset_id: Uuid::from_bytes([9; 16]).hyphenated().to_string()
+            })
+        );
+
+        account.assets.push(account.assets[0].clone());
+        assert!(validate_assets(&account).is_err());
+
+        account.assets.pop();
+        account.blockchain = Blockchain::Bitcoin;
+        assert!(validate_assets(&account).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -280,7 +568,6 @@ pub mod account_test_utils {
     use super::*;
     use crate::repositories::ACCOUNT_REPOSITORY;
     use orbit_essentials::repository::Repository;
-    use uuid::Uuid;
 
     pub fn mock_account() -> Account {
         Account {
@@ -291,11 +578,16 @@ pub mod account_test_utils {
             decimals: 0u32,
             name: "foo".to_string(),
             standard: BlockchainStandard::Native,
+            network: NetworkProfile::Mainnet,
             last_modification_timestamp: 0,
             metadata: Metadata::mock(),
             symbol: "ICP".to_string(),
             transfer_request_policy_id: None,
             configs_request_policy_id: None,
+            trusted_destinations: Vec::new(),
+            subaccounts: Vec::new(),
+            webhooks: Vec::new(),
+            assets: Vec::new(),
         }
     }
 
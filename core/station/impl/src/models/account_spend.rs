@@ -0,0 +1,126 @@
+use super::{Account, AccountId, RegisteredAssetId, RequestId, RequestOperation};
+use crate::repositories::ACCOUNT_REPOSITORY;
+use candid::Nat;
+use orbit_essentials::{model::ModelKey, repository::Repository, storable, types::Timestamp};
+
+/// The key of the spends, ordered by account, asset and time so that the spends of an asset of an
+/// account within a window are a range of the repository.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountSpendKey {
+    pub account_id: AccountId,
+    /// The registered asset of the account that was spent, the own asset of the account if not set.
+    #[serde(default)]
+    pub asset_id: Option<RegisteredAssetId>,
+    pub spent_at: Timestamp,
+    pub request_id: RequestId,
+}
+
+/// The amount reserved on an account by an approved request that moves value out of it, or by an
+/// execution of a standing order, which counts towards the `VelocityLimit` policy rules of the
+/// account.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountSpend {
+    pub account_id: AccountId,
+    /// The registered asset of the account that was spent, the own asset of the account if not set.
+    #[serde(default)]
+    pub asset_id: Option<RegisteredAssetId>,
+    pub request_id: RequestId,
+    pub amount: Nat,
+    /// The time at which the request was approved or the standing order executed.
+    pub spent_at: Timestamp,
+}
+
+impl ModelKey<AccountSpendKey> for AccountSpend {
+    fn key(&self) -> AccountSpendKey {
+        AccountSpendKey {
+            account_id: self.account_id,
+            asset_id: self.asset_id,
+            spent_at: self.spent_at,
+            request_id: self.request_id,
+        }
+    }
+}
+
+/// The amount of an asset of an account that an operation moves out of the account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperationSpend {
+    pub account_id: AccountId,
+    pub asset_id: Option<RegisteredAssetId>,
+    pub amount: Nat,
+}
+
+impl AccountSpend {
+    /// How long the spends are kept, which bounds the window of the velocity limits.
+    pub const RETENTION_NS: u64 = 31 * 24 * 60 * 60 * 1_000_000_000;
+
+    /// Returns the amount of the asset of the account that the operation moves out, if any.
+    ///
+    /// The velocity limits apply to each asset of an account separately, in the units of the asset.
+    /// A sweep is charged with the last known balance of the account until it is executed, an
+    /// allowance with its full amount and a token of a collection counts as one.
+    pub fn spent_by(operation: &RequestOperation) -> Option<OperationSpend> {
+        let (account_id, asset_id, amount) = match operation {
+            RequestOperation::Transfer(transfer) => (
+                transfer.input.from_account_id,
+                transfer.input.asset_id,
+                transfer.input.amount.clone(),
+            ),
+            RequestOperation::SplitTransfer(split) => (
+                split.input.from_account_id,
+                None,
+                split.input.total_amount.clone(),
+            ),
+            RequestOperation::SweepAccount(sweep) => (
+                sweep.input.from_account_id,
+                None,
+                match &sweep.amount {
+                    Some(amount) => amount.clone(),
+                    None => ACCOUNT_REPOSITORY
+                        .get(&Account::key(sweep.input.from_account_id))
+                        .and_then(|account| account.balance)
+                        .map(|balance| balance.balance)
+                        .unwrap_or(Nat::from(0u64)),
+                },
+            ),
+            RequestOperation::SwapTokens(swap) => (
+                swap.input.from_account_id,
+                None,
+                swap.input.amount_in.clone(),
+            ),
+            RequestOperation::Approve(approve) => (
+                approve.input.from_account_id,
+                None,
+                approve.input.amount.clone(),
+            ),
+            RequestOperation::TransferNft(transfer) => {
+                (transfer.input.from_account_id, None, Nat::from(1u64))
+            }
+            _ => return None,
+        };
+
+        Some(OperationSpend {
+            account_id,
+            asset_id,
+            amount,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod account_spend_test_utils {
+    use super::AccountSpend;
+    use candid::Nat;
+    use uuid::Uuid;
+
+    pub fn mock_account_spend() -> AccountSpend {
+        AccountSpend {
+            account_id: [0; 16],
+            asset_id: None,
+            request_id: *Uuid::new_v4().as_bytes(),
+            amount: Nat::from(100u64),
+            spent_at: 0,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use super::{AccountId, TransferId};
+use orbit_essentials::model::ModelKey;
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::fmt::{Display, Formatter};
+
+/// The kind of the ledger operation that moved the funds of the account.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountTransactionKind {
+    Transfer,
+    Mint,
+    Burn,
+}
+
+impl Display for AccountTransactionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountTransactionKind::Transfer => write!(f, "transfer"),
+            AccountTransactionKind::Mint => write!(f, "mint"),
+            AccountTransactionKind::Burn => write!(f, "burn"),
+        }
+    }
+}
+
+/// Whether the funds moved into or out of the account.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountTransactionDirection {
+    Inbound,
+    Outbound,
+}
+
+impl Display for AccountTransactionDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountTransactionDirection::Inbound => write!(f, "inbound"),
+            AccountTransactionDirection::Outbound => write!(f, "outbound"),
+        }
+    }
+}
+
+/// A transaction of an account as recorded by the ledger, imported from the index canister of the ledger.
+///
+/// Unlike transfers, the transactions include the deposits and any other movement of funds that did not
+/// go through a request of the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountTransaction {
+    pub account_id: AccountId,
+    /// The index of the ledger block that recorded the transaction.
+    pub block_index: u64,
+    pub kind: AccountTransactionKind,
+    pub direction: AccountTransactionDirection,
+    /// The other account of the transfer, mints and burns have none.
+    pub counterparty: Option<String>,
+    pub amount: candid::Nat,
+    pub fee: Option<candid::Nat>,
+    pub memo: Option<Vec<u8>>,
+    /// The time the ledger recorded the transaction, if reported by the index canister.
+    pub timestamp: Option<Timestamp>,
+    /// The transfer of the station that submitted the transaction, if any.
+    pub transfer_id: Option<TransferId>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountTransactionKey {
+    pub account_id: AccountId,
+    pub block_index: u64,
+}
+
+impl ModelKey<AccountTransactionKey> for AccountTransaction {
+    fn key(&self) -> AccountTransactionKey {
+        AccountTransactionKey {
+            account_id: self.account_id,
+            block_index: self.block_index,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod account_transaction_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_account_transaction() -> AccountTransaction {
+        AccountTransaction {
+            account_id: *Uuid::new_v4().as_bytes(),
+            block_index: 1,
+            kind: AccountTransactionKind::Transfer,
+            direction: AccountTransactionDirection::Inbound,
+            counterparty: Some(
+                "hwlgi-7ekwn-bvzxd-fk2ar-4wn6a-kvnmb-ymvvw-5ozoi-dcyv7-kqbsb-dae".to_string(),
+            ),
+            amount: candid::Nat::from(100_000_000u64),
+            fee: Some(candid::Nat::from(10_000u64)),
+            memo: None,
+            timestamp: Some(0),
+            transfer_id: None,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    core::{ic_cdk::api::id as self_canister_id, utils::hmac_sha256},
+    errors::AccountError,
+};
+use candid::Principal;
+use orbit_essentials::{model::ModelValidatorResult, storable};
+use std::collections::HashSet;
+
+/// A canister endpoint that is called when a transfer of the account completes or fails, so that
+/// downstream systems do not need to poll the station for the transfers.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccountWebhook {
+    pub canister_id: Principal,
+    /// The update method of the canister that receives the callbacks.
+    pub method_name: String,
+    /// The secret shared with the receiver, the callbacks are signed with it if set.
+    pub secret: Option<String>,
+}
+
+impl AccountWebhook {
+    pub const MAX_PER_ACCOUNT: usize = 5;
+    pub const MAX_METHOD_NAME_LEN: usize = 100;
+    pub const SECRET_LEN_RANGE: (usize, usize) = (16, 256);
+
+    /// Returns the hex encoded HMAC-SHA256 of the event keyed by the secret of the webhook, if it has one.
+    pub fn sign(&self, event: &[u8]) -> Option<String> {
+        self.secret
+            .as_ref()
+            .map(|secret| hex::encode(hmac_sha256(secret.as_bytes(), event)))
+    }
+}
+
+pub fn validate_webhooks(webhooks: &[AccountWebhook]) -> ModelValidatorResult<AccountError> {
+    if webhooks.len() > AccountWebhook::MAX_PER_ACCOUNT {
+        return Err(AccountError::ValidationError {
+            info: format!(
+                "An account can have at most {} webhooks",
+                AccountWebhook::MAX_PER_ACCOUNT
+            ),
+        });
+    }
+
+    let mut endpoints = HashSet::new();
+    for webhook in webhooks {
+        // the callbacks are made by the station itself, hence not to itself nor the management canister
+        if webhook.canister_id == self_canister_id()
+            || webhook.canister_id == Principal::management_canister()
+            || webhook.canister_id == Principal::anonymous()
+        {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "The canister {} cannot receive webhook callbacks",
+                    webhook.canister_id
+                ),
+            });
+        }
+
+        if webhook.method_name.trim().is_empty()
+            || webhook.method_name.len() > AccountWebhook::MAX_METHOD_NAME_LEN
+        {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "The method name of a webhook must be between 1 and {} characters",
+                    AccountWebhook::MAX_METHOD_NAME_LEN
+                ),
+            });
+        }
+
+        if let Some(secret) = &webhook.secret {
+            let (min_len, max_len) = AccountWebhook::SECRET_LEN_RANGE;
+            if secret.len() < min_len || secret.len() > max_len {
+                return Err(AccountError::ValidationError {
+                    info: format!(
+                        "The secret of a webhook must be between {} and {} characters",
+                        min_len, max_len
+                    ),
+                });
+            }
+        }
+
+        if !endpoints.insert((webhook.canister_id, webhook.method_name.as_str())) {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "The webhook {}.{} is listed more than once",
+                    webhook.canister_id, webhook.method_name
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(method_name: &str, secret: Option<&str>) -> AccountWebhook {
+        AccountWebhook {
+            canister_id: Principal::from_slice(&[1; 29]),
+            method_name: method_name.to_string(),
+            secret: secret.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn validates_webhooks() {
+        assert!(validate_webhooks(&[
+            webhook("on_transfer", None),
+            webhook("on_payroll_transfer", Some("a-shared-secret-of-the-erp")),
+        ])
+        .is_ok());
+
+        assert!(
+            validate_webhooks(&[webhook("on_transfer", None), webhook("on_transfer", None)])
+                .is_err()
+        );
+        assert!(validate_webhooks(&[webhook(" ", None)]).is_err());
+        assert!(validate_webhooks(&[webhook("on_transfer", Some("short"))]).is_err());
+        assert!(validate_webhooks(&[AccountWebhook {
+            canister_id: self_canister_id(),
+            ..webhook("on_transfer", None)
+        }])
+        .is_err());
+    }
+
+    #[test]
+    fn signs_with_the_secret_of_the_webhook() {
+        assert_eq!(webhook("on_transfer", None).sign(b"event"), None);
+        // RFC 4231, test case 2
+        assert_eq!(
+            webhook("on_transfer", Some("Jefe"))
+                .sign(b"what do ya want for nothing?")
+                .unwrap(),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -30,6 +30,10 @@ pub struct AddressBookEntry {
     /// The labels associated with the address.
     #[serde(default)]
     pub labels: Vec<String>,
+    /// Whether the transfers to the address must have a memo, e.g. exchange deposit addresses that
+    /// credit the deposits to the account identified by the memo.
+    #[serde(default)]
+    pub requires_memo: bool,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
 }
@@ -268,6 +272,7 @@ pub mod address_book_entry_test_utils {
             labels: Vec::new(),
             blockchain: Blockchain::InternetComputer,
             metadata: Metadata::mock(),
+            requires_memo: false,
             last_modification_timestamp: 0,
         }
     }
@@ -0,0 +1,100 @@
+use crate::{errors::AddressBookError, models::AddressBookEntry};
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    storable,
+    types::Timestamp,
+};
+
+/// A label of the managed taxonomy that address book entries are categorized with (e.g. `vendor`).
+///
+/// Once the taxonomy has a label, the entries can only use the labels it defines.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddressBookLabel {
+    /// The name of the label, compared case-insensitively like the label filters of the entries.
+    pub name: String,
+    /// What the label is used for, e.g. which counterparties it groups.
+    pub description: Option<String>,
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl AddressBookLabel {
+    pub const MAX_NAME_LEN: usize = AddressBookEntry::MAX_LABEL_LENGTH;
+    pub const MAX_DESCRIPTION_LEN: usize = 200;
+    pub const MAX_LABELS: usize = 200;
+
+    /// Creates the key of the label, which is its lowercased name.
+    pub fn key(name: &str) -> String {
+        name.to_lowercase()
+    }
+
+    pub fn to_key(&self) -> String {
+        Self::key(&self.name)
+    }
+}
+
+impl ModelValidator<AddressBookError> for AddressBookLabel {
+    fn validate(&self) -> ModelValidatorResult<AddressBookError> {
+        if self.name.is_empty()
+            || self.name.trim() != self.name
+            || self.name.len() > Self::MAX_NAME_LEN
+        {
+            return Err(AddressBookError::ValidationError {
+                info: format!(
+                    "Label `{}` must be between 1 and {} characters without surrounding spaces",
+                    self.name,
+                    Self::MAX_NAME_LEN
+                ),
+            });
+        }
+
+        if self
+            .description
+            .as_ref()
+            .is_some_and(|description| description.len() > Self::MAX_DESCRIPTION_LEN)
+        {
+            return Err(AddressBookError::ValidationError {
+                info: format!(
+                    "Label description cannot be longer than {} characters",
+                    Self::MAX_DESCRIPTION_LEN
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_the_label() {
+        let mut label = AddressBookLabel {
+            name: "vendor".to_string(),
+            description: Some("Suppliers paid against invoices".to_string()),
+            last_modification_timestamp: 0,
+        };
+
+        assert!(label.validate().is_ok());
+
+        label.name = " vendor".to_string();
+        assert!(label.validate().is_err());
+
+        label.name = String::new();
+        assert!(label.validate().is_err());
+
+        label.name = "vendor".to_string();
+        label.description = Some("a".repeat(AddressBookLabel::MAX_DESCRIPTION_LEN + 1));
+        assert!(label.validate().is_err());
+    }
+
+    #[test]
+    fn keys_are_case_insensitive() {
+        assert_eq!(
+            AddressBookLabel::key("Vendor"),
+            AddressBookLabel::key("vendor")
+        );
+    }
+}
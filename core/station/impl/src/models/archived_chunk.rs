@@ -0,0 +1,26 @@
+use super::ArchiveHead;
+use orbit_essentials::{storable, types::Timestamp};
+
+/// A chunk of the settled history kept in the station when no archive canister is configured.
+///
+/// The chunk is stored as the candid encoding of its `ArchiveChunk`, so that it is downloaded as is
+/// and its hash can be verified against the previous chunk.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArchivedChunk {
+    pub sequence: u64,
+    pub hash: Vec<u8>,
+    pub blob: Vec<u8>,
+    pub archived_at: Timestamp,
+}
+
+impl ArchivedChunk {
+    /// The head that the next chunk kept in the station is chained to.
+    pub fn to_head(&self) -> ArchiveHead {
+        ArchiveHead {
+            sequence: self.sequence,
+            hash: self.hash.clone(),
+            archived_at: self.archived_at,
+        }
+    }
+}
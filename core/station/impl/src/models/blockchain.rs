@@ -26,7 +26,11 @@ impl Blockchain {
     pub fn supported_standards(&self) -> Vec<BlockchainStandard> {
         match self {
             Blockchain::InternetComputer => {
-                vec![BlockchainStandard::Native, BlockchainStandard::ICRC1]
+                vec![
+                    BlockchainStandard::Native,
+                    BlockchainStandard::ICRC1,
+                    BlockchainStandard::ICRC7,
+                ]
             }
             Blockchain::Ethereum => vec![BlockchainStandard::Native, BlockchainStandard::ERC20],
             Blockchain::Bitcoin => vec![BlockchainStandard::Native],
@@ -57,6 +61,43 @@ impl Display for Blockchain {
     }
 }
 
+/// The network of an account, selected when the account is created, which decides the ledgers, minters
+/// and nodes that the blockchain adapter of the account reaches.
+///
+/// On the Internet Computer the testnet profile holds the test tokens hosted on mainnet (e.g. ckTESTBTC),
+/// whereas the local profile targets the canisters of a local replica.
+#[storable]
+#[derive(CandidType, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NetworkProfile {
+    #[default]
+    Mainnet,
+    Testnet,
+    Local,
+}
+
+impl FromStr for NetworkProfile {
+    type Err = ();
+
+    fn from_str(variant: &str) -> Result<NetworkProfile, Self::Err> {
+        match variant {
+            "mainnet" => Ok(NetworkProfile::Mainnet),
+            "testnet" => Ok(NetworkProfile::Testnet),
+            "local" => Ok(NetworkProfile::Local),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for NetworkProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkProfile::Mainnet => write!(f, "mainnet"),
+            NetworkProfile::Testnet => write!(f, "testnet"),
+            NetworkProfile::Local => write!(f, "local"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +115,19 @@ mod tests {
         assert_eq!(Blockchain::from_str("btc").unwrap(), Blockchain::Bitcoin);
     }
 
+    #[test]
+    fn network_profile_match_string_representation() {
+        for network in [
+            NetworkProfile::Mainnet,
+            NetworkProfile::Testnet,
+            NetworkProfile::Local,
+        ] {
+            assert_eq!(NetworkProfile::from_str(&network.to_string()), Ok(network));
+        }
+
+        assert_eq!(NetworkProfile::default(), NetworkProfile::Mainnet);
+    }
+
     #[test]
     fn match_native_symbols_successfully() {
         assert_eq!(Blockchain::InternetComputer.native_symbol(), "ICP");
@@ -89,6 +143,9 @@ mod tests {
         assert!(Blockchain::InternetComputer
             .supported_standards()
             .contains(&BlockchainStandard::ICRC1));
+        assert!(Blockchain::InternetComputer
+            .supported_standards()
+            .contains(&BlockchainStandard::ICRC7));
         assert!(Blockchain::Ethereum
             .supported_standards()
             .contains(&BlockchainStandard::Native));
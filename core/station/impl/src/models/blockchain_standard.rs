@@ -11,6 +11,8 @@ pub enum BlockchainStandard {
     Native,
     ICRC1,
     ERC20,
+    /// The ICRC-7 NFT standard, the balance of the account is the number of tokens it holds.
+    ICRC7,
 }
 
 impl FromStr for BlockchainStandard {
@@ -21,6 +23,7 @@ impl FromStr for BlockchainStandard {
             "native" => Ok(BlockchainStandard::Native),
             "icrc1" => Ok(BlockchainStandard::ICRC1),
             "erc20" => Ok(BlockchainStandard::ERC20),
+            "icrc7" => Ok(BlockchainStandard::ICRC7),
             _ => Err(()),
         }
     }
@@ -32,6 +35,7 @@ impl Display for BlockchainStandard {
             BlockchainStandard::Native => write!(f, "native"),
             BlockchainStandard::ERC20 => write!(f, "erc20"),
             BlockchainStandard::ICRC1 => write!(f, "icrc1"),
+            BlockchainStandard::ICRC7 => write!(f, "icrc7"),
         }
     }
 }
@@ -57,5 +61,10 @@ mod tests {
             BlockchainStandard::from_str("erc20").unwrap(),
             BlockchainStandard::ERC20
         );
+        assert_eq!(BlockchainStandard::ICRC7.to_string(), "icrc7");
+        assert_eq!(
+            BlockchainStandard::from_str("icrc7").unwrap(),
+            BlockchainStandard::ICRC7
+        );
     }
 }
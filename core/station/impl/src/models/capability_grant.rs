@@ -0,0 +1,173 @@
+use super::{AccountId, CreateCapabilityGrantOperationInput, UserId};
+use crate::errors::CapabilityGrantError;
+use candid::Principal;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The capability grant id, which is a UUID.
+pub type CapabilityGrantId = UUID;
+
+/// A revocable grant that lets a principal that is not a station user (e.g. the canister of a
+/// budgeting app) read one account until it expires.
+///
+/// The grant only covers the queries of its scopes, every other call of the grantee is authorized
+/// with the regular permissions.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CapabilityGrant {
+    pub id: CapabilityGrantId,
+    pub account_id: AccountId,
+    pub grantee: Principal,
+    pub scopes: Vec<CapabilityScope>,
+    pub expires_at: Timestamp,
+    pub created_by: UserId,
+    pub revoked_at: Option<Timestamp>,
+    pub created_timestamp: Timestamp,
+    pub last_modification_timestamp: Timestamp,
+}
+
+/// The queries of the account that a capability grant gives access to.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CapabilityScope {
+    /// The transfers sent from the account, with `list_account_transfers`.
+    Transfers,
+    /// The balances of the account, with `fetch_account_balances`.
+    Balances,
+}
+
+impl ModelKey<CapabilityGrantId> for CapabilityGrant {
+    fn key(&self) -> CapabilityGrantId {
+        self.id
+    }
+}
+
+impl CapabilityGrant {
+    /// The longest a grant can be valid for, so that forgotten grants eventually stop working.
+    pub const MAX_DURATION_NS: u64 = 365 * 24 * 60 * 60 * 1_000_000_000;
+
+    /// Creates the grant of the operation input, with its scopes sorted and deduplicated.
+    pub fn new(
+        id: CapabilityGrantId,
+        input: CreateCapabilityGrantOperationInput,
+        created_by: UserId,
+        now: Timestamp,
+    ) -> Self {
+        let mut scopes = input.scopes;
+        scopes.sort();
+        scopes.dedup();
+
+        Self {
+            id,
+            account_id: input.account_id,
+            grantee: input.grantee,
+            scopes,
+            expires_at: input.expires_at,
+            created_by,
+            revoked_at: None,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        }
+    }
+
+    /// Whether the grant gives access to the scope of the account at the given time.
+    pub fn allows(&self, account_id: &AccountId, scope: &CapabilityScope, now: Timestamp) -> bool {
+        self.is_active(now) && self.account_id == *account_id && self.scopes.contains(scope)
+    }
+
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+}
+
+impl ModelValidator<CapabilityGrantError> for CapabilityGrant {
+    fn validate(&self) -> ModelValidatorResult<CapabilityGrantError> {
+        if self.grantee == Principal::anonymous() {
+            return Err(CapabilityGrantError::ValidationError {
+                info: "The grantee cannot be the anonymous principal.".to_string(),
+            });
+        }
+
+        if self.scopes.is_empty() {
+            return Err(CapabilityGrantError::ValidationError {
+                info: "The grant must have at least one scope.".to_string(),
+            });
+        }
+
+        if self.expires_at <= self.created_timestamp
+            || self.expires_at - self.created_timestamp > Self::MAX_DURATION_NS
+        {
+            return Err(CapabilityGrantError::InvalidExpiration {
+                max_duration_days: Self::MAX_DURATION_NS / (24 * 60 * 60 * 1_000_000_000),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::capability_grant_test_utils::mock_capability_grant;
+    use super::*;
+
+    #[test]
+    fn grant_only_allows_its_account_and_scopes() {
+        let grant = mock_capability_grant();
+
+        assert!(grant.allows(&grant.account_id, &CapabilityScope::Transfers, 0));
+        assert!(!grant.allows(&grant.account_id, &CapabilityScope::Balances, 0));
+        assert!(!grant.allows(&[9; 16], &CapabilityScope::Transfers, 0));
+    }
+
+    #[test]
+    fn expired_or_revoked_grant_allows_nothing() {
+        let mut grant = mock_capability_grant();
+
+        assert!(!grant.allows(
+            &grant.account_id,
+            &CapabilityScope::Transfers,
+            grant.expires_at
+        ));
+
+        grant.revoked_at = Some(0);
+
+        assert!(!grant.allows(&grant.account_id, &CapabilityScope::Transfers, 0));
+    }
+
+    #[test]
+    fn fail_grant_beyond_the_max_duration() {
+        let mut grant = mock_capability_grant();
+        grant.expires_at = grant.created_timestamp + CapabilityGrant::MAX_DURATION_NS + 1;
+
+        assert_eq!(
+            grant.validate(),
+            Err(CapabilityGrantError::InvalidExpiration {
+                max_duration_days: 365
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod capability_grant_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_capability_grant() -> CapabilityGrant {
+        CapabilityGrant {
+            id: *Uuid::new_v4().as_bytes(),
+            account_id: [1; 16],
+            grantee: Principal::from_slice(&[5; 29]),
+            scopes: vec![CapabilityScope::Transfers],
+            expires_at: 24 * 60 * 60 * 1_000_000_000,
+            created_by: [2; 16],
+            revoked_at: None,
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
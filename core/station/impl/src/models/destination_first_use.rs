@@ -0,0 +1,97 @@
+use super::TransferId;
+use candid::Deserialize;
+use orbit_essentials::{storable, types::Timestamp};
+
+/// The first completed transfer to a destination address, which tells the reviewers whether the
+/// transfers of a request go to an address that was never paid before.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DestinationFirstUse {
+    pub address: String,
+    pub transfer_id: TransferId,
+    pub first_used_at: Timestamp,
+}
+
+impl DestinationFirstUse {
+    /// The destinations first used within this period are flagged as recent to the reviewers.
+    pub const RECENT_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+    pub fn to_key(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Returns the age in days of the destination at the given time.
+    pub fn age_days(&self, now: Timestamp) -> u64 {
+        now.saturating_sub(self.first_used_at) / (24 * 60 * 60 * 1_000_000_000)
+    }
+}
+
+/// Flags the destination of a transfer request that the reviewers should scrutinize.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum DestinationWarning {
+    /// The destination was never paid before the request.
+    NeverUsed,
+    /// The destination was first paid within the recent period.
+    RecentlyUsed { first_used_at: Timestamp },
+}
+
+impl DestinationWarning {
+    /// Returns the warning of the destination given its first use, the transfer of the request is
+    /// ignored so that the request keeps its warning once executed.
+    pub fn new(
+        first_use: Option<DestinationFirstUse>,
+        transfer_id: Option<TransferId>,
+        now: Timestamp,
+    ) -> Option<Self> {
+        match first_use {
+            None => Some(DestinationWarning::NeverUsed),
+            Some(first_use) if Some(first_use.transfer_id) == transfer_id => {
+                Some(DestinationWarning::NeverUsed)
+            }
+            Some(first_use)
+                if now.saturating_sub(first_use.first_used_at) < DestinationFirstUse::RECENT_NS =>
+            {
+                Some(DestinationWarning::RecentlyUsed {
+                    first_used_at: first_use.first_used_at,
+                })
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_about_new_and_recent_destinations() {
+        let day_ns = 24 * 60 * 60 * 1_000_000_000;
+        let now = 30 * day_ns;
+        let first_use = |first_used_at: Timestamp| DestinationFirstUse {
+            address: "0x1234".to_string(),
+            transfer_id: [1; 16],
+            first_used_at,
+        };
+
+        assert_eq!(
+            DestinationWarning::new(None, None, now),
+            Some(DestinationWarning::NeverUsed)
+        );
+        assert_eq!(
+            DestinationWarning::new(Some(first_use(now - day_ns)), None, now),
+            Some(DestinationWarning::RecentlyUsed {
+                first_used_at: now - day_ns
+            })
+        );
+        assert_eq!(
+            DestinationWarning::new(Some(first_use(now - day_ns)), Some([1; 16]), now),
+            Some(DestinationWarning::NeverUsed)
+        );
+        assert_eq!(
+            DestinationWarning::new(Some(first_use(now - 10 * day_ns)), None, now),
+            None
+        );
+        assert_eq!(first_use(now - 10 * day_ns).age_days(now), 10);
+    }
+}
@@ -0,0 +1,96 @@
+use num_bigint::BigUint;
+use orbit_essentials::types::Timestamp;
+use std::fmt::{Display, Formatter};
+
+/// The fiat currencies that the balances and transfers are valued in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FiatCurrency {
+    USD,
+    EUR,
+}
+
+impl FiatCurrency {
+    pub const ALL: [FiatCurrency; 2] = [FiatCurrency::USD, FiatCurrency::EUR];
+}
+
+impl Display for FiatCurrency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FiatCurrency::USD => write!(f, "USD"),
+            FiatCurrency::EUR => write!(f, "EUR"),
+        }
+    }
+}
+
+/// The rate of an asset in a fiat currency, as returned by the exchange rate canister.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExchangeRate {
+    /// The symbol of the asset (e.g. `ICP`).
+    pub symbol: String,
+    pub currency: FiatCurrency,
+    /// The price of one unit of the asset, scaled by `10^decimals`.
+    pub rate: u64,
+    pub decimals: u32,
+    /// The time of the rate, in nanoseconds since the epoch.
+    pub timestamp: Timestamp,
+}
+
+/// The value of an amount of an asset in a fiat currency.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FiatValue {
+    pub currency: FiatCurrency,
+    /// The value, scaled by `10^decimals`.
+    pub value: candid::Nat,
+    pub decimals: u32,
+    /// The time of the rate the value was computed with.
+    pub rate_timestamp: Timestamp,
+}
+
+impl ExchangeRate {
+    /// The fiat values are expressed in cents.
+    pub const FIAT_DECIMALS: u32 = 2;
+
+    /// Returns the fiat value of the amount, given in the smallest unit of the asset.
+    pub fn fiat_value(&self, amount: &candid::Nat, amount_decimals: u32) -> FiatValue {
+        let value = amount.0.clone() * self.rate * BigUint::from(10u32).pow(Self::FIAT_DECIMALS)
+            / BigUint::from(10u32).pow(amount_decimals + self.decimals);
+
+        FiatValue {
+            currency: self.currency,
+            value: candid::Nat(value),
+            decimals: Self::FIAT_DECIMALS,
+            rate_timestamp: self.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fiat_value_is_expressed_in_cents() {
+        let rate = ExchangeRate {
+            symbol: "ICP".to_string(),
+            currency: FiatCurrency::USD,
+            // 8.25 USD
+            rate: 8_250_000_000,
+            decimals: 9,
+            timestamp: 1,
+        };
+
+        // 2.5 ICP
+        let value = rate.fiat_value(&candid::Nat::from(250_000_000u64), 8);
+
+        assert_eq!(value.currency, FiatCurrency::USD);
+        assert_eq!(value.value, candid::Nat::from(2_062u64));
+        assert_eq!(value.decimals, ExchangeRate::FIAT_DECIMALS);
+        assert_eq!(value.rate_timestamp, 1);
+    }
+
+    #[test]
+    fn match_string_representation() {
+        assert_eq!(FiatCurrency::USD.to_string(), "USD");
+        assert_eq!(FiatCurrency::EUR.to_string(), "EUR");
+    }
+}
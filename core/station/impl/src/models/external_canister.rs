@@ -8,7 +8,7 @@ use super::{
     CanisterMethod, ConfigureExternalCanisterSettingsInput, CreateExternalCanisterOperationInput,
     CreateExternalCanisterOperationKind, ExternalCanisterChangeCallRequestPoliciesInput,
     ExternalCanisterRequestPoliciesCreateInput, ExternalCanisterRequestPoliciesUpdateInput,
-    RequestPolicy, RequestPolicyRule,
+    RequestPolicy, RequestPolicyRule, UserId,
 };
 use crate::errors::{ExternalCanisterError, ExternalCanisterValidationError};
 use crate::repositories::REQUEST_POLICY_REPOSITORY;
@@ -51,6 +51,12 @@ pub struct ExternalCanister {
     pub created_at: Timestamp,
     /// The last time the record was updated.
     pub modified_at: Option<Timestamp>,
+    /// The monitoring of the canister, if enabled.
+    #[serde(default)]
+    pub monitoring: Option<ExternalCanisterMonitoring>,
+    /// The hash of the module last installed through a `ChangeExternalCanister` request.
+    #[serde(default)]
+    pub module_hash: Option<Vec<u8>>,
 }
 
 #[storable]
@@ -106,6 +112,128 @@ pub enum ExternalCanisterState {
     Archived,
 }
 
+/// The rules that are periodically evaluated against the status of the canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterMonitoringRules {
+    /// Notifies when the cycles balance of the canister falls below this threshold.
+    pub cycles_threshold: Option<u64>,
+    /// Notifies when the canister is stopping or stopped.
+    pub notify_on_stopped: bool,
+    /// Notifies when the module hash of the canister changes.
+    pub notify_on_module_hash_change: bool,
+    /// Creates a `FundExternalCanister` request with these cycles when the cycles threshold is crossed.
+    pub auto_fund_cycles: Option<u64>,
+}
+
+/// The monitoring of an external canister, with the rules and the last observed state of the canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterMonitoring {
+    pub rules: ExternalCanisterMonitoringRules,
+    /// The user that configured the rules, used as the requester of the automatically created requests.
+    pub configured_by: UserId,
+    /// The last time the status of the canister was checked.
+    pub last_checked_at: Option<Timestamp>,
+    /// The module hash observed on the last check.
+    pub last_module_hash: Option<Vec<u8>>,
+    /// Whether the cycles are below the threshold since the last notification, to only notify once.
+    pub low_cycles_alerted: bool,
+    /// Whether the canister is stopped since the last notification, to only notify once.
+    pub stopped_alerted: bool,
+}
+
+impl ExternalCanisterMonitoring {
+    pub fn new(rules: ExternalCanisterMonitoringRules, configured_by: UserId) -> Self {
+        Self {
+            rules,
+            configured_by,
+            last_checked_at: None,
+            last_module_hash: None,
+            low_cycles_alerted: false,
+            stopped_alerted: false,
+        }
+    }
+
+    /// Records the observed status of the canister and returns the events that match the rules.
+    ///
+    /// Low cycles and stopped events are only returned once until the condition clears, and module hash
+    /// changes are only detected once a previous module hash was observed.
+    pub fn observe(
+        &mut self,
+        cycles: u64,
+        is_stopped: bool,
+        module_hash: Option<Vec<u8>>,
+        observed_at: Timestamp,
+    ) -> Vec<ExternalCanisterMonitoringEvent> {
+        let mut events = Vec::new();
+
+        match self.rules.cycles_threshold {
+            Some(threshold) if cycles < threshold => {
+                if !self.low_cycles_alerted {
+                    events.push(ExternalCanisterMonitoringEvent::LowCycles {
+                        cycles,
+                        threshold,
+                        fund_request_id: None,
+                    });
+                }
+
+                self.low_cycles_alerted = true;
+            }
+            _ => self.low_cycles_alerted = false,
+        }
+
+        if self.rules.notify_on_stopped && is_stopped {
+            if !self.stopped_alerted {
+                events.push(ExternalCanisterMonitoringEvent::Stopped);
+            }
+
+            self.stopped_alerted = true;
+        } else {
+            self.stopped_alerted = false;
+        }
+
+        if self.rules.notify_on_module_hash_change
+            && self.last_checked_at.is_some()
+            && self.last_module_hash != module_hash
+        {
+            events.push(ExternalCanisterMonitoringEvent::ModuleHashChanged {
+                previous: self.last_module_hash.clone(),
+                current: module_hash.clone(),
+            });
+        }
+
+        self.last_module_hash = module_hash;
+        self.last_checked_at = Some(observed_at);
+
+        events
+    }
+}
+
+/// An event detected by the monitoring of an external canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ExternalCanisterMonitoringEvent {
+    LowCycles {
+        cycles: u64,
+        threshold: u64,
+        /// The funding request that was automatically created, if enabled.
+        fund_request_id: Option<UUID>,
+    },
+    Stopped,
+    ModuleHashChanged {
+        previous: Option<Vec<u8>>,
+        current: Option<Vec<u8>>,
+    },
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ExternalCanisterMonitoringInput {
+    Enable(ExternalCanisterMonitoringRules),
+    Disable,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ExternalCanisterCallerMethodsPrivileges {
     pub validation_method: ValidationMethodResourceTarget,
@@ -129,6 +257,17 @@ impl ExternalCanister {
     pub const MAX_LABELS: usize = 10;
     pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
 
+    /// Records the module installed by a request, which the monitoring then does not report as an
+    /// unexpected module hash change.
+    pub fn record_installed_module(&mut self, module_hash: Vec<u8>, installed_at: Timestamp) {
+        if let Some(monitoring) = self.monitoring.as_mut() {
+            monitoring.last_module_hash = Some(module_hash.clone());
+        }
+
+        self.module_hash = Some(module_hash);
+        self.modified_at = Some(installed_at);
+    }
+
     /// Checks if the external canister is archived.
     pub fn is_archived(&self) -> bool {
         self.state == ExternalCanisterState::Archived
@@ -221,12 +360,36 @@ fn validate_labels(labels: &[String]) -> ModelValidatorResult<ExternalCanisterEr
     Ok(())
 }
 
+fn validate_monitoring_rules(
+    rules: &ExternalCanisterMonitoringRules,
+) -> ModelValidatorResult<ExternalCanisterError> {
+    if rules.auto_fund_cycles.is_some() && rules.cycles_threshold.is_none() {
+        return Err(ExternalCanisterError::ValidationError {
+            info: "The auto funding of the external canister requires a cycles threshold."
+                .to_string(),
+        });
+    }
+
+    if rules.auto_fund_cycles == Some(0) {
+        return Err(ExternalCanisterError::ValidationError {
+            info: "The auto funding cycles of the external canister must be greater than zero."
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
 impl ModelValidator<ExternalCanisterError> for ExternalCanister {
     fn validate(&self) -> ModelValidatorResult<ExternalCanisterError> {
         validate_name(&self.name)?;
         validate_description(&self.description)?;
         validate_labels(&self.labels)?;
 
+        if let Some(monitoring) = &self.monitoring {
+            validate_monitoring_rules(&monitoring.rules)?;
+        }
+
         Ok(())
     }
 }
@@ -431,6 +594,8 @@ pub mod external_canister_test_utils {
             state: ExternalCanisterState::Active,
             created_at: next_time(),
             modified_at: None,
+            monitoring: None,
+            module_hash: None,
         }
     }
 }
@@ -563,6 +728,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn monitoring_notifies_events_once_until_cleared() {
+        let mut monitoring = ExternalCanisterMonitoring::new(
+            ExternalCanisterMonitoringRules {
+                cycles_threshold: Some(1_000),
+                notify_on_stopped: true,
+                notify_on_module_hash_change: true,
+                auto_fund_cycles: None,
+            },
+            [1; 16],
+        );
+
+        // the first check only records the module hash
+        let events = monitoring.observe(500, true, Some(vec![1]), 1);
+        assert_eq!(
+            events,
+            vec![
+                ExternalCanisterMonitoringEvent::LowCycles {
+                    cycles: 500,
+                    threshold: 1_000,
+                    fund_request_id: None,
+                },
+                ExternalCanisterMonitoringEvent::Stopped,
+            ]
+        );
+
+        // the same conditions are not notified again
+        let events = monitoring.observe(400, true, Some(vec![2]), 2);
+        assert_eq!(
+            events,
+            vec![ExternalCanisterMonitoringEvent::ModuleHashChanged {
+                previous: Some(vec![1]),
+                current: Some(vec![2]),
+            }]
+        );
+
+        // once cleared, the conditions are notified again
+        assert!(monitoring
+            .observe(2_000, false, Some(vec![2]), 3)
+            .is_empty());
+        assert_eq!(monitoring.observe(500, false, Some(vec![2]), 4).len(), 1);
+        assert_eq!(monitoring.last_checked_at, Some(4));
+    }
+
+    #[test]
+    fn installed_module_is_not_reported_as_changed() {
+        let mut external_canister = mock_external_canister();
+        let mut monitoring = ExternalCanisterMonitoring::new(
+            ExternalCanisterMonitoringRules {
+                cycles_threshold: None,
+                notify_on_stopped: false,
+                notify_on_module_hash_change: true,
+                auto_fund_cycles: None,
+            },
+            [1; 16],
+        );
+        monitoring.observe(1_000, false, Some(vec![1]), 1);
+        external_canister.monitoring = Some(monitoring);
+
+        external_canister.record_installed_module(vec![2], 2);
+
+        assert_eq!(external_canister.module_hash, Some(vec![2]));
+        assert_eq!(external_canister.modified_at, Some(2));
+
+        let monitoring = external_canister.monitoring.as_mut().unwrap();
+        assert!(monitoring
+            .observe(1_000, false, Some(vec![2]), 3)
+            .is_empty());
+        assert_eq!(monitoring.observe(1_000, false, Some(vec![3]), 4).len(), 1);
+    }
+
+    #[test]
+    fn invalid_external_canister_validation_with_auto_fund_without_threshold() {
+        let result = validate_monitoring_rules(&ExternalCanisterMonitoringRules {
+            cycles_threshold: None,
+            notify_on_stopped: true,
+            notify_on_module_hash_change: true,
+            auto_fund_cycles: Some(1_000_000_000_000),
+        });
+
+        assert!(result.is_err());
+        assert!(validate_monitoring_rules(&ExternalCanisterMonitoringRules {
+            cycles_threshold: Some(500_000_000_000),
+            notify_on_stopped: false,
+            notify_on_module_hash_change: false,
+            auto_fund_cycles: Some(1_000_000_000_000),
+        })
+        .is_ok());
+    }
+
     #[test]
     fn update_existing_model_with_changes() {
         let mut model = mock_external_canister();
@@ -573,6 +828,7 @@ mod tests {
             permissions: None,
             request_policies: None,
             state: Some(ExternalCanisterState::Archived),
+            monitoring: None,
         };
 
         model.update_with(changes);
@@ -0,0 +1,154 @@
+use super::{AccountId, IcrcAccount, RequestId, UserId};
+use crate::errors::FundingRequestError;
+use candid::Principal;
+use orbit_essentials::model::{ModelKey, ModelValidator, ModelValidatorResult};
+use orbit_essentials::storable;
+use orbit_essentials::types::{Timestamp, UUID};
+
+/// The funding request id, which is a UUID.
+pub type FundingRequestId = UUID;
+
+/// A request for an external payer to fund a station account with ICRC-2.
+///
+/// The payer approves the station account as the spender of the `approve_amount` on the ledger, once the
+/// allowance is observed a transfer request pulling the `amount` with `icrc2_transfer_from` is created, the
+/// funds are then collected after that request is approved like any other transfer.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FundingRequest {
+    pub id: FundingRequestId,
+    /// The station account that receives the funds.
+    pub account_id: AccountId,
+    /// The ledger account of the payer, which approves the allowance.
+    pub payer: IcrcAccount,
+    /// The amount to collect, in the smallest unit of the asset.
+    pub amount: candid::Nat,
+    /// The ledger fee paid by the payer when the funds are pulled.
+    pub fee: candid::Nat,
+    /// The ledger that the allowance is approved on.
+    pub ledger_canister_id: Principal,
+    /// The spender account that the payer approves, which is the ledger account of the station account.
+    pub spender: IcrcAccount,
+    /// A free form reference for the payer (e.g. an invoice number).
+    pub reference: Option<String>,
+    pub status: FundingRequestStatus,
+    pub requested_by: UserId,
+    /// The time after which the allowance is no longer watched.
+    pub expires_at: Timestamp,
+    pub created_timestamp: Timestamp,
+    pub last_modification_timestamp: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FundingRequestStatus {
+    /// The allowance of the payer is being watched.
+    Pending,
+    /// The allowance was observed and the transfer request that collects the funds was created.
+    CollectRequested { request_id: RequestId },
+    /// The allowance was not observed before the funding request expired.
+    Expired,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FundingRequestKey {
+    pub id: FundingRequestId,
+}
+
+impl ModelKey<FundingRequestKey> for FundingRequest {
+    fn key(&self) -> FundingRequestKey {
+        FundingRequestKey { id: self.id }
+    }
+}
+
+impl FundingRequest {
+    pub const MAX_REFERENCE_LENGTH: usize = 100;
+    /// The default time the allowance of the payer is watched for.
+    pub const DEFAULT_EXPIRATION_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+    /// The amount the payer must approve, which covers the ledger fee of `icrc2_transfer_from`.
+    pub fn approve_amount(&self) -> candid::Nat {
+        self.amount.clone() + self.fee.clone()
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == FundingRequestStatus::Pending
+    }
+}
+
+impl ModelValidator<FundingRequestError> for FundingRequest {
+    fn validate(&self) -> ModelValidatorResult<FundingRequestError> {
+        if self.amount == candid::Nat::from(0u64) {
+            return Err(FundingRequestError::ValidationError {
+                info: "The amount must be greater than zero".to_string(),
+            });
+        }
+
+        if let Some(reference) = &self.reference {
+            if reference.len() > Self::MAX_REFERENCE_LENGTH {
+                return Err(FundingRequestError::ValidationError {
+                    info: format!(
+                        "The reference must be at most {} characters long",
+                        Self::MAX_REFERENCE_LENGTH
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::funding_request_test_utils::mock_funding_request;
+    use super::*;
+
+    #[test]
+    fn approve_amount_covers_the_fee() {
+        let funding_request = mock_funding_request();
+
+        assert_eq!(
+            funding_request.approve_amount(),
+            candid::Nat::from(100_010_000u64)
+        );
+    }
+
+    #[test]
+    fn fail_zero_amount_and_long_reference() {
+        let mut funding_request = mock_funding_request();
+        funding_request.amount = candid::Nat::from(0u64);
+
+        assert!(funding_request.validate().is_err());
+
+        let mut funding_request = mock_funding_request();
+        funding_request.reference = Some("a".repeat(FundingRequest::MAX_REFERENCE_LENGTH + 1));
+
+        assert!(funding_request.validate().is_err());
+    }
+}
+
+#[cfg(test)]
+pub mod funding_request_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_funding_request() -> FundingRequest {
+        FundingRequest {
+            id: *Uuid::new_v4().as_bytes(),
+            account_id: *Uuid::new_v4().as_bytes(),
+            payer: IcrcAccount::new(Principal::from_slice(&[1; 29]), None),
+            amount: candid::Nat::from(100_000_000u64),
+            fee: candid::Nat::from(10_000u64),
+            ledger_canister_id: Principal::from_slice(&[2; 10]),
+            spender: IcrcAccount::new(Principal::from_slice(&[3; 10]), Some([4; 32])),
+            reference: Some("INV-001".to_string()),
+            status: FundingRequestStatus::Pending,
+            requested_by: *Uuid::new_v4().as_bytes(),
+            expires_at: FundingRequest::DEFAULT_EXPIRATION_NS,
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use candid::Principal;
+use orbit_essentials::storable;
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// The subaccount of an ICRC-1 account.
+pub type IcrcSubaccount = [u8; 32];
+
+/// An account of an ICRC-1 ledger, made of an owner and an optional subaccount.
+///
+/// The textual representation follows the ICRC-1 encoding, `<owner>-<checksum>.<subaccount>`, where the
+/// checksum and subaccount parts are omitted for the default subaccount.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IcrcAccount {
+    pub owner: Principal,
+    pub subaccount: Option<IcrcSubaccount>,
+}
+
+impl IcrcAccount {
+    pub const DEFAULT_SUBACCOUNT: IcrcSubaccount = [0; 32];
+
+    pub fn new(owner: Principal, subaccount: Option<IcrcSubaccount>) -> Self {
+        Self { owner, subaccount }
+    }
+
+    /// Returns the subaccount, where the default subaccount is always `None`.
+    pub fn effective_subaccount(&self) -> Option<IcrcSubaccount> {
+        self.subaccount
+            .filter(|subaccount| *subaccount != Self::DEFAULT_SUBACCOUNT)
+    }
+
+    fn checksum(&self, subaccount: &IcrcSubaccount) -> String {
+        let mut bytes = self.owner.as_slice().to_vec();
+        bytes.extend_from_slice(subaccount);
+
+        base32_encode(&crc32(&bytes).to_be_bytes())
+    }
+}
+
+impl Display for IcrcAccount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.effective_subaccount() {
+            None => write!(f, "{}", self.owner),
+            Some(subaccount) => write!(
+                f,
+                "{}-{}.{}",
+                self.owner,
+                self.checksum(&subaccount),
+                hex::encode(subaccount).trim_start_matches('0')
+            ),
+        }
+    }
+}
+
+impl FromStr for IcrcAccount {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some((owner_and_checksum, subaccount)) = value.split_once('.') else {
+            let owner = Principal::from_text(value)
+                .map_err(|err| format!("invalid account owner: {}", err))?;
+
+            return Ok(Self::new(owner, None));
+        };
+
+        let (owner, checksum) = owner_and_checksum
+            .rsplit_once('-')
+            .ok_or("missing account checksum".to_string())?;
+        let owner =
+            Principal::from_text(owner).map_err(|err| format!("invalid account owner: {}", err))?;
+
+        if subaccount.is_empty() || subaccount.len() > 64 || subaccount.starts_with('0') {
+            return Err("invalid account subaccount".to_string());
+        }
+
+        let subaccount_bytes = hex::decode(format!("{:0>64}", subaccount))
+            .map_err(|err| format!("invalid account subaccount: {}", err))?;
+        let mut padded_subaccount = Self::DEFAULT_SUBACCOUNT;
+        padded_subaccount.copy_from_slice(&subaccount_bytes);
+
+        let account = Self::new(owner, Some(padded_subaccount));
+
+        if account.checksum(&padded_subaccount) != checksum {
+            return Err("invalid account checksum".to_string());
+        }
+
+        Ok(account)
+    }
+}
+
+/// The CRC-32 (ISO-HDLC) checksum used by the ICRC-1 textual encoding.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+
+    for byte in bytes {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            let mask = (!(crc & 1)).wrapping_add(1);
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+/// Encodes the bytes in lowercase RFC 4648 base32, without padding.
+fn base32_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+    let mut encoded = String::new();
+    let mut buffer = 0_u32;
+    let mut bits = 0_u32;
+
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+
+    if bits > 0 {
+        encoded.push(ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_subaccount_is_encoded_as_principal() {
+        let owner =
+            Principal::from_text("k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae")
+                .unwrap();
+        let account = IcrcAccount::new(owner, Some(IcrcAccount::DEFAULT_SUBACCOUNT));
+
+        assert_eq!(account.to_string(), owner.to_text());
+        assert_eq!(
+            IcrcAccount::from_str(&owner.to_text()).unwrap(),
+            IcrcAccount::new(owner, None)
+        );
+    }
+
+    #[test]
+    fn encodes_subaccount_with_checksum() {
+        let owner =
+            Principal::from_text("k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae")
+                .unwrap();
+        let mut subaccount = IcrcAccount::DEFAULT_SUBACCOUNT;
+        subaccount[31] = 1;
+
+        let account = IcrcAccount::new(owner, Some(subaccount));
+
+        assert_eq!(
+            account.to_string(),
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1"
+        );
+        assert_eq!(
+            IcrcAccount::from_str(&account.to_string()).unwrap(),
+            account
+        );
+    }
+
+    #[test]
+    fn fail_invalid_checksum() {
+        let result = IcrcAccount::from_str(
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-aaaaaaa.1",
+        );
+
+        assert!(result.is_err());
+    }
+}
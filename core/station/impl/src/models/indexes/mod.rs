@@ -2,7 +2,12 @@ pub mod notification_user_index;
 pub mod request_index;
 pub mod request_policy_resource_index;
 pub mod request_resource_index;
+pub mod request_term_index;
 pub mod transfer_account_index;
+pub mod transfer_account_status_index;
+pub mod transfer_category_index;
+pub mod transfer_destination_index;
+pub mod transfer_metadata_index;
 pub mod transfer_status_index;
 pub mod unique_index;
 pub mod user_status_group_index;
@@ -19,6 +19,10 @@ pub struct RequestIndexFields {
     pub approved_by: BTreeSet<UserId>,
     pub rejected_by: BTreeSet<UserId>,
     pub resources: Vec<Resource>,
+    #[serde(default)]
+    pub confidential: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[storable]
@@ -30,6 +34,10 @@ pub enum RequestIndexKeyKind {
     ScheduledAt(Timestamp),
     // Always created for each request, with the status of the request
     Status(RequestStatusCode),
+    // Created for each tag of the request
+    Tag(String),
+    // Only created if the request depends on another request, with the id of the dependency
+    DependsOn(RequestId),
 }
 
 #[storable]
@@ -71,6 +79,8 @@ impl Request {
                 })
                 .collect(),
             resources: self.operation.to_resources(),
+            confidential: self.confidential,
+            tags: self.tags.clone(),
         }
     }
 
@@ -110,6 +120,35 @@ impl Request {
         )
     }
 
+    /// Converts the request to an index by its dependency if it depends on another request.
+    fn to_index_by_dependency(&self) -> Option<(RequestIndexKey, RequestIndexFields)> {
+        self.depends_on.map(|dependency_id| {
+            (
+                RequestIndexKey {
+                    kind: RequestIndexKeyKind::DependsOn(dependency_id),
+                    request_id: self.id,
+                },
+                self.index_fields(),
+            )
+        })
+    }
+
+    /// Converts the request to an index for each of its tags.
+    fn to_indexes_by_tag(&self) -> Vec<(RequestIndexKey, RequestIndexFields)> {
+        self.tags
+            .iter()
+            .map(|tag| {
+                (
+                    RequestIndexKey {
+                        kind: RequestIndexKeyKind::Tag(tag.to_owned()),
+                        request_id: self.id,
+                    },
+                    self.index_fields(),
+                )
+            })
+            .collect()
+    }
+
     /// Converts the request to a list of indexes.
     pub fn to_indexes(&self) -> Vec<(RequestIndexKey, RequestIndexFields)> {
         let mut indexes = vec![self.to_index_by_status(), self.to_index_by_created_at()];
@@ -118,6 +157,12 @@ impl Request {
             indexes.push(index);
         }
 
+        indexes.extend(self.to_indexes_by_tag());
+
+        if let Some(index) = self.to_index_by_dependency() {
+            indexes.push(index);
+        }
+
         indexes
     }
 }
@@ -68,6 +68,7 @@ mod tests {
                 identities: vec![],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                approve_only_identities: vec![],
             },
             user_id: None,
         });
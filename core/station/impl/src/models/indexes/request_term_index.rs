@@ -0,0 +1,90 @@
+use crate::models::{Request, RequestId};
+use orbit_essentials::storable;
+use std::collections::BTreeSet;
+
+/// Index of requests by the words of their title and summary, to search them by keywords.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestTermIndex {
+    /// The normalized word found in the title or summary of the request.
+    pub term: String,
+    /// The request id, which is a UUID.
+    pub request_id: RequestId,
+}
+
+#[derive(Clone, Debug)]
+pub struct RequestTermIndexCriteria {
+    /// Matches the terms that start with the prefix, which must be normalized.
+    pub term_prefix: String,
+}
+
+impl RequestTermIndex {
+    /// The words shorter than this are too common to narrow down a search and are not indexed.
+    pub const MIN_TERM_LEN: usize = 2;
+    /// The longer words are truncated, which keeps the index entries bounded.
+    pub const MAX_TERM_LEN: usize = 32;
+
+    /// Splits the text into the distinct lowercase words that are indexed.
+    pub fn terms(text: &str) -> BTreeSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().count() >= Self::MIN_TERM_LEN)
+            .map(|word| {
+                word.chars()
+                    .take(Self::MAX_TERM_LEN)
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .collect()
+    }
+}
+
+impl Request {
+    pub fn to_index_by_terms(&self) -> Vec<RequestTermIndex> {
+        let mut terms = RequestTermIndex::terms(&self.title);
+        if let Some(summary) = &self.summary {
+            terms.extend(RequestTermIndex::terms(summary));
+        }
+
+        terms
+            .into_iter()
+            .map(|term| RequestTermIndex {
+                term,
+                request_id: self.id,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_test_utils::mock_request;
+
+    #[test]
+    fn splits_the_text_into_normalized_terms() {
+        assert_eq!(
+            RequestTermIndex::terms("Marketing payout (March), marketing-team & a #2"),
+            BTreeSet::from([
+                "marketing".to_string(),
+                "payout".to_string(),
+                "march".to_string(),
+                "team".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn indexes_the_title_and_summary() {
+        let mut request = mock_request();
+        request.title = "Marketing payout".to_string();
+        request.summary = Some("For March".to_string());
+
+        let terms = request
+            .to_index_by_terms()
+            .into_iter()
+            .map(|index| index.term)
+            .collect::<Vec<_>>();
+
+        assert_eq!(terms, vec!["for", "march", "marketing", "payout"]);
+    }
+}
@@ -58,6 +58,13 @@ mod tests {
             initiator_user: [2; 16],
             last_modification_timestamp: 0,
             metadata: Metadata::default(),
+            spend_from: None,
+            memo: None,
+            submitted_details: None,
+            failed_attempts: Vec::new(),
+            next_attempt_at: None,
+            category: None,
+            asset_id: None,
         };
 
         let index = transfer.to_index_by_account();
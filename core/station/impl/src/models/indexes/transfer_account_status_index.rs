@@ -0,0 +1,58 @@
+use crate::models::{AccountId, Transfer, TransferId};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::hash::Hash;
+
+/// Represents a transfer index by the account it is sent from and its status.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferAccountStatusIndex {
+    /// The account the transfer is sent from.
+    pub account_id: AccountId,
+    /// The status of the transfer.
+    pub status: String,
+    /// The timestamp of the transfer creation.
+    pub created_timestamp: Timestamp,
+    /// The transfer id, which is a UUID.
+    pub transfer_id: TransferId,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransferAccountStatusIndexCriteria {
+    pub account_id: AccountId,
+    pub status: String,
+    pub from_dt: Option<Timestamp>,
+    pub to_dt: Option<Timestamp>,
+}
+
+impl Transfer {
+    pub fn to_index_by_account_status(&self) -> TransferAccountStatusIndex {
+        TransferAccountStatusIndex {
+            account_id: self.from_account,
+            status: self.status.to_string(),
+            created_timestamp: self.created_timestamp,
+            transfer_id: self.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{transfer_test_utils::mock_transfer, TransferStatus};
+
+    #[test]
+    fn test_transfer_to_index_by_account_status() {
+        let mut transfer = mock_transfer();
+        transfer.from_account = [1; 16];
+        transfer.status = TransferStatus::Failed {
+            reason: "error".to_string(),
+        };
+
+        let index = transfer.to_index_by_account_status();
+
+        assert_eq!(index.account_id, [1; 16]);
+        assert_eq!(index.status, "failed");
+        assert_eq!(index.created_timestamp, transfer.created_timestamp);
+        assert_eq!(index.transfer_id, transfer.id);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::models::{Transfer, TransferId};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::hash::Hash;
+
+/// Represents a transfer index by its category, across the accounts of the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferCategoryIndex {
+    /// The category of the transfer.
+    pub category: String,
+    /// The timestamp of the transfer creation.
+    pub created_timestamp: Timestamp,
+    /// The transfer id, which is a UUID.
+    pub transfer_id: TransferId,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransferCategoryIndexCriteria {
+    pub category: String,
+    pub from_dt: Option<Timestamp>,
+    pub to_dt: Option<Timestamp>,
+}
+
+impl Transfer {
+    pub fn to_index_by_category(&self) -> Option<TransferCategoryIndex> {
+        self.category
+            .as_ref()
+            .map(|category| TransferCategoryIndex {
+                category: category.to_owned(),
+                created_timestamp: self.created_timestamp,
+                transfer_id: self.id,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::transfer_test_utils::mock_transfer;
+
+    #[test]
+    fn test_transfer_to_index_by_category() {
+        let mut transfer = mock_transfer();
+
+        assert!(transfer.to_index_by_category().is_none());
+
+        transfer.category = Some("payroll".to_string());
+
+        let index = transfer.to_index_by_category().unwrap();
+
+        assert_eq!(index.category, "payroll");
+        assert_eq!(index.created_timestamp, transfer.created_timestamp);
+        assert_eq!(index.transfer_id, transfer.id);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models::{AccountId, Transfer, TransferId};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::hash::Hash;
+
+/// Represents a transfer index by the account it is sent from and its destination address.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferDestinationIndex {
+    /// The account the transfer is sent from.
+    pub account_id: AccountId,
+    /// The destination address of the transfer.
+    pub to_address: String,
+    /// The timestamp of the transfer creation.
+    pub created_timestamp: Timestamp,
+    /// The transfer id, which is a UUID.
+    pub transfer_id: TransferId,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransferDestinationIndexCriteria {
+    pub account_id: AccountId,
+    pub to_address: String,
+    pub from_dt: Option<Timestamp>,
+    pub to_dt: Option<Timestamp>,
+}
+
+impl Transfer {
+    pub fn to_index_by_destination(&self) -> TransferDestinationIndex {
+        TransferDestinationIndex {
+            account_id: self.from_account,
+            to_address: self.to_address.clone(),
+            created_timestamp: self.created_timestamp,
+            transfer_id: self.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::transfer_test_utils::mock_transfer;
+
+    #[test]
+    fn test_transfer_to_index_by_destination() {
+        let mut transfer = mock_transfer();
+        transfer.from_account = [1; 16];
+        transfer.to_address = "0x1234".to_string();
+
+        let index = transfer.to_index_by_destination();
+
+        assert_eq!(index.account_id, [1; 16]);
+        assert_eq!(index.to_address, "0x1234");
+        assert_eq!(index.created_timestamp, transfer.created_timestamp);
+        assert_eq!(index.transfer_id, transfer.id);
+    }
+}
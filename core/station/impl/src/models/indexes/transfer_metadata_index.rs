@@ -0,0 +1,73 @@
+use crate::models::{AccountId, Transfer, TransferId, INDEXED_METADATA_KEYS};
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use std::hash::Hash;
+
+/// Represents a transfer index by the value of a well-known metadata key.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferMetadataIndex {
+    /// The account the transfer is sent from.
+    pub account_id: AccountId,
+    /// The metadata key.
+    pub key: String,
+    /// The metadata value.
+    pub value: String,
+    /// The timestamp of the transfer creation.
+    pub created_timestamp: Timestamp,
+    /// The transfer id, which is a UUID.
+    pub transfer_id: TransferId,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransferMetadataIndexCriteria {
+    pub account_id: AccountId,
+    pub key: String,
+    pub value: String,
+    pub from_dt: Option<Timestamp>,
+    pub to_dt: Option<Timestamp>,
+}
+
+impl Transfer {
+    pub fn to_index_by_metadata(&self) -> Vec<TransferMetadataIndex> {
+        INDEXED_METADATA_KEYS
+            .iter()
+            .filter_map(|key| {
+                self.metadata.get(key).map(|value| TransferMetadataIndex {
+                    account_id: self.from_account,
+                    key: key.to_string(),
+                    value,
+                    created_timestamp: self.created_timestamp,
+                    transfer_id: self.id,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{
+        transfer_test_utils::mock_transfer, Metadata, METADATA_CATEGORY_KEY, METADATA_MEMO_KEY,
+    };
+
+    #[test]
+    fn test_transfer_to_index_by_metadata() {
+        let mut transfer = mock_transfer();
+        transfer.metadata = Metadata::new(
+            [
+                (METADATA_CATEGORY_KEY.to_string(), "payroll".to_string()),
+                (METADATA_MEMO_KEY.to_string(), "42".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let indexes = transfer.to_index_by_metadata();
+
+        assert_eq!(indexes.len(), 1);
+        assert_eq!(indexes[0].key, METADATA_CATEGORY_KEY);
+        assert_eq!(indexes[0].value, "payroll");
+        assert_eq!(indexes[0].transfer_id, transfer.id);
+    }
+}
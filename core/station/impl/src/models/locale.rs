@@ -0,0 +1,200 @@
+use crate::errors::LocaleError;
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    storable,
+    types::Timestamp,
+};
+use std::collections::BTreeMap;
+
+/// The locale the messages are rendered in when neither the user nor the station set one.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// The keys of the messages emitted by the station, with their English template.
+///
+/// The templates reference their arguments as `{name}`, which the translations are expected to keep.
+pub const LOCALE_MESSAGES: &[(&str, &str)] = &[
+    (
+        "self_check.passed.title",
+        "Station {version} passed its self-check",
+    ),
+    (
+        "self_check.failed.title",
+        "Station {version} failed its self-check",
+    ),
+    (
+        "self_check.failed.message",
+        "The new version may misbehave, see the report in the system info. Failed checks: {failed_checks}",
+    ),
+    (
+        "blockchain_paused.title",
+        "Transfers on {blockchain} are paused",
+    ),
+    (
+        "blockchain_paused.message",
+        "The transfer executions were paused after repeated ledger failures, the created transfers \
+        are kept until they are resumed. Last failure: {reason}",
+    ),
+    (
+        "pending_vote_reminder.title",
+        "Your vote is pending on: {request_title}",
+    ),
+    (
+        "pending_vote_reminder.message",
+        "The request expires at {expiration_dt} if it is still pending.",
+    ),
+];
+
+/// Returns the English template of the message, if the key is emitted by the station.
+pub fn english_message(key: &str) -> Option<&'static str> {
+    LOCALE_MESSAGES
+        .iter()
+        .find(|(message_key, _)| *message_key == key)
+        .map(|(_, template)| *template)
+}
+
+/// Replaces the `{name}` arguments of the template with their value.
+pub fn render_template(template: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |message, (name, value)| {
+            message.replace(&format!("{{{}}}", name), value)
+        })
+}
+
+/// Lowercases the locale tag so that `pt-BR` and `pt-br` select the same catalog.
+pub fn normalize_locale(locale: &str) -> String {
+    locale.trim().to_ascii_lowercase()
+}
+
+/// The translations of the messages emitted by the station in a single locale, uploaded by an admin.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LocaleCatalog {
+    /// The normalized locale tag, e.g. `fr` or `pt-br`.
+    pub locale: String,
+    /// The templates by message key, the keys that the station does not emit are not kept.
+    pub messages: BTreeMap<String, String>,
+    pub last_modification_timestamp: Timestamp,
+}
+
+/// How an uploaded catalog compares with the keys of the messages emitted by the station.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocaleCatalogReport {
+    /// The keys emitted by the station without a translation, which are rendered with the fallback locales.
+    pub missing_keys: Vec<String>,
+    /// The keys of the catalog that the station does not emit.
+    pub unused_keys: Vec<String>,
+}
+
+impl LocaleCatalogReport {
+    pub fn new(messages: &BTreeMap<String, String>) -> Self {
+        Self {
+            missing_keys: LOCALE_MESSAGES
+                .iter()
+                .filter(|(key, _)| !messages.contains_key(*key))
+                .map(|(key, _)| key.to_string())
+                .collect(),
+            unused_keys: messages
+                .keys()
+                .filter(|key| english_message(key).is_none())
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl LocaleCatalog {
+    pub const MAX_LOCALE_LEN: usize = 35;
+    pub const MAX_MESSAGE_LEN: usize = 1_000;
+
+    pub fn message(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+}
+
+/// Checks that the locale is a tag made of alphanumeric subtags separated by dashes, e.g. `pt-br`.
+pub fn validate_locale(locale: &str) -> ModelValidatorResult<LocaleError> {
+    let is_valid = !locale.is_empty()
+        && locale.len() <= LocaleCatalog::MAX_LOCALE_LEN
+        && locale
+            .split('-')
+            .all(|subtag| !subtag.is_empty() && subtag.chars().all(|c| c.is_ascii_alphanumeric()));
+
+    if !is_valid {
+        return Err(LocaleError::InvalidLocale {
+            locale: locale.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_messages(messages: &BTreeMap<String, String>) -> ModelValidatorResult<LocaleError> {
+    for (key, template) in messages {
+        if template.len() > LocaleCatalog::MAX_MESSAGE_LEN {
+            return Err(LocaleError::MessageTooLong {
+                key: key.to_string(),
+                max_length: LocaleCatalog::MAX_MESSAGE_LEN,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<LocaleError> for LocaleCatalog {
+    fn validate(&self) -> ModelValidatorResult<LocaleError> {
+        validate_locale(&self.locale)?;
+        validate_messages(&self.messages)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_arguments_of_the_template() {
+        assert_eq!(
+            render_template(
+                english_message("blockchain_paused.title").unwrap(),
+                &[("blockchain", "icp")]
+            ),
+            "Transfers on icp are paused"
+        );
+        assert_eq!(
+            render_template("{a} and {a}, not {b}", &[("a", "x")]),
+            "x and x, not {b}"
+        );
+    }
+
+    #[test]
+    fn reports_the_missing_and_unused_keys() {
+        let messages = BTreeMap::from([
+            (
+                "blockchain_paused.title".to_string(),
+                "Les transferts sur {blockchain} sont suspendus".to_string(),
+            ),
+            ("unknown.key".to_string(), "Inconnu".to_string()),
+        ]);
+
+        let report = LocaleCatalogReport::new(&messages);
+
+        assert_eq!(report.unused_keys, vec!["unknown.key".to_string()]);
+        assert_eq!(report.missing_keys.len(), LOCALE_MESSAGES.len() - 1);
+        assert!(!report
+            .missing_keys
+            .contains(&"blockchain_paused.title".to_string()));
+    }
+
+    #[test]
+    fn validates_the_locale_tag() {
+        assert!(validate_locale("en").is_ok());
+        assert!(validate_locale("pt-br").is_ok());
+        assert!(validate_locale("").is_err());
+        assert!(validate_locale("pt-").is_err());
+        assert!(validate_locale("pt_br").is_err());
+        assert!(validate_locale(&"a".repeat(LocaleCatalog::MAX_LOCALE_LEN + 1)).is_err());
+    }
+}
@@ -5,9 +5,27 @@
 pub mod account;
 pub use account::*;
 
+pub mod trusted_destination;
+pub use trusted_destination::*;
+
+pub mod account_webhook;
+pub use account_webhook::*;
+
+pub mod sns_token;
+pub use sns_token::*;
+
+pub mod registered_asset;
+pub use registered_asset::*;
+
+pub mod icrc_account;
+pub use icrc_account::*;
+
 pub mod address_book;
 pub use address_book::*;
 
+pub mod address_book_label;
+pub use address_book_label::*;
+
 pub mod blockchain;
 pub use blockchain::*;
 
@@ -23,18 +41,75 @@ pub use user::*;
 pub mod external_canister;
 pub use external_canister::*;
 
+pub mod funding_request;
+pub use funding_request::*;
+
+pub mod payout_run;
+pub use payout_run::*;
+
+pub mod destination_first_use;
+pub use destination_first_use::*;
+
+pub mod scheduled_policy_change;
+pub use scheduled_policy_change::*;
+
+pub mod request_view;
+pub use request_view::*;
+
+pub mod request_comment;
+pub use request_comment::*;
+
+pub mod scheduled_transfer;
+pub use scheduled_transfer::*;
+
+pub mod capability_grant;
+pub use capability_grant::*;
+
+pub mod account_spend;
+pub use account_spend::*;
+
+pub mod support_access_log;
+pub use support_access_log::*;
+
+pub mod account_transaction;
+pub use account_transaction::*;
+
+pub mod spending_aggregate;
+pub use spending_aggregate::*;
+
 pub mod user_group;
 pub use user_group::*;
 
+pub mod user_onboarding;
+pub use user_onboarding::*;
+
 pub mod user_status;
 pub use user_status::*;
 
 pub mod account_balance;
 pub use account_balance::*;
 
+pub mod exchange_rate;
+pub use exchange_rate::*;
+
 pub mod transfer;
 pub use transfer::*;
 
+pub mod transfer_receipt;
+pub use transfer_receipt::*;
+
+pub mod transfer_category;
+pub use transfer_category::*;
+
+pub mod transfer_export;
+pub use transfer_export::*;
+
+pub mod pending_execution;
+pub use pending_execution::*;
+
+pub mod locale;
+pub use locale::*;
+
 pub mod notification;
 pub use notification::*;
 
@@ -44,6 +119,12 @@ pub use notification_status::*;
 pub mod notification_type;
 pub use notification_type::*;
 
+pub mod notification_template;
+pub use notification_template::*;
+
+pub mod archived_chunk;
+pub use archived_chunk::*;
+
 pub mod request_approval;
 pub use request_approval::*;
 
@@ -82,6 +163,9 @@ pub use percentage::*;
 pub mod system;
 pub use system::*;
 
+pub mod rpc_provider;
+pub use rpc_provider::*;
+
 pub mod configuration;
 pub use configuration::*;
 
@@ -0,0 +1,187 @@
+use super::{validate_locale, Notification, NotificationType, RequestOperationType};
+use crate::errors::NotificationTemplateError;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::Timestamp,
+};
+
+/// The placeholders that the templates can reference as `{name}`.
+pub const NOTIFICATION_TEMPLATE_PLACEHOLDERS: &[&str] = &[
+    "request_id",
+    "request_title",
+    "request_summary",
+    "operation",
+    "requester",
+];
+
+/// The notifications about requests that can be worded by a template.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NotificationTemplateEvent {
+    RequestCreated,
+    RequestRejected,
+    RequestFailed,
+    RequestCancelled,
+}
+
+impl NotificationTemplateEvent {
+    pub fn from_notification_type(notification_type: &NotificationType) -> Option<Self> {
+        match notification_type {
+            NotificationType::RequestCreated(_) => Some(Self::RequestCreated),
+            NotificationType::RequestRejected(_) => Some(Self::RequestRejected),
+            NotificationType::RequestFailed(_) => Some(Self::RequestFailed),
+            NotificationType::RequestCancelled(_) => Some(Self::RequestCancelled),
+            NotificationType::SystemMessage
+            | NotificationType::ExternalCanisterMonitoring(_)
+            | NotificationType::RequestCommented(_)
+            | NotificationType::PendingVoteReminder(_) => None,
+        }
+    }
+}
+
+/// Selects the notifications a template applies to, the unset operation type and locale match any.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NotificationTemplateKey {
+    pub event: NotificationTemplateEvent,
+    pub operation_type: Option<RequestOperationType>,
+    /// The normalized locale tag, e.g. `fr` or `pt-br`.
+    pub locale: Option<String>,
+}
+
+/// The wording of the notifications of a request event, configured through a `ManageSystemInfo`
+/// request.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NotificationTemplate {
+    pub key: NotificationTemplateKey,
+    pub title: String,
+    /// The message of the notification, the summary of the request is sent if unset.
+    pub message: Option<String>,
+    pub last_modification_timestamp: Timestamp,
+}
+
+/// Sets or removes the template of the notifications selected by the key.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NotificationTemplateChange {
+    Set {
+        key: NotificationTemplateKey,
+        title: String,
+        message: Option<String>,
+    },
+    Remove(NotificationTemplateKey),
+}
+
+impl ModelKey<NotificationTemplateKey> for NotificationTemplate {
+    fn key(&self) -> NotificationTemplateKey {
+        self.key.clone()
+    }
+}
+
+/// Checks that the template only references the placeholders filled by the station.
+fn validate_placeholders(template: &str) -> ModelValidatorResult<NotificationTemplateError> {
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+
+        let placeholder = &rest[start + 1..start + end];
+        if !NOTIFICATION_TEMPLATE_PLACEHOLDERS.contains(&placeholder) {
+            return Err(NotificationTemplateError::UnknownPlaceholder {
+                placeholder: placeholder.to_string(),
+            });
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<NotificationTemplateError> for NotificationTemplate {
+    fn validate(&self) -> ModelValidatorResult<NotificationTemplateError> {
+        if let Some(locale) = &self.key.locale {
+            validate_locale(locale).map_err(|_| NotificationTemplateError::InvalidLocale {
+                locale: locale.to_string(),
+            })?;
+        }
+
+        if self.title.trim().is_empty() || self.title.len() > Notification::MAX_TITLE_LEN as usize {
+            return Err(NotificationTemplateError::InvalidTitleLength {
+                max_length: Notification::MAX_TITLE_LEN as usize,
+            });
+        }
+
+        if let Some(message) = &self.message {
+            if message.len() > Notification::MAX_MESSAGE_LEN as usize {
+                return Err(NotificationTemplateError::MessageTooLong {
+                    max_length: Notification::MAX_MESSAGE_LEN as usize,
+                });
+            }
+
+            validate_placeholders(message)?;
+        }
+
+        validate_placeholders(&self.title)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod notification_template_test_utils {
+    use super::*;
+
+    pub fn mock_notification_template() -> NotificationTemplate {
+        NotificationTemplate {
+            key: NotificationTemplateKey {
+                event: NotificationTemplateEvent::RequestCreated,
+                operation_type: Some(RequestOperationType::Transfer),
+                locale: None,
+            },
+            title: "Transfer {request_title} awaits your approval".to_string(),
+            message: Some("Requested by {requester}".to_string()),
+            last_modification_timestamp: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::notification_template_test_utils::mock_notification_template;
+    use super::*;
+
+    #[test]
+    fn accepts_the_known_placeholders() {
+        assert!(mock_notification_template().validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        let mut template = mock_notification_template();
+        template.message = Some("Requested by {requestor}".to_string());
+
+        assert_eq!(
+            template.validate(),
+            Err(NotificationTemplateError::UnknownPlaceholder {
+                placeholder: "requestor".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_empty_titles_and_invalid_locales() {
+        let mut template = mock_notification_template();
+        template.title = " ".to_string();
+
+        assert!(template.validate().is_err());
+
+        let mut template = mock_notification_template();
+        template.key.locale = Some("pt_br".to_string());
+
+        assert!(template.validate().is_err());
+    }
+}
@@ -1,6 +1,12 @@
+use super::{
+    ExternalCanisterEntryId, ExternalCanisterMonitoringEvent, RequestCommentId, RequestId, UserId,
+};
+use candid::Principal;
 use orbit_essentials::storable;
 use orbit_essentials::types::UUID;
 use station_api::{
+    EXTERNAL_CANISTER_MONITORING_NOTIFICATION_TYPE, PENDING_VOTE_REMINDER_NOTIFICATION_TYPE,
+    REQUEST_CANCELLED_NOTIFICATION_TYPE, REQUEST_COMMENTED_NOTIFICATION_TYPE,
     REQUEST_CREATED_NOTIFICATION_TYPE, REQUEST_FAILED_NOTIFICATION_TYPE,
     REQUEST_REJECTED_NOTIFICATION_TYPE, SYSTEM_MESSAGE_NOTIFICATION_TYPE,
 };
@@ -13,6 +19,10 @@ pub enum NotificationType {
     RequestCreated(RequestCreatedNotification),
     RequestFailed(RequestFailedNotification),
     RequestRejected(RequestRejectedNotification),
+    RequestCancelled(RequestCancelledNotification),
+    ExternalCanisterMonitoring(ExternalCanisterMonitoringNotification),
+    RequestCommented(RequestCommentedNotification),
+    PendingVoteReminder(PendingVoteReminderNotification),
 }
 
 #[storable]
@@ -24,6 +34,24 @@ pub struct RequestNotification {
 pub type RequestCreatedNotification = RequestNotification;
 pub type RequestFailedNotification = RequestNotification;
 pub type RequestRejectedNotification = RequestNotification;
+pub type RequestCancelledNotification = RequestNotification;
+pub type PendingVoteReminderNotification = RequestNotification;
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCommentedNotification {
+    pub request_id: RequestId,
+    pub comment_id: RequestCommentId,
+    pub author_id: UserId,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExternalCanisterMonitoringNotification {
+    pub external_canister_id: ExternalCanisterEntryId,
+    pub canister_id: Principal,
+    pub event: ExternalCanisterMonitoringEvent,
+}
 
 impl Display for NotificationType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -38,6 +66,18 @@ impl Display for NotificationType {
             NotificationType::RequestRejected(_) => {
                 write!(f, "{}", REQUEST_REJECTED_NOTIFICATION_TYPE)
             }
+            NotificationType::RequestCancelled(_) => {
+                write!(f, "{}", REQUEST_CANCELLED_NOTIFICATION_TYPE)
+            }
+            NotificationType::ExternalCanisterMonitoring(_) => {
+                write!(f, "{}", EXTERNAL_CANISTER_MONITORING_NOTIFICATION_TYPE)
+            }
+            NotificationType::RequestCommented(_) => {
+                write!(f, "{}", REQUEST_COMMENTED_NOTIFICATION_TYPE)
+            }
+            NotificationType::PendingVoteReminder(_) => {
+                write!(f, "{}", PENDING_VOTE_REMINDER_NOTIFICATION_TYPE)
+            }
         }
     }
 }
@@ -75,5 +115,41 @@ mod tests {
             .to_string(),
             "request-rejected"
         );
+
+        assert_eq!(
+            NotificationType::RequestCancelled(RequestCancelledNotification {
+                request_id: [0; 16]
+            })
+            .to_string(),
+            "request-cancelled"
+        );
+
+        assert_eq!(
+            NotificationType::ExternalCanisterMonitoring(ExternalCanisterMonitoringNotification {
+                external_canister_id: [0; 16],
+                canister_id: Principal::anonymous(),
+                event: ExternalCanisterMonitoringEvent::Stopped,
+            })
+            .to_string(),
+            "external-canister-monitoring"
+        );
+
+        assert_eq!(
+            NotificationType::RequestCommented(RequestCommentedNotification {
+                request_id: [0; 16],
+                comment_id: [1; 16],
+                author_id: [2; 16],
+            })
+            .to_string(),
+            "request-commented"
+        );
+
+        assert_eq!(
+            NotificationType::PendingVoteReminder(PendingVoteReminderNotification {
+                request_id: [0; 16]
+            })
+            .to_string(),
+            "pending-vote-reminder"
+        );
     }
 }
@@ -0,0 +1,262 @@
+use super::{RequestId, RequestStatus, UserId};
+use crate::errors::PayoutRunError;
+use orbit_essentials::model::{ModelKey, ModelValidator, ModelValidatorResult};
+use orbit_essentials::storable;
+use orbit_essentials::types::{Timestamp, UUID};
+
+/// The payout run id, which is a UUID.
+pub type PayoutRunId = UUID;
+
+/// A group of transfer requests created together (e.g. a payroll run).
+///
+/// The run is tracked as a unit while each of its transfers is approved and executed on its own, its
+/// status and totals are derived from its requests when it is read.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PayoutRun {
+    pub id: PayoutRunId,
+    pub name: String,
+    /// The transfer requests of the run, in the order of the input transfers.
+    pub request_ids: Vec<RequestId>,
+    pub created_by: UserId,
+    pub created_timestamp: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PayoutRunKey {
+    pub id: PayoutRunId,
+}
+
+impl ModelKey<PayoutRunKey> for PayoutRun {
+    fn key(&self) -> PayoutRunKey {
+        PayoutRunKey { id: self.id }
+    }
+}
+
+/// The aggregate status of a payout run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutRunStatus {
+    /// Some of the transfer requests can still complete.
+    Pending,
+    /// All the transfer requests completed.
+    Completed,
+    /// Some of the transfer requests completed, the others were rejected, cancelled or failed.
+    PartiallyCompleted,
+    /// None of the transfer requests completed.
+    Failed,
+}
+
+/// The outcome of a transfer request of a payout run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutRunRequestOutcome {
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl From<&RequestStatus> for PayoutRunRequestOutcome {
+    fn from(status: &RequestStatus) -> Self {
+        match status {
+            RequestStatus::Created
+            | RequestStatus::Approved
+            | RequestStatus::Scheduled { .. }
+            | RequestStatus::Processing { .. } => PayoutRunRequestOutcome::Pending,
+            RequestStatus::Completed { .. } => PayoutRunRequestOutcome::Completed,
+            RequestStatus::Rejected
+            | RequestStatus::Cancelled { .. }
+            | RequestStatus::Failed { .. } => PayoutRunRequestOutcome::Failed,
+        }
+    }
+}
+
+/// The total amount of an asset sent by a payout run, in the smallest unit of the asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutRunAssetTotal {
+    pub blockchain: String,
+    pub symbol: String,
+    pub decimals: u32,
+    pub requested: candid::Nat,
+    pub completed: candid::Nat,
+    pub pending: candid::Nat,
+}
+
+impl PayoutRunAssetTotal {
+    pub fn add(&mut self, amount: &candid::Nat, outcome: PayoutRunRequestOutcome) {
+        self.requested += amount.clone();
+
+        match outcome {
+            PayoutRunRequestOutcome::Pending => self.pending += amount.clone(),
+            PayoutRunRequestOutcome::Completed => self.completed += amount.clone(),
+            PayoutRunRequestOutcome::Failed => {}
+        }
+    }
+}
+
+/// A payout run with the status and totals derived from its transfer requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutRunSummary {
+    pub payout_run: PayoutRun,
+    pub status: PayoutRunStatus,
+    pub pending_count: u64,
+    pub completed_count: u64,
+    pub failed_count: u64,
+    pub totals: Vec<PayoutRunAssetTotal>,
+}
+
+impl PayoutRunSummary {
+    /// Aggregates the outcomes of the transfer requests, the totals are computed by the caller since
+    /// they depend on the accounts of the transfers.
+    pub fn new(
+        payout_run: PayoutRun,
+        outcomes: &[PayoutRunRequestOutcome],
+        totals: Vec<PayoutRunAssetTotal>,
+    ) -> Self {
+        let count = |outcome: PayoutRunRequestOutcome| {
+            outcomes.iter().filter(|other| **other == outcome).count() as u64
+        };
+        let pending_count = count(PayoutRunRequestOutcome::Pending);
+        let completed_count = count(PayoutRunRequestOutcome::Completed);
+        let failed_count = count(PayoutRunRequestOutcome::Failed);
+
+        let status = if pending_count > 0 {
+            PayoutRunStatus::Pending
+        } else if failed_count == 0 {
+            PayoutRunStatus::Completed
+        } else if completed_count > 0 {
+            PayoutRunStatus::PartiallyCompleted
+        } else {
+            PayoutRunStatus::Failed
+        };
+
+        Self {
+            payout_run,
+            status,
+            pending_count,
+            completed_count,
+            failed_count,
+            totals,
+        }
+    }
+}
+
+impl PayoutRun {
+    pub const MAX_NAME_LENGTH: usize = 100;
+    pub const MAX_TRANSFERS: usize = 100;
+
+    pub fn validate_name(name: &str) -> ModelValidatorResult<PayoutRunError> {
+        if name.trim().is_empty() || name.len() > Self::MAX_NAME_LENGTH {
+            return Err(PayoutRunError::ValidationError {
+                info: format!(
+                    "The name must be between 1 and {} characters long",
+                    Self::MAX_NAME_LENGTH
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks the number of transfers of the run, which is known before its requests are created.
+    pub fn validate_transfer_count(count: usize) -> ModelValidatorResult<PayoutRunError> {
+        if count == 0 || count > Self::MAX_TRANSFERS {
+            return Err(PayoutRunError::ValidationError {
+                info: format!(
+                    "A payout run must have between 1 and {} transfers",
+                    Self::MAX_TRANSFERS
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl ModelValidator<PayoutRunError> for PayoutRun {
+    fn validate(&self) -> ModelValidatorResult<PayoutRunError> {
+        Self::validate_name(&self.name)?;
+        Self::validate_transfer_count(self.request_ids.len())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::payout_run_test_utils::mock_payout_run;
+    use super::*;
+
+    #[test]
+    fn status_is_derived_from_the_request_outcomes() {
+        use PayoutRunRequestOutcome::*;
+
+        let status = |outcomes: &[PayoutRunRequestOutcome]| {
+            PayoutRunSummary::new(mock_payout_run(), outcomes, vec![]).status
+        };
+
+        assert_eq!(status(&[Completed, Pending]), PayoutRunStatus::Pending);
+        assert_eq!(status(&[Completed, Completed]), PayoutRunStatus::Completed);
+        assert_eq!(
+            status(&[Completed, Failed]),
+            PayoutRunStatus::PartiallyCompleted
+        );
+        assert_eq!(status(&[Failed, Failed]), PayoutRunStatus::Failed);
+
+        let summary = PayoutRunSummary::new(mock_payout_run(), &[Pending, Failed, Failed], vec![]);
+        assert_eq!(summary.pending_count, 1);
+        assert_eq!(summary.completed_count, 0);
+        assert_eq!(summary.failed_count, 2);
+    }
+
+    #[test]
+    fn asset_total_only_counts_the_failed_transfers_as_requested() {
+        let mut total = PayoutRunAssetTotal {
+            blockchain: "icp".to_string(),
+            symbol: "ICP".to_string(),
+            decimals: 8,
+            requested: candid::Nat::from(0u64),
+            completed: candid::Nat::from(0u64),
+            pending: candid::Nat::from(0u64),
+        };
+
+        total.add(
+            &candid::Nat::from(100u64),
+            PayoutRunRequestOutcome::Completed,
+        );
+        total.add(&candid::Nat::from(20u64), PayoutRunRequestOutcome::Pending);
+        total.add(&candid::Nat::from(3u64), PayoutRunRequestOutcome::Failed);
+
+        assert_eq!(total.requested, candid::Nat::from(123u64));
+        assert_eq!(total.completed, candid::Nat::from(100u64));
+        assert_eq!(total.pending, candid::Nat::from(20u64));
+    }
+
+    #[test]
+    fn fail_empty_name_and_too_many_transfers() {
+        let mut payout_run = mock_payout_run();
+        payout_run.name = " ".to_string();
+
+        assert!(payout_run.validate().is_err());
+
+        let mut payout_run = mock_payout_run();
+        payout_run.request_ids = vec![[1; 16]; PayoutRun::MAX_TRANSFERS + 1];
+
+        assert!(payout_run.validate().is_err());
+    }
+}
+
+#[cfg(test)]
+pub mod payout_run_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_payout_run() -> PayoutRun {
+        PayoutRun {
+            id: *Uuid::new_v4().as_bytes(),
+            name: "Payroll".to_string(),
+            request_ids: vec![*Uuid::new_v4().as_bytes()],
+            created_by: *Uuid::new_v4().as_bytes(),
+            created_timestamp: 0,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::{AccountId, Request, RequestId, RequestStatus, Transfer, TransferId};
+use orbit_essentials::types::Timestamp;
+
+/// An approved request that waits for the job executing the scheduled requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingRequestExecution {
+    pub request_id: RequestId,
+    pub title: String,
+    /// The operation of the request, e.g. `transfer`.
+    pub operation: String,
+    /// The time from which the request is executed by the next run of the job.
+    pub scheduled_at: Timestamp,
+    /// The position of the request in the queue, starting at 1.
+    pub queue_position: usize,
+}
+
+/// A transfer created by an executed request that waits to be submitted to the blockchain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingTransferExecution {
+    pub transfer_id: TransferId,
+    pub request_id: RequestId,
+    pub from_account_id: AccountId,
+    pub to_address: String,
+    pub amount: candid::Nat,
+    /// The time from which the transfer is submitted, which is later than its creation when it
+    /// waits for the backoff of a retry.
+    pub scheduled_at: Timestamp,
+    /// Whether the transfers of the blockchain of the account are paused, which keeps the transfer
+    /// in the queue until they are resumed.
+    pub paused: bool,
+    /// The position of the transfer in the queue, starting at 1.
+    pub queue_position: usize,
+}
+
+/// The work waiting for the execution jobs, each queue in the order the jobs take it up.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PendingExecutions {
+    pub requests: Vec<PendingRequestExecution>,
+    pub transfers: Vec<PendingTransferExecution>,
+    /// The time of the next run of the job executing the scheduled requests, if it is scheduled.
+    pub next_request_execution_at: Option<Timestamp>,
+    /// The time of the next run of the job submitting the created transfers, if it is scheduled.
+    pub next_transfer_execution_at: Option<Timestamp>,
+}
+
+impl PendingExecutions {
+    /// Orders the scheduled requests by the time they are due, the other requests are skipped.
+    pub fn queue_requests(requests: Vec<Request>) -> Vec<PendingRequestExecution> {
+        let mut queue = requests
+            .into_iter()
+            .filter_map(|request| match request.status {
+                RequestStatus::Scheduled { scheduled_at } => Some((scheduled_at, request)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        queue.sort_by(|(a_at, a), (b_at, b)| (a_at, a.id).cmp(&(b_at, b.id)));
+
+        queue
+            .into_iter()
+            .enumerate()
+            .map(
+                |(position, (scheduled_at, request))| PendingRequestExecution {
+                    request_id: request.id,
+                    operation: request.operation.to_string(),
+                    title: request.title,
+                    scheduled_at,
+                    queue_position: position + 1,
+                },
+            )
+            .collect()
+    }
+
+    /// Orders the created transfers by the time they are due, the transfers of the paused
+    /// blockchains being last since they are only taken up once resumed.
+    pub fn queue_transfers(
+        transfers: Vec<Transfer>,
+        is_paused: impl Fn(&Transfer) -> bool,
+    ) -> Vec<PendingTransferExecution> {
+        let mut queue = transfers
+            .into_iter()
+            .map(|transfer| {
+                let scheduled_at = transfer
+                    .next_attempt_at
+                    .unwrap_or(transfer.created_timestamp);
+
+                (is_paused(&transfer), scheduled_at, transfer)
+            })
+            .collect::<Vec<_>>();
+        queue.sort_by(|(a_paused, a_at, a), (b_paused, b_at, b)| {
+            (a_paused, a_at, a.id).cmp(&(b_paused, b_at, b.id))
+        });
+
+        queue
+            .into_iter()
+            .enumerate()
+            .map(
+                |(position, (paused, scheduled_at, transfer))| PendingTransferExecution {
+                    transfer_id: transfer.id,
+                    request_id: transfer.request_id,
+                    from_account_id: transfer.from_account,
+                    to_address: transfer.to_address,
+                    amount: transfer.amount,
+                    scheduled_at,
+                    paused,
+                    queue_position: position + 1,
+                },
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{request_test_utils::mock_request, transfer_test_utils::mock_transfer};
+
+    #[test]
+    fn queues_the_requests_by_their_scheduled_time() {
+        let requests = [30, 10, 20]
+            .into_iter()
+            .map(|scheduled_at| {
+                let mut request = mock_request();
+                request.status = RequestStatus::Scheduled { scheduled_at };
+                request
+            })
+            .chain(std::iter::once(mock_request()))
+            .collect::<Vec<_>>();
+
+        let queue = PendingExecutions::queue_requests(requests.clone());
+
+        assert_eq!(
+            queue
+                .iter()
+                .map(|pending| (pending.request_id, pending.queue_position))
+                .collect::<Vec<_>>(),
+            vec![
+                (requests[1].id, 1),
+                (requests[2].id, 2),
+                (requests[0].id, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn queues_the_transfers_of_paused_blockchains_last() {
+        let mut paused = mock_transfer();
+        paused.created_timestamp = 10;
+        paused.from_account = [1; 16];
+        let mut retried = mock_transfer();
+        retried.created_timestamp = 20;
+        retried.next_attempt_at = Some(40);
+        let mut created = mock_transfer();
+        created.created_timestamp = 30;
+
+        let queue = PendingExecutions::queue_transfers(
+            vec![paused.clone(), retried.clone(), created.clone()],
+            |transfer| transfer.from_account == [1; 16],
+        );
+
+        assert_eq!(
+            queue
+                .iter()
+                .map(|pending| (pending.transfer_id, pending.scheduled_at, pending.paused))
+                .collect::<Vec<_>>(),
+            vec![
+                (created.id, 30, false),
+                (retried.id, 40, false),
+                (paused.id, 10, true)
+            ]
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
 };
 
 use super::{resource::Resource, User, UserGroupId, UserId};
+use candid::Principal;
 use orbit_essentials::model::{ModelKey, ModelValidator, ModelValidatorResult};
 use orbit_essentials::storable;
 
@@ -153,6 +154,62 @@ impl Permission {
     }
 }
 
+/// The access-control configuration of a station in a portable form, which can be imported into
+/// another station (e.g. to promote the permissions of a staging station to production).
+///
+/// The users and groups are referenced by name since their ids differ between stations.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct AccessPolicyDocument {
+    pub user_groups: Vec<String>,
+    pub users: Vec<AccessPolicyDocumentUser>,
+    pub permissions: Vec<AccessPolicyDocumentPermission>,
+}
+
+/// A user referenced by the permissions of the document.
+///
+/// The identities are used to find the user in the importing station before falling back to its name.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccessPolicyDocumentUser {
+    pub name: String,
+    pub identities: Vec<Principal>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccessPolicyDocumentPermission {
+    pub resource: Resource,
+    pub auth_scope: AuthScope,
+    /// The names of the allowed user groups.
+    pub user_groups: Vec<String>,
+    /// The names of the allowed users.
+    pub users: Vec<String>,
+}
+
+/// A difference between the document and the importing station that the importer should review.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccessPolicyImportConflict {
+    /// A group with the same name already exists and is used instead of creating a new one.
+    ExistingUserGroup { name: String },
+    /// The user does not exist in the station and is left out of the imported permissions.
+    MissingUser { name: String },
+    /// The resource does not exist in the station (e.g. an account of the exporting station), the
+    /// permission is not imported.
+    InvalidResource { resource: Resource, reason: String },
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct AccessPolicyImportReport {
+    /// The groups of the document that did not exist and are created by the import.
+    pub created_user_groups: Vec<String>,
+    /// The number of permissions merged into the station.
+    pub imported_permissions: u64,
+    pub conflicts: Vec<AccessPolicyImportConflict>,
+}
+
 #[cfg(any(test, feature = "canbench"))]
 pub mod permission_test_utils {
     use crate::models::resource::{
@@ -0,0 +1,207 @@
+use super::{
+    Asset, Blockchain, BlockchainStandard, Metadata, ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+    ACCOUNT_METADATA_SYMBOL_KEY, ASSET_METADATA_INDEX_CANISTER_ID_KEY,
+};
+use crate::{errors::AssetError, repositories::REGISTERED_ASSET_REPOSITORY};
+use candid::{CandidType, Deserialize, Principal};
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The registered asset id, which is a UUID.
+pub type RegisteredAssetId = UUID;
+
+/// An ICRC-1 token registered by the station, whose accounts are served like the built-in ICRC-1 tokens.
+///
+/// Registering a ledger makes it possible to hold new tokens without upgrading the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RegisteredAsset {
+    pub id: RegisteredAssetId,
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
+    /// The token symbol (e.g. `CHAT`).
+    pub symbol: String,
+    /// The token name (e.g. `OpenChat`).
+    pub name: String,
+    pub decimals: u32,
+    /// The asset metadata (e.g. `{"logo": "https://example.com/logo.png"}`).
+    pub metadata: Metadata,
+    /// The last time the record was updated or created.
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl ModelKey<RegisteredAssetId> for RegisteredAsset {
+    fn key(&self) -> RegisteredAssetId {
+        self.id
+    }
+}
+
+impl RegisteredAsset {
+    pub const SYMBOL_RANGE: (u8, u8) = (1, 32);
+    pub const NAME_RANGE: (u8, u8) = (1, 100);
+    pub const MAX_DECIMALS: u32 = 18;
+
+    /// The supported asset of the token, whose metadata can be used as is to add an account for it.
+    pub fn to_asset(&self) -> Asset {
+        let mut metadata = self.metadata.as_btreemap().clone();
+        metadata.insert(
+            ACCOUNT_METADATA_SYMBOL_KEY.to_string(),
+            self.symbol.to_owned(),
+        );
+        metadata.insert(
+            ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+            self.ledger_canister_id.to_text(),
+        );
+
+        if let Some(index_canister_id) = &self.index_canister_id {
+            metadata.insert(
+                ASSET_METADATA_INDEX_CANISTER_ID_KEY.to_string(),
+                index_canister_id.to_text(),
+            );
+        }
+
+        Asset {
+            blockchain: Blockchain::InternetComputer,
+            standard: BlockchainStandard::ICRC1,
+            symbol: self.symbol.to_owned(),
+            name: self.name.to_owned(),
+            metadata: Metadata::new(metadata),
+        }
+    }
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub struct RegisteredAssetCallerPrivileges {
+    pub id: RegisteredAssetId,
+    pub can_edit: bool,
+    pub can_delete: bool,
+}
+
+fn validate_symbol(symbol: &str) -> ModelValidatorResult<AssetError> {
+    let (min_length, max_length) = RegisteredAsset::SYMBOL_RANGE;
+    if symbol.trim().len() < min_length as usize || symbol.len() > max_length as usize {
+        return Err(AssetError::InvalidSymbolLength {
+            min_length,
+            max_length,
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_name(name: &str) -> ModelValidatorResult<AssetError> {
+    let (min_length, max_length) = RegisteredAsset::NAME_RANGE;
+    if name.trim().len() < min_length as usize || name.len() > max_length as usize {
+        return Err(AssetError::InvalidNameLength {
+            min_length,
+            max_length,
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_unique_ledger(
+    asset_id: &RegisteredAssetId,
+    ledger_canister_id: &Principal,
+) -> ModelValidatorResult<AssetError> {
+    if let Some(asset) = REGISTERED_ASSET_REPOSITORY.find_by_ledger(ledger_canister_id) {
+        if asset.id != *asset_id {
+            return Err(AssetError::DuplicateLedger {
+                ledger_canister_id: ledger_canister_id.to_text(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<AssetError> for RegisteredAsset {
+    fn validate(&self) -> ModelValidatorResult<AssetError> {
+        self.metadata.validate()?;
+        validate_symbol(&self.symbol)?;
+        validate_name(&self.name)?;
+
+        if self.decimals > Self::MAX_DECIMALS {
+            return Err(AssetError::InvalidDecimals {
+                max_decimals: Self::MAX_DECIMALS,
+            });
+        }
+
+        validate_unique_ledger(&self.id, &self.ledger_canister_id)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::registered_asset_test_utils::mock_registered_asset;
+    use super::*;
+    use orbit_essentials::repository::Repository;
+
+    #[test]
+    fn fail_registered_asset_invalid_symbol() {
+        let mut asset = mock_registered_asset();
+        asset.symbol = " ".to_string();
+
+        assert_eq!(
+            asset.validate(),
+            Err(AssetError::InvalidSymbolLength {
+                min_length: RegisteredAsset::SYMBOL_RANGE.0,
+                max_length: RegisteredAsset::SYMBOL_RANGE.1,
+            })
+        );
+    }
+
+    #[test]
+    fn fail_registered_asset_too_many_decimals() {
+        let mut asset = mock_registered_asset();
+        asset.decimals = RegisteredAsset::MAX_DECIMALS + 1;
+
+        assert_eq!(
+            asset.validate(),
+            Err(AssetError::InvalidDecimals {
+                max_decimals: RegisteredAsset::MAX_DECIMALS
+            })
+        );
+    }
+
+    #[test]
+    fn fail_registered_asset_duplicate_ledger() {
+        let asset = mock_registered_asset();
+        REGISTERED_ASSET_REPOSITORY.insert(asset.id, asset.clone());
+
+        let mut other = mock_registered_asset();
+        other.id = [1; 16];
+
+        assert!(asset.validate().is_ok());
+        assert_eq!(
+            other.validate(),
+            Err(AssetError::DuplicateLedger {
+                ledger_canister_id: asset.ledger_canister_id.to_text()
+            })
+        );
+    }
+}
+
+#[cfg(any(test, feature = "canbench"))]
+pub mod registered_asset_test_utils {
+    use super::*;
+
+    pub fn mock_registered_asset() -> RegisteredAsset {
+        RegisteredAsset {
+            id: [0; 16],
+            ledger_canister_id: Principal::from_slice(&[2; 10]),
+            index_canister_id: None,
+            symbol: "TKN".to_string(),
+            name: "Token".to_string(),
+            decimals: 8,
+            metadata: Metadata::default(),
+            last_modification_timestamp: 0,
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use super::request_policy_rule::{RequestEvaluationResult, RequestPolicyRuleInput};
 use super::{
-    ConfigureExternalCanisterOperationKind, DisplayUser, EvaluationStatus, RequestApproval,
-    RequestApprovalStatus, RequestOperation, RequestStatus, UserId, UserKey,
+    ConfigureExternalCanisterOperationKind, DisplayUser, EvaluationStatus,
+    FundExternalCanisterOperationKind, RequestApproval, RequestApprovalStatus, RequestOperation,
+    RequestStatus, SystemInfo, SystemState, UserId, UserKey,
 };
 use crate::core::evaluation::{
     Evaluate, REQUEST_APPROVE_RIGHTS_REQUEST_POLICY_RULE_EVALUATOR, REQUEST_POLICY_RULE_EVALUATOR,
@@ -9,16 +10,18 @@ use crate::core::evaluation::{
 };
 use crate::core::ic_cdk::api::print;
 use crate::core::ic_cdk::next_time;
+use crate::core::read_system_state;
 use crate::core::request::{
     RequestApprovalRightsEvaluator, RequestEvaluator, RequestPossibleApproversFinder,
+    RequestVoteEvaluator,
 };
 use crate::core::validation::{
-    EnsureAccount, EnsureAddressBookEntry, EnsureIdExists, EnsureRequestPolicy, EnsureUser,
-    EnsureUserGroup,
+    EnsureAccount, EnsureAddressBookEntry, EnsureAddressBookLabel, EnsureCapabilityGrant,
+    EnsureIdExists, EnsureRegisteredAsset, EnsureRequestPolicy, EnsureUser, EnsureUserGroup,
 };
 use crate::errors::{EvaluateError, RequestError, ValidationError};
 use crate::models::resource::{ExecutionMethodResourceTarget, ValidationMethodResourceTarget};
-use crate::repositories::USER_REPOSITORY;
+use crate::repositories::{REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY, USER_REPOSITORY};
 use candid::{CandidType, Deserialize};
 use orbit_essentials::model::{ContextualModel, ModelKey};
 use orbit_essentials::repository::Repository;
@@ -28,6 +31,7 @@ use orbit_essentials::{
     types::{Timestamp, UUID},
 };
 use std::collections::HashSet;
+use uuid::Uuid;
 
 /// The request id, which is a UUID.
 pub type RequestId = UUID;
@@ -61,6 +65,23 @@ pub struct Request {
     pub execution_plan: RequestExecutionPlan,
     /// The list of user approvals on the request.
     pub approvals: Vec<RequestApproval>,
+    /// Confidential requests are only visible to their requester, the users that can approve them
+    /// and the admins, regardless of the permissions to read requests.
+    #[serde(default)]
+    pub confidential: bool,
+    /// Free-form tags to organize the requests (e.g. `treasury`, `ops`), stored in lowercase.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The request that must complete before this request is executed, this request fails if its
+    /// dependency is rejected, cancelled or fails.
+    #[serde(default)]
+    pub depends_on: Option<RequestId>,
+    /// The amendments of the operation by the requester, oldest first.
+    #[serde(default)]
+    pub amendments: Vec<RequestAmendment>,
+    /// Whether the possible approvers that did not vote yet were reminded before the expiration.
+    #[serde(default)]
+    pub vote_reminder_sent: bool,
     /// The timestamp of the request creation.
     pub created_timestamp: Timestamp,
     /// The last time the record was updated or created.
@@ -80,18 +101,43 @@ impl ModelKey<RequestKey> for Request {
     }
 }
 
+/// A replacement of the operation of a request by its requester, made before other users voted.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestAmendment {
+    /// The operation the request had before the amendment.
+    pub previous_operation: RequestOperation,
+    /// The reason given by the requester for the amendment.
+    pub reason: Option<String>,
+    /// The time at which the request was amended.
+    pub amended_at: Timestamp,
+}
+
 #[derive(CandidType, Deserialize, Debug, Clone)]
 pub struct RequestCallerPrivileges {
     pub id: UUID,
     pub can_approve: bool,
 }
 
+/// The state of the dependency of a request, which must complete before the request is executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestDependencyState {
+    /// The request has no dependency or its dependency completed.
+    Satisfied,
+    /// The dependency can still complete.
+    Pending,
+    /// The dependency can no longer complete.
+    Failed { reason: String },
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct RequestAdditionalInfo {
     pub id: UUID,
     pub requester_name: String,
     pub approvers: Vec<DisplayUser>,
     pub evaluation_result: Option<RequestEvaluationResult>,
+    /// The warning about the destination of the transfer, if the request is a transfer.
+    pub destination_warning: Option<DestinationWarning>,
 }
 
 fn validate_title(title: &str) -> ModelValidatorResult<RequestError> {
@@ -122,6 +168,44 @@ fn validate_summary(summary: &Option<String>) -> ModelValidatorResult<RequestErr
     Ok(())
 }
 
+fn validate_tags(tags: &[String]) -> ModelValidatorResult<RequestError> {
+    if tags.len() > Request::MAX_TAGS as usize {
+        return Err(RequestError::ValidationError {
+            info: format!(
+                "Request tags exceed the maximum allowed: {}",
+                Request::MAX_TAGS
+            ),
+        });
+    }
+
+    for tag in tags {
+        if tag.is_empty() || tag.len() > Request::MAX_TAG_LEN as usize {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "Request tag length must be between 1 and {}",
+                    Request::MAX_TAG_LEN
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_depends_on(request: &Request) -> ModelValidatorResult<RequestError> {
+    if request.depends_on == Some(request.id) {
+        return Err(RequestError::ValidationError {
+            info: "A request cannot depend on itself".to_string(),
+        });
+    }
+
+    if let RequestDependencyState::Failed { reason } = request.dependency_state() {
+        return Err(RequestError::ValidationError { info: reason });
+    }
+
+    Ok(())
+}
+
 fn validate_requested_by(requested_by: &UserId) -> ModelValidatorResult<RequestError> {
     USER_REPOSITORY
         .get(&UserKey { id: *requested_by })
@@ -139,6 +223,37 @@ fn validate_request_operation_foreign_keys(
         RequestOperation::Transfer(op) => {
             EnsureAccount::id_exists(&op.input.from_account_id)?;
         }
+        RequestOperation::Approve(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+        }
+        RequestOperation::SweepAccount(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+        }
+        RequestOperation::SplitTransfer(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+        }
+        RequestOperation::SwapTokens(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+            EnsureAccount::id_exists(&op.input.to_account_id)?;
+        }
+        RequestOperation::AddScheduledTransfer(op) => {
+            EnsureAccount::id_exists(&op.input.transfer.from_account_id)?;
+        }
+        RequestOperation::TransferNft(op) => {
+            EnsureAccount::id_exists(&op.input.from_account_id)?;
+        }
+        RequestOperation::DeriveSubaccount(op) => {
+            EnsureAccount::id_exists(&op.input.account_id)?;
+        }
+        RequestOperation::SetAutoApprovalForTrustedDestinations(op) => {
+            EnsureAccount::id_exists(&op.input.account_id)?;
+        }
+        RequestOperation::CreateCapabilityGrant(op) => {
+            EnsureAccount::id_exists(&op.input.account_id)?;
+        }
+        RequestOperation::RevokeCapabilityGrant(op) => {
+            EnsureCapabilityGrant::id_exists(&op.input.grant_id)?;
+        }
         RequestOperation::AddAccount(op) => {
             op.input.read_permission.validate()?;
             op.input.configs_permission.validate()?;
@@ -177,13 +292,20 @@ fn validate_request_operation_foreign_keys(
                 policy_rule.validate()?;
             }
         }
-        RequestOperation::AddAddressBookEntry(_) => (),
+        RequestOperation::AddAddressBookEntry(op) => {
+            EnsureAddressBookLabel::id_list_exists(&op.input.labels)?;
+        }
         RequestOperation::EditAddressBookEntry(op) => {
             EnsureAddressBookEntry::id_exists(&op.input.address_book_entry_id)?;
+
+            if let Some(labels) = &op.input.labels {
+                EnsureAddressBookLabel::id_list_exists(labels)?;
+            }
         }
         RequestOperation::RemoveAddressBookEntry(op) => {
             EnsureAddressBookEntry::id_exists(&op.input.address_book_entry_id)?;
         }
+        RequestOperation::ManageAddressBookLabels(_) => (),
         RequestOperation::AddUser(op) => {
             EnsureUserGroup::id_list_exists(&op.input.groups)?;
         }
@@ -206,8 +328,26 @@ fn validate_request_operation_foreign_keys(
             }
         }
         RequestOperation::AddUserGroup(_) => (),
+        RequestOperation::AddTeam(op) => {
+            EnsureUser::id_list_exists(&op.input.member_ids)?;
+
+            for resource in &op.input.resources {
+                resource.validate()?;
+            }
+        }
+        // the resources and users missing in the station are reported by the import instead
+        RequestOperation::ImportAccessPolicies(_) => (),
+        RequestOperation::AddAsset(_) => (),
+        RequestOperation::EditAsset(op) => {
+            EnsureRegisteredAsset::id_exists(&op.input.asset_id)?;
+        }
+        RequestOperation::RemoveAsset(op) => {
+            EnsureRegisteredAsset::id_exists(&op.input.asset_id)?;
+        }
         RequestOperation::EditUserGroup(op) => {
             EnsureUserGroup::id_exists(&op.input.user_group_id)?;
+            EnsureUser::id_list_exists(&op.input.add_members)?;
+            EnsureUser::id_list_exists(&op.input.remove_members)?;
         }
         RequestOperation::RemoveUserGroup(ok) => {
             EnsureUserGroup::id_exists(&ok.input.user_group_id)?;
@@ -223,7 +363,12 @@ fn validate_request_operation_foreign_keys(
                 }
             }
         }
-        RequestOperation::FundExternalCanister(_) => (),
+        RequestOperation::FundExternalCanister(op) => {
+            let FundExternalCanisterOperationKind::Send(input) = &op.kind;
+            if let Some(from_account_id) = &input.from_account_id {
+                EnsureAccount::id_exists(from_account_id)?;
+            }
+        }
         RequestOperation::CreateExternalCanister(op) => {
             op.input.validate()?;
         }
@@ -266,6 +411,8 @@ impl ModelValidator<RequestError> for Request {
     fn validate(&self) -> ModelValidatorResult<RequestError> {
         validate_title(&self.title)?;
         validate_summary(&self.summary)?;
+        validate_tags(&self.tags)?;
+        validate_depends_on(self)?;
         validate_requested_by(&self.requested_by)?;
 
         validate_request_operation_foreign_keys(&self.operation)?;
@@ -277,6 +424,129 @@ impl ModelValidator<RequestError> for Request {
 impl Request {
     pub const MAX_TITLE_LEN: u8 = 255;
     pub const MAX_SUMMARY_LEN: u16 = 1000;
+    pub const MAX_TAGS: u8 = 10;
+    pub const MAX_TAG_LEN: u8 = 32;
+    pub const MAX_AMENDMENTS: usize = 10;
+
+    /// Normalizes the tags so that they match regardless of their case and surrounding whitespace,
+    /// the duplicates are removed.
+    pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+        let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let tag = tag.trim().to_lowercase();
+            if !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+
+        normalized
+    }
+
+    /// Returns whether the dependency of the request completed, is still pending or can no longer
+    /// complete.
+    pub fn dependency_state(&self) -> RequestDependencyState {
+        let Some(dependency_id) = self.depends_on else {
+            return RequestDependencyState::Satisfied;
+        };
+
+        let dependency_id_str = Uuid::from_bytes(dependency_id).hyphenated().to_string();
+        let Some(dependency) = REQUEST_REPOSITORY.get(&Request::key(dependency_id)) else {
+            return RequestDependencyState::Failed {
+                reason: format!(
+                    "The request {} it depends on was not found.",
+                    dependency_id_str
+                ),
+            };
+        };
+
+        match dependency.status {
+            RequestStatus::Completed { .. } => RequestDependencyState::Satisfied,
+            RequestStatus::Rejected => RequestDependencyState::Failed {
+                reason: format!(
+                    "The request {} it depends on was rejected.",
+                    dependency_id_str
+                ),
+            },
+            RequestStatus::Cancelled { .. } => RequestDependencyState::Failed {
+                reason: format!(
+                    "The request {} it depends on was cancelled.",
+                    dependency_id_str
+                ),
+            },
+            RequestStatus::Failed { .. } => RequestDependencyState::Failed {
+                reason: format!("The request {} it depends on failed.", dependency_id_str),
+            },
+            RequestStatus::Created
+            | RequestStatus::Validating
+            | RequestStatus::Approved
+            | RequestStatus::Scheduled { .. }
+            | RequestStatus::Processing { .. } => RequestDependencyState::Pending,
+        }
+    }
+
+    /// Returns whether users other than the requester voted on the request.
+    pub fn has_votes_from_others(&self) -> bool {
+        self.approvals
+            .iter()
+            .any(|approval| approval.approver_id != self.requested_by)
+    }
+
+    /// Replaces the operation of the pending request and resets its votes, the previous operation
+    /// is recorded in the amendments of the request.
+    ///
+    /// The request can only be amended with an operation of the same type, and only as long as no
+    /// user other than the requester voted on it.
+    pub fn amend(
+        &mut self,
+        operation: RequestOperation,
+        reason: Option<String>,
+        amended_at: Timestamp,
+    ) -> ModelValidatorResult<RequestError> {
+        if self.status != RequestStatus::Created {
+            return Err(RequestError::NotAllowedModification {
+                request_id: Uuid::from_bytes(self.id).hyphenated().to_string(),
+            });
+        }
+
+        if self.has_votes_from_others() {
+            return Err(RequestError::AmendmentNotAllowed);
+        }
+
+        if std::mem::discriminant(&self.operation) != std::mem::discriminant(&operation) {
+            return Err(RequestError::ValidationError {
+                info: "The request can only be amended with an operation of the same type"
+                    .to_string(),
+            });
+        }
+
+        if self.amendments.len() >= Self::MAX_AMENDMENTS {
+            return Err(RequestError::ValidationError {
+                info: format!(
+                    "The request cannot be amended more than {} times",
+                    Self::MAX_AMENDMENTS
+                ),
+            });
+        }
+
+        if let Some(reason) = &reason {
+            if reason.len() > RequestApproval::MAX_REASON_LEN as usize {
+                return Err(RequestError::ApprovalReasonTooLong {
+                    max_len: RequestApproval::MAX_REASON_LEN,
+                });
+            }
+        }
+
+        let previous_operation = std::mem::replace(&mut self.operation, operation);
+        self.amendments.push(RequestAmendment {
+            previous_operation,
+            reason,
+            amended_at,
+        });
+        self.approvals.clear();
+        self.last_modification_timestamp = amended_at;
+
+        Ok(())
+    }
 
     /// Creates a new request key from the given key components.
     pub fn key(request_id: RequestId) -> RequestKey {
@@ -300,11 +570,40 @@ impl Request {
         approvers
     }
 
-    /// Gives the default expiration date for a request which is 30 days from the current time.
+    /// Gives the default expiration date for a request, which is the default expiration period
+    /// configured for the station from the current time.
     pub fn default_expiration_dt_ns() -> Timestamp {
-        let time_in_ns: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+        let expiration_secs = match read_system_state() {
+            SystemState::Initialized(system_info) => {
+                system_info.get_default_request_expiration_secs()
+            }
+            SystemState::Uninitialized => SystemInfo::DEFAULT_REQUEST_EXPIRATION_SECS,
+        };
+
+        next_time() + expiration_secs * 1_000_000_000
+    }
 
-        next_time() + time_in_ns
+    /// Returns the expiration date that the policies matching the operation set for the request,
+    /// the shortest expiration period applies when several of them define one.
+    pub fn policy_expiration_dt(&self) -> Option<Timestamp> {
+        self.operation
+            .to_resources()
+            .into_iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource))
+            .filter_map(|policy| policy.expiration_period_ns())
+            .min()
+            .map(|expiration_period_ns| next_time().saturating_add(expiration_period_ns))
+    }
+
+    /// Returns the strictest max re-authentication age that the policies of the request require from
+    /// the approvers, in minutes.
+    pub fn max_reauthentication_age_mins(&self) -> Option<u32> {
+        self.operation
+            .to_resources()
+            .into_iter()
+            .flat_map(|resource| REQUEST_POLICY_REPOSITORY.find_by_resource(resource))
+            .filter_map(|policy| policy.rule.max_reauthentication_age_mins())
+            .min()
     }
 
     /// Checks if the user can approve the request.
@@ -347,6 +646,7 @@ impl Request {
         user_id: UUID,
         decision: RequestApprovalStatus,
         reason: Option<String>,
+        reauthenticated_at: Option<Timestamp>,
     ) -> ModelValidatorResult<RequestError> {
         if self
             .approvals
@@ -364,6 +664,7 @@ impl Request {
             status_reason: reason,
             decided_dt: now,
             last_modification_timestamp: now,
+            reauthenticated_at,
         };
 
         approval.validate()?;
@@ -374,12 +675,32 @@ impl Request {
     }
 
     pub async fn reevaluate(&mut self) -> Result<Option<RequestEvaluationResult>, EvaluateError> {
-        if self.status == RequestStatus::Created {
-            let evaluator = RequestEvaluator {
-                request: self.to_owned(),
-                policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
-            };
+        self.apply_evaluation(RequestEvaluator {
+            request: self.to_owned(),
+            policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+        })
+    }
 
+    /// Reevaluates the request after the vote of the user was added to it, applying the vote to
+    /// the previous evaluation of the request when it is still current.
+    pub async fn reevaluate_vote(
+        &mut self,
+        voter_id: UserId,
+        previous_evaluation: Option<RequestEvaluationResult>,
+    ) -> Result<Option<RequestEvaluationResult>, EvaluateError> {
+        self.apply_evaluation(RequestVoteEvaluator {
+            request: self.to_owned(),
+            policy_rule_evaluator: REQUEST_POLICY_RULE_EVALUATOR.to_owned(),
+            previous_evaluation,
+            voter_id,
+        })
+    }
+
+    fn apply_evaluation(
+        &mut self,
+        evaluator: impl Evaluate<RequestEvaluationResult>,
+    ) -> Result<Option<RequestEvaluationResult>, EvaluateError> {
+        if self.status == RequestStatus::Created {
             let evaluation_result = evaluator.evaluate()?;
 
             if evaluation_result.status == EvaluationStatus::Approved {
@@ -404,6 +725,16 @@ impl Request {
         evaluator.evaluate()
     }
 
+    /// Whether the request has validations that require inter-canister calls, these run after the
+    /// request is created.
+    pub fn requires_async_validation(&self) -> bool {
+        matches!(
+            &self.operation,
+            RequestOperation::CallExternalCanister(operation)
+                if operation.input.validation_method.is_some()
+        )
+    }
+
     /// Checks if the request is finalized.
     ///
     /// A request that is finalized won't have its status changed anymore.
@@ -451,6 +782,115 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn request_dependency_state_follows_the_dependency() {
+        let mut dependency = mock_request();
+        dependency.status = RequestStatus::Created;
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        let mut request = mock_request();
+        request.depends_on = Some(dependency.id);
+
+        assert_eq!(request.dependency_state(), RequestDependencyState::Pending);
+        assert!(validate_depends_on(&request).is_ok());
+
+        dependency.status = RequestStatus::Completed { completed_at: 0 };
+        REQUEST_REPOSITORY.insert(dependency.to_key(), dependency.clone());
+
+        assert_eq!(
+            request.dependency_state(),
+            RequestDependencyState::Satisfied
+        );
+
+        request.depends_on = Some([9; 16]);
+
+        assert!(matches!(
+            request.dependency_state(),
+            RequestDependencyState::Failed { .. }
+        ));
+        assert!(validate_depends_on(&request).is_err());
+    }
+
+    #[test]
+    fn request_is_amended_until_others_vote() {
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        let previous_operation = request.operation.clone();
+
+        let mut amended_operation = previous_operation.clone();
+        if let RequestOperation::Transfer(operation) = &mut amended_operation {
+            operation.input.to = "0x5678".to_string();
+        }
+
+        request
+            .amend(
+                amended_operation.clone(),
+                Some("Wrong destination".to_string()),
+                10,
+            )
+            .expect("The requester vote should not prevent the amendment");
+
+        assert_eq!(request.operation, amended_operation);
+        assert!(request.approvals.is_empty());
+        assert_eq!(request.amendments.len(), 1);
+        assert_eq!(request.amendments[0].previous_operation, previous_operation);
+        assert_eq!(request.amendments[0].amended_at, 10);
+
+        request
+            .add_approval([2; 16], RequestApprovalStatus::Approved, None, None)
+            .unwrap();
+
+        assert_eq!(
+            request.amend(previous_operation, None, 20),
+            Err(RequestError::AmendmentNotAllowed)
+        );
+    }
+
+    #[test]
+    fn request_cannot_be_amended_with_another_operation_type() {
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+
+        let result = request.amend(
+            RequestOperation::AddUser(AddUserOperation {
+                user_id: None,
+                input: AddUserOperationInput {
+                    name: "user-1".to_string(),
+                    identities: vec![],
+                    groups: vec![],
+                    status: crate::models::UserStatus::Active,
+                    approve_only_identities: vec![],
+                },
+            }),
+            None,
+            10,
+        );
+
+        assert!(matches!(result, Err(RequestError::ValidationError { .. })));
+        assert!(request.amendments.is_empty());
+    }
+
+    #[test]
+    fn request_tags_are_normalized_and_validated() {
+        let tags = Request::normalize_tags(vec![
+            " Treasury ".to_string(),
+            "treasury".to_string(),
+            "OPS".to_string(),
+        ]);
+
+        assert_eq!(tags, vec!["treasury".to_string(), "ops".to_string()]);
+        assert!(validate_tags(&tags).is_ok());
+
+        assert!(validate_tags(&["".to_string()]).is_err());
+        assert!(validate_tags(&["a".repeat(Request::MAX_TAG_LEN as usize + 1)]).is_err());
+        assert!(validate_tags(
+            &(0..=Request::MAX_TAGS)
+                .map(|i| format!("tag-{}", i))
+                .collect::<Vec<_>>()
+        )
+        .is_err());
+    }
+
     #[test]
     fn fail_request_summary_too_big() {
         let mut request = mock_request();
@@ -482,6 +922,7 @@ mod tests {
                     name: "a".to_owned(),
                     blockchain: crate::models::Blockchain::InternetComputer,
                     standard: crate::models::BlockchainStandard::Native,
+                    network: crate::models::NetworkProfile::Mainnet,
                     metadata: Metadata::default(),
                     read_permission: Allow::default(),
                     configs_permission: Allow::default(),
@@ -505,6 +946,10 @@ mod tests {
                 metadata: Metadata::default(),
                 to: "0x1234".to_string(),
                 from_account_id: account.id,
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
             },
         });
 
@@ -527,6 +972,10 @@ mod tests {
                 metadata: Metadata::default(),
                 to: "0x1234".to_string(),
                 from_account_id: [0; 16],
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
             },
         }))
         .expect_err("Invalid account id should fail");
@@ -538,6 +987,7 @@ mod tests {
                 identities: vec![],
                 groups: vec![[1; 16]],
                 status: crate::models::UserStatus::Active,
+                approve_only_identities: vec![],
             },
         }))
         .expect_err("Invalid user group id should fail");
@@ -547,7 +997,10 @@ mod tests {
                 input: crate::models::EditUserGroupOperationInput {
                     user_group_id: [0; 16],
                     name: "a".to_owned(),
+                    add_members: vec![],
+                    remove_members: vec![],
                 },
+                previous: None,
             },
         ))
         .expect_err("Invalid user group id should fail");
@@ -568,7 +1021,9 @@ mod tests {
                         crate::models::resource::ResourceIds::Ids(vec![[1; 16]]),
                     ),
                     rule: crate::models::request_policy_rule::RequestPolicyRule::AutoApproved,
+                    expiration_period_secs: None,
                 },
+                effective_from: None,
             },
         ))
         .expect_err("Invalid request specifier should fail");
@@ -579,7 +1034,10 @@ mod tests {
                     policy_id: [0; 16],
                     specifier: None,
                     rule: None,
+                    expiration_period_secs: None,
                 },
+                previous: None,
+                effective_from: None,
             },
         ))
         .expect_err("Invalid request policy id should fail");
@@ -598,6 +1056,7 @@ mod tests {
                     name: "a".to_owned(),
                     blockchain: crate::models::Blockchain::InternetComputer,
                     standard: crate::models::BlockchainStandard::Native,
+                    network: crate::models::NetworkProfile::Mainnet,
                     metadata: Metadata::default(),
                     read_permission: Allow {
                         auth_scope: crate::models::permission::AuthScope::Restricted,
@@ -623,7 +1082,10 @@ mod tests {
                     configs_request_policy: None,
                     transfer_request_policy: None,
                     name: None,
+                    webhooks: None,
+                    assets: None,
                 },
+                previous: None,
             },
         ))
         .expect_err("Invalid account id should fail");
@@ -635,7 +1097,9 @@ mod tests {
                     address_owner: None,
                     change_metadata: None,
                     labels: None,
+                    requires_memo: None,
                 },
+                previous: None,
             },
         ))
         .expect_err("Invalid address book entry id should fail");
@@ -658,7 +1122,9 @@ mod tests {
                     identities: None,
                     status: None,
                     cancel_pending_requests: None,
+                    approve_only_identities: None,
                 },
+                previous: None,
             },
         ))
         .expect_err("Invalid user id should fail");
@@ -675,6 +1141,8 @@ mod tests {
                     user_groups: None,
                     auth_scope: None,
                 },
+                previous: None,
+                effective_from: None,
             },
         ))
         .expect_err("Invalid resource id should fail");
@@ -709,6 +1177,10 @@ pub mod request_test_utils {
                     metadata: Metadata::default(),
                     to: "0x1234".to_string(),
                     from_account_id: [1; 16],
+                    spend_from: None,
+                    memo: None,
+                    category: None,
+                    asset_id: None,
                 },
             }),
             approvals: vec![RequestApproval {
@@ -717,7 +1189,13 @@ pub mod request_test_utils {
                 status_reason: None,
                 decided_dt: 0,
                 last_modification_timestamp: 0,
+                reauthenticated_at: None,
             }],
+            confidential: false,
+            tags: vec![],
+            depends_on: None,
+            amendments: vec![],
+            vote_reminder_sent: false,
             created_timestamp: 0,
             last_modification_timestamp: 0,
         }
@@ -19,10 +19,21 @@ pub struct RequestApproval {
     pub decided_dt: Timestamp,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
+    /// When the approver last re-authenticated from another of its identities before the decision.
+    #[serde(default)]
+    pub reauthenticated_at: Option<Timestamp>,
 }
 
 impl RequestApproval {
     pub const MAX_REASON_LEN: u8 = 200;
+
+    /// Whether the decision was made within `max_reauthentication_age_mins` of the approver re-authenticating.
+    pub fn is_recently_reauthenticated(&self, max_reauthentication_age_mins: u32) -> bool {
+        self.reauthenticated_at.is_some_and(|reauthenticated_at| {
+            self.decided_dt.saturating_sub(reauthenticated_at)
+                <= u64::from(max_reauthentication_age_mins) * 60 * 1_000_000_000
+        })
+    }
 }
 
 fn validate_reason(reason: &Option<String>) -> ModelValidatorResult<RequestError> {
@@ -87,6 +98,7 @@ pub mod request_approval_test_utils {
             status_reason: None,
             decided_dt: 0,
             last_modification_timestamp: 0,
+            reauthenticated_at: None,
         }
     }
 
@@ -97,6 +109,7 @@ pub mod request_approval_test_utils {
             status_reason: None,
             decided_dt: 0,
             last_modification_timestamp: 0,
+            reauthenticated_at: None,
         }
     }
 
@@ -107,6 +120,7 @@ pub mod request_approval_test_utils {
             status_reason: None,
             decided_dt: 0,
             last_modification_timestamp: 0,
+            reauthenticated_at: None,
         }
     }
 }
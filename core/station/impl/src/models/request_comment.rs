@@ -0,0 +1,105 @@
+use super::{RequestId, UserId};
+use crate::errors::RequestCommentError;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The request comment id, which is a UUID.
+pub type RequestCommentId = UUID;
+
+/// The key of the comments, sorted by request and then by creation time so that the thread of a
+/// request can be read with a range scan.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCommentKey {
+    pub request_id: RequestId,
+    pub created_timestamp: Timestamp,
+    pub id: RequestCommentId,
+}
+
+/// A comment left on a request, so that the requester and the approvers can discuss it in-context.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestComment {
+    pub id: RequestCommentId,
+    pub request_id: RequestId,
+    pub author_id: UserId,
+    pub body: String,
+    pub created_timestamp: Timestamp,
+}
+
+impl ModelKey<RequestCommentKey> for RequestComment {
+    fn key(&self) -> RequestCommentKey {
+        RequestCommentKey {
+            request_id: self.request_id,
+            created_timestamp: self.created_timestamp,
+            id: self.id,
+        }
+    }
+}
+
+impl RequestComment {
+    pub const MAX_BODY_LEN: usize = 2000;
+    /// The maximum number of comments in the thread of a request.
+    pub const MAX_COMMENTS_PER_REQUEST: usize = 500;
+}
+
+impl ModelValidator<RequestCommentError> for RequestComment {
+    fn validate(&self) -> ModelValidatorResult<RequestCommentError> {
+        if self.body.trim().is_empty() || self.body.chars().count() > Self::MAX_BODY_LEN {
+            return Err(RequestCommentError::InvalidBodyLength {
+                max_length: Self::MAX_BODY_LEN,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::request_comment_test_utils::mock_request_comment;
+    use super::*;
+
+    #[test]
+    fn fail_request_comment_blank_body() {
+        let mut comment = mock_request_comment();
+        comment.body = " \n".to_string();
+
+        assert_eq!(
+            comment.validate(),
+            Err(RequestCommentError::InvalidBodyLength {
+                max_length: RequestComment::MAX_BODY_LEN,
+            })
+        );
+    }
+
+    #[test]
+    fn fail_request_comment_body_too_long() {
+        let mut comment = mock_request_comment();
+        comment.body = "a".repeat(RequestComment::MAX_BODY_LEN + 1);
+
+        assert!(comment.validate().is_err());
+
+        comment.body = "a".repeat(RequestComment::MAX_BODY_LEN);
+
+        assert!(comment.validate().is_ok());
+    }
+}
+
+#[cfg(any(test, feature = "canbench"))]
+pub mod request_comment_test_utils {
+    use super::*;
+
+    pub fn mock_request_comment() -> RequestComment {
+        RequestComment {
+            id: [0; 16],
+            request_id: [1; 16],
+            author_id: [2; 16],
+            body: "Is the destination the new cold wallet?".to_string(),
+            created_timestamp: 0,
+        }
+    }
+}
@@ -1,11 +1,16 @@
 use super::{
-    permission::{Allow, AuthScope},
+    permission::{AccessPolicyDocument, AccessPolicyImportReport, Allow, AuthScope},
     request_policy_rule::{RequestPolicyRule, RequestPolicyRuleInput},
     request_specifier::RequestSpecifier,
     resource::{Resource, ValidationMethodResourceTarget},
-    AccountId, AddressBookEntryId, Blockchain, BlockchainStandard, ChangeMetadata,
-    CycleObtainStrategy, DisasterRecoveryCommittee, ExternalCanisterCallPermission,
-    ExternalCanisterState, MetadataItem, UserGroupId, UserId, UserStatus,
+    AccountId, AccountWebhook, AddressBookEntryId, ApprovalReminders, ArchiveSink, Blockchain,
+    BlockchainStandard, CapabilityGrantId, CapabilityScope, ChangeMetadata, CycleObtainStrategy,
+    CycleThresholds, DisasterRecoveryCommittee, ExternalCanisterCallPermission,
+    ExternalCanisterMonitoringInput, ExternalCanisterState, FinalityThreshold, IcrcAccount,
+    MetadataItem, NetworkProfile, NotificationTemplateChange, RegisteredAssetId,
+    RequestCreationRateLimits, RequestOperationLimits, RequestPolicyExpirationInput,
+    RpcProvidersConfig, ScheduledTransferId, StableMemoryGuardrail, TransferMemo,
+    TransferRetryPolicy, TrustedDestination, UserGroupId, UserId, UserStatus,
 };
 use crate::core::validation::EnsureExternalCanister;
 use crate::errors::ValidationError;
@@ -14,7 +19,10 @@ use candid::Principal;
 use orbit_essentials::cdk::api::management_canister::main::{self as mgmt};
 use orbit_essentials::cmc::SubnetSelection;
 use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
-use orbit_essentials::{storable, types::UUID};
+use orbit_essentials::{
+    storable,
+    types::{Timestamp, UUID},
+};
 use std::fmt::Display;
 
 #[storable(skip_deserialize = true)]
@@ -44,6 +52,22 @@ pub enum RequestOperation {
     RemoveRequestPolicy(RemoveRequestPolicyOperation),
     ManageSystemInfo(ManageSystemInfoOperation),
     SetDisasterRecovery(SetDisasterRecoveryOperation),
+    Approve(ApproveOperation),
+    SetAutoApprovalForTrustedDestinations(SetAutoApprovalForTrustedDestinationsOperation),
+    TransferNft(TransferNftOperation),
+    DeriveSubaccount(DeriveSubaccountOperation),
+    ImportAccessPolicies(ImportAccessPoliciesOperation),
+    AddAsset(AddAssetOperation),
+    EditAsset(EditAssetOperation),
+    RemoveAsset(RemoveAssetOperation),
+    SwapTokens(SwapTokensOperation),
+    AddScheduledTransfer(AddScheduledTransferOperation),
+    AddTeam(AddTeamOperation),
+    SweepAccount(SweepAccountOperation),
+    SplitTransfer(SplitTransferOperation),
+    ManageAddressBookLabels(ManageAddressBookLabelsOperation),
+    CreateCapabilityGrant(CreateCapabilityGrantOperation),
+    RevokeCapabilityGrant(RevokeCapabilityGrantOperation),
 }
 
 impl Display for RequestOperation {
@@ -74,6 +98,26 @@ impl Display for RequestOperation {
             RequestOperation::RemoveRequestPolicy(_) => write!(f, "remove_request_policy"),
             RequestOperation::ManageSystemInfo(_) => write!(f, "manage_system_info"),
             RequestOperation::SetDisasterRecovery(_) => write!(f, "set_disaster_recovery"),
+            RequestOperation::Approve(_) => write!(f, "approve"),
+            RequestOperation::SetAutoApprovalForTrustedDestinations(_) => {
+                write!(f, "set_auto_approval_for_trusted_destinations")
+            }
+            RequestOperation::TransferNft(_) => write!(f, "transfer_nft"),
+            RequestOperation::DeriveSubaccount(_) => write!(f, "derive_subaccount"),
+            RequestOperation::ImportAccessPolicies(_) => write!(f, "import_access_policies"),
+            RequestOperation::AddAsset(_) => write!(f, "add_asset"),
+            RequestOperation::EditAsset(_) => write!(f, "edit_asset"),
+            RequestOperation::RemoveAsset(_) => write!(f, "remove_asset"),
+            RequestOperation::SwapTokens(_) => write!(f, "swap_tokens"),
+            RequestOperation::AddScheduledTransfer(_) => write!(f, "add_scheduled_transfer"),
+            RequestOperation::AddTeam(_) => write!(f, "add_team"),
+            RequestOperation::SweepAccount(_) => write!(f, "sweep_account"),
+            RequestOperation::SplitTransfer(_) => write!(f, "split_transfer"),
+            RequestOperation::ManageAddressBookLabels(_) => {
+                write!(f, "manage_address_book_labels")
+            }
+            RequestOperation::CreateCapabilityGrant(_) => write!(f, "create_capability_grant"),
+            RequestOperation::RevokeCapabilityGrant(_) => write!(f, "revoke_capability_grant"),
         }
     }
 }
@@ -95,6 +139,212 @@ pub struct TransferOperationInput {
     pub metadata: Metadata,
     pub network: String,
     pub fee: Option<candid::Nat>,
+    /// The ledger account to pull the funds from with `icrc2_transfer_from`, using the allowance
+    /// it approved for the station account.
+    #[serde(default)]
+    pub spend_from: Option<IcrcAccount>,
+    #[serde(default)]
+    pub memo: Option<TransferMemo>,
+    /// The registered transfer category the transfer is booked under.
+    #[serde(default)]
+    pub category: Option<String>,
+    /// The registered asset held by the account that is sent, the own asset of the account if not set.
+    #[serde(default)]
+    pub asset_id: Option<RegisteredAssetId>,
+}
+
+/// Transfers the full balance of an account minus the fee, both are only known when the request
+/// is executed so that no dust is left behind by a balance that changed after its creation.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SweepAccountOperation {
+    pub transfer_id: Option<UUID>,
+    pub input: SweepAccountOperationInput,
+    /// The swept amount, only available after the operation is executed.
+    pub amount: Option<candid::Nat>,
+    pub fee: Option<candid::Nat>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SweepAccountOperationInput {
+    pub from_account_id: AccountId,
+    pub to: String,
+    pub metadata: Metadata,
+    pub network: String,
+    pub memo: Option<TransferMemo>,
+}
+
+/// Distributes a total amount of an account across several destinations, one transfer each, that
+/// are submitted one after the other once the request is executed.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SplitTransferOperation {
+    /// The transfers of the destinations in their order, only available after the operation is executed.
+    pub transfer_ids: Vec<UUID>,
+    pub input: SplitTransferOperationInput,
+    /// The fee paid by each of the transfers, only available after the operation is executed.
+    pub fee: Option<candid::Nat>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SplitTransferOperationInput {
+    pub from_account_id: AccountId,
+    pub total_amount: candid::Nat,
+    pub destinations: Vec<SplitTransferDestination>,
+    pub metadata: Metadata,
+    pub network: String,
+    pub memo: Option<TransferMemo>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SplitTransferDestination {
+    pub to: String,
+    pub share: SplitTransferShare,
+}
+
+/// The part of the total amount sent to a destination.
+///
+/// The fixed amounts are paid first, the percentages split what is left of the total and must add
+/// up to 100%.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SplitTransferShare {
+    /// The percentage in basis points, e.g. `2_500` for 25%.
+    BasisPoints(u16),
+    Amount(candid::Nat),
+}
+
+impl SplitTransferOperationInput {
+    pub const MAX_DESTINATIONS: usize = 50;
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddScheduledTransferOperation {
+    /// The standing order created by the request, only available after the operation is executed.
+    pub scheduled_transfer_id: Option<ScheduledTransferId>,
+    pub input: AddScheduledTransferOperationInput,
+}
+
+/// Creates a standing order that repeats the transfer at a fixed interval.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddScheduledTransferOperationInput {
+    pub transfer: TransferOperationInput,
+    /// The time between two executions of the transfer.
+    pub interval_ns: u64,
+    /// The time of the first execution, the transfer is first executed with the request if not set.
+    pub start_at: Option<Timestamp>,
+    /// No execution happens after this time, the standing order runs until it is cancelled if not set.
+    pub end_at: Option<Timestamp>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApproveOperation {
+    /// The ledger block index of the approval, only available after the operation is executed.
+    pub block_index: Option<u64>,
+    pub input: ApproveOperationInput,
+}
+
+/// Grants an ICRC-2 allowance to the spender over the funds of the station account.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApproveOperationInput {
+    pub from_account_id: AccountId,
+    pub spender: IcrcAccount,
+    /// The allowance, which replaces any previous allowance of the spender.
+    pub amount: candid::Nat,
+    /// When set, the approval fails if the current allowance of the spender is different.
+    pub expected_allowance: Option<candid::Nat>,
+    pub expires_at: Option<Timestamp>,
+    pub fee: Option<candid::Nat>,
+}
+
+/// The decentralized exchanges that the swaps can be executed on.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dex {
+    ICPSwap,
+    Sonic,
+    KongSwap,
+}
+
+impl Display for Dex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dex::ICPSwap => write!(f, "icpswap"),
+            Dex::Sonic => write!(f, "sonic"),
+            Dex::KongSwap => write!(f, "kongswap"),
+        }
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SwapTokensOperation {
+    /// The trade of the swap, only available after the operation is executed.
+    pub result: Option<SwapTokensResult>,
+    pub input: SwapTokensOperationInput,
+}
+
+/// Swaps the asset of a station account for the asset of another station account on a DEX.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SwapTokensOperationInput {
+    pub from_account_id: AccountId,
+    pub to_account_id: AccountId,
+    pub dex: Dex,
+    /// The pool canister of the pair, required by the DEXes that have a canister per pool.
+    pub pool_canister_id: Option<Principal>,
+    pub amount_in: candid::Nat,
+    /// The amount of the bought asset that the approvers agreed to.
+    pub expected_amount_out: candid::Nat,
+    /// The maximum slippage from the expected amount, in basis points.
+    pub max_slippage_bps: u16,
+}
+
+impl SwapTokensOperationInput {
+    /// The maximum slippage that can be requested, in basis points.
+    pub const MAX_SLIPPAGE_BPS: u16 = 5_000;
+
+    /// The minimum amount of the bought asset, below which the swap is not executed.
+    pub fn min_amount_out(&self) -> candid::Nat {
+        let max_slippage_bps = self.max_slippage_bps.min(10_000);
+
+        self.expected_amount_out.clone() * candid::Nat::from(10_000 - max_slippage_bps)
+            / candid::Nat::from(10_000u16)
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SwapTokensResult {
+    pub amount_in: candid::Nat,
+    /// The amount of the bought asset received by the account.
+    pub amount_out: candid::Nat,
+    pub executed_at: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferNftOperation {
+    /// The collection transaction index of the transfer, only available after the operation is executed.
+    pub block_index: Option<u64>,
+    pub input: TransferNftOperationInput,
+}
+
+/// Transfers a token of the ICRC-7 collection held by the NFT account of the station.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferNftOperationInput {
+    pub from_account_id: AccountId,
+    pub token_id: candid::Nat,
+    pub to: IcrcAccount,
+    pub memo: Option<Vec<u8>>,
 }
 
 #[storable]
@@ -111,6 +361,8 @@ pub struct AddAccountOperationInput {
     pub name: String,
     pub blockchain: Blockchain,
     pub standard: BlockchainStandard,
+    #[serde(default)]
+    pub network: NetworkProfile,
     pub metadata: Metadata,
     pub read_permission: Allow,
     pub configs_permission: Allow,
@@ -119,10 +371,44 @@ pub struct AddAccountOperationInput {
     pub transfer_request_policy: Option<RequestPolicyRule>,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SetAutoApprovalForTrustedDestinationsOperation {
+    pub input: SetAutoApprovalForTrustedDestinationsOperationInput,
+}
+
+/// Replaces the trusted destinations of the account, whose transfers can then be auto approved
+/// by the `TrustedDestination` policy rule.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SetAutoApprovalForTrustedDestinationsOperationInput {
+    pub account_id: AccountId,
+    pub trusted_destinations: Vec<TrustedDestination>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeriveSubaccountOperation {
+    /// The index of the derived subaccount, only available after the operation is executed.
+    pub subaccount_index: Option<u32>,
+    pub input: DeriveSubaccountOperationInput,
+}
+
+/// Derives a new ledger subaccount for the account, whose balance is added to the account balance.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeriveSubaccountOperationInput {
+    pub account_id: AccountId,
+    pub name: String,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditAccountOperation {
     pub input: EditAccountOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditAccountOperationInput>,
 }
 
 #[storable]
@@ -135,6 +421,12 @@ pub struct EditAccountOperationInput {
     pub transfer_permission: Option<Allow>,
     pub configs_request_policy: Option<RequestPolicyRuleInput>,
     pub transfer_request_policy: Option<RequestPolicyRuleInput>,
+    /// Replaces the webhooks of the account if set.
+    #[serde(default)]
+    pub webhooks: Option<Vec<AccountWebhook>>,
+    /// Replaces the registered assets held by the account if set.
+    #[serde(default)]
+    pub assets: Option<Vec<RegisteredAssetId>>,
 }
 
 #[storable]
@@ -154,12 +446,17 @@ pub struct AddAddressBookEntryOperationInput {
     #[serde(default)]
     pub labels: Vec<String>,
     pub metadata: Vec<MetadataItem>,
+    #[serde(default)]
+    pub requires_memo: bool,
 }
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditAddressBookEntryOperation {
     pub input: EditAddressBookEntryOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditAddressBookEntryOperationInput>,
 }
 
 #[storable]
@@ -170,6 +467,8 @@ pub struct EditAddressBookEntryOperationInput {
     pub change_metadata: Option<ChangeMetadata>,
     #[serde(default)]
     pub labels: Option<Vec<String>>,
+    #[serde(default)]
+    pub requires_memo: Option<bool>,
 }
 
 #[storable]
@@ -184,6 +483,58 @@ pub struct RemoveAddressBookEntryOperationInput {
     pub address_book_entry_id: AddressBookEntryId,
 }
 
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddressBookLabelInput {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Changes the taxonomy of the labels that the address book entries can use.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ManageAddressBookLabelsOperationInput {
+    /// The labels to define, the description of the labels that are already defined is replaced.
+    pub set_labels: Vec<AddressBookLabelInput>,
+    /// The labels to remove, which must no longer be used by any entry.
+    pub remove_labels: Vec<String>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ManageAddressBookLabelsOperation {
+    pub input: ManageAddressBookLabelsOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CreateCapabilityGrantOperation {
+    /// The capability grant id, only available after the operation is executed.
+    pub grant_id: Option<CapabilityGrantId>,
+    pub input: CreateCapabilityGrantOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CreateCapabilityGrantOperationInput {
+    pub account_id: AccountId,
+    pub grantee: Principal,
+    pub scopes: Vec<CapabilityScope>,
+    pub expires_at: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RevokeCapabilityGrantOperation {
+    pub input: RevokeCapabilityGrantOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RevokeCapabilityGrantOperationInput {
+    pub grant_id: CapabilityGrantId,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddUserOperation {
@@ -198,12 +549,17 @@ pub struct AddUserOperationInput {
     pub identities: Vec<Principal>,
     pub groups: Vec<UUID>,
     pub status: UserStatus,
+    #[serde(default)]
+    pub approve_only_identities: Vec<Principal>,
 }
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditUserOperation {
     pub input: EditUserOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditUserOperationInput>,
 }
 
 #[storable]
@@ -215,6 +571,8 @@ pub struct EditUserOperationInput {
     pub groups: Option<Vec<UUID>>,
     pub status: Option<UserStatus>,
     pub cancel_pending_requests: Option<bool>,
+    #[serde(default)]
+    pub approve_only_identities: Option<Vec<Principal>>,
 }
 
 #[storable]
@@ -230,10 +588,30 @@ pub struct AddUserGroupOperationInput {
     pub name: String,
 }
 
+/// Sets up a new team in one request, by creating its user group, adding the members to it and
+/// allowing it on the resources.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddTeamOperationInput {
+    pub name: String,
+    pub member_ids: Vec<UserId>,
+    pub resources: Vec<Resource>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddTeamOperation {
+    pub user_group_id: Option<UserGroupId>,
+    pub input: AddTeamOperationInput,
+}
+
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditUserGroupOperation {
     pub input: EditUserGroupOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditUserGroupOperationInput>,
 }
 
 #[storable]
@@ -241,6 +619,10 @@ pub struct EditUserGroupOperation {
 pub struct EditUserGroupOperationInput {
     pub user_group_id: UUID,
     pub name: String,
+    #[serde(default)]
+    pub add_members: Vec<UserId>,
+    #[serde(default)]
+    pub remove_members: Vec<UserId>,
 }
 
 #[storable]
@@ -338,6 +720,9 @@ pub struct ChangeExternalCanisterOperationInput {
     pub module: Vec<u8>,
     pub module_extra_chunks: Option<WasmModuleExtraChunks>,
     pub arg: Option<Vec<u8>>,
+    /// The pending request for the same canister that is cancelled once this one is approved.
+    #[serde(default)]
+    pub replaces: Option<UUID>,
 }
 
 #[storable]
@@ -507,6 +892,10 @@ pub struct CreateExternalCanisterOperation {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct FundExternalCanisterSendCyclesInput {
     pub cycles: u64,
+    /// The cycles ledger account the cycles are withdrawn from, the station's own cycles are sent
+    /// when it is not set.
+    #[serde(default)]
+    pub from_account_id: Option<AccountId>,
 }
 
 #[storable]
@@ -570,6 +959,8 @@ pub struct ConfigureExternalCanisterSettingsInput {
     pub state: Option<ExternalCanisterState>,
     pub permissions: Option<ExternalCanisterPermissionsUpdateInput>,
     pub request_policies: Option<ExternalCanisterRequestPoliciesUpdateInput>,
+    #[serde(default)]
+    pub monitoring: Option<ExternalCanisterMonitoringInput>,
 }
 
 #[storable]
@@ -622,6 +1013,81 @@ pub struct EditPermissionOperationInput {
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditPermissionOperation {
     pub input: EditPermissionOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditPermissionOperationInput>,
+    /// The time the changes take effect, until then they are kept as a scheduled policy change.
+    #[serde(default)]
+    pub effective_from: Option<Timestamp>,
+}
+
+/// Merges the permissions of an access policy document into the station.
+///
+/// The allowed users and groups are added to the ones of the existing permissions, the missing groups
+/// are created.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ImportAccessPoliciesOperationInput {
+    pub document: AccessPolicyDocument,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ImportAccessPoliciesOperation {
+    /// The report of the import, only available after the operation is executed.
+    pub report: Option<AccessPolicyImportReport>,
+    pub input: ImportAccessPoliciesOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddAssetOperationInput {
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u32,
+    pub metadata: Metadata,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AddAssetOperation {
+    /// The asset id is only available after the operation is executed.
+    pub asset_id: Option<RegisteredAssetId>,
+    pub input: AddAssetOperationInput,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EditAssetOperationInput {
+    pub asset_id: RegisteredAssetId,
+    pub index_canister_id: Option<Principal>,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+    pub decimals: Option<u32>,
+    pub change_metadata: Option<ChangeMetadata>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EditAssetOperation {
+    pub input: EditAssetOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditAssetOperationInput>,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RemoveAssetOperationInput {
+    pub asset_id: RegisteredAssetId,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RemoveAssetOperation {
+    pub input: RemoveAssetOperationInput,
 }
 
 #[storable]
@@ -629,13 +1095,19 @@ pub struct EditPermissionOperation {
 pub struct AddRequestPolicyOperationInput {
     pub specifier: RequestSpecifier,
     pub rule: RequestPolicyRule,
+    #[serde(default)]
+    pub expiration_period_secs: Option<u64>,
 }
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AddRequestPolicyOperation {
+    /// The policy created by the request, not set while the policy is scheduled.
     pub policy_id: Option<UUID>,
     pub input: AddRequestPolicyOperationInput,
+    /// The time the policy takes effect, until then it is kept as a scheduled policy change.
+    #[serde(default)]
+    pub effective_from: Option<Timestamp>,
 }
 
 #[storable]
@@ -644,12 +1116,20 @@ pub struct EditRequestPolicyOperationInput {
     pub policy_id: UUID,
     pub specifier: Option<RequestSpecifier>,
     pub rule: Option<RequestPolicyRule>,
+    #[serde(default)]
+    pub expiration_period_secs: Option<RequestPolicyExpirationInput>,
 }
 
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct EditRequestPolicyOperation {
     pub input: EditRequestPolicyOperationInput,
+    /// The current values of the fields changed by the input, snapshotted when the request was created.
+    #[serde(default)]
+    pub previous: Option<EditRequestPolicyOperationInput>,
+    /// The time the changes take effect, until then they are kept as a scheduled policy change.
+    #[serde(default)]
+    pub effective_from: Option<Timestamp>,
 }
 
 #[storable]
@@ -669,6 +1149,46 @@ pub struct RemoveRequestPolicyOperation {
 pub struct ManageSystemInfoOperationInput {
     pub name: Option<String>,
     pub cycle_obtain_strategy: Option<CycleObtainStrategy>,
+    #[serde(default)]
+    pub request_operation_limits: Option<RequestOperationLimits>,
+    #[serde(default)]
+    pub rpc_providers: Option<Vec<RpcProvidersConfig>>,
+    #[serde(default)]
+    pub finality_thresholds: Option<Vec<FinalityThreshold>>,
+    #[serde(default)]
+    pub transfer_retry_policy: Option<TransferRetryPolicy>,
+    #[serde(default)]
+    pub max_suggested_version: Option<VersionPin>,
+    #[serde(default)]
+    pub archive_sink: Option<ArchiveSinkChange>,
+    #[serde(default)]
+    pub stable_memory_guardrail: Option<StableMemoryGuardrail>,
+    #[serde(default)]
+    pub approval_reminders: Option<ApprovalReminders>,
+    #[serde(default)]
+    pub notification_templates: Option<Vec<NotificationTemplateChange>>,
+    #[serde(default)]
+    pub request_creation_rate_limits: Option<RequestCreationRateLimits>,
+    #[serde(default)]
+    pub default_request_expiration_secs: Option<u64>,
+    #[serde(default)]
+    pub cycle_thresholds: Option<CycleThresholds>,
+}
+
+/// Sets or removes the canister the settled history is exported to.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ArchiveSinkChange {
+    Set(ArchiveSink),
+    Remove,
+}
+
+/// Pins or unpins the maximum version suggested by the update checker.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum VersionPin {
+    Pin(String),
+    Unpin,
 }
 
 #[storable]
@@ -28,6 +28,22 @@ pub enum RequestOperationFilterType {
     ManageSystemInfo,
     ConfigureExternalCanister(Principal),
     FundExternalCanister(Principal),
+    Approve(AccountId),
+    SetAutoApprovalForTrustedDestinations(AccountId),
+    TransferNft(AccountId),
+    DeriveSubaccount(AccountId),
+    ImportAccessPolicies,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    SwapTokens(AccountId),
+    AddScheduledTransfer(AccountId),
+    AddTeam,
+    SweepAccount(AccountId),
+    SplitTransfer(AccountId),
+    ManageAddressBookLabels,
+    CreateCapabilityGrant(AccountId),
+    RevokeCapabilityGrant,
 }
 
 impl From<RequestOperation> for RequestOperationFilterType {
@@ -80,6 +96,50 @@ impl From<RequestOperation> for RequestOperationFilterType {
             RequestOperation::FundExternalCanister(operation) => {
                 RequestOperationFilterType::FundExternalCanister(operation.canister_id)
             }
+            RequestOperation::Approve(operation) => {
+                RequestOperationFilterType::Approve(operation.input.from_account_id)
+            }
+            RequestOperation::SetAutoApprovalForTrustedDestinations(operation) => {
+                RequestOperationFilterType::SetAutoApprovalForTrustedDestinations(
+                    operation.input.account_id,
+                )
+            }
+            RequestOperation::TransferNft(operation) => {
+                RequestOperationFilterType::TransferNft(operation.input.from_account_id)
+            }
+            RequestOperation::DeriveSubaccount(operation) => {
+                RequestOperationFilterType::DeriveSubaccount(operation.input.account_id)
+            }
+            RequestOperation::ImportAccessPolicies(_) => {
+                RequestOperationFilterType::ImportAccessPolicies
+            }
+            RequestOperation::AddAsset(_) => RequestOperationFilterType::AddAsset,
+            RequestOperation::EditAsset(_) => RequestOperationFilterType::EditAsset,
+            RequestOperation::RemoveAsset(_) => RequestOperationFilterType::RemoveAsset,
+            RequestOperation::SwapTokens(operation) => {
+                RequestOperationFilterType::SwapTokens(operation.input.from_account_id)
+            }
+            RequestOperation::AddScheduledTransfer(operation) => {
+                RequestOperationFilterType::AddScheduledTransfer(
+                    operation.input.transfer.from_account_id,
+                )
+            }
+            RequestOperation::AddTeam(_) => RequestOperationFilterType::AddTeam,
+            RequestOperation::SweepAccount(operation) => {
+                RequestOperationFilterType::SweepAccount(operation.input.from_account_id)
+            }
+            RequestOperation::SplitTransfer(operation) => {
+                RequestOperationFilterType::SplitTransfer(operation.input.from_account_id)
+            }
+            RequestOperation::ManageAddressBookLabels(_) => {
+                RequestOperationFilterType::ManageAddressBookLabels
+            }
+            RequestOperation::CreateCapabilityGrant(operation) => {
+                RequestOperationFilterType::CreateCapabilityGrant(operation.input.account_id)
+            }
+            RequestOperation::RevokeCapabilityGrant(_) => {
+                RequestOperationFilterType::RevokeCapabilityGrant
+            }
         }
     }
 }
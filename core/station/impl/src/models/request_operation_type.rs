@@ -33,10 +33,27 @@ pub enum RequestOperationType {
     SetDisasterRecovery = 23,
     ConfigureExternalCanister = 24,
     FundExternalCanister = 25,
+    Approve = 26,
+    SetAutoApprovalForTrustedDestinations = 27,
+    TransferNft = 28,
+    DeriveSubaccount = 29,
+    ImportAccessPolicies = 30,
+    AddAsset = 31,
+    EditAsset = 32,
+    RemoveAsset = 33,
+    SwapTokens = 34,
+    AddScheduledTransfer = 35,
+    AddTeam = 36,
+    SweepAccount = 37,
+    SplitTransfer = 38,
+    ManageAddressBookLabels = 39,
+    CreateCapabilityGrant = 40,
+    RevokeCapabilityGrant = 41,
 }
 
 /// A helper enum to filter the requests based on the operation type and
 /// optional additional data (e.g. account id).
+#[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ListRequestsOperationType {
     Transfer(Option<AccountId>),
@@ -62,6 +79,22 @@ pub enum ListRequestsOperationType {
     EditAddressBookEntry,
     RemoveAddressBookEntry,
     ManageSystemInfo,
+    Approve(Option<AccountId>),
+    SetAutoApprovalForTrustedDestinations(Option<AccountId>),
+    TransferNft(Option<AccountId>),
+    DeriveSubaccount(Option<AccountId>),
+    ImportAccessPolicies,
+    AddAsset,
+    EditAsset,
+    RemoveAsset,
+    SwapTokens(Option<AccountId>),
+    AddScheduledTransfer(Option<AccountId>),
+    AddTeam,
+    SweepAccount(Option<AccountId>),
+    SplitTransfer(Option<AccountId>),
+    ManageAddressBookLabels,
+    CreateCapabilityGrant(Option<AccountId>),
+    RevokeCapabilityGrant,
 }
 
 impl PartialEq<ListRequestsOperationType> for RequestOperationFilterType {
@@ -164,6 +197,84 @@ impl PartialEq<ListRequestsOperationType> for RequestOperationFilterType {
             ListRequestsOperationType::ManageSystemInfo => {
                 matches!(self, RequestOperationFilterType::ManageSystemInfo)
             }
+            ListRequestsOperationType::Approve(None) => {
+                matches!(self, RequestOperationFilterType::Approve(_))
+            }
+            ListRequestsOperationType::Approve(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::Approve(id) if id == account_id)
+            }
+            ListRequestsOperationType::SetAutoApprovalForTrustedDestinations(None) => {
+                matches!(
+                    self,
+                    RequestOperationFilterType::SetAutoApprovalForTrustedDestinations(_)
+                )
+            }
+            ListRequestsOperationType::SetAutoApprovalForTrustedDestinations(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::SetAutoApprovalForTrustedDestinations(id) if id == account_id)
+            }
+            ListRequestsOperationType::TransferNft(None) => {
+                matches!(self, RequestOperationFilterType::TransferNft(_))
+            }
+            ListRequestsOperationType::TransferNft(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::TransferNft(id) if id == account_id)
+            }
+            ListRequestsOperationType::DeriveSubaccount(None) => {
+                matches!(self, RequestOperationFilterType::DeriveSubaccount(_))
+            }
+            ListRequestsOperationType::DeriveSubaccount(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::DeriveSubaccount(id) if id == account_id)
+            }
+            ListRequestsOperationType::ImportAccessPolicies => {
+                matches!(self, RequestOperationFilterType::ImportAccessPolicies)
+            }
+            ListRequestsOperationType::AddAsset => {
+                matches!(self, RequestOperationFilterType::AddAsset)
+            }
+            ListRequestsOperationType::EditAsset => {
+                matches!(self, RequestOperationFilterType::EditAsset)
+            }
+            ListRequestsOperationType::RemoveAsset => {
+                matches!(self, RequestOperationFilterType::RemoveAsset)
+            }
+            ListRequestsOperationType::SwapTokens(None) => {
+                matches!(self, RequestOperationFilterType::SwapTokens(_))
+            }
+            ListRequestsOperationType::SwapTokens(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::SwapTokens(id) if id == account_id)
+            }
+            ListRequestsOperationType::AddScheduledTransfer(None) => {
+                matches!(self, RequestOperationFilterType::AddScheduledTransfer(_))
+            }
+            ListRequestsOperationType::AddScheduledTransfer(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::AddScheduledTransfer(id) if id == account_id)
+            }
+            ListRequestsOperationType::AddTeam => {
+                matches!(self, RequestOperationFilterType::AddTeam)
+            }
+            ListRequestsOperationType::SweepAccount(None) => {
+                matches!(self, RequestOperationFilterType::SweepAccount(_))
+            }
+            ListRequestsOperationType::SweepAccount(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::SweepAccount(id) if id == account_id)
+            }
+            ListRequestsOperationType::SplitTransfer(None) => {
+                matches!(self, RequestOperationFilterType::SplitTransfer(_))
+            }
+            ListRequestsOperationType::SplitTransfer(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::SplitTransfer(id) if id == account_id)
+            }
+            ListRequestsOperationType::ManageAddressBookLabels => {
+                matches!(self, RequestOperationFilterType::ManageAddressBookLabels)
+            }
+            ListRequestsOperationType::CreateCapabilityGrant(None) => {
+                matches!(self, RequestOperationFilterType::CreateCapabilityGrant(_))
+            }
+            ListRequestsOperationType::CreateCapabilityGrant(Some(account_id)) => {
+                matches!(self, RequestOperationFilterType::CreateCapabilityGrant(id) if id == account_id)
+            }
+            ListRequestsOperationType::RevokeCapabilityGrant => {
+                matches!(self, RequestOperationFilterType::RevokeCapabilityGrant)
+            }
         }
     }
 }
@@ -196,6 +307,24 @@ impl FromStr for RequestOperationType {
             "set_disaster_recovery_committee" => Ok(RequestOperationType::SetDisasterRecovery),
             "configure_external_canister" => Ok(RequestOperationType::ConfigureExternalCanister),
             "fund_external_canister" => Ok(RequestOperationType::FundExternalCanister),
+            "approve" => Ok(RequestOperationType::Approve),
+            "set_auto_approval_for_trusted_destinations" => {
+                Ok(RequestOperationType::SetAutoApprovalForTrustedDestinations)
+            }
+            "transfer_nft" => Ok(RequestOperationType::TransferNft),
+            "derive_subaccount" => Ok(RequestOperationType::DeriveSubaccount),
+            "import_access_policies" => Ok(RequestOperationType::ImportAccessPolicies),
+            "add_asset" => Ok(RequestOperationType::AddAsset),
+            "edit_asset" => Ok(RequestOperationType::EditAsset),
+            "remove_asset" => Ok(RequestOperationType::RemoveAsset),
+            "swap_tokens" => Ok(RequestOperationType::SwapTokens),
+            "add_scheduled_transfer" => Ok(RequestOperationType::AddScheduledTransfer),
+            "add_team" => Ok(RequestOperationType::AddTeam),
+            "sweep_account" => Ok(RequestOperationType::SweepAccount),
+            "split_transfer" => Ok(RequestOperationType::SplitTransfer),
+            "manage_address_book_labels" => Ok(RequestOperationType::ManageAddressBookLabels),
+            "create_capability_grant" => Ok(RequestOperationType::CreateCapabilityGrant),
+            "revoke_capability_grant" => Ok(RequestOperationType::RevokeCapabilityGrant),
             _ => Err(()),
         }
     }
@@ -231,6 +360,26 @@ impl Display for RequestOperationType {
                 write!(f, "configure_external_canister")
             }
             RequestOperationType::FundExternalCanister => write!(f, "fund_external_canister"),
+            RequestOperationType::Approve => write!(f, "approve"),
+            RequestOperationType::SetAutoApprovalForTrustedDestinations => {
+                write!(f, "set_auto_approval_for_trusted_destinations")
+            }
+            RequestOperationType::TransferNft => write!(f, "transfer_nft"),
+            RequestOperationType::DeriveSubaccount => write!(f, "derive_subaccount"),
+            RequestOperationType::ImportAccessPolicies => write!(f, "import_access_policies"),
+            RequestOperationType::AddAsset => write!(f, "add_asset"),
+            RequestOperationType::EditAsset => write!(f, "edit_asset"),
+            RequestOperationType::RemoveAsset => write!(f, "remove_asset"),
+            RequestOperationType::SwapTokens => write!(f, "swap_tokens"),
+            RequestOperationType::AddScheduledTransfer => write!(f, "add_scheduled_transfer"),
+            RequestOperationType::AddTeam => write!(f, "add_team"),
+            RequestOperationType::SweepAccount => write!(f, "sweep_account"),
+            RequestOperationType::SplitTransfer => write!(f, "split_transfer"),
+            RequestOperationType::ManageAddressBookLabels => {
+                write!(f, "manage_address_book_labels")
+            }
+            RequestOperationType::CreateCapabilityGrant => write!(f, "create_capability_grant"),
+            RequestOperationType::RevokeCapabilityGrant => write!(f, "revoke_capability_grant"),
         }
     }
 }
@@ -365,5 +514,69 @@ mod tests {
             RequestOperationType::from_str("fund_external_canister").unwrap(),
             RequestOperationType::FundExternalCanister
         );
+        assert_eq!(
+            RequestOperationType::from_str("approve").unwrap(),
+            RequestOperationType::Approve
+        );
+        assert_eq!(
+            RequestOperationType::from_str("set_auto_approval_for_trusted_destinations").unwrap(),
+            RequestOperationType::SetAutoApprovalForTrustedDestinations
+        );
+        assert_eq!(
+            RequestOperationType::from_str("transfer_nft").unwrap(),
+            RequestOperationType::TransferNft
+        );
+        assert_eq!(
+            RequestOperationType::from_str("derive_subaccount").unwrap(),
+            RequestOperationType::DeriveSubaccount
+        );
+        assert_eq!(
+            RequestOperationType::from_str("import_access_policies").unwrap(),
+            RequestOperationType::ImportAccessPolicies
+        );
+        assert_eq!(
+            RequestOperationType::from_str("add_asset").unwrap(),
+            RequestOperationType::AddAsset
+        );
+        assert_eq!(
+            RequestOperationType::from_str("edit_asset").unwrap(),
+            RequestOperationType::EditAsset
+        );
+        assert_eq!(
+            RequestOperationType::from_str("remove_asset").unwrap(),
+            RequestOperationType::RemoveAsset
+        );
+        assert_eq!(
+            RequestOperationType::from_str("swap_tokens").unwrap(),
+            RequestOperationType::SwapTokens
+        );
+        assert_eq!(
+            RequestOperationType::from_str("add_scheduled_transfer").unwrap(),
+            RequestOperationType::AddScheduledTransfer
+        );
+        assert_eq!(
+            RequestOperationType::from_str("add_team").unwrap(),
+            RequestOperationType::AddTeam
+        );
+        assert_eq!(
+            RequestOperationType::from_str("sweep_account").unwrap(),
+            RequestOperationType::SweepAccount
+        );
+        assert_eq!(
+            RequestOperationType::from_str("split_transfer").unwrap(),
+            RequestOperationType::SplitTransfer
+        );
+        assert_eq!(
+            RequestOperationType::from_str("manage_address_book_labels").unwrap(),
+            RequestOperationType::ManageAddressBookLabels
+        );
+        assert_eq!(
+            RequestOperationType::from_str("create_capability_grant").unwrap(),
+            RequestOperationType::CreateCapabilityGrant
+        );
+        assert_eq!(
+            RequestOperationType::from_str("revoke_capability_grant").unwrap(),
+            RequestOperationType::RevokeCapabilityGrant
+        );
     }
 }
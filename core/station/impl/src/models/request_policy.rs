@@ -22,6 +22,10 @@ pub struct RequestPolicy {
     pub id: UUID,
     pub specifier: RequestSpecifier,
     pub rule: RequestPolicyRule,
+    /// The time the requests matching the policy stay open for approvals, the default expiration
+    /// period of the requests applies if not set.
+    #[serde(default)]
+    pub expiration_period_secs: Option<u64>,
 }
 
 impl ModelKey<UUID> for RequestPolicy {
@@ -30,6 +34,23 @@ impl ModelKey<UUID> for RequestPolicy {
     }
 }
 
+impl RequestPolicy {
+    pub const MIN_EXPIRATION_PERIOD_SECS: u64 = 60 * 60;
+    pub const MAX_EXPIRATION_PERIOD_SECS: u64 = 365 * 24 * 60 * 60;
+
+    pub fn expiration_period_ns(&self) -> Option<u64> {
+        self.expiration_period_secs
+            .map(|secs| secs.saturating_mul(1_000_000_000))
+    }
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestPolicyExpirationInput {
+    Remove,
+    Set(u64),
+}
+
 #[derive(CandidType, Deserialize, Debug, Clone)]
 pub struct RequestPolicyCallerPrivileges {
     pub id: UUID,
@@ -57,10 +78,45 @@ impl ModelValidator<RequestPolicyError> for RequestPolicy {
     fn validate(&self) -> ModelValidatorResult<RequestPolicyError> {
         self.specifier.validate()?;
         self.rule.validate()?;
+
+        if let Some(expiration_period_secs) = self.expiration_period_secs {
+            if !(Self::MIN_EXPIRATION_PERIOD_SECS..=Self::MAX_EXPIRATION_PERIOD_SECS)
+                .contains(&expiration_period_secs)
+            {
+                return Err(RequestPolicyError::ValidationError {
+                    info: format!(
+                        "The expiration period must be between {} and {} seconds",
+                        Self::MIN_EXPIRATION_PERIOD_SECS,
+                        Self::MAX_EXPIRATION_PERIOD_SECS
+                    ),
+                });
+            }
+        }
+
         Ok(())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::request_policy_test_utils::mock_request_policy;
+    use super::*;
+
+    #[test]
+    fn fail_request_policy_expiration_period_out_of_range() {
+        let mut policy = mock_request_policy();
+
+        policy.expiration_period_secs = Some(RequestPolicy::MIN_EXPIRATION_PERIOD_SECS - 1);
+        assert!(policy.validate().is_err());
+
+        policy.expiration_period_secs = Some(RequestPolicy::MAX_EXPIRATION_PERIOD_SECS + 1);
+        assert!(policy.validate().is_err());
+
+        policy.expiration_period_secs = Some(RequestPolicy::MIN_EXPIRATION_PERIOD_SECS);
+        assert!(policy.validate().is_ok());
+    }
+}
+
 #[cfg(test)]
 pub mod request_policy_test_utils {
     use super::RequestPolicy;
@@ -74,6 +130,7 @@ pub mod request_policy_test_utils {
             id: *Uuid::new_v4().as_bytes(),
             specifier: RequestSpecifier::AddAccount,
             rule: RequestPolicyRule::AutoApproved,
+            expiration_period_secs: None,
         }
     }
 }
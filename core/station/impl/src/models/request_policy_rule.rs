@@ -2,17 +2,27 @@ use super::{
     request_specifier::{
         Match, RequestHasMetadata, UserInvolvedInPolicyRuleForRequestResource, UserSpecifier,
     },
-    EvaluateError, EvaluationStatus, MetadataItem, Percentage, Request, RequestApprovalStatus,
-    RequestId, RequestOperation, UserId, UserStatus,
+    AccountId, AccountSpend, AddressBookEntryId, EvaluateError, EvaluationStatus,
+    ListRequestsOperationType, MetadataItem, Percentage, Request, RequestApprovalStatus, RequestId,
+    RequestOperation, RequestStatusCode, TransferOperation, UserId, UserStatus,
 };
 use crate::{
-    core::{ic_cdk::api::print, utils::calculate_minimum_threshold},
+    core::{
+        ic_cdk::api::{print, time},
+        utils::calculate_minimum_threshold,
+    },
     errors::{MatchError, ValidationError},
-    repositories::{UserWhereClause, ADDRESS_BOOK_REPOSITORY, USER_REPOSITORY},
+    repositories::{
+        RequestWhereClause, UserWhereClause, ACCOUNT_SPEND_REPOSITORY, ADDRESS_BOOK_REPOSITORY,
+        DESTINATION_FIRST_USE_REPOSITORY, REQUEST_REPOSITORY, USER_REPOSITORY,
+    },
     services::ACCOUNT_SERVICE,
 };
+use candid::Nat;
 use orbit_essentials::model::{ModelKey, ModelValidator, ModelValidatorResult};
+use orbit_essentials::repository::Repository;
 use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
 use station_api::EvaluationSummaryReasonDTO;
 use std::{cmp, hash::Hash};
 use std::{collections::HashSet, sync::Arc};
@@ -25,21 +35,115 @@ pub enum RequestPolicyRule {
     Quorum(UserSpecifier, u16),
     AllowListedByMetadata(MetadataItem),
     AllowListed,
+    /// Approves transfers whose destination is in the address book with the label, combined with
+    /// `And` it only permits the transfers to these destinations.
+    AllowListedByLabel(String),
+    /// Approves transfers to the trusted destinations of the account, as long as the amount sent
+    /// to the destination within the period stays within its max amount.
+    TrustedDestination,
+    /// Requires every approval to be made within the given number of minutes of the approver
+    /// re-authenticating from another of its identities (e.g. a hardware key), it is meant to be
+    /// combined with a quorum for high-value requests.
+    RecentAuthentication(u32),
+    /// Approves the requests that move value out of an account (e.g. transfers, sweeps, swaps or
+    /// allowances) as long as the amount of the asset sent from the account within the rolling
+    /// window stays within the max amount, it is meant to be combined with a quorum to either
+    /// reject or escalate the requests that exceed it.
+    VelocityLimit(VelocityLimit),
+    /// Approves transfers whose amount is within the range, it is meant to be combined with the
+    /// rule of the approval tier of the range, e.g. `Or([And([AmountRange(..100), AutoApproved]),
+    /// And([AmountRange(100..), Quorum(..)])])`.
+    AmountRange(AmountRange),
+    /// Approves transfers whose destination was first paid at least the given number of days ago,
+    /// it is meant to be combined with a quorum so that new destinations get more scrutiny.
+    DestinationAge(u32),
+    /// Rejects the request as soon as one of the users rejects it, whatever the status of the other
+    /// rules and of the other matching policies. It never approves a request on its own.
+    Veto(UserSpecifier),
     // Logical operators
     Or(Vec<RequestPolicyRule>),
     And(Vec<RequestPolicyRule>),
     Not(Box<RequestPolicyRule>),
 }
 
+/// The max amount that can be sent from an account within a rolling window.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VelocityLimit {
+    pub max_amount: Nat,
+    /// The window is capped to the retention of the spends of the accounts.
+    pub window_secs: u64,
+}
+
+impl VelocityLimit {
+    pub fn window_ns(&self) -> u64 {
+        self.window_secs
+            .saturating_mul(1_000_000_000)
+            .min(AccountSpend::RETENTION_NS)
+    }
+}
+
+/// The range of the transfer amounts, from the min amount included to the max amount excluded so
+/// that adjacent tiers can share their bound.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AmountRange {
+    pub min_amount: Option<Nat>,
+    pub max_amount: Option<Nat>,
+}
+
+impl AmountRange {
+    pub fn contains(&self, amount: &Nat) -> bool {
+        self.min_amount.as_ref().map_or(true, |min| amount >= min)
+            && self.max_amount.as_ref().map_or(true, |max| amount < max)
+    }
+}
+
+impl RequestPolicyRule {
+    /// Returns the strictest max re-authentication age required by the rule to approve, if any.
+    pub fn max_reauthentication_age_mins(&self) -> Option<u32> {
+        match self {
+            RequestPolicyRule::RecentAuthentication(max_reauthentication_age_mins) => {
+                Some(*max_reauthentication_age_mins)
+            }
+            RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
+                policy_rules
+                    .iter()
+                    .filter_map(RequestPolicyRule::max_reauthentication_age_mins)
+                    .min()
+            }
+            RequestPolicyRule::Not(rule) => rule.max_reauthentication_age_mins(),
+            RequestPolicyRule::AutoApproved
+            | RequestPolicyRule::QuorumPercentage(..)
+            | RequestPolicyRule::Quorum(..)
+            | RequestPolicyRule::AllowListedByMetadata(_)
+            | RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedByLabel(_)
+            | RequestPolicyRule::TrustedDestination
+            | RequestPolicyRule::VelocityLimit(_)
+            | RequestPolicyRule::AmountRange(_)
+            | RequestPolicyRule::DestinationAge(_)
+            | RequestPolicyRule::Veto(_) => None,
+        }
+    }
+}
+
 impl ModelValidator<ValidationError> for RequestPolicyRule {
     fn validate(&self) -> ModelValidatorResult<ValidationError> {
         match self {
             RequestPolicyRule::AutoApproved
             | RequestPolicyRule::AllowListedByMetadata(_)
-            | RequestPolicyRule::AllowListed => Ok(()),
+            | RequestPolicyRule::AllowListed
+            | RequestPolicyRule::AllowListedByLabel(_)
+            | RequestPolicyRule::TrustedDestination
+            | RequestPolicyRule::RecentAuthentication(_)
+            | RequestPolicyRule::VelocityLimit(_)
+            | RequestPolicyRule::AmountRange(_)
+            | RequestPolicyRule::DestinationAge(_) => Ok(()),
 
             RequestPolicyRule::QuorumPercentage(user_specifier, _)
-            | RequestPolicyRule::Quorum(user_specifier, _) => user_specifier.validate(),
+            | RequestPolicyRule::Quorum(user_specifier, _)
+            | RequestPolicyRule::Veto(user_specifier) => user_specifier.validate(),
 
             RequestPolicyRule::Or(policy_rules) | RequestPolicyRule::And(policy_rules) => {
                 for rule in policy_rules {
@@ -70,6 +174,42 @@ pub enum EvaluatedRequestPolicyRule {
         metadata: MetadataItem,
     },
     AllowListed,
+    AllowListedByLabel {
+        label: String,
+        /// The address book entry of the destination, if the destination is in the address book.
+        address_book_entry_id: Option<AddressBookEntryId>,
+    },
+    TrustedDestination {
+        /// The max amount of the matching trusted destination, if the destination is trusted.
+        max_amount: Option<Nat>,
+        /// The amount already sent to the destination within the period, excluding the request.
+        period_usage: Nat,
+    },
+    RecentAuthentication {
+        max_reauthentication_age_mins: u32,
+        /// The approvers whose approval was made too long after they re-authenticated.
+        stale_approvers: Vec<UserId>,
+    },
+    VelocityLimit {
+        max_amount: Nat,
+        window_secs: u64,
+        /// The amount already sent from the account within the window, excluding the request.
+        spent: Nat,
+    },
+    AmountRange {
+        range: AmountRange,
+        /// The amount of the transfer, if the request is a transfer.
+        amount: Option<Nat>,
+    },
+    DestinationAge {
+        min_age_days: u32,
+        /// The first time the destination was paid, if it was ever paid.
+        first_used_at: Option<Timestamp>,
+    },
+    Veto {
+        /// The users of the rule that rejected the request.
+        vetoed_by: Vec<UserId>,
+    },
     // Logical operators
     Or(Vec<RequestPolicyRuleResult>),
     And(Vec<RequestPolicyRuleResult>),
@@ -86,6 +226,20 @@ pub struct RequestPolicyRuleResult {
 type EvaluationSummaryReason = EvaluationSummaryReasonDTO;
 
 impl RequestPolicyRuleResult {
+    /// Whether a veto of the rule or of one of its sub-rules was cast, even if the logical
+    /// operators would otherwise mask it.
+    pub fn is_vetoed(&self) -> bool {
+        match &self.evaluated_rule {
+            EvaluatedRequestPolicyRule::Veto { vetoed_by } => !vetoed_by.is_empty(),
+            EvaluatedRequestPolicyRule::Or(rule_results)
+            | EvaluatedRequestPolicyRule::And(rule_results) => {
+                rule_results.iter().any(RequestPolicyRuleResult::is_vetoed)
+            }
+            EvaluatedRequestPolicyRule::Not(rule_result) => rule_result.is_vetoed(),
+            _ => false,
+        }
+    }
+
     pub fn get_status_reason(
         &self,
         final_status: EvaluationStatus,
@@ -109,11 +263,42 @@ impl RequestPolicyRuleResult {
                     reasons.push(EvaluationSummaryReason::AllowListMetadata);
                 }
             }
-            EvaluatedRequestPolicyRule::AllowListed => {
+            EvaluatedRequestPolicyRule::AllowListed
+            | EvaluatedRequestPolicyRule::AllowListedByLabel { .. } => {
                 if final_status == self.status {
                     reasons.push(EvaluationSummaryReason::AllowList);
                 }
             }
+            EvaluatedRequestPolicyRule::TrustedDestination { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::TrustedDestination);
+                }
+            }
+            EvaluatedRequestPolicyRule::RecentAuthentication { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::RecentAuthentication);
+                }
+            }
+            EvaluatedRequestPolicyRule::VelocityLimit { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::VelocityLimit);
+                }
+            }
+            EvaluatedRequestPolicyRule::AmountRange { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::AmountRange);
+                }
+            }
+            EvaluatedRequestPolicyRule::DestinationAge { .. } => {
+                if final_status == self.status {
+                    reasons.push(EvaluationSummaryReason::DestinationAge);
+                }
+            }
+            EvaluatedRequestPolicyRule::Veto { vetoed_by } => {
+                if final_status == EvaluationStatus::Rejected && !vetoed_by.is_empty() {
+                    reasons.push(EvaluationSummaryReason::Veto);
+                }
+            }
             EvaluatedRequestPolicyRule::Or(rule_results)
             | EvaluatedRequestPolicyRule::And(rule_results) => {
                 for rule_result in rule_results {
@@ -151,6 +336,21 @@ pub struct RequestEvaluationResult {
     pub request_id: RequestId,
     pub status: EvaluationStatus,
     pub policy_results: Vec<RequestPolicyRuleResult>,
+    /// What the evaluation was computed from, the next vote applies to it only if it is still current.
+    #[serde(default)]
+    pub cache_key: Option<EvaluationCacheKey>,
+}
+
+/// Identifies the state of the station and of the request that an evaluation was computed from.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EvaluationCacheKey {
+    /// The time the canister instance that computed the evaluation was started.
+    pub epoch: u64,
+    /// Incremented by the changes of the policies and of the users that can approve requests.
+    pub generation: u64,
+    /// The number of votes the request had when it was evaluated.
+    pub approvals_count: usize,
 }
 
 impl ModelKey<RequestId> for RequestEvaluationResult {
@@ -195,6 +395,17 @@ pub trait EvaluateRequestPolicyRule<
 >: Sync + Send
 {
     fn evaluate(&self, ctx: Context) -> Result<Status, Error>;
+
+    /// Evaluates the rule after the vote of the user was added to the request, reusing the
+    /// previous result for the parts of the rule that the vote cannot change.
+    fn apply_vote(
+        &self,
+        ctx: Context,
+        _previous: &Status,
+        _voter_id: &UserId,
+    ) -> Result<Status, Error> {
+        self.evaluate(ctx)
+    }
 }
 
 #[derive(Clone)]
@@ -248,6 +459,71 @@ impl RequestPolicyRuleEvaluator {
             .collect()
     }
 
+    fn apply_vote_to_policy_rules(
+        &self,
+        request: &Arc<Request>,
+        policy_rules: &[RequestPolicyRule],
+        previous: &[RequestPolicyRuleResult],
+        voter_id: &UserId,
+    ) -> Result<Vec<RequestPolicyRuleResult>, EvaluateError> {
+        policy_rules
+            .iter()
+            .zip(previous)
+            .map(|(rule, previous)| {
+                self.apply_vote(
+                    (request.to_owned(), Arc::new(rule.to_owned())),
+                    previous,
+                    voter_id,
+                )
+            })
+            .collect()
+    }
+
+    /// Adds the vote to the approvals counted by a quorum in its previous evaluation, without
+    /// looking up the users that can approve the request again.
+    ///
+    /// Returns `None` if the voter is not one of the approvers of the quorum, in which case the
+    /// previous evaluation is unchanged.
+    fn apply_vote_to_approvals(
+        &self,
+        request: &Arc<Request>,
+        user_specifier: &UserSpecifier,
+        previous_approvers: &[UserId],
+        previous_total_possible_approvers: usize,
+        voter_id: &UserId,
+    ) -> Result<Option<RequestApprovalSummary>, MatchError> {
+        if previous_approvers.contains(voter_id)
+            || self
+                .find_matching_users(request, &[(*voter_id, ())], user_specifier)?
+                .is_empty()
+        {
+            return Ok(None);
+        }
+
+        let mut approvers = previous_approvers.to_vec();
+        approvers.push(*voter_id);
+
+        let statuses = request
+            .approvals
+            .iter()
+            .filter(|approval| approvers.contains(&approval.approver_id))
+            .map(|approval| approval.status.to_owned())
+            .collect::<Vec<_>>();
+
+        Ok(Some(RequestApprovalSummary {
+            total_possible_approvers: cmp::max(previous_total_possible_approvers, approvers.len()),
+            approved: statuses
+                .iter()
+                .filter(|&status| *status == RequestApprovalStatus::Approved)
+                .count(),
+            rejected: statuses
+                .iter()
+                .filter(|&status| *status == RequestApprovalStatus::Rejected)
+                .count(),
+            approvers,
+        }))
+    }
+
     fn find_matching_users<UserMatchReturn>(
         &self,
         request: &Arc<Request>,
@@ -336,6 +612,144 @@ impl RequestPolicyRuleEvaluator {
     }
 }
 
+impl RequestPolicyRuleEvaluator {
+    /// The request statuses whose transfers count towards the usage of a trusted destination.
+    const TRUSTED_DESTINATION_USAGE_STATUSES: [RequestStatusCode; 4] = [
+        RequestStatusCode::Approved,
+        RequestStatusCode::Scheduled,
+        RequestStatusCode::Processing,
+        RequestStatusCode::Completed,
+    ];
+
+    /// Approves the destination if its address book entry has the label.
+    fn evaluate_allow_listed_by_label(
+        &self,
+        from_account_id: &AccountId,
+        to: &str,
+        label: &str,
+    ) -> RequestPolicyRuleResult {
+        let address_book_entry = match ACCOUNT_SERVICE.get_account(from_account_id) {
+            Ok(account) => {
+                ADDRESS_BOOK_REPOSITORY.find_by_address(account.blockchain, to.to_string())
+            }
+            Err(e) => {
+                print(format!(
+                    "Rule rejected due to account not being found: {:?}",
+                    e
+                ));
+
+                None
+            }
+        };
+
+        let is_labeled = address_book_entry
+            .as_ref()
+            .is_some_and(|entry| entry.labels.iter().any(|entry_label| entry_label == label));
+
+        RequestPolicyRuleResult {
+            status: if is_labeled {
+                EvaluationStatus::Approved
+            } else {
+                EvaluationStatus::Rejected
+            },
+            evaluated_rule: EvaluatedRequestPolicyRule::AllowListedByLabel {
+                label: label.to_string(),
+                address_book_entry_id: address_book_entry.map(|entry| entry.id),
+            },
+        }
+    }
+
+    fn evaluate_trusted_destination(
+        &self,
+        request: &Request,
+        transfer: &TransferOperation,
+    ) -> Result<RequestPolicyRuleResult, EvaluateError> {
+        let rejected = |max_amount: Option<Nat>, period_usage: Nat| RequestPolicyRuleResult {
+            status: EvaluationStatus::Rejected,
+            evaluated_rule: EvaluatedRequestPolicyRule::TrustedDestination {
+                max_amount,
+                period_usage,
+            },
+        };
+
+        let account = match ACCOUNT_SERVICE.get_account(&transfer.input.from_account_id) {
+            Ok(account) => account,
+            Err(e) => {
+                print(format!(
+                    "Rule rejected due to account not being found: {:?}",
+                    e
+                ));
+
+                return Ok(rejected(None, Nat::from(0u64)));
+            }
+        };
+
+        let Some(destination) = account
+            .trusted_destinations
+            .iter()
+            .find(|destination| destination.address == transfer.input.to)
+        else {
+            return Ok(rejected(None, Nat::from(0u64)));
+        };
+
+        let request_ids = REQUEST_REPOSITORY
+            .find_ids_where(
+                RequestWhereClause {
+                    created_dt_from: Some(
+                        request
+                            .created_timestamp
+                            .saturating_sub(destination.period.duration_ns()),
+                    ),
+                    created_dt_to: None,
+                    expiration_dt_from: None,
+                    expiration_dt_to: None,
+                    operation_types: vec![ListRequestsOperationType::Transfer(Some(account.id))],
+                    statuses: Self::TRUSTED_DESTINATION_USAGE_STATUSES.to_vec(),
+                    approvers: vec![],
+                    not_approvers: vec![],
+                    requesters: vec![],
+                    not_requesters: vec![],
+                    excluded_ids: vec![request.id],
+                    tags: vec![],
+                    visible_to: None,
+                },
+                None,
+            )
+            .map_err(|e| {
+                EvaluateError::UnexpectedError(anyhow::anyhow!(
+                    "Failed to find the transfers to the trusted destination: {}",
+                    e
+                ))
+            })?;
+
+        let period_usage = request_ids
+            .iter()
+            .filter_map(|id| REQUEST_REPOSITORY.get(&Request::key(*id)))
+            .filter_map(|request| match request.operation {
+                RequestOperation::Transfer(transfer)
+                    if transfer.input.to == destination.address =>
+                {
+                    Some(transfer.input.amount)
+                }
+                _ => None,
+            })
+            .fold(Nat::from(0u64), |total, amount| total + amount);
+
+        let max_amount = destination.max_amount.clone();
+        if period_usage.clone() + transfer.input.amount.clone() > max_amount {
+            return Ok(rejected(Some(max_amount), period_usage));
+        }
+
+        Ok(RequestPolicyRuleResult {
+            status: EvaluationStatus::Approved,
+            evaluated_rule: EvaluatedRequestPolicyRule::TrustedDestination {
+                max_amount: Some(max_amount),
+                period_usage,
+            },
+        })
+    }
+}
+
 impl
     EvaluateRequestPolicyRule<
         RequestPolicyRuleResult,
@@ -431,80 +845,323 @@ impl
                     evaluated_rule: EvaluatedRequestPolicyRule::AllowListed,
                 })
             }
-            RequestPolicyRule::And(policy_rules) => {
-                let evaluation_statuses = self.evaluate_policy_rules(&request, policy_rules)?;
-
-                if evaluation_statuses
-                    .iter()
-                    .any(|result| result.status == EvaluationStatus::Rejected)
-                {
-                    return Ok(RequestPolicyRuleResult {
-                        status: EvaluationStatus::Rejected,
-                        evaluated_rule: EvaluatedRequestPolicyRule::And(evaluation_statuses),
-                    });
+            RequestPolicyRule::AllowListedByLabel(label) => match &request.operation {
+                RequestOperation::Transfer(transfer) => Ok(self.evaluate_allow_listed_by_label(
+                    &transfer.input.from_account_id,
+                    &transfer.input.to,
+                    label,
+                )),
+                RequestOperation::SweepAccount(sweep) => Ok(self.evaluate_allow_listed_by_label(
+                    &sweep.input.from_account_id,
+                    &sweep.input.to,
+                    label,
+                )),
+                _ => Ok(RequestPolicyRuleResult {
+                    status: EvaluationStatus::Rejected,
+                    evaluated_rule: EvaluatedRequestPolicyRule::AllowListedByLabel {
+                        label: label.to_owned(),
+                        address_book_entry_id: None,
+                    },
+                }),
+            },
+            RequestPolicyRule::TrustedDestination => match &request.operation {
+                RequestOperation::Transfer(transfer) => {
+                    self.evaluate_trusted_destination(&request, transfer)
                 }
+                _ => Ok(RequestPolicyRuleResult {
+                    status: EvaluationStatus::Rejected,
+                    evaluated_rule: EvaluatedRequestPolicyRule::TrustedDestination {
+                        max_amount: None,
+                        period_usage: Nat::from(0u64),
+                    },
+                }),
+            },
+            RequestPolicyRule::VelocityLimit(limit) => {
+                let (status, spent) = match AccountSpend::spent_by(&request.operation) {
+                    Some(spend) => {
+                        let spent = ACCOUNT_SPEND_REPOSITORY.total_spent_since(
+                            &spend.account_id,
+                            spend.asset_id.as_ref(),
+                            time().saturating_sub(limit.window_ns()),
+                            &request.id,
+                        );
 
-                if evaluation_statuses
-                    .iter()
-                    .all(|result| result.status == EvaluationStatus::Approved)
-                {
-                    return Ok(RequestPolicyRuleResult {
-                        status: EvaluationStatus::Approved,
-                        evaluated_rule: EvaluatedRequestPolicyRule::And(evaluation_statuses),
-                    });
-                }
+                        if spent.clone() + spend.amount > limit.max_amount {
+                            (EvaluationStatus::Rejected, spent)
+                        } else {
+                            (EvaluationStatus::Approved, spent)
+                        }
+                    }
+                    None => (EvaluationStatus::Rejected, Nat::from(0u64)),
+                };
 
                 Ok(RequestPolicyRuleResult {
-                    status: EvaluationStatus::Pending,
-                    evaluated_rule: EvaluatedRequestPolicyRule::And(evaluation_statuses),
+                    status,
+                    evaluated_rule: EvaluatedRequestPolicyRule::VelocityLimit {
+                        max_amount: limit.max_amount.clone(),
+                        window_secs: limit.window_secs,
+                        spent,
+                    },
                 })
             }
-            RequestPolicyRule::Or(policy_rules) => {
-                let evaluation_statuses = self.evaluate_policy_rules(&request, policy_rules)?;
+            RequestPolicyRule::AmountRange(range) => {
+                let amount = match &request.operation {
+                    RequestOperation::Transfer(transfer) if transfer.input.asset_id.is_none() => {
+                        Some(transfer.input.amount.clone())
+                    }
+                    RequestOperation::SplitTransfer(split) => {
+                        Some(split.input.total_amount.clone())
+                    }
+                    _ => None,
+                };
 
-                if evaluation_statuses
+                Ok(RequestPolicyRuleResult {
+                    status: match &amount {
+                        Some(amount) if range.contains(amount) => EvaluationStatus::Approved,
+                        _ => EvaluationStatus::Rejected,
+                    },
+                    evaluated_rule: EvaluatedRequestPolicyRule::AmountRange {
+                        range: range.clone(),
+                        amount,
+                    },
+                })
+            }
+            RequestPolicyRule::DestinationAge(min_age_days) => {
+                let to = match &request.operation {
+                    RequestOperation::Transfer(transfer) => Some(&transfer.input.to),
+                    RequestOperation::SweepAccount(sweep) => Some(&sweep.input.to),
+                    _ => None,
+                };
+                let first_use = to.and_then(|to| DESTINATION_FIRST_USE_REPOSITORY.get(to));
+                let is_old_enough = first_use.as_ref().is_some_and(|first_use| {
+                    first_use.age_days(time()) >= u64::from(*min_age_days)
+                });
+
+                Ok(RequestPolicyRuleResult {
+                    status: if is_old_enough {
+                        EvaluationStatus::Approved
+                    } else {
+                        EvaluationStatus::Rejected
+                    },
+                    evaluated_rule: EvaluatedRequestPolicyRule::DestinationAge {
+                        min_age_days: *min_age_days,
+                        first_used_at: first_use.map(|first_use| first_use.first_used_at),
+                    },
+                })
+            }
+            RequestPolicyRule::Veto(user_specifier) => {
+                let rejections = request
+                    .approvals
                     .iter()
-                    .any(|result| result.status == EvaluationStatus::Approved)
-                {
-                    return Ok(RequestPolicyRuleResult {
-                        status: EvaluationStatus::Approved,
-                        evaluated_rule: EvaluatedRequestPolicyRule::Or(evaluation_statuses),
-                    });
-                }
+                    .filter(|approval| approval.status == RequestApprovalStatus::Rejected)
+                    .map(|approval| (approval.approver_id, approval.approver_id))
+                    .collect::<Vec<_>>();
+                let vetoed_by = self.find_matching_users(&request, &rejections, user_specifier)?;
 
-                if evaluation_statuses
+                Ok(RequestPolicyRuleResult {
+                    status: if vetoed_by.is_empty() {
+                        EvaluationStatus::Approved
+                    } else {
+                        EvaluationStatus::Rejected
+                    },
+                    evaluated_rule: EvaluatedRequestPolicyRule::Veto { vetoed_by },
+                })
+            }
+            RequestPolicyRule::RecentAuthentication(max_reauthentication_age_mins) => {
+                let approvals = request
+                    .approvals
                     .iter()
-                    .all(|result| result.status == EvaluationStatus::Rejected)
-                {
-                    return Ok(RequestPolicyRuleResult {
-                        status: EvaluationStatus::Rejected,
-                        evaluated_rule: EvaluatedRequestPolicyRule::Or(evaluation_statuses),
-                    });
-                }
+                    .filter(|approval| approval.status == RequestApprovalStatus::Approved)
+                    .collect::<Vec<_>>();
+                let stale_approvers = approvals
+                    .iter()
+                    .filter(|approval| {
+                        !approval.is_recently_reauthenticated(*max_reauthentication_age_mins)
+                    })
+                    .map(|approval| approval.approver_id)
+                    .collect::<Vec<_>>();
 
                 Ok(RequestPolicyRuleResult {
-                    status: EvaluationStatus::Pending,
-                    evaluated_rule: EvaluatedRequestPolicyRule::Or(evaluation_statuses),
+                    status: if !stale_approvers.is_empty() {
+                        EvaluationStatus::Rejected
+                    } else if approvals.is_empty() {
+                        EvaluationStatus::Pending
+                    } else {
+                        EvaluationStatus::Approved
+                    },
+                    evaluated_rule: EvaluatedRequestPolicyRule::RecentAuthentication {
+                        max_reauthentication_age_mins: *max_reauthentication_age_mins,
+                        stale_approvers,
+                    },
                 })
             }
-            RequestPolicyRule::Not(policy_rule) => {
-                let evaluation_result = self.evaluate((
-                    request.to_owned(),
-                    Arc::new(policy_rule.as_ref().to_owned()),
-                ))?;
+            RequestPolicyRule::And(policy_rules) => Ok(and_result(
+                self.evaluate_policy_rules(&request, policy_rules)?,
+            )),
+            RequestPolicyRule::Or(policy_rules) => Ok(or_result(
+                self.evaluate_policy_rules(&request, policy_rules)?,
+            )),
+            RequestPolicyRule::Not(policy_rule) => Ok(not_result(self.evaluate((
+                request.to_owned(),
+                Arc::new(policy_rule.as_ref().to_owned()),
+            ))?)),
+        }
+    }
+
+    fn apply_vote(
+        &self,
+        (request, critera): (Arc<Request>, Arc<RequestPolicyRule>),
+        previous: &RequestPolicyRuleResult,
+        voter_id: &UserId,
+    ) -> Result<RequestPolicyRuleResult, EvaluateError> {
+        match (critera.as_ref(), &previous.evaluated_rule) {
+            (
+                RequestPolicyRule::QuorumPercentage(user_specifier, percentage),
+                EvaluatedRequestPolicyRule::QuorumPercentage {
+                    total_possible_approvers,
+                    approvers,
+                    ..
+                },
+            ) => {
+                let Some(approval_summary) = self.apply_vote_to_approvals(
+                    &request,
+                    user_specifier,
+                    approvers,
+                    *total_possible_approvers,
+                    voter_id,
+                )?
+                else {
+                    return Ok(previous.to_owned());
+                };
+                let min_approved = calculate_minimum_threshold(
+                    percentage,
+                    &approval_summary.total_possible_approvers,
+                );
+
                 Ok(RequestPolicyRuleResult {
-                    status: match evaluation_result.status {
-                        EvaluationStatus::Pending => EvaluationStatus::Pending,
-                        EvaluationStatus::Approved => EvaluationStatus::Rejected,
-                        EvaluationStatus::Rejected => EvaluationStatus::Approved,
+                    status: approval_summary.evaluate(min_approved),
+                    evaluated_rule: EvaluatedRequestPolicyRule::QuorumPercentage {
+                        total_possible_approvers: approval_summary.total_possible_approvers,
+                        approvers: approval_summary.approvers,
+                        min_approved,
                     },
-                    evaluated_rule: EvaluatedRequestPolicyRule::Not(Box::new(evaluation_result)),
                 })
             }
+            (
+                RequestPolicyRule::Quorum(user_specifier, min_approved),
+                EvaluatedRequestPolicyRule::Quorum {
+                    total_possible_approvers,
+                    approvers,
+                    ..
+                },
+            ) => {
+                let Some(approval_summary) = self.apply_vote_to_approvals(
+                    &request,
+                    user_specifier,
+                    approvers,
+                    *total_possible_approvers,
+                    voter_id,
+                )?
+                else {
+                    return Ok(previous.to_owned());
+                };
+
+                Ok(RequestPolicyRuleResult {
+                    status: approval_summary.evaluate(*min_approved as usize),
+                    evaluated_rule: EvaluatedRequestPolicyRule::Quorum {
+                        total_possible_approvers: approval_summary.total_possible_approvers,
+                        approvers: approval_summary.approvers,
+                        min_approved: *min_approved as usize,
+                    },
+                })
+            }
+            (RequestPolicyRule::And(policy_rules), EvaluatedRequestPolicyRule::And(previous))
+                if policy_rules.len() == previous.len() =>
+            {
+                Ok(and_result(self.apply_vote_to_policy_rules(
+                    &request,
+                    policy_rules,
+                    previous,
+                    voter_id,
+                )?))
+            }
+            (RequestPolicyRule::Or(policy_rules), EvaluatedRequestPolicyRule::Or(previous))
+                if policy_rules.len() == previous.len() =>
+            {
+                Ok(or_result(self.apply_vote_to_policy_rules(
+                    &request,
+                    policy_rules,
+                    previous,
+                    voter_id,
+                )?))
+            }
+            (RequestPolicyRule::Not(policy_rule), EvaluatedRequestPolicyRule::Not(previous)) => {
+                Ok(not_result(self.apply_vote(
+                    (request, Arc::new(policy_rule.as_ref().to_owned())),
+                    previous,
+                    voter_id,
+                )?))
+            }
+            // The other rules do not depend on the votes or depend on state that can change
+            // between the votes (e.g. the spends of the account), so they are evaluated again.
+            _ => self.evaluate((request, critera)),
         }
     }
 }
 
+fn and_result(evaluation_statuses: Vec<RequestPolicyRuleResult>) -> RequestPolicyRuleResult {
+    let status = if evaluation_statuses
+        .iter()
+        .any(|result| result.status == EvaluationStatus::Rejected)
+    {
+        EvaluationStatus::Rejected
+    } else if evaluation_statuses
+        .iter()
+        .all(|result| result.status == EvaluationStatus::Approved)
+    {
+        EvaluationStatus::Approved
+    } else {
+        EvaluationStatus::Pending
+    };
+
+    RequestPolicyRuleResult {
+        status,
+        evaluated_rule: EvaluatedRequestPolicyRule::And(evaluation_statuses),
+    }
+}
+
+fn or_result(evaluation_statuses: Vec<RequestPolicyRuleResult>) -> RequestPolicyRuleResult {
+    let status = if evaluation_statuses
+        .iter()
+        .any(|result| result.status == EvaluationStatus::Approved)
+    {
+        EvaluationStatus::Approved
+    } else if evaluation_statuses
+        .iter()
+        .all(|result| result.status == EvaluationStatus::Rejected)
+    {
+        EvaluationStatus::Rejected
+    } else {
+        EvaluationStatus::Pending
+    };
+
+    RequestPolicyRuleResult {
+        status,
+        evaluated_rule: EvaluatedRequestPolicyRule::Or(evaluation_statuses),
+    }
+}
+
+fn not_result(evaluation_result: RequestPolicyRuleResult) -> RequestPolicyRuleResult {
+    RequestPolicyRuleResult {
+        status: match evaluation_result.status {
+            EvaluationStatus::Pending => EvaluationStatus::Pending,
+            EvaluationStatus::Approved => EvaluationStatus::Rejected,
+            EvaluationStatus::Rejected => EvaluationStatus::Approved,
+        },
+        evaluated_rule: EvaluatedRequestPolicyRule::Not(Box::new(evaluation_result)),
+    }
+}
+
 #[cfg(test)]
 pub mod request_policy_rule_test_utils {
     use super::*;
@@ -540,6 +1197,7 @@ pub mod request_policy_rule_test_utils {
                     },
                 },
             ],
+            cache_key: None,
         }
     }
 }
@@ -547,7 +1205,23 @@ pub mod request_policy_rule_test_utils {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::core::validation::disable_mock_resource_validation;
+    use crate::{
+        core::{
+            evaluation::REQUEST_POLICY_RULE_EVALUATOR, validation::disable_mock_resource_validation,
+        },
+        models::{
+            account_test_utils::mock_account,
+            address_book_entry_test_utils::mock_address_book_entry,
+            request_test_utils::mock_request, user_test_utils::add_user, AccountBalance,
+            ApproveOperation, ApproveOperationInput, Dex, IcrcAccount, Metadata, RequestApproval,
+            RequestStatus, SplitTransferOperation, SplitTransferOperationInput,
+            SwapTokensOperation, SwapTokensOperationInput, SweepAccountOperation,
+            SweepAccountOperationInput, TransferNftOperation, TransferNftOperationInput,
+            TrustedDestination, TrustedDestinationPeriod,
+        },
+        repositories::ACCOUNT_REPOSITORY,
+    };
+    use candid::Principal;
 
     #[test]
     fn fail_critera_with_non_existent_user_specifier() {
@@ -626,6 +1300,7 @@ mod test {
             request_id: [0; 16],
             status: result.status.clone(),
             policy_results: vec![result],
+            cache_key: None,
         };
 
         assert_eq!(
@@ -633,4 +1308,423 @@ mod test {
             vec![EvaluationSummaryReason::AllowListMetadata]
         );
     }
+
+    #[test]
+    fn test_trusted_destination_is_capped_per_period() {
+        let mut account = mock_account();
+        account.id = [1; 16];
+        account.trusted_destinations = vec![TrustedDestination {
+            address: "0x1234".to_string(),
+            max_amount: Nat::from(250u64),
+            period: TrustedDestinationPeriod::Week,
+        }];
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let transfer_request = |status: RequestStatus, amount: u64| {
+            let mut request = mock_request();
+            request.status = status;
+            request.created_timestamp = 10;
+            if let RequestOperation::Transfer(transfer) = &mut request.operation {
+                transfer.input.amount = Nat::from(amount);
+            }
+            request
+        };
+
+        let executed = transfer_request(RequestStatus::Completed { completed_at: 10 }, 100);
+        REQUEST_REPOSITORY.insert(executed.to_key(), executed);
+
+        let request = transfer_request(RequestStatus::Created, 100);
+        let evaluate = |request: &Request| {
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((
+                    Arc::new(request.clone()),
+                    Arc::new(RequestPolicyRule::TrustedDestination),
+                ))
+                .unwrap()
+        };
+
+        let result = evaluate(&request);
+        assert_eq!(result.status, EvaluationStatus::Approved);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::TrustedDestination {
+                max_amount: Some(Nat::from(250u64)),
+                period_usage: Nat::from(100u64),
+            }
+        );
+
+        let approved = transfer_request(RequestStatus::Approved, 100);
+        REQUEST_REPOSITORY.insert(approved.to_key(), approved);
+
+        assert_eq!(evaluate(&request).status, EvaluationStatus::Rejected);
+
+        let mut untrusted = transfer_request(RequestStatus::Created, 1);
+        if let RequestOperation::Transfer(transfer) = &mut untrusted.operation {
+            transfer.input.to = "0x5678".to_string();
+        }
+
+        let result = evaluate(&untrusted);
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::TrustedDestination {
+                max_amount: None,
+                period_usage: Nat::from(0u64),
+            }
+        );
+    }
+
+    #[test]
+    fn test_recent_authentication_requires_recent_reauthentications() {
+        let minute = 60 * 1_000_000_000;
+        let approval = |approver_id: UserId, reauthenticated_at: Option<u64>| RequestApproval {
+            approver_id,
+            status: RequestApprovalStatus::Approved,
+            status_reason: None,
+            decided_dt: 30 * minute,
+            last_modification_timestamp: 30 * minute,
+            reauthenticated_at,
+        };
+        let evaluate = |approvals: Vec<RequestApproval>| {
+            let mut request = mock_request();
+            request.approvals = approvals;
+
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((
+                    Arc::new(request),
+                    Arc::new(RequestPolicyRule::RecentAuthentication(15)),
+                ))
+                .unwrap()
+        };
+
+        assert_eq!(evaluate(vec![]).status, EvaluationStatus::Pending);
+        assert_eq!(
+            evaluate(vec![approval([1; 16], Some(20 * minute))]).status,
+            EvaluationStatus::Approved
+        );
+
+        let result = evaluate(vec![
+            approval([1; 16], Some(20 * minute)),
+            approval([2; 16], Some(10 * minute)),
+            approval([3; 16], None),
+        ]);
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::RecentAuthentication {
+                max_reauthentication_age_mins: 15,
+                stale_approvers: vec![[2; 16], [3; 16]],
+            }
+        );
+    }
+
+    #[test]
+    fn test_max_reauthentication_age_is_the_strictest_of_the_rule() {
+        let rule = RequestPolicyRule::Or(vec![
+            RequestPolicyRule::AutoApproved,
+            RequestPolicyRule::And(vec![
+                RequestPolicyRule::Quorum(UserSpecifier::Any, 1),
+                RequestPolicyRule::RecentAuthentication(30),
+            ]),
+            RequestPolicyRule::RecentAuthentication(10),
+        ]);
+
+        assert_eq!(rule.max_reauthentication_age_mins(), Some(10));
+        assert_eq!(
+            RequestPolicyRule::AutoApproved.max_reauthentication_age_mins(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_velocity_limit_caps_the_spend_within_the_window() {
+        let account_id = [7; 16];
+        let spend = |amount: u64, spent_at: u64| AccountSpend {
+            account_id,
+            asset_id: None,
+            request_id: *uuid::Uuid::new_v4().as_bytes(),
+            amount: Nat::from(amount),
+            spent_at,
+        };
+        let day_ns = 24 * 60 * 60 * 1_000_000_000;
+        let now = time();
+
+        for spend in [spend(600, now - day_ns - 1), spend(300, now - 10)] {
+            ACCOUNT_SPEND_REPOSITORY.insert(spend.key(), spend);
+        }
+
+        let evaluate_asset = |amount: u64, asset_id: Option<[u8; 16]>| {
+            let mut request = mock_request();
+            if let RequestOperation::Transfer(transfer) = &mut request.operation {
+                transfer.input.from_account_id = account_id;
+                transfer.input.amount = Nat::from(amount);
+                transfer.input.asset_id = asset_id;
+            }
+
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((
+                    Arc::new(request),
+                    Arc::new(RequestPolicyRule::VelocityLimit(VelocityLimit {
+                        max_amount: Nat::from(1_000u64),
+                        window_secs: 24 * 60 * 60,
+                    })),
+                ))
+                .unwrap()
+        };
+        let evaluate = |amount: u64| evaluate_asset(amount, None);
+
+        let result = evaluate(700);
+        assert_eq!(result.status, EvaluationStatus::Approved);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::VelocityLimit {
+                max_amount: Nat::from(1_000u64),
+                window_secs: 24 * 60 * 60,
+                spent: Nat::from(300u64),
+            }
+        );
+
+        assert_eq!(evaluate(701).status, EvaluationStatus::Rejected);
+
+        // the other assets of the account have their own window, in their own units
+        assert_eq!(
+            evaluate_asset(1_000, Some([9; 16])).status,
+            EvaluationStatus::Approved
+        );
+
+        let mut asset_spend = spend(1_000, now - 10);
+        asset_spend.asset_id = Some([9; 16]);
+        ACCOUNT_SPEND_REPOSITORY.insert(asset_spend.key(), asset_spend);
+
+        assert_eq!(
+            evaluate_asset(1, Some([9; 16])).status,
+            EvaluationStatus::Rejected
+        );
+        assert_eq!(evaluate(700).status, EvaluationStatus::Approved);
+    }
+
+    #[test]
+    fn test_velocity_limit_counts_every_operation_that_moves_value_out() {
+        let mut account = mock_account();
+        account.balance = Some(AccountBalance {
+            balance: Nat::from(10u64),
+            last_modification_timestamp: 0,
+        });
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let spend = AccountSpend {
+            account_id: account.id,
+            asset_id: None,
+            request_id: [1; 16],
+            amount: Nat::from(1_000u64),
+            spent_at: time() - 10,
+        };
+        ACCOUNT_SPEND_REPOSITORY.insert(spend.key(), spend);
+
+        let icrc_account = IcrcAccount::new(Principal::anonymous(), None);
+        let operations = vec![
+            RequestOperation::SplitTransfer(SplitTransferOperation {
+                transfer_ids: vec![],
+                input: SplitTransferOperationInput {
+                    from_account_id: account.id,
+                    total_amount: Nat::from(10u64),
+                    destinations: vec![],
+                    metadata: Metadata::default(),
+                    network: "mainnet".to_string(),
+                    memo: None,
+                },
+                fee: None,
+            }),
+            RequestOperation::SweepAccount(SweepAccountOperation {
+                transfer_id: None,
+                input: SweepAccountOperationInput {
+                    from_account_id: account.id,
+                    to: "destination".to_string(),
+                    metadata: Metadata::default(),
+                    network: "mainnet".to_string(),
+                    memo: None,
+                },
+                amount: None,
+                fee: None,
+            }),
+            RequestOperation::SwapTokens(SwapTokensOperation {
+                result: None,
+                input: SwapTokensOperationInput {
+                    from_account_id: account.id,
+                    to_account_id: [2; 16],
+                    dex: Dex::ICPSwap,
+                    pool_canister_id: None,
+                    amount_in: Nat::from(10u64),
+                    expected_amount_out: Nat::from(10u64),
+                    max_slippage_bps: 100,
+                },
+            }),
+            RequestOperation::Approve(ApproveOperation {
+                block_index: None,
+                input: ApproveOperationInput {
+                    from_account_id: account.id,
+                    spender: icrc_account.clone(),
+                    amount: Nat::from(10u64),
+                    expected_allowance: None,
+                    expires_at: None,
+                    fee: None,
+                },
+            }),
+            RequestOperation::TransferNft(TransferNftOperation {
+                block_index: None,
+                input: TransferNftOperationInput {
+                    from_account_id: account.id,
+                    token_id: Nat::from(1u64),
+                    to: icrc_account,
+                    memo: None,
+                },
+            }),
+        ];
+
+        for operation in operations {
+            let evaluate = |max_amount: u64| {
+                let mut request = mock_request();
+                request.operation = operation.clone();
+
+                REQUEST_POLICY_RULE_EVALUATOR
+                    .evaluate((
+                        Arc::new(request),
+                        Arc::new(RequestPolicyRule::VelocityLimit(VelocityLimit {
+                            max_amount: Nat::from(max_amount),
+                            window_secs: 24 * 60 * 60,
+                        })),
+                    ))
+                    .unwrap()
+                    .status
+            };
+
+            assert_eq!(evaluate(1_010), EvaluationStatus::Approved, "{}", operation);
+            assert_eq!(evaluate(1_000), EvaluationStatus::Rejected, "{}", operation);
+        }
+    }
+
+    #[test]
+    fn test_amount_ranges_select_the_approval_tier() {
+        let approver = add_user(&[1; 16]);
+        let range = |min_amount: Option<u64>, max_amount: Option<u64>| {
+            RequestPolicyRule::AmountRange(AmountRange {
+                min_amount: min_amount.map(Nat::from),
+                max_amount: max_amount.map(Nat::from),
+            })
+        };
+        let tiers = RequestPolicyRule::Or(vec![
+            RequestPolicyRule::And(vec![
+                range(None, Some(100)),
+                RequestPolicyRule::AutoApproved,
+            ]),
+            RequestPolicyRule::And(vec![
+                range(Some(100), None),
+                RequestPolicyRule::Quorum(UserSpecifier::Id(vec![approver.id]), 1),
+            ]),
+        ]);
+
+        let evaluate = |amount: u64| {
+            let mut request = mock_request();
+            request.approvals = vec![];
+            if let RequestOperation::Transfer(transfer) = &mut request.operation {
+                transfer.input.amount = Nat::from(amount);
+            }
+
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((Arc::new(request), Arc::new(tiers.clone())))
+                .unwrap()
+                .status
+        };
+
+        assert_eq!(evaluate(99), EvaluationStatus::Approved);
+        // the max amount is excluded from the range, so the amount falls in the next tier
+        assert_eq!(evaluate(100), EvaluationStatus::Pending);
+        assert_eq!(evaluate(10_000), EvaluationStatus::Pending);
+    }
+
+    #[test]
+    fn test_destination_age_rejects_new_destinations() {
+        let day_ns = 24 * 60 * 60 * 1_000_000_000;
+        let now = time();
+        DESTINATION_FIRST_USE_REPOSITORY.record_use("0xold", [1; 16], now - 30 * day_ns);
+        DESTINATION_FIRST_USE_REPOSITORY.record_use("0xrecent", [2; 16], now - day_ns);
+
+        let evaluate = |to: &str| {
+            let mut request = mock_request();
+            if let RequestOperation::Transfer(transfer) = &mut request.operation {
+                transfer.input.to = to.to_string();
+            }
+
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((
+                    Arc::new(request),
+                    Arc::new(RequestPolicyRule::DestinationAge(7)),
+                ))
+                .unwrap()
+        };
+
+        assert_eq!(evaluate("0xold").status, EvaluationStatus::Approved);
+        assert_eq!(evaluate("0xrecent").status, EvaluationStatus::Rejected);
+
+        let result = evaluate("0xnew");
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::DestinationAge {
+                min_age_days: 7,
+                first_used_at: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_allow_listed_by_label_joins_the_address_book() {
+        let mut account = mock_account();
+        account.id = [1; 16];
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut entry = mock_address_book_entry();
+        entry.blockchain = account.blockchain.clone();
+        entry.labels = vec!["payroll".to_string()];
+        ADDRESS_BOOK_REPOSITORY.insert(entry.to_key(), entry.clone());
+
+        let evaluate = |to: &str, label: &str| {
+            let mut request = mock_request();
+            if let RequestOperation::Transfer(transfer) = &mut request.operation {
+                transfer.input.from_account_id = account.id;
+                transfer.input.to = to.to_string();
+            }
+
+            REQUEST_POLICY_RULE_EVALUATOR
+                .evaluate((
+                    Arc::new(request),
+                    Arc::new(RequestPolicyRule::AllowListedByLabel(label.to_string())),
+                ))
+                .unwrap()
+        };
+
+        let result = evaluate(&entry.address, "payroll");
+        assert_eq!(result.status, EvaluationStatus::Approved);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::AllowListedByLabel {
+                label: "payroll".to_string(),
+                address_book_entry_id: Some(entry.id),
+            }
+        );
+
+        // the destination is in the address book, but without the label
+        let result = evaluate(&entry.address, "vendors");
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+
+        let result = evaluate("unknown-destination", "payroll");
+        assert_eq!(result.status, EvaluationStatus::Rejected);
+        assert_eq!(
+            result.evaluated_rule,
+            EvaluatedRequestPolicyRule::AllowListedByLabel {
+                label: "payroll".to_string(),
+                address_book_entry_id: None,
+            }
+        );
+    }
 }
@@ -1,8 +1,8 @@
 use super::resource::{Resource, ResourceIds};
 use super::{MetadataItem, Request, RequestId, RequestOperation, RequestOperationType};
 use crate::core::validation::{
-    EnsureAccount, EnsureAddressBookEntry, EnsureIdExists, EnsureRequestPolicy,
-    EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
+    EnsureAccount, EnsureAddressBookEntry, EnsureIdExists, EnsureRegisteredAsset,
+    EnsureRequestPolicy, EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
 };
 use crate::errors::ValidationError;
 use crate::models::resource::{CallExternalCanisterResourceTarget, ExternalCanisterId};
@@ -76,6 +76,9 @@ pub enum RequestSpecifier {
     RemoveUserGroup(ResourceIds),
     ManageSystemInfo,
     SystemUpgrade,
+    AddAsset,
+    EditAsset(ResourceIds),
+    RemoveAsset(ResourceIds),
 }
 
 impl ModelValidator<ValidationError> for RequestSpecifier {
@@ -91,7 +94,8 @@ impl ModelValidator<ValidationError> for RequestSpecifier {
             | RequestSpecifier::AddRequestPolicy
             | RequestSpecifier::ManageSystemInfo
             | RequestSpecifier::SetDisasterRecovery
-            | RequestSpecifier::AddUserGroup => (),
+            | RequestSpecifier::AddUserGroup
+            | RequestSpecifier::AddAsset => (),
 
             RequestSpecifier::CallExternalCanister(target) => {
                 target.validate()?;
@@ -121,6 +125,10 @@ impl ModelValidator<ValidationError> for RequestSpecifier {
             | RequestSpecifier::RemoveUserGroup(resource_ids) => {
                 EnsureUserGroup::resource_ids_exist(resource_ids)?
             }
+            RequestSpecifier::EditAsset(resource_ids)
+            | RequestSpecifier::RemoveAsset(resource_ids) => {
+                EnsureRegisteredAsset::resource_ids_exist(resource_ids)?
+            }
         }
         Ok(())
     }
@@ -157,6 +165,9 @@ impl From<&RequestSpecifier> for RequestOperationType {
             RequestSpecifier::RemoveUserGroup(_) => RequestOperationType::RemoveUserGroup,
             RequestSpecifier::ManageSystemInfo => RequestOperationType::ManageSystemInfo,
             RequestSpecifier::SetDisasterRecovery => RequestOperationType::SetDisasterRecovery,
+            RequestSpecifier::AddAsset => RequestOperationType::AddAsset,
+            RequestSpecifier::EditAsset(_) => RequestOperationType::EditAsset,
+            RequestSpecifier::RemoveAsset(_) => RequestOperationType::RemoveAsset,
         }
     }
 }
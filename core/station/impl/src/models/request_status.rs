@@ -8,11 +8,23 @@ pub enum RequestStatus {
     Created,
     Approved,
     Rejected,
-    Scheduled { scheduled_at: Timestamp },
-    Cancelled { reason: Option<String> },
-    Processing { started_at: Timestamp },
-    Completed { completed_at: Timestamp },
-    Failed { reason: Option<String> },
+    Scheduled {
+        scheduled_at: Timestamp,
+    },
+    Cancelled {
+        reason: Option<String>,
+    },
+    Processing {
+        started_at: Timestamp,
+    },
+    Completed {
+        completed_at: Timestamp,
+    },
+    Failed {
+        reason: Option<String>,
+    },
+    /// The request awaits the validations that require inter-canister calls.
+    Validating,
 }
 
 #[storable]
@@ -27,6 +39,7 @@ pub enum RequestStatusCode {
     Processing = 5,
     Completed = 6,
     Failed = 7,
+    Validating = 8,
 }
 
 impl From<RequestStatus> for RequestStatusCode {
@@ -40,6 +53,7 @@ impl From<RequestStatus> for RequestStatusCode {
             RequestStatus::Completed { .. } => RequestStatusCode::Completed,
             RequestStatus::Failed { .. } => RequestStatusCode::Failed,
             RequestStatus::Cancelled { .. } => RequestStatusCode::Cancelled,
+            RequestStatus::Validating => RequestStatusCode::Validating,
         }
     }
 }
@@ -63,6 +77,7 @@ impl TryFrom<u8> for RequestStatusCode {
             5 => Ok(RequestStatusCode::Processing),
             6 => Ok(RequestStatusCode::Completed),
             7 => Ok(RequestStatusCode::Failed),
+            8 => Ok(RequestStatusCode::Validating),
             _ => Err(()),
         }
     }
@@ -79,6 +94,7 @@ impl Display for RequestStatusCode {
             RequestStatusCode::Completed => write!(f, "completed"),
             RequestStatusCode::Failed => write!(f, "failed"),
             RequestStatusCode::Cancelled => write!(f, "cancelled"),
+            RequestStatusCode::Validating => write!(f, "validating"),
         }
     }
 }
@@ -94,6 +110,7 @@ impl RequestStatus {
             RequestStatus::Completed { .. } => RequestStatusCode::Completed,
             RequestStatus::Failed { .. } => RequestStatusCode::Failed,
             RequestStatus::Cancelled { .. } => RequestStatusCode::Cancelled,
+            RequestStatus::Validating => RequestStatusCode::Validating,
         }
     }
 }
@@ -112,6 +129,7 @@ mod tests {
         assert_eq!(RequestStatusCode::Completed.to_string(), "completed");
         assert_eq!(RequestStatusCode::Failed.to_string(), "failed");
         assert_eq!(RequestStatusCode::Cancelled.to_string(), "cancelled");
+        assert_eq!(RequestStatusCode::Validating.to_string(), "validating");
     }
 
     #[test]
@@ -124,6 +142,7 @@ mod tests {
         assert_eq!(u8::from(RequestStatusCode::Completed), 6);
         assert_eq!(u8::from(RequestStatusCode::Failed), 7);
         assert_eq!(u8::from(RequestStatusCode::Cancelled), 3);
+        assert_eq!(u8::from(RequestStatusCode::Validating), 8);
     }
 
     #[test]
@@ -160,5 +179,9 @@ mod tests {
             RequestStatusCode::try_from(3),
             Ok(RequestStatusCode::Cancelled)
         );
+        assert_eq!(
+            RequestStatusCode::try_from(8),
+            Ok(RequestStatusCode::Validating)
+        );
     }
 }
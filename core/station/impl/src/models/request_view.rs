@@ -0,0 +1,175 @@
+use super::{ListRequestsOperationType, RequestStatusCode, UserGroupId, UserId};
+use crate::errors::RequestViewError;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The request view id, which is a UUID.
+pub type RequestViewId = UUID;
+
+/// A saved query over the requests, so that teams can standardize their triage views
+/// (e.g. "Transfers pending for more than 48h").
+///
+/// A view is owned by the user that created it and can be shared with the members of one of its groups.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestView {
+    pub id: RequestViewId,
+    pub name: String,
+    pub owner_id: UserId,
+    /// The group whose members can also list the requests of the view.
+    pub shared_with_group_id: Option<UserGroupId>,
+    pub query: RequestViewQuery,
+    pub created_timestamp: Timestamp,
+    pub last_modification_timestamp: Timestamp,
+}
+
+/// The filters and sort order of a view, the empty filters match all the requests.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestViewQuery {
+    pub statuses: Vec<RequestStatusCode>,
+    pub operation_types: Vec<ListRequestsOperationType>,
+    pub requester_ids: Vec<UserId>,
+    pub approver_ids: Vec<UserId>,
+    /// Only the requests created at least this long ago, relative to the time the view is listed.
+    pub min_age_ns: Option<u64>,
+    /// Only the requests created at most this long ago, relative to the time the view is listed.
+    pub max_age_ns: Option<u64>,
+    /// Only the requests that the caller can still approve.
+    pub only_approvable: bool,
+    pub sort_by: Option<RequestViewSortBy>,
+}
+
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestViewSortBy {
+    CreatedAt(RequestViewSortDirection),
+    ExpirationDt(RequestViewSortDirection),
+    LastModificationDt(RequestViewSortDirection),
+}
+
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RequestViewSortDirection {
+    Asc,
+    Desc,
+}
+
+impl ModelKey<RequestViewId> for RequestView {
+    fn key(&self) -> RequestViewId {
+        self.id
+    }
+}
+
+impl RequestView {
+    pub const NAME_RANGE: (u8, u8) = (1, 100);
+    /// The maximum number of views a user can own.
+    pub const MAX_VIEWS_PER_USER: usize = 50;
+    /// The maximum number of users or operation types a view can filter by.
+    pub const MAX_FILTER_ENTRIES: usize = 50;
+
+    /// Whether the user owns the view or is a member of the group it is shared with.
+    pub fn is_visible_to(&self, user_id: &UserId, user_groups: &[UserGroupId]) -> bool {
+        self.owner_id == *user_id
+            || self
+                .shared_with_group_id
+                .is_some_and(|group_id| user_groups.contains(&group_id))
+    }
+}
+
+impl ModelValidator<RequestViewError> for RequestView {
+    fn validate(&self) -> ModelValidatorResult<RequestViewError> {
+        let (min_length, max_length) = Self::NAME_RANGE;
+        if self.name.trim().len() < min_length as usize || self.name.len() > max_length as usize {
+            return Err(RequestViewError::InvalidNameLength {
+                min_length,
+                max_length,
+            });
+        }
+
+        if self.query.requester_ids.len() > Self::MAX_FILTER_ENTRIES
+            || self.query.approver_ids.len() > Self::MAX_FILTER_ENTRIES
+            || self.query.operation_types.len() > Self::MAX_FILTER_ENTRIES
+        {
+            return Err(RequestViewError::TooManyFilterEntries {
+                max: Self::MAX_FILTER_ENTRIES,
+            });
+        }
+
+        if let (Some(min_age_ns), Some(max_age_ns)) = (self.query.min_age_ns, self.query.max_age_ns)
+        {
+            if min_age_ns > max_age_ns {
+                return Err(RequestViewError::ValidationError {
+                    info: "The minimum age of the requests cannot be above their maximum age."
+                        .to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::request_view_test_utils::mock_request_view;
+    use super::*;
+
+    #[test]
+    fn fail_request_view_empty_name() {
+        let mut view = mock_request_view();
+        view.name = " ".to_string();
+
+        assert_eq!(
+            view.validate(),
+            Err(RequestViewError::InvalidNameLength {
+                min_length: RequestView::NAME_RANGE.0,
+                max_length: RequestView::NAME_RANGE.1,
+            })
+        );
+    }
+
+    #[test]
+    fn fail_request_view_inverted_age_range() {
+        let mut view = mock_request_view();
+        view.query.min_age_ns = Some(20);
+        view.query.max_age_ns = Some(10);
+
+        assert!(view.validate().is_err());
+    }
+
+    #[test]
+    fn shared_view_is_visible_to_the_group_members() {
+        let mut view = mock_request_view();
+        view.shared_with_group_id = Some([9; 16]);
+
+        assert!(view.is_visible_to(&view.owner_id.clone(), &[]));
+        assert!(view.is_visible_to(&[2; 16], &[[9; 16]]));
+        assert!(!view.is_visible_to(&[2; 16], &[[8; 16]]));
+    }
+}
+
+#[cfg(any(test, feature = "canbench"))]
+pub mod request_view_test_utils {
+    use super::*;
+
+    pub fn mock_request_view() -> RequestView {
+        RequestView {
+            id: [0; 16],
+            name: "Transfers pending for more than 48h".to_string(),
+            owner_id: [1; 16],
+            shared_with_group_id: None,
+            query: RequestViewQuery {
+                statuses: vec![RequestStatusCode::Created],
+                operation_types: vec![ListRequestsOperationType::Transfer(None)],
+                min_age_ns: Some(48 * 60 * 60 * 1_000_000_000),
+                ..Default::default()
+            },
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
@@ -9,8 +9,8 @@ use uuid::Uuid;
 
 use crate::{
     core::validation::{
-        EnsureAccount, EnsureAddressBookEntry, EnsureNotification, EnsureRequest,
-        EnsureRequestPolicy, EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
+        EnsureAccount, EnsureAddressBookEntry, EnsureNotification, EnsureRegisteredAsset,
+        EnsureRequest, EnsureRequestPolicy, EnsureResourceIdExists, EnsureUser, EnsureUserGroup,
     },
     errors::ValidationError,
     models::CanisterMethod,
@@ -33,6 +33,8 @@ pub enum Resource {
     System(SystemResourceAction),
     User(UserResourceAction),
     UserGroup(ResourceAction),
+    /// The assets registered in the station, the built-in assets are not resources.
+    Asset(ResourceAction),
 }
 
 impl ModelValidator<ValidationError> for Resource {
@@ -90,11 +92,14 @@ impl ModelValidator<ValidationError> for Resource {
                 SystemResourceAction::SystemInfo
                 | SystemResourceAction::Capabilities
                 | SystemResourceAction::ManageSystemInfo
-                | SystemResourceAction::Upgrade => (),
+                | SystemResourceAction::Upgrade
+                | SystemResourceAction::AuditOutflows => (),
             },
             Resource::User(action) => match action {
                 UserResourceAction::List | UserResourceAction::Create => (),
-                UserResourceAction::Read(resource_id) | UserResourceAction::Update(resource_id) => {
+                UserResourceAction::Read(resource_id)
+                | UserResourceAction::Update(resource_id)
+                | UserResourceAction::ViewAs(resource_id) => {
                     EnsureUser::resource_id_exists(resource_id)?
                 }
             },
@@ -106,6 +111,14 @@ impl ModelValidator<ValidationError> for Resource {
                     EnsureUserGroup::resource_id_exists(resource_id)?
                 }
             },
+            Resource::Asset(action) => match action {
+                ResourceAction::List | ResourceAction::Create => (),
+                ResourceAction::Read(resource_id)
+                | ResourceAction::Update(resource_id)
+                | ResourceAction::Delete(resource_id) => {
+                    EnsureRegisteredAsset::resource_id_exists(resource_id)?
+                }
+            },
         }
         Ok(())
     }
@@ -135,6 +148,8 @@ pub enum UserResourceAction {
     Create,
     Read(ResourceId),
     Update(ResourceId),
+    /// Viewing the resources and privileges that are visible to the user, without acting on its behalf.
+    ViewAs(ResourceId),
 }
 
 #[storable]
@@ -154,6 +169,8 @@ pub enum SystemResourceAction {
     Capabilities,
     ManageSystemInfo,
     Upgrade,
+    /// Auditing the pending transfers of all the accounts against the blockchain history.
+    AuditOutflows,
 }
 
 #[storable]
@@ -270,7 +287,7 @@ impl Resource {
         Resource::Permission(PermissionResourceAction::Read)
     }
     pub fn max() -> Self {
-        Resource::UserGroup(ResourceAction::Delete(ResourceId::Id([u8::MAX; 16])))
+        Resource::Asset(ResourceAction::Delete(ResourceId::Id([u8::MAX; 16])))
     }
 
     /// Returns the expanded list of resources that the resource represents.
@@ -560,6 +577,9 @@ impl Resource {
                 SystemResourceAction::Upgrade => {
                     vec![Resource::System(SystemResourceAction::Upgrade)]
                 }
+                SystemResourceAction::AuditOutflows => {
+                    vec![Resource::System(SystemResourceAction::AuditOutflows)]
+                }
             },
             Resource::User(action) => match action {
                 UserResourceAction::Create => vec![Resource::User(UserResourceAction::Create)],
@@ -582,6 +602,15 @@ impl Resource {
                 UserResourceAction::Update(ResourceId::Any) => {
                     vec![Resource::User(UserResourceAction::Update(ResourceId::Any))]
                 }
+                UserResourceAction::ViewAs(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::User(UserResourceAction::ViewAs(ResourceId::Id(*id))),
+                        Resource::User(UserResourceAction::ViewAs(ResourceId::Any)),
+                    ]
+                }
+                UserResourceAction::ViewAs(ResourceId::Any) => {
+                    vec![Resource::User(UserResourceAction::ViewAs(ResourceId::Any))]
+                }
             },
             Resource::UserGroup(action) => match action {
                 ResourceAction::Create => vec![Resource::UserGroup(ResourceAction::Create)],
@@ -614,6 +643,87 @@ impl Resource {
                     vec![Resource::UserGroup(ResourceAction::Delete(ResourceId::Any))]
                 }
             },
+            Resource::Asset(action) => match action {
+                ResourceAction::Create => vec![Resource::Asset(ResourceAction::Create)],
+                ResourceAction::Delete(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Asset(ResourceAction::Delete(ResourceId::Id(*id))),
+                        Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::List => vec![Resource::Asset(ResourceAction::List)],
+                ResourceAction::Read(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Asset(ResourceAction::Read(ResourceId::Id(*id))),
+                        Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::Update(ResourceId::Id(id)) => {
+                    vec![
+                        Resource::Asset(ResourceAction::Update(ResourceId::Id(*id))),
+                        Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+                    ]
+                }
+                ResourceAction::Update(ResourceId::Any) => {
+                    vec![Resource::Asset(ResourceAction::Update(ResourceId::Any))]
+                }
+                ResourceAction::Read(ResourceId::Any) => {
+                    vec![Resource::Asset(ResourceAction::Read(ResourceId::Any))]
+                }
+                ResourceAction::Delete(ResourceId::Any) => {
+                    vec![Resource::Asset(ResourceAction::Delete(ResourceId::Any))]
+                }
+            },
+        }
+    }
+
+    /// Returns true if acting on the resource changes the configuration or the funds of the station,
+    /// which is the case of all the request operations.
+    ///
+    /// Reading resources, voting on requests and managing own notifications do not.
+    pub fn changes_configuration(&self) -> bool {
+        match self {
+            Resource::Permission(action) => match action {
+                PermissionResourceAction::Read => false,
+                PermissionResourceAction::Update => true,
+            },
+            Resource::Account(action) => match action {
+                AccountResourceAction::List | AccountResourceAction::Read(_) => false,
+                AccountResourceAction::Create
+                | AccountResourceAction::Transfer(_)
+                | AccountResourceAction::Update(_) => true,
+            },
+            Resource::AddressBook(action)
+            | Resource::RequestPolicy(action)
+            | Resource::UserGroup(action)
+            | Resource::Asset(action) => match action {
+                ResourceAction::List | ResourceAction::Read(_) => false,
+                ResourceAction::Create | ResourceAction::Update(_) | ResourceAction::Delete(_) => {
+                    true
+                }
+            },
+            Resource::ExternalCanister(action) => match action {
+                ExternalCanisterResourceAction::List | ExternalCanisterResourceAction::Read(_) => {
+                    false
+                }
+                ExternalCanisterResourceAction::Create
+                | ExternalCanisterResourceAction::Change(_)
+                | ExternalCanisterResourceAction::Fund(_)
+                | ExternalCanisterResourceAction::Call(_) => true,
+            },
+            Resource::Notification(_) | Resource::Request(_) => false,
+            Resource::System(action) => match action {
+                SystemResourceAction::SystemInfo
+                | SystemResourceAction::Capabilities
+                | SystemResourceAction::AuditOutflows => false,
+                SystemResourceAction::ManageSystemInfo | SystemResourceAction::Upgrade => true,
+            },
+            Resource::User(action) => match action {
+                UserResourceAction::List
+                | UserResourceAction::Read(_)
+                | UserResourceAction::ViewAs(_) => false,
+                UserResourceAction::Create | UserResourceAction::Update(_) => true,
+            },
         }
     }
 }
@@ -633,6 +743,7 @@ impl Display for Resource {
             Resource::System(action) => write!(f, "System({})", action),
             Resource::User(action) => write!(f, "User({})", action),
             Resource::UserGroup(action) => write!(f, "UserGroup({})", action),
+            Resource::Asset(action) => write!(f, "Asset({})", action),
         }
     }
 }
@@ -771,6 +882,7 @@ impl Display for SystemResourceAction {
             SystemResourceAction::Capabilities => write!(f, "Capabilities"),
             SystemResourceAction::ManageSystemInfo => write!(f, "ManageSystemInfo"),
             SystemResourceAction::Upgrade => write!(f, "Upgrade"),
+            SystemResourceAction::AuditOutflows => write!(f, "AuditOutflows"),
         }
     }
 }
@@ -782,6 +894,7 @@ impl Display for UserResourceAction {
             UserResourceAction::Create => write!(f, "Create"),
             UserResourceAction::Read(id) => write!(f, "Read({})", id),
             UserResourceAction::Update(id) => write!(f, "Update({})", id),
+            UserResourceAction::ViewAs(id) => write!(f, "ViewAs({})", id),
         }
     }
 }
@@ -851,11 +964,17 @@ mod test {
             Resource::User(UserResourceAction::Create),
             Resource::User(UserResourceAction::Read(ResourceId::Any)),
             Resource::User(UserResourceAction::Update(ResourceId::Any)),
+            Resource::User(UserResourceAction::ViewAs(ResourceId::Any)),
             Resource::UserGroup(ResourceAction::List),
             Resource::UserGroup(ResourceAction::Create),
             Resource::UserGroup(ResourceAction::Read(ResourceId::Any)),
             Resource::UserGroup(ResourceAction::Update(ResourceId::Any)),
             Resource::UserGroup(ResourceAction::Delete(ResourceId::Any)),
+            Resource::Asset(ResourceAction::List),
+            Resource::Asset(ResourceAction::Create),
+            Resource::Asset(ResourceAction::Read(ResourceId::Any)),
+            Resource::Asset(ResourceAction::Update(ResourceId::Any)),
+            Resource::Asset(ResourceAction::Delete(ResourceId::Any)),
         ];
 
         for resource in valid_resources {
@@ -882,9 +1001,13 @@ mod test {
             Resource::RequestPolicy(ResourceAction::Delete(ResourceId::Id([0; 16]))),
             Resource::User(UserResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::User(UserResourceAction::Update(ResourceId::Id([0; 16]))),
+            Resource::User(UserResourceAction::ViewAs(ResourceId::Id([0; 16]))),
             Resource::UserGroup(ResourceAction::Read(ResourceId::Id([0; 16]))),
             Resource::UserGroup(ResourceAction::Update(ResourceId::Id([0; 16]))),
             Resource::UserGroup(ResourceAction::Delete(ResourceId::Id([0; 16]))),
+            Resource::Asset(ResourceAction::Read(ResourceId::Id([0; 16]))),
+            Resource::Asset(ResourceAction::Update(ResourceId::Id([0; 16]))),
+            Resource::Asset(ResourceAction::Delete(ResourceId::Id([0; 16]))),
         ];
 
         for resource in invalid_resources {
@@ -893,4 +1016,41 @@ mod test {
                 .expect_err("Non existent resource should be invalid");
         }
     }
+
+    #[test]
+    fn only_mutating_actions_change_the_configuration() {
+        let configuration_changes = vec![
+            Resource::Permission(PermissionResourceAction::Update),
+            Resource::Account(AccountResourceAction::Create),
+            Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+            Resource::AddressBook(ResourceAction::Delete(ResourceId::Any)),
+            Resource::ExternalCanister(ExternalCanisterResourceAction::Fund(
+                ExternalCanisterId::Any,
+            )),
+            Resource::RequestPolicy(ResourceAction::Update(ResourceId::Any)),
+            Resource::System(SystemResourceAction::Upgrade),
+            Resource::User(UserResourceAction::Create),
+            Resource::UserGroup(ResourceAction::Create),
+        ];
+
+        for resource in configuration_changes {
+            assert!(resource.changes_configuration(), "{}", resource);
+        }
+
+        let other_actions = vec![
+            Resource::Permission(PermissionResourceAction::Read),
+            Resource::Account(AccountResourceAction::Read(ResourceId::Any)),
+            Resource::ExternalCanister(ExternalCanisterResourceAction::List),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+            Resource::Request(RequestResourceAction::List),
+            Resource::System(SystemResourceAction::SystemInfo),
+            Resource::System(SystemResourceAction::AuditOutflows),
+            Resource::User(UserResourceAction::ViewAs(ResourceId::Any)),
+            Resource::UserGroup(ResourceAction::List),
+        ];
+
+        for resource in other_actions {
+            assert!(!resource.changes_configuration(), "{}", resource);
+        }
+    }
 }
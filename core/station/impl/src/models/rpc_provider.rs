@@ -0,0 +1,138 @@
+use super::Blockchain;
+use crate::errors::RpcError;
+use orbit_essentials::model::{ModelValidator, ModelValidatorResult};
+use orbit_essentials::storable;
+use std::collections::BTreeSet;
+
+/// A JSON-RPC endpoint used by the outcall based blockchain adapters (e.g. Ethereum).
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RpcProvider {
+    /// The provider name (e.g. `cloudflare`).
+    pub name: String,
+    /// The JSON-RPC endpoint, which must use `https`.
+    pub url: String,
+}
+
+/// The RPC providers of a given blockchain.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RpcProvidersConfig {
+    /// The blockchain the providers are used for.
+    pub blockchain: Blockchain,
+    /// The providers, tried in order of health when one of them fails.
+    pub providers: Vec<RpcProvider>,
+    /// The number of providers that must return the same response for a read to be accepted.
+    pub read_quorum: u8,
+}
+
+impl RpcProvidersConfig {
+    pub const MAX_PROVIDERS: usize = 10;
+    pub const MAX_URL_LENGTH: usize = 255;
+}
+
+impl ModelValidator<RpcError> for RpcProvidersConfig {
+    fn validate(&self) -> ModelValidatorResult<RpcError> {
+        // an empty list of providers removes the configuration of the blockchain
+        if self.providers.is_empty() {
+            return Ok(());
+        }
+
+        if self.providers.len() > Self::MAX_PROVIDERS {
+            return Err(RpcError::InvalidConfig {
+                info: format!("at most {} providers are allowed", Self::MAX_PROVIDERS),
+            });
+        }
+
+        if self.read_quorum == 0 || self.read_quorum as usize > self.providers.len() {
+            return Err(RpcError::InvalidConfig {
+                info: format!(
+                    "the read quorum must be between 1 and the number of providers ({})",
+                    self.providers.len()
+                ),
+            });
+        }
+
+        let mut urls = BTreeSet::new();
+        for provider in self.providers.iter() {
+            if !provider.url.starts_with("https://") || provider.url.len() > Self::MAX_URL_LENGTH {
+                return Err(RpcError::InvalidConfig {
+                    info: format!(
+                        "the url of provider `{}` must be an https url of at most {} characters",
+                        provider.name,
+                        Self::MAX_URL_LENGTH
+                    ),
+                });
+            }
+
+            if !urls.insert(provider.url.as_str()) {
+                return Err(RpcError::InvalidConfig {
+                    info: format!("the url of provider `{}` is duplicated", provider.name),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod rpc_provider_test_utils {
+    use super::*;
+
+    pub fn mock_rpc_providers_config() -> RpcProvidersConfig {
+        RpcProvidersConfig {
+            blockchain: Blockchain::Ethereum,
+            providers: vec![
+                RpcProvider {
+                    name: "primary".to_string(),
+                    url: "https://primary.example.com".to_string(),
+                },
+                RpcProvider {
+                    name: "secondary".to_string(),
+                    url: "https://secondary.example.com".to_string(),
+                },
+                RpcProvider {
+                    name: "tertiary".to_string(),
+                    url: "https://tertiary.example.com".to_string(),
+                },
+            ],
+            read_quorum: 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rpc_provider_test_utils::mock_rpc_providers_config;
+    use super::*;
+
+    #[test]
+    fn valid_config_passes_validation() {
+        assert!(mock_rpc_providers_config().validate().is_ok());
+    }
+
+    #[test]
+    fn fail_quorum_above_providers_count() {
+        let mut config = mock_rpc_providers_config();
+        config.read_quorum = 4;
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn fail_insecure_provider_url() {
+        let mut config = mock_rpc_providers_config();
+        config.providers[0].url = "http://primary.example.com".to_string();
+
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn fail_duplicated_provider_url() {
+        let mut config = mock_rpc_providers_config();
+        config.providers[1].url = config.providers[0].url.clone();
+
+        assert!(config.validate().is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use super::{
+    AddRequestPolicyOperationInput, EditPermissionOperationInput, EditRequestPolicyOperationInput,
+    RequestId,
+};
+use orbit_essentials::model::ModelKey;
+use orbit_essentials::storable;
+use orbit_essentials::types::{Timestamp, UUID};
+
+/// The scheduled policy change id, which is a UUID.
+pub type ScheduledPolicyChangeId = UUID;
+
+/// A request policy or permission change that was approved ahead of the time it takes effect.
+///
+/// The change is kept until its `effective_from` time and then applied by a background job, which
+/// lets governance changes be announced in advance and take effect at a coordinated time.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScheduledPolicyChange {
+    pub id: ScheduledPolicyChangeId,
+    /// The request that scheduled the change.
+    pub request_id: RequestId,
+    pub change: PolicyChange,
+    /// The time the change takes effect.
+    pub effective_from: Timestamp,
+    pub status: ScheduledPolicyChangeStatus,
+    pub created_timestamp: Timestamp,
+    pub last_modification_timestamp: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PolicyChange {
+    AddRequestPolicy(AddRequestPolicyOperationInput),
+    EditRequestPolicy(EditRequestPolicyOperationInput),
+    EditPermission(EditPermissionOperationInput),
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ScheduledPolicyChangeStatus {
+    /// The change is waiting for its effective time.
+    Pending,
+    /// The change was applied.
+    Activated { activated_at: Timestamp },
+    /// The change could not be applied at its effective time (e.g. the policy was removed meanwhile).
+    Failed { reason: String },
+}
+
+impl ModelKey<ScheduledPolicyChangeId> for ScheduledPolicyChange {
+    fn key(&self) -> ScheduledPolicyChangeId {
+        self.id
+    }
+}
+
+impl ScheduledPolicyChange {
+    pub fn is_pending(&self) -> bool {
+        self.status == ScheduledPolicyChangeStatus::Pending
+    }
+
+    /// Whether the change is pending and its effective time has been reached.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.is_pending() && self.effective_from <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scheduled_policy_change_test_utils::mock_scheduled_policy_change;
+    use super::*;
+
+    #[test]
+    fn only_pending_changes_past_their_effective_time_are_due() {
+        let mut change = mock_scheduled_policy_change();
+        change.effective_from = 100;
+
+        assert!(!change.is_due(99));
+        assert!(change.is_due(100));
+
+        change.status = ScheduledPolicyChangeStatus::Activated { activated_at: 100 };
+
+        assert!(!change.is_due(200));
+    }
+}
+
+#[cfg(test)]
+pub mod scheduled_policy_change_test_utils {
+    use super::*;
+    use crate::models::{
+        request_policy_rule::RequestPolicyRule, request_specifier::RequestSpecifier,
+    };
+    use uuid::Uuid;
+
+    pub fn mock_scheduled_policy_change() -> ScheduledPolicyChange {
+        ScheduledPolicyChange {
+            id: *Uuid::new_v4().as_bytes(),
+            request_id: *Uuid::new_v4().as_bytes(),
+            change: PolicyChange::AddRequestPolicy(AddRequestPolicyOperationInput {
+                specifier: RequestSpecifier::AddAccount,
+                rule: RequestPolicyRule::AutoApproved,
+                expiration_period_secs: None,
+            }),
+            effective_from: 0,
+            status: ScheduledPolicyChangeStatus::Pending,
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use super::{RequestId, TransferId, TransferOperationInput, UserId};
+use crate::errors::ScheduledTransferError;
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator, ModelValidatorResult},
+    storable,
+    types::{Timestamp, UUID},
+};
+
+/// The scheduled transfer id, which is a UUID.
+pub type ScheduledTransferId = UUID;
+
+/// A standing order that repeats the same transfer at a fixed interval (e.g. a monthly payment).
+///
+/// The standing order is created once its request is approved, each execution then creates the
+/// transfer without another approval until the end date is reached or the standing order is cancelled.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScheduledTransfer {
+    pub id: ScheduledTransferId,
+    /// The request that created the standing order, which is also the request of its transfers.
+    pub request_id: RequestId,
+    pub requested_by: UserId,
+    pub transfer: TransferOperationInput,
+    /// The time between two executions of the transfer.
+    pub interval_ns: u64,
+    pub next_execution_at: Timestamp,
+    /// No execution happens after this time.
+    pub end_at: Option<Timestamp>,
+    /// The number of transfers created by the standing order.
+    pub executions: u64,
+    pub last_transfer_id: Option<TransferId>,
+    /// The reason the last execution could not create its transfer, cleared by the next successful one.
+    pub last_error: Option<String>,
+    pub status: ScheduledTransferStatus,
+    pub created_timestamp: Timestamp,
+    pub last_modification_timestamp: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ScheduledTransferStatus {
+    /// The transfer is executed at every interval.
+    Active,
+    /// The end date of the standing order was reached.
+    Completed { completed_at: Timestamp },
+    Cancelled {
+        cancelled_at: Timestamp,
+        cancelled_by: UserId,
+    },
+}
+
+impl ModelKey<ScheduledTransferId> for ScheduledTransfer {
+    fn key(&self) -> ScheduledTransferId {
+        self.id
+    }
+}
+
+impl ScheduledTransfer {
+    /// The shortest interval between two executions, to keep the standing orders from draining
+    /// the account faster than it can be noticed.
+    pub const MIN_INTERVAL_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    pub fn is_active(&self) -> bool {
+        self.status == ScheduledTransferStatus::Active
+    }
+
+    /// Whether the standing order is active and its next execution time has been reached.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.is_active() && self.next_execution_at <= now
+    }
+
+    /// Moves the next execution to the first interval after `now`, the executions missed while the
+    /// canister was not running are skipped rather than executed at once.
+    ///
+    /// Returns whether the standing order has another execution before its end date.
+    pub fn advance(&mut self, now: Timestamp) -> bool {
+        let missed_intervals = now.saturating_sub(self.next_execution_at) / self.interval_ns;
+
+        self.next_execution_at = self.next_execution_at.saturating_add(
+            self.interval_ns
+                .saturating_mul(missed_intervals.saturating_add(1)),
+        );
+
+        self.end_at
+            .map_or(true, |end_at| self.next_execution_at <= end_at)
+    }
+}
+
+impl ModelValidator<ScheduledTransferError> for ScheduledTransfer {
+    fn validate(&self) -> ModelValidatorResult<ScheduledTransferError> {
+        if self.interval_ns < Self::MIN_INTERVAL_NS {
+            return Err(ScheduledTransferError::InvalidInterval {
+                min_interval_secs: Self::MIN_INTERVAL_NS / 1_000_000_000,
+            });
+        }
+
+        if let Some(end_at) = self.end_at {
+            if end_at < self.next_execution_at {
+                return Err(ScheduledTransferError::InvalidEndDate);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scheduled_transfer_test_utils::mock_scheduled_transfer;
+    use super::*;
+
+    #[test]
+    fn fail_scheduled_transfer_interval_too_short() {
+        let mut scheduled_transfer = mock_scheduled_transfer();
+        scheduled_transfer.interval_ns = ScheduledTransfer::MIN_INTERVAL_NS - 1;
+
+        assert_eq!(
+            scheduled_transfer.validate(),
+            Err(ScheduledTransferError::InvalidInterval {
+                min_interval_secs: 3600
+            })
+        );
+    }
+
+    #[test]
+    fn fail_scheduled_transfer_ends_before_first_execution() {
+        let mut scheduled_transfer = mock_scheduled_transfer();
+        scheduled_transfer.end_at = Some(scheduled_transfer.next_execution_at - 1);
+
+        assert_eq!(
+            scheduled_transfer.validate(),
+            Err(ScheduledTransferError::InvalidEndDate)
+        );
+    }
+
+    #[test]
+    fn advance_skips_the_missed_executions() {
+        let mut scheduled_transfer = mock_scheduled_transfer();
+        scheduled_transfer.next_execution_at = 100;
+        scheduled_transfer.interval_ns = 10;
+
+        assert!(scheduled_transfer.advance(100));
+        assert_eq!(scheduled_transfer.next_execution_at, 110);
+
+        assert!(scheduled_transfer.advance(135));
+        assert_eq!(scheduled_transfer.next_execution_at, 140);
+    }
+
+    #[test]
+    fn advance_past_the_end_date_has_no_execution_left() {
+        let mut scheduled_transfer = mock_scheduled_transfer();
+        scheduled_transfer.next_execution_at = 100;
+        scheduled_transfer.interval_ns = 10;
+        scheduled_transfer.end_at = Some(115);
+
+        assert!(scheduled_transfer.advance(100));
+        assert!(!scheduled_transfer.advance(110));
+    }
+}
+
+#[cfg(test)]
+pub mod scheduled_transfer_test_utils {
+    use super::*;
+    use crate::models::Metadata;
+    use uuid::Uuid;
+
+    pub fn mock_scheduled_transfer() -> ScheduledTransfer {
+        ScheduledTransfer {
+            id: *Uuid::new_v4().as_bytes(),
+            request_id: [1; 16],
+            requested_by: [2; 16],
+            transfer: TransferOperationInput {
+                from_account_id: [3; 16],
+                to: "destination".to_string(),
+                amount: candid::Nat::from(100u64),
+                metadata: Metadata::default(),
+                network: "mainnet".to_string(),
+                fee: None,
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
+            },
+            interval_ns: ScheduledTransfer::MIN_INTERVAL_NS,
+            next_execution_at: 1_000,
+            end_at: None,
+            executions: 0,
+            last_transfer_id: None,
+            last_error: None,
+            status: ScheduledTransferStatus::Active,
+            created_timestamp: 0,
+            last_modification_timestamp: 0,
+        }
+    }
+}
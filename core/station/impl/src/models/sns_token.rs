@@ -0,0 +1,95 @@
+use super::{
+    Asset, Blockchain, BlockchainStandard, Metadata, ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+    ACCOUNT_METADATA_SYMBOL_KEY,
+};
+use candid::Principal;
+use orbit_essentials::storable;
+use std::collections::BTreeMap;
+
+/// The asset metadata key for the root canister id of the SNS that governs the token.
+pub const ASSET_METADATA_SNS_ROOT_CANISTER_ID_KEY: &str = "sns_root_canister_id";
+
+/// The asset metadata key for the index canister id of the token ledger.
+pub const ASSET_METADATA_INDEX_CANISTER_ID_KEY: &str = "index_canister_id";
+
+/// The ledger of an SNS token, as discovered from the SNS-W canister.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SnsToken {
+    pub root_canister_id: Principal,
+    pub ledger_canister_id: Principal,
+    pub index_canister_id: Option<Principal>,
+    /// The token symbol (e.g. `CHAT`), as reported by the ledger.
+    pub symbol: String,
+    /// The token name (e.g. `OpenChat`), as reported by the ledger.
+    pub name: String,
+    pub decimals: u32,
+}
+
+impl SnsToken {
+    /// The supported asset of the token, whose metadata can be used as is to add an account for it.
+    pub fn to_asset(&self) -> Asset {
+        let mut metadata = BTreeMap::from([
+            (
+                ACCOUNT_METADATA_SYMBOL_KEY.to_string(),
+                self.symbol.to_owned(),
+            ),
+            (
+                ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                self.ledger_canister_id.to_text(),
+            ),
+            (
+                ASSET_METADATA_SNS_ROOT_CANISTER_ID_KEY.to_string(),
+                self.root_canister_id.to_text(),
+            ),
+        ]);
+
+        if let Some(index_canister_id) = &self.index_canister_id {
+            metadata.insert(
+                ASSET_METADATA_INDEX_CANISTER_ID_KEY.to_string(),
+                index_canister_id.to_text(),
+            );
+        }
+
+        Asset {
+            blockchain: Blockchain::InternetComputer,
+            standard: BlockchainStandard::ICRC1,
+            symbol: self.symbol.to_owned(),
+            name: self.name.to_owned(),
+            metadata: Metadata::new(metadata),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sns_token_asset_holds_the_ledger_canister_id() {
+        let token = SnsToken {
+            root_canister_id: Principal::from_slice(&[1; 10]),
+            ledger_canister_id: Principal::from_slice(&[2; 10]),
+            index_canister_id: None,
+            symbol: "CHAT".to_string(),
+            name: "OpenChat".to_string(),
+            decimals: 8,
+        };
+
+        let asset = token.to_asset();
+
+        assert_eq!(asset.standard, BlockchainStandard::ICRC1);
+        assert_eq!(
+            asset.metadata.get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY),
+            Some(token.ledger_canister_id.to_text())
+        );
+        assert_eq!(
+            asset.metadata.get(ACCOUNT_METADATA_SYMBOL_KEY),
+            Some("CHAT".to_string())
+        );
+        assert_eq!(
+            asset.metadata.get(ASSET_METADATA_INDEX_CANISTER_ID_KEY),
+            None
+        );
+    }
+}
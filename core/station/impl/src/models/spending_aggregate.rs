@@ -0,0 +1,180 @@
+use super::{AccountId, Transfer};
+use candid::Nat;
+use orbit_essentials::model::ModelKey;
+use orbit_essentials::storable;
+use orbit_essentials::types::Timestamp;
+use uuid::Uuid;
+
+/// The transfer metadata key of the tag the spending can be grouped by (e.g. `payroll`).
+pub const TRANSFER_METADATA_TAG_KEY: &str = "tag";
+
+/// The calendar period the spending is aggregated over, in UTC.
+#[storable]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpendingPeriod {
+    Day,
+    /// The weeks start on Monday.
+    Week,
+    Month,
+}
+
+impl SpendingPeriod {
+    pub const ALL: [SpendingPeriod; 3] = [
+        SpendingPeriod::Day,
+        SpendingPeriod::Week,
+        SpendingPeriod::Month,
+    ];
+
+    const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    /// Returns the start of the period that contains the timestamp.
+    pub fn start_of(&self, timestamp: Timestamp) -> Timestamp {
+        let days = timestamp / Self::DAY_NS;
+
+        let start_day = match self {
+            SpendingPeriod::Day => days,
+            // the unix epoch was a Thursday
+            SpendingPeriod::Week => days.saturating_sub((days + 3) % 7),
+            SpendingPeriod::Month => {
+                let (year, month) = year_month_from_days(days as i64);
+
+                days_from_year_month(year, month) as u64
+            }
+        };
+
+        start_day * Self::DAY_NS
+    }
+}
+
+/// Returns the year and month of the day since the unix epoch, in the proleptic Gregorian calendar.
+fn year_month_from_days(days: i64) -> (i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month)
+}
+
+/// Returns the days since the unix epoch of the first day of the month.
+fn days_from_year_month(year: i64, month: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// What the spending is grouped by.
+#[storable]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SpendingGroupBy {
+    Account,
+    Destination,
+    /// The `tag` metadata of the transfers, transfers without it are not part of any tag group.
+    Tag,
+    Asset,
+}
+
+impl SpendingGroupBy {
+    pub const ALL: [SpendingGroupBy; 4] = [
+        SpendingGroupBy::Account,
+        SpendingGroupBy::Destination,
+        SpendingGroupBy::Tag,
+        SpendingGroupBy::Asset,
+    ];
+
+    /// Returns the group of the transfer, if it belongs to one.
+    pub fn group_of(&self, transfer: &Transfer, symbol: &str) -> Option<String> {
+        match self {
+            SpendingGroupBy::Account => Some(
+                Uuid::from_bytes(transfer.from_account)
+                    .hyphenated()
+                    .to_string(),
+            ),
+            SpendingGroupBy::Destination => Some(transfer.to_address.clone()),
+            SpendingGroupBy::Tag => transfer
+                .metadata
+                .get(TRANSFER_METADATA_TAG_KEY)
+                .filter(|tag| !tag.trim().is_empty()),
+            SpendingGroupBy::Asset => Some(symbol.to_string()),
+        }
+    }
+}
+
+/// The total amount sent by an account to a group within a period, aggregated from the completed transfers.
+///
+/// The aggregates are kept per account so that the summaries only include the accounts the caller can read.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpendingAggregate {
+    pub period: SpendingPeriod,
+    pub period_start: Timestamp,
+    pub account_id: AccountId,
+    pub group_by: SpendingGroupBy,
+    /// The group the transfers belong to (e.g. the destination address or the tag).
+    pub group: String,
+    /// The symbol of the asset of the account, which the total is denominated in.
+    pub symbol: String,
+    pub total: Nat,
+    pub count: u64,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpendingAggregateKey {
+    pub period: SpendingPeriod,
+    pub period_start: Timestamp,
+    pub account_id: AccountId,
+    pub group_by: SpendingGroupBy,
+    pub group: String,
+}
+
+impl ModelKey<SpendingAggregateKey> for SpendingAggregate {
+    fn key(&self) -> SpendingAggregateKey {
+        SpendingAggregateKey {
+            period: self.period,
+            period_start: self.period_start,
+            account_id: self.account_id,
+            group_by: self.group_by,
+            group: self.group.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn periods_start_on_calendar_boundaries() {
+        // 2024-03-15T13:05:00Z, a Friday
+        let timestamp = 1_710_507_900_000_000_000;
+
+        assert_eq!(
+            SpendingPeriod::Day.start_of(timestamp),
+            1_710_460_800_000_000_000
+        );
+        assert_eq!(
+            SpendingPeriod::Week.start_of(timestamp),
+            1_710_115_200_000_000_000
+        );
+        assert_eq!(
+            SpendingPeriod::Month.start_of(timestamp),
+            1_709_251_200_000_000_000
+        );
+        assert_eq!(SpendingPeriod::Month.start_of(0), 0);
+    }
+}
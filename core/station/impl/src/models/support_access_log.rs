@@ -0,0 +1,56 @@
+use super::UserId;
+use orbit_essentials::model::ModelKey;
+use orbit_essentials::storable;
+use orbit_essentials::types::{Timestamp, UUID};
+
+/// The support access log entry id, which is a UUID.
+pub type SupportAccessLogEntryId = UUID;
+
+/// Records that a user viewed the resources and privileges visible to another user with `view_as`.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SupportAccessLogEntry {
+    pub id: SupportAccessLogEntryId,
+    /// The user that viewed the access of the other user.
+    pub viewer_id: UserId,
+    /// The user whose access was viewed.
+    pub viewed_user_id: UserId,
+    pub viewed_at: Timestamp,
+}
+
+/// The entries are ordered by the time of the view, the oldest first.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SupportAccessLogEntryKey {
+    pub viewed_at: Timestamp,
+    pub id: SupportAccessLogEntryId,
+}
+
+impl ModelKey<SupportAccessLogEntryKey> for SupportAccessLogEntry {
+    fn key(&self) -> SupportAccessLogEntryKey {
+        SupportAccessLogEntryKey {
+            viewed_at: self.viewed_at,
+            id: self.id,
+        }
+    }
+}
+
+impl SupportAccessLogEntry {
+    /// The maximum number of entries kept, the oldest entries are removed first.
+    pub const MAX_ENTRIES: usize = 1_000;
+}
+
+#[cfg(test)]
+pub mod support_access_log_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_support_access_log_entry() -> SupportAccessLogEntry {
+        SupportAccessLogEntry {
+            id: *Uuid::new_v4().as_bytes(),
+            viewer_id: *Uuid::new_v4().as_bytes(),
+            viewed_user_id: *Uuid::new_v4().as_bytes(),
+            viewed_at: 0,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     core::{
         ic_cdk::api::{time, trap},
-        SYSTEM_RESERVED_MEMORY_BYTES,
+        STABLE_MEMORY_CAPACITY, SYSTEM_RESERVED_MEMORY_BYTES,
     },
+    errors::RequestError,
     STABLE_MEMORY_VERSION, SYSTEM_VERSION,
 };
 use candid::Principal;
@@ -11,7 +12,10 @@ use orbit_essentials::storable;
 use orbit_essentials::types::{Timestamp, UUID};
 use std::borrow::Cow;
 
-use super::{AccountId, UserGroupId};
+use super::{
+    AccountId, Blockchain, RequestOperation, RequestOperationType, RpcProvidersConfig, SnsToken,
+    UserGroupId,
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SystemState {
@@ -36,6 +40,405 @@ pub enum CycleObtainStrategy {
     },
 }
 
+/// The number of confirmations after which the transfers of a blockchain are considered final,
+/// which overrides the default finality of the blockchain adapter.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FinalityThreshold {
+    pub blockchain: Blockchain,
+    pub required_confirmations: u32,
+}
+
+impl FinalityThreshold {
+    pub const MAX_REQUIRED_CONFIRMATIONS: u32 = 1_000;
+}
+
+/// A blockchain whose transfer executions were paused by the circuit breaker after repeated failures.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PausedBlockchain {
+    pub blockchain: Blockchain,
+    pub paused_at: Timestamp,
+    /// The failure that tripped the circuit breaker.
+    pub reason: String,
+}
+
+impl PausedBlockchain {
+    pub const MAX_REASON_LENGTH: usize = 500;
+}
+
+/// Where the settled history is exported to before it is pruned, a user-owned canister or the
+/// station itself.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArchiveSink {
+    /// The archive canister the chunks are appended to, the chunks are kept in the station to be
+    /// downloaded if unset.
+    pub canister_id: Option<Principal>,
+    /// The number of days the settled requests and transfers are kept in the station.
+    pub retention_days: u32,
+}
+
+impl ArchiveSink {
+    pub const MIN_RETENTION_DAYS: u32 = 31;
+    pub const MAX_RETENTION_DAYS: u32 = 3_650;
+
+    pub fn retention_ns(&self) -> u64 {
+        u64::from(self.retention_days).saturating_mul(24 * 60 * 60 * 1_000_000_000)
+    }
+}
+
+/// How the transfer executions that failed with a transient ledger error are retried.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferRetryPolicy {
+    /// The maximum number of submissions of a transfer, `1` disables the retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, which doubles after every failed attempt.
+    pub initial_backoff_secs: u64,
+}
+
+impl Default for TransferRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+            initial_backoff_secs: Self::DEFAULT_INITIAL_BACKOFF_SECS,
+        }
+    }
+}
+
+impl TransferRetryPolicy {
+    pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+    pub const DEFAULT_INITIAL_BACKOFF_SECS: u64 = 30;
+    pub const MAX_ATTEMPTS: u32 = 10;
+    pub const MAX_INITIAL_BACKOFF_SECS: u64 = 60 * 60;
+    /// The longest delay between two attempts, whatever the number of failed attempts.
+    pub const MAX_BACKOFF_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    /// Returns the delay before the next attempt, given the number of attempts that already failed.
+    pub fn backoff_ns(&self, failed_attempts: u32) -> u64 {
+        let multiplier = 2u64.saturating_pow(failed_attempts.saturating_sub(1));
+
+        self.initial_backoff_secs
+            .saturating_mul(1_000_000_000)
+            .saturating_mul(multiplier)
+            .min(Self::MAX_BACKOFF_NS)
+    }
+
+    /// Whether a transfer can be submitted again after the given number of failed attempts.
+    pub fn can_retry(&self, failed_attempts: u32) -> bool {
+        failed_attempts < self.max_attempts
+    }
+}
+
+/// Refuses the new requests once the stable memory usage crosses the high-water mark, so that the
+/// station keeps enough room to vote, execute and prune instead of trapping at the memory limit.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StableMemoryGuardrail {
+    /// The share of the stable memory capacity above which the new requests are refused.
+    pub high_water_mark_percent: u8,
+}
+
+impl Default for StableMemoryGuardrail {
+    fn default() -> Self {
+        Self {
+            high_water_mark_percent: Self::DEFAULT_HIGH_WATER_MARK_PERCENT,
+        }
+    }
+}
+
+impl StableMemoryGuardrail {
+    pub const DEFAULT_HIGH_WATER_MARK_PERCENT: u8 = 90;
+    pub const MIN_HIGH_WATER_MARK_PERCENT: u8 = 50;
+    pub const MAX_HIGH_WATER_MARK_PERCENT: u8 = 99;
+
+    pub fn high_water_mark_bytes(&self) -> u64 {
+        STABLE_MEMORY_CAPACITY / 100 * u64::from(self.high_water_mark_percent)
+    }
+
+    /// Whether the new requests are refused with the given stable memory usage.
+    pub fn is_reached(&self, used_bytes: u64) -> bool {
+        used_bytes >= self.high_water_mark_bytes()
+    }
+
+    /// Checks that the request can be created with the given stable memory usage.
+    ///
+    /// The upgrades and the system changes are still accepted above the mark, since they are how the
+    /// admins free up memory (e.g. by configuring an archive sink) or raise the mark.
+    pub fn check(&self, used_bytes: u64, operation: &RequestOperation) -> Result<(), RequestError> {
+        let is_critical = matches!(
+            operation,
+            RequestOperation::SystemUpgrade(_)
+                | RequestOperation::ManageSystemInfo(_)
+                | RequestOperation::SetDisasterRecovery(_)
+        );
+
+        if !is_critical && self.is_reached(used_bytes) {
+            return Err(RequestError::StorageCapacityReached {
+                used_bytes,
+                high_water_mark_bytes: self.high_water_mark_bytes(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Forwards the requests awaiting approval to the control panel, which reminds the approvers on the
+/// e-mail and webhook channels they configured there, as the station does not store how to reach them.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ApprovalReminders {
+    /// The control panel the reminders are forwarded to, they are not forwarded if unset.
+    pub control_panel_id: Option<Principal>,
+    /// How long before a request expires the possible approvers that did not vote yet are notified
+    /// again, they are not reminded if unset.
+    #[serde(default)]
+    pub pending_vote_lead_time_secs: Option<u64>,
+}
+
+impl ApprovalReminders {
+    /// The shortest lead time, below which the reminder could arrive after the request expired.
+    pub const MIN_PENDING_VOTE_LEAD_TIME_SECS: u64 = 15 * 60;
+    /// The longest lead time, which is the longest expiration of a request by default.
+    pub const MAX_PENDING_VOTE_LEAD_TIME_SECS: u64 = 30 * 24 * 60 * 60;
+}
+
+/// When the fund manager tops up the upgrader canister with cycles and by how much, based on its
+/// estimated runtime or on its balance while the runtime can not be estimated yet.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CycleThresholds {
+    /// The estimated runtime below which the canister is topped up.
+    pub min_runtime_secs: u64,
+    /// The runtime that the top up is sized to add.
+    pub fund_runtime_secs: u64,
+    /// The most cycles added by a single top up.
+    pub max_runtime_cycles_fund: u64,
+    /// The balance below which the canister is topped up while its runtime can not be estimated.
+    pub fallback_min_cycles: u64,
+    /// The cycles added while the runtime can not be estimated.
+    pub fallback_fund_cycles: u64,
+}
+
+impl Default for CycleThresholds {
+    fn default() -> Self {
+        Self {
+            min_runtime_secs: 14 * 24 * 60 * 60,
+            fund_runtime_secs: 30 * 24 * 60 * 60,
+            max_runtime_cycles_fund: 1_000_000_000_000,
+            fallback_min_cycles: 125_000_000_000,
+            fallback_fund_cycles: 250_000_000_000,
+        }
+    }
+}
+
+/// The last chunk appended to the archive canister, which the next chunk is chained to.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArchiveHead {
+    pub sequence: u64,
+    pub hash: Vec<u8>,
+    pub archived_at: Timestamp,
+}
+
+/// The outcome of a check of the self-check suite.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SelfCheckResult {
+    pub name: String,
+    pub passed: bool,
+    /// What went wrong, only set for the failed checks.
+    pub details: Option<String>,
+}
+
+/// The report of the self-check suite that runs after the station upgraded itself.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SelfCheckReport {
+    /// The version of the station that was checked.
+    pub version: String,
+    pub checked_at: Timestamp,
+    pub checks: Vec<SelfCheckResult>,
+}
+
+impl SelfCheckReport {
+    /// The maximum length of the details of a check, to keep the system info within its reserved memory.
+    pub const MAX_DETAILS_LEN: usize = 200;
+
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+}
+
+/// Guardrails enforced when a request is created, to reject operations that would fail at execution.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestOperationLimits {
+    /// The maximum size in bytes of a wasm module of an upgrade or install operation.
+    pub max_wasm_module_size: u64,
+    /// The maximum size in bytes of the argument of an upgrade, install or call operation.
+    pub max_arg_size: u64,
+    /// The maximum number of entries of a list in the operation input (e.g. identities, groups, labels).
+    pub max_batch_length: u64,
+}
+
+impl Default for RequestOperationLimits {
+    fn default() -> Self {
+        Self {
+            max_wasm_module_size: Self::DEFAULT_MAX_WASM_MODULE_SIZE,
+            max_arg_size: Self::DEFAULT_MAX_ARG_SIZE,
+            max_batch_length: Self::DEFAULT_MAX_BATCH_LENGTH,
+        }
+    }
+}
+
+impl RequestOperationLimits {
+    /// Modules that are larger than the message size limit must be provided through extra chunks.
+    pub const DEFAULT_MAX_WASM_MODULE_SIZE: u64 = 2 * 1024 * 1024;
+    pub const DEFAULT_MAX_ARG_SIZE: u64 = 2 * 1024 * 1024;
+    pub const DEFAULT_MAX_BATCH_LENGTH: u64 = 1_000;
+
+    /// Checks that the given operation is within the configured limits.
+    pub fn check(&self, operation: &RequestOperation) -> Result<(), RequestError> {
+        match operation {
+            RequestOperation::SystemUpgrade(operation) => {
+                self.check_wasm_module(&operation.input.module)?;
+                self.check_arg(&operation.input.arg)?;
+            }
+            RequestOperation::ChangeExternalCanister(operation) => {
+                self.check_wasm_module(&operation.input.module)?;
+                self.check_arg(&operation.input.arg)?;
+            }
+            RequestOperation::CallExternalCanister(operation) => {
+                self.check_arg(&operation.input.arg)?;
+            }
+            RequestOperation::AddUser(operation) => {
+                self.check_batch("identities", operation.input.identities.len())?;
+                self.check_batch("groups", operation.input.groups.len())?;
+            }
+            RequestOperation::EditUser(operation) => {
+                if let Some(identities) = &operation.input.identities {
+                    self.check_batch("identities", identities.len())?;
+                }
+                if let Some(groups) = &operation.input.groups {
+                    self.check_batch("groups", groups.len())?;
+                }
+            }
+            RequestOperation::EditPermission(operation) => {
+                if let Some(users) = &operation.input.users {
+                    self.check_batch("users", users.len())?;
+                }
+                if let Some(user_groups) = &operation.input.user_groups {
+                    self.check_batch("user_groups", user_groups.len())?;
+                }
+            }
+            RequestOperation::AddAddressBookEntry(operation) => {
+                self.check_batch("labels", operation.input.labels.len())?;
+                self.check_batch("metadata", operation.input.metadata.len())?;
+            }
+            RequestOperation::EditAddressBookEntry(operation) => {
+                if let Some(labels) = &operation.input.labels {
+                    self.check_batch("labels", labels.len())?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn check_wasm_module(&self, module: &[u8]) -> Result<(), RequestError> {
+        Self::check_limit("wasm_module_size", module.len(), self.max_wasm_module_size)
+    }
+
+    fn check_arg(&self, arg: &Option<Vec<u8>>) -> Result<(), RequestError> {
+        match arg {
+            Some(arg) => Self::check_limit("arg_size", arg.len(), self.max_arg_size),
+            None => Ok(()),
+        }
+    }
+
+    fn check_batch(&self, field: &str, len: usize) -> Result<(), RequestError> {
+        Self::check_limit(&format!("{}_length", field), len, self.max_batch_length)
+    }
+
+    fn check_limit(limit: &str, actual: usize, max: u64) -> Result<(), RequestError> {
+        if actual as u64 > max {
+            return Err(RequestError::OperationLimitExceeded {
+                limit: limit.to_string(),
+                max,
+                actual: actual as u64,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The period over which the requests created by a user are counted.
+#[storable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RateLimitPeriod {
+    Hour,
+    Day,
+}
+
+impl RateLimitPeriod {
+    pub fn as_nanos(&self) -> u64 {
+        match self {
+            RateLimitPeriod::Hour => 60 * 60 * 1_000_000_000,
+            RateLimitPeriod::Day => 24 * 60 * 60 * 1_000_000_000,
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitPeriod::Hour => write!(f, "hour"),
+            RateLimitPeriod::Day => write!(f, "day"),
+        }
+    }
+}
+
+/// Caps the number of requests that each user can create within a period.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCreationRateLimit {
+    /// The operation of the counted requests, all the requests are counted if unset.
+    pub operation_type: Option<RequestOperationType>,
+    pub period: RateLimitPeriod,
+    pub max_requests: u32,
+}
+
+impl RequestCreationRateLimit {
+    pub fn applies_to(&self, operation_type: &RequestOperationType) -> bool {
+        self.operation_type
+            .as_ref()
+            .map_or(true, |limited| limited == operation_type)
+    }
+}
+
+/// The rate limits enforced when a request is created, the members of the override group are exempt.
+#[storable]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestCreationRateLimits {
+    pub limits: Vec<RequestCreationRateLimit>,
+    pub override_group_id: Option<UserGroupId>,
+}
+
+impl RequestCreationRateLimits {
+    /// The maximum number of limits, to keep the system info within its reserved memory.
+    pub const MAX_LIMITS: usize = 32;
+
+    pub fn is_exempt(&self, groups: &[UserGroupId]) -> bool {
+        self.override_group_id
+            .is_some_and(|override_group_id| groups.contains(&override_group_id))
+    }
+}
+
 #[storable(size = SYSTEM_RESERVED_MEMORY_BYTES)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SystemInfo {
@@ -54,6 +457,61 @@ pub struct SystemInfo {
     /// Defines how the station tops up itself with cycles.
     #[serde(default)]
     cycle_obtain_strategy: CycleObtainStrategy,
+    /// The limits enforced on the operations of new requests.
+    #[serde(default)]
+    request_operation_limits: RequestOperationLimits,
+    /// The JSON-RPC providers of the outcall based blockchain adapters.
+    #[serde(default)]
+    rpc_providers: Vec<RpcProvidersConfig>,
+    /// The finality thresholds that override the defaults of the blockchain adapters.
+    #[serde(default)]
+    finality_thresholds: Vec<FinalityThreshold>,
+    /// How the transfers that failed with a transient ledger error are retried.
+    #[serde(default)]
+    transfer_retry_policy: TransferRetryPolicy,
+    /// The maximum version that the update checker is allowed to suggest, if pinned by the owners.
+    #[serde(default)]
+    max_suggested_version: Option<String>,
+    /// The SNS tokens discovered from the SNS-W canister.
+    #[serde(default)]
+    sns_tokens: Vec<SnsToken>,
+    /// Last time the SNS tokens were discovered.
+    #[serde(default)]
+    sns_tokens_refreshed_at: Option<Timestamp>,
+    /// The time until which the completed transfers are included in the spending aggregates.
+    #[serde(default)]
+    spending_aggregated_until: Option<Timestamp>,
+    /// The blockchains whose transfer executions are paused until an admin resumes them.
+    #[serde(default)]
+    paused_blockchains: Vec<PausedBlockchain>,
+    /// The canister the settled history is exported to before it is pruned, if configured.
+    #[serde(default)]
+    archive_sink: Option<ArchiveSink>,
+    /// The last chunk appended to the archive sink.
+    #[serde(default)]
+    archive_head: Option<ArchiveHead>,
+    /// The report of the self-check suite that ran after the last upgrade.
+    #[serde(default)]
+    last_self_check: Option<SelfCheckReport>,
+    /// The locale of the messages of the users that did not choose one, if it is not English.
+    #[serde(default)]
+    default_locale: Option<String>,
+    /// The high-water mark of the stable memory above which the new requests are refused.
+    #[serde(default)]
+    stable_memory_guardrail: StableMemoryGuardrail,
+    /// Where the requests awaiting approval are forwarded to remind the approvers.
+    #[serde(default)]
+    approval_reminders: ApprovalReminders,
+    /// How many requests the users can create within an hour or a day.
+    #[serde(default)]
+    request_creation_rate_limits: RequestCreationRateLimits,
+    /// How long the requests stay open when their policies do not set an expiration period, 30 days
+    /// if unset.
+    #[serde(default)]
+    default_request_expiration_secs: Option<u64>,
+    /// When and by how much the upgrader canister is topped up with cycles.
+    #[serde(default)]
+    cycle_thresholds: CycleThresholds,
     /// The system version.
     version: Option<String>,
     /// Last run migration version.
@@ -72,12 +530,31 @@ impl Default for SystemInfo {
             version: Some(SYSTEM_VERSION.to_string()),
             stable_memory_version: Some(STABLE_MEMORY_VERSION),
             cycle_obtain_strategy: CycleObtainStrategy::default(),
+            request_operation_limits: RequestOperationLimits::default(),
+            rpc_providers: Vec::new(),
+            finality_thresholds: Vec::new(),
+            transfer_retry_policy: TransferRetryPolicy::default(),
+            max_suggested_version: None,
+            sns_tokens: Vec::new(),
+            sns_tokens_refreshed_at: None,
+            spending_aggregated_until: None,
+            paused_blockchains: Vec::new(),
+            archive_sink: None,
+            archive_head: None,
+            last_self_check: None,
+            default_locale: None,
+            stable_memory_guardrail: StableMemoryGuardrail::default(),
+            approval_reminders: ApprovalReminders::default(),
+            request_creation_rate_limits: RequestCreationRateLimits::default(),
+            default_request_expiration_secs: None,
+            cycle_thresholds: CycleThresholds::default(),
         }
     }
 }
 
 impl SystemInfo {
     pub const MAX_NAME_LENGTH: usize = 48;
+    pub const DEFAULT_REQUEST_EXPIRATION_SECS: u64 = 30 * 24 * 60 * 60;
 
     pub fn new(upgrader_canister_id: Principal, upgrader_wasm_module: Vec<u8>) -> Self {
         Self {
@@ -103,6 +580,205 @@ impl SystemInfo {
         self.cycle_obtain_strategy = strategy;
     }
 
+    pub fn get_request_operation_limits(&self) -> &RequestOperationLimits {
+        &self.request_operation_limits
+    }
+
+    pub fn set_request_operation_limits(&mut self, limits: RequestOperationLimits) {
+        self.request_operation_limits = limits;
+    }
+
+    pub fn get_rpc_providers(&self, blockchain: &Blockchain) -> Option<&RpcProvidersConfig> {
+        self.rpc_providers
+            .iter()
+            .find(|config| config.blockchain == *blockchain)
+    }
+
+    pub fn get_all_rpc_providers(&self) -> &[RpcProvidersConfig] {
+        &self.rpc_providers
+    }
+
+    /// Replaces the providers of the blockchain of the given config, an empty list removes them.
+    pub fn set_rpc_providers(&mut self, config: RpcProvidersConfig) {
+        self.rpc_providers
+            .retain(|existing| existing.blockchain != config.blockchain);
+
+        if !config.providers.is_empty() {
+            self.rpc_providers.push(config);
+        }
+    }
+
+    /// Returns the configured number of confirmations after which the transfers of the blockchain are final.
+    pub fn get_finality_threshold(&self, blockchain: &Blockchain) -> Option<u32> {
+        self.finality_thresholds
+            .iter()
+            .find(|threshold| threshold.blockchain == *blockchain)
+            .map(|threshold| threshold.required_confirmations)
+    }
+
+    pub fn get_finality_thresholds(&self) -> &[FinalityThreshold] {
+        &self.finality_thresholds
+    }
+
+    /// Replaces all the finality thresholds, an empty list restores the defaults of the blockchain adapters.
+    pub fn set_finality_thresholds(&mut self, thresholds: Vec<FinalityThreshold>) {
+        self.finality_thresholds = thresholds;
+    }
+
+    pub fn get_transfer_retry_policy(&self) -> &TransferRetryPolicy {
+        &self.transfer_retry_policy
+    }
+
+    pub fn set_transfer_retry_policy(&mut self, policy: TransferRetryPolicy) {
+        self.transfer_retry_policy = policy;
+    }
+
+    pub fn get_stable_memory_guardrail(&self) -> &StableMemoryGuardrail {
+        &self.stable_memory_guardrail
+    }
+
+    pub fn set_stable_memory_guardrail(&mut self, guardrail: StableMemoryGuardrail) {
+        self.stable_memory_guardrail = guardrail;
+    }
+
+    pub fn get_approval_reminders(&self) -> &ApprovalReminders {
+        &self.approval_reminders
+    }
+
+    pub fn set_approval_reminders(&mut self, approval_reminders: ApprovalReminders) {
+        self.approval_reminders = approval_reminders;
+    }
+
+    pub fn get_request_creation_rate_limits(&self) -> &RequestCreationRateLimits {
+        &self.request_creation_rate_limits
+    }
+
+    pub fn set_request_creation_rate_limits(&mut self, rate_limits: RequestCreationRateLimits) {
+        self.request_creation_rate_limits = rate_limits;
+    }
+
+    pub fn get_default_request_expiration_secs(&self) -> u64 {
+        self.default_request_expiration_secs
+            .unwrap_or(Self::DEFAULT_REQUEST_EXPIRATION_SECS)
+    }
+
+    pub fn set_default_request_expiration_secs(&mut self, expiration_secs: u64) {
+        self.default_request_expiration_secs = Some(expiration_secs);
+    }
+
+    pub fn get_cycle_thresholds(&self) -> &CycleThresholds {
+        &self.cycle_thresholds
+    }
+
+    pub fn set_cycle_thresholds(&mut self, thresholds: CycleThresholds) {
+        self.cycle_thresholds = thresholds;
+    }
+
+    pub fn get_max_suggested_version(&self) -> Option<&str> {
+        self.max_suggested_version.as_deref()
+    }
+
+    pub fn set_max_suggested_version(&mut self, version: Option<String>) {
+        self.max_suggested_version = version;
+    }
+
+    pub fn get_sns_tokens(&self) -> &[SnsToken] {
+        &self.sns_tokens
+    }
+
+    pub fn get_sns_tokens_refreshed_at(&self) -> Option<Timestamp> {
+        self.sns_tokens_refreshed_at
+    }
+
+    pub fn set_sns_tokens(&mut self, tokens: Vec<SnsToken>, refreshed_at: Timestamp) {
+        self.sns_tokens = tokens;
+        self.sns_tokens_refreshed_at = Some(refreshed_at);
+    }
+
+    pub fn get_spending_aggregated_until(&self) -> Option<Timestamp> {
+        self.spending_aggregated_until
+    }
+
+    pub fn set_spending_aggregated_until(&mut self, aggregated_until: Timestamp) {
+        self.spending_aggregated_until = Some(aggregated_until);
+    }
+
+    pub fn get_paused_blockchain(&self, blockchain: &Blockchain) -> Option<&PausedBlockchain> {
+        self.paused_blockchains
+            .iter()
+            .find(|paused| paused.blockchain == *blockchain)
+    }
+
+    pub fn get_paused_blockchains(&self) -> &[PausedBlockchain] {
+        &self.paused_blockchains
+    }
+
+    /// Pauses the transfer executions of the blockchain, unless it is already paused.
+    pub fn pause_blockchain(&mut self, mut paused: PausedBlockchain) {
+        if self.get_paused_blockchain(&paused.blockchain).is_some() {
+            return;
+        }
+
+        paused.reason = paused
+            .reason
+            .chars()
+            .take(PausedBlockchain::MAX_REASON_LENGTH)
+            .collect();
+
+        self.paused_blockchains.push(paused);
+    }
+
+    /// Resumes the transfer executions of the blockchain, returns `false` if it was not paused.
+    pub fn resume_blockchain(&mut self, blockchain: &Blockchain) -> bool {
+        let paused_count = self.paused_blockchains.len();
+        self.paused_blockchains
+            .retain(|paused| paused.blockchain != *blockchain);
+
+        self.paused_blockchains.len() != paused_count
+    }
+
+    pub fn get_archive_sink(&self) -> Option<&ArchiveSink> {
+        self.archive_sink.as_ref()
+    }
+
+    /// Sets or removes the archive sink, moving to another canister starts a new hash chain.
+    pub fn set_archive_sink(&mut self, sink: Option<ArchiveSink>) {
+        let same_canister = match (&self.archive_sink, &sink) {
+            (Some(current), Some(new)) => current.canister_id == new.canister_id,
+            _ => false,
+        };
+
+        if !same_canister {
+            self.archive_head = None;
+        }
+
+        self.archive_sink = sink;
+    }
+
+    pub fn get_archive_head(&self) -> Option<&ArchiveHead> {
+        self.archive_head.as_ref()
+    }
+
+    pub fn set_archive_head(&mut self, head: ArchiveHead) {
+        self.archive_head = Some(head);
+    }
+
+    pub fn get_last_self_check(&self) -> Option<&SelfCheckReport> {
+        self.last_self_check.as_ref()
+    }
+
+    pub fn set_last_self_check(&mut self, report: SelfCheckReport) {
+        self.last_self_check = Some(report);
+    }
+
+    pub fn get_default_locale(&self) -> Option<&str> {
+        self.default_locale.as_deref()
+    }
+
+    pub fn set_default_locale(&mut self, locale: Option<String>) {
+        self.default_locale = locale;
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
@@ -218,6 +894,10 @@ impl Storable for SystemState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{
+        AddUserOperation, AddUserOperationInput, SetDisasterRecoveryOperation,
+        SetDisasterRecoveryOperationInput, UserStatus,
+    };
 
     #[test]
     fn test_system_info_name_validation() {
@@ -242,4 +922,131 @@ mod tests {
         info.set_name("  test".to_string());
         assert_eq!(info.name, "test");
     }
+
+    #[test]
+    fn test_transfer_retry_backoff_doubles_until_the_cap() {
+        let policy = TransferRetryPolicy {
+            max_attempts: 3,
+            initial_backoff_secs: 30,
+        };
+
+        assert_eq!(policy.backoff_ns(1), 30 * 1_000_000_000);
+        assert_eq!(policy.backoff_ns(2), 60 * 1_000_000_000);
+        assert_eq!(policy.backoff_ns(3), 120 * 1_000_000_000);
+        assert_eq!(policy.backoff_ns(64), TransferRetryPolicy::MAX_BACKOFF_NS);
+
+        assert!(policy.can_retry(2));
+        assert!(!policy.can_retry(3));
+    }
+
+    #[test]
+    fn test_finality_thresholds_override_blockchains() {
+        let mut info = SystemInfo::default();
+        assert_eq!(info.get_finality_threshold(&Blockchain::Bitcoin), None);
+
+        info.set_finality_thresholds(vec![FinalityThreshold {
+            blockchain: Blockchain::Bitcoin,
+            required_confirmations: 3,
+        }]);
+        assert_eq!(info.get_finality_threshold(&Blockchain::Bitcoin), Some(3));
+        assert_eq!(info.get_finality_threshold(&Blockchain::Ethereum), None);
+
+        info.set_finality_thresholds(Vec::new());
+        assert_eq!(info.get_finality_threshold(&Blockchain::Bitcoin), None);
+    }
+
+    #[test]
+    fn test_moving_the_archive_sink_starts_a_new_chain() {
+        let mut info = SystemInfo::default();
+        let sink = ArchiveSink {
+            canister_id: Some(Principal::from_slice(&[1; 29])),
+            retention_days: 90,
+        };
+        let head = ArchiveHead {
+            sequence: 4,
+            hash: vec![7; 32],
+            archived_at: 0,
+        };
+
+        info.set_archive_sink(Some(sink.clone()));
+        info.set_archive_head(head.clone());
+
+        info.set_archive_sink(Some(ArchiveSink {
+            retention_days: 180,
+            ..sink.clone()
+        }));
+        assert_eq!(info.get_archive_head(), Some(&head));
+
+        info.set_archive_sink(Some(ArchiveSink {
+            canister_id: None,
+            ..sink
+        }));
+        assert_eq!(info.get_archive_head(), None);
+    }
+
+    #[test]
+    fn test_request_operation_limits_reject_oversized_batches() {
+        let limits = RequestOperationLimits {
+            max_batch_length: 2,
+            ..Default::default()
+        };
+        let mut operation = AddUserOperation {
+            user_id: None,
+            input: AddUserOperationInput {
+                name: "user".to_string(),
+                identities: vec![Principal::anonymous(); 2],
+                groups: vec![],
+                status: UserStatus::Active,
+                approve_only_identities: vec![],
+            },
+        };
+
+        assert!(limits
+            .check(&RequestOperation::AddUser(operation.clone()))
+            .is_ok());
+
+        operation.input.identities.push(Principal::anonymous());
+
+        assert_eq!(
+            limits.check(&RequestOperation::AddUser(operation)),
+            Err(RequestError::OperationLimitExceeded {
+                limit: "identities_length".to_string(),
+                max: 2,
+                actual: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_stable_memory_guardrail_only_lets_critical_requests_through() {
+        let guardrail = StableMemoryGuardrail {
+            high_water_mark_percent: 80,
+        };
+        let mark = guardrail.high_water_mark_bytes();
+        let add_user = RequestOperation::AddUser(AddUserOperation {
+            user_id: None,
+            input: AddUserOperationInput {
+                name: "user".to_string(),
+                identities: vec![Principal::anonymous()],
+                groups: vec![],
+                status: UserStatus::Active,
+                approve_only_identities: vec![],
+            },
+        });
+        let set_disaster_recovery =
+            RequestOperation::SetDisasterRecovery(SetDisasterRecoveryOperation {
+                input: SetDisasterRecoveryOperationInput { committee: None },
+            });
+
+        assert_eq!(mark, STABLE_MEMORY_CAPACITY / 100 * 80);
+        assert!(guardrail.check(mark - 1, &add_user).is_ok());
+        assert_eq!(
+            guardrail.check(mark, &add_user),
+            Err(RequestError::StorageCapacityReached {
+                used_bytes: mark,
+                high_water_mark_bytes: mark,
+            })
+        );
+        assert!(guardrail.check(mark, &set_disaster_recovery).is_ok());
+    }
 }
@@ -1,4 +1,4 @@
-use super::{AccountId, UserId};
+use super::{AccountId, Blockchain, BlockchainStandard, IcrcAccount, RegisteredAssetId, UserId};
 use crate::core::ic_cdk::next_time;
 use crate::core::validation::{EnsureAccount, EnsureIdExists, EnsureRequest, EnsureUser};
 use crate::errors::{RecordValidationError, TransferError};
@@ -16,6 +16,20 @@ use std::{
 };
 
 pub const METADATA_MEMO_KEY: &str = "memo";
+/// The id of the invoice paid by the transfer (e.g. `INV-2024/0042`).
+pub const METADATA_INVOICE_ID_KEY: &str = "invoice_id";
+/// The bookkeeping category of the transfer (e.g. `payroll`).
+pub const METADATA_CATEGORY_KEY: &str = "category";
+/// A free-form reference to the payment (e.g. an order or a contract).
+pub const METADATA_REFERENCE_KEY: &str = "reference";
+
+/// The well-known metadata keys, whose values are validated and indexed so that the transfers of an
+/// account can be filtered by them.
+pub const INDEXED_METADATA_KEYS: [&str; 3] = [
+    METADATA_INVOICE_ID_KEY,
+    METADATA_CATEGORY_KEY,
+    METADATA_REFERENCE_KEY,
+];
 
 /// The transfer id, which is a UUID.
 pub type TransferId = UUID;
@@ -27,6 +41,12 @@ pub enum TransferStatus {
     Processing {
         started_at: Timestamp,
     },
+    /// The transaction was submitted and awaits enough confirmations to be considered final.
+    Confirming {
+        submitted_at: Timestamp,
+        confirmations: u32,
+        required_confirmations: u32,
+    },
     Completed {
         signature: Option<String>,
         hash: Option<String>,
@@ -42,12 +62,82 @@ impl Display for TransferStatus {
         match self {
             TransferStatus::Created => write!(f, "created"),
             TransferStatus::Processing { .. } => write!(f, "processing"),
+            TransferStatus::Confirming { .. } => write!(f, "confirming"),
             TransferStatus::Completed { .. } => write!(f, "completed"),
             TransferStatus::Failed { .. } => write!(f, "failed"),
         }
     }
 }
 
+/// A submission of the transfer that failed, kept to explain the retries of the transfer.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferAttempt {
+    pub attempted_at: Timestamp,
+    pub error: String,
+}
+
+/// The memo that is recorded on the ledger with the transfer.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferMemo {
+    Number(u64),
+    Blob(Vec<u8>),
+}
+
+impl TransferMemo {
+    /// The maximum length of an ICRC-1 memo, as enforced by the ledgers by default.
+    pub const MAX_ICRC1_BLOB_LENGTH: usize = 32;
+
+    /// Validates that the memo is supported by the ledger of the given blockchain and standard.
+    ///
+    /// The ICP ledger only records numeric memos, while ICRC-1 ledgers record blobs in which
+    /// numeric memos are encoded as big endian bytes.
+    pub fn validate_for(
+        &self,
+        blockchain: &Blockchain,
+        standard: &BlockchainStandard,
+    ) -> ModelValidatorResult<TransferError> {
+        match (blockchain, standard, self) {
+            (Blockchain::InternetComputer, BlockchainStandard::Native, TransferMemo::Number(_))
+            | (Blockchain::InternetComputer, BlockchainStandard::ICRC1, TransferMemo::Number(_)) => {
+                Ok(())
+            }
+            (Blockchain::InternetComputer, BlockchainStandard::ICRC1, TransferMemo::Blob(blob)) => {
+                if blob.len() > Self::MAX_ICRC1_BLOB_LENGTH {
+                    return Err(TransferError::ValidationError {
+                        info: format!(
+                            "Transfer memo length exceeds the maximum of {} bytes",
+                            Self::MAX_ICRC1_BLOB_LENGTH
+                        ),
+                    });
+                }
+
+                Ok(())
+            }
+            (Blockchain::InternetComputer, BlockchainStandard::Native, TransferMemo::Blob(_)) => {
+                Err(TransferError::ValidationError {
+                    info: "The ICP ledger only supports numeric transfer memos".to_string(),
+                })
+            }
+            (blockchain, standard, _) => Err(TransferError::ValidationError {
+                info: format!(
+                    "Transfer memos are not supported for {} {} accounts",
+                    blockchain, standard
+                ),
+            }),
+        }
+    }
+
+    /// Returns the memo as it is recorded by ICRC-1 ledgers.
+    pub fn to_icrc1_bytes(&self) -> Vec<u8> {
+        match self {
+            TransferMemo::Number(number) => number.to_be_bytes().to_vec(),
+            TransferMemo::Blob(blob) => blob.clone(),
+        }
+    }
+}
+
 /// Represents a transfer in the system.
 #[storable]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -72,6 +162,29 @@ pub struct Transfer {
     pub blockchain_network: String,
     /// The transfer metadata (e.g. `memo`, `description`, etc.)
     pub metadata: Metadata,
+    /// The ledger account the funds are pulled from with `icrc2_transfer_from`, if any.
+    ///
+    /// The station account is then the spender of the allowance approved by this account.
+    #[serde(default)]
+    pub spend_from: Option<IcrcAccount>,
+    /// The memo recorded on the ledger, if not set the `memo` metadata or the transfer id is used.
+    #[serde(default)]
+    pub memo: Option<TransferMemo>,
+    /// The details of the submitted transaction (e.g. `transaction_hash`, `block_height`).
+    #[serde(default)]
+    pub submitted_details: Option<Vec<(String, String)>>,
+    /// The submissions that failed, the transfer is only failed once the retries are exhausted.
+    #[serde(default)]
+    pub failed_attempts: Vec<TransferAttempt>,
+    /// The time after which the transfer is submitted again, if waiting for a retry.
+    #[serde(default)]
+    pub next_attempt_at: Option<Timestamp>,
+    /// The bookkeeping category of the transfer, one of the registered transfer categories.
+    #[serde(default)]
+    pub category: Option<String>,
+    /// The registered asset held by the account that is sent, the own asset of the account if not set.
+    #[serde(default)]
+    pub asset_id: Option<RegisteredAssetId>,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
     /// The creation timestamp of the transfer.
@@ -94,6 +207,9 @@ impl ModelKey<TransferKey> for Transfer {
 impl Transfer {
     pub const ADDRESS_RANGE: (u8, u8) = (1, 255);
     pub const NETWORK_RANGE: (u8, u8) = (1, 50);
+    pub const MAX_INVOICE_ID_LEN: usize = 64;
+    pub const MAX_CATEGORY_LEN: usize = 32;
+    pub const MAX_REFERENCE_LEN: usize = 128;
 
     /// Creates a new transfer key from the given key components.
     pub fn key(id: TransferId) -> TransferKey {
@@ -104,6 +220,41 @@ impl Transfer {
         Self::key(self.id)
     }
 
+    /// Validates the metadata of a transfer, including the values of the well-known keys.
+    pub fn validate_metadata(metadata: &Metadata) -> ModelValidatorResult<TransferError> {
+        metadata.validate()?;
+
+        if let Some(invoice_id) = metadata.get(METADATA_INVOICE_ID_KEY) {
+            validate_metadata_value(
+                METADATA_INVOICE_ID_KEY,
+                &invoice_id,
+                Self::MAX_INVOICE_ID_LEN,
+                |c| c.is_ascii_alphanumeric() || "-_/.#".contains(c),
+            )?;
+        }
+
+        // categories are compared as they are, so they are kept to a single lowercase form
+        if let Some(category) = metadata.get(METADATA_CATEGORY_KEY) {
+            validate_metadata_value(
+                METADATA_CATEGORY_KEY,
+                &category,
+                Self::MAX_CATEGORY_LEN,
+                |c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_',
+            )?;
+        }
+
+        if let Some(reference) = metadata.get(METADATA_REFERENCE_KEY) {
+            validate_metadata_value(
+                METADATA_REFERENCE_KEY,
+                reference.trim(),
+                Self::MAX_REFERENCE_LEN,
+                |c| !c.is_control(),
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn metadata_map(&self) -> HashMap<String, String> {
         self.metadata.map()
     }
@@ -133,10 +284,42 @@ impl Transfer {
             fee,
             blockchain_network,
             metadata,
+            spend_from: None,
+            memo: None,
+            submitted_details: None,
+            failed_attempts: Vec::new(),
+            next_attempt_at: None,
+            category: None,
+            asset_id: None,
             last_modification_timestamp: now,
             created_timestamp: now,
         }
     }
+
+    /// Whether the transfer is waiting for the backoff of its next attempt to pass.
+    pub fn is_awaiting_retry(&self, now: Timestamp) -> bool {
+        self.next_attempt_at.is_some_and(|at| at > now)
+    }
+}
+
+/// The on-chain outcome of a pending transfer, as observed when auditing the blockchain history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PendingOutflowOutcome {
+    /// The transaction was found on chain with the given details (e.g. block_height).
+    OnChain { details: Vec<(String, String)> },
+    /// The transaction was not found on chain.
+    NotOnChain,
+    /// The outcome could not be determined.
+    Unknown { reason: String },
+}
+
+/// A pending transfer with the outcome observed on chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingOutflowAudit {
+    /// The audited transfer.
+    pub transfer: Transfer,
+    /// The outcome observed on chain.
+    pub outcome: PendingOutflowOutcome,
 }
 
 fn validate_to_address(to_address: &str) -> ModelValidatorResult<TransferError> {
@@ -155,6 +338,21 @@ fn validate_to_address(to_address: &str) -> ModelValidatorResult<TransferError>
     Ok(())
 }
 
+fn validate_metadata_value(
+    key: &str,
+    value: &str,
+    max_len: usize,
+    is_allowed: impl Fn(char) -> bool,
+) -> ModelValidatorResult<TransferError> {
+    if value.is_empty() || value.len() > max_len || !value.chars().all(is_allowed) {
+        return Err(TransferError::ValidationError {
+            info: format!("Invalid `{}` metadata `{}`", key, value),
+        });
+    }
+
+    Ok(())
+}
+
 fn validate_network(blockchain_network: &str) -> ModelValidatorResult<TransferError> {
     if (blockchain_network.len() < Transfer::NETWORK_RANGE.0 as usize)
         || (blockchain_network.len() > Transfer::NETWORK_RANGE.1 as usize)
@@ -173,7 +371,7 @@ fn validate_network(blockchain_network: &str) -> ModelValidatorResult<TransferEr
 
 impl ModelValidator<TransferError> for Transfer {
     fn validate(&self) -> ModelValidatorResult<TransferError> {
-        self.metadata.validate()?;
+        Self::validate_metadata(&self.metadata)?;
         validate_to_address(&self.to_address)?;
         validate_network(&self.blockchain_network)?;
 
@@ -304,6 +502,70 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_memo_validation() {
+        let icp = (Blockchain::InternetComputer, BlockchainStandard::Native);
+        let icrc1 = (Blockchain::InternetComputer, BlockchainStandard::ICRC1);
+
+        assert!(TransferMemo::Number(42)
+            .validate_for(&icp.0, &icp.1)
+            .is_ok());
+        assert!(TransferMemo::Number(42)
+            .validate_for(&icrc1.0, &icrc1.1)
+            .is_ok());
+        assert!(
+            TransferMemo::Blob(vec![1; TransferMemo::MAX_ICRC1_BLOB_LENGTH])
+                .validate_for(&icrc1.0, &icrc1.1)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn fail_memo_not_supported_by_ledger() {
+        assert!(TransferMemo::Blob(vec![1])
+            .validate_for(&Blockchain::InternetComputer, &BlockchainStandard::Native)
+            .is_err());
+        assert!(
+            TransferMemo::Blob(vec![1; TransferMemo::MAX_ICRC1_BLOB_LENGTH + 1])
+                .validate_for(&Blockchain::InternetComputer, &BlockchainStandard::ICRC1)
+                .is_err()
+        );
+        assert!(TransferMemo::Number(42)
+            .validate_for(&Blockchain::Bitcoin, &BlockchainStandard::Native)
+            .is_err());
+    }
+
+    #[test]
+    fn numeric_memos_are_big_endian_for_icrc1() {
+        assert_eq!(
+            TransferMemo::Number(258).to_icrc1_bytes(),
+            vec![0, 0, 0, 0, 0, 0, 1, 2]
+        );
+        assert_eq!(TransferMemo::Blob(vec![7, 8]).to_icrc1_bytes(), vec![7, 8]);
+    }
+
+    #[test]
+    fn well_known_metadata_is_validated() {
+        let metadata = |key: &str, value: &str| {
+            Metadata::new([(key.to_string(), value.to_string())].into_iter().collect())
+        };
+
+        assert!(
+            Transfer::validate_metadata(&metadata(METADATA_INVOICE_ID_KEY, "INV-2024/0042"))
+                .is_ok()
+        );
+        assert!(Transfer::validate_metadata(&metadata(METADATA_INVOICE_ID_KEY, "INV 42")).is_err());
+        assert!(Transfer::validate_metadata(&metadata(METADATA_CATEGORY_KEY, "payroll")).is_ok());
+        assert!(Transfer::validate_metadata(&metadata(METADATA_CATEGORY_KEY, "Payroll")).is_err());
+        assert!(
+            Transfer::validate_metadata(&metadata(METADATA_REFERENCE_KEY, "Order #12, March"))
+                .is_ok()
+        );
+        assert!(Transfer::validate_metadata(&metadata(METADATA_REFERENCE_KEY, " ")).is_err());
+        // the other keys are only checked for their length
+        assert!(Transfer::validate_metadata(&metadata("note", "Any value")).is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -325,6 +587,13 @@ pub mod transfer_test_utils {
             fee: candid::Nat::from(0_u64),
             blockchain_network: "a".repeat(50),
             metadata: Metadata::default(),
+            spend_from: None,
+            memo: None,
+            submitted_details: None,
+            failed_attempts: Vec::new(),
+            next_attempt_at: None,
+            category: None,
+            asset_id: None,
             last_modification_timestamp: now,
             created_timestamp: now,
         }
@@ -0,0 +1,89 @@
+use crate::{errors::TransferCategoryError, models::Transfer};
+use orbit_essentials::{
+    model::{ModelValidator, ModelValidatorResult},
+    storable,
+    types::Timestamp,
+};
+
+/// A category of the managed registry that transfers are tagged with for bookkeeping (e.g. `payroll`).
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferCategory {
+    /// The name of the category, a lowercase slug that the transfers reference.
+    pub name: String,
+    /// What the category is used for, e.g. the account of the chart of accounts it maps to.
+    pub description: Option<String>,
+    pub last_modification_timestamp: Timestamp,
+}
+
+impl TransferCategory {
+    pub const MAX_NAME_LEN: usize = Transfer::MAX_CATEGORY_LEN;
+    pub const MAX_DESCRIPTION_LEN: usize = 200;
+}
+
+/// Checks that the name is made of lowercase letters, digits, dashes and underscores, since the
+/// categories are compared as they are.
+pub fn validate_category_name(name: &str) -> ModelValidatorResult<TransferCategoryError> {
+    let is_valid = !name.is_empty()
+        && name.len() <= TransferCategory::MAX_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+    if !is_valid {
+        return Err(TransferCategoryError::InvalidName {
+            name: name.to_string(),
+            max_length: TransferCategory::MAX_NAME_LEN,
+        });
+    }
+
+    Ok(())
+}
+
+impl ModelValidator<TransferCategoryError> for TransferCategory {
+    fn validate(&self) -> ModelValidatorResult<TransferCategoryError> {
+        validate_category_name(&self.name)?;
+
+        if self
+            .description
+            .as_ref()
+            .is_some_and(|description| description.len() > Self::MAX_DESCRIPTION_LEN)
+        {
+            return Err(TransferCategoryError::DescriptionTooLong {
+                max_length: Self::MAX_DESCRIPTION_LEN,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_the_category() {
+        let mut category = TransferCategory {
+            name: "payroll".to_string(),
+            description: Some("Salaries and contractor fees".to_string()),
+            last_modification_timestamp: 0,
+        };
+
+        assert!(category.validate().is_ok());
+
+        category.description = Some("a".repeat(TransferCategory::MAX_DESCRIPTION_LEN + 1));
+
+        assert!(category.validate().is_err());
+    }
+
+    #[test]
+    fn validates_the_category_name() {
+        assert!(validate_category_name("payroll").is_ok());
+        assert!(validate_category_name("r-and-d_2024").is_ok());
+        assert!(validate_category_name("").is_err());
+        assert!(validate_category_name("Payroll").is_err());
+        assert!(validate_category_name("pay roll").is_err());
+        assert!(validate_category_name(&"a".repeat(TransferCategory::MAX_NAME_LEN + 1)).is_err());
+    }
+}
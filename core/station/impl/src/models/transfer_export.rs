@@ -0,0 +1,108 @@
+use serde::Serialize;
+
+/// The columns of the CSV exports, in the order of the fields of `TransferExportRow`.
+pub const TRANSFER_EXPORT_CSV_HEADER: &str = "transfer_id,request_id,created_at,status,completed_at,to,amount,fee,network,block_index,transaction_hash,category,approvers";
+
+/// A transfer as exported for bookkeeping tools, the amounts are in the smallest unit of the asset.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TransferExportRow {
+    pub transfer_id: String,
+    pub request_id: String,
+    pub created_at: String,
+    pub status: String,
+    pub completed_at: Option<String>,
+    pub to: String,
+    pub amount: String,
+    pub fee: String,
+    pub network: String,
+    pub block_index: Option<u64>,
+    pub transaction_hash: Option<String>,
+    pub category: Option<String>,
+    /// The names of the users that approved the transfer request.
+    pub approvers: Vec<String>,
+}
+
+impl TransferExportRow {
+    /// Renders the row as a CSV line, the approvers are separated by `;` within their column.
+    pub fn to_csv_line(&self) -> String {
+        [
+            self.transfer_id.clone(),
+            self.request_id.clone(),
+            self.created_at.clone(),
+            self.status.clone(),
+            self.completed_at.clone().unwrap_or_default(),
+            self.to.clone(),
+            self.amount.clone(),
+            self.fee.clone(),
+            self.network.clone(),
+            self.block_index
+                .map(|block_index| block_index.to_string())
+                .unwrap_or_default(),
+            self.transaction_hash.clone().unwrap_or_default(),
+            self.category.clone().unwrap_or_default(),
+            self.approvers.join(";"),
+        ]
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+
+    /// Renders the row as a single line JSON object.
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("Failed to serialize the transfer export row")
+    }
+}
+
+/// Quotes the field if it contains a separator, a quote or a line break, doubling its quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_row() -> TransferExportRow {
+        TransferExportRow {
+            transfer_id: "0d6e8b3a-4c2e-4a43-9d38-3f5a1c3b8e21".to_string(),
+            request_id: "5b1f0e8c-2a7d-4a5e-8c1b-6f9d3e2a1b40".to_string(),
+            created_at: "2024-05-01T10:00:00Z".to_string(),
+            status: "completed".to_string(),
+            completed_at: Some("2024-05-01T10:01:00Z".to_string()),
+            to: "0x1234".to_string(),
+            amount: "100000000".to_string(),
+            fee: "10000".to_string(),
+            network: "mainnet".to_string(),
+            block_index: Some(42),
+            transaction_hash: None,
+            category: None,
+            approvers: vec!["Alice".to_string(), "Bob, \"the CFO\"".to_string()],
+        }
+    }
+
+    #[test]
+    fn renders_the_csv_line_in_the_order_of_the_header() {
+        let line = mock_row().to_csv_line();
+
+        assert_eq!(
+            line,
+            "0d6e8b3a-4c2e-4a43-9d38-3f5a1c3b8e21,5b1f0e8c-2a7d-4a5e-8c1b-6f9d3e2a1b40,\
+            2024-05-01T10:00:00Z,completed,2024-05-01T10:01:00Z,0x1234,100000000,10000,mainnet,42,,,\
+            \"Alice;Bob, \"\"the CFO\"\"\""
+        );
+    }
+
+    #[test]
+    fn renders_the_json_line() {
+        let line = mock_row().to_json_line();
+
+        assert!(!line.contains('\n'));
+        assert!(line.contains("\"block_index\":42"));
+        assert!(line.contains("\"approvers\":[\"Alice\",\"Bob, \\\"the CFO\\\"\"]"));
+    }
+}
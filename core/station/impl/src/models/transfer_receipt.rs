@@ -0,0 +1,62 @@
+use super::{AccountId, RequestId, TransferId, UserId};
+use candid::Nat;
+use orbit_essentials::{storable, types::Timestamp};
+
+/// The proof of a completed transfer, whose hash is certified so that third parties can verify
+/// the payment with the certificate of the station rather than by trusting the payer.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferReceipt {
+    pub transfer_id: TransferId,
+    pub request_id: RequestId,
+    pub from_account: AccountId,
+    /// The blockchain of the account, e.g. `icp`.
+    pub blockchain: String,
+    /// The symbol of the transferred asset.
+    pub symbol: String,
+    pub decimals: u32,
+    pub to_address: String,
+    pub amount: Nat,
+    pub fee: Nat,
+    /// The index of the block that includes the transaction, if the blockchain reported it.
+    pub block_index: Option<u64>,
+    /// The hash of the transaction, if the blockchain reported it.
+    pub transaction_hash: Option<String>,
+    /// The approvals that made the transfer request pass.
+    pub approvals: Vec<TransferReceiptApproval>,
+    pub completed_at: Timestamp,
+}
+
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransferReceiptApproval {
+    pub approver_id: UserId,
+    pub approved_at: Timestamp,
+}
+
+#[cfg(test)]
+pub mod transfer_receipt_test_utils {
+    use super::*;
+    use uuid::Uuid;
+
+    pub fn mock_transfer_receipt() -> TransferReceipt {
+        TransferReceipt {
+            transfer_id: *Uuid::new_v4().as_bytes(),
+            request_id: [2; 16],
+            from_account: [1; 16],
+            blockchain: "icp".to_string(),
+            symbol: "ICP".to_string(),
+            decimals: 8,
+            to_address: "x".repeat(64),
+            amount: Nat::from(100_u64),
+            fee: Nat::from(10_000_u64),
+            block_index: Some(7),
+            transaction_hash: None,
+            approvals: vec![TransferReceiptApproval {
+                approver_id: [3; 16],
+                approved_at: 1,
+            }],
+            completed_at: 2,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::errors::AccountError;
+use candid::Nat;
+use orbit_essentials::{model::ModelValidatorResult, storable};
+use std::collections::HashSet;
+
+/// The period over which the amount sent to a trusted destination is capped.
+#[storable]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TrustedDestinationPeriod {
+    Day,
+    Week,
+    Month,
+}
+
+impl TrustedDestinationPeriod {
+    const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    pub fn duration_ns(&self) -> u64 {
+        match self {
+            TrustedDestinationPeriod::Day => Self::DAY_NS,
+            TrustedDestinationPeriod::Week => 7 * Self::DAY_NS,
+            TrustedDestinationPeriod::Month => 30 * Self::DAY_NS,
+        }
+    }
+}
+
+/// A destination of an account whose transfers are auto approved by the `TrustedDestination`
+/// policy rule, as long as the amount sent to it within the period stays within `max_amount`.
+#[storable]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TrustedDestination {
+    /// The destination address, as used in the `to` field of the transfers.
+    pub address: String,
+    /// The maximum amount that can be sent to the destination within the period.
+    pub max_amount: Nat,
+    pub period: TrustedDestinationPeriod,
+}
+
+impl TrustedDestination {
+    pub const MAX_PER_ACCOUNT: usize = 50;
+}
+
+pub fn validate_trusted_destinations(
+    trusted_destinations: &[TrustedDestination],
+) -> ModelValidatorResult<AccountError> {
+    if trusted_destinations.len() > TrustedDestination::MAX_PER_ACCOUNT {
+        return Err(AccountError::ValidationError {
+            info: format!(
+                "An account can have at most {} trusted destinations",
+                TrustedDestination::MAX_PER_ACCOUNT
+            ),
+        });
+    }
+
+    let mut addresses = HashSet::new();
+    for destination in trusted_destinations {
+        if destination.address.trim().is_empty() {
+            return Err(AccountError::ValidationError {
+                info: "The address of a trusted destination cannot be empty".to_string(),
+            });
+        }
+
+        if destination.max_amount == 0u64 {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "The max amount of the trusted destination {} must be greater than zero",
+                    destination.address
+                ),
+            });
+        }
+
+        if !addresses.insert(destination.address.as_str()) {
+            return Err(AccountError::ValidationError {
+                info: format!(
+                    "The trusted destination {} is listed more than once",
+                    destination.address
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trusted_destination(address: &str, max_amount: u64) -> TrustedDestination {
+        TrustedDestination {
+            address: address.to_string(),
+            max_amount: Nat::from(max_amount),
+            period: TrustedDestinationPeriod::Week,
+        }
+    }
+
+    #[test]
+    fn test_validate_trusted_destinations() {
+        assert!(validate_trusted_destinations(&[
+            trusted_destination("vendor-a", 100),
+            trusted_destination("vendor-b", 200),
+        ])
+        .is_ok());
+    }
+
+    #[test]
+    fn fail_trusted_destinations_with_duplicates_or_zero_amount() {
+        assert!(validate_trusted_destinations(&[
+            trusted_destination("vendor-a", 100),
+            trusted_destination("vendor-a", 200),
+        ])
+        .is_err());
+        assert!(validate_trusted_destinations(&[trusted_destination("vendor-a", 0)]).is_err());
+        assert!(validate_trusted_destinations(&[trusted_destination(" ", 10)]).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use super::UserStatus;
+use super::{OnboardingStep, UserOnboarding, UserStatus};
 use crate::{
     core::validation::{EnsureIdExists, EnsureUserGroup},
     errors::{RecordValidationError, UserError},
@@ -31,6 +31,36 @@ pub struct User {
     pub groups: Vec<UUID>,
     /// The last time the record was updated or created.
     pub last_modification_timestamp: Timestamp,
+    /// The SHA-256 hash of the secret token of the user's calendar feed url, if one was created.
+    #[serde(default)]
+    pub calendar_feed_token_hash: Option<[u8; 32]>,
+    /// The onboarding checklist of the user.
+    #[serde(default)]
+    pub onboarding: UserOnboarding,
+    /// The locale the messages to the user are rendered in, the station default is used if unset.
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// The identities of the user that can only vote on requests, they can't create requests or
+    /// change the configuration of the station (e.g. a hot identity used for daily approvals).
+    #[serde(default)]
+    pub approve_only_identities: Vec<Principal>,
+    /// The types of the notifications the station does not send to the user (e.g. `request-created`).
+    #[serde(default)]
+    pub muted_notification_types: Vec<String>,
+    /// The last time the user re-authenticated with one of its identities, which only counts as a
+    /// step-up for the approvals made from the other identities of the user.
+    #[serde(default)]
+    pub reauthentication: Option<UserReauthentication>,
+}
+
+/// A re-authentication of a user, recorded with the identity it was made from.
+#[storable]
+#[derive(CandidType, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserReauthentication {
+    /// The identity of the user that re-authenticated.
+    pub identity: Principal,
+    /// The time the re-authentication was recorded at.
+    pub at: Timestamp,
 }
 
 #[storable]
@@ -74,6 +104,26 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    /// Returns true if the identity of the user is restricted to voting on requests.
+    pub fn is_approve_only(&self, identity: &Principal) -> bool {
+        self.approve_only_identities.contains(identity)
+    }
+
+    /// Returns the time of the last re-authentication of the user made from another identity than
+    /// the given one, the identity approving a request can't step itself up.
+    pub fn reauthenticated_at(&self, identity: &Principal) -> Option<Timestamp> {
+        self.reauthentication
+            .as_ref()
+            .filter(|reauthentication| reauthentication.identity != *identity)
+            .map(|reauthentication| reauthentication.at)
+    }
+
+    /// Completes the onboarding steps that are derived from the state of the user (e.g. its groups),
+    /// returns `true` if any step was completed.
+    pub fn refresh_onboarding(&mut self, now: Timestamp) -> bool {
+        !self.groups.is_empty() && self.onboarding.complete(OnboardingStep::JoinedGroups, now)
+    }
 }
 
 fn validate_identities(identities: &[Principal]) -> ModelValidatorResult<UserError> {
@@ -98,6 +148,21 @@ fn validate_identities(identities: &[Principal]) -> ModelValidatorResult<UserErr
     Ok(())
 }
 
+fn validate_approve_only_identities(
+    approve_only_identities: &[Principal],
+    identities: &[Principal],
+) -> ModelValidatorResult<UserError> {
+    for identity in approve_only_identities {
+        if !identities.contains(identity) {
+            return Err(UserError::ApproveOnlyIdentityNotAssociated {
+                identity: identity.to_text(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_groups(group_ids: &[UUID]) -> ModelValidatorResult<UserError> {
     if group_ids.len() > User::MAX_USER_GROUPS as usize {
         return Err(UserError::TooManyUserGroups {
@@ -127,6 +192,7 @@ fn validate_name(name: &str) -> ModelValidatorResult<UserError> {
 impl ModelValidator<UserError> for User {
     fn validate(&self) -> ModelValidatorResult<UserError> {
         validate_identities(&self.identities)?;
+        validate_approve_only_identities(&self.approve_only_identities, &self.identities)?;
         validate_groups(&self.groups)?;
         validate_name(&self.name)?;
 
@@ -200,6 +266,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fail_approve_only_identity_not_associated_with_the_user() {
+        let mut user = mock_user();
+        let other_identity = Principal::from_slice(&[2; 29]);
+        user.approve_only_identities = vec![other_identity];
+
+        let result =
+            validate_approve_only_identities(&user.approve_only_identities, &user.identities);
+
+        assert_eq!(
+            result.unwrap_err(),
+            UserError::ApproveOnlyIdentityNotAssociated {
+                identity: other_identity.to_text()
+            }
+        );
+
+        user.identities.push(other_identity);
+
+        assert!(
+            validate_approve_only_identities(&user.approve_only_identities, &user.identities)
+                .is_ok()
+        );
+        assert!(user.is_approve_only(&other_identity));
+        assert!(!user.is_approve_only(&user.identities[0]));
+    }
+
     #[test]
     fn test_user_groups_validation() {
         let mut user = mock_user();
@@ -306,6 +398,12 @@ pub mod user_test_utils {
             name: format!("user_{}", uuid),
             status: UserStatus::Active,
             last_modification_timestamp: 0,
+            calendar_feed_token_hash: None,
+            onboarding: UserOnboarding::default(),
+            locale: None,
+            approve_only_identities: vec![],
+            muted_notification_types: vec![],
+            reauthentication: None,
         }
     }
 
@@ -0,0 +1,117 @@
+use candid::CandidType;
+use orbit_essentials::{storable, types::Timestamp};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+};
+
+/// The steps of the onboarding checklist of a user, in the order they are usually completed.
+#[storable]
+#[derive(CandidType, Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OnboardingStep {
+    /// The user confirmed the control of its identity by calling the station.
+    IdentityConfirmed,
+    /// The user is a member of at least one user group.
+    JoinedGroups,
+    /// The user approved or rejected a request for the first time.
+    FirstVoteCast,
+    /// The user reviewed its notification preferences.
+    NotificationPreferencesSet,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 4] = [
+        OnboardingStep::IdentityConfirmed,
+        OnboardingStep::JoinedGroups,
+        OnboardingStep::FirstVoteCast,
+        OnboardingStep::NotificationPreferencesSet,
+    ];
+
+    /// Whether the step is completed by the user, the other steps are tracked by the station.
+    pub fn is_self_reported(&self) -> bool {
+        matches!(
+            self,
+            OnboardingStep::IdentityConfirmed | OnboardingStep::NotificationPreferencesSet
+        )
+    }
+}
+
+impl Display for OnboardingStep {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            OnboardingStep::IdentityConfirmed => write!(f, "identity_confirmed"),
+            OnboardingStep::JoinedGroups => write!(f, "joined_groups"),
+            OnboardingStep::FirstVoteCast => write!(f, "first_vote_cast"),
+            OnboardingStep::NotificationPreferencesSet => write!(f, "notification_preferences_set"),
+        }
+    }
+}
+
+/// The onboarding checklist of a user, with the time each completed step was first completed.
+#[storable]
+#[derive(CandidType, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UserOnboarding {
+    pub completed_steps: BTreeMap<OnboardingStep, Timestamp>,
+}
+
+impl UserOnboarding {
+    /// Completes the step, returns `false` if it was already completed.
+    pub fn complete(&mut self, step: OnboardingStep, completed_at: Timestamp) -> bool {
+        if self.completed_steps.contains_key(&step) {
+            return false;
+        }
+
+        self.completed_steps.insert(step, completed_at);
+
+        true
+    }
+
+    pub fn completed_at(&self, step: &OnboardingStep) -> Option<Timestamp> {
+        self.completed_steps.get(step).copied()
+    }
+
+    /// The first step of the checklist that is not yet completed.
+    pub fn next_step(&self) -> Option<OnboardingStep> {
+        OnboardingStep::ALL
+            .into_iter()
+            .find(|step| !self.completed_steps.contains_key(step))
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.next_step().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_keep_their_first_completion_time() {
+        let mut onboarding = UserOnboarding::default();
+
+        assert!(onboarding.complete(OnboardingStep::JoinedGroups, 10));
+        assert!(!onboarding.complete(OnboardingStep::JoinedGroups, 20));
+
+        assert_eq!(
+            onboarding.completed_at(&OnboardingStep::JoinedGroups),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn next_step_follows_the_checklist_order() {
+        let mut onboarding = UserOnboarding::default();
+        onboarding.complete(OnboardingStep::IdentityConfirmed, 1);
+        onboarding.complete(OnboardingStep::FirstVoteCast, 2);
+
+        assert_eq!(onboarding.next_step(), Some(OnboardingStep::JoinedGroups));
+        assert!(!onboarding.is_completed());
+
+        onboarding.complete(OnboardingStep::JoinedGroups, 3);
+        onboarding.complete(OnboardingStep::NotificationPreferencesSet, 4);
+
+        assert_eq!(onboarding.next_step(), None);
+        assert!(onboarding.is_completed());
+    }
+}
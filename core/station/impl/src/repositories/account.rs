@@ -1,7 +1,9 @@
 use super::indexes::unique_index::UniqueIndexRepository;
 use crate::{
     core::{
-        metrics::ACCOUNT_METRICS, observer::Observer, utils::format_unique_string,
+        metrics::{observe_repository_write, ACCOUNT_METRICS},
+        observer::Observer,
+        utils::format_unique_string,
         with_memory_manager, Memory, ACCOUNT_MEMORY_ID,
     },
     models::{indexes::unique_index::UniqueIndexKey, Account, AccountId, AccountKey},
@@ -82,6 +84,8 @@ impl IndexedRepository<AccountKey, Account, VirtualMemory<Memory>> for AccountRe
 
 impl Repository<AccountKey, Account, VirtualMemory<Memory>> for AccountRepository {
     fn insert(&self, key: AccountKey, value: Account) -> Option<Account> {
+        observe_repository_write("accounts", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
@@ -0,0 +1,193 @@
+use crate::{
+    core::{with_memory_manager, Memory, ACCOUNT_SPEND_MEMORY_ID},
+    models::{AccountId, AccountSpend, AccountSpendKey, RegisteredAssetId, RequestId},
+};
+use candid::Nat;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::model::ModelKey;
+use orbit_essentials::repository::{Repository, StableDb};
+use orbit_essentials::types::Timestamp;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<AccountSpendKey, AccountSpend, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ACCOUNT_SPEND_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ACCOUNT_SPEND_REPOSITORY: Arc<AccountSpendRepository> =
+        Arc::new(AccountSpendRepository::default());
+}
+
+/// A repository that stores the amounts spent from the accounts in stable memory.
+#[derive(Default, Debug)]
+pub struct AccountSpendRepository {}
+
+impl StableDb<AccountSpendKey, AccountSpend, VirtualMemory<Memory>> for AccountSpendRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<AccountSpendKey, AccountSpend, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<AccountSpendKey, AccountSpend, VirtualMemory<Memory>> for AccountSpendRepository {}
+
+impl AccountSpendRepository {
+    /// Returns the spends of the asset of the account since the given time, the oldest first.
+    pub fn find_by_account_since(
+        &self,
+        account_id: &AccountId,
+        asset_id: Option<&RegisteredAssetId>,
+        since: Timestamp,
+    ) -> Vec<AccountSpend> {
+        DB.with(|db| {
+            db.borrow()
+                .range(
+                    AccountSpendKey {
+                        account_id: *account_id,
+                        asset_id: asset_id.copied(),
+                        spent_at: since,
+                        request_id: [0; 16],
+                    }..,
+                )
+                .take_while(|(key, _)| {
+                    key.account_id == *account_id && key.asset_id.as_ref() == asset_id
+                })
+                .map(|(_, spend)| spend)
+                .collect()
+        })
+    }
+
+    /// Returns the total spent of the asset of the account since the given time, leaving out the
+    /// request.
+    pub fn total_spent_since(
+        &self,
+        account_id: &AccountId,
+        asset_id: Option<&RegisteredAssetId>,
+        since: Timestamp,
+        excluded_request_id: &RequestId,
+    ) -> Nat {
+        self.find_by_account_since(account_id, asset_id, since)
+            .into_iter()
+            .filter(|spend| spend.request_id != *excluded_request_id)
+            .fold(Nat::from(0u64), |total, spend| total + spend.amount)
+    }
+
+    /// Removes the spends of the asset of the account that are older than the given time.
+    pub fn remove_older_than(
+        &self,
+        account_id: &AccountId,
+        asset_id: Option<&RegisteredAssetId>,
+        before: Timestamp,
+    ) {
+        let keys = DB.with(|db| {
+            db.borrow()
+                .range(
+                    AccountSpendKey {
+                        account_id: *account_id,
+                        asset_id: asset_id.copied(),
+                        spent_at: 0,
+                        request_id: [0; 16],
+                    }..,
+                )
+                .take_while(|(key, _)| {
+                    key.account_id == *account_id
+                        && key.asset_id.as_ref() == asset_id
+                        && key.spent_at < before
+                })
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>()
+        });
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+
+    /// Removes the spend of the request from the asset of the account, if any.
+    pub fn remove_by_request_id(
+        &self,
+        account_id: &AccountId,
+        asset_id: Option<&RegisteredAssetId>,
+        request_id: &RequestId,
+    ) {
+        let keys = self
+            .find_by_account_since(account_id, asset_id, 0)
+            .into_iter()
+            .filter(|spend| spend.request_id == *request_id)
+            .map(|spend| spend.key())
+            .collect::<Vec<_>>();
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_spend_test_utils::mock_account_spend;
+
+    #[test]
+    fn total_spent_since_only_counts_the_window_of_the_account() {
+        let repository = AccountSpendRepository::default();
+
+        let mut old = mock_account_spend();
+        old.spent_at = 5;
+        let mut recent = mock_account_spend();
+        recent.spent_at = 20;
+        let mut latest = mock_account_spend();
+        latest.spent_at = 30;
+        let mut other_account = mock_account_spend();
+        other_account.account_id = [1; 16];
+        other_account.spent_at = 25;
+        let mut other_asset = mock_account_spend();
+        other_asset.asset_id = Some([2; 16]);
+        other_asset.spent_at = 25;
+
+        for spend in [&old, &recent, &latest, &other_account, &other_asset] {
+            repository.insert(spend.key(), spend.to_owned());
+        }
+
+        assert_eq!(
+            repository.total_spent_since(&[0; 16], None, 10, &[9; 16]),
+            Nat::from(200u64)
+        );
+        assert_eq!(
+            repository.total_spent_since(&[0; 16], None, 10, &latest.request_id),
+            Nat::from(100u64)
+        );
+        assert_eq!(
+            repository.total_spent_since(&[0; 16], Some(&[2; 16]), 10, &[9; 16]),
+            Nat::from(100u64)
+        );
+
+        repository.remove_older_than(&[0; 16], None, 25);
+
+        assert_eq!(
+            repository.find_by_account_since(&[0; 16], None, 0),
+            vec![latest.clone()]
+        );
+        assert_eq!(
+            repository.find_by_account_since(&[1; 16], None, 0),
+            vec![other_account]
+        );
+        assert_eq!(
+            repository.find_by_account_since(&[0; 16], Some(&[2; 16]), 0),
+            vec![other_asset]
+        );
+
+        repository.remove_by_request_id(&[0; 16], None, &latest.request_id);
+
+        assert!(repository
+            .find_by_account_since(&[0; 16], None, 0)
+            .is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    core::{
+        metrics::{observe_repository_scan, observe_repository_write},
+        with_memory_manager, Memory, ACCOUNT_TRANSACTION_MEMORY_ID,
+    },
+    models::{AccountId, AccountTransaction, AccountTransactionKey},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<AccountTransactionKey, AccountTransaction, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ACCOUNT_TRANSACTION_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ACCOUNT_TRANSACTION_REPOSITORY: Arc<AccountTransactionRepository> =
+        Arc::new(AccountTransactionRepository::default());
+}
+
+/// A repository that stores the transactions imported from the ledger index canisters in stable memory.
+#[derive(Default, Debug)]
+pub struct AccountTransactionRepository {}
+
+impl StableDb<AccountTransactionKey, AccountTransaction, VirtualMemory<Memory>>
+    for AccountTransactionRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<AccountTransactionKey, AccountTransaction, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<AccountTransactionKey, AccountTransaction, VirtualMemory<Memory>>
+    for AccountTransactionRepository
+{
+    fn insert(
+        &self,
+        key: AccountTransactionKey,
+        value: AccountTransaction,
+    ) -> Option<AccountTransaction> {
+        observe_repository_write("account_transactions", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+impl AccountTransactionRepository {
+    /// Returns the imported transactions of the account, the most recent first.
+    pub fn find_by_account_id(&self, account_id: &AccountId) -> Vec<AccountTransaction> {
+        let transactions = self.list();
+        observe_repository_scan("account_transactions", transactions.len());
+
+        let mut transactions = transactions
+            .into_iter()
+            .filter(|transaction| transaction.account_id == *account_id)
+            .collect::<Vec<_>>();
+
+        transactions.sort_by(|a, b| b.block_index.cmp(&a.block_index));
+
+        transactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_transaction_test_utils::mock_account_transaction;
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn find_by_account_returns_the_most_recent_first() {
+        let repository = AccountTransactionRepository::default();
+        let older = mock_account_transaction();
+        let mut newer = mock_account_transaction();
+        newer.account_id = older.account_id;
+        newer.block_index = older.block_index + 10;
+        let other = mock_account_transaction();
+
+        for transaction in [&older, &newer, &other] {
+            repository.insert(transaction.key(), transaction.clone());
+        }
+
+        assert_eq!(
+            repository.find_by_account_id(&older.account_id),
+            vec![newer, older]
+        );
+    }
+}
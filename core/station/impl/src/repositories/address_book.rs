@@ -1,8 +1,9 @@
 use super::indexes::unique_index::UniqueIndexRepository;
 use crate::{
     core::{
-        metrics::ADDRESS_BOOK_METRICS, utils::max_string_of_size, with_memory_manager, Memory,
-        ADDRESS_BOOK_MEMORY_ID,
+        metrics::{observe_repository_write, ADDRESS_BOOK_METRICS},
+        utils::max_string_of_size,
+        with_memory_manager, Memory, ADDRESS_BOOK_MEMORY_ID,
     },
     models::{
         indexes::unique_index::UniqueIndexKey, AddressBookEntry, AddressBookEntryId,
@@ -83,6 +84,8 @@ impl Repository<AddressBookEntryKey, AddressBookEntry, VirtualMemory<Memory>>
         key: AddressBookEntryKey,
         value: AddressBookEntry,
     ) -> Option<AddressBookEntry> {
+        observe_repository_write("address_book", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
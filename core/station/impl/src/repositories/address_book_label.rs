@@ -0,0 +1,71 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory,
+        ADDRESS_BOOK_LABEL_MEMORY_ID,
+    },
+    models::AddressBookLabel,
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<String, AddressBookLabel, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ADDRESS_BOOK_LABEL_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ADDRESS_BOOK_LABEL_REPOSITORY: Arc<AddressBookLabelRepository> =
+        Arc::new(AddressBookLabelRepository::default());
+}
+
+/// A repository that stores the labels of the address book taxonomy by their lowercased name.
+#[derive(Default, Debug)]
+pub struct AddressBookLabelRepository {}
+
+impl StableDb<String, AddressBookLabel, VirtualMemory<Memory>> for AddressBookLabelRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<String, AddressBookLabel, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<String, AddressBookLabel, VirtualMemory<Memory>> for AddressBookLabelRepository {
+    fn insert(&self, key: String, value: AddressBookLabel) -> Option<AddressBookLabel> {
+        observe_repository_write("address_book_labels", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perform_crud() {
+        let repository = AddressBookLabelRepository::default();
+        let label = AddressBookLabel {
+            name: "Vendor".to_string(),
+            description: None,
+            last_modification_timestamp: 0,
+        };
+
+        assert!(repository.get(&label.to_key()).is_none());
+
+        repository.insert(label.to_key(), label.clone());
+
+        assert_eq!(
+            repository.get(&AddressBookLabel::key("vendor")),
+            Some(label.clone())
+        );
+        assert!(repository.remove(&label.to_key()).is_some());
+        assert!(repository.get(&label.to_key()).is_none());
+    }
+}
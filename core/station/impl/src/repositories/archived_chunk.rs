@@ -0,0 +1,106 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory, ARCHIVED_CHUNK_MEMORY_ID,
+    },
+    models::ArchivedChunk,
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<u64, ArchivedChunk, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(ARCHIVED_CHUNK_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref ARCHIVED_CHUNK_REPOSITORY: Arc<ArchivedChunkRepository> =
+        Arc::new(ArchivedChunkRepository::default());
+}
+
+/// A repository that stores the chunks of the settled history kept in the station by their sequence.
+#[derive(Default, Debug)]
+pub struct ArchivedChunkRepository {}
+
+impl StableDb<u64, ArchivedChunk, VirtualMemory<Memory>> for ArchivedChunkRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<u64, ArchivedChunk, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<u64, ArchivedChunk, VirtualMemory<Memory>> for ArchivedChunkRepository {
+    fn insert(&self, key: u64, value: ArchivedChunk) -> Option<ArchivedChunk> {
+        observe_repository_write("archived_chunks", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+impl ArchivedChunkRepository {
+    /// Returns the last chunk, which the next chunk is chained to.
+    pub fn last(&self) -> Option<ArchivedChunk> {
+        DB.with(|m| m.borrow().last_key_value().map(|(_, chunk)| chunk))
+    }
+
+    /// Returns up to `limit` chunks from the given sequence, with the sequence of the next chunk if any.
+    pub fn find_from(&self, from_sequence: u64, limit: usize) -> (Vec<ArchivedChunk>, Option<u64>) {
+        DB.with(|m| {
+            let mut chunks = m
+                .borrow()
+                .range(from_sequence..)
+                .take(limit.saturating_add(1))
+                .map(|(_, chunk)| chunk)
+                .collect::<Vec<_>>();
+
+            let next_sequence = if chunks.len() > limit {
+                chunks.pop().map(|chunk| chunk.sequence)
+            } else {
+                None
+            };
+
+            (chunks, next_sequence)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_chunk(sequence: u64) -> ArchivedChunk {
+        ArchivedChunk {
+            sequence,
+            hash: vec![sequence as u8; 32],
+            blob: vec![1, 2, 3],
+            archived_at: 0,
+        }
+    }
+
+    #[test]
+    fn finds_the_chunks_from_a_sequence() {
+        let repository = ArchivedChunkRepository::default();
+
+        assert!(repository.last().is_none());
+
+        for sequence in 0..5 {
+            repository.insert(sequence, mock_chunk(sequence));
+        }
+
+        assert_eq!(repository.last(), Some(mock_chunk(4)));
+
+        let (chunks, next_sequence) = repository.find_from(1, 2);
+        assert_eq!(chunks, vec![mock_chunk(1), mock_chunk(2)]);
+        assert_eq!(next_sequence, Some(3));
+
+        let (chunks, next_sequence) = repository.find_from(3, 2);
+        assert_eq!(chunks, vec![mock_chunk(3), mock_chunk(4)]);
+        assert_eq!(next_sequence, None);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    core::{with_memory_manager, Memory, CAPABILITY_GRANT_MEMORY_ID},
+    models::{AccountId, CapabilityGrant, CapabilityGrantId},
+};
+use candid::Principal;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<CapabilityGrantId, CapabilityGrant, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(CAPABILITY_GRANT_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref CAPABILITY_GRANT_REPOSITORY: Arc<CapabilityGrantRepository> =
+        Arc::new(CapabilityGrantRepository::default());
+}
+
+/// A repository that stores the capability grants in stable memory.
+#[derive(Default, Debug)]
+pub struct CapabilityGrantRepository {}
+
+impl StableDb<CapabilityGrantId, CapabilityGrant, VirtualMemory<Memory>>
+    for CapabilityGrantRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<CapabilityGrantId, CapabilityGrant, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<CapabilityGrantId, CapabilityGrant, VirtualMemory<Memory>>
+    for CapabilityGrantRepository
+{
+}
+
+impl CapabilityGrantRepository {
+    /// Returns the grants of the account, the most recent first.
+    pub fn find_by_account_id(&self, account_id: &AccountId) -> Vec<CapabilityGrant> {
+        let mut grants = self
+            .list()
+            .into_iter()
+            .filter(|grant| grant.account_id == *account_id)
+            .collect::<Vec<_>>();
+
+        grants.sort_by(|a, b| b.created_timestamp.cmp(&a.created_timestamp));
+
+        grants
+    }
+
+    /// Returns the grants given to the principal, including the expired and revoked ones.
+    pub fn find_by_grantee(&self, grantee: &Principal) -> Vec<CapabilityGrant> {
+        self.list()
+            .into_iter()
+            .filter(|grant| grant.grantee == *grantee)
+            .collect()
+    }
+}
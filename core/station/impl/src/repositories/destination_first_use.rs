@@ -0,0 +1,89 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory,
+        DESTINATION_FIRST_USE_MEMORY_ID,
+    },
+    models::{DestinationFirstUse, TransferId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    repository::{Repository, StableDb},
+    types::Timestamp,
+};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<String, DestinationFirstUse, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(DESTINATION_FIRST_USE_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref DESTINATION_FIRST_USE_REPOSITORY: Arc<DestinationFirstUseRepository> =
+        Arc::new(DestinationFirstUseRepository::default());
+}
+
+/// A repository that stores the first use of the destination addresses by their address.
+#[derive(Default, Debug)]
+pub struct DestinationFirstUseRepository {}
+
+impl StableDb<String, DestinationFirstUse, VirtualMemory<Memory>>
+    for DestinationFirstUseRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<String, DestinationFirstUse, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<String, DestinationFirstUse, VirtualMemory<Memory>>
+    for DestinationFirstUseRepository
+{
+    fn insert(&self, key: String, value: DestinationFirstUse) -> Option<DestinationFirstUse> {
+        observe_repository_write("destination_first_uses", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+impl DestinationFirstUseRepository {
+    /// Records the completed transfer as the first use of its destination, unless the destination
+    /// was already used.
+    pub fn record_use(&self, address: &str, transfer_id: TransferId, used_at: Timestamp) {
+        if self.get(&address.to_string()).is_some() {
+            return;
+        }
+
+        let first_use = DestinationFirstUse {
+            address: address.to_string(),
+            transfer_id,
+            first_used_at: used_at,
+        };
+
+        self.insert(first_use.to_key(), first_use);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_first_use_of_the_destination() {
+        let repository = DestinationFirstUseRepository::default();
+
+        repository.record_use("0x1234", [1; 16], 10);
+        repository.record_use("0x1234", [2; 16], 20);
+
+        let first_use = repository.get(&"0x1234".to_string()).unwrap();
+
+        assert_eq!(first_use.transfer_id, [1; 16]);
+        assert_eq!(first_use.first_used_at, 10);
+        assert!(repository.get(&"0x5678".to_string()).is_none());
+    }
+}
@@ -1,6 +1,9 @@
 use super::indexes::unique_index::UniqueIndexRepository;
 use crate::{
-    core::{utils::format_unique_string, with_memory_manager, Memory, EXTERNAL_CANISTER_MEMORY_ID},
+    core::{
+        metrics::observe_repository_write, utils::format_unique_string, with_memory_manager,
+        Memory, EXTERNAL_CANISTER_MEMORY_ID,
+    },
     models::{
         indexes::unique_index::UniqueIndexKey, ExternalCanister, ExternalCanisterEntryId,
         ExternalCanisterKey, ExternalCanisterState,
@@ -84,6 +87,8 @@ impl Repository<ExternalCanisterKey, ExternalCanister, VirtualMemory<Memory>>
         key: ExternalCanisterKey,
         value: ExternalCanister,
     ) -> Option<ExternalCanister> {
+        observe_repository_write("external_canisters", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
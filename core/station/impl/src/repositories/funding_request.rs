@@ -0,0 +1,92 @@
+use crate::{
+    core::{with_memory_manager, Memory, FUNDING_REQUEST_MEMORY_ID},
+    models::{AccountId, FundingRequest, FundingRequestKey},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<FundingRequestKey, FundingRequest, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(FUNDING_REQUEST_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref FUNDING_REQUEST_REPOSITORY: Arc<FundingRequestRepository> =
+        Arc::new(FundingRequestRepository::default());
+}
+
+/// A repository that stores the funding requests of the accounts in stable memory.
+#[derive(Default, Debug)]
+pub struct FundingRequestRepository {}
+
+impl StableDb<FundingRequestKey, FundingRequest, VirtualMemory<Memory>>
+    for FundingRequestRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<FundingRequestKey, FundingRequest, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<FundingRequestKey, FundingRequest, VirtualMemory<Memory>>
+    for FundingRequestRepository
+{
+}
+
+impl FundingRequestRepository {
+    /// Returns the funding requests of the account, the most recent first.
+    pub fn find_by_account_id(&self, account_id: &AccountId) -> Vec<FundingRequest> {
+        let mut funding_requests = self
+            .list()
+            .into_iter()
+            .filter(|funding_request| funding_request.account_id == *account_id)
+            .collect::<Vec<_>>();
+
+        funding_requests.sort_by(|a, b| b.created_timestamp.cmp(&a.created_timestamp));
+
+        funding_requests
+    }
+
+    /// Returns the funding requests whose allowance is still being watched.
+    pub fn find_pending(&self) -> Vec<FundingRequest> {
+        self.list()
+            .into_iter()
+            .filter(FundingRequest::is_pending)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{funding_request_test_utils::mock_funding_request, FundingRequestStatus};
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn find_by_account_and_pending() {
+        let repository = FundingRequestRepository::default();
+        let pending = mock_funding_request();
+        let mut expired = mock_funding_request();
+        expired.account_id = pending.account_id;
+        expired.status = FundingRequestStatus::Expired;
+        expired.created_timestamp = 1;
+
+        repository.insert(pending.key(), pending.clone());
+        repository.insert(expired.key(), expired.clone());
+
+        assert_eq!(
+            repository.find_by_account_id(&pending.account_id),
+            vec![expired, pending.clone()]
+        );
+        assert_eq!(repository.find_pending(), vec![pending]);
+    }
+}
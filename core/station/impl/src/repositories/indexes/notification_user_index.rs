@@ -1,5 +1,8 @@
 use crate::{
-    core::{with_memory_manager, Memory, NOTIFICATION_USER_INDEX_MEMORY_ID},
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        NOTIFICATION_USER_INDEX_MEMORY_ID,
+    },
     models::{
         indexes::notification_user_index::{NotificationUserIndex, NotificationUserIndexCriteria},
         NotificationId,
@@ -56,10 +59,15 @@ impl IndexRepository<NotificationUserIndex, NotificationId> for NotificationUser
                 notification_id: [u8::MAX; 16],
             };
 
-            db.borrow()
+            let found = db
+                .borrow()
                 .range(start_key..=end_key)
                 .map(|(index, _)| index.notification_id)
-                .collect::<HashSet<NotificationId>>()
+                .collect::<HashSet<NotificationId>>();
+
+            observe_repository_scan("notification_user_index", found.len());
+
+            found
         })
     }
 }
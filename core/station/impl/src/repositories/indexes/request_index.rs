@@ -1,5 +1,6 @@
 use crate::{
     core::{
+        metrics::observe_repository_scan,
         utils::{MAX_UUID, MIN_UUID},
         with_memory_manager, Memory, REQUEST_INDEX_MEMORY_ID,
     },
@@ -84,6 +85,32 @@ impl RequestIndexRepository {
         )
     }
 
+    /// Returns all the requests that are tagged with the given tag.
+    pub fn find_by_tag(
+        &self,
+        tag: &str,
+        take_limit: Option<usize>,
+    ) -> HashMap<RequestId, RequestIndexFields> {
+        self.find_by_criteria(
+            RequestIndexKeyKind::Tag(tag.to_owned()),
+            RequestIndexKeyKind::Tag(tag.to_owned()),
+            take_limit,
+        )
+    }
+
+    /// Returns all the requests that depend on the given request.
+    pub fn find_by_dependency(
+        &self,
+        dependency_id: &RequestId,
+        take_limit: Option<usize>,
+    ) -> HashMap<RequestId, RequestIndexFields> {
+        self.find_by_criteria(
+            RequestIndexKeyKind::DependsOn(*dependency_id),
+            RequestIndexKeyKind::DependsOn(*dependency_id),
+            take_limit,
+        )
+    }
+
     /// Returns all the entries that are between the given keys.
     fn find_by_criteria(
         &self,
@@ -92,7 +119,8 @@ impl RequestIndexRepository {
         take_limit: Option<usize>,
     ) -> HashMap<RequestId, RequestIndexFields> {
         DB.with(|m| {
-            m.borrow()
+            let found = m
+                .borrow()
                 .range(
                     RequestIndexKey {
                         kind: start_key,
@@ -112,7 +140,11 @@ impl RequestIndexRepository {
                         value,
                     )| (request_id, value),
                 )
-                .collect()
+                .collect::<HashMap<_, _>>();
+
+            observe_repository_scan("request_index", found.len());
+
+            found
         })
     }
 }
@@ -1,5 +1,8 @@
 use crate::{
-    core::{with_memory_manager, Memory, REQUEST_RESOURCE_INDEX_MEMORY_ID},
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        REQUEST_RESOURCE_INDEX_MEMORY_ID,
+    },
     models::{
         indexes::request_resource_index::{RequestResourceIndex, RequestResourceIndexCriteria},
         resource::Resource,
@@ -54,10 +57,15 @@ impl IndexRepository<RequestResourceIndex, Resource> for RequestResourceIndexRep
                 resource: Resource::max(),
             };
 
-            db.borrow()
+            let found = db
+                .borrow()
                 .range(start_key..=end_key)
                 .map(|(index, _)| index.resource)
-                .collect::<HashSet<Resource>>()
+                .collect::<HashSet<Resource>>();
+
+            observe_repository_scan("request_resource_index", found.len());
+
+            found
         })
     }
 }
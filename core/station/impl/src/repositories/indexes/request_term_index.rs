@@ -0,0 +1,114 @@
+use crate::{
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory, REQUEST_TERM_INDEX_MEMORY_ID,
+    },
+    models::{
+        indexes::request_term_index::{RequestTermIndex, RequestTermIndexCriteria},
+        RequestId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::repository::IndexRepository;
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<RequestTermIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REQUEST_TERM_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+/// A repository that enables finding requests by the words of their title and summary.
+#[derive(Default, Debug)]
+pub struct RequestTermIndexRepository {}
+
+impl RequestTermIndexRepository {
+    /// Clears the repository by removing all the entries.
+    pub fn clear(&self) {
+        DB.with(|m| m.borrow_mut().clear_new());
+    }
+}
+
+impl IndexRepository<RequestTermIndex, RequestId> for RequestTermIndexRepository {
+    type FindByCriteria = RequestTermIndexCriteria;
+
+    fn exists(&self, index: &RequestTermIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: RequestTermIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &RequestTermIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<RequestId> {
+        DB.with(|db| {
+            let start_key = RequestTermIndex {
+                term: criteria.term_prefix.to_owned(),
+                request_id: [u8::MIN; 16],
+            };
+
+            let found = db
+                .borrow()
+                .range(start_key..)
+                .take_while(|(index, _)| index.term.starts_with(&criteria.term_prefix))
+                .map(|(index, _)| index.request_id)
+                .collect::<HashSet<RequestId>>();
+
+            observe_repository_scan("request_term_index", found.len());
+
+            found
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_index(term: &str, request_id: RequestId) -> RequestTermIndex {
+        RequestTermIndex {
+            term: term.to_string(),
+            request_id,
+        }
+    }
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = RequestTermIndexRepository::default();
+        let index = mock_index("payout", [1; 16]);
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_by_term_prefix() {
+        let repository = RequestTermIndexRepository::default();
+        repository.insert(mock_index("march", [1; 16]));
+        repository.insert(mock_index("marketing", [2; 16]));
+        repository.insert(mock_index("mars", [3; 16]));
+        repository.insert(mock_index("payout", [4; 16]));
+
+        let result = repository.find_by_criteria(RequestTermIndexCriteria {
+            term_prefix: "mar".to_string(),
+        });
+
+        assert_eq!(result, HashSet::from([[1; 16], [2; 16], [3; 16]]));
+
+        let result = repository.find_by_criteria(RequestTermIndexCriteria {
+            term_prefix: "marc".to_string(),
+        });
+
+        assert_eq!(result, HashSet::from([[1; 16]]));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     core::{
-        ic_cdk::api::print, ic_cdk::next_time, with_memory_manager, Memory,
-        TRANSFER_ACCOUNT_INDEX_MEMORY_ID,
+        ic_cdk::api::print, ic_cdk::next_time, metrics::observe_repository_scan,
+        with_memory_manager, Memory, TRANSFER_ACCOUNT_INDEX_MEMORY_ID,
     },
     models::{
         indexes::transfer_account_index::{TransferAccountIndex, TransferAccountIndexCriteria},
@@ -9,7 +9,7 @@ use crate::{
     },
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
-use orbit_essentials::repository::IndexRepository;
+use orbit_essentials::{repository::IndexRepository, types::Timestamp};
 use std::{cell::RefCell, collections::HashSet};
 
 thread_local! {
@@ -28,24 +28,12 @@ impl TransferAccountIndexRepository {
     pub fn clear(&self) {
         DB.with(|m| m.borrow_mut().clear_new());
     }
-}
-
-impl IndexRepository<TransferAccountIndex, TransferId> for TransferAccountIndexRepository {
-    type FindByCriteria = TransferAccountIndexCriteria;
-
-    fn exists(&self, index: &TransferAccountIndex) -> bool {
-        DB.with(|m| m.borrow().get(index).is_some())
-    }
 
-    fn insert(&self, index: TransferAccountIndex) {
-        DB.with(|m| m.borrow_mut().insert(index, ()));
-    }
-
-    fn remove(&self, index: &TransferAccountIndex) -> bool {
-        DB.with(|m| m.borrow_mut().remove(index).is_some())
-    }
-
-    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<TransferId> {
+    /// Returns the matching transfers with their creation time, from the oldest.
+    pub fn find_ordered_by_criteria(
+        &self,
+        criteria: TransferAccountIndexCriteria,
+    ) -> Vec<(Timestamp, TransferId)> {
         DB.with(|db| {
             let now = next_time();
 
@@ -64,7 +52,7 @@ impl IndexRepository<TransferAccountIndex, TransferId> for TransferAccountIndexR
 
             if from_dt > to_dt {
                 print(format!("Invalid TransferAccountIndexRepository::FindByCriteria: from_dt {} is greater than to_dt {}", from_dt, to_dt));
-                return HashSet::new();
+                return Vec::new();
             }
 
             let start_key = TransferAccountIndex {
@@ -78,14 +66,42 @@ impl IndexRepository<TransferAccountIndex, TransferId> for TransferAccountIndexR
                 transfer_id: [u8::MAX; 16],
             };
 
-            db.borrow()
+            let found = db
+                .borrow()
                 .range(start_key..=end_key)
-                .map(|(index, _)| index.transfer_id)
-                .collect::<HashSet<TransferId>>()
+                .map(|(index, _)| (index.created_timestamp, index.transfer_id))
+                .collect::<Vec<_>>();
+
+            observe_repository_scan("transfer_account_index", found.len());
+
+            found
         })
     }
 }
 
+impl IndexRepository<TransferAccountIndex, TransferId> for TransferAccountIndexRepository {
+    type FindByCriteria = TransferAccountIndexCriteria;
+
+    fn exists(&self, index: &TransferAccountIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: TransferAccountIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &TransferAccountIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<TransferId> {
+        self.find_ordered_by_criteria(criteria)
+            .into_iter()
+            .map(|(_, transfer_id)| transfer_id)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,138 @@
+use crate::{
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        TRANSFER_ACCOUNT_STATUS_INDEX_MEMORY_ID,
+    },
+    models::{
+        indexes::transfer_account_status_index::{
+            TransferAccountStatusIndex, TransferAccountStatusIndexCriteria,
+        },
+        TransferId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::{repository::IndexRepository, types::Timestamp};
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<TransferAccountStatusIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_ACCOUNT_STATUS_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+#[derive(Default, Debug)]
+pub struct TransferAccountStatusIndexRepository {}
+
+impl TransferAccountStatusIndexRepository {
+    /// Clears the repository by removing all the entries.
+    pub fn clear(&self) {
+        DB.with(|m| m.borrow_mut().clear_new());
+    }
+
+    /// Returns the matching transfers with their creation time, from the oldest.
+    pub fn find_ordered_by_criteria(
+        &self,
+        criteria: TransferAccountStatusIndexCriteria,
+    ) -> Vec<(Timestamp, TransferId)> {
+        DB.with(|db| {
+            let start_key = TransferAccountStatusIndex {
+                account_id: criteria.account_id,
+                status: criteria.status.to_owned(),
+                created_timestamp: criteria.from_dt.unwrap_or(u64::MIN),
+                transfer_id: [u8::MIN; 16],
+            };
+            let end_key = TransferAccountStatusIndex {
+                account_id: criteria.account_id,
+                status: criteria.status,
+                created_timestamp: criteria.to_dt.unwrap_or(u64::MAX),
+                transfer_id: [u8::MAX; 16],
+            };
+
+            let found = db
+                .borrow()
+                .range(start_key..=end_key)
+                .map(|(index, _)| (index.created_timestamp, index.transfer_id))
+                .collect::<Vec<_>>();
+
+            observe_repository_scan("transfer_account_status_index", found.len());
+
+            found
+        })
+    }
+}
+
+impl IndexRepository<TransferAccountStatusIndex, TransferId>
+    for TransferAccountStatusIndexRepository
+{
+    type FindByCriteria = TransferAccountStatusIndexCriteria;
+
+    fn exists(&self, index: &TransferAccountStatusIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: TransferAccountStatusIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &TransferAccountStatusIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<TransferId> {
+        self.find_ordered_by_criteria(criteria)
+            .into_iter()
+            .map(|(_, transfer_id)| transfer_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_index(
+        status: &str,
+        created_timestamp: u64,
+        transfer_id: TransferId,
+    ) -> TransferAccountStatusIndex {
+        TransferAccountStatusIndex {
+            account_id: [1; 16],
+            status: status.to_string(),
+            created_timestamp,
+            transfer_id,
+        }
+    }
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = TransferAccountStatusIndexRepository::default();
+        let index = mock_index("created", 10, [1; 16]);
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_ordered_by_criteria() {
+        let repository = TransferAccountStatusIndexRepository::default();
+        repository.insert(mock_index("completed", 20, [2; 16]));
+        repository.insert(mock_index("completed", 10, [1; 16]));
+        repository.insert(mock_index("failed", 15, [3; 16]));
+
+        let result = repository.find_ordered_by_criteria(TransferAccountStatusIndexCriteria {
+            account_id: [1; 16],
+            status: "completed".to_string(),
+            from_dt: None,
+            to_dt: None,
+        });
+
+        assert_eq!(result, vec![(10, [1; 16]), (20, [2; 16])]);
+    }
+}
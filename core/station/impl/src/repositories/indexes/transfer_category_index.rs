@@ -0,0 +1,133 @@
+use crate::{
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        TRANSFER_CATEGORY_INDEX_MEMORY_ID,
+    },
+    models::indexes::transfer_category_index::{
+        TransferCategoryIndex, TransferCategoryIndexCriteria,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::{
+    repository::IndexRepository,
+    types::{Timestamp, UUID},
+};
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<TransferCategoryIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_CATEGORY_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+#[derive(Default, Debug)]
+pub struct TransferCategoryIndexRepository {}
+
+impl TransferCategoryIndexRepository {
+    /// Clears the repository by removing all the entries.
+    pub fn clear(&self) {
+        DB.with(|m| m.borrow_mut().clear_new());
+    }
+
+    /// Returns the matching transfers with their creation time, from the oldest.
+    pub fn find_ordered_by_criteria(
+        &self,
+        criteria: TransferCategoryIndexCriteria,
+    ) -> Vec<(Timestamp, UUID)> {
+        DB.with(|db| {
+            let start_key = TransferCategoryIndex {
+                category: criteria.category.to_owned(),
+                created_timestamp: criteria.from_dt.unwrap_or(u64::MIN),
+                transfer_id: [u8::MIN; 16],
+            };
+            let end_key = TransferCategoryIndex {
+                category: criteria.category,
+                created_timestamp: criteria.to_dt.unwrap_or(u64::MAX),
+                transfer_id: [u8::MAX; 16],
+            };
+
+            let found = db
+                .borrow()
+                .range(start_key..=end_key)
+                .map(|(index, _)| (index.created_timestamp, index.transfer_id))
+                .collect::<Vec<_>>();
+
+            observe_repository_scan("transfer_category_index", found.len());
+
+            found
+        })
+    }
+}
+
+impl IndexRepository<TransferCategoryIndex, UUID> for TransferCategoryIndexRepository {
+    type FindByCriteria = TransferCategoryIndexCriteria;
+
+    fn exists(&self, index: &TransferCategoryIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: TransferCategoryIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &TransferCategoryIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<UUID> {
+        self.find_ordered_by_criteria(criteria)
+            .into_iter()
+            .map(|(_, transfer_id)| transfer_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_index(
+        category: &str,
+        created_timestamp: u64,
+        transfer_id: UUID,
+    ) -> TransferCategoryIndex {
+        TransferCategoryIndex {
+            category: category.to_string(),
+            created_timestamp,
+            transfer_id,
+        }
+    }
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = TransferCategoryIndexRepository::default();
+        let index = mock_index("payroll", 10, [1; 16]);
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_by_criteria() {
+        let repository = TransferCategoryIndexRepository::default();
+        repository.insert(mock_index("payroll", 10, [1; 16]));
+        repository.insert(mock_index("payroll", 20, [2; 16]));
+        repository.insert(mock_index("payroll", 30, [3; 16]));
+        repository.insert(mock_index("payroll-bonus", 20, [4; 16]));
+
+        let result = repository.find_by_criteria(TransferCategoryIndexCriteria {
+            category: "payroll".to_string(),
+            from_dt: Some(15),
+            to_dt: Some(30),
+        });
+
+        assert_eq!(result, HashSet::from([[2; 16], [3; 16]]));
+    }
+}
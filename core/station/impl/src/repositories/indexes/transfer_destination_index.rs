@@ -0,0 +1,136 @@
+use crate::{
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        TRANSFER_DESTINATION_INDEX_MEMORY_ID,
+    },
+    models::{
+        indexes::transfer_destination_index::{
+            TransferDestinationIndex, TransferDestinationIndexCriteria,
+        },
+        TransferId,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::{repository::IndexRepository, types::Timestamp};
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<TransferDestinationIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_DESTINATION_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+#[derive(Default, Debug)]
+pub struct TransferDestinationIndexRepository {}
+
+impl TransferDestinationIndexRepository {
+    /// Clears the repository by removing all the entries.
+    pub fn clear(&self) {
+        DB.with(|m| m.borrow_mut().clear_new());
+    }
+
+    /// Returns the matching transfers with their creation time, from the oldest.
+    pub fn find_ordered_by_criteria(
+        &self,
+        criteria: TransferDestinationIndexCriteria,
+    ) -> Vec<(Timestamp, TransferId)> {
+        DB.with(|db| {
+            let start_key = TransferDestinationIndex {
+                account_id: criteria.account_id,
+                to_address: criteria.to_address.to_owned(),
+                created_timestamp: criteria.from_dt.unwrap_or(u64::MIN),
+                transfer_id: [u8::MIN; 16],
+            };
+            let end_key = TransferDestinationIndex {
+                account_id: criteria.account_id,
+                to_address: criteria.to_address,
+                created_timestamp: criteria.to_dt.unwrap_or(u64::MAX),
+                transfer_id: [u8::MAX; 16],
+            };
+
+            let found = db
+                .borrow()
+                .range(start_key..=end_key)
+                .map(|(index, _)| (index.created_timestamp, index.transfer_id))
+                .collect::<Vec<_>>();
+
+            observe_repository_scan("transfer_destination_index", found.len());
+
+            found
+        })
+    }
+}
+
+impl IndexRepository<TransferDestinationIndex, TransferId> for TransferDestinationIndexRepository {
+    type FindByCriteria = TransferDestinationIndexCriteria;
+
+    fn exists(&self, index: &TransferDestinationIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: TransferDestinationIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &TransferDestinationIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<TransferId> {
+        self.find_ordered_by_criteria(criteria)
+            .into_iter()
+            .map(|(_, transfer_id)| transfer_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_index(
+        to_address: &str,
+        created_timestamp: u64,
+        transfer_id: TransferId,
+    ) -> TransferDestinationIndex {
+        TransferDestinationIndex {
+            account_id: [1; 16],
+            to_address: to_address.to_string(),
+            created_timestamp,
+            transfer_id,
+        }
+    }
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = TransferDestinationIndexRepository::default();
+        let index = mock_index("0x1234", 10, [1; 16]);
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_ordered_by_criteria() {
+        let repository = TransferDestinationIndexRepository::default();
+        repository.insert(mock_index("0x1234", 20, [2; 16]));
+        repository.insert(mock_index("0x1234", 10, [1; 16]));
+        repository.insert(mock_index("0x5678", 15, [3; 16]));
+
+        let result = repository.find_ordered_by_criteria(TransferDestinationIndexCriteria {
+            account_id: [1; 16],
+            to_address: "0x1234".to_string(),
+            from_dt: None,
+            to_dt: None,
+        });
+
+        assert_eq!(result, vec![(10, [1; 16]), (20, [2; 16])]);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        TRANSFER_METADATA_INDEX_MEMORY_ID,
+    },
+    models::indexes::transfer_metadata_index::{
+        TransferMetadataIndex, TransferMetadataIndexCriteria,
+    },
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use orbit_essentials::{
+    repository::IndexRepository,
+    types::{Timestamp, UUID},
+};
+use std::{cell::RefCell, collections::HashSet};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<TransferMetadataIndex, (), VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_METADATA_INDEX_MEMORY_ID))
+    )
+  })
+}
+
+#[derive(Default, Debug)]
+pub struct TransferMetadataIndexRepository {}
+
+impl TransferMetadataIndexRepository {
+    /// Clears the repository by removing all the entries.
+    pub fn clear(&self) {
+        DB.with(|m| m.borrow_mut().clear_new());
+    }
+
+    /// Returns the matching transfers with their creation time, from the oldest.
+    pub fn find_ordered_by_criteria(
+        &self,
+        criteria: TransferMetadataIndexCriteria,
+    ) -> Vec<(Timestamp, UUID)> {
+        DB.with(|db| {
+            let start_key = TransferMetadataIndex {
+                account_id: criteria.account_id,
+                key: criteria.key.to_owned(),
+                value: criteria.value.to_owned(),
+                created_timestamp: criteria.from_dt.unwrap_or(u64::MIN),
+                transfer_id: [u8::MIN; 16],
+            };
+            let end_key = TransferMetadataIndex {
+                account_id: criteria.account_id,
+                key: criteria.key,
+                value: criteria.value,
+                created_timestamp: criteria.to_dt.unwrap_or(u64::MAX),
+                transfer_id: [u8::MAX; 16],
+            };
+
+            let found = db
+                .borrow()
+                .range(start_key..=end_key)
+                .map(|(index, _)| (index.created_timestamp, index.transfer_id))
+                .collect::<Vec<_>>();
+
+            observe_repository_scan("transfer_metadata_index", found.len());
+
+            found
+        })
+    }
+}
+
+impl IndexRepository<TransferMetadataIndex, UUID> for TransferMetadataIndexRepository {
+    type FindByCriteria = TransferMetadataIndexCriteria;
+
+    fn exists(&self, index: &TransferMetadataIndex) -> bool {
+        DB.with(|m| m.borrow().get(index).is_some())
+    }
+
+    fn insert(&self, index: TransferMetadataIndex) {
+        DB.with(|m| m.borrow_mut().insert(index, ()));
+    }
+
+    fn remove(&self, index: &TransferMetadataIndex) -> bool {
+        DB.with(|m| m.borrow_mut().remove(index).is_some())
+    }
+
+    fn find_by_criteria(&self, criteria: Self::FindByCriteria) -> HashSet<UUID> {
+        self.find_ordered_by_criteria(criteria)
+            .into_iter()
+            .map(|(_, transfer_id)| transfer_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_index(value: &str, created_timestamp: u64, transfer_id: UUID) -> TransferMetadataIndex {
+        TransferMetadataIndex {
+            account_id: [1; 16],
+            key: "category".to_string(),
+            value: value.to_string(),
+            created_timestamp,
+            transfer_id,
+        }
+    }
+
+    #[test]
+    fn test_repository_crud() {
+        let repository = TransferMetadataIndexRepository::default();
+        let index = mock_index("payroll", 10, [1; 16]);
+
+        assert!(!repository.exists(&index));
+
+        repository.insert(index.clone());
+
+        assert!(repository.exists(&index));
+        assert!(repository.remove(&index));
+        assert!(!repository.exists(&index));
+    }
+
+    #[test]
+    fn test_find_by_criteria() {
+        let repository = TransferMetadataIndexRepository::default();
+        repository.insert(mock_index("payroll", 10, [1; 16]));
+        repository.insert(mock_index("payroll", 20, [2; 16]));
+        repository.insert(mock_index("payroll-bonus", 10, [3; 16]));
+
+        let result = repository.find_by_criteria(TransferMetadataIndexCriteria {
+            account_id: [1; 16],
+            key: "category".to_string(),
+            value: "payroll".to_string(),
+            from_dt: None,
+            to_dt: Some(15),
+        });
+
+        assert_eq!(result, HashSet::from([[1; 16]]));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    core::{with_memory_manager, Memory, TRANSFER_STATUS_INDEX_MEMORY_ID},
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        TRANSFER_STATUS_INDEX_MEMORY_ID,
+    },
     models::indexes::transfer_status_index::{TransferStatusIndex, TransferStatusIndexCriteria},
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
@@ -52,10 +55,15 @@ impl IndexRepository<TransferStatusIndex, UUID> for TransferStatusIndexRepositor
                 transfer_id: [std::u8::MAX; 16],
             };
 
-            db.borrow()
+            let found = db
+                .borrow()
                 .range(start_key..=end_key)
                 .map(|(index, _)| index.transfer_id)
-                .collect::<HashSet<UUID>>()
+                .collect::<HashSet<UUID>>();
+
+            observe_repository_scan("transfer_status_index", found.len());
+
+            found
         })
     }
 }
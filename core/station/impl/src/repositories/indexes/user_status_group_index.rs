@@ -1,5 +1,8 @@
 use crate::{
-    core::{with_memory_manager, Memory, USER_STATUS_GROUP_INDEX_MEMORY_ID},
+    core::{
+        metrics::observe_repository_scan, with_memory_manager, Memory,
+        USER_STATUS_GROUP_INDEX_MEMORY_ID,
+    },
     models::{
         indexes::user_status_group_index::{UserStatusGroupIndex, UserStatusGroupIndexCriteria},
         UserId,
@@ -56,10 +59,15 @@ impl IndexRepository<UserStatusGroupIndex, UserId> for UserStatusGroupIndexRepos
                 user_id: [u8::MAX; 16],
             };
 
-            db.borrow()
+            let found = db
+                .borrow()
                 .range(start_key..=end_key)
                 .map(|(index, _)| index.user_id)
-                .collect::<HashSet<UserId>>()
+                .collect::<HashSet<UserId>>();
+
+            observe_repository_scan("user_status_group_index", found.len());
+
+            found
         })
     }
 }
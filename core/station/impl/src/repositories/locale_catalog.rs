@@ -0,0 +1,71 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory, LOCALE_CATALOG_MEMORY_ID,
+    },
+    models::LocaleCatalog,
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<String, LocaleCatalog, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(LOCALE_CATALOG_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref LOCALE_CATALOG_REPOSITORY: Arc<LocaleCatalogRepository> =
+        Arc::new(LocaleCatalogRepository::default());
+}
+
+/// A repository that stores the uploaded locale catalogs by their normalized locale tag.
+#[derive(Default, Debug)]
+pub struct LocaleCatalogRepository {}
+
+impl StableDb<String, LocaleCatalog, VirtualMemory<Memory>> for LocaleCatalogRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<String, LocaleCatalog, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<String, LocaleCatalog, VirtualMemory<Memory>> for LocaleCatalogRepository {
+    fn insert(&self, key: String, value: LocaleCatalog) -> Option<LocaleCatalog> {
+        observe_repository_write("locale_catalogs", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn perform_crud() {
+        let repository = LocaleCatalogRepository::default();
+        let catalog = LocaleCatalog {
+            locale: "fr".to_string(),
+            messages: BTreeMap::from([(
+                "blockchain_paused.title".to_string(),
+                "Les transferts sur {blockchain} sont suspendus".to_string(),
+            )]),
+            last_modification_timestamp: 0,
+        };
+
+        assert!(repository.get(&catalog.locale).is_none());
+
+        repository.insert(catalog.locale.clone(), catalog.clone());
+
+        assert_eq!(repository.get(&catalog.locale), Some(catalog.clone()));
+        assert!(repository.remove(&catalog.locale).is_some());
+        assert!(repository.get(&catalog.locale).is_none());
+    }
+}
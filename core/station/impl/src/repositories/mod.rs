@@ -3,6 +3,9 @@
 pub mod address_book;
 pub use address_book::*;
 
+pub mod address_book_label;
+pub use address_book_label::*;
+
 pub mod user;
 pub use user::*;
 
@@ -15,12 +18,66 @@ pub use account::*;
 pub mod external_canister;
 pub use external_canister::*;
 
+pub mod funding_request;
+pub use funding_request::*;
+
+pub mod payout_run;
+pub use payout_run::*;
+
+pub mod destination_first_use;
+pub use destination_first_use::*;
+
+pub mod support_access_log;
+pub use support_access_log::*;
+
+pub mod account_transaction;
+pub use account_transaction::*;
+
+pub mod spending_aggregate;
+pub use spending_aggregate::*;
+
+pub mod registered_asset;
+pub use registered_asset::*;
+
+pub mod scheduled_policy_change;
+pub use scheduled_policy_change::*;
+
+pub mod request_view;
+pub use request_view::*;
+
+pub mod request_comment;
+pub use request_comment::*;
+
+pub mod scheduled_transfer;
+pub use scheduled_transfer::*;
+
+pub mod capability_grant;
+pub use capability_grant::*;
+
+pub mod account_spend;
+pub use account_spend::*;
+
 pub mod transfer;
 pub use transfer::*;
 
+pub mod transfer_receipt;
+pub use transfer_receipt::*;
+
+pub mod transfer_category;
+pub use transfer_category::*;
+
+pub mod locale_catalog;
+pub use locale_catalog::*;
+
 pub mod notification;
 pub use notification::*;
 
+pub mod notification_template;
+pub use notification_template::*;
+
+pub mod archived_chunk;
+pub use archived_chunk::*;
+
 pub mod request;
 pub use request::*;
 
@@ -1,6 +1,9 @@
 use super::indexes::notification_user_index::NotificationUserIndexRepository;
 use crate::{
-    core::{utils::SortDirection, with_memory_manager, Memory, NOTIFICATION_MEMORY_ID},
+    core::{
+        metrics::observe_repository_write, utils::SortDirection, with_memory_manager, Memory,
+        NOTIFICATION_MEMORY_ID,
+    },
     models::{
         indexes::notification_user_index::NotificationUserIndexCriteria, Notification,
         NotificationKey, NotificationStatus, UserId,
@@ -61,6 +64,8 @@ impl IndexedRepository<NotificationKey, Notification, VirtualMemory<Memory>>
 
 impl Repository<NotificationKey, Notification, VirtualMemory<Memory>> for NotificationRepository {
     fn insert(&self, key: NotificationKey, value: Notification) -> Option<Notification> {
+        observe_repository_write("notifications", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
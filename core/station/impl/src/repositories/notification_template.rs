@@ -0,0 +1,80 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory,
+        NOTIFICATION_TEMPLATE_MEMORY_ID,
+    },
+    models::{NotificationTemplate, NotificationTemplateKey},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<NotificationTemplateKey, NotificationTemplate, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(NOTIFICATION_TEMPLATE_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref NOTIFICATION_TEMPLATE_REPOSITORY: Arc<NotificationTemplateRepository> =
+        Arc::new(NotificationTemplateRepository::default());
+}
+
+/// A repository that stores the templates of the notifications by the notifications they apply to.
+#[derive(Default, Debug)]
+pub struct NotificationTemplateRepository {}
+
+impl StableDb<NotificationTemplateKey, NotificationTemplate, VirtualMemory<Memory>>
+    for NotificationTemplateRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<
+                NotificationTemplateKey,
+                NotificationTemplate,
+                VirtualMemory<Memory>,
+            >,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<NotificationTemplateKey, NotificationTemplate, VirtualMemory<Memory>>
+    for NotificationTemplateRepository
+{
+    fn insert(
+        &self,
+        key: NotificationTemplateKey,
+        value: NotificationTemplate,
+    ) -> Option<NotificationTemplate> {
+        observe_repository_write("notification_templates", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::notification_template_test_utils::mock_notification_template;
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn perform_crud() {
+        let repository = NotificationTemplateRepository::default();
+        let template = mock_notification_template();
+
+        assert!(repository.get(&template.key()).is_none());
+
+        repository.insert(template.key(), template.clone());
+
+        assert_eq!(repository.get(&template.key()), Some(template.clone()));
+        assert!(repository.remove(&template.key()).is_some());
+        assert!(repository.get(&template.key()).is_none());
+    }
+}
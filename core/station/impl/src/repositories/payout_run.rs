@@ -0,0 +1,42 @@
+use crate::{
+    core::{metrics::observe_repository_write, with_memory_manager, Memory, PAYOUT_RUN_MEMORY_ID},
+    models::{PayoutRun, PayoutRunKey},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<PayoutRunKey, PayoutRun, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(PAYOUT_RUN_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref PAYOUT_RUN_REPOSITORY: Arc<PayoutRunRepository> =
+        Arc::new(PayoutRunRepository::default());
+}
+
+/// A repository that stores the payout runs in stable memory.
+#[derive(Default, Debug)]
+pub struct PayoutRunRepository {}
+
+impl StableDb<PayoutRunKey, PayoutRun, VirtualMemory<Memory>> for PayoutRunRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<PayoutRunKey, PayoutRun, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<PayoutRunKey, PayoutRun, VirtualMemory<Memory>> for PayoutRunRepository {
+    fn insert(&self, key: PayoutRunKey, value: PayoutRun) -> Option<PayoutRun> {
+        observe_repository_write("payout_runs", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    core::{cache::Cache, ic_cdk::api::print, with_memory_manager, Memory, PERMISSION_MEMORY_ID},
+    core::{
+        cache::Cache, ic_cdk::api::print, metrics::observe_repository_write, with_memory_manager,
+        Memory, PERMISSION_MEMORY_ID,
+    },
     models::{
         permission::{Allow, Permission, PermissionKey},
         resource::{
@@ -97,6 +100,8 @@ impl Repository<PermissionKey, Permission, VirtualMemory<Memory>> for Permission
     }
 
     fn insert(&self, key: PermissionKey, value: Permission) -> Option<Permission> {
+        observe_repository_write("permissions", &value);
+
         // Update the cache with the new value.
         CACHE.with(|cache| {
             cache
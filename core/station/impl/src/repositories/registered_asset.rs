@@ -0,0 +1,60 @@
+use crate::{
+    core::{with_memory_manager, Memory, REGISTERED_ASSET_MEMORY_ID},
+    models::{RegisteredAsset, RegisteredAssetId},
+};
+use candid::Principal;
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<RegisteredAssetId, RegisteredAsset, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REGISTERED_ASSET_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref REGISTERED_ASSET_REPOSITORY: Arc<RegisteredAssetRepository> =
+        Arc::new(RegisteredAssetRepository::default());
+}
+
+/// A repository that stores the registered assets in stable memory.
+#[derive(Default, Debug)]
+pub struct RegisteredAssetRepository {}
+
+impl StableDb<RegisteredAssetId, RegisteredAsset, VirtualMemory<Memory>>
+    for RegisteredAssetRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<RegisteredAssetId, RegisteredAsset, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<RegisteredAssetId, RegisteredAsset, VirtualMemory<Memory>>
+    for RegisteredAssetRepository
+{
+}
+
+impl RegisteredAssetRepository {
+    /// Returns the asset registered for the ledger, the registry is small enough to be scanned.
+    pub fn find_by_ledger(&self, ledger_canister_id: &Principal) -> Option<RegisteredAsset> {
+        self.list()
+            .into_iter()
+            .find(|asset| asset.ledger_canister_id == *ledger_canister_id)
+    }
+
+    /// Returns the asset registered with the symbol, the symbol is case insensitive.
+    pub fn find_by_symbol(&self, symbol: &str) -> Option<RegisteredAsset> {
+        self.list()
+            .into_iter()
+            .find(|asset| asset.symbol.eq_ignore_ascii_case(symbol))
+    }
+}
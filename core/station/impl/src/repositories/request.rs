@@ -1,23 +1,33 @@
 use super::indexes::{
     request_index::RequestIndexRepository, request_resource_index::RequestResourceIndexRepository,
+    request_term_index::RequestTermIndexRepository,
 };
 use crate::{
     core::{
+        authorization::Authorization,
         cache::Cache,
-        metrics::{metrics_observe_insert_request, metrics_observe_remove_request},
+        metrics::{
+            metrics_observe_insert_request, metrics_observe_remove_request,
+            observe_repository_write,
+        },
         observer::Observer,
-        with_memory_manager, Memory, REQUEST_MEMORY_ID,
+        with_memory_manager, CallContext, Memory, REQUEST_MEMORY_ID,
     },
     errors::RepositoryError,
     jobs::{jobs_observe_insert_request, jobs_observe_remove_request},
     models::{
         indexes::{
-            request_index::RequestIndexFields, request_resource_index::RequestResourceIndexCriteria,
+            request_index::RequestIndexFields,
+            request_resource_index::RequestResourceIndexCriteria,
+            request_term_index::{RequestTermIndex, RequestTermIndexCriteria},
         },
         resource::Resource,
         ListRequestsOperationType, Request, RequestId, RequestKey, RequestStatus,
         RequestStatusCode,
     },
+    services::{
+        record_certification_observes_insert_request, record_certification_observes_remove_request,
+    },
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
 use lazy_static::lazy_static;
@@ -26,7 +36,13 @@ use orbit_essentials::{
     types::{Timestamp, UUID},
 };
 use station_api::ListRequestsSortBy;
-use std::{cell::RefCell, collections::HashSet, sync::Arc, u64};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    ops::Bound,
+    sync::Arc,
+    u64,
+};
 
 thread_local! {
     static DB: RefCell<StableBTreeMap<RequestKey, Request, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
@@ -49,6 +65,7 @@ lazy_static! {
 pub struct RequestRepository {
     index: RequestIndexRepository,
     resource_index: RequestResourceIndexRepository,
+    term_index: RequestTermIndexRepository,
     change_observer: Observer<(Request, Option<Request>)>,
     remove_observer: Observer<Request>,
 }
@@ -58,16 +75,19 @@ impl Default for RequestRepository {
         let mut change_observer = Observer::default();
         metrics_observe_insert_request(&mut change_observer);
         jobs_observe_insert_request(&mut change_observer);
+        record_certification_observes_insert_request(&mut change_observer);
 
         let mut remove_observer = Observer::default();
         metrics_observe_remove_request(&mut remove_observer);
         jobs_observe_remove_request(&mut remove_observer);
+        record_certification_observes_remove_request(&mut remove_observer);
 
         Self {
             change_observer,
             remove_observer,
             index: RequestIndexRepository::default(),
             resource_index: Default::default(),
+            term_index: Default::default(),
         }
     }
 }
@@ -92,6 +112,10 @@ impl IndexedRepository<RequestKey, Request, VirtualMemory<Memory>> for RequestRe
             self.resource_index.remove(index);
         });
 
+        entry.to_index_by_terms().iter().for_each(|index| {
+            self.term_index.remove(index);
+        });
+
         entry.to_indexes().iter().for_each(|(index_key, _)| {
             self.index.remove(index_key);
         });
@@ -112,6 +136,10 @@ impl IndexedRepository<RequestKey, Request, VirtualMemory<Memory>> for RequestRe
             self.resource_index.insert(index);
         });
 
+        entry.to_index_by_terms().into_iter().for_each(|index| {
+            self.term_index.insert(index);
+        });
+
         entry
             .to_indexes()
             .into_iter()
@@ -126,11 +154,14 @@ impl IndexedRepository<RequestKey, Request, VirtualMemory<Memory>> for RequestRe
 
         self.index.clear();
         self.resource_index.clear();
+        self.term_index.clear();
     }
 }
 
 impl Repository<RequestKey, Request, VirtualMemory<Memory>> for RequestRepository {
     fn insert(&self, key: RequestKey, value: Request) -> Option<Request> {
+        observe_repository_write("requests", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
@@ -163,6 +194,22 @@ impl RequestRepository {
     /// so the max cache storage size is around 300 MiB.
     const MAX_INDEXED_FIELDS_CACHE_SIZE: usize = 500_000;
 
+    /// Returns up to `limit` requests in the order of their ids, starting after the given id.
+    pub fn list_after(&self, after: Option<RequestId>, limit: usize) -> Vec<Request> {
+        let start = match after {
+            Some(id) => Bound::Excluded(RequestKey { id }),
+            None => Bound::Unbounded,
+        };
+
+        DB.with(|db| {
+            db.borrow()
+                .range((start, Bound::Unbounded))
+                .take(limit)
+                .map(|(_, request)| request)
+                .collect()
+        })
+    }
+
     /// Find requests that have the provided status and would be expired between the provided timestamps.
     pub fn find_by_status_and_expiration_dt(
         &self,
@@ -214,6 +261,15 @@ impl RequestRepository {
             .collect::<Vec<Request>>()
     }
 
+    /// Finds the requests that depend on the given request.
+    pub fn find_dependents(&self, request_id: &RequestId) -> Vec<Request> {
+        self.index
+            .find_by_dependency(request_id, None)
+            .keys()
+            .filter_map(|dependent_id| self.get(&RequestKey { id: *dependent_id }))
+            .collect()
+    }
+
     /// Find requests that are scheduled between the provided timestamps.
     pub fn find_scheduled(
         &self,
@@ -248,6 +304,42 @@ impl RequestRepository {
             .collect()
     }
 
+    /// Finds the requests whose title or summary contain a word starting with each of the words of the
+    /// query, from the most recent.
+    pub fn search_ids(&self, query: &str) -> Vec<RequestId> {
+        let mut found: Option<HashSet<RequestId>> = None;
+        for term in RequestTermIndex::terms(query) {
+            let matches = self
+                .term_index
+                .find_by_criteria(RequestTermIndexCriteria { term_prefix: term });
+
+            found = Some(match found {
+                Some(found) => found.intersection(&matches).copied().collect(),
+                None => matches,
+            });
+
+            if found.as_ref().is_some_and(HashSet::is_empty) {
+                break;
+            }
+        }
+
+        let mut request_ids = found
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|request_id| {
+                self.find_indexed_fields_by_request_id(&request_id)
+                    .map(|fields| (fields.created_at, request_id))
+            })
+            .collect::<Vec<_>>();
+
+        request_ids.sort_by(|a, b| b.cmp(a));
+
+        request_ids
+            .into_iter()
+            .map(|(_, request_id)| request_id)
+            .collect()
+    }
+
     /// Find the indexed fields of a request by its id.
     pub fn find_indexed_fields_by_request_id(
         &self,
@@ -276,13 +368,27 @@ impl RequestRepository {
         sort_by: Option<ListRequestsSortBy>,
     ) -> Result<Vec<UUID>, RepositoryError> {
         let mut entries = Vec::<(RequestId, RequestIndexFields)>::new();
+        let created_dt_from = condition.created_dt_from.unwrap_or(0);
+        let created_dt_to = condition.created_dt_to.unwrap_or(u64::MAX);
 
         // first find the initial result set that would narrow down the search space
-        entries.extend(self.index.find_by_created_at_between(
-            condition.created_dt_from.unwrap_or(0),
-            condition.created_dt_to.unwrap_or(u64::MAX),
-            None,
-        ));
+        if condition.tags.is_empty() {
+            entries.extend(self.index.find_by_created_at_between(
+                created_dt_from,
+                created_dt_to,
+                None,
+            ));
+        } else {
+            // the requests with any of the tags, which are usually few compared to all the requests
+            let mut tagged = HashMap::new();
+            for tag in &condition.tags {
+                tagged.extend(self.index.find_by_tag(tag, None));
+            }
+
+            entries.extend(tagged.into_iter().filter(|(_, fields)| {
+                fields.created_at >= created_dt_from && fields.created_at <= created_dt_to
+            }));
+        }
 
         // transform lists to constant lookup time
         let where_approvals: HashSet<_> = condition.approvers.iter().cloned().collect();
@@ -349,6 +455,15 @@ impl RequestRepository {
                     return false;
                 }
 
+                if fields.confidential
+                    && condition
+                        .visible_to
+                        .as_ref()
+                        .is_some_and(|ctx| Authorization::is_confidential_request_hidden(ctx, id))
+                {
+                    return false;
+                }
+
                 INDEXED_FIELDS_CACHE.with(|cache| {
                     cache.borrow_mut().insert(*id, fields.clone());
                 });
@@ -431,18 +546,26 @@ pub struct RequestWhereClause {
     pub requesters: Vec<UUID>,
     pub not_requesters: Vec<UUID>,
     pub excluded_ids: Vec<UUID>,
+    /// Matches the requests with any of the tags, which must be normalized.
+    pub tags: Vec<String>,
+    /// Leaves out the confidential requests that the caller can't read.
+    pub visible_to: Option<CallContext>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{
-        indexes::request_resource_index::RequestResourceIndex,
-        request_test_utils::{self, mock_request},
-        resource::{AccountResourceAction, ResourceId},
-        AddUserGroupOperation, AddUserGroupOperationInput, EditUserGroupOperation,
-        EditUserGroupOperationInput, RequestOperation, RequestStatus, TransferOperation,
-        TransferOperationInput,
+    use crate::{
+        models::{
+            indexes::request_resource_index::RequestResourceIndex,
+            request_test_utils::{self, mock_request},
+            resource::{AccountResourceAction, ResourceId},
+            user_test_utils::mock_user,
+            AddUserGroupOperation, AddUserGroupOperationInput, EditUserGroupOperation,
+            EditUserGroupOperationInput, RequestOperation, RequestStatus, TransferOperation,
+            TransferOperationInput,
+        },
+        repositories::USER_REPOSITORY,
     };
     use uuid::Uuid;
 
@@ -460,6 +583,81 @@ mod tests {
         assert!(repository.get(&request.to_key()).is_none());
     }
 
+    #[test]
+    fn find_ids_where_leaves_out_the_hidden_confidential_requests() {
+        let reader = mock_user();
+        USER_REPOSITORY.insert(reader.to_key(), reader.clone());
+
+        let visible = mock_request();
+        let mut confidential = mock_request();
+        confidential.confidential = true;
+        let mut requested = mock_request();
+        requested.confidential = true;
+        requested.requested_by = reader.id;
+
+        for request in [&visible, &confidential, &requested] {
+            REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+        }
+
+        let find = |visible_to: Option<CallContext>| {
+            let mut ids = REQUEST_REPOSITORY
+                .find_ids_where(
+                    RequestWhereClause {
+                        visible_to,
+                        ..Default::default()
+                    },
+                    None,
+                )
+                .unwrap();
+            ids.sort();
+            ids
+        };
+
+        let mut expected = vec![visible.id, requested.id];
+        expected.sort();
+        assert_eq!(find(Some(CallContext::new(reader.identities[0]))), expected);
+
+        let mut expected = vec![visible.id, confidential.id, requested.id];
+        expected.sort();
+        assert_eq!(find(None), expected);
+    }
+
+    #[test]
+    fn search_by_title_and_summary_words() {
+        let repository = RequestRepository::default();
+        let mut march = mock_request();
+        march.title = "Marketing payout".to_string();
+        march.summary = Some("Campaign of March".to_string());
+        march.created_timestamp = 20;
+        let mut april = mock_request();
+        april.title = "Marketing payout".to_string();
+        april.summary = Some("Campaign of April".to_string());
+        april.created_timestamp = 30;
+        let mut upgrade = mock_request();
+        upgrade.title = "Upgrade the station".to_string();
+        upgrade.summary = None;
+
+        for request in [&march, &april, &upgrade] {
+            repository.insert(request.to_key(), request.clone());
+        }
+
+        assert_eq!(
+            repository.search_ids("marketing payout March"),
+            vec![march.id]
+        );
+        assert_eq!(
+            repository.search_ids("market pay"),
+            vec![april.id, march.id]
+        );
+        assert!(repository.search_ids("marketing june").is_empty());
+        assert!(repository.search_ids("a").is_empty());
+
+        march.title = "Sales payout".to_string();
+        repository.insert(march.to_key(), march.clone());
+
+        assert_eq!(repository.search_ids("marketing"), vec![april.id]);
+    }
+
     #[test]
     fn find_by_expiration_dt_and_status() {
         let repository = RequestRepository::default();
@@ -530,6 +728,8 @@ mod tests {
             requesters: vec![],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -579,6 +779,8 @@ mod tests {
             requesters: vec![],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -631,6 +833,8 @@ mod tests {
             statuses: vec![RequestStatusCode::Created],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -651,6 +855,8 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -671,6 +877,8 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved, RequestStatusCode::Created],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -691,6 +899,8 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -716,6 +926,8 @@ mod tests {
             requesters: vec![],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -780,7 +992,10 @@ mod tests {
             input: EditUserGroupOperationInput {
                 user_group_id: *Uuid::new_v4().as_bytes(),
                 name: "bar".to_string(),
+                add_members: vec![],
+                remove_members: vec![],
             },
+            previous: None,
         });
         REQUEST_REPOSITORY.insert(edit_group_request.to_key(), edit_group_request.clone());
 
@@ -796,6 +1011,8 @@ mod tests {
             statuses: vec![RequestStatusCode::Approved],
             not_requesters: vec![],
             excluded_ids: vec![],
+            tags: vec![],
+            visible_to: None,
         };
 
         let requests = REQUEST_REPOSITORY
@@ -805,6 +1022,52 @@ mod tests {
         assert_eq!(requests.len(), 1);
         assert_eq!(requests[0], add_group_request.id);
     }
+
+    #[test]
+    fn find_with_any_of_the_tags() {
+        let mut treasury_request = mock_request();
+        treasury_request.tags = vec!["treasury".to_string()];
+        REQUEST_REPOSITORY.insert(treasury_request.to_key(), treasury_request.clone());
+
+        let mut ops_request = mock_request();
+        ops_request.tags = vec!["ops".to_string(), "treasury".to_string()];
+        REQUEST_REPOSITORY.insert(ops_request.to_key(), ops_request.clone());
+
+        let untagged_request = mock_request();
+        REQUEST_REPOSITORY.insert(untagged_request.to_key(), untagged_request.clone());
+
+        let find_ids = |tags: Vec<&str>| -> HashSet<UUID> {
+            REQUEST_REPOSITORY
+                .find_ids_where(
+                    RequestWhereClause {
+                        tags: tags.into_iter().map(String::from).collect(),
+                        ..Default::default()
+                    },
+                    None,
+                )
+                .unwrap()
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(
+            find_ids(vec!["treasury"]),
+            HashSet::from([treasury_request.id, ops_request.id])
+        );
+        assert_eq!(find_ids(vec!["ops"]), HashSet::from([ops_request.id]));
+        assert_eq!(
+            find_ids(vec!["ops", "admin"]),
+            HashSet::from([ops_request.id])
+        );
+        assert_eq!(find_ids(vec![]).len(), 3);
+
+        // the tag index follows the updates of the request
+        ops_request.tags = vec!["admin".to_string()];
+        REQUEST_REPOSITORY.insert(ops_request.to_key(), ops_request.clone());
+
+        assert!(find_ids(vec!["ops"]).is_empty());
+        assert_eq!(find_ids(vec!["admin"]), HashSet::from([ops_request.id]));
+    }
 }
 
 #[cfg(feature = "canbench")]
@@ -897,6 +1160,8 @@ mod benchs {
                     statuses: vec![RequestStatusCode::Created],
                     excluded_ids: vec![],
                     not_requesters: vec![],
+                    tags: vec![],
+                    visible_to: None,
                 },
                 None,
             );
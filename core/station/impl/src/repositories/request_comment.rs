@@ -0,0 +1,116 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory, REQUEST_COMMENT_MEMORY_ID,
+    },
+    models::{RequestComment, RequestCommentKey, RequestId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    model::ModelKey,
+    repository::{Repository, StableDb},
+};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<RequestCommentKey, RequestComment, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REQUEST_COMMENT_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref REQUEST_COMMENT_REPOSITORY: Arc<RequestCommentRepository> =
+        Arc::new(RequestCommentRepository::default());
+}
+
+/// A repository that stores the comments of the requests in stable memory.
+#[derive(Default, Debug)]
+pub struct RequestCommentRepository {}
+
+impl StableDb<RequestCommentKey, RequestComment, VirtualMemory<Memory>>
+    for RequestCommentRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<RequestCommentKey, RequestComment, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<RequestCommentKey, RequestComment, VirtualMemory<Memory>>
+    for RequestCommentRepository
+{
+    fn insert(&self, key: RequestCommentKey, value: RequestComment) -> Option<RequestComment> {
+        observe_repository_write("request_comments", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+impl RequestCommentRepository {
+    /// Returns the comments of the request, the oldest first.
+    pub fn find_by_request_id(&self, request_id: &RequestId) -> Vec<RequestComment> {
+        DB.with(|db| {
+            db.borrow()
+                .range(
+                    RequestCommentKey {
+                        request_id: *request_id,
+                        created_timestamp: 0,
+                        id: [0; 16],
+                    }..,
+                )
+                .take_while(|(key, _)| key.request_id == *request_id)
+                .map(|(_, comment)| comment)
+                .collect()
+        })
+    }
+
+    /// Removes the comments of the request.
+    pub fn remove_by_request_id(&self, request_id: &RequestId) {
+        for comment in self.find_by_request_id(request_id) {
+            self.remove(&comment.key());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_comment_test_utils::mock_request_comment;
+
+    #[test]
+    fn find_comments_by_request() {
+        let repository = RequestCommentRepository::default();
+
+        let mut first = mock_request_comment();
+        first.created_timestamp = 10;
+        let mut second = mock_request_comment();
+        second.id = [1; 16];
+        second.created_timestamp = 20;
+        let mut other = mock_request_comment();
+        other.id = [2; 16];
+        other.request_id = [9; 16];
+
+        for comment in [&second, &first, &other] {
+            repository.insert(comment.key(), comment.to_owned());
+        }
+
+        assert_eq!(
+            repository.find_by_request_id(&first.request_id),
+            vec![first.clone(), second]
+        );
+
+        repository.remove_by_request_id(&first.request_id);
+
+        assert!(repository.find_by_request_id(&first.request_id).is_empty());
+        assert_eq!(
+            repository.find_by_request_id(&other.request_id),
+            vec![other]
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    core::{with_memory_manager, Memory, REQUEST_EVALUATION_RESULT_MEMORY_ID},
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory,
+        REQUEST_EVALUATION_RESULT_MEMORY_ID,
+    },
     models::{RequestEvaluationResult, RequestId},
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
@@ -40,6 +43,15 @@ impl StableDb<RequestId, RequestEvaluationResult, VirtualMemory<Memory>>
 impl Repository<RequestId, RequestEvaluationResult, VirtualMemory<Memory>>
     for EvaluationResultRepository
 {
+    fn insert(
+        &self,
+        key: RequestId,
+        value: RequestEvaluationResult,
+    ) -> Option<RequestEvaluationResult> {
+        observe_repository_write("request_evaluation_results", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
 }
 
 #[cfg(test)]
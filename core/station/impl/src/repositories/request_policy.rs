@@ -3,7 +3,9 @@ use super::indexes::request_policy_resource_index::{
 };
 use crate::{
     core::{
-        metrics::REQUEST_POLICY_METRICS, with_memory_manager, Memory, REQUEST_POLICIES_MEMORY_ID,
+        metrics::{observe_repository_write, REQUEST_POLICY_METRICS},
+        request::invalidate_cached_evaluations,
+        with_memory_manager, Memory, REQUEST_POLICIES_MEMORY_ID,
     },
     models::{
         indexes::request_policy_resource_index::RequestPolicyResourceIndexCriteria,
@@ -68,6 +70,8 @@ impl IndexedRepository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPo
 
 impl Repository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRepository {
     fn insert(&self, key: UUID, value: RequestPolicy) -> Option<RequestPolicy> {
+        observe_repository_write("request_policies", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
@@ -79,6 +83,7 @@ impl Repository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRep
             });
 
             self.save_entry_indexes(&value, prev.as_ref());
+            invalidate_cached_evaluations();
 
             prev
         })
@@ -97,6 +102,7 @@ impl Repository<UUID, RequestPolicy, VirtualMemory<Memory>> for RequestPolicyRep
                 });
 
                 self.remove_entry_indexes(prev);
+                invalidate_cached_evaluations();
             }
 
             prev
@@ -202,6 +208,7 @@ mod tests {
             specifier: RequestSpecifier::Transfer(ResourceIds::Ids(vec![
                 [10; 16], [11; 16], [12; 16],
             ])),
+            expiration_period_secs: None,
         };
 
         repository.insert(other_policy.id, other_policy.clone());
@@ -309,6 +316,7 @@ mod benchs {
                     specifier: RequestSpecifier::ChangeExternalCanister(
                         ExternalCanisterId::Canister(canister_id),
                     ),
+                    expiration_period_secs: None,
                 });
             }
 
@@ -327,6 +335,7 @@ mod benchs {
                             ),
                         },
                     ),
+                    expiration_period_secs: None,
                 });
             }
 
@@ -350,6 +359,7 @@ mod benchs {
                             ),
                         },
                     ),
+                    expiration_period_secs: None,
                 });
             }
 
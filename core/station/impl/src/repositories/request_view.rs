@@ -0,0 +1,95 @@
+use crate::{
+    core::{with_memory_manager, Memory, REQUEST_VIEW_MEMORY_ID},
+    models::{RequestView, RequestViewId, UserGroupId, UserId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<RequestViewId, RequestView, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(REQUEST_VIEW_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref REQUEST_VIEW_REPOSITORY: Arc<RequestViewRepository> =
+        Arc::new(RequestViewRepository::default());
+}
+
+/// A repository that stores the saved request views in stable memory.
+#[derive(Default, Debug)]
+pub struct RequestViewRepository {}
+
+impl StableDb<RequestViewId, RequestView, VirtualMemory<Memory>> for RequestViewRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<RequestViewId, RequestView, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<RequestViewId, RequestView, VirtualMemory<Memory>> for RequestViewRepository {}
+
+impl RequestViewRepository {
+    /// Returns the views owned by the user.
+    pub fn find_by_owner(&self, owner_id: &UserId) -> Vec<RequestView> {
+        self.list()
+            .into_iter()
+            .filter(|view| view.owner_id == *owner_id)
+            .collect()
+    }
+
+    /// Returns the views owned by the user or shared with one of its groups, sorted by name.
+    pub fn find_visible_to(
+        &self,
+        user_id: &UserId,
+        user_groups: &[UserGroupId],
+    ) -> Vec<RequestView> {
+        let mut views = self
+            .list()
+            .into_iter()
+            .filter(|view| view.is_visible_to(user_id, user_groups))
+            .collect::<Vec<_>>();
+
+        views.sort_by(|a, b| a.name.cmp(&b.name));
+
+        views
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::request_view_test_utils::mock_request_view;
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn find_views_visible_to_the_user() {
+        let repository = RequestViewRepository::default();
+
+        let owned = mock_request_view();
+        let mut shared = mock_request_view();
+        shared.id = [1; 16];
+        shared.name = "Approvals".to_string();
+        shared.owner_id = [2; 16];
+        shared.shared_with_group_id = Some([5; 16]);
+        let mut private = mock_request_view();
+        private.id = [2; 16];
+        private.owner_id = [2; 16];
+
+        for view in [&owned, &shared, &private] {
+            repository.insert(view.key(), view.to_owned());
+        }
+
+        assert_eq!(
+            repository.find_visible_to(&owned.owner_id, &[[5; 16]]),
+            vec![shared, owned.clone()]
+        );
+        assert_eq!(repository.find_by_owner(&owned.owner_id), vec![owned]);
+    }
+}
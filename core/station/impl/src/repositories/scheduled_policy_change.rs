@@ -0,0 +1,90 @@
+use crate::{
+    core::{with_memory_manager, Memory, SCHEDULED_POLICY_CHANGE_MEMORY_ID},
+    models::{ScheduledPolicyChange, ScheduledPolicyChangeId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<ScheduledPolicyChangeId, ScheduledPolicyChange, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(SCHEDULED_POLICY_CHANGE_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref SCHEDULED_POLICY_CHANGE_REPOSITORY: Arc<ScheduledPolicyChangeRepository> =
+        Arc::new(ScheduledPolicyChangeRepository::default());
+}
+
+/// A repository that stores the scheduled policy changes in stable memory.
+#[derive(Default, Debug)]
+pub struct ScheduledPolicyChangeRepository {}
+
+impl StableDb<ScheduledPolicyChangeId, ScheduledPolicyChange, VirtualMemory<Memory>>
+    for ScheduledPolicyChangeRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<
+                ScheduledPolicyChangeId,
+                ScheduledPolicyChange,
+                VirtualMemory<Memory>,
+            >,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<ScheduledPolicyChangeId, ScheduledPolicyChange, VirtualMemory<Memory>>
+    for ScheduledPolicyChangeRepository
+{
+}
+
+impl ScheduledPolicyChangeRepository {
+    /// Returns the changes that are waiting for their effective time, ordered by that time.
+    pub fn find_pending(&self) -> Vec<ScheduledPolicyChange> {
+        let mut changes = self
+            .list()
+            .into_iter()
+            .filter(ScheduledPolicyChange::is_pending)
+            .collect::<Vec<_>>();
+
+        changes.sort_by_key(|change| change.effective_from);
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        scheduled_policy_change_test_utils::mock_scheduled_policy_change,
+        ScheduledPolicyChangeStatus,
+    };
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn find_pending_changes_by_effective_time() {
+        let repository = ScheduledPolicyChangeRepository::default();
+
+        let mut later = mock_scheduled_policy_change();
+        later.effective_from = 20;
+        let mut sooner = mock_scheduled_policy_change();
+        sooner.effective_from = 10;
+        let mut activated = mock_scheduled_policy_change();
+        activated.status = ScheduledPolicyChangeStatus::Activated { activated_at: 5 };
+
+        for change in [&later, &sooner, &activated] {
+            repository.insert(change.key(), change.to_owned());
+        }
+
+        assert_eq!(repository.find_pending(), vec![sooner, later]);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    core::{with_memory_manager, Memory, SCHEDULED_TRANSFER_MEMORY_ID},
+    models::{AccountId, ScheduledTransfer, ScheduledTransferId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<ScheduledTransferId, ScheduledTransfer, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(SCHEDULED_TRANSFER_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref SCHEDULED_TRANSFER_REPOSITORY: Arc<ScheduledTransferRepository> =
+        Arc::new(ScheduledTransferRepository::default());
+}
+
+/// A repository that stores the standing orders in stable memory.
+#[derive(Default, Debug)]
+pub struct ScheduledTransferRepository {}
+
+impl StableDb<ScheduledTransferId, ScheduledTransfer, VirtualMemory<Memory>>
+    for ScheduledTransferRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<ScheduledTransferId, ScheduledTransfer, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<ScheduledTransferId, ScheduledTransfer, VirtualMemory<Memory>>
+    for ScheduledTransferRepository
+{
+}
+
+impl ScheduledTransferRepository {
+    /// Returns the standing orders that are still executed, ordered by their next execution time.
+    pub fn find_active(&self) -> Vec<ScheduledTransfer> {
+        let mut scheduled_transfers = self
+            .list()
+            .into_iter()
+            .filter(ScheduledTransfer::is_active)
+            .collect::<Vec<_>>();
+
+        scheduled_transfers.sort_by_key(|scheduled_transfer| scheduled_transfer.next_execution_at);
+
+        scheduled_transfers
+    }
+
+    /// Returns the standing orders of the account, the most recent first.
+    pub fn find_by_account_id(&self, account_id: &AccountId) -> Vec<ScheduledTransfer> {
+        let mut scheduled_transfers = self
+            .list()
+            .into_iter()
+            .filter(|scheduled_transfer| scheduled_transfer.transfer.from_account_id == *account_id)
+            .collect::<Vec<_>>();
+
+        scheduled_transfers.sort_by(|a, b| b.created_timestamp.cmp(&a.created_timestamp));
+
+        scheduled_transfers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        scheduled_transfer_test_utils::mock_scheduled_transfer, ScheduledTransferStatus,
+    };
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn find_active_by_next_execution_time() {
+        let repository = ScheduledTransferRepository::default();
+
+        let mut later = mock_scheduled_transfer();
+        later.next_execution_at = 20;
+        let mut sooner = mock_scheduled_transfer();
+        sooner.next_execution_at = 10;
+        let mut completed = mock_scheduled_transfer();
+        completed.status = ScheduledTransferStatus::Completed { completed_at: 5 };
+
+        for scheduled_transfer in [&later, &sooner, &completed] {
+            repository.insert(scheduled_transfer.key(), scheduled_transfer.to_owned());
+        }
+
+        assert_eq!(repository.find_active(), vec![sooner, later]);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    core::{with_memory_manager, Memory, SPENDING_AGGREGATE_MEMORY_ID},
+    models::{SpendingAggregate, SpendingAggregateKey, SpendingGroupBy, SpendingPeriod},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use orbit_essentials::types::Timestamp;
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<SpendingAggregateKey, SpendingAggregate, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(SPENDING_AGGREGATE_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref SPENDING_AGGREGATE_REPOSITORY: Arc<SpendingAggregateRepository> =
+        Arc::new(SpendingAggregateRepository::default());
+}
+
+/// A repository that stores the spending aggregates of the accounts in stable memory.
+#[derive(Default, Debug)]
+pub struct SpendingAggregateRepository {}
+
+impl StableDb<SpendingAggregateKey, SpendingAggregate, VirtualMemory<Memory>>
+    for SpendingAggregateRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<SpendingAggregateKey, SpendingAggregate, VirtualMemory<Memory>>,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<SpendingAggregateKey, SpendingAggregate, VirtualMemory<Memory>>
+    for SpendingAggregateRepository
+{
+}
+
+impl SpendingAggregateRepository {
+    /// Returns the aggregates of the period and grouping whose period starts within the given range.
+    pub fn find_by_period(
+        &self,
+        period: SpendingPeriod,
+        group_by: SpendingGroupBy,
+        from_dt: Option<Timestamp>,
+        to_dt: Option<Timestamp>,
+    ) -> Vec<SpendingAggregate> {
+        self.list()
+            .into_iter()
+            .filter(|aggregate| {
+                aggregate.period == period
+                    && aggregate.group_by == group_by
+                    && from_dt.map_or(true, |from_dt| aggregate.period_start >= from_dt)
+                    && to_dt.map_or(true, |to_dt| aggregate.period_start <= to_dt)
+            })
+            .collect()
+    }
+}
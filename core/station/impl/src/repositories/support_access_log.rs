@@ -0,0 +1,125 @@
+use crate::{
+    core::{with_memory_manager, Memory, SUPPORT_ACCESS_LOG_MEMORY_ID},
+    models::{SupportAccessLogEntry, SupportAccessLogEntryKey, UserId},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<SupportAccessLogEntryKey, SupportAccessLogEntry, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(SUPPORT_ACCESS_LOG_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref SUPPORT_ACCESS_LOG_REPOSITORY: Arc<SupportAccessLogRepository> =
+        Arc::new(SupportAccessLogRepository::default());
+}
+
+/// A repository that stores the support access log in stable memory.
+#[derive(Default, Debug)]
+pub struct SupportAccessLogRepository {}
+
+impl StableDb<SupportAccessLogEntryKey, SupportAccessLogEntry, VirtualMemory<Memory>>
+    for SupportAccessLogRepository
+{
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(
+            &mut StableBTreeMap<
+                SupportAccessLogEntryKey,
+                SupportAccessLogEntry,
+                VirtualMemory<Memory>,
+            >,
+        ) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<SupportAccessLogEntryKey, SupportAccessLogEntry, VirtualMemory<Memory>>
+    for SupportAccessLogRepository
+{
+}
+
+impl SupportAccessLogRepository {
+    /// Returns the entries the most recent first, only the ones of the viewed user if given.
+    pub fn find_most_recent(&self, viewed_user_id: Option<&UserId>) -> Vec<SupportAccessLogEntry> {
+        // the entries are listed in the order of their keys, the oldest first
+        self.list()
+            .into_iter()
+            .rev()
+            .filter(|entry| viewed_user_id.map_or(true, |id| entry.viewed_user_id == *id))
+            .collect()
+    }
+
+    /// Removes the oldest entries that exceed `SupportAccessLogEntry::MAX_ENTRIES`.
+    pub fn prune(&self) {
+        let excess = self
+            .len()
+            .saturating_sub(SupportAccessLogEntry::MAX_ENTRIES);
+        if excess == 0 {
+            return;
+        }
+
+        let keys = DB.with(|db| {
+            db.borrow()
+                .iter()
+                .take(excess)
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>()
+        });
+
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::support_access_log_test_utils::mock_support_access_log_entry;
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn prune_keeps_the_most_recent_entries() {
+        let repository = SupportAccessLogRepository::default();
+        for viewed_at in 0..(SupportAccessLogEntry::MAX_ENTRIES as u64 + 2) {
+            let mut entry = mock_support_access_log_entry();
+            entry.viewed_at = viewed_at;
+            repository.insert(entry.key(), entry);
+        }
+
+        repository.prune();
+
+        let entries = repository.find_most_recent(None);
+        assert_eq!(entries.len(), SupportAccessLogEntry::MAX_ENTRIES);
+        assert_eq!(entries.last().map(|entry| entry.viewed_at), Some(2));
+    }
+
+    #[test]
+    fn find_most_recent_orders_the_entries_by_view_time() {
+        let repository = SupportAccessLogRepository::default();
+        let viewed_user_id = [1; 16];
+        for viewed_at in [3, 1, 2] {
+            let mut entry = mock_support_access_log_entry();
+            entry.viewed_user_id = viewed_user_id;
+            entry.viewed_at = viewed_at;
+            repository.insert(entry.key(), entry);
+        }
+        let other_user_entry = mock_support_access_log_entry();
+        repository.insert(other_user_entry.key(), other_user_entry);
+
+        let viewed_at = repository
+            .find_most_recent(Some(&viewed_user_id))
+            .into_iter()
+            .map(|entry| entry.viewed_at)
+            .collect::<Vec<_>>();
+        assert_eq!(viewed_at, vec![3, 2, 1]);
+    }
+}
@@ -1,30 +1,47 @@
 use super::indexes::{
     transfer_account_index::TransferAccountIndexRepository,
+    transfer_account_status_index::TransferAccountStatusIndexRepository,
+    transfer_category_index::TransferCategoryIndexRepository,
+    transfer_destination_index::TransferDestinationIndexRepository,
+    transfer_metadata_index::TransferMetadataIndexRepository,
     transfer_status_index::TransferStatusIndexRepository,
 };
 use crate::{
     core::{
-        metrics::{metrics_observe_insert_transfer, metrics_observe_remove_transfer},
+        authorization::Authorization,
+        metrics::{
+            metrics_observe_insert_transfer, metrics_observe_remove_transfer,
+            observe_repository_write,
+        },
         observer::Observer,
-        with_memory_manager, Memory, TRANSFER_MEMORY_ID,
+        with_memory_manager, CallContext, Memory, TRANSFER_MEMORY_ID,
     },
     jobs::jobs_observe_insert_transfer,
+    mappers::TransferStatusMapper,
     models::{
         indexes::{
             transfer_account_index::TransferAccountIndexCriteria,
+            transfer_account_status_index::TransferAccountStatusIndexCriteria,
+            transfer_category_index::TransferCategoryIndexCriteria,
+            transfer_destination_index::TransferDestinationIndexCriteria,
+            transfer_metadata_index::TransferMetadataIndexCriteria,
             transfer_status_index::TransferStatusIndexCriteria,
         },
-        AccountId, Transfer, TransferKey,
+        AccountId, MetadataItem, Transfer, TransferId, TransferKey,
+    },
+    services::{
+        account_webhook_observes_insert_transfer, record_certification_observes_insert_transfer,
+        record_certification_observes_remove_transfer,
     },
 };
 use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
 use lazy_static::lazy_static;
 use orbit_essentials::{
     repository::{IndexRepository, IndexedRepository, Repository, StableDb},
-    types::Timestamp,
+    types::{Timestamp, UUID},
 };
 use station_api::TransferStatusTypeDTO;
-use std::cell::RefCell;
+use std::{cell::RefCell, collections::HashSet, ops::Bound, sync::Arc};
 
 thread_local! {
     /// The memory reference to the Transfer repository.
@@ -36,7 +53,8 @@ thread_local! {
 }
 
 lazy_static! {
-    pub static ref TRANSFER_REPOSITORY: TransferRepository = TransferRepository::default();
+    pub static ref TRANSFER_REPOSITORY: Arc<TransferRepository> =
+        Arc::new(TransferRepository::default());
 }
 
 /// A repository that enables managing transfer in stable memory.
@@ -44,6 +62,10 @@ lazy_static! {
 pub struct TransferRepository {
     account_index: TransferAccountIndexRepository,
     status_index: TransferStatusIndexRepository,
+    metadata_index: TransferMetadataIndexRepository,
+    account_status_index: TransferAccountStatusIndexRepository,
+    destination_index: TransferDestinationIndexRepository,
+    category_index: TransferCategoryIndexRepository,
     change_observer: Observer<(Transfer, Option<Transfer>)>,
     remove_observer: Observer<Transfer>,
 }
@@ -53,13 +75,20 @@ impl Default for TransferRepository {
         let mut change_observer = Observer::default();
         metrics_observe_insert_transfer(&mut change_observer);
         jobs_observe_insert_transfer(&mut change_observer);
+        account_webhook_observes_insert_transfer(&mut change_observer);
+        record_certification_observes_insert_transfer(&mut change_observer);
 
         let mut remove_observer = Observer::default();
         metrics_observe_remove_transfer(&mut remove_observer);
+        record_certification_observes_remove_transfer(&mut remove_observer);
 
         Self {
             account_index: TransferAccountIndexRepository::default(),
             status_index: TransferStatusIndexRepository::default(),
+            metadata_index: TransferMetadataIndexRepository::default(),
+            account_status_index: TransferAccountStatusIndexRepository::default(),
+            destination_index: TransferDestinationIndexRepository::default(),
+            category_index: TransferCategoryIndexRepository::default(),
             change_observer,
             remove_observer,
         }
@@ -79,22 +108,52 @@ impl IndexedRepository<TransferKey, Transfer, VirtualMemory<Memory>> for Transfe
     fn remove_entry_indexes(&self, entry: &Transfer) {
         self.account_index.remove(&entry.to_index_by_account());
         self.status_index.remove(&entry.to_index_by_status());
+        self.account_status_index
+            .remove(&entry.to_index_by_account_status());
+        self.destination_index
+            .remove(&entry.to_index_by_destination());
+
+        if let Some(index) = entry.to_index_by_category() {
+            self.category_index.remove(&index);
+        }
+
+        for index in entry.to_index_by_metadata() {
+            self.metadata_index.remove(&index);
+        }
     }
 
     fn add_entry_indexes(&self, entry: &Transfer) {
         self.account_index.insert(entry.to_index_by_account());
         self.status_index.insert(entry.to_index_by_status());
+        self.account_status_index
+            .insert(entry.to_index_by_account_status());
+        self.destination_index
+            .insert(entry.to_index_by_destination());
+
+        if let Some(index) = entry.to_index_by_category() {
+            self.category_index.insert(index);
+        }
+
+        for index in entry.to_index_by_metadata() {
+            self.metadata_index.insert(index);
+        }
     }
 
     /// Clears all the indexes for the repository.
     fn clear_indexes(&self) {
         self.account_index.clear();
         self.status_index.clear();
+        self.metadata_index.clear();
+        self.account_status_index.clear();
+        self.destination_index.clear();
+        self.category_index.clear();
     }
 }
 
 impl Repository<TransferKey, Transfer, VirtualMemory<Memory>> for TransferRepository {
     fn insert(&self, key: TransferKey, value: Transfer) -> Option<Transfer> {
+        observe_repository_write("transfers", &value);
+
         DB.with(|m| {
             let prev = m.borrow_mut().insert(key, value.clone());
 
@@ -125,6 +184,22 @@ impl Repository<TransferKey, Transfer, VirtualMemory<Memory>> for TransferReposi
 }
 
 impl TransferRepository {
+    /// Returns up to `limit` transfers in the order of their ids, starting after the given id.
+    pub fn list_after(&self, after: Option<TransferId>, limit: usize) -> Vec<Transfer> {
+        let start = match after {
+            Some(id) => Bound::Excluded(TransferKey { id }),
+            None => Bound::Unbounded,
+        };
+
+        DB.with(|db| {
+            db.borrow()
+                .range((start, Bound::Unbounded))
+                .take(limit)
+                .map(|(_, transfer)| transfer)
+                .collect()
+        })
+    }
+
     pub fn find_by_account(
         &self,
         account_id: AccountId,
@@ -140,6 +215,108 @@ impl TransferRepository {
                 to_dt: created_dt_to,
             });
 
+        self.filter_by_status(transfers, status)
+    }
+
+    /// Returns a page of the matching transfers from the newest, starting at the given transfer if
+    /// any, with the id of the transfer that starts the next page.
+    pub fn find_page_where(
+        &self,
+        condition: TransferWhereClause,
+        start_at: Option<(Timestamp, TransferId)>,
+        limit: usize,
+    ) -> (Vec<Transfer>, Option<TransferId>) {
+        let from_dt = condition.created_dt_from.unwrap_or(u64::MIN);
+        let to_dt = condition.created_dt_to.unwrap_or(u64::MAX).min(
+            start_at
+                .map(|(created_timestamp, _)| created_timestamp)
+                .unwrap_or(u64::MAX),
+        );
+
+        if from_dt > to_dt {
+            return (Vec::new(), None);
+        }
+
+        // the most selective index narrows down the search space, the other conditions are
+        // checked on the transfers
+        let entries = if let Some(metadata) = &condition.metadata {
+            self.metadata_index
+                .find_ordered_by_criteria(TransferMetadataIndexCriteria {
+                    account_id: condition.account_id,
+                    key: metadata.key.to_owned(),
+                    value: metadata.value.to_owned(),
+                    from_dt: Some(from_dt),
+                    to_dt: Some(to_dt),
+                })
+        } else if let Some(to_address) = &condition.to_address {
+            self.destination_index
+                .find_ordered_by_criteria(TransferDestinationIndexCriteria {
+                    account_id: condition.account_id,
+                    to_address: to_address.to_owned(),
+                    from_dt: Some(from_dt),
+                    to_dt: Some(to_dt),
+                })
+        } else if let Some(category) = &condition.category {
+            self.category_index
+                .find_ordered_by_criteria(TransferCategoryIndexCriteria {
+                    category: category.to_owned(),
+                    from_dt: Some(from_dt),
+                    to_dt: Some(to_dt),
+                })
+        } else if let Some(status) = &condition.status {
+            self.account_status_index
+                .find_ordered_by_criteria(TransferAccountStatusIndexCriteria {
+                    account_id: condition.account_id,
+                    status: TransferStatusMapper::from_status_type_dto(status).to_string(),
+                    from_dt: Some(from_dt),
+                    to_dt: Some(to_dt),
+                })
+        } else {
+            self.account_index
+                .find_ordered_by_criteria(TransferAccountIndexCriteria {
+                    account_id: condition.account_id,
+                    from_dt: Some(from_dt),
+                    to_dt: Some(to_dt),
+                })
+        };
+
+        let mut transfers = entries
+            .into_iter()
+            .rev()
+            .skip_while(|entry| start_at.is_some_and(|start_at| *entry > start_at))
+            .filter_map(|(_, transfer_id)| self.get(&Transfer::key(transfer_id)))
+            .filter(|transfer| condition.matches(transfer));
+
+        let page = transfers.by_ref().take(limit).collect::<Vec<_>>();
+        let next = transfers.next().map(|transfer| transfer.id);
+
+        (page, next)
+    }
+
+    /// Returns the transfers of all the accounts with the category that were created in the period,
+    /// from the oldest.
+    pub fn find_by_category(
+        &self,
+        category: &str,
+        created_dt_from: Option<Timestamp>,
+        created_dt_to: Option<Timestamp>,
+    ) -> Vec<Transfer> {
+        self.category_index
+            .find_ordered_by_criteria(TransferCategoryIndexCriteria {
+                category: category.to_string(),
+                from_dt: created_dt_from,
+                to_dt: created_dt_to,
+            })
+            .into_iter()
+            .filter_map(|(_, transfer_id)| self.get(&Transfer::key(transfer_id)))
+            .collect()
+    }
+
+    fn filter_by_status(
+        &self,
+        transfers: HashSet<UUID>,
+        status: Option<TransferStatusTypeDTO>,
+    ) -> Vec<Transfer> {
         transfers
             .iter()
             .filter_map(|id| match (self.get(&Transfer::key(*id)), status.clone()) {
@@ -186,10 +363,58 @@ impl TransferRepository {
     }
 }
 
+/// The conditions of the transfers listed by `TransferRepository::find_page_where`.
+#[derive(Debug, Clone, Default)]
+pub struct TransferWhereClause {
+    pub account_id: AccountId,
+    pub created_dt_from: Option<Timestamp>,
+    pub created_dt_to: Option<Timestamp>,
+    pub status: Option<TransferStatusTypeDTO>,
+    pub to_address: Option<String>,
+    pub metadata: Option<MetadataItem>,
+    pub category: Option<String>,
+    pub min_amount: Option<candid::Nat>,
+    pub max_amount: Option<candid::Nat>,
+    /// Leaves out the transfers of the confidential requests that the caller can't read.
+    pub visible_to: Option<CallContext>,
+}
+
+impl TransferWhereClause {
+    fn matches(&self, transfer: &Transfer) -> bool {
+        // the category index spans the accounts of the station
+        transfer.from_account == self.account_id
+            && self
+                .status
+                .as_ref()
+                .map_or(true, |status| *status == transfer.status.clone().into())
+            && self
+                .to_address
+                .as_ref()
+                .map_or(true, |to_address| *to_address == transfer.to_address)
+            && self.metadata.as_ref().map_or(true, |metadata| {
+                transfer.metadata.get(&metadata.key).as_ref() == Some(&metadata.value)
+            })
+            && self.category.as_ref().map_or(true, |category| {
+                transfer.category.as_ref() == Some(category)
+            })
+            && self
+                .min_amount
+                .as_ref()
+                .map_or(true, |min_amount| transfer.amount >= *min_amount)
+            && self
+                .max_amount
+                .as_ref()
+                .map_or(true, |max_amount| transfer.amount <= *max_amount)
+            && self.visible_to.as_ref().map_or(true, |ctx| {
+                !Authorization::is_confidential_request_hidden(ctx, &transfer.request_id)
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::transfer_test_utils;
+    use crate::models::{transfer_test_utils, Metadata, METADATA_INVOICE_ID_KEY};
 
     #[test]
     fn perform_crud() {
@@ -231,4 +456,146 @@ mod tests {
 
         assert!(transfers.is_empty());
     }
+
+    #[test]
+    fn find_by_account_metadata() {
+        let repository = TransferRepository::default();
+        let mut transfer = transfer_test_utils::mock_transfer();
+        transfer.from_account = [1; 16];
+        transfer.metadata = Metadata::new(
+            [(METADATA_INVOICE_ID_KEY.to_string(), "INV-1".to_string())]
+                .into_iter()
+                .collect(),
+        );
+
+        repository.insert(transfer.to_key(), transfer.clone());
+
+        let find = |value: &str| {
+            repository
+                .find_page_where(
+                    TransferWhereClause {
+                        account_id: [1; 16],
+                        metadata: Some(MetadataItem {
+                            key: METADATA_INVOICE_ID_KEY.to_string(),
+                            value: value.to_string(),
+                        }),
+                        ..Default::default()
+                    },
+                    None,
+                    10,
+                )
+                .0
+        };
+
+        assert_eq!(find("INV-1"), vec![transfer.clone()]);
+        assert!(find("INV-2").is_empty());
+
+        repository.remove(&transfer.to_key());
+
+        assert!(find("INV-1").is_empty());
+    }
+
+    #[test]
+    fn find_page_where_lists_from_the_newest() {
+        let repository = TransferRepository::default();
+        let transfers = (1..=5u64)
+            .map(|i| {
+                let mut transfer = transfer_test_utils::mock_transfer();
+                transfer.from_account = [1; 16];
+                transfer.created_timestamp = i;
+                transfer.amount = candid::Nat::from(i * 100);
+                transfer.to_address = if i % 2 == 0 { "even" } else { "odd" }.to_string();
+                repository.insert(transfer.to_key(), transfer.clone());
+
+                transfer
+            })
+            .collect::<Vec<_>>();
+
+        let condition = TransferWhereClause {
+            account_id: [1; 16],
+            ..Default::default()
+        };
+
+        let (page, next) = repository.find_page_where(condition.clone(), None, 2);
+        assert_eq!(page, vec![transfers[4].clone(), transfers[3].clone()]);
+        assert_eq!(next, Some(transfers[2].id));
+
+        let (page, next) = repository.find_page_where(
+            condition,
+            Some((transfers[2].created_timestamp, transfers[2].id)),
+            5,
+        );
+        assert_eq!(
+            page,
+            vec![
+                transfers[2].clone(),
+                transfers[1].clone(),
+                transfers[0].clone()
+            ]
+        );
+        assert_eq!(next, None);
+
+        let (page, _) = repository.find_page_where(
+            TransferWhereClause {
+                account_id: [1; 16],
+                to_address: Some("odd".to_string()),
+                min_amount: Some(candid::Nat::from(200u64)),
+                ..Default::default()
+            },
+            None,
+            5,
+        );
+        assert_eq!(page, vec![transfers[4].clone(), transfers[2].clone()]);
+
+        let (page, _) = repository.find_page_where(
+            TransferWhereClause {
+                account_id: [1; 16],
+                status: Some(TransferStatusTypeDTO::Created),
+                max_amount: Some(candid::Nat::from(100u64)),
+                ..Default::default()
+            },
+            None,
+            5,
+        );
+        assert_eq!(page, vec![transfers[0].clone()]);
+    }
+
+    #[test]
+    fn find_by_category_across_accounts() {
+        let repository = TransferRepository::default();
+        let transfers = (1..=3u64)
+            .map(|i| {
+                let mut transfer = transfer_test_utils::mock_transfer();
+                transfer.from_account = [i as u8; 16];
+                transfer.created_timestamp = i * 10;
+                transfer.category = Some("payroll".to_string());
+                repository.insert(transfer.to_key(), transfer.clone());
+
+                transfer
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            repository.find_by_category("payroll", Some(15), None),
+            vec![transfers[1].clone(), transfers[2].clone()]
+        );
+        assert!(repository.find_by_category("grants", None, None).is_empty());
+
+        let (page, _) = repository.find_page_where(
+            TransferWhereClause {
+                account_id: [1; 16],
+                category: Some("payroll".to_string()),
+                ..Default::default()
+            },
+            None,
+            5,
+        );
+        assert_eq!(page, vec![transfers[0].clone()]);
+
+        let mut recategorized = transfers[0].clone();
+        recategorized.category = None;
+        repository.insert(recategorized.to_key(), recategorized);
+
+        assert_eq!(repository.find_by_category("payroll", None, None).len(), 2);
+    }
 }
@@ -0,0 +1,67 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory, TRANSFER_CATEGORY_MEMORY_ID,
+    },
+    models::TransferCategory,
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<String, TransferCategory, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_CATEGORY_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref TRANSFER_CATEGORY_REPOSITORY: Arc<TransferCategoryRepository> =
+        Arc::new(TransferCategoryRepository::default());
+}
+
+/// A repository that stores the registered transfer categories by their name.
+#[derive(Default, Debug)]
+pub struct TransferCategoryRepository {}
+
+impl StableDb<String, TransferCategory, VirtualMemory<Memory>> for TransferCategoryRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<String, TransferCategory, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<String, TransferCategory, VirtualMemory<Memory>> for TransferCategoryRepository {
+    fn insert(&self, key: String, value: TransferCategory) -> Option<TransferCategory> {
+        observe_repository_write("transfer_categories", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perform_crud() {
+        let repository = TransferCategoryRepository::default();
+        let category = TransferCategory {
+            name: "payroll".to_string(),
+            description: None,
+            last_modification_timestamp: 0,
+        };
+
+        assert!(repository.get(&category.name).is_none());
+
+        repository.insert(category.name.clone(), category.clone());
+
+        assert_eq!(repository.get(&category.name), Some(category.clone()));
+        assert!(repository.remove(&category.name).is_some());
+        assert!(repository.get(&category.name).is_none());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    core::{
+        metrics::observe_repository_write, with_memory_manager, Memory, TRANSFER_RECEIPT_MEMORY_ID,
+    },
+    models::{TransferId, TransferReceipt},
+};
+use ic_stable_structures::{memory_manager::VirtualMemory, StableBTreeMap};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::{Repository, StableDb};
+use std::{cell::RefCell, sync::Arc};
+
+thread_local! {
+  static DB: RefCell<StableBTreeMap<TransferId, TransferReceipt, VirtualMemory<Memory>>> = with_memory_manager(|memory_manager| {
+    RefCell::new(
+      StableBTreeMap::init(memory_manager.get(TRANSFER_RECEIPT_MEMORY_ID))
+    )
+  })
+}
+
+lazy_static! {
+    pub static ref TRANSFER_RECEIPT_REPOSITORY: Arc<TransferReceiptRepository> =
+        Arc::new(TransferReceiptRepository::default());
+}
+
+/// A repository that stores the receipts of the completed transfers in stable memory, they are
+/// kept after the transfers themselves are archived.
+#[derive(Default, Debug)]
+pub struct TransferReceiptRepository {}
+
+impl StableDb<TransferId, TransferReceipt, VirtualMemory<Memory>> for TransferReceiptRepository {
+    fn with_db<F, R>(f: F) -> R
+    where
+        F: FnOnce(&mut StableBTreeMap<TransferId, TransferReceipt, VirtualMemory<Memory>>) -> R,
+    {
+        DB.with(|m| f(&mut m.borrow_mut()))
+    }
+}
+
+impl Repository<TransferId, TransferReceipt, VirtualMemory<Memory>> for TransferReceiptRepository {
+    fn insert(&self, key: TransferId, value: TransferReceipt) -> Option<TransferReceipt> {
+        observe_repository_write("transfer_receipts", &value);
+
+        DB.with(|m| m.borrow_mut().insert(key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::transfer_receipt_test_utils::mock_transfer_receipt;
+
+    #[test]
+    fn perform_crud() {
+        let repository = TransferReceiptRepository::default();
+        let receipt = mock_transfer_receipt();
+
+        assert!(repository.get(&receipt.transfer_id).is_none());
+
+        repository.insert(receipt.transfer_id, receipt.clone());
+
+        assert_eq!(repository.get(&receipt.transfer_id), Some(receipt.clone()));
+        assert!(repository.remove(&receipt.transfer_id).is_some());
+        assert!(repository.get(&receipt.transfer_id).is_none());
+    }
+}
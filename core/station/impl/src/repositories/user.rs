@@ -4,7 +4,11 @@ use super::indexes::{
 use crate::core::ic_cdk::api::print;
 use crate::{
     core::{
-        cache::Cache, metrics::USER_METRICS, observer::Observer, utils::format_unique_string,
+        cache::Cache,
+        metrics::{observe_repository_write, USER_METRICS},
+        observer::Observer,
+        request::invalidate_cached_evaluations,
+        utils::format_unique_string,
         with_memory_manager, Memory, USER_MEMORY_ID,
     },
     models::{
@@ -139,6 +143,8 @@ impl Repository<UserKey, User, VirtualMemory<Memory>> for UserRepository {
     }
 
     fn insert(&self, key: UserKey, value: User) -> Option<User> {
+        observe_repository_write("users", &value);
+
         DB.with(|m| {
             CACHE.with(|cache| cache.borrow_mut().insert(key.id, value.clone()));
 
@@ -153,6 +159,13 @@ impl Repository<UserKey, User, VirtualMemory<Memory>> for UserRepository {
 
             self.save_entry_indexes(&value, prev.as_ref());
 
+            // The approvers of the quorums depend on the status and the groups of the users.
+            if prev.as_ref().map_or(true, |prev| {
+                prev.status != value.status || prev.groups != value.groups
+            }) {
+                invalidate_cached_evaluations();
+            }
+
             let args = (value, prev);
             self.change_observer.notify(&args);
 
@@ -177,6 +190,7 @@ impl Repository<UserKey, User, VirtualMemory<Memory>> for UserRepository {
 
             if let Some(prev) = &prev {
                 self.remove_entry_indexes(prev);
+                invalidate_cached_evaluations();
             }
 
             if let Some(prev) = &prev {
@@ -1,7 +1,10 @@
 use super::indexes::unique_index::UniqueIndexRepository;
 use crate::{
     core::{
-        cache::Cache, ic_cdk::api::print, metrics::USER_GROUP_METRICS, utils::format_unique_string,
+        cache::Cache,
+        ic_cdk::api::print,
+        metrics::{observe_repository_write, USER_GROUP_METRICS},
+        utils::format_unique_string,
         with_memory_manager, Memory, USER_GROUP_MEMORY_ID,
     },
     models::{indexes::unique_index::UniqueIndexKey, UserGroup, UserGroupId},
@@ -103,6 +106,8 @@ impl Repository<UUID, UserGroup, VirtualMemory<Memory>> for UserGroupRepository
     }
 
     fn insert(&self, key: UserGroupId, value: UserGroup) -> Option<UserGroup> {
+        observe_repository_write("user_groups", &value);
+
         DB.with(|m| {
             CACHE.with(|cache| cache.borrow_mut().insert(key, value.clone()));
 
@@ -2,34 +2,47 @@ use crate::{
     core::{
         authorization::Authorization,
         generate_uuid_v4,
-        ic_cdk::next_time,
+        ic_cdk::{api::id as station_canister_id, next_time},
         read_system_info,
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         write_system_info, CallContext, ACCOUNT_BALANCE_FRESHNESS_IN_MS,
     },
     errors::AccountError,
-    factories::blockchains::BlockchainApiFactory,
+    factories::blockchains::{
+        icrc1_balance_of, icrc1_decimals, icrc1_symbol, BlockchainApi, BlockchainApiFactory, CkBtc,
+        CyclesLedger, Icrc7, InternetComputer,
+    },
     mappers::{account::AccountMapper, HelperMapper},
     models::{
+        permission::Allow,
         request_policy_rule::RequestPolicyRuleInput,
         request_specifier::RequestSpecifier,
         resource::{AccountResourceAction, Resource, ResourceId, ResourceIds},
-        Account, AccountBalance, AccountCallerPrivileges, AccountId, AddAccountOperationInput,
-        AddRequestPolicyOperationInput, Blockchain, BlockchainStandard, CycleObtainStrategy,
-        EditAccountOperationInput, EditPermissionOperationInput,
+        Account, AccountAsset, AccountBalance, AccountCallerPrivileges, AccountDiscovery,
+        AccountId, AccountSubaccount, AddAccountOperationInput, AddRequestPolicyOperationInput,
+        Blockchain, BlockchainStandard, CycleObtainStrategy, DiscoveredAccount,
+        EditAccountOperationInput, EditPermissionOperationInput, IcrcAccount, Metadata,
+        NetworkProfile, TrustedDestination, ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+        ACCOUNT_METADATA_SYMBOL_KEY,
     },
     repositories::{AccountRepository, AccountWhereClause, ACCOUNT_REPOSITORY},
     services::{
         permission::{PermissionService, PERMISSION_SERVICE},
-        RequestPolicyService, REQUEST_POLICY_SERVICE,
+        ExchangeRateService, RequestPolicyService, ASSET_SERVICE, EXCHANGE_RATE_SERVICE,
+        REQUEST_POLICY_SERVICE,
     },
 };
+use candid::Principal;
 use lazy_static::lazy_static;
+use num_bigint::BigUint;
 use orbit_essentials::{
     api::ServiceResult, model::ModelValidator, repository::Repository, types::UUID,
 };
-use station_api::{AccountBalanceDTO, FetchAccountBalancesInput, ListAccountsInput};
-use std::sync::Arc;
+use station_api::{
+    AccountBalanceDTO, DiscoverAccountsInput, FetchAccountBalancesInput, ListAccountsInput,
+    ListNftsInput,
+};
+use std::{collections::BTreeMap, sync::Arc};
 use uuid::Uuid;
 
 use super::SYSTEM_SERVICE;
@@ -39,6 +52,7 @@ lazy_static! {
         Arc::clone(&REQUEST_POLICY_SERVICE),
         Arc::clone(&PERMISSION_SERVICE),
         Arc::clone(&ACCOUNT_REPOSITORY),
+        Arc::clone(&EXCHANGE_RATE_SERVICE),
     ));
 }
 
@@ -47,21 +61,25 @@ pub struct AccountService {
     request_policy_service: Arc<RequestPolicyService>,
     permission_service: Arc<PermissionService>,
     account_repository: Arc<AccountRepository>,
+    exchange_rate_service: Arc<ExchangeRateService>,
 }
 
 impl AccountService {
     const DEFAULT_ACCOUNT_LIST_LIMIT: u16 = 50;
     const MAX_ACCOUNT_LIST_LIMIT: u16 = 1000;
+    const MAX_DISCOVERY_LEDGERS: u8 = 10;
 
     pub fn new(
         request_policy_service: Arc<RequestPolicyService>,
         permission_service: Arc<PermissionService>,
         account_repository: Arc<AccountRepository>,
+        exchange_rate_service: Arc<ExchangeRateService>,
     ) -> Self {
         Self {
             request_policy_service,
             permission_service,
             account_repository,
+            exchange_rate_service,
         }
     }
 
@@ -144,7 +162,7 @@ impl AccountService {
             })?
         }
         let blockchain_api =
-            BlockchainApiFactory::build(&input.blockchain.clone(), &input.standard.clone())?;
+            BlockchainApiFactory::build(&input.blockchain, &input.standard, &input.network)?;
         let mut new_account =
             AccountMapper::from_create_input(input.to_owned(), *uuid.as_bytes(), None)?;
 
@@ -155,6 +173,15 @@ impl AccountService {
             new_account.address = account_address;
         }
 
+        // Blockchains that are bridged to the account ledger (e.g. BTC for ckBTC) expose their own
+        // deposit addresses, which are stored in the account metadata so they can be shared.
+        let deposit_addresses = blockchain_api.deposit_addresses(&new_account).await?;
+        if !deposit_addresses.is_empty() {
+            let mut metadata = new_account.metadata.as_btreemap().to_owned();
+            metadata.extend(deposit_addresses);
+            new_account.metadata = Metadata::new(metadata);
+        }
+
         if let Some(criteria) = &input.transfer_request_policy {
             criteria.validate()?;
         };
@@ -187,6 +214,7 @@ impl AccountService {
                             *uuid.as_bytes()
                         ])),
                         rule: policy_rule.clone(),
+                        expiration_period_secs: None,
                     })?;
 
             new_account.transfer_request_policy_id = Some(transfer_request_policy.id);
@@ -201,6 +229,7 @@ impl AccountService {
                             *uuid.as_bytes()
                         ])),
                         rule: policy_rule.to_owned(),
+                        expiration_period_secs: None,
                     })?;
 
             new_account.configs_request_policy_id = Some(configs_request_policy.id);
@@ -304,6 +333,26 @@ impl AccountService {
         if let Some(permission) = &input.transfer_permission {
             permission.validate()?;
         };
+        if let Some(webhooks) = &input.webhooks {
+            account.webhooks = webhooks.to_owned();
+        }
+        if let Some(asset_ids) = &input.assets {
+            // the assets that are still held keep their last fetched balance
+            account.assets = asset_ids
+                .iter()
+                .map(|asset_id| {
+                    account
+                        .assets
+                        .iter()
+                        .find(|held| held.asset_id == *asset_id)
+                        .cloned()
+                        .unwrap_or(AccountAsset {
+                            asset_id: *asset_id,
+                            balance: None,
+                        })
+                })
+                .collect();
+        }
 
         if let Some(transfer_request_policy_input) = input.transfer_request_policy {
             self.request_policy_service.handle_policy_change(
@@ -367,6 +416,145 @@ impl AccountService {
         Ok(account)
     }
 
+    /// Replaces the trusted destinations of the account, whose transfers can then be auto approved
+    /// by the `TrustedDestination` policy rule.
+    pub fn set_trusted_destinations(
+        &self,
+        account_id: &AccountId,
+        trusted_destinations: Vec<TrustedDestination>,
+    ) -> ServiceResult<Account> {
+        let mut account = self.get_account(account_id)?;
+
+        account.trusted_destinations = trusted_destinations;
+        account.validate()?;
+
+        account.last_modification_timestamp = next_time();
+        self.account_repository
+            .insert(account.to_key(), account.to_owned());
+
+        Ok(account)
+    }
+
+    /// Derives a new ledger subaccount for the account, which can be used as an additional deposit
+    /// address (e.g. per department), and returns it.
+    pub async fn derive_subaccount(
+        &self,
+        account_id: &AccountId,
+        name: String,
+    ) -> ServiceResult<AccountSubaccount> {
+        let account = self.get_account(account_id)?;
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)?;
+        let index = account.next_subaccount_index();
+        let address = blockchain_api
+            .generate_subaccount_address(&account, index)
+            .await?;
+
+        // the account is read again since it could have changed while the address was generated
+        let mut account = self.get_account(account_id)?;
+        let subaccount = AccountSubaccount {
+            index,
+            name,
+            address,
+            balance: None,
+        };
+
+        account.subaccounts.push(subaccount.clone());
+        account.validate()?;
+
+        account.last_modification_timestamp = next_time();
+        self.account_repository
+            .insert(account.to_key(), account.to_owned());
+
+        Ok(subaccount)
+    }
+
+    /// Fetches the balance of the account, which is the sum of the balances of its main address and
+    /// its subaccounts, the balances of the subaccounts are updated on the account.
+    async fn fetch_aggregated_balance(
+        &self,
+        blockchain_api: &dyn BlockchainApi,
+        account: &mut Account,
+    ) -> ServiceResult<BigUint> {
+        let mut balance = blockchain_api.balance(account).await?;
+
+        for position in 0..account.subaccounts.len() {
+            let subaccount_balance = blockchain_api
+                .subaccount_balance(account, account.subaccounts[position].index)
+                .await?;
+
+            balance += &subaccount_balance;
+            account.subaccounts[position].balance = Some(AccountBalance {
+                balance: candid::Nat(subaccount_balance),
+                last_modification_timestamp: next_time(),
+            });
+        }
+
+        Ok(balance)
+    }
+
+    /// Fetches the balance of the account and of the assets it holds from the blockchain and caches
+    /// them on the account, their modification timestamp records when they were refreshed.
+    pub async fn refresh_account_balance(
+        &self,
+        account_id: &AccountId,
+    ) -> ServiceResult<AccountBalance> {
+        let mut account = self.get_account(account_id)?;
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)?;
+        let fetched_balance = self
+            .fetch_aggregated_balance(blockchain_api.as_ref(), &mut account)
+            .await?;
+        let balance = AccountBalance {
+            balance: candid::Nat(fetched_balance),
+            last_modification_timestamp: next_time(),
+        };
+
+        let mut asset_balances = Vec::new();
+        for held in account.assets.iter() {
+            let mut holder = account.for_asset(Some(&held.asset_id))?;
+            let asset_api =
+                BlockchainApiFactory::build(&holder.blockchain, &holder.standard, &holder.network)?;
+            let fetched_asset_balance = self
+                .fetch_aggregated_balance(asset_api.as_ref(), &mut holder)
+                .await?;
+
+            asset_balances.push((
+                held.asset_id,
+                AccountBalance {
+                    balance: candid::Nat(fetched_asset_balance),
+                    last_modification_timestamp: next_time(),
+                },
+            ));
+        }
+
+        // the account is read again since it could have changed while the balance was fetched
+        let mut latest_account = self.get_account(account_id)?;
+        for subaccount in latest_account.subaccounts.iter_mut() {
+            if let Some(fetched) = account
+                .subaccounts
+                .iter()
+                .find(|fetched| fetched.index == subaccount.index)
+            {
+                subaccount.balance = fetched.balance.clone();
+            }
+        }
+        for held in latest_account.assets.iter_mut() {
+            if let Some((_, asset_balance)) = asset_balances
+                .iter()
+                .find(|(asset_id, _)| *asset_id == held.asset_id)
+            {
+                held.balance = Some(asset_balance.clone());
+            }
+        }
+        latest_account.balance = Some(balance.clone());
+
+        self.account_repository
+            .insert(latest_account.to_key(), latest_account);
+
+        Ok(balance)
+    }
+
     /// Returns the balances of the requested accounts.
     ///
     /// If the balance is considered fresh it will be returned, otherwise it will be fetched from the blockchain.
@@ -389,7 +577,7 @@ impl AccountService {
             .find_by_ids(account_ids.iter().map(|id| *id.as_bytes()).collect());
 
         let mut balances = Vec::new();
-        for mut account in accounts {
+        for account in accounts {
             let balance_considered_fresh = match &account.balance {
                 Some(balance) => {
                     let balance_age_ns = next_time() - balance.last_modification_timestamp;
@@ -397,35 +585,243 @@ impl AccountService {
                 }
                 None => false,
             };
-            let balance: AccountBalance = match (&account.balance, balance_considered_fresh) {
+            let blockchain_api = BlockchainApiFactory::build(
+                &account.blockchain,
+                &account.standard,
+                &account.network,
+            )?;
+            let (balance, account) = match (&account.balance, balance_considered_fresh) {
                 (None, _) | (_, false) => {
-                    let blockchain_api =
-                        BlockchainApiFactory::build(&account.blockchain, &account.standard)?;
-                    let fetched_balance = blockchain_api.balance(&account).await?;
-                    let new_balance = AccountBalance {
-                        balance: candid::Nat(fetched_balance),
-                        last_modification_timestamp: next_time(),
-                    };
-
-                    account.balance = Some(new_balance.clone());
-
-                    self.account_repository
-                        .insert(account.to_key(), account.clone());
+                    let balance = self.refresh_account_balance(&account.id).await?;
 
-                    new_balance
+                    // read again for the balances of the held assets, refreshed along the account
+                    (balance, self.get_account(&account.id)?)
                 }
-                (Some(balance), _) => balance.to_owned(),
+                (Some(balance), _) => (balance.to_owned(), account),
             };
 
+            // Pending deposits are informative only, failing to fetch them must not hide the balance.
+            let pending_deposits = blockchain_api
+                .pending_deposits(&account)
+                .await
+                .unwrap_or_default();
+
+            let fiat_values = self.exchange_rate_service.fiat_values(
+                &account.symbol,
+                &balance.balance,
+                account.decimals,
+            );
+
             balances.push(AccountMapper::to_balance_dto(
                 balance,
-                account.decimals,
-                account.id,
+                &account,
+                pending_deposits,
+                fiat_values,
             ));
         }
 
         Ok(balances)
     }
+
+    /// Returns the ids of the tokens held by the given NFT account, in the order of its collection.
+    pub async fn list_nfts(&self, input: ListNftsInput) -> ServiceResult<Vec<candid::Nat>> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.get_account(account_id.as_bytes())?;
+
+        if account.standard != BlockchainStandard::ICRC7 {
+            Err(AccountError::ValidationError {
+                info: format!(
+                    "The account {} does not hold NFTs, its standard is {}",
+                    account_id.hyphenated(),
+                    account.standard
+                ),
+            })?
+        }
+
+        let token_ids = Icrc7::create(account.network)
+            .list_nfts(&account, input.prev, input.take)
+            .await?;
+
+        Ok(token_ids)
+    }
+
+    /// Checks the balances of the station derivable addresses on the given ledgers, which are the
+    /// default account of the station and the subaccounts of its existing accounts, and proposes an
+    /// `AddAccount` operation for each non-empty balance that is not yet tracked by a station account.
+    ///
+    /// Eases the migration of existing treasuries whose funds are already held by the station canister.
+    pub async fn discover_accounts(
+        &self,
+        input: DiscoverAccountsInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<AccountDiscovery> {
+        if input.ledger_canister_ids.is_empty()
+            || input.ledger_canister_ids.len() > Self::MAX_DISCOVERY_LEDGERS as usize
+        {
+            Err(AccountError::DiscoveryLedgersBatchRange {
+                min: 1,
+                max: Self::MAX_DISCOVERY_LEDGERS,
+            })?
+        }
+
+        let accounts = self.account_repository.list();
+        let mut candidates = vec![(None, IcrcAccount::new(station_canister_id(), None))];
+        candidates.extend(accounts.iter().map(|account| {
+            (
+                Some(account.id),
+                IcrcAccount::new(
+                    station_canister_id(),
+                    Some(InternetComputer::subaccount_from_station_account_id(
+                        &account.id,
+                    )),
+                ),
+            )
+        }));
+
+        let mut ledger_canister_ids = input.ledger_canister_ids;
+        ledger_canister_ids.sort();
+        ledger_canister_ids.dedup();
+
+        let mut discovery = AccountDiscovery::default();
+        for ledger_canister_id in ledger_canister_ids {
+            let (symbol, decimals) = match futures::join!(
+                icrc1_symbol(ledger_canister_id),
+                icrc1_decimals(ledger_canister_id)
+            ) {
+                (Ok(symbol), Ok(decimals)) => (symbol, decimals as u32),
+                (Err(err), _) | (_, Err(err)) => {
+                    discovery
+                        .failed_ledgers
+                        .push((ledger_canister_id, err.to_string()));
+                    continue;
+                }
+            };
+
+            let balances = futures::future::join_all(
+                candidates
+                    .iter()
+                    .map(|(_, address)| icrc1_balance_of(ledger_canister_id, address)),
+            )
+            .await;
+
+            for ((account_id, address), balance) in candidates.iter().zip(balances) {
+                let balance = match balance {
+                    Ok(balance) => balance,
+                    Err(err) => {
+                        discovery
+                            .failed_ledgers
+                            .push((ledger_canister_id, err.to_string()));
+                        break;
+                    }
+                };
+
+                let account =
+                    account_id.and_then(|id| accounts.iter().find(|account| account.id == id));
+
+                if balance == candid::Nat::from(0_u64)
+                    || account
+                        .is_some_and(|account| Self::tracks_ledger(account, &ledger_canister_id))
+                {
+                    continue;
+                }
+
+                discovery.accounts.push(DiscoveredAccount {
+                    ledger_canister_id,
+                    address: address.to_owned(),
+                    account_id: *account_id,
+                    proposed_operation: Self::propose_discovered_account(
+                        &ledger_canister_id,
+                        &symbol,
+                        account,
+                        ctx,
+                    ),
+                    symbol: symbol.to_owned(),
+                    decimals,
+                    balance,
+                });
+            }
+        }
+
+        Ok(discovery)
+    }
+
+    /// Returns the blockchain standard of the assets held by the given ledger.
+    fn ledger_standard(ledger_canister_id: &Principal) -> BlockchainStandard {
+        match *ledger_canister_id == InternetComputer::ledger_canister_id() {
+            true => BlockchainStandard::Native,
+            false => BlockchainStandard::ICRC1,
+        }
+    }
+
+    /// Whether the account already holds the asset of the given ledger.
+    fn tracks_ledger(account: &Account, ledger_canister_id: &Principal) -> bool {
+        account.blockchain == Blockchain::InternetComputer
+            && match Self::ledger_standard(ledger_canister_id) {
+                BlockchainStandard::Native => account.standard == BlockchainStandard::Native,
+                standard => {
+                    account.standard == standard
+                        && account
+                            .metadata
+                            .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+                            .is_some_and(|id| id == ledger_canister_id.to_text())
+                }
+            }
+    }
+
+    /// Builds the operation that adds an account for the discovered asset, accessible by the caller,
+    /// or `None` if the asset is not supported by the station.
+    fn propose_discovered_account(
+        ledger_canister_id: &Principal,
+        symbol: &str,
+        holder: Option<&Account>,
+        ctx: &CallContext,
+    ) -> Option<AddAccountOperationInput> {
+        let blockchain = Blockchain::InternetComputer;
+        let standard = Self::ledger_standard(ledger_canister_id);
+
+        BlockchainApiFactory::build(&blockchain, &standard, &NetworkProfile::default()).ok()?;
+
+        // The ICRC-1 adapter supports the ckBTC ledger, the cycles ledger, the discovered SNS ledgers
+        // and the registered ledgers.
+        if standard == BlockchainStandard::ICRC1
+            && *ledger_canister_id != CkBtc::ledger_canister_id()
+            && !CyclesLedger::is_cycles_ledger(ledger_canister_id)
+            && !ASSET_SERVICE.is_supported_sns_ledger(ledger_canister_id)
+            && !ASSET_SERVICE.is_registered_ledger(ledger_canister_id)
+        {
+            return None;
+        }
+
+        let mut metadata = BTreeMap::new();
+        if standard != BlockchainStandard::Native {
+            metadata.insert(ACCOUNT_METADATA_SYMBOL_KEY.to_string(), symbol.to_string());
+            metadata.insert(
+                ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+                ledger_canister_id.to_text(),
+            );
+        }
+
+        let allow = match ctx.user() {
+            Some(user) => Allow::users(vec![user.id]),
+            None => Allow::default(),
+        };
+
+        Some(AddAccountOperationInput {
+            name: match holder {
+                Some(holder) => format!("{} ({})", symbol, holder.name),
+                None => symbol.to_string(),
+            },
+            blockchain,
+            standard,
+            network: NetworkProfile::default(),
+            metadata: Metadata::new(metadata),
+            read_permission: allow.to_owned(),
+            configs_permission: allow.to_owned(),
+            transfer_permission: allow,
+            configs_request_policy: None,
+            transfer_request_policy: None,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -438,8 +834,9 @@ mod tests {
         models::{
             account_test_utils::mock_account, permission::Allow,
             request_policy_rule::RequestPolicyRule, request_specifier::UserSpecifier,
-            user_test_utils::mock_user, AddAccountOperation, AddAccountOperationInput, Blockchain,
-            BlockchainStandard, Metadata, User,
+            user_test_utils::mock_user, AccountWebhook, AddAccountOperation,
+            AddAccountOperationInput, Blockchain, BlockchainStandard, Metadata, NetworkProfile,
+            User,
         },
         repositories::UserRepository,
     };
@@ -487,6 +884,7 @@ mod tests {
                 name: "foo".to_string(),
                 blockchain: Blockchain::InternetComputer,
                 standard: BlockchainStandard::Native,
+                network: NetworkProfile::Mainnet,
                 metadata: Metadata::default(),
                 read_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -518,6 +916,7 @@ mod tests {
                 name: account.name,
                 blockchain: Blockchain::InternetComputer,
                 standard: BlockchainStandard::Native,
+                network: NetworkProfile::Mainnet,
                 metadata: Metadata::default(),
                 read_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -542,6 +941,7 @@ mod tests {
             name: "foo".to_string(),
             blockchain: Blockchain::InternetComputer,
             standard: BlockchainStandard::Native,
+            network: NetworkProfile::Mainnet,
             metadata: Metadata::default(),
             read_permission: Allow::users(vec![ctx.caller_user.id]),
             configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -629,6 +1029,7 @@ mod tests {
             name: "foo2".to_string(),
             blockchain: Blockchain::InternetComputer,
             standard: BlockchainStandard::Native,
+            network: NetworkProfile::Mainnet,
             metadata: Metadata::default(),
             read_permission: Allow::users(vec![ctx.caller_user.id]),
             configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -661,6 +1062,8 @@ mod tests {
             configs_permission: None,
             transfer_request_policy: None,
             configs_request_policy: None,
+            webhooks: None,
+            assets: None,
         };
 
         let result = ctx.service.edit_account(operation).await;
@@ -672,6 +1075,40 @@ mod tests {
         assert_eq!(updated_account.name, "test_edit");
     }
 
+    #[tokio::test]
+    async fn derive_subaccounts() {
+        let ctx = setup();
+        let account = mock_account();
+
+        ctx.repository.insert(account.to_key(), account.clone());
+
+        let marketing = ctx
+            .service
+            .derive_subaccount(&account.id, "Marketing".to_string())
+            .await
+            .unwrap();
+        let payroll = ctx
+            .service
+            .derive_subaccount(&account.id, "Payroll".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(marketing.index, 1);
+        assert_eq!(payroll.index, 2);
+        assert_ne!(marketing.address, payroll.address);
+        assert_eq!(
+            ctx.service.get_account(&account.id).unwrap().subaccounts,
+            vec![marketing, payroll]
+        );
+
+        let result = ctx
+            .service
+            .derive_subaccount(&account.id, "Payroll".to_string())
+            .await;
+
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn edit_account_with_duplicate_name_should_fail() {
         let ctx = setup();
@@ -691,6 +1128,8 @@ mod tests {
             configs_permission: None,
             transfer_request_policy: None,
             configs_request_policy: None,
+            webhooks: None,
+            assets: None,
         };
 
         let result = ctx.service.edit_account(operation).await;
@@ -707,6 +1146,7 @@ mod tests {
                 name: "foo".to_string(),
                 blockchain: Blockchain::InternetComputer,
                 standard: BlockchainStandard::ERC20,
+                network: NetworkProfile::Mainnet,
                 metadata: Metadata::default(),
                 read_permission: Allow::users(vec![ctx.caller_user.id]),
                 configs_permission: Allow::users(vec![ctx.caller_user.id]),
@@ -739,6 +1179,8 @@ mod tests {
             configs_permission: None,
             transfer_request_policy: None,
             configs_request_policy: None,
+            webhooks: None,
+            assets: None,
         };
 
         assert!(ctx.service.edit_account(base_input.clone()).await.is_ok());
@@ -786,5 +1228,115 @@ mod tests {
             })
             .await
             .expect_err("transfer_request_policy should be invalid");
+
+        ctx.service
+            .edit_account(EditAccountOperationInput {
+                webhooks: Some(vec![AccountWebhook {
+                    canister_id: Principal::management_canister(),
+                    method_name: "on_transfer".to_string(),
+                    secret: None,
+                }]),
+                ..base_input.clone()
+            })
+            .await
+            .expect_err("webhooks should be invalid");
+    }
+
+    #[tokio::test]
+    async fn fail_discover_accounts_with_too_many_ledgers() {
+        let ctx = setup();
+        let call_context = CallContext::new(ctx.caller_user.identities[0]);
+
+        let result = ctx
+            .service
+            .discover_accounts(
+                DiscoverAccountsInput {
+                    ledger_canister_ids: (0..=AccountService::MAX_DISCOVERY_LEDGERS)
+                        .map(|i| Principal::from_slice(&[i; 29]))
+                        .collect(),
+                },
+                &call_context,
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn discovered_account_is_tracked_by_matching_account() {
+        let ledger_canister_id = Principal::from_slice(&[7; 29]);
+        let mut account = mock_account();
+        account.blockchain = Blockchain::InternetComputer;
+        account.standard = BlockchainStandard::Native;
+
+        assert!(AccountService::tracks_ledger(
+            &account,
+            &InternetComputer::ledger_canister_id()
+        ));
+        assert!(!AccountService::tracks_ledger(
+            &account,
+            &ledger_canister_id
+        ));
+
+        account.standard = BlockchainStandard::ICRC1;
+        account.metadata = Metadata::new(BTreeMap::from([(
+            ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+            ledger_canister_id.to_text(),
+        )]));
+
+        assert!(AccountService::tracks_ledger(&account, &ledger_canister_id));
+    }
+
+    #[test]
+    fn proposes_account_for_supported_discovered_assets() {
+        let ctx = setup();
+        let call_context = CallContext::new(ctx.caller_user.identities[0]);
+
+        let proposal = AccountService::propose_discovered_account(
+            &InternetComputer::ledger_canister_id(),
+            "ICP",
+            None,
+            &call_context,
+        )
+        .expect("ICP should be supported");
+
+        assert_eq!(proposal.name, "ICP");
+        assert_eq!(proposal.standard, BlockchainStandard::Native);
+        assert_eq!(
+            proposal.transfer_permission,
+            Allow::users(vec![ctx.caller_user.id])
+        );
+
+        let proposal = AccountService::propose_discovered_account(
+            &CkBtc::ledger_canister_id(),
+            CkBtc::SYMBOL,
+            None,
+            &call_context,
+        )
+        .expect("ckBTC should be supported");
+
+        assert_eq!(proposal.standard, BlockchainStandard::ICRC1);
+        assert_eq!(
+            proposal
+                .metadata
+                .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY),
+            Some(CkBtc::LEDGER_CANISTER_ID.to_string())
+        );
+
+        assert!(AccountService::propose_discovered_account(
+            &CyclesLedger::ledger_canister_id(),
+            CyclesLedger::SYMBOL,
+            None,
+            &call_context,
+        )
+        .is_some());
+
+        assert!(AccountService::propose_discovered_account(
+            &Principal::from_slice(&[7; 29]),
+            "TKN",
+            None,
+            &call_context,
+        )
+        .is_none());
     }
 }
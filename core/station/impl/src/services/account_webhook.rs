@@ -0,0 +1,175 @@
+use crate::{
+    core::{
+        ic_cdk::api::{id as self_canister_id, print},
+        observer::Observer,
+    },
+    models::{Account, AccountWebhook, Transfer, TransferStatus},
+    repositories::{AccountRepository, ACCOUNT_REPOSITORY},
+};
+use ic_cdk::api::call::call_raw;
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use station_api::{TransferWebhookCallbackInput, TransferWebhookEventDTO};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref ACCOUNT_WEBHOOK_SERVICE: Arc<AccountWebhookService> =
+        Arc::new(AccountWebhookService::new(Arc::clone(&ACCOUNT_REPOSITORY)));
+}
+
+/// Calls the webhooks of an account when one of its transfers completes or fails.
+#[derive(Default, Debug)]
+pub struct AccountWebhookService {
+    account_repository: Arc<AccountRepository>,
+}
+
+impl AccountWebhookService {
+    pub fn new(account_repository: Arc<AccountRepository>) -> Self {
+        Self { account_repository }
+    }
+
+    /// Returns the webhooks of the account of the transfer with the signed event to send them.
+    pub fn callbacks(
+        &self,
+        transfer: &Transfer,
+    ) -> Vec<(AccountWebhook, TransferWebhookCallbackInput)> {
+        let Some(account) = self
+            .account_repository
+            .get(&Account::key(transfer.from_account))
+        else {
+            return Vec::new();
+        };
+
+        if account.webhooks.is_empty() {
+            return Vec::new();
+        }
+
+        let event = candid::encode_one(TransferWebhookEventDTO {
+            station_id: self_canister_id(),
+            transfer: transfer.to_dto(),
+        })
+        .expect("Failed to encode the transfer webhook event");
+
+        account
+            .webhooks
+            .into_iter()
+            .map(|webhook| {
+                let signature = webhook.sign(&event);
+
+                (
+                    webhook,
+                    TransferWebhookCallbackInput {
+                        event: event.clone(),
+                        signature,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Calls the webhooks of the account of the transfer, the failed calls are logged and not retried.
+    pub async fn notify_transfer(&self, transfer: &Transfer) {
+        for (webhook, input) in self.callbacks(transfer) {
+            let arg = candid::encode_one(input).expect("Failed to encode the webhook callback");
+
+            if let Err((_, error)) =
+                call_raw(webhook.canister_id, &webhook.method_name, arg, 0).await
+            {
+                print(format!(
+                    "Failed to call the webhook {}.{} for transfer {}: {}",
+                    webhook.canister_id,
+                    webhook.method_name,
+                    Uuid::from_bytes(transfer.id).hyphenated(),
+                    error
+                ));
+            }
+        }
+    }
+}
+
+fn is_finished(transfer: &Transfer) -> bool {
+    matches!(
+        transfer.status,
+        TransferStatus::Completed { .. } | TransferStatus::Failed { .. }
+    )
+}
+
+pub fn account_webhook_observes_insert_transfer(
+    observer: &mut Observer<(Transfer, Option<Transfer>)>,
+) {
+    observer.add_listener(Box::new(|(transfer, prev)| {
+        if !is_finished(transfer) || prev.as_ref().is_some_and(is_finished) {
+            return;
+        }
+
+        let transfer = transfer.to_owned();
+        crate::core::ic_cdk::spawn(async move {
+            ACCOUNT_WEBHOOK_SERVICE.notify_transfer(&transfer).await;
+        });
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::utils::hmac_sha256,
+        models::{account_test_utils::add_account, transfer_test_utils::mock_transfer},
+    };
+    use candid::Principal;
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn signs_the_event_for_each_webhook() {
+        let mut account = add_account(&[1; 16]);
+        account.webhooks = vec![
+            AccountWebhook {
+                canister_id: Principal::from_slice(&[2; 29]),
+                method_name: "on_transfer".to_string(),
+                secret: Some("a-shared-secret-of-the-erp".to_string()),
+            },
+            AccountWebhook {
+                canister_id: Principal::from_slice(&[3; 29]),
+                method_name: "on_transfer".to_string(),
+                secret: None,
+            },
+        ];
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut transfer = mock_transfer();
+        transfer.from_account = account.id;
+        transfer.status = TransferStatus::Failed {
+            reason: "insufficient funds".to_string(),
+        };
+
+        let callbacks = ACCOUNT_WEBHOOK_SERVICE.callbacks(&transfer);
+
+        assert_eq!(callbacks.len(), 2);
+
+        let (_, signed) = &callbacks[0];
+        assert_eq!(
+            signed.signature,
+            Some(hex::encode(hmac_sha256(
+                b"a-shared-secret-of-the-erp",
+                &signed.event
+            )))
+        );
+        assert_eq!(callbacks[1].1.signature, None);
+
+        let event = candid::decode_one::<TransferWebhookEventDTO>(&signed.event).unwrap();
+        assert_eq!(
+            event.transfer.id,
+            Uuid::from_bytes(transfer.id).hyphenated().to_string()
+        );
+    }
+
+    #[test]
+    fn has_no_callbacks_without_webhooks() {
+        let account = add_account(&[1; 16]);
+        let mut transfer = mock_transfer();
+        transfer.from_account = account.id;
+
+        assert!(ACCOUNT_WEBHOOK_SERVICE.callbacks(&transfer).is_empty());
+    }
+}
@@ -2,6 +2,7 @@ use crate::{
     core::{
         authorization::Authorization,
         generate_uuid_v4,
+        ic_cdk::next_time,
         utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
@@ -10,35 +11,46 @@ use crate::{
     models::{
         resource::{Resource, ResourceAction, ResourceId},
         AddAddressBookEntryOperationInput, AddressBookEntry, AddressBookEntryCallerPrivileges,
-        AddressBookEntryId, EditAddressBookEntryOperationInput, ListAddressBookEntriesInput,
+        AddressBookEntryId, AddressBookLabel, EditAddressBookEntryOperationInput,
+        ListAddressBookEntriesInput, ManageAddressBookLabelsOperationInput,
         RemoveAddressBookEntryOperationInput,
     },
-    repositories::{AddressBookRepository, AddressBookWhereClause, ADDRESS_BOOK_REPOSITORY},
+    repositories::{
+        AddressBookLabelRepository, AddressBookRepository, AddressBookWhereClause,
+        ADDRESS_BOOK_LABEL_REPOSITORY, ADDRESS_BOOK_REPOSITORY,
+    },
 };
 use lazy_static::lazy_static;
 use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
 use station_api::PaginationInput;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use uuid::Uuid;
 
 lazy_static! {
-    pub static ref ADDRESS_BOOK_SERVICE: Arc<AddressBookService> = Arc::new(
-        AddressBookService::new(Arc::clone(&ADDRESS_BOOK_REPOSITORY),)
-    );
+    pub static ref ADDRESS_BOOK_SERVICE: Arc<AddressBookService> =
+        Arc::new(AddressBookService::new(
+            Arc::clone(&ADDRESS_BOOK_REPOSITORY),
+            Arc::clone(&ADDRESS_BOOK_LABEL_REPOSITORY),
+        ));
 }
 
 #[derive(Default, Debug)]
 pub struct AddressBookService {
     address_book_repository: Arc<AddressBookRepository>,
+    address_book_label_repository: Arc<AddressBookLabelRepository>,
 }
 
 impl AddressBookService {
     pub const DEFAULT_ENTRIES_LIMIT: u16 = 100;
     pub const MAX_LIST_ENTRIES_LIMIT: u16 = 1000;
 
-    pub fn new(address_book_repository: Arc<AddressBookRepository>) -> Self {
+    pub fn new(
+        address_book_repository: Arc<AddressBookRepository>,
+        address_book_label_repository: Arc<AddressBookLabelRepository>,
+    ) -> Self {
         Self {
             address_book_repository,
+            address_book_label_repository,
         }
     }
 
@@ -108,6 +120,7 @@ impl AddressBookService {
 
         let new_entry = AddressBookMapper::from_create_input(input.to_owned(), *uuid.as_bytes())?;
         new_entry.validate()?;
+        self.ensure_labels_defined(&new_entry.labels)?;
 
         if let Some(v) = self
             .address_book_repository
@@ -141,6 +154,15 @@ impl AddressBookService {
             entry.metadata.change(change_metadata);
         }
 
+        if let Some(requires_memo) = input.requires_memo {
+            entry.requires_memo = requires_memo;
+        }
+
+        if let Some(labels) = input.labels {
+            self.ensure_labels_defined(&labels)?;
+            entry.labels = labels;
+        }
+
         entry.validate()?;
 
         self.address_book_repository
@@ -160,6 +182,133 @@ impl AddressBookService {
 
         Ok(entry)
     }
+
+    /// Returns the labels of the address book taxonomy sorted by name.
+    pub fn list_labels(&self) -> Vec<AddressBookLabel> {
+        // the repository is keyed by the lowercased name, so its order is already the name order
+        self.address_book_label_repository.list()
+    }
+
+    /// Defines and removes labels of the address book taxonomy, returns the resulting labels.
+    pub fn manage_labels(
+        &self,
+        input: ManageAddressBookLabelsOperationInput,
+    ) -> ServiceResult<Vec<AddressBookLabel>> {
+        let now = next_time();
+        let labels = input
+            .set_labels
+            .into_iter()
+            .map(|label| AddressBookLabel {
+                name: label.name,
+                description: label.description,
+                last_modification_timestamp: now,
+            })
+            .collect::<Vec<_>>();
+
+        for label in &labels {
+            label.validate()?;
+
+            if input
+                .remove_labels
+                .iter()
+                .any(|removed| AddressBookLabel::key(removed) == label.to_key())
+            {
+                Err(AddressBookError::ValidationError {
+                    info: format!("Label `{}` cannot be both set and removed", label.name),
+                })?;
+            }
+        }
+
+        for removed in &input.remove_labels {
+            if self
+                .address_book_label_repository
+                .get(&AddressBookLabel::key(removed))
+                .is_none()
+            {
+                Err(AddressBookError::UndefinedLabel {
+                    label: removed.to_owned(),
+                })?;
+            }
+
+            let entries = self
+                .address_book_repository
+                .find_where(AddressBookWhereClause {
+                    ids: None,
+                    addresses: None,
+                    blockchain: None,
+                    labels: Some(vec![removed.to_owned()]),
+                })
+                .len();
+
+            if entries > 0 {
+                Err(AddressBookError::LabelInUse {
+                    label: removed.to_owned(),
+                    entries,
+                })?;
+            }
+        }
+
+        let new_labels = labels
+            .iter()
+            .filter(|label| {
+                self.address_book_label_repository
+                    .get(&label.to_key())
+                    .is_none()
+            })
+            .map(|label| label.to_key())
+            .collect::<HashSet<_>>()
+            .len();
+        let removed_labels = input
+            .remove_labels
+            .iter()
+            .map(|removed| AddressBookLabel::key(removed))
+            .collect::<HashSet<_>>()
+            .len();
+
+        if self.address_book_label_repository.len() + new_labels - removed_labels
+            > AddressBookLabel::MAX_LABELS
+        {
+            Err(AddressBookError::ValidationError {
+                info: format!(
+                    "The address book cannot have more than {} labels",
+                    AddressBookLabel::MAX_LABELS
+                ),
+            })?;
+        }
+
+        for removed in &input.remove_labels {
+            self.address_book_label_repository
+                .remove(&AddressBookLabel::key(removed));
+        }
+
+        for label in labels {
+            self.address_book_label_repository
+                .insert(label.to_key(), label);
+        }
+
+        Ok(self.list_labels())
+    }
+
+    /// Checks that the labels are defined in the taxonomy, any label is accepted while it is empty.
+    fn ensure_labels_defined(&self, labels: &[String]) -> Result<(), AddressBookError> {
+        if self.address_book_label_repository.is_empty() {
+            return Ok(());
+        }
+
+        for label in labels {
+            if self
+                .address_book_label_repository
+                .get(&AddressBookLabel::key(label))
+                .is_none()
+            {
+                return Err(AddressBookError::UndefinedLabel {
+                    label: label.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -169,7 +318,8 @@ mod tests {
         core::test_utils,
         models::{
             address_book_entry_test_utils::mock_address_book_entry, AddAddressBookEntryOperation,
-            AddAddressBookEntryOperationInput, Blockchain, ChangeMetadata, Metadata, MetadataItem,
+            AddAddressBookEntryOperationInput, AddressBookLabelInput, Blockchain, ChangeMetadata,
+            Metadata, MetadataItem,
         },
     };
     use station_api::MetadataDTO;
@@ -201,6 +351,7 @@ mod tests {
                 blockchain: Blockchain::InternetComputer,
                 metadata: address_book_entry.metadata.clone().into(),
                 labels: vec![],
+                requires_memo: false,
             },
         };
 
@@ -246,6 +397,7 @@ mod tests {
                 metadata.as_btreemap().to_owned(),
             )),
             labels: None,
+            requires_memo: None,
         };
         let result = ctx.service.edit_entry(operation).await;
         assert!(result.is_ok());
@@ -286,6 +438,7 @@ mod tests {
                 diff_metadata_dto.as_btreemap().to_owned(),
             )),
             labels: None,
+            requires_memo: None,
         };
         let result = ctx.service.edit_entry(operation).await;
         assert!(result.is_ok());
@@ -303,6 +456,7 @@ mod tests {
             address_owner: None,
             change_metadata: Some(ChangeMetadata::RemoveKeys(remove_keys)),
             labels: None,
+            requires_memo: None,
         };
         let result = ctx.service.edit_entry(operation).await;
         assert!(result.is_ok());
@@ -331,4 +485,102 @@ mod tests {
             .get_entry_by_id(&address_book_entry.id)
             .unwrap_err();
     }
+
+    fn label_input(name: &str) -> AddressBookLabelInput {
+        AddressBookLabelInput {
+            name: name.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn manage_labels() {
+        let ctx = setup();
+
+        let labels = ctx
+            .service
+            .manage_labels(ManageAddressBookLabelsOperationInput {
+                set_labels: vec![label_input("vendor"), label_input("Payroll")],
+                remove_labels: vec![],
+            })
+            .unwrap();
+
+        assert_eq!(
+            labels.iter().map(|l| l.name.as_str()).collect::<Vec<_>>(),
+            vec!["Payroll", "vendor"]
+        );
+
+        let labels = ctx
+            .service
+            .manage_labels(ManageAddressBookLabelsOperationInput {
+                set_labels: vec![],
+                remove_labels: vec!["payroll".to_string()],
+            })
+            .unwrap();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "vendor");
+
+        // removing a label that is not defined fails
+        ctx.service
+            .manage_labels(ManageAddressBookLabelsOperationInput {
+                set_labels: vec![],
+                remove_labels: vec!["payroll".to_string()],
+            })
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn entries_must_use_the_defined_labels() {
+        let ctx = setup();
+        let address_book_entry = mock_address_book_entry();
+
+        ctx.repository
+            .insert(address_book_entry.to_key(), address_book_entry.clone());
+
+        let edit_labels = |labels: Vec<&str>| EditAddressBookEntryOperationInput {
+            address_book_entry_id: address_book_entry.id,
+            address_owner: None,
+            change_metadata: None,
+            labels: Some(labels.into_iter().map(String::from).collect()),
+            requires_memo: None,
+        };
+
+        // the labels are free-form until the taxonomy is defined
+        ctx.service
+            .edit_entry(edit_labels(vec!["anything"]))
+            .await
+            .unwrap();
+
+        ctx.service
+            .manage_labels(ManageAddressBookLabelsOperationInput {
+                set_labels: vec![label_input("vendor")],
+                remove_labels: vec![],
+            })
+            .unwrap();
+
+        ctx.service
+            .edit_entry(edit_labels(vec!["anything"]))
+            .await
+            .unwrap_err();
+
+        let entry = ctx
+            .service
+            .edit_entry(edit_labels(vec!["Vendor"]))
+            .await
+            .unwrap();
+
+        assert_eq!(entry.labels, vec!["Vendor".to_string()]);
+
+        // a label that is still used by an entry cannot be removed
+        let error = ctx
+            .service
+            .manage_labels(ManageAddressBookLabelsOperationInput {
+                set_labels: vec![],
+                remove_labels: vec!["vendor".to_string()],
+            })
+            .unwrap_err();
+
+        assert_eq!(error.code, "LABEL_IN_USE");
+    }
 }
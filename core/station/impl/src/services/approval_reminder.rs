@@ -0,0 +1,145 @@
+use crate::{
+    core::{ic_cdk::api::print, read_system_info},
+    models::{Request, User, UserId},
+    repositories::{UserRepository, USER_REPOSITORY},
+};
+use candid::{CandidType, Deserialize, Principal};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ApiResult, repository::Repository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref APPROVAL_REMINDER_SERVICE: Arc<ApprovalReminderService> =
+        Arc::new(ApprovalReminderService::new(Arc::clone(&USER_REPOSITORY)));
+}
+
+/// The input of the `notify_approvers` method of the control panel.
+#[derive(CandidType, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NotifyApproversInput {
+    pub request_id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub approvers: Vec<Principal>,
+}
+
+/// The reply of the control panel, the number of users it reminded is not used by the station.
+#[derive(CandidType, Deserialize, Debug)]
+struct NotifyApproversResponse {}
+
+/// Forwards the requests awaiting approval to the control panel, which reminds the approvers on the
+/// e-mail and webhook channels they configured there.
+#[derive(Default, Debug)]
+pub struct ApprovalReminderService {
+    user_repository: Arc<UserRepository>,
+}
+
+impl ApprovalReminderService {
+    pub fn new(user_repository: Arc<UserRepository>) -> Self {
+        Self { user_repository }
+    }
+
+    /// Returns the control panel to forward the request to with the reminder for the identities of
+    /// the approvers, `None` if the reminders are not forwarded or there is nobody to remind.
+    pub fn reminder(
+        &self,
+        request: &Request,
+        approvers: &[UserId],
+    ) -> Option<(Principal, NotifyApproversInput)> {
+        let control_panel_id = read_system_info()
+            .get_approval_reminders()
+            .control_panel_id?;
+
+        let identities = approvers
+            .iter()
+            .filter_map(|approver| self.user_repository.get(&User::key(*approver)))
+            .flat_map(|user| user.identities)
+            .collect::<Vec<_>>();
+
+        if identities.is_empty() {
+            return None;
+        }
+
+        Some((
+            control_panel_id,
+            NotifyApproversInput {
+                request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+                title: request.title.to_owned(),
+                summary: request.summary.to_owned(),
+                approvers: identities,
+            },
+        ))
+    }
+
+    /// Forwards the request to the control panel, a failed forward is logged and not retried since the
+    /// approvers are still notified by the station.
+    pub async fn forward(&self, request: &Request, approvers: &[UserId]) {
+        let Some((control_panel_id, input)) = self.reminder(request, approvers) else {
+            return;
+        };
+
+        let result: Result<(ApiResult<NotifyApproversResponse>,), _> =
+            ic_cdk::call(control_panel_id, "notify_approvers", (input,)).await;
+
+        let error = match result {
+            Ok((Ok(_),)) => return,
+            Ok((Err(error),)) => error.code,
+            Err((_, error)) => error,
+        };
+
+        print(format!(
+            "Failed to forward the approval reminder of request {} to the control panel: {}",
+            Uuid::from_bytes(request.id).hyphenated(),
+            error
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{test_utils, write_system_info},
+        models::{request_test_utils::mock_request, user_test_utils::mock_user, ApprovalReminders},
+    };
+
+    #[test]
+    fn forwards_the_identities_of_the_approvers_once_enabled() {
+        test_utils::init_canister_system();
+
+        let mut approver = mock_user();
+        approver.identities = vec![
+            Principal::from_slice(&[1; 29]),
+            Principal::from_slice(&[2; 29]),
+        ];
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let request = mock_request();
+
+        assert_eq!(
+            APPROVAL_REMINDER_SERVICE.reminder(&request, &[approver.id]),
+            None
+        );
+
+        let control_panel_id = Principal::from_slice(&[9; 29]);
+        let mut system_info = read_system_info();
+        system_info.set_approval_reminders(ApprovalReminders {
+            control_panel_id: Some(control_panel_id),
+            pending_vote_lead_time_secs: None,
+        });
+        write_system_info(system_info);
+
+        let (forwarded_to, input) = APPROVAL_REMINDER_SERVICE
+            .reminder(&request, &[approver.id, [7; 16]])
+            .unwrap();
+
+        assert_eq!(forwarded_to, control_panel_id);
+        assert_eq!(input.approvers, approver.identities);
+        assert_eq!(input.title, request.title);
+        assert_eq!(
+            input.request_id,
+            Uuid::from_bytes(request.id).hyphenated().to_string()
+        );
+        assert_eq!(APPROVAL_REMINDER_SERVICE.reminder(&request, &[]), None);
+    }
+}
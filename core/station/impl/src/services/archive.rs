@@ -0,0 +1,487 @@
+use crate::{
+    core::{
+        ic_cdk::{api::print, next_time},
+        read_system_info, write_system_info,
+    },
+    errors::ArchiveError,
+    mappers::TransferMapper,
+    models::{
+        ArchiveHead, ArchiveSink, ArchivedChunk, Request, RequestKey, RequestOperation,
+        RequestStatusCode, Transfer, TransferStatus,
+    },
+    repositories::{
+        ArchivedChunkRepository, EvaluationResultRepository, RequestCommentRepository,
+        RequestRepository, TransferRepository, ARCHIVED_CHUNK_REPOSITORY,
+        REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository, utils::timestamp_to_rfc3339};
+use sha2::{Digest, Sha256};
+use station_api::{
+    ArchiveChunkDTO, ArchivedRecordDTO, DownloadArchiveChunkInput, DownloadArchiveChunkResponse,
+    QueryArchiveInput, QueryArchiveResponse,
+};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref ARCHIVE_SERVICE: Arc<ArchiveService> = Arc::new(ArchiveService::new(
+        Arc::clone(&REQUEST_REPOSITORY),
+        Arc::clone(&REQUEST_EVALUATION_RESULT_REPOSITORY),
+        Arc::clone(&ARCHIVED_CHUNK_REPOSITORY),
+    ));
+}
+
+/// A settled request and the transfers it created, exported together to the archive canister.
+#[derive(Clone, Debug)]
+pub struct SettledRequest {
+    pub request: Request,
+    pub transfers: Vec<Transfer>,
+}
+
+/// Exports the settled requests and transfers that are older than the retention window to the
+/// user-owned archive canister, and prunes them from the station once the canister accepted them.
+///
+/// Without an archive canister the chunks are kept in the station as candid encoded blobs, which
+/// take much less space than the records and their indexes, to be downloaded one at a time.
+///
+/// The archive canister must implement the following methods:
+///
+/// - `append_archive_chunk : (ArchiveChunk) -> (variant { Ok; Err : text })`, which is expected
+///   to reject the chunks that do not follow its last chunk.
+/// - `query_archive : (QueryArchiveInput) -> (QueryArchiveResponse) query`
+#[derive(Default, Debug)]
+pub struct ArchiveService {
+    request_repository: Arc<RequestRepository>,
+    evaluation_result_repository: Arc<EvaluationResultRepository>,
+    archived_chunk_repository: Arc<ArchivedChunkRepository>,
+    transfer_repository: TransferRepository,
+    request_comment_repository: RequestCommentRepository,
+}
+
+impl ArchiveService {
+    /// The maximum number of requests exported in a chunk, to stay within the message size limit.
+    pub const MAX_CHUNK_REQUESTS: usize = 50;
+    /// The maximum number of chunks kept in the station that are returned by a query.
+    pub const MAX_QUERY_CHUNKS: u16 = 10;
+
+    const SETTLED_STATUSES: [RequestStatusCode; 4] = [
+        RequestStatusCode::Completed,
+        RequestStatusCode::Failed,
+        RequestStatusCode::Rejected,
+        RequestStatusCode::Cancelled,
+    ];
+
+    pub fn new(
+        request_repository: Arc<RequestRepository>,
+        evaluation_result_repository: Arc<EvaluationResultRepository>,
+        archived_chunk_repository: Arc<ArchivedChunkRepository>,
+    ) -> Self {
+        Self {
+            request_repository,
+            evaluation_result_repository,
+            archived_chunk_repository,
+            transfer_repository: TransferRepository::default(),
+            request_comment_repository: RequestCommentRepository::default(),
+        }
+    }
+
+    /// Exports the next chunk of the settled history, returns `true` if more is left to export.
+    pub async fn archive_history(&self) -> ServiceResult<bool> {
+        let Some(sink) = read_system_info().get_archive_sink().cloned() else {
+            return Ok(false);
+        };
+
+        let now = next_time();
+        let settled = self.find_settled_requests(&sink, now, Self::MAX_CHUNK_REQUESTS + 1);
+        let has_more = settled.len() > Self::MAX_CHUNK_REQUESTS;
+        let settled = settled
+            .into_iter()
+            .take(Self::MAX_CHUNK_REQUESTS)
+            .collect::<Vec<_>>();
+
+        if settled.is_empty() {
+            return Ok(false);
+        }
+
+        // the chunks kept in the station form a single chain, even if an archive canister was used
+        // in between
+        let head = match sink.canister_id {
+            Some(_) => read_system_info().get_archive_head().cloned(),
+            None => self
+                .archived_chunk_repository
+                .last()
+                .map(|chunk| chunk.to_head()),
+        };
+        let (chunk, hash) = Self::build_chunk(head.as_ref(), &settled, now);
+
+        match sink.canister_id {
+            Some(canister_id) => {
+                let (result,): (Result<(), String>,) =
+                    ic_cdk::call(canister_id, "append_archive_chunk", (chunk.clone(),))
+                        .await
+                        .map_err(|err| ArchiveError::CallFailed {
+                            reason: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+                        })?;
+
+                result.map_err(|reason| ArchiveError::CallFailed { reason })?;
+            }
+            None => {
+                self.archived_chunk_repository.insert(
+                    chunk.sequence,
+                    ArchivedChunk {
+                        sequence: chunk.sequence,
+                        hash: hash.clone(),
+                        blob: candid::encode_one(&chunk)
+                            .expect("Failed to encode the archive chunk"),
+                        archived_at: now,
+                    },
+                );
+            }
+        }
+
+        // the sink may have been moved while the chunk was appended, which starts a new chain
+        let mut system_info = read_system_info();
+        if system_info
+            .get_archive_sink()
+            .is_some_and(|current| current.canister_id == sink.canister_id)
+        {
+            system_info.set_archive_head(ArchiveHead {
+                sequence: chunk.sequence,
+                hash,
+                archived_at: now,
+            });
+            write_system_info(system_info);
+        }
+
+        self.prune(&settled);
+
+        print(format!(
+            "Archived {} requests in chunk {} to {}",
+            settled.len(),
+            chunk.sequence,
+            sink.canister_id
+                .map(|canister_id| canister_id.to_text())
+                .unwrap_or_else(|| "the station".to_string())
+        ));
+
+        Ok(has_more)
+    }
+
+    /// Forwards the query to the archive canister, so that the pruned history stays accessible
+    /// through the station, or reads the chunks kept in the station.
+    pub async fn query_archive(
+        &self,
+        input: QueryArchiveInput,
+    ) -> ServiceResult<QueryArchiveResponse> {
+        let sink = read_system_info().get_archive_sink().cloned();
+        let Some(canister_id) = sink.as_ref().and_then(|sink| sink.canister_id) else {
+            if sink.is_none() && self.archived_chunk_repository.is_empty() {
+                Err(ArchiveError::NotConfigured)?
+            }
+
+            return self.query_archived_chunks(input);
+        };
+
+        let (response,): (QueryArchiveResponse,) =
+            ic_cdk::call(canister_id, "query_archive", (input,))
+                .await
+                .map_err(|err| ArchiveError::CallFailed {
+                    reason: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+                })?;
+
+        Ok(response)
+    }
+
+    /// Reads the chunks kept in the station, which are decoded from their blobs.
+    fn query_archived_chunks(
+        &self,
+        input: QueryArchiveInput,
+    ) -> ServiceResult<QueryArchiveResponse> {
+        let limit = input
+            .limit
+            .unwrap_or(Self::MAX_QUERY_CHUNKS)
+            .min(Self::MAX_QUERY_CHUNKS);
+        let (chunks, next_sequence) = self
+            .archived_chunk_repository
+            .find_from(input.from_sequence.unwrap_or_default(), limit as usize);
+
+        Ok(QueryArchiveResponse {
+            chunks: chunks
+                .into_iter()
+                .map(|chunk| {
+                    candid::decode_one::<ArchiveChunkDTO>(&chunk.blob)
+                        .expect("Failed to decode the archive chunk")
+                })
+                .collect(),
+            next_sequence,
+        })
+    }
+
+    /// Returns the blob of a chunk kept in the station, the first one if no sequence is given, so
+    /// that the archive is downloaded one chunk at a time. The chunks kept in the station are
+    /// numbered without gaps from `0`.
+    pub fn download_archive_chunk(
+        &self,
+        input: DownloadArchiveChunkInput,
+    ) -> ServiceResult<DownloadArchiveChunkResponse> {
+        let sequence = input.sequence.unwrap_or_default();
+        let chunk = self
+            .archived_chunk_repository
+            .get(&sequence)
+            .ok_or(ArchiveError::ChunkNotFound { sequence })?;
+        let next_sequence = sequence + 1;
+
+        Ok(DownloadArchiveChunkResponse {
+            sequence,
+            hash: hex::encode(&chunk.hash),
+            chunk: chunk.blob,
+            next_sequence: self
+                .archived_chunk_repository
+                .exists(&next_sequence)
+                .then_some(next_sequence),
+        })
+    }
+
+    /// Returns the settled requests that were last modified before the retention window, the
+    /// transfer requests are only settled once their transfer is completed or failed.
+    pub fn find_settled_requests(
+        &self,
+        sink: &ArchiveSink,
+        now: u64,
+        limit: usize,
+    ) -> Vec<SettledRequest> {
+        let retained_from = now.saturating_sub(sink.retention_ns());
+        let mut requests = Self::SETTLED_STATUSES
+            .into_iter()
+            .flat_map(|status| {
+                self.request_repository
+                    .find_by_status(status, None, Some(retained_from))
+            })
+            .collect::<Vec<_>>();
+
+        requests.sort_by_key(|request| (request.last_modification_timestamp, request.id));
+
+        requests
+            .into_iter()
+            .filter_map(|request| {
+                let transfer_ids = match &request.operation {
+                    RequestOperation::Transfer(operation) => {
+                        operation.transfer_id.into_iter().collect()
+                    }
+                    RequestOperation::SweepAccount(operation) => {
+                        operation.transfer_id.into_iter().collect()
+                    }
+                    RequestOperation::SplitTransfer(operation) => operation.transfer_ids.clone(),
+                    _ => Vec::new(),
+                };
+                let transfers = transfer_ids
+                    .into_iter()
+                    .map(|transfer_id| {
+                        let transfer = self.transfer_repository.get(&Transfer::key(transfer_id))?;
+
+                        matches!(
+                            transfer.status,
+                            TransferStatus::Completed { .. } | TransferStatus::Failed { .. }
+                        )
+                        .then_some(transfer)
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(SettledRequest { request, transfers })
+            })
+            .take(limit)
+            .collect()
+    }
+
+    /// Builds the chunk that follows the given head, returns it with its hash.
+    pub fn build_chunk(
+        head: Option<&ArchiveHead>,
+        settled: &[SettledRequest],
+        now: u64,
+    ) -> (ArchiveChunkDTO, Vec<u8>) {
+        let sequence = head.map(|head| head.sequence + 1).unwrap_or_default();
+        let previous_hash = head.map(|head| hex::encode(&head.hash));
+        let records = settled
+            .iter()
+            .flat_map(|settled| {
+                std::iter::once(ArchivedRecordDTO::Request(settled.request.clone().to_dto())).chain(
+                    settled.transfers.iter().map(|transfer| {
+                        ArchivedRecordDTO::Transfer(TransferMapper::to_dto(transfer.clone()))
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let encoded = candid::encode_args((sequence, previous_hash.clone(), records.clone()))
+            .expect("Failed to encode the archive chunk");
+        let hash = Sha256::digest(&encoded).to_vec();
+
+        let chunk = ArchiveChunkDTO {
+            sequence,
+            previous_hash,
+            records,
+            hash: hex::encode(&hash),
+            archived_at: timestamp_to_rfc3339(&now),
+        };
+
+        (chunk, hash)
+    }
+
+    /// Removes the archived requests, their transfers, comments and evaluation results from the station.
+    fn prune(&self, settled: &[SettledRequest]) {
+        for settled in settled {
+            for transfer in &settled.transfers {
+                self.transfer_repository.remove(&transfer.to_key());
+            }
+
+            self.evaluation_result_repository
+                .remove(&settled.request.id);
+            self.request_comment_repository
+                .remove_by_request_id(&settled.request.id);
+            self.request_repository.remove(&RequestKey {
+                id: settled.request.id,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            request_test_utils::mock_request, transfer_test_utils::mock_transfer, RequestStatus,
+            TransferOperation,
+        },
+        repositories::TRANSFER_REPOSITORY,
+    };
+    use uuid::Uuid;
+
+    const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+    fn mock_sink() -> ArchiveSink {
+        ArchiveSink {
+            canister_id: Some(candid::Principal::from_slice(&[1; 29])),
+            retention_days: ArchiveSink::MIN_RETENTION_DAYS,
+        }
+    }
+
+    fn add_request(status: RequestStatus, last_modification_timestamp: u64) -> Request {
+        let mut request = mock_request();
+        request.status = status;
+        request.last_modification_timestamp = last_modification_timestamp;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        request
+    }
+
+    #[test]
+    fn only_settled_requests_older_than_the_retention_are_archived() {
+        let now = 100 * DAY_NS;
+        let old = now - 40 * DAY_NS;
+
+        let completed = add_request(RequestStatus::Completed { completed_at: old }, old);
+        add_request(RequestStatus::Completed { completed_at: now }, now);
+        add_request(RequestStatus::Created, old);
+
+        let mut transfer = mock_transfer();
+        transfer.status = TransferStatus::Processing { started_at: old };
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+        // the transfer of a completed request may still be processing, e.g. awaiting its confirmations
+        let mut unsettled_transfer = mock_request();
+        unsettled_transfer.status = RequestStatus::Completed { completed_at: old };
+        unsettled_transfer.last_modification_timestamp = old;
+        if let RequestOperation::Transfer(TransferOperation { transfer_id, .. }) =
+            &mut unsettled_transfer.operation
+        {
+            *transfer_id = Some(transfer.id);
+        }
+        REQUEST_REPOSITORY.insert(unsettled_transfer.to_key(), unsettled_transfer);
+
+        let settled = ARCHIVE_SERVICE.find_settled_requests(&mock_sink(), now, 10);
+
+        assert_eq!(settled.len(), 1);
+        assert_eq!(settled[0].request.id, completed.id);
+
+        ARCHIVE_SERVICE.prune(&settled);
+
+        assert!(REQUEST_REPOSITORY.get(&completed.to_key()).is_none());
+        assert!(ARCHIVE_SERVICE
+            .find_settled_requests(&mock_sink(), now, 10)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn without_archive_canister_the_chunks_are_kept_in_the_station() {
+        test_utils::init_canister_system();
+
+        let mut system_info = read_system_info();
+        system_info.set_archive_sink(Some(ArchiveSink {
+            canister_id: None,
+            ..mock_sink()
+        }));
+        write_system_info(system_info);
+
+        let old = next_time().saturating_sub(40 * DAY_NS);
+        let first = add_request(RequestStatus::Rejected, old);
+
+        assert!(!ARCHIVE_SERVICE.archive_history().await.unwrap());
+        assert!(REQUEST_REPOSITORY.get(&first.to_key()).is_none());
+
+        let second = add_request(RequestStatus::Cancelled { reason: None }, old);
+        ARCHIVE_SERVICE.archive_history().await.unwrap();
+
+        let download = ARCHIVE_SERVICE
+            .download_archive_chunk(DownloadArchiveChunkInput { sequence: None })
+            .unwrap();
+        assert_eq!(download.sequence, 0);
+        assert_eq!(download.next_sequence, Some(1));
+
+        let chunk = candid::decode_one::<ArchiveChunkDTO>(&download.chunk).unwrap();
+        assert_eq!(chunk.hash, download.hash);
+        assert!(matches!(
+            &chunk.records[..],
+            [ArchivedRecordDTO::Request(request)] if request.id == Uuid::from_bytes(first.id).hyphenated().to_string()
+        ));
+
+        let archive = ARCHIVE_SERVICE
+            .query_archive(QueryArchiveInput {
+                from_sequence: Some(1),
+                limit: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(archive.chunks.len(), 1);
+        assert_eq!(archive.chunks[0].previous_hash, Some(chunk.hash));
+        assert!(REQUEST_REPOSITORY.get(&second.to_key()).is_none());
+
+        assert!(ARCHIVE_SERVICE
+            .download_archive_chunk(DownloadArchiveChunkInput { sequence: Some(2) })
+            .is_err());
+    }
+
+    #[test]
+    fn chunks_are_chained_by_hash() {
+        let settled = vec![SettledRequest {
+            request: mock_request(),
+            transfers: Vec::new(),
+        }];
+
+        let (first, first_hash) = ArchiveService::build_chunk(None, &settled, 0);
+        assert_eq!(first.sequence, 0);
+        assert_eq!(first.previous_hash, None);
+        assert_eq!(first.hash, hex::encode(&first_hash));
+
+        let head = ArchiveHead {
+            sequence: first.sequence,
+            hash: first_hash.clone(),
+            archived_at: 0,
+        };
+        let (second, second_hash) = ArchiveService::build_chunk(Some(&head), &settled, 0);
+        assert_eq!(second.sequence, 1);
+        assert_eq!(second.previous_hash, Some(hex::encode(&first_hash)));
+        assert_ne!(second_hash, first_hash);
+    }
+}
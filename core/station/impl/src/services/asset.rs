@@ -0,0 +1,463 @@
+use crate::{
+    core::{
+        authorization::Authorization,
+        generate_uuid_v4,
+        ic_cdk::{api::print, next_time},
+        read_system_info,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+        write_system_info, CallContext, ASSETS,
+    },
+    errors::{AssetError, BlockchainApiError},
+    factories::blockchains::{
+        icrc1_decimals, icrc1_name, icrc1_symbol, CkBtc, CyclesLedger, InternetComputer,
+    },
+    models::{
+        resource::{Resource, ResourceAction, ResourceId},
+        AddAssetOperationInput, Asset, EditAssetOperationInput, RegisteredAsset,
+        RegisteredAssetCallerPrivileges, RegisteredAssetId, RemoveAssetOperationInput, SnsToken,
+        ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY,
+    },
+    repositories::{
+        AccountRepository, RegisteredAssetRepository, ACCOUNT_REPOSITORY,
+        REGISTERED_ASSET_REPOSITORY,
+    },
+};
+use candid::{CandidType, Deserialize, Principal};
+use futures::future;
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, model::ModelValidator, repository::Repository, types::Timestamp,
+};
+use station_api::PaginationInput;
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref ASSET_SERVICE: Arc<AssetService> = Arc::new(AssetService::new(
+        Arc::clone(&REGISTERED_ASSET_REPOSITORY),
+        Arc::clone(&ACCOUNT_REPOSITORY),
+    ));
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+struct ListDeployedSnsesRequest {}
+
+#[derive(CandidType, Deserialize, Debug)]
+struct DeployedSns {
+    root_canister_id: Option<Principal>,
+    index_canister_id: Option<Principal>,
+    ledger_canister_id: Option<Principal>,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+struct ListDeployedSnsesResponse {
+    instances: Vec<DeployedSns>,
+}
+
+/// Lists the assets that accounts can be added for, which are the natively supported assets, the
+/// SNS tokens discovered from the SNS-W canister and the ICRC-1 tokens registered by the station.
+///
+/// The discovered SNS tokens are kept in the system info and refreshed once they are older than
+/// `SNS_TOKENS_FRESHNESS_NS`, if the refresh fails the previously discovered tokens are used.
+#[derive(Default, Debug)]
+pub struct AssetService {
+    registered_asset_repository: Arc<RegisteredAssetRepository>,
+    account_repository: Arc<AccountRepository>,
+}
+
+impl AssetService {
+    pub const SNS_WASM_CANISTER_ID: &'static str = "qaa6y-5yaaa-aaaaa-aaafa-cai";
+    pub const SNS_TOKENS_FRESHNESS_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+    pub const DEFAULT_ASSETS_LIMIT: u16 = 100;
+    pub const MAX_LIST_ASSETS_LIMIT: u16 = 1000;
+
+    pub fn new(
+        registered_asset_repository: Arc<RegisteredAssetRepository>,
+        account_repository: Arc<AccountRepository>,
+    ) -> Self {
+        Self {
+            registered_asset_repository,
+            account_repository,
+        }
+    }
+
+    /// Returns the supported assets, refreshing the discovered SNS tokens if they are stale.
+    pub async fn list_supported_assets(&self) -> Vec<Asset> {
+        let now = next_time();
+        if self.sns_tokens_are_stale(read_system_info().get_sns_tokens_refreshed_at(), now) {
+            match Self::discover_sns_tokens().await {
+                Ok(tokens) => {
+                    let mut system_info = read_system_info();
+                    system_info.set_sns_tokens(tokens, now);
+                    write_system_info(system_info);
+                }
+                Err(error) => print(format!("Failed to discover the SNS tokens: {}", error)),
+            }
+        }
+
+        let mut assets: Vec<Asset> =
+            ASSETS.with(|assets| assets.borrow().iter().cloned().collect());
+        assets.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        assets.extend(
+            read_system_info()
+                .get_sns_tokens()
+                .iter()
+                .map(SnsToken::to_asset),
+        );
+        assets.extend(
+            self.registered_asset_repository
+                .list()
+                .iter()
+                .map(RegisteredAsset::to_asset),
+        );
+
+        assets
+    }
+
+    /// Returns the registered asset with the given id.
+    pub fn get_registered_asset(&self, id: &RegisteredAssetId) -> ServiceResult<RegisteredAsset> {
+        let asset = self
+            .registered_asset_repository
+            .get(id)
+            .ok_or(AssetError::NotFound {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?;
+
+        Ok(asset)
+    }
+
+    /// Returns the registered assets that the caller has access to, sorted by symbol.
+    pub fn list_registered_assets(
+        &self,
+        paginate: Option<PaginationInput>,
+        ctx: &CallContext,
+    ) -> ServiceResult<PaginatedData<RegisteredAsset>> {
+        let mut assets: Vec<RegisteredAsset> = self
+            .registered_asset_repository
+            .list()
+            .into_iter()
+            .filter(|asset| {
+                Authorization::is_allowed(
+                    ctx,
+                    &Resource::Asset(ResourceAction::Read(ResourceId::Id(asset.id))),
+                )
+            })
+            .collect();
+        assets.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+        Ok(paginated_items(PaginatedItemsArgs {
+            offset: paginate.to_owned().and_then(|p| p.offset),
+            limit: paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_ASSETS_LIMIT),
+            max_limit: Some(Self::MAX_LIST_ASSETS_LIMIT),
+            items: &assets,
+        })?)
+    }
+
+    /// Returns the caller privileges for the given registered asset.
+    pub fn get_caller_privileges_for_asset(
+        &self,
+        id: &RegisteredAssetId,
+        ctx: &CallContext,
+    ) -> RegisteredAssetCallerPrivileges {
+        RegisteredAssetCallerPrivileges {
+            id: *id,
+            can_edit: Authorization::is_allowed(
+                ctx,
+                &Resource::Asset(ResourceAction::Update(ResourceId::Id(*id))),
+            ),
+            can_delete: Authorization::is_allowed(
+                ctx,
+                &Resource::Asset(ResourceAction::Delete(ResourceId::Id(*id))),
+            ),
+        }
+    }
+
+    /// Registers the ICRC-1 ledger, the ledgers supported by the station out of the box can not be registered.
+    pub async fn add_asset(&self, input: AddAssetOperationInput) -> ServiceResult<RegisteredAsset> {
+        if Self::is_built_in_ledger(&input.ledger_canister_id) {
+            Err(AssetError::BuiltInLedger {
+                ledger_canister_id: input.ledger_canister_id.to_text(),
+            })?;
+        }
+
+        let asset = RegisteredAsset {
+            id: *generate_uuid_v4().await.as_bytes(),
+            ledger_canister_id: input.ledger_canister_id,
+            index_canister_id: input.index_canister_id,
+            symbol: input.symbol,
+            name: input.name,
+            decimals: input.decimals,
+            metadata: input.metadata,
+            last_modification_timestamp: next_time(),
+        };
+
+        asset.validate()?;
+
+        self.registered_asset_repository
+            .insert(asset.id, asset.to_owned());
+
+        Ok(asset)
+    }
+
+    /// Edits the registered asset, its ledger can not be changed since the accounts refer to it.
+    pub async fn edit_asset(
+        &self,
+        input: EditAssetOperationInput,
+    ) -> ServiceResult<RegisteredAsset> {
+        let mut asset = self.get_registered_asset(&input.asset_id)?;
+
+        if let Some(index_canister_id) = input.index_canister_id {
+            asset.index_canister_id = Some(index_canister_id);
+        }
+
+        if let Some(symbol) = input.symbol {
+            asset.symbol = symbol;
+        }
+
+        if let Some(name) = input.name {
+            asset.name = name;
+        }
+
+        if let Some(decimals) = input.decimals {
+            asset.decimals = decimals;
+        }
+
+        if let Some(change_metadata) = input.change_metadata {
+            asset.metadata.change(change_metadata);
+        }
+
+        asset.last_modification_timestamp = next_time();
+        asset.validate()?;
+
+        self.registered_asset_repository
+            .insert(asset.id, asset.to_owned());
+
+        Ok(asset)
+    }
+
+    /// Removes the registered asset, which is only possible once no account holds it anymore.
+    pub async fn remove_asset(
+        &self,
+        input: RemoveAssetOperationInput,
+    ) -> ServiceResult<RegisteredAsset> {
+        let asset = self.get_registered_asset(&input.asset_id)?;
+        let ledger_canister_id = asset.ledger_canister_id.to_text();
+
+        let accounts = self
+            .account_repository
+            .list()
+            .iter()
+            .filter(|account| {
+                account
+                    .metadata
+                    .get(ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY)
+                    .is_some_and(|ledger| ledger == ledger_canister_id)
+                    || account.assets.iter().any(|held| held.asset_id == asset.id)
+            })
+            .count();
+
+        if accounts > 0 {
+            Err(AssetError::InUse { accounts })?;
+        }
+
+        self.registered_asset_repository.remove(&asset.id);
+
+        Ok(asset)
+    }
+
+    /// Returns the registered asset whose ledger is the given canister.
+    pub fn find_registered_asset_by_ledger(
+        &self,
+        ledger_canister_id: &Principal,
+    ) -> Option<RegisteredAsset> {
+        self.registered_asset_repository
+            .find_by_ledger(ledger_canister_id)
+    }
+
+    /// Returns the registered asset with the given symbol, the symbol is case insensitive.
+    pub fn find_registered_asset_by_symbol(&self, symbol: &str) -> Option<RegisteredAsset> {
+        self.registered_asset_repository.find_by_symbol(symbol)
+    }
+
+    pub fn is_registered_ledger(&self, ledger_canister_id: &Principal) -> bool {
+        self.find_registered_asset_by_ledger(ledger_canister_id)
+            .is_some()
+    }
+
+    fn is_built_in_ledger(ledger_canister_id: &Principal) -> bool {
+        *ledger_canister_id == InternetComputer::ledger_canister_id()
+            || *ledger_canister_id == CkBtc::ledger_canister_id()
+            || CyclesLedger::is_cycles_ledger(ledger_canister_id)
+    }
+
+    /// Returns the discovered SNS token whose ledger is the given canister.
+    pub fn find_sns_token_by_ledger(&self, ledger_canister_id: &Principal) -> Option<SnsToken> {
+        read_system_info()
+            .get_sns_tokens()
+            .iter()
+            .find(|token| token.ledger_canister_id == *ledger_canister_id)
+            .cloned()
+    }
+
+    /// Returns the discovered SNS token with the given symbol, the symbol is case insensitive.
+    pub fn find_sns_token_by_symbol(&self, symbol: &str) -> Option<SnsToken> {
+        read_system_info()
+            .get_sns_tokens()
+            .iter()
+            .find(|token| token.symbol.eq_ignore_ascii_case(symbol))
+            .cloned()
+    }
+
+    pub fn is_supported_sns_ledger(&self, ledger_canister_id: &Principal) -> bool {
+        self.find_sns_token_by_ledger(ledger_canister_id).is_some()
+    }
+
+    fn sns_tokens_are_stale(&self, refreshed_at: Option<Timestamp>, now: Timestamp) -> bool {
+        match refreshed_at {
+            Some(refreshed_at) => now.saturating_sub(refreshed_at) > Self::SNS_TOKENS_FRESHNESS_NS,
+            None => true,
+        }
+    }
+
+    /// Lists the SNSes deployed by the SNS-W canister and fetches the token details of their ledgers,
+    /// the SNSes whose ledger can not be reached are skipped.
+    async fn discover_sns_tokens() -> Result<Vec<SnsToken>, BlockchainApiError> {
+        let (response,): (ListDeployedSnsesResponse,) = ic_cdk::call(
+            Principal::from_text(Self::SNS_WASM_CANISTER_ID).unwrap(),
+            "list_deployed_snses",
+            (ListDeployedSnsesRequest {},),
+        )
+        .await
+        .map_err(|err| BlockchainApiError::BlockchainNetworkError {
+            info: format!("rejection_code: {:?}, err: {}", err.0, err.1),
+        })?;
+
+        let tokens = future::join_all(response.instances.into_iter().filter_map(|sns| {
+            let root_canister_id = sns.root_canister_id?;
+            let ledger_canister_id = sns.ledger_canister_id?;
+
+            Some(async move {
+                let (symbol, name, decimals) = futures::try_join!(
+                    icrc1_symbol(ledger_canister_id),
+                    icrc1_name(ledger_canister_id),
+                    icrc1_decimals(ledger_canister_id),
+                )
+                .ok()?;
+
+                Some(SnsToken {
+                    root_canister_id,
+                    ledger_canister_id,
+                    index_canister_id: sns.index_canister_id,
+                    symbol,
+                    name,
+                    decimals: decimals as u32,
+                })
+            })
+        }))
+        .await;
+
+        Ok(tokens.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, Metadata},
+    };
+    use std::collections::BTreeMap;
+
+    fn mock_add_asset_input(ledger_canister_id: Principal) -> AddAssetOperationInput {
+        AddAssetOperationInput {
+            ledger_canister_id,
+            index_canister_id: None,
+            symbol: "TKN".to_string(),
+            name: "Token".to_string(),
+            decimals: 8,
+            metadata: Metadata::default(),
+        }
+    }
+
+    #[test]
+    fn discovered_sns_tokens_are_found_by_ledger_and_symbol() {
+        test_utils::init_canister_system();
+
+        let token = SnsToken {
+            root_canister_id: Principal::from_slice(&[1; 10]),
+            ledger_canister_id: Principal::from_slice(&[2; 10]),
+            index_canister_id: None,
+            symbol: "CHAT".to_string(),
+            name: "OpenChat".to_string(),
+            decimals: 8,
+        };
+
+        let mut system_info = read_system_info();
+        system_info.set_sns_tokens(vec![token.clone()], next_time());
+        write_system_info(system_info);
+
+        assert!(ASSET_SERVICE.is_supported_sns_ledger(&token.ledger_canister_id));
+        assert!(!ASSET_SERVICE.is_supported_sns_ledger(&token.root_canister_id));
+        assert_eq!(ASSET_SERVICE.find_sns_token_by_symbol("chat"), Some(token));
+    }
+
+    #[tokio::test]
+    async fn built_in_ledgers_can_not_be_registered() {
+        test_utils::init_canister_system();
+
+        let result = ASSET_SERVICE
+            .add_asset(mock_add_asset_input(CkBtc::ledger_canister_id()))
+            .await;
+
+        assert!(result.is_err());
+        assert!(REGISTERED_ASSET_REPOSITORY.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn registered_asset_is_removed_once_no_account_holds_it() {
+        test_utils::init_canister_system();
+
+        let ledger_canister_id = Principal::from_slice(&[2; 10]);
+        let asset = ASSET_SERVICE
+            .add_asset(mock_add_asset_input(ledger_canister_id))
+            .await
+            .unwrap();
+
+        assert!(ASSET_SERVICE.is_registered_ledger(&ledger_canister_id));
+        assert_eq!(
+            ASSET_SERVICE
+                .find_registered_asset_by_symbol("tkn")
+                .map(|asset| asset.id),
+            Some(asset.id)
+        );
+
+        let mut account = mock_account();
+        account.metadata = Metadata::new(BTreeMap::from([(
+            ACCOUNT_METADATA_LEDGER_CANISTER_ID_KEY.to_string(),
+            ledger_canister_id.to_text(),
+        )]));
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let remove_input = RemoveAssetOperationInput { asset_id: asset.id };
+        assert!(ASSET_SERVICE
+            .remove_asset(remove_input.clone())
+            .await
+            .is_err());
+
+        ACCOUNT_REPOSITORY.remove(&account.to_key());
+        ASSET_SERVICE.remove_asset(remove_input).await.unwrap();
+
+        assert!(!ASSET_SERVICE.is_registered_ledger(&ledger_canister_id));
+    }
+
+    #[test]
+    fn sns_tokens_are_refreshed_once_stale() {
+        let service = AssetService::default();
+
+        assert!(service.sns_tokens_are_stale(None, 10));
+        assert!(!service.sns_tokens_are_stale(Some(10), 10));
+        assert!(service.sns_tokens_are_stale(Some(10), 11 + AssetService::SNS_TOKENS_FRESHNESS_NS));
+    }
+}
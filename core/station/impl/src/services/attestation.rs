@@ -0,0 +1,170 @@
+use crate::{
+    core::{
+        certification::{attestation_witness, certify_attestation_hash},
+        ic_cdk::{
+            api::{data_certificate, id as self_canister_id},
+            next_time,
+        },
+        read_system_info,
+    },
+    errors::SystemError,
+    models::{UserStatus, ADMIN_GROUP_ID},
+    repositories::{
+        RequestPolicyRepository, UserRepository, REQUEST_POLICY_REPOSITORY, USER_REPOSITORY,
+    },
+    SYSTEM_VERSION,
+};
+use ic_cdk::api::management_canister::main::{self as mgmt, CanisterInfoRequest};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, http::cbor_encode, repository::Repository, utils::timestamp_to_rfc3339,
+};
+use sha2::{Digest, Sha256};
+use station_api::{AttestedAdminDTO, GetStationAttestationResponse, StationAttestationDTO};
+use std::{cell::RefCell, sync::Arc};
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref ATTESTATION_SERVICE: Arc<AttestationService> =
+        Arc::new(AttestationService::new(
+            Arc::clone(&USER_REPOSITORY),
+            Arc::clone(&REQUEST_POLICY_REPOSITORY)
+        ));
+}
+
+thread_local! {
+    /// The candid encoding of the latest certified attestation, reset on upgrades.
+    static ATTESTATION: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// Certifies a statement of the wasm module, version, admins and governance of the station, so that
+/// counterparties can verify they deal with an unmodified station before they transact with it.
+///
+/// Certified data can only be set in update calls, hence the attestation is refreshed periodically by a
+/// job and the query serves the latest attestation rather than the current state.
+#[derive(Default, Debug)]
+pub struct AttestationService {
+    user_repository: Arc<UserRepository>,
+    request_policy_repository: Arc<RequestPolicyRepository>,
+}
+
+impl AttestationService {
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        request_policy_repository: Arc<RequestPolicyRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            request_policy_repository,
+        }
+    }
+
+    /// Looks up the hash of the module installed on the station and certifies a new attestation.
+    ///
+    /// The attestation is still certified without the module hash if the management canister fails to
+    /// report it, the verifiers then only trust the other statements.
+    pub async fn refresh_attestation(&self) {
+        let module_hash = mgmt::canister_info(CanisterInfoRequest {
+            canister_id: self_canister_id(),
+            num_requested_changes: None,
+        })
+        .await
+        .ok()
+        .and_then(|(info,)| info.module_hash);
+
+        self.certify_attestation(module_hash);
+    }
+
+    /// Takes a new attestation of the station and certifies its hash.
+    pub fn certify_attestation(&self, module_hash: Option<Vec<u8>>) {
+        let system_info = read_system_info();
+        let attestation = StationAttestationDTO {
+            station_id: self_canister_id(),
+            wasm_module_hash: module_hash.map(hex::encode),
+            version: SYSTEM_VERSION.to_string(),
+            upgrader_id: *system_info.get_upgrader_canister_id(),
+            admins: self
+                .user_repository
+                .find_by_group_and_status(ADMIN_GROUP_ID, &UserStatus::Active)
+                .into_iter()
+                .map(|user| AttestedAdminDTO {
+                    id: Uuid::from_bytes(user.id).hyphenated().to_string(),
+                    name: user.name,
+                    identities: user.identities,
+                })
+                .collect(),
+            disaster_recovery_committee: system_info
+                .get_disaster_recovery_committee()
+                .cloned()
+                .map(Into::into),
+            request_policies: self
+                .request_policy_repository
+                .list()
+                .into_iter()
+                .map(|policy| policy.to_dto())
+                .collect(),
+            attested_at: timestamp_to_rfc3339(&next_time()),
+        };
+
+        let attestation =
+            candid::encode_one(&attestation).expect("Failed to encode the station attestation");
+        let attestation_hash: [u8; 32] = Sha256::digest(&attestation).into();
+
+        ATTESTATION.with(|certified| *certified.borrow_mut() = Some(attestation));
+
+        certify_attestation_hash(attestation_hash);
+    }
+
+    /// Returns the latest attestation with the certificate and witness proving its hash is certified.
+    pub fn get_station_attestation(&self) -> ServiceResult<GetStationAttestationResponse> {
+        let attestation = ATTESTATION
+            .with(|certified| certified.borrow().clone())
+            .ok_or(SystemError::AttestationNotCertified)?;
+
+        Ok(GetStationAttestationResponse {
+            attestation,
+            certificate: data_certificate(),
+            witness: cbor_encode(&attestation_witness()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{request_policy_test_utils::mock_request_policy, user_test_utils::mock_user},
+    };
+    use orbit_essentials::model::ModelKey;
+
+    #[test]
+    fn serves_the_certified_attestation() {
+        test_utils::init_canister_system();
+
+        let mut admin = mock_user();
+        admin.groups = vec![*ADMIN_GROUP_ID];
+        admin.status = UserStatus::Active;
+        USER_REPOSITORY.insert(admin.to_key(), admin.clone());
+
+        let policy = mock_request_policy();
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy.clone());
+
+        ATTESTATION_SERVICE.certify_attestation(Some(vec![1, 2, 3]));
+
+        let response = ATTESTATION_SERVICE.get_station_attestation().unwrap();
+        let attestation: StationAttestationDTO = candid::decode_one(&response.attestation).unwrap();
+
+        assert_eq!(attestation.wasm_module_hash, Some("010203".to_string()));
+        assert_eq!(attestation.version, SYSTEM_VERSION);
+        assert!(attestation
+            .admins
+            .iter()
+            .any(|attested| attested.identities == admin.identities));
+        assert!(attestation
+            .request_policies
+            .iter()
+            .any(|attested| attested.id == Uuid::from_bytes(policy.id).hyphenated().to_string()));
+        assert!(response.certificate.is_none());
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    core::{authorization::Authorization, ic_cdk::next_time, CallContext},
+    errors::UserError,
+    models::{
+        resource::{RequestResourceAction, Resource, ResourceId},
+        Request, RequestStatus, RequestStatusCode, User, UserId,
+    },
+    repositories::{
+        RequestRepository, RequestWhereClause, UserRepository, REQUEST_REPOSITORY, USER_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    repository::Repository,
+    types::{Timestamp, UUID},
+    utils::{random_bytes, sha256_hash, timestamp_to_rfc3339},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref CALENDAR_FEED_SERVICE: Arc<CalendarFeedService> =
+        Arc::new(CalendarFeedService::new(
+            Arc::clone(&USER_REPOSITORY),
+            Arc::clone(&REQUEST_REPOSITORY),
+        ));
+}
+
+/// Builds the ICS calendar feeds of the users, which contain the approval deadlines of the requests
+/// that are pending their vote and the execution times of the scheduled requests they can read.
+///
+/// The feeds are served over `http_request` with a capability url, `/calendar/<user_id>/<token>.ics`,
+/// so calendar clients can subscribe to them without authenticating.
+#[derive(Default, Debug)]
+pub struct CalendarFeedService {
+    user_repository: Arc<UserRepository>,
+    request_repository: Arc<RequestRepository>,
+}
+
+impl CalendarFeedService {
+    pub const PATH_PREFIX: &'static str = "/calendar/";
+    pub const PATH_SUFFIX: &'static str = ".ics";
+
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        request_repository: Arc<RequestRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            request_repository,
+        }
+    }
+
+    /// Creates a new secret token for the calendar feed of the caller and returns the feed path.
+    ///
+    /// Only the hash of the token is stored, creating a new token revokes the previous feed url.
+    pub async fn create_feed_path(&self, ctx: &CallContext) -> ServiceResult<String> {
+        let mut user = self.caller_user(ctx)?;
+        let token = hex::encode(random_bytes::<32>().await);
+
+        user.calendar_feed_token_hash = Some(Self::hash_token(&token));
+        user.last_modification_timestamp = next_time();
+
+        self.user_repository.insert(user.to_key(), user.to_owned());
+
+        Ok(format!(
+            "{}{}/{}{}",
+            Self::PATH_PREFIX,
+            Uuid::from_bytes(user.id).hyphenated(),
+            token,
+            Self::PATH_SUFFIX
+        ))
+    }
+
+    /// Revokes the calendar feed url of the caller.
+    pub fn revoke_feed(&self, ctx: &CallContext) -> ServiceResult<()> {
+        let mut user = self.caller_user(ctx)?;
+
+        user.calendar_feed_token_hash = None;
+        user.last_modification_timestamp = next_time();
+
+        self.user_repository.insert(user.to_key(), user);
+
+        Ok(())
+    }
+
+    /// Returns the ICS feed for the given feed path, or `None` if the path is not a valid capability url.
+    pub fn render_feed(&self, path: &str) -> Option<String> {
+        let (user_id, token) = path
+            .strip_prefix(Self::PATH_PREFIX)?
+            .strip_suffix(Self::PATH_SUFFIX)?
+            .split_once('/')?;
+        let user_id = *Uuid::parse_str(user_id).ok()?.as_bytes();
+        let user = self.user_repository.get(&User::key(user_id))?;
+
+        if !user.is_active() || user.calendar_feed_token_hash != Some(Self::hash_token(token)) {
+            return None;
+        }
+
+        let ctx = CallContext::new(*user.identities.first()?);
+        let now = next_time();
+        let mut events = Vec::new();
+
+        for request in self.pending_approval_requests(&user.id, &ctx) {
+            events.push(CalendarEvent {
+                uid: format!("{}-deadline", Uuid::from_bytes(request.id).hyphenated()),
+                starts_at: request.expiration_dt,
+                summary: format!("Approval deadline: {}", request.title),
+                description: request.summary.to_owned(),
+            });
+        }
+
+        for request in self.request_repository.find_scheduled(Some(now), None) {
+            if let RequestStatus::Scheduled { scheduled_at } = request.status {
+                if !Authorization::is_confidential_request_hidden(&ctx, &request.id)
+                    && Self::can_read(&ctx, &request.id)
+                {
+                    events.push(CalendarEvent {
+                        uid: format!("{}-execution", Uuid::from_bytes(request.id).hyphenated()),
+                        starts_at: scheduled_at,
+                        summary: format!("Scheduled execution: {}", request.title),
+                        description: request.summary.to_owned(),
+                    });
+                }
+            }
+        }
+
+        Some(render_calendar(now, &events))
+    }
+
+    /// Returns the requests that are still open and that the user can read but has not voted on yet.
+    fn pending_approval_requests(&self, user_id: &UserId, ctx: &CallContext) -> Vec<Request> {
+        self.request_repository
+            .find_ids_where(
+                RequestWhereClause {
+                    created_dt_from: None,
+                    created_dt_to: None,
+                    expiration_dt_from: None,
+                    expiration_dt_to: None,
+                    operation_types: vec![],
+                    statuses: vec![RequestStatusCode::Created],
+                    requesters: vec![],
+                    approvers: vec![],
+                    not_approvers: vec![*user_id],
+                    not_requesters: vec![*user_id],
+                    excluded_ids: vec![],
+                    tags: vec![],
+                    visible_to: Some(ctx.to_owned()),
+                },
+                None,
+            )
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|request_id| Self::can_read(ctx, request_id))
+            .filter_map(|request_id| self.request_repository.get(&Request::key(request_id)))
+            .collect()
+    }
+
+    fn can_read(ctx: &CallContext, request_id: &UUID) -> bool {
+        Authorization::is_allowed(
+            ctx,
+            &Resource::Request(RequestResourceAction::Read(ResourceId::Id(*request_id))),
+        )
+    }
+
+    fn caller_user(&self, ctx: &CallContext) -> ServiceResult<User> {
+        let user = self.user_repository.find_by_identity(&ctx.caller()).ok_or(
+            UserError::NotFoundUserIdentity {
+                identity: ctx.caller().to_text(),
+            },
+        )?;
+
+        Ok(user)
+    }
+
+    fn hash_token(token: &str) -> [u8; 32] {
+        let mut hash = [0; 32];
+        hash.copy_from_slice(&sha256_hash(token.as_bytes()));
+
+        hash
+    }
+}
+
+struct CalendarEvent {
+    uid: String,
+    starts_at: Timestamp,
+    summary: String,
+    description: Option<String>,
+}
+
+/// Renders the events as an RFC 5545 calendar.
+fn render_calendar(now: Timestamp, events: &[CalendarEvent]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Orbit//Station//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "X-WR-CALNAME:Orbit Station".to_string(),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@orbit", event.uid));
+        lines.push(format!("DTSTAMP:{}", format_ics_datetime(now)));
+        lines.push(format!("DTSTART:{}", format_ics_datetime(event.starts_at)));
+        lines.push(format!("DTEND:{}", format_ics_datetime(event.starts_at)));
+        lines.push(format!("SUMMARY:{}", escape_ics_text(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_ics_text(description)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_ics_line(line))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// Formats the timestamp as an UTC date-time, e.g. `20240101T120000Z`.
+fn format_ics_datetime(timestamp: Timestamp) -> String {
+    let rfc3339 = timestamp_to_rfc3339(&timestamp);
+    let (datetime, _) = rfc3339.split_at(19);
+
+    format!("{}Z", datetime.replace(['-', ':'], ""))
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Folds the line so that no line is longer than 75 octets and terminates it with CRLF.
+fn fold_ics_line(line: &str) -> String {
+    const MAX_LINE_OCTETS: usize = 75;
+
+    let mut folded = String::new();
+    let mut line_octets = 0;
+
+    for char in line.chars() {
+        if line_octets + char.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+
+        folded.push(char);
+        line_octets += char.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            permission::{Allow, Permission},
+            request_test_utils::mock_request,
+            user_test_utils::mock_user,
+            RequestKey,
+        },
+        repositories::permission::PERMISSION_REPOSITORY,
+    };
+    use orbit_essentials::model::ModelKey;
+
+    fn feed_token(path: &str) -> &str {
+        path.rsplit('/')
+            .next()
+            .unwrap()
+            .trim_end_matches(CalendarFeedService::PATH_SUFFIX)
+    }
+
+    #[tokio::test]
+    async fn renders_feed_with_pending_deadlines() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let permission = Permission::new(
+            Allow::authenticated(),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let mut request = mock_request();
+        request.status = RequestStatus::Created;
+        request.title = "Pay, the supplier".to_string();
+        REQUEST_REPOSITORY.insert(RequestKey { id: request.id }, request.clone());
+
+        let ctx = CallContext::new(user.identities[0]);
+        let path = CALENDAR_FEED_SERVICE.create_feed_path(&ctx).await.unwrap();
+
+        assert!(!feed_token(&path).is_empty());
+
+        let feed = CALENDAR_FEED_SERVICE.render_feed(&path).unwrap();
+
+        assert!(feed.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(feed.ends_with("END:VCALENDAR\r\n"));
+        assert!(feed.contains(&format!(
+            "DTSTART:{}\r\n",
+            format_ics_datetime(request.expiration_dt)
+        )));
+    }
+
+    #[tokio::test]
+    async fn leaves_out_the_confidential_requests() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let permission = Permission::new(
+            Allow::authenticated(),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let statuses = [
+            RequestStatus::Created,
+            RequestStatus::Scheduled {
+                scheduled_at: next_time() + 24 * 60 * 60 * 1_000_000_000,
+            },
+        ];
+        for (i, status) in statuses.into_iter().enumerate() {
+            for confidential in [false, true] {
+                let mut request = mock_request();
+                request.status = status.clone();
+                request.confidential = confidential;
+                request.title = format!("Request {} confidential {}", i, confidential);
+                REQUEST_REPOSITORY.insert(RequestKey { id: request.id }, request);
+            }
+        }
+
+        let ctx = CallContext::new(user.identities[0]);
+        let path = CALENDAR_FEED_SERVICE.create_feed_path(&ctx).await.unwrap();
+        let feed = CALENDAR_FEED_SERVICE.render_feed(&path).unwrap();
+
+        assert!(feed.contains("Request 0 confidential false"));
+        assert!(feed.contains("Request 1 confidential false"));
+        assert!(!feed.contains("confidential true"));
+    }
+
+    #[tokio::test]
+    async fn rejects_revoked_and_invalid_tokens() {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let ctx = CallContext::new(user.identities[0]);
+        let path = CALENDAR_FEED_SERVICE.create_feed_path(&ctx).await.unwrap();
+
+        let invalid_path = path.replace(feed_token(&path), "invalid");
+        assert!(CALENDAR_FEED_SERVICE.render_feed(&invalid_path).is_none());
+
+        CALENDAR_FEED_SERVICE.revoke_feed(&ctx).unwrap();
+        assert!(CALENDAR_FEED_SERVICE.render_feed(&path).is_none());
+    }
+
+    #[test]
+    fn formats_ics_text_and_lines() {
+        assert_eq!(format_ics_datetime(0), "19700101T000000Z");
+        assert_eq!(escape_ics_text("a,b;c\nd"), "a\\,b\\;c\\nd");
+
+        let folded = fold_ics_line(&"a".repeat(80));
+        assert_eq!(
+            folded,
+            format!("{}\r\n {}\r\n", "a".repeat(75), "a".repeat(5))
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    core::{generate_uuid_v4, ic_cdk::next_time},
+    errors::{AccountError, CapabilityGrantError},
+    models::{
+        resource::{AccountResourceAction, Resource, ResourceId},
+        Account, AccountId, CapabilityGrant, CapabilityGrantId, CapabilityScope,
+        CreateCapabilityGrantOperationInput, UserId,
+    },
+    repositories::{
+        AccountRepository, CapabilityGrantRepository, ACCOUNT_REPOSITORY,
+        CAPABILITY_GRANT_REPOSITORY,
+    },
+};
+use candid::Principal;
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref CAPABILITY_GRANT_SERVICE: Arc<CapabilityGrantService> =
+        Arc::new(CapabilityGrantService::new(
+            Arc::clone(&CAPABILITY_GRANT_REPOSITORY),
+            Arc::clone(&ACCOUNT_REPOSITORY),
+        ));
+}
+
+/// Manages the capability grants that let third-party principals read a single account.
+///
+/// The grants are created and revoked by the execution of the `CreateCapabilityGrant` and
+/// `RevokeCapabilityGrant` requests, so that they are governed by the request policies.
+#[derive(Default, Debug)]
+pub struct CapabilityGrantService {
+    capability_grant_repository: Arc<CapabilityGrantRepository>,
+    account_repository: Arc<AccountRepository>,
+}
+
+impl CapabilityGrantService {
+    pub fn new(
+        capability_grant_repository: Arc<CapabilityGrantRepository>,
+        account_repository: Arc<AccountRepository>,
+    ) -> Self {
+        Self {
+            capability_grant_repository,
+            account_repository,
+        }
+    }
+
+    pub fn get_capability_grant(&self, id: &CapabilityGrantId) -> ServiceResult<CapabilityGrant> {
+        let grant =
+            self.capability_grant_repository
+                .get(id)
+                .ok_or(CapabilityGrantError::NotFound {
+                    id: Uuid::from_bytes(*id).hyphenated().to_string(),
+                })?;
+
+        Ok(grant)
+    }
+
+    /// Creates the grant of an approved request, on behalf of the user that requested it.
+    pub async fn create_capability_grant(
+        &self,
+        input: CreateCapabilityGrantOperationInput,
+        created_by: UserId,
+    ) -> ServiceResult<CapabilityGrant> {
+        if self
+            .account_repository
+            .get(&Account::key(input.account_id))
+            .is_none()
+        {
+            Err(AccountError::AccountNotFound {
+                id: Uuid::from_bytes(input.account_id).hyphenated().to_string(),
+            })?
+        }
+
+        let grant = CapabilityGrant::new(
+            *generate_uuid_v4().await.as_bytes(),
+            input,
+            created_by,
+            next_time(),
+        );
+
+        grant.validate()?;
+
+        self.capability_grant_repository
+            .insert(grant.key(), grant.clone());
+
+        Ok(grant)
+    }
+
+    /// Returns the grants of the account, including the expired and revoked ones.
+    pub fn list_capability_grants(&self, account_id: &AccountId) -> Vec<CapabilityGrant> {
+        self.capability_grant_repository
+            .find_by_account_id(account_id)
+    }
+
+    /// Revokes the grant, its grantee loses the access immediately.
+    pub fn revoke_capability_grant(
+        &self,
+        id: &CapabilityGrantId,
+    ) -> ServiceResult<CapabilityGrant> {
+        let mut grant = self.get_capability_grant(id)?;
+
+        if grant.revoked_at.is_some() {
+            Err(CapabilityGrantError::AlreadyRevoked {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?
+        }
+
+        let now = next_time();
+        grant.revoked_at = Some(now);
+        grant.last_modification_timestamp = now;
+
+        self.capability_grant_repository
+            .insert(grant.key(), grant.clone());
+
+        Ok(grant)
+    }
+
+    /// Whether the principal holds an active grant of the scope for the resource, only the read
+    /// access to a single account can be granted.
+    pub fn is_granted(
+        &self,
+        grantee: &Principal,
+        scope: &CapabilityScope,
+        resource: &Resource,
+    ) -> bool {
+        let Resource::Account(AccountResourceAction::Read(ResourceId::Id(account_id))) = resource
+        else {
+            return false;
+        };
+
+        let now = next_time();
+
+        self.capability_grant_repository
+            .find_by_grantee(grantee)
+            .iter()
+            .any(|grant| grant.allows(account_id, scope, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{account_test_utils::mock_account, user_test_utils::mock_user},
+        repositories::USER_REPOSITORY,
+    };
+
+    struct TestContext {
+        service: CapabilityGrantService,
+        account: Account,
+        user_id: UserId,
+    }
+
+    fn setup() -> TestContext {
+        test_utils::init_canister_system();
+
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        TestContext {
+            service: CapabilityGrantService::default(),
+            account,
+            user_id: user.id,
+        }
+    }
+
+    fn grant_input(account: &Account, grantee: Principal) -> CreateCapabilityGrantOperationInput {
+        CreateCapabilityGrantOperationInput {
+            account_id: account.id,
+            grantee,
+            scopes: vec![CapabilityScope::Transfers, CapabilityScope::Transfers],
+            expires_at: next_time() + 24 * 60 * 60 * 1_000_000_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn grant_gives_access_to_the_account_until_revoked() {
+        let ctx = setup();
+        let grantee = Principal::from_slice(&[5; 29]);
+        let account_read =
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(ctx.account.id)));
+
+        let grant = ctx
+            .service
+            .create_capability_grant(grant_input(&ctx.account, grantee), ctx.user_id)
+            .await
+            .unwrap();
+
+        assert_eq!(grant.scopes, vec![CapabilityScope::Transfers]);
+        assert_eq!(grant.created_by, ctx.user_id);
+
+        assert!(ctx
+            .service
+            .is_granted(&grantee, &CapabilityScope::Transfers, &account_read));
+        assert!(!ctx
+            .service
+            .is_granted(&grantee, &CapabilityScope::Balances, &account_read));
+        assert!(!ctx.service.is_granted(
+            &grantee,
+            &CapabilityScope::Transfers,
+            &Resource::Account(AccountResourceAction::Read(ResourceId::Any))
+        ));
+
+        ctx.service.revoke_capability_grant(&grant.id).unwrap();
+
+        assert!(!ctx
+            .service
+            .is_granted(&grantee, &CapabilityScope::Transfers, &account_read));
+        assert!(ctx.service.revoke_capability_grant(&grant.id).is_err());
+    }
+
+    #[tokio::test]
+    async fn fail_grant_for_unknown_account() {
+        let ctx = setup();
+        let mut input = grant_input(&ctx.account, Principal::from_slice(&[5; 29]));
+        input.account_id = *Uuid::new_v4().as_bytes();
+
+        assert!(ctx
+            .service
+            .create_capability_grant(input, ctx.user_id)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{
+    core::{
+        authorization::Authorization,
+        ic_cdk::{
+            api::{print, time},
+            next_time,
+        },
+        limiter::Limiter,
+        read_system_info, write_system_info, CallContext,
+    },
+    errors::SystemError,
+    jobs,
+    models::{
+        resource::{Resource, SystemResourceAction},
+        Blockchain, NotificationType, PausedBlockchain,
+    },
+    repositories::{UserRepository, USER_REPOSITORY},
+    services::{LocaleService, NotificationService, LOCALE_SERVICE, NOTIFICATION_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
+
+lazy_static! {
+    pub static ref CIRCUIT_BREAKER_SERVICE: Arc<CircuitBreakerService> =
+        Arc::new(CircuitBreakerService::new(
+            Arc::clone(&USER_REPOSITORY),
+            Arc::clone(&NOTIFICATION_SERVICE),
+            Arc::clone(&LOCALE_SERVICE),
+        ));
+}
+
+const EXECUTIONS_RESOLUTION: Duration = Duration::from_secs(10);
+const EXECUTIONS_TIME_WINDOW: Duration = Duration::from_secs(600);
+
+/// The transfer executions of a blockchain in the time window.
+#[derive(Debug)]
+struct BlockchainExecutions {
+    attempts: Limiter,
+    failures: Limiter,
+}
+
+thread_local! {
+    /// The recent transfer executions of each blockchain, reset on upgrades.
+    static BLOCKCHAIN_EXECUTIONS: RefCell<HashMap<Blockchain, BlockchainExecutions>> = RefCell::new(HashMap::new());
+}
+
+/// Pauses the transfer executions of a blockchain when its ledger keeps failing them.
+///
+/// Retrying transfers during a ledger incident burns fees without completing them, so once the
+/// failures of a blockchain exceed the threshold within the time window, the created transfers of
+/// that blockchain are kept until an admin resumes it.
+#[derive(Default, Debug)]
+pub struct CircuitBreakerService {
+    user_repository: Arc<UserRepository>,
+    notification_service: Arc<NotificationService>,
+    locale_service: Arc<LocaleService>,
+}
+
+impl CircuitBreakerService {
+    /// The minimum number of failures within the time window before the circuit breaker trips.
+    pub const MIN_FAILURES: usize = 5;
+    /// The share of the executions within the time window that must fail for the circuit breaker to trip.
+    pub const FAILURE_RATE_PERCENT: usize = 50;
+
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        notification_service: Arc<NotificationService>,
+        locale_service: Arc<LocaleService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            notification_service,
+            locale_service,
+        }
+    }
+
+    /// Returns whether the transfer executions of the blockchain are paused.
+    pub fn is_paused(&self, blockchain: &Blockchain) -> bool {
+        read_system_info()
+            .get_paused_blockchain(blockchain)
+            .is_some()
+    }
+
+    pub fn record_success(&self, blockchain: &Blockchain) {
+        Self::record_execution(blockchain, false);
+    }
+
+    /// Records a failed execution, the blockchain is paused and the admins are notified if the
+    /// failures exceed the threshold.
+    pub async fn record_failure(&self, blockchain: &Blockchain, reason: &str) {
+        if !Self::record_execution(blockchain, true) || self.is_paused(blockchain) {
+            return;
+        }
+
+        let mut system_info = read_system_info();
+        system_info.pause_blockchain(PausedBlockchain {
+            blockchain: blockchain.clone(),
+            paused_at: next_time(),
+            reason: reason.to_string(),
+        });
+        write_system_info(system_info);
+
+        print(format!(
+            "Transfer executions of {} paused after repeated failures: {}",
+            blockchain, reason
+        ));
+
+        self.notify_admins(blockchain, reason).await;
+    }
+
+    /// Resumes the transfer executions of the blockchain, the transfers created while it was paused
+    /// are executed right away.
+    pub fn resume_blockchain(&self, blockchain: &Blockchain) -> ServiceResult<()> {
+        let mut system_info = read_system_info();
+        if !system_info.resume_blockchain(blockchain) {
+            Err(SystemError::BlockchainNotPaused {
+                blockchain: blockchain.to_string(),
+            })?
+        }
+        write_system_info(system_info);
+
+        BLOCKCHAIN_EXECUTIONS.with(|executions| executions.borrow_mut().remove(blockchain));
+
+        jobs::schedule_created_transfers_execution();
+
+        Ok(())
+    }
+
+    /// Adds the execution to the time window of the blockchain and returns whether the failures
+    /// exceed the threshold.
+    fn record_execution(blockchain: &Blockchain, failed: bool) -> bool {
+        let now = UNIX_EPOCH + Duration::from_nanos(time());
+
+        BLOCKCHAIN_EXECUTIONS.with(|executions| {
+            let mut executions = executions.borrow_mut();
+            let executions =
+                executions
+                    .entry(blockchain.clone())
+                    .or_insert_with(|| BlockchainExecutions {
+                        attempts: Limiter::new(EXECUTIONS_RESOLUTION, EXECUTIONS_TIME_WINDOW),
+                        failures: Limiter::new(EXECUTIONS_RESOLUTION, EXECUTIONS_TIME_WINDOW),
+                    });
+
+            executions.attempts.add(now, 1);
+            executions.failures.purge_old(now);
+            if failed {
+                executions.failures.add(now, 1);
+            }
+
+            let attempts = executions.attempts.get_count();
+            let failures = executions.failures.get_count();
+
+            failures >= Self::MIN_FAILURES
+                && failures * 100 >= attempts * Self::FAILURE_RATE_PERCENT
+        })
+    }
+
+    /// Notifies the users that can resume the blockchain.
+    async fn notify_admins(&self, blockchain: &Blockchain, reason: &str) {
+        let resource = Resource::System(SystemResourceAction::ManageSystemInfo);
+        let blockchain = blockchain.to_string();
+        let args = [("blockchain", blockchain.as_str()), ("reason", reason)];
+
+        for user in self.user_repository.list() {
+            let can_resume = user.is_active()
+                && user.identities.first().is_some_and(|identity| {
+                    Authorization::is_allowed(&CallContext::new(*identity), &resource)
+                });
+
+            if !can_resume {
+                continue;
+            }
+
+            let title = self
+                .locale_service
+                .render(&user, "blockchain_paused.title", &args);
+            let message = self
+                .locale_service
+                .render(&user, "blockchain_paused.message", &args);
+
+            self.notification_service
+                .send_notification(
+                    user.id,
+                    NotificationType::SystemMessage,
+                    title,
+                    Some(message),
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            permission::{Allow, Permission},
+            user_test_utils::mock_user,
+        },
+        repositories::{permission::PERMISSION_REPOSITORY, NOTIFICATION_REPOSITORY},
+    };
+    use orbit_essentials::model::ModelKey;
+
+    #[tokio::test]
+    async fn pauses_the_blockchain_after_repeated_failures() {
+        test_utils::init_canister_system();
+
+        let admin = mock_user();
+        let other_user = mock_user();
+        for user in [&admin, &other_user] {
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+        }
+
+        let permission = Permission::new(
+            Allow::users(vec![admin.id]),
+            Resource::System(SystemResourceAction::ManageSystemInfo),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let service = CircuitBreakerService::default();
+        for _ in 1..CircuitBreakerService::MIN_FAILURES {
+            service
+                .record_failure(&Blockchain::InternetComputer, "ledger unavailable")
+                .await;
+        }
+
+        assert!(!service.is_paused(&Blockchain::InternetComputer));
+
+        service
+            .record_failure(&Blockchain::InternetComputer, "ledger unavailable")
+            .await;
+
+        assert!(service.is_paused(&Blockchain::InternetComputer));
+        assert!(!service.is_paused(&Blockchain::Bitcoin));
+        assert_eq!(
+            read_system_info()
+                .get_paused_blockchain(&Blockchain::InternetComputer)
+                .unwrap()
+                .reason,
+            "ledger unavailable"
+        );
+
+        let notifications = NOTIFICATION_REPOSITORY.find_by_user_id(admin.id);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0].notification_type,
+            NotificationType::SystemMessage
+        );
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(other_user.id)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn keeps_the_blockchain_running_when_most_executions_succeed() {
+        test_utils::init_canister_system();
+
+        let service = CircuitBreakerService::default();
+        for _ in 0..CircuitBreakerService::MIN_FAILURES {
+            service.record_success(&Blockchain::Ethereum);
+            service.record_success(&Blockchain::Ethereum);
+            service
+                .record_failure(&Blockchain::Ethereum, "nonce too low")
+                .await;
+        }
+
+        assert!(!service.is_paused(&Blockchain::Ethereum));
+    }
+
+    #[tokio::test]
+    async fn resume_blockchain_clears_the_pause() {
+        test_utils::init_canister_system();
+
+        let service = CircuitBreakerService::default();
+        assert!(service.resume_blockchain(&Blockchain::Bitcoin).is_err());
+
+        for _ in 0..CircuitBreakerService::MIN_FAILURES {
+            service
+                .record_failure(&Blockchain::Bitcoin, "rpc timeout")
+                .await;
+        }
+        assert!(service.is_paused(&Blockchain::Bitcoin));
+
+        service.resume_blockchain(&Blockchain::Bitcoin).unwrap();
+
+        assert!(!service.is_paused(&Blockchain::Bitcoin));
+        assert!(read_system_info().get_paused_blockchains().is_empty());
+
+        // the failures before the pause are not counted again
+        service
+            .record_failure(&Blockchain::Bitcoin, "rpc timeout")
+            .await;
+        assert!(!service.is_paused(&Blockchain::Bitcoin));
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    core::ic_cdk::{api::print, next_time},
+    models::{ExchangeRate, FiatCurrency, FiatValue},
+    repositories::{AccountRepository, ACCOUNT_REPOSITORY},
+};
+use candid::{CandidType, Deserialize, Principal};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
+
+lazy_static! {
+    pub static ref EXCHANGE_RATE_SERVICE: Arc<ExchangeRateService> =
+        Arc::new(ExchangeRateService::new(Arc::clone(&ACCOUNT_REPOSITORY)));
+}
+
+thread_local! {
+    /// The latest rates fetched from the exchange rate canister, reset on upgrades.
+    static EXCHANGE_RATES: RefCell<HashMap<(String, FiatCurrency), ExchangeRate>> = RefCell::new(HashMap::new());
+}
+
+/// Values the balances and transfers of the accounts in fiat currencies.
+///
+/// The rates of the assets held by the accounts are fetched from the exchange rate canister (XRC) by a
+/// job and cached, the valuations only read the cache and are omitted when the rate is missing or stale.
+#[derive(Default, Debug)]
+pub struct ExchangeRateService {
+    account_repository: Arc<AccountRepository>,
+}
+
+impl ExchangeRateService {
+    pub const XRC_CANISTER_ID: &'static str = "uf6dk-hyaaa-aaaaq-qaaaq-cai";
+    /// The cycles attached to each call of the exchange rate canister, the unused ones are refunded.
+    pub const XRC_CALL_CYCLES: u128 = 1_000_000_000;
+    /// The age after which a cached rate is no longer used to value the amounts.
+    pub const MAX_RATE_AGE_NS: u64 = 60 * 60 * 1_000_000_000;
+
+    pub fn new(account_repository: Arc<AccountRepository>) -> Self {
+        Self { account_repository }
+    }
+
+    /// Returns the cached rate of the asset, unless it is stale.
+    pub fn get_rate(&self, symbol: &str, currency: FiatCurrency) -> Option<ExchangeRate> {
+        let now = next_time();
+
+        EXCHANGE_RATES.with(|rates| {
+            rates
+                .borrow()
+                .get(&(Self::xrc_symbol(symbol), currency))
+                .filter(|rate| now.saturating_sub(rate.timestamp) <= Self::MAX_RATE_AGE_NS)
+                .cloned()
+        })
+    }
+
+    /// Returns the value of the amount in each fiat currency whose rate is available.
+    pub fn fiat_values(&self, symbol: &str, amount: &candid::Nat, decimals: u32) -> Vec<FiatValue> {
+        FiatCurrency::ALL
+            .iter()
+            .filter_map(|currency| self.get_rate(symbol, *currency))
+            .map(|rate| rate.fiat_value(amount, decimals))
+            .collect()
+    }
+
+    /// Fetches the rates of the assets held by the accounts, the previous rate is kept if the fetch fails.
+    pub async fn refresh_rates(&self) {
+        let symbols: BTreeSet<String> = self
+            .account_repository
+            .list()
+            .iter()
+            .map(|account| Self::xrc_symbol(&account.symbol))
+            .collect();
+
+        for symbol in symbols {
+            for currency in FiatCurrency::ALL {
+                if symbol == currency.to_string() {
+                    continue;
+                }
+
+                match Self::fetch_rate(&symbol, currency).await {
+                    Ok(rate) => Self::cache_rate(rate),
+                    Err(error) => print(format!(
+                        "Failed to fetch the {}/{} exchange rate: {}",
+                        symbol, currency, error
+                    )),
+                }
+            }
+        }
+    }
+
+    fn cache_rate(rate: ExchangeRate) {
+        EXCHANGE_RATES.with(|rates| {
+            rates
+                .borrow_mut()
+                .insert((rate.symbol.to_owned(), rate.currency), rate)
+        });
+    }
+
+    /// The chain-key tokens are valued as the asset they are backed by (e.g. `ckBTC` as `BTC`).
+    fn xrc_symbol(symbol: &str) -> String {
+        match symbol.strip_prefix("ck") {
+            Some(backing_symbol) if !backing_symbol.is_empty() => backing_symbol.to_uppercase(),
+            _ => symbol.to_uppercase(),
+        }
+    }
+
+    async fn fetch_rate(symbol: &str, currency: FiatCurrency) -> Result<ExchangeRate, String> {
+        let request = XrcGetExchangeRateRequest {
+            base_asset: XrcAsset {
+                symbol: symbol.to_string(),
+                class: XrcAssetClass::Cryptocurrency,
+            },
+            quote_asset: XrcAsset {
+                symbol: currency.to_string(),
+                class: XrcAssetClass::FiatCurrency,
+            },
+            timestamp: None,
+        };
+
+        let (result,): (XrcGetExchangeRateResult,) = ic_cdk::api::call::call_with_payment128(
+            Principal::from_text(Self::XRC_CANISTER_ID).unwrap(),
+            "get_exchange_rate",
+            (request,),
+            Self::XRC_CALL_CYCLES,
+        )
+        .await
+        .map_err(|err| format!("rejection_code: {:?}, err: {}", err.0, err.1))?;
+
+        match result {
+            XrcGetExchangeRateResult::Ok(rate) => Ok(ExchangeRate {
+                symbol: symbol.to_string(),
+                currency,
+                rate: rate.rate,
+                decimals: rate.metadata.decimals,
+                // the exchange rate canister uses seconds since the epoch
+                timestamp: rate.timestamp.saturating_mul(1_000_000_000),
+            }),
+            XrcGetExchangeRateResult::Err(err) => Err(format!("{:?}", err)),
+        }
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum XrcAssetClass {
+    Cryptocurrency,
+    FiatCurrency,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct XrcAsset {
+    symbol: String,
+    class: XrcAssetClass,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct XrcGetExchangeRateRequest {
+    base_asset: XrcAsset,
+    quote_asset: XrcAsset,
+    timestamp: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct XrcExchangeRateMetadata {
+    decimals: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct XrcExchangeRate {
+    timestamp: u64,
+    rate: u64,
+    metadata: XrcExchangeRateMetadata,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+struct XrcOtherError {
+    code: u32,
+    description: String,
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum XrcExchangeRateError {
+    AnonymousPrincipalNotAllowed,
+    Pending,
+    CryptoBaseAssetNotFound,
+    CryptoQuoteAssetNotFound,
+    StablecoinRateNotFound,
+    StablecoinRateTooFewRates,
+    StablecoinRateZeroRate,
+    ForexInvalidTimestamp,
+    ForexBaseAssetNotFound,
+    ForexQuoteAssetNotFound,
+    ForexAssetsNotFound,
+    RateLimited,
+    NotEnoughCycles,
+    FailedToAcceptCycles,
+    InconsistentRatesReceived,
+    Other(XrcOtherError),
+}
+
+#[derive(CandidType, Deserialize, Clone, Debug)]
+enum XrcGetExchangeRateResult {
+    Ok(XrcExchangeRate),
+    Err(XrcExchangeRateError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_amounts_with_the_cached_rates() {
+        let service = ExchangeRateService::default();
+        ExchangeRateService::cache_rate(ExchangeRate {
+            symbol: "BTC".to_string(),
+            currency: FiatCurrency::EUR,
+            rate: 50_000,
+            decimals: 0,
+            timestamp: next_time(),
+        });
+
+        // the chain-key token is valued as its backing asset
+        let values = service.fiat_values("ckBTC", &candid::Nat::from(10_000_000u64), 8);
+
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].currency, FiatCurrency::EUR);
+        assert_eq!(values[0].value, candid::Nat::from(500_000u64));
+
+        assert!(service
+            .fiat_values("ICP", &candid::Nat::from(1u64), 8)
+            .is_empty());
+    }
+
+    #[test]
+    fn ignores_stale_rates() {
+        let service = ExchangeRateService::default();
+        ExchangeRateService::cache_rate(ExchangeRate {
+            symbol: "ICP".to_string(),
+            currency: FiatCurrency::USD,
+            rate: 10,
+            decimals: 0,
+            timestamp: next_time().saturating_sub(ExchangeRateService::MAX_RATE_AGE_NS + 1),
+        });
+
+        assert_eq!(service.get_rate("ICP", FiatCurrency::USD), None);
+    }
+}
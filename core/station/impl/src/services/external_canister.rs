@@ -1,11 +1,12 @@
 use super::permission::{PermissionService, PERMISSION_SERVICE};
 use super::request_policy::{RequestPolicyService, REQUEST_POLICY_SERVICE};
 use crate::core::authorization::Authorization;
-use crate::core::ic_cdk::api::print;
+use crate::core::ic_cdk::{api::print, next_time};
 use crate::core::utils::{retain_accessible_resources, PaginatedData};
 use crate::core::validation::EnsureExternalCanister;
 use crate::core::CallContext;
 use crate::errors::ExternalCanisterError;
+use crate::jobs;
 use crate::mappers::ExternalCanisterMapper;
 use crate::models::permission::Permission;
 use crate::models::request_specifier::RequestSpecifier;
@@ -23,8 +24,9 @@ use crate::models::{
     ExternalCanisterCallerMethodsPrivileges, ExternalCanisterCallerPrivileges,
     ExternalCanisterChangeCallPermissionsInput, ExternalCanisterChangeCallRequestPoliciesInput,
     ExternalCanisterChangeRequestPolicyRule, ExternalCanisterEntryId, ExternalCanisterKey,
-    ExternalCanisterPermissions, ExternalCanisterPermissionsUpdateInput,
-    ExternalCanisterRequestPolicies, ExternalCanisterRequestPoliciesUpdateInput, RequestPolicy,
+    ExternalCanisterMonitoring, ExternalCanisterMonitoringInput, ExternalCanisterPermissions,
+    ExternalCanisterPermissionsUpdateInput, ExternalCanisterRequestPolicies,
+    ExternalCanisterRequestPoliciesUpdateInput, RequestPolicy, UserId,
 };
 use crate::repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY};
 use crate::repositories::{
@@ -572,6 +574,54 @@ impl ExternalCanisterService {
         Ok(external_canister)
     }
 
+    /// Enables or disables the monitoring of an external canister.
+    ///
+    /// Changing the rules resets the observed state, so the ongoing conditions are notified again.
+    pub fn configure_external_canister_monitoring(
+        &self,
+        id: &ExternalCanisterEntryId,
+        input: ExternalCanisterMonitoringInput,
+        configured_by: UserId,
+    ) -> ServiceResult<ExternalCanister> {
+        let mut external_canister = self.get_external_canister(id)?;
+
+        external_canister.monitoring = match input {
+            ExternalCanisterMonitoringInput::Enable(rules) => {
+                Some(ExternalCanisterMonitoring::new(rules, configured_by))
+            }
+            ExternalCanisterMonitoringInput::Disable => None,
+        };
+        external_canister.validate()?;
+
+        self.external_canister_repository
+            .insert(external_canister.key(), external_canister.clone());
+
+        if external_canister.monitoring.is_some() {
+            jobs::schedule_external_canister_monitoring();
+        }
+
+        Ok(external_canister)
+    }
+
+    /// Records the module hash installed on the canister by a `ChangeExternalCanister` request.
+    ///
+    /// The canister can be changed without being registered in the station, in which case nothing is
+    /// recorded.
+    pub fn record_installed_module(&self, canister_id: &Principal, module_hash: Vec<u8>) {
+        let Some(mut external_canister) = self
+            .external_canister_repository
+            .find_by_canister_id(canister_id)
+            .and_then(|id| self.get_external_canister(&id).ok())
+        else {
+            return;
+        };
+
+        external_canister.record_installed_module(module_hash, next_time());
+
+        self.external_canister_repository
+            .insert(external_canister.key(), external_canister);
+    }
+
     // Updates the request policies of the external canister.
     fn configure_external_canister_request_policies(
         &self,
@@ -618,6 +668,7 @@ impl ExternalCanisterService {
                                 policy_id,
                                 rule: Some(updated_change_policy.rule),
                                 specifier: None,
+                                expiration_period_secs: None,
                             },
                         )?;
                     }
@@ -628,6 +679,7 @@ impl ExternalCanisterService {
                                 specifier: RequestSpecifier::ChangeExternalCanister(
                                     ExternalCanisterId::Canister(external_canister.canister_id),
                                 ),
+                                expiration_period_secs: None,
                             },
                         )?;
                     }
@@ -772,6 +824,7 @@ impl ExternalCanisterService {
                             policy_id: *policy_id,
                             rule: Some(updated_call_policy.rule.clone()),
                             specifier: None,
+                            expiration_period_secs: None,
                         },
                     )?;
                 }
@@ -810,6 +863,7 @@ impl ExternalCanisterService {
                                         .clone(),
                                 },
                             ),
+                            expiration_period_secs: None,
                         },
                     )?;
                 }
@@ -1330,6 +1384,7 @@ mod tests {
                 specifier: RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Canister(
                     Principal::from_slice(&[1; 29]),
                 )),
+                expiration_period_secs: None,
             })
             .unwrap();
 
@@ -1375,6 +1430,7 @@ mod tests {
             .add_request_policy(AddRequestPolicyOperationInput {
                 rule: RequestPolicyRule::AutoApproved,
                 specifier: RequestSpecifier::AddAccount,
+                expiration_period_secs: None,
             })
             .unwrap();
 
@@ -1429,6 +1485,7 @@ mod tests {
             .add_request_policy(AddRequestPolicyOperationInput {
                 rule: RequestPolicyRule::AutoApproved,
                 specifier: RequestSpecifier::AddAccount,
+                expiration_period_secs: None,
             })
             .unwrap();
 
@@ -1713,6 +1770,7 @@ mod tests {
                                 ),
                             ),
                         }),
+                        monitoring: None,
                     },
                 )
                 .unwrap();
@@ -1862,6 +1920,7 @@ mod tests {
                                 ),
                             ),
                         }),
+                        monitoring: None,
                     },
                 )
                 .unwrap();
@@ -1948,6 +2007,7 @@ mod tests {
                                 ),
                             ),
                         }),
+                        monitoring: None,
                     },
                 )
                 .unwrap();
@@ -2003,6 +2063,7 @@ mod tests {
                                 ),
                             ),
                         }),
+                        monitoring: None,
                     },
                 )
                 .unwrap();
@@ -2219,6 +2280,7 @@ mod tests {
                     state: None,
                     permissions: None,
                     request_policies: None,
+                    monitoring: None,
                 },
             )
             .unwrap();
@@ -2234,6 +2296,7 @@ mod tests {
                 state: None,
                 permissions: None,
                 request_policies: None,
+                monitoring: None,
             },
         );
 
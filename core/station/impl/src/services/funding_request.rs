@@ -0,0 +1,344 @@
+use crate::{
+    core::{
+        generate_uuid_v4,
+        ic_cdk::{api::print, next_time},
+        CallContext,
+    },
+    errors::FundingRequestError,
+    factories::blockchains::BlockchainApiFactory,
+    jobs,
+    mappers::HelperMapper,
+    models::{
+        AccountId, FundingRequest, FundingRequestId, FundingRequestKey, FundingRequestStatus,
+        IcrcAccount, RequestId, User,
+    },
+    repositories::{
+        FundingRequestRepository, UserRepository, FUNDING_REQUEST_REPOSITORY, USER_REPOSITORY,
+    },
+    services::{
+        AccountService, RequestService, UserService, ACCOUNT_SERVICE, REQUEST_SERVICE, USER_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+    utils::rfc3339_to_timestamp,
+};
+use station_api::{
+    CreateFundingRequestInput, CreateRequestInput, RequestOperationInput, TransferOperationInput,
+};
+use std::{str::FromStr, sync::Arc};
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref FUNDING_REQUEST_SERVICE: Arc<FundingRequestService> =
+        Arc::new(FundingRequestService::new(
+            Arc::clone(&FUNDING_REQUEST_REPOSITORY),
+            Arc::clone(&USER_REPOSITORY),
+            Arc::clone(&ACCOUNT_SERVICE),
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&REQUEST_SERVICE),
+        ));
+}
+
+/// Handles the funding requests, which let external payers fund the station accounts with ICRC-2.
+///
+/// The payer approves the allowance described by the funding request, the allowances of the pending
+/// funding requests are then watched and a transfer request that pulls the funds with `icrc2_transfer_from`
+/// is created on behalf of the requester once the allowance covers the requested amount.
+#[derive(Default, Debug)]
+pub struct FundingRequestService {
+    funding_request_repository: Arc<FundingRequestRepository>,
+    user_repository: Arc<UserRepository>,
+    account_service: Arc<AccountService>,
+    user_service: Arc<UserService>,
+    request_service: Arc<RequestService>,
+}
+
+impl FundingRequestService {
+    pub fn new(
+        funding_request_repository: Arc<FundingRequestRepository>,
+        user_repository: Arc<UserRepository>,
+        account_service: Arc<AccountService>,
+        user_service: Arc<UserService>,
+        request_service: Arc<RequestService>,
+    ) -> Self {
+        Self {
+            funding_request_repository,
+            user_repository,
+            account_service,
+            user_service,
+            request_service,
+        }
+    }
+
+    pub fn get_funding_request(&self, id: &FundingRequestId) -> ServiceResult<FundingRequest> {
+        let funding_request = self
+            .funding_request_repository
+            .get(&FundingRequestKey { id: *id })
+            .ok_or(FundingRequestError::NotFound {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?;
+
+        Ok(funding_request)
+    }
+
+    /// Returns the funding requests of the account, the most recent first.
+    pub fn list_funding_requests(
+        &self,
+        account_id: &AccountId,
+    ) -> ServiceResult<Vec<FundingRequest>> {
+        self.account_service.get_account(account_id)?;
+
+        Ok(self
+            .funding_request_repository
+            .find_by_account_id(account_id))
+    }
+
+    /// Creates a funding request for the account and starts watching the allowance of the payer.
+    pub async fn create_funding_request(
+        &self,
+        input: CreateFundingRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<FundingRequest> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.account_service.get_account(account_id.as_bytes())?;
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let payer = IcrcAccount::from_str(&input.payer).map_err(|error| {
+            FundingRequestError::InvalidPayer {
+                payer: input.payer.clone(),
+                error,
+            }
+        })?;
+
+        let now = next_time();
+        let expires_at = match input.expires_at {
+            Some(expires_at) => rfc3339_to_timestamp(expires_at.as_str()),
+            None => now.saturating_add(FundingRequest::DEFAULT_EXPIRATION_NS),
+        };
+
+        if expires_at <= now {
+            Err(FundingRequestError::InvalidExpiration)?
+        }
+
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)?;
+        let spender = blockchain_api.allowance_spender(&account)?;
+        let fee = blockchain_api.transaction_fee(&account).await?.fee;
+
+        let funding_request = FundingRequest {
+            id: *generate_uuid_v4().await.as_bytes(),
+            account_id: account.id,
+            payer,
+            amount: input.amount,
+            fee: candid::Nat(fee),
+            ledger_canister_id: spender.ledger_canister_id,
+            spender: spender.spender,
+            reference: input.reference,
+            status: FundingRequestStatus::Pending,
+            requested_by: requester.id,
+            expires_at,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        funding_request.validate()?;
+
+        self.funding_request_repository
+            .insert(funding_request.key(), funding_request.clone());
+
+        jobs::schedule_funding_request_watch();
+
+        Ok(funding_request)
+    }
+
+    /// Checks the allowances of the pending funding requests, requesting the collection of the funds of
+    /// the approved ones and expiring the stale ones.
+    ///
+    /// Returns whether there are funding requests left to watch.
+    pub async fn watch_pending_funding_requests(&self) -> bool {
+        for funding_request in self.funding_request_repository.find_pending() {
+            if funding_request.expires_at <= next_time() {
+                self.update_status(&funding_request, FundingRequestStatus::Expired);
+
+                continue;
+            }
+
+            match self.is_allowance_approved(&funding_request).await {
+                Ok(true) => {
+                    if let Some(request_id) = self.request_collect(&funding_request).await {
+                        self.update_status(
+                            &funding_request,
+                            FundingRequestStatus::CollectRequested { request_id },
+                        );
+                    }
+                }
+                Ok(false) => {}
+                Err(error) => print(format!(
+                    "Failed to fetch the allowance of the funding request {}: {}",
+                    Uuid::from_bytes(funding_request.id).hyphenated(),
+                    error
+                )),
+            }
+        }
+
+        !self.funding_request_repository.find_pending().is_empty()
+    }
+
+    /// Whether the payer approved an allowance that covers the funding request and does not expire before it.
+    async fn is_allowance_approved(&self, funding_request: &FundingRequest) -> ServiceResult<bool> {
+        let account = self
+            .account_service
+            .get_account(&funding_request.account_id)?;
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)?;
+        let allowance = blockchain_api
+            .allowance(&account, &funding_request.payer)
+            .await?;
+
+        Ok(allowance.amount >= funding_request.approve_amount().0
+            && allowance
+                .expires_at
+                .map_or(true, |expires_at| expires_at > next_time()))
+    }
+
+    /// Creates the transfer request that pulls the funds from the payer on behalf of the requester.
+    async fn request_collect(&self, funding_request: &FundingRequest) -> Option<RequestId> {
+        let requester = self
+            .user_repository
+            .get(&User::key(funding_request.requested_by))
+            .filter(|user| user.is_active())?;
+        let ctx = CallContext::new(*requester.identities.first()?);
+
+        let result = self
+            .request_service
+            .create_request(
+                CreateRequestInput {
+                    operation: RequestOperationInput::Transfer(TransferOperationInput {
+                        from_account_id: Uuid::from_bytes(funding_request.account_id)
+                            .hyphenated()
+                            .to_string(),
+                        to: funding_request.spender.to_string(),
+                        amount: funding_request.amount.clone(),
+                        fee: Some(funding_request.fee.clone()),
+                        metadata: Vec::new(),
+                        network: None,
+                        spend_from: Some(funding_request.payer.to_string()),
+                        memo: None,
+                        category: None,
+                        asset_id: None,
+                    }),
+                    title: Some(match &funding_request.reference {
+                        Some(reference) => format!("Collect funding {}", reference),
+                        None => "Collect funding".to_string(),
+                    }),
+                    summary: Some(format!(
+                        "Automatically requested after {} approved the allowance of the funding request {}.",
+                        funding_request.payer,
+                        Uuid::from_bytes(funding_request.id).hyphenated()
+                    )),
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                &ctx,
+            )
+            .await;
+
+        match result {
+            Ok(request) => Some(request.id),
+            Err(error) => {
+                print(format!(
+                    "Failed to request the collection of the funding request {}: {}",
+                    Uuid::from_bytes(funding_request.id).hyphenated(),
+                    error
+                ));
+
+                None
+            }
+        }
+    }
+
+    fn update_status(&self, funding_request: &FundingRequest, status: FundingRequestStatus) {
+        let mut funding_request = funding_request.clone();
+        funding_request.status = status;
+        funding_request.last_modification_timestamp = next_time();
+
+        self.funding_request_repository
+            .insert(funding_request.key(), funding_request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            account_test_utils::mock_account, funding_request_test_utils::mock_funding_request,
+            user_test_utils::mock_user,
+        },
+        repositories::ACCOUNT_REPOSITORY,
+    };
+    use candid::Principal;
+
+    #[tokio::test]
+    async fn create_funding_request_describes_the_approval() {
+        test_utils::init_canister_system();
+
+        let mut user = mock_user();
+        user.identities = vec![Principal::from_slice(&[9; 29])];
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let funding_request = FUNDING_REQUEST_SERVICE
+            .create_funding_request(
+                CreateFundingRequestInput {
+                    account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                    payer: Principal::from_slice(&[1; 29]).to_text(),
+                    amount: candid::Nat::from(100_000_000u64),
+                    reference: Some("INV-001".to_string()),
+                    expires_at: None,
+                },
+                &CallContext::new(user.identities[0]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(funding_request.status, FundingRequestStatus::Pending);
+        assert_eq!(funding_request.requested_by, user.id);
+        assert!(funding_request.approve_amount() > funding_request.amount);
+        assert_eq!(
+            FUNDING_REQUEST_SERVICE
+                .list_funding_requests(&account.id)
+                .unwrap(),
+            vec![funding_request]
+        );
+    }
+
+    #[tokio::test]
+    async fn expired_funding_requests_are_no_longer_watched() {
+        test_utils::init_canister_system();
+
+        let mut funding_request = mock_funding_request();
+        funding_request.expires_at = 0;
+        FUNDING_REQUEST_REPOSITORY.insert(funding_request.key(), funding_request.clone());
+
+        assert!(
+            !FUNDING_REQUEST_SERVICE
+                .watch_pending_funding_requests()
+                .await
+        );
+        assert_eq!(
+            FUNDING_REQUEST_SERVICE
+                .get_funding_request(&funding_request.id)
+                .unwrap()
+                .status,
+            FundingRequestStatus::Expired
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    core::{ic_cdk::next_time, read_system_info, write_system_info},
+    models::{
+        english_message, normalize_locale, render_template, LocaleCatalog, LocaleCatalogReport,
+        User, DEFAULT_LOCALE,
+    },
+    repositories::{LocaleCatalogRepository, LOCALE_CATALOG_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use std::{collections::BTreeMap, sync::Arc};
+
+lazy_static! {
+    pub static ref LOCALE_SERVICE: Arc<LocaleService> =
+        Arc::new(LocaleService::new(Arc::clone(&LOCALE_CATALOG_REPOSITORY)));
+}
+
+/// Renders the messages emitted by the station in the locale of their recipient.
+#[derive(Default, Debug)]
+pub struct LocaleService {
+    locale_catalog_repository: Arc<LocaleCatalogRepository>,
+}
+
+impl LocaleService {
+    pub fn new(locale_catalog_repository: Arc<LocaleCatalogRepository>) -> Self {
+        Self {
+            locale_catalog_repository,
+        }
+    }
+
+    /// Renders the message for the user, falling back from the locale of the user to the default
+    /// locale of the station and then to English for the keys their catalogs do not translate.
+    pub fn render(&self, user: &User, key: &str, args: &[(&str, &str)]) -> String {
+        let system_info = read_system_info();
+        let locales = [
+            user.locale.as_deref(),
+            system_info.get_default_locale(),
+            Some(DEFAULT_LOCALE),
+        ];
+
+        let template = locales
+            .into_iter()
+            .flatten()
+            .find_map(|locale| {
+                self.locale_catalog_repository
+                    .get(&locale.to_string())
+                    .and_then(|catalog| catalog.message(key).map(str::to_string))
+            })
+            .or_else(|| english_message(key).map(str::to_string))
+            .unwrap_or_else(|| key.to_string());
+
+        render_template(&template, args)
+    }
+
+    /// Stores the catalog of the locale and reports how it compares with the keys emitted by the
+    /// station, an empty catalog removes the locale.
+    pub fn upload_catalog(
+        &self,
+        locale: &str,
+        messages: BTreeMap<String, String>,
+        set_as_default: bool,
+    ) -> ServiceResult<LocaleCatalogReport> {
+        let report = LocaleCatalogReport::new(&messages);
+        let catalog = LocaleCatalog {
+            locale: normalize_locale(locale),
+            messages: messages
+                .into_iter()
+                .filter(|(key, _)| english_message(key).is_some())
+                .collect(),
+            last_modification_timestamp: next_time(),
+        };
+
+        catalog.validate()?;
+
+        if catalog.messages.is_empty() {
+            self.locale_catalog_repository.remove(&catalog.locale);
+        } else {
+            self.locale_catalog_repository
+                .insert(catalog.locale.clone(), catalog.clone());
+        }
+
+        if set_as_default {
+            let mut system_info = read_system_info();
+            system_info.set_default_locale(Some(catalog.locale));
+            write_system_info(system_info);
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::test_utils, models::user_test_utils::mock_user};
+
+    fn upload(
+        locale: &str,
+        messages: &[(&str, &str)],
+        set_as_default: bool,
+    ) -> LocaleCatalogReport {
+        LOCALE_SERVICE
+            .upload_catalog(
+                locale,
+                messages
+                    .iter()
+                    .map(|(key, template)| (key.to_string(), template.to_string()))
+                    .collect(),
+                set_as_default,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn renders_with_the_fallback_chain() {
+        test_utils::init_canister_system();
+
+        upload(
+            "fr",
+            &[(
+                "blockchain_paused.title",
+                "Les transferts sur {blockchain} sont suspendus",
+            )],
+            true,
+        );
+        upload(
+            "de",
+            &[("blockchain_paused.message", "Letzter Fehler: {reason}")],
+            false,
+        );
+
+        let mut user = mock_user();
+        user.locale = Some("de".to_string());
+
+        // translated by the locale of the user
+        assert_eq!(
+            LOCALE_SERVICE.render(&user, "blockchain_paused.message", &[("reason", "timeout")]),
+            "Letzter Fehler: timeout"
+        );
+        // translated by the default locale of the station
+        assert_eq!(
+            LOCALE_SERVICE.render(&user, "blockchain_paused.title", &[("blockchain", "icp")]),
+            "Les transferts sur icp sont suspendus"
+        );
+        // translated by neither, hence in English
+        assert_eq!(
+            LOCALE_SERVICE.render(&user, "self_check.passed.title", &[("version", "1.0.0")]),
+            "Station 1.0.0 passed its self-check"
+        );
+    }
+
+    #[test]
+    fn upload_reports_and_drops_the_unused_keys() {
+        test_utils::init_canister_system();
+
+        let report = upload(
+            "PT-BR",
+            &[
+                ("self_check.passed.title", "Estação {version} passou"),
+                ("self_check.passed.titel", "Estação {version} passou"),
+            ],
+            false,
+        );
+
+        assert_eq!(
+            report.unused_keys,
+            vec!["self_check.passed.titel".to_string()]
+        );
+        assert!(!report
+            .missing_keys
+            .contains(&"self_check.passed.title".to_string()));
+
+        let catalog = LOCALE_CATALOG_REPOSITORY.get(&"pt-br".to_string()).unwrap();
+        assert_eq!(catalog.messages.len(), 1);
+        assert_eq!(read_system_info().get_default_locale(), None);
+
+        upload("pt-br", &[], false);
+
+        assert!(LOCALE_CATALOG_REPOSITORY
+            .get(&"pt-br".to_string())
+            .is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_locales() {
+        assert!(LOCALE_SERVICE
+            .upload_catalog("pt_BR", BTreeMap::new(), false)
+            .is_err());
+    }
+}
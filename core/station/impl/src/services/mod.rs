@@ -3,6 +3,9 @@
 mod account;
 pub use account::*;
 
+mod asset;
+pub use asset::*;
+
 mod address_book;
 pub use address_book::*;
 
@@ -30,10 +33,91 @@ pub use request_policy::*;
 mod change_canister;
 pub use change_canister::*;
 
+mod circuit_breaker;
+pub use circuit_breaker::*;
+
+mod exchange_rate;
+pub use exchange_rate::*;
+
 mod external_canister;
 pub use external_canister::*;
 
+mod funding_request;
+pub use funding_request::*;
+
+mod payout_run;
+pub use payout_run::*;
+
+mod scheduled_policy_change;
+pub use scheduled_policy_change::*;
+
+mod support_access;
+pub use support_access::*;
+
+mod transaction_history;
+pub use transaction_history::*;
+
+mod spending_summary;
+pub use spending_summary::*;
+
 pub mod permission;
 
+mod policy_suggestion;
+pub use policy_suggestion::*;
+
+mod calendar_feed;
+pub use calendar_feed::*;
+
 mod disaster_recovery;
 pub use disaster_recovery::*;
+
+mod proof_of_reserves;
+pub use proof_of_reserves::*;
+
+mod transfer_receipt;
+pub use transfer_receipt::*;
+
+mod record_certification;
+pub use record_certification::*;
+
+mod account_webhook;
+pub use account_webhook::*;
+
+mod request_view;
+pub use request_view::*;
+
+mod request_comment;
+pub use request_comment::*;
+
+mod scheduled_transfer;
+pub use scheduled_transfer::*;
+
+mod capability_grant;
+pub use capability_grant::*;
+
+mod archive;
+pub use archive::*;
+
+mod self_check;
+pub use self_check::*;
+
+mod locale;
+pub use locale::*;
+
+mod notification_template;
+pub use notification_template::*;
+
+mod transfer_category;
+pub use transfer_category::*;
+
+mod transfer_export;
+pub use transfer_export::*;
+
+mod pending_execution;
+pub use pending_execution::*;
+
+mod approval_reminder;
+pub use approval_reminder::*;
+
+mod attestation;
+pub use attestation::*;
@@ -2,12 +2,17 @@ use crate::{
     core::{generate_uuid_v4, ic_cdk::next_time, utils::SortDirection, CallContext},
     errors::NotificationError,
     mappers::HelperMapper,
-    models::{Notification, NotificationId, NotificationStatus, NotificationType, UserId},
+    models::{
+        Notification, NotificationId, NotificationStatus, NotificationTemplateEvent,
+        NotificationType, Request, UserId,
+    },
     repositories::{
         NotificationFindByUserWhereClause, NotificationRepository, NotificationSortBy,
         NOTIFICATION_REPOSITORY,
     },
-    services::{UserService, USER_SERVICE},
+    services::{
+        NotificationTemplateService, UserService, NOTIFICATION_TEMPLATE_SERVICE, USER_SERVICE,
+    },
 };
 use lazy_static::lazy_static;
 use orbit_essentials::repository::Repository;
@@ -22,6 +27,7 @@ lazy_static! {
         Arc::new(NotificationService::new(
             Arc::clone(&USER_SERVICE),
             Arc::clone(&NOTIFICATION_REPOSITORY),
+            Arc::clone(&NOTIFICATION_TEMPLATE_SERVICE),
         ));
 }
 
@@ -29,16 +35,19 @@ lazy_static! {
 pub struct NotificationService {
     user_service: Arc<UserService>,
     notification_repository: Arc<NotificationRepository>,
+    notification_template_service: Arc<NotificationTemplateService>,
 }
 
 impl NotificationService {
     pub fn new(
         user_service: Arc<UserService>,
         notification_repository: Arc<NotificationRepository>,
+        notification_template_service: Arc<NotificationTemplateService>,
     ) -> Self {
         Self {
             user_service,
             notification_repository,
+            notification_template_service,
         }
     }
 
@@ -98,6 +107,7 @@ impl NotificationService {
         Ok(())
     }
 
+    /// Sends the notification to the user, unless the user muted its type.
     pub async fn send_notification(
         &self,
         user_id: UserId,
@@ -105,6 +115,13 @@ impl NotificationService {
         title: String,
         message: Option<String>,
     ) {
+        if self.user_service.get_user(&user_id).is_ok_and(|user| {
+            user.muted_notification_types
+                .contains(&notification_type.to_string())
+        }) {
+            return;
+        }
+
         let now = next_time();
         let notification_id = generate_uuid_v4().await;
         let notification = Notification {
@@ -128,6 +145,26 @@ impl NotificationService {
         self.notification_repository
             .insert(notification.to_key(), notification);
     }
+
+    /// Sends a notification about the request, worded by the template configured for its operation
+    /// if any, otherwise with the title and summary of the request.
+    pub async fn send_request_notification(
+        &self,
+        user_id: UserId,
+        notification_type: NotificationType,
+        request: &Request,
+    ) {
+        let (title, message) =
+            NotificationTemplateEvent::from_notification_type(&notification_type)
+                .and_then(|event| {
+                    self.notification_template_service
+                        .render(event, request, &user_id)
+                })
+                .unwrap_or_else(|| (request.title.to_owned(), request.summary.to_owned()));
+
+        self.send_notification(user_id, notification_type, title, message)
+            .await;
+    }
 }
 
 #[cfg(test)]
@@ -135,7 +172,10 @@ mod tests {
     use super::*;
     use crate::{
         core::test_utils,
-        models::{notification_test_utils::mock_notification, user_test_utils::mock_user, User},
+        models::{
+            notification_test_utils::mock_notification, user_test_utils::mock_user,
+            RequestCommentedNotification, User,
+        },
         repositories::UserRepository,
     };
     use candid::Principal;
@@ -202,4 +242,36 @@ mod tests {
             NotificationStatus::Read
         );
     }
+
+    #[tokio::test]
+    async fn muted_notification_types_are_not_sent() {
+        let mut ctx = setup();
+        ctx.caller_user.muted_notification_types = vec!["request-commented".to_string()];
+        UserRepository::default().insert(ctx.caller_user.to_key(), ctx.caller_user.clone());
+
+        ctx.service
+            .send_notification(
+                ctx.caller_user.id,
+                NotificationType::RequestCommented(RequestCommentedNotification {
+                    request_id: [1; 16],
+                    comment_id: [2; 16],
+                    author_id: [3; 16],
+                }),
+                "Muted".to_string(),
+                None,
+            )
+            .await;
+        ctx.service
+            .send_notification(
+                ctx.caller_user.id,
+                NotificationType::SystemMessage,
+                "Sent".to_string(),
+                None,
+            )
+            .await;
+
+        let notifications = ctx.repository.find_by_user_id(ctx.caller_user.id);
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].title, "Sent");
+    }
 }
@@ -0,0 +1,260 @@
+use crate::{
+    core::{ic_cdk::next_time, read_system_info},
+    models::{
+        render_template, NotificationTemplate, NotificationTemplateChange,
+        NotificationTemplateEvent, NotificationTemplateKey, Request, RequestOperationType, User,
+        UserId,
+    },
+    repositories::{
+        NotificationTemplateRepository, UserRepository, NOTIFICATION_TEMPLATE_REPOSITORY,
+        USER_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref NOTIFICATION_TEMPLATE_SERVICE: Arc<NotificationTemplateService> =
+        Arc::new(NotificationTemplateService::new(
+            Arc::clone(&NOTIFICATION_TEMPLATE_REPOSITORY),
+            Arc::clone(&USER_REPOSITORY),
+        ));
+}
+
+/// Words the notifications about requests with the templates configured for their operation.
+#[derive(Default, Debug)]
+pub struct NotificationTemplateService {
+    notification_template_repository: Arc<NotificationTemplateRepository>,
+    user_repository: Arc<UserRepository>,
+}
+
+impl NotificationTemplateService {
+    pub fn new(
+        notification_template_repository: Arc<NotificationTemplateRepository>,
+        user_repository: Arc<UserRepository>,
+    ) -> Self {
+        Self {
+            notification_template_repository,
+            user_repository,
+        }
+    }
+
+    pub fn list_templates(&self) -> Vec<NotificationTemplate> {
+        self.notification_template_repository.list()
+    }
+
+    /// Applies the changes of an approved `ManageSystemInfo` request, the templates were validated
+    /// when the request was created.
+    pub fn apply_changes(&self, changes: Vec<NotificationTemplateChange>) {
+        for change in changes {
+            match change {
+                NotificationTemplateChange::Set {
+                    key,
+                    title,
+                    message,
+                } => {
+                    self.notification_template_repository.insert(
+                        key.clone(),
+                        NotificationTemplate {
+                            key,
+                            title,
+                            message,
+                            last_modification_timestamp: next_time(),
+                        },
+                    );
+                }
+                NotificationTemplateChange::Remove(key) => {
+                    self.notification_template_repository.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Renders the title and message of the notification of the request event for the user.
+    ///
+    /// The template in the locale of the user is preferred, then the one in the default locale of the
+    /// station and then the one for any locale, and within a locale the template of the operation of
+    /// the request over the one for any operation. Returns `None` if no template applies.
+    pub fn render(
+        &self,
+        event: NotificationTemplateEvent,
+        request: &Request,
+        user_id: &UserId,
+    ) -> Option<(String, Option<String>)> {
+        let system_info = read_system_info();
+        let user_locale = self
+            .user_repository
+            .get(&User::key(*user_id))
+            .and_then(|user| user.locale);
+        let operation_type = RequestOperationType::from(request.operation.clone());
+
+        let template = [
+            user_locale.as_deref(),
+            system_info.get_default_locale(),
+            None,
+        ]
+        .into_iter()
+        .flat_map(|locale| {
+            [Some(operation_type.clone()), None]
+                .into_iter()
+                .map(move |operation_type| NotificationTemplateKey {
+                    event,
+                    operation_type,
+                    locale: locale.map(str::to_string),
+                })
+        })
+        .find_map(|key| self.notification_template_repository.get(&key))?;
+
+        let request_id = Uuid::from_bytes(request.id).hyphenated().to_string();
+        let requester = self
+            .user_repository
+            .get(&User::key(request.requested_by))
+            .map(|user| user.name)
+            .unwrap_or_else(|| {
+                Uuid::from_bytes(request.requested_by)
+                    .hyphenated()
+                    .to_string()
+            });
+        let operation = operation_type.to_string();
+        let summary = request.summary.clone().unwrap_or_default();
+        let args = [
+            ("request_id", request_id.as_str()),
+            ("request_title", request.title.as_str()),
+            ("request_summary", summary.as_str()),
+            ("operation", operation.as_str()),
+            ("requester", requester.as_str()),
+        ];
+
+        Some((
+            render_template(&template.title, &args),
+            template
+                .message
+                .map(|message| render_template(&message, &args))
+                .or_else(|| request.summary.clone()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            request_test_utils::mock_request, user_test_utils::mock_user, AddUserOperation,
+            AddUserOperationInput, RequestOperation, UserStatus,
+        },
+    };
+    use orbit_essentials::model::ModelKey;
+
+    fn set(
+        event: NotificationTemplateEvent,
+        operation_type: Option<RequestOperationType>,
+        locale: Option<&str>,
+        title: &str,
+    ) -> NotificationTemplateChange {
+        NotificationTemplateChange::Set {
+            key: NotificationTemplateKey {
+                event,
+                operation_type,
+                locale: locale.map(str::to_string),
+            },
+            title: title.to_string(),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn renders_the_most_specific_template() {
+        test_utils::init_canister_system();
+
+        let mut requester = mock_user();
+        requester.name = "Alice".to_string();
+        USER_REPOSITORY.insert(requester.to_key(), requester.clone());
+
+        let mut recipient = mock_user();
+        recipient.locale = Some("fr".to_string());
+        USER_REPOSITORY.insert(recipient.to_key(), recipient.clone());
+
+        let mut request = mock_request();
+        request.requested_by = requester.id;
+        request.title = "Pay the rent".to_string();
+        request.summary = Some("For March".to_string());
+
+        let event = NotificationTemplateEvent::RequestCreated;
+
+        assert_eq!(
+            NOTIFICATION_TEMPLATE_SERVICE.render(event, &request, &recipient.id),
+            None
+        );
+
+        NOTIFICATION_TEMPLATE_SERVICE.apply_changes(vec![
+            set(event, None, None, "{requester} requested {operation}"),
+            set(
+                event,
+                Some(RequestOperationType::Transfer),
+                None,
+                "Transfer: {request_title}",
+            ),
+            set(
+                event,
+                Some(RequestOperationType::Transfer),
+                Some("de"),
+                "Überweisung: {request_title}",
+            ),
+            set(event, None, Some("fr"), "{requester} a demandé {operation}"),
+        ]);
+
+        // the locale of the user takes precedence over the operation
+        assert_eq!(
+            NOTIFICATION_TEMPLATE_SERVICE.render(event, &request, &recipient.id),
+            Some((
+                "Alice a demandé transfer".to_string(),
+                Some("For March".to_string())
+            ))
+        );
+
+        recipient.locale = None;
+        USER_REPOSITORY.insert(recipient.to_key(), recipient.clone());
+
+        assert_eq!(
+            NOTIFICATION_TEMPLATE_SERVICE
+                .render(event, &request, &recipient.id)
+                .map(|(title, _)| title),
+            Some("Transfer: Pay the rent".to_string())
+        );
+
+        request.operation = RequestOperation::AddUser(AddUserOperation {
+            user_id: None,
+            input: AddUserOperationInput {
+                name: "Bob".to_string(),
+                identities: vec![],
+                groups: vec![],
+                status: UserStatus::Active,
+                approve_only_identities: vec![],
+            },
+        });
+
+        assert_eq!(
+            NOTIFICATION_TEMPLATE_SERVICE
+                .render(event, &request, &recipient.id)
+                .map(|(title, _)| title),
+            Some("Alice requested add_user".to_string())
+        );
+
+        NOTIFICATION_TEMPLATE_SERVICE.apply_changes(vec![NotificationTemplateChange::Remove(
+            NotificationTemplateKey {
+                event,
+                operation_type: None,
+                locale: None,
+            },
+        )]);
+
+        assert_eq!(
+            NOTIFICATION_TEMPLATE_SERVICE.render(event, &request, &recipient.id),
+            None
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    core::{generate_uuid_v4, ic_cdk::next_time, CallContext},
+    errors::PayoutRunError,
+    models::{
+        PayoutRun, PayoutRunAssetTotal, PayoutRunId, PayoutRunKey, PayoutRunRequestOutcome,
+        PayoutRunSummary, Request, RequestId, RequestOperation,
+    },
+    repositories::{
+        PayoutRunRepository, RequestRepository, PAYOUT_RUN_REPOSITORY, REQUEST_REPOSITORY,
+    },
+    services::{
+        AccountService, RequestService, UserService, ACCOUNT_SERVICE, REQUEST_SERVICE, USER_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+};
+use station_api::{CreatePayoutRunInput, CreateRequestInput, RequestOperationInput};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref PAYOUT_RUN_SERVICE: Arc<PayoutRunService> = Arc::new(PayoutRunService::new(
+        Arc::clone(&PAYOUT_RUN_REPOSITORY),
+        Arc::clone(&REQUEST_REPOSITORY),
+        Arc::clone(&ACCOUNT_SERVICE),
+        Arc::clone(&USER_SERVICE),
+        Arc::clone(&REQUEST_SERVICE),
+    ));
+}
+
+/// Handles the payout runs, which group the transfer requests created from one batch so that
+/// they can be tracked as a unit.
+#[derive(Default, Debug)]
+pub struct PayoutRunService {
+    payout_run_repository: Arc<PayoutRunRepository>,
+    request_repository: Arc<RequestRepository>,
+    account_service: Arc<AccountService>,
+    user_service: Arc<UserService>,
+    request_service: Arc<RequestService>,
+}
+
+impl PayoutRunService {
+    pub fn new(
+        payout_run_repository: Arc<PayoutRunRepository>,
+        request_repository: Arc<RequestRepository>,
+        account_service: Arc<AccountService>,
+        user_service: Arc<UserService>,
+        request_service: Arc<RequestService>,
+    ) -> Self {
+        Self {
+            payout_run_repository,
+            request_repository,
+            account_service,
+            user_service,
+            request_service,
+        }
+    }
+
+    pub fn get_payout_run(&self, id: &PayoutRunId) -> ServiceResult<PayoutRun> {
+        let payout_run = self
+            .payout_run_repository
+            .get(&PayoutRunKey { id: *id })
+            .ok_or(PayoutRunError::NotFound {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?;
+
+        Ok(payout_run)
+    }
+
+    /// Returns the payout run with the status and totals per asset of its transfer requests.
+    ///
+    /// The requests that were archived since the run was created are left out.
+    pub fn get_payout_run_summary(&self, id: &PayoutRunId) -> ServiceResult<PayoutRunSummary> {
+        let payout_run = self.get_payout_run(id)?;
+        let mut outcomes = Vec::with_capacity(payout_run.request_ids.len());
+        let mut totals: Vec<PayoutRunAssetTotal> = Vec::new();
+
+        for request_id in &payout_run.request_ids {
+            let Some(request) = self.request_repository.get(&Request::key(*request_id)) else {
+                continue;
+            };
+            let outcome = PayoutRunRequestOutcome::from(&request.status);
+            outcomes.push(outcome);
+
+            let RequestOperation::Transfer(operation) = &request.operation else {
+                continue;
+            };
+            let Ok(account) = self
+                .account_service
+                .get_account(&operation.input.from_account_id)
+                .and_then(|account| {
+                    account
+                        .for_asset(operation.input.asset_id.as_ref())
+                        .map_err(Into::into)
+                })
+            else {
+                continue;
+            };
+
+            let blockchain = account.blockchain.to_string();
+            match totals
+                .iter_mut()
+                .find(|total| total.blockchain == blockchain && total.symbol == account.symbol)
+            {
+                Some(total) => total.add(&operation.input.amount, outcome),
+                None => {
+                    let mut total = PayoutRunAssetTotal {
+                        blockchain,
+                        symbol: account.symbol,
+                        decimals: account.decimals,
+                        requested: candid::Nat::from(0u64),
+                        completed: candid::Nat::from(0u64),
+                        pending: candid::Nat::from(0u64),
+                    };
+                    total.add(&operation.input.amount, outcome);
+                    totals.push(total);
+                }
+            }
+        }
+
+        Ok(PayoutRunSummary::new(payout_run, &outcomes, totals))
+    }
+
+    /// Creates a transfer request for each of the transfers and groups them in a payout run.
+    ///
+    /// The run is only created if all its requests are, the requests already created are cancelled
+    /// otherwise.
+    pub async fn create_payout_run(
+        &self,
+        input: CreatePayoutRunInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<PayoutRun> {
+        let creator = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let name = input.name.trim().to_string();
+
+        PayoutRun::validate_name(&name)?;
+        PayoutRun::validate_transfer_count(input.transfers.len())?;
+
+        let transfer_count = input.transfers.len();
+        let mut request_ids = Vec::with_capacity(transfer_count);
+        for (index, transfer) in input.transfers.into_iter().enumerate() {
+            let result = self
+                .request_service
+                .create_request(
+                    CreateRequestInput {
+                        operation: RequestOperationInput::Transfer(transfer),
+                        title: Some(format!("{} ({}/{})", name, index + 1, transfer_count)),
+                        summary: input.summary.clone(),
+                        execution_plan: input.execution_plan.clone(),
+                        confidential: None,
+                        tags: None,
+                        depends_on: None,
+                    },
+                    ctx,
+                )
+                .await;
+
+            match result {
+                Ok(request) => request_ids.push(request.id),
+                Err(error) => {
+                    self.cancel_requests(&request_ids, &name);
+
+                    Err(PayoutRunError::TransferRequestFailed {
+                        index,
+                        error: error.to_string(),
+                    })?
+                }
+            }
+        }
+
+        let payout_run = PayoutRun {
+            id: *generate_uuid_v4().await.as_bytes(),
+            name,
+            request_ids,
+            created_by: creator.id,
+            created_timestamp: next_time(),
+        };
+
+        payout_run.validate()?;
+
+        self.payout_run_repository
+            .insert(payout_run.key(), payout_run.clone());
+
+        Ok(payout_run)
+    }
+
+    fn cancel_requests(&self, request_ids: &[RequestId], name: &str) {
+        for request_id in request_ids {
+            if let Some(request) = self.request_repository.get(&Request::key(*request_id)) {
+                self.request_repository.cancel_request(
+                    request,
+                    format!("The payout run {} could not be created.", name),
+                    next_time(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        account_test_utils::mock_account, payout_run_test_utils::mock_payout_run,
+        request_test_utils::mock_request, RequestStatus,
+    };
+    use crate::repositories::ACCOUNT_REPOSITORY;
+
+    fn add_transfer_request(
+        from_account_id: [u8; 16],
+        amount: u64,
+        status: RequestStatus,
+    ) -> Request {
+        let mut request = mock_request();
+        request.status = status;
+        if let RequestOperation::Transfer(operation) = &mut request.operation {
+            operation.input.from_account_id = from_account_id;
+            operation.input.amount = candid::Nat::from(amount);
+        }
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        request
+    }
+
+    #[test]
+    fn summary_aggregates_the_transfer_requests() {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let completed = add_transfer_request(
+            account.id,
+            100,
+            RequestStatus::Completed { completed_at: 0 },
+        );
+        let pending = add_transfer_request(account.id, 20, RequestStatus::Created);
+        let rejected = add_transfer_request(account.id, 3, RequestStatus::Rejected);
+
+        let mut payout_run = mock_payout_run();
+        payout_run.request_ids = vec![completed.id, pending.id, rejected.id];
+        PAYOUT_RUN_REPOSITORY.insert(payout_run.key(), payout_run.clone());
+
+        let summary = PAYOUT_RUN_SERVICE
+            .get_payout_run_summary(&payout_run.id)
+            .unwrap();
+
+        assert_eq!(summary.status, crate::models::PayoutRunStatus::Pending);
+        assert_eq!(
+            (
+                summary.pending_count,
+                summary.completed_count,
+                summary.failed_count
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!(summary.totals.len(), 1);
+        assert_eq!(summary.totals[0].symbol, account.symbol);
+        assert_eq!(summary.totals[0].requested, candid::Nat::from(123u64));
+        assert_eq!(summary.totals[0].completed, candid::Nat::from(100u64));
+        assert_eq!(summary.totals[0].pending, candid::Nat::from(20u64));
+    }
+
+    #[test]
+    fn summary_of_unknown_run_fails() {
+        assert!(PAYOUT_RUN_SERVICE.get_payout_run_summary(&[9; 16]).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    core::{utils::retain_accessible_resources, CallContext},
+    jobs::{next_request_execution_at, next_transfer_execution_at},
+    models::{
+        resource::{RequestResourceAction, Resource, ResourceId},
+        Account, PendingExecutions, RequestStatusCode, TransferStatus,
+    },
+    repositories::{
+        AccountRepository, RequestRepository, TransferRepository, ACCOUNT_REPOSITORY,
+        REQUEST_REPOSITORY, TRANSFER_REPOSITORY,
+    },
+    services::{CircuitBreakerService, CIRCUIT_BREAKER_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref PENDING_EXECUTION_SERVICE: Arc<PendingExecutionService> =
+        Arc::new(PendingExecutionService::new(
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&TRANSFER_REPOSITORY),
+            Arc::clone(&ACCOUNT_REPOSITORY),
+            Arc::clone(&CIRCUIT_BREAKER_SERVICE),
+        ));
+}
+
+/// Shows the approved requests and the created transfers that wait for the execution jobs.
+#[derive(Default, Debug)]
+pub struct PendingExecutionService {
+    request_repository: Arc<RequestRepository>,
+    transfer_repository: Arc<TransferRepository>,
+    account_repository: Arc<AccountRepository>,
+    circuit_breaker_service: Arc<CircuitBreakerService>,
+}
+
+impl PendingExecutionService {
+    pub fn new(
+        request_repository: Arc<RequestRepository>,
+        transfer_repository: Arc<TransferRepository>,
+        account_repository: Arc<AccountRepository>,
+        circuit_breaker_service: Arc<CircuitBreakerService>,
+    ) -> Self {
+        Self {
+            request_repository,
+            transfer_repository,
+            account_repository,
+            circuit_breaker_service,
+        }
+    }
+
+    /// Returns the queues of the execution jobs with the time of their next run.
+    ///
+    /// Only the entries of the requests the caller can read are returned, they keep their position
+    /// in the whole queue.
+    pub fn list_pending_executions(&self, ctx: &CallContext) -> PendingExecutions {
+        let requests =
+            self.request_repository
+                .find_by_status(RequestStatusCode::Scheduled, None, None);
+        let transfers = self.transfer_repository.find_by_status(
+            TransferStatus::Created.to_string(),
+            None,
+            None,
+        );
+
+        let mut requests = PendingExecutions::queue_requests(requests);
+        retain_accessible_resources(ctx, &mut requests, |pending| {
+            Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+                pending.request_id,
+            )))
+        });
+
+        let mut transfers = PendingExecutions::queue_transfers(transfers, |transfer| {
+            self.account_repository
+                .get(&Account::key(transfer.from_account))
+                .is_some_and(|account| self.circuit_breaker_service.is_paused(&account.blockchain))
+        });
+        retain_accessible_resources(ctx, &mut transfers, |pending| {
+            Resource::Request(RequestResourceAction::Read(ResourceId::Id(
+                pending.request_id,
+            )))
+        });
+
+        PendingExecutions {
+            requests,
+            transfers,
+            next_request_execution_at: next_request_execution_at(),
+            next_transfer_execution_at: next_transfer_execution_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{
+            permission::{Allow, Permission},
+            request_test_utils::mock_request,
+            transfer_test_utils::mock_transfer,
+            user_test_utils::mock_user,
+            RequestStatus,
+        },
+        repositories::{permission::PERMISSION_REPOSITORY, USER_REPOSITORY},
+    };
+    use orbit_essentials::{cdk::mocks::TEST_CANISTER_ID, model::ModelKey};
+
+    #[test]
+    fn lists_the_scheduled_requests_and_created_transfers() {
+        let service = PendingExecutionService {
+            request_repository: Arc::new(RequestRepository::with_empty_observers()),
+            transfer_repository: Arc::new(TransferRepository::with_empty_observers()),
+            ..Default::default()
+        };
+
+        let mut scheduled = mock_request();
+        scheduled.status = RequestStatus::Scheduled { scheduled_at: 10 };
+        let mut completed = mock_request();
+        completed.status = RequestStatus::Completed { completed_at: 5 };
+        for request in [&scheduled, &completed] {
+            service
+                .request_repository
+                .insert(request.to_key(), request.clone());
+        }
+
+        let created = mock_transfer();
+        service
+            .transfer_repository
+            .insert(created.to_key(), created.clone());
+
+        let pending = service.list_pending_executions(&CallContext::new(TEST_CANISTER_ID));
+
+        assert_eq!(pending.requests.len(), 1);
+        assert_eq!(pending.requests[0].request_id, scheduled.id);
+        assert_eq!(pending.requests[0].scheduled_at, 10);
+        assert_eq!(pending.transfers.len(), 1);
+        assert_eq!(pending.transfers[0].transfer_id, created.id);
+        assert!(!pending.transfers[0].paused);
+    }
+
+    #[test]
+    fn hides_the_confidential_requests_from_readers() {
+        let service = PendingExecutionService {
+            request_repository: Arc::new(RequestRepository::with_empty_observers()),
+            transfer_repository: Arc::new(TransferRepository::with_empty_observers()),
+            ..Default::default()
+        };
+
+        let reader = mock_user();
+        USER_REPOSITORY.insert(reader.to_key(), reader.clone());
+
+        let permission = Permission::new(
+            Allow::users(vec![reader.id]),
+            Resource::Request(RequestResourceAction::Read(ResourceId::Any)),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let mut visible = mock_request();
+        visible.status = RequestStatus::Scheduled { scheduled_at: 20 };
+        let mut confidential = mock_request();
+        confidential.status = RequestStatus::Scheduled { scheduled_at: 10 };
+        confidential.confidential = true;
+        for request in [&visible, &confidential] {
+            service
+                .request_repository
+                .insert(request.to_key(), request.clone());
+        }
+
+        let mut transfer = mock_transfer();
+        transfer.request_id = confidential.id;
+        service
+            .transfer_repository
+            .insert(transfer.to_key(), transfer.clone());
+
+        let pending = service.list_pending_executions(&CallContext::new(reader.identities[0]));
+
+        assert_eq!(pending.requests.len(), 1);
+        assert_eq!(pending.requests[0].request_id, visible.id);
+        assert_eq!(pending.requests[0].queue_position, 2);
+        assert!(pending.transfers.is_empty());
+    }
+}
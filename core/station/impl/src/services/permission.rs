@@ -4,11 +4,18 @@ use crate::{
         validation::{EnsureIdExists, EnsureUser, EnsureUserGroup},
     },
     models::{
-        permission::{Allow, Permission},
+        permission::{
+            AccessPolicyDocument, AccessPolicyDocumentPermission, AccessPolicyDocumentUser,
+            AccessPolicyImportConflict, AccessPolicyImportReport, Allow, Permission,
+        },
         resource::Resource,
-        EditPermissionOperationInput, User, UserGroup,
+        AddTeamOperationInput, AddUserGroupOperationInput, EditPermissionOperationInput,
+        EditUserOperationInput, User, UserGroup, UserGroupId, UserId,
+    },
+    repositories::{
+        permission::{PermissionRepository, PERMISSION_REPOSITORY},
+        USER_GROUP_REPOSITORY, USER_REPOSITORY,
     },
-    repositories::permission::{PermissionRepository, PERMISSION_REPOSITORY},
     services::{UserGroupService, UserService, USER_GROUP_SERVICE, USER_SERVICE},
 };
 use candid::CandidType;
@@ -16,7 +23,10 @@ use lazy_static::lazy_static;
 use orbit_essentials::{api::ServiceResult, model::ModelKey};
 use orbit_essentials::{model::ModelValidator, repository::Repository};
 use station_api::ListPermissionsInput;
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    sync::Arc,
+};
 
 lazy_static! {
     pub static ref PERMISSION_SERVICE: Arc<PermissionService> = Arc::new(PermissionService::new(
@@ -96,6 +106,52 @@ impl PermissionService {
         Ok(permission)
     }
 
+    /// Sets up a new team by creating its user group, adding the members to it and allowing it on
+    /// the resources.
+    ///
+    /// The members and resources are validated before the group is created, so that an invalid
+    /// input does not leave a group without its members or permissions behind.
+    pub async fn add_team(&self, input: AddTeamOperationInput) -> ServiceResult<UserGroup> {
+        EnsureUser::id_list_exists(&input.member_ids)?;
+        for resource in &input.resources {
+            resource.validate()?;
+        }
+
+        let user_group = self
+            .user_group_service
+            .create(AddUserGroupOperationInput { name: input.name })
+            .await?;
+
+        for user_id in input.member_ids.iter().collect::<BTreeSet<_>>() {
+            let mut groups = self.user_service.get_user(user_id)?.groups;
+            groups.push(user_group.id);
+
+            self.user_service
+                .edit_user(EditUserOperationInput {
+                    user_id: *user_id,
+                    name: None,
+                    identities: None,
+                    groups: Some(groups),
+                    status: None,
+                    cancel_pending_requests: None,
+                    approve_only_identities: None,
+                })
+                .await?;
+        }
+
+        for resource in &input.resources {
+            let mut permission = self.get_permission(resource);
+            if !permission.allow.user_groups.contains(&user_group.id) {
+                permission.allow.user_groups.push(user_group.id);
+            }
+
+            self.permission_repository
+                .insert(permission.key(), permission.to_owned());
+        }
+
+        Ok(user_group)
+    }
+
     /// Lists permissions with optional pagination.
     pub async fn list_permissions(
         &self,
@@ -147,6 +203,199 @@ impl PermissionService {
 
         Ok(PermissionDependenciesResponse { groups, users })
     }
+
+    /// Exports the permissions of the station as a portable document.
+    pub fn export_access_policies(&self) -> AccessPolicyDocument {
+        let mut group_names: BTreeMap<UserGroupId, String> = BTreeMap::new();
+        let mut users: BTreeMap<UserId, AccessPolicyDocumentUser> = BTreeMap::new();
+        let mut permissions = Vec::new();
+
+        for permission in self.permission_repository.list() {
+            let mut user_groups = Vec::new();
+            for group_id in &permission.allow.user_groups {
+                if let Some(name) = group_names.get(group_id) {
+                    user_groups.push(name.clone());
+                } else if let Ok(user_group) = self.user_group_service.get(group_id) {
+                    group_names.insert(*group_id, user_group.name.clone());
+                    user_groups.push(user_group.name);
+                }
+            }
+
+            let mut user_names = Vec::new();
+            for user_id in &permission.allow.users {
+                if let Some(user) = users.get(user_id) {
+                    user_names.push(user.name.clone());
+                } else if let Ok(user) = self.user_service.get_user(user_id) {
+                    user_names.push(user.name.clone());
+                    users.insert(
+                        *user_id,
+                        AccessPolicyDocumentUser {
+                            name: user.name,
+                            identities: user.identities,
+                        },
+                    );
+                }
+            }
+
+            permissions.push(AccessPolicyDocumentPermission {
+                resource: permission.resource,
+                auth_scope: permission.allow.auth_scope,
+                user_groups,
+                users: user_names,
+            });
+        }
+
+        AccessPolicyDocument {
+            user_groups: group_names
+                .into_values()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect(),
+            users: users.into_values().collect(),
+            permissions,
+        }
+    }
+
+    /// Returns the report of importing the document without changing the station.
+    pub async fn preview_access_policies_import(
+        &self,
+        document: &AccessPolicyDocument,
+    ) -> ServiceResult<AccessPolicyImportReport> {
+        self.merge_access_policies(document, true).await
+    }
+
+    /// Merges the permissions of the document into the station.
+    ///
+    /// The users and groups of the document are added to the ones already allowed and the auth scope
+    /// of the document replaces the current one. The groups missing in the station are created, while
+    /// the missing users and resources are left out and reported.
+    pub async fn import_access_policies(
+        &self,
+        document: &AccessPolicyDocument,
+    ) -> ServiceResult<AccessPolicyImportReport> {
+        self.merge_access_policies(document, false).await
+    }
+
+    async fn merge_access_policies(
+        &self,
+        document: &AccessPolicyDocument,
+        dry_run: bool,
+    ) -> ServiceResult<AccessPolicyImportReport> {
+        let mut report = AccessPolicyImportReport::default();
+
+        let group_names = document
+            .user_groups
+            .iter()
+            .chain(
+                document
+                    .permissions
+                    .iter()
+                    .flat_map(|permission| permission.user_groups.iter()),
+            )
+            .collect::<BTreeSet<_>>();
+
+        // the groups created by a preview have no id, but the permissions are not stored either
+        let mut group_ids: BTreeMap<&String, Option<UserGroupId>> = BTreeMap::new();
+        for name in group_names {
+            let group_id = match USER_GROUP_REPOSITORY.find_by_name(name) {
+                Some(user_group) => {
+                    report
+                        .conflicts
+                        .push(AccessPolicyImportConflict::ExistingUserGroup { name: name.clone() });
+
+                    Some(user_group.id)
+                }
+                None if dry_run => {
+                    report.created_user_groups.push(name.clone());
+
+                    None
+                }
+                None => {
+                    let user_group = self
+                        .user_group_service
+                        .create(AddUserGroupOperationInput { name: name.clone() })
+                        .await?;
+                    report.created_user_groups.push(name.clone());
+
+                    Some(user_group.id)
+                }
+            };
+
+            group_ids.insert(name, group_id);
+        }
+
+        let user_names = document
+            .permissions
+            .iter()
+            .flat_map(|permission| permission.users.iter())
+            .collect::<BTreeSet<_>>();
+
+        let mut user_ids: BTreeMap<&String, UserId> = BTreeMap::new();
+        for name in user_names {
+            let user_id = document
+                .users
+                .iter()
+                .filter(|user| &user.name == name)
+                .flat_map(|user| user.identities.iter())
+                .find_map(|identity| USER_REPOSITORY.find_by_identity(identity))
+                .map(|user| user.id)
+                .or_else(|| USER_REPOSITORY.find_by_name(name));
+
+            match user_id {
+                Some(user_id) => {
+                    user_ids.insert(name, user_id);
+                }
+                None => report
+                    .conflicts
+                    .push(AccessPolicyImportConflict::MissingUser { name: name.clone() }),
+            }
+        }
+
+        for imported in &document.permissions {
+            if let Err(e) = imported.resource.validate() {
+                report
+                    .conflicts
+                    .push(AccessPolicyImportConflict::InvalidResource {
+                        resource: imported.resource.clone(),
+                        reason: e.to_string(),
+                    });
+
+                continue;
+            }
+
+            let mut permission = self.get_permission(&imported.resource);
+            permission.allow.auth_scope = imported.auth_scope.clone();
+
+            for group_id in imported
+                .user_groups
+                .iter()
+                .filter_map(|name| group_ids.get(name).copied().flatten())
+            {
+                if !permission.allow.user_groups.contains(&group_id) {
+                    permission.allow.user_groups.push(group_id);
+                }
+            }
+
+            for user_id in imported
+                .users
+                .iter()
+                .filter_map(|name| user_ids.get(name).copied())
+            {
+                if !permission.allow.users.contains(&user_id) {
+                    permission.allow.users.push(user_id);
+                }
+            }
+
+            if !dry_run {
+                self.permission_repository
+                    .insert(permission.key(), permission);
+            }
+
+            report.imported_permissions += 1;
+        }
+
+        Ok(report)
+    }
 }
 
 #[cfg(test)]
@@ -157,8 +406,8 @@ mod tests {
         models::{
             permission::{permission_test_utils::mock_permission, AuthScope},
             resource::{AccountResourceAction, RequestResourceAction, ResourceId},
-            user_group_test_utils::mock_user_group,
-            user_test_utils::mock_user,
+            user_group_test_utils::{self, mock_user_group},
+            user_test_utils::{self, mock_user},
         },
         repositories::{USER_GROUP_REPOSITORY, USER_REPOSITORY},
     };
@@ -351,4 +600,69 @@ mod tests {
             Permission::new(Allow::default(), resource)
         );
     }
+
+    #[tokio::test]
+    async fn import_access_policies_merges_the_exported_document() {
+        disable_mock_resource_validation();
+
+        let finance = user_group_test_utils::add_group("Finance");
+        let user = user_test_utils::add_user(&[1; 16]);
+        let resource = Resource::Request(RequestResourceAction::List);
+        PERMISSION_REPOSITORY.insert(
+            resource.clone(),
+            Permission::new(
+                Allow {
+                    auth_scope: AuthScope::Restricted,
+                    users: vec![user.id],
+                    user_groups: vec![finance.id],
+                },
+                resource.clone(),
+            ),
+        );
+
+        let mut document = PERMISSION_SERVICE.export_access_policies();
+
+        assert_eq!(document.user_groups, vec!["Finance".to_string()]);
+        assert_eq!(document.users.len(), 1);
+        assert_eq!(document.permissions[0].users, vec![user.name.clone()]);
+
+        document.user_groups.push("Auditors".to_string());
+        document.permissions[0]
+            .user_groups
+            .push("Auditors".to_string());
+        document.permissions[0].users.push("bob".to_string());
+
+        let preview = PERMISSION_SERVICE
+            .preview_access_policies_import(&document)
+            .await
+            .unwrap();
+
+        assert_eq!(preview.created_user_groups, vec!["Auditors".to_string()]);
+        assert!(USER_GROUP_REPOSITORY.find_by_name("Auditors").is_none());
+
+        let report = PERMISSION_SERVICE
+            .import_access_policies(&document)
+            .await
+            .unwrap();
+
+        assert_eq!(report, preview);
+        assert_eq!(report.imported_permissions, 1);
+        assert_eq!(
+            report.conflicts,
+            vec![
+                AccessPolicyImportConflict::ExistingUserGroup {
+                    name: "Finance".to_string()
+                },
+                AccessPolicyImportConflict::MissingUser {
+                    name: "bob".to_string()
+                },
+            ]
+        );
+
+        let auditors = USER_GROUP_REPOSITORY.find_by_name("Auditors").unwrap();
+        let permission = PERMISSION_SERVICE.get_permission(&resource);
+
+        assert_eq!(permission.allow.users, vec![user.id]);
+        assert_eq!(permission.allow.user_groups, vec![finance.id, auditors.id]);
+    }
 }
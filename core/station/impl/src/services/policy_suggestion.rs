@@ -0,0 +1,419 @@
+use crate::{
+    core::ic_cdk::next_time,
+    models::{
+        permission::{AuthScope, Permission},
+        request_specifier::UserSpecifier,
+        EditPermissionOperationInput, EditRequestPolicyOperationInput, Request, RequestPolicy,
+        RequestPolicyRule, User, UserId,
+    },
+    repositories::{
+        permission::{PermissionRepository, PERMISSION_REPOSITORY},
+        RequestPolicyRepository, RequestRepository, RequestWhereClause, UserRepository,
+        REQUEST_POLICY_REPOSITORY, REQUEST_REPOSITORY, USER_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    repository::Repository,
+    types::{Timestamp, UUID},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+lazy_static! {
+    pub static ref POLICY_SUGGESTION_SERVICE: Arc<PolicySuggestionService> =
+        Arc::new(PolicySuggestionService::new(
+            Arc::clone(&PERMISSION_REPOSITORY),
+            Arc::clone(&REQUEST_POLICY_REPOSITORY),
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&USER_REPOSITORY),
+        ));
+}
+
+/// The operation that applies a suggestion, ready to be submitted as a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolicySuggestionOperation {
+    EditPermission(EditPermissionOperationInput),
+    EditRequestPolicy(EditRequestPolicyOperationInput),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicySuggestion {
+    /// Why the current permission or policy is broader than its usage.
+    pub reason: String,
+    pub operation: PolicySuggestionOperation,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicySuggestions {
+    /// The requests created since `analyzed_from` that the suggestions are based on.
+    pub analyzed_requests: u64,
+    pub analyzed_from: Timestamp,
+    pub suggestions: Vec<PolicySuggestion>,
+}
+
+/// Suggests least-privilege permissions and request policies based on who actually creates and
+/// approves the requests of the station.
+///
+/// Only the permissions and policies that were used by the analyzed requests are considered, since
+/// an unused grant can't tell whether it is too broad or simply not needed yet.
+#[derive(Default, Debug)]
+pub struct PolicySuggestionService {
+    permission_repository: Arc<PermissionRepository>,
+    request_policy_repository: Arc<RequestPolicyRepository>,
+    request_repository: Arc<RequestRepository>,
+    user_repository: Arc<UserRepository>,
+}
+
+impl PolicySuggestionService {
+    /// The requests created within this window are analyzed.
+    pub const ANALYSIS_WINDOW_NS: u64 = 90 * 24 * 60 * 60 * 1_000_000_000;
+
+    pub fn new(
+        permission_repository: Arc<PermissionRepository>,
+        request_policy_repository: Arc<RequestPolicyRepository>,
+        request_repository: Arc<RequestRepository>,
+        user_repository: Arc<UserRepository>,
+    ) -> Self {
+        Self {
+            permission_repository,
+            request_policy_repository,
+            request_repository,
+            user_repository,
+        }
+    }
+
+    /// Returns the suggested permission and request policy changes.
+    pub fn suggest_policies(&self) -> ServiceResult<PolicySuggestions> {
+        let analyzed_from = next_time().saturating_sub(Self::ANALYSIS_WINDOW_NS);
+        let requests = self.find_requests_created_since(analyzed_from)?;
+        let active_users: Vec<User> = self
+            .user_repository
+            .list()
+            .into_iter()
+            .filter(User::is_active)
+            .collect();
+
+        let mut suggestions = self.suggest_permissions(&requests, &active_users);
+        suggestions.extend(self.suggest_request_policies(&requests, &active_users));
+
+        Ok(PolicySuggestions {
+            analyzed_requests: requests.len() as u64,
+            analyzed_from,
+            suggestions,
+        })
+    }
+
+    fn find_requests_created_since(&self, from: Timestamp) -> ServiceResult<Vec<Request>> {
+        let request_ids = self.request_repository.find_ids_where(
+            RequestWhereClause {
+                created_dt_from: Some(from),
+                created_dt_to: None,
+                expiration_dt_from: None,
+                expiration_dt_to: None,
+                operation_types: vec![],
+                statuses: vec![],
+                approvers: vec![],
+                not_approvers: vec![],
+                requesters: vec![],
+                not_requesters: vec![],
+                excluded_ids: vec![],
+                tags: vec![],
+                visible_to: None,
+            },
+            None,
+        )?;
+
+        Ok(request_ids
+            .into_iter()
+            .filter_map(|id| self.request_repository.get(&Request::key(id)))
+            .collect())
+    }
+
+    /// Suggests to restrict the permissions that allow creating requests to the users and groups
+    /// that actually created them.
+    fn suggest_permissions(
+        &self,
+        requests: &[Request],
+        active_users: &[User],
+    ) -> Vec<PolicySuggestion> {
+        let active_user_ids: BTreeSet<UserId> = active_users.iter().map(|user| user.id).collect();
+
+        self.permission_repository
+            .list()
+            .into_iter()
+            .filter_map(|permission| {
+                let requesters: BTreeSet<UserId> = requests
+                    .iter()
+                    .filter(|request| {
+                        request
+                            .operation
+                            .to_resources()
+                            .contains(&permission.resource)
+                    })
+                    .map(|request| request.requested_by)
+                    .filter(|user_id| active_user_ids.contains(user_id))
+                    .collect();
+
+                Self::suggest_permission(&permission, &requesters, active_users)
+            })
+            .collect()
+    }
+
+    fn suggest_permission(
+        permission: &Permission,
+        requesters: &BTreeSet<UserId>,
+        active_users: &[User],
+    ) -> Option<PolicySuggestion> {
+        if requesters.is_empty() {
+            return None;
+        }
+
+        let used_groups: BTreeSet<UUID> = permission
+            .allow
+            .user_groups
+            .iter()
+            .filter(|group_id| {
+                active_users
+                    .iter()
+                    .any(|user| requesters.contains(&user.id) && user.groups.contains(group_id))
+            })
+            .cloned()
+            .collect();
+
+        // requesters that are not covered by the used groups are granted directly
+        let users: BTreeSet<UserId> = requesters
+            .iter()
+            .filter(|user_id| {
+                !active_users.iter().any(|user| {
+                    user.id == **user_id && user.groups.iter().any(|id| used_groups.contains(id))
+                })
+            })
+            .cloned()
+            .collect();
+
+        let current_users: BTreeSet<UserId> = permission.allow.users.iter().cloned().collect();
+        let current_groups: BTreeSet<UUID> = permission.allow.user_groups.iter().cloned().collect();
+
+        if permission.allow.auth_scope == AuthScope::Restricted
+            && users == current_users
+            && used_groups == current_groups
+        {
+            return None;
+        }
+
+        Some(PolicySuggestion {
+            reason: format!(
+                "Only {} user(s) created requests for {} in the analyzed period, but the permission allows {}.",
+                requesters.len(),
+                permission.resource,
+                match permission.allow.auth_scope {
+                    AuthScope::Public => "everyone".to_string(),
+                    AuthScope::Authenticated => "all authenticated users".to_string(),
+                    AuthScope::Restricted => format!(
+                        "{} user(s) and {} group(s)",
+                        current_users.len(),
+                        current_groups.len()
+                    ),
+                }
+            ),
+            operation: PolicySuggestionOperation::EditPermission(EditPermissionOperationInput {
+                resource: permission.resource.clone(),
+                auth_scope: Some(AuthScope::Restricted),
+                users: Some(users.into_iter().collect()),
+                user_groups: Some(used_groups.into_iter().collect()),
+            }),
+        })
+    }
+
+    /// Suggests to replace the quorum of the request policies by the users that actually voted on
+    /// the requests they matched, keeping the same minimum number of approvals.
+    fn suggest_request_policies(
+        &self,
+        requests: &[Request],
+        active_users: &[User],
+    ) -> Vec<PolicySuggestion> {
+        let mut approvers_by_policy: BTreeMap<UUID, (RequestPolicy, BTreeSet<UserId>)> =
+            BTreeMap::new();
+
+        for request in requests {
+            let matching_policies = request
+                .operation
+                .to_resources()
+                .iter()
+                .flat_map(|resource| {
+                    self.request_policy_repository
+                        .find_by_resource(resource.to_owned())
+                })
+                .collect::<Vec<_>>();
+
+            for policy in matching_policies {
+                approvers_by_policy
+                    .entry(policy.id)
+                    .or_insert_with(|| (policy, BTreeSet::new()))
+                    .1
+                    .extend(
+                        request
+                            .approvals
+                            .iter()
+                            .map(|approval| approval.approver_id),
+                    );
+            }
+        }
+
+        approvers_by_policy
+            .into_values()
+            .filter_map(|(policy, approvers)| {
+                Self::suggest_request_policy(&policy, &approvers, active_users)
+            })
+            .collect()
+    }
+
+    fn suggest_request_policy(
+        policy: &RequestPolicy,
+        approvers: &BTreeSet<UserId>,
+        active_users: &[User],
+    ) -> Option<PolicySuggestion> {
+        let RequestPolicyRule::Quorum(specifier, min_approved) = &policy.rule else {
+            return None;
+        };
+
+        let eligible: BTreeSet<UserId> = active_users
+            .iter()
+            .filter(|user| match specifier {
+                UserSpecifier::Any => true,
+                UserSpecifier::Group(group_ids) => {
+                    user.groups.iter().any(|id| group_ids.contains(id))
+                }
+                UserSpecifier::Id(_) => false,
+            })
+            .map(|user| user.id)
+            .collect();
+
+        let approvers: BTreeSet<UserId> = approvers.intersection(&eligible).cloned().collect();
+
+        // the quorum must still be reachable by the remaining approvers
+        if approvers.len() < *min_approved as usize || approvers.len() >= eligible.len() {
+            return None;
+        }
+
+        Some(PolicySuggestion {
+            reason: format!(
+                "Only {} of the {} eligible user(s) voted on the requests matching the policy in the analyzed period.",
+                approvers.len(),
+                eligible.len()
+            ),
+            operation: PolicySuggestionOperation::EditRequestPolicy(
+                EditRequestPolicyOperationInput {
+                    policy_id: policy.id,
+                    specifier: None,
+                    rule: Some(RequestPolicyRule::Quorum(
+                        UserSpecifier::Id(approvers.into_iter().collect()),
+                        *min_approved,
+                    )),
+                    expiration_period_secs: None,
+                },
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        permission::Allow,
+        request_specifier::RequestSpecifier,
+        resource::{AccountResourceAction, Resource, ResourceId, ResourceIds},
+        user_test_utils::mock_user,
+    };
+
+    #[test]
+    fn permission_is_restricted_to_the_requesters() {
+        let mut requester = mock_user();
+        requester.groups = vec![[1; 16]];
+        let other = mock_user();
+        let permission = Permission::new(
+            Allow::authenticated(),
+            Resource::Account(AccountResourceAction::Transfer(ResourceId::Any)),
+        );
+
+        let suggestion = PolicySuggestionService::suggest_permission(
+            &permission,
+            &BTreeSet::from([requester.id]),
+            &[requester.clone(), other],
+        )
+        .unwrap();
+
+        assert_eq!(
+            suggestion.operation,
+            PolicySuggestionOperation::EditPermission(EditPermissionOperationInput {
+                resource: permission.resource.clone(),
+                auth_scope: Some(AuthScope::Restricted),
+                users: Some(vec![requester.id]),
+                user_groups: Some(vec![]),
+            })
+        );
+
+        let restricted = Permission::new(
+            Allow::users(vec![requester.id]),
+            permission.resource.clone(),
+        );
+
+        assert!(PolicySuggestionService::suggest_permission(
+            &restricted,
+            &BTreeSet::from([requester.id]),
+            &[requester],
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn quorum_is_narrowed_to_the_actual_approvers() {
+        let group_id = [1; 16];
+        let users: Vec<User> = (0..3)
+            .map(|_| {
+                let mut user = mock_user();
+                user.groups = vec![group_id];
+                user
+            })
+            .collect();
+        let policy = RequestPolicy {
+            id: [2; 16],
+            specifier: RequestSpecifier::Transfer(ResourceIds::Any),
+            rule: RequestPolicyRule::Quorum(UserSpecifier::Group(vec![group_id]), 2),
+            expiration_period_secs: None,
+        };
+
+        let suggestion = PolicySuggestionService::suggest_request_policy(
+            &policy,
+            &BTreeSet::from([users[0].id, users[1].id]),
+            &users,
+        )
+        .unwrap();
+
+        let mut approvers = vec![users[0].id, users[1].id];
+        approvers.sort();
+
+        assert_eq!(
+            suggestion.operation,
+            PolicySuggestionOperation::EditRequestPolicy(EditRequestPolicyOperationInput {
+                policy_id: policy.id,
+                specifier: None,
+                rule: Some(RequestPolicyRule::Quorum(UserSpecifier::Id(approvers), 2)),
+                expiration_period_secs: None,
+            })
+        );
+
+        // a single approver can't reach the quorum of the policy
+        assert!(PolicySuggestionService::suggest_request_policy(
+            &policy,
+            &BTreeSet::from([users[0].id]),
+            &users,
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    core::{
+        certification::{certify_reserves_hash, reserves_witness},
+        ic_cdk::{api::data_certificate, next_time},
+    },
+    errors::AccountError,
+    mappers::account::AccountMapper,
+    repositories::{AccountRepository, ACCOUNT_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, http::cbor_encode, repository::Repository, utils::timestamp_to_rfc3339,
+};
+use sha2::{Digest, Sha256};
+use station_api::{GetProofOfReservesResponse, ReservesSnapshotDTO};
+use std::{cell::RefCell, sync::Arc};
+
+lazy_static! {
+    pub static ref PROOF_OF_RESERVES_SERVICE: Arc<ProofOfReservesService> =
+        Arc::new(ProofOfReservesService::new(Arc::clone(&ACCOUNT_REPOSITORY)));
+}
+
+thread_local! {
+    /// The candid encoding of the latest certified reserves snapshot, reset on upgrades.
+    static RESERVES_SNAPSHOT: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// Certifies the addresses and last-known balances of all the accounts, so that auditors can verify the
+/// reserves of the station with the certificate of a query call instead of trusting the replica.
+///
+/// Certified data can only be set in update calls, hence the snapshot is taken periodically by a job
+/// and the query serves the latest snapshot rather than the current balances.
+#[derive(Default, Debug)]
+pub struct ProofOfReservesService {
+    account_repository: Arc<AccountRepository>,
+}
+
+impl ProofOfReservesService {
+    pub fn new(account_repository: Arc<AccountRepository>) -> Self {
+        Self { account_repository }
+    }
+
+    /// Takes a new snapshot of the reserves and certifies its hash.
+    pub fn certify_reserves(&self) {
+        let snapshot = ReservesSnapshotDTO {
+            taken_at: timestamp_to_rfc3339(&next_time()),
+            accounts: self
+                .account_repository
+                .list()
+                .into_iter()
+                .map(AccountMapper::to_reserve_dto)
+                .collect(),
+        };
+
+        let snapshot =
+            candid::encode_one(&snapshot).expect("Failed to encode the reserves snapshot");
+        let snapshot_hash: [u8; 32] = Sha256::digest(&snapshot).into();
+
+        RESERVES_SNAPSHOT
+            .with(|reserves_snapshot| *reserves_snapshot.borrow_mut() = Some(snapshot));
+
+        certify_reserves_hash(snapshot_hash);
+    }
+
+    /// Returns the latest reserves snapshot with the certificate and witness proving its hash is certified.
+    pub fn get_proof_of_reserves(&self) -> ServiceResult<GetProofOfReservesResponse> {
+        let snapshot = RESERVES_SNAPSHOT
+            .with(|reserves_snapshot| reserves_snapshot.borrow().clone())
+            .ok_or(AccountError::ReservesNotCertified)?;
+
+        Ok(GetProofOfReservesResponse {
+            snapshot,
+            certificate: data_certificate(),
+            witness: cbor_encode(&reserves_witness()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account_test_utils::add_account;
+
+    #[test]
+    fn fails_before_the_reserves_are_certified() {
+        let service = ProofOfReservesService::default();
+
+        assert!(service.get_proof_of_reserves().is_err());
+    }
+
+    #[test]
+    fn serves_the_certified_snapshot() {
+        let account = add_account(&[1; 16]);
+        let service = ProofOfReservesService::new(Arc::clone(&ACCOUNT_REPOSITORY));
+
+        service.certify_reserves();
+
+        let proof = service.get_proof_of_reserves().unwrap();
+        let snapshot: ReservesSnapshotDTO = candid::decode_one(&proof.snapshot).unwrap();
+
+        assert_eq!(snapshot.accounts.len(), 1);
+        assert_eq!(snapshot.accounts[0].addresses, vec![account.address]);
+        assert!(proof.certificate.is_none());
+    }
+}
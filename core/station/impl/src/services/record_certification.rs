@@ -0,0 +1,311 @@
+use crate::{
+    core::{
+        certification::{
+            certify_request_hash, certify_transfer_hash, insert_request_hash, insert_transfer_hash,
+            remove_request_hash, remove_transfer_hash, request_witness, transfer_witness,
+            update_certified_data,
+        },
+        ic_cdk::api::data_certificate,
+        observer::Observer,
+    },
+    models::{Request, RequestId, Transfer, TransferId},
+    repositories::{
+        RequestRepository, TransferRepository, REQUEST_REPOSITORY, TRANSFER_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::http::cbor_encode;
+use sha2::{Digest, Sha256};
+use station_api::CertifiedRecordDTO;
+use std::{cell::RefCell, sync::Arc};
+
+lazy_static! {
+    pub static ref RECORD_CERTIFICATION_SERVICE: Arc<RecordCertificationService> =
+        Arc::new(RecordCertificationService::new(
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&TRANSFER_REPOSITORY),
+        ));
+}
+
+thread_local! {
+    /// How far the certified records are restored after an upgrade, unset once all of them are.
+    static RESTORE_CURSOR: RefCell<Option<RestoreCursor>> = const { RefCell::new(None) };
+}
+
+/// The records left to add to the certified tree after an upgrade.
+#[derive(Clone, Copy, Debug)]
+enum RestoreCursor {
+    /// The requests after the given id, and then all the transfers.
+    Requests(Option<RequestId>),
+    /// The transfers after the given id.
+    Transfers(Option<TransferId>),
+}
+
+/// Certifies the hash of every request and transfer, so that the clients can read them with query
+/// calls and still verify the responses.
+///
+/// The hashes are updated by the repositories on every write.
+#[derive(Default, Debug)]
+pub struct RecordCertificationService {
+    request_repository: Arc<RequestRepository>,
+    transfer_repository: Arc<TransferRepository>,
+}
+
+impl RecordCertificationService {
+    pub fn new(
+        request_repository: Arc<RequestRepository>,
+        transfer_repository: Arc<TransferRepository>,
+    ) -> Self {
+        Self {
+            request_repository,
+            transfer_repository,
+        }
+    }
+
+    /// Returns the certified encoding of the request with the certificate and witness of its hash, or
+    /// `None` while the certified records are being restored after an upgrade.
+    pub fn request_certification(&self, request: &Request) -> Option<CertifiedRecordDTO> {
+        if Self::is_restoring() {
+            return None;
+        }
+
+        let (record, _) = Self::encode_request(request);
+
+        Some(CertifiedRecordDTO {
+            record,
+            certificate: data_certificate(),
+            witness: cbor_encode(&request_witness(&request.id)),
+        })
+    }
+
+    /// Returns the certified encoding of the transfer with the certificate and witness of its hash, or
+    /// `None` while the certified records are being restored after an upgrade.
+    pub fn transfer_certification(&self, transfer: &Transfer) -> Option<CertifiedRecordDTO> {
+        if Self::is_restoring() {
+            return None;
+        }
+
+        let (record, _) = Self::encode_transfer(transfer);
+
+        Some(CertifiedRecordDTO {
+            record,
+            certificate: data_certificate(),
+            witness: cbor_encode(&transfer_witness(&transfer.id)),
+        })
+    }
+
+    /// Whether the certified records are still being restored, the records are not certified until then.
+    fn is_restoring() -> bool {
+        RESTORE_CURSOR.with(|cursor| cursor.borrow().is_some())
+    }
+
+    /// Marks all the stored requests and transfers to be added back to the certified tree, which is
+    /// kept in heap memory and therefore lost on upgrades.
+    ///
+    /// Encoding every record does not fit in a single message, so they are restored in batches by
+    /// `restore_certified_records`.
+    pub fn start_restoring_certified_records(&self) {
+        RESTORE_CURSOR.with(|cursor| *cursor.borrow_mut() = Some(RestoreCursor::Requests(None)));
+    }
+
+    /// Adds the hashes of up to `max_batch_size` records to the certified tree and updates the
+    /// certified data, returning `true` once all the records are restored.
+    pub fn restore_certified_records(&self, max_batch_size: usize) -> bool {
+        let Some(mut cursor) = RESTORE_CURSOR.with(|cursor| *cursor.borrow()) else {
+            return true;
+        };
+
+        let mut remaining = max_batch_size;
+
+        if let RestoreCursor::Requests(after) = cursor {
+            let requests = self.request_repository.list_after(after, remaining);
+            remaining -= requests.len();
+
+            cursor = match requests.last() {
+                Some(last) if remaining == 0 => RestoreCursor::Requests(Some(last.id)),
+                _ => RestoreCursor::Transfers(None),
+            };
+
+            for request in requests {
+                let (_, request_hash) = Self::encode_request(&request);
+
+                insert_request_hash(request.id, request_hash);
+            }
+        }
+
+        let is_restored = match cursor {
+            RestoreCursor::Transfers(after) if remaining > 0 => {
+                let transfers = self.transfer_repository.list_after(after, remaining);
+                let is_restored = transfers.len() < remaining;
+
+                if let Some(last) = transfers.last() {
+                    cursor = RestoreCursor::Transfers(Some(last.id));
+                }
+
+                for transfer in transfers {
+                    let (_, transfer_hash) = Self::encode_transfer(&transfer);
+
+                    insert_transfer_hash(transfer.id, transfer_hash);
+                }
+
+                is_restored
+            }
+            _ => false,
+        };
+
+        RESTORE_CURSOR.with(|restore_cursor| {
+            *restore_cursor.borrow_mut() = (!is_restored).then_some(cursor);
+        });
+
+        update_certified_data();
+
+        is_restored
+    }
+
+    /// Returns the candid encoding of the request without full info with its sha256 hash.
+    fn encode_request(request: &Request) -> (Vec<u8>, [u8; 32]) {
+        let request =
+            candid::encode_one(request.clone().to_dto()).expect("Failed to encode the request");
+        let request_hash: [u8; 32] = Sha256::digest(&request).into();
+
+        (request, request_hash)
+    }
+
+    /// Returns the candid encoding of the transfer with its sha256 hash.
+    ///
+    /// The fiat values are left out since they follow the exchange rates.
+    fn encode_transfer(transfer: &Transfer) -> (Vec<u8>, [u8; 32]) {
+        let mut transfer = transfer.to_dto();
+        transfer.fiat_values.clear();
+
+        let transfer = candid::encode_one(transfer).expect("Failed to encode the transfer");
+        let transfer_hash: [u8; 32] = Sha256::digest(&transfer).into();
+
+        (transfer, transfer_hash)
+    }
+}
+
+pub fn record_certification_observes_insert_request(
+    observer: &mut Observer<(Request, Option<Request>)>,
+) {
+    observer.add_listener(Box::new(|(request, _)| {
+        let (_, request_hash) = RecordCertificationService::encode_request(request);
+
+        certify_request_hash(request.id, request_hash);
+    }));
+}
+
+pub fn record_certification_observes_remove_request(observer: &mut Observer<Request>) {
+    observer.add_listener(Box::new(|request| {
+        remove_request_hash(&request.id);
+    }));
+}
+
+pub fn record_certification_observes_insert_transfer(
+    observer: &mut Observer<(Transfer, Option<Transfer>)>,
+) {
+    observer.add_listener(Box::new(|(transfer, _)| {
+        let (_, transfer_hash) = RecordCertificationService::encode_transfer(transfer);
+
+        certify_transfer_hash(transfer.id, transfer_hash);
+    }));
+}
+
+pub fn record_certification_observes_remove_transfer(observer: &mut Observer<Transfer>) {
+    observer.add_listener(Box::new(|transfer| {
+        remove_transfer_hash(&transfer.id);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::certification::{REQUESTS_LABEL, TRANSFERS_LABEL},
+        models::{request_test_utils::mock_request, transfer_test_utils::mock_transfer},
+    };
+    use ic_certification::LookupResult;
+    use orbit_essentials::repository::Repository;
+    use station_api::{RequestDTO, TransferDTO};
+
+    fn certified_request_hash(request: &Request) -> Option<Vec<u8>> {
+        match request_witness(&request.id).lookup_path([REQUESTS_LABEL.as_bytes(), &request.id]) {
+            LookupResult::Found(hash) => Some(hash.to_vec()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn certifies_the_latest_version_of_the_request() {
+        let mut request = mock_request();
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        request.title = "Updated".to_string();
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let certification = RECORD_CERTIFICATION_SERVICE
+            .request_certification(&request)
+            .unwrap();
+        let record: RequestDTO = candid::decode_one(&certification.record).unwrap();
+        let record_hash: [u8; 32] = Sha256::digest(&certification.record).into();
+
+        assert_eq!(record.title, "Updated");
+        assert_eq!(certified_request_hash(&request), Some(record_hash.to_vec()));
+
+        REQUEST_REPOSITORY.remove(&request.to_key());
+
+        assert_eq!(certified_request_hash(&request), None);
+    }
+
+    #[test]
+    fn certifies_the_transfer_without_its_fiat_values() {
+        let transfer = mock_transfer();
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+
+        let certification = RECORD_CERTIFICATION_SERVICE
+            .transfer_certification(&transfer)
+            .unwrap();
+        let record: TransferDTO = candid::decode_one(&certification.record).unwrap();
+
+        assert!(record.fiat_values.is_empty());
+        assert!(certification.certificate.is_none());
+    }
+
+    #[test]
+    fn restores_the_certified_records_in_batches() {
+        let requests = (0..3).map(|_| mock_request()).collect::<Vec<_>>();
+        for request in &requests {
+            REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+        }
+        let transfer = mock_transfer();
+        TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer.clone());
+
+        // the hashes are lost on upgrades
+        for request in &requests {
+            remove_request_hash(&request.id);
+        }
+        remove_transfer_hash(&transfer.id);
+
+        RECORD_CERTIFICATION_SERVICE.start_restoring_certified_records();
+        assert!(RECORD_CERTIFICATION_SERVICE
+            .request_certification(&requests[0])
+            .is_none());
+
+        assert!(!RECORD_CERTIFICATION_SERVICE.restore_certified_records(2));
+        assert!(!RECORD_CERTIFICATION_SERVICE.restore_certified_records(2));
+        assert!(RECORD_CERTIFICATION_SERVICE.restore_certified_records(2));
+
+        for request in &requests {
+            let certification = RECORD_CERTIFICATION_SERVICE
+                .request_certification(request)
+                .unwrap();
+            let record_hash: [u8; 32] = Sha256::digest(&certification.record).into();
+
+            assert_eq!(certified_request_hash(request), Some(record_hash.to_vec()));
+        }
+        assert!(matches!(
+            transfer_witness(&transfer.id).lookup_path([TRANSFERS_LABEL.as_bytes(), &transfer.id]),
+            LookupResult::Found(_)
+        ));
+    }
+}
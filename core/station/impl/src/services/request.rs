@@ -1,24 +1,35 @@
 use crate::{
     core::{
         authorization::Authorization,
-        ic_cdk::next_time,
+        evaluation::{Evaluate, REQUEST_POLICY_RULE_EVALUATOR},
+        ic_cdk::{api::time, next_time},
+        read_system_info,
+        request::RequestEvaluator,
+        stable_memory_used_bytes,
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
-    errors::{RequestError, RequestExecuteError},
+    errors::{RequestError, RequestExecuteError, UserGroupError},
     factories::requests::{RequestExecuteStage, RequestFactory},
     mappers::HelperMapper,
     models::{
+        indexes::request_term_index::RequestTermIndex,
         resource::{RequestResourceAction, Resource, ResourceId},
-        DisplayUser, NotificationType, Request, RequestAdditionalInfo, RequestApprovalStatus,
-        RequestCallerPrivileges, RequestCreatedNotification, RequestRejectedNotification,
-        RequestStatus, RequestStatusCode,
+        DestinationWarning, DisplayUser, NotificationType, OnboardingStep, Request,
+        RequestAdditionalInfo, RequestApproval, RequestApprovalStatus, RequestCallerPrivileges,
+        RequestCancelledNotification, RequestCreatedNotification, RequestEvaluationResult,
+        RequestExecutionPlan, RequestOperation, RequestOperationType, RequestRejectedNotification,
+        RequestStatus, RequestStatusCode, Transfer, User,
     },
     repositories::{
         EvaluationResultRepository, RequestRepository, RequestWhereClause,
-        REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+        DESTINATION_FIRST_USE_REPOSITORY, REQUEST_EVALUATION_RESULT_REPOSITORY, REQUEST_REPOSITORY,
+        TRANSFER_REPOSITORY,
+    },
+    services::{
+        NotificationService, RequestPolicyService, UserService, APPROVAL_REMINDER_SERVICE,
+        NOTIFICATION_SERVICE, REQUEST_POLICY_SERVICE, USER_GROUP_SERVICE, USER_SERVICE,
     },
-    services::{NotificationService, UserService, NOTIFICATION_SERVICE, USER_SERVICE},
 };
 use ic_cdk::print;
 use lazy_static::lazy_static;
@@ -26,8 +37,10 @@ use orbit_essentials::utils::rfc3339_to_timestamp;
 use orbit_essentials::{api::ServiceResult, model::ModelValidator};
 use orbit_essentials::{repository::Repository, types::UUID};
 use station_api::{
-    CreateRequestInput, GetNextApprovableRequestInput, ListRequestsInput,
-    SubmitRequestApprovalInput,
+    AmendRequestInput, CancelRequestInput, CreateRequestInput, EditUserGroupOperationInput,
+    GetNextApprovableRequestInput, ListRequestsInput, RequestOperationInput,
+    RequestUserGroupMembershipInput, SearchRequestsInput, SubmitRequestApprovalInput,
+    UserGroupMembershipActionDTO,
 };
 use std::sync::Arc;
 use uuid::Uuid;
@@ -38,6 +51,7 @@ lazy_static! {
         Arc::clone(&REQUEST_REPOSITORY),
         Arc::clone(&NOTIFICATION_SERVICE),
         Arc::clone(&REQUEST_EVALUATION_RESULT_REPOSITORY),
+        Arc::clone(&REQUEST_POLICY_SERVICE),
     ));
 }
 
@@ -47,6 +61,7 @@ pub struct RequestService {
     request_repository: Arc<RequestRepository>,
     evaluation_result_repository: Arc<EvaluationResultRepository>,
     notification_service: Arc<NotificationService>,
+    request_policy_service: Arc<RequestPolicyService>,
 }
 
 #[derive(Debug)]
@@ -58,18 +73,21 @@ pub struct RequestEditInput {
 impl RequestService {
     const DEFAULT_REQUEST_LIST_LIMIT: u16 = 100;
     const MAX_REQUEST_LIST_LIMIT: u16 = 250;
+    const MAX_SEARCH_QUERY_LEN: usize = 200;
 
     pub fn new(
         user_service: Arc<UserService>,
         request_repository: Arc<RequestRepository>,
         notification_service: Arc<NotificationService>,
         evaluation_result_repository: Arc<EvaluationResultRepository>,
+        request_policy_service: Arc<RequestPolicyService>,
     ) -> Self {
         Self {
             user_service,
             request_repository,
             notification_service,
             evaluation_result_repository,
+            request_policy_service,
         }
     }
 
@@ -103,6 +121,7 @@ impl RequestService {
         &self,
         request: &Request,
         with_evaluation_results: bool,
+        ctx: &CallContext,
     ) -> ServiceResult<RequestAdditionalInfo> {
         let requester = self.user_service.get_user(&request.requested_by);
         let approvers = request
@@ -139,9 +158,34 @@ impl RequestService {
             requester_name: requester.map_or("Unknown".to_string(), |user| user.name),
             approvers,
             evaluation_result,
+            destination_warning: Self::get_destination_warning(request, ctx),
         })
     }
 
+    /// Flags the destination of the transfer requests that was never paid or only recently paid.
+    ///
+    /// A first use by a transfer of a confidential request that the caller can't read is not
+    /// disclosed, the destination is then flagged as never paid.
+    fn get_destination_warning(request: &Request, ctx: &CallContext) -> Option<DestinationWarning> {
+        let (to, transfer_id) = match &request.operation {
+            RequestOperation::Transfer(transfer) => (&transfer.input.to, transfer.transfer_id),
+            RequestOperation::SweepAccount(sweep) => (&sweep.input.to, sweep.transfer_id),
+            _ => return None,
+        };
+
+        let first_use = DESTINATION_FIRST_USE_REPOSITORY
+            .get(to)
+            .filter(|first_use| {
+                !TRANSFER_REPOSITORY
+                    .get(&Transfer::key(first_use.transfer_id))
+                    .is_some_and(|transfer| {
+                        Authorization::is_confidential_request_hidden(ctx, &transfer.request_id)
+                    })
+            });
+
+        DestinationWarning::new(first_use, transfer_id, time())
+    }
+
     pub async fn list_requests(
         &self,
         input: ListRequestsInput,
@@ -206,6 +250,8 @@ impl RequestService {
                 not_approvers: filter_by_votable.clone(),
                 not_requesters: filter_by_votable,
                 excluded_ids: vec![],
+                tags: Request::normalize_tags(input.tags.unwrap_or_default()),
+                visible_to: Some(ctx.to_owned()),
             },
             input.sort_by,
         )?;
@@ -287,6 +333,8 @@ impl RequestService {
                 not_approvers: filter_by_votable.clone(),
                 not_requesters: filter_by_votable,
                 excluded_ids: exclude_request_ids,
+                tags: vec![],
+                visible_to: ctx.cloned(),
             },
             None,
         )?;
@@ -308,6 +356,56 @@ impl RequestService {
         Ok(None)
     }
 
+    /// Searches the requests by the words of their title and summary, from the most recent.
+    pub fn search_requests(
+        &self,
+        input: SearchRequestsInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<PaginatedData<Request>> {
+        if input.query.len() > Self::MAX_SEARCH_QUERY_LEN {
+            Err(RequestError::ValidationError {
+                info: format!(
+                    "The search query exceeds the maximum length of {}",
+                    Self::MAX_SEARCH_QUERY_LEN
+                ),
+            })?
+        }
+
+        if RequestTermIndex::terms(&input.query).is_empty() {
+            Err(RequestError::ValidationError {
+                info: format!(
+                    "The search query must contain a word of at least {} characters",
+                    RequestTermIndex::MIN_TERM_LEN
+                ),
+            })?
+        }
+
+        let mut request_ids = self.request_repository.search_ids(&input.query);
+
+        // filter out requests that the caller does not have access to read
+        retain_accessible_resources(ctx, &mut request_ids, |id| {
+            Resource::Request(RequestResourceAction::Read(ResourceId::Id(*id)))
+        });
+
+        let paginated_ids = paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_REQUEST_LIST_LIMIT),
+            max_limit: Some(Self::MAX_REQUEST_LIST_LIMIT),
+            items: &request_ids,
+        })?;
+
+        Ok(PaginatedData {
+            total: paginated_ids.total,
+            next_offset: paginated_ids.next_offset,
+            items: paginated_ids
+                .items
+                .into_iter()
+                .filter_map(|id| self.get_request(&id).ok())
+                .collect(),
+        })
+    }
+
     /// Creates a new request adding the caller user as the requester.
     ///
     /// By default the request has an expiration date of 7 days from the creation date.
@@ -317,20 +415,222 @@ impl RequestService {
         ctx: &CallContext,
     ) -> ServiceResult<Request> {
         let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let confidential = input.confidential.unwrap_or_default();
+        let tags = Request::normalize_tags(input.tags.clone().unwrap_or_default());
+        let depends_on = input
+            .depends_on
+            .clone()
+            .map(HelperMapper::to_uuid)
+            .transpose()?
+            .map(|dependency_id| *dependency_id.as_bytes());
         let mut request = RequestFactory::create_request(requester.id, input).await?;
+        request.confidential = confidential;
+        request.tags = tags;
+        request.depends_on = depends_on;
 
         // Different request types may have different validation rules.
         request.validate()?;
 
+        // A deferred execution is only meaningful ahead of time, the approved request then stays
+        // scheduled until the execution time.
+        if let RequestExecutionPlan::Scheduled { execution_time } = request.execution_plan {
+            if execution_time <= next_time() {
+                Err(RequestError::ValidationError {
+                    info: "The execution time of the request must be in the future".to_string(),
+                })?
+            }
+        }
+
+        // Operations that exceed the configured limits would fail at execution.
+        read_system_info()
+            .get_request_operation_limits()
+            .check(&request.operation)?;
+
+        self.check_creation_rate_limits(&requester, &request.operation)?;
+
+        // Past the stable memory high-water mark only the requests that help the station recover are
+        // accepted, the approvals and executions of the existing requests are not affected.
+        read_system_info()
+            .get_stable_memory_guardrail()
+            .check(stable_memory_used_bytes(), &request.operation)?;
+
         // Insert the request into the repository before adding approvals so checks that depend on the
         // request being in the repository pass.
         self.request_repository
             .insert(request.to_key(), request.to_owned());
 
-        if request.can_approve(&requester.id) {
-            request.add_approval(requester.id, RequestApprovalStatus::Approved, None)?;
+        // The requester only approves on creation when they re-authenticated recently enough for the
+        // policies, otherwise they can still approve afterwards once re-authenticated.
+        if request.can_approve(&requester.id)
+            && Self::check_recent_reauthentication(&request, ctx).is_ok()
+        {
+            request.add_approval(
+                requester.id,
+                RequestApprovalStatus::Approved,
+                None,
+                ctx.reauthenticated_at(),
+            )?;
+        }
+
+        // The validations that require inter-canister calls are completed by a job, the request
+        // is only open for approvals once they passed.
+        if request.requires_async_validation() {
+            request.status = RequestStatus::Validating;
+            self.request_repository
+                .insert(request.to_key(), request.to_owned());
+
+            return Ok(request);
+        }
+
+        self.open_request(request).await
+    }
+
+    /// Creates the request of the caller to join or leave a user group.
+    ///
+    /// The request edits the members of the group, hence it is approved according to the policies of
+    /// the edits of the group without the caller needing the permission to edit it.
+    pub async fn request_user_group_membership(
+        &self,
+        input: RequestUserGroupMembershipInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let user_group =
+            USER_GROUP_SERVICE.get(HelperMapper::to_uuid(input.user_group_id)?.as_bytes())?;
+        let user_group_id = Uuid::from_bytes(user_group.id).hyphenated().to_string();
+        let is_member = requester.groups.contains(&user_group.id);
+        let members = Some(vec![Uuid::from_bytes(requester.id)
+            .hyphenated()
+            .to_string()]);
+
+        let (title, add_members, remove_members) = match input.action {
+            UserGroupMembershipActionDTO::Join => {
+                if is_member {
+                    Err(UserGroupError::AlreadyMember { id: user_group_id })?
+                }
+
+                (
+                    format!("Join the user group {}", user_group.name),
+                    members,
+                    None,
+                )
+            }
+            UserGroupMembershipActionDTO::Leave => {
+                if !is_member {
+                    Err(UserGroupError::NotMember { id: user_group_id })?
+                }
+
+                (
+                    format!("Leave the user group {}", user_group.name),
+                    None,
+                    members,
+                )
+            }
+        };
+
+        self.create_request(
+            CreateRequestInput {
+                operation: RequestOperationInput::EditUserGroup(EditUserGroupOperationInput {
+                    user_group_id,
+                    name: user_group.name,
+                    add_members,
+                    remove_members,
+                }),
+                title: Some(title),
+                summary: input.summary,
+                execution_plan: None,
+                confidential: None,
+                tags: None,
+                depends_on: None,
+            },
+            ctx,
+        )
+        .await
+    }
+
+    /// Evaluates the policies of the request that the caller would create with the operation, and
+    /// finds the users that could approve it, without creating the request.
+    ///
+    /// The approval the requester adds on creation is not counted, hence the evaluation shows what
+    /// the request needs from all of its approvers.
+    pub async fn evaluate_request_policies(
+        &self,
+        operation: RequestOperationInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<(RequestEvaluationResult, Vec<DisplayUser>)> {
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request = RequestFactory::create_request(
+            requester.id,
+            CreateRequestInput {
+                operation,
+                title: None,
+                summary: None,
+                execution_plan: None,
+                confidential: None,
+                tags: None,
+                depends_on: None,
+            },
+        )
+        .await?;
+
+        request.validate()?;
+
+        let evaluation_result =
+            RequestEvaluator::new(REQUEST_POLICY_RULE_EVALUATOR.to_owned(), request.to_owned())
+                .evaluate()?;
+
+        let mut possible_approvers = request
+            .find_all_possible_approvers()
+            .await?
+            .into_iter()
+            .filter_map(|user_id| self.user_service.get_user(&user_id).ok())
+            .map(|user| DisplayUser {
+                id: user.id,
+                name: user.name,
+            })
+            .collect::<Vec<DisplayUser>>();
+        possible_approvers.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+        Ok((evaluation_result, possible_approvers))
+    }
+
+    /// Runs the validations of a request that awaits them, opens the request for approvals if they
+    /// passed and fails it with the reason otherwise.
+    pub async fn complete_request_validation(&self, request: Request) -> ServiceResult<Request> {
+        let validation = RequestFactory::validate_request(&request).await;
+
+        // the request may have changed while the validation calls were made
+        let mut request = self.get_request(&request.id)?;
+        if request.status != RequestStatus::Validating {
+            return Ok(request);
+        }
+
+        match validation {
+            Ok(operation) => {
+                request.operation = operation;
+                request.status = RequestStatus::Created;
+                request.last_modification_timestamp = next_time();
+                self.request_repository
+                    .insert(request.to_key(), request.to_owned());
+
+                self.open_request(request).await
+            }
+            Err(error) => {
+                let reason = match error {
+                    RequestError::ValidationError { info } => info,
+                    error => error.to_string(),
+                };
+
+                let request_id = request.id;
+                self.fail_request(request, reason, next_time()).await;
+
+                self.get_request(&request_id)
+            }
         }
+    }
 
+    /// Evaluates the request that was just opened for approvals and notifies the users about it.
+    async fn open_request(&self, mut request: Request) -> ServiceResult<Request> {
         // When a request is created, it is immediately evaluated to determine its status.
         // This is done because the request may be immediately rejected or approved based on the policies.
         let maybe_evaluation = request.reevaluate().await?;
@@ -345,6 +645,8 @@ impl RequestService {
 
         if request.status == RequestStatus::Created {
             self.created_request_hook(&request).await;
+        } else if request.status == RequestStatus::Approved {
+            self.approved_request_hook(&request).await;
         } else if request.status == RequestStatus::Rejected {
             self.rejected_request_hook(&request).await;
         }
@@ -352,28 +654,56 @@ impl RequestService {
         Ok(request)
     }
 
+    async fn approved_request_hook(&self, request: &Request) {
+        self.request_policy_service.record_spend(request);
+
+        // the correction of a pending canister change takes its place once approved
+        if let RequestOperation::ChangeExternalCanister(operation) = &request.operation {
+            if let Some(replaced) = operation
+                .input
+                .replaces
+                .and_then(|replaced_id| self.request_repository.get(&Request::key(replaced_id)))
+                .filter(|replaced| replaced.status == RequestStatus::Created)
+            {
+                let replaced_id = replaced.id;
+                self.request_repository.cancel_request(
+                    replaced,
+                    format!(
+                        "The request has been superseded by request {}.",
+                        Uuid::from_bytes(request.id).hyphenated()
+                    ),
+                    next_time(),
+                );
+
+                if let Ok(replaced) = self.get_request(&replaced_id) {
+                    self.cancelled_request_hook(&replaced, false).await;
+                }
+            }
+        }
+    }
+
     async fn rejected_request_hook(&self, request: &Request) {
         self.notification_service
-            .send_notification(
+            .send_request_notification(
                 request.requested_by,
                 NotificationType::RequestRejected(RequestRejectedNotification {
                     request_id: request.id,
                 }),
-                request.title.to_owned(),
-                request.summary.to_owned(),
+                request,
             )
             .await;
     }
 
     pub async fn failed_request_hook(&self, request: &Request) {
+        self.request_policy_service.release_spend(request);
+
         self.notification_service
-            .send_notification(
+            .send_request_notification(
                 request.requested_by,
                 NotificationType::RequestFailed(RequestRejectedNotification {
                     request_id: request.id,
                 }),
-                request.title.to_owned(),
-                request.summary.to_owned(),
+                request,
             )
             .await;
     }
@@ -393,18 +723,25 @@ impl RequestService {
 
         possible_approvers.remove(&request.requested_by);
 
-        for approver in possible_approvers {
+        for approver in possible_approvers.iter() {
             self.notification_service
-                .send_notification(
-                    approver,
+                .send_request_notification(
+                    *approver,
                     NotificationType::RequestCreated(RequestCreatedNotification {
                         request_id: request.id,
                     }),
-                    request.title.to_owned(),
-                    request.summary.to_owned(),
+                    request,
                 )
                 .await;
         }
+
+        let request = request.to_owned();
+        let approvers = possible_approvers.into_iter().collect::<Vec<_>>();
+        crate::core::ic_cdk::spawn(async move {
+            APPROVAL_REMINDER_SERVICE
+                .forward(&request, &approvers)
+                .await;
+        });
     }
 
     pub async fn submit_request_approval(
@@ -420,12 +757,26 @@ impl RequestService {
             Err(RequestError::ApprovalNotAllowed)?
         }
 
-        let approval_decision = input.decision.into();
+        let approval_decision: RequestApprovalStatus = input.decision.into();
+
+        if approval_decision == RequestApprovalStatus::Approved {
+            Self::check_recent_reauthentication(&request, ctx)?;
+        }
 
-        request.add_approval(approver.id, approval_decision, input.reason)?;
+        request.add_approval(
+            approver.id,
+            approval_decision,
+            input.reason,
+            ctx.reauthenticated_at(),
+        )?;
 
         // Must happen after the approval is added to the request to ensure the approval is counted.
-        let maybe_evaluation = request.reevaluate().await?;
+        let maybe_evaluation = request
+            .reevaluate_vote(
+                approver.id,
+                self.evaluation_result_repository.get(&request.id),
+            )
+            .await?;
 
         self.request_repository
             .insert(request.to_key(), request.to_owned());
@@ -435,13 +786,278 @@ impl RequestService {
                 .insert(request.id, evaluation);
         }
 
-        if request.status == RequestStatus::Rejected {
+        if request.status == RequestStatus::Approved {
+            self.approved_request_hook(&request).await;
+        } else if request.status == RequestStatus::Rejected {
             self.rejected_request_hook(&request).await;
         }
 
+        if approver
+            .onboarding
+            .completed_at(&OnboardingStep::FirstVoteCast)
+            .is_none()
+        {
+            self.user_service
+                .complete_onboarding_steps(&approver.id, &[OnboardingStep::FirstVoteCast])?;
+        }
+
+        Ok(request)
+    }
+
+    /// Checks that the requester stays within the configured request creation rate limits, the members
+    /// of the override group are not limited.
+    fn check_creation_rate_limits(
+        &self,
+        requester: &User,
+        operation: &RequestOperation,
+    ) -> ServiceResult<()> {
+        let system_info = read_system_info();
+        let rate_limits = system_info.get_request_creation_rate_limits();
+        if rate_limits.limits.is_empty() || rate_limits.is_exempt(&requester.groups) {
+            return Ok(());
+        }
+
+        let operation_type = RequestOperationType::from(operation.clone());
+        let now = next_time();
+        for limit in rate_limits
+            .limits
+            .iter()
+            .filter(|limit| limit.applies_to(&operation_type))
+        {
+            let request_ids = self.request_repository.find_ids_where(
+                RequestWhereClause {
+                    created_dt_from: Some(now.saturating_sub(limit.period.as_nanos())),
+                    requesters: vec![requester.id],
+                    ..Default::default()
+                },
+                None,
+            )?;
+
+            let created_requests = match &limit.operation_type {
+                Some(_) => request_ids
+                    .iter()
+                    .filter_map(|request_id| {
+                        self.request_repository.get(&Request::key(*request_id))
+                    })
+                    .filter(|request| {
+                        RequestOperationType::from(request.operation.clone()) == operation_type
+                    })
+                    .count(),
+                None => request_ids.len(),
+            };
+
+            if created_requests >= limit.max_requests as usize {
+                Err(RequestError::CreationRateLimitExceeded {
+                    max_requests: limit.max_requests,
+                    period: limit.period.to_string(),
+                    operation_type: limit
+                        .operation_type
+                        .as_ref()
+                        .map(|operation_type| operation_type.to_string()),
+                })?
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the caller re-authenticated recently enough to approve the request, as required by
+    /// the `RecentAuthentication` rules of its policies.
+    fn check_recent_reauthentication(request: &Request, ctx: &CallContext) -> ServiceResult<()> {
+        let Some(max_reauthentication_age_mins) = request.max_reauthentication_age_mins() else {
+            return Ok(());
+        };
+
+        let is_recent = ctx.reauthenticated_at().is_some_and(|authenticated_at| {
+            next_time().saturating_sub(authenticated_at)
+                <= u64::from(max_reauthentication_age_mins) * 60 * 1_000_000_000
+        });
+
+        if !is_recent {
+            Err(RequestError::RecentAuthenticationRequired {
+                max_reauthentication_age_mins,
+            })?
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the operation of a pending request on behalf of its requester, as long as no other
+    /// user voted on it.
+    ///
+    /// The votes are reset and the policies of the request are evaluated again, the previous operation
+    /// is kept in the amendments of the request.
+    pub async fn amend_request(
+        &self,
+        input: AmendRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let mut request = self.get_request(request_id.as_bytes())?;
+
+        if request.requested_by != requester.id {
+            Err(RequestError::AmendmentNotAllowed)?
+        }
+
+        // The factory maps and validates the operation input the same way as for a new request.
+        let operation = RequestFactory::create_request(
+            requester.id,
+            CreateRequestInput {
+                operation: input.operation,
+                title: Some(request.title.to_owned()),
+                summary: request.summary.to_owned(),
+                execution_plan: None,
+                confidential: None,
+                tags: None,
+                depends_on: None,
+            },
+        )
+        .await?
+        .operation;
+
+        read_system_info()
+            .get_request_operation_limits()
+            .check(&operation)?;
+
+        request.amend(operation, input.reason, next_time())?;
+        request.validate()?;
+
+        // the cached evaluation was made for the previous operation and votes
+        self.evaluation_result_repository.remove(&request.id);
+        self.request_repository
+            .insert(request.to_key(), request.to_owned());
+
+        if request.can_approve(&requester.id)
+            && Self::check_recent_reauthentication(&request, ctx).is_ok()
+        {
+            request.add_approval(
+                requester.id,
+                RequestApprovalStatus::Approved,
+                None,
+                ctx.reauthenticated_at(),
+            )?;
+        }
+
+        if request.requires_async_validation() {
+            request.status = RequestStatus::Validating;
+            self.request_repository
+                .insert(request.to_key(), request.to_owned());
+
+            return Ok(request);
+        }
+
+        self.open_request(request).await
+    }
+
+    /// Cancels a pending request on behalf of its requester.
+    ///
+    /// The users that could approve the request are notified, since their decision is no longer needed.
+    pub async fn cancel_request(
+        &self,
+        input: CancelRequestInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<Request> {
+        let requester = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request_id = HelperMapper::to_uuid(input.request_id)?;
+        let request = self.get_request(request_id.as_bytes())?;
+
+        if request.requested_by != requester.id {
+            Err(RequestError::CancellationNotAllowed)?
+        }
+
+        if !matches!(
+            request.status,
+            RequestStatus::Created | RequestStatus::Validating
+        ) {
+            Err(RequestError::NotAllowedModification {
+                request_id: request_id.hyphenated().to_string(),
+            })?
+        }
+
+        if let Some(reason) = &input.reason {
+            if reason.len() > RequestApproval::MAX_REASON_LEN as usize {
+                Err(RequestError::ApprovalReasonTooLong {
+                    max_len: RequestApproval::MAX_REASON_LEN,
+                })?
+            }
+        }
+
+        // the approvers were not notified yet about the requests that await validation
+        let notify_approvers = request.status == RequestStatus::Created;
+
+        self.request_repository.cancel_request(
+            request,
+            input
+                .reason
+                .unwrap_or_else(|| "The request has been cancelled by the requester.".to_string()),
+            next_time(),
+        );
+
+        let request = self.get_request(request_id.as_bytes())?;
+
+        if notify_approvers {
+            self.cancelled_request_hook(&request, false).await;
+        }
+
         Ok(request)
     }
 
+    /// Cancels the request that expired while still pending and notifies the requester and the
+    /// users that could vote on it.
+    pub async fn expire_request(&self, request: Request) {
+        let request_id = request.id;
+
+        self.request_repository.cancel_request(
+            request,
+            "The request has expired".to_string(),
+            next_time(),
+        );
+
+        if let Ok(request) = self.get_request(&request_id) {
+            self.cancelled_request_hook(&request, true).await;
+        }
+    }
+
+    async fn cancelled_request_hook(&self, request: &Request, notify_requester: bool) {
+        let mut voters = request
+            .find_all_possible_approvers()
+            .await
+            .unwrap_or_else(|_| {
+                print(format!(
+                    "Failed to find all possible approvers for request {}",
+                    Uuid::from_bytes(request.id).hyphenated()
+                ));
+
+                Default::default()
+            });
+
+        // the policies may have changed since the votes were cast
+        voters.extend(
+            request
+                .approvals
+                .iter()
+                .map(|approval| approval.approver_id),
+        );
+        if notify_requester {
+            voters.insert(request.requested_by);
+        } else {
+            voters.remove(&request.requested_by);
+        }
+
+        for voter in voters {
+            self.notification_service
+                .send_request_notification(
+                    voter,
+                    NotificationType::RequestCancelled(RequestCancelledNotification {
+                        request_id: request.id,
+                    }),
+                    request,
+                )
+                .await;
+        }
+    }
+
     pub async fn fail_request(
         &self,
         mut request: Request,
@@ -508,33 +1124,38 @@ impl RequestService {
 mod tests {
     use super::*;
     use crate::{
-        core::test_utils,
+        core::{ic_cdk::api::time, test_utils, write_system_info},
         models::{
             account_test_utils::mock_account,
             permission::Allow,
-            request_policy_rule::RequestPolicyRule,
+            request_policy_rule::{RequestPolicyRule, VelocityLimit},
             request_policy_test_utils::mock_request_policy,
             request_specifier::{RequestSpecifier, UserSpecifier},
             request_test_utils::mock_request,
-            resource::ResourceIds,
+            resource::{ExternalCanisterId, ResourceIds},
+            transfer_test_utils::mock_transfer,
             user_test_utils::mock_user,
             AddAccountOperationInput, AddAddressBookEntryOperation,
             AddAddressBookEntryOperationInput, AddUserOperation, AddUserOperationInput, Blockchain,
-            BlockchainStandard, Metadata, Percentage, RequestApproval, RequestOperation,
+            BlockchainStandard, CanisterInstallMode, CanisterUpgradeModeArgs,
+            ChangeExternalCanisterOperation, ChangeExternalCanisterOperationInput,
+            EvaluationStatus, Metadata, NetworkProfile, Percentage, RateLimitPeriod,
+            RequestApproval, RequestCreationRateLimit, RequestCreationRateLimits, RequestOperation,
             RequestPolicy, RequestStatus, TransferOperation, TransferOperationInput, User,
             UserGroup, UserStatus, ADMIN_GROUP_ID,
         },
         repositories::{
-            request_policy::REQUEST_POLICY_REPOSITORY, AccountRepository, NOTIFICATION_REPOSITORY,
-            USER_GROUP_REPOSITORY, USER_REPOSITORY,
+            request_policy::REQUEST_POLICY_REPOSITORY, AccountRepository, ACCOUNT_SPEND_REPOSITORY,
+            NOTIFICATION_REPOSITORY, USER_GROUP_REPOSITORY, USER_REPOSITORY,
         },
         services::AccountService,
     };
     use candid::Principal;
-    use orbit_essentials::model::ModelKey;
+    use orbit_essentials::{model::ModelKey, utils::timestamp_to_rfc3339};
     use station_api::{
         ListRequestsOperationTypeDTO, RequestApprovalStatusDTO, RequestStatusCodeDTO,
     };
+    use std::collections::HashSet;
 
     struct TestContext {
         repository: RequestRepository,
@@ -594,6 +1215,10 @@ mod tests {
                 metadata: Metadata::default(),
                 network: "mainnet".to_string(),
                 to: "0x1234".to_string(),
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
             },
         });
 
@@ -625,6 +1250,10 @@ mod tests {
                 metadata: Metadata::default(),
                 network: "mainnet".to_string(),
                 to: "0x1234".to_string(),
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
             },
         });
         request.approvals = vec![];
@@ -707,11 +1336,18 @@ mod tests {
                             metadata: vec![],
                             network: None,
                             to: "0x1234".to_string(),
+                            spend_from: None,
+                            memo: None,
+                            category: None,
+                            asset_id: None,
                         },
                     ),
                     title: None,
                     summary: None,
                     execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
                 },
                 &ctx.call_context,
             )
@@ -724,36 +1360,454 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn user_approvals_on_their_own_request() {
+    async fn requester_amends_request_before_others_vote() {
         let ctx = setup();
+        let mut related_user = mock_user();
+        related_user.identities = vec![Principal::from_slice(&[25; 29])];
+        related_user.status = UserStatus::Active;
+        USER_REPOSITORY.insert(related_user.to_key(), related_user.clone());
 
-        let policy = RequestPolicy {
-            id: [0; 16],
-            specifier: RequestSpecifier::AddAddressBookEntry,
-            rule: RequestPolicyRule::And(vec![RequestPolicyRule::QuorumPercentage(
-                UserSpecifier::Group(vec![*ADMIN_GROUP_ID]),
-                Percentage(51),
-            )]),
-        };
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
 
-        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::QuorumPercentage(
+            UserSpecifier::Id(vec![ctx.caller_user.id, related_user.id]),
+            Percentage(100),
+        );
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let transfer_to = |to: &str| {
+            station_api::RequestOperationInput::Transfer(station_api::TransferOperationInput {
+                from_account_id: Uuid::from_bytes(account.id.to_owned())
+                    .hyphenated()
+                    .to_string(),
+                amount: candid::Nat(100u32.into()),
+                fee: None,
+                metadata: vec![],
+                network: None,
+                to: to.to_string(),
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
+            })
+        };
 
         let request = ctx
             .service
             .create_request(
-                CreateRequestInput {
-                    operation: station_api::RequestOperationInput::AddAddressBookEntry(
-                        station_api::AddAddressBookEntryOperationInput {
-                            address_owner: "".to_owned(),
-                            address: "abc".to_owned(),
+                station_api::CreateRequestInput {
+                    operation: transfer_to("0x1234"),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+        let request_id = Uuid::from_bytes(request.id).hyphenated().to_string();
+
+        // only the requester can amend the request
+        assert_eq!(
+            ctx.service
+                .amend_request(
+                    station_api::AmendRequestInput {
+                        request_id: request_id.clone(),
+                        operation: transfer_to("0x5678"),
+                        reason: None,
+                    },
+                    &CallContext::new(related_user.identities[0]),
+                )
+                .await,
+            Err(RequestError::AmendmentNotAllowed.into())
+        );
+
+        let amended = ctx
+            .service
+            .amend_request(
+                station_api::AmendRequestInput {
+                    request_id: request_id.clone(),
+                    operation: transfer_to("0x5678"),
+                    reason: Some("Wrong destination".to_string()),
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(amended.status, RequestStatus::Created);
+        assert!(matches!(
+            &amended.operation,
+            RequestOperation::Transfer(operation) if operation.input.to == "0x5678"
+        ));
+        assert_eq!(amended.amendments.len(), 1);
+        assert_eq!(amended.amendments[0].previous_operation, request.operation);
+        assert_eq!(amended.approvals.len(), 1);
+        assert_eq!(amended.approvals[0].approver_id, ctx.caller_user.id);
+
+        // once another user voted the request can no longer be amended
+        let mut voted = amended.clone();
+        voted
+            .add_approval(related_user.id, RequestApprovalStatus::Rejected, None, None)
+            .unwrap();
+        ctx.repository.insert(voted.to_key(), voted.clone());
+
+        assert_eq!(
+            ctx.service
+                .amend_request(
+                    station_api::AmendRequestInput {
+                        request_id,
+                        operation: transfer_to("0x1234"),
+                        reason: None,
+                    },
+                    &ctx.call_context,
+                )
+                .await,
+            Err(RequestError::AmendmentNotAllowed.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn evaluate_request_policies_does_not_create_the_request() {
+        let ctx = setup();
+        let mut approver = mock_user();
+        approver.identities = vec![Principal::from_slice(&[25; 29])];
+        approver.id = [25; 16];
+        approver.status = UserStatus::Active;
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::Quorum(UserSpecifier::Id(vec![approver.id]), 1);
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let (evaluation_result, possible_approvers) = ctx
+            .service
+            .evaluate_request_policies(
+                station_api::RequestOperationInput::Transfer(station_api::TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                    amount: candid::Nat::from(100u64),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                    to: "0x1234".to_string(),
+                    spend_from: None,
+                    memo: None,
+                    category: None,
+                    asset_id: None,
+                }),
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(evaluation_result.status, EvaluationStatus::Pending);
+        assert_eq!(evaluation_result.policy_results.len(), 1);
+        assert_eq!(
+            possible_approvers
+                .iter()
+                .map(|user| user.id)
+                .collect::<Vec<_>>(),
+            vec![approver.id]
+        );
+        assert!(ctx.repository.list().is_empty());
+        assert!(NOTIFICATION_REPOSITORY.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn approved_transfers_count_towards_the_velocity_limit() {
+        let ctx = setup();
+        let account = mock_account();
+        ctx.account_repository
+            .insert(account.to_key(), account.clone());
+
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier = RequestSpecifier::Transfer(ResourceIds::Any);
+        request_policy.rule = RequestPolicyRule::VelocityLimit(VelocityLimit {
+            max_amount: candid::Nat::from(150u64),
+            window_secs: 24 * 60 * 60,
+        });
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let transfer_input = || station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::Transfer(
+                station_api::TransferOperationInput {
+                    from_account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                    amount: candid::Nat::from(100u64),
+                    fee: None,
+                    metadata: vec![],
+                    network: None,
+                    to: "0x1234".to_string(),
+                    spend_from: None,
+                    memo: None,
+                    category: None,
+                    asset_id: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let approved = ctx
+            .service
+            .create_request(transfer_input(), &ctx.call_context)
+            .await
+            .unwrap();
+        assert_eq!(approved.status, RequestStatus::Approved);
+        assert_eq!(
+            ACCOUNT_SPEND_REPOSITORY.total_spent_since(&account.id, None, 0, &[0; 16]),
+            candid::Nat::from(100u64)
+        );
+
+        let exceeding = ctx
+            .service
+            .create_request(transfer_input(), &ctx.call_context)
+            .await
+            .unwrap();
+        assert_eq!(exceeding.status, RequestStatus::Rejected);
+
+        ctx.service
+            .fail_request(approved, "failed".to_string(), 0)
+            .await;
+        assert_eq!(
+            ACCOUNT_SPEND_REPOSITORY.total_spent_since(&account.id, None, 0, &[0; 16]),
+            candid::Nat::from(0u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn approving_a_canister_change_cancels_the_request_it_replaces() {
+        let ctx = setup();
+        let canister_id = Principal::from_slice(&[7; 29]);
+
+        let mut request_policy = mock_request_policy();
+        request_policy.specifier =
+            RequestSpecifier::ChangeExternalCanister(ExternalCanisterId::Any);
+        request_policy.rule = RequestPolicyRule::AutoApproved;
+        REQUEST_POLICY_REPOSITORY.insert(request_policy.id, request_policy.to_owned());
+
+        let change_input = |replaces: Option<UUID>| station_api::CreateRequestInput {
+            operation: station_api::RequestOperationInput::ChangeExternalCanister(
+                station_api::ChangeExternalCanisterOperationInput {
+                    canister_id,
+                    mode: station_api::CanisterInstallMode::Upgrade,
+                    module: vec![1, 2, 3],
+                    module_extra_chunks: None,
+                    arg: None,
+                    replaces: replaces.map(|id| Uuid::from_bytes(id).hyphenated().to_string()),
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let mut replaced = mock_request();
+        replaced.status = RequestStatus::Created;
+        replaced.operation =
+            RequestOperation::ChangeExternalCanister(ChangeExternalCanisterOperation {
+                module_checksum: vec![],
+                arg_checksum: None,
+                input: ChangeExternalCanisterOperationInput {
+                    canister_id,
+                    mode: CanisterInstallMode::Upgrade(CanisterUpgradeModeArgs {}),
+                    module: vec![1, 2],
+                    module_extra_chunks: None,
+                    arg: None,
+                    replaces: None,
+                },
+            });
+        ctx.repository
+            .insert(replaced.to_key(), replaced.to_owned());
+
+        let correction = ctx
+            .service
+            .create_request(change_input(Some(replaced.id)), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(correction.status, RequestStatus::Approved);
+        assert!(matches!(
+            ctx.repository.get(&replaced.to_key()).unwrap().status,
+            RequestStatus::Cancelled { .. }
+        ));
+
+        // a settled request can no longer be replaced
+        assert!(ctx
+            .service
+            .create_request(change_input(Some(replaced.id)), &ctx.call_context)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn requester_cancels_own_request_and_voters_are_notified() {
+        let ctx = setup();
+        let mut voter = mock_user();
+        voter.identities = vec![Principal::from_slice(&[25; 29])];
+        voter.status = UserStatus::Active;
+        USER_REPOSITORY.insert(voter.to_key(), voter.clone());
+
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.status = RequestStatus::Created;
+        request.approvals = vec![RequestApproval {
+            approver_id: voter.id,
+            status: RequestApprovalStatus::Approved,
+            status_reason: None,
+            decided_dt: 0,
+            last_modification_timestamp: 0,
+            reauthenticated_at: None,
+        }];
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        let input = station_api::CancelRequestInput {
+            request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+            reason: Some("Sent to the wrong address".to_string()),
+        };
+
+        assert_eq!(
+            ctx.service
+                .cancel_request(input.clone(), &CallContext::new(voter.identities[0]))
+                .await,
+            Err(RequestError::CancellationNotAllowed.into())
+        );
+
+        let cancelled = ctx
+            .service
+            .cancel_request(input.clone(), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cancelled.status,
+            RequestStatus::Cancelled {
+                reason: Some("Sent to the wrong address".to_string())
+            }
+        );
+        assert!(ctx
+            .service
+            .cancel_request(input, &ctx.call_context)
+            .await
+            .is_err());
+
+        let notifications = NOTIFICATION_REPOSITORY.list();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].target_user_id, voter.id);
+        assert_eq!(
+            notifications[0].notification_type,
+            NotificationType::RequestCancelled(RequestCancelledNotification {
+                request_id: request.id
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_with_a_validation_method_await_validation() {
+        let ctx = setup();
+        let canister_method = |method_name: &str| station_api::CanisterMethodDTO {
+            canister_id: Principal::from_slice(&[10; 29]),
+            method_name: method_name.to_string(),
+        };
+
+        let request = ctx
+            .service
+            .create_request(
+                station_api::CreateRequestInput {
+                    operation: station_api::RequestOperationInput::CallExternalCanister(
+                        station_api::CallExternalCanisterOperationInput {
+                            validation_method: Some(canister_method("validate")),
+                            execution_method: canister_method("execute"),
+                            arg: None,
+                            execution_method_cycles: None,
+                        },
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Validating);
+        assert!(!request.can_approve(&ctx.caller_user.id));
+        assert!(NOTIFICATION_REPOSITORY.list().is_empty());
+
+        // the requester can still withdraw the request while it's validated
+        let cancelled = ctx
+            .service
+            .cancel_request(
+                station_api::CancelRequestInput {
+                    request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+                    reason: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(cancelled.status, RequestStatus::Cancelled { .. }));
+    }
+
+    #[tokio::test]
+    async fn user_approvals_on_their_own_request() {
+        let ctx = setup();
+
+        let policy = RequestPolicy {
+            id: [0; 16],
+            specifier: RequestSpecifier::AddAddressBookEntry,
+            rule: RequestPolicyRule::And(vec![RequestPolicyRule::QuorumPercentage(
+                UserSpecifier::Group(vec![*ADMIN_GROUP_ID]),
+                Percentage(51),
+            )]),
+            expiration_period_secs: None,
+        };
+
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+
+        let request = ctx
+            .service
+            .create_request(
+                CreateRequestInput {
+                    operation: station_api::RequestOperationInput::AddAddressBookEntry(
+                        station_api::AddAddressBookEntryOperationInput {
+                            address_owner: "".to_owned(),
+                            address: "abc".to_owned(),
                             blockchain: "icp".to_owned(),
                             metadata: vec![],
                             labels: vec![],
+                            requires_memo: None,
                         },
                     ),
                     title: None,
                     summary: None,
                     execution_plan: Some(station_api::RequestExecutionScheduleDTO::Immediate),
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
                 },
                 &ctx.call_context,
             )
@@ -763,6 +1817,387 @@ mod tests {
         assert!(!request.approvals.is_empty());
     }
 
+    #[tokio::test]
+    async fn deferred_executions_must_be_in_the_future() {
+        let ctx = setup();
+        let input = |execution_time: u64| CreateRequestInput {
+            operation: station_api::RequestOperationInput::AddAddressBookEntry(
+                station_api::AddAddressBookEntryOperationInput {
+                    address_owner: "".to_owned(),
+                    address: "abc".to_owned(),
+                    blockchain: "icp".to_owned(),
+                    metadata: vec![],
+                    labels: vec![],
+                    requires_memo: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: Some(station_api::RequestExecutionScheduleDTO::Scheduled {
+                execution_time: timestamp_to_rfc3339(&execution_time),
+            }),
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let hour_ns = 60 * 60 * 1_000_000_000;
+
+        ctx.service
+            .create_request(input(time() - hour_ns), &ctx.call_context)
+            .await
+            .expect_err("Past execution times are rejected");
+
+        let execution_time = (time() + hour_ns) / 1_000_000_000 * 1_000_000_000;
+        let request = ctx
+            .service
+            .create_request(input(execution_time), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            request.execution_plan,
+            RequestExecutionPlan::Scheduled { execution_time }
+        );
+    }
+
+    #[test]
+    fn search_queries_must_contain_a_keyword() {
+        let ctx = setup();
+        let input = |query: &str| SearchRequestsInput {
+            query: query.to_owned(),
+            paginate: None,
+            with_evaluation_results: false,
+        };
+
+        assert!(ctx
+            .service
+            .search_requests(input("a & b"), &ctx.call_context)
+            .is_err());
+        assert!(ctx
+            .service
+            .search_requests(input(&"payout ".repeat(50)), &ctx.call_context)
+            .is_err());
+        assert!(ctx
+            .service
+            .search_requests(input("payout"), &ctx.call_context)
+            .unwrap()
+            .items
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn request_creation_is_rate_limited_per_user() {
+        let ctx = setup();
+        let input = |name: &str| CreateRequestInput {
+            operation: station_api::RequestOperationInput::AddAddressBookEntry(
+                station_api::AddAddressBookEntryOperationInput {
+                    address_owner: name.to_owned(),
+                    address: "abc".to_owned(),
+                    blockchain: "icp".to_owned(),
+                    metadata: vec![],
+                    labels: vec![],
+                    requires_memo: None,
+                },
+            ),
+            title: None,
+            summary: None,
+            execution_plan: None,
+            confidential: None,
+            tags: None,
+            depends_on: None,
+        };
+
+        let mut system_info = read_system_info();
+        system_info.set_request_creation_rate_limits(RequestCreationRateLimits {
+            limits: vec![RequestCreationRateLimit {
+                operation_type: Some(RequestOperationType::AddAddressBookEntry),
+                period: RateLimitPeriod::Hour,
+                max_requests: 2,
+            }],
+            override_group_id: None,
+        });
+        write_system_info(system_info.clone());
+
+        for name in ["alice", "bob"] {
+            ctx.service
+                .create_request(input(name), &ctx.call_context)
+                .await
+                .unwrap();
+        }
+
+        let error = ctx
+            .service
+            .create_request(input("carol"), &ctx.call_context)
+            .await
+            .expect_err("The third request within the hour is rate limited");
+
+        assert_eq!(
+            error.details.unwrap().get("max_requests"),
+            Some(&"2".to_string())
+        );
+
+        // the members of the override group are not limited
+        system_info.set_request_creation_rate_limits(RequestCreationRateLimits {
+            override_group_id: Some(*ADMIN_GROUP_ID),
+            ..system_info.get_request_creation_rate_limits().clone()
+        });
+        write_system_info(system_info);
+
+        ctx.service
+            .create_request(input("carol"), &ctx.call_context)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn users_request_to_join_and_leave_user_groups() {
+        let ctx = setup();
+        let user_group = UserGroup {
+            id: [7; 16],
+            name: "Treasury".to_owned(),
+            last_modification_timestamp: 0,
+        };
+        USER_GROUP_REPOSITORY.insert(user_group.id, user_group.clone());
+
+        let request = ctx
+            .service
+            .request_user_group_membership(
+                RequestUserGroupMembershipInput {
+                    user_group_id: Uuid::from_bytes(user_group.id).hyphenated().to_string(),
+                    action: UserGroupMembershipActionDTO::Join,
+                    summary: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.requested_by, ctx.caller_user.id);
+        assert_eq!(request.title, "Join the user group Treasury");
+        match request.operation {
+            RequestOperation::EditUserGroup(operation) => {
+                assert_eq!(operation.input.name, "Treasury");
+                assert_eq!(operation.input.add_members, vec![ctx.caller_user.id]);
+                assert!(operation.input.remove_members.is_empty());
+            }
+            _ => panic!("Expected an EditUserGroup operation"),
+        }
+
+        // the caller is only a member of the admin group
+        ctx.service
+            .request_user_group_membership(
+                RequestUserGroupMembershipInput {
+                    user_group_id: Uuid::from_bytes(user_group.id).hyphenated().to_string(),
+                    action: UserGroupMembershipActionDTO::Leave,
+                    summary: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .expect_err("Leaving a group of which the caller is not a member should fail");
+
+        ctx.service
+            .request_user_group_membership(
+                RequestUserGroupMembershipInput {
+                    user_group_id: Uuid::from_bytes(*ADMIN_GROUP_ID).hyphenated().to_string(),
+                    action: UserGroupMembershipActionDTO::Join,
+                    summary: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .expect_err("Joining a group of which the caller is already a member should fail");
+    }
+
+    #[tokio::test]
+    async fn requests_expire_after_the_shortest_policy_expiration_period() {
+        let ctx = setup();
+        let day_secs = 24 * 60 * 60;
+
+        for (id, expiration_period_secs) in
+            [([0; 16], Some(2 * day_secs)), ([1; 16], Some(day_secs))]
+        {
+            let policy = RequestPolicy {
+                id,
+                specifier: RequestSpecifier::AddAddressBookEntry,
+                rule: RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 2),
+                expiration_period_secs,
+            };
+
+            REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+        }
+
+        let request = ctx
+            .service
+            .create_request(
+                CreateRequestInput {
+                    operation: station_api::RequestOperationInput::AddAddressBookEntry(
+                        station_api::AddAddressBookEntryOperationInput {
+                            address_owner: "".to_owned(),
+                            address: "abc".to_owned(),
+                            blockchain: "icp".to_owned(),
+                            metadata: vec![],
+                            labels: vec![],
+                            requires_memo: None,
+                        },
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: Some(station_api::RequestExecutionScheduleDTO::Immediate),
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        let day_ns = day_secs * 1_000_000_000;
+        assert!(request.expiration_dt > request.created_timestamp + day_ns);
+        assert!(request.expiration_dt < request.created_timestamp + 2 * day_ns);
+    }
+
+    #[tokio::test]
+    async fn expired_request_is_cancelled_and_the_requester_and_voters_are_notified() {
+        let ctx = setup();
+        let mut voter = mock_user();
+        voter.identities = vec![Principal::from_slice(&[25; 29])];
+        voter.status = UserStatus::Active;
+        USER_REPOSITORY.insert(voter.to_key(), voter.clone());
+
+        let mut request = mock_request();
+        request.requested_by = ctx.caller_user.id;
+        request.status = RequestStatus::Created;
+        request.approvals = vec![RequestApproval {
+            approver_id: voter.id,
+            status: RequestApprovalStatus::Approved,
+            status_reason: None,
+            decided_dt: 0,
+            last_modification_timestamp: 0,
+            reauthenticated_at: None,
+        }];
+        ctx.repository.insert(request.to_key(), request.to_owned());
+
+        ctx.service.expire_request(request.to_owned()).await;
+
+        assert_eq!(
+            ctx.repository.get(&request.to_key()).unwrap().status,
+            RequestStatus::Cancelled {
+                reason: Some("The request has expired".to_string())
+            }
+        );
+
+        let notified_users = NOTIFICATION_REPOSITORY
+            .list()
+            .into_iter()
+            .map(|notification| notification.target_user_id)
+            .collect::<HashSet<_>>();
+        assert!(notified_users.contains(&ctx.caller_user.id));
+        assert!(notified_users.contains(&voter.id));
+    }
+
+    #[tokio::test]
+    async fn approvals_require_a_recent_reauthentication() {
+        let ctx = setup();
+        let hardware_key = Principal::from_slice(&[10; 29]);
+        let mut user = ctx.caller_user.clone();
+        user.identities.push(hardware_key);
+        USER_REPOSITORY.insert(user.to_key(), user);
+
+        let policy = RequestPolicy {
+            id: [0; 16],
+            specifier: RequestSpecifier::AddAddressBookEntry,
+            rule: RequestPolicyRule::And(vec![
+                RequestPolicyRule::Quorum(UserSpecifier::Group(vec![*ADMIN_GROUP_ID]), 1),
+                RequestPolicyRule::RecentAuthentication(15),
+            ]),
+            expiration_period_secs: None,
+        };
+
+        REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
+
+        let request = ctx
+            .service
+            .create_request(
+                CreateRequestInput {
+                    operation: station_api::RequestOperationInput::AddAddressBookEntry(
+                        station_api::AddAddressBookEntryOperationInput {
+                            address_owner: "".to_owned(),
+                            address: "abc".to_owned(),
+                            blockchain: "icp".to_owned(),
+                            metadata: vec![],
+                            labels: vec![],
+                            requires_memo: None,
+                        },
+                    ),
+                    title: None,
+                    summary: None,
+                    execution_plan: Some(station_api::RequestExecutionScheduleDTO::Immediate),
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                },
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        // the requester did not re-authenticate, so their approval is not added on creation
+        assert!(request.approvals.is_empty());
+        assert_eq!(request.status, RequestStatus::Created);
+
+        let approval_input = SubmitRequestApprovalInput {
+            request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+            decision: RequestApprovalStatusDTO::Approved,
+            reason: None,
+        };
+
+        let result = ctx
+            .service
+            .submit_request_approval(approval_input.clone(), &ctx.call_context)
+            .await;
+        assert_eq!(
+            result.unwrap_err(),
+            RequestError::RecentAuthenticationRequired {
+                max_reauthentication_age_mins: 15
+            }
+            .into()
+        );
+
+        // the voting identity can't step itself up
+        USER_SERVICE.reauthenticate(&ctx.call_context).unwrap();
+
+        let result = ctx
+            .service
+            .submit_request_approval(
+                approval_input.clone(),
+                &CallContext::new(ctx.call_context.caller()),
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err(),
+            RequestError::RecentAuthenticationRequired {
+                max_reauthentication_age_mins: 15
+            }
+            .into()
+        );
+
+        USER_SERVICE
+            .reauthenticate(&CallContext::new(hardware_key))
+            .unwrap();
+
+        let request = ctx
+            .service
+            .submit_request_approval(approval_input, &CallContext::new(ctx.call_context.caller()))
+            .await
+            .unwrap();
+
+        assert_eq!(request.status, RequestStatus::Approved);
+        assert!(request.approvals[0].reauthenticated_at.is_some());
+    }
+
     #[tokio::test]
     async fn users_with_approval_rights_can_view_request() {
         let requester = mock_user();
@@ -780,6 +2215,7 @@ mod tests {
                 UserSpecifier::Id(vec![requester.id, approver.id, another_user.id]),
                 2,
             )]),
+            expiration_period_secs: None,
         };
 
         REQUEST_POLICY_REPOSITORY.insert(policy.id, policy);
@@ -794,6 +2230,7 @@ mod tests {
                 blockchain: Blockchain::InternetComputer,
                 metadata: vec![],
                 labels: vec![],
+                requires_memo: false,
             },
         });
         request.approvals = vec![
@@ -803,6 +2240,7 @@ mod tests {
                 decided_dt: 10,
                 last_modification_timestamp: 10,
                 status_reason: None,
+                reauthenticated_at: None,
             },
             RequestApproval {
                 approver_id: approver.id,
@@ -810,6 +2248,7 @@ mod tests {
                 decided_dt: 10,
                 last_modification_timestamp: 10,
                 status_reason: None,
+                reauthenticated_at: None,
             },
         ];
         request.status = RequestStatus::Failed {
@@ -831,6 +2270,7 @@ mod tests {
             paginate: None,
             sort_by: None,
             statuses: None,
+            tags: None,
         };
 
         let users = vec![requester, approver, another_user];
@@ -866,6 +2306,10 @@ mod tests {
                 metadata: Metadata::default(),
                 network: "mainnet".to_string(),
                 to: "0x1234".to_string(),
+                spend_from: None,
+                memo: None,
+                category: None,
+                asset_id: None,
             },
         });
         request.created_timestamp = 10;
@@ -898,6 +2342,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: false,
                     with_evaluation_results: false,
+                    tags: None,
                 },
                 &ctx.call_context,
             )
@@ -931,6 +2376,7 @@ mod tests {
                     name: "foo".to_string(),
                     blockchain: Blockchain::InternetComputer,
                     standard: BlockchainStandard::Native,
+                    network: NetworkProfile::Mainnet,
                     metadata: Metadata::default(),
                     transfer_request_policy: Some(RequestPolicyRule::QuorumPercentage(
                         UserSpecifier::Id(vec![ctx.caller_user.id, transfer_requester_user.id]),
@@ -958,6 +2404,7 @@ mod tests {
                 identities: vec![Principal::from_slice(&[3; 29])],
                 name: "user-1".to_string(),
                 status: UserStatus::Active,
+                approve_only_identities: vec![],
             },
         });
         irrelevant_request.created_timestamp = 9;
@@ -983,6 +2430,10 @@ mod tests {
                         metadata: Metadata::default(),
                         network: "mainnet".to_string(),
                         to: "0x1234".to_string(),
+                        spend_from: None,
+                        memo: None,
+                        category: None,
+                        asset_id: None,
                     },
                 });
                 transfer.created_timestamp = 10 + i as u64;
@@ -992,6 +2443,7 @@ mod tests {
                     status: RequestApprovalStatus::Approved,
                     status_reason: None,
                     approver_id: transfer.requested_by,
+                    reauthenticated_at: None,
                 }];
                 ctx.repository
                     .insert(transfer.to_key(), transfer.to_owned());
@@ -1017,6 +2469,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    tags: None,
                 },
                 &ctx.call_context,
             )
@@ -1042,6 +2495,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    tags: None,
                 },
                 &CallContext::new(transfer_requester_user.identities[0]),
             )
@@ -1066,6 +2520,7 @@ mod tests {
                     sort_by: None,
                     only_approvable: true,
                     with_evaluation_results: false,
+                    tags: None,
                 },
                 &CallContext::new(no_access_user.identities[0]),
             )
@@ -1107,6 +2562,7 @@ mod tests {
                     )),
                     only_approvable: true,
                     with_evaluation_results: false,
+                    tags: None,
                 },
                 &ctx.call_context,
             )
@@ -1117,6 +2573,50 @@ mod tests {
         assert_eq!(votable_requests.items[0].id, transfer_requests[0].id);
         assert_eq!(votable_requests.items[1].id, transfer_requests[2].id);
     }
+
+    #[test]
+    fn destination_warning_hides_the_first_use_of_confidential_requests() {
+        let ctx = setup();
+
+        let mut confidential_request = mock_request();
+        confidential_request.confidential = true;
+        ctx.repository
+            .insert(confidential_request.to_key(), confidential_request.clone());
+
+        let mut first_transfer = mock_transfer();
+        first_transfer.request_id = confidential_request.id;
+        TRANSFER_REPOSITORY.insert(first_transfer.to_key(), first_transfer.clone());
+
+        let mut request = mock_request();
+        request.id = [2; 16];
+        let RequestOperation::Transfer(operation) = &request.operation else {
+            panic!("Expected a transfer operation");
+        };
+        let first_used_at = time();
+        DESTINATION_FIRST_USE_REPOSITORY.record_use(
+            &operation.input.to,
+            first_transfer.id,
+            first_used_at,
+        );
+
+        let outsider_principal = Principal::from_slice(&[7; 29]);
+        let mut outsider = mock_user();
+        outsider.identities = vec![outsider_principal];
+        outsider.groups = vec![];
+        USER_REPOSITORY.insert(outsider.to_key(), outsider);
+
+        assert_eq!(
+            RequestService::get_destination_warning(&request, &ctx.call_context),
+            Some(DestinationWarning::RecentlyUsed { first_used_at })
+        );
+        assert_eq!(
+            RequestService::get_destination_warning(
+                &request,
+                &CallContext::new(outsider_principal)
+            ),
+            Some(DestinationWarning::NeverUsed)
+        );
+    }
 }
 
 #[cfg(feature = "canbench")]
@@ -1203,6 +2703,7 @@ mod benchs {
                             )),
                             only_approvable: false,
                             with_evaluation_results: false,
+                            tags: None,
                         },
                         &CallContext::new(Principal::from_slice(&[5; 29])),
                     )
@@ -1248,6 +2749,7 @@ mod benchs {
                             )),
                             only_approvable: false,
                             with_evaluation_results: false,
+                            tags: None,
                         },
                         &CallContext::new(Principal::from_slice(&[5; 29])),
                     )
@@ -0,0 +1,330 @@
+use crate::{
+    core::{
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+        CallContext,
+    },
+    errors::{RequestCommentError, RequestError},
+    mappers::HelperMapper,
+    models::{
+        NotificationType, Request, RequestComment, RequestCommentedNotification, RequestStatus,
+        User,
+    },
+    repositories::{
+        RequestCommentRepository, RequestRepository, REQUEST_COMMENT_REPOSITORY, REQUEST_REPOSITORY,
+    },
+    services::{NotificationService, UserService, NOTIFICATION_SERVICE, USER_SERVICE},
+};
+use ic_cdk::print;
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+    types::UUID,
+};
+use station_api::{AddRequestCommentInput, ListRequestCommentsInput};
+use std::{collections::BTreeSet, sync::Arc};
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref REQUEST_COMMENT_SERVICE: Arc<RequestCommentService> =
+        Arc::new(RequestCommentService::new(
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&REQUEST_COMMENT_REPOSITORY),
+            Arc::clone(&NOTIFICATION_SERVICE),
+        ));
+}
+
+/// Manages the discussion threads of the requests.
+#[derive(Default, Debug)]
+pub struct RequestCommentService {
+    user_service: Arc<UserService>,
+    request_repository: Arc<RequestRepository>,
+    request_comment_repository: Arc<RequestCommentRepository>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl RequestCommentService {
+    pub const DEFAULT_COMMENTS_LIMIT: u16 = 50;
+    pub const MAX_LIST_COMMENTS_LIMIT: u16 = 100;
+
+    pub fn new(
+        user_service: Arc<UserService>,
+        request_repository: Arc<RequestRepository>,
+        request_comment_repository: Arc<RequestCommentRepository>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            user_service,
+            request_repository,
+            request_comment_repository,
+            notification_service,
+        }
+    }
+
+    /// Adds a comment of the caller to the thread of the request and notifies its participants.
+    pub async fn add_comment(
+        &self,
+        input: AddRequestCommentInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<RequestComment> {
+        let author = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let request = self.get_request(HelperMapper::to_uuid(input.request_id)?.as_bytes())?;
+        let thread = self
+            .request_comment_repository
+            .find_by_request_id(&request.id);
+
+        if thread.len() >= RequestComment::MAX_COMMENTS_PER_REQUEST {
+            Err(RequestCommentError::TooManyComments {
+                max: RequestComment::MAX_COMMENTS_PER_REQUEST,
+            })?
+        }
+
+        let comment = RequestComment {
+            id: *generate_uuid_v4().await.as_bytes(),
+            request_id: request.id,
+            author_id: author.id,
+            body: input.body,
+            created_timestamp: next_time(),
+        };
+
+        comment.validate()?;
+
+        self.request_comment_repository
+            .insert(comment.key(), comment.clone());
+
+        self.notify_participants(&request, &thread, &comment, &author)
+            .await;
+
+        Ok(comment)
+    }
+
+    /// Returns a page of the comments of the request, the oldest first.
+    pub fn list_comments(
+        &self,
+        input: ListRequestCommentsInput,
+    ) -> ServiceResult<PaginatedData<RequestComment>> {
+        let request = self.get_request(HelperMapper::to_uuid(input.request_id)?.as_bytes())?;
+        let comments = self
+            .request_comment_repository
+            .find_by_request_id(&request.id);
+
+        Ok(paginated_items(PaginatedItemsArgs {
+            offset: input.paginate.to_owned().and_then(|p| p.offset),
+            limit: input.paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_COMMENTS_LIMIT),
+            max_limit: Some(Self::MAX_LIST_COMMENTS_LIMIT),
+            items: &comments,
+        })?)
+    }
+
+    fn get_request(&self, request_id: &UUID) -> ServiceResult<Request> {
+        let request = self
+            .request_repository
+            .get(&Request::key(*request_id))
+            .ok_or(RequestError::NotFound {
+                request_id: Uuid::from_bytes(*request_id).hyphenated().to_string(),
+            })?;
+
+        Ok(request)
+    }
+
+    /// Notifies the requester, the users that voted or commented and, while the request is pending,
+    /// the users that can still approve it.
+    async fn notify_participants(
+        &self,
+        request: &Request,
+        thread: &[RequestComment],
+        comment: &RequestComment,
+        author: &User,
+    ) {
+        let mut recipients = BTreeSet::from([request.requested_by]);
+        recipients.extend(
+            request
+                .approvals
+                .iter()
+                .map(|approval| approval.approver_id),
+        );
+        recipients.extend(thread.iter().map(|comment| comment.author_id));
+
+        if request.status == RequestStatus::Created {
+            match request.find_all_possible_approvers().await {
+                Ok(approvers) => recipients.extend(approvers),
+                Err(_) => print(format!(
+                    "Failed to find all possible approvers for request {}",
+                    Uuid::from_bytes(request.id).hyphenated()
+                )),
+            }
+        }
+
+        recipients.remove(&author.id);
+
+        for user_id in recipients {
+            self.notification_service
+                .send_notification(
+                    user_id,
+                    NotificationType::RequestCommented(RequestCommentedNotification {
+                        request_id: request.id,
+                        comment_id: comment.id,
+                        author_id: author.id,
+                    }),
+                    request.title.to_owned(),
+                    Some(format!("{}: {}", author.name, comment.body)),
+                )
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            request_test_utils::mock_request, user_test_utils::mock_user, RequestApproval,
+            RequestApprovalStatus,
+        },
+        repositories::{NotificationRepository, UserRepository},
+    };
+    use candid::Principal;
+    use station_api::PaginationInput;
+
+    struct TestContext {
+        service: RequestCommentService,
+        caller_user: User,
+        call_context: CallContext,
+    }
+
+    fn setup() -> TestContext {
+        test_utils::init_canister_system();
+
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+        let mut user = mock_user();
+        user.identities = vec![call_context.caller()];
+
+        UserRepository::default().insert(user.to_key(), user.clone());
+
+        TestContext {
+            service: RequestCommentService::default(),
+            caller_user: user,
+            call_context,
+        }
+    }
+
+    fn add_comment_input(request: &Request, body: &str) -> AddRequestCommentInput {
+        AddRequestCommentInput {
+            request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn add_comment_notifies_the_participants_but_the_author() {
+        let ctx = setup();
+
+        let mut request = mock_request();
+        request.requested_by = [1; 16];
+        request.approvals = vec![
+            RequestApproval {
+                approver_id: ctx.caller_user.id,
+                status: RequestApprovalStatus::Approved,
+                status_reason: None,
+                decided_dt: 0,
+                last_modification_timestamp: 0,
+                reauthenticated_at: None,
+            },
+            RequestApproval {
+                approver_id: [3; 16],
+                status: RequestApprovalStatus::Rejected,
+                status_reason: None,
+                decided_dt: 0,
+                last_modification_timestamp: 0,
+                reauthenticated_at: None,
+            },
+        ];
+        request.status = RequestStatus::Rejected;
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let comment = ctx
+            .service
+            .add_comment(
+                add_comment_input(&request, "Is the destination the new cold wallet?"),
+                &ctx.call_context,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(comment.author_id, ctx.caller_user.id);
+
+        let notifications = NotificationRepository::default();
+        for user_id in [[1; 16], [3; 16]] {
+            let received = notifications.find_by_user_id(user_id);
+            assert_eq!(received.len(), 1);
+            assert_eq!(
+                received[0].notification_type,
+                NotificationType::RequestCommented(RequestCommentedNotification {
+                    request_id: request.id,
+                    comment_id: comment.id,
+                    author_id: ctx.caller_user.id,
+                })
+            );
+        }
+        assert!(notifications.find_by_user_id(ctx.caller_user.id).is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_comments_oldest_first() {
+        let ctx = setup();
+
+        let request = mock_request();
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        for body in ["first", "second", "third"] {
+            ctx.service
+                .add_comment(add_comment_input(&request, body), &ctx.call_context)
+                .await
+                .unwrap();
+        }
+
+        let page = ctx
+            .service
+            .list_comments(ListRequestCommentsInput {
+                request_id: Uuid::from_bytes(request.id).hyphenated().to_string(),
+                paginate: Some(PaginationInput {
+                    offset: None,
+                    limit: Some(2),
+                }),
+            })
+            .unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.next_offset, Some(2));
+        assert_eq!(
+            page.items
+                .iter()
+                .map(|comment| comment.body.as_str())
+                .collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+    }
+
+    #[tokio::test]
+    async fn fail_add_blank_comment() {
+        let ctx = setup();
+
+        let request = mock_request();
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let error = ctx
+            .service
+            .add_comment(add_comment_input(&request, "  "), &ctx.call_context)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.code, "INVALID_BODY_LENGTH");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     core::{
         authorization::Authorization,
+        ic_cdk::next_time,
         utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
         CallContext,
     },
@@ -9,39 +10,104 @@ use crate::{
         request_policy_rule::RequestPolicyRuleInput,
         request_specifier::RequestSpecifier,
         resource::{Resource, ResourceAction, ResourceId},
-        AddRequestPolicyOperationInput, EditRequestPolicyOperationInput, RequestPolicy,
-        RequestPolicyCallerPrivileges,
+        AccountSpend, AddRequestPolicyOperationInput, EditRequestPolicyOperationInput,
+        OperationSpend, Request, RequestId, RequestPolicy, RequestPolicyCallerPrivileges,
+        RequestPolicyExpirationInput, Transfer,
+    },
+    repositories::{
+        request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
+        AccountSpendRepository, ACCOUNT_SPEND_REPOSITORY,
     },
-    repositories::request_policy::{RequestPolicyRepository, REQUEST_POLICY_REPOSITORY},
 };
 use lazy_static::lazy_static;
 use orbit_essentials::{api::ServiceResult, cdk::api::print, types::UUID};
-use orbit_essentials::{model::ModelValidator, repository::Repository};
+use orbit_essentials::{
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+};
 use station_api::ListRequestPoliciesInput;
 use std::sync::Arc;
 use uuid::Uuid;
 
 lazy_static! {
-    pub static ref REQUEST_POLICY_SERVICE: Arc<RequestPolicyService> = Arc::new(
-        RequestPolicyService::new(Arc::clone(&REQUEST_POLICY_REPOSITORY))
-    );
+    pub static ref REQUEST_POLICY_SERVICE: Arc<RequestPolicyService> =
+        Arc::new(RequestPolicyService::new(
+            Arc::clone(&REQUEST_POLICY_REPOSITORY),
+            Arc::clone(&ACCOUNT_SPEND_REPOSITORY),
+        ));
 }
 
 #[derive(Default, Debug)]
 pub struct RequestPolicyService {
     request_policy_repository: Arc<RequestPolicyRepository>,
+    account_spend_repository: Arc<AccountSpendRepository>,
 }
 
 impl RequestPolicyService {
     pub const DEFAULT_POLICIES_LIMIT: u16 = 100;
     pub const MAX_LIST_POLICIES_LIMIT: u16 = 1000;
 
-    pub fn new(request_policy_repository: Arc<RequestPolicyRepository>) -> Self {
+    pub fn new(
+        request_policy_repository: Arc<RequestPolicyRepository>,
+        account_spend_repository: Arc<AccountSpendRepository>,
+    ) -> Self {
         Self {
             request_policy_repository,
+            account_spend_repository,
+        }
+    }
+
+    /// Records the amount of an approved request that moves value out of an account, so that it
+    /// counts towards the velocity limits of the account.
+    pub fn record_spend(&self, request: &Request) {
+        if let Some(spend) = AccountSpend::spent_by(&request.operation) {
+            self.insert_spend(request.id, spend);
+        }
+    }
+
+    /// Records the transfer of an execution of a standing order, each execution is charged against
+    /// the velocity limits of the account on its own since only the standing order was approved.
+    pub fn record_scheduled_transfer_spend(&self, transfer: &Transfer) {
+        self.insert_spend(
+            transfer.request_id,
+            OperationSpend {
+                account_id: transfer.from_account,
+                asset_id: transfer.asset_id,
+                amount: transfer.amount.clone(),
+            },
+        );
+    }
+
+    /// Releases the spend of a request that failed, since nothing was sent.
+    pub fn release_spend(&self, request: &Request) {
+        if let Some(spend) = AccountSpend::spent_by(&request.operation) {
+            self.account_spend_repository.remove_by_request_id(
+                &spend.account_id,
+                spend.asset_id.as_ref(),
+                &request.id,
+            );
         }
     }
 
+    /// Inserts the spend and drops the spends of the asset of the account past their retention.
+    fn insert_spend(&self, request_id: RequestId, spend: OperationSpend) {
+        let now = next_time();
+        let spend = AccountSpend {
+            account_id: spend.account_id,
+            asset_id: spend.asset_id,
+            request_id,
+            amount: spend.amount,
+            spent_at: now,
+        };
+
+        self.account_spend_repository.remove_older_than(
+            &spend.account_id,
+            spend.asset_id.as_ref(),
+            now.saturating_sub(AccountSpend::RETENTION_NS),
+        );
+        self.account_spend_repository.insert(spend.key(), spend);
+    }
+
     pub fn get_request_policy(&self, id: &UUID) -> ServiceResult<RequestPolicy, RequestError> {
         let policy =
             self.request_policy_repository
@@ -61,6 +127,7 @@ impl RequestPolicyService {
             id: *Uuid::new_v4().as_bytes(),
             specifier: input.specifier,
             rule: input.rule,
+            expiration_period_secs: input.expiration_period_secs,
         };
 
         policy.validate()?;
@@ -104,6 +171,7 @@ impl RequestPolicyService {
                             policy_id: *existing_policy_id,
                             specifier: Some(specifier),
                             rule: Some(policy_rule),
+                            expiration_period_secs: None,
                         })?;
                     }
                     None => {
@@ -111,6 +179,7 @@ impl RequestPolicyService {
                         let policy = self.add_request_policy(AddRequestPolicyOperationInput {
                             specifier,
                             rule: policy_rule,
+                            expiration_period_secs: None,
                         })?;
 
                         *editable_policy_id = Some(policy.id);
@@ -136,6 +205,13 @@ impl RequestPolicyService {
             policy.rule = policy_rule;
         }
 
+        if let Some(expiration_period) = input.expiration_period_secs {
+            policy.expiration_period_secs = match expiration_period {
+                RequestPolicyExpirationInput::Remove => None,
+                RequestPolicyExpirationInput::Set(secs) => Some(secs),
+            };
+        }
+
         policy.validate()?;
 
         self.request_policy_repository
@@ -211,6 +287,7 @@ mod tests {
         let policy = service.add_request_policy(AddRequestPolicyOperationInput {
             specifier: RequestSpecifier::AddAccount,
             rule: RequestPolicyRule::AutoApproved,
+            expiration_period_secs: Some(24 * 60 * 60),
         });
 
         assert!(policy.is_ok());
@@ -220,11 +297,13 @@ mod tests {
 
         assert_eq!(fetched_policy.specifier, policy.specifier);
         assert_eq!(fetched_policy.rule, policy.rule);
+        assert_eq!(fetched_policy.expiration_period_secs, Some(24 * 60 * 60));
 
         let policy = service.edit_request_policy(EditRequestPolicyOperationInput {
             policy_id: policy.id,
             specifier: Some(RequestSpecifier::AddAccount),
             rule: Some(RequestPolicyRule::AutoApproved),
+            expiration_period_secs: Some(RequestPolicyExpirationInput::Remove),
         });
 
         assert!(policy.is_ok());
@@ -234,6 +313,7 @@ mod tests {
 
         assert_eq!(updated_policy.specifier, policy.specifier);
         assert_eq!(updated_policy.rule, policy.rule);
+        assert_eq!(updated_policy.expiration_period_secs, None);
     }
 
     #[test]
@@ -272,6 +352,7 @@ mod tests {
             .add_request_policy(AddRequestPolicyOperationInput {
                 specifier: RequestSpecifier::AddAccount,
                 rule: RequestPolicyRule::AutoApproved,
+                expiration_period_secs: None,
             })
             .unwrap();
 
@@ -0,0 +1,327 @@
+use crate::{
+    core::{generate_uuid_v4, ic_cdk::next_time, CallContext},
+    errors::RequestViewError,
+    mappers::{HelperMapper, RequestViewMapper},
+    models::{RequestView, RequestViewId, User, UserGroupId},
+    repositories::{RequestViewRepository, REQUEST_VIEW_REPOSITORY},
+    services::{UserService, USER_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, model::ModelValidator, repository::Repository, utils::timestamp_to_rfc3339,
+};
+use station_api::{
+    CreateRequestViewInput, EditRequestViewInput, ListRequestsInput, PaginationInput,
+    RequestViewQueryDTO,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref REQUEST_VIEW_SERVICE: Arc<RequestViewService> =
+        Arc::new(RequestViewService::new(
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&REQUEST_VIEW_REPOSITORY),
+        ));
+}
+
+/// Manages the saved views of the requests, which can only be changed by the user that owns them.
+#[derive(Default, Debug)]
+pub struct RequestViewService {
+    user_service: Arc<UserService>,
+    request_view_repository: Arc<RequestViewRepository>,
+}
+
+impl RequestViewService {
+    pub fn new(
+        user_service: Arc<UserService>,
+        request_view_repository: Arc<RequestViewRepository>,
+    ) -> Self {
+        Self {
+            user_service,
+            request_view_repository,
+        }
+    }
+
+    /// Returns the view if it is visible to the user.
+    pub fn get_view(&self, id: &RequestViewId, user: &User) -> ServiceResult<RequestView> {
+        let view = self
+            .request_view_repository
+            .get(id)
+            .filter(|view| view.is_visible_to(&user.id, &user.groups))
+            .ok_or(RequestViewError::NotFound {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?;
+
+        Ok(view)
+    }
+
+    /// Returns the views owned by the caller or shared with one of its groups.
+    pub fn list_views(&self, ctx: &CallContext) -> ServiceResult<Vec<RequestView>> {
+        let user = self.user_service.get_user_by_identity(&ctx.caller())?;
+
+        Ok(self
+            .request_view_repository
+            .find_visible_to(&user.id, &user.groups))
+    }
+
+    pub async fn create_view(
+        &self,
+        input: CreateRequestViewInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<RequestView> {
+        let owner = self.user_service.get_user_by_identity(&ctx.caller())?;
+
+        if self.request_view_repository.find_by_owner(&owner.id).len()
+            >= RequestView::MAX_VIEWS_PER_USER
+        {
+            Err(RequestViewError::TooManyViews {
+                max: RequestView::MAX_VIEWS_PER_USER,
+            })?
+        }
+
+        let now = next_time();
+        let view = RequestView {
+            id: *generate_uuid_v4().await.as_bytes(),
+            name: input.name,
+            owner_id: owner.id,
+            shared_with_group_id: Self::shared_with_group(&owner, input.shared_with_group_id)?,
+            query: RequestViewMapper::to_query(input.query)?,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        view.validate()?;
+
+        self.request_view_repository.insert(view.id, view.clone());
+
+        Ok(view)
+    }
+
+    /// Replaces the name, sharing and query of a view owned by the caller.
+    pub fn edit_view(
+        &self,
+        input: EditRequestViewInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<RequestView> {
+        let owner = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let view_id = *HelperMapper::to_uuid(input.view_id)?.as_bytes();
+        let mut view = self.get_owned_view(&view_id, &owner)?;
+
+        view.name = input.name;
+        view.shared_with_group_id = Self::shared_with_group(&owner, input.shared_with_group_id)?;
+        view.query = RequestViewMapper::to_query(input.query)?;
+        view.last_modification_timestamp = next_time();
+
+        view.validate()?;
+
+        self.request_view_repository.insert(view.id, view.clone());
+
+        Ok(view)
+    }
+
+    pub fn remove_view(&self, view_id: String, ctx: &CallContext) -> ServiceResult<()> {
+        let owner = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let view_id = *HelperMapper::to_uuid(view_id)?.as_bytes();
+        let view = self.get_owned_view(&view_id, &owner)?;
+
+        self.request_view_repository.remove(&view.id);
+
+        Ok(())
+    }
+
+    /// Builds the input to list the requests matching the view, with the ages of the view
+    /// relative to the current time.
+    pub fn to_list_requests_input(
+        &self,
+        view_id: String,
+        paginate: Option<PaginationInput>,
+        with_evaluation_results: bool,
+        ctx: &CallContext,
+    ) -> ServiceResult<ListRequestsInput> {
+        let user = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let view_id = *HelperMapper::to_uuid(view_id)?.as_bytes();
+        let view = self.get_view(&view_id, &user)?;
+        let now = next_time();
+        let created_from_dt = view
+            .query
+            .max_age_ns
+            .map(|max_age| timestamp_to_rfc3339(&now.saturating_sub(max_age)));
+        let created_to_dt = view
+            .query
+            .min_age_ns
+            .map(|min_age| timestamp_to_rfc3339(&now.saturating_sub(min_age)));
+        let query = RequestViewQueryDTO::from(view.query);
+
+        Ok(ListRequestsInput {
+            requester_ids: query.requester_ids,
+            approver_ids: query.approver_ids,
+            statuses: query.statuses,
+            operation_types: query.operation_types,
+            expiration_from_dt: None,
+            expiration_to_dt: None,
+            created_from_dt,
+            created_to_dt,
+            paginate,
+            sort_by: query.sort_by,
+            only_approvable: query.only_approvable,
+            with_evaluation_results,
+            tags: None,
+        })
+    }
+
+    fn get_owned_view(&self, id: &RequestViewId, owner: &User) -> ServiceResult<RequestView> {
+        let view = self.get_view(id, owner)?;
+
+        if view.owner_id != owner.id {
+            Err(RequestViewError::Forbidden)?
+        }
+
+        Ok(view)
+    }
+
+    /// The view can only be shared with a group the owner is a member of.
+    fn shared_with_group(
+        owner: &User,
+        group_id: Option<String>,
+    ) -> ServiceResult<Option<UserGroupId>> {
+        let Some(group_id) = group_id else {
+            return Ok(None);
+        };
+
+        let group_id = *HelperMapper::to_uuid(group_id)?.as_bytes();
+        if !owner.groups.contains(&group_id) {
+            Err(RequestViewError::NotGroupMember {
+                group_id: Uuid::from_bytes(group_id).hyphenated().to_string(),
+            })?
+        }
+
+        Ok(Some(group_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{user_test_utils::mock_user, ListRequestsOperationType, RequestStatusCode},
+        repositories::UserRepository,
+    };
+    use candid::Principal;
+    use station_api::{ListRequestsOperationTypeDTO, RequestStatusCodeDTO};
+
+    struct TestContext {
+        service: RequestViewService,
+        caller_user: User,
+        call_context: CallContext,
+    }
+
+    fn setup() -> TestContext {
+        test_utils::init_canister_system();
+
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+        let mut user = mock_user();
+        user.identities = vec![call_context.caller()];
+        user.groups = vec![[7; 16]];
+
+        UserRepository::default().insert(user.to_key(), user.clone());
+
+        TestContext {
+            service: RequestViewService::default(),
+            caller_user: user,
+            call_context,
+        }
+    }
+
+    fn pending_transfers_input() -> CreateRequestViewInput {
+        CreateRequestViewInput {
+            name: "Transfers pending for more than 48h".to_string(),
+            shared_with_group_id: Some(Uuid::from_bytes([7; 16]).hyphenated().to_string()),
+            query: RequestViewQueryDTO {
+                statuses: Some(vec![RequestStatusCodeDTO::Created]),
+                operation_types: Some(vec![ListRequestsOperationTypeDTO::Transfer(None)]),
+                requester_ids: None,
+                approver_ids: None,
+                min_age_secs: Some(48 * 60 * 60),
+                max_age_secs: None,
+                only_approvable: false,
+                sort_by: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn create_view_and_list_it_as_group_member() {
+        let ctx = setup();
+
+        let view = ctx
+            .service
+            .create_view(pending_transfers_input(), &ctx.call_context)
+            .await
+            .unwrap();
+
+        assert_eq!(view.owner_id, ctx.caller_user.id);
+        assert_eq!(view.query.statuses, vec![RequestStatusCode::Created]);
+        assert_eq!(
+            view.query.operation_types,
+            vec![ListRequestsOperationType::Transfer(None)]
+        );
+        assert_eq!(view.query.min_age_ns, Some(48 * 60 * 60 * 1_000_000_000));
+
+        let member_context = CallContext::new(Principal::from_slice(&[10; 29]));
+        let mut member = mock_user();
+        member.identities = vec![member_context.caller()];
+        member.groups = vec![[7; 16]];
+        UserRepository::default().insert(member.to_key(), member.clone());
+
+        let views = ctx.service.list_views(&member_context).unwrap();
+
+        assert_eq!(views, vec![view.clone()]);
+        assert!(ctx
+            .service
+            .remove_view(
+                Uuid::from_bytes(view.id).hyphenated().to_string(),
+                &member_context
+            )
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn fail_share_view_with_group_of_other_users() {
+        let ctx = setup();
+        let mut input = pending_transfers_input();
+        input.shared_with_group_id = Some(Uuid::from_bytes([8; 16]).hyphenated().to_string());
+
+        let result = ctx.service.create_view(input, &ctx.call_context).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn list_requests_input_is_relative_to_now() {
+        let ctx = setup();
+        let view = ctx
+            .service
+            .create_view(pending_transfers_input(), &ctx.call_context)
+            .await
+            .unwrap();
+
+        let input = ctx
+            .service
+            .to_list_requests_input(
+                Uuid::from_bytes(view.id).hyphenated().to_string(),
+                None,
+                false,
+                &ctx.call_context,
+            )
+            .unwrap();
+
+        assert!(input.created_from_dt.is_none());
+        assert!(input.created_to_dt.is_some());
+        assert!(matches!(
+            input.statuses.as_deref(),
+            Some([RequestStatusCodeDTO::Created])
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    core::ic_cdk::{api::print, next_time},
+    jobs,
+    models::{PolicyChange, RequestId, ScheduledPolicyChange, ScheduledPolicyChangeStatus},
+    repositories::{ScheduledPolicyChangeRepository, SCHEDULED_POLICY_CHANGE_REPOSITORY},
+    services::{
+        permission::{PermissionService, PERMISSION_SERVICE},
+        RequestPolicyService, REQUEST_POLICY_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult, model::ModelKey, repository::Repository, types::Timestamp,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref SCHEDULED_POLICY_CHANGE_SERVICE: Arc<ScheduledPolicyChangeService> =
+        Arc::new(ScheduledPolicyChangeService::new(
+            Arc::clone(&SCHEDULED_POLICY_CHANGE_REPOSITORY),
+            Arc::clone(&REQUEST_POLICY_SERVICE),
+            Arc::clone(&PERMISSION_SERVICE),
+        ));
+}
+
+/// Handles the request policy and permission changes that take effect at a later time.
+///
+/// The approved change is kept as a pending scheduled change and applied by a job once its
+/// effective time is reached.
+#[derive(Default, Debug)]
+pub struct ScheduledPolicyChangeService {
+    scheduled_policy_change_repository: Arc<ScheduledPolicyChangeRepository>,
+    request_policy_service: Arc<RequestPolicyService>,
+    permission_service: Arc<PermissionService>,
+}
+
+impl ScheduledPolicyChangeService {
+    pub fn new(
+        scheduled_policy_change_repository: Arc<ScheduledPolicyChangeRepository>,
+        request_policy_service: Arc<RequestPolicyService>,
+        permission_service: Arc<PermissionService>,
+    ) -> Self {
+        Self {
+            scheduled_policy_change_repository,
+            request_policy_service,
+            permission_service,
+        }
+    }
+
+    /// Returns the effective time if it is still in the future, in which case the change must be scheduled.
+    pub fn deferred_until(effective_from: Option<Timestamp>) -> Option<Timestamp> {
+        effective_from.filter(|effective_from| *effective_from > next_time())
+    }
+
+    /// Keeps the change until its effective time and schedules its activation.
+    pub fn schedule_policy_change(
+        &self,
+        request_id: RequestId,
+        change: PolicyChange,
+        effective_from: Timestamp,
+    ) -> ScheduledPolicyChange {
+        let now = next_time();
+        let scheduled_change = ScheduledPolicyChange {
+            id: *Uuid::new_v4().as_bytes(),
+            request_id,
+            change,
+            effective_from,
+            status: ScheduledPolicyChangeStatus::Pending,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        self.scheduled_policy_change_repository
+            .insert(scheduled_change.key(), scheduled_change.clone());
+
+        jobs::schedule_policy_change_activation(effective_from);
+
+        scheduled_change
+    }
+
+    /// Returns the changes that are waiting for their effective time.
+    pub fn list_pending_policy_changes(&self) -> Vec<ScheduledPolicyChange> {
+        self.scheduled_policy_change_repository.find_pending()
+    }
+
+    /// Applies the pending changes whose effective time has been reached, in the order of that time.
+    ///
+    /// A change that cannot be applied anymore is marked as failed with the reason, the other changes
+    /// are still applied.
+    pub fn activate_due_policy_changes(&self) {
+        let now = next_time();
+
+        for mut scheduled_change in self.scheduled_policy_change_repository.find_pending() {
+            if !scheduled_change.is_due(now) {
+                break;
+            }
+
+            scheduled_change.status = match self.apply(&scheduled_change.change) {
+                Ok(()) => ScheduledPolicyChangeStatus::Activated { activated_at: now },
+                Err(error) => {
+                    print(format!(
+                        "Failed to activate the scheduled policy change {}: {}",
+                        Uuid::from_bytes(scheduled_change.id).hyphenated(),
+                        error
+                    ));
+
+                    ScheduledPolicyChangeStatus::Failed {
+                        reason: error.to_string(),
+                    }
+                }
+            };
+            scheduled_change.last_modification_timestamp = now;
+
+            self.scheduled_policy_change_repository
+                .insert(scheduled_change.key(), scheduled_change);
+        }
+    }
+
+    fn apply(&self, change: &PolicyChange) -> ServiceResult<()> {
+        match change {
+            PolicyChange::AddRequestPolicy(input) => {
+                self.request_policy_service
+                    .add_request_policy(input.to_owned())?;
+            }
+            PolicyChange::EditRequestPolicy(input) => {
+                self.request_policy_service
+                    .edit_request_policy(input.to_owned())?;
+            }
+            PolicyChange::EditPermission(input) => {
+                self.permission_service.edit_permission(input.to_owned())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::ic_cdk::api::time,
+        models::{
+            request_policy_rule::RequestPolicyRule, request_specifier::RequestSpecifier,
+            AddRequestPolicyOperationInput, EditRequestPolicyOperationInput,
+        },
+        repositories::REQUEST_POLICY_REPOSITORY,
+    };
+
+    #[test]
+    fn activate_due_policy_changes_applies_them_once() {
+        let in_the_future = time() + 60 * 1_000_000_000;
+        let scheduled_change = SCHEDULED_POLICY_CHANGE_SERVICE.schedule_policy_change(
+            [1; 16],
+            PolicyChange::AddRequestPolicy(AddRequestPolicyOperationInput {
+                specifier: RequestSpecifier::AddAccount,
+                rule: RequestPolicyRule::AutoApproved,
+                expiration_period_secs: None,
+            }),
+            in_the_future,
+        );
+        let due_change = SCHEDULED_POLICY_CHANGE_SERVICE.schedule_policy_change(
+            [2; 16],
+            PolicyChange::AddRequestPolicy(AddRequestPolicyOperationInput {
+                specifier: RequestSpecifier::AddUser,
+                rule: RequestPolicyRule::AutoApproved,
+                expiration_period_secs: None,
+            }),
+            0,
+        );
+
+        SCHEDULED_POLICY_CHANGE_SERVICE.activate_due_policy_changes();
+
+        let policies = REQUEST_POLICY_REPOSITORY.list();
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].specifier, RequestSpecifier::AddUser);
+        assert!(matches!(
+            SCHEDULED_POLICY_CHANGE_REPOSITORY
+                .get(&due_change.id)
+                .unwrap()
+                .status,
+            ScheduledPolicyChangeStatus::Activated { .. }
+        ));
+        assert_eq!(
+            SCHEDULED_POLICY_CHANGE_SERVICE.list_pending_policy_changes(),
+            vec![scheduled_change]
+        );
+    }
+
+    #[test]
+    fn activate_due_policy_changes_keeps_the_failure_reason() {
+        let scheduled_change = SCHEDULED_POLICY_CHANGE_SERVICE.schedule_policy_change(
+            [1; 16],
+            PolicyChange::EditRequestPolicy(EditRequestPolicyOperationInput {
+                policy_id: [9; 16],
+                specifier: None,
+                rule: Some(RequestPolicyRule::AutoApproved),
+                expiration_period_secs: None,
+            }),
+            0,
+        );
+
+        SCHEDULED_POLICY_CHANGE_SERVICE.activate_due_policy_changes();
+
+        assert!(matches!(
+            SCHEDULED_POLICY_CHANGE_REPOSITORY
+                .get(&scheduled_change.id)
+                .unwrap()
+                .status,
+            ScheduledPolicyChangeStatus::Failed { .. }
+        ));
+        assert!(SCHEDULED_POLICY_CHANGE_SERVICE
+            .list_pending_policy_changes()
+            .is_empty());
+    }
+}
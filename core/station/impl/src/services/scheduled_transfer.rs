@@ -0,0 +1,381 @@
+use crate::{
+    core::{
+        generate_uuid_v4,
+        ic_cdk::{api::print, next_time},
+        CallContext,
+    },
+    errors::ScheduledTransferError,
+    factories::blockchains::BlockchainApiFactory,
+    jobs,
+    models::{
+        Account, AccountId, AddScheduledTransferOperationInput, Request, ScheduledTransfer,
+        ScheduledTransferId, ScheduledTransferStatus, Transfer,
+    },
+    repositories::{
+        AccountRepository, ScheduledTransferRepository, ACCOUNT_REPOSITORY,
+        SCHEDULED_TRANSFER_REPOSITORY,
+    },
+    services::{
+        RequestPolicyService, TransferService, UserService, REQUEST_POLICY_SERVICE, USER_SERVICE,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{
+    api::ServiceResult,
+    model::{ModelKey, ModelValidator},
+    repository::Repository,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref SCHEDULED_TRANSFER_SERVICE: Arc<ScheduledTransferService> =
+        Arc::new(ScheduledTransferService::new(
+            Arc::clone(&SCHEDULED_TRANSFER_REPOSITORY),
+            Arc::clone(&ACCOUNT_REPOSITORY),
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&REQUEST_POLICY_SERVICE),
+        ));
+}
+
+/// Handles the standing orders, which create the same transfer at every interval once their
+/// request is approved.
+#[derive(Default, Debug)]
+pub struct ScheduledTransferService {
+    scheduled_transfer_repository: Arc<ScheduledTransferRepository>,
+    account_repository: Arc<AccountRepository>,
+    user_service: Arc<UserService>,
+    request_policy_service: Arc<RequestPolicyService>,
+    transfer_service: TransferService,
+}
+
+impl ScheduledTransferService {
+    pub fn new(
+        scheduled_transfer_repository: Arc<ScheduledTransferRepository>,
+        account_repository: Arc<AccountRepository>,
+        user_service: Arc<UserService>,
+        request_policy_service: Arc<RequestPolicyService>,
+    ) -> Self {
+        Self {
+            scheduled_transfer_repository,
+            account_repository,
+            user_service,
+            request_policy_service,
+            transfer_service: TransferService::default(),
+        }
+    }
+
+    pub fn get_scheduled_transfer(
+        &self,
+        id: &ScheduledTransferId,
+    ) -> ServiceResult<ScheduledTransfer> {
+        let scheduled_transfer =
+            self.scheduled_transfer_repository
+                .get(id)
+                .ok_or(ScheduledTransferError::NotFound {
+                    id: Uuid::from_bytes(*id).hyphenated().to_string(),
+                })?;
+
+        Ok(scheduled_transfer)
+    }
+
+    /// Creates the standing order of the approved request and schedules its first execution.
+    pub async fn add_scheduled_transfer(
+        &self,
+        request: &Request,
+        input: AddScheduledTransferOperationInput,
+    ) -> ServiceResult<ScheduledTransfer> {
+        let now = next_time();
+        let scheduled_transfer = ScheduledTransfer {
+            id: *generate_uuid_v4().await.as_bytes(),
+            request_id: request.id,
+            requested_by: request.requested_by,
+            transfer: input.transfer,
+            interval_ns: input.interval_ns,
+            next_execution_at: input.start_at.unwrap_or(now).max(now),
+            end_at: input.end_at,
+            executions: 0,
+            last_transfer_id: None,
+            last_error: None,
+            status: ScheduledTransferStatus::Active,
+            created_timestamp: now,
+            last_modification_timestamp: now,
+        };
+
+        scheduled_transfer.validate()?;
+
+        self.scheduled_transfer_repository
+            .insert(scheduled_transfer.key(), scheduled_transfer.clone());
+
+        jobs::schedule_scheduled_transfer_execution(scheduled_transfer.next_execution_at);
+
+        Ok(scheduled_transfer)
+    }
+
+    /// Returns the standing orders that send from the account.
+    pub fn list_scheduled_transfers(&self, account_id: &AccountId) -> Vec<ScheduledTransfer> {
+        self.scheduled_transfer_repository
+            .find_by_account_id(account_id)
+    }
+
+    /// Stops the standing order, the transfers it already created are not affected.
+    pub fn cancel_scheduled_transfer(
+        &self,
+        id: &ScheduledTransferId,
+        ctx: &CallContext,
+    ) -> ServiceResult<ScheduledTransfer> {
+        let mut scheduled_transfer = self.get_scheduled_transfer(id)?;
+
+        if !scheduled_transfer.is_active() {
+            Err(ScheduledTransferError::NotActive {
+                id: Uuid::from_bytes(*id).hyphenated().to_string(),
+            })?
+        }
+
+        let user = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let now = next_time();
+
+        scheduled_transfer.status = ScheduledTransferStatus::Cancelled {
+            cancelled_at: now,
+            cancelled_by: user.id,
+        };
+        scheduled_transfer.last_modification_timestamp = now;
+
+        self.scheduled_transfer_repository
+            .insert(scheduled_transfer.key(), scheduled_transfer.clone());
+
+        Ok(scheduled_transfer)
+    }
+
+    /// Creates the transfers of the standing orders whose execution time has been reached.
+    ///
+    /// A transfer that cannot be created is recorded as the last error of its standing order, which
+    /// is still executed at the next interval.
+    pub async fn execute_due_scheduled_transfers(&self) {
+        let now = next_time();
+
+        for mut scheduled_transfer in self.scheduled_transfer_repository.find_active() {
+            if !scheduled_transfer.is_due(now) {
+                break;
+            }
+
+            match self.create_transfer(&scheduled_transfer).await {
+                Ok(transfer) => {
+                    scheduled_transfer.last_transfer_id = Some(transfer.id);
+                    scheduled_transfer.last_error = None;
+                }
+                Err(error) => {
+                    print(format!(
+                        "Failed to execute the scheduled transfer {}: {}",
+                        Uuid::from_bytes(scheduled_transfer.id).hyphenated(),
+                        error
+                    ));
+
+                    scheduled_transfer.last_error = Some(error);
+                }
+            }
+
+            scheduled_transfer.executions += 1;
+            scheduled_transfer.last_modification_timestamp = now;
+
+            if scheduled_transfer.advance(now) {
+                jobs::schedule_scheduled_transfer_execution(scheduled_transfer.next_execution_at);
+            } else {
+                scheduled_transfer.status =
+                    ScheduledTransferStatus::Completed { completed_at: now };
+            }
+
+            self.scheduled_transfer_repository
+                .insert(scheduled_transfer.key(), scheduled_transfer);
+        }
+    }
+
+    async fn create_transfer(
+        &self,
+        scheduled_transfer: &ScheduledTransfer,
+    ) -> Result<Transfer, String> {
+        let input = &scheduled_transfer.transfer;
+        let account = self
+            .account_repository
+            .get(&Account::key(input.from_account_id))
+            .ok_or(format!(
+                "Account {} does not exist.",
+                Uuid::from_bytes(input.from_account_id).hyphenated()
+            ))?
+            .for_asset(input.asset_id.as_ref())
+            .map_err(|e| e.to_string())?;
+
+        let fee = match &input.fee {
+            Some(fee) => fee.clone(),
+            None => {
+                let blockchain_api = BlockchainApiFactory::build(
+                    &account.blockchain,
+                    &account.standard,
+                    &account.network,
+                )
+                .map_err(|e| format!("Failed to build blockchain api: {}", e))?;
+                let transaction_fee = blockchain_api
+                    .transaction_fee(&account)
+                    .await
+                    .map_err(|e| format!("Failed to fetch transaction fee: {}", e))?;
+
+                candid::Nat(transaction_fee.fee)
+            }
+        };
+
+        let mut transfer = Transfer::new(
+            scheduled_transfer.request_id,
+            *generate_uuid_v4().await.as_bytes(),
+            scheduled_transfer.requested_by,
+            input.from_account_id,
+            input.to.clone(),
+            input.metadata.clone(),
+            input.amount.clone(),
+            fee,
+            input.network.clone(),
+        );
+        transfer.spend_from = input.spend_from.clone();
+        transfer.memo = input.memo.clone();
+        transfer.category = input.category.clone();
+        transfer.asset_id = input.asset_id;
+
+        let transfer = self
+            .transfer_service
+            .add_transfer(transfer)
+            .map_err(|e| format!("Failed to validate transfer: {}", e))?;
+
+        self.request_policy_service
+            .record_scheduled_transfer_spend(&transfer);
+
+        Ok(transfer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            account_test_utils::mock_account, request_test_utils::mock_request,
+            scheduled_transfer_test_utils::mock_scheduled_transfer, user_test_utils::mock_user,
+        },
+        repositories::{UserRepository, ACCOUNT_SPEND_REPOSITORY, TRANSFER_REPOSITORY},
+    };
+    use candid::Principal;
+
+    fn add_account() -> Account {
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        account
+    }
+
+    #[tokio::test]
+    async fn execute_due_scheduled_transfers_creates_the_transfer_and_moves_on() {
+        let account = add_account();
+        let mut scheduled_transfer = mock_scheduled_transfer();
+        scheduled_transfer.transfer.from_account_id = account.id;
+        scheduled_transfer.transfer.to = "destination-address".to_string();
+        scheduled_transfer.transfer.fee = Some(candid::Nat::from(10_000u64));
+        scheduled_transfer.next_execution_at = 0;
+        SCHEDULED_TRANSFER_REPOSITORY.insert(scheduled_transfer.key(), scheduled_transfer.clone());
+
+        SCHEDULED_TRANSFER_SERVICE
+            .execute_due_scheduled_transfers()
+            .await;
+
+        let executed = SCHEDULED_TRANSFER_REPOSITORY
+            .get(&scheduled_transfer.id)
+            .unwrap();
+        assert_eq!(executed.executions, 1);
+        assert_eq!(executed.last_error, None);
+        assert!(executed.next_execution_at > next_time() - ScheduledTransfer::MIN_INTERVAL_NS);
+        assert!(executed.is_active());
+
+        let transfer = TRANSFER_REPOSITORY
+            .get(&Transfer::key(executed.last_transfer_id.unwrap()))
+            .unwrap();
+        assert_eq!(transfer.request_id, scheduled_transfer.request_id);
+        assert_eq!(transfer.amount, scheduled_transfer.transfer.amount);
+
+        // the execution counts towards the velocity limits of the account
+        let spends = ACCOUNT_SPEND_REPOSITORY.find_by_account_since(&account.id, None, 0);
+        assert_eq!(spends.len(), 1);
+        assert_eq!(spends[0].request_id, scheduled_transfer.request_id);
+        assert_eq!(spends[0].amount, scheduled_transfer.transfer.amount);
+    }
+
+    #[tokio::test]
+    async fn execute_due_scheduled_transfers_completes_at_the_end_date() {
+        let mut scheduled_transfer = mock_scheduled_transfer();
+        scheduled_transfer.next_execution_at = 0;
+        scheduled_transfer.end_at = Some(1);
+        SCHEDULED_TRANSFER_REPOSITORY.insert(scheduled_transfer.key(), scheduled_transfer.clone());
+
+        SCHEDULED_TRANSFER_SERVICE
+            .execute_due_scheduled_transfers()
+            .await;
+
+        let executed = SCHEDULED_TRANSFER_REPOSITORY
+            .get(&scheduled_transfer.id)
+            .unwrap();
+        // the account of the mock does not exist, the failure is kept for the requester
+        assert!(executed.last_error.is_some());
+        assert!(matches!(
+            executed.status,
+            ScheduledTransferStatus::Completed { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_and_cancel_scheduled_transfer() {
+        test_utils::init_canister_system();
+
+        let call_context = CallContext::new(Principal::from_slice(&[9; 29]));
+        let mut user = mock_user();
+        user.identities = vec![call_context.caller()];
+        UserRepository::default().insert(user.to_key(), user.clone());
+
+        let scheduled_transfer = SCHEDULED_TRANSFER_SERVICE
+            .add_scheduled_transfer(
+                &mock_request(),
+                AddScheduledTransferOperationInput {
+                    transfer: mock_scheduled_transfer().transfer,
+                    interval_ns: ScheduledTransfer::MIN_INTERVAL_NS,
+                    start_at: None,
+                    end_at: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            SCHEDULED_TRANSFER_SERVICE
+                .list_scheduled_transfers(&scheduled_transfer.transfer.from_account_id),
+            vec![scheduled_transfer.clone()]
+        );
+
+        let cancelled = SCHEDULED_TRANSFER_SERVICE
+            .cancel_scheduled_transfer(&scheduled_transfer.id, &call_context)
+            .unwrap();
+
+        assert_eq!(
+            cancelled.status,
+            ScheduledTransferStatus::Cancelled {
+                cancelled_at: cancelled.last_modification_timestamp,
+                cancelled_by: user.id,
+            }
+        );
+        assert_eq!(
+            SCHEDULED_TRANSFER_SERVICE
+                .cancel_scheduled_transfer(&scheduled_transfer.id, &call_context),
+            Err(ScheduledTransferError::NotActive {
+                id: Uuid::from_bytes(scheduled_transfer.id)
+                    .hyphenated()
+                    .to_string(),
+            }
+            .into())
+        );
+    }
+}
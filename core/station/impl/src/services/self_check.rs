@@ -0,0 +1,402 @@
+use crate::{
+    core::{
+        authorization::Authorization,
+        evaluation::{Evaluate, REQUEST_POLICY_RULE_EVALUATOR},
+        ic_cdk::{api::print, next_time},
+        read_system_info,
+        request::RequestEvaluator,
+        write_system_info, CallContext,
+    },
+    models::{
+        resource::{Resource, SystemResourceAction},
+        system::{SelfCheckReport, SelfCheckResult},
+        NotificationType, RequestStatusCode, TransferStatus, User,
+    },
+    repositories::{
+        permission::PERMISSION_REPOSITORY, RequestRepository, TransferRepository, UserRepository,
+        REQUEST_REPOSITORY, USER_GROUP_REPOSITORY, USER_REPOSITORY,
+    },
+    services::{LocaleService, NotificationService, LOCALE_SERVICE, NOTIFICATION_SERVICE},
+    SYSTEM_VERSION,
+};
+use lazy_static::lazy_static;
+use orbit_essentials::repository::Repository;
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref SELF_CHECK_SERVICE: Arc<SelfCheckService> = Arc::new(SelfCheckService::new(
+        Arc::clone(&USER_REPOSITORY),
+        Arc::clone(&REQUEST_REPOSITORY),
+        Arc::clone(&NOTIFICATION_SERVICE),
+        Arc::clone(&LOCALE_SERVICE),
+    ));
+}
+
+/// Runs a suite of checks after the station upgraded itself, so that the admins learn that the new
+/// version misbehaves with the data of the station before it causes more harm.
+///
+/// The checks are read-only: the invariants of the repositories, a sample of the indexes compared
+/// with the records they point to, and the evaluation of the policies for a sample of the requests.
+#[derive(Default, Debug)]
+pub struct SelfCheckService {
+    user_repository: Arc<UserRepository>,
+    request_repository: Arc<RequestRepository>,
+    transfer_repository: TransferRepository,
+    notification_service: Arc<NotificationService>,
+    locale_service: Arc<LocaleService>,
+}
+
+impl SelfCheckService {
+    /// The maximum number of records sampled by each index and evaluation check.
+    pub const SAMPLE_SIZE: usize = 20;
+
+    const SAMPLED_REQUEST_STATUSES: [RequestStatusCode; 4] = [
+        RequestStatusCode::Created,
+        RequestStatusCode::Approved,
+        RequestStatusCode::Scheduled,
+        RequestStatusCode::Processing,
+    ];
+
+    pub fn new(
+        user_repository: Arc<UserRepository>,
+        request_repository: Arc<RequestRepository>,
+        notification_service: Arc<NotificationService>,
+        locale_service: Arc<LocaleService>,
+    ) -> Self {
+        Self {
+            user_repository,
+            request_repository,
+            transfer_repository: TransferRepository::default(),
+            notification_service,
+            locale_service,
+        }
+    }
+
+    /// Runs the checks, stores the report in the system info and notifies the admins of the outcome.
+    pub async fn run_self_check(&self) -> SelfCheckReport {
+        let report = SelfCheckReport {
+            version: SYSTEM_VERSION.to_string(),
+            checked_at: next_time(),
+            checks: vec![
+                Self::to_result("active_users", self.check_active_users()),
+                Self::to_result("user_groups", self.check_user_groups()),
+                Self::to_result("permissions", self.check_permissions()),
+                Self::to_result("request_indexes", self.check_request_indexes()),
+                Self::to_result("transfer_indexes", self.check_transfer_indexes()),
+                Self::to_result("policy_evaluation", self.check_policy_evaluation()),
+            ],
+        };
+
+        let mut system_info = read_system_info();
+        system_info.set_last_self_check(report.clone());
+        write_system_info(system_info);
+
+        for check in report.checks.iter().filter(|check| !check.passed) {
+            print(format!(
+                "Self-check `{}` failed: {}",
+                check.name,
+                check.details.as_deref().unwrap_or_default()
+            ));
+        }
+
+        self.notify_admins(&report).await;
+
+        report
+    }
+
+    fn to_result(name: &str, outcome: Result<(), String>) -> SelfCheckResult {
+        SelfCheckResult {
+            name: name.to_string(),
+            passed: outcome.is_ok(),
+            details: outcome.err().map(|details| {
+                details
+                    .chars()
+                    .take(SelfCheckReport::MAX_DETAILS_LEN)
+                    .collect()
+            }),
+        }
+    }
+
+    /// The station is unusable without users to approve the requests.
+    fn check_active_users(&self) -> Result<(), String> {
+        if !self
+            .user_repository
+            .list()
+            .iter()
+            .any(|user| user.is_active())
+        {
+            return Err("there is no active user".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn check_user_groups(&self) -> Result<(), String> {
+        for user in self.user_repository.list() {
+            if let Some(group_id) = user
+                .groups
+                .iter()
+                .find(|group_id| USER_GROUP_REPOSITORY.get(group_id).is_none())
+            {
+                return Err(format!(
+                    "user {} is a member of the missing group {}",
+                    Uuid::from_bytes(user.id).hyphenated(),
+                    Uuid::from_bytes(*group_id).hyphenated()
+                ));
+            }
+        }
+
+        if let Some(committee) = read_system_info().get_disaster_recovery_committee() {
+            if USER_GROUP_REPOSITORY
+                .get(&committee.user_group_id)
+                .is_none()
+            {
+                return Err(format!(
+                    "the disaster recovery committee is the missing group {}",
+                    Uuid::from_bytes(committee.user_group_id).hyphenated()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_permissions(&self) -> Result<(), String> {
+        for permission in PERMISSION_REPOSITORY.list() {
+            if let Some(group_id) = permission
+                .allow
+                .user_groups
+                .iter()
+                .find(|group_id| USER_GROUP_REPOSITORY.get(group_id).is_none())
+            {
+                return Err(format!(
+                    "the permission of {} allows the missing group {}",
+                    permission.resource,
+                    Uuid::from_bytes(*group_id).hyphenated()
+                ));
+            }
+
+            if let Some(user_id) = permission
+                .allow
+                .users
+                .iter()
+                .find(|user_id| self.user_repository.get(&User::key(**user_id)).is_none())
+            {
+                return Err(format!(
+                    "the permission of {} allows the missing user {}",
+                    permission.resource,
+                    Uuid::from_bytes(*user_id).hyphenated()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compares the status index of the requests with the status of the requests it points to.
+    fn check_request_indexes(&self) -> Result<(), String> {
+        for status in Self::SAMPLED_REQUEST_STATUSES {
+            for request in self
+                .request_repository
+                .find_by_status(status.clone(), None, None)
+                .into_iter()
+                .take(Self::SAMPLE_SIZE)
+            {
+                let actual_status = RequestStatusCode::from(request.status.clone());
+
+                if actual_status != status {
+                    return Err(format!(
+                        "request {} is indexed as {} but is {}",
+                        Uuid::from_bytes(request.id).hyphenated(),
+                        status,
+                        actual_status
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compares the status and account indexes of the pending transfers with the transfers they point to.
+    fn check_transfer_indexes(&self) -> Result<(), String> {
+        let statuses = [
+            TransferStatus::Created.to_string(),
+            TransferStatus::Processing { started_at: 0 }.to_string(),
+        ];
+
+        for status in statuses {
+            for transfer in self
+                .transfer_repository
+                .find_by_status(status.clone(), None, None)
+                .into_iter()
+                .take(Self::SAMPLE_SIZE)
+            {
+                if transfer.status.to_string() != status {
+                    return Err(format!(
+                        "transfer {} is indexed as {} but is {}",
+                        Uuid::from_bytes(transfer.id).hyphenated(),
+                        status,
+                        transfer.status
+                    ));
+                }
+
+                let is_indexed_by_account = self
+                    .transfer_repository
+                    .find_by_account(
+                        transfer.from_account,
+                        Some(transfer.created_timestamp),
+                        Some(transfer.created_timestamp),
+                        None,
+                    )
+                    .iter()
+                    .any(|indexed| indexed.id == transfer.id);
+
+                if !is_indexed_by_account {
+                    return Err(format!(
+                        "transfer {} is missing from the index of its account",
+                        Uuid::from_bytes(transfer.id).hyphenated()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates the policies for a sample of the requests awaiting approvals, without storing the results.
+    fn check_policy_evaluation(&self) -> Result<(), String> {
+        for request in self
+            .request_repository
+            .find_by_status(RequestStatusCode::Created, None, None)
+            .into_iter()
+            .take(Self::SAMPLE_SIZE)
+        {
+            let request_id = request.id;
+
+            RequestEvaluator::new(REQUEST_POLICY_RULE_EVALUATOR.to_owned(), request)
+                .evaluate()
+                .map_err(|err| {
+                    format!(
+                        "the evaluation of request {} failed: {}",
+                        Uuid::from_bytes(request_id).hyphenated(),
+                        err
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Notifies the users that can manage the system of the outcome of the checks.
+    async fn notify_admins(&self, report: &SelfCheckReport) {
+        let resource = Resource::System(SystemResourceAction::ManageSystemInfo);
+        let failed_checks = report
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let args = [
+            ("version", report.version.as_str()),
+            ("failed_checks", failed_checks.as_str()),
+        ];
+
+        for user in self.user_repository.list() {
+            let can_manage = user.is_active()
+                && user.identities.first().is_some_and(|identity| {
+                    Authorization::is_allowed(&CallContext::new(*identity), &resource)
+                });
+
+            if !can_manage {
+                continue;
+            }
+
+            let (title, message) = if report.passed() {
+                (
+                    self.locale_service
+                        .render(&user, "self_check.passed.title", &args),
+                    None,
+                )
+            } else {
+                (
+                    self.locale_service
+                        .render(&user, "self_check.failed.title", &args),
+                    Some(
+                        self.locale_service
+                            .render(&user, "self_check.failed.message", &args),
+                    ),
+                )
+            };
+
+            self.notification_service
+                .send_notification(user.id, NotificationType::SystemMessage, title, message)
+                .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            permission::{Allow, Permission},
+            user_test_utils::mock_user,
+        },
+        repositories::NOTIFICATION_REPOSITORY,
+    };
+    use orbit_essentials::model::ModelKey;
+
+    #[tokio::test]
+    async fn reports_the_missing_groups_to_the_admins() {
+        test_utils::init_canister_system();
+
+        let admin = mock_user();
+        let mut other_user = mock_user();
+        other_user.groups = vec![[9; 16]];
+        for user in [&admin, &other_user] {
+            USER_REPOSITORY.insert(user.to_key(), user.clone());
+        }
+
+        let permission = Permission::new(
+            Allow::users(vec![admin.id]),
+            Resource::System(SystemResourceAction::ManageSystemInfo),
+        );
+        PERMISSION_REPOSITORY.insert(permission.key(), permission);
+
+        let report = SELF_CHECK_SERVICE.run_self_check().await;
+
+        assert!(!report.passed());
+        let failed_checks = report
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(failed_checks, vec!["user_groups"]);
+        assert_eq!(read_system_info().get_last_self_check(), Some(&report));
+
+        let notifications = NOTIFICATION_REPOSITORY.find_by_user_id(admin.id);
+        assert_eq!(notifications.len(), 1);
+        assert!(notifications[0].title.contains("failed"));
+        assert!(NOTIFICATION_REPOSITORY
+            .find_by_user_id(other_user.id)
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn passes_with_consistent_data() {
+        test_utils::init_canister_system();
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user);
+
+        let report = SELF_CHECK_SERVICE.run_self_check().await;
+
+        assert!(report.passed());
+        assert_eq!(report.version, SYSTEM_VERSION);
+    }
+}
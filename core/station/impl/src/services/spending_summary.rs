@@ -0,0 +1,275 @@
+use crate::{
+    core::{
+        ic_cdk::next_time, read_system_info, utils::retain_accessible_resources, write_system_info,
+        CallContext,
+    },
+    models::{
+        resource::{AccountResourceAction, Resource, ResourceId},
+        Account, AccountId, SpendingAggregate, SpendingAggregateKey, SpendingGroupBy,
+        SpendingPeriod, Transfer, TransferStatus,
+    },
+    repositories::{
+        AccountRepository, SpendingAggregateRepository, TransferRepository, ACCOUNT_REPOSITORY,
+        SPENDING_AGGREGATE_REPOSITORY, TRANSFER_REPOSITORY,
+    },
+};
+use candid::Nat;
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository, types::Timestamp};
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
+
+lazy_static! {
+    pub static ref SPENDING_SUMMARY_SERVICE: Arc<SpendingSummaryService> =
+        Arc::new(SpendingSummaryService::new(
+            Arc::clone(&SPENDING_AGGREGATE_REPOSITORY),
+            Arc::clone(&TRANSFER_REPOSITORY),
+            Arc::clone(&ACCOUNT_REPOSITORY),
+        ));
+}
+
+/// The spending of a group within a period, summed over the accounts the caller can read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpendingSummaryEntry {
+    pub period_start: Timestamp,
+    pub group: String,
+    pub symbol: String,
+    pub total: Nat,
+    pub count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpendingSummary {
+    /// The entries the most recent period first.
+    pub entries: Vec<SpendingSummaryEntry>,
+    /// The time until which the completed transfers are included in the summary.
+    pub aggregated_until: Option<Timestamp>,
+}
+
+/// Aggregates the completed transfers by period and group to power the spending dashboards.
+///
+/// The aggregates are computed incrementally by a job, so that the summaries do not need to go over
+/// the transfers of the station.
+#[derive(Default, Debug)]
+pub struct SpendingSummaryService {
+    spending_aggregate_repository: Arc<SpendingAggregateRepository>,
+    transfer_repository: Arc<TransferRepository>,
+    account_repository: Arc<AccountRepository>,
+}
+
+impl SpendingSummaryService {
+    pub fn new(
+        spending_aggregate_repository: Arc<SpendingAggregateRepository>,
+        transfer_repository: Arc<TransferRepository>,
+        account_repository: Arc<AccountRepository>,
+    ) -> Self {
+        Self {
+            spending_aggregate_repository,
+            transfer_repository,
+            account_repository,
+        }
+    }
+
+    /// Adds the transfers completed since the last aggregation to the aggregates and returns their number.
+    pub fn aggregate_completed_transfers(&self) -> usize {
+        let mut system_info = read_system_info();
+        let aggregated_until = next_time();
+        let transfers = self.transfer_repository.find_by_status(
+            TransferStatus::Completed {
+                completed_at: 0,
+                hash: None,
+                signature: None,
+            }
+            .to_string(),
+            system_info
+                .get_spending_aggregated_until()
+                .map(|from_dt| from_dt.saturating_add(1)),
+            Some(aggregated_until),
+        );
+
+        let mut aggregated = 0;
+        for transfer in transfers {
+            let Some(account) = self
+                .account_repository
+                .get(&Account::key(transfer.from_account))
+            else {
+                continue;
+            };
+
+            self.aggregate_transfer(&transfer, &account);
+            aggregated += 1;
+        }
+
+        system_info.set_spending_aggregated_until(aggregated_until);
+        write_system_info(system_info);
+
+        aggregated
+    }
+
+    fn aggregate_transfer(&self, transfer: &Transfer, account: &Account) {
+        let completed_at = match transfer.status {
+            TransferStatus::Completed { completed_at, .. } => completed_at,
+            _ => transfer.last_modification_timestamp,
+        };
+
+        for period in SpendingPeriod::ALL {
+            for group_by in SpendingGroupBy::ALL {
+                let Some(group) = group_by.group_of(transfer, &account.symbol) else {
+                    continue;
+                };
+
+                let key = SpendingAggregateKey {
+                    period,
+                    period_start: period.start_of(completed_at),
+                    account_id: account.id,
+                    group_by,
+                    group,
+                };
+
+                let mut aggregate =
+                    self.spending_aggregate_repository
+                        .get(&key)
+                        .unwrap_or_else(|| SpendingAggregate {
+                            period: key.period,
+                            period_start: key.period_start,
+                            account_id: key.account_id,
+                            group_by: key.group_by,
+                            group: key.group.clone(),
+                            symbol: account.symbol.clone(),
+                            total: Nat::from(0u64),
+                            count: 0,
+                        });
+
+                aggregate.total += transfer.amount.clone();
+                aggregate.count += 1;
+
+                self.spending_aggregate_repository.insert(key, aggregate);
+            }
+        }
+    }
+
+    /// Returns the spending of the accounts the caller can read, by period and group.
+    ///
+    /// The amounts of different assets are never summed together, the groups spanning several assets
+    /// have one entry per asset.
+    pub fn get_spending_summary(
+        &self,
+        period: SpendingPeriod,
+        group_by: SpendingGroupBy,
+        from_dt: Option<Timestamp>,
+        to_dt: Option<Timestamp>,
+        ctx: &CallContext,
+    ) -> ServiceResult<SpendingSummary> {
+        let mut accounts = self.account_repository.list();
+        retain_accessible_resources(ctx, &mut accounts, |account: &Account| {
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(account.id)))
+        });
+        let readable_accounts: HashSet<AccountId> =
+            accounts.into_iter().map(|account| account.id).collect();
+
+        let mut entries: BTreeMap<(Timestamp, String, String), SpendingSummaryEntry> =
+            BTreeMap::new();
+        for aggregate in self.spending_aggregate_repository.find_by_period(
+            period,
+            group_by,
+            from_dt.map(|from_dt| period.start_of(from_dt)),
+            to_dt,
+        ) {
+            if !readable_accounts.contains(&aggregate.account_id) {
+                continue;
+            }
+
+            let entry = entries
+                .entry((
+                    aggregate.period_start,
+                    aggregate.group.clone(),
+                    aggregate.symbol.clone(),
+                ))
+                .or_insert_with(|| SpendingSummaryEntry {
+                    period_start: aggregate.period_start,
+                    group: aggregate.group,
+                    symbol: aggregate.symbol,
+                    total: Nat::from(0u64),
+                    count: 0,
+                });
+
+            entry.total += aggregate.total;
+            entry.count += aggregate.count;
+        }
+
+        let mut entries: Vec<SpendingSummaryEntry> = entries.into_values().collect();
+        entries.sort_by(|a, b| b.period_start.cmp(&a.period_start));
+
+        Ok(SpendingSummary {
+            entries,
+            aggregated_until: read_system_info().get_spending_aggregated_until(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::{ic_cdk::api::id as self_canister_id, test_utils},
+        models::{
+            account_test_utils::mock_account, transfer_test_utils::mock_transfer, Metadata,
+            TRANSFER_METADATA_TAG_KEY,
+        },
+    };
+    use orbit_essentials::model::ModelKey;
+    use std::collections::BTreeMap as Map;
+
+    #[test]
+    fn completed_transfers_are_aggregated_once() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        for tag in ["payroll", "payroll", "vendors"] {
+            let mut transfer = mock_transfer();
+            transfer.from_account = account.id;
+            transfer.amount = Nat::from(100u64);
+            transfer.metadata = Metadata::new(Map::from([(
+                TRANSFER_METADATA_TAG_KEY.to_string(),
+                tag.to_string(),
+            )]));
+            transfer.status = TransferStatus::Completed {
+                completed_at: 1,
+                hash: None,
+                signature: None,
+            };
+            transfer.last_modification_timestamp = 1;
+            TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer);
+        }
+
+        assert_eq!(SPENDING_SUMMARY_SERVICE.aggregate_completed_transfers(), 3);
+        assert_eq!(SPENDING_SUMMARY_SERVICE.aggregate_completed_transfers(), 0);
+
+        let summary = SPENDING_SUMMARY_SERVICE
+            .get_spending_summary(
+                SpendingPeriod::Month,
+                SpendingGroupBy::Tag,
+                None,
+                None,
+                &CallContext::new(self_canister_id()),
+            )
+            .unwrap();
+
+        assert!(summary.aggregated_until.is_some());
+        assert_eq!(
+            summary
+                .entries
+                .iter()
+                .map(|entry| (entry.group.as_str(), entry.total.clone(), entry.count))
+                .collect::<Vec<_>>(),
+            vec![
+                ("payroll", Nat::from(200u64), 2),
+                ("vendors", Nat::from(100u64), 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    core::{
+        generate_uuid_v4,
+        ic_cdk::next_time,
+        utils::{paginated_items, retain_accessible_resources, PaginatedData, PaginatedItemsArgs},
+        CallContext,
+    },
+    models::{
+        resource::{AccountResourceAction, Resource, ResourceId},
+        Account, AccountCallerPrivileges, SupportAccessLogEntry, User, UserId,
+    },
+    repositories::{
+        AccountRepository, SupportAccessLogRepository, ACCOUNT_REPOSITORY,
+        SUPPORT_ACCESS_LOG_REPOSITORY,
+    },
+    services::{AccountService, UserService, ACCOUNT_SERVICE, USER_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelKey, repository::Repository};
+use station_api::{PaginationInput, UserPrivilege};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref SUPPORT_ACCESS_SERVICE: Arc<SupportAccessService> =
+        Arc::new(SupportAccessService::new(
+            Arc::clone(&SUPPORT_ACCESS_LOG_REPOSITORY),
+            Arc::clone(&ACCOUNT_REPOSITORY),
+            Arc::clone(&USER_SERVICE),
+            Arc::clone(&ACCOUNT_SERVICE),
+        ));
+}
+
+/// The resources and privileges that are visible to a user, as seen by the support user that viewed them.
+#[derive(Debug, Clone)]
+pub struct SupportAccessView {
+    pub user: User,
+    pub privileges: Vec<UserPrivilege>,
+    pub accounts: Vec<Account>,
+    pub account_privileges: Vec<AccountCallerPrivileges>,
+}
+
+/// Lets the users with the `ViewAs` permission debug the access of other users, by evaluating what the
+/// user can see with the context of the user instead of impersonating it.
+///
+/// Every view is recorded in the support access log.
+#[derive(Default, Debug)]
+pub struct SupportAccessService {
+    support_access_log_repository: Arc<SupportAccessLogRepository>,
+    account_repository: Arc<AccountRepository>,
+    user_service: Arc<UserService>,
+    account_service: Arc<AccountService>,
+}
+
+impl SupportAccessService {
+    pub const DEFAULT_LOG_LIST_LIMIT: u16 = 50;
+    pub const MAX_LOG_LIST_LIMIT: u16 = 200;
+
+    pub fn new(
+        support_access_log_repository: Arc<SupportAccessLogRepository>,
+        account_repository: Arc<AccountRepository>,
+        user_service: Arc<UserService>,
+        account_service: Arc<AccountService>,
+    ) -> Self {
+        Self {
+            support_access_log_repository,
+            account_repository,
+            user_service,
+            account_service,
+        }
+    }
+
+    /// Returns the resources and privileges visible to the user and records the view in the support access log.
+    pub async fn view_as(
+        &self,
+        user_id: &UserId,
+        ctx: &CallContext,
+    ) -> ServiceResult<SupportAccessView> {
+        let viewer = self.user_service.get_user_by_identity(&ctx.caller())?;
+        let user = self.user_service.get_user(user_id)?;
+        let user_ctx = CallContext::of_user(user.clone());
+
+        let privileges = self.user_service.get_caller_privileges(&user_ctx).await?;
+
+        let mut accounts = self.account_repository.list();
+        retain_accessible_resources(&user_ctx, &mut accounts, |account: &Account| {
+            Resource::Account(AccountResourceAction::Read(ResourceId::Id(account.id)))
+        });
+
+        let mut account_privileges = Vec::new();
+        for account in &accounts {
+            account_privileges.push(
+                self.account_service
+                    .get_caller_privileges_for_account(&account.id, &user_ctx)
+                    .await?,
+            );
+        }
+
+        let entry = SupportAccessLogEntry {
+            id: *generate_uuid_v4().await.as_bytes(),
+            viewer_id: viewer.id,
+            viewed_user_id: user.id,
+            viewed_at: next_time(),
+        };
+
+        self.support_access_log_repository
+            .insert(entry.key(), entry);
+        self.support_access_log_repository.prune();
+
+        Ok(SupportAccessView {
+            user,
+            privileges,
+            accounts,
+            account_privileges,
+        })
+    }
+
+    /// Returns the support access log the most recent first, only the views of the given user if set.
+    pub fn list_support_access_log(
+        &self,
+        viewed_user_id: Option<&UserId>,
+        paginate: Option<PaginationInput>,
+    ) -> ServiceResult<PaginatedData<SupportAccessLogEntry>> {
+        let entries = self
+            .support_access_log_repository
+            .find_most_recent(viewed_user_id);
+
+        Ok(paginated_items(PaginatedItemsArgs {
+            offset: paginate.to_owned().and_then(|p| p.offset),
+            limit: paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_LOG_LIST_LIMIT),
+            max_limit: Some(Self::MAX_LOG_LIST_LIMIT),
+            items: &entries,
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            account_test_utils::mock_account, user_test_utils::mock_user,
+            EditPermissionOperationInput,
+        },
+        repositories::USER_REPOSITORY,
+        services::permission::PERMISSION_SERVICE,
+    };
+
+    #[tokio::test]
+    async fn view_as_evaluates_the_access_of_the_user() {
+        test_utils::init_canister_system();
+
+        let viewer = mock_user();
+        USER_REPOSITORY.insert(viewer.to_key(), viewer.clone());
+        let user = mock_user();
+        USER_REPOSITORY.insert(user.to_key(), user.clone());
+
+        let visible_account = mock_account();
+        ACCOUNT_REPOSITORY.insert(visible_account.to_key(), visible_account.clone());
+        let hidden_account = mock_account();
+        ACCOUNT_REPOSITORY.insert(hidden_account.to_key(), hidden_account.clone());
+
+        PERMISSION_SERVICE
+            .edit_permission(EditPermissionOperationInput {
+                resource: Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+                    visible_account.id,
+                ))),
+                auth_scope: None,
+                users: Some(vec![user.id]),
+                user_groups: None,
+            })
+            .unwrap();
+        PERMISSION_SERVICE
+            .edit_permission(EditPermissionOperationInput {
+                resource: Resource::Account(AccountResourceAction::Read(ResourceId::Id(
+                    hidden_account.id,
+                ))),
+                auth_scope: None,
+                users: Some(vec![viewer.id]),
+                user_groups: None,
+            })
+            .unwrap();
+
+        let view = SUPPORT_ACCESS_SERVICE
+            .view_as(&user.id, &CallContext::new(viewer.identities[0]))
+            .await
+            .unwrap();
+
+        assert_eq!(view.user.id, user.id);
+        assert_eq!(
+            view.accounts
+                .iter()
+                .map(|account| account.id)
+                .collect::<Vec<_>>(),
+            vec![visible_account.id]
+        );
+
+        let log = SUPPORT_ACCESS_SERVICE
+            .list_support_access_log(Some(&user.id), None)
+            .unwrap();
+        assert_eq!(log.total, 1);
+        assert_eq!(log.items[0].viewer_id, viewer.id);
+    }
+}
@@ -9,11 +9,12 @@ use crate::{
     },
     errors::SystemError,
     factories::blockchains::InternetComputer,
+    jobs,
     models::{
         system::{DisasterRecoveryCommittee, SystemInfo, SystemState},
-        CanisterInstallMode, CanisterUpgradeModeArgs, CycleObtainStrategy,
+        ArchiveSinkChange, CanisterInstallMode, CanisterUpgradeModeArgs, CycleObtainStrategy,
         ManageSystemInfoOperationInput, RequestId, RequestKey, RequestOperation, RequestStatus,
-        SystemUpgradeTarget, WasmModuleExtraChunks,
+        SystemUpgradeTarget, VersionPin, WasmModuleExtraChunks,
     },
     repositories::{
         permission::PERMISSION_REPOSITORY, RequestRepository, REQUEST_REPOSITORY,
@@ -22,6 +23,7 @@ use crate::{
     services::{
         change_canister::{ChangeCanisterService, CHANGE_CANISTER_SERVICE},
         disaster_recovery::DISASTER_RECOVERY_SERVICE,
+        notification_template::NOTIFICATION_TEMPLATE_SERVICE,
         request::{RequestService, REQUEST_SERVICE},
     },
     SYSTEM_VERSION,
@@ -122,7 +124,78 @@ impl SystemService {
             system_info.set_cycle_obtain_strategy(strategy);
         }
 
+        if let Some(limits) = input.request_operation_limits {
+            system_info.set_request_operation_limits(limits);
+        }
+
+        if let Some(rpc_providers) = input.rpc_providers {
+            for config in rpc_providers {
+                system_info.set_rpc_providers(config);
+            }
+        }
+
+        if let Some(finality_thresholds) = input.finality_thresholds {
+            system_info.set_finality_thresholds(finality_thresholds);
+        }
+
+        if let Some(transfer_retry_policy) = input.transfer_retry_policy {
+            system_info.set_transfer_retry_policy(transfer_retry_policy);
+        }
+
+        match input.max_suggested_version {
+            Some(VersionPin::Pin(version)) => system_info.set_max_suggested_version(Some(version)),
+            Some(VersionPin::Unpin) => system_info.set_max_suggested_version(None),
+            None => {}
+        }
+
+        if let Some(guardrail) = input.stable_memory_guardrail {
+            system_info.set_stable_memory_guardrail(guardrail);
+        }
+
+        if let Some(approval_reminders) = input.approval_reminders {
+            if approval_reminders.pending_vote_lead_time_secs.is_some() {
+                jobs::schedule_pending_vote_reminders();
+            }
+
+            system_info.set_approval_reminders(approval_reminders);
+        }
+
+        if let Some(rate_limits) = input.request_creation_rate_limits {
+            system_info.set_request_creation_rate_limits(rate_limits);
+        }
+
+        if let Some(expiration_secs) = input.default_request_expiration_secs {
+            system_info.set_default_request_expiration_secs(expiration_secs);
+        }
+
+        if let Some(thresholds) = input.cycle_thresholds {
+            #[cfg(target_arch = "wasm32")]
+            self.set_fund_manager_cycle_thresholds(&thresholds);
+
+            system_info.set_cycle_thresholds(thresholds);
+        }
+
+        if let Some(changes) = input.notification_templates {
+            NOTIFICATION_TEMPLATE_SERVICE.apply_changes(changes);
+        }
+
+        match input.archive_sink {
+            Some(ArchiveSinkChange::Set(sink)) => {
+                system_info.set_archive_sink(Some(sink));
+
+                jobs::schedule_history_archiving();
+            }
+            Some(ArchiveSinkChange::Remove) => system_info.set_archive_sink(None),
+            None => {}
+        }
+
+        let has_rpc_providers = !system_info.get_all_rpc_providers().is_empty();
+
         write_system_info(system_info);
+
+        if has_rpc_providers {
+            jobs::schedule_rpc_providers_health_check();
+        }
     }
 
     pub fn set_disaster_recovery_committee(committee: Option<DisasterRecoveryCommittee>) {
@@ -217,6 +290,17 @@ impl SystemService {
         });
     }
 
+    #[cfg(target_arch = "wasm32")]
+    pub fn set_fund_manager_cycle_thresholds(&self, thresholds: &crate::models::CycleThresholds) {
+        install_canister_handlers::FUND_MANAGER.with(|fund_manager| {
+            let mut fund_manager = fund_manager.borrow_mut();
+            let options = fund_manager.get_options();
+            let options =
+                options.with_strategy(install_canister_handlers::fund_strategy(thresholds));
+            fund_manager.with_options(options);
+        });
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn install_canister_post_process(&self, _system_info: SystemInfo, _install: SystemInstall) {}
 
@@ -250,6 +334,7 @@ impl SystemService {
             install_canister_handlers::monitor_upgrader_cycles(
                 *system_info.get_upgrader_canister_id(),
                 *system_info.get_cycle_obtain_strategy(),
+                system_info.get_cycle_thresholds(),
             );
 
             // initializes the job timers after the canister is fully initialized
@@ -340,6 +425,9 @@ impl SystemService {
             }
             SystemInstall::Upgrade(_) => {
                 install_canister_post_process_finish(system_info);
+
+                // checks that the new version works with the data of the station
+                crate::jobs::schedule_self_check();
             }
         };
     }
@@ -531,6 +619,7 @@ mod init_canister_sync_handlers {
                 groups: vec![ADMIN_GROUP_ID.to_owned()],
                 name: admin.name.to_owned(),
                 status: UserStatus::Active,
+                approve_only_identities: vec![],
             })?;
 
             print(&format!(
@@ -561,7 +650,7 @@ mod install_canister_handlers {
     use crate::models::request_specifier::UserSpecifier;
     use crate::models::{
         AddAccountOperationInput, AddRequestPolicyOperationInput, CycleObtainStrategy,
-        EditPermissionOperationInput, RequestPolicyRule, ADMIN_GROUP_ID,
+        CycleThresholds, EditPermissionOperationInput, RequestPolicyRule, ADMIN_GROUP_ID,
     };
     use crate::services::permission::PERMISSION_SERVICE;
     use crate::services::ACCOUNT_SERVICE;
@@ -595,6 +684,7 @@ mod install_canister_handlers {
                 .add_request_policy(AddRequestPolicyOperationInput {
                     specifier: policy.0.to_owned(),
                     rule: policy.1.to_owned(),
+                    expiration_period_secs: None,
                 })
                 .map_err(|e| format!("Failed to add default request policy: {:?}", e))?;
         }
@@ -629,6 +719,7 @@ mod install_canister_handlers {
                         .expect("Invalid blockchain"),
                     standard: BlockchainMapper::to_blockchain_standard(account.standard)
                         .expect("Invalid blockchain standard"),
+                    network: account.network.map(Into::into).unwrap_or_default(),
                     metadata: account.metadata.into(),
                     transfer_request_policy: Some(RequestPolicyRule::Quorum(
                         UserSpecifier::Group(vec![*ADMIN_GROUP_ID]),
@@ -731,10 +822,23 @@ mod install_canister_handlers {
         .map_err(|e| format!("Failed to set station controller: {:?}", e))
     }
 
+    /// The funding strategy of the fund manager for the configured cycle thresholds.
+    pub fn fund_strategy(thresholds: &CycleThresholds) -> FundStrategy {
+        FundStrategy::BelowEstimatedRuntime(
+            EstimatedRuntime::new()
+                .with_min_runtime_secs(thresholds.min_runtime_secs)
+                .with_fund_runtime_secs(thresholds.fund_runtime_secs)
+                .with_max_runtime_cycles_fund(thresholds.max_runtime_cycles_fund.into())
+                .with_fallback_min_cycles(thresholds.fallback_min_cycles.into())
+                .with_fallback_fund_cycles(thresholds.fallback_fund_cycles.into()),
+        )
+    }
+
     /// Starts the fund manager service setting it up to monitor the upgrader canister cycles and top it up if needed.
     pub fn monitor_upgrader_cycles(
         upgrader_id: Principal,
         cycle_obtain_strategy: CycleObtainStrategy,
+        cycle_thresholds: &CycleThresholds,
     ) {
         print(format!(
             "Starting fund manager to monitor self {} and upgrader canister {} cycles",
@@ -747,14 +851,7 @@ mod install_canister_handlers {
 
             let mut fund_manager_options = FundManagerOptions::new()
                 .with_interval_secs(24 * 60 * 60) // daily
-                .with_strategy(FundStrategy::BelowEstimatedRuntime(
-                    EstimatedRuntime::new()
-                        .with_min_runtime_secs(14 * 24 * 60 * 60) // 14 days
-                        .with_fund_runtime_secs(30 * 24 * 60 * 60) // 30 days
-                        .with_max_runtime_cycles_fund(1_000_000_000_000)
-                        .with_fallback_min_cycles(125_000_000_000)
-                        .with_fallback_fund_cycles(250_000_000_000),
-                ));
+                .with_strategy(fund_strategy(cycle_thresholds));
 
             fund_manager_options = fund_manager_options.with_obtain_cycles_options(
                 SYSTEM_SERVICE.get_obtain_cycle_config(&cycle_obtain_strategy),
@@ -0,0 +1,212 @@
+use crate::{
+    core::utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+    factories::blockchains::{LedgerIndex, TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY},
+    models::{AccountId, AccountTransaction, AccountTransactionDirection, Transfer, TransferId},
+    repositories::{
+        AccountTransactionRepository, TransferRepository, ACCOUNT_TRANSACTION_REPOSITORY,
+        TRANSFER_REPOSITORY,
+    },
+    services::{AccountService, ACCOUNT_SERVICE},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelKey, repository::Repository};
+use station_api::{PaginationInput, TransferStatusTypeDTO};
+use std::{collections::HashMap, sync::Arc};
+
+lazy_static! {
+    pub static ref TRANSACTION_HISTORY_SERVICE: Arc<TransactionHistoryService> =
+        Arc::new(TransactionHistoryService::new(
+            Arc::clone(&ACCOUNT_TRANSACTION_REPOSITORY),
+            Arc::clone(&TRANSFER_REPOSITORY),
+            Arc::clone(&ACCOUNT_SERVICE),
+        ));
+}
+
+/// Imports the transaction history of the accounts from the index canisters of their ledgers.
+///
+/// The imported transactions include the deposits and any other movement of funds that did not go
+/// through a request, the outbound transactions submitted by the station are linked to their transfer.
+#[derive(Default, Debug)]
+pub struct TransactionHistoryService {
+    account_transaction_repository: Arc<AccountTransactionRepository>,
+    transfer_repository: Arc<TransferRepository>,
+    account_service: Arc<AccountService>,
+}
+
+impl TransactionHistoryService {
+    pub const DEFAULT_LIST_LIMIT: u16 = 50;
+    pub const MAX_LIST_LIMIT: u16 = 200;
+    /// The number of transactions requested from the index canister at once.
+    pub const IMPORT_PAGE_SIZE: u64 = 100;
+    /// The maximum number of pages requested by a single import, later imports continue the history.
+    pub const MAX_IMPORT_PAGES: usize = 10;
+
+    pub fn new(
+        account_transaction_repository: Arc<AccountTransactionRepository>,
+        transfer_repository: Arc<TransferRepository>,
+        account_service: Arc<AccountService>,
+    ) -> Self {
+        Self {
+            account_transaction_repository,
+            transfer_repository,
+            account_service,
+        }
+    }
+
+    /// Returns the imported transactions of the account, the most recent first.
+    pub fn list_account_transactions(
+        &self,
+        account_id: &AccountId,
+        paginate: Option<PaginationInput>,
+    ) -> ServiceResult<PaginatedData<AccountTransaction>> {
+        self.account_service.get_account(account_id)?;
+
+        let transactions = self
+            .account_transaction_repository
+            .find_by_account_id(account_id);
+
+        Ok(paginated_items(PaginatedItemsArgs {
+            offset: paginate.to_owned().and_then(|p| p.offset),
+            limit: paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_LIST_LIMIT),
+            max_limit: Some(Self::MAX_LIST_LIMIT),
+            items: &transactions,
+        })?)
+    }
+
+    /// Imports the transactions of the account from the index canister of its ledger and returns the
+    /// number of imported transactions.
+    ///
+    /// The transactions more recent than the imported ones are imported first, the import then continues
+    /// with the transactions older than the imported ones until the whole history is imported.
+    pub async fn import_account_transactions(
+        &self,
+        account_id: &AccountId,
+    ) -> ServiceResult<usize> {
+        let account = self.account_service.get_account(account_id)?;
+        let index = LedgerIndex::of_account(&account)?;
+
+        let imported = self
+            .account_transaction_repository
+            .find_by_account_id(account_id);
+        let mut newest_imported = imported.first().map(|transaction| transaction.block_index);
+        let oldest_imported = imported.last().map(|transaction| transaction.block_index);
+        let transfers = self.transfers_by_block_index(account_id);
+
+        let mut imported_count = 0;
+        let mut start = None;
+        for _ in 0..Self::MAX_IMPORT_PAGES {
+            let page = index
+                .account_transactions(&account, start, Self::IMPORT_PAGE_SIZE)
+                .await?;
+
+            let Some(last_block_index) = page.transactions.last().map(|tx| tx.block_index) else {
+                break;
+            };
+
+            let mut reached_imported = false;
+            for mut transaction in page.transactions {
+                if newest_imported.is_some_and(|newest| transaction.block_index <= newest) {
+                    reached_imported = true;
+                    break;
+                }
+
+                if transaction.direction == AccountTransactionDirection::Outbound {
+                    transaction.transfer_id = transfers.get(&transaction.block_index).copied();
+                }
+
+                self.account_transaction_repository
+                    .insert(transaction.key(), transaction);
+                imported_count += 1;
+            }
+
+            start = if reached_imported {
+                // continues with the transactions older than the ones imported before
+                newest_imported = None;
+                oldest_imported.and_then(|oldest| oldest.checked_sub(1))
+            } else {
+                last_block_index.checked_sub(1)
+            };
+
+            let history_exhausted = start.map_or(true, |start| {
+                page.oldest_tx_id
+                    .map_or(true, |oldest_tx_id| start < oldest_tx_id)
+            });
+
+            if history_exhausted {
+                break;
+            }
+        }
+
+        Ok(imported_count)
+    }
+
+    /// Returns the completed transfers of the account by the block index of their ledger transaction.
+    fn transfers_by_block_index(&self, account_id: &AccountId) -> HashMap<u64, TransferId> {
+        self.transfer_repository
+            .find_by_account(
+                *account_id,
+                None,
+                None,
+                Some(TransferStatusTypeDTO::Completed),
+            )
+            .into_iter()
+            .filter_map(|transfer: Transfer| {
+                transfer
+                    .submitted_details?
+                    .into_iter()
+                    .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY)
+                    .and_then(|(_, block_height)| block_height.parse().ok())
+                    .map(|block_index| (block_index, transfer.id))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            account_test_utils::mock_account,
+            account_transaction_test_utils::mock_account_transaction,
+        },
+        repositories::ACCOUNT_REPOSITORY,
+    };
+
+    #[test]
+    fn list_account_transactions_is_paginated() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        for block_index in 0..3 {
+            let mut transaction = mock_account_transaction();
+            transaction.account_id = account.id;
+            transaction.block_index = block_index;
+            ACCOUNT_TRANSACTION_REPOSITORY.insert(transaction.key(), transaction);
+        }
+
+        let page = TRANSACTION_HISTORY_SERVICE
+            .list_account_transactions(
+                &account.id,
+                Some(PaginationInput {
+                    offset: None,
+                    limit: Some(2),
+                }),
+            )
+            .unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.next_offset, Some(2));
+        assert_eq!(
+            page.items
+                .iter()
+                .map(|transaction| transaction.block_index)
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+    }
+}
@@ -1,17 +1,30 @@
 use super::{AccountService, UserService};
 use crate::{
-    core::{authorization::Authorization, CallContext},
-    errors::{AccountError, TransferError},
+    core::{
+        authorization::Authorization,
+        utils::{paginated_items, PaginatedData, PaginatedItemsArgs},
+        CallContext,
+    },
+    errors::{AccountError, PaginationError, TransferError},
+    factories::blockchains::{
+        BlockchainApiFactory, BlockchainTransactionFeeOption, BlockchainTransactionLookup,
+    },
     mappers::HelperMapper,
     models::{
         resource::{AccountResourceAction, Resource, ResourceId},
-        Transfer, TransferId,
+        MetadataItem, PendingOutflowAudit, PendingOutflowOutcome, Transfer, TransferId,
+        TransferStatus, INDEXED_METADATA_KEYS,
     },
-    repositories::TransferRepository,
+    repositories::{TransferRepository, TransferWhereClause},
 };
+use futures::future;
 use orbit_essentials::repository::Repository;
-use orbit_essentials::{api::ServiceResult, model::ModelValidator, utils::rfc3339_to_timestamp};
-use station_api::ListAccountTransfersInput;
+use orbit_essentials::{
+    api::{ApiError, ServiceResult},
+    model::ModelValidator,
+    utils::rfc3339_to_timestamp,
+};
+use station_api::{GetTransferFeesInput, ListAccountTransfersInput, PaginationInput};
 use uuid::Uuid;
 
 #[derive(Default, Debug)]
@@ -22,6 +35,12 @@ pub struct TransferService {
 }
 
 impl TransferService {
+    pub const DEFAULT_LIST_TRANSFERS_LIMIT: u16 = 50;
+    pub const MAX_LIST_TRANSFERS_LIMIT: u16 = 200;
+    /// Every audited transfer is looked up on its blockchain, hence the small pages.
+    pub const DEFAULT_AUDIT_OUTFLOWS_LIMIT: u16 = 10;
+    pub const MAX_AUDIT_OUTFLOWS_LIMIT: u16 = 25;
+
     pub fn add_transfer(&self, transfer: Transfer) -> ServiceResult<Transfer> {
         transfer.validate()?;
 
@@ -63,22 +82,176 @@ impl TransferService {
         Ok(transfers)
     }
 
+    /// Lists a page of the transfers of the account from the newest, returns the id of the transfer
+    /// that starts the next page if there are more.
+    ///
+    /// The transfers of the confidential requests that the caller can't read are left out.
     pub fn list_account_transfers(
         &self,
         input: ListAccountTransfersInput,
-    ) -> ServiceResult<Vec<Transfer>> {
+        ctx: &CallContext,
+    ) -> ServiceResult<(Vec<Transfer>, Option<TransferId>)> {
         let account = self
             .account_service
             .get_account(HelperMapper::to_uuid(input.account_id)?.as_bytes())?;
 
-        let transfers = self.transfer_repository.find_by_account(
-            account.id,
-            input.from_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
-            input.to_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
-            input.status,
+        if let Some(metadata) = &input.metadata {
+            if !INDEXED_METADATA_KEYS.contains(&metadata.key.as_str()) {
+                Err(TransferError::ValidationError {
+                    info: format!(
+                        "Transfers can only be filtered by the metadata keys: {}",
+                        INDEXED_METADATA_KEYS.join(", ")
+                    ),
+                })?
+            }
+        }
+
+        if let Some(limit) = input.limit {
+            if limit > Self::MAX_LIST_TRANSFERS_LIMIT {
+                Err(PaginationError::MaxLimitExceeded {
+                    max: Self::MAX_LIST_TRANSFERS_LIMIT,
+                })?
+            }
+        }
+
+        // the cursor is the transfer that starts the page
+        let start_at = match input.cursor {
+            Some(cursor) => {
+                let transfer = HelperMapper::to_uuid(cursor.clone())
+                    .ok()
+                    .and_then(|transfer_id| {
+                        self.transfer_repository
+                            .get(&Transfer::key(*transfer_id.as_bytes()))
+                    })
+                    .filter(|transfer| transfer.from_account == account.id)
+                    .ok_or(TransferError::ValidationError {
+                        info: format!("Invalid cursor `{}`", cursor),
+                    })?;
+
+                Some((transfer.created_timestamp, transfer.id))
+            }
+            None => None,
+        };
+
+        let condition = TransferWhereClause {
+            account_id: account.id,
+            created_dt_from: input.from_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
+            created_dt_to: input.to_dt.map(|dt| rfc3339_to_timestamp(dt.as_str())),
+            status: input.status,
+            to_address: input.to_address,
+            metadata: input.metadata.map(MetadataItem::from),
+            category: input.category,
+            min_amount: input.min_amount,
+            max_amount: input.max_amount,
+            visible_to: Some(ctx.to_owned()),
+        };
+
+        Ok(self.transfer_repository.find_page_where(
+            condition,
+            start_at,
+            input.limit.unwrap_or(Self::DEFAULT_LIST_TRANSFERS_LIMIT) as usize,
+        ))
+    }
+
+    /// Returns the slow, standard and fast fee options of a transfer from the given account, so that the
+    /// cost of a transfer can be previewed before requesting it.
+    pub async fn get_transfer_fees(
+        &self,
+        input: GetTransferFeesInput,
+    ) -> ServiceResult<Vec<BlockchainTransactionFeeOption>> {
+        let account_id = HelperMapper::to_uuid(input.account_id)?;
+        let account = self.account_service.get_account(account_id.as_bytes())?;
+        let blockchain_api =
+            BlockchainApiFactory::build(&account.blockchain, &account.standard, &account.network)?;
+
+        blockchain_api.transaction_fee_options(&account).await
+    }
+
+    /// Cross-checks a page of the created and processing transfers against the blockchain history,
+    /// the transfers are ordered by the time they were created or started processing.
+    ///
+    /// A pending transfer leaves that status once submitted, so it is not expected to be on chain: only
+    /// the transfers of the page that are found on chain or whose outcome can't be determined are
+    /// returned. This is useful to verify the safety of the funds after outages or network partitions.
+    pub async fn audit_pending_outflows(
+        &self,
+        paginate: Option<PaginationInput>,
+    ) -> ServiceResult<PaginatedData<PendingOutflowAudit>> {
+        let mut transfers = self.transfer_repository.find_by_status(
+            TransferStatus::Created.to_string(),
+            None,
+            None,
         );
+        transfers.extend(self.transfer_repository.find_by_status(
+            TransferStatus::Processing { started_at: 0 }.to_string(),
+            None,
+            None,
+        ));
+        transfers.sort_by_key(|transfer| match transfer.status {
+            TransferStatus::Processing { started_at } => (started_at, transfer.id),
+            _ => (transfer.created_timestamp, transfer.id),
+        });
+
+        let page = paginated_items(PaginatedItemsArgs {
+            offset: paginate.as_ref().and_then(|p| p.offset),
+            limit: paginate.and_then(|p| p.limit),
+            default_limit: Some(Self::DEFAULT_AUDIT_OUTFLOWS_LIMIT),
+            max_limit: Some(Self::MAX_AUDIT_OUTFLOWS_LIMIT),
+            items: &transfers,
+        })?;
 
-        Ok(transfers)
+        // the created transfers without a failed attempt were never submitted
+        let audits = future::join_all(
+            page.items
+                .into_iter()
+                .filter(|transfer| {
+                    transfer.status != TransferStatus::Created
+                        || !transfer.failed_attempts.is_empty()
+                })
+                .map(|transfer| async move {
+                    let outcome = self.find_outflow_outcome(&transfer).await;
+
+                    PendingOutflowAudit { transfer, outcome }
+                }),
+        )
+        .await;
+
+        Ok(PaginatedData {
+            items: audits
+                .into_iter()
+                .filter(|audit| audit.outcome != PendingOutflowOutcome::NotOnChain)
+                .collect(),
+            next_offset: page.next_offset,
+            total: page.total,
+        })
+    }
+
+    async fn find_outflow_outcome(&self, transfer: &Transfer) -> PendingOutflowOutcome {
+        let lookup = async {
+            let account = self
+                .account_service
+                .get_account(&transfer.from_account)?
+                .for_asset(transfer.asset_id.as_ref())
+                .map_err(ApiError::from)?;
+            let blockchain_api = BlockchainApiFactory::build(
+                &account.blockchain,
+                &account.standard,
+                &account.network,
+            )
+            .map_err(ApiError::from)?;
+
+            blockchain_api.find_transaction(&account, transfer).await
+        };
+
+        match lookup.await {
+            Ok(BlockchainTransactionLookup::Found(submitted)) => PendingOutflowOutcome::OnChain {
+                details: submitted.details,
+            },
+            Ok(BlockchainTransactionLookup::NotFound) => PendingOutflowOutcome::NotOnChain,
+            Err(error) => PendingOutflowOutcome::Unknown {
+                reason: error.to_json_string(),
+            },
+        }
     }
 
     fn assert_transfer_access(&self, transfer: &Transfer, ctx: &CallContext) -> ServiceResult<()> {
@@ -94,6 +267,10 @@ impl TransferService {
             Err(AccountError::Forbidden)?
         }
 
+        if Authorization::is_confidential_request_hidden(ctx, &transfer.request_id) {
+            Err(AccountError::Forbidden)?
+        }
+
         Ok(())
     }
 }
@@ -103,9 +280,11 @@ mod tests {
     use super::*;
     use crate::{
         core::{test_utils, validation::disable_mock_resource_validation},
+        factories::blockchains::BlockchainTransactionFeeTier,
         models::{
             account_test_utils::mock_account, request_test_utils::mock_request,
-            transfer_test_utils::mock_transfer, user_test_utils::mock_user, Account, User,
+            transfer_test_utils::mock_transfer, user_test_utils::mock_user, Account, Blockchain,
+            TransferAttempt, User, ADMIN_GROUP_ID,
         },
         repositories::{
             ACCOUNT_REPOSITORY, REQUEST_REPOSITORY, TRANSFER_REPOSITORY, USER_REPOSITORY,
@@ -262,4 +441,160 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn hides_the_transfers_of_confidential_requests() {
+        let ctx = setup();
+
+        let mut confidential_request = mock_request();
+        confidential_request.confidential = true;
+        REQUEST_REPOSITORY.insert(confidential_request.to_key(), confidential_request.clone());
+
+        let mut visible = mock_transfer();
+        visible.from_account = ctx.account.id;
+        visible.request_id = [2; 16];
+        visible.created_timestamp = 1;
+        let mut confidential = mock_transfer();
+        confidential.from_account = ctx.account.id;
+        confidential.request_id = confidential_request.id;
+        confidential.initiator_user = ctx.caller_user.id;
+        confidential.created_timestamp = 2;
+        for transfer in [&visible, &confidential] {
+            ctx.repository.insert(transfer.to_key(), transfer.clone());
+        }
+
+        let list = |call_context: &CallContext| {
+            ctx.service
+                .list_account_transfers(
+                    ListAccountTransfersInput {
+                        account_id: Uuid::from_bytes(ctx.account.id).hyphenated().to_string(),
+                        from_dt: None,
+                        to_dt: None,
+                        status: None,
+                        to_address: None,
+                        metadata: None,
+                        category: None,
+                        min_amount: None,
+                        max_amount: None,
+                        cursor: None,
+                        limit: None,
+                    },
+                    call_context,
+                )
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|transfer| transfer.id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(list(&ctx.call_context), vec![visible.id]);
+        assert!(ctx
+            .service
+            .get_transfer(&confidential.id, &ctx.call_context)
+            .is_err());
+
+        let mut admin = ctx.caller_user.clone();
+        admin.groups = vec![*ADMIN_GROUP_ID];
+        USER_REPOSITORY.insert(admin.to_key(), admin);
+
+        assert_eq!(
+            list(&CallContext::new(ctx.call_context.caller())),
+            vec![confidential.id, visible.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn audit_pending_outflows_only_reports_the_inconsistent_transfers() {
+        let ctx = setup();
+        let mut account = ctx.account.clone();
+        account.blockchain = Blockchain::Ethereum;
+
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        // never submitted, so consistent with its status
+        let mut created_transfer = mock_transfer();
+        created_transfer.from_account = account.id;
+        created_transfer.status = TransferStatus::Created;
+
+        // its outcome can't be looked up since it has no submitted transaction
+        let mut processing_transfer = mock_transfer();
+        processing_transfer.from_account = account.id;
+        processing_transfer.status = TransferStatus::Processing { started_at: 1 };
+
+        let mut completed_transfer = mock_transfer();
+        completed_transfer.from_account = account.id;
+        completed_transfer.status = TransferStatus::Completed {
+            signature: None,
+            hash: None,
+            completed_at: 2,
+        };
+
+        for transfer in [&created_transfer, &processing_transfer, &completed_transfer] {
+            ctx.repository.insert(transfer.to_key(), transfer.clone());
+        }
+
+        let audits = ctx.service.audit_pending_outflows(None).await.unwrap();
+
+        assert_eq!(audits.total, 2);
+        assert_eq!(audits.items.len(), 1);
+        assert_eq!(audits.items[0].transfer.id, processing_transfer.id);
+        assert!(matches!(
+            audits.items[0].outcome,
+            PendingOutflowOutcome::Unknown { .. }
+        ));
+
+        // the created transfer is on the second page, and reported once it was found on chain
+        let second_page = PaginationInput {
+            offset: Some(1),
+            limit: Some(1),
+        };
+        let audits = ctx
+            .service
+            .audit_pending_outflows(Some(second_page.clone()))
+            .await
+            .unwrap();
+        assert_eq!(audits.total, 2);
+        assert!(audits.items.is_empty());
+
+        created_transfer.failed_attempts = vec![TransferAttempt {
+            attempted_at: 2,
+            error: "Timeout".to_string(),
+        }];
+        created_transfer.submitted_details =
+            Some(vec![("transaction_hash".to_string(), "0x1234".to_string())]);
+        ctx.repository
+            .insert(created_transfer.to_key(), created_transfer.clone());
+
+        let audits = ctx
+            .service
+            .audit_pending_outflows(Some(second_page))
+            .await
+            .unwrap();
+        assert_eq!(audits.items.len(), 1);
+        assert_eq!(audits.items[0].transfer.id, created_transfer.id);
+        assert!(matches!(
+            audits.items[0].outcome,
+            PendingOutflowOutcome::OnChain { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn fixed_fee_ledgers_return_the_same_fee_for_every_tier() {
+        let ctx = setup();
+
+        let fees = ctx
+            .service
+            .get_transfer_fees(GetTransferFeesInput {
+                account_id: Uuid::from_bytes(ctx.account.id).hyphenated().to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fees.iter().map(|option| option.tier).collect::<Vec<_>>(),
+            BlockchainTransactionFeeTier::ALL.to_vec()
+        );
+        assert!(fees.iter().all(|option| option.fee.fee == fees[0].fee.fee));
+    }
 }
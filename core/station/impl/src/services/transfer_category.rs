@@ -0,0 +1,120 @@
+use crate::{
+    core::ic_cdk::next_time,
+    errors::TransferCategoryError,
+    models::TransferCategory,
+    repositories::{TransferCategoryRepository, TRANSFER_CATEGORY_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, model::ModelValidator, repository::Repository};
+use std::sync::Arc;
+
+lazy_static! {
+    pub static ref TRANSFER_CATEGORY_SERVICE: Arc<TransferCategoryService> = Arc::new(
+        TransferCategoryService::new(Arc::clone(&TRANSFER_CATEGORY_REPOSITORY))
+    );
+}
+
+/// Manages the registry of the categories that transfers can be tagged with.
+#[derive(Default, Debug)]
+pub struct TransferCategoryService {
+    transfer_category_repository: Arc<TransferCategoryRepository>,
+}
+
+impl TransferCategoryService {
+    pub fn new(transfer_category_repository: Arc<TransferCategoryRepository>) -> Self {
+        Self {
+            transfer_category_repository,
+        }
+    }
+
+    /// Returns the registered categories ordered by name.
+    pub fn list_categories(&self) -> Vec<TransferCategory> {
+        self.transfer_category_repository.list()
+    }
+
+    /// Registers the category, or updates its description if it is already registered.
+    pub fn set_category(
+        &self,
+        name: String,
+        description: Option<String>,
+    ) -> ServiceResult<TransferCategory> {
+        let category = TransferCategory {
+            name,
+            description: description.filter(|description| !description.trim().is_empty()),
+            last_modification_timestamp: next_time(),
+        };
+
+        category.validate()?;
+
+        self.transfer_category_repository
+            .insert(category.name.clone(), category.clone());
+
+        Ok(category)
+    }
+
+    /// Removes the category from the registry, the transfers already tagged with it keep it.
+    pub fn remove_category(&self, name: &str) -> ServiceResult<TransferCategory> {
+        let category = self
+            .transfer_category_repository
+            .remove(&name.to_string())
+            .ok_or(TransferCategoryError::NotFound {
+                name: name.to_string(),
+            })?;
+
+        Ok(category)
+    }
+
+    /// Fails unless the category is registered, which is required to tag new transfers with it.
+    pub fn ensure_registered(&self, name: &str) -> ServiceResult<(), TransferCategoryError> {
+        if self
+            .transfer_category_repository
+            .get(&name.to_string())
+            .is_none()
+        {
+            return Err(TransferCategoryError::NotFound {
+                name: name.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manages_the_registry() {
+        let service = TransferCategoryService::default();
+
+        assert!(service.ensure_registered("payroll").is_err());
+
+        service
+            .set_category("payroll".to_string(), Some("Salaries".to_string()))
+            .unwrap();
+        service.set_category("grants".to_string(), None).unwrap();
+
+        assert!(service.ensure_registered("payroll").is_ok());
+        assert_eq!(
+            service
+                .list_categories()
+                .into_iter()
+                .map(|category| category.name)
+                .collect::<Vec<_>>(),
+            vec!["grants".to_string(), "payroll".to_string()]
+        );
+
+        service.remove_category("payroll").unwrap();
+
+        assert!(service.ensure_registered("payroll").is_err());
+        assert!(service.remove_category("payroll").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_categories() {
+        let service = TransferCategoryService::default();
+
+        assert!(service.set_category("Payroll".to_string(), None).is_err());
+    }
+}
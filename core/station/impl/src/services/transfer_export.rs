@@ -0,0 +1,284 @@
+use super::TransferService;
+use crate::{
+    core::CallContext,
+    factories::blockchains::TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    models::{
+        Request, RequestApprovalStatus, Transfer, TransferExportRow, TransferId, TransferStatus,
+        User, TRANSFER_EXPORT_CSV_HEADER,
+    },
+    repositories::{RequestRepository, UserRepository, REQUEST_REPOSITORY, USER_REPOSITORY},
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, repository::Repository, utils::timestamp_to_rfc3339};
+use station_api::{
+    ExportAccountTransfersInput, ListAccountTransfersInput, TransferExportFormatDTO,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref TRANSFER_EXPORT_SERVICE: Arc<TransferExportService> =
+        Arc::new(TransferExportService::new(
+            Arc::clone(&REQUEST_REPOSITORY),
+            Arc::clone(&USER_REPOSITORY),
+        ));
+}
+
+/// Exports the transfers of an account in chunks for bookkeeping tools.
+#[derive(Default, Debug)]
+pub struct TransferExportService {
+    transfer_service: TransferService,
+    request_repository: Arc<RequestRepository>,
+    user_repository: Arc<UserRepository>,
+}
+
+impl TransferExportService {
+    /// The number of transfers in a chunk, kept small enough for the response to fit in a query reply.
+    pub const EXPORT_CHUNK_SIZE: u16 = TransferService::MAX_LIST_TRANSFERS_LIMIT;
+
+    pub fn new(
+        request_repository: Arc<RequestRepository>,
+        user_repository: Arc<UserRepository>,
+    ) -> Self {
+        Self {
+            transfer_service: TransferService::default(),
+            request_repository,
+            user_repository,
+        }
+    }
+
+    /// Exports a chunk of the transfers of the account from the newest, returns the id of the transfer
+    /// that starts the next chunk if there are more.
+    pub fn export_account_transfers(
+        &self,
+        input: ExportAccountTransfersInput,
+        ctx: &CallContext,
+    ) -> ServiceResult<(String, Option<TransferId>)> {
+        let is_first_chunk = input.cursor.is_none();
+        let (transfers, next_transfer_id) = self.transfer_service.list_account_transfers(
+            ListAccountTransfersInput {
+                status: None,
+                to_dt: input.to_dt,
+                from_dt: input.from_dt,
+                account_id: input.account_id,
+                metadata: None,
+                category: None,
+                to_address: None,
+                min_amount: None,
+                max_amount: None,
+                cursor: input.cursor,
+                limit: Some(Self::EXPORT_CHUNK_SIZE),
+            },
+            ctx,
+        )?;
+
+        let rows = transfers.iter().map(|transfer| self.export_row(transfer));
+        let mut content = String::new();
+        match input.format {
+            TransferExportFormatDTO::Csv => {
+                if is_first_chunk {
+                    content.push_str(TRANSFER_EXPORT_CSV_HEADER);
+                    content.push('\n');
+                }
+
+                for row in rows {
+                    content.push_str(&row.to_csv_line());
+                    content.push('\n');
+                }
+            }
+            TransferExportFormatDTO::Json => {
+                for row in rows {
+                    content.push_str(&row.to_json_line());
+                    content.push('\n');
+                }
+            }
+        }
+
+        Ok((content, next_transfer_id))
+    }
+
+    fn export_row(&self, transfer: &Transfer) -> TransferExportRow {
+        // the transfers of a standing order share its request, hence its approvals
+        let approvers = self
+            .request_repository
+            .get(&Request::key(transfer.request_id))
+            .map(|request| {
+                request
+                    .approvals
+                    .into_iter()
+                    .filter(|approval| approval.status == RequestApprovalStatus::Approved)
+                    .map(|approval| {
+                        self.user_repository
+                            .get(&User::key(approval.approver_id))
+                            .map(|user| user.name)
+                            .unwrap_or_else(|| {
+                                Uuid::from_bytes(approval.approver_id)
+                                    .hyphenated()
+                                    .to_string()
+                            })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let (completed_at, transaction_hash) = match &transfer.status {
+            TransferStatus::Completed {
+                completed_at, hash, ..
+            } => (Some(timestamp_to_rfc3339(completed_at)), hash.clone()),
+            _ => (None, None),
+        };
+
+        let block_index = transfer.submitted_details.as_ref().and_then(|details| {
+            details
+                .iter()
+                .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY)
+                .and_then(|(_, block_height)| block_height.parse().ok())
+        });
+
+        TransferExportRow {
+            transfer_id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
+            request_id: Uuid::from_bytes(transfer.request_id)
+                .hyphenated()
+                .to_string(),
+            created_at: timestamp_to_rfc3339(&transfer.created_timestamp),
+            status: transfer.status.to_string(),
+            completed_at,
+            to: transfer.to_address.clone(),
+            amount: transfer.amount.0.to_string(),
+            fee: transfer.fee.0.to_string(),
+            network: transfer.blockchain_network.clone(),
+            block_index,
+            transaction_hash,
+            category: transfer.category.clone(),
+            approvers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::test_utils,
+        models::{
+            account_test_utils::mock_account, request_test_utils::mock_request,
+            transfer_test_utils::mock_transfer, user_test_utils::mock_user, RequestApproval,
+        },
+        repositories::{ACCOUNT_REPOSITORY, TRANSFER_REPOSITORY},
+    };
+    use orbit_essentials::{cdk::mocks::TEST_CANISTER_ID, model::ModelKey};
+
+    #[test]
+    fn exports_the_transfers_in_chunks() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let mut approver = mock_user();
+        approver.name = "Alice".to_string();
+        USER_REPOSITORY.insert(approver.to_key(), approver.clone());
+
+        let mut request = mock_request();
+        request.approvals = vec![RequestApproval {
+            approver_id: approver.id,
+            status: RequestApprovalStatus::Approved,
+            status_reason: None,
+            decided_dt: 0,
+            last_modification_timestamp: 0,
+            reauthenticated_at: None,
+        }];
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let transfers_count = TransferExportService::EXPORT_CHUNK_SIZE as u64 + 1;
+        for i in 0..transfers_count {
+            let mut transfer = mock_transfer();
+            transfer.id = *Uuid::new_v4().as_bytes();
+            transfer.from_account = account.id;
+            transfer.request_id = request.id;
+            transfer.created_timestamp = i;
+            TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer);
+        }
+
+        let export = |cursor| {
+            TRANSFER_EXPORT_SERVICE
+                .export_account_transfers(
+                    ExportAccountTransfersInput {
+                        account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                        from_dt: None,
+                        to_dt: None,
+                        format: TransferExportFormatDTO::Csv,
+                        cursor,
+                    },
+                    &CallContext::new(TEST_CANISTER_ID),
+                )
+                .unwrap()
+        };
+
+        let (first_chunk, next_transfer_id) = export(None);
+        let first_lines = first_chunk.lines().collect::<Vec<_>>();
+        assert_eq!(first_lines[0], TRANSFER_EXPORT_CSV_HEADER);
+        assert_eq!(
+            first_lines.len(),
+            TransferExportService::EXPORT_CHUNK_SIZE as usize + 1
+        );
+        assert!(first_lines[1].ends_with(",Alice"));
+
+        let (last_chunk, next_transfer_id) = export(Some(
+            Uuid::from_bytes(next_transfer_id.unwrap())
+                .hyphenated()
+                .to_string(),
+        ));
+        assert_eq!(last_chunk.lines().count(), 1);
+        assert!(next_transfer_id.is_none());
+    }
+
+    #[test]
+    fn leaves_out_the_transfers_of_confidential_requests() {
+        test_utils::init_canister_system();
+
+        let account = mock_account();
+        ACCOUNT_REPOSITORY.insert(account.to_key(), account.clone());
+
+        let reader = mock_user();
+        USER_REPOSITORY.insert(reader.to_key(), reader.clone());
+
+        let visible_request = mock_request();
+        let mut confidential_request = mock_request();
+        confidential_request.confidential = true;
+
+        for request in [&visible_request, &confidential_request] {
+            REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+            let mut transfer = mock_transfer();
+            transfer.from_account = account.id;
+            transfer.request_id = request.id;
+            TRANSFER_REPOSITORY.insert(transfer.to_key(), transfer);
+        }
+
+        let (content, _) = TRANSFER_EXPORT_SERVICE
+            .export_account_transfers(
+                ExportAccountTransfersInput {
+                    account_id: Uuid::from_bytes(account.id).hyphenated().to_string(),
+                    from_dt: None,
+                    to_dt: None,
+                    format: TransferExportFormatDTO::Json,
+                    cursor: None,
+                },
+                &CallContext::new(reader.identities[0]),
+            )
+            .unwrap();
+
+        assert_eq!(content.lines().count(), 1);
+        assert!(content.contains(
+            &Uuid::from_bytes(visible_request.id)
+                .hyphenated()
+                .to_string()
+        ));
+        assert!(!content.contains(
+            &Uuid::from_bytes(confidential_request.id)
+                .hyphenated()
+                .to_string()
+        ));
+    }
+}
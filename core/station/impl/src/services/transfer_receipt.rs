@@ -0,0 +1,295 @@
+use crate::{
+    core::{
+        authorization::Authorization,
+        certification::{
+            certify_transfer_receipt_hash, insert_transfer_receipt_hash, transfer_receipt_witness,
+        },
+        ic_cdk::api::data_certificate,
+        CallContext,
+    },
+    errors::{AccountError, TransferError},
+    factories::blockchains::TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY,
+    models::{
+        Account, Request, RequestApprovalStatus, Transfer, TransferId, TransferReceipt,
+        TransferReceiptApproval, TransferStatus,
+    },
+    repositories::{
+        AccountRepository, RequestRepository, TransferReceiptRepository, ACCOUNT_REPOSITORY,
+        REQUEST_REPOSITORY, TRANSFER_RECEIPT_REPOSITORY,
+    },
+};
+use lazy_static::lazy_static;
+use orbit_essentials::{api::ServiceResult, http::cbor_encode, repository::Repository};
+use sha2::{Digest, Sha256};
+use station_api::{GetTransferReceiptResponse, TransferReceiptDTO};
+use std::sync::Arc;
+use uuid::Uuid;
+
+lazy_static! {
+    pub static ref TRANSFER_RECEIPT_SERVICE: Arc<TransferReceiptService> =
+        Arc::new(TransferReceiptService::new(
+            Arc::clone(&TRANSFER_RECEIPT_REPOSITORY),
+            Arc::clone(&ACCOUNT_REPOSITORY),
+            Arc::clone(&REQUEST_REPOSITORY),
+        ));
+}
+
+/// Issues a receipt for every completed transfer and certifies its hash, so that the receiver of a
+/// payment can verify it with the certificate of a query call to the station.
+#[derive(Default, Debug)]
+pub struct TransferReceiptService {
+    transfer_receipt_repository: Arc<TransferReceiptRepository>,
+    account_repository: Arc<AccountRepository>,
+    request_repository: Arc<RequestRepository>,
+}
+
+impl TransferReceiptService {
+    pub fn new(
+        transfer_receipt_repository: Arc<TransferReceiptRepository>,
+        account_repository: Arc<AccountRepository>,
+        request_repository: Arc<RequestRepository>,
+    ) -> Self {
+        Self {
+            transfer_receipt_repository,
+            account_repository,
+            request_repository,
+        }
+    }
+
+    /// Stores the receipt of the completed transfer and certifies its hash.
+    pub fn issue_receipt(&self, transfer: &Transfer) -> ServiceResult<TransferReceipt> {
+        let TransferStatus::Completed {
+            hash, completed_at, ..
+        } = &transfer.status
+        else {
+            return Err(TransferError::ReceiptNotFound {
+                transfer_id: Uuid::from_bytes(transfer.id).hyphenated().to_string(),
+            }
+            .into());
+        };
+
+        let account = self
+            .account_repository
+            .get(&Account::key(transfer.from_account))
+            .ok_or(AccountError::AccountNotFound {
+                id: Uuid::from_bytes(transfer.from_account)
+                    .hyphenated()
+                    .to_string(),
+            })?;
+
+        // the transfers of a standing order share its request, hence its approvals
+        let approvals = self
+            .request_repository
+            .get(&Request::key(transfer.request_id))
+            .map(|request| {
+                request
+                    .approvals
+                    .into_iter()
+                    .filter(|approval| approval.status == RequestApprovalStatus::Approved)
+                    .map(|approval| TransferReceiptApproval {
+                        approver_id: approval.approver_id,
+                        approved_at: approval.decided_dt,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let block_index = transfer.submitted_details.as_ref().and_then(|details| {
+            details
+                .iter()
+                .find(|(key, _)| key == TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY)
+                .and_then(|(_, block_height)| block_height.parse().ok())
+        });
+
+        let receipt = TransferReceipt {
+            transfer_id: transfer.id,
+            request_id: transfer.request_id,
+            from_account: transfer.from_account,
+            blockchain: account.blockchain.to_string(),
+            symbol: account.symbol,
+            decimals: account.decimals,
+            to_address: transfer.to_address.clone(),
+            amount: transfer.amount.clone(),
+            fee: transfer.fee.clone(),
+            block_index,
+            transaction_hash: hash.clone(),
+            approvals,
+            completed_at: *completed_at,
+        };
+
+        self.transfer_receipt_repository
+            .insert(receipt.transfer_id, receipt.clone());
+
+        let (_, receipt_hash) = Self::encode(&receipt);
+        certify_transfer_receipt_hash(receipt.transfer_id, receipt_hash);
+
+        Ok(receipt)
+    }
+
+    /// Returns the receipt of the transfer with the certificate and witness proving its hash is certified.
+    ///
+    /// The receipts of the confidential requests that the caller can't read are not found.
+    pub fn get_transfer_receipt(
+        &self,
+        transfer_id: &TransferId,
+        ctx: &CallContext,
+    ) -> ServiceResult<GetTransferReceiptResponse> {
+        let receipt = self
+            .transfer_receipt_repository
+            .get(transfer_id)
+            .filter(|receipt| {
+                !Authorization::is_confidential_request_hidden(ctx, &receipt.request_id)
+            })
+            .ok_or(TransferError::ReceiptNotFound {
+                transfer_id: Uuid::from_bytes(*transfer_id).hyphenated().to_string(),
+            })?;
+
+        let (receipt, _) = Self::encode(&receipt);
+
+        Ok(GetTransferReceiptResponse {
+            receipt,
+            certificate: data_certificate(),
+            witness: cbor_encode(&transfer_receipt_witness(transfer_id)),
+        })
+    }
+
+    /// Adds the hashes of the stored receipts to the certified tree, which is kept in heap memory and
+    /// therefore lost on upgrades.
+    ///
+    /// The certified data must be updated afterwards.
+    pub fn restore_certified_receipts(&self) {
+        for receipt in self.transfer_receipt_repository.list() {
+            let (_, receipt_hash) = Self::encode(&receipt);
+
+            insert_transfer_receipt_hash(receipt.transfer_id, receipt_hash);
+        }
+    }
+
+    /// Returns the candid encoding of the receipt with its sha256 hash.
+    fn encode(receipt: &TransferReceipt) -> (Vec<u8>, [u8; 32]) {
+        let receipt = candid::encode_one(TransferReceiptDTO::from(receipt.clone()))
+            .expect("Failed to encode the transfer receipt");
+        let receipt_hash: [u8; 32] = Sha256::digest(&receipt).into();
+
+        (receipt, receipt_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{
+            account_test_utils::add_account, request_test_utils::mock_request,
+            transfer_test_utils::mock_transfer, user_test_utils::mock_user, RequestApproval,
+        },
+        repositories::USER_REPOSITORY,
+    };
+    use orbit_essentials::cdk::mocks::TEST_CANISTER_ID;
+
+    fn setup() -> (TransferReceiptService, Transfer) {
+        let account = add_account(&[1; 16]);
+
+        let mut request = mock_request();
+        request.approvals = vec![
+            RequestApproval {
+                approver_id: [3; 16],
+                status: RequestApprovalStatus::Approved,
+                status_reason: None,
+                decided_dt: 1,
+                last_modification_timestamp: 1,
+                reauthenticated_at: None,
+            },
+            RequestApproval {
+                approver_id: [4; 16],
+                status: RequestApprovalStatus::Rejected,
+                status_reason: None,
+                decided_dt: 1,
+                last_modification_timestamp: 1,
+                reauthenticated_at: None,
+            },
+        ];
+        REQUEST_REPOSITORY.insert(request.to_key(), request.clone());
+
+        let mut transfer = mock_transfer();
+        transfer.from_account = account.id;
+        transfer.request_id = request.id;
+        transfer.submitted_details = Some(vec![(
+            TRANSACTION_SUBMITTED_DETAILS_BLOCK_HEIGHT_KEY.to_string(),
+            "42".to_string(),
+        )]);
+
+        (
+            TransferReceiptService::new(
+                Arc::clone(&TRANSFER_RECEIPT_REPOSITORY),
+                Arc::clone(&ACCOUNT_REPOSITORY),
+                Arc::clone(&REQUEST_REPOSITORY),
+            ),
+            transfer,
+        )
+    }
+
+    #[test]
+    fn only_completed_transfers_have_a_receipt() {
+        let (service, transfer) = setup();
+
+        assert!(service.issue_receipt(&transfer).is_err());
+        assert!(service
+            .get_transfer_receipt(&transfer.id, &CallContext::new(TEST_CANISTER_ID))
+            .is_err());
+    }
+
+    #[test]
+    fn serves_the_certified_receipt() {
+        let (service, mut transfer) = setup();
+        transfer.status = TransferStatus::Completed {
+            signature: None,
+            hash: Some("abc".to_string()),
+            completed_at: 2,
+        };
+
+        service.issue_receipt(&transfer).unwrap();
+
+        let response = service
+            .get_transfer_receipt(&transfer.id, &CallContext::new(TEST_CANISTER_ID))
+            .unwrap();
+        let receipt: TransferReceiptDTO = candid::decode_one(&response.receipt).unwrap();
+
+        assert_eq!(
+            receipt.transfer_id,
+            Uuid::from_bytes(transfer.id).hyphenated().to_string()
+        );
+        assert_eq!(receipt.block_index, Some(42));
+        assert_eq!(receipt.transaction_hash, Some("abc".to_string()));
+        assert_eq!(receipt.approvals.len(), 1);
+        assert!(response.certificate.is_none());
+    }
+
+    #[test]
+    fn hides_the_receipts_of_confidential_requests() {
+        let (service, mut transfer) = setup();
+        transfer.status = TransferStatus::Completed {
+            signature: None,
+            hash: None,
+            completed_at: 2,
+        };
+
+        let mut request = REQUEST_REPOSITORY
+            .get(&Request::key(transfer.request_id))
+            .unwrap();
+        request.confidential = true;
+        REQUEST_REPOSITORY.insert(request.to_key(), request);
+
+        service.issue_receipt(&transfer).unwrap();
+
+        let reader = mock_user();
+        USER_REPOSITORY.insert(reader.to_key(), reader.clone());
+
+        assert!(service
+            .get_transfer_receipt(&transfer.id, &CallContext::new(reader.identities[0]))
+            .is_err());
+        assert!(service
+            .get_transfer_receipt(&transfer.id, &CallContext::new(TEST_CANISTER_ID))
+            .is_ok());
+    }
+}
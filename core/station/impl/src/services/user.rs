@@ -8,9 +8,11 @@ use crate::{
     errors::UserError,
     mappers::{authorization::USER_PRIVILEGES, HelperMapper, UserMapper},
     models::{
+        normalize_locale,
         resource::{Resource, ResourceId, UserResourceAction},
-        AddUserOperationInput, EditUserOperationInput, RequestStatus, RequestStatusCode, User,
-        UserCallerPrivileges, UserGroupId, UserId, UserStatus, ADMIN_GROUP_ID,
+        validate_locale, AddUserOperationInput, EditUserOperationInput, OnboardingStep,
+        RequestStatus, RequestStatusCode, User, UserCallerPrivileges, UserGroupId, UserId,
+        UserReauthentication, UserStatus, ADMIN_GROUP_ID,
     },
     repositories::{
         RequestRepository, UserRepository, UserWhereClause, REQUEST_REPOSITORY, USER_REPOSITORY,
@@ -116,7 +118,8 @@ impl UserService {
 
         self.assert_name_has_no_associated_user(&input.name, None)?;
 
-        let user = UserMapper::from_create_input(*Uuid::new_v4().as_bytes(), input);
+        let mut user = UserMapper::from_create_input(*Uuid::new_v4().as_bytes(), input);
+        user.refresh_onboarding(user.last_modification_timestamp);
 
         user.validate()?;
 
@@ -144,6 +147,7 @@ impl UserService {
         let cancel_pending_requests = input.cancel_pending_requests;
 
         user.update_with(input)?;
+        user.refresh_onboarding(next_time());
         user.validate()?;
 
         self.user_repository.insert(user.to_key(), user.to_owned());
@@ -168,6 +172,99 @@ impl UserService {
         Ok(user)
     }
 
+    /// Completes steps of the onboarding checklist of the user, together with the steps that are
+    /// derived from the state of the user, and returns the updated user.
+    pub fn complete_onboarding_steps(
+        &self,
+        user_id: &UserId,
+        steps: &[OnboardingStep],
+    ) -> ServiceResult<User> {
+        let mut user = self.get_user(user_id)?;
+        let now = next_time();
+
+        let mut has_changed = user.refresh_onboarding(now);
+        for step in steps {
+            has_changed |= user.onboarding.complete(*step, now);
+        }
+
+        if has_changed {
+            self.user_repository.insert(user.to_key(), user.to_owned());
+        }
+
+        Ok(user)
+    }
+
+    /// Completes a self reported step of the onboarding checklist of the caller.
+    ///
+    /// Calling the station confirms the control of the identity, so the identity step is completed too.
+    pub fn report_onboarding_step(
+        &self,
+        step: OnboardingStep,
+        ctx: &CallContext,
+    ) -> ServiceResult<User> {
+        if !step.is_self_reported() {
+            Err(UserError::OnboardingStepNotSelfReported {
+                step: step.to_string(),
+            })?
+        }
+
+        let user = self.get_user_by_identity(&ctx.caller())?;
+
+        self.complete_onboarding_steps(&user.id, &[OnboardingStep::IdentityConfirmed, step])
+    }
+
+    /// Sets the locale the messages to the caller are rendered in, `None` restores the station default.
+    pub fn set_locale(&self, locale: Option<String>, ctx: &CallContext) -> ServiceResult<User> {
+        let mut user = self.get_user_by_identity(&ctx.caller())?;
+
+        let locale = locale.as_deref().map(normalize_locale);
+        if let Some(locale) = &locale {
+            validate_locale(locale)?;
+        }
+
+        user.locale = locale;
+        user.last_modification_timestamp = next_time();
+
+        self.user_repository.insert(user.to_key(), user.to_owned());
+
+        Ok(user)
+    }
+
+    /// Sets the types of the notifications the station does not send to the caller.
+    pub fn set_muted_notification_types(
+        &self,
+        mut muted_notification_types: Vec<String>,
+        ctx: &CallContext,
+    ) -> ServiceResult<User> {
+        let mut user = self.get_user_by_identity(&ctx.caller())?;
+
+        muted_notification_types.sort();
+        muted_notification_types.dedup();
+
+        user.muted_notification_types = muted_notification_types;
+        user.last_modification_timestamp = next_time();
+
+        self.user_repository.insert(user.to_key(), user.to_owned());
+
+        Ok(user)
+    }
+
+    /// Records that the caller re-authenticated with the station now, which steps up the approvals
+    /// the user makes from its other identities.
+    pub fn reauthenticate(&self, ctx: &CallContext) -> ServiceResult<UserReauthentication> {
+        let mut user = self.get_user_by_identity(&ctx.caller())?;
+        let reauthentication = UserReauthentication {
+            identity: ctx.caller(),
+            at: next_time(),
+        };
+
+        user.reauthentication = Some(reauthentication.clone());
+
+        self.user_repository.insert(user.to_key(), user);
+
+        Ok(reauthentication)
+    }
+
     /// Returns the list of active users in the given groups.
     pub fn get_active_users_in_groups(&self, group_ids: &[UserGroupId]) -> Vec<User> {
         self.user_repository.find_where(UserWhereClause {
@@ -393,6 +490,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "user-1".to_string(),
+            approve_only_identities: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -414,6 +512,7 @@ mod tests {
             groups: vec![[0; 16]],
             status: UserStatus::Active,
             name: "user-1".to_string(),
+            approve_only_identities: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -432,6 +531,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "Jane Doe".to_string(),
+            approve_only_identities: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -442,6 +542,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "John Doe".to_string(),
+            approve_only_identities: vec![],
         };
 
         let result = ctx.service.add_user(input);
@@ -464,6 +565,7 @@ mod tests {
             groups: vec![*ADMIN_GROUP_ID],
             status: UserStatus::Active,
             name: "Jane Doe".to_string(),
+            approve_only_identities: vec![],
         };
 
         let result = USER_SERVICE.add_user(input);
@@ -493,6 +595,7 @@ mod tests {
             groups: None,
             status: None,
             cancel_pending_requests: None,
+            approve_only_identities: None,
         };
 
         let result = USER_SERVICE.edit_user(input).await;
@@ -520,6 +623,7 @@ mod tests {
             name: None,
             status: None,
             cancel_pending_requests: None,
+            approve_only_identities: None,
         };
 
         let result = ctx.service.edit_user(input).await;
@@ -529,6 +633,43 @@ mod tests {
         assert_eq!(user.identities, vec![ctx.call_context.caller()]);
     }
 
+    #[tokio::test]
+    async fn edit_user_approve_only_identities() {
+        let ctx: TestContext = setup();
+        let cold_identity = Principal::from_slice(&[1; 29]);
+        let hot_identity = Principal::from_slice(&[2; 29]);
+        let mut user = user_test_utils::mock_user();
+        user.identities = vec![cold_identity, hot_identity];
+
+        ctx.repository.insert(user.to_key(), user.clone());
+
+        let mut input = EditUserOperationInput {
+            user_id: user.id,
+            identities: None,
+            groups: None,
+            name: None,
+            status: None,
+            cancel_pending_requests: None,
+            approve_only_identities: Some(vec![Principal::from_slice(&[3; 29])]),
+        };
+
+        let result = ctx.service.edit_user(input.clone()).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("APPROVE_ONLY_IDENTITY_NOT_ASSOCIATED"));
+
+        input.approve_only_identities = Some(vec![hot_identity]);
+        let user = ctx.service.edit_user(input.clone()).await.unwrap();
+        assert_eq!(user.approve_only_identities, vec![hot_identity]);
+
+        // removing the identity from the user also removes its restriction
+        input.identities = Some(vec![cold_identity]);
+        input.approve_only_identities = None;
+        let user = ctx.service.edit_user(input).await.unwrap();
+        assert!(user.approve_only_identities.is_empty());
+    }
+
     #[tokio::test]
     async fn edit_user_should_fail_for_identity_of_existing_user() {
         let mut user = user_test_utils::mock_user();
@@ -546,6 +687,7 @@ mod tests {
             name: None,
             status: None,
             cancel_pending_requests: None,
+            approve_only_identities: None,
         };
 
         let result = USER_SERVICE.edit_user(input).await;
@@ -635,6 +777,7 @@ pub mod user_service_test_utils {
                 groups: groups.iter().map(|g| g.id).collect(),
                 status: UserStatus::Active,
                 name: user_id.to_string(),
+                approve_only_identities: vec![],
             };
 
             users.push(USER_SERVICE.add_user(input).unwrap());
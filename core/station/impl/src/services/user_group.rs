@@ -1,4 +1,4 @@
-use super::SystemService;
+use super::{SystemService, UserService};
 use crate::core::authorization::Authorization;
 use crate::core::ic_cdk::next_time;
 use crate::core::utils::{
@@ -8,7 +8,8 @@ use crate::core::{generate_uuid_v4, CallContext};
 use crate::errors::UserGroupError;
 use crate::models::resource::{Resource, ResourceAction, ResourceId};
 use crate::models::{
-    AddUserGroupOperationInput, EditUserGroupOperationInput, UserGroup, UserGroupCallerPrivileges,
+    AddUserGroupOperationInput, EditUserGroupOperationInput, EditUserOperationInput, UserGroup,
+    UserGroupCallerPrivileges, UserGroupId, UserId,
 };
 use crate::repositories::{UseGroupWhereClause, UserGroupRepository};
 use lazy_static::lazy_static;
@@ -17,6 +18,7 @@ use orbit_essentials::model::ModelValidator;
 use orbit_essentials::repository::Repository;
 use orbit_essentials::types::UUID;
 use station_api::ListUserGroupsInput;
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -29,6 +31,7 @@ lazy_static! {
 pub struct UserGroupService {
     system_service: Arc<SystemService>,
     user_group_repository: Arc<UserGroupRepository>,
+    user_service: Arc<UserService>,
 }
 
 impl UserGroupService {
@@ -118,9 +121,51 @@ impl UserGroupService {
         self.user_group_repository
             .insert(user_group.id, user_group.clone());
 
+        for user_id in input.add_members.iter().collect::<BTreeSet<_>>() {
+            self.set_membership(user_id, &user_group.id, true).await?;
+        }
+
+        for user_id in input.remove_members.iter().collect::<BTreeSet<_>>() {
+            self.set_membership(user_id, &user_group.id, false).await?;
+        }
+
         Ok(user_group)
     }
 
+    /// Adds the user to the group or removes them from it, the user is left unchanged if they are
+    /// already in the expected state.
+    async fn set_membership(
+        &self,
+        user_id: &UserId,
+        user_group_id: &UserGroupId,
+        is_member: bool,
+    ) -> ServiceResult<()> {
+        let mut groups = self.user_service.get_user(user_id)?.groups;
+        if groups.contains(user_group_id) == is_member {
+            return Ok(());
+        }
+
+        if is_member {
+            groups.push(*user_group_id);
+        } else {
+            groups.retain(|group_id| group_id != user_group_id);
+        }
+
+        self.user_service
+            .edit_user(EditUserOperationInput {
+                user_id: *user_id,
+                name: None,
+                identities: None,
+                groups: Some(groups),
+                status: None,
+                cancel_pending_requests: None,
+                approve_only_identities: None,
+            })
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn remove(&self, id: &UUID) -> ServiceResult<()> {
         let user_group = self.get(id)?;
 
@@ -16,7 +16,8 @@ fn skip_certification_cel_expr() -> String {
     DefaultCelBuilder::skip_certification().to_string()
 }
 
-fn skip_certification_asset_tree() -> HashTree {
+/// The tree certifying that the HTTP responses of the canister skip certification.
+pub fn skip_certification_asset_tree() -> HashTree {
     let cel_expr_hash = hash(skip_certification_cel_expr().as_bytes());
     labeled(
         "http_expr",
@@ -25,8 +26,17 @@ fn skip_certification_asset_tree() -> HashTree {
 }
 
 pub fn add_skip_certification_headers(response: &mut HttpResponse) {
+    add_skip_certification_headers_with_witness(response, &skip_certification_asset_tree());
+}
+
+/// Adds the skip certification headers for a canister that certifies more data than the
+/// `skip_certification_asset_tree`, the witness must reveal its `http_expr` branch.
+pub fn add_skip_certification_headers_with_witness(
+    response: &mut HttpResponse,
+    witness: &HashTree,
+) {
     if let Some(certified_data) = data_certificate() {
-        let witness = cbor_encode(&skip_certification_asset_tree());
+        let witness = cbor_encode(witness);
         let expr_path = ["http_expr", "<*>"];
         let expr_path = cbor_encode(&expr_path);
 
@@ -47,7 +57,7 @@ pub fn add_skip_certification_headers(response: &mut HttpResponse) {
 }
 
 // Encoding
-fn cbor_encode(value: &impl Serialize) -> Vec<u8> {
+pub fn cbor_encode(value: &impl Serialize) -> Vec<u8> {
     let mut serializer = serde_cbor::Serializer::new(Vec::new());
     serializer
         .self_describe()
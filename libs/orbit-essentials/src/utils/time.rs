@@ -20,6 +20,17 @@ pub fn rfc3339_to_timestamp(rfc3339: &str) -> Timestamp {
     datetime as Timestamp
 }
 
+/// Parses the RFC3339 datetime of an untrusted input, which is rejected rather than trapping if it is
+/// malformed or before the unix epoch.
+pub fn try_rfc3339_to_timestamp(rfc3339: &str) -> Result<Timestamp, String> {
+    let nanoseconds = OffsetDateTime::parse(rfc3339, &Rfc3339)
+        .map_err(|e| format!("Invalid datetime Rfc3339 format: {}", e))?
+        .unix_timestamp_nanos();
+
+    Timestamp::try_from(nanoseconds)
+        .map_err(|_| format!("The datetime {} is out of range", rfc3339))
+}
+
 thread_local! {
     static CURRENT_TIME: RefCell<u64> = const { RefCell::new(0) };
 }
@@ -58,6 +69,16 @@ mod tests {
         assert_eq!(timestamp, 1_710_843_144_770_000_000);
     }
 
+    #[test]
+    fn rejects_invalid_rfc3339() {
+        assert_eq!(
+            try_rfc3339_to_timestamp("2024-03-19T10:12:24.77Z"),
+            Ok(1_710_843_144_770_000_000)
+        );
+        assert!(try_rfc3339_to_timestamp("tomorrow").is_err());
+        assert!(try_rfc3339_to_timestamp("1969-12-31T23:59:59Z").is_err());
+    }
+
     #[test]
     fn time_increments_correctly() {
         // The first call to `time` should return the current round time.
@@ -52,4 +52,4 @@ fi
 gzip -d wasms/station.wasm.gz
 mv wasms/station.wasm wasms/station.wasm.gz
 
-cargo test --package integration-tests $TESTNAME -- --test-threads $TEST_THREADS --nocapture
+cargo test --package integration-tests --package station-test-harness $TESTNAME -- --test-threads $TEST_THREADS --nocapture
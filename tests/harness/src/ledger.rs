@@ -0,0 +1,53 @@
+use crate::setup::{ICP_FEE, ICP_LEDGER_CANISTER_ID};
+use candid::Principal;
+use ic_ledger_types::{
+    AccountBalanceArgs, AccountIdentifier, Memo, Tokens, TransferArgs, TransferError,
+    DEFAULT_SUBACCOUNT,
+};
+use pocket_ic::{update_candid_as, PocketIc};
+
+/// Returns the ICP balance of the account, in e8s.
+pub fn get_icp_account_balance(env: &PocketIc, account: AccountIdentifier) -> u64 {
+    let (balance,): (Tokens,) = update_candid_as(
+        env,
+        ICP_LEDGER_CANISTER_ID,
+        Principal::anonymous(),
+        "account_balance",
+        (AccountBalanceArgs { account },),
+    )
+    .unwrap();
+
+    balance.e8s()
+}
+
+/// Returns the ICP balance of the default account of the principal, in e8s.
+pub fn get_icp_balance(env: &PocketIc, owner: Principal) -> u64 {
+    get_icp_account_balance(env, AccountIdentifier::new(&owner, &DEFAULT_SUBACCOUNT))
+}
+
+/// Sends ICP from the default account of the sender and returns the block index of the transfer.
+pub fn send_icp_to_account(
+    env: &PocketIc,
+    sender: Principal,
+    to: AccountIdentifier,
+    e8s: u64,
+) -> Result<u64, TransferError> {
+    let transfer_args = TransferArgs {
+        memo: Memo(0),
+        amount: Tokens::from_e8s(e8s),
+        fee: Tokens::from_e8s(ICP_FEE),
+        from_subaccount: None,
+        to,
+        created_at_time: None,
+    };
+    let (result,): (Result<u64, TransferError>,) = update_candid_as(
+        env,
+        ICP_LEDGER_CANISTER_ID,
+        sender,
+        "transfer",
+        (transfer_args,),
+    )
+    .unwrap();
+
+    result
+}
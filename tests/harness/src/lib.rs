@@ -0,0 +1,31 @@
+//! A PocketIC harness to enact governance scenarios against a station.
+//!
+//! The harness spins up the ICP ledger, the control panel and a station with its upgrader, and
+//! exposes high level helpers to create users, propose requests, vote on them and wait for their
+//! execution. It is meant for regression tests of features that span several modules of the
+//! station (e.g. request policies combined with transfers):
+//!
+//! ```ignore
+//! let harness = StationHarness::builder()
+//!     .with_admin("alice")
+//!     .with_admin("bob")
+//!     .with_quorum(2)
+//!     .build();
+//!
+//! let alice = harness.admin("alice");
+//! let bob = harness.admin("bob");
+//! let request = harness.propose(alice, add_user_operation);
+//! harness.approve(bob, &request);
+//! harness.wait_for_completion(&request).unwrap();
+//! ```
+//!
+//! The canister modules are read from the `wasms` directory of the repository, and the PocketIC
+//! binary from the `POCKET_IC_BIN` environment variable, like the integration tests.
+
+mod ledger;
+mod setup;
+mod station;
+
+pub use ledger::*;
+pub use setup::*;
+pub use station::*;
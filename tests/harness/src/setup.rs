@@ -0,0 +1,207 @@
+use candid::{CandidType, Encode, Principal};
+use ic_ledger_types::{AccountIdentifier, Tokens, DEFAULT_SUBACCOUNT};
+use pocket_ic::{query_candid_as, PocketIc, PocketIcBuilder};
+use station_api::{AdminInitInput, HealthStatus, SystemInit, SystemInstall, SystemUpgraderInput};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+pub const ICP_LEDGER_CANISTER_ID: Principal =
+    Principal::from_slice(&[0, 0, 0, 0, 0, 0, 0, 2, 1, 1]);
+pub const CANISTER_INITIAL_CYCLES: u128 = 100_000_000_000_000;
+/// The balance of the controller on the ICP ledger after the setup, in e8s.
+pub const CONTROLLER_INITIAL_BALANCE: u64 = 1_000_000_000_000;
+pub const ICP: u64 = 100_000_000; // in e8s
+pub const ICP_FEE: u64 = 10_000; // in e8s
+
+/// The canisters installed by the harness.
+#[derive(Clone, Copy, Debug)]
+pub struct CanisterIds {
+    pub icp_ledger: Principal,
+    pub control_panel: Principal,
+    pub station: Principal,
+}
+
+/// An initial admin of the station.
+#[derive(Clone, Debug)]
+pub struct AdminConfig {
+    pub name: String,
+    pub identity: Principal,
+}
+
+/// The configuration the station is installed with.
+#[derive(Clone, Debug)]
+pub struct StationConfig {
+    pub name: String,
+    pub admins: Vec<AdminConfig>,
+    pub quorum: Option<u16>,
+    pub fallback_controller: Option<Principal>,
+}
+
+#[derive(CandidType)]
+enum NnsLedgerCanisterPayload {
+    Init(NnsLedgerCanisterInitPayload),
+}
+
+#[derive(CandidType)]
+struct NnsLedgerCanisterInitPayload {
+    minting_account: String,
+    initial_values: HashMap<String, Tokens>,
+    send_whitelist: HashSet<Principal>,
+    transfer_fee: Option<Tokens>,
+    token_symbol: Option<String>,
+    token_name: Option<String>,
+}
+
+/// Returns a principal that is unique to the test user `n`.
+pub fn test_identity(n: u64) -> Principal {
+    let mut bytes = n.to_le_bytes().to_vec();
+    bytes.push(0xfe); // internal marker for user test ids
+    bytes.push(0x01); // marker for opaque ids
+    Principal::from_slice(&bytes)
+}
+
+pub fn controller_test_id() -> Principal {
+    let mut bytes = 0_u64.to_le_bytes().to_vec();
+    bytes.push(0xfd); // internal marker for controller test id
+    bytes.push(0x01); // marker for opaque ids
+    Principal::from_slice(&bytes)
+}
+
+pub fn minter_test_id() -> Principal {
+    let mut bytes = 0_u64.to_le_bytes().to_vec();
+    bytes.push(0xfc); // internal marker for minter test id
+    bytes.push(0x01); // marker for opaque ids
+    Principal::from_slice(&bytes)
+}
+
+/// Creates a new PocketIC instance with the ICP ledger, the control panel and a station installed.
+pub fn install_environment(config: &StationConfig) -> (PocketIc, CanisterIds) {
+    let path = env::var_os("POCKET_IC_BIN")
+        .expect("The environment variable POCKET_IC_BIN containing the absolute path to the PocketIC binary is not set")
+        .into_string()
+        .expect("Invalid string path");
+
+    if !Path::new(&path).exists() {
+        panic!(
+            "Could not find the PocketIC binary at {:?}, running ./scripts/run-integration-tests.sh sets it up.",
+            &path
+        );
+    }
+
+    let env = PocketIcBuilder::new()
+        .with_nns_subnet()
+        .with_application_subnet()
+        .build();
+
+    // the time is set in the past so that the tests can still switch PocketIC to live mode
+    env.set_time(SystemTime::now() - Duration::from_secs(24 * 60 * 60));
+
+    let controller = controller_test_id();
+    let icp_ledger = install_icp_ledger(&env, controller);
+    let control_panel = create_canister(&env, controller);
+    env.install_canister(
+        control_panel,
+        get_canister_wasm("control_panel"),
+        Encode!(&()).unwrap(),
+        Some(controller),
+    );
+    let station = install_station(&env, controller, config);
+
+    (
+        env,
+        CanisterIds {
+            icp_ledger,
+            control_panel,
+            station,
+        },
+    )
+}
+
+pub fn create_canister(env: &PocketIc, controller: Principal) -> Principal {
+    let canister_id = env.create_canister_with_settings(Some(controller), None);
+    env.add_cycles(canister_id, CANISTER_INITIAL_CYCLES);
+    canister_id
+}
+
+fn install_icp_ledger(env: &PocketIc, controller: Principal) -> Principal {
+    let icp_ledger = env
+        .create_canister_with_id(Some(controller), None, ICP_LEDGER_CANISTER_ID)
+        .unwrap();
+    let init_args = NnsLedgerCanisterPayload::Init(NnsLedgerCanisterInitPayload {
+        minting_account: AccountIdentifier::new(&minter_test_id(), &DEFAULT_SUBACCOUNT).to_string(),
+        initial_values: HashMap::from([(
+            AccountIdentifier::new(&controller, &DEFAULT_SUBACCOUNT).to_string(),
+            Tokens::from_e8s(CONTROLLER_INITIAL_BALANCE),
+        )]),
+        send_whitelist: HashSet::new(),
+        transfer_fee: Some(Tokens::from_e8s(ICP_FEE)),
+        token_symbol: Some("ICP".to_string()),
+        token_name: Some("Internet Computer".to_string()),
+    });
+    env.install_canister(
+        icp_ledger,
+        get_canister_wasm("icp_ledger"),
+        Encode!(&init_args).unwrap(),
+        Some(controller),
+    );
+
+    icp_ledger
+}
+
+fn install_station(env: &PocketIc, controller: Principal, config: &StationConfig) -> Principal {
+    let station = create_canister(env, controller);
+    env.set_controllers(station, Some(controller), vec![controller, station])
+        .unwrap();
+
+    let init_args = SystemInstall::Init(SystemInit {
+        name: config.name.to_owned(),
+        admins: config
+            .admins
+            .iter()
+            .map(|admin| AdminInitInput {
+                name: admin.name.to_owned(),
+                identity: admin.identity,
+            })
+            .collect(),
+        quorum: config.quorum,
+        upgrader: SystemUpgraderInput::WasmModule(get_canister_wasm("upgrader")),
+        fallback_controller: config.fallback_controller,
+        accounts: None,
+    });
+    env.install_canister(
+        station,
+        get_canister_wasm("station"),
+        Encode!(&init_args).unwrap(),
+        Some(controller),
+    );
+
+    // the station finishes its initialization with timers and inter-canister calls (seeding the
+    // uuid generator, creating and installing the upgrader and updating its own controllers)
+    for _ in 0..10 {
+        env.tick();
+    }
+
+    let (health_status,): (HealthStatus,) =
+        query_candid_as(env, station, controller, "health_status", ())
+            .expect("Unexpected error calling Station health_status");
+    assert_eq!(health_status, HealthStatus::Healthy);
+
+    station
+}
+
+pub fn get_canister_wasm(canister_name: &str) -> Vec<u8> {
+    let file_path = wasms_dir().join(format!("{canister_name}.wasm.gz"));
+
+    fs::read(&file_path)
+        .unwrap_or_else(|_| panic!("Failed to read file: {}", file_path.to_string_lossy()))
+}
+
+fn wasms_dir() -> PathBuf {
+    PathBuf::from(
+        env::var("CARGO_MANIFEST_DIR").expect("Failed to read CARGO_MANIFEST_DIR env variable"),
+    )
+    .join("wasms")
+}
@@ -0,0 +1,389 @@
+use crate::ledger::send_icp_to_account;
+use crate::setup::{
+    controller_test_id, install_environment, test_identity, AdminConfig, CanisterIds, StationConfig,
+};
+use candid::Principal;
+use ic_ledger_types::{AccountIdentifier, DEFAULT_SUBACCOUNT};
+use pocket_ic::{query_candid_as, update_candid_as, PocketIc};
+use station_api::{
+    AccountDTO, AddAccountOperationInput, AddUserOperationInput, AllowDTO, ApiErrorDTO,
+    AuthScopeDTO, CreateRequestInput, CreateRequestResponse, GetRequestInput, GetRequestResponse,
+    MeResponse, RequestApprovalStatusDTO, RequestDTO, RequestExecutionScheduleDTO,
+    RequestOperationDTO, RequestOperationInput, RequestPolicyRuleDTO, RequestStatusDTO,
+    SubmitRequestApprovalInput, SubmitRequestApprovalResponse, TransferOperationInput, UserDTO,
+    UserStatusDTO,
+};
+use std::cell::Cell;
+use std::time::Duration;
+
+/// The id of the admin group that is created when the station is installed.
+pub const ADMIN_GROUP_ID: &str = "00000000-0000-4000-8000-000000000000";
+
+/// The maximum number of timer rounds to wait for a request to reach a final status.
+const MAX_PROCESSING_ROUNDS: usize = 100;
+/// The period of the timer that processes the approved requests.
+const PROCESSING_PERIOD: Duration = Duration::from_secs(5);
+
+/// Builds a [`StationHarness`] with the initial admins and quorum of the scenario.
+#[derive(Clone, Debug)]
+pub struct StationHarnessBuilder {
+    name: String,
+    admins: Vec<AdminConfig>,
+    quorum: Option<u16>,
+    fallback_controller: Option<Principal>,
+}
+
+impl Default for StationHarnessBuilder {
+    fn default() -> Self {
+        Self {
+            name: "Station".to_string(),
+            admins: Vec::new(),
+            quorum: None,
+            fallback_controller: None,
+        }
+    }
+}
+
+impl StationHarnessBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Adds an initial admin with a generated identity.
+    pub fn with_admin(self, name: &str) -> Self {
+        let identity = test_identity(self.admins.len() as u64 + 1);
+        self.with_admin_identity(name, identity)
+    }
+
+    pub fn with_admin_identity(mut self, name: &str, identity: Principal) -> Self {
+        self.admins.push(AdminConfig {
+            name: name.to_string(),
+            identity,
+        });
+        self
+    }
+
+    /// Sets the number of admin approvals required by the initial policies.
+    pub fn with_quorum(mut self, quorum: u16) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    pub fn with_fallback_controller(mut self, fallback_controller: Principal) -> Self {
+        self.fallback_controller = Some(fallback_controller);
+        self
+    }
+
+    /// Installs the canisters, a single `admin` is added if none was configured.
+    pub fn build(mut self) -> StationHarness {
+        if self.admins.is_empty() {
+            self = self.with_admin("admin");
+        }
+
+        let config = StationConfig {
+            name: self.name,
+            admins: self.admins,
+            quorum: self.quorum,
+            fallback_controller: self.fallback_controller,
+        };
+        let (env, canister_ids) = install_environment(&config);
+
+        StationHarness {
+            env,
+            canister_ids,
+            controller: controller_test_id(),
+            next_identity: Cell::new(config.admins.len() as u64 + 1),
+            admins: config.admins,
+        }
+    }
+}
+
+/// A station running in PocketIC with helpers to enact governance scenarios.
+pub struct StationHarness {
+    env: PocketIc,
+    canister_ids: CanisterIds,
+    controller: Principal,
+    admins: Vec<AdminConfig>,
+    next_identity: Cell<u64>,
+}
+
+impl StationHarness {
+    pub fn builder() -> StationHarnessBuilder {
+        StationHarnessBuilder::new()
+    }
+
+    pub fn env(&self) -> &PocketIc {
+        &self.env
+    }
+
+    pub fn canister_ids(&self) -> CanisterIds {
+        self.canister_ids
+    }
+
+    /// The controller of the canisters, which holds the initial ICP supply.
+    pub fn controller(&self) -> Principal {
+        self.controller
+    }
+
+    /// Returns the identity of the initial admin with the given name.
+    pub fn admin(&self, name: &str) -> Principal {
+        self.admins
+            .iter()
+            .find(|admin| admin.name == name)
+            .unwrap_or_else(|| panic!("Admin {} is not configured", name))
+            .identity
+    }
+
+    /// Returns a fresh identity that is not known to the station.
+    pub fn new_identity(&self) -> Principal {
+        let n = self.next_identity.get();
+        self.next_identity.set(n + 1);
+        test_identity(n)
+    }
+
+    /// Advances the time and lets the timers of the canisters run.
+    pub fn advance_time(&self, duration: Duration) {
+        self.env.advance_time(duration);
+        self.env.tick();
+    }
+
+    /// Returns the station user of the identity.
+    pub fn me(&self, identity: Principal) -> UserDTO {
+        let (res,): (Result<MeResponse, ApiErrorDTO>,) =
+            query_candid_as(&self.env, self.canister_ids.station, identity, "me", ()).unwrap();
+
+        res.unwrap().me
+    }
+
+    /// Creates a request and returns the error of the station if it is refused.
+    pub fn try_propose(
+        &self,
+        proposer: Principal,
+        input: CreateRequestInput,
+    ) -> Result<RequestDTO, ApiErrorDTO> {
+        let (res,): (Result<CreateRequestResponse, ApiErrorDTO>,) = update_candid_as(
+            &self.env,
+            self.canister_ids.station,
+            proposer,
+            "create_request",
+            (input,),
+        )
+        .unwrap();
+
+        res.map(|response| response.request)
+    }
+
+    /// Creates a request for the operation that is executed as soon as it is approved.
+    pub fn propose(&self, proposer: Principal, operation: RequestOperationInput) -> RequestDTO {
+        self.try_propose(
+            proposer,
+            CreateRequestInput {
+                operation,
+                title: None,
+                summary: None,
+                execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+                confidential: None,
+                tags: None,
+                depends_on: None,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Votes on the request and returns the error of the station if the vote is refused.
+    pub fn try_vote(
+        &self,
+        voter: Principal,
+        request: &RequestDTO,
+        decision: RequestApprovalStatusDTO,
+        reason: Option<String>,
+    ) -> Result<RequestDTO, ApiErrorDTO> {
+        let input = SubmitRequestApprovalInput {
+            request_id: request.id.to_owned(),
+            decision,
+            reason,
+        };
+        let (res,): (Result<SubmitRequestApprovalResponse, ApiErrorDTO>,) = update_candid_as(
+            &self.env,
+            self.canister_ids.station,
+            voter,
+            "submit_request_approval",
+            (input,),
+        )
+        .unwrap();
+
+        res.map(|response| response.request)
+    }
+
+    pub fn approve(&self, voter: Principal, request: &RequestDTO) -> RequestDTO {
+        self.try_vote(voter, request, RequestApprovalStatusDTO::Approved, None)
+            .unwrap()
+    }
+
+    pub fn reject(&self, voter: Principal, request: &RequestDTO) -> RequestDTO {
+        self.try_vote(voter, request, RequestApprovalStatusDTO::Rejected, None)
+            .unwrap()
+    }
+
+    /// Returns the latest state of the request, as seen by the first admin.
+    pub fn get_request(&self, request: &RequestDTO) -> RequestDTO {
+        let input = GetRequestInput {
+            request_id: request.id.to_owned(),
+            with_full_info: Some(false),
+        };
+        let (res,): (Result<GetRequestResponse, ApiErrorDTO>,) = query_candid_as(
+            &self.env,
+            self.canister_ids.station,
+            self.admins[0].identity,
+            "get_request",
+            (input,),
+        )
+        .unwrap();
+
+        res.unwrap().request
+    }
+
+    /// Waits for the request to reach a final status, returning it if it completed or its
+    /// status otherwise (`None` if it is still pending after the maximum number of rounds).
+    pub fn wait_for_completion(
+        &self,
+        request: &RequestDTO,
+    ) -> Result<RequestDTO, Option<RequestStatusDTO>> {
+        for _ in 0..MAX_PROCESSING_ROUNDS {
+            let request = self.get_request(request);
+            match request.status {
+                RequestStatusDTO::Completed { .. } => return Ok(request),
+                RequestStatusDTO::Rejected
+                | RequestStatusDTO::Cancelled { .. }
+                | RequestStatusDTO::Failed { .. } => return Err(Some(request.status)),
+                RequestStatusDTO::Created
+                | RequestStatusDTO::Approved
+                | RequestStatusDTO::Scheduled { .. }
+                | RequestStatusDTO::Processing { .. }
+                | RequestStatusDTO::Validating => {}
+            }
+            self.advance_time(PROCESSING_PERIOD);
+        }
+
+        Err(None)
+    }
+
+    /// Proposes the operation, has it approved by the initial admins for as long as it is pending
+    /// and waits for it to complete, so that scenarios can be set up whatever the quorum.
+    pub fn execute(&self, proposer: Principal, operation: RequestOperationInput) -> RequestDTO {
+        let request = self.propose(proposer, operation);
+
+        for admin in self
+            .admins
+            .iter()
+            .filter(|admin| admin.identity != proposer)
+        {
+            if !matches!(self.get_request(&request).status, RequestStatusDTO::Created) {
+                break;
+            }
+
+            self.approve(admin.identity, &request);
+        }
+
+        self.wait_for_completion(&request).unwrap_or_else(|status| {
+            panic!("Request {} did not complete: {:?}", request.id, status)
+        })
+    }
+
+    /// Returns the operation input to add an active user with a fresh identity to the groups.
+    pub fn add_user_operation(&self, name: &str, groups: Vec<String>) -> RequestOperationInput {
+        RequestOperationInput::AddUser(AddUserOperationInput {
+            name: name.to_string(),
+            identities: vec![self.new_identity()],
+            groups,
+            status: UserStatusDTO::Active,
+            approve_only_identities: None,
+        })
+    }
+
+    /// Adds a user through a request of the proposer and returns its identity.
+    pub fn add_user(&self, proposer: Principal, name: &str, groups: Vec<String>) -> Principal {
+        let operation = self.add_user_operation(name, groups);
+        let identity = match &operation {
+            RequestOperationInput::AddUser(input) => input.identities[0],
+            _ => unreachable!(),
+        };
+
+        self.execute(proposer, operation);
+
+        identity
+    }
+
+    /// Adds an ICP account that the given users can read, configure and transfer from.
+    pub fn add_icp_account(
+        &self,
+        proposer: Principal,
+        name: &str,
+        users: &[Principal],
+        transfer_request_policy: Option<RequestPolicyRuleDTO>,
+    ) -> AccountDTO {
+        let allow = AllowDTO {
+            auth_scope: AuthScopeDTO::Restricted,
+            users: users.iter().map(|user| self.me(*user).id).collect(),
+            user_groups: vec![],
+        };
+        let request = self.execute(
+            proposer,
+            RequestOperationInput::AddAccount(AddAccountOperationInput {
+                name: name.to_string(),
+                blockchain: "icp".to_string(),
+                standard: "native".to_string(),
+                network: None,
+                metadata: vec![],
+                read_permission: allow.clone(),
+                configs_permission: allow.clone(),
+                transfer_permission: allow,
+                configs_request_policy: None,
+                transfer_request_policy,
+            }),
+        );
+
+        match request.operation {
+            RequestOperationDTO::AddAccount(operation) => operation
+                .account
+                .expect("The account of a completed request should be set"),
+            _ => panic!("Request {} is not an AddAccount request", request.id),
+        }
+    }
+
+    /// Sends ICP from the controller to the account of the station.
+    pub fn fund_account(&self, account: &AccountDTO, e8s: u64) {
+        let to = AccountIdentifier::from_hex(&account.address)
+            .expect("The ICP account address should be an account identifier");
+
+        send_icp_to_account(&self.env, self.controller, to, e8s)
+            .expect("Failed to fund the account");
+    }
+
+    /// Returns the operation input to send ICP from the account to the default account of the
+    /// beneficiary.
+    pub fn transfer_operation(
+        &self,
+        account: &AccountDTO,
+        beneficiary: Principal,
+        e8s: u64,
+    ) -> RequestOperationInput {
+        RequestOperationInput::Transfer(TransferOperationInput {
+            from_account_id: account.id.to_owned(),
+            to: AccountIdentifier::new(&beneficiary, &DEFAULT_SUBACCOUNT).to_hex(),
+            amount: e8s.into(),
+            fee: None,
+            metadata: vec![],
+            network: None,
+            spend_from: None,
+            memo: None,
+            category: None,
+            asset_id: None,
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use station_api::{QuorumDTO, RequestPolicyRuleDTO, RequestStatusDTO, UserSpecifierDTO};
+use station_test_harness::{get_icp_balance, StationHarness, ADMIN_GROUP_ID, ICP};
+
+#[test]
+fn transfer_requires_the_quorum_of_the_account_policy() {
+    let harness = StationHarness::builder()
+        .with_admin("alice")
+        .with_admin("bob")
+        .with_quorum(2)
+        .build();
+    let alice = harness.admin("alice");
+    let bob = harness.admin("bob");
+
+    let carol = harness.add_user(alice, "carol", vec![ADMIN_GROUP_ID.to_string()]);
+    assert_eq!(harness.me(carol).name, "carol");
+
+    let account = harness.add_icp_account(
+        alice,
+        "treasury",
+        &[alice, bob, carol],
+        Some(RequestPolicyRuleDTO::Quorum(QuorumDTO {
+            approvers: UserSpecifierDTO::Id(vec![harness.me(bob).id, harness.me(carol).id]),
+            min_approved: 2,
+        })),
+    );
+    harness.fund_account(&account, 3 * ICP);
+
+    let beneficiary = harness.new_identity();
+    let transfer = harness.propose(
+        alice,
+        harness.transfer_operation(&account, beneficiary, ICP),
+    );
+    harness.approve(bob, &transfer);
+
+    // a single approval of the policy approvers is not enough
+    let transfer = harness.get_request(&transfer);
+    assert!(matches!(transfer.status, RequestStatusDTO::Created));
+
+    harness.approve(carol, &transfer);
+    harness.wait_for_completion(&transfer).unwrap();
+    assert_eq!(get_icp_balance(harness.env(), beneficiary), ICP);
+
+    let transfer = harness.propose(
+        alice,
+        harness.transfer_operation(&account, beneficiary, ICP),
+    );
+    harness.reject(bob, &transfer);
+    harness.reject(carol, &transfer);
+
+    assert!(matches!(
+        harness.wait_for_completion(&transfer),
+        Err(Some(RequestStatusDTO::Rejected))
+    ));
+    assert_eq!(get_icp_balance(harness.env(), beneficiary), ICP);
+}
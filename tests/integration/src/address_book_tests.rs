@@ -30,6 +30,7 @@ fn address_book_entry_lifecycle() {
                 key: "kyc".to_string(),
                 value: "false".to_string(),
             }],
+            requires_memo: None,
         });
     let add_address_book_entry_request = execute_request(
         &env,
@@ -67,6 +68,7 @@ fn address_book_entry_lifecycle() {
                 key: "kyc".to_string(),
                 value: "true".to_string(),
             }],
+            requires_memo: None,
         });
     execute_request(
         &env,
@@ -87,6 +89,7 @@ fn address_book_entry_lifecycle() {
                 key: "kyc".to_string(),
                 value: "true".to_string(),
             }],
+            requires_memo: None,
         });
     let add_address_book_entry_request = execute_request(
         &env,
@@ -150,6 +153,7 @@ fn address_book_entry_lifecycle() {
                 key: "kyc".to_string(),
                 value: "true".to_string(),
             }])),
+            requires_memo: None,
         });
     execute_request(
         &env,
@@ -237,6 +241,7 @@ fn check_address_book_for_transfer() {
                 key: "kyc".to_string(),
                 value: "false".to_string(),
             }],
+            requires_memo: None,
         });
     let add_address_book_entry_request = execute_request(
         &env,
@@ -260,6 +265,7 @@ fn check_address_book_for_transfer() {
         name: "admin".to_string(),
         blockchain: "icp".to_string(),
         standard: "native".to_string(),
+        network: None,
         read_permission: AllowDTO {
             auth_scope: station_api::AuthScopeDTO::Restricted,
             user_groups: vec![],
@@ -310,6 +316,10 @@ fn check_address_book_for_transfer() {
         fee: None,
         metadata: vec![],
         network: None,
+        spend_from: None,
+        memo: None,
+        category: None,
+        asset_id: None,
     });
     let transfer_error = execute_request(
         &env,
@@ -337,6 +347,7 @@ fn check_address_book_for_transfer() {
                 key: "kyc".to_string(),
                 value: "true".to_string(),
             }])),
+            requires_memo: None,
         });
     execute_request(
         &env,
@@ -88,6 +88,7 @@ fn asset_upload() {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Asset(RequestAssetArgs {
                 action: RequestAssetActionArgs::Upload(request_args),
             }),
@@ -167,6 +168,7 @@ fn asset_validation() {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Asset(RequestAssetArgs {
                 action: RequestAssetActionArgs::Upload(request_args),
             }),
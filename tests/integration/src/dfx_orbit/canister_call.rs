@@ -60,6 +60,7 @@ fn canister_call() {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Canister(RequestCanisterArgs {
                 action: RequestCanisterActionArgs::Call(inner_args.clone()),
             }),
@@ -99,6 +99,7 @@ fn canister_install(use_chunks: bool) {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Canister(RequestCanisterArgs {
                 action: RequestCanisterActionArgs::Install(inner_args.clone()),
             }),
@@ -67,6 +67,7 @@ fn change_controllers() {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Canister(RequestCanisterArgs {
                 action: RequestCanisterActionArgs::UpdateSettings(add_controller_args.clone()),
             }),
@@ -84,6 +85,7 @@ fn change_controllers() {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Canister(RequestCanisterArgs {
                 action: RequestCanisterActionArgs::UpdateSettings(
                     add_remove_controller_args.clone(),
@@ -103,6 +105,7 @@ fn change_controllers() {
         let request = RequestArgs {
             title: None,
             summary: None,
+            execute_at: None,
             action: RequestArgsActions::Canister(RequestCanisterArgs {
                 action: RequestCanisterActionArgs::UpdateSettings(remove_controller_args.clone()),
             }),
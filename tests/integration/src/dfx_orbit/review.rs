@@ -90,6 +90,7 @@ fn review() {
                 sort_by: None,
                 only_approvable: true,
                 with_evaluation_results: false,
+                tags: None,
             })
             .await
             .unwrap();
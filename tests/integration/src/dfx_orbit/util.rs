@@ -42,7 +42,8 @@ pub(super) async fn poll_request_completion(
             RequestStatusDTO::Approved
             | RequestStatusDTO::Created
             | RequestStatusDTO::Scheduled { .. }
-            | RequestStatusDTO::Processing { .. } => (),
+            | RequestStatusDTO::Processing { .. }
+            | RequestStatusDTO::Validating => (),
         }
 
         if Instant::now() > timeout {
@@ -80,6 +81,7 @@ fn permit_operation(env: &PocketIc, canister_ids: &CanisterIds, resource: Resour
         auth_scope: Some(AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(env, WALLET_ADMIN_USER, canister_ids.station, add_permission).unwrap();
 }
@@ -110,6 +112,7 @@ pub(super) fn permit_list_reads(env: &PocketIc, canister_ids: &CanisterIds) {
         auth_scope: Some(AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(
         env,
@@ -128,6 +131,8 @@ fn set_four_eyes_on(env: &PocketIc, canister_ids: &CanisterIds, specifier: Reque
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            effective_from: None,
+            expiration_period_secs: None,
         });
     execute_request(
         env,
@@ -163,6 +168,8 @@ pub(super) fn set_auto_approve_on(
         RequestOperationInput::AddRequestPolicy(AddRequestPolicyOperationInput {
             specifier,
             rule: RequestPolicyRuleDTO::AutoApproved,
+            effective_from: None,
+            expiration_period_secs: None,
         });
     execute_request(
         env,
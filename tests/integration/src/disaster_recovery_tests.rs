@@ -263,6 +263,7 @@ fn auto_syncs_on_account_creation() {
         name: "admin".to_string(),
         blockchain: "icp".to_string(),
         standard: "native".to_string(),
+        network: None,
         read_permission: AllowDTO {
             auth_scope: station_api::AuthScopeDTO::Restricted,
             user_groups: vec![],
@@ -479,6 +480,7 @@ fn test_disaster_recovery_flow_recreates_same_accounts() {
             name: format!("account-{}", account_nr),
             blockchain: "icp".to_string(),
             standard: "native".to_string(),
+            network: None,
             read_permission: AllowDTO {
                 auth_scope: station_api::AuthScopeDTO::Restricted,
                 user_groups: vec![],
@@ -532,6 +534,7 @@ fn test_disaster_recovery_flow_recreates_same_accounts() {
             name: name.to_string(),
             blockchain: "icp".to_string(),
             standard: "native".to_string(),
+            network: None,
             metadata: vec![],
         })
         .collect();
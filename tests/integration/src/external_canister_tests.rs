@@ -2,8 +2,8 @@ use crate::setup::{create_canister, setup_new_env, WALLET_ADMIN_USER};
 use crate::utils::{
     add_user, bump_time_to_avoid_ratelimit, canister_status, execute_request,
     get_core_canister_health_status, get_request, submit_request, submit_request_approval,
-    submit_request_raw, submit_request_with_expected_trap, update_raw,
-    upload_canister_chunks_to_asset_canister, user_test_id, wait_for_request, COUNTER_WAT,
+    submit_request_with_expected_trap, update_raw, upload_canister_chunks_to_asset_canister,
+    user_test_id, wait_for_request, wait_for_request_validation, COUNTER_WAT,
 };
 use crate::TestEnv;
 use candid::{Encode, Principal};
@@ -70,6 +70,7 @@ fn successful_four_eyes_upgrade() {
             module: base_chunk,
             module_extra_chunks: Some(module_extra_chunks),
             arg: None,
+            replaces: None,
         });
     let trap_message = submit_request_with_expected_trap(
         &env,
@@ -91,6 +92,7 @@ fn successful_four_eyes_upgrade() {
         auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(
         &env,
@@ -137,6 +139,8 @@ fn successful_four_eyes_upgrade() {
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            effective_from: None,
+            expiration_period_secs: None,
         });
     execute_request(
         &env,
@@ -244,6 +248,7 @@ fn upgrade_reinstall_list_test() {
             module: base_chunk.clone(),
             module_extra_chunks: Some(module_extra_chunks.clone()),
             arg: None,
+            replaces: None,
         });
     execute_request(
         &env,
@@ -269,6 +274,7 @@ fn upgrade_reinstall_list_test() {
             module: base_chunk,
             module_extra_chunks: Some(module_extra_chunks),
             arg: None,
+            replaces: None,
         });
     execute_request(
         &env,
@@ -302,6 +308,7 @@ fn upgrade_reinstall_list_test() {
         sort_by: None,
         only_approvable: false,
         with_evaluation_results: false,
+        tags: None,
     };
     let res: (ApiResult<ListRequestsResponse>,) = update_candid_as(
         &env,
@@ -330,6 +337,7 @@ fn upgrade_reinstall_list_test() {
         sort_by: None,
         only_approvable: false,
         with_evaluation_results: false,
+        tags: None,
     };
     let res: (ApiResult<ListRequestsResponse>,) = update_candid_as(
         &env,
@@ -356,6 +364,7 @@ fn upgrade_reinstall_list_test() {
         sort_by: None,
         only_approvable: false,
         with_evaluation_results: false,
+        tags: None,
     };
     let res: (ApiResult<ListRequestsResponse>,) = update_candid_as(
         &env,
@@ -428,6 +437,7 @@ fn create_external_canister_and_check_status() {
         auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(
         &env,
@@ -481,6 +491,8 @@ fn create_external_canister_and_check_status() {
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            effective_from: None,
+            expiration_period_secs: None,
         });
     execute_request(
         &env,
@@ -599,6 +611,7 @@ fn create_external_canister_and_check_status() {
         auth_scope: None,
         user_groups: None,
         users: Some(vec![user_a_dto.id.to_string()]),
+        effective_from: None,
     });
     execute_request(
         &env,
@@ -761,6 +774,7 @@ fn call_external_canister_test() {
         auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(
         &env,
@@ -787,6 +801,7 @@ fn call_external_canister_test() {
         auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(
         &env,
@@ -797,14 +812,18 @@ fn call_external_canister_test() {
     .unwrap();
 
     // now the request to call the counter canister can be successfully submitted
-    // and it is immediately rejected because nobody can actually approve it
+    // and it is rejected once validated because nobody can actually approve it
     let call_canister_operation_request = submit_request(
         &env,
         user_a,
         canister_ids.station,
         call_canister_operation.clone(),
     );
-    let rejected_request = get_request(
+    match call_canister_operation_request.status {
+        RequestStatusDTO::Validating => (),
+        _ => panic!("Request should await validation."),
+    };
+    let rejected_request = wait_for_request_validation(
         &env,
         user_a,
         canister_ids.station,
@@ -849,23 +868,25 @@ fn call_external_canister_test() {
             arg: Some(42_u32.to_le_bytes().to_vec()),
             execution_method_cycles: Some(10_000_000_000_000),
         });
-    let request_error = submit_request_raw(
+    let failing_validation_request = submit_request(
         &env,
         user_a,
         canister_ids.station,
         failing_validation_call_canister_operation,
-    )
-    .unwrap()
-    .0
-    .unwrap_err();
-    assert_eq!(
-        request_error.message,
-        Some("The request has failed validation.".to_string())
     );
-    assert_eq!(
-        *request_error.details.clone().unwrap().get("info").unwrap(),
-        "failed to validate call external canister request: bad".to_string()
+    let failed_request = wait_for_request_validation(
+        &env,
+        user_a,
+        canister_ids.station,
+        failing_validation_request,
     );
+    match failed_request.status {
+        RequestStatusDTO::Failed { reason } => assert_eq!(
+            reason,
+            Some("failed to validate call external canister request: bad".to_string())
+        ),
+        _ => panic!("Request should have failed validation."),
+    };
 
     // the validation counter should increase now since the validation was performed and returned a failure
     let ctr = update_raw(
@@ -988,6 +1009,8 @@ fn call_external_canister_test() {
                 approvers: UserSpecifierDTO::Any,
                 min_approved: 2,
             }),
+            effective_from: None,
+            expiration_period_secs: None,
         });
     execute_request(
         &env,
@@ -1006,7 +1029,7 @@ fn call_external_canister_test() {
         canister_ids.station,
         call_canister_operation.clone(),
     );
-    let created_request = get_request(
+    let created_request = wait_for_request_validation(
         &env,
         user_a,
         canister_ids.station,
@@ -405,6 +405,7 @@ fn assert_can_list_requests(
                 offset: Some(0),
                 limit: Some(25),
             }),
+            tags: None,
         },),
     )
     .unwrap();
@@ -111,6 +111,7 @@ fn notification_authorization() {
         identities: vec![user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        approve_only_identities: None,
     };
     execute_request(
         &env,
@@ -126,6 +127,7 @@ fn notification_authorization() {
         auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     };
     execute_request(
         &env,
@@ -29,6 +29,7 @@ fn test_request_count_rate_limiter() {
             groups: None,
             status: None,
             cancel_pending_requests: None,
+            approve_only_identities: None,
         };
         RequestOperationInput::EditUser(edit_user_operation_input)
     });
@@ -74,6 +75,9 @@ fn test_request_size_rate_limiter() {
             title: None,
             summary: None,
             execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+            confidential: None,
+            tags: None,
+            depends_on: None,
         };
         let bytes = Encode!(&create_request_input).unwrap();
         assert!(arg_length <= bytes.len() && bytes.len() <= request_size);
@@ -102,6 +106,7 @@ fn register_test_canister(env: &PocketIc, canister_ids: &CanisterIds) -> Princip
         groups: None,
         status: None,
         cancel_pending_requests: None,
+        approve_only_identities: None,
     };
     execute_request(
         env,
@@ -131,6 +136,9 @@ where
             title: None,
             summary: None,
             execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+            confidential: None,
+            tags: None,
+            depends_on: None,
         };
         let create_request_bytes = Encode!(&create_request_input).unwrap();
         update_candid_as::<_, ()>(
@@ -23,12 +23,16 @@ fn register_user_successful() {
         identities: vec![user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        approve_only_identities: None,
     };
     let add_user_request = CreateRequestInput {
         operation: RequestOperationInput::AddUser(add_user),
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        tags: None,
+        depends_on: None,
     };
 
     let res: (Result<CreateRequestResponse, ApiErrorDTO>,) = update_candid_as(
@@ -17,6 +17,7 @@ pub fn add_account(
             name: format!("account-{}", next_id),
             blockchain: "icp".to_string(),
             standard: "native".to_string(),
+            network: None,
             metadata: Vec::new(),
             configs_permission: station_api::AllowDTO {
                 auth_scope: station_api::AuthScopeDTO::Authenticated,
@@ -71,6 +72,8 @@ pub fn edit_account_name(
             transfer_permission: None,
             configs_request_policy: None,
             transfer_request_policy: None,
+            webhooks: None,
+            assets: None,
         }),
     );
 
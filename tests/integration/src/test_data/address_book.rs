@@ -21,6 +21,7 @@ pub fn add_address_book_entry(
                 address_owner: format!("user-{}", next_id),
                 metadata: Vec::new(),
                 address: format!("{}{}", "0x", sha256_hex(&next_id.to_le_bytes())),
+                requires_memo: None,
             },
         ),
     );
@@ -58,6 +59,7 @@ pub fn edit_address_book_entry_owner(
                 address_owner: Some(address_owner),
                 change_metadata: None,
                 labels: None,
+                requires_memo: None,
             },
         ),
     );
@@ -18,6 +18,7 @@ pub fn edit_permission(
                 auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
                 user_groups: None,
                 users: None,
+                effective_from: None,
             },
         ),
     );
@@ -16,6 +16,8 @@ pub fn add_request_policy(
             station_api::AddRequestPolicyOperationInput {
                 specifier,
                 rule: station_api::RequestPolicyRuleDTO::AutoApproved,
+                effective_from: None,
+                expiration_period_secs: None,
             },
         ),
     );
@@ -19,6 +19,7 @@ pub fn add_user(
             identities: vec![identity],
             groups: group_ids,
             status: station_api::UserStatusDTO::Active,
+            approve_only_identities: None,
         });
     let add_user_request = submit_request(env, requester, station_canister_id, add_user);
     let new_request = wait_for_request(env, requester, station_canister_id, add_user_request)
@@ -45,6 +46,7 @@ pub fn edit_user_name(
             groups: None,
             status: None,
             cancel_pending_requests: None,
+            approve_only_identities: None,
         });
 
     let edit_user_request = submit_request(env, requester, station_canister_id, edit_user);
@@ -43,6 +43,8 @@ pub fn edit_user_group(
             station_api::EditUserGroupOperationInput {
                 user_group_id,
                 name,
+                add_members: None,
+                remove_members: None,
             },
         ),
     );
@@ -37,6 +37,7 @@ fn make_transfer_successful() {
         name: "test".to_string(),
         blockchain: "icp".to_string(),
         standard: "native".to_string(),
+        network: None,
         read_permission: AllowDTO {
             auth_scope: station_api::AuthScopeDTO::Restricted,
             user_groups: vec![],
@@ -71,6 +72,9 @@ fn make_transfer_successful() {
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        tags: None,
+        depends_on: None,
     };
     let res: (ApiResult<CreateRequestResponse>,) = update_candid_as(
         &env,
@@ -161,12 +165,19 @@ fn make_transfer_successful() {
         fee: None,
         metadata: vec![],
         network: None,
+        spend_from: None,
+        memo: None,
+        category: None,
+        asset_id: None,
     };
     let transfer_request = CreateRequestInput {
         operation: RequestOperationInput::Transfer(transfer),
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        tags: None,
+        depends_on: None,
     };
     let res: (Result<CreateRequestResponse, ApiErrorDTO>,) = update_candid_as(
         &env,
@@ -259,6 +270,14 @@ fn make_transfer_successful() {
             from_dt: None,
             to_dt: None,
             status: None,
+            metadata: None,
+            category: None,
+            asset_id: None,
+            to_address: None,
+            min_amount: None,
+            max_amount: None,
+            cursor: None,
+            limit: None,
         },),
     )
     .unwrap();
@@ -21,6 +21,7 @@ fn cancel_pending_requests() {
         auth_scope: Some(station_api::AuthScopeDTO::Authenticated),
         user_groups: None,
         users: None,
+        effective_from: None,
     });
     execute_request(
         &env,
@@ -37,6 +38,7 @@ fn cancel_pending_requests() {
         identities: vec![alice_user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        approve_only_identities: None,
     });
     let request_dto =
         execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_user).unwrap();
@@ -52,6 +54,7 @@ fn cancel_pending_requests() {
         identities: vec![bob_user_id],
         groups: vec![],
         status: station_api::UserStatusDTO::Active,
+        approve_only_identities: None,
     });
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, add_user).unwrap();
 
@@ -109,6 +112,7 @@ fn cancel_pending_requests() {
         groups: None,
         status: None,
         cancel_pending_requests: None,
+        approve_only_identities: None,
     });
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, edit_user).unwrap();
     for request_dto in alice_request_dtos.clone() {
@@ -127,6 +131,7 @@ fn cancel_pending_requests() {
         groups: None,
         status: None,
         cancel_pending_requests: Some(true),
+        approve_only_identities: None,
     });
     execute_request(&env, WALLET_ADMIN_USER, canister_ids.station, edit_user).unwrap();
     for request_dto in alice_request_dtos {
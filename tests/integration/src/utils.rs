@@ -149,7 +149,8 @@ fn is_request_completed(request: RequestDTO) -> bool {
         | RequestStatusDTO::Created
         | RequestStatusDTO::Approved
         | RequestStatusDTO::Scheduled { .. }
-        | RequestStatusDTO::Processing { .. } => false,
+        | RequestStatusDTO::Processing { .. }
+        | RequestStatusDTO::Validating => false,
     }
 }
 
@@ -162,7 +163,8 @@ fn is_request_evaluated(request: RequestDTO) -> bool {
         RequestStatusDTO::Created
         | RequestStatusDTO::Approved
         | RequestStatusDTO::Scheduled { .. }
-        | RequestStatusDTO::Processing { .. } => false,
+        | RequestStatusDTO::Processing { .. }
+        | RequestStatusDTO::Validating => false,
     }
 }
 
@@ -177,6 +179,9 @@ pub fn submit_request_raw(
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        tags: None,
+        depends_on: None,
     };
     update_candid_as(
         env,
@@ -247,6 +252,24 @@ pub fn wait_for_request_with_extra_ticks(
     Err(None)
 }
 
+/// Waits for the validations that require inter-canister calls and returns the validated request.
+pub fn wait_for_request_validation(
+    env: &PocketIc,
+    user_id: Principal,
+    station_canister_id: CanisterId,
+    request: RequestDTO,
+) -> RequestDTO {
+    for _ in 0..100 {
+        let new_request = get_request(env, user_id, station_canister_id, request.clone());
+        if !matches!(new_request.status, RequestStatusDTO::Validating) {
+            return new_request;
+        }
+        env.advance_time(Duration::from_secs(1));
+        env.tick();
+    }
+    panic!("Request {} is still awaiting validation", request.id);
+}
+
 pub fn execute_request(
     env: &PocketIc,
     user_id: Principal,
@@ -334,6 +357,7 @@ pub fn add_user_with_name(
         identities: vec![identity],
         groups: group_ids,
         status: UserStatusDTO::Active,
+        approve_only_identities: None,
     });
     let add_user_request = submit_request(env, WALLET_ADMIN_USER, station_canister_id, add_user);
     let new_request = wait_for_request(
@@ -581,6 +605,7 @@ pub fn create_icp_account(env: &PocketIc, station_id: Principal, user_id: UuidDT
         name: "test".to_string(),
         blockchain: "icp".to_string(),
         standard: "native".to_string(),
+        network: None,
         read_permission: AllowDTO {
             auth_scope: station_api::AuthScopeDTO::Restricted,
             user_groups: vec![],
@@ -615,6 +640,9 @@ pub fn create_icp_account(env: &PocketIc, station_id: Principal, user_id: UuidDT
         title: None,
         summary: None,
         execution_plan: Some(RequestExecutionScheduleDTO::Immediate),
+        confidential: None,
+        tags: None,
+        depends_on: None,
     };
     let res: (ApiResult<CreateRequestResponse>,) = update_candid_as(
         env,
@@ -913,6 +941,7 @@ pub(crate) fn add_external_canister_call_any_method_permission_and_approval(
                     },
                 ),
             ),
+            effective_from: None,
         }),
     )
     .expect("Failed to add permission to call external canister");
@@ -930,6 +959,8 @@ pub(crate) fn add_external_canister_call_any_method_permission_and_approval(
                 },
             ),
             rule: station_api::RequestPolicyRuleDTO::Quorum(quorum),
+            effective_from: None,
+            expiration_period_secs: None,
         }),
     )
     .expect("Failed to add approval policy to call external canister");
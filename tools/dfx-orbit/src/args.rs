@@ -4,11 +4,15 @@ use crate::{
     asset::{RequestAssetArgs, VerifyAssetArgs},
     canister::{RequestCanisterArgs, VerifyCanisterArgs},
     dfx::OrbitExtensionAgent,
+    doctor::{resolve_station_config, DoctorArgs},
+    group::RequestGroupArgs,
     me::MeArgs,
     permission::RequestPermissionArgs,
     review::ReviewArgs,
     station::StationArgs,
-    util::init_logger,
+    transfer::RequestTransferArgs,
+    user::RequestUserArgs,
+    util::{init_logger, parse_execution_plan},
     DfxOrbit,
 };
 use clap::{Parser, Subcommand};
@@ -60,6 +64,8 @@ pub enum DfxOrbitSubcommands {
     Review(ReviewArgs),
     /// Gets the caller's profile on an Orbit station.
     Me(MeArgs),
+    /// Checks the local configuration and the connection to the station, and suggests fixes.
+    Doctor(DoctorArgs),
 }
 
 /// Request canister changes.
@@ -74,6 +80,11 @@ pub struct RequestArgs {
     #[clap(long)]
     pub summary: Option<String>,
 
+    /// Execute the request at this time once approved instead of right away, e.g.
+    /// `2025-03-03T09:00:00Z` or `next monday 09:00` (UTC)
+    #[clap(long)]
+    pub execute_at: Option<String>,
+
     #[clap(subcommand)]
     pub action: RequestArgsActions,
 }
@@ -88,6 +99,14 @@ pub enum RequestArgsActions {
     /// Request permissions
     #[clap(subcommand)]
     Permission(RequestPermissionArgs),
+    /// Request transfers from an account, from a CSV file of payments
+    Transfer(RequestTransferArgs),
+    /// Request changes to users
+    #[clap(subcommand)]
+    User(RequestUserArgs),
+    /// Request changes to user groups
+    #[clap(subcommand)]
+    Group(RequestGroupArgs),
 }
 
 #[derive(Debug, Clone, Subcommand)]
@@ -107,21 +126,23 @@ impl DfxOrbitArgs {
         let orbit_agent = OrbitExtensionAgent::new()?;
 
         // We don't need to instanciate a StationAgent to execute this command directly on the orbit agent
-        if let DfxOrbitSubcommands::Station(station_args) = self.command {
-            station_args.execute(orbit_agent)?;
-            return Ok(());
+        match self.command {
+            DfxOrbitSubcommands::Station(station_args) if !station_args.calls_station() => {
+                station_args.execute(orbit_agent)?;
+                return Ok(());
+            }
+            _ => {}
         };
 
-        let config = if let Some(station_name) = self.station {
-            orbit_agent.station(&station_name)?
-        } else if let Some(station_file) = self.station_file {
-            orbit_agent.station_from_path(&station_file)?
-        } else {
-            orbit_agent
-                .default_station()?
-                .ok_or_else(|| anyhow::format_err!("No default station specified"))?
+        // The configuration is resolved by the doctor itself, so that its errors can be diagnosed
+        if let DfxOrbitSubcommands::Doctor(doctor_args) = self.command {
+            return doctor_args
+                .execute(orbit_agent, self.station, self.station_file, self.identity)
+                .await;
         };
 
+        let config = resolve_station_config(&orbit_agent, self.station, self.station_file)?;
+
         let dfx_orbit = DfxOrbit::new(orbit_agent, config, self.identity, logger).await?;
 
         match self.command {
@@ -135,6 +156,21 @@ impl DfxOrbitArgs {
                 }
                 Ok(())
             }
+            // A request is created for every payment of the file
+            DfxOrbitSubcommands::Request(RequestArgs {
+                title,
+                summary,
+                execute_at,
+                action: RequestArgsActions::Transfer(transfer_args),
+            }) => {
+                let execution_plan = execute_at
+                    .as_deref()
+                    .map(parse_execution_plan)
+                    .transpose()?;
+                transfer_args
+                    .execute(&dfx_orbit, title, summary, execution_plan)
+                    .await
+            }
             DfxOrbitSubcommands::Request(request_args) => {
                 let request = dfx_orbit
                     .station
@@ -166,7 +202,10 @@ impl DfxOrbitArgs {
                 Ok(())
             }
             DfxOrbitSubcommands::Review(review_args) => review_args.execute(&dfx_orbit).await,
-            DfxOrbitSubcommands::Station(_) => unreachable!(),
+            DfxOrbitSubcommands::Station(station_args) => {
+                station_args.execute_on_station(&dfx_orbit).await
+            }
+            DfxOrbitSubcommands::Doctor(_) => unreachable!(),
         }
     }
 }
@@ -181,13 +220,25 @@ impl RequestArgs {
             RequestArgsActions::Permission(permission_args) => {
                 permission_args.into_request(dfx_orbit)?
             }
+            RequestArgsActions::User(user_args) => user_args.into(),
+            RequestArgsActions::Group(group_args) => group_args.into(),
+            RequestArgsActions::Transfer(_) => {
+                anyhow::bail!("Transfers create one request per payment of the CSV file")
+            }
         };
 
         Ok(CreateRequestInput {
             operation,
             title: self.title,
             summary: self.summary,
-            execution_plan: None,
+            execution_plan: self
+                .execute_at
+                .as_deref()
+                .map(parse_execution_plan)
+                .transpose()?,
+            confidential: None,
+            tags: None,
+            depends_on: None,
         })
     }
 }
@@ -31,6 +31,10 @@ pub struct RequestCanisterInstallArgs {
     /// The asset canister name or ID to upload module chunks to.
     #[clap(long)]
     pub asset_canister: Option<String>,
+    /// The ID of a pending request for the same canister that this one corrects, it is cancelled
+    /// once this request is approved.
+    #[clap(long)]
+    pub replaces: Option<String>,
 }
 
 #[derive(CandidType)]
@@ -103,6 +107,7 @@ impl RequestCanisterInstallArgs {
             module,
             module_extra_chunks,
             arg,
+            replaces: self.replaces,
         };
         Ok(RequestOperationInput::ChangeExternalCanister(operation))
     }
@@ -148,6 +153,9 @@ impl RequestCanisterInstallArgs {
             );
             bail!("Argument checksum does not match");
         }
+        if op.replaces != self.replaces {
+            bail!("Replaced request {:?} does not match", op.replaces);
+        }
 
         Ok(())
     }
@@ -217,6 +225,9 @@ impl DfxOrbit {
         if let Some(arg_checksum) = &op.arg_checksum {
             writeln!(output, "Argument checksum: {}", arg_checksum)?;
         }
+        if let Some(replaces) = &op.replaces {
+            writeln!(output, "Replaces request: {}", replaces)?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,261 @@
+//! Diagnoses the local configuration and the connection to the station.
+
+use crate::{dfx::OrbitExtensionAgent, station::StationAgent, StationConfig};
+use candid::Principal;
+use clap::Parser;
+use station_api::{HealthStatus, UserStatusDTO, STATION_API_VERSION};
+use std::{
+    fmt::Display,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Debug, Clone, Parser)]
+pub struct DoctorArgs {}
+
+impl DoctorArgs {
+    /// Runs the checks one after the other, the checks that depend on a failed one are skipped.
+    pub(crate) async fn execute(
+        self,
+        mut orbit_agent: OrbitExtensionAgent,
+        station: Option<String>,
+        station_file: Option<PathBuf>,
+        identity: Option<String>,
+    ) -> anyhow::Result<()> {
+        let mut report = Report::default();
+
+        let config = match resolve_station_config(&orbit_agent, station, station_file) {
+            Ok(config) => {
+                report.pass(
+                    "Station config",
+                    format!(
+                        "{} ({}) on {}",
+                        config.name, config.station_id, config.network
+                    ),
+                );
+                if !config.url.starts_with("https://") && !config.url.starts_with("http://") {
+                    report.warn(
+                        "Station config",
+                        format!("the Orbit UI URL \"{}\" is not a http(s) URL", config.url),
+                        format!(
+                            "Run `dfx-orbit station edit {} --url https://orbitwallet.io`",
+                            config.name
+                        ),
+                    );
+                }
+                config
+            }
+            Err(err) => {
+                report.fail(
+                    "Station config",
+                    err,
+                    "Run `dfx-orbit station add STATION_NAME --station-id STATION_ID --network ic`, \
+                    then `dfx-orbit station use STATION_NAME` to make it the default station",
+                );
+                return report.finish();
+            }
+        };
+
+        let interface = match orbit_agent.dfx_interface(&config.network, identity).await {
+            Ok(interface) => interface,
+            Err(err) => {
+                report.fail(
+                    "Identity and network",
+                    err,
+                    format!(
+                        "Check that `dfx identity whoami` works and that the network \"{}\" is \
+                        defined (and running, for a local network)",
+                        config.network
+                    ),
+                );
+                return report.finish();
+            }
+        };
+
+        let principal = match interface.identity().sender() {
+            Ok(principal) if principal == Principal::anonymous() => {
+                report.fail(
+                    "Identity",
+                    "the anonymous identity cannot be registered on a station",
+                    "Run `dfx identity use IDENTITY_NAME` or pass `--identity IDENTITY_NAME`",
+                );
+                return report.finish();
+            }
+            Ok(principal) => {
+                report.pass("Identity", principal);
+                principal
+            }
+            Err(err) => {
+                report.fail(
+                    "Identity",
+                    err,
+                    "Check that the identity can be unlocked with `dfx identity get-principal`",
+                );
+                return report.finish();
+            }
+        };
+
+        let delegation_chain = interface.identity().delegation_chain();
+        if let Some(expiration) = delegation_chain
+            .iter()
+            .map(|signed| signed.delegation.expiration)
+            .min()
+        {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or_default();
+
+            if expiration <= now {
+                report.fail(
+                    "Delegation",
+                    "the delegation of the identity has expired",
+                    "Renew the delegation of the identity, then run the command again",
+                );
+                return report.finish();
+            }
+
+            report.pass(
+                "Delegation",
+                format!("expires in {} minutes", (expiration - now) / 60_000_000_000),
+            );
+        }
+
+        let station = StationAgent::new(interface.agent().clone(), config);
+
+        match station.health_status().await {
+            Ok(HealthStatus::Healthy) => report.pass("Station reachable", "healthy"),
+            Ok(HealthStatus::Uninitialized) => {
+                report.fail(
+                    "Station reachable",
+                    "the station is not initialized yet",
+                    "Wait for the station to finish its initialization, then run the command again",
+                );
+                return report.finish();
+            }
+            Err(err) => {
+                report.fail(
+                    "Station reachable",
+                    err,
+                    format!(
+                        "Check the station ID with `dfx-orbit station show` against the wallet ID in \
+                        the station settings, and that it is deployed on the \"{}\" network",
+                        station.config.network
+                    ),
+                );
+                return report.finish();
+            }
+        }
+
+        match station.me().await {
+            Ok(me) if matches!(me.me.status, UserStatusDTO::Active) => {
+                report.pass("Registration", format!("{} ({})", me.me.name, me.me.id))
+            }
+            Ok(me) => report.warn(
+                "Registration",
+                format!("the user {} ({}) is inactive", me.me.name, me.me.id),
+                "Ask an admin of the station to activate your user",
+            ),
+            Err(err) => report.fail(
+                "Registration",
+                err,
+                format!(
+                    "Add the principal {principal} to your user in Orbit: \
+                    Settings -> Users -> Edit a user -> Identities"
+                ),
+            ),
+        }
+
+        match station.capabilities().await {
+            Ok(response) => match response.capabilities.api_version {
+                Some(api_version) if api_version == STATION_API_VERSION => report.pass(
+                    "Version",
+                    format!(
+                        "station {} implements the API {api_version}",
+                        response.capabilities.version
+                    ),
+                ),
+                Some(api_version) => report.warn(
+                    "Version",
+                    format!(
+                        "the station implements the API {api_version}, dfx-orbit was built for \
+                        {STATION_API_VERSION}"
+                    ),
+                    "Install the dfx-orbit release matching the station version, or upgrade the \
+                    station, some commands may fail until then",
+                ),
+                None => report.warn(
+                    "Version",
+                    format!(
+                        "the station {} does not report its API version, dfx-orbit was built for \
+                        {STATION_API_VERSION}",
+                        response.capabilities.version
+                    ),
+                    "Upgrade the station to the latest version",
+                ),
+            },
+            Err(err) => report.warn(
+                "Version",
+                format!("could not read the capabilities of the station: {err}"),
+                "Ask an admin of the station for the permission to read its capabilities",
+            ),
+        }
+
+        report.finish()
+    }
+}
+
+/// Resolves the station to run the command on, the explicit station first, then the default one.
+pub(crate) fn resolve_station_config(
+    orbit_agent: &OrbitExtensionAgent,
+    station: Option<String>,
+    station_file: Option<PathBuf>,
+) -> anyhow::Result<StationConfig> {
+    if let Some(station_name) = station {
+        orbit_agent.station(&station_name)
+    } else if let Some(station_file) = station_file {
+        orbit_agent.station_from_path(&station_file)
+    } else {
+        orbit_agent
+            .default_station()?
+            .ok_or_else(|| anyhow::format_err!("No default station specified"))
+    }
+}
+
+/// Prints the outcome of the checks as they run.
+#[derive(Default)]
+struct Report {
+    failed: bool,
+    warnings: usize,
+}
+
+impl Report {
+    fn pass(&mut self, check: &str, details: impl Display) {
+        println!("[ok]   {check}: {details}");
+    }
+
+    fn warn(&mut self, check: &str, details: impl Display, fix: impl Display) {
+        self.warnings += 1;
+        println!("[warn] {check}: {details}");
+        println!("       Fix: {fix}");
+    }
+
+    fn fail(&mut self, check: &str, details: impl Display, fix: impl Display) {
+        self.failed = true;
+        println!("[fail] {check}: {details}");
+        println!("       Fix: {fix}");
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        if self.failed {
+            anyhow::bail!("Some checks failed, see the fixes above");
+        }
+
+        match self.warnings {
+            0 => println!("All checks passed"),
+            warnings => println!("All checks passed with {warnings} warning(s)"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+//! Makes `AddUserGroup`, `EditUserGroup` and `RemoveUserGroup` requests to Orbit.
+
+use clap::{Parser, Subcommand};
+use station_api::{
+    AddUserGroupOperationInput, EditUserGroupOperationInput, RemoveUserGroupOperationInput,
+    RequestOperationInput,
+};
+
+/// Request changes to the user groups of the station.
+#[derive(Debug, Clone, Subcommand)]
+#[clap(version, about, long_about = None)]
+pub enum RequestGroupArgs {
+    /// Request to add a user group
+    Add(RequestGroupAddArgs),
+    /// Request to rename a user group
+    Edit(RequestGroupEditArgs),
+    /// Request to remove a user group
+    Remove(RequestGroupRemoveArgs),
+}
+
+impl From<RequestGroupArgs> for RequestOperationInput {
+    fn from(value: RequestGroupArgs) -> Self {
+        match value {
+            RequestGroupArgs::Add(args) => {
+                RequestOperationInput::AddUserGroup(AddUserGroupOperationInput { name: args.name })
+            }
+            RequestGroupArgs::Edit(args) => {
+                RequestOperationInput::EditUserGroup(EditUserGroupOperationInput {
+                    user_group_id: args.group,
+                    name: args.name,
+                    add_members: None,
+                    remove_members: None,
+                })
+            }
+            RequestGroupArgs::Remove(args) => {
+                RequestOperationInput::RemoveUserGroup(RemoveUserGroupOperationInput {
+                    user_group_id: args.group,
+                })
+            }
+        }
+    }
+}
+
+/// Requests to add a user group to the station.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestGroupAddArgs {
+    /// The name of the user group
+    #[clap(long)]
+    pub name: String,
+}
+
+/// Requests to rename a user group of the station.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestGroupEditArgs {
+    /// The ID of the user group
+    pub group: String,
+    /// The new name of the user group
+    #[clap(long)]
+    pub name: String,
+}
+
+/// Requests to remove a user group of the station.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestGroupRemoveArgs {
+    /// The ID of the user group
+    pub group: String,
+}
@@ -8,11 +8,15 @@ pub mod args;
 pub mod asset;
 pub mod canister;
 pub mod dfx;
+mod doctor;
+pub mod group;
 pub mod local_config;
 mod me;
 pub mod permission;
 pub mod review;
 pub mod station;
+pub mod transfer;
+pub mod user;
 mod util;
 
 use anyhow::{anyhow, bail, Context};
@@ -80,6 +80,7 @@ impl RequestPermissionUpgradeCanisterArgs {
                 auth_scope: None,
                 users: Some(self.user),
                 user_groups: Some(self.group),
+                effective_from: None,
             },
         ))
     }
@@ -111,6 +112,7 @@ impl RequestPermissionCallCanisterArgs {
                 auth_scope: None,
                 users: Some(self.user),
                 user_groups: Some(self.group),
+                effective_from: None,
             },
         ))
     }
@@ -134,6 +136,7 @@ impl From<RequestPermissionUpdatePermissionsArgs> for RequestOperationInput {
             auth_scope: None,
             users: Some(value.user),
             user_groups: Some(value.group),
+            effective_from: None,
         })
     }
 }
@@ -156,6 +159,7 @@ impl From<RequestPermissionReadPermissionsArgs> for RequestOperationInput {
             auth_scope: None,
             users: Some(value.user),
             user_groups: Some(value.group),
+            effective_from: None,
         })
     }
 }
@@ -1,5 +1,6 @@
 use crate::DfxOrbit;
 use clap::{Parser, Subcommand};
+use display::display_request_comments;
 use slog::{info, warn};
 use station_api::{
     GetNextApprovableRequestInput, GetRequestInput, RequestApprovalStatusDTO, RequestStatusDTO,
@@ -7,10 +8,11 @@ use station_api::{
 };
 use util::{external_canister_operations, print_as_json};
 
-pub use crate::review::list::ReviewListArgs;
+pub use crate::review::{list::ReviewListArgs, report::ReviewReportArgs};
 
 mod display;
 mod list;
+mod report;
 mod util;
 
 /// Station management commands.
@@ -32,6 +34,8 @@ pub enum ReviewActionArgs {
     Next(ReviewNextArgs),
     /// Review a specific request.
     Id(ReviewIdArgs),
+    /// Generate a markdown report of the requests created in a period.
+    Report(ReviewReportArgs),
 }
 
 /// Reviews the next request.
@@ -45,6 +49,9 @@ pub struct ReviewIdArgs {
     /// Prompt the user to reject the request
     #[clap(short, long, action, value_name = "REASON", conflicts_with = "approve")]
     pub(crate) reject: Option<Option<String>>,
+    /// Comment the request, the other participants of the request are notified
+    #[clap(short, long, value_name = "TEXT")]
+    pub(crate) comment: Option<String>,
 }
 
 impl From<ReviewIdArgs> for GetRequestInput {
@@ -92,6 +99,17 @@ impl From<ReviewNextArgs> for GetNextApprovableRequestInput {
     }
 }
 
+impl DfxOrbit {
+    async fn print_request_comments(&self, request_id: String) -> anyhow::Result<()> {
+        let comments = self.station.review_comments(request_id).await?;
+        if comments.total > 0 {
+            println!("{}", display_request_comments(&comments)?);
+        }
+
+        Ok(())
+    }
+}
+
 impl ReviewArgs {
     pub(crate) async fn execute(self, dfx_orbit: &DfxOrbit) -> anyhow::Result<()> {
         let as_json = self.json;
@@ -107,6 +125,25 @@ impl ReviewArgs {
                 }
                 Ok(())
             }
+            ReviewActionArgs::Report(args) => {
+                let response = dfx_orbit.fetch_report_requests(&args).await?;
+
+                if as_json {
+                    print_as_json(&response)?;
+                    return Ok(());
+                }
+
+                let report = dfx_orbit.display_report(&args, response)?;
+                match args.output {
+                    Some(output) => {
+                        std::fs::write(&output, report)?;
+                        info!(dfx_orbit.logger, "Report written to {}", output.display());
+                    }
+                    None => println!("{}", report),
+                }
+
+                Ok(())
+            }
             ReviewActionArgs::Next(args) => {
                 let request = dfx_orbit.station.review_next(args.into()).await?;
 
@@ -116,7 +153,9 @@ impl ReviewArgs {
                 if as_json {
                     print_as_json(&request)?;
                 } else {
-                    println!("{}", dfx_orbit.display_get_request_response(request)?)
+                    let request_id = request.request.id.clone();
+                    println!("{}", dfx_orbit.display_get_request_response(request)?);
+                    dfx_orbit.print_request_comments(request_id).await?;
                 }
 
                 Ok(())
@@ -129,7 +168,18 @@ impl ReviewArgs {
                     println!(
                         "{}",
                         dfx_orbit.display_get_request_response(request.clone())?
-                    )
+                    );
+                    dfx_orbit
+                        .print_request_comments(args.request_id.clone())
+                        .await?;
+                }
+
+                if let Some(body) = args.comment.clone() {
+                    dfx_orbit
+                        .station
+                        .comment(args.request_id.clone(), body)
+                        .await?;
+                    info!(dfx_orbit.logger, "Submitted comment");
                 }
 
                 if let RequestStatusDTO::Created = request.request.status {
@@ -1,8 +1,9 @@
 use crate::DfxOrbit;
 use station_api::{
     EvaluatedRequestPolicyRuleDTO, EvaluationStatusDTO, GetRequestResponse,
-    RequestAdditionalInfoDTO, RequestApprovalDTO, RequestApprovalStatusDTO, RequestDTO,
-    RequestOperationDTO, RequestStatusDTO,
+    ListRequestCommentsResponse, RequestAdditionalInfoDTO, RequestApprovalDTO,
+    RequestApprovalStatusDTO, RequestDTO, RequestExecutionScheduleDTO, RequestOperationDTO,
+    RequestStatusDTO, TransferMemoDTO, TransferOperationDTO,
 };
 use std::{collections::BTreeMap, fmt::Write};
 
@@ -46,8 +47,15 @@ impl DfxOrbit {
         if let Some(additional_status) = display_additional_stats_info(&base_info.status) {
             writeln!(output, "{}", additional_status)?;
         }
+        if let RequestExecutionScheduleDTO::Scheduled { execution_time } = &base_info.execution_plan
+        {
+            writeln!(output, "Execute at: {}", execution_time)?;
+        }
 
         match base_info.operation {
+            RequestOperationDTO::Transfer(op) => {
+                display_transfer_operation(&mut output, op.as_ref())?;
+            }
             RequestOperationDTO::ChangeExternalCanister(op) => {
                 self.display_change_canister_operation(&mut output, op.as_ref())?;
             }
@@ -63,6 +71,60 @@ impl DfxOrbit {
     }
 }
 
+pub(super) fn display_request_comments(
+    comments: &ListRequestCommentsResponse,
+) -> anyhow::Result<String> {
+    let mut output = String::new();
+
+    writeln!(output, "=== COMMENTS ({}) ===", comments.total)?;
+    for comment in &comments.comments {
+        writeln!(
+            output,
+            "[{}] {}: {}",
+            comment.created_at, comment.author.name, comment.body
+        )?;
+    }
+    if comments.next_offset.is_some() {
+        writeln!(
+            output,
+            "Only the first {} comments are shown, see the request URL for the rest",
+            comments.comments.len()
+        )?;
+    }
+
+    Ok(output)
+}
+
+fn display_transfer_operation<W: Write>(
+    writer: &mut W,
+    op: &TransferOperationDTO,
+) -> anyhow::Result<()> {
+    writeln!(writer, "=== Transfer ===")?;
+    match &op.from_account {
+        Some(account) => writeln!(writer, "From: {} ({})", account.name, account.id)?,
+        None => writeln!(writer, "From: {}", op.input.from_account_id)?,
+    }
+    if let Some(spend_from) = &op.input.spend_from {
+        writeln!(writer, "Spend from: {}", spend_from)?;
+    }
+    writeln!(writer, "To: {}", op.input.to)?;
+    writeln!(writer, "Amount: {}", op.input.amount)?;
+    if let Some(fee) = op.fee.as_ref().or(op.input.fee.as_ref()) {
+        writeln!(writer, "Fee: {}", fee)?;
+    }
+    writeln!(writer, "Network: {}", op.network.name)?;
+    match &op.input.memo {
+        Some(TransferMemoDTO::Number(memo)) => writeln!(writer, "Memo: {}", memo)?,
+        Some(TransferMemoDTO::Blob(memo)) => writeln!(writer, "Memo: 0x{}", hex::encode(memo))?,
+        None => (),
+    }
+    if let Some(category) = &op.input.category {
+        writeln!(writer, "Category: {}", category)?;
+    }
+
+    Ok(())
+}
+
 fn display_approvers_and_rejectors<W: Write>(
     writer: &mut W,
     base_info: &RequestDTO,
@@ -159,6 +221,95 @@ fn display_evaluated_rule<W: Write>(
         EvaluatedRequestPolicyRuleDTO::AllowListed => {
             writeln!(writer, "The request is allow-listed")?
         }
+        EvaluatedRequestPolicyRuleDTO::AllowListedByLabel {
+            label,
+            address_book_entry_id: Some(address_book_entry_id),
+        } => writeln!(
+            writer,
+            "The destination is the address book entry {address_book_entry_id}, required label: {label}"
+        )?,
+        EvaluatedRequestPolicyRuleDTO::AllowListedByLabel {
+            label,
+            address_book_entry_id: None,
+        } => writeln!(
+            writer,
+            "The destination is not in the address book, required label: {label}"
+        )?,
+        EvaluatedRequestPolicyRuleDTO::TrustedDestination {
+            max_amount: Some(max_amount),
+            period_usage,
+        } => writeln!(
+            writer,
+            "The destination is trusted, sent in the period: {period_usage}, max amount: {max_amount}"
+        )?,
+        EvaluatedRequestPolicyRuleDTO::TrustedDestination {
+            max_amount: None, ..
+        } => writeln!(writer, "The destination is not trusted")?,
+        EvaluatedRequestPolicyRuleDTO::RecentAuthentication {
+            max_reauthentication_age_mins,
+            stale_approvers,
+        } => {
+            writeln!(
+                writer,
+                "Approvals must be made within {max_reauthentication_age_mins} minutes of re-authenticating"
+            )?;
+            if !stale_approvers.is_empty() {
+                writeln!(
+                    writer,
+                    "Approved too long after re-authenticating: {}",
+                    stale_approvers.join(", ")
+                )?;
+            }
+        }
+        EvaluatedRequestPolicyRuleDTO::VelocityLimit {
+            max_amount,
+            window_secs,
+            spent,
+        } => writeln!(
+            writer,
+            "Sent from the account in the last {window_secs} seconds: {spent}, max amount: {max_amount}"
+        )?,
+        EvaluatedRequestPolicyRuleDTO::AmountRange {
+            min_amount,
+            max_amount,
+            amount,
+        } => {
+            let bound = |bound: &Option<candid::Nat>| {
+                bound
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default()
+            };
+            match amount {
+                Some(amount) => writeln!(
+                    writer,
+                    "Amount {amount} for the range {}..{}",
+                    bound(min_amount),
+                    bound(max_amount)
+                )?,
+                None => writeln!(writer, "The request is not a transfer")?,
+            }
+        }
+        EvaluatedRequestPolicyRuleDTO::DestinationAge {
+            min_age_days,
+            first_used_at,
+        } => match first_used_at {
+            Some(first_used_at) => writeln!(
+                writer,
+                "Destination first paid at {first_used_at}, min age: {min_age_days} days"
+            )?,
+            None => writeln!(
+                writer,
+                "Destination never paid before, min age: {min_age_days} days"
+            )?,
+        },
+        EvaluatedRequestPolicyRuleDTO::Veto { vetoed_by } => {
+            if vetoed_by.is_empty() {
+                writeln!(writer, "No veto was cast")?
+            } else {
+                writeln!(writer, "Vetoed by: {}", vetoed_by.join(", "))?
+            }
+        }
         // TODO: Implement nested rules (requires some refactoring in this file)
         EvaluatedRequestPolicyRuleDTO::AnyOf(_)
         | EvaluatedRequestPolicyRuleDTO::AllOf(_)
@@ -241,6 +392,24 @@ pub(super) fn display_request_operation(op: &RequestOperationDTO) -> &'static st
         RequestOperationDTO::EditRequestPolicy(_) => "EditRequestPolicy",
         RequestOperationDTO::RemoveRequestPolicy(_) => "RemoveRequestPolicy",
         RequestOperationDTO::ManageSystemInfo(_) => "ManageSystemInfo",
+        RequestOperationDTO::Approve(_) => "Approve",
+        RequestOperationDTO::SetAutoApprovalForTrustedDestinations(_) => {
+            "SetAutoApprovalForTrustedDestinations"
+        }
+        RequestOperationDTO::TransferNft(_) => "TransferNft",
+        RequestOperationDTO::DeriveSubaccount(_) => "DeriveSubaccount",
+        RequestOperationDTO::ImportAccessPolicies(_) => "ImportAccessPolicies",
+        RequestOperationDTO::AddAsset(_) => "AddAsset",
+        RequestOperationDTO::EditAsset(_) => "EditAsset",
+        RequestOperationDTO::RemoveAsset(_) => "RemoveAsset",
+        RequestOperationDTO::SwapTokens(_) => "SwapTokens",
+        RequestOperationDTO::AddScheduledTransfer(_) => "AddScheduledTransfer",
+        RequestOperationDTO::AddTeam(_) => "AddTeam",
+        RequestOperationDTO::SweepAccount(_) => "SweepAccount",
+        RequestOperationDTO::SplitTransfer(_) => "SplitTransfer",
+        RequestOperationDTO::ManageAddressBookLabels(_) => "ManageAddressBookLabels",
+        RequestOperationDTO::CreateCapabilityGrant(_) => "CreateCapabilityGrant",
+        RequestOperationDTO::RevokeCapabilityGrant(_) => "RevokeCapabilityGrant",
     }
 }
 
@@ -254,6 +423,7 @@ pub(super) fn display_request_status(status: &RequestStatusDTO) -> &'static str
         RequestStatusDTO::Processing { .. } => "Processing",
         RequestStatusDTO::Completed { .. } => "Completed",
         RequestStatusDTO::Failed { .. } => "Failed",
+        RequestStatusDTO::Validating => "Validating",
     }
 }
 
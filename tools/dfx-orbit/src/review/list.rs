@@ -49,24 +49,34 @@ impl DfxOrbit {
         &self,
         args: &ReviewListArgs,
     ) -> anyhow::Result<ListRequestsResponse> {
-        let initial_request = self.initial_request(args);
+        self.parallel_fetch_requests(
+            self.initial_request(args),
+            args.offset,
+            args.chunk_size,
+            args.limit,
+        )
+        .await
+    }
+
+    /// Fetch all the requests matching the filters of the initial request, in chunks in parallel
+    pub(super) async fn parallel_fetch_requests(
+        &self,
+        initial_request: ListRequestsInput,
+        offset: u64,
+        chunk_size: u16,
+        limit: Option<u64>,
+    ) -> anyhow::Result<ListRequestsResponse> {
         let total = self
             .station
             .review_list(initial_request.clone())
             .await?
             .total;
 
-        let requests = self.generate_requests(
-            &initial_request,
-            total,
-            args.offset,
-            args.chunk_size,
-            args.limit,
-        );
+        let requests = self.generate_requests(&initial_request, total, offset, chunk_size, limit);
         debug!(
             self.logger,
             "There are {} entries, which will be fetched in {} parallel requests",
-            total - args.offset,
+            total.saturating_sub(offset),
             requests.len()
         );
 
@@ -99,6 +109,7 @@ impl DfxOrbit {
             sort_by: Some(ListRequestsSortBy::CreatedAt(SortDirection::Desc)),
             only_approvable: args.only_approvable,
             with_evaluation_results: true,
+            tags: None,
         }
     }
 
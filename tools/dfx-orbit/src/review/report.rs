@@ -0,0 +1,206 @@
+use super::display::{display_request_operation, display_request_status};
+use crate::DfxOrbit;
+use clap::Parser;
+use station_api::{
+    ListRequestsInput, ListRequestsResponse, ListRequestsSortBy, PaginationInput,
+    RequestApprovalStatusDTO, RequestDTO, RequestStatusDTO, SortDirection,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    path::PathBuf,
+};
+
+/// Generates a markdown report of the requests created in a period.
+#[derive(Debug, Clone, Parser)]
+pub struct ReviewReportArgs {
+    /// Include the requests created since this date (e.g. "2024-10-01" or "2024-10-01T12:00:00Z")
+    #[clap(long)]
+    pub(crate) since: String,
+
+    /// Include the requests created until this date, defaults to now
+    #[clap(long)]
+    pub(crate) until: Option<String>,
+
+    /// Write the report to this file instead of printing it
+    #[clap(short, long)]
+    pub(crate) output: Option<PathBuf>,
+
+    /// Fetch the values in chunks of this size
+    #[clap(long, default_value = "20")]
+    pub(crate) chunk_size: u16,
+}
+
+impl DfxOrbit {
+    /// Fetch all the requests created in the period of the report, oldest first
+    pub(super) async fn fetch_report_requests(
+        &self,
+        args: &ReviewReportArgs,
+    ) -> anyhow::Result<ListRequestsResponse> {
+        let initial_request = ListRequestsInput {
+            requester_ids: None,
+            approver_ids: None,
+            statuses: None,
+            operation_types: None,
+            expiration_from_dt: None,
+            expiration_to_dt: None,
+            created_from_dt: Some(parse_report_date(&args.since)?),
+            created_to_dt: args.until.as_deref().map(parse_report_date).transpose()?,
+            paginate: Some(PaginationInput {
+                offset: Some(0),
+                limit: Some(0),
+            }),
+            sort_by: Some(ListRequestsSortBy::CreatedAt(SortDirection::Asc)),
+            only_approvable: false,
+            with_evaluation_results: false,
+            tags: None,
+        };
+
+        self.parallel_fetch_requests(initial_request, 0, args.chunk_size, None)
+            .await
+    }
+
+    pub(super) fn display_report(
+        &self,
+        args: &ReviewReportArgs,
+        data: ListRequestsResponse,
+    ) -> anyhow::Result<String> {
+        let add_info = data
+            .additional_info
+            .into_iter()
+            .map(|info| (info.id.clone(), info))
+            .collect::<HashMap<String, _>>();
+
+        let mut output = String::new();
+
+        writeln!(output, "# Requests report of {}", self.station.config.name)?;
+        writeln!(output)?;
+        writeln!(
+            output,
+            "- Station: `{}`",
+            self.station.config.station_id.to_text()
+        )?;
+        writeln!(
+            output,
+            "- Period: {} to {}",
+            args.since,
+            args.until.as_deref().unwrap_or("now")
+        )?;
+        writeln!(output, "- Requests: {}", data.requests.len())?;
+        writeln!(output)?;
+
+        if data.requests.is_empty() {
+            writeln!(output, "No requests were created in this period.")?;
+            return Ok(output);
+        }
+
+        let mut outcomes = BTreeMap::<&str, usize>::new();
+        for request in &data.requests {
+            *outcomes
+                .entry(display_request_status(&request.status))
+                .or_default() += 1;
+        }
+
+        writeln!(output, "## Outcomes")?;
+        writeln!(output)?;
+        writeln!(output, "| Outcome | Requests |")?;
+        writeln!(output, "| --- | --- |")?;
+        for (outcome, count) in outcomes {
+            writeln!(output, "| {} | {} |", outcome, count)?;
+        }
+        writeln!(output)?;
+
+        writeln!(output, "## Requests")?;
+        writeln!(output)?;
+        writeln!(
+            output,
+            "| Created | Title | Operation | Outcome | Requested by | Approved by | Rejected by | Link |"
+        )?;
+        writeln!(output, "| --- | --- | --- | --- | --- | --- | --- | --- |")?;
+
+        for request in &data.requests {
+            let info = add_info.get(&request.id);
+            let user_name = |user_id: &String| {
+                info.and_then(|info| info.approvers.iter().find(|user| user.id == *user_id))
+                    .map(|user| user.name.clone())
+                    .unwrap_or_else(|| user_id.clone())
+            };
+
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} | {} | {} | [{}]({}) |",
+                request.created_at,
+                escape_cell(&request.title),
+                display_request_operation(&request.operation),
+                escape_cell(&display_outcome(&request.status)),
+                escape_cell(
+                    &info
+                        .map(|info| info.requester_name.clone())
+                        .unwrap_or_else(|| request.requested_by.clone())
+                ),
+                escape_cell(&approvers(
+                    request,
+                    RequestApprovalStatusDTO::Approved,
+                    user_name
+                )),
+                escape_cell(&approvers(
+                    request,
+                    RequestApprovalStatusDTO::Rejected,
+                    user_name
+                )),
+                request.id,
+                self.station.request_url(&request.id)
+            )?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// Parses a date in one of the formats supported by `dateparser` into a RFC 3339 timestamp.
+fn parse_report_date(date: &str) -> anyhow::Result<String> {
+    let date = dateparser::parse(date)
+        .map_err(|err| anyhow::anyhow!("Invalid date \"{}\": {}", date, err))?;
+
+    Ok(date.to_rfc3339())
+}
+
+/// The status of the request, with the reason it was cancelled or failed if any.
+fn display_outcome(status: &RequestStatusDTO) -> String {
+    let reason = match status {
+        RequestStatusDTO::Cancelled { reason } | RequestStatusDTO::Failed { reason } => {
+            reason.as_deref()
+        }
+        _ => None,
+    };
+
+    match reason {
+        Some(reason) => format!("{}: {}", display_request_status(status), reason),
+        None => display_request_status(status).to_string(),
+    }
+}
+
+/// The names of the users who submitted the given decision on the request, separated by commas.
+fn approvers(
+    request: &RequestDTO,
+    decision: RequestApprovalStatusDTO,
+    user_name: impl Fn(&String) -> String,
+) -> String {
+    let names = request
+        .approvals
+        .iter()
+        .filter(|approval| approval.status == decision)
+        .map(|approval| user_name(&approval.approver_id))
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        String::from("-")
+    } else {
+        names.join(", ")
+    }
+}
+
+/// Escapes the characters that would break the layout of a markdown table cell.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\n', '\r'], " ")
+}
@@ -1,16 +1,22 @@
 //! A dfx and IC agent for communicating with an Orbit station.
 
 mod agent;
+mod backup;
 mod config;
 mod error;
+mod restore;
 
-use crate::dfx::OrbitExtensionAgent;
+use crate::{dfx::OrbitExtensionAgent, DfxOrbit};
 use anyhow::Context;
 use candid::Principal;
 use clap::{Parser, Subcommand};
 use std::fmt::{self, Display, Formatter};
 
-pub use self::agent::{StationAgent, StationAgentResult, StationConfig};
+pub use self::{
+    agent::{StationAgent, StationAgentResult, StationConfig},
+    backup::StationBackupArgs,
+    restore::StationRestoreArgs,
+};
 
 /// Station management commands
 #[derive(Debug, Subcommand)]
@@ -30,6 +36,10 @@ pub enum StationArgs {
     Edit(StationEditArgs),
     /// Removes an Orbit station from the local dfx configuration
     Remove(StationRemoveArgs),
+    /// Downloads the governance state of the station to a backup file
+    Backup(StationBackupArgs),
+    /// Restores the governance state of the station from a backup file, by requesting the changes
+    Restore(StationRestoreArgs),
 }
 
 /// Adds an Orbit station to the local dfx configuration
@@ -123,6 +133,20 @@ pub struct StationUseArgs {
 }
 
 impl StationArgs {
+    /// Whether the command calls the station, rather than only managing the local dfx configuration.
+    pub(crate) fn calls_station(&self) -> bool {
+        matches!(self, StationArgs::Backup(_) | StationArgs::Restore(_))
+    }
+
+    /// Implements the CLI commands that call the station.
+    pub(crate) async fn execute_on_station(self, dfx_orbit: &DfxOrbit) -> anyhow::Result<()> {
+        match self {
+            StationArgs::Backup(backup_args) => backup_args.execute(dfx_orbit).await,
+            StationArgs::Restore(restore_args) => restore_args.execute(dfx_orbit).await,
+            _ => unreachable!(),
+        }
+    }
+
     /// Implements CLI commands for managing Orbit stations.
     pub(crate) fn execute(self, orbit_agent: OrbitExtensionAgent) -> anyhow::Result<()> {
         match self {
@@ -178,6 +202,7 @@ impl StationArgs {
                     )
                     .with_context(|| "Failed to rename station in local dfx config")?;
             }
+            StationArgs::Backup(_) | StationArgs::Restore(_) => unreachable!(),
         }
         Ok(())
     }
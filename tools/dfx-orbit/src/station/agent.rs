@@ -2,10 +2,14 @@ pub use crate::station::{config::StationConfig, error::StationAgentResult};
 use candid::CandidType;
 use ic_agent::{agent::UpdateBuilder, Agent};
 use station_api::{
-    ApiErrorDTO, CreateRequestInput, CreateRequestResponse, GetNextApprovableRequestInput,
-    GetNextApprovableRequestResponse, GetRequestInput, GetRequestResponse, ListRequestsInput,
-    ListRequestsResponse, MeResponse, RequestApprovalStatusDTO, SubmitRequestApprovalInput,
-    SubmitRequestApprovalResponse,
+    AddRequestCommentInput, AddRequestCommentResponse, ApiErrorDTO, CapabilitiesResponse,
+    CreateRequestInput, CreateRequestResponse, GetAccountInput, GetAccountResponse,
+    GetNextApprovableRequestInput, GetNextApprovableRequestResponse, GetRequestInput,
+    GetRequestResponse, HealthStatus, ListPermissionsInput, ListPermissionsResponse,
+    ListRequestCommentsInput, ListRequestCommentsResponse, ListRequestPoliciesInput,
+    ListRequestPoliciesResponse, ListRequestsInput, ListRequestsResponse, ListUserGroupsInput,
+    ListUserGroupsResponse, ListUsersInput, ListUsersResponse, MeResponse, PaginationInput,
+    RequestApprovalStatusDTO, SubmitRequestApprovalInput, SubmitRequestApprovalResponse,
 };
 
 /// A dfx agent for communicating with a specific station.
@@ -63,10 +67,30 @@ impl StationAgent {
         Ok(())
     }
 
+    pub async fn account(&self, args: GetAccountInput) -> StationAgentResult<GetAccountResponse> {
+        self.update_orbit_typed("get_account", args).await
+    }
+
     pub async fn me(&self) -> StationAgentResult<MeResponse> {
         self.update_orbit_typed("me", ()).await
     }
 
+    /// Checks that the station is reachable and initialized.
+    pub async fn health_status(&self) -> StationAgentResult<HealthStatus> {
+        let response_bytes = self
+            .agent
+            .query(&self.config.station_id, "health_status")
+            .with_arg(candid::encode_one(())?)
+            .call()
+            .await?;
+
+        Ok(candid::decode_one(&response_bytes)?)
+    }
+
+    pub async fn capabilities(&self) -> StationAgentResult<CapabilitiesResponse> {
+        self.update_orbit_typed("capabilities", ()).await
+    }
+
     pub async fn review_id(&self, args: GetRequestInput) -> StationAgentResult<GetRequestResponse> {
         self.update_orbit_typed("get_request", args).await
     }
@@ -86,6 +110,61 @@ impl StationAgent {
             .await
     }
 
+    pub async fn comment(
+        &self,
+        request_id: String,
+        body: String,
+    ) -> StationAgentResult<AddRequestCommentResponse> {
+        self.update_orbit_typed(
+            "add_request_comment",
+            AddRequestCommentInput { request_id, body },
+        )
+        .await
+    }
+
+    /// Fetches the first page of the comments of the request, the oldest first.
+    pub async fn review_comments(
+        &self,
+        request_id: String,
+    ) -> StationAgentResult<ListRequestCommentsResponse> {
+        self.update_orbit_typed(
+            "list_request_comments",
+            ListRequestCommentsInput {
+                request_id,
+                paginate: Some(PaginationInput {
+                    offset: None,
+                    limit: Some(100),
+                }),
+            },
+        )
+        .await
+    }
+
+    pub async fn list_users(&self, args: ListUsersInput) -> StationAgentResult<ListUsersResponse> {
+        self.update_orbit_typed("list_users", args).await
+    }
+
+    pub async fn list_user_groups(
+        &self,
+        args: ListUserGroupsInput,
+    ) -> StationAgentResult<ListUserGroupsResponse> {
+        self.update_orbit_typed("list_user_groups", args).await
+    }
+
+    pub async fn list_permissions(
+        &self,
+        args: ListPermissionsInput,
+    ) -> StationAgentResult<ListPermissionsResponse> {
+        self.update_orbit_typed("list_permissions", args).await
+    }
+
+    pub async fn list_request_policies(
+        &self,
+        args: ListRequestPoliciesInput,
+    ) -> StationAgentResult<ListRequestPoliciesResponse> {
+        self.update_orbit_typed("list_request_policies", args).await
+    }
+
     async fn update_orbit(&self, method_name: &str) -> UpdateBuilder {
         self.agent.update(&self.config.station_id, method_name)
     }
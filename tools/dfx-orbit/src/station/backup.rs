@@ -0,0 +1,259 @@
+use crate::DfxOrbit;
+use anyhow::{bail, Context};
+use candid::Principal;
+use clap::Parser;
+use orbit_essentials::utils::timestamp_to_rfc3339;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use station_api::{
+    ListPermissionsInput, ListUserGroupsInput, ListUsersInput, PaginationInput, PermissionDTO,
+    RequestPolicyDTO, UserDTO, UserGroupDTO,
+};
+use std::{
+    future::Future,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Downloads the governance state of the station to a backup file
+#[derive(Debug, Parser)]
+pub struct StationBackupArgs {
+    /// The file to write the backup to
+    pub(crate) file: PathBuf,
+}
+
+/// The governance state of a station: its user groups, users, permissions and request policies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceState {
+    pub user_groups: Vec<UserGroupDTO>,
+    pub users: Vec<UserDTO>,
+    pub permissions: Vec<PermissionDTO>,
+    pub request_policies: Vec<RequestPolicyDTO>,
+}
+
+impl GovernanceState {
+    /// The hex encoded sha256 hash of the JSON encoding of the state.
+    fn checksum(&self) -> anyhow::Result<String> {
+        let encoded = serde_json::to_vec(self).with_context(|| "Failed to encode the state")?;
+
+        Ok(hex::encode(Sha256::digest(encoded)))
+    }
+}
+
+/// An off-chain copy of the governance state of a station, with the checksum of the state so that a
+/// corrupted or edited backup is never restored.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StationBackup {
+    pub version: u32,
+    pub station_id: Principal,
+    pub created_at: String,
+    pub checksum: String,
+    pub state: GovernanceState,
+}
+
+impl StationBackup {
+    pub const VERSION: u32 = 1;
+
+    fn new(station_id: Principal, state: GovernanceState) -> anyhow::Result<Self> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+
+        Ok(Self {
+            version: Self::VERSION,
+            station_id,
+            created_at: timestamp_to_rfc3339(&now),
+            checksum: state.checksum()?,
+            state,
+        })
+    }
+
+    /// Reads the backup from the file, failing if its content does not match its checksum.
+    pub(crate) fn read(file: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read(file)
+            .with_context(|| format!("Failed to read the backup {}", file.display()))?;
+        let backup: Self = serde_json::from_slice(&content)
+            .with_context(|| format!("{} is not a station backup", file.display()))?;
+
+        if backup.version != Self::VERSION {
+            bail!(
+                "The backup {} has the unsupported version {}",
+                file.display(),
+                backup.version
+            );
+        }
+
+        if backup.state.checksum()? != backup.checksum {
+            bail!(
+                "The backup {} is corrupted, its content does not match its checksum",
+                file.display()
+            );
+        }
+
+        Ok(backup)
+    }
+
+    fn write(&self, file: &Path) -> anyhow::Result<()> {
+        let content =
+            serde_json::to_vec_pretty(self).with_context(|| "Failed to encode the backup")?;
+
+        std::fs::write(file, content)
+            .with_context(|| format!("Failed to write the backup {}", file.display()))
+    }
+}
+
+/// A progress bar printed on stderr, which is redrawn in place as the chunks are transferred.
+pub(crate) struct ProgressBar {
+    label: String,
+    total: usize,
+}
+
+impl ProgressBar {
+    const WIDTH: usize = 30;
+
+    pub(crate) fn new(label: &str, total: usize) -> Self {
+        Self {
+            label: label.to_string(),
+            total,
+        }
+    }
+
+    pub(crate) fn update(&self, done: usize) {
+        let filled = match self.total {
+            0 => Self::WIDTH,
+            total => done.min(total) * Self::WIDTH / total,
+        };
+
+        eprint!(
+            "\r{:<24} [{}{}] {}/{}",
+            self.label,
+            "#".repeat(filled),
+            "-".repeat(Self::WIDTH - filled),
+            done,
+            self.total
+        );
+        let _ = std::io::stderr().flush();
+    }
+
+    pub(crate) fn finish(&self) {
+        eprintln!();
+    }
+}
+
+impl DfxOrbit {
+    /// The number of entries downloaded per call to the list endpoints of the station.
+    const BACKUP_CHUNK_SIZE: u16 = 100;
+
+    /// Downloads the governance state of the station through its paginated list endpoints.
+    pub(crate) async fn download_governance_state(&self) -> anyhow::Result<GovernanceState> {
+        let user_groups = download_chunks("User groups", |paginate| async move {
+            let response = self
+                .station
+                .list_user_groups(ListUserGroupsInput {
+                    search_term: None,
+                    paginate: Some(paginate),
+                })
+                .await?;
+
+            Ok((response.user_groups, response.next_offset, response.total))
+        })
+        .await?;
+
+        let users = download_chunks("Users", |paginate| async move {
+            let response = self
+                .station
+                .list_users(ListUsersInput {
+                    search_term: None,
+                    statuses: None,
+                    groups: None,
+                    paginate: Some(paginate),
+                })
+                .await?;
+
+            Ok((response.users, response.next_offset, response.total))
+        })
+        .await?;
+
+        let permissions = download_chunks("Permissions", |paginate| async move {
+            let response = self
+                .station
+                .list_permissions(ListPermissionsInput {
+                    resources: None,
+                    paginate: Some(paginate),
+                })
+                .await?;
+
+            Ok((response.permissions, response.next_offset, response.total))
+        })
+        .await?;
+
+        let request_policies = download_chunks("Request policies", |paginate| async move {
+            let response = self.station.list_request_policies(paginate).await?;
+
+            Ok((response.policies, response.next_offset, response.total))
+        })
+        .await?;
+
+        Ok(GovernanceState {
+            user_groups,
+            users,
+            permissions,
+            request_policies,
+        })
+    }
+}
+
+/// Downloads all the chunks of a paginated list, `fetch` returns the entries of the chunk, the offset
+/// of the next chunk and the total number of entries.
+async fn download_chunks<T, F, Fut>(label: &str, fetch: F) -> anyhow::Result<Vec<T>>
+where
+    F: Fn(PaginationInput) -> Fut,
+    Fut: Future<Output = anyhow::Result<(Vec<T>, Option<u64>, u64)>>,
+{
+    let mut entries = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let (chunk, next_offset, total) = fetch(PaginationInput {
+            offset: Some(offset),
+            limit: Some(DfxOrbit::BACKUP_CHUNK_SIZE),
+        })
+        .await?;
+
+        entries.extend(chunk);
+        let progress = ProgressBar::new(label, total as usize);
+        progress.update(entries.len());
+
+        match next_offset {
+            Some(next_offset) => offset = next_offset,
+            None => {
+                progress.finish();
+                break;
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+impl StationBackupArgs {
+    pub(crate) async fn execute(self, dfx_orbit: &DfxOrbit) -> anyhow::Result<()> {
+        let state = dfx_orbit.download_governance_state().await?;
+        let backup = StationBackup::new(dfx_orbit.station.config.station_id, state)?;
+        backup.write(&self.file)?;
+
+        // Read the file back, so that a backup that could not be restored is reported right away
+        let backup = StationBackup::read(&self.file)?;
+
+        println!(
+            "Backed up {} user groups, {} users, {} permissions and {} request policies to {}",
+            backup.state.user_groups.len(),
+            backup.state.users.len(),
+            backup.state.permissions.len(),
+            backup.state.request_policies.len(),
+            self.file.display()
+        );
+        println!("Checksum: {}", backup.checksum);
+
+        Ok(())
+    }
+}
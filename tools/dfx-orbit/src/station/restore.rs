@@ -0,0 +1,288 @@
+use super::backup::{GovernanceState, ProgressBar, StationBackup};
+use crate::DfxOrbit;
+use anyhow::{bail, Context};
+use clap::Parser;
+use serde::Serialize;
+use station_api::{
+    AddRequestPolicyOperationInput, AddUserGroupOperationInput, AddUserOperationInput,
+    CreateRequestInput, EditPermissionOperationInput, EditRequestPolicyOperationInput,
+    EditUserGroupOperationInput, EditUserOperationInput, RequestOperationInput,
+    RequestPolicyExpirationInput, UserDTO,
+};
+use std::path::PathBuf;
+
+/// Restores the governance state of the station from a backup file, by requesting the changes
+#[derive(Debug, Parser)]
+pub struct StationRestoreArgs {
+    /// The backup file to restore
+    pub(crate) file: PathBuf,
+}
+
+/// A change to request so that the station matches the backed up state again.
+struct RestoreChange {
+    description: String,
+    operation: RequestOperationInput,
+}
+
+/// Checks if both values have the same JSON encoding, as the DTOs do not implement `PartialEq`.
+fn same<T: Serialize>(left: &T, right: &T) -> anyhow::Result<bool> {
+    Ok(serde_json::to_value(left)? == serde_json::to_value(right)?)
+}
+
+fn user_group_ids(user: &UserDTO) -> Vec<String> {
+    user.groups.iter().map(|group| group.id.clone()).collect()
+}
+
+/// Lists the changes that bring the current state back to the backed up state.
+///
+/// The entries created after the backup are kept, and the removed ones are added back with new ids.
+fn restore_changes(
+    backup: &GovernanceState,
+    current: &GovernanceState,
+) -> anyhow::Result<Vec<RestoreChange>> {
+    let mut changes = Vec::new();
+
+    for user_group in &backup.user_groups {
+        let existing = current
+            .user_groups
+            .iter()
+            .find(|existing| existing.id == user_group.id);
+
+        match existing {
+            Some(existing) if existing.name == user_group.name => {}
+            Some(_) => changes.push(RestoreChange {
+                description: format!("the name of the user group \"{}\"", user_group.name),
+                operation: RequestOperationInput::EditUserGroup(EditUserGroupOperationInput {
+                    user_group_id: user_group.id.clone(),
+                    name: user_group.name.clone(),
+                    add_members: None,
+                    remove_members: None,
+                }),
+            }),
+            None => changes.push(RestoreChange {
+                description: format!("the user group \"{}\"", user_group.name),
+                operation: RequestOperationInput::AddUserGroup(AddUserGroupOperationInput {
+                    name: user_group.name.clone(),
+                }),
+            }),
+        }
+    }
+
+    for user in &backup.users {
+        let existing = current.users.iter().find(|existing| existing.id == user.id);
+
+        match existing {
+            Some(existing)
+                if same(
+                    &(
+                        &existing.name,
+                        &existing.identities,
+                        &existing.approve_only_identities,
+                        &existing.status,
+                    ),
+                    &(
+                        &user.name,
+                        &user.identities,
+                        &user.approve_only_identities,
+                        &user.status,
+                    ),
+                )? && user_group_ids(existing) == user_group_ids(user) => {}
+            Some(_) => changes.push(RestoreChange {
+                description: format!("the user \"{}\"", user.name),
+                operation: RequestOperationInput::EditUser(EditUserOperationInput {
+                    id: user.id.clone(),
+                    name: Some(user.name.clone()),
+                    identities: Some(user.identities.clone()),
+                    groups: Some(user_group_ids(user)),
+                    status: Some(user.status.clone()),
+                    cancel_pending_requests: None,
+                    approve_only_identities: Some(user.approve_only_identities.clone()),
+                }),
+            }),
+            None => changes.push(RestoreChange {
+                description: format!("the user \"{}\"", user.name),
+                operation: RequestOperationInput::AddUser(AddUserOperationInput {
+                    name: user.name.clone(),
+                    identities: user.identities.clone(),
+                    groups: user_group_ids(user),
+                    status: user.status.clone(),
+                    approve_only_identities: Some(user.approve_only_identities.clone()),
+                }),
+            }),
+        }
+    }
+
+    for permission in &backup.permissions {
+        let mut existing = None;
+        for candidate in &current.permissions {
+            if same(&candidate.resource, &permission.resource)? {
+                existing = Some(candidate);
+                break;
+            }
+        }
+
+        if let Some(existing) = existing {
+            if same(&existing.allow, &permission.allow)? {
+                continue;
+            }
+        }
+
+        changes.push(RestoreChange {
+            description: format!(
+                "the permission of {}",
+                serde_json::to_string(&permission.resource)?
+            ),
+            operation: RequestOperationInput::EditPermission(EditPermissionOperationInput {
+                resource: permission.resource.clone(),
+                auth_scope: Some(permission.allow.auth_scope.clone()),
+                users: Some(permission.allow.users.clone()),
+                user_groups: Some(permission.allow.user_groups.clone()),
+                effective_from: None,
+            }),
+        });
+    }
+
+    for policy in &backup.request_policies {
+        let existing = current
+            .request_policies
+            .iter()
+            .find(|existing| existing.id == policy.id);
+
+        match existing {
+            Some(existing) if same(existing, policy)? => {}
+            Some(_) => changes.push(RestoreChange {
+                description: format!("the request policy {}", policy.id),
+                operation: RequestOperationInput::EditRequestPolicy(
+                    EditRequestPolicyOperationInput {
+                        policy_id: policy.id.clone(),
+                        specifier: Some(policy.specifier.clone()),
+                        rule: Some(policy.rule.clone()),
+                        expiration_period_secs: Some(match policy.expiration_period_secs {
+                            Some(secs) => RequestPolicyExpirationInput::Set(secs),
+                            None => RequestPolicyExpirationInput::Remove,
+                        }),
+                        effective_from: None,
+                    },
+                ),
+            }),
+            None => changes.push(RestoreChange {
+                description: format!("the request policy {}", policy.id),
+                operation: RequestOperationInput::AddRequestPolicy(
+                    AddRequestPolicyOperationInput {
+                        specifier: policy.specifier.clone(),
+                        rule: policy.rule.clone(),
+                        expiration_period_secs: policy.expiration_period_secs,
+                        effective_from: None,
+                    },
+                ),
+            }),
+        }
+    }
+
+    Ok(changes)
+}
+
+impl DfxOrbit {
+    /// The number of restore requests created concurrently.
+    const RESTORE_CHUNK_SIZE: usize = 10;
+
+    /// Creates a request for every change, returning the ids of the created requests and the changes
+    /// that could not be requested with their error.
+    async fn request_changes(
+        &self,
+        changes: &[RestoreChange],
+        backup: &StationBackup,
+    ) -> (Vec<String>, Vec<String>) {
+        let progress = ProgressBar::new("Requesting changes", changes.len());
+        let mut request_ids = Vec::new();
+        let mut failures = Vec::new();
+
+        for (index, chunk) in changes.chunks(Self::RESTORE_CHUNK_SIZE).enumerate() {
+            let responses = futures::future::join_all(chunk.iter().map(|change| {
+                self.station.request(CreateRequestInput {
+                    operation: change.operation.clone(),
+                    title: Some(format!("Restore {}", change.description)),
+                    summary: Some(format!(
+                        "Restores {} from the backup taken at {}.",
+                        change.description, backup.created_at
+                    )),
+                    execution_plan: None,
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                })
+            }))
+            .await;
+
+            for (change, response) in chunk.iter().zip(responses) {
+                match response {
+                    Ok(response) => request_ids.push(response.request.id),
+                    Err(err) => failures.push(format!("{}: {}", change.description, err)),
+                }
+            }
+
+            progress.update((index * Self::RESTORE_CHUNK_SIZE + chunk.len()).min(changes.len()));
+        }
+        progress.finish();
+
+        (request_ids, failures)
+    }
+}
+
+impl StationRestoreArgs {
+    pub(crate) async fn execute(self, dfx_orbit: &DfxOrbit) -> anyhow::Result<()> {
+        let backup = StationBackup::read(&self.file)?;
+
+        let station_id = dfx_orbit.station.config.station_id;
+        if backup.station_id != station_id {
+            bail!(
+                "The backup was taken from the station {}, not from {}",
+                backup.station_id,
+                station_id
+            );
+        }
+
+        let current = dfx_orbit.download_governance_state().await?;
+        let changes = restore_changes(&backup.state, &current)?;
+
+        if changes.is_empty() {
+            println!(
+                "The station matches the backup taken at {}",
+                backup.created_at
+            );
+            return Ok(());
+        }
+
+        println!(
+            "Restoring the backup taken at {} requests:",
+            backup.created_at
+        );
+        for change in &changes {
+            println!("  - {}", change.description);
+        }
+        dfx_core::cli::ask_for_consent(&format!("Create {} requests?", changes.len()))
+            .with_context(|| "Restore cancelled")?;
+
+        let (request_ids, failures) = dfx_orbit.request_changes(&changes, &backup).await;
+
+        for request_id in &request_ids {
+            println!("Created request: {request_id}");
+        }
+        if !request_ids.is_empty() {
+            println!("To review the requests, run: dfx-orbit review list --all");
+        }
+
+        if !failures.is_empty() {
+            for failure in &failures {
+                eprintln!("Failed to request {failure}");
+            }
+            bail!(
+                "{} of {} changes could not be requested",
+                failures.len(),
+                changes.len()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+//! Makes `Transfer` requests to Orbit from a CSV file of payments.
+
+use crate::DfxOrbit;
+use anyhow::{anyhow, bail, Context};
+use candid::{Nat, Principal};
+use clap::Parser;
+use station_api::{
+    AccountDTO, CreateRequestInput, GetAccountInput, RequestExecutionScheduleDTO,
+    RequestOperationInput, TransferMemoDTO, TransferOperationInput,
+};
+use std::path::PathBuf;
+use tabled::{
+    settings::{Settings, Style},
+    Table,
+};
+
+/// Requests transfers from an account, one request per row of a CSV file.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestTransferArgs {
+    /// The ID of the account to send the transfers from
+    #[clap(long)]
+    pub from: String,
+
+    /// A CSV file with a `destination,amount,memo` row per transfer, the memo is optional and a
+    /// header row is skipped. Amounts are in the asset's units (e.g. `1.5` ICP).
+    #[clap(long)]
+    pub csv: PathBuf,
+}
+
+/// A payment read from the CSV file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PaymentRow {
+    line: usize,
+    destination: String,
+    amount: String,
+    memo: Option<String>,
+}
+
+impl RequestTransferArgs {
+    /// Validates all the rows of the file before creating any request, then creates one transfer
+    /// request per row and prints what was submitted.
+    pub(crate) async fn execute(
+        self,
+        dfx_orbit: &DfxOrbit,
+        title: Option<String>,
+        summary: Option<String>,
+        execution_plan: Option<RequestExecutionScheduleDTO>,
+    ) -> anyhow::Result<()> {
+        let content = std::fs::read_to_string(&self.csv)
+            .with_context(|| format!("Failed to read {}", self.csv.display()))?;
+        let rows = parse_payment_rows(&content)?;
+        if rows.is_empty() {
+            bail!("{} does not contain any payment", self.csv.display());
+        }
+
+        let account = dfx_orbit
+            .station
+            .account(GetAccountInput {
+                account_id: self.from.clone(),
+            })
+            .await?
+            .account;
+
+        let mut transfers = Vec::with_capacity(rows.len());
+        let mut errors = Vec::new();
+        for row in &rows {
+            match to_transfer_input(&account, row) {
+                Ok(transfer) => transfers.push(transfer),
+                Err(err) => errors.push(format!("line {}: {err}", row.line)),
+            }
+        }
+        if !errors.is_empty() {
+            bail!(
+                "No request was created, the file has invalid rows:\n{}",
+                errors.join("\n")
+            );
+        }
+
+        let mut submitted = Vec::with_capacity(rows.len());
+        let mut failed = 0;
+        for (row, transfer) in rows.iter().zip(transfers) {
+            let result = dfx_orbit
+                .station
+                .request(CreateRequestInput {
+                    operation: RequestOperationInput::Transfer(transfer),
+                    title: title.clone(),
+                    summary: summary.clone(),
+                    execution_plan: execution_plan.clone(),
+                    confidential: None,
+                    tags: None,
+                    depends_on: None,
+                })
+                .await;
+
+            let (request_id, status) = match result {
+                Ok(response) => (response.request.id, String::from("Created")),
+                Err(err) => {
+                    failed += 1;
+                    (String::from("-"), format!("Failed: {err}"))
+                }
+            };
+
+            submitted.push([
+                row.line.to_string(),
+                row.destination.clone(),
+                format!("{} {}", row.amount, account.symbol),
+                row.memo.clone().unwrap_or(String::from("-")),
+                request_id,
+                status,
+            ]);
+        }
+
+        println!("{}", display_submitted(submitted));
+
+        if failed > 0 {
+            bail!("{failed} of {} transfer requests failed", rows.len());
+        }
+
+        Ok(())
+    }
+}
+
+fn display_submitted(rows: Vec<[String; 6]>) -> String {
+    let titled_iter = std::iter::once([
+        String::from("Line"),
+        String::from("Destination"),
+        String::from("Amount"),
+        String::from("Memo"),
+        String::from("Request ID"),
+        String::from("Status"),
+    ])
+    .chain(rows);
+
+    let table_config = Settings::default().with(Style::psql());
+    Table::from_iter(titled_iter).with(table_config).to_string()
+}
+
+/// Parses the `destination,amount,memo` rows, skipping the empty lines and the header row.
+fn parse_payment_rows(content: &str) -> anyhow::Result<Vec<PaymentRow>> {
+    let mut rows = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+
+        if fields.iter().all(|field| field.is_empty()) {
+            continue;
+        }
+
+        if rows.is_empty() && fields[0].eq_ignore_ascii_case("destination") {
+            continue;
+        }
+
+        let (destination, amount, memo) = match fields.as_slice() {
+            [destination, amount] => (destination, amount, None),
+            [destination, amount, memo] => (
+                destination,
+                amount,
+                Some(memo).filter(|memo| !memo.is_empty()),
+            ),
+            _ => bail!(
+                "line {line_number}: expected `destination,amount,memo`, found {} columns",
+                fields.len()
+            ),
+        };
+
+        rows.push(PaymentRow {
+            line: line_number,
+            destination: destination.to_string(),
+            amount: amount.to_string(),
+            memo: memo.map(|memo| memo.to_string()),
+        });
+    }
+
+    Ok(rows)
+}
+
+fn to_transfer_input(
+    account: &AccountDTO,
+    row: &PaymentRow,
+) -> anyhow::Result<TransferOperationInput> {
+    validate_address(&account.blockchain, &account.standard, &row.destination)?;
+
+    Ok(TransferOperationInput {
+        from_account_id: account.id.clone(),
+        to: row.destination.clone(),
+        amount: parse_amount(&row.amount, account.decimals)?,
+        fee: None,
+        metadata: vec![],
+        network: None,
+        spend_from: None,
+        memo: row
+            .memo
+            .as_deref()
+            .map(|memo| parse_memo(&account.blockchain, &account.standard, memo))
+            .transpose()?,
+        category: None,
+        asset_id: None,
+    })
+}
+
+/// Converts a decimal amount (e.g. `1.5`) to the smallest unit of the asset.
+fn parse_amount(amount: &str, decimals: u32) -> anyhow::Result<Nat> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        bail!("invalid amount `{amount}`");
+    }
+
+    if fraction.len() > decimals as usize {
+        bail!("the amount `{amount}` has more than {decimals} decimals");
+    }
+
+    let units = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+    let units = units
+        .parse::<Nat>()
+        .map_err(|_| anyhow!("invalid amount `{amount}`"))?;
+
+    if units == Nat::from(0u64) {
+        bail!("the amount must be greater than zero");
+    }
+
+    Ok(units)
+}
+
+/// Checks the format of the destination for the blockchain of the account, the station still
+/// validates the destination when the request is created.
+fn validate_address(blockchain: &str, standard: &str, address: &str) -> anyhow::Result<()> {
+    let is_valid = match (blockchain, standard) {
+        ("icp", "native") => is_hex(address, 64) || is_icrc1_account(address),
+        ("icp", _) => is_icrc1_account(address),
+        ("eth", _) => address
+            .strip_prefix("0x")
+            .is_some_and(|hex| is_hex(hex, 40)),
+        ("btc", _) => {
+            (26..=90).contains(&address.len()) && address.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+        _ => bail!("transfers from {blockchain} accounts are not supported"),
+    };
+
+    if !is_valid {
+        bail!("invalid {blockchain} destination `{address}`");
+    }
+
+    Ok(())
+}
+
+/// The ICP ledger only supports numeric memos, the other ledgers take up to 32 bytes.
+fn parse_memo(blockchain: &str, standard: &str, memo: &str) -> anyhow::Result<TransferMemoDTO> {
+    if let Ok(number) = memo.parse::<u64>() {
+        return Ok(TransferMemoDTO::Number(number));
+    }
+
+    if (blockchain, standard) == ("icp", "native") {
+        bail!("the memo `{memo}` must be a number for ICP transfers");
+    }
+
+    if memo.len() > 32 {
+        bail!("the memo `{memo}` is longer than 32 bytes");
+    }
+
+    Ok(TransferMemoDTO::Blob(memo.as_bytes().to_vec()))
+}
+
+fn is_hex(value: &str, len: usize) -> bool {
+    value.len() == len && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Whether the value is an ICRC-1 textual account, `<principal>` or `<principal>-<checksum>.<subaccount>`.
+fn is_icrc1_account(value: &str) -> bool {
+    match value.split_once('.') {
+        None => Principal::from_text(value).is_ok(),
+        Some((owner_and_checksum, subaccount)) => {
+            let Some((owner, checksum)) = owner_and_checksum.rsplit_once('-') else {
+                return false;
+            };
+
+            Principal::from_text(owner).is_ok()
+                && checksum.len() == 7
+                && !subaccount.is_empty()
+                && subaccount.len() <= 64
+                && !subaccount.starts_with('0')
+                && subaccount.chars().all(|c| c.is_ascii_hexdigit())
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Makes `AddUser` and `EditUser` requests to Orbit.
+
+use candid::Principal;
+use clap::{Parser, Subcommand, ValueEnum};
+use station_api::{
+    AddUserOperationInput, EditUserOperationInput, RequestOperationInput, UserStatusDTO,
+};
+
+/// Request changes to the users of the station.
+#[derive(Debug, Clone, Subcommand)]
+#[clap(version, about, long_about = None)]
+pub enum RequestUserArgs {
+    /// Request to add a user
+    Add(RequestUserAddArgs),
+    /// Request to edit a user, only the given fields are changed
+    Edit(RequestUserEditArgs),
+}
+
+impl From<RequestUserArgs> for RequestOperationInput {
+    fn from(value: RequestUserArgs) -> Self {
+        match value {
+            RequestUserArgs::Add(args) => args.into(),
+            RequestUserArgs::Edit(args) => args.into(),
+        }
+    }
+}
+
+/// Requests to add a user to the station.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestUserAddArgs {
+    /// The name of the user
+    #[clap(long)]
+    pub name: String,
+    /// A principal the user authenticates with
+    #[clap(long)]
+    pub identity: Vec<Principal>,
+    /// The ID of a group the user is a member of
+    #[clap(long)]
+    pub group: Vec<String>,
+    /// The status of the user
+    #[clap(long, value_enum, default_value_t = UserStatusArgs::Active)]
+    pub status: UserStatusArgs,
+}
+
+impl From<RequestUserAddArgs> for RequestOperationInput {
+    fn from(value: RequestUserAddArgs) -> Self {
+        RequestOperationInput::AddUser(AddUserOperationInput {
+            name: value.name,
+            identities: value.identity,
+            groups: value.group,
+            status: value.status.into(),
+            approve_only_identities: None,
+        })
+    }
+}
+
+/// Requests to edit a user of the station.
+#[derive(Debug, Clone, Parser)]
+pub struct RequestUserEditArgs {
+    /// The ID of the user
+    pub user: String,
+    /// The new name of the user
+    #[clap(long)]
+    pub name: Option<String>,
+    /// A principal the user authenticates with.  WARNING: The identities that are not listed are removed from the user.
+    #[clap(long)]
+    pub identity: Option<Vec<Principal>>,
+    /// The ID of a group the user is a member of.  WARNING: The user is removed from the groups that are not listed.
+    #[clap(long)]
+    pub group: Option<Vec<String>>,
+    /// The new status of the user
+    #[clap(long, value_enum)]
+    pub status: Option<UserStatusArgs>,
+    /// Cancel the pending requests of the user
+    #[clap(long)]
+    pub cancel_pending_requests: bool,
+}
+
+impl From<RequestUserEditArgs> for RequestOperationInput {
+    fn from(value: RequestUserEditArgs) -> Self {
+        RequestOperationInput::EditUser(EditUserOperationInput {
+            id: value.user,
+            name: value.name,
+            identities: value.identity,
+            groups: value.group,
+            status: value.status.map(Into::into),
+            cancel_pending_requests: value.cancel_pending_requests.then_some(true),
+            approve_only_identities: None,
+        })
+    }
+}
+
+/// The status of a user, equivalent to `station_api::UserStatusDTO`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, ValueEnum)]
+pub enum UserStatusArgs {
+    /// The user can use the station
+    Active,
+    /// The user can no longer use the station
+    Inactive,
+}
+
+impl From<UserStatusArgs> for UserStatusDTO {
+    fn from(value: UserStatusArgs) -> Self {
+        match value {
+            UserStatusArgs::Active => UserStatusDTO::Active,
+            UserStatusArgs::Inactive => UserStatusDTO::Inactive,
+        }
+    }
+}
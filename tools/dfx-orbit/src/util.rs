@@ -1,7 +1,23 @@
 use crate::DfxOrbit;
 use anyhow::{bail, Context};
 use dfx_core::config::model::dfinity::CanisterTypeProperties;
-use std::path::{Path, PathBuf};
+use orbit_essentials::utils::{rfc3339_to_timestamp, timestamp_to_rfc3339};
+use station_api::RequestExecutionScheduleDTO;
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const DAY_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
 
 impl DfxOrbit {
     pub(super) fn as_path_bufs(
@@ -26,6 +42,83 @@ impl DfxOrbit {
     }
 }
 
+/// Parses the time an approved request is executed at, either a date in one of the formats supported
+/// by `dateparser` or `next WEEKDAY [HH:MM]` in UTC (e.g. `next monday 09:00`).
+pub(crate) fn parse_execution_plan(
+    execute_at: &str,
+) -> anyhow::Result<RequestExecutionScheduleDTO> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+
+    let execution_time = match parse_next_weekday(execute_at, now)? {
+        Some(execution_time) => execution_time,
+        None => {
+            let date = dateparser::parse(execute_at)
+                .map_err(|err| anyhow::anyhow!("Invalid date \"{}\": {}", execute_at, err))?;
+            rfc3339_to_timestamp(&date.to_rfc3339())
+        }
+    };
+
+    if execution_time <= now {
+        bail!("The execution time \"{execute_at}\" is in the past");
+    }
+
+    Ok(RequestExecutionScheduleDTO::Scheduled {
+        execution_time: timestamp_to_rfc3339(&execution_time),
+    })
+}
+
+/// Parses `next WEEKDAY [HH:MM]`, which is the first such weekday after today at the time in UTC,
+/// midnight if unset. Returns `None` for the other formats.
+fn parse_next_weekday(execute_at: &str, now: u64) -> anyhow::Result<Option<u64>> {
+    let execute_at = execute_at.trim().to_lowercase();
+    let execute_at = execute_at
+        .strip_suffix("utc")
+        .map(str::trim_end)
+        .unwrap_or(&execute_at);
+    let Some(rest) = execute_at.strip_prefix("next ") else {
+        return Ok(None);
+    };
+
+    let mut parts = rest.split_whitespace();
+    let weekday = parts.next().unwrap_or_default();
+    let Some(weekday) = WEEKDAYS.iter().position(|day| *day == weekday) else {
+        bail!(
+            "Unknown weekday \"{weekday}\", expected one of {}",
+            WEEKDAYS.join(", ")
+        );
+    };
+
+    let time_of_day = match parts.next() {
+        Some(time) => {
+            let (hours, minutes) = time
+                .split_once(':')
+                .with_context(|| format!("Invalid time \"{time}\", expected HH:MM"))?;
+            let hours: u64 = hours.parse()?;
+            let minutes: u64 = minutes.parse()?;
+            if hours > 23 || minutes > 59 {
+                bail!("Invalid time \"{time}\", expected HH:MM");
+            }
+
+            (hours * 60 + minutes) * 60 * 1_000_000_000
+        }
+        None => 0,
+    };
+
+    if let Some(extra) = parts.next() {
+        bail!("Unexpected \"{extra}\", expected next WEEKDAY [HH:MM]");
+    }
+
+    let today = now / DAY_NS;
+    // 1970-01-01 was a thursday, the weeks start on monday
+    let today_weekday = (today + 3) % 7;
+    let days_ahead = match (weekday as u64 + 7 - today_weekday) % 7 {
+        0 => 7,
+        days_ahead => days_ahead,
+    };
+
+    Ok(Some((today + days_ahead) * DAY_NS + time_of_day))
+}
+
 /// Initalize the logger
 ///
 /// Default log level is WARN, can be turned up to TRCE by adding -v flags